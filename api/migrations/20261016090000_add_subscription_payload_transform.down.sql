@@ -0,0 +1,3 @@
+-- Dropping the column drops its CHECK constraint with it.
+ALTER TABLE webhook.subscription
+    DROP COLUMN payload_transform;
@@ -0,0 +1,14 @@
+-- Lets a subscription reshape the event payload before it is delivered: pick
+-- and rename fields, wrap the result in an envelope, add constant fields.
+--
+-- The transform is declarative on purpose: it is a JSON document the API
+-- validates and the worker interprets, never code. NULL means "deliver the
+-- payload verbatim", which is what every existing subscription keeps doing.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN payload_transform JSONB;
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_payload_transform_is_object CHECK (
+        payload_transform IS NULL OR jsonb_typeof(payload_transform) = 'object'
+    );
@@ -0,0 +1,44 @@
+-- Lets a subscription narrow the events it receives with an expression over
+-- the event labels and JSON payload fields, on top of the event type and the
+-- label equality it already had.
+--
+-- The filter is evaluated by the API, not by the dispatch trigger: the payload
+-- may live in object storage, in which case the trigger only ever sees a NULL
+-- `payload` column. The trigger therefore keeps dispatching unfiltered
+-- subscriptions and leaves filtered ones alone; the API creates their request
+-- attempts in the same transaction, from the payload it has in hand.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN filter JSONB;
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_filter_is_object CHECK (
+        filter IS NULL OR jsonb_typeof(filter) = 'object'
+    );
+
+CREATE OR REPLACE FUNCTION event.dispatch()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+AS
+$$
+BEGIN
+    IF new.dispatched_at IS NOT NULL THEN
+        RETURN new;
+    END IF;
+
+    INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id)
+    SELECT new.event__id, s.subscription__id, s.application__id
+    FROM webhook.subscription AS s
+    INNER JOIN webhook.subscription__event_type AS set ON set.subscription__id = s.subscription__id
+    WHERE s.is_enabled
+      AND s.application__id = new.application__id
+      AND s.deleted_at IS NULL
+      AND s.filter IS NULL
+      AND set.event_type__name = new.event_type__name
+      AND new.labels @> s.labels
+    FOR SHARE OF s;
+
+    UPDATE event.event SET dispatched_at = statement_timestamp() WHERE event__id = new.event__id;
+    RETURN new;
+END;
+$$;
@@ -0,0 +1 @@
+DROP TABLE webhook.bulk_retry;
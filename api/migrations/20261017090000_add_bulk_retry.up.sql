@@ -0,0 +1,40 @@
+-- Redelivers, in the background, every request attempt of an application that
+-- matches a filter, instead of having a client loop over them one by one.
+--
+-- A bulk retry is a job: the API records the filter and how many (event,
+-- subscription) pairs matched it, and a housekeeping task creates the new
+-- request attempts a batch at a time. The cursor is the last pair handled, in
+-- (event__id, subscription__id) order, so a restarted API resumes where the
+-- previous batch stopped. `max_created_at` is pinned when the job is created,
+-- so the request attempts the job creates never match its own filter.
+--
+-- Only one bulk retry per application can be running at a time: two of them
+-- over overlapping filters would race to redeliver the same events.
+
+CREATE TABLE webhook.bulk_retry (
+    bulk_retry__id UUID NOT NULL DEFAULT public.gen_random_uuid() PRIMARY KEY,
+    application__id UUID NOT NULL REFERENCES event.application (application__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    event__id UUID,
+    subscription__id UUID,
+    event_type_names TEXT[] NOT NULL DEFAULT '{}',
+    min_created_at TIMESTAMPTZ NOT NULL,
+    max_created_at TIMESTAMPTZ NOT NULL,
+    status TEXT NOT NULL,
+    total_count BIGINT NOT NULL,
+    processed_count BIGINT NOT NULL DEFAULT 0,
+    retried_count BIGINT NOT NULL DEFAULT 0,
+    cursor_event__id UUID,
+    cursor_subscription__id UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp(),
+    completed_at TIMESTAMPTZ,
+    cancelled_at TIMESTAMPTZ,
+    CONSTRAINT bulk_retry_status CHECK (status IN ('failed', 'successful')),
+    CONSTRAINT bulk_retry_cursor CHECK ((cursor_event__id IS NULL) = (cursor_subscription__id IS NULL)),
+    CONSTRAINT bulk_retry_finished_once CHECK (completed_at IS NULL OR cancelled_at IS NULL)
+);
+
+CREATE UNIQUE INDEX bulk_retry_running_idx
+    ON webhook.bulk_retry (application__id)
+    WHERE completed_at IS NULL AND cancelled_at IS NULL;
+
@@ -0,0 +1,9 @@
+DROP TABLE webhook.subscription_failure_streak;
+
+DROP INDEX webhook.subscription_auto_disabled_unnotified_idx;
+
+ALTER TABLE webhook.subscription
+    DROP COLUMN auto_disabled_notified_at,
+    DROP COLUMN auto_disabled_reason,
+    DROP COLUMN auto_disabled_at,
+    DROP COLUMN circuit_breaker;
@@ -0,0 +1,30 @@
+-- Lets a subscription opt into a circuit breaker: once its deliveries keep
+-- failing past the configured thresholds, the worker disables it and records
+-- why, and the API notifies the organization.
+--
+-- The failure streak lives in its own table rather than on the subscription:
+-- the worker bumps it on every failed delivery, and doing so on the
+-- subscription row would contend with the dispatch trigger (which takes it
+-- FOR SHARE) and with subscription edits.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN circuit_breaker JSONB,
+    ADD COLUMN auto_disabled_at TIMESTAMPTZ,
+    ADD COLUMN auto_disabled_reason TEXT,
+    ADD COLUMN auto_disabled_notified_at TIMESTAMPTZ;
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_circuit_breaker_is_object CHECK (
+        circuit_breaker IS NULL OR jsonb_typeof(circuit_breaker) = 'object'
+    );
+
+CREATE INDEX subscription_auto_disabled_unnotified_idx
+    ON webhook.subscription (auto_disabled_at)
+    WHERE auto_disabled_at IS NOT NULL AND auto_disabled_notified_at IS NULL;
+
+CREATE TABLE webhook.subscription_failure_streak (
+    subscription__id UUID NOT NULL PRIMARY KEY,
+    consecutive_failures INTEGER NOT NULL,
+    failing_since TIMESTAMPTZ NOT NULL,
+    CONSTRAINT subscription_failure_streak_subscription__id_fk FOREIGN KEY (subscription__id) REFERENCES webhook.subscription (subscription__id) ON DELETE CASCADE ON UPDATE CASCADE
+);
@@ -0,0 +1,5 @@
+ALTER TABLE webhook.subscription
+    DROP CONSTRAINT subscription_retry_policy_is_object;
+
+ALTER TABLE webhook.subscription
+    DROP COLUMN retry_policy;
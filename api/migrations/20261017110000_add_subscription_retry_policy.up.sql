@@ -0,0 +1,9 @@
+-- Lets a subscription override the retry schedule of the output workers: how
+-- many retries, the delays between them and the window they must fit in. The
+-- API validates it against the ceilings of the instance.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN retry_policy JSONB;
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_retry_policy_is_object CHECK (retry_policy IS NULL OR jsonb_typeof(retry_policy) = 'object');
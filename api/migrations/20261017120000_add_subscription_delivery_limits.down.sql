@@ -0,0 +1,11 @@
+DROP FUNCTION webhook.acquire_delivery_slot(TEXT, UUID, INTEGER, INTEGER, INTERVAL);
+
+DROP TABLE webhook.delivery_lease;
+
+DROP TABLE webhook.delivery_throttle;
+
+ALTER TABLE webhook.subscription
+    DROP CONSTRAINT subscription_delivery_limits_is_object;
+
+ALTER TABLE webhook.subscription
+    DROP COLUMN delivery_limits;
@@ -0,0 +1,116 @@
+-- Lets a subscription cap how fast and how many of its deliveries are made,
+-- optionally counting together every subscription of its application that
+-- delivers to the same host.
+--
+-- Output workers of every process share the counters through
+-- `webhook.acquire_delivery_slot`, called outside of the long transaction in
+-- which a pg worker holds the request attempt it delivers: the counters must
+-- be visible to the other workers as soon as a delivery starts. A delivery in
+-- flight holds a lease, which expires on its own if the worker dies before
+-- releasing it.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN delivery_limits JSONB;
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_delivery_limits_is_object CHECK (
+        delivery_limits IS NULL OR jsonb_typeof(delivery_limits) = 'object'
+    );
+
+CREATE TABLE webhook.delivery_throttle
+(
+    throttle_key      TEXT        NOT NULL,
+    window_started_at TIMESTAMPTZ NOT NULL,
+    started_count     INTEGER     NOT NULL DEFAULT 0,
+    refused_count     INTEGER     NOT NULL DEFAULT 0,
+    CONSTRAINT delivery_throttle_pkey PRIMARY KEY (throttle_key)
+);
+
+CREATE TABLE webhook.delivery_lease
+(
+    request_attempt__id UUID        NOT NULL,
+    throttle_key        TEXT        NOT NULL,
+    expires_at          TIMESTAMPTZ NOT NULL,
+    CONSTRAINT delivery_lease_pkey PRIMARY KEY (request_attempt__id)
+);
+
+CREATE INDEX delivery_lease_throttle_key_idx ON webhook.delivery_lease (throttle_key, expires_at);
+
+-- Returns NULL when the delivery can start now (and then holds a lease until
+-- `webhook.delivery_lease` loses the request attempt's row), or when to try
+-- it again. Refusals are spread over the following seconds, in proportion to
+-- how many were refused in the current second, so that a large backlog is
+-- not picked again all at once.
+CREATE FUNCTION webhook.acquire_delivery_slot(
+    p_throttle_key TEXT,
+    p_request_attempt_id UUID,
+    p_max_per_second INTEGER,
+    p_max_in_flight INTEGER,
+    p_lease_duration INTERVAL
+)
+    RETURNS TIMESTAMPTZ
+    LANGUAGE plpgsql
+AS
+$$
+DECLARE
+    v_now       TIMESTAMPTZ := clock_timestamp();
+    v_window    TIMESTAMPTZ := date_trunc('second', v_now);
+    v_throttle  webhook.delivery_throttle;
+    v_in_flight INTEGER;
+BEGIN
+    INSERT INTO webhook.delivery_throttle (throttle_key, window_started_at)
+    VALUES (p_throttle_key, v_window)
+    ON CONFLICT (throttle_key) DO NOTHING;
+
+    SELECT * INTO v_throttle
+    FROM webhook.delivery_throttle
+    WHERE throttle_key = p_throttle_key
+    FOR UPDATE;
+
+    IF v_throttle.window_started_at < v_window THEN
+        v_throttle.window_started_at := v_window;
+        v_throttle.started_count := 0;
+        v_throttle.refused_count := 0;
+    END IF;
+
+    IF p_max_per_second IS NOT NULL AND v_throttle.started_count >= p_max_per_second THEN
+        UPDATE webhook.delivery_throttle
+        SET window_started_at = v_throttle.window_started_at,
+            started_count = v_throttle.started_count,
+            refused_count = v_throttle.refused_count + 1
+        WHERE throttle_key = p_throttle_key;
+        RETURN v_window + make_interval(secs => 1 + v_throttle.refused_count / p_max_per_second);
+    END IF;
+
+    IF p_max_in_flight IS NOT NULL THEN
+        DELETE FROM webhook.delivery_lease
+        WHERE throttle_key = p_throttle_key
+          AND expires_at <= v_now;
+
+        SELECT COUNT(*) INTO v_in_flight
+        FROM webhook.delivery_lease
+        WHERE throttle_key = p_throttle_key
+          AND request_attempt__id <> p_request_attempt_id;
+
+        IF v_in_flight >= p_max_in_flight THEN
+            UPDATE webhook.delivery_throttle
+            SET window_started_at = v_throttle.window_started_at,
+                started_count = v_throttle.started_count,
+                refused_count = v_throttle.refused_count + 1
+            WHERE throttle_key = p_throttle_key;
+            RETURN v_now + make_interval(secs => 1 + v_throttle.refused_count / p_max_in_flight);
+        END IF;
+
+        INSERT INTO webhook.delivery_lease (request_attempt__id, throttle_key, expires_at)
+        VALUES (p_request_attempt_id, p_throttle_key, v_now + p_lease_duration)
+        ON CONFLICT (request_attempt__id) DO UPDATE SET throttle_key = excluded.throttle_key, expires_at = excluded.expires_at;
+    END IF;
+
+    UPDATE webhook.delivery_throttle
+    SET window_started_at = v_throttle.window_started_at,
+        started_count = v_throttle.started_count + 1,
+        refused_count = v_throttle.refused_count
+    WHERE throttle_key = p_throttle_key;
+    RETURN NULL;
+END;
+$$;
@@ -0,0 +1,40 @@
+-- Restores the dispatch trigger as it was before ordering keys, then drops the
+-- columns.
+
+CREATE OR REPLACE FUNCTION event.dispatch()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+AS
+$$
+BEGIN
+    IF new.dispatched_at IS NOT NULL THEN
+        RETURN new;
+    END IF;
+
+    INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id)
+    SELECT new.event__id, s.subscription__id, s.application__id
+    FROM webhook.subscription AS s
+    INNER JOIN webhook.subscription__event_type AS set ON set.subscription__id = s.subscription__id
+    WHERE s.is_enabled
+      AND s.application__id = new.application__id
+      AND s.deleted_at IS NULL
+      AND s.filter IS NULL
+      AND set.event_type__name = new.event_type__name
+      AND new.labels @> s.labels
+    FOR SHARE OF s;
+
+    UPDATE event.event SET dispatched_at = statement_timestamp() WHERE event__id = new.event__id;
+    RETURN new;
+END;
+$$;
+
+DROP INDEX webhook.request_attempt_pending_ordering_key_idx;
+
+ALTER TABLE webhook.request_attempt
+    DROP COLUMN ordering_key;
+
+ALTER TABLE webhook.subscription
+    DROP CONSTRAINT subscription_ordering_key_is_object;
+
+ALTER TABLE webhook.subscription
+    DROP COLUMN ordering_key;
@@ -0,0 +1,51 @@
+-- Lets a subscription deliver the events that share a key (a label or a JSON
+-- payload field) one at a time, in the order they were received.
+--
+-- The key of each request attempt is stored with it, and carried over to the
+-- retries. Like filters, it may come from a payload the dispatch trigger does
+-- not see, so the trigger leaves ordered subscriptions to the API. Workers do
+-- not deliver a request attempt while an attempt of an earlier event with the
+-- same key, for the same subscription, is still pending.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN ordering_key JSONB;
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_ordering_key_is_object CHECK (
+        ordering_key IS NULL OR jsonb_typeof(ordering_key) = 'object'
+    );
+
+ALTER TABLE webhook.request_attempt
+    ADD COLUMN ordering_key TEXT;
+
+CREATE INDEX request_attempt_pending_ordering_key_idx
+    ON webhook.request_attempt (subscription__id, ordering_key)
+    WHERE ordering_key IS NOT NULL AND succeeded_at IS NULL AND failed_at IS NULL;
+
+CREATE OR REPLACE FUNCTION event.dispatch()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+AS
+$$
+BEGIN
+    IF new.dispatched_at IS NOT NULL THEN
+        RETURN new;
+    END IF;
+
+    INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id)
+    SELECT new.event__id, s.subscription__id, s.application__id
+    FROM webhook.subscription AS s
+    INNER JOIN webhook.subscription__event_type AS set ON set.subscription__id = s.subscription__id
+    WHERE s.is_enabled
+      AND s.application__id = new.application__id
+      AND s.deleted_at IS NULL
+      AND s.filter IS NULL
+      AND s.ordering_key IS NULL
+      AND set.event_type__name = new.event_type__name
+      AND new.labels @> s.labels
+    FOR SHARE OF s;
+
+    UPDATE event.event SET dispatched_at = statement_timestamp() WHERE event__id = new.event__id;
+    RETURN new;
+END;
+$$;
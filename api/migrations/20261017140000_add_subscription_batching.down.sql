@@ -0,0 +1,26 @@
+DROP INDEX webhook.request_attempt_delivery_batch__id_idx;
+
+-- Only the earliest request attempt of each batch keeps the response
+UPDATE webhook.request_attempt AS ra
+SET response__id = NULL
+WHERE ra.delivery_batch__id IS NOT NULL
+    AND EXISTS (
+        SELECT 1
+        FROM webhook.request_attempt AS other
+        WHERE other.response__id = ra.response__id
+            AND (other.created_at, other.request_attempt__id) < (ra.created_at, ra.request_attempt__id)
+    );
+
+DROP INDEX webhook.request_attempt_response__id_idx;
+
+ALTER TABLE webhook.request_attempt
+    ADD CONSTRAINT request_attempt_response__id_key UNIQUE (response__id);
+
+ALTER TABLE webhook.request_attempt
+    DROP COLUMN delivery_batch__id;
+
+ALTER TABLE webhook.subscription
+    DROP CONSTRAINT subscription_batching_is_object;
+
+ALTER TABLE webhook.subscription
+    DROP COLUMN batching;
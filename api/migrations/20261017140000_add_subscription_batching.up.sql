@@ -0,0 +1,28 @@
+-- Lets a subscription receive its events in batches: up to a number of events,
+-- or whatever is pending once the oldest has waited long enough, sent together
+-- as one HTTP request whose body is a JSON array.
+--
+-- Every request attempt delivered in a batch records the batch it was part of,
+-- and they all share the one response of the batch: a response is no longer
+-- linked to a single request attempt.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN batching JSONB;
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_batching_is_object CHECK (
+        batching IS NULL OR jsonb_typeof(batching) = 'object'
+    );
+
+ALTER TABLE webhook.request_attempt
+    ADD COLUMN delivery_batch__id UUID;
+
+ALTER TABLE webhook.request_attempt
+    DROP CONSTRAINT request_attempt_response__id_key;
+
+CREATE INDEX request_attempt_response__id_idx
+    ON webhook.request_attempt (response__id);
+
+CREATE INDEX request_attempt_delivery_batch__id_idx
+    ON webhook.request_attempt (delivery_batch__id)
+    WHERE delivery_batch__id IS NOT NULL;
@@ -0,0 +1 @@
+DROP TABLE webhook.subscription_client_certificate;
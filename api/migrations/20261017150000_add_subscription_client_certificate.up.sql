@@ -0,0 +1,27 @@
+-- Lets a subscription present a client certificate to its target, for targets
+-- that require mutual TLS.
+--
+-- The private key is encrypted with an instance key the API and the workers
+-- share (AES-256-GCM, the subscription ID as associated data): the nonce comes
+-- first, then the ciphertext. The certificate itself is public and stored as
+-- uploaded, with what was read from it at upload time.
+--
+-- `expiry_notified_at` records that the organization was warned the
+-- certificate is about to expire; replacing the certificate resets it.
+
+CREATE TABLE webhook.subscription_client_certificate (
+    subscription__id UUID NOT NULL PRIMARY KEY REFERENCES webhook.subscription (subscription__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    certificate_pem TEXT NOT NULL,
+    encrypted_private_key BYTEA NOT NULL,
+    subject TEXT NOT NULL,
+    fingerprint_sha256 TEXT NOT NULL,
+    not_before TIMESTAMPTZ NOT NULL,
+    not_after TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp(),
+    expiry_notified_at TIMESTAMPTZ
+);
+
+CREATE INDEX subscription_client_certificate_not_after_idx
+    ON webhook.subscription_client_certificate (not_after)
+    WHERE expiry_notified_at IS NULL;
@@ -0,0 +1 @@
+DROP TABLE webhook.subscription_oauth2_client_credentials;
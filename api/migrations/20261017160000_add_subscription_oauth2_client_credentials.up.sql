@@ -0,0 +1,17 @@
+-- Lets a subscription authenticate to its target with an OAuth2 access token
+-- obtained with the client credentials grant (RFC 6749 section 4.4).
+--
+-- The output workers request the token from `token_url`, cache it until it
+-- expires and send it as a bearer token with every delivery. The client secret
+-- is stored the same way as the headers of HTTP targets, and is never returned
+-- by the API.
+
+CREATE TABLE webhook.subscription_oauth2_client_credentials (
+    subscription__id UUID NOT NULL PRIMARY KEY REFERENCES webhook.subscription (subscription__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    token_url TEXT NOT NULL,
+    client_id TEXT NOT NULL,
+    client_secret TEXT NOT NULL,
+    scopes TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp()
+);
@@ -0,0 +1,3 @@
+DROP VIEW webhook.target_message_queue;
+DROP TABLE webhook.target_sqs;
+DROP TABLE webhook.target_amqp;
@@ -0,0 +1,47 @@
+-- Lets a subscription deliver its events to a message queue instead of an HTTP
+-- endpoint: an exchange of an AMQP 0.9.1 broker, or an SQS-compatible queue.
+--
+-- Like `webhook.target_http`, each kind of target is a table inheriting
+-- `webhook.target`. Credentials are stored the same way as the headers of HTTP
+-- targets, and are never returned by the API.
+
+CREATE TABLE webhook.target_amqp (
+    target__id UUID NOT NULL DEFAULT public.gen_random_uuid() PRIMARY KEY REFERENCES webhook.subscription (target__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    username TEXT NOT NULL,
+    password TEXT NOT NULL,
+    exchange TEXT NOT NULL,
+    routing_key TEXT NOT NULL
+)
+INHERITS (webhook.target);
+
+CREATE TABLE webhook.target_sqs (
+    target__id UUID NOT NULL DEFAULT public.gen_random_uuid() PRIMARY KEY REFERENCES webhook.subscription (target__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    queue_url TEXT NOT NULL,
+    region TEXT NOT NULL,
+    access_key_id TEXT NOT NULL,
+    secret_access_key TEXT NOT NULL
+)
+INHERITS (webhook.target);
+
+-- What the output workers deliver to when a subscription's target is a message
+-- queue, in the shape they read it
+CREATE VIEW webhook.target_message_queue AS
+    SELECT target__id, jsonb_build_object(
+        'type', 'amqp',
+        'url', url,
+        'username', username,
+        'password', password,
+        'exchange', exchange,
+        'routing_key', routing_key
+    ) AS target
+    FROM webhook.target_amqp
+    UNION ALL
+    SELECT target__id, jsonb_build_object(
+        'type', 'sqs',
+        'queue_url', queue_url,
+        'region', region,
+        'access_key_id', access_key_id,
+        'secret_access_key', secret_access_key
+    ) AS target
+    FROM webhook.target_sqs;
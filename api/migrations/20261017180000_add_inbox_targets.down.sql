@@ -0,0 +1,2 @@
+DROP TABLE webhook.inbox_lease;
+DROP TABLE webhook.target_inbox;
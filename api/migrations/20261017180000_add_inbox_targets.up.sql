@@ -0,0 +1,20 @@
+-- Lets a subscription hold its events in an inbox that the consumer drains
+-- through the API, instead of having them pushed by the output workers.
+--
+-- Like the other kinds of target, an inbox is a table inheriting
+-- `webhook.target`; it has nothing to configure. Events are still request
+-- attempts: pulling one leases it (`picked_at` is set) until the lease expires,
+-- and acknowledging or rejecting it sets `succeeded_at` or `failed_at`.
+
+CREATE TABLE webhook.target_inbox (
+    target__id UUID NOT NULL DEFAULT public.gen_random_uuid() PRIMARY KEY REFERENCES webhook.subscription (target__id) ON UPDATE CASCADE ON DELETE CASCADE
+)
+INHERITS (webhook.target);
+
+-- Current lease of a request attempt pulled from an inbox; the receipt is what
+-- the consumer settles it with, and is replaced every time the attempt is leased
+CREATE TABLE webhook.inbox_lease (
+    request_attempt__id UUID NOT NULL PRIMARY KEY REFERENCES webhook.request_attempt (request_attempt__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    receipt UUID NOT NULL UNIQUE,
+    leased_until TIMESTAMPTZ NOT NULL
+);
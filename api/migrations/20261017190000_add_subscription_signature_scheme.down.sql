@@ -0,0 +1,2 @@
+ALTER TABLE webhook.subscription
+    DROP COLUMN signature_scheme;
@@ -0,0 +1,12 @@
+-- Lets a subscription choose how its deliveries are signed: with the Hook0
+-- signature header (`hook0`), or with the `webhook-id`, `webhook-timestamp` and
+-- `webhook-signature` headers of the Standard Webhooks specification
+-- (`standard_webhooks`), which many consumer frameworks verify out of the box.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN signature_scheme TEXT NOT NULL DEFAULT 'hook0';
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_signature_scheme CHECK (
+        signature_scheme IN ('hook0', 'standard_webhooks')
+    );
@@ -0,0 +1 @@
+DROP TABLE event.application_signing_key;
@@ -0,0 +1,23 @@
+-- Ed25519 key pairs an application signs its deliveries with, on top of the
+-- HMAC signatures keyed with the secrets of its subscriptions: consumers
+-- verify them with the public keys the API publishes, and cannot forge them.
+--
+-- The newest key that is not retired signs; older ones stay published until
+-- they are retired, so that deliveries signed before a rotation can still be
+-- verified. The private key is a PKCS#8 document encrypted the same way as the
+-- private keys of client certificates (AES-256-GCM with the instance key, the
+-- application ID as associated data, the nonce first), and is never returned
+-- by the API.
+
+CREATE TABLE event.application_signing_key (
+    signing_key__id UUID NOT NULL DEFAULT public.gen_random_uuid() PRIMARY KEY,
+    application__id UUID NOT NULL REFERENCES event.application (application__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    public_key BYTEA NOT NULL,
+    encrypted_private_key BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp(),
+    retired_at TIMESTAMPTZ
+);
+
+CREATE INDEX application_signing_key_application__id_idx
+    ON event.application_signing_key (application__id, created_at DESC)
+    WHERE retired_at IS NULL;
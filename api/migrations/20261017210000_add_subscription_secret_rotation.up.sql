@@ -0,0 +1,14 @@
+-- Lets the secret of a subscription be rotated without downtime: the secret it
+-- replaces is kept as `previous_secret`, and deliveries are signed with both
+-- until `previous_secret_expires_at`, so that consumers can switch secrets at
+-- their own pace. Finalizing the rotation forgets the previous secret; rolling
+-- it back makes it the secret again.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN previous_secret UUID,
+    ADD COLUMN previous_secret_expires_at TIMESTAMPTZ;
+
+ALTER TABLE webhook.subscription
+    ADD CONSTRAINT subscription_previous_secret CHECK (
+        (previous_secret IS NULL) = (previous_secret_expires_at IS NULL)
+    );
@@ -0,0 +1,3 @@
+DROP TRIGGER request_attempt_notify ON webhook.request_attempt;
+
+DROP FUNCTION webhook.notify_request_attempts();
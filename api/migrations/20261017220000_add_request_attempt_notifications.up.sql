@@ -0,0 +1,31 @@
+-- Wakes idle Postgres-backed workers up when request attempts are ready to be
+-- delivered, instead of leaving them to notice at their next poll.
+--
+-- Whatever inserts request attempts (the dispatch trigger, the API, workers
+-- scheduling retries), a notification is sent on the `webhook_request_attempt`
+-- channel once per statement that inserted at least one attempt that is due
+-- now. Attempts delayed to later are still found by polling, as are attempts
+-- whose notification a worker missed.
+
+CREATE FUNCTION webhook.notify_request_attempts()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+AS
+$$
+BEGIN
+    IF EXISTS (
+        SELECT 1
+        FROM new_request_attempts
+        WHERE delay_until IS NULL OR delay_until <= statement_timestamp()
+    ) THEN
+        PERFORM pg_notify('webhook_request_attempt', '');
+    END IF;
+    RETURN NULL;
+END;
+$$;
+
+CREATE TRIGGER request_attempt_notify
+    AFTER INSERT ON webhook.request_attempt
+    REFERENCING NEW TABLE AS new_request_attempts
+    FOR EACH STATEMENT
+    EXECUTE FUNCTION webhook.notify_request_attempts();
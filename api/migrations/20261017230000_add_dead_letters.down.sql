@@ -0,0 +1,4 @@
+ALTER TABLE webhook.subscription
+    DROP COLUMN dead_letters_notified_at;
+
+DROP TABLE webhook.dead_letter;
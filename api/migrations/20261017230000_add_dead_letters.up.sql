@@ -0,0 +1,40 @@
+-- Deliveries the worker gave up on, kept per subscription until they are
+-- redelivered or purged: when retries are exhausted, or when the delivery
+-- could not be signed or transformed and retrying would fail the same way.
+-- Deliveries of subscriptions that were disabled or deleted are not dead
+-- letters, since nobody is waiting for them anymore.
+--
+-- A dead letter points to the last request attempt of the delivery, whose
+-- response tells why it failed. Redelivering it creates a new request attempt
+-- and removes the dead letter; a redelivery that fails for good again becomes
+-- a new dead letter.
+--
+-- Once a subscription has at least the threshold of the instance in dead
+-- letters, the API sends an event and stamps `dead_letters_notified_at`, so
+-- that it is sent once; the stamp is cleared when the subscription goes back
+-- below the threshold.
+
+CREATE TABLE webhook.dead_letter (
+    dead_letter__id UUID NOT NULL DEFAULT public.gen_random_uuid() PRIMARY KEY,
+    application__id UUID NOT NULL REFERENCES event.application (application__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    subscription__id UUID NOT NULL REFERENCES webhook.subscription (subscription__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    event__id UUID NOT NULL REFERENCES event.event (event__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    request_attempt__id UUID NOT NULL UNIQUE REFERENCES webhook.request_attempt (request_attempt__id) ON UPDATE CASCADE ON DELETE CASCADE,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp(),
+    CONSTRAINT dead_letter_reason CHECK (
+        reason IN ('retries_exhausted', 'signature_failed', 'transform_failed')
+    )
+);
+
+CREATE INDEX dead_letter_subscription__id_idx
+    ON webhook.dead_letter (subscription__id, created_at DESC);
+
+CREATE INDEX dead_letter_application__id_idx
+    ON webhook.dead_letter (application__id, created_at DESC);
+
+CREATE INDEX dead_letter_event__id_idx
+    ON webhook.dead_letter (event__id);
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN dead_letters_notified_at TIMESTAMPTZ;
@@ -0,0 +1,16 @@
+DELETE FROM webhook.dead_letter
+WHERE reason = 'permanent_failure';
+
+ALTER TABLE webhook.dead_letter
+    DROP CONSTRAINT dead_letter_reason;
+
+ALTER TABLE webhook.dead_letter
+    ADD CONSTRAINT dead_letter_reason CHECK (
+        reason IN ('retries_exhausted', 'signature_failed', 'transform_failed')
+    );
+
+ALTER TABLE webhook.response
+    DROP COLUMN rule_outcome;
+
+ALTER TABLE webhook.subscription
+    DROP COLUMN response_rules;
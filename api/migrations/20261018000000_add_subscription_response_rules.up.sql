@@ -0,0 +1,29 @@
+-- Lets a subscription decide the outcome of its HTTP deliveries from their
+-- response rather than from the status code alone: each rule matches status
+-- codes, optionally along with a text of the body or a header, and maps them
+-- to `success`, `retryable`, `permanent_failure` (the delivery gives up right
+-- away) or `disable_subscription` (it gives up and the subscription is
+-- disabled). The first rule that matches wins; a response no rule matches
+-- keeps the default behaviour.
+--
+-- The outcome a rule gave is kept on the response, so that the status of the
+-- request attempt tells why it succeeded or failed.
+
+ALTER TABLE webhook.subscription
+    ADD COLUMN response_rules JSONB;
+
+ALTER TABLE webhook.response
+    ADD COLUMN rule_outcome TEXT;
+
+ALTER TABLE webhook.response
+    ADD CONSTRAINT response_rule_outcome CHECK (
+        rule_outcome IN ('success', 'retryable', 'permanent_failure', 'disable_subscription')
+    );
+
+ALTER TABLE webhook.dead_letter
+    DROP CONSTRAINT dead_letter_reason;
+
+ALTER TABLE webhook.dead_letter
+    ADD CONSTRAINT dead_letter_reason CHECK (
+        reason IN ('retries_exhausted', 'signature_failed', 'transform_failed', 'permanent_failure')
+    );
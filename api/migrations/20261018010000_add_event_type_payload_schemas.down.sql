@@ -0,0 +1,5 @@
+ALTER TABLE event.event_type
+    DROP COLUMN payload_schema_mode;
+
+ALTER TABLE event.event_type
+    DROP COLUMN payload_schema;
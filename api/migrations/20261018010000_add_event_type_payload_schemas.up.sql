@@ -0,0 +1,17 @@
+-- Lets an event type describe its payloads with a JSON Schema. The payloads of
+-- `application/json` events of this type are validated against it at
+-- ingestion, according to the mode of the event type: `enforce` refuses the
+-- events whose payload does not match, `warn` ingests them anyway and only
+-- reports the violations, and `off` skips the validation while keeping the
+-- schema around.
+
+ALTER TABLE event.event_type
+    ADD COLUMN payload_schema JSONB;
+
+ALTER TABLE event.event_type
+    ADD COLUMN payload_schema_mode TEXT NOT NULL DEFAULT 'enforce';
+
+ALTER TABLE event.event_type
+    ADD CONSTRAINT event_type_payload_schema_mode CHECK (
+        payload_schema_mode IN ('enforce', 'warn', 'off')
+    );
@@ -0,0 +1,72 @@
+-- Lets the payload contract of an event type evolve through numbered versions.
+-- A version can carry its own payload schema, used instead of the one of the
+-- event type for the events that declare it.
+--
+-- An event may declare the version it was emitted with, and a subscription may
+-- pin a version of each of its event types: it then only receives the events
+-- of that type that declare the pinned version, or no version at all.
+--
+-- A version can be deprecated with a sunset date, after which the events that
+-- declare it are refused.
+
+CREATE TABLE event.event_type_version (
+    application__id UUID NOT NULL,
+    event_type__name TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    payload_schema JSONB,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT statement_timestamp(),
+    deprecated_at TIMESTAMPTZ,
+    sunset_at TIMESTAMPTZ,
+    CONSTRAINT event_type_version_pkey PRIMARY KEY (application__id, event_type__name, version),
+    CONSTRAINT event_type_version_event_type__name_fkey FOREIGN KEY (application__id, event_type__name)
+        REFERENCES event.event_type (application__id, event_type__name) ON UPDATE CASCADE ON DELETE CASCADE,
+    CONSTRAINT event_type_version_is_positive CHECK (version >= 1),
+    CONSTRAINT event_type_version_sunset_is_deprecated CHECK (sunset_at IS NULL OR deprecated_at IS NOT NULL)
+);
+
+ALTER TABLE event.event
+    ADD COLUMN event_type_version INTEGER;
+
+ALTER TABLE event.event
+    ADD CONSTRAINT event_event_type_version_fkey FOREIGN KEY (application__id, event_type__name, event_type_version)
+        REFERENCES event.event_type_version (application__id, event_type__name, version) ON UPDATE RESTRICT ON DELETE RESTRICT;
+
+ALTER TABLE webhook.subscription__event_type
+    ADD COLUMN event_type_version INTEGER;
+
+ALTER TABLE webhook.subscription__event_type
+    ADD CONSTRAINT subscription__event_type_event_type_version_fkey FOREIGN KEY (application__id, event_type__name, event_type_version)
+        REFERENCES event.event_type_version (application__id, event_type__name, version) ON UPDATE CASCADE ON DELETE CASCADE;
+
+CREATE INDEX subscription__event_type_event_type_version_idx
+    ON webhook.subscription__event_type (application__id, event_type__name, event_type_version)
+    WHERE event_type_version IS NOT NULL;
+
+CREATE OR REPLACE FUNCTION event.dispatch()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+AS
+$$
+BEGIN
+    IF new.dispatched_at IS NOT NULL THEN
+        RETURN new;
+    END IF;
+
+    INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id)
+    SELECT new.event__id, s.subscription__id, s.application__id
+    FROM webhook.subscription AS s
+    INNER JOIN webhook.subscription__event_type AS set ON set.subscription__id = s.subscription__id
+    WHERE s.is_enabled
+      AND s.application__id = new.application__id
+      AND s.deleted_at IS NULL
+      AND s.filter IS NULL
+      AND s.ordering_key IS NULL
+      AND set.event_type__name = new.event_type__name
+      AND (set.event_type_version IS NULL OR new.event_type_version IS NULL OR set.event_type_version = new.event_type_version)
+      AND new.labels @> s.labels
+    FOR SHARE OF s;
+
+    UPDATE event.event SET dispatched_at = statement_timestamp() WHERE event__id = new.event__id;
+    RETURN new;
+END;
+$$;
@@ -0,0 +1,42 @@
+-- Restores the dispatch trigger as it was before scheduled event delivery, then
+-- drops the delivery and cancellation dates of events and the cancellation date
+-- of request attempts.
+
+CREATE OR REPLACE FUNCTION event.dispatch()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+AS
+$$
+BEGIN
+    IF new.dispatched_at IS NOT NULL THEN
+        RETURN new;
+    END IF;
+
+    INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id)
+    SELECT new.event__id, s.subscription__id, s.application__id
+    FROM webhook.subscription AS s
+    INNER JOIN webhook.subscription__event_type AS set ON set.subscription__id = s.subscription__id
+    WHERE s.is_enabled
+      AND s.application__id = new.application__id
+      AND s.deleted_at IS NULL
+      AND s.filter IS NULL
+      AND s.ordering_key IS NULL
+      AND set.event_type__name = new.event_type__name
+      AND (set.event_type_version IS NULL OR new.event_type_version IS NULL OR set.event_type_version = new.event_type_version)
+      AND new.labels @> s.labels
+    FOR SHARE OF s;
+
+    UPDATE event.event SET dispatched_at = statement_timestamp() WHERE event__id = new.event__id;
+    RETURN new;
+END;
+$$;
+
+DROP INDEX event.event_application__id_deliver_at_idx;
+
+ALTER TABLE event.event
+    DROP COLUMN cancelled_at,
+    DROP COLUMN deliver_at;
+
+ALTER TABLE webhook.request_attempt
+    DROP CONSTRAINT request_attempt_cancelled_is_failed,
+    DROP COLUMN cancelled_at;
@@ -0,0 +1,48 @@
+-- Lets an event be ingested now and delivered later: the request attempts of an
+-- event with a delivery date wait until then before being picked up.
+--
+-- A scheduled event can be cancelled as long as it has not been delivered; its
+-- pending request attempts are then failed and it is not listed as scheduled
+-- anymore. They keep the date they were cancelled at, which tells them apart
+-- from deliveries that failed.
+
+ALTER TABLE event.event
+    ADD COLUMN deliver_at TIMESTAMPTZ,
+    ADD COLUMN cancelled_at TIMESTAMPTZ;
+
+ALTER TABLE webhook.request_attempt
+    ADD COLUMN cancelled_at TIMESTAMPTZ,
+    ADD CONSTRAINT request_attempt_cancelled_is_failed CHECK (cancelled_at IS NULL OR failed_at IS NOT NULL);
+
+CREATE INDEX event_application__id_deliver_at_idx
+    ON event.event (application__id, deliver_at)
+    WHERE deliver_at IS NOT NULL AND cancelled_at IS NULL;
+
+CREATE OR REPLACE FUNCTION event.dispatch()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+AS
+$$
+BEGIN
+    IF new.dispatched_at IS NOT NULL THEN
+        RETURN new;
+    END IF;
+
+    INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, delay_until)
+    SELECT new.event__id, s.subscription__id, s.application__id, new.deliver_at
+    FROM webhook.subscription AS s
+    INNER JOIN webhook.subscription__event_type AS set ON set.subscription__id = s.subscription__id
+    WHERE s.is_enabled
+      AND s.application__id = new.application__id
+      AND s.deleted_at IS NULL
+      AND s.filter IS NULL
+      AND s.ordering_key IS NULL
+      AND set.event_type__name = new.event_type__name
+      AND (set.event_type_version IS NULL OR new.event_type_version IS NULL OR set.event_type_version = new.event_type_version)
+      AND new.labels @> s.labels
+    FOR SHARE OF s;
+
+    UPDATE event.event SET dispatched_at = statement_timestamp() WHERE event__id = new.event__id;
+    RETURN new;
+END;
+$$;
@@ -0,0 +1,14 @@
+ALTER TABLE webhook.request_attempt
+    DROP CONSTRAINT request_attempt_expired_is_failed;
+
+ALTER TABLE webhook.request_attempt
+    DROP COLUMN expired_at;
+
+ALTER TABLE event.event
+    DROP COLUMN expires_at;
+
+ALTER TABLE event.event_type
+    DROP CONSTRAINT event_type_default_ttl_s_is_positive;
+
+ALTER TABLE event.event_type
+    DROP COLUMN default_ttl_s;
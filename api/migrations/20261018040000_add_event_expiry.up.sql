@@ -0,0 +1,23 @@
+-- Lets an event stop being delivered once it is no longer relevant: past its
+-- expiry date, the request attempts of an event that are still pending or
+-- waiting are cancelled instead of being delivered or retried.
+--
+-- The expiry date of an event is given at ingestion, or derived from the
+-- default time to live of its event type. A request attempt cancelled this way
+-- is failed and keeps the date it expired at, which tells it apart from a
+-- delivery that failed.
+
+ALTER TABLE event.event_type
+    ADD COLUMN default_ttl_s INTEGER;
+
+ALTER TABLE event.event_type
+    ADD CONSTRAINT event_type_default_ttl_s_is_positive CHECK (default_ttl_s IS NULL OR default_ttl_s >= 1);
+
+ALTER TABLE event.event
+    ADD COLUMN expires_at TIMESTAMPTZ;
+
+ALTER TABLE webhook.request_attempt
+    ADD COLUMN expired_at TIMESTAMPTZ;
+
+ALTER TABLE webhook.request_attempt
+    ADD CONSTRAINT request_attempt_expired_is_failed CHECK (expired_at IS NULL OR failed_at IS NOT NULL);
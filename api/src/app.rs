@@ -227,6 +227,12 @@ pub fn build_app(
                 .service(web::scope("/payload_content_types").service(
                     web::resource("").route(web::get().to(handlers::events::payload_content_types)),
                 ))
+                .service(
+                    web::scope("/jwks").service(
+                        web::resource("/{application_id}")
+                            .route(web::get().to(handlers::signing_keys::jwks)),
+                    ),
+                )
                 .service(web::scope("/register").service(
                     web::resource("").route(web::post().to(handlers::registrations::register)),
                 ))
@@ -272,6 +278,20 @@ pub fn build_app(
                                 .route(web::delete().to(handlers::applications::delete)),
                         ),
                 )
+                .service(
+                    web::scope("/signing_keys")
+                        .wrap(Compat::new(rate_limiters.token())) // Middleware order is counter intuitive: this is executed second
+                        .wrap(biscuit_auth.clone()) // Middleware order is counter intuitive: this is executed first
+                        .service(
+                            web::resource("")
+                                .route(web::get().to(handlers::signing_keys::list))
+                                .route(web::post().to(handlers::signing_keys::create)),
+                        )
+                        .service(
+                            web::resource("/{signing_key_id}")
+                                .route(web::delete().to(handlers::signing_keys::delete)),
+                        ),
+                )
                 .service(
                     web::scope("/event_types")
                         .wrap(Compat::new(rate_limiters.token())) // Middleware order is counter intuitive: this is executed second
@@ -284,7 +304,16 @@ pub fn build_app(
                         .service(
                             web::resource("/{event_type_name}")
                                 .route(web::get().to(handlers::event_types::get))
+                                .route(web::put().to(handlers::event_types::edit))
                                 .route(web::delete().to(handlers::event_types::delete)),
+                        )
+                        .service(
+                            web::resource("/{event_type_name}/versions")
+                                .route(web::post().to(handlers::event_types::create_version)),
+                        )
+                        .service(
+                            web::resource("/{event_type_name}/deprecate")
+                                .route(web::post().to(handlers::event_types::deprecate_version)),
                         ),
                 )
                 .service(
@@ -326,6 +355,11 @@ pub fn build_app(
                         .wrap(Compat::new(rate_limiters.token())) // Middleware order is counter intuitive: this is executed second
                         .wrap(biscuit_auth.clone()) // Middleware order is counter intuitive: this is executed first/ Middleware order is counter intuitive: this is executed first
                         .service(web::resource("").route(web::get().to(handlers::events::list)))
+                        // Registered before `/{event_id}`, which would otherwise capture it
+                        .service(
+                            web::resource("/batch")
+                                .route(web::post().to(handlers::events::ingest_batch)),
+                        )
                         .service(
                             web::resource("/{event_id}")
                                 .route(web::get().to(handlers::events::get)),
@@ -333,6 +367,10 @@ pub fn build_app(
                         .service(
                             web::resource("/{event_id}/replay")
                                 .route(web::post().to(handlers::events::replay)),
+                        )
+                        .service(
+                            web::resource("/{event_id}/cancel")
+                                .route(web::post().to(handlers::events::cancel)),
                         ),
                 )
                 .service(
@@ -368,6 +406,50 @@ pub fn build_app(
                                 .route(web::get().to(handlers::subscriptions::get))
                                 .route(web::put().to(handlers::subscriptions::edit))
                                 .route(web::delete().to(handlers::subscriptions::delete)),
+                        )
+                        .service(
+                            web::resource("/{subscription_id}/client_certificate")
+                                .route(web::get().to(handlers::client_certificates::get))
+                                .route(web::put().to(handlers::client_certificates::edit))
+                                .route(web::delete().to(handlers::client_certificates::delete)),
+                        )
+                        .service(
+                            web::resource("/{subscription_id}/oauth2_client_credentials")
+                                .route(web::get().to(handlers::oauth2_client_credentials::get))
+                                .route(web::put().to(handlers::oauth2_client_credentials::edit))
+                                .route(
+                                    web::delete().to(handlers::oauth2_client_credentials::delete),
+                                ),
+                        )
+                        .service(
+                            web::resource("/{subscription_id}/secret_rotation")
+                                .route(web::get().to(handlers::secret_rotations::get))
+                                .route(web::post().to(handlers::secret_rotations::create)),
+                        )
+                        .service(
+                            web::resource("/{subscription_id}/secret_rotation/finalize")
+                                .route(web::post().to(handlers::secret_rotations::finalize)),
+                        )
+                        .service(
+                            web::resource("/{subscription_id}/secret_rotation/rollback")
+                                .route(web::post().to(handlers::secret_rotations::rollback)),
+                        ),
+                )
+                .service(
+                    web::scope("/inbox")
+                        .wrap(Compat::new(rate_limiters.token())) // Middleware order is counter intuitive: this is executed second
+                        .wrap(biscuit_auth.clone()) // Middleware order is counter intuitive: this is executed first
+                        .service(
+                            web::resource("/{subscription_id}")
+                                .route(web::get().to(handlers::inbox::pull)),
+                        )
+                        .service(
+                            web::resource("/{subscription_id}/ack")
+                                .route(web::post().to(handlers::inbox::ack)),
+                        )
+                        .service(
+                            web::resource("/{subscription_id}/nack")
+                                .route(web::post().to(handlers::inbox::nack)),
                         ),
                 )
                 .service(
@@ -381,6 +463,57 @@ pub fn build_app(
                         .service(
                             web::resource("/{request_attempt_id}")
                                 .route(web::get().to(handlers::request_attempts::get)),
+                        )
+                        .service(
+                            web::resource("/{request_attempt_id}/retry")
+                                .route(web::post().to(handlers::request_attempts::retry)),
+                        ),
+                )
+                .service(
+                    web::scope("/dead_letters")
+                        .wrap(Compat::new(rate_limiters.token())) // Middleware order is counter intuitive: this is executed second
+                        .wrap(biscuit_auth.clone()) // Middleware order is counter intuitive: this is executed first
+                        .service(
+                            web::resource("").route(web::get().to(handlers::dead_letters::list)),
+                        )
+                        .service(
+                            web::resource("/redeliver")
+                                .route(web::post().to(handlers::dead_letters::redeliver_many)),
+                        )
+                        .service(
+                            web::resource("/purge")
+                                .route(web::post().to(handlers::dead_letters::purge)),
+                        )
+                        .service(
+                            web::resource("/{dead_letter_id}")
+                                .route(web::get().to(handlers::dead_letters::get))
+                                .route(web::delete().to(handlers::dead_letters::delete)),
+                        )
+                        .service(
+                            web::resource("/{dead_letter_id}/redeliver")
+                                .route(web::post().to(handlers::dead_letters::redeliver)),
+                        ),
+                )
+                .service(
+                    web::scope("/bulk_retries")
+                        .wrap(Compat::new(rate_limiters.token())) // Middleware order is counter intuitive: this is executed second
+                        .wrap(biscuit_auth.clone()) // Middleware order is counter intuitive: this is executed first
+                        .service(
+                            web::resource("")
+                                .route(web::get().to(handlers::bulk_retries::list))
+                                .route(web::post().to(handlers::bulk_retries::create)),
+                        )
+                        .service(
+                            web::resource("/dry_run")
+                                .route(web::post().to(handlers::bulk_retries::dry_run)),
+                        )
+                        .service(
+                            web::resource("/{bulk_retry_id}")
+                                .route(web::get().to(handlers::bulk_retries::get)),
+                        )
+                        .service(
+                            web::resource("/{bulk_retry_id}/cancel")
+                                .route(web::post().to(handlers::bulk_retries::cancel)),
                         ),
                 )
                 .service(
@@ -508,6 +641,10 @@ pub(crate) mod test_support {
             cloudflare_turnstile_secret_key: None,
             google_ads: None,
             signup_attribution_retention_in_days: 30,
+            max_subscription_retries: 24,
+            max_subscription_retry_window: Duration::from_secs(8 * 24 * 60 * 60),
+            client_certificate_encryption_key: None,
+            payload_schemas: Default::default(),
         }
     }
 
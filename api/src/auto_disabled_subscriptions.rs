@@ -0,0 +1,339 @@
+//! Follow-up of the subscriptions a circuit breaker or a response rule disabled.
+//!
+//! The worker disables a subscription in the transaction of the failed delivery that tripped its
+//! breaker or matched a `disable_subscription` response rule, and leaves the rest to this job: the
+//! request attempts still pending are cancelled, the editors of the organization are emailed and
+//! an `api.subscription.disabled` event is sent.
+//!
+//! A subscription is claimed by stamping `auto_disabled_notified_at`, in the transaction that
+//! cancels its pending request attempts, so it is followed up once across API instances. Mails and
+//! events go out after the commit: at most once, never twice.
+
+use actix_web::rt::time::sleep;
+use chrono::{DateTime, Utc};
+use hook0_client::Hook0Client;
+use lettre::Address;
+use lettre::message::Mailbox;
+use sqlx::{PgPool, query, query_as};
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::hook0_client::{EventSubscriptionDisabled, Hook0ClientEvent};
+use crate::mailer::{Mail, Mailer};
+use crate::opentelemetry::report_cancelled_request_attempts;
+
+const STARTUP_GRACE_PERIOD: Duration = Duration::from_secs(15);
+
+/// Upper bound on how many subscriptions a single pass follows up.
+const MAX_PER_RUN: i64 = 100;
+
+#[derive(Debug)]
+struct AutoDisabledSubscription {
+    subscription_id: Uuid,
+    application_id: Uuid,
+    organization_id: Uuid,
+    application_name: String,
+    description: Option<String>,
+    url: Option<String>,
+    auto_disabled_at: DateTime<Utc>,
+    auto_disabled_reason: Option<String>,
+    /// Neither the subscription nor its application was deleted since.
+    is_live: bool,
+}
+
+impl AutoDisabledSubscription {
+    /// How the subscription is called in the mail: its description, or else where it delivers.
+    fn name(&self) -> String {
+        self.description
+            .clone()
+            .or_else(|| self.url.clone())
+            .unwrap_or_else(|| self.subscription_id.to_string())
+    }
+
+    fn reason(&self) -> String {
+        self.auto_disabled_reason
+            .clone()
+            .unwrap_or_else(|| "Deliveries kept failing".to_owned())
+    }
+}
+
+pub async fn periodically_follow_up_auto_disabled_subscriptions(
+    db: &PgPool,
+    mailer: Mailer,
+    hook0_client: Option<Hook0Client>,
+    period: Duration,
+) {
+    sleep(STARTUP_GRACE_PERIOD).await;
+
+    loop {
+        match follow_up_auto_disabled_subscriptions(db, &mailer, hook0_client.as_ref()).await {
+            Ok(0) => {}
+            Ok(n) => info!("Followed up {n} subscriptions disabled by their circuit breaker"),
+            Err(e) => {
+                error!("Could not follow up subscriptions disabled by their circuit breaker: {e}")
+            }
+        }
+
+        sleep(period).await;
+    }
+}
+
+/// One pass: claim the subscriptions not followed up yet and cancel their pending request
+/// attempts, then notify. Returns how many subscriptions were followed up.
+async fn follow_up_auto_disabled_subscriptions(
+    db: &PgPool,
+    mailer: &Mailer,
+    hook0_client: Option<&Hook0Client>,
+) -> Result<usize, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let subscriptions = query_as!(
+        AutoDisabledSubscription,
+        r#"
+            SELECT
+                s.subscription__id AS subscription_id,
+                s.application__id AS application_id,
+                a.organization__id AS organization_id,
+                a.name AS application_name,
+                s.description,
+                COALESCE(th.url, ta.url, ts.queue_url) AS "url?",
+                s.auto_disabled_at AS "auto_disabled_at!",
+                s.auto_disabled_reason,
+                (s.deleted_at IS NULL AND a.deleted_at IS NULL) AS "is_live!"
+            FROM webhook.subscription AS s
+            INNER JOIN event.application AS a ON a.application__id = s.application__id
+            LEFT JOIN webhook.target_http AS th ON th.target__id = s.target__id
+            LEFT JOIN webhook.target_amqp AS ta ON ta.target__id = s.target__id
+            LEFT JOIN webhook.target_sqs AS ts ON ts.target__id = s.target__id
+            WHERE s.auto_disabled_at IS NOT NULL
+                AND s.auto_disabled_notified_at IS NULL
+            ORDER BY s.auto_disabled_at ASC
+            LIMIT $1
+            FOR UPDATE OF s SKIP LOCKED
+        "#,
+        MAX_PER_RUN,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    if subscriptions.is_empty() {
+        return Ok(0);
+    }
+    let subscription_ids = subscriptions
+        .iter()
+        .map(|s| s.subscription_id)
+        .collect::<Vec<_>>();
+
+    let cancelled_request_attempts = query!(
+        "
+            UPDATE webhook.request_attempt
+            SET failed_at = statement_timestamp()
+            WHERE subscription__id = ANY($1)
+                AND failed_at IS NULL
+                AND succeeded_at IS NULL
+        ",
+        &subscription_ids,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    query!(
+        "
+            UPDATE webhook.subscription
+            SET auto_disabled_notified_at = statement_timestamp()
+            WHERE subscription__id = ANY($1)
+        ",
+        &subscription_ids,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    if cancelled_request_attempts > 0 {
+        report_cancelled_request_attempts(cancelled_request_attempts);
+    }
+
+    for subscription in subscriptions.iter().filter(|s| s.is_live) {
+        if let Err(e) = send_emails(db, mailer, subscription).await {
+            error!(subscription_id = %subscription.subscription_id, "Could not email the organization about a subscription disabled by its circuit breaker: {e}");
+        }
+
+        if let Some(hook0_client) = hook0_client {
+            let hook0_client_event: Hook0ClientEvent = EventSubscriptionDisabled {
+                organization_id: subscription.organization_id,
+                application_id: subscription.application_id,
+                subscription_id: subscription.subscription_id,
+                disabled_at: subscription.auto_disabled_at,
+                reason: subscription.reason(),
+            }
+            .into();
+            if let Err(e) = hook0_client
+                .send_event(&hook0_client_event.mk_hook0_event())
+                .await
+            {
+                error!("Hook0ClientError: {e}");
+            };
+        }
+    }
+
+    Ok(subscriptions.len())
+}
+
+/// Email every editor of the organization; a recipient that cannot be reached does not prevent
+/// the others from being.
+async fn send_emails(
+    db: &PgPool,
+    mailer: &Mailer,
+    subscription: &AutoDisabledSubscription,
+) -> Result<(), sqlx::Error> {
+    struct Recipient {
+        first_name: String,
+        last_name: String,
+        email: String,
+    }
+    let recipients = query_as!(
+        Recipient,
+        r#"
+            SELECT u.first_name, u.last_name, u.email
+            FROM iam.user AS u
+            INNER JOIN iam.user__organization AS ou ON ou.user__id = u.user__id
+            WHERE ou.organization__id = $1
+                AND ou.role = 'editor'
+        "#,
+        subscription.organization_id,
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut mail = Mail::SubscriptionDisabled {
+        recipient_first_name: None,
+        application_name: subscription.application_name.clone(),
+        subscription_name: subscription.name(),
+        reason: subscription.reason(),
+        extra_variables: vec![],
+    };
+    let subscription_url = mailer.build_tracked_app_url(
+        &mail,
+        &format!(
+            "/organizations/{}/applications/{}/subscriptions/{}",
+            subscription.organization_id, subscription.application_id, subscription.subscription_id
+        ),
+    );
+    mail.add_variable("subscription_url_tracked".to_owned(), subscription_url);
+
+    for recipient in recipients {
+        let address = match Address::from_str(&recipient.email) {
+            Ok(address) => address,
+            Err(e) => {
+                warn!("Error trying to parse email address: {e}");
+                continue;
+            }
+        };
+        let mut recipient_mail = mail.clone();
+        if let Mail::SubscriptionDisabled {
+            recipient_first_name,
+            ..
+        } = &mut recipient_mail
+        {
+            *recipient_first_name = Some(recipient.first_name.clone());
+        }
+        let mailbox = Mailbox::new(
+            Some(format!("{} {}", recipient.first_name, recipient.last_name)),
+            address,
+        );
+        if let Err(e) = mailer.send_mail(recipient_mail, mailbox).await {
+            warn!(subscription_id = %subscription.subscription_id, "Could not send email: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google_ads::test_support::{
+        seed_event, seed_membership, seed_org, seed_subscription, seed_user, test_state_with_smtp,
+    };
+    use crate::mailer::test_support::FakeSmtp;
+    use biscuit_auth::KeyPair;
+
+    async fn seed_auto_disabled_subscription(pool: &PgPool, application_id: Uuid) -> Uuid {
+        let subscription_id =
+            seed_subscription(pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+        sqlx::query(
+            r#"
+                UPDATE webhook.subscription
+                SET is_enabled = false, circuit_breaker = '{"max_consecutive_failures":3}'::jsonb, auto_disabled_at = statement_timestamp(), auto_disabled_reason = '3 consecutive failed deliveries (limit is 3)'
+                WHERE subscription__id = $1
+            "#,
+        )
+        .bind(subscription_id)
+        .execute(pool)
+        .await
+        .expect("seed auto-disabled state");
+        subscription_id
+    }
+
+    async fn pending_request_attempts(pool: &PgPool, subscription_id: Uuid) -> i64 {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM webhook.request_attempt WHERE subscription__id = $1 AND failed_at IS NULL AND succeeded_at IS NULL",
+        )
+        .bind(subscription_id)
+        .fetch_one(pool)
+        .await
+        .expect("count pending request attempts")
+    }
+
+    /// A disabled subscription gets its pending deliveries cancelled and its organization's
+    /// editors (not viewers) emailed, once: the next pass finds nothing left to do.
+    #[sqlx::test]
+    async fn editors_are_notified_once_and_pending_attempts_cancelled(pool: PgPool) {
+        let smtp = FakeSmtp::start();
+        let editor = seed_user(&pool).await;
+        let org = seed_org(&pool, editor).await;
+        seed_membership(&pool, editor, org, "editor").await;
+        let viewer = seed_user(&pool).await;
+        seed_membership(&pool, viewer, org, "viewer").await;
+        let (application_id, event_id) = seed_event(&pool, org).await;
+        let subscription_id = seed_auto_disabled_subscription(&pool, application_id).await;
+        sqlx::query(
+            "INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id) VALUES ($1, $2, $3)",
+        )
+        .bind(event_id)
+        .bind(subscription_id)
+        .bind(application_id)
+        .execute(&pool)
+        .await
+        .expect("seed pending request attempt");
+
+        let state = test_state_with_smtp(
+            pool.clone(),
+            KeyPair::new().private(),
+            None,
+            &smtp.connection_url,
+        )
+        .await;
+
+        let followed_up = follow_up_auto_disabled_subscriptions(&pool, &state.mailer, None)
+            .await
+            .expect("first pass");
+        assert_eq!(followed_up, 1);
+        assert_eq!(pending_request_attempts(&pool, subscription_id).await, 0);
+        assert_eq!(
+            smtp.wait_for(1, Duration::from_secs(5)).await,
+            1,
+            "only the editor is emailed"
+        );
+
+        let followed_up = follow_up_auto_disabled_subscriptions(&pool, &state.mailer, None)
+            .await
+            .expect("second pass");
+        assert_eq!(followed_up, 0);
+        assert_eq!(smtp.delivered(), 1);
+    }
+}
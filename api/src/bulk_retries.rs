@@ -0,0 +1,587 @@
+//! Background redelivery of the request attempts matching a filter.
+//!
+//! A bulk retry redelivers each matching (event, subscription) pair once, by creating a new
+//! request attempt for it, exactly like retrying a single request attempt would. Pairs are handled
+//! a batch at a time, in `(event__id, subscription__id)` order, and the job row remembers the last
+//! pair handled; a batch and the cursor move are committed together, so a crash never redelivers
+//! a pair twice nor skips one. Batches are spaced by a fixed period, which is what keeps a large
+//! bulk retry from flooding the workers.
+
+use actix_web::rt::time::sleep;
+use chrono::{DateTime, Utc};
+use paperclip::actix::Apiv2Schema;
+use serde::{Deserialize, Serialize};
+use sqlx::{PgConnection, PgExecutor, PgPool, query, query_as, query_scalar};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tracing::{debug, error, info};
+use uuid::Uuid;
+
+use crate::handlers::events::{load_event_payload, send_request_attempts_to_pulsar};
+use crate::opentelemetry::report_retried_request_attempts;
+use crate::problems::Hook0Problem;
+use crate::{ObjectStorageConfig, PulsarConfig};
+
+const STARTUP_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Outcome of the request attempts a bulk retry redelivers
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Apiv2Schema)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkRetryStatus {
+    /// Request attempts that failed for good: pairs that were eventually delivered are left alone
+    #[default]
+    Failed,
+    /// Request attempts that succeeded, for a target that lost what it received
+    Successful,
+}
+
+impl BulkRetryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Failed => "failed",
+            Self::Successful => "successful",
+        }
+    }
+
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "successful" => Self::Successful,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// Which request attempts of an application a bulk retry redelivers.
+#[derive(Debug, Clone)]
+pub struct BulkRetryFilter {
+    pub event_id: Option<Uuid>,
+    pub subscription_id: Option<Uuid>,
+    pub event_type_names: Vec<String>,
+    pub min_created_at: DateTime<Utc>,
+    pub max_created_at: DateTime<Utc>,
+    pub status: BulkRetryStatus,
+}
+
+/// Count the (event, subscription) pairs a bulk retry with this filter would redeliver now.
+///
+/// A pair is left out when its subscription is disabled or deleted, when a delivery of it is still
+/// pending, and, when retrying failures, when it was eventually delivered or was cancelled along
+/// with its scheduled event.
+pub async fn count_matching_pairs<'e, E: PgExecutor<'e>>(
+    executor: E,
+    application_id: Uuid,
+    filter: &BulkRetryFilter,
+) -> Result<i64, sqlx::Error> {
+    query_scalar!(
+        r#"
+            SELECT COUNT(*) AS "count!"
+            FROM (
+                SELECT DISTINCT ra.event__id, ra.subscription__id
+                FROM webhook.request_attempt AS ra
+                INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
+                INNER JOIN event.event AS e ON e.event__id = ra.event__id
+                WHERE ra.application__id = $1
+                    AND (ra.event__id = $2 OR $2 IS NULL)
+                    AND (ra.subscription__id = $3 OR $3 IS NULL)
+                    AND ra.created_at BETWEEN $4 AND $5
+                    AND (e.event_type__name = any($6) OR $6 = '{}')
+                    AND CASE WHEN $7 = 'successful' THEN ra.succeeded_at IS NOT NULL ELSE ra.failed_at IS NOT NULL AND ra.cancelled_at IS NULL END
+                    AND s.is_enabled AND s.deleted_at IS NULL
+                    AND NOT EXISTS (
+                        SELECT 1
+                        FROM webhook.request_attempt AS other
+                        WHERE other.event__id = ra.event__id
+                            AND other.subscription__id = ra.subscription__id
+                            AND other.failed_at IS NULL
+                            AND (other.succeeded_at IS NULL OR $7 = 'failed')
+                    )
+            ) AS pairs
+        "#,
+        application_id,
+        filter.event_id,
+        filter.subscription_id,
+        filter.min_created_at,
+        filter.max_created_at,
+        &filter.event_type_names,
+        filter.status.as_str(),
+    )
+    .fetch_one(executor)
+    .await
+}
+
+pub async fn periodically_process_bulk_retries(
+    housekeeping_semaphore: &Semaphore,
+    db: &PgPool,
+    pulsar: Option<Arc<PulsarConfig>>,
+    object_storage: Option<ObjectStorageConfig>,
+    period: Duration,
+    batch_size: u16,
+) {
+    sleep(STARTUP_GRACE_PERIOD).await;
+
+    while let Ok(permit) = housekeeping_semaphore.acquire().await {
+        match process_next_batch(db, pulsar.as_ref(), object_storage.as_ref(), batch_size).await {
+            // Nothing to do: there is no point in polling faster than the batches would be spaced
+            Ok(None) => {}
+            Ok(Some(batch)) => {
+                debug!(
+                    "Bulk retry {} redelivered {} request attempts out of {} pairs",
+                    batch.bulk_retry_id, batch.retried, batch.processed
+                );
+                if batch.completed {
+                    info!("Bulk retry {} is completed", batch.bulk_retry_id);
+                }
+            }
+            Err(e) => error!("Could not process bulk retry: {e}"),
+        }
+        drop(permit);
+
+        sleep(period).await;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ProcessedBatch {
+    bulk_retry_id: Uuid,
+    processed: usize,
+    retried: usize,
+    completed: bool,
+}
+
+/// Handle the next batch of the running bulk retry that waited the longest.
+///
+/// Running bulk retries take turns, so a large one does not hold back the others. The job row is
+/// locked until the batch is committed; another API instance picks another job meanwhile.
+async fn process_next_batch(
+    db: &PgPool,
+    pulsar: Option<&Arc<PulsarConfig>>,
+    object_storage: Option<&ObjectStorageConfig>,
+    batch_size: u16,
+) -> Result<Option<ProcessedBatch>, Hook0Problem> {
+    let mut tx = db.begin().await?;
+
+    #[allow(non_snake_case)]
+    struct RawBulkRetry {
+        bulk_retry__id: Uuid,
+        application__id: Uuid,
+        event__id: Option<Uuid>,
+        subscription__id: Option<Uuid>,
+        event_type_names: Vec<String>,
+        min_created_at: DateTime<Utc>,
+        max_created_at: DateTime<Utc>,
+        status: String,
+        cursor_event__id: Option<Uuid>,
+        cursor_subscription__id: Option<Uuid>,
+    }
+    let Some(job) = query_as!(
+        RawBulkRetry,
+        "
+            SELECT
+                bulk_retry__id,
+                application__id,
+                event__id,
+                subscription__id,
+                event_type_names,
+                min_created_at,
+                max_created_at,
+                status,
+                cursor_event__id,
+                cursor_subscription__id
+            FROM webhook.bulk_retry
+            WHERE completed_at IS NULL AND cancelled_at IS NULL
+            ORDER BY updated_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+        ",
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    else {
+        return Ok(None);
+    };
+    let status = BulkRetryStatus::from_db(&job.status);
+
+    #[allow(non_snake_case)]
+    struct RawPair {
+        event__id: Uuid,
+        subscription__id: Uuid,
+    }
+    let pairs = query_as!(
+        RawPair,
+        r#"
+            SELECT DISTINCT ra.event__id, ra.subscription__id
+            FROM webhook.request_attempt AS ra
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
+            INNER JOIN event.event AS e ON e.event__id = ra.event__id
+            WHERE ra.application__id = $1
+                AND (ra.event__id = $2 OR $2 IS NULL)
+                AND (ra.subscription__id = $3 OR $3 IS NULL)
+                AND ra.created_at BETWEEN $4 AND $5
+                AND (e.event_type__name = any($6) OR $6 = '{}')
+                AND CASE WHEN $7 = 'successful' THEN ra.succeeded_at IS NOT NULL ELSE ra.failed_at IS NOT NULL AND ra.cancelled_at IS NULL END
+                AND s.is_enabled AND s.deleted_at IS NULL
+                AND NOT EXISTS (
+                    SELECT 1
+                    FROM webhook.request_attempt AS other
+                    WHERE other.event__id = ra.event__id
+                        AND other.subscription__id = ra.subscription__id
+                        AND other.failed_at IS NULL
+                        AND (other.succeeded_at IS NULL OR $7 = 'failed')
+                )
+                AND (ra.event__id, ra.subscription__id) > ($8, $9)
+            ORDER BY ra.event__id, ra.subscription__id
+            LIMIT $10
+        "#,
+        job.application__id,
+        job.event__id,
+        job.subscription__id,
+        job.min_created_at,
+        job.max_created_at,
+        &job.event_type_names,
+        status.as_str(),
+        job.cursor_event__id.unwrap_or_else(Uuid::nil),
+        job.cursor_subscription__id.unwrap_or_else(Uuid::nil),
+        i64::from(batch_size),
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let (event_ids, subscription_ids): (Vec<Uuid>, Vec<Uuid>) = pairs
+        .iter()
+        .map(|p| (p.event__id, p.subscription__id))
+        .unzip();
+
+    // Same lock as retrying a single request attempt, so that the pending check of the INSERT
+    // below holds until the new request attempts are committed
+    query!(
+        "
+            SELECT subscription__id
+            FROM webhook.subscription
+            WHERE subscription__id = ANY($1)
+            ORDER BY subscription__id
+            FOR UPDATE
+        ",
+        &subscription_ids,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    #[allow(non_snake_case)]
+    struct RawNewRequestAttempt {
+        request_attempt__id: Uuid,
+        event__id: Uuid,
+    }
+    let new_request_attempts = query_as!(
+        RawNewRequestAttempt,
+        "
+            INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, ordering_key)
+            SELECT $1, pair.event__id, pair.subscription__id, (
+                SELECT last.ordering_key
+                FROM webhook.request_attempt AS last
+                WHERE last.event__id = pair.event__id
+                    AND last.subscription__id = pair.subscription__id
+                ORDER BY last.created_at DESC
+                LIMIT 1
+            )
+            FROM UNNEST($2::UUID[], $3::UUID[]) AS pair (event__id, subscription__id)
+            WHERE NOT EXISTS (
+                SELECT 1
+                FROM webhook.request_attempt AS other
+                WHERE other.event__id = pair.event__id
+                    AND other.subscription__id = pair.subscription__id
+                    AND other.failed_at IS NULL
+                    AND (other.succeeded_at IS NULL OR $4 = 'failed')
+            )
+            RETURNING request_attempt__id, event__id
+        ",
+        job.application__id,
+        &event_ids,
+        &subscription_ids,
+        status.as_str(),
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let completed = pairs.len() < usize::from(batch_size);
+    let last_pair = pairs.last();
+    query!(
+        "
+            UPDATE webhook.bulk_retry
+            SET processed_count = processed_count + $2,
+                retried_count = retried_count + $3,
+                cursor_event__id = COALESCE($4, cursor_event__id),
+                cursor_subscription__id = COALESCE($5, cursor_subscription__id),
+                updated_at = statement_timestamp(),
+                completed_at = CASE WHEN $6 THEN statement_timestamp() END
+            WHERE bulk_retry__id = $1
+        ",
+        job.bulk_retry__id,
+        i64::try_from(pairs.len()).unwrap_or(i64::MAX),
+        i64::try_from(new_request_attempts.len()).unwrap_or(i64::MAX),
+        last_pair.map(|p| p.event__id),
+        last_pair.map(|p| p.subscription__id),
+        completed,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if let Some(pulsar) = pulsar {
+        send_retries_to_pulsar(
+            &mut tx,
+            pulsar,
+            object_storage,
+            job.application__id,
+            new_request_attempts
+                .iter()
+                .map(|ra| (ra.event__id, ra.request_attempt__id)),
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+    report_retried_request_attempts(u64::try_from(new_request_attempts.len()).unwrap_or(u64::MAX));
+
+    Ok(Some(ProcessedBatch {
+        bulk_retry_id: job.bulk_retry__id,
+        processed: pairs.len(),
+        retried: new_request_attempts.len(),
+        completed,
+    }))
+}
+
+/// Send request attempts just created to redeliver events, given as `(event_id, request_attempt_id)`, to Pulsar.
+pub async fn send_retries_to_pulsar(
+    conn: &mut PgConnection,
+    pulsar: &Arc<PulsarConfig>,
+    object_storage: Option<&ObjectStorageConfig>,
+    application_id: Uuid,
+    new_request_attempts: impl IntoIterator<Item = (Uuid, Uuid)>,
+) -> Result<(), Hook0Problem> {
+    let mut request_attempts_by_event = HashMap::<Uuid, Vec<Uuid>>::new();
+    for (event_id, request_attempt_id) in new_request_attempts {
+        request_attempts_by_event
+            .entry(event_id)
+            .or_default()
+            .push(request_attempt_id);
+    }
+    if request_attempts_by_event.is_empty() {
+        return Ok(());
+    }
+
+    #[allow(non_snake_case)]
+    struct RawEvent {
+        event__id: Uuid,
+        event_type__name: String,
+        received_at: DateTime<Utc>,
+        payload: Option<Vec<u8>>,
+        payload_content_type: String,
+    }
+    let events = query_as!(
+        RawEvent,
+        "
+            SELECT event__id, event_type__name, received_at, payload, payload_content_type
+            FROM event.event
+            WHERE event__id = ANY($1)
+        ",
+        &request_attempts_by_event
+            .keys()
+            .copied()
+            .collect::<Vec<_>>(),
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for event in events {
+        let payload = load_event_payload(
+            object_storage,
+            application_id,
+            event.event__id,
+            event.received_at,
+            event.payload,
+        )
+        .await
+        .ok_or(Hook0Problem::InternalServerError)?;
+
+        for request_attempt_id in request_attempts_by_event
+            .get(&event.event__id)
+            .into_iter()
+            .flatten()
+        {
+            send_request_attempts_to_pulsar(
+                &mut *conn,
+                pulsar,
+                application_id,
+                event.event__id,
+                event.received_at,
+                &event.event_type__name,
+                &payload,
+                &event.payload_content_type,
+                Some(*request_attempt_id),
+                true,
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google_ads::test_support::{seed_event, seed_org, seed_subscription, seed_user};
+
+    async fn seed_e2e_subscription(pool: &PgPool, application_id: Uuid, is_enabled: bool) -> Uuid {
+        let subscription_id =
+            seed_subscription(pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+        sqlx::query("UPDATE webhook.subscription SET is_enabled = $2 WHERE subscription__id = $1")
+            .bind(subscription_id)
+            .bind(is_enabled)
+            .execute(pool)
+            .await
+            .expect("seed subscription state");
+        subscription_id
+    }
+
+    async fn seed_finished_request_attempt(
+        pool: &PgPool,
+        application_id: Uuid,
+        event_id: Uuid,
+        subscription_id: Uuid,
+        succeeded: bool,
+    ) {
+        sqlx::query(
+            r#"
+                INSERT INTO webhook.request_attempt
+                    (event__id, subscription__id, application__id, picked_at, failed_at, succeeded_at)
+                VALUES (
+                    $1, $2, $3, statement_timestamp(),
+                    CASE WHEN $4 THEN NULL ELSE statement_timestamp() END,
+                    CASE WHEN $4 THEN statement_timestamp() END
+                )
+            "#,
+        )
+        .bind(event_id)
+        .bind(subscription_id)
+        .bind(application_id)
+        .bind(succeeded)
+        .execute(pool)
+        .await
+        .expect("seed request attempt");
+    }
+
+    /// Only pairs that failed for good, on an enabled subscription, are redelivered; each of them
+    /// exactly once, whatever the batch size. A cancelled delivery did not fail.
+    #[sqlx::test]
+    async fn failed_pairs_are_redelivered_once_batch_by_batch(pool: PgPool) {
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        let (application_id, event_1) = seed_event(&pool, org).await;
+        let event_2: Uuid = sqlx::query_scalar(
+            r#"
+                INSERT INTO event.event (application__id, event_type__name, payload_content_type, ip, occurred_at)
+                VALUES ($1, 'test.resource.created', 'application/json', '127.0.0.1'::inet, statement_timestamp())
+                RETURNING event__id
+            "#,
+        )
+        .bind(application_id)
+        .fetch_one(&pool)
+        .await
+        .expect("seed second event");
+
+        let down = seed_e2e_subscription(&pool, application_id, true).await;
+        let recovered = seed_e2e_subscription(&pool, application_id, true).await;
+        let disabled = seed_e2e_subscription(&pool, application_id, false).await;
+        let cancelled = seed_e2e_subscription(&pool, application_id, true).await;
+
+        seed_finished_request_attempt(&pool, application_id, event_1, down, false).await;
+        seed_finished_request_attempt(&pool, application_id, event_2, down, false).await;
+        // Failed, then delivered by an automatic retry
+        seed_finished_request_attempt(&pool, application_id, event_1, recovered, false).await;
+        seed_finished_request_attempt(&pool, application_id, event_1, recovered, true).await;
+        seed_finished_request_attempt(&pool, application_id, event_2, recovered, true).await;
+        seed_finished_request_attempt(&pool, application_id, event_2, disabled, false).await;
+        // Cancelled along with its scheduled event
+        seed_finished_request_attempt(&pool, application_id, event_1, cancelled, false).await;
+        sqlx::query(
+            "UPDATE webhook.request_attempt SET cancelled_at = failed_at WHERE subscription__id = $1",
+        )
+        .bind(cancelled)
+        .execute(&pool)
+        .await
+        .expect("cancel request attempt");
+
+        let filter = BulkRetryFilter {
+            event_id: None,
+            subscription_id: None,
+            event_type_names: vec!["test.resource.created".to_owned()],
+            min_created_at: DateTime::<Utc>::UNIX_EPOCH,
+            max_created_at: Utc::now(),
+            status: BulkRetryStatus::Failed,
+        };
+        let total_count = count_matching_pairs(&pool, application_id, &filter)
+            .await
+            .unwrap();
+        assert_eq!(total_count, 2);
+
+        let bulk_retry_id: Uuid = sqlx::query_scalar(
+            r#"
+                INSERT INTO webhook.bulk_retry
+                    (application__id, event_type_names, min_created_at, max_created_at, status, total_count)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING bulk_retry__id
+            "#,
+        )
+        .bind(application_id)
+        .bind(&filter.event_type_names)
+        .bind(filter.min_created_at)
+        .bind(filter.max_created_at)
+        .bind(filter.status.as_str())
+        .bind(total_count)
+        .fetch_one(&pool)
+        .await
+        .expect("seed bulk retry");
+
+        let mut batches = Vec::new();
+        while let Some(batch) = process_next_batch(&pool, None, None, 1).await.unwrap() {
+            assert_eq!(batch.bulk_retry_id, bulk_retry_id);
+            batches.push((batch.processed, batch.retried, batch.completed));
+            assert!(batches.len() <= 3, "the bulk retry never completes");
+        }
+        assert_eq!(batches, [(1, 1, false), (1, 1, false), (0, 0, true)]);
+
+        let (processed_count, retried_count, completed): (i64, i64, bool) = sqlx::query_as(
+            "SELECT processed_count, retried_count, completed_at IS NOT NULL FROM webhook.bulk_retry WHERE bulk_retry__id = $1",
+        )
+        .bind(bulk_retry_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!((processed_count, retried_count, completed), (2, 2, true));
+
+        let pending: Vec<(Uuid, Uuid)> = sqlx::query_as(
+            r#"
+                SELECT event__id, subscription__id
+                FROM webhook.request_attempt
+                WHERE succeeded_at IS NULL AND failed_at IS NULL
+                ORDER BY event__id
+            "#,
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let mut expected = vec![(event_1, down), (event_2, down)];
+        expected.sort();
+        assert_eq!(pending, expected);
+
+        // Both pairs now have a pending delivery, so nothing would be redelivered twice
+        assert_eq!(
+            count_matching_pairs(&pool, application_id, &filter)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
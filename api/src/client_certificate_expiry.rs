@@ -0,0 +1,271 @@
+//! Warning about the client certificates of subscriptions that are about to expire.
+//!
+//! A certificate is claimed by stamping `expiry_notified_at`, so it is warned about once across API
+//! instances; uploading a new certificate clears the stamp. Mails go out after the commit: at most
+//! once, never twice.
+
+use actix_web::rt::time::sleep;
+use chrono::{DateTime, Utc};
+use lettre::Address;
+use lettre::message::Mailbox;
+use sqlx::{PgPool, query, query_as};
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::mailer::{Mail, Mailer};
+
+const STARTUP_GRACE_PERIOD: Duration = Duration::from_secs(15);
+
+/// Upper bound on how many certificates a single pass warns about.
+const MAX_PER_RUN: i64 = 100;
+
+#[derive(Debug)]
+struct ExpiringClientCertificate {
+    subscription_id: Uuid,
+    application_id: Uuid,
+    organization_id: Uuid,
+    application_name: String,
+    description: Option<String>,
+    url: Option<String>,
+    subject: String,
+    not_after: DateTime<Utc>,
+}
+
+impl ExpiringClientCertificate {
+    /// How the subscription is called in the mail: its description, or else where it delivers.
+    fn subscription_name(&self) -> String {
+        self.description
+            .clone()
+            .or_else(|| self.url.clone())
+            .unwrap_or_else(|| self.subscription_id.to_string())
+    }
+}
+
+pub async fn periodically_warn_about_expiring_client_certificates(
+    db: &PgPool,
+    mailer: Mailer,
+    warning: Duration,
+    period: Duration,
+) {
+    sleep(STARTUP_GRACE_PERIOD).await;
+
+    loop {
+        match warn_about_expiring_client_certificates(db, &mailer, warning).await {
+            Ok(0) => {}
+            Ok(n) => info!("Warned about {n} client certificates about to expire"),
+            Err(e) => error!("Could not warn about client certificates about to expire: {e}"),
+        }
+
+        sleep(period).await;
+    }
+}
+
+/// One pass: claim the certificates of live subscriptions that expire within `warning` and were
+/// not warned about yet, then email. Returns how many certificates were warned about.
+async fn warn_about_expiring_client_certificates(
+    db: &PgPool,
+    mailer: &Mailer,
+    warning: Duration,
+) -> Result<usize, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let certificates = query_as!(
+        ExpiringClientCertificate,
+        r#"
+            SELECT
+                s.subscription__id AS subscription_id,
+                s.application__id AS application_id,
+                a.organization__id AS organization_id,
+                a.name AS application_name,
+                s.description,
+                th.url AS "url?",
+                scc.subject,
+                scc.not_after
+            FROM webhook.subscription_client_certificate AS scc
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = scc.subscription__id
+            INNER JOIN event.application AS a ON a.application__id = s.application__id
+            LEFT JOIN webhook.target_http AS th ON th.target__id = s.target__id
+            WHERE scc.expiry_notified_at IS NULL
+                AND scc.not_after < statement_timestamp() + $1::interval
+                AND s.deleted_at IS NULL
+                AND a.deleted_at IS NULL
+            ORDER BY scc.not_after ASC
+            LIMIT $2
+            FOR UPDATE OF scc SKIP LOCKED
+        "#,
+        warning as _,
+        MAX_PER_RUN,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    if certificates.is_empty() {
+        return Ok(0);
+    }
+    let subscription_ids = certificates
+        .iter()
+        .map(|c| c.subscription_id)
+        .collect::<Vec<_>>();
+
+    query!(
+        "
+            UPDATE webhook.subscription_client_certificate
+            SET expiry_notified_at = statement_timestamp()
+            WHERE subscription__id = ANY($1)
+        ",
+        &subscription_ids,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    for certificate in &certificates {
+        warn!(
+            subscription_id = %certificate.subscription_id,
+            not_after = %certificate.not_after,
+            "Client certificate of subscription is about to expire"
+        );
+        if let Err(e) = send_emails(db, mailer, certificate).await {
+            error!(subscription_id = %certificate.subscription_id, "Could not email the organization about a client certificate about to expire: {e}");
+        }
+    }
+
+    Ok(certificates.len())
+}
+
+/// Email every editor of the organization; a recipient that cannot be reached does not prevent
+/// the others from being.
+async fn send_emails(
+    db: &PgPool,
+    mailer: &Mailer,
+    certificate: &ExpiringClientCertificate,
+) -> Result<(), sqlx::Error> {
+    struct Recipient {
+        first_name: String,
+        last_name: String,
+        email: String,
+    }
+    let recipients = query_as!(
+        Recipient,
+        r#"
+            SELECT u.first_name, u.last_name, u.email
+            FROM iam.user AS u
+            INNER JOIN iam.user__organization AS ou ON ou.user__id = u.user__id
+            WHERE ou.organization__id = $1
+                AND ou.role = 'editor'
+        "#,
+        certificate.organization_id,
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut mail = Mail::ClientCertificateExpiring {
+        recipient_first_name: None,
+        application_name: certificate.application_name.clone(),
+        subscription_name: certificate.subscription_name(),
+        certificate_subject: certificate.subject.clone(),
+        expires_at: certificate
+            .not_after
+            .format("%Y-%m-%d %H:%M UTC")
+            .to_string(),
+        extra_variables: vec![],
+    };
+    let subscription_url = mailer.build_tracked_app_url(
+        &mail,
+        &format!(
+            "/organizations/{}/applications/{}/subscriptions/{}",
+            certificate.organization_id, certificate.application_id, certificate.subscription_id
+        ),
+    );
+    mail.add_variable("subscription_url_tracked".to_owned(), subscription_url);
+
+    for recipient in recipients {
+        let address = match Address::from_str(&recipient.email) {
+            Ok(address) => address,
+            Err(e) => {
+                warn!("Error trying to parse email address: {e}");
+                continue;
+            }
+        };
+        let mut recipient_mail = mail.clone();
+        if let Mail::ClientCertificateExpiring {
+            recipient_first_name,
+            ..
+        } = &mut recipient_mail
+        {
+            *recipient_first_name = Some(recipient.first_name.clone());
+        }
+        let mailbox = Mailbox::new(
+            Some(format!("{} {}", recipient.first_name, recipient.last_name)),
+            address,
+        );
+        if let Err(e) = mailer.send_mail(recipient_mail, mailbox).await {
+            warn!(subscription_id = %certificate.subscription_id, "Could not send email: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google_ads::test_support::{
+        seed_event, seed_membership, seed_org, seed_subscription, seed_user, test_state_with_smtp,
+    };
+    use crate::mailer::test_support::FakeSmtp;
+    use biscuit_auth::KeyPair;
+
+    async fn seed_client_certificate(pool: &PgPool, application_id: Uuid, expires_in_days: i32) {
+        let subscription_id =
+            seed_subscription(pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+        sqlx::query(
+            r#"
+                INSERT INTO webhook.subscription_client_certificate
+                    (subscription__id, certificate_pem, encrypted_private_key, subject, fingerprint_sha256, not_before, not_after)
+                VALUES ($1, '', '\x00', 'CN=test', '00', statement_timestamp(), statement_timestamp() + make_interval(days => $2))
+            "#,
+        )
+        .bind(subscription_id)
+        .bind(expires_in_days)
+        .execute(pool)
+        .await
+        .expect("seed client certificate");
+    }
+
+    /// Only certificates expiring within the warning window are warned about, and only once.
+    #[sqlx::test]
+    async fn editors_are_warned_once_about_certificates_about_to_expire(pool: PgPool) {
+        let smtp = FakeSmtp::start();
+        let editor = seed_user(&pool).await;
+        let org = seed_org(&pool, editor).await;
+        seed_membership(&pool, editor, org, "editor").await;
+        let (application_id, _) = seed_event(&pool, org).await;
+        seed_client_certificate(&pool, application_id, 10).await;
+        seed_client_certificate(&pool, application_id, 60).await;
+
+        let state = test_state_with_smtp(
+            pool.clone(),
+            KeyPair::new().private(),
+            None,
+            &smtp.connection_url,
+        )
+        .await;
+        let warning = Duration::from_secs(30 * 24 * 60 * 60);
+
+        let warned = warn_about_expiring_client_certificates(&pool, &state.mailer, warning)
+            .await
+            .expect("first pass");
+        assert_eq!(warned, 1);
+        assert_eq!(smtp.wait_for(1, Duration::from_secs(5)).await, 1);
+
+        let warned = warn_about_expiring_client_certificates(&pool, &state.mailer, warning)
+            .await
+            .expect("second pass");
+        assert_eq!(warned, 0);
+        assert_eq!(smtp.delivered(), 1);
+    }
+}
@@ -0,0 +1,206 @@
+//! Warnings about subscriptions piling up dead letters.
+//!
+//! Once a subscription has at least the threshold of dead letters, an
+//! `api.subscription.dead_letters_exceeded` event is sent. A subscription is claimed by stamping
+//! `dead_letters_notified_at`, so it is warned once across API instances; the stamp is cleared
+//! when redeliveries and purges bring it back below the threshold, so that it is warned again if
+//! it piles up dead letters anew.
+
+use actix_web::rt::time::sleep;
+use hook0_client::Hook0Client;
+use sqlx::{PgPool, query, query_as};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::hook0_client::{EventSubscriptionDeadLettersExceeded, Hook0ClientEvent};
+
+const STARTUP_GRACE_PERIOD: Duration = Duration::from_secs(15);
+
+/// Upper bound on how many subscriptions a single pass warns about.
+const MAX_PER_RUN: i64 = 100;
+
+#[derive(Debug)]
+struct SubscriptionOverThreshold {
+    subscription_id: Uuid,
+    application_id: Uuid,
+    organization_id: Uuid,
+    dead_letter_count: i64,
+}
+
+pub async fn periodically_check_dead_letters(
+    housekeeping_semaphore: &Semaphore,
+    db: &PgPool,
+    hook0_client: Option<Hook0Client>,
+    threshold: u32,
+    period: Duration,
+) {
+    sleep(STARTUP_GRACE_PERIOD).await;
+
+    while let Ok(permit) = housekeeping_semaphore.acquire().await {
+        match check_dead_letters(db, hook0_client.as_ref(), threshold).await {
+            Ok(0) => {}
+            Ok(n) => info!("Warned about {n} subscriptions over the dead letter threshold"),
+            Err(e) => error!("Could not check the dead letters of subscriptions: {e}"),
+        }
+        drop(permit);
+
+        sleep(period).await;
+    }
+}
+
+/// One pass: rearm the subscriptions back below the threshold, then claim and warn about those
+/// over it. Returns how many subscriptions were warned about.
+async fn check_dead_letters(
+    db: &PgPool,
+    hook0_client: Option<&Hook0Client>,
+    threshold: u32,
+) -> Result<usize, sqlx::Error> {
+    let threshold = i64::from(threshold);
+
+    query!(
+        "
+            UPDATE webhook.subscription AS s
+            SET dead_letters_notified_at = NULL
+            WHERE s.dead_letters_notified_at IS NOT NULL
+                AND (
+                    SELECT COUNT(*)
+                    FROM webhook.dead_letter AS dl
+                    WHERE dl.subscription__id = s.subscription__id
+                ) < $1
+        ",
+        threshold,
+    )
+    .execute(db)
+    .await?;
+
+    let mut tx = db.begin().await?;
+
+    let subscriptions = query_as!(
+        SubscriptionOverThreshold,
+        r#"
+            SELECT
+                s.subscription__id AS subscription_id,
+                s.application__id AS application_id,
+                a.organization__id AS organization_id,
+                dl.count AS "dead_letter_count!"
+            FROM (
+                SELECT subscription__id, COUNT(*) AS count
+                FROM webhook.dead_letter
+                GROUP BY subscription__id
+                HAVING COUNT(*) >= $1
+            ) AS dl
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = dl.subscription__id
+            INNER JOIN event.application AS a ON a.application__id = s.application__id
+            WHERE s.dead_letters_notified_at IS NULL
+                AND s.deleted_at IS NULL
+                AND a.deleted_at IS NULL
+            ORDER BY dl.count DESC
+            LIMIT $2
+            FOR UPDATE OF s SKIP LOCKED
+        "#,
+        threshold,
+        MAX_PER_RUN,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    if subscriptions.is_empty() {
+        return Ok(0);
+    }
+
+    query!(
+        "
+            UPDATE webhook.subscription
+            SET dead_letters_notified_at = statement_timestamp()
+            WHERE subscription__id = ANY($1)
+        ",
+        &subscriptions
+            .iter()
+            .map(|s| s.subscription_id)
+            .collect::<Vec<_>>(),
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    if let Some(hook0_client) = hook0_client {
+        for subscription in &subscriptions {
+            let hook0_client_event: Hook0ClientEvent = EventSubscriptionDeadLettersExceeded {
+                organization_id: subscription.organization_id,
+                application_id: subscription.application_id,
+                subscription_id: subscription.subscription_id,
+                dead_letter_count: subscription.dead_letter_count,
+                threshold,
+            }
+            .into();
+            if let Err(e) = hook0_client
+                .send_event(&hook0_client_event.mk_hook0_event())
+                .await
+            {
+                error!("Hook0ClientError: {e}");
+            };
+        }
+    }
+
+    Ok(subscriptions.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google_ads::test_support::{seed_event, seed_org, seed_subscription, seed_user};
+
+    async fn seed_dead_letters(pool: &PgPool, count: usize) -> Uuid {
+        let user = seed_user(pool).await;
+        let org = seed_org(pool, user).await;
+        let (application_id, event_id) = seed_event(pool, org).await;
+        let subscription_id =
+            seed_subscription(pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+
+        for _ in 0..count {
+            sqlx::query(
+                r#"
+                    WITH ra AS (
+                        INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, picked_at, failed_at)
+                        VALUES ($1, $2, $3, statement_timestamp(), statement_timestamp())
+                        RETURNING request_attempt__id
+                    )
+                    INSERT INTO webhook.dead_letter (application__id, subscription__id, event__id, request_attempt__id, reason)
+                    SELECT $3, $2, $1, request_attempt__id, 'retries_exhausted'
+                    FROM ra
+                "#,
+            )
+            .bind(event_id)
+            .bind(subscription_id)
+            .bind(application_id)
+            .execute(pool)
+            .await
+            .expect("seed dead letter");
+        }
+        subscription_id
+    }
+
+    /// A subscription is reported once when it reaches the threshold, and again only after going
+    /// back below it.
+    #[sqlx::test]
+    async fn subscriptions_over_the_threshold_are_reported_once(pool: PgPool) {
+        let subscription_id = seed_dead_letters(&pool, 3).await;
+
+        assert_eq!(check_dead_letters(&pool, None, 4).await.unwrap(), 0);
+        assert_eq!(check_dead_letters(&pool, None, 3).await.unwrap(), 1);
+        assert_eq!(check_dead_letters(&pool, None, 3).await.unwrap(), 0);
+
+        sqlx::query(
+            "DELETE FROM webhook.dead_letter WHERE dead_letter__id = (SELECT dead_letter__id FROM webhook.dead_letter WHERE subscription__id = $1 LIMIT 1)",
+        )
+        .bind(subscription_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert_eq!(check_dead_letters(&pool, None, 3).await.unwrap(), 0);
+        assert_eq!(check_dead_letters(&pool, None, 2).await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,183 @@
+//! Cancellation of the deliveries of expired events.
+//!
+//! Past its `expires_at`, an event is no longer worth delivering: its request attempts that are
+//! still pending or waiting for a retry are failed and stamped with `expired_at`, which tells them
+//! apart from deliveries that failed. So are the ones pulled from an inbox whose lease lapsed,
+//! since they would otherwise be offered again. Request attempts being delivered are left to
+//! finish. Workers and inbox pulls do not pick the request attempts of an expired event either,
+//! so nothing is delivered late between two passes.
+
+use actix_web::rt::time::sleep;
+use sqlx::{PgPool, query};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tracing::{error, info};
+
+use crate::opentelemetry::report_cancelled_request_attempts;
+
+const STARTUP_GRACE_PERIOD: Duration = Duration::from_secs(15);
+
+/// Upper bound on how many request attempts a single statement cancels; a pass goes on until
+/// fewer are left.
+const MAX_PER_STATEMENT: i64 = 1000;
+
+pub async fn periodically_cancel_expired_request_attempts(
+    housekeeping_semaphore: &Semaphore,
+    db: &PgPool,
+    period: Duration,
+) {
+    sleep(STARTUP_GRACE_PERIOD).await;
+
+    while let Ok(permit) = housekeeping_semaphore.acquire().await {
+        match cancel_expired_request_attempts(db).await {
+            Ok(0) => {}
+            Ok(n) => info!("Cancelled {n} request attempts of expired events"),
+            Err(e) => error!("Could not cancel the request attempts of expired events: {e}"),
+        }
+        drop(permit);
+
+        sleep(period).await;
+    }
+}
+
+/// One pass: cancel the pending and waiting request attempts of expired events, including the
+/// ones whose inbox lease lapsed. Returns how many request attempts were cancelled.
+async fn cancel_expired_request_attempts(db: &PgPool) -> Result<u64, sqlx::Error> {
+    let mut total = 0;
+
+    loop {
+        let cancelled = query!(
+            "
+                WITH expired AS (
+                    SELECT ra.request_attempt__id
+                    FROM webhook.request_attempt AS ra
+                    INNER JOIN event.event AS e ON e.event__id = ra.event__id
+                    LEFT JOIN webhook.inbox_lease AS l ON l.request_attempt__id = ra.request_attempt__id
+                    WHERE ra.succeeded_at IS NULL
+                        AND ra.failed_at IS NULL
+                        AND COALESCE(l.leased_until <= statement_timestamp(), ra.picked_at IS NULL)
+                        AND e.expires_at <= statement_timestamp()
+                    LIMIT $1
+                    FOR UPDATE OF ra SKIP LOCKED
+                ), released AS (
+                    DELETE FROM webhook.inbox_lease
+                    WHERE request_attempt__id IN (SELECT request_attempt__id FROM expired)
+                )
+                UPDATE webhook.request_attempt AS ra
+                SET failed_at = statement_timestamp(), expired_at = statement_timestamp()
+                FROM expired
+                WHERE ra.request_attempt__id = expired.request_attempt__id
+            ",
+            MAX_PER_STATEMENT,
+        )
+        .execute(db)
+        .await?
+        .rows_affected();
+
+        if cancelled > 0 {
+            report_cancelled_request_attempts(cancelled);
+        }
+        total += cancelled;
+
+        if cancelled < MAX_PER_STATEMENT as u64 {
+            return Ok(total);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google_ads::test_support::{seed_event, seed_org, seed_subscription, seed_user};
+    use uuid::Uuid;
+
+    /// Only the request attempts of an expired event that are neither done nor being delivered
+    /// are cancelled, and they are told apart from failed deliveries. An inbox lease that lapsed
+    /// does not count as a delivery in progress.
+    #[sqlx::test]
+    async fn pending_request_attempts_of_expired_events_are_cancelled(pool: PgPool) {
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        let (application_id, event_id) = seed_event(&pool, org).await;
+        let subscription_id =
+            seed_subscription(&pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+
+        let (pending, waiting, lapsed): (Uuid, Uuid, Uuid) =
+            sqlx::query_as(
+                r#"
+                    WITH pending AS (
+                        INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id)
+                        VALUES ($1, $2, $3)
+                        RETURNING request_attempt__id
+                    ), waiting AS (
+                        INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, delay_until, retry_count)
+                        VALUES ($1, $2, $3, statement_timestamp() + interval '1 hour', 1)
+                        RETURNING request_attempt__id
+                    ), in_progress AS (
+                        INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, picked_at)
+                        VALUES ($1, $2, $3, statement_timestamp())
+                        RETURNING request_attempt__id
+                    ), lapsed AS (
+                        INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, picked_at)
+                        VALUES ($1, $2, $3, statement_timestamp())
+                        RETURNING request_attempt__id
+                    ), lapsed_lease AS (
+                        INSERT INTO webhook.inbox_lease (request_attempt__id, receipt, leased_until)
+                        SELECT request_attempt__id, public.gen_random_uuid(), statement_timestamp() - interval '1 second'
+                        FROM lapsed
+                    ), leased AS (
+                        INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, picked_at)
+                        VALUES ($1, $2, $3, statement_timestamp())
+                        RETURNING request_attempt__id
+                    ), live_lease AS (
+                        INSERT INTO webhook.inbox_lease (request_attempt__id, receipt, leased_until)
+                        SELECT request_attempt__id, public.gen_random_uuid(), statement_timestamp() + interval '1 hour'
+                        FROM leased
+                    ), succeeded AS (
+                        INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, picked_at, succeeded_at)
+                        VALUES ($1, $2, $3, statement_timestamp(), statement_timestamp())
+                        RETURNING request_attempt__id
+                    )
+                    SELECT pending.request_attempt__id, waiting.request_attempt__id, lapsed.request_attempt__id
+                    FROM pending, waiting, in_progress, lapsed, leased, succeeded
+                "#,
+            )
+            .bind(event_id)
+            .bind(subscription_id)
+            .bind(application_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cancel_expired_request_attempts(&pool).await.unwrap(),
+            0,
+            "an event without an expiry date never expires"
+        );
+
+        sqlx::query(
+            "UPDATE event.event SET expires_at = statement_timestamp() - interval '1 minute' WHERE event__id = $1",
+        )
+        .bind(event_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert_eq!(cancel_expired_request_attempts(&pool).await.unwrap(), 3);
+        assert_eq!(cancel_expired_request_attempts(&pool).await.unwrap(), 0);
+
+        let expired: Vec<(Uuid, bool)> = sqlx::query_as(
+            "SELECT request_attempt__id, expired_at IS NOT NULL AND failed_at IS NOT NULL FROM webhook.request_attempt WHERE event__id = $1",
+        )
+        .bind(event_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        for (request_attempt_id, is_expired) in expired {
+            assert_eq!(
+                is_expired,
+                [pending, waiting, lapsed].contains(&request_attempt_id),
+                "only the pending and waiting request attempts expire"
+            );
+        }
+    }
+}
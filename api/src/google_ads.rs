@@ -1029,31 +1029,45 @@ pub(crate) mod test_support {
         (application_id, event_id)
     }
 
-    /// Seed a webhook delivery attempt for `application_id` / `event_id`. When
-    /// `succeeded` is `true` its `succeeded_at` is set, which is exactly what the
-    /// first-webhook-delivered `EXISTS` predicate matches; otherwise it is left
-    /// pending (`succeeded_at` NULL) to model a delivery that has not succeeded
-    /// yet. Creates the required subscription so the request attempt's NOT NULL
-    /// `subscription__id` foreign key is satisfied.
-    pub(crate) async fn seed_request_attempt(
+    /// Insert an enabled subscription of `application_id` matching `labels` and
+    /// return its id. It has no target row and listens to no event type: tests
+    /// that need either (or any other column) add them on top.
+    pub(crate) async fn seed_subscription(
         pool: &PgPool,
         application_id: Uuid,
-        event_id: Uuid,
-        succeeded: bool,
-    ) {
+        labels: serde_json::Value,
+    ) -> Uuid {
         let subscription_id = Uuid::new_v4();
         sqlx::query(
             r#"
                 INSERT INTO webhook.subscription
                     (subscription__id, application__id, is_enabled, secret, metadata, labels, target__id, created_at, updated_at)
-                VALUES ($1, $2, true, public.gen_random_uuid(), '{}'::jsonb, '{"e2e":"1"}'::jsonb, public.gen_random_uuid(), statement_timestamp(), statement_timestamp())
+                VALUES ($1, $2, true, public.gen_random_uuid(), '{}'::jsonb, $3, public.gen_random_uuid(), statement_timestamp(), statement_timestamp())
             "#,
         )
         .bind(subscription_id)
         .bind(application_id)
+        .bind(labels)
         .execute(pool)
         .await
         .expect("seed subscription");
+        subscription_id
+    }
+
+    /// Seed a webhook delivery attempt for `application_id` / `event_id`. When
+    /// `succeeded` is `true` its `succeeded_at` is set, which is exactly what the
+    /// first-webhook-delivered `EXISTS` predicate matches; otherwise it is left
+    /// pending (`succeeded_at` NULL) to model a delivery that has not succeeded
+    /// yet. Creates the required subscription so the request attempt's NOT NULL
+    /// `subscription__id` foreign key is satisfied.
+    pub(crate) async fn seed_request_attempt(
+        pool: &PgPool,
+        application_id: Uuid,
+        event_id: Uuid,
+        succeeded: bool,
+    ) {
+        let subscription_id =
+            seed_subscription(pool, application_id, serde_json::json!({ "e2e": "1" })).await;
 
         sqlx::query(
             r#"
@@ -1129,6 +1143,42 @@ pub(crate) mod test_support {
         token.serialized_biscuit
     }
 
+    /// The real biscuit auth middleware over the test database, accepting the
+    /// tokens `issue_user_token` mints with `private_key`.
+    pub(crate) fn test_biscuit_auth(
+        pool: &PgPool,
+        private_key: &biscuit_auth::PrivateKey,
+    ) -> crate::middleware_biscuit::BiscuitAuth {
+        crate::middleware_biscuit::BiscuitAuth {
+            db: pool.clone(),
+            biscuit_private_key: private_key.clone(),
+            master_api_key: None,
+            enable_application_secret_compatibility: false,
+        }
+    }
+
+    /// Spin up the endpoints under test over the test database, with a
+    /// `test_state`. `|app, biscuit_auth| ...` receives the `App` holding that
+    /// state and the middleware from `test_biscuit_auth`, and registers the
+    /// routes, wrapped in it where they need authentication. A macro rather
+    /// than a function because the type of an initialized actix test service
+    /// is not nameable here.
+    macro_rules! init_test_app {
+        ($pool:expr, $private_key:expr, |$app:ident, $biscuit_auth:ident| $routes:expr) => {{
+            let state = crate::google_ads::test_support::test_state(
+                $pool.clone(),
+                $private_key.clone(),
+                None,
+            )
+            .await;
+            let $biscuit_auth =
+                crate::google_ads::test_support::test_biscuit_auth(&$pool, &$private_key);
+            let $app = actix_web::App::new().app_data(actix_web::web::Data::new(state));
+            actix_web::test::init_service($routes).await
+        }};
+    }
+    pub(crate) use init_test_app;
+
     /// Build a `State` suitable for handler tests: real DB pool + real Google
     /// Ads client (pointed at the fake server by the caller), everything else
     /// inert (no Pulsar, no object storage, no Hook0 self-eventing, quotas
@@ -1237,6 +1287,14 @@ pub(crate) mod test_support {
             cloudflare_turnstile_secret_key: None,
             google_ads,
             signup_attribution_retention_in_days: 30,
+            max_subscription_retries: 24,
+            max_subscription_retry_window: Duration::from_secs(8 * 24 * 60 * 60),
+            client_certificate_encryption_key:
+                crate::client_certificates::parse_client_certificate_key(
+                    "BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwc=",
+                )
+                .ok(),
+            payload_schemas: Default::default(),
         }
     }
 
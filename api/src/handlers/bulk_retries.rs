@@ -0,0 +1,518 @@
+use actix_web::web::ReqData;
+use biscuit_auth::Biscuit;
+use chrono::{DateTime, Utc};
+use paperclip::actix::web::{Data, Json, Path, Query};
+use paperclip::actix::{Apiv2Schema, CreatedJson, api_v2_operation};
+use serde::{Deserialize, Serialize};
+use sqlx::{query_as, query_scalar};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::bulk_retries::{BulkRetryFilter, BulkRetryStatus, count_matching_pairs};
+use crate::iam::{Action, authorize_for_application};
+use crate::openapi::OaBiscuit;
+use crate::problems::Hook0Problem;
+
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct BulkRetry {
+    pub bulk_retry_id: Uuid,
+    pub application_id: Uuid,
+    pub event_id: Option<Uuid>,
+    pub subscription_id: Option<Uuid>,
+    pub event_type_names: Vec<String>,
+    pub min_created_at: DateTime<Utc>,
+    pub max_created_at: DateTime<Utc>,
+    pub status: BulkRetryStatus,
+    /// Number of (event, subscription) pairs that matched when the bulk retry was created
+    pub total_count: i64,
+    /// Number of pairs handled so far
+    pub processed_count: i64,
+    /// Number of request attempts created so far
+    pub retried_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+}
+
+#[allow(non_snake_case)]
+struct RawBulkRetry {
+    bulk_retry__id: Uuid,
+    application__id: Uuid,
+    event__id: Option<Uuid>,
+    subscription__id: Option<Uuid>,
+    event_type_names: Vec<String>,
+    min_created_at: DateTime<Utc>,
+    max_created_at: DateTime<Utc>,
+    status: String,
+    total_count: i64,
+    processed_count: i64,
+    retried_count: i64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    completed_at: Option<DateTime<Utc>>,
+    cancelled_at: Option<DateTime<Utc>>,
+}
+
+impl From<RawBulkRetry> for BulkRetry {
+    fn from(raw: RawBulkRetry) -> Self {
+        Self {
+            bulk_retry_id: raw.bulk_retry__id,
+            application_id: raw.application__id,
+            event_id: raw.event__id,
+            subscription_id: raw.subscription__id,
+            event_type_names: raw.event_type_names,
+            min_created_at: raw.min_created_at,
+            max_created_at: raw.max_created_at,
+            status: BulkRetryStatus::from_db(&raw.status),
+            total_count: raw.total_count,
+            processed_count: raw.processed_count,
+            retried_count: raw.retried_count,
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+            completed_at: raw.completed_at,
+            cancelled_at: raw.cancelled_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema, Validate)]
+pub struct BulkRetryPost {
+    application_id: Uuid,
+    event_id: Option<Uuid>,
+    subscription_id: Option<Uuid>,
+    #[validate(length(max = 100))]
+    event_type_names: Option<Vec<String>>,
+    min_created_at: Option<DateTime<Utc>>,
+    max_created_at: Option<DateTime<Utc>>,
+    /// Redeliver request attempts that failed (default) or that succeeded
+    status: Option<BulkRetryStatus>,
+}
+
+impl BulkRetryPost {
+    fn filter(&self) -> BulkRetryFilter {
+        let now = Utc::now();
+        BulkRetryFilter {
+            event_id: self.event_id,
+            subscription_id: self.subscription_id,
+            event_type_names: self.event_type_names.to_owned().unwrap_or_default(),
+            min_created_at: self.min_created_at.unwrap_or(DateTime::<Utc>::UNIX_EPOCH),
+            // Never in the future, so that the request attempts the bulk retry creates do not match it
+            max_created_at: self.max_created_at.map_or(now, |max| max.min(now)),
+            status: self.status.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct BulkRetryDryRun {
+    /// Number of (event, subscription) pairs a bulk retry would redeliver now
+    pub count: i64,
+}
+
+#[api_v2_operation(
+    summary = "Count what a bulk retry would redeliver",
+    description = "Takes the same filters as creating a bulk retry and returns how many (event, subscription) pairs it would redeliver right now, without redelivering anything.",
+    operation_id = "bulkRetries.dry_run",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "mcp", "sdk")
+)]
+pub async fn dry_run(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    body: Json<BulkRetryPost>,
+) -> Result<Json<BulkRetryDryRun>, Hook0Problem> {
+    let filter = body.filter();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::BulkRetryCreate {
+            application_id: &body.application_id,
+            event_type_names: &filter.event_type_names,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let count = count_matching_pairs(&state.db, body.application_id, &filter).await?;
+    Ok(Json(BulkRetryDryRun { count }))
+}
+
+#[api_v2_operation(
+    summary = "Create a bulk retry",
+    description = "Redelivers, in the background, every (event, subscription) pair with a request attempt matching the filters: each pair gets a new request attempt, at a pace that does not flood the workers. Pairs whose subscription is disabled or that still have a pending delivery are left out, and so are pairs that were eventually delivered when retrying failures. Only one bulk retry per application can run at a time.",
+    operation_id = "bulkRetries.create",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "mcp", "sdk")
+)]
+pub async fn create(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    body: Json<BulkRetryPost>,
+) -> Result<CreatedJson<BulkRetry>, Hook0Problem> {
+    let filter = body.filter();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::BulkRetryCreate {
+            application_id: &body.application_id,
+            event_type_names: &filter.event_type_names,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let mut tx = state.db.begin().await?;
+    let total_count = count_matching_pairs(&mut *tx, body.application_id, &filter).await?;
+    let bulk_retry = query_as!(
+        RawBulkRetry,
+        "
+            INSERT INTO webhook.bulk_retry (
+                application__id,
+                event__id,
+                subscription__id,
+                event_type_names,
+                min_created_at,
+                max_created_at,
+                status,
+                total_count
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING
+                bulk_retry__id,
+                application__id,
+                event__id,
+                subscription__id,
+                event_type_names,
+                min_created_at,
+                max_created_at,
+                status,
+                total_count,
+                processed_count,
+                retried_count,
+                created_at,
+                updated_at,
+                completed_at,
+                cancelled_at
+        ",
+        body.application_id,
+        filter.event_id,
+        filter.subscription_id,
+        &filter.event_type_names,
+        filter.min_created_at,
+        filter.max_created_at,
+        filter.status.as_str(),
+        total_count,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(CreatedJson(bulk_retry.into()))
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct Qs {
+    application_id: Uuid,
+}
+
+#[api_v2_operation(
+    summary = "List bulk retries",
+    description = "Lists the bulk retries of an application, most recent first, with their progress.",
+    operation_id = "bulkRetries.list",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "mcp", "sdk")
+)]
+pub async fn list(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    qs: Query<Qs>,
+) -> Result<Json<Vec<BulkRetry>>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::BulkRetryList {
+            application_id: &qs.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let bulk_retries = query_as!(
+        RawBulkRetry,
+        "
+            SELECT
+                bulk_retry__id,
+                application__id,
+                event__id,
+                subscription__id,
+                event_type_names,
+                min_created_at,
+                max_created_at,
+                status,
+                total_count,
+                processed_count,
+                retried_count,
+                created_at,
+                updated_at,
+                completed_at,
+                cancelled_at
+            FROM webhook.bulk_retry
+            WHERE application__id = $1
+            ORDER BY created_at DESC
+            LIMIT 100
+        ",
+        &qs.application_id,
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(
+        bulk_retries.into_iter().map(BulkRetry::from).collect(),
+    ))
+}
+
+#[api_v2_operation(
+    summary = "Get a bulk retry",
+    description = "Retrieves a bulk retry and its progress: how many (event, subscription) pairs matched when it was created, how many were handled and how many request attempts were created so far.",
+    operation_id = "bulkRetries.get",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "mcp", "sdk")
+)]
+pub async fn get(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    bulk_retry_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<Json<BulkRetry>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::BulkRetryGet {
+            application_id: &qs.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let bulk_retry = query_as!(
+        RawBulkRetry,
+        "
+            SELECT
+                bulk_retry__id,
+                application__id,
+                event__id,
+                subscription__id,
+                event_type_names,
+                min_created_at,
+                max_created_at,
+                status,
+                total_count,
+                processed_count,
+                retried_count,
+                created_at,
+                updated_at,
+                completed_at,
+                cancelled_at
+            FROM webhook.bulk_retry
+            WHERE application__id = $1
+                AND bulk_retry__id = $2
+        ",
+        &qs.application_id,
+        &bulk_retry_id.into_inner(),
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(Hook0Problem::NotFound)?;
+
+    Ok(Json(bulk_retry.into()))
+}
+
+#[api_v2_operation(
+    summary = "Cancel a bulk retry",
+    description = "Stops a running bulk retry. Request attempts it already created are delivered anyway.",
+    operation_id = "bulkRetries.cancel",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "mcp", "sdk")
+)]
+pub async fn cancel(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    bulk_retry_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<Json<BulkRetry>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::BulkRetryCancel {
+            application_id: &qs.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let bulk_retry_id = bulk_retry_id.into_inner();
+    // Waits for the batch being processed, if any, to be committed
+    let cancelled = query_as!(
+        RawBulkRetry,
+        "
+            UPDATE webhook.bulk_retry
+            SET cancelled_at = statement_timestamp(), updated_at = statement_timestamp()
+            WHERE application__id = $1
+                AND bulk_retry__id = $2
+                AND completed_at IS NULL
+                AND cancelled_at IS NULL
+            RETURNING
+                bulk_retry__id,
+                application__id,
+                event__id,
+                subscription__id,
+                event_type_names,
+                min_created_at,
+                max_created_at,
+                status,
+                total_count,
+                processed_count,
+                retried_count,
+                created_at,
+                updated_at,
+                completed_at,
+                cancelled_at
+        ",
+        &qs.application_id,
+        &bulk_retry_id,
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    match cancelled {
+        Some(bulk_retry) => Ok(Json(bulk_retry.into())),
+        None => {
+            let exists = query_scalar!(
+                r#"
+                    SELECT EXISTS(
+                        SELECT 1
+                        FROM webhook.bulk_retry
+                        WHERE application__id = $1 AND bulk_retry__id = $2
+                    ) AS "exists!"
+                "#,
+                &qs.application_id,
+                &bulk_retry_id,
+            )
+            .fetch_one(&state.db)
+            .await?;
+            if exists {
+                Err(Hook0Problem::BulkRetryFinished)
+            } else {
+                Err(Hook0Problem::NotFound)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_subscription,
+        seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use sqlx::PgPool;
+
+    /// A dry run counts what a bulk retry would redeliver without creating it, and a second bulk
+    /// retry cannot start while the first one is running.
+    #[sqlx::test]
+    async fn one_bulk_retry_runs_at_a_time(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, event_id) = seed_event(&pool, org).await;
+        let subscription_id =
+            seed_subscription(&pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+        sqlx::query(
+            r#"
+                INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, picked_at, failed_at)
+                VALUES ($1, $2, $3, statement_timestamp(), statement_timestamp())
+            "#,
+        )
+        .bind(event_id)
+        .bind(subscription_id)
+        .bind(application_id)
+        .execute(&pool)
+        .await
+        .expect("seed failed request attempt");
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1/bulk_retries")
+                .wrap(biscuit_auth)
+                .route("", web::post().to(super::create))
+                .route("/dry_run", web::post().to(super::dry_run))
+                .route("/{bulk_retry_id}/cancel", web::post().to(super::cancel)),
+        ));
+        let post = |uri: &str, body: serde_json::Value| {
+            test::TestRequest::post()
+                .uri(uri)
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .set_json(body)
+                .to_request()
+        };
+        let filter = serde_json::json!({
+            "application_id": application_id,
+            "subscription_id": subscription_id,
+        });
+
+        let resp =
+            test::call_service(&app, post("/api/v1/bulk_retries/dry_run", filter.clone())).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["count"], 1);
+
+        let resp = test::call_service(&app, post("/api/v1/bulk_retries", filter.clone())).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["total_count"], 1);
+        assert_eq!(body["status"], "failed");
+        let bulk_retry_id = body["bulk_retry_id"].as_str().unwrap().to_owned();
+
+        let resp = test::call_service(&app, post("/api/v1/bulk_retries", filter.clone())).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let cancel_uri =
+            format!("/api/v1/bulk_retries/{bulk_retry_id}/cancel?application_id={application_id}");
+        let resp = test::call_service(&app, post(&cancel_uri, serde_json::json!({}))).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = test::call_service(&app, post(&cancel_uri, serde_json::json!({}))).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let resp = test::call_service(&app, post("/api/v1/bulk_retries", filter)).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+}
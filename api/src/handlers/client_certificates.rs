@@ -0,0 +1,412 @@
+use actix_web::web::ReqData;
+use biscuit_auth::Biscuit;
+use chrono::{DateTime, Utc};
+use paperclip::actix::web::{Data, Json, Path, Query};
+use paperclip::actix::{Apiv2Schema, NoContent, api_v2_operation};
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::client_certificates::ClientCertificate as ParsedClientCertificate;
+use crate::iam::{Action, authorize_for_application};
+use crate::openapi::OaBiscuit;
+use crate::problems::Hook0Problem;
+
+/// Client certificate a subscription presents to its target (mutual TLS). Its private key is
+/// never returned.
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct ClientCertificate {
+    pub subscription_id: Uuid,
+    /// Certificate chain, leaf certificate first
+    pub certificate_pem: String,
+    pub subject: String,
+    /// SHA-256 fingerprint of the leaf certificate, as hexadecimal
+    pub fingerprint_sha256: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[allow(non_snake_case)]
+struct RawClientCertificate {
+    subscription__id: Uuid,
+    certificate_pem: String,
+    subject: String,
+    fingerprint_sha256: String,
+    not_before: DateTime<Utc>,
+    not_after: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<RawClientCertificate> for ClientCertificate {
+    fn from(raw: RawClientCertificate) -> Self {
+        Self {
+            subscription_id: raw.subscription__id,
+            certificate_pem: raw.certificate_pem,
+            subject: raw.subject,
+            fingerprint_sha256: raw.fingerprint_sha256,
+            not_before: raw.not_before,
+            not_after: raw.not_after,
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema, Validate)]
+pub struct ClientCertificatePut {
+    /// PEM-encoded certificate chain, leaf certificate first
+    #[validate(length(min = 1, max = 65536))]
+    certificate_pem: String,
+    /// PEM-encoded unencrypted private key of the leaf certificate (PKCS#8, PKCS#1 or SEC1)
+    #[validate(length(min = 1, max = 16384))]
+    private_key_pem: String,
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct Qs {
+    application_id: Uuid,
+}
+
+async fn subscription_exists(
+    db: &sqlx::PgPool,
+    application_id: &Uuid,
+    subscription_id: &Uuid,
+) -> Result<bool, Hook0Problem> {
+    let exists = query_scalar!(
+        r#"
+            SELECT EXISTS(
+                SELECT 1
+                FROM webhook.subscription
+                WHERE application__id = $1 AND subscription__id = $2 AND deleted_at IS NULL
+            ) AS "exists!"
+        "#,
+        application_id,
+        subscription_id,
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(exists)
+}
+
+#[api_v2_operation(
+    summary = "Get the client certificate of a subscription",
+    description = "Retrieves the client certificate the subscription presents to its target when the target requires mutual TLS. The private key is never returned.",
+    operation_id = "clientCertificates.get",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn get(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<Json<ClientCertificate>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionGet {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let client_certificate = query_as!(
+        RawClientCertificate,
+        "
+            SELECT
+                scc.subscription__id,
+                scc.certificate_pem,
+                scc.subject,
+                scc.fingerprint_sha256,
+                scc.not_before,
+                scc.not_after,
+                scc.created_at,
+                scc.updated_at
+            FROM webhook.subscription_client_certificate AS scc
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = scc.subscription__id
+            WHERE s.application__id = $1
+                AND s.subscription__id = $2
+                AND s.deleted_at IS NULL
+        ",
+        &qs.application_id,
+        &subscription_id,
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(Hook0Problem::NotFound)?;
+
+    Ok(Json(client_certificate.into()))
+}
+
+#[api_v2_operation(
+    summary = "Set the client certificate of a subscription",
+    description = "Uploads the client certificate and private key the subscription presents to its target when the target requires mutual TLS, replacing the previous ones. They are checked on upload: the certificate must not be expired and the private key must be the one of its leaf certificate. The private key is stored encrypted and never returned.",
+    operation_id = "clientCertificates.update",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn edit(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+    body: Json<ClientCertificatePut>,
+) -> Result<Json<ClientCertificate>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionEdit {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let encryption_key = state
+        .client_certificate_encryption_key
+        .as_ref()
+        .ok_or(Hook0Problem::ClientCertificatesDisabled)?;
+
+    if !subscription_exists(&state.db, &qs.application_id, &subscription_id).await? {
+        return Err(Hook0Problem::NotFound);
+    }
+
+    let parsed =
+        ParsedClientCertificate::parse(&body.certificate_pem, &body.private_key_pem, Utc::now())
+            .map_err(Hook0Problem::InvalidClientCertificate)?;
+    let encrypted_private_key = encryption_key
+        .encrypt(&subscription_id, parsed.private_key_pem.as_bytes())
+        .map_err(|e| {
+            tracing::error!("Could not encrypt the private key of a client certificate: {e}");
+            Hook0Problem::InternalServerError
+        })?;
+
+    let client_certificate = query_as!(
+        RawClientCertificate,
+        "
+            INSERT INTO webhook.subscription_client_certificate (
+                subscription__id,
+                certificate_pem,
+                encrypted_private_key,
+                subject,
+                fingerprint_sha256,
+                not_before,
+                not_after
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (subscription__id) DO UPDATE
+            SET certificate_pem = EXCLUDED.certificate_pem,
+                encrypted_private_key = EXCLUDED.encrypted_private_key,
+                subject = EXCLUDED.subject,
+                fingerprint_sha256 = EXCLUDED.fingerprint_sha256,
+                not_before = EXCLUDED.not_before,
+                not_after = EXCLUDED.not_after,
+                updated_at = statement_timestamp(),
+                expiry_notified_at = NULL
+            RETURNING
+                subscription__id,
+                certificate_pem,
+                subject,
+                fingerprint_sha256,
+                not_before,
+                not_after,
+                created_at,
+                updated_at
+        ",
+        &subscription_id,
+        &parsed.certificate_pem,
+        &encrypted_private_key,
+        &parsed.subject,
+        &parsed.fingerprint_sha256,
+        parsed.not_before,
+        parsed.not_after,
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(Json(client_certificate.into()))
+}
+
+#[api_v2_operation(
+    summary = "Remove the client certificate of a subscription",
+    description = "Removes the client certificate of the subscription and its private key; the subscription no longer presents a certificate to its target.",
+    operation_id = "clientCertificates.delete",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn delete(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<NoContent, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionEdit {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let deleted = query!(
+        "
+            DELETE FROM webhook.subscription_client_certificate AS scc
+            USING webhook.subscription AS s
+            WHERE s.subscription__id = scc.subscription__id
+                AND s.application__id = $1
+                AND s.subscription__id = $2
+                AND s.deleted_at IS NULL
+        ",
+        &qs.application_id,
+        &subscription_id,
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    if deleted == 0 {
+        Err(Hook0Problem::NotFound)
+    } else {
+        Ok(NoContent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client_certificates::test_support::self_signed;
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_subscription,
+        seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use sqlx::PgPool;
+
+    /// An uploaded certificate is checked, its private key stored encrypted and never returned,
+    /// and it can be removed.
+    #[sqlx::test]
+    async fn client_certificate_lifecycle(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, _) = seed_event(&pool, org).await;
+        let subscription_id =
+            seed_subscription(&pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1/subscriptions")
+                .wrap(biscuit_auth)
+                .route(
+                    "/{subscription_id}/client_certificate",
+                    web::get().to(super::get),
+                )
+                .route(
+                    "/{subscription_id}/client_certificate",
+                    web::put().to(super::edit),
+                )
+                .route(
+                    "/{subscription_id}/client_certificate",
+                    web::delete().to(super::delete),
+                ),
+        ));
+        let uri = format!(
+            "/api/v1/subscriptions/{subscription_id}/client_certificate?application_id={application_id}"
+        );
+        let authorization = ("Authorization", format!("Bearer {user_token}"));
+
+        let (certificate, key) = self_signed(90);
+        let (_, other_key) = self_signed(90);
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::put()
+                .uri(&uri)
+                .insert_header(authorization.clone())
+                .set_json(serde_json::json!({
+                    "certificate_pem": certificate,
+                    "private_key_pem": other_key,
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["id"], "InvalidClientCertificate");
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::put()
+                .uri(&uri)
+                .insert_header(authorization.clone())
+                .set_json(serde_json::json!({
+                    "certificate_pem": certificate,
+                    "private_key_pem": key,
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["subscription_id"], subscription_id.to_string());
+        assert!(body.get("private_key_pem").is_none());
+
+        let stored: Vec<u8> = sqlx::query_scalar(
+            "SELECT encrypted_private_key FROM webhook.subscription_client_certificate WHERE subscription__id = $1",
+        )
+        .bind(subscription_id)
+        .fetch_one(&pool)
+        .await
+        .expect("stored client certificate");
+        assert!(!String::from_utf8_lossy(&stored).contains("PRIVATE KEY"));
+
+        let get = || {
+            test::TestRequest::get()
+                .uri(&uri)
+                .insert_header(authorization.clone())
+                .to_request()
+        };
+        let resp = test::call_service(&app, get()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::delete()
+                .uri(&uri)
+                .insert_header(authorization.clone())
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let resp = test::call_service(&app, get()).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
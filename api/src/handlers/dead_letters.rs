@@ -0,0 +1,864 @@
+use actix_web::web::ReqData;
+use biscuit_auth::Biscuit;
+use chrono::{DateTime, Utc};
+use paperclip::actix::web::{Data, Json, Path, Query};
+use paperclip::actix::{Apiv2Schema, CreatedJson, NoContent, api_v2_operation};
+use serde::{Deserialize, Serialize};
+use sqlx::{PgConnection, query, query_as, query_scalar};
+use std::collections::HashMap;
+use tracing::error;
+use url::Url;
+use uuid::Uuid;
+
+use crate::bulk_retries::send_retries_to_pulsar;
+use crate::handlers::request_attempts::{EventSummary, SubscriptionSummary};
+use crate::handlers::responses::{Response, load_response};
+use crate::iam::{Action, authorize_for_application};
+use crate::openapi::OaBiscuit;
+use crate::opentelemetry::report_retried_request_attempts;
+use crate::pagination::{Cursor, EncodedDescCursor, NextPageParts, Paginated};
+use crate::problems::Hook0Problem;
+
+/// Maximum number of dead letters a single bulk redelivery handles
+const MAX_REDELIVERED_AT_ONCE: i64 = 100;
+
+/// Delivery an output worker gave up on
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct DeadLetter {
+    pub dead_letter_id: Uuid,
+    pub event: EventSummary,
+    pub subscription: SubscriptionSummary,
+    /// Last request attempt of the delivery
+    pub request_attempt_id: Uuid,
+    pub reason: DeadLetterReason,
+    pub retry_count: i16,
+    /// Response to the last request attempt, if the target was reached
+    pub response_id: Option<Uuid>,
+    pub http_response_status: Option<i16>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Why the output worker gave up on a delivery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Apiv2Schema)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadLetterReason {
+    /// Every retry of the retry policy of the subscription failed
+    RetriesExhausted,
+    /// The delivery could not be signed
+    SignatureFailed,
+    /// The payload transform of the subscription failed
+    TransformFailed,
+    /// A response rule of the subscription marked the response as a permanent failure
+    PermanentFailure,
+}
+
+impl DeadLetterReason {
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "signature_failed" => Self::SignatureFailed,
+            "transform_failed" => Self::TransformFailed,
+            "permanent_failure" => Self::PermanentFailure,
+            _ => Self::RetriesExhausted,
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+struct RawDeadLetter {
+    dead_letter__id: Uuid,
+    event__id: Uuid,
+    event_type__name: String,
+    subscription__id: Uuid,
+    subscription__description: Option<String>,
+    request_attempt__id: Uuid,
+    reason: String,
+    retry_count: i16,
+    response__id: Option<Uuid>,
+    http_response_status: Option<i16>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<RawDeadLetter> for DeadLetter {
+    fn from(raw: RawDeadLetter) -> Self {
+        Self {
+            dead_letter_id: raw.dead_letter__id,
+            event: EventSummary {
+                event_id: raw.event__id,
+                event_type_name: raw.event_type__name,
+            },
+            subscription: SubscriptionSummary {
+                subscription_id: raw.subscription__id,
+                description: raw.subscription__description,
+            },
+            request_attempt_id: raw.request_attempt__id,
+            reason: DeadLetterReason::from_db(&raw.reason),
+            retry_count: raw.retry_count,
+            response_id: raw.response__id,
+            http_response_status: raw.http_response_status,
+            created_at: raw.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct Qs {
+    application_id: Uuid,
+    subscription_id: Option<Uuid>,
+    pagination_cursor: Option<EncodedDescCursor>,
+}
+
+#[api_v2_operation(
+    summary = "List dead letters",
+    description = "Retrieves the deliveries output workers gave up on, because the retries of the subscription were exhausted or because the delivery could not be signed or transformed, most recent first. Filter by subscription_id. Paginated via Link header.",
+    operation_id = "deadLetters.list",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn list(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    qs: Query<Qs>,
+) -> Result<Paginated<Json<Vec<DeadLetter>>>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::DeadLetterList {
+            application_id: &qs.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let pagination = qs.pagination_cursor.unwrap_or_default().0;
+
+    let dead_letters = query_as!(
+        RawDeadLetter,
+        "
+            SELECT
+                dl.dead_letter__id,
+                dl.event__id,
+                e.event_type__name,
+                dl.subscription__id,
+                s.description AS subscription__description,
+                dl.request_attempt__id,
+                dl.reason,
+                ra.retry_count,
+                ra.response__id,
+                r.http_code AS http_response_status,
+                dl.created_at
+            FROM webhook.dead_letter AS dl
+            INNER JOIN webhook.request_attempt AS ra ON ra.request_attempt__id = dl.request_attempt__id
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = dl.subscription__id
+            INNER JOIN event.event AS e ON e.event__id = dl.event__id
+            LEFT JOIN webhook.response AS r ON r.response__id = ra.response__id
+            WHERE dl.application__id = $1
+                AND (dl.subscription__id = $2 OR $2 IS NULL)
+                AND (dl.created_at, dl.dead_letter__id) < ($3, $4)
+            ORDER BY
+                dl.created_at DESC,
+                dl.dead_letter__id ASC
+            LIMIT 50
+        ",
+        &qs.application_id,
+        qs.subscription_id,
+        pagination.date,
+        pagination.id,
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(Hook0Problem::from)?
+    .into_iter()
+    .map(DeadLetter::from)
+    .collect::<Vec<_>>();
+
+    let next_page_parts = dead_letters.last().and_then(|dl| {
+        if state.app_url.as_str().ends_with('/') {
+            Ok(state.app_url.clone())
+        } else {
+            Url::parse(&format!("{}/", state.app_url))
+        }
+        .inspect_err(|e| {
+            error!("Error that should never happen while building app URL for pagination: {e}");
+        })
+        .ok()
+        .and_then(|app_url| {
+            app_url
+                .join("/api/v1/dead_letters")
+                .inspect_err(|e| {
+                    error!(
+                        "Error that should never happen while building app URL for pagination: {e}"
+                    );
+                })
+                .ok()
+        })
+        .map(|endpoint_url| NextPageParts {
+            endpoint_url,
+            qs: vec![
+                ("application_id", Some(qs.application_id.to_string())),
+                ("subscription_id", qs.subscription_id.map(|v| v.to_string())),
+            ],
+            cursor: Cursor {
+                date: dl.created_at,
+                id: dl.dead_letter_id,
+            },
+        })
+    });
+
+    Ok(Paginated {
+        data: Json(dead_letters),
+        next_page_parts,
+    })
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct GetQs {
+    application_id: Uuid,
+}
+
+/// Dead letter with the response to its last request attempt
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct DeadLetterDetails {
+    pub dead_letter: DeadLetter,
+    /// Response to the last request attempt, if the target was reached
+    pub last_response: Option<Response>,
+}
+
+#[api_v2_operation(
+    summary = "Get a dead letter by its ID",
+    description = "Retrieves a delivery an output worker gave up on, with the response to its last request attempt (status code, headers and body) when the target was reached, to tell why it failed.",
+    operation_id = "deadLetters.get",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn get(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    qs: Query<GetQs>,
+    dead_letter_id: Path<Uuid>,
+) -> Result<Json<DeadLetterDetails>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::DeadLetterGet {
+            application_id: &qs.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let dead_letter = query_as!(
+        RawDeadLetter,
+        "
+            SELECT
+                dl.dead_letter__id,
+                dl.event__id,
+                e.event_type__name,
+                dl.subscription__id,
+                s.description AS subscription__description,
+                dl.request_attempt__id,
+                dl.reason,
+                ra.retry_count,
+                ra.response__id,
+                r.http_code AS http_response_status,
+                dl.created_at
+            FROM webhook.dead_letter AS dl
+            INNER JOIN webhook.request_attempt AS ra ON ra.request_attempt__id = dl.request_attempt__id
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = dl.subscription__id
+            INNER JOIN event.event AS e ON e.event__id = dl.event__id
+            LEFT JOIN webhook.response AS r ON r.response__id = ra.response__id
+            WHERE dl.application__id = $1
+                AND dl.dead_letter__id = $2
+        ",
+        &qs.application_id,
+        &dead_letter_id.into_inner(),
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(Hook0Problem::from)?
+    .map(DeadLetter::from)
+    .ok_or(Hook0Problem::NotFound)?;
+
+    let last_response = match dead_letter.response_id {
+        Some(response_id) => {
+            load_response(
+                &state.db,
+                state.object_storage.as_ref(),
+                qs.application_id,
+                response_id,
+            )
+            .await?
+        }
+        None => None,
+    };
+
+    Ok(Json(DeadLetterDetails {
+        dead_letter,
+        last_response,
+    }))
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct RedeliverDeadLetter {
+    application_id: Uuid,
+}
+
+/// Dead letter that was redelivered, and the request attempt that redelivers it
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct RedeliveredDeadLetter {
+    pub dead_letter_id: Uuid,
+    pub request_attempt_id: Uuid,
+}
+
+#[api_v2_operation(
+    summary = "Redeliver a dead letter",
+    description = "Delivers the event of a dead letter again, to its subscription only. A new request attempt is created and the dead letter is removed; if the new request attempt fails for good too, it becomes a new dead letter.",
+    operation_id = "deadLetters.redeliver",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn redeliver(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    dead_letter_id: Path<Uuid>,
+    body: Json<RedeliverDeadLetter>,
+) -> Result<CreatedJson<RedeliveredDeadLetter>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::DeadLetterRedeliver {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let dead_letter_id = dead_letter_id.into_inner();
+    let mut tx = state.db.begin().await?;
+
+    struct RawRedeliverable {
+        subscription_is_active: bool,
+        pending: bool,
+    }
+    // Same lock as retrying a request attempt, so that the pending check holds until the new request attempt is committed
+    let redeliverable = query_as!(
+        RawRedeliverable,
+        r#"
+            SELECT
+                (s.is_enabled AND s.deleted_at IS NULL) AS "subscription_is_active!",
+                EXISTS(
+                    SELECT 1
+                    FROM webhook.request_attempt AS other
+                    WHERE other.event__id = dl.event__id
+                        AND other.subscription__id = dl.subscription__id
+                        AND other.succeeded_at IS NULL
+                        AND other.failed_at IS NULL
+                ) AS "pending!"
+            FROM webhook.dead_letter AS dl
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = dl.subscription__id
+            WHERE dl.application__id = $1
+                AND dl.dead_letter__id = $2
+            FOR UPDATE OF s
+        "#,
+        &body.application_id,
+        &dead_letter_id,
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(Hook0Problem::from)?
+    .ok_or(Hook0Problem::NotFound)?;
+
+    if !redeliverable.subscription_is_active {
+        return Err(Hook0Problem::SubscriptionDisabled);
+    }
+    if redeliverable.pending {
+        return Err(Hook0Problem::RequestAttemptRetryPending);
+    }
+
+    let redelivered =
+        redeliver_dead_letters(&mut tx, &state, body.application_id, &[dead_letter_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(Hook0Problem::NotFound)?;
+
+    tx.commit().await?;
+    report_retried_request_attempts(1);
+
+    Ok(CreatedJson(redelivered))
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct RedeliverDeadLetters {
+    application_id: Uuid,
+    /// Only redeliver the dead letters of this subscription
+    subscription_id: Option<Uuid>,
+}
+
+#[api_v2_operation(
+    summary = "Redeliver dead letters",
+    description = "Redelivers the oldest dead letters of the application, or of one of its subscriptions, at most 100 at a time: call it again until it returns an empty list. Dead letters of disabled subscriptions, and dead letters whose event is already being delivered again to their subscription, are left alone.",
+    operation_id = "deadLetters.redeliverMany",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn redeliver_many(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    body: Json<RedeliverDeadLetters>,
+) -> Result<Json<Vec<RedeliveredDeadLetter>>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::DeadLetterRedeliver {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let mut tx = state.db.begin().await?;
+
+    let dead_letter_ids = query_scalar!(
+        "
+            SELECT dl.dead_letter__id
+            FROM webhook.dead_letter AS dl
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = dl.subscription__id
+            WHERE dl.application__id = $1
+                AND (dl.subscription__id = $2 OR $2 IS NULL)
+                AND s.is_enabled AND s.deleted_at IS NULL
+                AND NOT EXISTS (
+                    SELECT 1
+                    FROM webhook.request_attempt AS other
+                    WHERE other.event__id = dl.event__id
+                        AND other.subscription__id = dl.subscription__id
+                        AND other.succeeded_at IS NULL
+                        AND other.failed_at IS NULL
+                )
+            ORDER BY dl.created_at ASC
+            LIMIT $3
+        ",
+        &body.application_id,
+        body.subscription_id,
+        MAX_REDELIVERED_AT_ONCE,
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(Hook0Problem::from)?;
+
+    let redelivered =
+        redeliver_dead_letters(&mut tx, &state, body.application_id, &dead_letter_ids).await?;
+
+    tx.commit().await?;
+    report_retried_request_attempts(u64::try_from(redelivered.len()).unwrap_or(u64::MAX));
+
+    Ok(Json(redelivered))
+}
+
+/// Redeliver the dead letters that can be: a new request attempt is created for each (event,
+/// subscription) pair, and the dead letters are removed. Dead letters of disabled subscriptions,
+/// or whose event is already being delivered again to their subscription, are left alone.
+async fn redeliver_dead_letters(
+    conn: &mut PgConnection,
+    state: &crate::State,
+    application_id: Uuid,
+    dead_letter_ids: &[Uuid],
+) -> Result<Vec<RedeliveredDeadLetter>, Hook0Problem> {
+    if dead_letter_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // Same lock as retrying a request attempt, so that the pending check below holds until the
+    // new request attempts are committed
+    query!(
+        "
+            SELECT subscription__id
+            FROM webhook.subscription
+            WHERE subscription__id IN (
+                SELECT subscription__id
+                FROM webhook.dead_letter
+                WHERE dead_letter__id = ANY($1)
+            )
+            ORDER BY subscription__id
+            FOR UPDATE
+        ",
+        dead_letter_ids,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    #[allow(non_snake_case)]
+    struct RawRedeliverable {
+        dead_letter__id: Uuid,
+        event__id: Uuid,
+        subscription__id: Uuid,
+        ordering_key: Option<String>,
+    }
+    let redeliverable = query_as!(
+        RawRedeliverable,
+        "
+            SELECT dl.dead_letter__id, dl.event__id, dl.subscription__id, ra.ordering_key
+            FROM webhook.dead_letter AS dl
+            INNER JOIN webhook.request_attempt AS ra ON ra.request_attempt__id = dl.request_attempt__id
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = dl.subscription__id
+            WHERE dl.application__id = $1
+                AND dl.dead_letter__id = ANY($2)
+                AND s.is_enabled AND s.deleted_at IS NULL
+                AND NOT EXISTS (
+                    SELECT 1
+                    FROM webhook.request_attempt AS other
+                    WHERE other.event__id = dl.event__id
+                        AND other.subscription__id = dl.subscription__id
+                        AND other.succeeded_at IS NULL
+                        AND other.failed_at IS NULL
+                )
+            ORDER BY dl.created_at ASC
+            FOR UPDATE OF dl
+        ",
+        application_id,
+        dead_letter_ids,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    if redeliverable.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // A pair can have several dead letters (an event redelivered by a bulk retry can fail for good
+    // again); one redelivery settles them all
+    let mut pairs = HashMap::<(Uuid, Uuid), Option<String>>::new();
+    for dl in &redeliverable {
+        pairs
+            .entry((dl.event__id, dl.subscription__id))
+            .or_insert_with(|| dl.ordering_key.clone());
+    }
+    let mut event_ids = Vec::with_capacity(pairs.len());
+    let mut subscription_ids = Vec::with_capacity(pairs.len());
+    let mut ordering_keys = Vec::with_capacity(pairs.len());
+    for ((event_id, subscription_id), ordering_key) in pairs {
+        event_ids.push(event_id);
+        subscription_ids.push(subscription_id);
+        ordering_keys.push(ordering_key);
+    }
+
+    #[allow(non_snake_case)]
+    struct RawNewRequestAttempt {
+        request_attempt__id: Uuid,
+        event__id: Uuid,
+        subscription__id: Uuid,
+    }
+    let new_request_attempts = query_as!(
+        RawNewRequestAttempt,
+        r#"
+            INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, ordering_key)
+            SELECT $1, pair.event__id, pair.subscription__id, pair.ordering_key
+            FROM UNNEST($2::UUID[], $3::UUID[], $4::TEXT[]) AS pair (event__id, subscription__id, ordering_key)
+            RETURNING request_attempt__id, event__id, subscription__id
+        "#,
+        application_id,
+        &event_ids,
+        &subscription_ids,
+        &ordering_keys as &[Option<String>],
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    query!(
+        "
+            DELETE FROM webhook.dead_letter
+            WHERE dead_letter__id = ANY($1)
+        ",
+        &redeliverable
+            .iter()
+            .map(|dl| dl.dead_letter__id)
+            .collect::<Vec<_>>(),
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    if let Some(pulsar) = &state.pulsar {
+        send_retries_to_pulsar(
+            conn,
+            pulsar,
+            state.object_storage.as_ref(),
+            application_id,
+            new_request_attempts
+                .iter()
+                .map(|ra| (ra.event__id, ra.request_attempt__id)),
+        )
+        .await?;
+    }
+
+    let request_attempt_ids = new_request_attempts
+        .into_iter()
+        .map(|ra| ((ra.event__id, ra.subscription__id), ra.request_attempt__id))
+        .collect::<HashMap<_, _>>();
+    Ok(redeliverable
+        .into_iter()
+        .filter_map(|dl| {
+            request_attempt_ids
+                .get(&(dl.event__id, dl.subscription__id))
+                .map(|request_attempt_id| RedeliveredDeadLetter {
+                    dead_letter_id: dl.dead_letter__id,
+                    request_attempt_id: *request_attempt_id,
+                })
+        })
+        .collect())
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct DeleteQs {
+    application_id: Uuid,
+}
+
+#[api_v2_operation(
+    summary = "Delete a dead letter",
+    description = "Removes a dead letter without redelivering it. Its event and request attempts are kept.",
+    operation_id = "deadLetters.delete",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn delete(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    qs: Query<DeleteQs>,
+    dead_letter_id: Path<Uuid>,
+) -> Result<NoContent, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::DeadLetterPurge {
+            application_id: &qs.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let deleted = query!(
+        "
+            DELETE FROM webhook.dead_letter
+            WHERE application__id = $1
+                AND dead_letter__id = $2
+        ",
+        &qs.application_id,
+        &dead_letter_id.into_inner(),
+    )
+    .execute(&state.db)
+    .await
+    .map_err(Hook0Problem::from)?
+    .rows_affected();
+
+    if deleted > 0 {
+        Ok(NoContent)
+    } else {
+        Err(Hook0Problem::NotFound)
+    }
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct PurgeDeadLetters {
+    application_id: Uuid,
+    /// Only purge the dead letters of this subscription
+    subscription_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct PurgedDeadLetters {
+    pub purged_count: i64,
+}
+
+#[api_v2_operation(
+    summary = "Purge dead letters",
+    description = "Removes every dead letter of the application, or of one of its subscriptions, without redelivering them. Their events and request attempts are kept.",
+    operation_id = "deadLetters.purge",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn purge(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    body: Json<PurgeDeadLetters>,
+) -> Result<Json<PurgedDeadLetters>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::DeadLetterPurge {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let purged = query!(
+        "
+            DELETE FROM webhook.dead_letter
+            WHERE application__id = $1
+                AND (subscription__id = $2 OR $2 IS NULL)
+        ",
+        &body.application_id,
+        body.subscription_id,
+    )
+    .execute(&state.db)
+    .await
+    .map_err(Hook0Problem::from)?
+    .rows_affected();
+
+    Ok(Json(PurgedDeadLetters {
+        purged_count: i64::try_from(purged).unwrap_or(i64::MAX),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_subscription,
+        seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    /// Insert a subscription whose delivery of the event failed for good, returning the
+    /// subscription and the dead letter.
+    async fn seed_dead_letter(pool: &PgPool, application_id: Uuid, event_id: Uuid) -> (Uuid, Uuid) {
+        let subscription_id =
+            seed_subscription(pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+
+        let request_attempt_id = Uuid::new_v4();
+        sqlx::query(
+            r#"
+                INSERT INTO webhook.request_attempt
+                    (request_attempt__id, event__id, subscription__id, application__id, picked_at, failed_at, retry_count)
+                VALUES ($1, $2, $3, $4, statement_timestamp(), statement_timestamp(), 8)
+            "#,
+        )
+        .bind(request_attempt_id)
+        .bind(event_id)
+        .bind(subscription_id)
+        .bind(application_id)
+        .execute(pool)
+        .await
+        .expect("seed failed request attempt");
+
+        let dead_letter_id = sqlx::query_scalar(
+            r#"
+                INSERT INTO webhook.dead_letter (application__id, subscription__id, event__id, request_attempt__id, reason)
+                VALUES ($1, $2, $3, $4, 'retries_exhausted')
+                RETURNING dead_letter__id
+            "#,
+        )
+        .bind(application_id)
+        .bind(subscription_id)
+        .bind(event_id)
+        .bind(request_attempt_id)
+        .fetch_one(pool)
+        .await
+        .expect("seed dead letter");
+        (subscription_id, dead_letter_id)
+    }
+
+    /// A dead letter is listed until it is redelivered, which queues a new request attempt once;
+    /// the others are purged without being redelivered.
+    #[sqlx::test]
+    async fn dead_letters_are_redelivered_or_purged(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, event_id) = seed_event(&pool, org).await;
+        let (subscription_id, dead_letter_id) =
+            seed_dead_letter(&pool, application_id, event_id).await;
+        seed_dead_letter(&pool, application_id, event_id).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1/dead_letters")
+                .wrap(biscuit_auth)
+                .route("", web::get().to(super::list))
+                .route("/purge", web::post().to(super::purge))
+                .route("/{dead_letter_id}", web::get().to(super::get))
+                .route(
+                    "/{dead_letter_id}/redeliver",
+                    web::post().to(super::redeliver),
+                ),
+        ));
+        let authorization = ("Authorization", format!("Bearer {user_token}"));
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/api/v1/dead_letters?application_id={application_id}"
+            ))
+            .insert_header(authorization.clone())
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body.as_array().map(Vec::len), Some(2));
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/api/v1/dead_letters/{dead_letter_id}?application_id={application_id}"
+            ))
+            .insert_header(authorization.clone())
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["dead_letter"]["reason"], "retries_exhausted");
+        assert_eq!(body["dead_letter"]["retry_count"], 8);
+        assert!(body["last_response"].is_null());
+
+        let redeliver = || {
+            test::TestRequest::post()
+                .uri(&format!("/api/v1/dead_letters/{dead_letter_id}/redeliver"))
+                .insert_header(authorization.clone())
+                .set_json(serde_json::json!({ "application_id": application_id }))
+                .to_request()
+        };
+        let resp = test::call_service(&app, redeliver()).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["dead_letter_id"], dead_letter_id.to_string());
+        let pending: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM webhook.request_attempt WHERE subscription__id = $1 AND failed_at IS NULL",
+        )
+        .bind(subscription_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(pending, 1);
+
+        let resp = test::call_service(&app, redeliver()).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/dead_letters/purge")
+            .insert_header(authorization.clone())
+            .set_json(serde_json::json!({ "application_id": application_id }))
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["purged_count"], 1);
+
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM webhook.dead_letter")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+    }
+}
@@ -1,19 +1,58 @@
 use actix_web::web::ReqData;
 use biscuit_auth::Biscuit;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use paperclip::actix::web::{Data, Json, Path, Query};
 use paperclip::actix::{Apiv2Schema, CreatedJson, NoContent, api_v2_operation};
 use serde::{Deserialize, Serialize};
-use sqlx::{query, query_as};
-use tracing::error;
+use serde_json::Value;
+use sqlx::{PgPool, query, query_as};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{error, warn};
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::hook0_client::{EventEventTypeCreated, EventEventTypeRemoved, Hook0ClientEvent};
+use crate::hook0_client::{
+    EventEventTypeCreated, EventEventTypeRemoved, EventSubscriptionEventTypeVersionDeprecated,
+    Hook0ClientEvent,
+};
 use crate::iam::{Action, authorize_for_application, get_owner_organization};
 use crate::openapi::OaBiscuit;
+use crate::opentelemetry::report_event_payload_schema_violation;
 use crate::problems::Hook0Problem;
 
+/// Upper bound on how many violations are reported for one payload.
+const MAX_REPORTED_VIOLATIONS: usize = 20;
+
+/// Upper bound on how many compiled payload schemas are kept; the cache starts over once it is full.
+const MAX_CACHED_PAYLOAD_SCHEMAS: usize = 1000;
+
+/// Payload schemas compiled once and reused for the payloads checked after, keyed by a hash of the
+/// schema so that a schema that changed is compiled again.
+#[derive(Debug, Default)]
+pub struct PayloadSchemaCache(Mutex<HashMap<String, Arc<jsonschema::Validator>>>);
+
+impl PayloadSchemaCache {
+    async fn validator(
+        &self,
+        schema_hash: String,
+        schema: &Value,
+    ) -> Result<Arc<jsonschema::Validator>, jsonschema::ValidationError<'static>> {
+        if let Some(validator) = self.0.lock().await.get(&schema_hash) {
+            return Ok(validator.clone());
+        }
+
+        let validator = Arc::new(jsonschema::validator_for(schema)?);
+        let mut validators = self.0.lock().await;
+        if validators.len() >= MAX_CACHED_PAYLOAD_SCHEMAS {
+            validators.clear();
+        }
+        validators.insert(schema_hash, validator.clone());
+        Ok(validator)
+    }
+}
+
 #[derive(Debug, Serialize, Apiv2Schema)]
 pub struct EventType {
     service_name: String,
@@ -21,6 +60,154 @@ pub struct EventType {
     verb_name: String,
     // status
     event_type_name: String,
+    payload_schema: Option<Value>,
+    payload_schema_mode: PayloadSchemaMode,
+    /// Time to live in seconds of the events of this type that do not give an expiry date
+    default_ttl_s: Option<i32>,
+    versions: Vec<EventTypeVersion>,
+}
+
+/// A version of the payload contract of an event type
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct EventTypeVersion {
+    version: i32,
+    /// JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type
+    payload_schema: Option<Value>,
+    created_at: DateTime<Utc>,
+    deprecated_at: Option<DateTime<Utc>>,
+    /// Date from which the events declaring this version are refused
+    sunset_at: Option<DateTime<Utc>>,
+}
+
+/// The deprecation of the event type version an event was ingested with
+#[derive(Debug, Clone, Serialize, Apiv2Schema)]
+pub struct EventTypeDeprecation {
+    event_type_version: i32,
+    deprecated_at: DateTime<Utc>,
+    sunset_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug)]
+struct EventTypeRaw {
+    service_name: String,
+    resource_type_name: String,
+    verb_name: String,
+    event_type_name: String,
+    payload_schema: Option<Value>,
+    payload_schema_mode: String,
+    default_ttl_s: Option<i32>,
+}
+
+impl From<EventTypeRaw> for EventType {
+    fn from(raw: EventTypeRaw) -> Self {
+        Self {
+            service_name: raw.service_name,
+            resource_type_name: raw.resource_type_name,
+            verb_name: raw.verb_name,
+            event_type_name: raw.event_type_name,
+            payload_schema: raw.payload_schema,
+            payload_schema_mode: PayloadSchemaMode::from_db(&raw.payload_schema_mode),
+            default_ttl_s: raw.default_ttl_s,
+            versions: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct EventTypeVersionRaw {
+    event_type_name: String,
+    version: i32,
+    payload_schema: Option<Value>,
+    created_at: DateTime<Utc>,
+    deprecated_at: Option<DateTime<Utc>>,
+    sunset_at: Option<DateTime<Utc>>,
+}
+
+impl From<EventTypeVersionRaw> for EventTypeVersion {
+    fn from(raw: EventTypeVersionRaw) -> Self {
+        Self {
+            version: raw.version,
+            payload_schema: raw.payload_schema,
+            created_at: raw.created_at,
+            deprecated_at: raw.deprecated_at,
+            sunset_at: raw.sunset_at,
+        }
+    }
+}
+
+/// Attaches their versions to event types of an application.
+async fn with_versions(
+    db: &PgPool,
+    application_id: &Uuid,
+    event_types: Vec<EventTypeRaw>,
+) -> Result<Vec<EventType>, Hook0Problem> {
+    let names = event_types
+        .iter()
+        .map(|et| et.event_type_name.to_owned())
+        .collect::<Vec<_>>();
+    let mut versions: HashMap<String, Vec<EventTypeVersion>> = HashMap::new();
+    for version in query_as!(
+        EventTypeVersionRaw,
+        "
+            SELECT event_type__name AS event_type_name, version, payload_schema, created_at, deprecated_at, sunset_at
+            FROM event.event_type_version
+            WHERE application__id = $1 AND event_type__name = ANY($2)
+            ORDER BY version ASC
+        ",
+        application_id,
+        &names,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(Hook0Problem::from)?
+    {
+        versions
+            .entry(version.event_type_name.to_owned())
+            .or_default()
+            .push(version.into());
+    }
+
+    Ok(event_types
+        .into_iter()
+        .map(|raw| {
+            let event_type_versions = versions.remove(&raw.event_type_name).unwrap_or_default();
+            EventType {
+                versions: event_type_versions,
+                ..raw.into()
+            }
+        })
+        .collect())
+}
+
+/// How the payload schema of an event type is applied to the `application/json` events ingested with it
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Apiv2Schema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadSchemaMode {
+    /// Events whose payload does not match the schema are refused
+    #[default]
+    Enforce,
+    /// Events whose payload does not match the schema are ingested anyway, and the violations are logged
+    Warn,
+    /// The schema is kept but not applied
+    Off,
+}
+
+impl PayloadSchemaMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Enforce => "enforce",
+            Self::Warn => "warn",
+            Self::Off => "off",
+        }
+    }
+
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "warn" => Self::Warn,
+            "off" => Self::Off,
+            _ => Self::Enforce,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Apiv2Schema)]
@@ -37,11 +224,51 @@ pub struct EventTypePost {
     resource_type: String,
     #[validate(non_control_character, length(min = 1, max = 50))]
     verb: String,
+    /// JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported
+    #[validate(custom(function = "crate::validators::event_type_payload_schema"))]
+    payload_schema: Option<Value>,
+    /// How the payload schema is applied at ingestion (default: `enforce`)
+    payload_schema_mode: Option<PayloadSchemaMode>,
+    /// Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+    #[validate(range(min = 1))]
+    default_ttl_s: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Apiv2Schema, Validate)]
+pub struct EventTypePut {
+    application_id: Uuid,
+    /// JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported
+    #[validate(custom(function = "crate::validators::event_type_payload_schema"))]
+    payload_schema: Option<Value>,
+    /// How the payload schema is applied at ingestion (default: `enforce`)
+    payload_schema_mode: Option<PayloadSchemaMode>,
+    /// Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+    #[validate(range(min = 1))]
+    default_ttl_s: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Apiv2Schema, Validate)]
+pub struct EventTypeVersionPost {
+    application_id: Uuid,
+    #[validate(range(min = 1))]
+    version: i32,
+    /// JSON Schema the payloads of the `application/json` events declaring this version must match, instead of the one of the event type; remote references are not supported
+    #[validate(custom(function = "crate::validators::event_type_payload_schema"))]
+    payload_schema: Option<Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Apiv2Schema, Validate)]
+pub struct EventTypeVersionDeprecate {
+    application_id: Uuid,
+    #[validate(range(min = 1))]
+    version: i32,
+    /// Date from which the events declaring this version are refused; none keeps accepting them
+    sunset_at: Option<DateTime<Utc>>,
 }
 
 #[api_v2_operation(
     summary = "Create a new event type",
-    description = "Registers a new event type for an application. Event types follow the pattern 'service.resource.verb' (e.g., 'order.payment.completed'). Subscriptions can filter which event types trigger webhooks.",
+    description = "Registers a new event type for an application. Event types follow the pattern 'service.resource.verb' (e.g., 'order.payment.completed'). Subscriptions can filter which event types trigger webhooks. An optional JSON Schema validates the payloads of the `application/json` events of the type at ingestion. An optional default time to live sets when the events of the type that do not give an expiry date expire. Creating an event type that already exists keeps its payload schema, mode and default time to live, unless new ones are given.",
     operation_id = "eventTypes.create",
     consumes = "application/json",
     produces = "application/json",
@@ -116,17 +343,20 @@ pub async fn create(
     .map_err(Hook0Problem::from)?;
 
     let event_type = query_as!(
-            EventType,
+            EventTypeRaw,
             "
-                INSERT INTO event.event_type (application__id, service__name, resource_type__name, verb__name)
-                VALUES ($1, $2, $3, $4)
-                ON CONFLICT (application__id, event_type__name) DO UPDATE SET deactivated_at = NULL
-                RETURNING service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name
+                INSERT INTO event.event_type (application__id, service__name, resource_type__name, verb__name, payload_schema, payload_schema_mode, default_ttl_s)
+                VALUES ($1, $2, $3, $4, $5, COALESCE($6, 'enforce'), $7)
+                ON CONFLICT (application__id, event_type__name) DO UPDATE SET deactivated_at = NULL, payload_schema = COALESCE(EXCLUDED.payload_schema, event_type.payload_schema), payload_schema_mode = COALESCE($6, event_type.payload_schema_mode), default_ttl_s = COALESCE(EXCLUDED.default_ttl_s, event_type.default_ttl_s)
+                RETURNING service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name, payload_schema, payload_schema_mode, default_ttl_s
             ",
             &body.application_id,
             &body.service,
             &body.resource_type,
-            &body.verb
+            &body.verb,
+            body.payload_schema.as_ref(),
+            body.payload_schema_mode.map(|mode| mode.as_str()),
+            body.default_ttl_s,
         )
         .fetch_one(&mut *tx)
         .await
@@ -134,6 +364,10 @@ pub async fn create(
 
     tx.commit().await.map_err(Hook0Problem::from)?;
 
+    let event_type = with_versions(&state.db, &body.application_id, vec![event_type])
+        .await?
+        .remove(0);
+
     if let Some(hook0_client) = state.hook0_client.as_ref() {
         let hook0_client_event: Hook0ClientEvent = EventEventTypeCreated {
             organization_id: get_owner_organization(&state.db, &body.application_id)
@@ -184,9 +418,9 @@ pub async fn list(
     .await?;
 
     let event_types = query_as!(
-            EventType,
+            EventTypeRaw,
             "
-                SELECT service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name
+                SELECT service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name, payload_schema, payload_schema_mode, default_ttl_s
                 FROM event.event_type
                 WHERE application__id = $1 AND deactivated_at IS NULL
                 ORDER BY event_type__name ASC
@@ -197,7 +431,9 @@ pub async fn list(
         .await
         .map_err(Hook0Problem::from)?;
 
-    Ok(Json(event_types))
+    Ok(Json(
+        with_versions(&state.db, &qs.application_id, event_types).await?,
+    ))
 }
 
 #[api_v2_operation(
@@ -227,9 +463,9 @@ pub async fn get(
     .await?;
 
     let event_type = query_as!(
-            EventType,
+            EventTypeRaw,
             "
-                SELECT service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name
+                SELECT service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name, payload_schema, payload_schema_mode, default_ttl_s
                 FROM event.event_type
                 WHERE application__id = $1 AND event_type__name = $2 AND deactivated_at IS NULL
             ",
@@ -241,11 +477,223 @@ pub async fn get(
         .map_err(Hook0Problem::from)?;
 
     match event_type {
-        Some(a) => Ok(Json(a)),
+        Some(a) => Ok(Json(
+            with_versions(&state.db, &qs.application_id, vec![a])
+                .await?
+                .remove(0),
+        )),
+        None => Err(Hook0Problem::NotFound),
+    }
+}
+
+#[api_v2_operation(
+    summary = "Update an event type",
+    description = "Sets the JSON Schema the payloads of the event type must match, and how it is applied at ingestion: `enforce` refuses the events whose payload does not match, `warn` ingests them anyway and logs the violations, and `off` keeps the schema without applying it. Also sets the default time to live of the events of the type that do not give an expiry date. Omitting the schema or the default time to live removes it.",
+    operation_id = "eventTypes.update",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Events Management", "mcp", "sdk")
+)]
+pub async fn edit(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    event_type_name: Path<String>,
+    body: Json<EventTypePut>,
+) -> Result<Json<EventType>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::EventTypeEdit {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let event_type = query_as!(
+            EventTypeRaw,
+            "
+                UPDATE event.event_type
+                SET payload_schema = $3, payload_schema_mode = $4, default_ttl_s = $5
+                WHERE application__id = $1 AND event_type__name = $2 AND deactivated_at IS NULL
+                RETURNING service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name, payload_schema, payload_schema_mode, default_ttl_s
+            ",
+            &body.application_id,
+            &event_type_name.into_inner(),
+            body.payload_schema.as_ref(),
+            body.payload_schema_mode.unwrap_or_default().as_str(),
+            body.default_ttl_s,
+        )
+        .fetch_optional(&state.db)
+        .await
+        .map_err(Hook0Problem::from)?;
+
+    match event_type {
+        Some(a) => Ok(Json(
+            with_versions(&state.db, &body.application_id, vec![a])
+                .await?
+                .remove(0),
+        )),
         None => Err(Hook0Problem::NotFound),
     }
 }
 
+#[api_v2_operation(
+    summary = "Create a new event type version",
+    description = "Declares a new version of the payload contract of an event type (e.g., version 2 of 'order.payment.completed'). Events can then declare the version they are emitted with, and subscriptions can pin the version they receive. A version can have its own JSON Schema, applied instead of the one of the event type to the events declaring it.",
+    operation_id = "eventTypes.createVersion",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Events Management", "mcp", "sdk")
+)]
+pub async fn create_version(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    event_type_name: Path<String>,
+    body: Json<EventTypeVersionPost>,
+) -> Result<CreatedJson<EventTypeVersion>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::EventTypeEdit {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let version = query_as!(
+            EventTypeVersionRaw,
+            "
+                INSERT INTO event.event_type_version (application__id, event_type__name, version, payload_schema)
+                SELECT application__id, event_type__name, $3, $4
+                FROM event.event_type
+                WHERE application__id = $1 AND event_type__name = $2 AND deactivated_at IS NULL
+                RETURNING event_type__name AS event_type_name, version, payload_schema, created_at, deprecated_at, sunset_at
+            ",
+            &body.application_id,
+            &event_type_name.into_inner(),
+            body.version,
+            body.payload_schema.as_ref(),
+        )
+        .fetch_optional(&state.db)
+        .await
+        .map_err(Hook0Problem::from)?;
+
+    match version {
+        Some(v) => Ok(CreatedJson(v.into())),
+        None => Err(Hook0Problem::NotFound),
+    }
+}
+
+#[api_v2_operation(
+    summary = "Deprecate an event type version",
+    description = "Marks a version of an event type as deprecated, optionally with a sunset date from which the events declaring it are refused. The deprecation is reported in the event type, in the responses to the ingestion of events declaring the version, and by an `api.subscription.event_type_version_deprecated` event for each subscription still pinned to it. Deprecating a version again only changes its sunset date.",
+    operation_id = "eventTypes.deprecateVersion",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Events Management", "mcp", "sdk")
+)]
+pub async fn deprecate_version(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    event_type_name: Path<String>,
+    body: Json<EventTypeVersionDeprecate>,
+) -> Result<Json<EventTypeVersion>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::EventTypeEdit {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let version = query_as!(
+            EventTypeVersionRaw,
+            r#"
+                UPDATE event.event_type_version AS etv
+                SET deprecated_at = COALESCE(etv.deprecated_at, statement_timestamp()), sunset_at = $4
+                FROM event.event_type AS et
+                WHERE et.application__id = etv.application__id AND et.event_type__name = etv.event_type__name AND et.deactivated_at IS NULL
+                    AND etv.application__id = $1 AND etv.event_type__name = $2 AND etv.version = $3
+                RETURNING etv.event_type__name AS event_type_name, etv.version, etv.payload_schema, etv.created_at, etv.deprecated_at, etv.sunset_at
+            "#,
+            &body.application_id,
+            &event_type_name.into_inner(),
+            body.version,
+            body.sunset_at,
+        )
+        .fetch_optional(&state.db)
+        .await
+        .map_err(Hook0Problem::from)?;
+    let Some(version) = version else {
+        return Err(Hook0Problem::NotFound);
+    };
+
+    if let Some(hook0_client) = state.hook0_client.as_ref() {
+        let pinned_subscriptions = query!(
+            "
+                SELECT s.subscription__id AS subscription_id
+                FROM webhook.subscription AS s
+                INNER JOIN webhook.subscription__event_type AS set ON set.subscription__id = s.subscription__id
+                WHERE set.application__id = $1 AND set.event_type__name = $2 AND set.event_type_version = $3
+                    AND s.deleted_at IS NULL AND s.is_enabled
+            ",
+            &body.application_id,
+            &version.event_type_name,
+            version.version,
+        )
+        .fetch_all(&state.db)
+        .await
+        .map_err(Hook0Problem::from)?;
+
+        let organization_id = get_owner_organization(&state.db, &body.application_id)
+            .await
+            .unwrap_or(Uuid::nil());
+        for subscription in pinned_subscriptions {
+            let hook0_client_event: Hook0ClientEvent =
+                EventSubscriptionEventTypeVersionDeprecated {
+                    organization_id,
+                    application_id: body.application_id,
+                    subscription_id: subscription.subscription_id,
+                    event_type_name: version.event_type_name.to_owned(),
+                    event_type_version: version.version,
+                    deprecated_at: version.deprecated_at.unwrap_or_else(Utc::now),
+                    sunset_at: version.sunset_at,
+                }
+                .into();
+            if let Err(e) = hook0_client
+                .send_event(&hook0_client_event.mk_hook0_event())
+                .await
+            {
+                error!("Hook0ClientError: {e}");
+            };
+        }
+    }
+
+    Ok(Json(version.into()))
+}
+
 #[api_v2_operation(
     summary = "Delete an event type",
     description = "Deactivates an event type, preventing it from being used for new events. Existing events using this type remain unaffected. Use this to clean up unused event types.",
@@ -274,9 +722,9 @@ pub async fn delete(
 
     let application_id = qs.application_id;
     let event_type = query_as!(
-            EventType,
+            EventTypeRaw,
             "
-                SELECT service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name
+                SELECT service__name AS service_name, resource_type__name AS resource_type_name, verb__name AS verb_name, event_type__name AS event_type_name, payload_schema, payload_schema_mode, default_ttl_s
                 FROM event.event_type
                 WHERE application__id = $1 AND event_type__name = $2 AND deactivated_at IS NULL
             ",
@@ -324,3 +772,433 @@ pub async fn delete(
         None => Err(Hook0Problem::NotFound),
     }
 }
+
+/// Checks that an event can be ingested with a version of its event type, and reports the
+/// deprecation of that version if it is deprecated.
+pub async fn check_event_type_version(
+    db: &PgPool,
+    application_id: &Uuid,
+    event_type_name: &str,
+    event_type_version: i32,
+) -> Result<Option<EventTypeDeprecation>, Hook0Problem> {
+    let version = query!(
+        r#"
+            SELECT deprecated_at, sunset_at, COALESCE(sunset_at <= statement_timestamp(), false) AS "is_sunset!"
+            FROM event.event_type_version
+            WHERE application__id = $1 AND event_type__name = $2 AND version = $3
+        "#,
+        application_id,
+        event_type_name,
+        event_type_version,
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(Hook0Problem::from)?;
+
+    match version {
+        None => Err(Hook0Problem::EventTypeVersionDoesNotExist),
+        Some(v) if v.is_sunset => Err(Hook0Problem::EventTypeVersionSunset),
+        Some(v) => Ok(v.deprecated_at.map(|deprecated_at| EventTypeDeprecation {
+            event_type_version,
+            deprecated_at,
+            sunset_at: v.sunset_at,
+        })),
+    }
+}
+
+/// Checks a JSON payload against the schema of the version of its event type it declares, or else
+/// of its event type, if there is one. A payload that does not match refuses the event in
+/// `enforce` mode, and is only logged in `warn` mode.
+pub async fn check_payload_schema(
+    db: &PgPool,
+    payload_schemas: &PayloadSchemaCache,
+    application_id: &Uuid,
+    event_type_name: &str,
+    event_type_version: Option<i32>,
+    payload: &[u8],
+) -> Result<(), Hook0Problem> {
+    let schema = query!(
+        r#"
+            SELECT
+                COALESCE(etv.payload_schema, et.payload_schema) AS "payload_schema!",
+                md5(COALESCE(etv.payload_schema, et.payload_schema)::text) AS "payload_schema_hash!",
+                et.payload_schema_mode
+            FROM event.event_type AS et
+            LEFT JOIN event.event_type_version AS etv ON etv.application__id = et.application__id AND etv.event_type__name = et.event_type__name AND etv.version = $3
+            WHERE et.application__id = $1 AND et.event_type__name = $2 AND COALESCE(etv.payload_schema, et.payload_schema) IS NOT NULL AND et.payload_schema_mode <> 'off'
+        "#,
+        application_id,
+        event_type_name,
+        event_type_version,
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(Hook0Problem::from)?;
+    let Some(schema) = schema else {
+        return Ok(());
+    };
+
+    let payload = serde_json::from_slice::<Value>(payload)
+        .map_err(|e| Hook0Problem::EventInvalidJsonPayload(e.to_string()))?;
+    let violations = match payload_schemas
+        .validator(schema.payload_schema_hash, &schema.payload_schema)
+        .await
+    {
+        Ok(validator) => payload_schema_violations(&validator, &payload),
+        Err(e) => {
+            // Schemas are checked when they are set, so this only happens if a schema that used to be accepted no longer is
+            error!(%application_id, event_type_name, "Could not compile the payload schema of an event type; skipping it: {e}");
+            return Ok(());
+        }
+    };
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    match PayloadSchemaMode::from_db(&schema.payload_schema_mode) {
+        PayloadSchemaMode::Warn => {
+            report_event_payload_schema_violation(PayloadSchemaMode::Warn.as_str());
+            warn!(
+                %application_id,
+                event_type_name,
+                "Event payload does not match the schema of its event type: {}",
+                violations.join("; ")
+            );
+            Ok(())
+        }
+        _ => {
+            report_event_payload_schema_violation(PayloadSchemaMode::Enforce.as_str());
+            Err(Hook0Problem::EventPayloadSchemaViolation(violations))
+        }
+    }
+}
+
+/// Where and how a payload does not match a schema, one entry per violation.
+fn payload_schema_violations(validator: &jsonschema::Validator, payload: &Value) -> Vec<String> {
+    validator
+        .iter_errors(payload)
+        .take(MAX_REPORTED_VIOLATIONS)
+        .map(|e| {
+            let path = e.instance_path().as_str();
+            format!("{}: {e}", if path.is_empty() { "/" } else { path })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_subscription,
+        seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use serde_json::json;
+
+    /// A payload schema is compiled once, and again only once it changed.
+    #[actix_web::test]
+    async fn compiled_payload_schemas_are_reused_until_they_change() {
+        let payload_schemas = PayloadSchemaCache::default();
+        let schema = json!({ "type": "object" });
+
+        let first = payload_schemas
+            .validator("a".to_owned(), &schema)
+            .await
+            .unwrap();
+        let again = payload_schemas
+            .validator("a".to_owned(), &schema)
+            .await
+            .unwrap();
+        assert!(Arc::ptr_eq(&first, &again));
+
+        let changed = payload_schemas
+            .validator("b".to_owned(), &json!({ "type": "array" }))
+            .await
+            .unwrap();
+        assert!(!Arc::ptr_eq(&first, &changed));
+        assert!(payload_schema_violations(&changed, &json!([])).is_empty());
+    }
+
+    /// A schema set on an event type is checked when it is set, then applied to payloads according
+    /// to its mode.
+    #[sqlx::test]
+    async fn payload_schemas_are_applied_according_to_their_mode(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, _) = seed_event(&pool, org).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1/event_types")
+                .wrap(biscuit_auth)
+                .route("/{event_type_name}", web::put().to(super::edit)),
+        ));
+        let edit = |event_type_name: &str, body: serde_json::Value| {
+            test::TestRequest::put()
+                .uri(&format!("/api/v1/event_types/{event_type_name}"))
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .set_json(body)
+                .to_request()
+        };
+
+        let schema = json!({
+            "type": "object",
+            "required": ["id"],
+            "properties": { "id": { "type": "string" } },
+        });
+        let resp = test::call_service(
+            &app,
+            edit(
+                "test.resource.created",
+                json!({ "application_id": application_id, "payload_schema": schema }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let edited: Value = test::read_body_json(resp).await;
+        assert_eq!(edited["payload_schema"], schema);
+        assert_eq!(edited["payload_schema_mode"], "enforce");
+
+        let resp = test::call_service(
+            &app,
+            edit(
+                "test.resource.created",
+                json!({ "application_id": application_id, "payload_schema": { "type": "objet" } }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let resp = test::call_service(
+            &app,
+            edit(
+                "test.resource.deleted",
+                json!({ "application_id": application_id, "payload_schema": schema }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let check = |payload: &'static str| {
+            let pool = pool.clone();
+            async move {
+                check_payload_schema(
+                    &pool,
+                    &PayloadSchemaCache::default(),
+                    &application_id,
+                    "test.resource.created",
+                    None,
+                    payload.as_bytes(),
+                )
+                .await
+            }
+        };
+        assert!(check(r#"{"id":"ord_1"}"#).await.is_ok());
+        match check(r#"{"id":1}"#).await {
+            Err(Hook0Problem::EventPayloadSchemaViolation(violations)) => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].starts_with("/id: "), "{violations:?}");
+            }
+            other => panic!("the payload is refused, got {other:?}"),
+        }
+
+        for mode in ["warn", "off"] {
+            let resp = test::call_service(
+                &app,
+                edit(
+                    "test.resource.created",
+                    json!({ "application_id": application_id, "payload_schema": schema, "payload_schema_mode": mode }),
+                ),
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert!(check(r#"{"id":1}"#).await.is_ok(), "{mode} lets it through");
+        }
+    }
+
+    /// Versions are declared and deprecated through the API; events declaring a version are only
+    /// dispatched to the subscriptions that pin it or pin nothing, and are refused once the
+    /// version is past its sunset date.
+    #[sqlx::test]
+    async fn event_type_versions_are_pinned_deprecated_and_sunset(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, _) = seed_event(&pool, org).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1/event_types")
+                .wrap(biscuit_auth)
+                .route(
+                    "/{event_type_name}/versions",
+                    web::post().to(super::create_version),
+                )
+                .route(
+                    "/{event_type_name}/deprecate",
+                    web::post().to(super::deprecate_version),
+                ),
+        ));
+        let post = |uri: &str, body: serde_json::Value| {
+            test::TestRequest::post()
+                .uri(&format!("/api/v1/event_types/test.resource.created/{uri}"))
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .set_json(body)
+                .to_request()
+        };
+
+        let v2_schema = json!({ "type": "object", "required": ["id"] });
+        let resp = test::call_service(
+            &app,
+            post(
+                "versions",
+                json!({ "application_id": application_id, "version": 1 }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = test::call_service(
+            &app,
+            post(
+                "versions",
+                json!({ "application_id": application_id, "version": 2, "payload_schema": v2_schema }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = test::call_service(
+            &app,
+            post(
+                "versions",
+                json!({ "application_id": application_id, "version": 1 }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // Only the events declaring the pinned version, or no version, reach a pinned subscription
+        for version in [None, Some(1), Some(2)] {
+            let subscription_id =
+                seed_subscription(&pool, application_id, json!({ "env": "prod" })).await;
+            query(
+                "INSERT INTO webhook.subscription__event_type (application__id, subscription__id, event_type__name, event_type_version) VALUES ($1, $2, 'test.resource.created', $3)",
+            )
+            .bind(application_id)
+            .bind(subscription_id)
+            .bind(version)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        for (version, expected) in [(None, 3), (Some(1), 2), (Some(2), 2)] {
+            let event_id: Uuid = sqlx::query_scalar(
+                r#"
+                    INSERT INTO event.event (application__id, event_type__name, event_type_version, payload_content_type, ip, occurred_at, labels)
+                    VALUES ($1, 'test.resource.created', $2, 'application/json', '127.0.0.1'::inet, statement_timestamp(), '{"env":"prod"}'::jsonb)
+                    RETURNING event__id
+                "#,
+            )
+            .bind(application_id)
+            .bind(version)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            let dispatched: i64 = sqlx::query_scalar(
+                "SELECT count(*) FROM webhook.request_attempt WHERE event__id = $1",
+            )
+            .bind(event_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            assert_eq!(dispatched, expected, "event declaring {version:?}");
+        }
+
+        assert!(matches!(
+            check_event_type_version(&pool, &application_id, "test.resource.created", 3).await,
+            Err(Hook0Problem::EventTypeVersionDoesNotExist)
+        ));
+        assert!(
+            check_event_type_version(&pool, &application_id, "test.resource.created", 1)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // The schema of a version applies to the events declaring it
+        assert!(
+            check_payload_schema(
+                &pool,
+                &PayloadSchemaCache::default(),
+                &application_id,
+                "test.resource.created",
+                Some(1),
+                b"[]"
+            )
+            .await
+            .is_ok()
+        );
+        assert!(matches!(
+            check_payload_schema(
+                &pool,
+                &PayloadSchemaCache::default(),
+                &application_id,
+                "test.resource.created",
+                Some(2),
+                b"[]"
+            )
+            .await,
+            Err(Hook0Problem::EventPayloadSchemaViolation(_))
+        ));
+
+        let sunset_at =
+            DateTime::from_timestamp(Utc::now().timestamp() + 30 * 24 * 3600, 0).unwrap();
+        let resp = test::call_service(
+            &app,
+            post(
+                "deprecate",
+                json!({ "application_id": application_id, "version": 1, "sunset_at": sunset_at }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let deprecated: Value = test::read_body_json(resp).await;
+        assert!(deprecated["deprecated_at"].is_string());
+        let deprecation =
+            check_event_type_version(&pool, &application_id, "test.resource.created", 1)
+                .await
+                .unwrap()
+                .expect("the version is reported as deprecated");
+        assert_eq!(deprecation.sunset_at, Some(sunset_at));
+
+        let resp = test::call_service(
+            &app,
+            post(
+                "deprecate",
+                json!({ "application_id": application_id, "version": 1, "sunset_at": Utc::now() - chrono::Duration::seconds(1) }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(matches!(
+            check_event_type_version(&pool, &application_id, "test.resource.created", 1).await,
+            Err(Hook0Problem::EventTypeVersionSunset)
+        ));
+
+        let resp = test::call_service(
+            &app,
+            post(
+                "deprecate",
+                json!({ "application_id": application_id, "version": 3 }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
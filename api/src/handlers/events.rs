@@ -5,14 +5,15 @@ use aws_sdk_s3::primitives::ByteStream;
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as Base64;
 use biscuit_auth::Biscuit;
-use chrono::{DateTime, Utc};
-use futures_util::future::try_join_all;
+use chrono::{DateTime, TimeDelta, Utc};
+use futures_util::future::{join_all, try_join_all};
 use paperclip::actix::web::{Data, Json, Path, Query};
 use paperclip::actix::{Apiv2Schema, CreatedJson, NoContent, api_v2_operation};
+use pulsar::SerializeMessage;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 use sqlx::types::ipnetwork::IpNetwork;
-use sqlx::{query, query_as, query_scalar};
+use sqlx::{Acquire, Postgres, Transaction, query, query_as, query_scalar};
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -20,23 +21,31 @@ use std::time::{Duration, Instant};
 use strum::{IntoStaticStr, VariantNames};
 use tracing::{error, trace};
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 use paperclip::v2::models::{DataType, DefaultSchemaRaw};
 use paperclip::v2::schema::Apiv2Schema;
 
-use crate::PulsarConfig;
 use crate::extractor_user_ip::UserIp;
+use crate::handlers::errors::Problem;
+use crate::handlers::event_types::{
+    EventTypeDeprecation, check_event_type_version, check_payload_schema,
+};
 use crate::iam::{Action, authorize_for_application};
 use crate::mailer::Mail;
 use crate::openapi::OaBiscuit;
 use crate::opentelemetry::{
-    report_event_payloads_stored_in_db_fallback, report_event_payloads_stored_in_object_storage,
-    report_ingested_events, report_ingestion_duration, report_ingestion_phase_durations,
-    report_replayed_events, report_request_attempts_sent_to_pulsar,
+    report_cancelled_request_attempts, report_event_payloads_stored_in_db_fallback,
+    report_event_payloads_stored_in_object_storage, report_ingested_events,
+    report_ingestion_duration, report_ingestion_phase_durations, report_replayed_events,
+    report_request_attempts_sent_to_pulsar,
+};
+use crate::problems::{Hook0Problem, ProblemDetails};
+use crate::quotas::{Quota, QuotaNotificationType, QuotaValue};
+use crate::subscription_filter::{
+    dispatch_to_filtered_subscriptions, find_filtered_subscriptions, needs_payload,
 };
-use crate::problems::Hook0Problem;
-use crate::quotas::{Quota, QuotaNotificationType};
+use crate::{ObjectStorageConfig, PulsarConfig};
 use hook0_protobuf::RequestAttempt;
 use hook0_sentry_integration::log_object_storage_error_with_context;
 
@@ -124,12 +133,16 @@ pub struct Qs {
 struct EventRaw {
     event__id: Uuid,
     event_type__name: String,
+    event_type_version: Option<i32>,
     payload_content_type: String,
     ip: IpNetwork,
     metadata: Option<Value>,
     occurred_at: DateTime<Utc>,
     received_at: DateTime<Utc>,
     labels: Value,
+    deliver_at: Option<DateTime<Utc>>,
+    cancelled_at: Option<DateTime<Utc>>,
+    expires_at: Option<DateTime<Utc>>,
 }
 
 impl EventRaw {
@@ -137,12 +150,16 @@ impl EventRaw {
         Event {
             event_id: self.event__id,
             event_type_name: self.event_type__name.clone(),
+            event_type_version: self.event_type_version,
             payload_content_type: self.payload_content_type.clone(),
             ip: self.ip.ip().to_string(),
             metadata: self.metadata.clone(),
             occurred_at: self.occurred_at,
             received_at: self.received_at,
             labels: self.labels.clone(),
+            deliver_at: self.deliver_at,
+            cancelled_at: self.cancelled_at,
+            expires_at: self.expires_at,
         }
     }
 }
@@ -151,17 +168,31 @@ impl EventRaw {
 pub struct Event {
     event_id: Uuid,
     event_type_name: String,
+    event_type_version: Option<i32>,
     payload_content_type: String,
     ip: String,
     metadata: Option<Value>,
     occurred_at: DateTime<Utc>,
     received_at: DateTime<Utc>,
     labels: Value,
+    /// Date from which the event is delivered, when it was scheduled for a later delivery
+    deliver_at: Option<DateTime<Utc>>,
+    /// Date the scheduled delivery of the event was cancelled
+    cancelled_at: Option<DateTime<Utc>>,
+    /// Date after which the event is no longer delivered
+    expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Apiv2Schema)]
+pub struct ListQs {
+    application_id: Uuid,
+    /// Only list the events scheduled for a later delivery that was not cancelled, soonest first
+    scheduled: Option<bool>,
 }
 
 #[api_v2_operation(
     summary = "List latest events",
-    description = "Retrieves the 100 most recently ingested events for an application. Each event includes its type, payload content type, metadata, labels, and timestamps. Use application_id query parameter to filter by application.",
+    description = "Retrieves the 100 most recently ingested events for an application. Each event includes its type, payload content type, metadata, labels, and timestamps. Use application_id query parameter to filter by application. With scheduled=true, retrieves instead the 100 next events scheduled for a later delivery that was not cancelled, soonest first.",
     operation_id = "events.list",
     consumes = "application/json",
     produces = "application/json",
@@ -171,7 +202,7 @@ pub async fn list(
     state: Data<crate::State>,
     _: OaBiscuit,
     biscuit: ReqData<Biscuit>,
-    qs: Query<Qs>,
+    qs: Query<ListQs>,
 ) -> Result<Json<Vec<Event>>, Hook0Problem> {
     authorize_for_application(
         &state.db,
@@ -184,10 +215,28 @@ pub async fn list(
     )
     .await?;
 
-    let raw_events = query_as!(
+    let raw_events = if qs.scheduled == Some(true) {
+        query_as!(
+            EventRaw,
+            "
+                SELECT event__id, event_type__name, event_type_version, payload_content_type, ip, metadata, occurred_at, received_at, labels, deliver_at, cancelled_at, expires_at
+                FROM event.event
+                WHERE application__id = $1
+                    AND deliver_at > statement_timestamp()
+                    AND cancelled_at IS NULL
+                ORDER BY deliver_at ASC
+                LIMIT 100
+            ",
+            &qs.application_id,
+        )
+        .fetch_all(&state.db)
+        .await
+        .map_err(Hook0Problem::from)?
+    } else {
+        query_as!(
             EventRaw,
             "
-                SELECT event__id, event_type__name, payload_content_type, ip, metadata, occurred_at, received_at, labels
+                SELECT event__id, event_type__name, event_type_version, payload_content_type, ip, metadata, occurred_at, received_at, labels, deliver_at, cancelled_at, expires_at
                 FROM event.event
                 WHERE application__id = $1
                 ORDER BY received_at DESC
@@ -197,7 +246,8 @@ pub async fn list(
         )
         .fetch_all(&state.db)
         .await
-        .map_err(Hook0Problem::from)?;
+        .map_err(Hook0Problem::from)?
+    };
 
     let events = raw_events.iter().map(|re| re.to_event()).collect();
     Ok(Json(events))
@@ -208,6 +258,7 @@ pub async fn list(
 struct EventWithPayloadRaw {
     event__id: Uuid,
     event_type__name: String,
+    event_type_version: Option<i32>,
     payload: Option<Vec<u8>>,
     payload_content_type: String,
     ip: IpNetwork,
@@ -215,6 +266,9 @@ struct EventWithPayloadRaw {
     occurred_at: DateTime<Utc>,
     received_at: DateTime<Utc>,
     labels: Value,
+    deliver_at: Option<DateTime<Utc>>,
+    cancelled_at: Option<DateTime<Utc>>,
+    expires_at: Option<DateTime<Utc>>,
 }
 
 impl EventWithPayloadRaw {
@@ -222,6 +276,7 @@ impl EventWithPayloadRaw {
         EventWithPayload {
             event_id: self.event__id,
             event_type_name: self.event_type__name.clone(),
+            event_type_version: self.event_type_version,
             payload: Base64.encode(payload),
             payload_content_type: self.payload_content_type.clone(),
             ip: self.ip.ip().to_string(),
@@ -229,6 +284,9 @@ impl EventWithPayloadRaw {
             occurred_at: self.occurred_at,
             received_at: self.received_at,
             labels: self.labels.clone(),
+            deliver_at: self.deliver_at,
+            cancelled_at: self.cancelled_at,
+            expires_at: self.expires_at,
         }
     }
 }
@@ -237,6 +295,7 @@ impl EventWithPayloadRaw {
 pub struct EventWithPayload {
     event_id: Uuid,
     event_type_name: String,
+    event_type_version: Option<i32>,
     payload: String,
     payload_content_type: String,
     ip: String,
@@ -244,6 +303,12 @@ pub struct EventWithPayload {
     occurred_at: DateTime<Utc>,
     received_at: DateTime<Utc>,
     labels: Value,
+    /// Date from which the event is delivered, when it was scheduled for a later delivery
+    deliver_at: Option<DateTime<Utc>>,
+    /// Date the scheduled delivery of the event was cancelled
+    cancelled_at: Option<DateTime<Utc>>,
+    /// Date after which the event is no longer delivered
+    expires_at: Option<DateTime<Utc>>,
 }
 
 #[api_v2_operation(
@@ -277,7 +342,7 @@ pub async fn get(
     let raw_event = query_as!(
             EventWithPayloadRaw,
             "
-                SELECT event__id, event_type__name, payload, payload_content_type, ip, metadata, occurred_at, received_at, labels
+                SELECT event__id, event_type__name, event_type_version, payload, payload_content_type, ip, metadata, occurred_at, received_at, labels, deliver_at, cancelled_at, expires_at
                 FROM event.event
                 WHERE application__id = $1 AND event__id = $2
             ",
@@ -348,6 +413,9 @@ pub struct EventPost {
     /// The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters.
     #[validate(non_control_character, length(min = 1, max = 200))]
     event_type: String,
+    /// Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+    #[validate(range(min = 1))]
+    event_type_version: Option<i32>,
     /// The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded).
     #[validate(length(max = 699_050))] // 512 kio of payload * 4/3 (base64) in bytes
     payload: String,
@@ -362,6 +430,10 @@ pub struct EventPost {
     /// Labels for event filtering and routing to subscriptions.
     #[validate(custom(function = "crate::validators::labels"))]
     labels: HashMap<String, String>,
+    /// Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the application.
+    deliver_at: Option<DateTime<Utc>>,
+    /// Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted from its delivery.
+    expires_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Serialize, Apiv2Schema)]
@@ -369,6 +441,9 @@ pub struct IngestedEvent {
     application_id: Uuid,
     event_id: Uuid,
     received_at: DateTime<Utc>,
+    /// Present when the event was ingested with a deprecated version of its event type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecation: Option<EventTypeDeprecation>,
 }
 
 #[api_v2_operation(
@@ -409,15 +484,258 @@ pub async fn ingest(
     if let Err(e) = body.validate() {
         return Err(Hook0Problem::Validation(e));
     }
-    let metadata = match body.metadata.as_ref() {
-        Some(m) => serde_json::to_value(m.clone())
-            .expect("could not serialize subscription metadata into JSON"),
-        None => json!({}),
-    };
-    let labels = serde_json::to_value(body.labels.clone())
-        .expect("could not serialize event labels into JSON");
     phases.push(("validation", phase_started_at.elapsed()));
 
+    events_per_day_allowance(&state, application_id, &mut phases).await?;
+
+    let (payload, deprecation) = check_event(&state, &body, &mut phases).await?;
+
+    let phase_started_at = Instant::now();
+    let mut tx = state.db.begin().await?;
+    phases.push(("db_begin", phase_started_at.elapsed()));
+
+    let (event_id, received_at) = store_event(
+        &state,
+        &mut tx,
+        IpNetwork::from(ip.into_inner()),
+        &body,
+        &payload,
+        &mut phases,
+    )
+    .await?;
+    let event = IngestedEvent {
+        application_id,
+        event_id,
+        received_at,
+        deprecation,
+    };
+
+    let phase_started_at = Instant::now();
+    tx.commit().await?;
+    phases.push(("commit", phase_started_at.elapsed()));
+
+    if let Some(pulsar) = &state.pulsar {
+        let phase_started_at = Instant::now();
+        if enqueue_event(&state, pulsar, &body, event_id, received_at, &payload).await {
+            phases.push(("request_attempts_enqueue", phase_started_at.elapsed()));
+        } else {
+            // Kept apart from the success phase so the cost of a failed enqueue (usually
+            // the producer lock timing out) does not pollute the distribution of healthy
+            // sends — same rationale as `object_storage_put_fallback`.
+            phases.push((
+                "request_attempts_enqueue_failed",
+                phase_started_at.elapsed(),
+            ));
+        }
+    }
+
+    report_ingested_events(1);
+    report_ingestion_duration(started_at.elapsed());
+    report_ingestion_phase_durations(&phases);
+
+    Ok(CreatedJson(event))
+}
+
+/// Events to be ingested into Hook0 in one request.
+#[derive(Debug, Deserialize, Apiv2Schema, Validate)]
+#[validate(schema(function = "events_batch_size"))]
+pub struct EventsBatchPost {
+    /// UUID of the application the events belong to. Events of the batch that carry another application ID are not ingested.
+    application_id: Uuid,
+    /// Events to ingest. Length: 1-100 events; the whole request body is also subject to the usual request size limit.
+    events: Vec<EventPost>,
+}
+
+fn events_batch_size(body: &EventsBatchPost) -> Result<(), ValidationError> {
+    crate::validators::events_batch(&body.events)
+}
+
+/// Whether an event of a batch was ingested
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Apiv2Schema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchedEventStatus {
+    /// The event was ingested and dispatched to the subscriptions it matches
+    Ingested,
+    /// The event was not ingested; the problem says why
+    Failed,
+}
+
+/// Outcome of one event of a batch, at the same position as the event in the batch
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct BatchedEvent {
+    status: BatchedEventStatus,
+    /// ID of the ingested event, or the one the event was submitted with when it was not ingested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_id: Option<Uuid>,
+    /// Present when the event was ingested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    received_at: Option<DateTime<Utc>>,
+    /// Present when the event was ingested with a deprecated version of its event type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecation: Option<EventTypeDeprecation>,
+    /// Present when the event was not ingested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    problem: Option<Problem>,
+}
+
+impl BatchedEvent {
+    fn failed(event: &EventPost, problem: Hook0Problem) -> Self {
+        Self {
+            status: BatchedEventStatus::Failed,
+            event_id: event.event_id,
+            received_at: None,
+            deprecation: None,
+            problem: Some(Problem::from(ProblemDetails::from(problem))),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct IngestedEventsBatch {
+    application_id: Uuid,
+    /// One outcome per submitted event, in the order the events were submitted
+    events: Vec<BatchedEvent>,
+}
+
+#[api_v2_operation(
+    summary = "Ingest a batch of events",
+    description = "Sends up to 100 events of one application to Hook0 in a single request. Authorization and the events-per-day quota are checked once for the whole batch, and the events that pass their own checks are ingested in one transaction. Each event is checked and stored on its own: the response carries one outcome per event, in submission order, with a problem describing why an event was not ingested. Events beyond the remaining daily quota fail with TooManyEventsToday.",
+    operation_id = "events.ingestBatch",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Events Management", "mcp", "sdk")
+)]
+pub async fn ingest_batch(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    ip: UserIp,
+    body: Json<EventsBatchPost>,
+) -> Result<Json<IngestedEventsBatch>, Hook0Problem> {
+    // Phases of every event of the batch are reported, so each phase keeps measuring the work done for one event; the duration of the whole request is not, as a batch would skew the distribution of single ingestions.
+    let mut phases: Vec<(&'static str, Duration)> = Vec::new();
+
+    let application_id = body.application_id;
+
+    let phase_started_at = Instant::now();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::EventIngest {
+            application_id: &application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+    phases.push(("authorization", phase_started_at.elapsed()));
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let allowance = events_per_day_allowance(&state, application_id, &mut phases).await?;
+
+    let ip = IpNetwork::from(ip.into_inner());
+    let mut results = Vec::with_capacity(body.events.len());
+    let mut ingested = Vec::with_capacity(body.events.len());
+
+    let phase_started_at = Instant::now();
+    let mut tx = state.db.begin().await?;
+    phases.push(("db_begin", phase_started_at.elapsed()));
+
+    for event in &body.events {
+        if event.application_id != application_id {
+            results.push(BatchedEvent::failed(event, Hook0Problem::Forbidden));
+            continue;
+        }
+        if let Some((remaining, limit)) = allowance
+            && ingested.len() >= remaining
+        {
+            results.push(BatchedEvent::failed(
+                event,
+                Hook0Problem::TooManyEventsToday(limit),
+            ));
+            continue;
+        }
+
+        let phase_started_at = Instant::now();
+        if let Err(e) = event.validate() {
+            results.push(BatchedEvent::failed(event, Hook0Problem::Validation(e)));
+            continue;
+        }
+        phases.push(("validation", phase_started_at.elapsed()));
+
+        let (payload, deprecation) = match check_event(&state, event, &mut phases).await {
+            Ok(checked) => checked,
+            Err(problem) => {
+                results.push(BatchedEvent::failed(event, problem));
+                continue;
+            }
+        };
+
+        // Each event is stored under its own savepoint, so an event that fails to be stored is rolled back without taking the rest of the batch with it.
+        let mut savepoint = Acquire::begin(&mut tx).await?;
+        match store_event(&state, &mut savepoint, ip, event, &payload, &mut phases).await {
+            Ok((event_id, received_at)) => {
+                savepoint.commit().await?;
+                results.push(BatchedEvent {
+                    status: BatchedEventStatus::Ingested,
+                    event_id: Some(event_id),
+                    received_at: Some(received_at),
+                    deprecation,
+                    problem: None,
+                });
+                ingested.push((event, event_id, received_at, payload));
+            }
+            Err(problem) => {
+                savepoint.rollback().await?;
+                results.push(BatchedEvent::failed(event, problem));
+            }
+        }
+    }
+
+    let phase_started_at = Instant::now();
+    tx.commit().await?;
+    phases.push(("commit", phase_started_at.elapsed()));
+
+    if let Some(pulsar) = &state.pulsar {
+        let phase_started_at = Instant::now();
+        let enqueued = join_all(
+            ingested
+                .iter()
+                .map(|(event, event_id, received_at, payload)| {
+                    enqueue_event(&state, pulsar, event, *event_id, *received_at, payload)
+                }),
+        )
+        .await;
+        if enqueued.iter().all(|enqueued| *enqueued) {
+            phases.push(("request_attempts_enqueue", phase_started_at.elapsed()));
+        } else {
+            phases.push((
+                "request_attempts_enqueue_failed",
+                phase_started_at.elapsed(),
+            ));
+        }
+    }
+
+    report_ingested_events(ingested.len() as u64);
+    report_ingestion_phase_durations(&phases);
+
+    Ok(Json(IngestedEventsBatch {
+        application_id,
+        events: results,
+    }))
+}
+
+/// Checks the events-per-day quota of an application before events are ingested into it, sending the quota notifications that are due.
+///
+/// Returns how many more events the application may ingest today along with its daily limit, or `None` when its organization is allowed to exceed the quota.
+async fn events_per_day_allowance(
+    state: &Data<crate::State>,
+    application_id: Uuid,
+    phases: &mut Vec<(&'static str, Duration)>,
+) -> Result<Option<(usize, QuotaValue)>, Hook0Problem> {
     let phase_started_at = Instant::now();
 
     let can_exceed_events_per_day_quota = query_scalar!(
@@ -489,7 +807,7 @@ pub async fn ingest(
                 state
                     .quotas
                     .send_application_email_notification(
-                        &state,
+                        state,
                         Quota::EventsPerDay,
                         QuotaNotificationType::Warning,
                         application_id,
@@ -501,163 +819,14 @@ pub async fn ingest(
             }
         }
 
-        let phase_started_at = Instant::now();
-        let content_type = PayloadContentType::from_str(&body.payload_content_type)?;
-        let payload = content_type.validate_and_decode(&body.payload)?;
-        phases.push(("payload_decode", phase_started_at.elapsed()));
-
-        let phase_started_at = Instant::now();
-        let mut tx = state.db.begin().await?;
-        phases.push(("db_begin", phase_started_at.elapsed()));
-
-        let phase_started_at = Instant::now();
-        let payload_to_insert = if let Some(true) =
-            state.object_storage.as_ref().map(|object_storage| {
-                object_storage.store_event_payloads
-                    && (object_storage.store_event_only_for.is_empty()
-                        || object_storage
-                            .store_event_only_for
-                            .contains(&application_id))
-            }) {
+        Ok(if can_exceed_events_per_day_quota {
             None
         } else {
-            Some(&payload)
-        };
-        let event = query_as!(
-                IngestedEvent,
-                "
-                    INSERT INTO event.event (application__id, event__id, event_type__name, payload, payload_content_type, ip, metadata, occurred_at, received_at, labels)
-                    VALUES ($1, COALESCE($2, uuidv7()), $3, $4, $5, $6, $7, $8, statement_timestamp(), $9)
-                    RETURNING application__id AS application_id, event__id AS event_id, received_at
-                ",
-                application_id,
-                body.event_id,
-                &body.event_type,
-                payload_to_insert,
-                &body.payload_content_type,
-                IpNetwork::from(ip.into_inner()),
-                metadata,
-                &body.occurred_at,
-                labels,
-            )
-            .fetch_one(&mut *tx)
-            .await
-            .map_err(Hook0Problem::from)?;
-
-        phases.push(("db_insert", phase_started_at.elapsed()));
-
-        if let Some(object_storage) = &state.object_storage
-            && object_storage.store_event_payloads
-            && (object_storage.store_event_only_for.is_empty()
-                || object_storage
-                    .store_event_only_for
-                    .contains(&application_id))
-        {
-            let phase_started_at = Instant::now();
-
-            let key = format!(
-                "{application_id}/event/{}/{}",
-                event.received_at.naive_utc().date(),
-                event.event_id
-            );
-            match object_storage
-                .client
-                .put_object()
-                .bucket(&object_storage.bucket)
-                .key(&key)
-                .content_type(&body.payload_content_type)
-                .body(ByteStream::from(payload.clone()))
-                .send()
-                .await
-            {
-                Ok(_) => {
-                    report_event_payloads_stored_in_object_storage(1);
-                    phases.push(("object_storage_put", phase_started_at.elapsed()));
-                }
-                Err(e) if object_storage.db_fallback_on_write_failure => {
-                    // The row was inserted with a NULL payload (destined for object
-                    // storage). Since the write failed and the DB fallback is enabled,
-                    // backfill the payload into the DB column so the event is still
-                    // deliverable — the output-worker reads the DB payload first.
-                    log_object_storage_error_with_context!(
-                        "S3 PUT OBJECT failed; falling back to storing the payload in the database",
-                        error_chain = DisplayErrorContext(&e).to_string(),
-                        object_key = &key,
-                    );
-                    query!(
-                        "UPDATE event.event SET payload = $1 WHERE application__id = $2 AND event__id = $3",
-                        &payload,
-                        application_id,
-                        event.event_id,
-                    )
-                    .execute(&mut *tx)
-                    .await
-                    .map_err(Hook0Problem::from)?;
-                    report_event_payloads_stored_in_db_fallback(1);
-                    // Kept apart from `object_storage_put` so that the cost of a failed
-                    // PUT (usually a timeout) plus its recovery does not pollute the
-                    // distribution of healthy writes.
-                    phases.push(("object_storage_put_fallback", phase_started_at.elapsed()));
-                }
-                Err(e) => {
-                    // DB fallback disabled: fail the request (and roll back the tx) so
-                    // the payload is never stored in the database.
-                    log_object_storage_error_with_context!(
-                        "S3 PUT OBJECT failed",
-                        error_chain = DisplayErrorContext(&e).to_string(),
-                        object_key = &key,
-                    );
-                    return Err(Hook0Problem::InternalServerError);
-                }
-            }
-        }
-
-        let phase_started_at = Instant::now();
-        tx.commit().await?;
-        phases.push(("commit", phase_started_at.elapsed()));
-
-        if let Some(pulsar) = &state.pulsar {
-            let phase_started_at = Instant::now();
-
-            match send_request_attempts_to_pulsar(
-                &state.db,
-                pulsar,
-                application_id,
-                event.event_id,
-                event.received_at,
-                &body.event_type,
-                &payload,
-                &body.payload_content_type,
-                false,
-            )
-            .await
-            {
-                Ok(()) => {
-                    phases.push(("request_attempts_enqueue", phase_started_at.elapsed()));
-                }
-                Err(e) => {
-                    error!(
-                        application_id = %application_id,
-                        event_id = %event.event_id,
-                        error = ?e,
-                        "Some/all request attempts may not have been enqueued to Pulsar after commit; output-worker will need to reconcile"
-                    );
-                    // Kept apart from the success phase so the cost of a failed enqueue (usually
-                    // the producer lock timing out) does not pollute the distribution of healthy
-                    // sends — same rationale as `object_storage_put_fallback`.
-                    phases.push((
-                        "request_attempts_enqueue_failed",
-                        phase_started_at.elapsed(),
-                    ));
-                }
-            }
-        }
-
-        report_ingested_events(1);
-        report_ingestion_duration(started_at.elapsed());
-        report_ingestion_phase_durations(&phases);
-
-        Ok(CreatedJson(event))
+            Some((
+                usize::try_from(events_per_days_limit - current_events_per_day).unwrap_or(0),
+                events_per_days_limit,
+            ))
+        })
     } else {
         if state.enable_quota_based_email_notifications {
             // Template Mail — same hydration pattern as
@@ -672,7 +841,7 @@ pub async fn ingest(
             state
                 .quotas
                 .send_application_email_notification(
-                    &state,
+                    state,
                     Quota::EventsPerDay,
                     QuotaNotificationType::Reached,
                     application_id,
@@ -684,6 +853,257 @@ pub async fn ingest(
     }
 }
 
+/// Decodes the payload of an event that passed validation and checks it against its event type and its delivery date against the events retention, returning the decoded payload and the deprecation of the declared event type version if any.
+async fn check_event(
+    state: &crate::State,
+    body: &EventPost,
+    phases: &mut Vec<(&'static str, Duration)>,
+) -> Result<(Vec<u8>, Option<EventTypeDeprecation>), Hook0Problem> {
+    let phase_started_at = Instant::now();
+    let content_type = PayloadContentType::from_str(&body.payload_content_type)?;
+    let payload = content_type.validate_and_decode(&body.payload)?;
+    phases.push(("payload_decode", phase_started_at.elapsed()));
+
+    let deprecation = match body.event_type_version {
+        Some(event_type_version) => {
+            let phase_started_at = Instant::now();
+            let deprecation = check_event_type_version(
+                &state.db,
+                &body.application_id,
+                &body.event_type,
+                event_type_version,
+            )
+            .await?;
+            phases.push(("event_type_version", phase_started_at.elapsed()));
+            deprecation
+        }
+        None => None,
+    };
+
+    if content_type == PayloadContentType::Json {
+        let phase_started_at = Instant::now();
+        check_payload_schema(
+            &state.db,
+            &state.payload_schemas,
+            &body.application_id,
+            &body.event_type,
+            body.event_type_version,
+            &payload,
+        )
+        .await?;
+        phases.push(("payload_schema", phase_started_at.elapsed()));
+    }
+
+    // An event must still be there when its delivery date comes, so it cannot be scheduled beyond the events retention of its application
+    if let Some(deliver_at) = body.deliver_at {
+        let phase_started_at = Instant::now();
+        let days_of_events_retention = state
+            .quotas
+            .get_events_retention_for_application(&state.db, &body.application_id)
+            .await?;
+        if TimeDelta::try_days(i64::from(days_of_events_retention))
+            .and_then(|retention| Utc::now().checked_add_signed(retention))
+            .is_some_and(|latest| deliver_at > latest)
+        {
+            return Err(Hook0Problem::EventDeliveryBeyondRetention(
+                days_of_events_retention,
+            ));
+        }
+        phases.push(("delivery_schedule", phase_started_at.elapsed()));
+    }
+
+    Ok((payload, deprecation))
+}
+
+/// Inserts an event, dispatches it to the subscriptions it matches and stores its payload in object storage if configured, all within `tx`; returns the ID and reception date of the event.
+async fn store_event(
+    state: &crate::State,
+    tx: &mut Transaction<'_, Postgres>,
+    ip: IpNetwork,
+    body: &EventPost,
+    payload: &Vec<u8>,
+    phases: &mut Vec<(&'static str, Duration)>,
+) -> Result<(Uuid, DateTime<Utc>), Hook0Problem> {
+    let application_id = body.application_id;
+    let metadata = match body.metadata.as_ref() {
+        Some(m) => serde_json::to_value(m.clone())
+            .expect("could not serialize subscription metadata into JSON"),
+        None => json!({}),
+    };
+    let labels = serde_json::to_value(body.labels.clone())
+        .expect("could not serialize event labels into JSON");
+
+    let phase_started_at = Instant::now();
+    let payload_to_insert = if let Some(true) =
+        state.object_storage.as_ref().map(|object_storage| {
+            object_storage.store_event_payloads
+                && (object_storage.store_event_only_for.is_empty()
+                    || object_storage
+                        .store_event_only_for
+                        .contains(&application_id))
+        }) {
+        None
+    } else {
+        Some(payload)
+    };
+    let inserted = query!(
+            "
+                INSERT INTO event.event (application__id, event__id, event_type__name, event_type_version, payload, payload_content_type, ip, metadata, occurred_at, received_at, labels, deliver_at, expires_at)
+                VALUES ($1, COALESCE($2, uuidv7()), $3, $10, $4, $5, $6, $7, $8, statement_timestamp(), $9, $11, COALESCE($12, (
+                    SELECT GREATEST(statement_timestamp(), $11) + make_interval(secs => et.default_ttl_s)
+                    FROM event.event_type AS et
+                    WHERE et.application__id = $1 AND et.event_type__name = $3
+                )))
+                RETURNING event__id AS event_id, received_at
+            ",
+            application_id,
+            body.event_id,
+            &body.event_type,
+            payload_to_insert,
+            &body.payload_content_type,
+            ip,
+            metadata,
+            &body.occurred_at,
+            labels,
+            body.event_type_version,
+            body.deliver_at,
+            body.expires_at,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(Hook0Problem::from)?;
+    let event_id = inserted.event_id;
+    let received_at = inserted.received_at;
+
+    phases.push(("db_insert", phase_started_at.elapsed()));
+
+    let phase_started_at = Instant::now();
+    let filtered_subscriptions = find_filtered_subscriptions(
+        &mut *tx,
+        application_id,
+        &body.event_type,
+        body.event_type_version,
+        &labels,
+    )
+    .await?;
+    dispatch_to_filtered_subscriptions(
+        &mut *tx,
+        application_id,
+        event_id,
+        &filtered_subscriptions,
+        &body.labels,
+        Some(payload),
+        &body.payload_content_type,
+        body.deliver_at,
+    )
+    .await?;
+    phases.push(("filtered_dispatch", phase_started_at.elapsed()));
+
+    if let Some(object_storage) = &state.object_storage
+        && object_storage.store_event_payloads
+        && (object_storage.store_event_only_for.is_empty()
+            || object_storage
+                .store_event_only_for
+                .contains(&application_id))
+    {
+        let phase_started_at = Instant::now();
+
+        let key = format!(
+            "{application_id}/event/{}/{}",
+            received_at.naive_utc().date(),
+            event_id
+        );
+        match object_storage
+            .client
+            .put_object()
+            .bucket(&object_storage.bucket)
+            .key(&key)
+            .content_type(&body.payload_content_type)
+            .body(ByteStream::from(payload.clone()))
+            .send()
+            .await
+        {
+            Ok(_) => {
+                report_event_payloads_stored_in_object_storage(1);
+                phases.push(("object_storage_put", phase_started_at.elapsed()));
+            }
+            Err(e) if object_storage.db_fallback_on_write_failure => {
+                // The row was inserted with a NULL payload (destined for object
+                // storage). Since the write failed and the DB fallback is enabled,
+                // backfill the payload into the DB column so the event is still
+                // deliverable — the output-worker reads the DB payload first.
+                log_object_storage_error_with_context!(
+                    "S3 PUT OBJECT failed; falling back to storing the payload in the database",
+                    error_chain = DisplayErrorContext(&e).to_string(),
+                    object_key = &key,
+                );
+                query!(
+                    "UPDATE event.event SET payload = $1 WHERE application__id = $2 AND event__id = $3",
+                    payload,
+                    application_id,
+                    event_id,
+                )
+                .execute(&mut **tx)
+                .await
+                .map_err(Hook0Problem::from)?;
+                report_event_payloads_stored_in_db_fallback(1);
+                // Kept apart from `object_storage_put` so that the cost of a failed
+                // PUT (usually a timeout) plus its recovery does not pollute the
+                // distribution of healthy writes.
+                phases.push(("object_storage_put_fallback", phase_started_at.elapsed()));
+            }
+            Err(e) => {
+                // DB fallback disabled: fail the request (and roll back the tx) so
+                // the payload is never stored in the database.
+                log_object_storage_error_with_context!(
+                    "S3 PUT OBJECT failed",
+                    error_chain = DisplayErrorContext(&e).to_string(),
+                    object_key = &key,
+                );
+                return Err(Hook0Problem::InternalServerError);
+            }
+        }
+    }
+
+    Ok((event_id, received_at))
+}
+
+/// Sends the request attempts of a freshly committed event to Pulsar; returns whether they were all enqueued.
+async fn enqueue_event(
+    state: &crate::State,
+    pulsar: &Arc<PulsarConfig>,
+    body: &EventPost,
+    event_id: Uuid,
+    received_at: DateTime<Utc>,
+    payload: &[u8],
+) -> bool {
+    match send_request_attempts_to_pulsar(
+        &state.db,
+        pulsar,
+        body.application_id,
+        event_id,
+        received_at,
+        &body.event_type,
+        payload,
+        &body.payload_content_type,
+        None,
+        false,
+    )
+    .await
+    {
+        Ok(()) => true,
+        Err(e) => {
+            error!(
+                application_id = %body.application_id,
+                event_id = %event_id,
+                error = ?e,
+                "Some/all request attempts may not have been enqueued to Pulsar after commit; output-worker will need to reconcile"
+            );
+            false
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Apiv2Schema)]
 pub struct ReplayEvent {
     application_id: Uuid,
@@ -691,7 +1111,7 @@ pub struct ReplayEvent {
 
 #[api_v2_operation(
     summary = "Replay an event",
-    description = "Re-triggers webhook deliveries for an existing event. All active subscriptions matching the event type and labels will receive the event again. Useful for retrying failed deliveries or testing webhooks.",
+    description = "Re-triggers webhook deliveries for an existing event. All active subscriptions matching the event type and labels will receive the event again. Useful for retrying failed deliveries or testing webhooks. An event scheduled for a later delivery is delivered at its delivery date, even if its delivery was cancelled.",
     operation_id = "events.replay",
     consumes = "application/json",
     tags("Events Management", "mcp", "sdk")
@@ -721,17 +1141,20 @@ pub async fn replay(
     struct ReplayedEvent {
         received_at: DateTime<Utc>,
         event_type: String,
+        event_type_version: Option<i32>,
         payload: Option<Vec<u8>>,
         payload_content_type: String,
+        labels: Value,
+        deliver_at: Option<DateTime<Utc>>,
     }
     let replayed = query_as!(
         ReplayedEvent,
         "
             UPDATE event.event
-            SET dispatched_at = NULL
+            SET dispatched_at = NULL, cancelled_at = NULL
             WHERE event__id = $1
                 AND application__id = $2
-            RETURNING received_at, event_type__name AS event_type, payload, payload_content_type
+            RETURNING received_at, event_type__name AS event_type, event_type_version, payload, payload_content_type, labels, deliver_at
         ",
         event_id,
         body.application_id,
@@ -742,80 +1165,218 @@ pub async fn replay(
 
     match replayed {
         Some(event) => {
-            if let Some(pulsar) = &state.pulsar {
-                let payload = if let Some(p) = event.payload {
-                    Some(p)
-                } else if let Some(os) = &state.object_storage {
-                    let key = format!(
-                        "{}/event/{}/{event_id}",
-                        body.application_id,
-                        event.received_at.naive_utc().date(),
-                    );
-                    match os
-                        .client
-                        .get_object()
-                        .bucket(&os.bucket)
-                        .key(&key)
-                        .send()
-                        .await
-                    {
-                        Ok(obj) => match obj.body.collect().await {
-                            Ok(ab) => Some(ab.to_vec()),
-                            Err(e) => {
-                                log_object_storage_error_with_context!(
-                                    "S3 GET OBJECT body collect failed",
-                                    error_chain = format!("{e}"),
-                                    object_key = &key,
-                                );
-                                None
-                            }
-                        },
-                        Err(e) => {
-                            log_object_storage_error_with_context!(
-                                "S3 GET OBJECT failed",
-                                error_chain = DisplayErrorContext(&e).to_string(),
-                                object_key = &key,
-                            );
-                            None
-                        }
-                    }
-                } else {
-                    None
-                };
-
-                if let Some(p) = payload {
-                    send_request_attempts_to_pulsar(
-                        &mut *tx,
-                        pulsar,
-                        body.application_id,
-                        event_id,
-                        event.received_at,
-                        &event.event_type,
-                        &p,
-                        &event.payload_content_type,
-                        true,
-                    )
-                    .await?;
+            let filtered_subscriptions = find_filtered_subscriptions(
+                &mut tx,
+                body.application_id,
+                &event.event_type,
+                event.event_type_version,
+                &event.labels,
+            )
+            .await?;
+
+            // The payload is only fetched when something needs it, as it may be in object storage
+            let payload = if state.pulsar.is_some() || needs_payload(&filtered_subscriptions) {
+                let payload = load_event_payload(
+                    state.object_storage.as_ref(),
+                    body.application_id,
+                    event_id,
+                    event.received_at,
+                    event.payload,
+                )
+                .await;
 
-                    tx.commit().await?;
-                    report_replayed_events(1);
-                    Ok(NoContent)
-                } else {
+                if payload.is_none() {
                     tx.rollback().await?;
-                    Err(Hook0Problem::InternalServerError)
+                    return Err(Hook0Problem::InternalServerError);
                 }
+                payload
             } else {
-                tx.commit().await?;
-                report_replayed_events(1);
-                Ok(NoContent)
+                None
+            };
+
+            let labels: HashMap<String, String> =
+                serde_json::from_value(event.labels).unwrap_or_else(|_| HashMap::new());
+            dispatch_to_filtered_subscriptions(
+                &mut tx,
+                body.application_id,
+                event_id,
+                &filtered_subscriptions,
+                &labels,
+                payload.as_deref(),
+                &event.payload_content_type,
+                event.deliver_at,
+            )
+            .await?;
+
+            if let (Some(pulsar), Some(p)) = (&state.pulsar, &payload) {
+                send_request_attempts_to_pulsar(
+                    &mut *tx,
+                    pulsar,
+                    body.application_id,
+                    event_id,
+                    event.received_at,
+                    &event.event_type,
+                    p,
+                    &event.payload_content_type,
+                    None,
+                    true,
+                )
+                .await?;
             }
+
+            tx.commit().await?;
+            report_replayed_events(1);
+            Ok(NoContent)
         }
         None => Err(Hook0Problem::NotFound),
     }
 }
 
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct CancelEvent {
+    application_id: Uuid,
+}
+
+#[api_v2_operation(
+    summary = "Cancel a scheduled event",
+    description = "Cancels the delivery of an event scheduled for later, as long as its delivery date has not been reached: its pending request attempts are marked as cancelled and it is no longer listed among scheduled events. Events that were not scheduled, were already cancelled or whose delivery date has passed cannot be cancelled.",
+    operation_id = "events.cancel",
+    consumes = "application/json",
+    tags("Events Management", "mcp", "sdk")
+)]
+pub async fn cancel(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    event_id: Path<Uuid>,
+    body: Json<CancelEvent>,
+) -> Result<NoContent, Hook0Problem> {
+    let event_id = event_id.into_inner();
+
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::EventCancel {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let mut tx = state.db.begin().await?;
+
+    let cancelled = query_scalar!(
+        "
+            UPDATE event.event
+            SET cancelled_at = statement_timestamp()
+            WHERE event__id = $1
+                AND application__id = $2
+                AND deliver_at > statement_timestamp()
+                AND cancelled_at IS NULL
+            RETURNING event__id
+        ",
+        event_id,
+        body.application_id,
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(Hook0Problem::from)?;
+
+    if cancelled.is_none() {
+        let exists = query_scalar!(
+            r#"
+                SELECT EXISTS (
+                    SELECT 1
+                    FROM event.event
+                    WHERE event__id = $1 AND application__id = $2
+                ) AS "exists!"
+            "#,
+            event_id,
+            body.application_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(Hook0Problem::from)?;
+        return Err(if exists {
+            Hook0Problem::EventNotScheduled
+        } else {
+            Hook0Problem::NotFound
+        });
+    }
+
+    let cancelled_request_attempts = query!(
+        "
+            UPDATE webhook.request_attempt
+            SET failed_at = statement_timestamp(), cancelled_at = statement_timestamp()
+            WHERE event__id = $1
+              AND failed_at IS NULL
+              AND succeeded_at IS NULL
+        ",
+        event_id,
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(Hook0Problem::from)?
+    .rows_affected();
+
+    tx.commit().await?;
+    report_cancelled_request_attempts(cancelled_request_attempts);
+
+    Ok(NoContent)
+}
+
+/// Get the payload of an event, from object storage if it is not in the database.
+pub(crate) async fn load_event_payload(
+    object_storage: Option<&ObjectStorageConfig>,
+    application_id: Uuid,
+    event_id: Uuid,
+    event_received_at: DateTime<Utc>,
+    db_payload: Option<Vec<u8>>,
+) -> Option<Vec<u8>> {
+    if let Some(p) = db_payload {
+        Some(p)
+    } else if let Some(os) = object_storage {
+        let key = format!(
+            "{application_id}/event/{}/{event_id}",
+            event_received_at.naive_utc().date(),
+        );
+        match os
+            .client
+            .get_object()
+            .bucket(&os.bucket)
+            .key(&key)
+            .send()
+            .await
+        {
+            Ok(obj) => match obj.body.collect().await {
+                Ok(ab) => Some(ab.to_vec()),
+                Err(e) => {
+                    log_object_storage_error_with_context!(
+                        "S3 GET OBJECT body collect failed",
+                        error_chain = format!("{e}"),
+                        object_key = &key,
+                    );
+                    None
+                }
+            },
+            Err(e) => {
+                log_object_storage_error_with_context!(
+                    "S3 GET OBJECT failed",
+                    error_chain = DisplayErrorContext(&e).to_string(),
+                    object_key = &key,
+                );
+                None
+            }
+        }
+    } else {
+        None
+    }
+}
+
+/// Send the request attempts of an event that are not finished yet to the Pulsar topics of their workers; `only_request_attempt_id` narrows this down to a single request attempt.
 #[allow(clippy::too_many_arguments)]
-async fn send_request_attempts_to_pulsar<'e, E>(
+pub(crate) async fn send_request_attempts_to_pulsar<'e, E>(
     executor: E,
     pulsar: &Arc<PulsarConfig>,
     application_id: Uuid,
@@ -824,6 +1385,7 @@ async fn send_request_attempts_to_pulsar<'e, E>(
     event_type: &str,
     payload: &[u8],
     payload_content_type: &str,
+    only_request_attempt_id: Option<Uuid>,
     wait_for_receipts: bool,
 ) -> Result<(), Hook0Problem>
 where
@@ -838,7 +1400,12 @@ where
         http_method: String,
         http_url: String,
         http_headers: serde_json::Value,
+        message_queue: Option<serde_json::Value>,
         secret: Uuid,
+        payload_transform: Option<serde_json::Value>,
+        signature_scheme: String,
+        response_rules: Option<serde_json::Value>,
+        delay_until: Option<DateTime<Utc>>,
         worker_id: Option<Uuid>,
         worker_queue_type: Option<String>,
     }
@@ -847,30 +1414,39 @@ where
     // goes back to the pool before the Pulsar sends below, which can block on the shared producer lock.
     let request_attempts = query_as!(
         RawRequestAttempt,
-        "
+        r#"
             SELECT
                 ra.request_attempt__id,
                 ra.subscription__id,
                 ra.created_at,
-                t_http.method AS http_method,
-                t_http.url AS http_url,
-                t_http.headers AS http_headers,
+                COALESCE(t_http.method, '') AS "http_method!",
+                COALESCE(t_http.url, '') AS "http_url!",
+                COALESCE(t_http.headers, '{}'::jsonb) AS "http_headers!",
+                t_mq.target AS message_queue,
                 s.secret,
+                s.payload_transform,
+                s.signature_scheme,
+                s.response_rules,
+                ra.delay_until,
                 COALESCE(sw.worker__id, ow.worker__id) AS worker_id,
                 COALESCE(w1.queue_type, w2.queue_type) AS worker_queue_type
             FROM webhook.request_attempt AS ra
             INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
-            INNER JOIN webhook.target_http AS t_http ON t_http.target__id = s.target__id
+            LEFT JOIN webhook.target_http AS t_http ON t_http.target__id = s.target__id
+            LEFT JOIN webhook.target_message_queue AS t_mq ON t_mq.target__id = s.target__id
             LEFT JOIN webhook.subscription__worker AS sw ON sw.subscription__id = ra.subscription__id
             LEFT JOIN infrastructure.worker AS w1 ON w1.worker__id = sw.worker__id
             INNER JOIN event.application AS a ON a.application__id = s.application__id
             LEFT JOIN iam.organization__worker AS ow ON ow.organization__id = a.organization__id AND ow.default = true
             LEFT JOIN infrastructure.worker AS w2 ON w2.worker__id = ow.worker__id
             WHERE ra.event__id = $1
+                AND (ra.request_attempt__id = $2 OR $2 IS NULL)
                 AND ra.succeeded_at IS NULL AND ra.failed_at IS NULL
                 AND a.deleted_at IS NULL
-        ",
+                AND (t_http.target__id IS NOT NULL OR t_mq.target__id IS NOT NULL)
+        "#,
         &event_id,
+        only_request_attempt_id,
     )
     .fetch_all(executor)
     .await?;
@@ -896,7 +1472,21 @@ where
                 payload: payload.to_owned(),
                 payload_content_type: payload_content_type.to_owned(),
                 secret: ra.secret,
+                payload_transform: ra.payload_transform,
+                message_queue: ra.message_queue,
+                signature_scheme: ra.signature_scheme,
+                response_rules: ra.response_rules,
             };
+            let mut message = RequestAttempt::serialize_message(request_attempt).map_err(|e| {
+                error!("Could not serialize a request attempt for Pulsar: {e}");
+                Hook0Problem::InternalServerError
+            })?;
+            // Request attempts of events scheduled for a later delivery are held back by Pulsar until then
+            if let Some(delay_until) = ra.delay_until
+                && delay_until > Utc::now()
+            {
+                message.deliver_at_time = Some(delay_until.timestamp_millis());
+            }
 
             let send_future = timeout(
                 Duration::from_secs(3),
@@ -912,7 +1502,7 @@ where
                     "persistent://{}/{}/{}.request_attempt",
                     pulsar.tenant, pulsar.namespace, worker_id,
                 ),
-                request_attempt,
+                message,
             )
             .await
             .map_err(|e| {
@@ -960,8 +1550,18 @@ where
 mod tests {
     use super::*;
 
+    use actix_web::http::StatusCode;
+    use actix_web::test::{TestRequest, call_service, read_body_json};
+    use actix_web::{HttpMessage, web};
+    use sqlx::PgPool;
+    use std::net::{IpAddr, Ipv4Addr};
     use strum::VariantNames;
 
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_subscription,
+        seed_user,
+    };
+
     #[test]
     fn payload_content_type_schema_contract() {
         let schema = PayloadContentType::raw_schema();
@@ -1053,4 +1653,235 @@ mod tests {
             Err(Hook0Problem::EventInvalidBase64Payload(_))
         ));
     }
+
+    /// A batch is authorized once and answers with one outcome per event: an event that fails its
+    /// own checks, or fails to be stored, does not prevent the others from being ingested.
+    #[sqlx::test]
+    async fn ingest_batch_reports_an_outcome_per_event(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, seeded_event_id) = seed_event(&pool, org).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1/events")
+                .wrap(biscuit_auth)
+                .route("/batch", web::post().to(super::ingest_batch)),
+        ));
+        let post = |events: Vec<Value>| {
+            let req = TestRequest::post()
+                .uri("/api/v1/events/batch")
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .set_json(json!({ "application_id": application_id, "events": events }))
+                .to_request();
+            req.extensions_mut().insert(IpAddr::V4(Ipv4Addr::LOCALHOST));
+            req
+        };
+        let event = |event_id: Option<Uuid>, app: Uuid, payload: &str| {
+            json!({
+                "application_id": app,
+                "event_id": event_id,
+                "event_type": "test.resource.created",
+                "payload": payload,
+                "payload_content_type": "application/json",
+                "occurred_at": Utc::now(),
+                "labels": { "env": "prod" },
+            })
+        };
+
+        let resp = call_service(&app, post(Vec::new())).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let first_id = Uuid::now_v7();
+        let resp = call_service(
+            &app,
+            post(vec![
+                event(Some(first_id), application_id, r#"{"n":1}"#),
+                event(None, application_id, r#"{"n":"#),
+                event(None, Uuid::new_v4(), r#"{"n":3}"#),
+                event(Some(first_id), application_id, r#"{"n":4}"#),
+                event(None, application_id, r#"{"n":5}"#),
+            ]),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = read_body_json(resp).await;
+        let outcomes = body["events"].as_array().unwrap();
+        let summary = outcomes
+            .iter()
+            .map(|outcome| {
+                (
+                    outcome["status"].as_str().unwrap(),
+                    outcome["problem"]["id"].as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("ingested", None),
+                ("failed", Some("EventInvalidJsonPayload")),
+                ("failed", Some("Forbidden")),
+                ("failed", Some("EventAlreadyIngested")),
+                ("ingested", None),
+            ]
+        );
+        assert_eq!(outcomes[0]["event_id"], json!(first_id));
+
+        let ingested: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM event.event WHERE application__id = $1 AND event__id <> $2",
+        )
+        .bind(application_id)
+        .bind(seeded_event_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(ingested, 2, "only the events that passed are stored");
+    }
+
+    /// A scheduled event waits until its delivery date, can be found among scheduled events and can
+    /// be cancelled until it is delivered; an event cannot be scheduled beyond the events retention.
+    #[sqlx::test]
+    async fn scheduled_events_are_listed_and_can_be_cancelled_before_delivery(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, _) = seed_event(&pool, org).await;
+        sqlx::query(
+            "UPDATE event.application SET days_of_events_retention_limit = 7 WHERE application__id = $1",
+        )
+        .bind(application_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        let subscription_id =
+            seed_subscription(&pool, application_id, json!({ "env": "prod" })).await;
+        sqlx::query(
+            "INSERT INTO webhook.subscription__event_type (application__id, subscription__id, event_type__name) VALUES ($1, $2, 'test.resource.created')",
+        )
+        .bind(application_id)
+        .bind(subscription_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1")
+                .wrap(biscuit_auth)
+                .route("/event", web::post().to(super::ingest))
+                .route("/events", web::get().to(super::list))
+                .route("/events/{event_id}/cancel", web::post().to(super::cancel)),
+        ));
+        let ingest = |deliver_at: Option<DateTime<Utc>>| {
+            let req = TestRequest::post()
+                .uri("/api/v1/event")
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .set_json(json!({
+                    "application_id": application_id,
+                    "event_type": "test.resource.created",
+                    "payload": "{}",
+                    "payload_content_type": "application/json",
+                    "occurred_at": Utc::now(),
+                    "labels": { "env": "prod" },
+                    "deliver_at": deliver_at,
+                }))
+                .to_request();
+            req.extensions_mut().insert(IpAddr::V4(Ipv4Addr::LOCALHOST));
+            req
+        };
+        let cancel = |event_id: Uuid| {
+            TestRequest::post()
+                .uri(&format!("/api/v1/events/{event_id}/cancel"))
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .set_json(json!({ "application_id": application_id }))
+                .to_request()
+        };
+        let scheduled_events = || {
+            TestRequest::get()
+                .uri(&format!(
+                    "/api/v1/events?application_id={application_id}&scheduled=true"
+                ))
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .to_request()
+        };
+        let problem_id = |body: Value| body["id"].as_str().map(str::to_owned);
+
+        let resp = call_service(&app, ingest(Some(Utc::now() + TimeDelta::days(30)))).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            problem_id(read_body_json(resp).await).as_deref(),
+            Some("EventDeliveryBeyondRetention")
+        );
+
+        let resp = call_service(&app, ingest(Some(Utc::now() + TimeDelta::hours(1)))).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let scheduled: Value = read_body_json(resp).await;
+        let scheduled_id: Uuid = serde_json::from_value(scheduled["event_id"].clone()).unwrap();
+        let resp = call_service(&app, ingest(None)).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let immediate: Value = read_body_json(resp).await;
+        let immediate_id: Uuid = serde_json::from_value(immediate["event_id"].clone()).unwrap();
+
+        let delays: Vec<(Uuid, bool)> = sqlx::query_as(
+            r#"
+                SELECT ra.event__id, ra.delay_until IS NOT DISTINCT FROM e.deliver_at AND ra.delay_until IS NOT NULL
+                FROM webhook.request_attempt AS ra
+                INNER JOIN event.event AS e ON e.event__id = ra.event__id
+                WHERE ra.application__id = $1
+                ORDER BY ra.event__id = $2 DESC
+            "#,
+        )
+        .bind(application_id)
+        .bind(scheduled_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            delays,
+            vec![(scheduled_id, true), (immediate_id, false)],
+            "only the request attempt of the scheduled event waits, until its delivery date"
+        );
+
+        let resp = call_service(&app, scheduled_events()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let listed: Value = read_body_json(resp).await;
+        assert_eq!(listed.as_array().unwrap().len(), 1);
+        assert_eq!(listed[0]["event_id"], json!(scheduled_id));
+
+        let resp = call_service(&app, cancel(immediate_id)).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            problem_id(read_body_json(resp).await).as_deref(),
+            Some("EventNotScheduled")
+        );
+        let resp = call_service(&app, cancel(Uuid::new_v4())).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let resp = call_service(&app, cancel(scheduled_id)).await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let cancelled: bool = sqlx::query_scalar(
+            "SELECT cancelled_at IS NOT NULL AND failed_at IS NOT NULL FROM webhook.request_attempt WHERE event__id = $1",
+        )
+        .bind(scheduled_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert!(
+            cancelled,
+            "the pending request attempt of a cancelled event is failed and marked as cancelled"
+        );
+
+        let listed: Value = read_body_json(call_service(&app, scheduled_events()).await).await;
+        assert_eq!(listed, json!([]));
+        let resp = call_service(&app, cancel(scheduled_id)).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
 }
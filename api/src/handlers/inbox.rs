@@ -0,0 +1,816 @@
+use actix_web::web::ReqData;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as Base64;
+use biscuit_auth::Biscuit;
+use chrono::{DateTime, Utc};
+use paperclip::actix::web::{Data, Json, Path, Query};
+use paperclip::actix::{Apiv2Schema, api_v2_operation};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{PgConnection, query, query_as, query_scalar};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::handlers::events::load_event_payload;
+use crate::handlers::subscriptions::RetryPolicy;
+use crate::iam::{Action, authorize_for_application};
+use crate::openapi::OaBiscuit;
+use crate::pagination::{Cursor, EncodedAscCursor};
+use crate::problems::Hook0Problem;
+
+const DEFAULT_LIMIT: i64 = 10;
+const DEFAULT_VISIBILITY_TIMEOUT_S: i32 = 30;
+
+/// Events leased from the inbox of a subscription
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct Inbox {
+    pub messages: Vec<InboxMessage>,
+    /// Pass it as `cursor` to the next pull to continue after the last message of this one
+    pub next_cursor: Option<String>,
+}
+
+/// An event leased from an inbox, to acknowledge or reject with its receipt before the lease expires
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct InboxMessage {
+    pub request_attempt_id: Uuid,
+    /// Identifies this lease of the request attempt; a later lease of the same attempt gets another one
+    pub receipt: Uuid,
+    /// When the event is offered again if it was neither acknowledged nor rejected
+    pub leased_until: DateTime<Utc>,
+    pub retry_count: i16,
+    pub event_id: Uuid,
+    pub event_type_name: String,
+    /// Base64-encoded
+    pub payload: String,
+    pub payload_content_type: String,
+    pub labels: Value,
+    pub occurred_at: DateTime<Utc>,
+    pub received_at: DateTime<Utc>,
+}
+
+/// Outcome of acknowledging or rejecting leased events
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct InboxSettlement {
+    /// Receipts of the events that were settled
+    pub settled: Vec<Uuid>,
+    /// Receipts that settled nothing: their lease expired, or the event was already settled
+    pub stale: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema, Validate)]
+pub struct PullQs {
+    application_id: Uuid,
+    /// Maximum number of events to lease (10 by default)
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<i64>,
+    /// For how long in seconds the leased events are not offered again (30 by default)
+    #[validate(range(min = 1, max = 43200))]
+    visibility_timeout_s: Option<i32>,
+    /// Only lease events after this cursor, returned by a previous pull
+    cursor: Option<EncodedAscCursor>,
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema, Validate)]
+pub struct InboxAck {
+    application_id: Uuid,
+    /// Receipts of the leased events
+    #[validate(length(min = 1, max = 100))]
+    receipts: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema, Validate)]
+pub struct InboxNack {
+    application_id: Uuid,
+    /// Receipts of the leased events
+    #[validate(length(min = 1, max = 100))]
+    receipts: Vec<Uuid>,
+    /// Whether the events are offered again, as new request attempts (true by default); they are not beyond the maximum number of retries of the subscription, or of this instance if it has none
+    retry: Option<bool>,
+    /// Delay in seconds before the events are offered again (0 by default)
+    #[validate(range(min = 0))]
+    retry_in_s: Option<i32>,
+}
+
+struct InboxSubscription {
+    retry_policy: Option<Value>,
+}
+
+/// The subscription behind an inbox, checking that it is one that can be drained.
+async fn inbox_subscription(
+    db: &mut PgConnection,
+    application_id: &Uuid,
+    subscription_id: &Uuid,
+) -> Result<InboxSubscription, Hook0Problem> {
+    let subscription = query!(
+        r#"
+            SELECT
+                s.is_enabled,
+                s.retry_policy,
+                EXISTS(SELECT 1 FROM webhook.target_inbox AS t WHERE t.target__id = s.target__id) AS "is_inbox!"
+            FROM webhook.subscription AS s
+            WHERE s.application__id = $1
+                AND s.subscription__id = $2
+                AND s.deleted_at IS NULL
+        "#,
+        application_id,
+        subscription_id,
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or(Hook0Problem::NotFound)?;
+
+    if !subscription.is_inbox {
+        Err(Hook0Problem::SubscriptionNotInbox)
+    } else if !subscription.is_enabled {
+        Err(Hook0Problem::SubscriptionDisabled)
+    } else {
+        Ok(InboxSubscription {
+            retry_policy: subscription.retry_policy,
+        })
+    }
+}
+
+/// Splits the receipts that were sent into the ones that settled something and the others.
+fn settlement(receipts: &[Uuid], settled: Vec<Uuid>) -> InboxSettlement {
+    let stale = receipts
+        .iter()
+        .filter(|receipt| !settled.contains(receipt))
+        .copied()
+        .collect();
+    InboxSettlement { settled, stale }
+}
+
+#[api_v2_operation(
+    summary = "Pull events from an inbox",
+    description = "Leases the next events waiting in the inbox of a subscription whose target is an inbox, oldest first. A leased event is not offered again until its lease expires, unless it is rejected; acknowledge it before then with its receipt. Events with an ordering key are only offered once the previous ones with the same key are settled. Pulls can follow each other with the cursor they return; an event whose lease expired is offered again to pulls that start before it.",
+    operation_id = "inbox.pull",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn pull(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<PullQs>,
+) -> Result<Json<Inbox>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::InboxPull {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = qs.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let mut tx = state.db.begin().await?;
+    inbox_subscription(&mut tx, &qs.application_id, &subscription_id).await?;
+
+    let cursor = qs.cursor.unwrap_or_default().0;
+    #[allow(non_snake_case)]
+    struct RawInboxMessage {
+        request_attempt__id: Uuid,
+        created_at: DateTime<Utc>,
+        receipt: Uuid,
+        leased_until: DateTime<Utc>,
+        retry_count: i16,
+        event__id: Uuid,
+        event_type__name: String,
+        payload: Option<Vec<u8>>,
+        payload_content_type: String,
+        labels: Value,
+        occurred_at: DateTime<Utc>,
+        received_at: DateTime<Utc>,
+    }
+    // Leased request attempts are picked like workers pick them, so that they show as in progress until they are settled
+    let leased = query_as!(
+        RawInboxMessage,
+        r#"
+            WITH offered AS (
+                SELECT ra.request_attempt__id
+                FROM webhook.request_attempt AS ra
+                INNER JOIN event.event AS e ON e.event__id = ra.event__id
+                LEFT JOIN webhook.inbox_lease AS l ON l.request_attempt__id = ra.request_attempt__id
+                WHERE ra.subscription__id = $1
+                    AND ra.succeeded_at IS NULL
+                    AND ra.failed_at IS NULL
+                    AND (ra.delay_until IS NULL OR ra.delay_until <= statement_timestamp())
+                    AND (e.expires_at IS NULL OR e.expires_at > statement_timestamp())
+                    AND COALESCE(l.leased_until <= statement_timestamp(), ra.picked_at IS NULL)
+                    AND (ra.created_at, ra.request_attempt__id) > ($2::timestamptz, $3::uuid)
+                    AND (
+                        ra.ordering_key IS NULL
+                        OR NOT EXISTS (
+                            SELECT 1
+                            FROM webhook.request_attempt AS prev
+                            INNER JOIN event.event AS prev_e ON prev_e.event__id = prev.event__id
+                            WHERE prev.subscription__id = ra.subscription__id
+                                AND prev.ordering_key = ra.ordering_key
+                                AND prev.succeeded_at IS NULL
+                                AND prev.failed_at IS NULL
+                                AND (prev_e.received_at, prev_e.event__id) < (e.received_at, e.event__id)
+                        )
+                    )
+                ORDER BY ra.created_at ASC, ra.request_attempt__id ASC
+                LIMIT $4
+                FOR UPDATE OF ra SKIP LOCKED
+            ), picked AS (
+                UPDATE webhook.request_attempt AS ra
+                SET picked_at = statement_timestamp(), worker_name = NULL, worker_version = NULL
+                FROM offered
+                WHERE ra.request_attempt__id = offered.request_attempt__id
+                RETURNING ra.request_attempt__id, ra.created_at, ra.retry_count, ra.event__id
+            ), leases AS (
+                INSERT INTO webhook.inbox_lease (request_attempt__id, receipt, leased_until)
+                SELECT request_attempt__id, public.gen_random_uuid(), statement_timestamp() + make_interval(secs => $5)
+                FROM offered
+                ON CONFLICT (request_attempt__id) DO UPDATE
+                SET receipt = EXCLUDED.receipt,
+                    leased_until = EXCLUDED.leased_until
+                RETURNING request_attempt__id, receipt, leased_until
+            )
+            SELECT
+                picked.request_attempt__id AS "request_attempt__id!",
+                picked.created_at AS "created_at!",
+                leases.receipt AS "receipt!",
+                leases.leased_until AS "leased_until!",
+                picked.retry_count AS "retry_count!",
+                e.event__id,
+                e.event_type__name,
+                e.payload,
+                e.payload_content_type,
+                e.labels,
+                e.occurred_at,
+                e.received_at
+            FROM picked
+            INNER JOIN leases ON leases.request_attempt__id = picked.request_attempt__id
+            INNER JOIN event.event AS e ON e.event__id = picked.event__id
+            ORDER BY picked.created_at ASC, picked.request_attempt__id ASC
+        "#,
+        &subscription_id,
+        cursor.date,
+        cursor.id,
+        qs.limit.unwrap_or(DEFAULT_LIMIT),
+        f64::from(
+            qs.visibility_timeout_s
+                .unwrap_or(DEFAULT_VISIBILITY_TIMEOUT_S)
+        ),
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let next_cursor = leased
+        .last()
+        .map(|m| Cursor {
+            date: m.created_at,
+            id: m.request_attempt__id,
+        })
+        .or(qs.cursor.map(|c| c.0))
+        .and_then(Cursor::to_qs_value);
+
+    let mut messages = Vec::with_capacity(leased.len());
+    for m in leased {
+        let payload = match load_event_payload(
+            state.object_storage.as_ref(),
+            qs.application_id,
+            m.event__id,
+            m.received_at,
+            m.payload,
+        )
+        .await
+        {
+            Some(payload) => payload,
+            // The leases are rolled back, so the events are offered again by the next pull
+            None if state.object_storage.is_some() => {
+                return Err(Hook0Problem::InternalServerError);
+            }
+            None => Vec::new(),
+        };
+        messages.push(InboxMessage {
+            request_attempt_id: m.request_attempt__id,
+            receipt: m.receipt,
+            leased_until: m.leased_until,
+            retry_count: m.retry_count,
+            event_id: m.event__id,
+            event_type_name: m.event_type__name,
+            payload: Base64.encode(payload),
+            payload_content_type: m.payload_content_type,
+            labels: m.labels,
+            occurred_at: m.occurred_at,
+            received_at: m.received_at,
+        });
+    }
+
+    tx.commit().await?;
+    Ok(Json(Inbox {
+        messages,
+        next_cursor,
+    }))
+}
+
+#[api_v2_operation(
+    summary = "Acknowledge events pulled from an inbox",
+    description = "Marks the events leased with these receipts as successfully delivered. A receipt whose lease expired settles nothing, since the event may have been leased again; it is returned as stale.",
+    operation_id = "inbox.ack",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn ack(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    body: Json<InboxAck>,
+) -> Result<Json<InboxSettlement>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::InboxAck {
+            application_id: &body.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let mut tx = state.db.begin().await?;
+    inbox_subscription(&mut tx, &body.application_id, &subscription_id).await?;
+
+    let settled = query_scalar!(
+        r#"
+            WITH settled AS (
+                UPDATE webhook.request_attempt AS ra
+                SET succeeded_at = statement_timestamp()
+                FROM webhook.inbox_lease AS l
+                WHERE l.request_attempt__id = ra.request_attempt__id
+                    AND l.receipt = ANY($2)
+                    AND l.leased_until > statement_timestamp()
+                    AND ra.subscription__id = $1
+                    AND ra.succeeded_at IS NULL
+                    AND ra.failed_at IS NULL
+                RETURNING ra.request_attempt__id, l.receipt
+            ), released AS (
+                DELETE FROM webhook.inbox_lease
+                WHERE request_attempt__id IN (SELECT request_attempt__id FROM settled)
+            )
+            SELECT receipt AS "receipt!"
+            FROM settled
+        "#,
+        &subscription_id,
+        &body.receipts,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(Json(settlement(&body.receipts, settled)))
+}
+
+#[api_v2_operation(
+    summary = "Reject events pulled from an inbox",
+    description = "Marks the events leased with these receipts as failed. Unless asked otherwise, each is offered again as a new request attempt, after an optional delay, as long as the subscription's maximum number of retries is not reached; otherwise it becomes a dead letter. A receipt whose lease expired settles nothing; it is returned as stale.",
+    operation_id = "inbox.nack",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn nack(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    body: Json<InboxNack>,
+) -> Result<Json<InboxSettlement>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::InboxNack {
+            application_id: &body.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+    let retry_in_s = body.retry_in_s.unwrap_or(0);
+    let max_retry_window_s =
+        i64::try_from(state.max_subscription_retry_window.as_secs()).unwrap_or(i64::MAX);
+    if i64::from(retry_in_s) > max_retry_window_s {
+        return Err(Hook0Problem::RetryPolicyOverLimit(format!(
+            "retry_in_s cannot exceed {max_retry_window_s} on this instance"
+        )));
+    }
+
+    let mut tx = state.db.begin().await?;
+    let subscription = inbox_subscription(&mut tx, &body.application_id, &subscription_id).await?;
+    let max_retries = subscription
+        .retry_policy
+        .and_then(|p| serde_json::from_value::<RetryPolicy>(p).ok())
+        .and_then(|p| p.max_retries)
+        .map(|n| i16::try_from(n).unwrap_or(i16::MAX))
+        .unwrap_or(i16::from(state.max_subscription_retries));
+    let retry = body.retry.unwrap_or(true);
+
+    let settled = query_scalar!(
+        r#"
+            WITH settled AS (
+                UPDATE webhook.request_attempt AS ra
+                SET failed_at = statement_timestamp()
+                FROM webhook.inbox_lease AS l
+                WHERE l.request_attempt__id = ra.request_attempt__id
+                    AND l.receipt = ANY($2)
+                    AND l.leased_until > statement_timestamp()
+                    AND ra.subscription__id = $1
+                    AND ra.succeeded_at IS NULL
+                    AND ra.failed_at IS NULL
+                RETURNING ra.request_attempt__id, ra.application__id, ra.event__id, ra.subscription__id, ra.retry_count, ra.ordering_key, l.receipt
+            ), released AS (
+                DELETE FROM webhook.inbox_lease
+                WHERE request_attempt__id IN (SELECT request_attempt__id FROM settled)
+            ), retried AS (
+                INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, retry_count, delay_until, ordering_key)
+                SELECT
+                    application__id,
+                    event__id,
+                    subscription__id,
+                    retry_count + 1,
+                    CASE WHEN $3::integer > 0 THEN statement_timestamp() + make_interval(secs => $3::integer) END,
+                    ordering_key
+                FROM settled
+                WHERE $4 AND retry_count < $5
+            ), dead_lettered AS (
+                INSERT INTO webhook.dead_letter (application__id, subscription__id, event__id, request_attempt__id, reason)
+                SELECT
+                    application__id,
+                    subscription__id,
+                    event__id,
+                    request_attempt__id,
+                    CASE WHEN $4 THEN 'retries_exhausted' ELSE 'permanent_failure' END
+                FROM settled
+                WHERE NOT ($4 AND retry_count < $5)
+                ON CONFLICT (request_attempt__id) DO NOTHING
+            )
+            SELECT receipt AS "receipt!"
+            FROM settled
+        "#,
+        &subscription_id,
+        &body.receipts,
+        retry_in_s,
+        retry,
+        max_retries,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(Json(settlement(&body.receipts, settled)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use serde_json::{Value, json};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    /// Events are leased one pull at a time, settled with their receipt, and offered again when
+    /// rejected or when their lease expires.
+    #[sqlx::test]
+    async fn inboxes_lease_and_settle_request_attempts(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, event_id) = seed_event(&pool, org).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1")
+                .wrap(biscuit_auth)
+                .route(
+                    "/subscriptions",
+                    web::post().to(crate::handlers::subscriptions::create),
+                )
+                .route("/inbox/{subscription_id}", web::get().to(super::pull))
+                .route("/inbox/{subscription_id}/ack", web::post().to(super::ack))
+                .route("/inbox/{subscription_id}/nack", web::post().to(super::nack)),
+        ));
+        let auth = ("Authorization", format!("Bearer {user_token}"));
+
+        let mut subscription_ids = Vec::new();
+        for target in [
+            json!({ "type": "inbox" }),
+            json!({
+                "type": "sqs",
+                "queue_url": "http://localhost:9324/000000000000/events",
+                "region": "elasticmq",
+                "access_key_id": "x",
+                "secret_access_key": "x",
+            }),
+        ] {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/subscriptions")
+                .insert_header(auth.clone())
+                .set_json(json!({
+                    "application_id": application_id,
+                    "is_enabled": true,
+                    "event_types": ["test.resource.created"],
+                    "labels": { "e2e": "1" },
+                    "target": target,
+                }))
+                .to_request();
+            let created: Value = test::call_and_read_body_json(&app, req).await;
+            assert_eq!(created["target"]["type"], target["type"]);
+            subscription_ids.push(created["subscription_id"].as_str().unwrap().to_owned());
+        }
+        let [inbox, queue] = [&subscription_ids[0], &subscription_ids[1]];
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/api/v1/inbox/{queue}?application_id={application_id}"
+            ))
+            .insert_header(auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let mut attempts = Vec::new();
+        for _ in 0..2 {
+            let id: Uuid = sqlx::query_scalar(
+                "INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id) VALUES ($1, $2, $3::text::uuid) RETURNING request_attempt__id",
+            )
+            .bind(application_id)
+            .bind(event_id)
+            .bind(inbox)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            attempts.push(id.to_string());
+        }
+
+        let pull = |query: String| {
+            test::TestRequest::get()
+                .uri(&format!(
+                    "/api/v1/inbox/{inbox}?application_id={application_id}{query}"
+                ))
+                .insert_header(auth.clone())
+                .to_request()
+        };
+        let settle = |verb: &str, body: Value| {
+            let mut body = body;
+            body["application_id"] = json!(application_id);
+            test::TestRequest::post()
+                .uri(&format!("/api/v1/inbox/{inbox}/{verb}"))
+                .insert_header(auth.clone())
+                .set_json(body)
+                .to_request()
+        };
+
+        let first: Value = test::call_and_read_body_json(&app, pull("&limit=1".to_owned())).await;
+        assert_eq!(first["messages"][0]["request_attempt_id"], attempts[0]);
+        assert_eq!(first["messages"][0]["event_id"], event_id.to_string());
+        let cursor = first["next_cursor"].as_str().unwrap();
+        let second: Value =
+            test::call_and_read_body_json(&app, pull(format!("&cursor={cursor}"))).await;
+        assert_eq!(second["messages"].as_array().unwrap().len(), 1);
+        assert_eq!(second["messages"][0]["request_attempt_id"], attempts[1]);
+        let none: Value = test::call_and_read_body_json(&app, pull(String::new())).await;
+        assert_eq!(none["messages"], json!([]), "leased events are not offered");
+
+        let acked = first["messages"][0]["receipt"].clone();
+        let settled: Value =
+            test::call_and_read_body_json(&app, settle("ack", json!({ "receipts": [acked] })))
+                .await;
+        assert_eq!(settled, json!({ "settled": [acked], "stale": [] }));
+        let again: Value =
+            test::call_and_read_body_json(&app, settle("ack", json!({ "receipts": [acked] })))
+                .await;
+        assert_eq!(again, json!({ "settled": [], "stale": [acked] }));
+
+        let nacked = second["messages"][0]["receipt"].clone();
+        let settled: Value =
+            test::call_and_read_body_json(&app, settle("nack", json!({ "receipts": [nacked] })))
+                .await;
+        assert_eq!(settled["settled"], json!([nacked]));
+        let retried: Value = test::call_and_read_body_json(&app, pull(String::new())).await;
+        assert_eq!(retried["messages"][0]["retry_count"], 1);
+
+        sqlx::query("UPDATE webhook.inbox_lease SET leased_until = statement_timestamp() - INTERVAL '1 second'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let released: Value = test::call_and_read_body_json(&app, pull(String::new())).await;
+        assert_eq!(
+            released["messages"][0]["request_attempt_id"],
+            retried["messages"][0]["request_attempt_id"]
+        );
+        let expired = retried["messages"][0]["receipt"].clone();
+        assert_ne!(released["messages"][0]["receipt"], expired);
+        let stale: Value =
+            test::call_and_read_body_json(&app, settle("ack", json!({ "receipts": [expired] })))
+                .await;
+        assert_eq!(stale["stale"], json!([expired]));
+
+        let outcomes: Vec<(bool, bool)> = sqlx::query_as(
+            "SELECT succeeded_at IS NOT NULL, failed_at IS NOT NULL FROM webhook.request_attempt WHERE request_attempt__id = ANY($1::text[]::uuid[]) ORDER BY created_at",
+        )
+        .bind(&attempts)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(outcomes, vec![(true, false), (false, true)]);
+        let dead_letters: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM webhook.dead_letter")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(dead_letters, 0, "a retried event is not a dead letter");
+    }
+
+    /// Without a retry policy, a rejected event is not offered again once the instance's maximum
+    /// number of retries is reached; it becomes a dead letter instead.
+    #[sqlx::test]
+    async fn rejected_events_are_not_retried_beyond_the_maximum(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, event_id) = seed_event(&pool, org).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1")
+                .wrap(biscuit_auth)
+                .route(
+                    "/subscriptions",
+                    web::post().to(crate::handlers::subscriptions::create),
+                )
+                .route("/inbox/{subscription_id}", web::get().to(super::pull))
+                .route("/inbox/{subscription_id}/nack", web::post().to(super::nack)),
+        ));
+        let auth = ("Authorization", format!("Bearer {user_token}"));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/subscriptions")
+            .insert_header(auth.clone())
+            .set_json(json!({
+                "application_id": application_id,
+                "is_enabled": true,
+                "event_types": ["test.resource.created"],
+                "labels": { "e2e": "1" },
+                "target": { "type": "inbox" },
+            }))
+            .to_request();
+        let created: Value = test::call_and_read_body_json(&app, req).await;
+        let inbox = created["subscription_id"].as_str().unwrap().to_owned();
+
+        // The test instance allows 24 retries
+        sqlx::query(
+            "INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, retry_count) VALUES ($1, $2, $3::text::uuid, 24)",
+        )
+        .bind(application_id)
+        .bind(event_id)
+        .bind(&inbox)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let pull = || {
+            test::TestRequest::get()
+                .uri(&format!(
+                    "/api/v1/inbox/{inbox}?application_id={application_id}"
+                ))
+                .insert_header(auth.clone())
+                .to_request()
+        };
+        let leased: Value = test::call_and_read_body_json(&app, pull()).await;
+        let receipt = leased["messages"][0]["receipt"].clone();
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/inbox/{inbox}/nack"))
+            .insert_header(auth.clone())
+            .set_json(json!({ "application_id": application_id, "receipts": [receipt] }))
+            .to_request();
+        let settled: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(settled["settled"], json!([receipt]));
+
+        let none: Value = test::call_and_read_body_json(&app, pull()).await;
+        assert_eq!(none["messages"], json!([]));
+        let attempts: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM webhook.request_attempt WHERE subscription__id = $1::text::uuid",
+        )
+        .bind(&inbox)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(attempts, 1);
+        let reasons: Vec<String> = sqlx::query_scalar(
+            "SELECT reason FROM webhook.dead_letter WHERE subscription__id = $1::text::uuid",
+        )
+        .bind(&inbox)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(reasons, vec!["retries_exhausted"]);
+    }
+
+    /// An event that expired while it was leased is not offered again once its lease lapses.
+    #[sqlx::test]
+    async fn expired_events_are_not_offered_again(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, event_id) = seed_event(&pool, org).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1")
+                .wrap(biscuit_auth)
+                .route(
+                    "/subscriptions",
+                    web::post().to(crate::handlers::subscriptions::create),
+                )
+                .route("/inbox/{subscription_id}", web::get().to(super::pull)),
+        ));
+        let auth = ("Authorization", format!("Bearer {user_token}"));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/subscriptions")
+            .insert_header(auth.clone())
+            .set_json(json!({
+                "application_id": application_id,
+                "is_enabled": true,
+                "event_types": ["test.resource.created"],
+                "labels": { "e2e": "1" },
+                "target": { "type": "inbox" },
+            }))
+            .to_request();
+        let created: Value = test::call_and_read_body_json(&app, req).await;
+        let inbox = created["subscription_id"].as_str().unwrap().to_owned();
+
+        sqlx::query(
+            "INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id) VALUES ($1, $2, $3::text::uuid)",
+        )
+        .bind(application_id)
+        .bind(event_id)
+        .bind(&inbox)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let pull = || {
+            test::TestRequest::get()
+                .uri(&format!(
+                    "/api/v1/inbox/{inbox}?application_id={application_id}"
+                ))
+                .insert_header(auth.clone())
+                .to_request()
+        };
+        let leased: Value = test::call_and_read_body_json(&app, pull()).await;
+        assert_eq!(leased["messages"].as_array().unwrap().len(), 1);
+
+        sqlx::query(
+            "UPDATE event.event SET expires_at = statement_timestamp() - interval '1 minute' WHERE event__id = $1",
+        )
+        .bind(event_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("UPDATE webhook.inbox_lease SET leased_until = statement_timestamp() - INTERVAL '1 second'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let none: Value = test::call_and_read_body_json(&app, pull()).await;
+        assert_eq!(none["messages"], json!([]));
+    }
+}
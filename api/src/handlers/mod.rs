@@ -16,18 +16,25 @@
 
 pub mod applications;
 pub mod auth;
+pub mod bulk_retries;
+pub mod client_certificates;
+pub mod dead_letters;
 pub mod email_preferences;
 pub mod environment_variables;
 pub mod errors;
 pub mod event_types;
 pub mod events;
 pub mod events_per_day;
+pub mod inbox;
 pub mod instance;
+pub mod oauth2_client_credentials;
 pub mod organizations;
 pub mod registrations;
 pub mod request_attempts;
 pub mod responses;
+pub mod secret_rotations;
 pub mod service_token;
+pub mod signing_keys;
 pub mod subscriptions;
 
 #[cfg(feature = "application-secret-compatibility")]
@@ -0,0 +1,376 @@
+use actix_web::web::ReqData;
+use biscuit_auth::Biscuit;
+use chrono::{DateTime, Utc};
+use paperclip::actix::web::{Data, Json, Path, Query};
+use paperclip::actix::{Apiv2Schema, NoContent, api_v2_operation};
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::iam::{Action, authorize_for_application};
+use crate::openapi::OaBiscuit;
+use crate::problems::Hook0Problem;
+
+/// OAuth2 client credentials a subscription obtains an access token with, which is sent to its
+/// target as a bearer token. The client secret is never returned.
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct OAuth2ClientCredentials {
+    pub subscription_id: Uuid,
+    pub token_url: String,
+    pub client_id: String,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[allow(non_snake_case)]
+struct RawOAuth2ClientCredentials {
+    subscription__id: Uuid,
+    token_url: String,
+    client_id: String,
+    scopes: Vec<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<RawOAuth2ClientCredentials> for OAuth2ClientCredentials {
+    fn from(raw: RawOAuth2ClientCredentials) -> Self {
+        Self {
+            subscription_id: raw.subscription__id,
+            token_url: raw.token_url,
+            client_id: raw.client_id,
+            scopes: raw.scopes,
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema, Validate)]
+pub struct OAuth2ClientCredentialsPut {
+    /// Token endpoint of the authorization server
+    #[validate(custom(function = "crate::validators::oauth2_token_url"))]
+    token_url: String,
+    #[validate(non_control_character, length(min = 1, max = 1000))]
+    client_id: String,
+    #[validate(custom(function = "crate::validators::oauth2_client_secret"))]
+    client_secret: String,
+    /// Scopes to request; none by default
+    #[validate(custom(function = "crate::validators::oauth2_scopes"))]
+    scopes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct Qs {
+    application_id: Uuid,
+}
+
+#[api_v2_operation(
+    summary = "Get the OAuth2 client credentials of a subscription",
+    description = "Retrieves the OAuth2 client credentials the subscription obtains an access token with; the token is sent to its target as a bearer token. The client secret is never returned.",
+    operation_id = "oauth2ClientCredentials.get",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn get(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<Json<OAuth2ClientCredentials>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionGet {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let credentials = query_as!(
+        RawOAuth2ClientCredentials,
+        "
+            SELECT
+                soc.subscription__id,
+                soc.token_url,
+                soc.client_id,
+                soc.scopes,
+                soc.created_at,
+                soc.updated_at
+            FROM webhook.subscription_oauth2_client_credentials AS soc
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = soc.subscription__id
+            WHERE s.application__id = $1
+                AND s.subscription__id = $2
+                AND s.deleted_at IS NULL
+        ",
+        &qs.application_id,
+        &subscription_id,
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(Hook0Problem::NotFound)?;
+
+    Ok(Json(credentials.into()))
+}
+
+#[api_v2_operation(
+    summary = "Set the OAuth2 client credentials of a subscription",
+    description = "Sets the OAuth2 client credentials the subscription obtains an access token with (client credentials grant), replacing the previous ones. Output workers request the token from the token endpoint, cache it until it expires and send it to the target as a bearer token with every delivery, in place of any Authorization header of the target. The client secret is never returned.",
+    operation_id = "oauth2ClientCredentials.update",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn edit(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+    body: Json<OAuth2ClientCredentialsPut>,
+) -> Result<Json<OAuth2ClientCredentials>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionEdit {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    let credentials = query_as!(
+        RawOAuth2ClientCredentials,
+        "
+            INSERT INTO webhook.subscription_oauth2_client_credentials (
+                subscription__id,
+                token_url,
+                client_id,
+                client_secret,
+                scopes
+            )
+            SELECT s.subscription__id, $3, $4, $5, $6
+            FROM webhook.subscription AS s
+            WHERE s.application__id = $1
+                AND s.subscription__id = $2
+                AND s.deleted_at IS NULL
+            ON CONFLICT (subscription__id) DO UPDATE
+            SET token_url = EXCLUDED.token_url,
+                client_id = EXCLUDED.client_id,
+                client_secret = EXCLUDED.client_secret,
+                scopes = EXCLUDED.scopes,
+                updated_at = statement_timestamp()
+            RETURNING
+                subscription__id,
+                token_url,
+                client_id,
+                scopes,
+                created_at,
+                updated_at
+        ",
+        &qs.application_id,
+        &subscription_id,
+        &body.token_url,
+        &body.client_id,
+        &body.client_secret,
+        body.scopes.as_deref().unwrap_or_default(),
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(Hook0Problem::NotFound)?;
+
+    Ok(Json(credentials.into()))
+}
+
+#[api_v2_operation(
+    summary = "Remove the OAuth2 client credentials of a subscription",
+    description = "Removes the OAuth2 client credentials of the subscription; its deliveries no longer carry an access token.",
+    operation_id = "oauth2ClientCredentials.delete",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn delete(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<NoContent, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionEdit {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let deleted = query!(
+        "
+            DELETE FROM webhook.subscription_oauth2_client_credentials AS soc
+            USING webhook.subscription AS s
+            WHERE s.subscription__id = soc.subscription__id
+                AND s.application__id = $1
+                AND s.subscription__id = $2
+                AND s.deleted_at IS NULL
+        ",
+        &qs.application_id,
+        &subscription_id,
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    if deleted == 0 {
+        Err(Hook0Problem::NotFound)
+    } else {
+        Ok(NoContent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_subscription,
+        seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use sqlx::PgPool;
+
+    /// Credentials are validated, their secret stored but never returned, and they can be
+    /// replaced and removed.
+    #[sqlx::test]
+    async fn oauth2_client_credentials_lifecycle(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, _) = seed_event(&pool, org).await;
+        let subscription_id =
+            seed_subscription(&pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1/subscriptions")
+                .wrap(biscuit_auth)
+                .route(
+                    "/{subscription_id}/oauth2_client_credentials",
+                    web::get().to(super::get),
+                )
+                .route(
+                    "/{subscription_id}/oauth2_client_credentials",
+                    web::put().to(super::edit),
+                )
+                .route(
+                    "/{subscription_id}/oauth2_client_credentials",
+                    web::delete().to(super::delete),
+                ),
+        ));
+        let uri = format!(
+            "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials?application_id={application_id}"
+        );
+        let authorization = ("Authorization", format!("Bearer {user_token}"));
+        let put = |body: serde_json::Value| {
+            test::TestRequest::put()
+                .uri(&uri)
+                .insert_header(authorization.clone())
+                .set_json(body)
+                .to_request()
+        };
+
+        let resp = test::call_service(
+            &app,
+            put(serde_json::json!({
+                "token_url": "ftp://auth.example.com/token",
+                "client_id": "hook0",
+                "client_secret": "s3cr3t\u{7}",
+            })),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert!(!body.contains("s3cr3t"), "{body}");
+
+        let resp = test::call_service(
+            &app,
+            put(serde_json::json!({
+                "token_url": "https://auth.example.com/oauth2/token",
+                "client_id": "hook0",
+                "client_secret": "s3cr3t",
+                "scopes": ["webhooks:write"],
+            })),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["client_id"], "hook0");
+        assert_eq!(body["scopes"], serde_json::json!(["webhooks:write"]));
+        assert!(body.get("client_secret").is_none());
+
+        let resp = test::call_service(
+            &app,
+            put(serde_json::json!({
+                "token_url": "https://auth.example.com/oauth2/token",
+                "client_id": "hook0-2",
+                "client_secret": "s3cr3t-2",
+            })),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let (client_id, client_secret, scopes): (String, String, Vec<String>) = sqlx::query_as(
+            "SELECT client_id, client_secret, scopes FROM webhook.subscription_oauth2_client_credentials WHERE subscription__id = $1",
+        )
+        .bind(subscription_id)
+        .fetch_one(&pool)
+        .await
+        .expect("stored credentials");
+        assert_eq!(
+            (client_id.as_str(), client_secret.as_str()),
+            ("hook0-2", "s3cr3t-2")
+        );
+        assert!(scopes.is_empty());
+
+        let get = || {
+            test::TestRequest::get()
+                .uri(&uri)
+                .insert_header(authorization.clone())
+                .to_request()
+        };
+        let resp = test::call_service(&app, get()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::delete()
+                .uri(&uri)
+                .insert_header(authorization.clone())
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let resp = test::call_service(&app, get()).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -2,19 +2,22 @@ use actix_web::web::ReqData;
 use biscuit_auth::Biscuit;
 use chrono::{DateTime, Utc};
 use paperclip::actix::web::{Data, Json, Path, Query};
-use paperclip::actix::{Apiv2Schema, api_v2_operation};
+use paperclip::actix::{Apiv2Schema, CreatedJson, api_v2_operation};
 use paperclip::v2::models::{DataType, DataTypeFormat, DefaultSchemaRaw};
 use paperclip::v2::schema::Apiv2Schema as Apiv2SchemaTrait;
 use serde::{Deserialize, Serialize};
-use sqlx::query_as;
+use sqlx::{query_as, query_scalar};
 use std::cmp::max;
 use std::collections::BTreeMap;
 use tracing::error;
 use url::Url;
 use uuid::Uuid;
 
+use crate::handlers::events::{load_event_payload, send_request_attempts_to_pulsar};
+use crate::handlers::subscriptions::ResponseOutcome;
 use crate::iam::{Action, authorize_for_application};
 use crate::openapi::OaBiscuit;
+use crate::opentelemetry::report_retried_request_attempts;
 use crate::pagination::{Cursor, EncodedDescCursor, NextPageParts, Paginated};
 use crate::problems::Hook0Problem;
 
@@ -30,6 +33,10 @@ pub struct RequestAttempt {
     pub succeeded_at: Option<DateTime<Utc>>,
     pub delay_until: Option<DateTime<Utc>>,
     pub response_id: Option<Uuid>,
+    /// Batch the request attempt was delivered in, shared with the other request attempts of the batch and their response
+    pub delivery_batch_id: Option<Uuid>,
+    /// Dead letter the request attempt became, when it was the last attempt of a delivery an output worker gave up on
+    pub dead_letter_id: Option<Uuid>,
     pub retry_count: i16,
     pub http_response_status: Option<i16>,
     pub status: RequestAttemptStatus,
@@ -63,10 +70,20 @@ pub enum RequestAttemptStatus {
     Successful {
         at: DateTime<Utc>,
         full_processing_ms: i64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rule_outcome: Option<ResponseOutcome>,
     },
     Failed {
         at: DateTime<Utc>,
         full_processing_ms: i64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rule_outcome: Option<ResponseOutcome>,
+    },
+    Expired {
+        at: DateTime<Utc>,
+    },
+    Cancelled {
+        at: DateTime<Utc>,
     },
 }
 
@@ -84,8 +101,10 @@ impl Apiv2SchemaTrait for RequestAttemptStatus {
         // - waiting: {type: "waiting", since: DateTime, until: DateTime}
         // - pending: {type: "pending", since: DateTime}
         // - in_progress: {type: "in_progress", since: DateTime}
-        // - successful: {type: "successful", at: DateTime, full_processing_ms: i64}
-        // - failed: {type: "failed", at: DateTime, full_processing_ms: i64}
+        // - successful: {type: "successful", at: DateTime, full_processing_ms: i64, rule_outcome?: string}
+        // - failed: {type: "failed", at: DateTime, full_processing_ms: i64, rule_outcome?: string}
+        // - expired: {type: "expired", at: DateTime}
+        // - cancelled: {type: "cancelled", at: DateTime}
 
         let mut properties = BTreeMap::new();
 
@@ -95,7 +114,7 @@ impl Apiv2SchemaTrait for RequestAttemptStatus {
             Box::new(DefaultSchemaRaw {
                 data_type: Some(DataType::String),
                 description: Some(
-                    "Status type discriminator. One of: waiting, pending, in_progress, successful, failed"
+                    "Status type discriminator. One of: waiting, pending, in_progress, successful, failed, expired, cancelled"
                         .to_owned(),
                 ),
                 enum_: vec![
@@ -104,6 +123,8 @@ impl Apiv2SchemaTrait for RequestAttemptStatus {
                     serde_json::Value::String("in_progress".to_owned()),
                     serde_json::Value::String("successful".to_owned()),
                     serde_json::Value::String("failed".to_owned()),
+                    serde_json::Value::String("expired".to_owned()),
+                    serde_json::Value::String("cancelled".to_owned()),
                 ],
                 ..Default::default()
             }),
@@ -143,7 +164,8 @@ impl Apiv2SchemaTrait for RequestAttemptStatus {
                 data_type: Some(DataType::String),
                 format: Some(DataTypeFormat::DateTime),
                 description: Some(
-                    "Timestamp when completed (present in successful, failed)".to_owned(),
+                    "Timestamp when completed (present in successful, failed, expired, cancelled)"
+                        .to_owned(),
                 ),
                 ..Default::default()
             }),
@@ -163,6 +185,25 @@ impl Apiv2SchemaTrait for RequestAttemptStatus {
             }),
         );
 
+        // rule_outcome field (present in successful, failed when a response rule matched)
+        properties.insert(
+            "rule_outcome".to_owned(),
+            Box::new(DefaultSchemaRaw {
+                data_type: Some(DataType::String),
+                description: Some(
+                    "Outcome given by a response rule of the subscription, when one matched the response (present in successful, failed). One of: success, retryable, permanent_failure, disable_subscription"
+                        .to_owned(),
+                ),
+                enum_: vec![
+                    serde_json::Value::String("success".to_owned()),
+                    serde_json::Value::String("retryable".to_owned()),
+                    serde_json::Value::String("permanent_failure".to_owned()),
+                    serde_json::Value::String("disable_subscription".to_owned()),
+                ],
+                ..Default::default()
+            }),
+        );
+
         // Only type is always required
         let mut required = std::collections::BTreeSet::new();
         required.insert("type".to_owned());
@@ -174,8 +215,10 @@ impl Apiv2SchemaTrait for RequestAttemptStatus {
                  - waiting: {type, since, until} - Scheduled for future delivery \
                  - pending: {type, since} - Ready to be processed \
                  - in_progress: {type, since} - Currently being delivered \
-                 - successful: {type, at, full_processing_ms} - Delivered successfully \
-                 - failed: {type, at, full_processing_ms} - Delivery failed"
+                 - successful: {type, at, full_processing_ms, rule_outcome?} - Delivered successfully \
+                 - failed: {type, at, full_processing_ms, rule_outcome?} - Delivery failed \
+                 - expired: {type, at} - Cancelled because its event expired before it was delivered \
+                 - cancelled: {type, at} - Cancelled along with its scheduled event before it was delivered"
                     .to_owned(),
             ),
             properties,
@@ -186,6 +229,7 @@ impl Apiv2SchemaTrait for RequestAttemptStatus {
 }
 
 impl RequestAttemptStatus {
+    #[allow(clippy::too_many_arguments)]
     pub fn compute(
         current_time: &DateTime<Utc>,
         created_at: &DateTime<Utc>,
@@ -193,20 +237,34 @@ impl RequestAttemptStatus {
         failed_at: &Option<DateTime<Utc>>,
         succeeded_at: &Option<DateTime<Utc>>,
         delay_until: &Option<DateTime<Utc>>,
+        expired_at: &Option<DateTime<Utc>>,
+        cancelled_at: &Option<DateTime<Utc>>,
+        rule_outcome: Option<ResponseOutcome>,
     ) -> Self {
         let start = match delay_until {
             Some(d) => max(created_at, d),
             None => created_at,
         };
 
+        // An expired request attempt is also failed, so that it is left alone by everything waiting for it to be done
+        if let Some(at) = expired_at {
+            return Self::Expired { at: *at };
+        }
+        // So is a cancelled one
+        if let Some(at) = cancelled_at {
+            return Self::Cancelled { at: *at };
+        }
+
         match (delay_until, picked_at, succeeded_at, failed_at) {
             (_, _, _, Some(at)) => Self::Failed {
                 at: *at,
                 full_processing_ms: (*at - *start).num_milliseconds(),
+                rule_outcome,
             },
             (_, _, Some(at), None) => Self::Successful {
                 at: *at,
                 full_processing_ms: (*at - *start).num_milliseconds(),
+                rule_outcome,
             },
             (_, Some(since), None, None) => Self::InProgress { since: *since },
             (Some(until), None, None, None) if until > current_time => Self::Waiting {
@@ -260,15 +318,20 @@ pub async fn get(
         failed_at: Option<DateTime<Utc>>,
         succeeded_at: Option<DateTime<Utc>>,
         delay_until: Option<DateTime<Utc>>,
+        expired_at: Option<DateTime<Utc>>,
+        cancelled_at: Option<DateTime<Utc>>,
         response__id: Option<Uuid>,
+        delivery_batch__id: Option<Uuid>,
+        dead_letter__id: Option<Uuid>,
         retry_count: i16,
         event_type__name: String,
         http_response_status: Option<i16>,
+        rule_outcome: Option<String>,
     }
 
     let raw = query_as!(
         RawRequestAttempt,
-        "
+        r#"
             SELECT
                 ra.request_attempt__id,
                 ra.event__id,
@@ -278,18 +341,24 @@ pub async fn get(
                 ra.failed_at,
                 ra.succeeded_at,
                 ra.delay_until,
+                ra.expired_at,
+                ra.cancelled_at,
                 ra.response__id,
+                ra.delivery_batch__id,
+                dl.dead_letter__id AS "dead_letter__id?",
                 ra.retry_count,
                 s.description AS subscription__description,
                 e.event_type__name,
-                r.http_code AS http_response_status
+                r.http_code AS http_response_status,
+                r.rule_outcome AS "rule_outcome?"
             FROM webhook.request_attempt AS ra
             INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
             INNER JOIN event.event AS e ON e.event__id = ra.event__id
             LEFT JOIN webhook.response AS r ON r.response__id = ra.response__id
+            LEFT JOIN webhook.dead_letter AS dl ON dl.request_attempt__id = ra.request_attempt__id
             WHERE ra.application__id = $1
                 AND ra.request_attempt__id = $2
-        ",
+        "#,
         &qs.application_id,
         &request_attempt_id.into_inner(),
     )
@@ -315,6 +384,8 @@ pub async fn get(
             succeeded_at: ra.succeeded_at,
             delay_until: ra.delay_until,
             response_id: ra.response__id,
+            delivery_batch_id: ra.delivery_batch__id,
+            dead_letter_id: ra.dead_letter__id,
             retry_count: ra.retry_count,
             http_response_status: ra.http_response_status,
             status: RequestAttemptStatus::compute(
@@ -324,6 +395,11 @@ pub async fn get(
                 &ra.failed_at,
                 &ra.succeeded_at,
                 &ra.delay_until,
+                &ra.expired_at,
+                &ra.cancelled_at,
+                ra.rule_outcome
+                    .as_deref()
+                    .and_then(ResponseOutcome::from_db),
             ),
         })),
         None => Err(Hook0Problem::NotFound),
@@ -345,7 +421,7 @@ pub struct Qs {
 
 #[api_v2_operation(
     summary = "List request attempts",
-    description = "Retrieves webhook delivery attempts for an application. Each attempt shows the delivery status (pending, in_progress, successful, failed, waiting), retry count, and timestamps. Filter by event_id, subscription_id, date range, or event types. Paginated via Link header.",
+    description = "Retrieves webhook delivery attempts for an application. Each attempt shows the delivery status (pending, in_progress, successful, failed, waiting, expired, cancelled), retry count, and timestamps. Filter by event_id, subscription_id, date range, or event types. Paginated via Link header.",
     operation_id = "requestAttempts.read",
     consumes = "application/json",
     produces = "application/json",
@@ -395,14 +471,19 @@ pub async fn list(
         failed_at: Option<DateTime<Utc>>,
         succeeded_at: Option<DateTime<Utc>>,
         delay_until: Option<DateTime<Utc>>,
+        expired_at: Option<DateTime<Utc>>,
+        cancelled_at: Option<DateTime<Utc>>,
         response__id: Option<Uuid>,
+        delivery_batch__id: Option<Uuid>,
+        dead_letter__id: Option<Uuid>,
         retry_count: i16,
         event_type__name: String,
         http_response_status: Option<i16>,
+        rule_outcome: Option<String>,
     }
     let raw_request_attempts = query_as!(
         RawRequestAttempt,
-        "
+        r#"
             SELECT
                 ra.request_attempt__id,
                 ra.event__id,
@@ -412,15 +493,21 @@ pub async fn list(
                 ra.failed_at,
                 ra.succeeded_at,
                 ra.delay_until,
+                ra.expired_at,
+                ra.cancelled_at,
                 ra.response__id,
+                ra.delivery_batch__id,
+                dl.dead_letter__id AS "dead_letter__id?",
                 ra.retry_count,
                 s.description AS subscription__description,
                 e.event_type__name,
-                r.http_code AS http_response_status
+                r.http_code AS http_response_status,
+                r.rule_outcome AS "rule_outcome?"
             FROM webhook.request_attempt AS ra
             INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
             INNER JOIN event.event AS e ON e.event__id = ra.event__id
             LEFT JOIN webhook.response AS r ON r.response__id = ra.response__id
+            LEFT JOIN webhook.dead_letter AS dl ON dl.request_attempt__id = ra.request_attempt__id
             WHERE ra.application__id = $1
                 AND (ra.event__id = $2 OR $2 IS NULL)
                 AND (s.subscription__id = $3 OR $3 IS NULL)
@@ -431,7 +518,7 @@ pub async fn list(
                 ra.created_at DESC,
                 ra.request_attempt__id ASC
             LIMIT 50
-        ",
+        "#,
         &qs.application_id,
         qs.event_id,
         qs.subscription_id,
@@ -464,6 +551,8 @@ pub async fn list(
             succeeded_at: ra.succeeded_at,
             delay_until: ra.delay_until,
             response_id: ra.response__id,
+            delivery_batch_id: ra.delivery_batch__id,
+            dead_letter_id: ra.dead_letter__id,
             retry_count: ra.retry_count,
             http_response_status: ra.http_response_status,
             status: RequestAttemptStatus::compute(
@@ -473,6 +562,11 @@ pub async fn list(
                 &ra.failed_at,
                 &ra.succeeded_at,
                 &ra.delay_until,
+                &ra.expired_at,
+                &ra.cancelled_at,
+                ra.rule_outcome
+                    .as_deref()
+                    .and_then(ResponseOutcome::from_db),
             ),
         })
         .collect::<Vec<_>>();
@@ -520,6 +614,195 @@ pub async fn list(
     })
 }
 
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct RetryRequestAttempt {
+    application_id: Uuid,
+}
+
+#[api_v2_operation(
+    summary = "Retry a request attempt",
+    description = "Delivers the event of a failed request attempt again, to the subscription of this request attempt only. A new request attempt is created and returned; it follows the usual retry policy if it fails too. Unlike replaying the event, other subscriptions are not affected.",
+    operation_id = "requestAttempts.retry",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "mcp", "sdk")
+)]
+pub async fn retry(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    request_attempt_id: Path<Uuid>,
+    body: Json<RetryRequestAttempt>,
+) -> Result<CreatedJson<RequestAttempt>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::RequestAttemptRetry {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let mut tx = state.db.begin().await?;
+
+    #[allow(non_snake_case)]
+    struct RawRetriedRequestAttempt {
+        event__id: Uuid,
+        subscription__id: Uuid,
+        subscription__description: Option<String>,
+        subscription_is_active: bool,
+        failed_at: Option<DateTime<Utc>>,
+        event_type__name: String,
+        event_received_at: DateTime<Utc>,
+        payload: Option<Vec<u8>>,
+        payload_content_type: String,
+        ordering_key: Option<String>,
+    }
+    // Locking the subscription serializes concurrent retries of its request attempts, so that the pending check below holds until the new request attempt is committed
+    let retried = query_as!(
+        RawRetriedRequestAttempt,
+        r#"
+            SELECT
+                ra.event__id,
+                ra.subscription__id,
+                ra.failed_at,
+                s.description AS subscription__description,
+                (s.is_enabled AND s.deleted_at IS NULL) AS "subscription_is_active!",
+                e.event_type__name,
+                e.received_at AS event_received_at,
+                e.payload,
+                e.payload_content_type,
+                ra.ordering_key
+            FROM webhook.request_attempt AS ra
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
+            INNER JOIN event.event AS e ON e.event__id = ra.event__id
+            WHERE ra.application__id = $1
+                AND ra.request_attempt__id = $2
+            FOR UPDATE OF s
+        "#,
+        &body.application_id,
+        &request_attempt_id.into_inner(),
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(Hook0Problem::from)?
+    .ok_or(Hook0Problem::NotFound)?;
+
+    if retried.failed_at.is_none() {
+        return Err(Hook0Problem::RequestAttemptNotFailed);
+    }
+    if !retried.subscription_is_active {
+        return Err(Hook0Problem::SubscriptionDisabled);
+    }
+
+    let pending = query_scalar!(
+        r#"
+            SELECT EXISTS(
+                SELECT 1
+                FROM webhook.request_attempt
+                WHERE event__id = $1
+                    AND subscription__id = $2
+                    AND succeeded_at IS NULL
+                    AND failed_at IS NULL
+            ) AS "pending!"
+        "#,
+        &retried.event__id,
+        &retried.subscription__id,
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(Hook0Problem::from)?;
+    if pending {
+        return Err(Hook0Problem::RequestAttemptRetryPending);
+    }
+
+    #[allow(non_snake_case)]
+    struct RawNewRequestAttempt {
+        request_attempt__id: Uuid,
+        created_at: DateTime<Utc>,
+    }
+    let new_request_attempt = query_as!(
+        RawNewRequestAttempt,
+        "
+            INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, ordering_key)
+            VALUES ($1, $2, $3, $4)
+            RETURNING request_attempt__id, created_at
+        ",
+        &body.application_id,
+        &retried.event__id,
+        &retried.subscription__id,
+        retried.ordering_key,
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(Hook0Problem::from)?;
+
+    if let Some(pulsar) = &state.pulsar {
+        let payload = load_event_payload(
+            state.object_storage.as_ref(),
+            body.application_id,
+            retried.event__id,
+            retried.event_received_at,
+            retried.payload,
+        )
+        .await
+        .ok_or(Hook0Problem::InternalServerError)?;
+
+        send_request_attempts_to_pulsar(
+            &mut *tx,
+            pulsar,
+            body.application_id,
+            retried.event__id,
+            retried.event_received_at,
+            &retried.event_type__name,
+            &payload,
+            &retried.payload_content_type,
+            Some(new_request_attempt.request_attempt__id),
+            true,
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+    report_retried_request_attempts(1);
+
+    Ok(CreatedJson(RequestAttempt {
+        request_attempt_id: new_request_attempt.request_attempt__id,
+        event_id: retried.event__id,
+        event: EventSummary {
+            event_id: retried.event__id,
+            event_type_name: retried.event_type__name,
+        },
+        subscription: SubscriptionSummary {
+            subscription_id: retried.subscription__id,
+            description: retried.subscription__description,
+        },
+        created_at: new_request_attempt.created_at,
+        picked_at: None,
+        failed_at: None,
+        succeeded_at: None,
+        delay_until: None,
+        response_id: None,
+        delivery_batch_id: None,
+        dead_letter_id: None,
+        retry_count: 0,
+        http_response_status: None,
+        status: RequestAttemptStatus::compute(
+            &Utc::now(),
+            &new_request_attempt.created_at,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            None,
+        ),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -548,8 +831,8 @@ mod tests {
         );
         assert_eq!(
             type_field.enum_.len(),
-            5,
-            "Should have 5 status type values"
+            7,
+            "Should have 7 status type values"
         );
 
         let type_values: Vec<&str> = type_field.enum_.iter().filter_map(|v| v.as_str()).collect();
@@ -565,6 +848,11 @@ mod tests {
             "Missing 'successful' type"
         );
         assert!(type_values.contains(&"failed"), "Missing 'failed' type");
+        assert!(type_values.contains(&"expired"), "Missing 'expired' type");
+        assert!(
+            type_values.contains(&"cancelled"),
+            "Missing 'cancelled' type"
+        );
     }
 
     #[test]
@@ -590,6 +878,10 @@ mod tests {
             props.contains_key("full_processing_ms"),
             "Should have 'full_processing_ms' field"
         );
+        assert!(
+            props.contains_key("rule_outcome"),
+            "Should have 'rule_outcome' field"
+        );
     }
 
     #[test]
@@ -626,3 +918,90 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod retry_tests {
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_subscription,
+        seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    /// Insert a subscription and a request attempt that already failed, returning the attempt id.
+    async fn seed_failed_request_attempt(
+        pool: &PgPool,
+        application_id: Uuid,
+        event_id: Uuid,
+    ) -> Uuid {
+        let subscription_id = seed_subscription(pool, application_id, json!({ "e2e": "1" })).await;
+
+        let request_attempt_id = Uuid::new_v4();
+        sqlx::query(
+            r#"
+                INSERT INTO webhook.request_attempt
+                    (request_attempt__id, event__id, subscription__id, application__id, picked_at, failed_at)
+                VALUES ($1, $2, $3, $4, statement_timestamp(), statement_timestamp())
+            "#,
+        )
+        .bind(request_attempt_id)
+        .bind(event_id)
+        .bind(subscription_id)
+        .bind(application_id)
+        .execute(pool)
+        .await
+        .expect("seed failed request attempt");
+        request_attempt_id
+    }
+
+    /// Retrying a failed attempt queues a fresh one; retrying it again while that one is still
+    /// waiting is refused, so a double click cannot deliver the event twice.
+    #[sqlx::test]
+    async fn a_failed_attempt_is_retried_once(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, event_id) = seed_event(&pool, org).await;
+        let request_attempt_id = seed_failed_request_attempt(&pool, application_id, event_id).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app.service(
+            web::scope("/api/v1/request_attempts")
+                .wrap(biscuit_auth)
+                .route("/{request_attempt_id}/retry", web::post().to(super::retry)),
+        ));
+        let retry = || {
+            test::TestRequest::post()
+                .uri(&format!(
+                    "/api/v1/request_attempts/{request_attempt_id}/retry"
+                ))
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .set_json(serde_json::json!({ "application_id": application_id }))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, retry()).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_ne!(body["request_attempt_id"], request_attempt_id.to_string());
+        assert_eq!(body["event_id"], event_id.to_string());
+        assert_eq!(body["retry_count"], 0);
+
+        let resp = test::call_service(&app, retry()).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let attempts: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM webhook.request_attempt WHERE event__id = $1")
+                .bind(event_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(attempts, 2);
+    }
+}
@@ -9,11 +9,12 @@ use paperclip::actix::web::{Data, Json, Path, Query};
 use paperclip::actix::{Apiv2Schema, api_v2_operation};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use sqlx::query_as;
+use sqlx::{PgPool, query_as};
 use std::collections::HashMap;
 use tracing::warn;
 use uuid::Uuid;
 
+use crate::ObjectStorageConfig;
 use crate::iam::{Action, authorize_for_application};
 use crate::openapi::OaBiscuit;
 use crate::problems::Hook0Problem;
@@ -61,6 +62,25 @@ pub async fn get(
     )
     .await?;
 
+    load_response(
+        &state.db,
+        state.object_storage.as_ref(),
+        qs.application_id,
+        response_id.into_inner(),
+    )
+    .await?
+    .map(Json)
+    .ok_or(Hook0Problem::NotFound)
+}
+
+/// A response of a request attempt of the application, with its body and headers read from
+/// object storage when they were stored there.
+pub async fn load_response(
+    db: &PgPool,
+    object_storage: Option<&ObjectStorageConfig>,
+    application_id: Uuid,
+    response_id: Uuid,
+) -> Result<Option<Response>, Hook0Problem> {
     #[allow(non_snake_case)]
     struct RawResponse {
         response__id: Uuid,
@@ -87,19 +107,21 @@ pub async fn get(
             INNER JOIN webhook.request_attempt AS ra ON ra.response__id = r.response__id
             INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
             WHERE s.application__id = $1 AND r.response__id = $2
+            ORDER BY ra.created_at ASC
+            LIMIT 1
         ",
-        &qs.application_id,
-        &response_id.into_inner(),
+        &application_id,
+        &response_id,
     )
-    .fetch_optional(&state.db)
+    .fetch_optional(db)
     .await
     .map_err(Hook0Problem::from)?;
 
     if let Some(rr) = raw_response {
-        let object_storage_response = if let Some(object_storage) = &state.object_storage {
+        let object_storage_response = if let Some(object_storage) = object_storage {
             let key = format!(
                 "{}/response/{}/{}",
-                qs.application_id,
+                application_id,
                 rr.request_attempt_created_at.naive_utc().date(),
                 rr.response__id,
             );
@@ -174,7 +196,7 @@ pub async fn get(
             String::from_utf8(bytes.to_owned()).unwrap_or_else(|_| Base64.encode(bytes))
         });
 
-        Ok(Json(Response {
+        Ok(Some(Response {
             response_id: rr.response__id,
             response_error_name: rr.response_error__name,
             http_code: rr.http_code,
@@ -183,6 +205,6 @@ pub async fn get(
             elapsed_time_ms: rr.elapsed_time_ms,
         }))
     } else {
-        Err(Hook0Problem::NotFound)
+        Ok(None)
     }
 }
@@ -0,0 +1,444 @@
+use actix_web::web::ReqData;
+use biscuit_auth::Biscuit;
+use chrono::{DateTime, Utc};
+use paperclip::actix::web::{Data, Json, Path, Query};
+use paperclip::actix::{Apiv2Schema, CreatedJson, NoContent, api_v2_operation};
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::iam::{Action, authorize_for_application};
+use crate::openapi::OaBiscuit;
+use crate::problems::Hook0Problem;
+
+/// How long the previous secret stays valid when a rotation does not say
+const DEFAULT_OVERLAP_SECONDS: i32 = 86_400;
+
+/// Rotation of the secret of a subscription: until `previous_secret_expires_at`, deliveries are
+/// signed with both the new secret and the one it replaced
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct SecretRotation {
+    pub subscription_id: Uuid,
+    /// The new secret of the subscription
+    pub secret: Uuid,
+    /// The secret the new one replaced
+    pub previous_secret: Uuid,
+    pub previous_secret_expires_at: DateTime<Utc>,
+}
+
+#[allow(non_snake_case)]
+struct RawSecretRotation {
+    subscription__id: Uuid,
+    secret: Uuid,
+    previous_secret: Uuid,
+    previous_secret_expires_at: DateTime<Utc>,
+}
+
+impl From<RawSecretRotation> for SecretRotation {
+    fn from(raw: RawSecretRotation) -> Self {
+        Self {
+            subscription_id: raw.subscription__id,
+            secret: raw.secret,
+            previous_secret: raw.previous_secret,
+            previous_secret_expires_at: raw.previous_secret_expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema, Validate)]
+pub struct SecretRotationPost {
+    /// How long the previous secret stays valid, in seconds (one day by default)
+    #[validate(range(min = 60, max = 2_592_000))]
+    overlap_seconds: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct Qs {
+    application_id: Uuid,
+}
+
+async fn subscription_exists(
+    db: &sqlx::PgPool,
+    application_id: &Uuid,
+    subscription_id: &Uuid,
+) -> Result<bool, Hook0Problem> {
+    let exists = query_scalar!(
+        r#"
+            SELECT EXISTS(
+                SELECT 1
+                FROM webhook.subscription
+                WHERE application__id = $1 AND subscription__id = $2 AND deleted_at IS NULL
+            ) AS "exists!"
+        "#,
+        application_id,
+        subscription_id,
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(exists)
+}
+
+#[api_v2_operation(
+    summary = "Get the secret rotation of a subscription",
+    description = "Retrieves the rotation of the secret of the subscription that is in progress, if any: until the previous secret expires, deliveries are signed with both secrets.",
+    operation_id = "secretRotations.get",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn get(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<Json<SecretRotation>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionGet {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let rotation = query_as!(
+        RawSecretRotation,
+        r#"
+            SELECT
+                subscription__id,
+                secret,
+                previous_secret AS "previous_secret!",
+                previous_secret_expires_at AS "previous_secret_expires_at!"
+            FROM webhook.subscription
+            WHERE application__id = $1
+                AND subscription__id = $2
+                AND deleted_at IS NULL
+                AND previous_secret_expires_at > statement_timestamp()
+        "#,
+        &qs.application_id,
+        &subscription_id,
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(Hook0Problem::NotFound)?;
+
+    Ok(Json(rotation.into()))
+}
+
+#[api_v2_operation(
+    summary = "Rotate the secret of a subscription",
+    description = "Gives the subscription a new secret while keeping the previous one valid for an overlap window: until it expires, deliveries carry a signature for each secret, so that consumers verifying with either keep accepting them. Only one rotation can be in progress at a time; finalize or roll back the current one first.",
+    operation_id = "secretRotations.create",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn create(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+    body: Json<SecretRotationPost>,
+) -> Result<CreatedJson<SecretRotation>, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionEdit {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    if let Err(e) = body.validate() {
+        return Err(Hook0Problem::Validation(e));
+    }
+
+    // A previous secret whose window is over is not used anymore and can be replaced
+    let rotation = query_as!(
+        RawSecretRotation,
+        r#"
+            UPDATE webhook.subscription
+            SET previous_secret = secret,
+                secret = public.gen_random_uuid(),
+                previous_secret_expires_at = statement_timestamp() + make_interval(secs => $3),
+                updated_at = statement_timestamp()
+            WHERE application__id = $1
+                AND subscription__id = $2
+                AND deleted_at IS NULL
+                AND (previous_secret_expires_at IS NULL OR previous_secret_expires_at <= statement_timestamp())
+            RETURNING
+                subscription__id,
+                secret,
+                previous_secret AS "previous_secret!",
+                previous_secret_expires_at AS "previous_secret_expires_at!"
+        "#,
+        &qs.application_id,
+        &subscription_id,
+        f64::from(body.overlap_seconds.unwrap_or(DEFAULT_OVERLAP_SECONDS)),
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    match rotation {
+        Some(rotation) => Ok(CreatedJson(rotation.into())),
+        None if subscription_exists(&state.db, &qs.application_id, &subscription_id).await? => {
+            Err(Hook0Problem::SecretRotationInProgress)
+        }
+        None => Err(Hook0Problem::NotFound),
+    }
+}
+
+#[api_v2_operation(
+    summary = "Finalize the secret rotation of a subscription",
+    description = "Ends the rotation of the secret of the subscription before its overlap window is over: the previous secret stops being valid and deliveries are only signed with the new one.",
+    operation_id = "secretRotations.finalize",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn finalize(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<NoContent, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionEdit {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let finalized = query!(
+        "
+            UPDATE webhook.subscription
+            SET previous_secret = NULL,
+                previous_secret_expires_at = NULL,
+                updated_at = statement_timestamp()
+            WHERE application__id = $1
+                AND subscription__id = $2
+                AND deleted_at IS NULL
+                AND previous_secret_expires_at > statement_timestamp()
+        ",
+        &qs.application_id,
+        &subscription_id,
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    if finalized == 0 {
+        Err(Hook0Problem::NotFound)
+    } else {
+        Ok(NoContent)
+    }
+}
+
+#[api_v2_operation(
+    summary = "Roll back the secret rotation of a subscription",
+    description = "Cancels the rotation of the secret of the subscription that is in progress: the previous secret becomes the secret of the subscription again and the new one stops being valid.",
+    operation_id = "secretRotations.rollback",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Subscriptions Management", "sdk")
+)]
+pub async fn rollback(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    subscription_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<NoContent, Hook0Problem> {
+    let subscription_id = subscription_id.into_inner();
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SubscriptionEdit {
+            application_id: &qs.application_id,
+            subscription_id: &subscription_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let rolled_back = query!(
+        "
+            UPDATE webhook.subscription
+            SET secret = previous_secret,
+                previous_secret = NULL,
+                previous_secret_expires_at = NULL,
+                updated_at = statement_timestamp()
+            WHERE application__id = $1
+                AND subscription__id = $2
+                AND deleted_at IS NULL
+                AND previous_secret_expires_at > statement_timestamp()
+        ",
+        &qs.application_id,
+        &subscription_id,
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    if rolled_back == 0 {
+        Err(Hook0Problem::NotFound)
+    } else {
+        Ok(NoContent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_subscription,
+        seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    /// A rotation keeps the previous secret until it is finalized, can be rolled back to it, and
+    /// only one can be in progress at a time.
+    #[sqlx::test]
+    async fn secret_rotation_lifecycle(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, _) = seed_event(&pool, org).await;
+        let subscription_id =
+            seed_subscription(&pool, application_id, serde_json::json!({ "e2e": "1" })).await;
+        let original_secret: Uuid = sqlx::query_scalar(
+            "SELECT secret FROM webhook.subscription WHERE subscription__id = $1",
+        )
+        .bind(subscription_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app
+            .wrap(biscuit_auth)
+            .route(
+                "/subscriptions/{subscription_id}/secret_rotation",
+                web::get().to(super::get),
+            )
+            .route(
+                "/subscriptions/{subscription_id}/secret_rotation",
+                web::post().to(super::create),
+            )
+            .route(
+                "/subscriptions/{subscription_id}/secret_rotation/finalize",
+                web::post().to(super::finalize),
+            )
+            .route(
+                "/subscriptions/{subscription_id}/secret_rotation/rollback",
+                web::post().to(super::rollback),
+            ));
+        let uri = |action: &str| {
+            format!(
+                "/subscriptions/{subscription_id}/secret_rotation{action}?application_id={application_id}"
+            )
+        };
+        let rotate = |overlap_seconds: i32| {
+            test::TestRequest::post()
+                .uri(&uri(""))
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .set_json(serde_json::json!({ "overlap_seconds": overlap_seconds }))
+                .to_request()
+        };
+        let act = |action: &str| {
+            test::TestRequest::post()
+                .uri(&uri(action))
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .to_request()
+        };
+        let secrets = || async {
+            sqlx::query_as::<_, (Uuid, Option<Uuid>)>(
+                "SELECT secret, previous_secret FROM webhook.subscription WHERE subscription__id = $1",
+            )
+            .bind(subscription_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+        };
+
+        // An overlap window shorter than a minute is refused
+        let resp = test::call_service(&app, rotate(1)).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let resp = test::call_service(&app, rotate(3600)).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let rotation: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(rotation["previous_secret"], original_secret.to_string());
+        let rotated_secret = rotation["secret"].as_str().unwrap().to_owned();
+        assert_ne!(rotated_secret, original_secret.to_string());
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&uri(""))
+                .insert_header(("Authorization", format!("Bearer {user_token}")))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // A second rotation would make the first previous secret invalid at once
+        let resp = test::call_service(&app, rotate(3600)).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // Rolling back restores the original secret
+        let resp = test::call_service(&app, act("/rollback")).await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert_eq!(secrets().await, (original_secret, None));
+        let resp = test::call_service(&app, act("/rollback")).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        // Finalizing keeps the new secret only
+        let resp = test::call_service(&app, rotate(3600)).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let rotation: serde_json::Value = test::read_body_json(resp).await;
+        let rotated_secret = Uuid::parse_str(rotation["secret"].as_str().unwrap()).unwrap();
+        let resp = test::call_service(&app, act("/finalize")).await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert_eq!(secrets().await, (rotated_secret, None));
+
+        // A rotation whose window is over is not in progress anymore
+        let resp = test::call_service(&app, rotate(60)).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        sqlx::query(
+            "UPDATE webhook.subscription SET previous_secret_expires_at = statement_timestamp() - interval '1 second' WHERE subscription__id = $1",
+        )
+        .bind(subscription_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        let resp = test::call_service(&app, act("/finalize")).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = test::call_service(&app, rotate(60)).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+}
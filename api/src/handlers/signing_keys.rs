@@ -0,0 +1,424 @@
+use actix_web::web::ReqData;
+use aws_lc_rs::signature::{Ed25519KeyPair, KeyPair};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as Base64Url;
+use biscuit_auth::Biscuit;
+use chrono::{DateTime, Utc};
+use paperclip::actix::web::{Data, Json, Path, Query};
+use paperclip::actix::{Apiv2Schema, CreatedJson, NoContent, api_v2_operation};
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::iam::{Action, authorize_for_application};
+use crate::openapi::OaBiscuit;
+use crate::problems::Hook0Problem;
+
+/// Ed25519 key an application signs its deliveries with; its private key is never returned
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct SigningKey {
+    /// Identifies the key in the `k` part of signatures and as the `kid` of its JSON Web Key
+    pub signing_key_id: Uuid,
+    pub application_id: Uuid,
+    /// Public key, encoded in base64url without padding like the `x` member of its JSON Web Key
+    pub public_key: String,
+    /// Whether deliveries are signed with this key, which only the newest key of the application is
+    pub is_current: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[allow(non_snake_case)]
+struct RawSigningKey {
+    signing_key__id: Uuid,
+    application__id: Uuid,
+    public_key: Vec<u8>,
+    is_current: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl From<RawSigningKey> for SigningKey {
+    fn from(raw: RawSigningKey) -> Self {
+        Self {
+            signing_key_id: raw.signing_key__id,
+            application_id: raw.application__id,
+            public_key: Base64Url.encode(raw.public_key),
+            is_current: raw.is_current,
+            created_at: raw.created_at,
+        }
+    }
+}
+
+/// Public keys of an application, as a JSON Web Key Set (RFC 7517)
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct JsonWebKeySet {
+    pub keys: Vec<JsonWebKey>,
+}
+
+/// An Ed25519 public key, as a JSON Web Key (RFC 8037)
+#[derive(Debug, Serialize, Apiv2Schema)]
+pub struct JsonWebKey {
+    /// Always `OKP`
+    pub kty: String,
+    /// Always `Ed25519`
+    pub crv: String,
+    /// Always `EdDSA`
+    pub alg: String,
+    /// Always `sig`
+    #[serde(rename = "use")]
+    pub usage: String,
+    /// ID of the signing key, which signatures name in their `k` part
+    pub kid: String,
+    /// Public key, encoded in base64url without padding
+    pub x: String,
+}
+
+impl From<SigningKey> for JsonWebKey {
+    fn from(key: SigningKey) -> Self {
+        Self {
+            kty: "OKP".to_owned(),
+            crv: "Ed25519".to_owned(),
+            alg: "EdDSA".to_owned(),
+            usage: "sig".to_owned(),
+            kid: key.signing_key_id.to_string(),
+            x: key.public_key,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct Qs {
+    application_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, Apiv2Schema)]
+pub struct SigningKeyPost {
+    application_id: Uuid,
+}
+
+/// Signing keys of an application that are not retired, oldest first.
+async fn active_keys(
+    db: &sqlx::PgPool,
+    application_id: &Uuid,
+) -> Result<Vec<SigningKey>, Hook0Problem> {
+    let keys = query_as!(
+        RawSigningKey,
+        r#"
+            SELECT
+                signing_key__id,
+                application__id,
+                public_key,
+                created_at,
+                ROW_NUMBER() OVER (ORDER BY created_at DESC, signing_key__id DESC) = 1 AS "is_current!"
+            FROM event.application_signing_key
+            WHERE application__id = $1 AND retired_at IS NULL
+            ORDER BY created_at ASC, signing_key__id ASC
+        "#,
+        application_id,
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(keys.into_iter().map(SigningKey::from).collect())
+}
+
+#[api_v2_operation(
+    summary = "List signing keys",
+    description = "Lists the Ed25519 keys of an application that are not retired. The newest one signs deliveries; the others stay published so that deliveries they signed can still be verified.",
+    operation_id = "signingKeys.list",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Applications Management", "sdk")
+)]
+pub async fn list(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    qs: Query<Qs>,
+) -> Result<Json<Vec<SigningKey>>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SigningKeyList {
+            application_id: &qs.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    Ok(Json(active_keys(&state.db, &qs.application_id).await?))
+}
+
+#[api_v2_operation(
+    summary = "Create a signing key",
+    description = "Generates an Ed25519 key pair for an application. Deliveries are signed with it from then on, in addition to the signatures keyed with the secrets of subscriptions; the previous keys stay published until they are retired. Creating a key is how keys are rotated. The private key is stored encrypted and never returned.",
+    operation_id = "signingKeys.create",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Applications Management", "sdk")
+)]
+pub async fn create(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    body: Json<SigningKeyPost>,
+) -> Result<CreatedJson<SigningKey>, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SigningKeyCreate {
+            application_id: &body.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let encryption_key = state
+        .client_certificate_encryption_key
+        .as_ref()
+        .ok_or(Hook0Problem::SigningKeysDisabled)?;
+
+    let (public_key, private_key_pkcs8) = Ed25519KeyPair::generate()
+        .and_then(|pair| {
+            let pkcs8 = pair.to_pkcs8()?;
+            Ok((pair.public_key().as_ref().to_vec(), pkcs8.as_ref().to_vec()))
+        })
+        .map_err(|e| {
+            error!("Could not generate an Ed25519 key pair: {e}");
+            Hook0Problem::InternalServerError
+        })?;
+    let encrypted_private_key = encryption_key
+        .encrypt(&body.application_id, &private_key_pkcs8)
+        .map_err(|e| {
+            error!("Could not encrypt the private key of a signing key: {e}");
+            Hook0Problem::InternalServerError
+        })?;
+
+    let key = query_as!(
+        RawSigningKey,
+        r#"
+            INSERT INTO event.application_signing_key (application__id, public_key, encrypted_private_key)
+            VALUES ($1, $2, $3)
+            RETURNING signing_key__id, application__id, public_key, created_at, true AS "is_current!"
+        "#,
+        &body.application_id,
+        &public_key,
+        &encrypted_private_key,
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(CreatedJson(key.into()))
+}
+
+#[api_v2_operation(
+    summary = "Retire a signing key",
+    description = "Stops publishing a signing key, so that deliveries it signed are not verified anymore. When it was the newest key, deliveries are signed with the newest of the remaining ones, if any.",
+    operation_id = "signingKeys.delete",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Applications Management", "sdk")
+)]
+pub async fn delete(
+    state: Data<crate::State>,
+    _: OaBiscuit,
+    biscuit: ReqData<Biscuit>,
+    signing_key_id: Path<Uuid>,
+    qs: Query<Qs>,
+) -> Result<NoContent, Hook0Problem> {
+    authorize_for_application(
+        &state.db,
+        &biscuit,
+        Action::SigningKeyDelete {
+            application_id: &qs.application_id,
+        },
+        state.max_authorization_time,
+        state.debug_authorizer,
+    )
+    .await?;
+
+    let retired = query!(
+        "
+            UPDATE event.application_signing_key
+            SET retired_at = statement_timestamp()
+            WHERE application__id = $1 AND signing_key__id = $2 AND retired_at IS NULL
+        ",
+        &qs.application_id,
+        &signing_key_id.into_inner(),
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    if retired == 0 {
+        Err(Hook0Problem::NotFound)
+    } else {
+        Ok(NoContent)
+    }
+}
+
+#[api_v2_operation(
+    summary = "Get the public keys of an application",
+    description = "Publishes the public keys deliveries of an application are signed with, as a JSON Web Key Set, for consumers to verify the `v2` part of signatures. It needs no authentication. A signature naming a key that is not listed was either forged or signed by a key that was retired.",
+    operation_id = "signingKeys.jwks",
+    consumes = "application/json",
+    produces = "application/json",
+    tags("Applications Management", "sdk")
+)]
+pub async fn jwks(
+    state: Data<crate::State>,
+    application_id: Path<Uuid>,
+) -> Result<Json<JsonWebKeySet>, Hook0Problem> {
+    let application_id = application_id.into_inner();
+    let exists = query_scalar!(
+        r#"
+            SELECT EXISTS (
+                SELECT 1 FROM event.application
+                WHERE application__id = $1 AND deleted_at IS NULL
+            ) AS "exists!"
+        "#,
+        &application_id,
+    )
+    .fetch_one(&state.db)
+    .await?;
+    if !exists {
+        return Err(Hook0Problem::NotFound);
+    }
+
+    let keys = active_keys(&state.db, &application_id).await?;
+    Ok(Json(JsonWebKeySet {
+        keys: keys.into_iter().map(JsonWebKey::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::google_ads::test_support::{
+        init_test_app, issue_user_token, seed_event, seed_membership, seed_org, seed_user,
+    };
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use sqlx::PgPool;
+
+    /// A created key is published at the JWKS endpoint and signs from then on; creating another
+    /// one rotates it, and a retired key is not published anymore.
+    #[sqlx::test]
+    async fn signing_key_rotation(pool: PgPool) {
+        let keypair = biscuit_auth::KeyPair::new();
+        let private_key = keypair.private();
+
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        seed_membership(&pool, user, org, "editor").await;
+        let user_token = issue_user_token(&pool, &private_key, user, org, "editor").await;
+        let (application_id, _) = seed_event(&pool, org).await;
+
+        let app = init_test_app!(pool, private_key, |app, biscuit_auth| app
+            .route("/api/v1/jwks/{application_id}", web::get().to(super::jwks))
+            .service(
+                web::scope("/api/v1/signing_keys")
+                    .wrap(biscuit_auth)
+                    .route("", web::get().to(super::list))
+                    .route("", web::post().to(super::create))
+                    .route("/{signing_key_id}", web::delete().to(super::delete)),
+            ));
+        let authorization = ("Authorization", format!("Bearer {user_token}"));
+        let jwks_uri = format!("/api/v1/jwks/{application_id}");
+
+        let create = || {
+            test::TestRequest::post()
+                .uri("/api/v1/signing_keys")
+                .insert_header(authorization.clone())
+                .set_json(serde_json::json!({ "application_id": application_id }))
+                .to_request()
+        };
+        let resp = test::call_service(&app, create()).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let first: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(first["is_current"], true);
+        assert!(first.get("private_key_pkcs8").is_none());
+
+        let stored: Vec<u8> = sqlx::query_scalar(
+            "SELECT encrypted_private_key FROM event.application_signing_key WHERE application__id = $1",
+        )
+        .bind(application_id)
+        .fetch_one(&pool)
+        .await
+        .expect("stored signing key");
+        assert!(aws_lc_rs::signature::Ed25519KeyPair::from_pkcs8(&stored).is_err());
+
+        // The JWKS endpoint needs no authentication
+        let resp =
+            test::call_service(&app, test::TestRequest::get().uri(&jwks_uri).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let jwks: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(jwks["keys"].as_array().map(Vec::len), Some(1));
+        assert_eq!(jwks["keys"][0]["kid"], first["signing_key_id"]);
+        assert_eq!(jwks["keys"][0]["x"], first["public_key"]);
+        assert_eq!(jwks["keys"][0]["kty"], "OKP");
+        assert_eq!(jwks["keys"][0]["crv"], "Ed25519");
+        assert_eq!(jwks["keys"][0]["use"], "sig");
+
+        let resp = test::call_service(&app, create()).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let second: serde_json::Value = test::read_body_json(resp).await;
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&format!(
+                    "/api/v1/signing_keys?application_id={application_id}"
+                ))
+                .insert_header(authorization.clone())
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let keys: serde_json::Value = test::read_body_json(resp).await;
+        let current = keys
+            .as_array()
+            .expect("a list of keys")
+            .iter()
+            .map(|key| (key["signing_key_id"].clone(), key["is_current"].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            current,
+            vec![
+                (first["signing_key_id"].clone(), serde_json::json!(false)),
+                (second["signing_key_id"].clone(), serde_json::json!(true)),
+            ]
+        );
+
+        let retire_uri = format!(
+            "/api/v1/signing_keys/{}?application_id={application_id}",
+            first["signing_key_id"].as_str().expect("a key ID")
+        );
+        let retire = || {
+            test::TestRequest::delete()
+                .uri(&retire_uri)
+                .insert_header(authorization.clone())
+                .to_request()
+        };
+        let resp = test::call_service(&app, retire()).await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let resp = test::call_service(&app, retire()).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let resp =
+            test::call_service(&app, test::TestRequest::get().uri(&jwks_uri).to_request()).await;
+        let jwks: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(jwks["keys"].as_array().map(Vec::len), Some(1));
+        assert_eq!(jwks["keys"][0]["kid"], second["signing_key_id"]);
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&format!("/api/v1/jwks/{}", uuid::Uuid::new_v4()))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -37,6 +37,9 @@ pub const EVENT_TYPES: &[&str] = &[
     "api.subscription.created",
     "api.subscription.updated",
     "api.subscription.removed",
+    "api.subscription.disabled",
+    "api.subscription.dead_letters_exceeded",
+    "api.subscription.event_type_version_deprecated",
 ];
 
 pub fn initialize(
@@ -131,6 +134,9 @@ pub enum Hook0ClientEvent {
     SubscriptionCreated(EventSubscriptionCreated),
     SubscriptionUpdated(EventSubscriptionUpdated),
     SubscriptionRemoved(EventSubscriptionRemoved),
+    SubscriptionDisabled(EventSubscriptionDisabled),
+    SubscriptionDeadLettersExceeded(EventSubscriptionDeadLettersExceeded),
+    SubscriptionEventTypeVersionDeprecated(EventSubscriptionEventTypeVersionDeprecated),
 }
 
 impl Hook0ClientEvent {
@@ -181,6 +187,11 @@ impl Hook0ClientEvent {
             }
             Self::SubscriptionUpdated(e) => to_event(e, None),
             Self::SubscriptionRemoved(e) => to_event(e, None),
+            Self::SubscriptionDisabled(e @ EventSubscriptionDisabled { disabled_at, .. }) => {
+                to_event(e, Some(disabled_at))
+            }
+            Self::SubscriptionDeadLettersExceeded(e) => to_event(e, None),
+            Self::SubscriptionEventTypeVersionDeprecated(e) => to_event(e, None),
         }
     }
 }
@@ -804,3 +815,110 @@ impl From<EventSubscriptionRemoved> for Hook0ClientEvent {
         Self::SubscriptionRemoved(e)
     }
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventSubscriptionDisabled {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub subscription_id: Uuid,
+    pub disabled_at: DateTime<Utc>,
+    pub reason: String,
+}
+
+impl Event for EventSubscriptionDisabled {
+    fn event_type(&self) -> &'static str {
+        "api.subscription.disabled"
+    }
+
+    fn labels(&self) -> Vec<(String, String)> {
+        vec![
+            (INSTANCE_LABEL.to_owned(), INSTANCE_VALUE.to_owned()),
+            (
+                ORGANIZATION_LABEL.to_owned(),
+                self.organization_id.to_string(),
+            ),
+            (
+                APPLICATION_LABEL.to_owned(),
+                self.application_id.to_string(),
+            ),
+        ]
+    }
+}
+
+impl From<EventSubscriptionDisabled> for Hook0ClientEvent {
+    fn from(e: EventSubscriptionDisabled) -> Self {
+        Self::SubscriptionDisabled(e)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventSubscriptionDeadLettersExceeded {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub subscription_id: Uuid,
+    pub dead_letter_count: i64,
+    pub threshold: i64,
+}
+
+impl Event for EventSubscriptionDeadLettersExceeded {
+    fn event_type(&self) -> &'static str {
+        "api.subscription.dead_letters_exceeded"
+    }
+
+    fn labels(&self) -> Vec<(String, String)> {
+        vec![
+            (INSTANCE_LABEL.to_owned(), INSTANCE_VALUE.to_owned()),
+            (
+                ORGANIZATION_LABEL.to_owned(),
+                self.organization_id.to_string(),
+            ),
+            (
+                APPLICATION_LABEL.to_owned(),
+                self.application_id.to_string(),
+            ),
+        ]
+    }
+}
+
+impl From<EventSubscriptionDeadLettersExceeded> for Hook0ClientEvent {
+    fn from(e: EventSubscriptionDeadLettersExceeded) -> Self {
+        Self::SubscriptionDeadLettersExceeded(e)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventSubscriptionEventTypeVersionDeprecated {
+    pub organization_id: Uuid,
+    pub application_id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_type_name: String,
+    pub event_type_version: i32,
+    pub deprecated_at: DateTime<Utc>,
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
+impl Event for EventSubscriptionEventTypeVersionDeprecated {
+    fn event_type(&self) -> &'static str {
+        "api.subscription.event_type_version_deprecated"
+    }
+
+    fn labels(&self) -> Vec<(String, String)> {
+        vec![
+            (INSTANCE_LABEL.to_owned(), INSTANCE_VALUE.to_owned()),
+            (
+                ORGANIZATION_LABEL.to_owned(),
+                self.organization_id.to_string(),
+            ),
+            (
+                APPLICATION_LABEL.to_owned(),
+                self.application_id.to_string(),
+            ),
+        ]
+    }
+}
+
+impl From<EventSubscriptionEventTypeVersionDeprecated> for Hook0ClientEvent {
+    fn from(e: EventSubscriptionEventTypeVersionDeprecated) -> Self {
+        Self::SubscriptionEventTypeVersionDeprecated(e)
+    }
+}
@@ -470,6 +470,16 @@ pub enum Action<'a> {
         application_id: &'a Uuid,
     },
     //
+    SigningKeyList {
+        application_id: &'a Uuid,
+    },
+    SigningKeyCreate {
+        application_id: &'a Uuid,
+    },
+    SigningKeyDelete {
+        application_id: &'a Uuid,
+    },
+    //
     EventTypeList {
         application_id: &'a Uuid,
     },
@@ -479,6 +489,9 @@ pub enum Action<'a> {
     EventTypeGet {
         application_id: &'a Uuid,
     },
+    EventTypeEdit {
+        application_id: &'a Uuid,
+    },
     EventTypeDelete {
         application_id: &'a Uuid,
     },
@@ -503,6 +516,19 @@ pub enum Action<'a> {
         subscription_id: &'a Uuid,
     },
     //
+    InboxPull {
+        application_id: &'a Uuid,
+        subscription_id: &'a Uuid,
+    },
+    InboxAck {
+        application_id: &'a Uuid,
+        subscription_id: &'a Uuid,
+    },
+    InboxNack {
+        application_id: &'a Uuid,
+        subscription_id: &'a Uuid,
+    },
+    //
     EventList {
         application_id: &'a Uuid,
     },
@@ -515,6 +541,9 @@ pub enum Action<'a> {
     EventReplay {
         application_id: &'a Uuid,
     },
+    EventCancel {
+        application_id: &'a Uuid,
+    },
     //
     RequestAttemptList {
         application_id: &'a Uuid,
@@ -523,6 +552,36 @@ pub enum Action<'a> {
     RequestAttemptGet {
         application_id: &'a Uuid,
     },
+    RequestAttemptRetry {
+        application_id: &'a Uuid,
+    },
+    //
+    DeadLetterList {
+        application_id: &'a Uuid,
+    },
+    DeadLetterGet {
+        application_id: &'a Uuid,
+    },
+    DeadLetterRedeliver {
+        application_id: &'a Uuid,
+    },
+    DeadLetterPurge {
+        application_id: &'a Uuid,
+    },
+    //
+    BulkRetryList {
+        application_id: &'a Uuid,
+    },
+    BulkRetryCreate {
+        application_id: &'a Uuid,
+        event_type_names: &'a [String],
+    },
+    BulkRetryGet {
+        application_id: &'a Uuid,
+    },
+    BulkRetryCancel {
+        application_id: &'a Uuid,
+    },
     //
     ResponseGet {
         application_id: &'a Uuid,
@@ -577,9 +636,14 @@ impl Action<'_> {
             #[cfg(feature = "application-secret-compatibility")]
             Self::ApplicationSecretDelete { .. } => "application_secret:delete",
             //
+            Self::SigningKeyList { .. } => "signing_key:list",
+            Self::SigningKeyCreate { .. } => "signing_key:create",
+            Self::SigningKeyDelete { .. } => "signing_key:delete",
+            //
             Self::EventTypeList { .. } => "event_type:list",
             Self::EventTypeCreate { .. } => "event_type:create",
             Self::EventTypeGet { .. } => "event_type:get",
+            Self::EventTypeEdit { .. } => "event_type:edit",
             Self::EventTypeDelete { .. } => "event_type:delete",
             //
             Self::SubscriptionList { .. } => "subscription:list",
@@ -588,13 +652,29 @@ impl Action<'_> {
             Self::SubscriptionEdit { .. } => "subscription:edit",
             Self::SubscriptionDelete { .. } => "subscription:delete",
             //
+            Self::InboxPull { .. } => "inbox:pull",
+            Self::InboxAck { .. } => "inbox:ack",
+            Self::InboxNack { .. } => "inbox:nack",
+            //
             Self::EventList { .. } => "event:list",
             Self::EventGet { .. } => "event:get",
             Self::EventIngest { .. } => "event:ingest",
             Self::EventReplay { .. } => "event:replay",
+            Self::EventCancel { .. } => "event:cancel",
             //
             Self::RequestAttemptList { .. } => "request_attempt:list",
             Self::RequestAttemptGet { .. } => "request_attempt:get",
+            Self::RequestAttemptRetry { .. } => "request_attempt:retry",
+            //
+            Self::DeadLetterList { .. } => "dead_letter:list",
+            Self::DeadLetterGet { .. } => "dead_letter:get",
+            Self::DeadLetterRedeliver { .. } => "dead_letter:redeliver",
+            Self::DeadLetterPurge { .. } => "dead_letter:purge",
+            //
+            Self::BulkRetryList { .. } => "bulk_retry:list",
+            Self::BulkRetryCreate { .. } => "bulk_retry:create",
+            Self::BulkRetryGet { .. } => "bulk_retry:get",
+            Self::BulkRetryCancel { .. } => "bulk_retry:cancel",
             //
             Self::ResponseGet { .. } => "response:get",
             //
@@ -647,9 +727,14 @@ impl Action<'_> {
             #[cfg(feature = "application-secret-compatibility")]
             Self::ApplicationSecretDelete { .. } => vec![],
             //
+            Self::SigningKeyList { .. } => vec![Role::Viewer],
+            Self::SigningKeyCreate { .. } => vec![],
+            Self::SigningKeyDelete { .. } => vec![],
+            //
             Self::EventTypeList { .. } => vec![Role::Viewer],
             Self::EventTypeCreate { .. } => vec![],
             Self::EventTypeGet { .. } => vec![Role::Viewer],
+            Self::EventTypeEdit { .. } => vec![],
             Self::EventTypeDelete { .. } => vec![],
             //
             Self::SubscriptionList { .. } => vec![Role::Viewer],
@@ -658,13 +743,29 @@ impl Action<'_> {
             Self::SubscriptionEdit { .. } => vec![],
             Self::SubscriptionDelete { .. } => vec![],
             //
+            Self::InboxPull { .. } => vec![],
+            Self::InboxAck { .. } => vec![],
+            Self::InboxNack { .. } => vec![],
+            //
             Self::EventList { .. } => vec![Role::Viewer],
             Self::EventGet { .. } => vec![Role::Viewer],
             Self::EventIngest { .. } => vec![],
             Self::EventReplay { .. } => vec![],
+            Self::EventCancel { .. } => vec![],
             //
             Self::RequestAttemptList { .. } => vec![Role::Viewer],
             Self::RequestAttemptGet { .. } => vec![Role::Viewer],
+            Self::RequestAttemptRetry { .. } => vec![],
+            //
+            Self::DeadLetterList { .. } => vec![Role::Viewer],
+            Self::DeadLetterGet { .. } => vec![Role::Viewer],
+            Self::DeadLetterRedeliver { .. } => vec![],
+            Self::DeadLetterPurge { .. } => vec![],
+            //
+            Self::BulkRetryList { .. } => vec![Role::Viewer],
+            Self::BulkRetryCreate { .. } => vec![],
+            Self::BulkRetryGet { .. } => vec![Role::Viewer],
+            Self::BulkRetryCancel { .. } => vec![],
             //
             Self::ResponseGet { .. } => vec![Role::Viewer],
             //
@@ -733,9 +834,14 @@ impl Action<'_> {
             #[cfg(feature = "application-secret-compatibility")]
             Self::ApplicationSecretDelete { application_id, .. } => Some(**application_id),
             //
+            Self::SigningKeyList { application_id, .. } => Some(**application_id),
+            Self::SigningKeyCreate { application_id, .. } => Some(**application_id),
+            Self::SigningKeyDelete { application_id, .. } => Some(**application_id),
+            //
             Self::EventTypeList { application_id, .. } => Some(**application_id),
             Self::EventTypeCreate { application_id, .. } => Some(**application_id),
             Self::EventTypeGet { application_id, .. } => Some(**application_id),
+            Self::EventTypeEdit { application_id, .. } => Some(**application_id),
             Self::EventTypeDelete { application_id, .. } => Some(**application_id),
             //
             Self::SubscriptionList { application_id, .. } => Some(**application_id),
@@ -744,13 +850,29 @@ impl Action<'_> {
             Self::SubscriptionEdit { application_id, .. } => Some(**application_id),
             Self::SubscriptionDelete { application_id, .. } => Some(**application_id),
             //
+            Self::InboxPull { application_id, .. } => Some(**application_id),
+            Self::InboxAck { application_id, .. } => Some(**application_id),
+            Self::InboxNack { application_id, .. } => Some(**application_id),
+            //
             Self::EventList { application_id, .. } => Some(**application_id),
             Self::EventGet { application_id, .. } => Some(**application_id),
             Self::EventIngest { application_id, .. } => Some(**application_id),
             Self::EventReplay { application_id, .. } => Some(**application_id),
+            Self::EventCancel { application_id, .. } => Some(**application_id),
             //
             Self::RequestAttemptList { application_id, .. } => Some(**application_id),
             Self::RequestAttemptGet { application_id, .. } => Some(**application_id),
+            Self::RequestAttemptRetry { application_id, .. } => Some(**application_id),
+            //
+            Self::DeadLetterList { application_id, .. } => Some(**application_id),
+            Self::DeadLetterGet { application_id, .. } => Some(**application_id),
+            Self::DeadLetterRedeliver { application_id, .. } => Some(**application_id),
+            Self::DeadLetterPurge { application_id, .. } => Some(**application_id),
+            //
+            Self::BulkRetryList { application_id, .. } => Some(**application_id),
+            Self::BulkRetryCreate { application_id, .. } => Some(**application_id),
+            Self::BulkRetryGet { application_id, .. } => Some(**application_id),
+            Self::BulkRetryCancel { application_id, .. } => Some(**application_id),
             //
             Self::ResponseGet { application_id, .. } => Some(**application_id),
             //
@@ -807,9 +929,14 @@ impl Action<'_> {
             #[cfg(feature = "application-secret-compatibility")]
             Self::ApplicationSecretDelete { .. } => vec![],
             //
+            Self::SigningKeyList { .. } => vec![],
+            Self::SigningKeyCreate { .. } => vec![],
+            Self::SigningKeyDelete { .. } => vec![],
+            //
             Self::EventTypeList { .. } => vec![],
             Self::EventTypeCreate { .. } => vec![],
             Self::EventTypeGet { .. } => vec![],
+            Self::EventTypeEdit { .. } => vec![],
             Self::EventTypeDelete { .. } => vec![],
             //
             Self::SubscriptionList { .. } => vec![],
@@ -838,10 +965,30 @@ impl Action<'_> {
                 subscription_id = *subscription_id
             )],
             //
+            Self::InboxPull {
+                subscription_id, ..
+            } => vec![fact!(
+                "subscription_id({subscription_id})",
+                subscription_id = *subscription_id
+            )],
+            Self::InboxAck {
+                subscription_id, ..
+            } => vec![fact!(
+                "subscription_id({subscription_id})",
+                subscription_id = *subscription_id
+            )],
+            Self::InboxNack {
+                subscription_id, ..
+            } => vec![fact!(
+                "subscription_id({subscription_id})",
+                subscription_id = *subscription_id
+            )],
+            //
             Self::EventList { .. } => vec![],
             Self::EventGet { .. } => vec![],
             Self::EventIngest { .. } => vec![],
             Self::EventReplay { .. } => vec![],
+            Self::EventCancel { .. } => vec![],
             //
             Self::RequestAttemptList {
                 event_type_names, ..
@@ -850,6 +997,22 @@ impl Action<'_> {
                 event_type_names,
             )],
             Self::RequestAttemptGet { .. } => vec![],
+            Self::RequestAttemptRetry { .. } => vec![],
+            //
+            Self::DeadLetterList { .. } => vec![],
+            Self::DeadLetterGet { .. } => vec![],
+            Self::DeadLetterRedeliver { .. } => vec![],
+            Self::DeadLetterPurge { .. } => vec![],
+            //
+            Self::BulkRetryList { .. } => vec![],
+            Self::BulkRetryCreate {
+                event_type_names, ..
+            } => vec![Self::mk_string_set_fact(
+                "event_type_names",
+                event_type_names,
+            )],
+            Self::BulkRetryGet { .. } => vec![],
+            Self::BulkRetryCancel { .. } => vec![],
             //
             Self::ResponseGet { .. } => vec![],
             //
@@ -90,6 +90,27 @@ pub enum Mail {
         discord_url: Url,
         unsubscribe_url: Url,
     },
+    /// A subscription's circuit breaker or one of its response rules disabled it. The link to the
+    /// subscription is injected through `extra_variables`, like the quota
+    /// dashboard link.
+    SubscriptionDisabled {
+        recipient_first_name: Option<String>,
+        application_name: String,
+        subscription_name: String,
+        reason: String,
+        extra_variables: Vec<(String, String)>,
+    },
+    /// The client certificate a subscription presents to its target
+    /// expires soon. The link to the subscription is injected through
+    /// `extra_variables`.
+    ClientCertificateExpiring {
+        recipient_first_name: Option<String>,
+        application_name: String,
+        subscription_name: String,
+        certificate_subject: String,
+        expires_at: String,
+        extra_variables: Vec<(String, String)>,
+    },
 }
 
 // Design tokens — sourced from www.hook0.com brand:
@@ -278,6 +299,12 @@ impl Mail {
             Mail::ReactivationNoEventDay7 { .. } => {
                 include_str!("mail_templates/reactivation/no_event_day7.mjml")
             }
+            Mail::SubscriptionDisabled { .. } => {
+                include_str!("mail_templates/subscription_disabled.mjml")
+            }
+            Mail::ClientCertificateExpiring { .. } => {
+                include_str!("mail_templates/client_certificate_expiring.mjml")
+            }
         }
     }
 
@@ -302,6 +329,12 @@ impl Mail {
             Mail::ReactivationNoEventDay7 { .. } => {
                 "Last nudge: still no event on your Hook0 account".to_owned()
             }
+            Mail::SubscriptionDisabled {
+                subscription_name, ..
+            } => format!("Subscription disabled: {subscription_name}"),
+            Mail::ClientCertificateExpiring {
+                subscription_name, ..
+            } => format!("Client certificate expiring soon: {subscription_name}"),
         }
     }
 
@@ -331,6 +364,12 @@ impl Mail {
             Mail::ReactivationNoEventDay7 { .. } => {
                 "One event is all it takes. Ask the community or reply and a human will help."
             }
+            Mail::SubscriptionDisabled { .. } => {
+                "Its deliveries failed. Fix the endpoint, then enable it again."
+            }
+            Mail::ClientCertificateExpiring { .. } => {
+                "Upload the renewed certificate before it expires to keep deliveries flowing."
+            }
         }
     }
 
@@ -346,6 +385,8 @@ impl Mail {
             Mail::ReactivationNoEventDay1 { .. } => "reactivation_no_event_d1",
             Mail::ReactivationNoEventDay3 { .. } => "reactivation_no_event_d3",
             Mail::ReactivationNoEventDay7 { .. } => "reactivation_no_event_d7",
+            Mail::SubscriptionDisabled { .. } => "subscription_disabled",
+            Mail::ClientCertificateExpiring { .. } => "client_certificate_expiring",
         }
     }
 
@@ -406,6 +447,14 @@ impl Mail {
             | Mail::ReactivationNoEventDay7 {
                 recipient_first_name,
                 ..
+            }
+            | Mail::SubscriptionDisabled {
+                recipient_first_name,
+                ..
+            }
+            | Mail::ClientCertificateExpiring {
+                recipient_first_name,
+                ..
             } => recipient_first_name.as_deref(),
         }
     }
@@ -464,6 +513,41 @@ impl Mail {
                 vars.extend(extra_variables.clone());
                 vars
             }
+            Mail::SubscriptionDisabled {
+                application_name,
+                subscription_name,
+                reason,
+                extra_variables,
+                ..
+            } => {
+                let mut vars = vec![
+                    ("application_name".to_owned(), application_name.to_owned()),
+                    ("subscription_name".to_owned(), subscription_name.to_owned()),
+                    ("reason".to_owned(), reason.to_owned()),
+                ];
+                vars.extend(extra_variables.clone());
+                vars
+            }
+            Mail::ClientCertificateExpiring {
+                application_name,
+                subscription_name,
+                certificate_subject,
+                expires_at,
+                extra_variables,
+                ..
+            } => {
+                let mut vars = vec![
+                    ("application_name".to_owned(), application_name.to_owned()),
+                    ("subscription_name".to_owned(), subscription_name.to_owned()),
+                    (
+                        "certificate_subject".to_owned(),
+                        certificate_subject.to_owned(),
+                    ),
+                    ("expires_at".to_owned(), expires_at.to_owned()),
+                ];
+                vars.extend(extra_variables.clone());
+                vars
+            }
         }
     }
 
@@ -482,6 +566,8 @@ impl Mail {
             Mail::ReactivationNoEventDay7 { discord_url, .. } => {
                 vec![("discord_url_tracked".to_owned(), discord_url.clone())]
             }
+            Mail::SubscriptionDisabled { .. } => vec![],
+            Mail::ClientCertificateExpiring { .. } => vec![],
         }
     }
 
@@ -513,6 +599,14 @@ impl Mail {
             } => {
                 extra_variables.push((key, value));
             }
+            Mail::SubscriptionDisabled {
+                extra_variables, ..
+            }
+            | Mail::ClientCertificateExpiring {
+                extra_variables, ..
+            } => {
+                extra_variables.push((key, value));
+            }
             _ => {}
         }
     }
@@ -1040,6 +1134,29 @@ mod tests {
             "dashboard_url_tracked".to_owned(),
             "https://app.hook0.com/organizations/x/dashboard?mtm_source=email&mtm_medium=transactional&mtm_campaign=quota_reached".to_owned(),
         );
+        let mut subscription_disabled = Mail::SubscriptionDisabled {
+            recipient_first_name: Some("Sarah".to_owned()),
+            application_name: "Billing".to_owned(),
+            subscription_name: "https://example.com/webhooks".to_owned(),
+            reason: "10 consecutive failed deliveries (limit is 10)".to_owned(),
+            extra_variables: vec![],
+        };
+        subscription_disabled.add_variable(
+            "subscription_url_tracked".to_owned(),
+            "https://app.hook0.com/organizations/x/applications/y/subscriptions/z?mtm_source=email&mtm_medium=transactional&mtm_campaign=subscription_disabled".to_owned(),
+        );
+        let mut client_certificate_expiring = Mail::ClientCertificateExpiring {
+            recipient_first_name: Some("Sarah".to_owned()),
+            application_name: "Billing".to_owned(),
+            subscription_name: "https://bank.example.com/webhooks".to_owned(),
+            certificate_subject: "CN=hook0.example.com".to_owned(),
+            expires_at: "2026-11-16".to_owned(),
+            extra_variables: vec![],
+        };
+        client_certificate_expiring.add_variable(
+            "subscription_url_tracked".to_owned(),
+            "https://app.hook0.com/organizations/x/applications/y/subscriptions/z?mtm_source=email&mtm_medium=transactional&mtm_campaign=client_certificate_expiring".to_owned(),
+        );
 
         vec![
             Mail::VerifyUserEmail {
@@ -1069,6 +1186,8 @@ mod tests {
                 discord_url: Url::from_str("https://www.hook0.com/community").unwrap(),
                 unsubscribe_url: unsubscribe_url_fixture(),
             },
+            subscription_disabled,
+            client_certificate_expiring,
         ]
     }
 
@@ -1714,6 +1833,54 @@ mod tests {
         }
     }
 
+    /// The subscription-disabled mail names what was disabled, why, and links
+    /// to the subscription; markup in those user-provided names stays text.
+    #[test]
+    fn subscription_disabled_names_the_subscription_and_reason() {
+        let mut mail = Mail::SubscriptionDisabled {
+            recipient_first_name: Some("Sarah".to_owned()),
+            application_name: "Billing <b>prod</b>".to_owned(),
+            subscription_name: "Invoices & payments".to_owned(),
+            reason: "10 consecutive failed deliveries (limit is 10)".to_owned(),
+            extra_variables: vec![],
+        };
+        mail.add_variable(
+            "subscription_url_tracked".to_owned(),
+            "https://app.hook0.com/organizations/x/applications/y/subscriptions/z".to_owned(),
+        );
+        let html = render(&mail);
+        assert!(html.contains("Invoices &amp; payments"));
+        assert!(html.contains("Billing &lt;b&gt;prod&lt;/b&gt;"));
+        assert!(html.contains("10 consecutive failed deliveries"));
+        assert!(html.contains("/applications/y/subscriptions/z"));
+        assert!(!mail.has_commercial_component());
+        assert!(mail.unsubscribe_url().is_none());
+    }
+
+    /// The client-certificate mail names the subscription, the certificate
+    /// and when it expires, and links to the subscription.
+    #[test]
+    fn client_certificate_expiring_names_the_certificate_and_its_expiry() {
+        let mut mail = Mail::ClientCertificateExpiring {
+            recipient_first_name: Some("Sarah".to_owned()),
+            application_name: "Billing".to_owned(),
+            subscription_name: "Bank & co".to_owned(),
+            certificate_subject: "CN=hook0.example.com".to_owned(),
+            expires_at: "2026-11-16".to_owned(),
+            extra_variables: vec![],
+        };
+        mail.add_variable(
+            "subscription_url_tracked".to_owned(),
+            "https://app.hook0.com/organizations/x/applications/y/subscriptions/z".to_owned(),
+        );
+        let html = render(&mail);
+        assert!(html.contains("Bank &amp; co"));
+        assert!(html.contains("CN=hook0.example.com"));
+        assert!(html.contains("2026-11-16"));
+        assert!(html.contains("/applications/y/subscriptions/z"));
+        assert!(!mail.has_commercial_component());
+    }
+
     /// Test #17 — a recipient name carrying *malformed* markup must still
     /// render. MRML parses the MJML as XML, so before escaping a legitimate
     /// name such as `A & B` or an unbalanced tag aborted the render, and
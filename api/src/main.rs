@@ -24,8 +24,14 @@ use url::Url;
 use uuid::Uuid;
 
 mod app;
+mod auto_disabled_subscriptions;
+mod bulk_retries;
+mod client_certificate_expiry;
+mod client_certificates;
 mod client_options;
 mod cloudflare_turnstile;
+mod dead_letters;
+mod expired_events;
 mod expired_tokens_cleanup;
 mod extractor_user_ip;
 mod google_ads;
@@ -53,6 +59,7 @@ mod reactivation_emails;
 mod signup_attribution_cleanup;
 mod signup_channel;
 mod soft_deleted_applications_cleanup;
+mod subscription_filter;
 mod unverified_users_cleanup;
 mod validators;
 
@@ -506,6 +513,50 @@ struct Config {
     #[clap(long, env, value_parser = humantime::parse_duration, default_value = "30d")]
     soft_deleted_applications_cleanup_grace_period: Duration,
 
+    /// [Bulk Retry] Duration to wait between two batches of request attempts created by bulk retries; set to 0 to disable the task (bulk retries are then left pending)
+    #[clap(long, env, value_parser = humantime::parse_duration, default_value = "1s")]
+    bulk_retry_period: Duration,
+
+    /// [Bulk Retry] Maximum number of request attempts a batch of a bulk retry creates; with the period, this caps how fast bulk retries feed the workers
+    #[clap(long, env, value_parser = clap::value_parser!(u16).range(1..), default_value_t = 100)]
+    bulk_retry_batch_size: u16,
+
+    /// [Circuit Breaker] Duration to wait between two checks for subscriptions disabled by their circuit breaker (their pending request attempts are then cancelled and their organization notified)
+    #[clap(long, env, value_parser = humantime::parse_duration, default_value = "1m")]
+    circuit_breaker_follow_up_period: Duration,
+
+    /// [Dead Letters] Number of dead letters from which a subscription is reported with an `api.subscription.dead_letters_exceeded` event
+    #[clap(long, env, value_parser = clap::value_parser!(u32).range(1..), default_value_t = 100)]
+    dead_letter_threshold: u32,
+
+    /// [Dead Letters] Duration to wait between two checks for subscriptions over the dead letter threshold; set to 0 to disable the task
+    #[clap(long, env, value_parser = humantime::parse_duration, default_value = "1m")]
+    dead_letter_check_period: Duration,
+
+    /// [Event Expiry] Duration to wait between two checks for expired events (their pending and waiting request attempts are then cancelled); set to 0 to disable the task
+    #[clap(long, env, value_parser = humantime::parse_duration, default_value = "30s")]
+    event_expiry_check_period: Duration,
+
+    /// [Retry Policy] Maximum number of retries the retry policy of a subscription can ask for
+    #[clap(long, env, default_value_t = 24)]
+    max_subscription_retries: u8,
+
+    /// [Retry Policy] Maximum time window the retry policy of a subscription can span; it also bounds each of its backoff steps
+    #[clap(long, env, value_parser = humantime::parse_duration, default_value = "8d")]
+    max_subscription_retry_window: Duration,
+
+    /// [Client Certificates] Key the private keys of client certificates and signing keys are encrypted with: 32 bytes encoded in base64, for example the output of `openssl rand -base64 32`; output workers must be given the same one. Without it, client certificates cannot be uploaded and signing keys cannot be created
+    #[clap(long, env, hide_env_values = true, value_parser = client_certificates::parse_client_certificate_key)]
+    client_certificate_encryption_key: Option<client_certificates::ClientCertificateKey>,
+
+    /// [Client Certificates] How long before a client certificate expires the editors of its organization are warned by email
+    #[clap(long, env, value_parser = humantime::parse_duration, default_value = "30d")]
+    client_certificate_expiry_warning: Duration,
+
+    /// [Client Certificates] Duration to wait between two checks for client certificates about to expire
+    #[clap(long, env, value_parser = humantime::parse_duration, default_value = "1h")]
+    client_certificate_expiry_check_period: Duration,
+
     /// [Web Server] If true, the secured HTTP headers will be enabled
     #[clap(long, env, default_value = "true")]
     enable_security_headers: bool,
@@ -895,6 +946,10 @@ pub struct State {
     /// safety net). Kept in range [1, 3650] by the CLI parser, so it always fits
     /// `i32` for the `MAKE_INTERVAL(days => …)` argument.
     signup_attribution_retention_in_days: i32,
+    max_subscription_retries: u8,
+    max_subscription_retry_window: Duration,
+    client_certificate_encryption_key: Option<client_certificates::ClientCertificateKey>,
+    payload_schemas: Arc<handlers::event_types::PayloadSchemaCache>,
 }
 
 #[derive(Clone)]
@@ -1495,6 +1550,27 @@ async fn main() -> anyhow::Result<()> {
             });
         }
 
+        // Spawn task to process bulk retries (disabled when the period is 0)
+        if !config.bulk_retry_period.is_zero() {
+            let bulk_retries_db = housekeeping_pool.clone();
+            let bulk_retries_semaphore = housekeeping_semaphore.clone();
+            let bulk_retries_pulsar = pulsar_config.clone();
+            let bulk_retries_object_storage = object_storage_config.clone();
+            actix_web::rt::spawn(async move {
+                bulk_retries::periodically_process_bulk_retries(
+                    &bulk_retries_semaphore,
+                    &bulk_retries_db,
+                    bulk_retries_pulsar,
+                    bulk_retries_object_storage,
+                    config.bulk_retry_period,
+                    config.bulk_retry_batch_size,
+                )
+                .await;
+            });
+        } else {
+            info!("Bulk retries are disabled (BULK_RETRY_PERIOD = 0)");
+        }
+
         // Create Mailer
         let smtp_config = mailer::MailerSmtpConfig {
             smtp_connection_url: config.smtp_connection_url,
@@ -1545,6 +1621,69 @@ async fn main() -> anyhow::Result<()> {
             });
         }
 
+        // Spawn task to follow up subscriptions disabled by their circuit breaker
+        // No housekeeping semaphore here because the notification must not wait for a long cleanup to complete
+        let auto_disabled_subscriptions_db = housekeeping_pool.clone();
+        let auto_disabled_subscriptions_mailer = mailer.clone();
+        let auto_disabled_subscriptions_hook0_client = hook0_client.clone();
+        actix_web::rt::spawn(async move {
+            auto_disabled_subscriptions::periodically_follow_up_auto_disabled_subscriptions(
+                &auto_disabled_subscriptions_db,
+                auto_disabled_subscriptions_mailer,
+                auto_disabled_subscriptions_hook0_client,
+                config.circuit_breaker_follow_up_period,
+            )
+            .await;
+        });
+
+        // Spawn task to report subscriptions piling up dead letters (disabled when the period is 0)
+        if !config.dead_letter_check_period.is_zero() {
+            let dead_letters_db = housekeeping_pool.clone();
+            let dead_letters_semaphore = housekeeping_semaphore.clone();
+            let dead_letters_hook0_client = hook0_client.clone();
+            actix_web::rt::spawn(async move {
+                dead_letters::periodically_check_dead_letters(
+                    &dead_letters_semaphore,
+                    &dead_letters_db,
+                    dead_letters_hook0_client,
+                    config.dead_letter_threshold,
+                    config.dead_letter_check_period,
+                )
+                .await;
+            });
+        } else {
+            info!("Dead letters check is disabled (DEAD_LETTER_CHECK_PERIOD = 0)");
+        }
+
+        // Spawn task to cancel the deliveries of expired events (disabled when the period is 0)
+        if !config.event_expiry_check_period.is_zero() {
+            let expired_events_db = housekeeping_pool.clone();
+            let expired_events_semaphore = housekeeping_semaphore.clone();
+            actix_web::rt::spawn(async move {
+                expired_events::periodically_cancel_expired_request_attempts(
+                    &expired_events_semaphore,
+                    &expired_events_db,
+                    config.event_expiry_check_period,
+                )
+                .await;
+            });
+        } else {
+            info!("Event expiry check is disabled (EVENT_EXPIRY_CHECK_PERIOD = 0)");
+        }
+
+        // Spawn task to warn organizations about client certificates about to expire
+        let client_certificate_expiry_db = housekeeping_pool.clone();
+        let client_certificate_expiry_mailer = mailer.clone();
+        actix_web::rt::spawn(async move {
+            client_certificate_expiry::periodically_warn_about_expiring_client_certificates(
+                &client_certificate_expiry_db,
+                client_certificate_expiry_mailer,
+                config.client_certificate_expiry_warning,
+                config.client_certificate_expiry_check_period,
+            )
+            .await;
+        });
+
         // Initialize state
         let initial_state = State {
             db: pool,
@@ -1598,6 +1737,10 @@ async fn main() -> anyhow::Result<()> {
                 config.signup_attribution_retention_in_days,
             )
             .unwrap_or(30),
+            max_subscription_retries: config.max_subscription_retries,
+            max_subscription_retry_window: config.max_subscription_retry_window,
+            client_certificate_encryption_key: config.client_certificate_encryption_key,
+            payload_schemas: Arc::new(handlers::event_types::PayloadSchemaCache::default()),
         };
 
         // Run web server
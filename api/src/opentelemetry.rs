@@ -214,6 +214,18 @@ pub fn report_ingested_events(amount: u64) {
     INGESTED_EVENTS.add(amount, &[]);
 }
 
+static EVENT_PAYLOAD_SCHEMA_VIOLATIONS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(crate_name!())
+        .u64_counter("events.payload_schema_violations")
+        .with_description("Events whose payload did not match the schema of their event type")
+        .build()
+});
+
+/// `mode` is the payload schema mode of the event type: `enforce` when the event was refused, `warn` when it was ingested anyway.
+pub fn report_event_payload_schema_violation(mode: &'static str) {
+    EVENT_PAYLOAD_SCHEMA_VIOLATIONS.add(1, &[KeyValue::new("mode", mode)]);
+}
+
 static EVENT_PAYLOADS_STORED_IN_OBJECT_STORAGE: LazyLock<Counter<u64>> = LazyLock::new(|| {
     global::meter(crate_name!())
         .u64_counter("events.payloads_stored_in_object_storage")
@@ -254,6 +266,16 @@ pub fn report_replayed_events(amount: u64) {
     REPLAYED_EVENTS.add(amount, &[]);
 }
 
+static RETRIED_REQUEST_ATTEMPTS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(crate_name!())
+        .u64_counter("request_attempts.retried")
+        .build()
+});
+
+pub fn report_retried_request_attempts(amount: u64) {
+    RETRIED_REQUEST_ATTEMPTS.add(amount, &[]);
+}
+
 static HEALTH_CHECK_DURATION: LazyLock<Histogram<f64>> = LazyLock::new(|| {
     global::meter(crate_name!())
         .f64_histogram("health_check.duration")
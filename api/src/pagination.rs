@@ -25,6 +25,13 @@ impl Cursor {
             .ok()
             .map(|bytes| BASE64_URL_SAFE.encode(bytes))
     }
+
+    pub fn from_qs_value(s: &str) -> Result<Self, String> {
+        BASE64_URL_SAFE
+            .decode(s)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| serde_json::from_slice::<Self>(&bytes).map_err(|e| e.to_string()))
+    }
 }
 
 /// Wrapper arround [`Cursor`] to implement the correct traits for it to be decoded fron base64 and correctly documented in OpenAPI spec
@@ -50,14 +57,7 @@ impl FromStr for EncodedDescCursor {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        BASE64_URL_SAFE
-            .decode(s)
-            .map_err(|e| e.to_string())
-            .and_then(|bytes| {
-                serde_json::from_slice::<Cursor>(&bytes)
-                    .map(Self)
-                    .map_err(|e| e.to_string())
-            })
+        Cursor::from_qs_value(s).map(Self)
     }
 }
 
@@ -71,6 +71,43 @@ impl<'de> Deserialize<'de> for EncodedDescCursor {
     }
 }
 
+/// Same as [`EncodedDescCursor`], for lists in ascending order: by default, it is before anything
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodedAscCursor(pub Cursor);
+
+impl Default for EncodedAscCursor {
+    fn default() -> Self {
+        Self(Cursor {
+            date: DateTime::UNIX_EPOCH,
+            id: Uuid::nil(),
+        })
+    }
+}
+
+impl TypedData for EncodedAscCursor {
+    fn data_type() -> paperclip::v2::models::DataType {
+        paperclip::v2::models::DataType::String
+    }
+}
+
+impl FromStr for EncodedAscCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cursor::from_qs_value(s).map(Self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EncodedAscCursor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        FromStr::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NextPageParts {
     pub endpoint_url: Url,
@@ -66,6 +66,9 @@ pub enum Hook0Problem {
 
     EventTypeAlreadyExist,
     EventTypeDoesNotExist,
+    EventTypeVersionAlreadyExist,
+    EventTypeVersionDoesNotExist,
+    EventTypeVersionSunset,
 
     UnauthorizedWorkers(Vec<String>),
 
@@ -73,11 +76,30 @@ pub enum Hook0Problem {
     EventInvalidPayloadContentType,
     EventInvalidBase64Payload(String),
     EventInvalidJsonPayload(String),
+    EventPayloadSchemaViolation(Vec<String>),
+    EventDeliveryBeyondRetention(QuotaValue),
+    EventNotScheduled,
 
     LabelsAmbiguity,
 
     InvalidDateRange,
 
+    RequestAttemptNotFailed,
+    RequestAttemptRetryPending,
+    SubscriptionDisabled,
+    SubscriptionNotInbox,
+    SecretRotationInProgress,
+
+    BulkRetryAlreadyRunning,
+    BulkRetryFinished,
+
+    RetryPolicyOverLimit(String),
+
+    ClientCertificatesDisabled,
+    InvalidClientCertificate(String),
+
+    SigningKeysDisabled,
+
     // Auth errors
     AuthNoAuthorizationHeader,
     AuthInvalidAuthorizationHeader,
@@ -211,6 +233,9 @@ impl Hook0Problem {
 
             Self::EventTypeAlreadyExist => "EventTypeAlreadyExist",
             Self::EventTypeDoesNotExist => "EventTypeDoesNotExist",
+            Self::EventTypeVersionAlreadyExist => "EventTypeVersionAlreadyExist",
+            Self::EventTypeVersionDoesNotExist => "EventTypeVersionDoesNotExist",
+            Self::EventTypeVersionSunset => "EventTypeVersionSunset",
 
             Self::UnauthorizedWorkers(_) => "UnauthorizedWorkers",
 
@@ -218,11 +243,29 @@ impl Hook0Problem {
             Self::EventInvalidPayloadContentType => "EventInvalidPayloadContentType",
             Self::EventInvalidBase64Payload(_) => "EventInvalidBase64Payload",
             Self::EventInvalidJsonPayload(_) => "EventInvalidJsonPayload",
+            Self::EventPayloadSchemaViolation(_) => "EventPayloadSchemaViolation",
+            Self::EventDeliveryBeyondRetention(_) => "EventDeliveryBeyondRetention",
+            Self::EventNotScheduled => "EventNotScheduled",
 
             Self::LabelsAmbiguity => "LabelsAmbiguity",
 
             Self::InvalidDateRange => "InvalidDateRange",
 
+            Self::RequestAttemptNotFailed => "RequestAttemptNotFailed",
+            Self::RequestAttemptRetryPending => "RequestAttemptRetryPending",
+            Self::SubscriptionDisabled => "SubscriptionDisabled",
+            Self::SubscriptionNotInbox => "SubscriptionNotInbox",
+            Self::SecretRotationInProgress => "SecretRotationInProgress",
+            Self::BulkRetryAlreadyRunning => "BulkRetryAlreadyRunning",
+            Self::BulkRetryFinished => "BulkRetryFinished",
+
+            Self::RetryPolicyOverLimit(_) => "RetryPolicyOverLimit",
+
+            Self::ClientCertificatesDisabled => "ClientCertificatesDisabled",
+            Self::InvalidClientCertificate(_) => "InvalidClientCertificate",
+
+            Self::SigningKeysDisabled => "SigningKeysDisabled",
+
             // Auth errors
             Self::AuthNoAuthorizationHeader => "AuthNoAuthorizationHeader",
             Self::AuthInvalidAuthorizationHeader => "AuthInvalidAuthorizationHeader",
@@ -285,9 +328,17 @@ impl From<sqlx::Error> for Hook0Problem {
                             "subscription__event_type_event_type__name_fkey"
                             | "event_event_type__name_fkey",
                         ) => Hook0Problem::EventTypeDoesNotExist,
+                        Some("event_type_version_pkey") => {
+                            Hook0Problem::EventTypeVersionAlreadyExist
+                        }
+                        Some(
+                            "subscription__event_type_event_type_version_fkey"
+                            | "event_event_type_version_fkey",
+                        ) => Hook0Problem::EventTypeVersionDoesNotExist,
                         Some("user__organization_pkey") => {
                             Hook0Problem::InvitedUserAlreadyInOrganization
                         }
+                        Some("bulk_retry_running_idx") => Hook0Problem::BulkRetryAlreadyRunning,
                         constraint => {
                             error!(
                                 "Database error (failed constraint = {}): {}",
@@ -524,6 +575,27 @@ impl From<Hook0Problem> for ProblemDetails {
                 validation: None,
                 status: StatusCode::BAD_REQUEST,
             },
+            Hook0Problem::EventTypeVersionAlreadyExist => ProblemDetails {
+                id: Hook0Problem::EventTypeVersionAlreadyExist,
+                title: "This event type version already exist",
+                detail: "This version of the event type is already present.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
+            Hook0Problem::EventTypeVersionDoesNotExist => ProblemDetails {
+                id: Hook0Problem::EventTypeVersionDoesNotExist,
+                title: "Invalid event type version",
+                detail: "This version of the event type does not exist. You should create it first.".into(),
+                validation: None,
+                status: StatusCode::BAD_REQUEST,
+            },
+            Hook0Problem::EventTypeVersionSunset => ProblemDetails {
+                id: Hook0Problem::EventTypeVersionSunset,
+                title: "Event type version is past its sunset date",
+                detail: "This version of the event type was deprecated and its sunset date has passed: events can no longer be ingested with it. You should emit a newer version.".into(),
+                validation: None,
+                status: StatusCode::GONE,
+            },
 
             Hook0Problem::UnauthorizedWorkers(w) => {
                 let detail = format!("You do not have access to the following workers: {}", w.join(", "));
@@ -573,6 +645,34 @@ impl From<Hook0Problem> for ProblemDetails {
                     status: StatusCode::BAD_REQUEST,
                 }
             },
+            Hook0Problem::EventPayloadSchemaViolation(violations) => {
+                let detail = format!("Event payload does not match the schema of its event type: {}.", violations.join("; "));
+                let validation = Some(Value::Array(violations.iter().cloned().map(Value::String).collect()));
+                ProblemDetails {
+                    id: Hook0Problem::EventPayloadSchemaViolation(violations),
+                    title: "Event payload does not match its schema",
+                    detail: detail.into(),
+                    validation,
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                }
+            },
+            Hook0Problem::EventDeliveryBeyondRetention(days) => {
+                let detail = format!("Events of this application are kept {days} days, so an event cannot be scheduled for delivery further than that in the future.");
+                ProblemDetails {
+                    id: Hook0Problem::EventDeliveryBeyondRetention(days),
+                    title: "Event delivery date is beyond the events retention",
+                    detail: detail.into(),
+                    validation: None,
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                }
+            },
+            Hook0Problem::EventNotScheduled => ProblemDetails {
+                id: Hook0Problem::EventNotScheduled,
+                title: "Event is not scheduled",
+                detail: "Only an event scheduled for a later delivery can be cancelled, as long as its delivery date has not been reached and it was not cancelled already.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
             Hook0Problem::LabelsAmbiguity => ProblemDetails {
                 id: Hook0Problem::LabelsAmbiguity,
                 title: "Ambiguous labels specification",
@@ -589,6 +689,93 @@ impl From<Hook0Problem> for ProblemDetails {
                 status: StatusCode::BAD_REQUEST,
             },
 
+            Hook0Problem::RequestAttemptNotFailed => ProblemDetails {
+                id: Hook0Problem::RequestAttemptNotFailed,
+                title: "Request attempt has not failed",
+                detail: "Only a request attempt that failed can be retried.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
+            Hook0Problem::RequestAttemptRetryPending => ProblemDetails {
+                id: Hook0Problem::RequestAttemptRetryPending,
+                title: "A delivery of this event to this subscription is still pending",
+                detail: "Another request attempt of the same event for the same subscription is waiting to be delivered or being delivered; retrying now would deliver the event twice.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
+            Hook0Problem::SubscriptionDisabled => ProblemDetails {
+                id: Hook0Problem::SubscriptionDisabled,
+                title: "Subscription is disabled",
+                detail: "Nothing can be delivered to a subscription that is disabled or deleted; enable it first.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
+            Hook0Problem::SubscriptionNotInbox => ProblemDetails {
+                id: Hook0Problem::SubscriptionNotInbox,
+                title: "Subscription is not an inbox",
+                detail: "Output workers push the events of this subscription to its target; only the events of a subscription whose target is an inbox are pulled through the API.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
+            Hook0Problem::SecretRotationInProgress => ProblemDetails {
+                id: Hook0Problem::SecretRotationInProgress,
+                title: "A secret rotation is already in progress",
+                detail: "The previous secret of this subscription is still valid; finalize or roll back the rotation in progress before starting another one.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
+            Hook0Problem::BulkRetryAlreadyRunning => ProblemDetails {
+                id: Hook0Problem::BulkRetryAlreadyRunning,
+                title: "A bulk retry is already running",
+                detail: "Only one bulk retry per application can run at a time; wait for the running one to complete or cancel it.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
+            Hook0Problem::BulkRetryFinished => ProblemDetails {
+                id: Hook0Problem::BulkRetryFinished,
+                title: "Bulk retry is not running anymore",
+                detail: "This bulk retry is already completed or cancelled.".into(),
+                validation: None,
+                status: StatusCode::CONFLICT,
+            },
+
+            Hook0Problem::RetryPolicyOverLimit(e) => {
+                let detail = format!("Retry policy goes beyond the limits of this instance: {e}.");
+                ProblemDetails {
+                    id: Hook0Problem::RetryPolicyOverLimit(e),
+                    title: "Retry policy is over the limit",
+                    detail: detail.into(),
+                    validation: None,
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                }
+            },
+
+            Hook0Problem::ClientCertificatesDisabled => ProblemDetails {
+                id: Hook0Problem::ClientCertificatesDisabled,
+                title: "Client certificates are disabled",
+                detail: "This instance has no key to encrypt the private keys of client certificates with; an administrator must configure one.".into(),
+                validation: None,
+                status: StatusCode::GONE,
+            },
+            Hook0Problem::InvalidClientCertificate(e) => {
+                let detail = format!("Client certificate cannot be used: {e}.");
+                ProblemDetails {
+                    id: Hook0Problem::InvalidClientCertificate(e),
+                    title: "Invalid client certificate",
+                    detail: detail.into(),
+                    validation: None,
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                }
+            },
+
+            Hook0Problem::SigningKeysDisabled => ProblemDetails {
+                id: Hook0Problem::SigningKeysDisabled,
+                title: "Signing keys are disabled",
+                detail: "This instance has no key to encrypt the private keys of signing keys with; an administrator must configure one.".into(),
+                validation: None,
+                status: StatusCode::GONE,
+            },
+
             // Auth error
             Hook0Problem::AuthNoAuthorizationHeader => ProblemDetails {
                 id: Hook0Problem::AuthNoAuthorizationHeader,
@@ -913,6 +1100,12 @@ mod tests {
                 Hook0Problem::EventInvalidJsonPayload(_) => {
                     Hook0Problem::EventInvalidJsonPayload(text)
                 }
+                Hook0Problem::EventPayloadSchemaViolation(_) => {
+                    Hook0Problem::EventPayloadSchemaViolation(words)
+                }
+                Hook0Problem::EventDeliveryBeyondRetention(_) => {
+                    Hook0Problem::EventDeliveryBeyondRetention(quota)
+                }
                 Hook0Problem::TooManyMembersPerOrganization(_) => {
                     Hook0Problem::TooManyMembersPerOrganization(quota)
                 }
@@ -344,6 +344,32 @@ impl Quotas {
         }
     }
 
+    /// Number of days the events of an application are kept before being cleaned up.
+    ///
+    /// Unlike [`Quotas::get_limit_for_application`], this does not depend on quotas being
+    /// enforced: it computes the retention the same way the old events cleanup job does.
+    pub async fn get_events_retention_for_application<'a, A: Acquire<'a, Database = Postgres>>(
+        &self,
+        db: A,
+        application_id: &Uuid,
+    ) -> Result<QuotaValue, sqlx::Error> {
+        let mut db = db.acquire().await?;
+        query_scalar!(
+            r#"
+                SELECT COALESCE(LEAST(a.days_of_events_retention_limit, p.days_of_events_retention_limit), $2) AS "days!"
+                FROM event.application AS a
+                INNER JOIN iam.organization AS o ON o.organization__id = a.organization__id
+                LEFT JOIN pricing.price AS pr ON pr.price__id = o.price__id
+                LEFT JOIN pricing.plan AS p ON p.plan__id = pr.plan__id
+                WHERE a.application__id = $1
+            "#,
+            application_id,
+            self.limits.global_days_of_events_retention_limit,
+        )
+        .fetch_one(&mut *db)
+        .await
+    }
+
     /// Bounds how long the current transaction may wait for the row locks it is about
     /// to take.
     ///
@@ -100,7 +100,10 @@ async fn vacuum_analyze<'a, A: Acquire<'a, Database = Postgres>>(db: A) -> Resul
                 webhook.subscription,
                 webhook.subscription__event_type,
                 webhook.subscription__worker,
-                webhook.target_http
+                webhook.target_http,
+                webhook.target_amqp,
+                webhook.target_sqs,
+                webhook.target_inbox
         "
     )
     .execute(&mut *db)
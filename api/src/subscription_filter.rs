@@ -0,0 +1,803 @@
+use chrono::{DateTime, Utc};
+use paperclip::v2::models::{DataType, DefaultSchemaRaw};
+use paperclip::v2::schema::Apiv2Schema;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Number, Value};
+use sqlx::{PgConnection, query, query_as};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::handlers::events::PayloadContentType;
+use crate::handlers::subscriptions::OrderingKey;
+use crate::problems::Hook0Problem;
+
+/// An expression a subscription can use to only receive some of the events of the types it
+/// subscribed to.
+///
+/// In JSON, a filter is an object with exactly one of:
+/// - `and` / `or`: a list of filters
+/// - `not`: a filter
+/// - `label` (name of an event label) or `field` (JSON pointer into a JSON payload), along with
+///   exactly one operator among `eq`, `in`, `prefix`, `gt`, `gte`, `lt`, `lte` and `exists`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "RawSubscriptionFilter", into = "RawSubscriptionFilter")]
+pub enum SubscriptionFilter {
+    And(Vec<SubscriptionFilter>),
+    Or(Vec<SubscriptionFilter>),
+    Not(Box<SubscriptionFilter>),
+    Label {
+        name: String,
+        condition: Condition,
+    },
+    Field {
+        pointer: String,
+        condition: Condition,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    Eq(Value),
+    In(Vec<Value>),
+    Prefix(String),
+    Gt(Number),
+    Gte(Number),
+    Lt(Number),
+    Lte(Number),
+    Exists(bool),
+}
+
+/// The JSON shape of a filter, where every property is optional; which combinations are allowed
+/// is decided when converting it into a `SubscriptionFilter`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct RawSubscriptionFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    and: Option<Vec<SubscriptionFilter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    or: Option<Vec<SubscriptionFilter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not: Option<Box<SubscriptionFilter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<String>,
+    // Without this, `"eq": null` would be read as if `eq` was absent
+    #[serde(
+        default,
+        deserialize_with = "deserialize_present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    eq: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#in: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gt: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gte: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lt: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lte: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exists: Option<bool>,
+}
+
+fn deserialize_present<'de, D>(deserializer: D) -> Result<Option<Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid filter: {0}")]
+pub struct InvalidSubscriptionFilter(&'static str);
+
+impl TryFrom<RawSubscriptionFilter> for SubscriptionFilter {
+    type Error = InvalidSubscriptionFilter;
+
+    fn try_from(raw: RawSubscriptionFilter) -> Result<Self, Self::Error> {
+        let conditions = [
+            raw.eq.map(Condition::Eq),
+            raw.r#in.map(Condition::In),
+            raw.prefix.map(Condition::Prefix),
+            raw.gt.map(Condition::Gt),
+            raw.gte.map(Condition::Gte),
+            raw.lt.map(Condition::Lt),
+            raw.lte.map(Condition::Lte),
+            raw.exists.map(Condition::Exists),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        let single_condition = |conditions: Vec<Condition>| match <[Condition; 1]>::try_from(
+            conditions,
+        ) {
+            Ok([condition]) => Ok(condition),
+            Err(_) => Err(InvalidSubscriptionFilter(
+                "'label' and 'field' need exactly one of 'eq', 'in', 'prefix', 'gt', 'gte', 'lt', 'lte' or 'exists'",
+            )),
+        };
+        let without_condition = |conditions: Vec<Condition>, filter| {
+            if conditions.is_empty() {
+                Ok(filter)
+            } else {
+                Err(InvalidSubscriptionFilter(
+                    "operators can only be used along with 'label' or 'field'",
+                ))
+            }
+        };
+
+        match (raw.and, raw.or, raw.not, raw.label, raw.field) {
+            (Some(filters), None, None, None, None) => {
+                without_condition(conditions, Self::And(filters))
+            }
+            (None, Some(filters), None, None, None) => {
+                without_condition(conditions, Self::Or(filters))
+            }
+            (None, None, Some(filter), None, None) => {
+                without_condition(conditions, Self::Not(filter))
+            }
+            (None, None, None, Some(name), None) => Ok(Self::Label {
+                name,
+                condition: single_condition(conditions)?,
+            }),
+            (None, None, None, None, Some(pointer)) => Ok(Self::Field {
+                pointer,
+                condition: single_condition(conditions)?,
+            }),
+            _ => Err(InvalidSubscriptionFilter(
+                "expected exactly one of 'and', 'or', 'not', 'label' or 'field'",
+            )),
+        }
+    }
+}
+
+impl From<SubscriptionFilter> for RawSubscriptionFilter {
+    fn from(filter: SubscriptionFilter) -> Self {
+        let with_condition = |raw: Self, condition| match condition {
+            Condition::Eq(v) => Self { eq: Some(v), ..raw },
+            Condition::In(v) => Self {
+                r#in: Some(v),
+                ..raw
+            },
+            Condition::Prefix(v) => Self {
+                prefix: Some(v),
+                ..raw
+            },
+            Condition::Gt(v) => Self { gt: Some(v), ..raw },
+            Condition::Gte(v) => Self {
+                gte: Some(v),
+                ..raw
+            },
+            Condition::Lt(v) => Self { lt: Some(v), ..raw },
+            Condition::Lte(v) => Self {
+                lte: Some(v),
+                ..raw
+            },
+            Condition::Exists(v) => Self {
+                exists: Some(v),
+                ..raw
+            },
+        };
+
+        match filter {
+            SubscriptionFilter::And(filters) => Self {
+                and: Some(filters),
+                ..Default::default()
+            },
+            SubscriptionFilter::Or(filters) => Self {
+                or: Some(filters),
+                ..Default::default()
+            },
+            SubscriptionFilter::Not(filter) => Self {
+                not: Some(filter),
+                ..Default::default()
+            },
+            SubscriptionFilter::Label { name, condition } => with_condition(
+                Self {
+                    label: Some(name),
+                    ..Default::default()
+                },
+                condition,
+            ),
+            SubscriptionFilter::Field { pointer, condition } => with_condition(
+                Self {
+                    field: Some(pointer),
+                    ..Default::default()
+                },
+                condition,
+            ),
+        }
+    }
+}
+
+// This implementation is manual because paperclip cannot describe a recursive type
+impl Apiv2Schema for SubscriptionFilter {
+    fn raw_schema() -> DefaultSchemaRaw {
+        DefaultSchemaRaw {
+            data_type: Some(DataType::Object),
+            description: Some("An object with exactly one of: `and` or `or` (a list of filters), `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload); `label` and `field` go along with exactly one operator among `eq`, `in`, `prefix`, `gt`, `gte`, `lt`, `lte` and `exists`".to_owned()),
+            example: Some(serde_json::json!({
+                "and": [
+                    { "field": "/amount", "gt": 1000 },
+                    { "label": "country", "in": ["FR", "DE"] },
+                ],
+            })),
+            ..Default::default()
+        }
+    }
+}
+
+impl SubscriptionFilter {
+    /// Whether the filter holds for an event; `payload` is `None` when the event payload is not
+    /// JSON, in which case no payload field exists.
+    pub fn matches(&self, labels: &HashMap<String, String>, payload: Option<&Value>) -> bool {
+        match self {
+            Self::And(filters) => filters.iter().all(|f| f.matches(labels, payload)),
+            Self::Or(filters) => filters.iter().any(|f| f.matches(labels, payload)),
+            Self::Not(filter) => !filter.matches(labels, payload),
+            Self::Label { name, condition } => condition.holds(
+                labels
+                    .get(name)
+                    .map(|label| Value::String(label.to_owned()))
+                    .as_ref(),
+            ),
+            Self::Field { pointer, condition } => {
+                condition.holds(payload.and_then(|p| p.pointer(pointer)))
+            }
+        }
+    }
+
+    /// Whether evaluating the filter requires the event payload, as opposed to its labels only.
+    pub fn needs_payload(&self) -> bool {
+        match self {
+            Self::And(filters) | Self::Or(filters) => filters.iter().any(Self::needs_payload),
+            Self::Not(filter) => filter.needs_payload(),
+            Self::Label { .. } => false,
+            Self::Field { .. } => true,
+        }
+    }
+}
+
+impl Condition {
+    fn holds(&self, value: Option<&Value>) -> bool {
+        match (self, value) {
+            (Self::Exists(exists), value) => value.is_some() == *exists,
+            (_, None) => false,
+            (Self::Eq(expected), Some(value)) => json_eq(value, expected),
+            (Self::In(expected), Some(value)) => expected.iter().any(|e| json_eq(value, e)),
+            (Self::Prefix(prefix), Some(value)) => {
+                value.as_str().is_some_and(|s| s.starts_with(prefix))
+            }
+            (Self::Gt(bound), Some(value)) => compare(value, bound) == Some(Ordering::Greater),
+            (Self::Gte(bound), Some(value)) => matches!(
+                compare(value, bound),
+                Some(Ordering::Greater | Ordering::Equal)
+            ),
+            (Self::Lt(bound), Some(value)) => compare(value, bound) == Some(Ordering::Less),
+            (Self::Lte(bound), Some(value)) => matches!(
+                compare(value, bound),
+                Some(Ordering::Less | Ordering::Equal)
+            ),
+        }
+    }
+}
+
+/// JSON equality, except that numbers are compared by value (`1` equals `1.0`).
+fn json_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        (a, b) => a == b,
+    }
+}
+
+/// Compare a value to a number; strings holding a number are accepted because labels are always
+/// strings.
+fn compare(value: &Value, bound: &Number) -> Option<Ordering> {
+    let value = match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }?;
+    value.partial_cmp(&bound.as_f64()?)
+}
+
+/// An enabled subscription that matches an event type and labels and carries a filter or an
+/// ordering key. Those are left aside by the `event.dispatch()` trigger because their filter or
+/// their key may need a payload the database does not have.
+#[derive(Debug)]
+pub struct FilteredSubscription {
+    subscription_id: Uuid,
+    filter: Option<SubscriptionFilter>,
+    ordering_key: Option<OrderingKey>,
+}
+
+pub async fn find_filtered_subscriptions(
+    db: &mut PgConnection,
+    application_id: Uuid,
+    event_type: &str,
+    event_type_version: Option<i32>,
+    labels: &Value,
+) -> Result<Vec<FilteredSubscription>, Hook0Problem> {
+    struct RawFilteredSubscription {
+        subscription_id: Uuid,
+        filter: Option<Value>,
+        ordering_key: Option<Value>,
+    }
+    let subscriptions = query_as!(
+        RawFilteredSubscription,
+        r#"
+            SELECT s.subscription__id AS subscription_id, s.filter, s.ordering_key
+            FROM webhook.subscription AS s
+            INNER JOIN webhook.subscription__event_type AS set ON set.subscription__id = s.subscription__id
+            WHERE s.is_enabled
+                AND s.application__id = $1
+                AND s.deleted_at IS NULL
+                AND (s.filter IS NOT NULL OR s.ordering_key IS NOT NULL)
+                AND set.event_type__name = $2
+                AND (set.event_type_version IS NULL OR $4::INTEGER IS NULL OR set.event_type_version = $4)
+                AND $3::jsonb @> s.labels
+            FOR SHARE OF s
+        "#,
+        application_id,
+        event_type,
+        labels,
+        event_type_version,
+    )
+    .fetch_all(db)
+    .await?;
+
+    subscriptions
+        .into_iter()
+        .map(|s| {
+            // The API validated the filter and the ordering key when they were saved; this can
+            // only be older ones that are not understood anymore. A filter that cannot be read
+            // fails the dispatch, as the subscription cannot be told to match or not
+            let filter = s
+                .filter
+                .map(serde_json::from_value::<SubscriptionFilter>)
+                .transpose()
+                .map_err(|e| {
+                    error!(
+                        "Could not read the filter of subscription {}: {e}",
+                        s.subscription_id
+                    );
+                    Hook0Problem::InternalServerError
+                })?;
+            let ordering_key = match s.ordering_key.map(serde_json::from_value::<OrderingKey>) {
+                Some(Ok(ordering_key)) => Some(ordering_key),
+                Some(Err(e)) => {
+                    error!(
+                        "Could not parse ordering key of subscription {}: {e}",
+                        s.subscription_id
+                    );
+                    None
+                }
+                None => None,
+            };
+            Ok(FilteredSubscription {
+                subscription_id: s.subscription_id,
+                filter,
+                ordering_key,
+            })
+        })
+        .collect()
+}
+
+pub fn needs_payload(subscriptions: &[FilteredSubscription]) -> bool {
+    subscriptions.iter().any(|s| {
+        s.filter
+            .as_ref()
+            .is_some_and(SubscriptionFilter::needs_payload)
+            || s.ordering_key
+                .as_ref()
+                .is_some_and(OrderingKey::needs_payload)
+    })
+}
+
+/// Create the request attempts of an event for the filtered subscriptions that it matches, along
+/// with its ordering key for the subscriptions that have one. This must run in the transaction
+/// that inserted or redispatched the event, so that workers see all the request attempts of the
+/// event at once. The request attempts of an event scheduled for a later delivery wait until
+/// `deliver_at`.
+#[allow(clippy::too_many_arguments)]
+pub async fn dispatch_to_filtered_subscriptions(
+    db: &mut PgConnection,
+    application_id: Uuid,
+    event_id: Uuid,
+    subscriptions: &[FilteredSubscription],
+    labels: &HashMap<String, String>,
+    payload: Option<&[u8]>,
+    payload_content_type: &str,
+    deliver_at: Option<DateTime<Utc>>,
+) -> Result<(), Hook0Problem> {
+    if subscriptions.is_empty() {
+        return Ok(());
+    }
+
+    let json: &str = PayloadContentType::Json.into();
+    let payload = payload
+        .filter(|_| payload_content_type == json)
+        .and_then(|p| serde_json::from_slice::<Value>(p).ok());
+
+    let (matching, ordering_keys): (Vec<_>, Vec<_>) = subscriptions
+        .iter()
+        .filter(|s| {
+            s.filter
+                .as_ref()
+                .is_none_or(|f| f.matches(labels, payload.as_ref()))
+        })
+        .map(|s| {
+            (
+                s.subscription_id,
+                s.ordering_key
+                    .as_ref()
+                    .and_then(|k| k.of(labels, payload.as_ref())),
+            )
+        })
+        .unzip();
+
+    if !matching.is_empty() {
+        query!(
+            "
+                INSERT INTO webhook.request_attempt (event__id, subscription__id, application__id, ordering_key, delay_until)
+                SELECT $1, pair.subscription__id, $2, pair.ordering_key, $5
+                FROM UNNEST($3::uuid[], $4::text[]) AS pair (subscription__id, ordering_key)
+            ",
+            event_id,
+            application_id,
+            &matching,
+            &ordering_keys as &[Option<String>],
+            deliver_at,
+        )
+        .execute(db)
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+    use sqlx::PgPool;
+
+    use crate::google_ads::test_support::{seed_event, seed_org, seed_subscription, seed_user};
+
+    fn filter(value: Value) -> SubscriptionFilter {
+        serde_json::from_value(value).expect("invalid filter")
+    }
+
+    fn labels(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn a_filter_survives_a_round_trip() {
+        let value = json!({
+            "and": [
+                { "field": "/amount", "gt": 1000 },
+                { "or": [
+                    { "label": "country", "in": ["FR", "DE"] },
+                    { "not": { "field": "/test", "exists": true } },
+                ] },
+                { "field": "/coupon", "eq": null },
+            ],
+        });
+        assert_eq!(serde_json::to_value(filter(value.clone())).unwrap(), value);
+    }
+
+    #[test]
+    fn malformed_filters_are_refused() {
+        for value in [
+            json!({}),
+            json!({ "label": "country" }),
+            json!({ "label": "country", "eq": "FR", "prefix": "F" }),
+            json!({ "label": "country", "field": "/country", "eq": "FR" }),
+            json!({ "and": [], "or": [] }),
+            json!({ "not": { "label": "a", "exists": true }, "eq": 1 }),
+            json!({ "label": "country", "equals": "FR" }),
+            json!({ "field": "/amount", "gt": "1000" }),
+        ] {
+            assert!(
+                serde_json::from_value::<SubscriptionFilter>(value.clone()).is_err(),
+                "{value} should be refused"
+            );
+        }
+    }
+
+    #[test]
+    fn label_conditions() {
+        let l = labels(&[("country", "FR"), ("tier", "12")]);
+        assert!(filter(json!({ "label": "country", "eq": "FR" })).matches(&l, None));
+        assert!(filter(json!({ "label": "country", "in": ["DE", "FR"] })).matches(&l, None));
+        assert!(filter(json!({ "label": "country", "prefix": "F" })).matches(&l, None));
+        assert!(filter(json!({ "label": "tier", "gte": 12 })).matches(&l, None));
+        assert!(!filter(json!({ "label": "tier", "lt": 12 })).matches(&l, None));
+        assert!(!filter(json!({ "label": "missing", "exists": true })).matches(&l, None));
+        assert!(filter(json!({ "label": "missing", "exists": false })).matches(&l, None));
+    }
+
+    #[test]
+    fn field_conditions() {
+        let l = HashMap::new();
+        let payload = json!({ "amount": 1500.0, "order": { "country": "DE", "ref": "ORD-1" } });
+        let p = Some(&payload);
+        assert!(filter(json!({ "field": "/amount", "gt": 1000 })).matches(&l, p));
+        assert!(filter(json!({ "field": "/amount", "eq": 1500 })).matches(&l, p));
+        assert!(!filter(json!({ "field": "/amount", "lte": 1000 })).matches(&l, p));
+        assert!(filter(json!({ "field": "/order/country", "in": ["FR", "DE"] })).matches(&l, p));
+        assert!(filter(json!({ "field": "/order/ref", "prefix": "ORD-" })).matches(&l, p));
+        assert!(!filter(json!({ "field": "/order/country", "gt": 1 })).matches(&l, p));
+        assert!(!filter(json!({ "field": "/order/missing", "eq": null })).matches(&l, p));
+    }
+
+    #[test]
+    fn fields_of_a_payload_that_is_not_json_do_not_exist() {
+        let l = HashMap::new();
+        assert!(!filter(json!({ "field": "/amount", "exists": true })).matches(&l, None));
+        assert!(filter(json!({ "not": { "field": "/amount", "gt": 0 } })).matches(&l, None));
+    }
+
+    #[test]
+    fn combinators() {
+        let l = labels(&[("country", "FR")]);
+        let payload = json!({ "amount": 500 });
+        let p = Some(&payload);
+        let f = filter(json!({
+            "or": [
+                { "field": "/amount", "gt": 1000 },
+                { "label": "country", "in": ["FR", "DE"] },
+            ],
+        }));
+        assert!(f.matches(&l, p));
+        assert!(!f.matches(&labels(&[("country", "US")]), p));
+        assert!(
+            !filter(json!({ "and": [{ "label": "country", "eq": "FR" }, { "field": "/amount", "gt": 1000 }] }))
+                .matches(&l, p)
+        );
+        assert!(filter(json!({ "and": [] })).matches(&l, p));
+        assert!(!filter(json!({ "or": [] })).matches(&l, p));
+    }
+
+    #[test]
+    fn only_field_conditions_need_the_payload() {
+        assert!(!filter(json!({ "not": { "label": "a", "exists": true } })).needs_payload());
+        assert!(
+            filter(json!({ "or": [{ "label": "a", "exists": true }, { "field": "/a", "exists": true }] }))
+                .needs_payload()
+        );
+    }
+
+    async fn seed_filtered_subscription(
+        pool: &PgPool,
+        application_id: Uuid,
+        filter: Option<Value>,
+    ) -> Uuid {
+        seed_ordered_subscription(pool, application_id, filter, None).await
+    }
+
+    async fn seed_ordered_subscription(
+        pool: &PgPool,
+        application_id: Uuid,
+        filter: Option<Value>,
+        ordering_key: Option<Value>,
+    ) -> Uuid {
+        let subscription_id =
+            seed_subscription(pool, application_id, json!({ "env": "prod" })).await;
+        sqlx::query(
+            "UPDATE webhook.subscription SET filter = $2, ordering_key = $3 WHERE subscription__id = $1",
+        )
+        .bind(subscription_id)
+        .bind(filter)
+        .bind(ordering_key)
+        .execute(pool)
+        .await
+        .expect("seed subscription filter");
+        sqlx::query(
+            "INSERT INTO webhook.subscription__event_type (application__id, subscription__id, event_type__name) VALUES ($1, $2, 'test.resource.created')",
+        )
+        .bind(application_id)
+        .bind(subscription_id)
+        .execute(pool)
+        .await
+        .expect("seed subscription event type");
+        subscription_id
+    }
+
+    /// The event row is inserted without its payload, as it is when the payload goes to object
+    /// storage: filters on payload fields must still be evaluated, against the payload the API has
+    /// in hand.
+    #[sqlx::test]
+    async fn filtered_subscriptions_are_dispatched_without_a_payload_in_database(pool: PgPool) {
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        let (application_id, _) = seed_event(&pool, org).await;
+
+        let unfiltered = seed_filtered_subscription(&pool, application_id, None).await;
+        let matching = seed_filtered_subscription(
+            &pool,
+            application_id,
+            Some(json!({ "and": [
+                { "field": "/amount", "gt": 1000 },
+                { "label": "country", "in": ["FR", "DE"] },
+            ] })),
+        )
+        .await;
+        seed_filtered_subscription(
+            &pool,
+            application_id,
+            Some(json!({ "field": "/amount", "lt": 10 })),
+        )
+        .await;
+
+        let mut tx = pool.begin().await.unwrap();
+        let event_labels = json!({ "env": "prod", "country": "FR" });
+        let event_id: Uuid = sqlx::query_scalar(
+            r#"
+                INSERT INTO event.event (application__id, event_type__name, payload_content_type, ip, occurred_at, labels)
+                VALUES ($1, 'test.resource.created', 'application/json', '127.0.0.1'::inet, statement_timestamp(), $2)
+                RETURNING event__id
+            "#,
+        )
+        .bind(application_id)
+        .bind(&event_labels)
+        .fetch_one(&mut *tx)
+        .await
+        .expect("insert event");
+
+        let subscriptions = find_filtered_subscriptions(
+            &mut tx,
+            application_id,
+            "test.resource.created",
+            None,
+            &event_labels,
+        )
+        .await
+        .unwrap();
+        assert_eq!(subscriptions.len(), 2);
+        dispatch_to_filtered_subscriptions(
+            &mut tx,
+            application_id,
+            event_id,
+            &subscriptions,
+            &labels(&[("env", "prod"), ("country", "FR")]),
+            Some(br#"{"amount":1500}"#),
+            "application/json",
+            None,
+        )
+        .await
+        .unwrap();
+        tx.commit().await.unwrap();
+
+        let mut dispatched: Vec<Uuid> = sqlx::query_scalar(
+            "SELECT subscription__id FROM webhook.request_attempt WHERE event__id = $1",
+        )
+        .bind(event_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        dispatched.sort();
+        let mut expected = vec![unfiltered, matching];
+        expected.sort();
+        assert_eq!(dispatched, expected);
+    }
+
+    /// A stored filter that cannot be read fails the dispatch instead of leaving its subscription
+    /// out.
+    #[sqlx::test]
+    async fn filters_that_cannot_be_read_fail_the_dispatch(pool: PgPool) {
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        let (application_id, _) = seed_event(&pool, org).await;
+        seed_filtered_subscription(&pool, application_id, Some(json!({ "field": 42 }))).await;
+
+        let mut tx = pool.begin().await.unwrap();
+        let result = find_filtered_subscriptions(
+            &mut tx,
+            application_id,
+            "test.resource.created",
+            None,
+            &json!({ "env": "prod" }),
+        )
+        .await;
+        assert!(matches!(result, Err(Hook0Problem::InternalServerError)));
+    }
+
+    /// Ordered subscriptions are dispatched by the API too, each request attempt carrying the key
+    /// of its event; an event without a key is not ordered.
+    #[sqlx::test]
+    async fn ordered_subscriptions_are_dispatched_with_the_key_of_the_event(pool: PgPool) {
+        let user = seed_user(&pool).await;
+        let org = seed_org(&pool, user).await;
+        let (application_id, _) = seed_event(&pool, org).await;
+        let by_customer = seed_ordered_subscription(
+            &pool,
+            application_id,
+            None,
+            Some(json!({ "field": "/customer/id" })),
+        )
+        .await;
+        let by_country = seed_ordered_subscription(
+            &pool,
+            application_id,
+            Some(json!({ "label": "country", "eq": "FR" })),
+            Some(json!({ "label": "country" })),
+        )
+        .await;
+
+        let event_labels = json!({ "env": "prod", "country": "FR" });
+        let mut keys = vec![];
+        for payload in [&br#"{"customer":{"id":42}}"#[..], br#"{"customer":{}}"#] {
+            let mut tx = pool.begin().await.unwrap();
+            let event_id: Uuid = sqlx::query_scalar(
+                r#"
+                    INSERT INTO event.event (application__id, event_type__name, payload_content_type, ip, occurred_at, labels)
+                    VALUES ($1, 'test.resource.created', 'application/json', '127.0.0.1'::inet, statement_timestamp(), $2)
+                    RETURNING event__id
+                "#,
+            )
+            .bind(application_id)
+            .bind(&event_labels)
+            .fetch_one(&mut *tx)
+            .await
+            .expect("insert event");
+            let subscriptions = find_filtered_subscriptions(
+                &mut tx,
+                application_id,
+                "test.resource.created",
+                None,
+                &event_labels,
+            )
+            .await
+            .unwrap();
+            assert!(needs_payload(&subscriptions));
+            dispatch_to_filtered_subscriptions(
+                &mut tx,
+                application_id,
+                event_id,
+                &subscriptions,
+                &labels(&[("env", "prod"), ("country", "FR")]),
+                Some(payload),
+                "application/json",
+                None,
+            )
+            .await
+            .unwrap();
+            tx.commit().await.unwrap();
+
+            for subscription_id in [by_customer, by_country] {
+                let key: Option<String> = sqlx::query_scalar(
+                    "SELECT ordering_key FROM webhook.request_attempt WHERE event__id = $1 AND subscription__id = $2",
+                )
+                .bind(event_id)
+                .bind(subscription_id)
+                .fetch_one(&pool)
+                .await
+                .expect("request attempt of an ordered subscription");
+                keys.push(key);
+            }
+        }
+        assert_eq!(
+            keys,
+            [
+                Some("42".to_owned()),
+                Some("FR".to_owned()),
+                None,
+                Some("FR".to_owned())
+            ]
+        );
+    }
+}
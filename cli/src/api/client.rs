@@ -251,6 +251,74 @@ impl ApiClient {
         self.handle_response(response).await
     }
 
+    /// Get an event type by its name
+    pub async fn get_event_type(
+        &self,
+        application_id: &Uuid,
+        event_type_name: &str,
+    ) -> Result<EventType, ApiError> {
+        let response = self
+            .client
+            .get(self.url(&format!("/event_types/{}", event_type_name)))
+            .query(&[("application_id", application_id.to_string())])
+            .bearer_auth(&self.secret)
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Update the payload schema of an event type
+    pub async fn update_event_type(
+        &self,
+        event_type_name: &str,
+        event_type: &EventTypePut,
+    ) -> Result<EventType, ApiError> {
+        let response = self
+            .client
+            .put(self.url(&format!("/event_types/{}", event_type_name)))
+            .bearer_auth(&self.secret)
+            .json(event_type)
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Declare a new version of an event type
+    pub async fn create_event_type_version(
+        &self,
+        event_type_name: &str,
+        version: &EventTypeVersionPost,
+    ) -> Result<EventTypeVersion, ApiError> {
+        let response = self
+            .client
+            .post(self.url(&format!("/event_types/{}/versions", event_type_name)))
+            .bearer_auth(&self.secret)
+            .json(version)
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Deprecate a version of an event type
+    pub async fn deprecate_event_type_version(
+        &self,
+        event_type_name: &str,
+        deprecation: &EventTypeVersionDeprecate,
+    ) -> Result<EventTypeVersion, ApiError> {
+        let response = self
+            .client
+            .post(self.url(&format!("/event_types/{}/deprecate", event_type_name)))
+            .bearer_auth(&self.secret)
+            .json(deprecation)
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
     /// Delete an event type
     pub async fn delete_event_type(
         &self,
@@ -436,6 +504,7 @@ impl ApiClient {
         let update = SubscriptionPut {
             application_id: sub.application_id,
             event_types: sub.event_types,
+            event_type_versions: Some(sub.event_type_versions),
             is_enabled: true,
             description: sub.description,
             labels: Some(sub.labels),
@@ -446,6 +515,14 @@ impl ApiClient {
             } else {
                 Some(sub.dedicated_workers)
             },
+            payload_transform: sub.payload_transform,
+            filter: sub.filter,
+            circuit_breaker: sub.circuit_breaker,
+            retry_policy: sub.retry_policy,
+            delivery_limits: sub.delivery_limits,
+            ordering_key: sub.ordering_key,
+            batching: sub.batching,
+            response_rules: sub.response_rules,
         };
         self.update_subscription(subscription_id, &update).await
     }
@@ -459,6 +536,7 @@ impl ApiClient {
         let update = SubscriptionPut {
             application_id: sub.application_id,
             event_types: sub.event_types,
+            event_type_versions: Some(sub.event_type_versions),
             is_enabled: false,
             description: sub.description,
             labels: Some(sub.labels),
@@ -469,10 +547,100 @@ impl ApiClient {
             } else {
                 Some(sub.dedicated_workers)
             },
+            payload_transform: sub.payload_transform,
+            filter: sub.filter,
+            circuit_breaker: sub.circuit_breaker,
+            retry_policy: sub.retry_policy,
+            delivery_limits: sub.delivery_limits,
+            ordering_key: sub.ordering_key,
+            batching: sub.batching,
+            response_rules: sub.response_rules,
         };
         self.update_subscription(subscription_id, &update).await
     }
 
+    /// Rotate the secret of a subscription, keeping the previous one valid for an overlap window
+    pub async fn rotate_subscription_secret(
+        &self,
+        subscription_id: &Uuid,
+        application_id: &Uuid,
+        rotation: &SecretRotationPost,
+    ) -> Result<SecretRotation, ApiError> {
+        let response = self
+            .client
+            .post(self.url(&format!(
+                "/subscriptions/{}/secret_rotation",
+                subscription_id
+            )))
+            .query(&[("application_id", application_id.to_string())])
+            .bearer_auth(&self.secret)
+            .json(rotation)
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Finalize the secret rotation of a subscription, invalidating the previous secret
+    pub async fn finalize_secret_rotation(
+        &self,
+        subscription_id: &Uuid,
+        application_id: &Uuid,
+    ) -> Result<(), ApiError> {
+        let response = self
+            .client
+            .post(self.url(&format!(
+                "/subscriptions/{}/secret_rotation/finalize",
+                subscription_id
+            )))
+            .query(&[("application_id", application_id.to_string())])
+            .bearer_auth(&self.secret)
+            .send()
+            .await?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::NOT_FOUND => {
+                let body = response.text().await.unwrap_or_default();
+                Err(ApiError::NotFound(body))
+            }
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                Err(ApiError::ServerError(format!("Status {status}: {body}")))
+            }
+        }
+    }
+
+    /// Roll back the secret rotation of a subscription, restoring the previous secret
+    pub async fn rollback_secret_rotation(
+        &self,
+        subscription_id: &Uuid,
+        application_id: &Uuid,
+    ) -> Result<(), ApiError> {
+        let response = self
+            .client
+            .post(self.url(&format!(
+                "/subscriptions/{}/secret_rotation/rollback",
+                subscription_id
+            )))
+            .query(&[("application_id", application_id.to_string())])
+            .bearer_auth(&self.secret)
+            .send()
+            .await?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::NOT_FOUND => {
+                let body = response.text().await.unwrap_or_default();
+                Err(ApiError::NotFound(body))
+            }
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                Err(ApiError::ServerError(format!("Status {status}: {body}")))
+            }
+        }
+    }
+
     // =========================================================================
     // Request Attempt endpoints
     // =========================================================================
@@ -535,6 +703,59 @@ impl ApiClient {
         self.handle_response(response).await
     }
 
+    // =========================================================================
+    // Bulk Retry endpoints
+    // =========================================================================
+
+    /// Count the request attempts a bulk retry would redeliver
+    pub async fn dry_run_bulk_retry(
+        &self,
+        bulk_retry: &BulkRetryPost,
+    ) -> Result<BulkRetryDryRun, ApiError> {
+        let response = self
+            .client
+            .post(self.url("/bulk_retries/dry_run"))
+            .bearer_auth(&self.secret)
+            .json(bulk_retry)
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Start a bulk retry
+    pub async fn create_bulk_retry(
+        &self,
+        bulk_retry: &BulkRetryPost,
+    ) -> Result<BulkRetry, ApiError> {
+        let response = self
+            .client
+            .post(self.url("/bulk_retries"))
+            .bearer_auth(&self.secret)
+            .json(bulk_retry)
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Get a bulk retry by ID
+    pub async fn get_bulk_retry(
+        &self,
+        bulk_retry_id: &Uuid,
+        application_id: &Uuid,
+    ) -> Result<BulkRetry, ApiError> {
+        let response = self
+            .client
+            .get(self.url(&format!("/bulk_retries/{}", bulk_retry_id)))
+            .bearer_auth(&self.secret)
+            .query(&[("application_id", application_id.to_string())])
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
     // =========================================================================
     // Application Secret endpoints
     // =========================================================================
@@ -111,6 +111,14 @@ pub struct EventType {
     pub event_type_name: Option<String>,
     #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub payload_schema: Option<serde_json::Value>,
+    #[serde(default)]
+    pub payload_schema_mode: Option<String>,
+    #[serde(default)]
+    pub default_ttl_s: Option<i32>,
+    #[serde(default)]
+    pub versions: Vec<EventTypeVersion>,
 }
 
 impl EventType {
@@ -145,6 +153,12 @@ pub struct EventTypePost {
     pub service: String,
     pub resource_type: String,
     pub verb: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_ttl_s: Option<i32>,
 }
 
 impl EventTypePost {
@@ -155,10 +169,60 @@ impl EventTypePost {
             service,
             resource_type,
             verb,
+            payload_schema: None,
+            payload_schema_mode: None,
+            default_ttl_s: None,
         })
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTypePut {
+    pub application_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_ttl_s: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTypeVersion {
+    pub version: i32,
+    #[serde(default)]
+    pub payload_schema: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub deprecated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTypeVersionPost {
+    pub application_id: Uuid,
+    pub version: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTypeVersionDeprecate {
+    pub application_id: Uuid,
+    pub version: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTypeDeprecation {
+    pub event_type_version: i32,
+    pub deprecated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
 // =============================================================================
 // Event
 // =============================================================================
@@ -171,6 +235,8 @@ pub struct Event {
     #[serde(default)]
     pub event_type_name: Option<String>,
     #[serde(default)]
+    pub event_type_version: Option<i32>,
+    #[serde(default)]
     pub payload: Option<String>,
     #[serde(default)]
     pub payload_content_type: Option<String>,
@@ -183,6 +249,8 @@ pub struct Event {
     #[serde(default)]
     pub occurred_at: Option<DateTime<Utc>>,
     pub received_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<EventTypeDeprecation>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -197,6 +265,8 @@ pub struct EventPost {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_id: Option<Uuid>,
     pub event_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_version: Option<i32>,
     pub payload: String,
     pub payload_content_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -254,6 +324,7 @@ impl EventPost {
             application_id,
             event_id: None,
             event_type,
+            event_type_version: None,
             payload,
             payload_content_type,
             metadata: None,
@@ -274,6 +345,8 @@ pub struct Subscription {
     pub is_enabled: bool,
     pub event_types: Vec<String>,
     #[serde(default)]
+    pub event_type_versions: HashMap<String, i32>,
+    #[serde(default)]
     pub description: Option<String>,
     pub secret: Uuid,
     #[serde(default)]
@@ -283,6 +356,26 @@ pub struct Subscription {
     pub target: Target,
     #[serde(default)]
     pub dedicated_workers: Vec<String>,
+    #[serde(default)]
+    pub payload_transform: Option<serde_json::Value>,
+    #[serde(default)]
+    pub filter: Option<serde_json::Value>,
+    #[serde(default)]
+    pub circuit_breaker: Option<serde_json::Value>,
+    #[serde(default)]
+    pub retry_policy: Option<serde_json::Value>,
+    #[serde(default)]
+    pub delivery_limits: Option<serde_json::Value>,
+    #[serde(default)]
+    pub ordering_key: Option<serde_json::Value>,
+    #[serde(default)]
+    pub batching: Option<serde_json::Value>,
+    #[serde(default)]
+    pub response_rules: Option<serde_json::Value>,
+    #[serde(default)]
+    pub auto_disabled_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub auto_disabled_reason: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -330,6 +423,8 @@ impl Target {
 pub struct SubscriptionPost {
     pub application_id: Uuid,
     pub event_types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_versions: Option<HashMap<String, i32>>,
     pub is_enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -340,12 +435,30 @@ pub struct SubscriptionPost {
     pub target: Target,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dedicated_workers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_transform: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_policy: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_limits: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordering_key: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batching: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_rules: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscriptionPut {
     pub application_id: Uuid,
     pub event_types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_versions: Option<HashMap<String, i32>>,
     pub is_enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -356,6 +469,36 @@ pub struct SubscriptionPut {
     pub target: Target,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dedicated_workers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_transform: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_policy: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_limits: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordering_key: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batching: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_rules: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretRotation {
+    pub subscription_id: Uuid,
+    pub secret: Uuid,
+    pub previous_secret: Uuid,
+    pub previous_secret_expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretRotationPost {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overlap_seconds: Option<i32>,
 }
 
 // =============================================================================
@@ -383,6 +526,8 @@ pub struct RequestAttempt {
     #[serde(default)]
     pub response_id: Option<Uuid>,
     #[serde(default)]
+    pub delivery_batch_id: Option<Uuid>,
+    #[serde(default)]
     pub retry_count: i16,
 }
 
@@ -407,6 +552,12 @@ pub enum RequestAttemptStatus {
         at: DateTime<Utc>,
         full_processing_ms: i64,
     },
+    Expired {
+        at: DateTime<Utc>,
+    },
+    Cancelled {
+        at: DateTime<Utc>,
+    },
 }
 
 impl RequestAttemptStatus {
@@ -425,10 +576,63 @@ impl RequestAttemptStatus {
             Self::InProgress { .. } => "In Progress",
             Self::Successful { .. } => "Successful",
             Self::Failed { .. } => "Failed",
+            Self::Expired { .. } => "Expired",
+            Self::Cancelled { .. } => "Cancelled",
         }
     }
 }
 
+// =============================================================================
+// Bulk Retry
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkRetry {
+    pub bulk_retry_id: Uuid,
+    pub application_id: Uuid,
+    #[serde(default)]
+    pub subscription_id: Option<Uuid>,
+    #[serde(default)]
+    pub event_type_names: Vec<String>,
+    pub min_created_at: DateTime<Utc>,
+    pub max_created_at: DateTime<Utc>,
+    pub status: String,
+    pub total_count: i64,
+    pub processed_count: i64,
+    pub retried_count: i64,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub cancelled_at: Option<DateTime<Utc>>,
+}
+
+impl BulkRetry {
+    pub fn is_running(&self) -> bool {
+        self.completed_at.is_none() && self.cancelled_at.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkRetryPost {
+    pub application_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkRetryDryRun {
+    pub count: i64,
+}
+
 // =============================================================================
 // Response (Webhook Response)
 // =============================================================================
@@ -578,6 +782,10 @@ mod tests {
             verb_name: "created".to_string(),
             event_type_name: None,
             created_at: None,
+            payload_schema: None,
+            payload_schema_mode: None,
+            default_ttl_s: None,
+            versions: Vec::new(),
         };
         assert_eq!(et.full_name(), "user.account.created");
     }
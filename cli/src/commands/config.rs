@@ -388,6 +388,9 @@ pub async fn init(_cli: &Cli, args: &InitArgs) -> Result<()> {
             service,
             resource_type: resource,
             verb,
+            payload_schema: None,
+            payload_schema_mode: None,
+            default_ttl_s: None,
         };
 
         client.create_event_type(&event_type_post).await?;
@@ -455,6 +458,9 @@ pub async fn init(_cli: &Cli, args: &InitArgs) -> Result<()> {
                 service,
                 resource_type: resource,
                 verb,
+                payload_schema: None,
+                payload_schema_mode: None,
+                default_ttl_s: None,
             };
 
             let sp = spinner(&format!("Creating '{}'...", event_type_name));
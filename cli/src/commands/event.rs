@@ -9,7 +9,9 @@ use uuid::Uuid;
 use crate::Cli;
 use crate::api::models::{EventFilters, EventPost, PaginationParams, base64_decode};
 use crate::commands::require_auth;
-use crate::output::{OutputFormat, TableOutput, output_many, output_one, output_success};
+use crate::output::{
+    OutputFormat, TableOutput, output_many, output_one, output_success, output_warning,
+};
 
 #[derive(Subcommand, Debug)]
 pub enum EventCommands {
@@ -47,6 +49,10 @@ pub struct SendArgs {
     /// Content type (default: application/json)
     #[arg(long, default_value = crate::api::models::CONTENT_TYPE_JSON)]
     pub content_type: String,
+
+    /// Version of the event type the payload follows
+    #[arg(long)]
+    pub event_type_version: Option<i32>,
 }
 
 #[derive(Args, Debug)]
@@ -114,9 +120,10 @@ async fn send(cli: &Cli, args: &SendArgs) -> Result<()> {
         )
     };
 
-    // Override event ID if provided
+    // Override event ID and event type version if provided
     let event = EventPost {
         event_id: args.event_id,
+        event_type_version: args.event_type_version,
         ..event
     };
 
@@ -133,6 +140,19 @@ async fn send(cli: &Cli, args: &SendArgs) -> Result<()> {
                 .as_deref()
                 .unwrap_or(&args.event_type)
         ));
+        if let Some(deprecation) = &result.deprecation {
+            output_warning(&format!(
+                "Version {} of this event type is deprecated{}",
+                deprecation.event_type_version,
+                deprecation
+                    .sunset_at
+                    .map(|sunset_at| format!(
+                        ": events declaring it will be refused from {}",
+                        sunset_at.to_rfc3339()
+                    ))
+                    .unwrap_or_default()
+            ));
+        }
     }
 
     Ok(())
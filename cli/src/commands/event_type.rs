@@ -1,8 +1,13 @@
 use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
 use clap::{Args, Subcommand};
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use crate::Cli;
-use crate::api::models::{EventType, EventTypePost};
+use crate::api::models::{
+    EventType, EventTypePost, EventTypePut, EventTypeVersionDeprecate, EventTypeVersionPost,
+};
 use crate::commands::require_auth;
 use crate::output::{output_many, output_one, output_success};
 
@@ -14,6 +19,18 @@ pub enum EventTypeCommands {
     /// List event types
     List(ListArgs),
 
+    /// Get event type details
+    Get(GetArgs),
+
+    /// Update the payload schema or the default time to live of an event type
+    Update(UpdateArgs),
+
+    /// Declare a new version of an event type
+    AddVersion(AddVersionArgs),
+
+    /// Deprecate a version of an event type
+    Deprecate(DeprecateArgs),
+
     /// Delete an event type
     Delete(DeleteArgs),
 }
@@ -34,6 +51,18 @@ pub struct CreateArgs {
     /// Verb name (alternative to full name)
     #[arg(long, short = 'b')]
     pub verb: Option<String>,
+
+    /// Read a JSON Schema the payloads of this event type must match from a file
+    #[arg(long)]
+    pub schema_file: Option<PathBuf>,
+
+    /// How the payload schema is applied at ingestion (default: enforce)
+    #[arg(long, value_parser = ["enforce", "warn", "off"])]
+    pub schema_mode: Option<String>,
+
+    /// Time to live in seconds of the events of this type that do not give an expiry date
+    #[arg(long, value_parser = clap::value_parser!(i32).range(1..))]
+    pub default_ttl: Option<i32>,
 }
 
 #[derive(Args, Debug)]
@@ -43,6 +72,66 @@ pub struct ListArgs {
     pub service: Option<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct GetArgs {
+    /// Event type name (e.g., user.account.created)
+    pub name: String,
+}
+
+#[derive(Args, Debug)]
+pub struct UpdateArgs {
+    /// Event type name (e.g., user.account.created)
+    pub name: String,
+
+    /// Read a new JSON Schema the payloads of this event type must match from a file
+    #[arg(long, conflicts_with = "no_schema")]
+    pub schema_file: Option<PathBuf>,
+
+    /// Remove the payload schema
+    #[arg(long)]
+    pub no_schema: bool,
+
+    /// How the payload schema is applied at ingestion
+    #[arg(long, value_parser = ["enforce", "warn", "off"])]
+    pub schema_mode: Option<String>,
+
+    /// New time to live in seconds of the events of this type that do not give an expiry date
+    #[arg(long, conflicts_with = "no_default_ttl", value_parser = clap::value_parser!(i32).range(1..))]
+    pub default_ttl: Option<i32>,
+
+    /// Remove the default time to live, so that events of this type only expire when they say so
+    #[arg(long)]
+    pub no_default_ttl: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct AddVersionArgs {
+    /// Event type name (e.g., user.account.created)
+    pub name: String,
+
+    /// Version number (1 or more)
+    #[arg(value_name = "VERSION")]
+    pub event_type_version: i32,
+
+    /// Read a JSON Schema the payloads declaring this version must match from a file
+    #[arg(long)]
+    pub schema_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct DeprecateArgs {
+    /// Event type name (e.g., user.account.created)
+    pub name: String,
+
+    /// Version number
+    #[arg(value_name = "VERSION")]
+    pub event_type_version: i32,
+
+    /// Date from which events declaring this version are refused (RFC 3339)
+    #[arg(long)]
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Args, Debug)]
 pub struct DeleteArgs {
     /// Event type name (e.g., user.account.created)
@@ -57,6 +146,10 @@ pub async fn execute(cli: &Cli, cmd: &EventTypeCommands) -> Result<()> {
     match cmd {
         EventTypeCommands::Create(args) => create(cli, args).await,
         EventTypeCommands::List(args) => list(cli, args).await,
+        EventTypeCommands::Get(args) => get(cli, args).await,
+        EventTypeCommands::Update(args) => update(cli, args).await,
+        EventTypeCommands::AddVersion(args) => add_version(cli, args).await,
+        EventTypeCommands::Deprecate(args) => deprecate(cli, args).await,
         EventTypeCommands::Delete(args) => delete(cli, args).await,
     }
 }
@@ -89,6 +182,9 @@ async fn create(cli: &Cli, args: &CreateArgs) -> Result<()> {
         service,
         resource_type: resource,
         verb,
+        payload_schema: args.schema_file.as_deref().map(read_schema).transpose()?,
+        payload_schema_mode: args.schema_mode.clone(),
+        default_ttl_s: args.default_ttl,
     };
 
     let result = client.create_event_type(&event_type_post).await?;
@@ -120,6 +216,122 @@ async fn list(cli: &Cli, args: &ListArgs) -> Result<()> {
     Ok(())
 }
 
+async fn get(cli: &Cli, args: &GetArgs) -> Result<()> {
+    let (client, _, profile) = require_auth(cli)?;
+
+    let event_type = client
+        .get_event_type(&profile.application_id, &args.name)
+        .await?;
+
+    output_one(&event_type, cli.output);
+
+    Ok(())
+}
+
+async fn update(cli: &Cli, args: &UpdateArgs) -> Result<()> {
+    let (client, _, profile) = require_auth(cli)?;
+
+    // The update replaces the schema, its mode and the default time to live, so whatever is not given is kept as it is
+    let current = client
+        .get_event_type(&profile.application_id, &args.name)
+        .await?;
+
+    let payload_schema = if args.no_schema {
+        None
+    } else if let Some(path) = &args.schema_file {
+        Some(read_schema(path)?)
+    } else {
+        current.payload_schema
+    };
+
+    let event_type_put = EventTypePut {
+        application_id: profile.application_id,
+        payload_schema,
+        payload_schema_mode: args.schema_mode.clone().or(current.payload_schema_mode),
+        default_ttl_s: if args.no_default_ttl {
+            None
+        } else {
+            args.default_ttl.or(current.default_ttl_s)
+        },
+    };
+
+    let result = client
+        .update_event_type(&args.name, &event_type_put)
+        .await?;
+
+    if cli.output == crate::output::OutputFormat::Json {
+        output_one(&result, cli.output);
+    } else {
+        output_success(&format!(
+            "Event type '{}' updated successfully!",
+            result.full_name()
+        ));
+    }
+
+    Ok(())
+}
+
+async fn add_version(cli: &Cli, args: &AddVersionArgs) -> Result<()> {
+    let (client, _, profile) = require_auth(cli)?;
+
+    let version_post = EventTypeVersionPost {
+        application_id: profile.application_id,
+        version: args.event_type_version,
+        payload_schema: args.schema_file.as_deref().map(read_schema).transpose()?,
+    };
+
+    let result = client
+        .create_event_type_version(&args.name, &version_post)
+        .await?;
+
+    if cli.output == crate::output::OutputFormat::Json {
+        output_one(&result, cli.output);
+    } else {
+        output_success(&format!(
+            "Version {} of event type '{}' created successfully!",
+            result.version, args.name
+        ));
+    }
+
+    Ok(())
+}
+
+async fn deprecate(cli: &Cli, args: &DeprecateArgs) -> Result<()> {
+    let (client, _, profile) = require_auth(cli)?;
+
+    let deprecation = EventTypeVersionDeprecate {
+        application_id: profile.application_id,
+        version: args.event_type_version,
+        sunset_at: args.sunset_at,
+    };
+
+    let result = client
+        .deprecate_event_type_version(&args.name, &deprecation)
+        .await?;
+
+    if cli.output == crate::output::OutputFormat::Json {
+        output_one(&result, cli.output);
+    } else {
+        output_success(&format!(
+            "Version {} of event type '{}' deprecated{}",
+            result.version,
+            args.name,
+            result
+                .sunset_at
+                .map(|sunset_at| format!(", sunset on {}", sunset_at.to_rfc3339()))
+                .unwrap_or_default()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Read a JSON Schema from a file
+fn read_schema(path: &Path) -> Result<serde_json::Value> {
+    let schema = fs::read_to_string(path)?;
+    serde_json::from_str(&schema).map_err(|e| anyhow!("Invalid JSON schema file: {}", e))
+}
+
 async fn delete(cli: &Cli, args: &DeleteArgs) -> Result<()> {
     let (client, _, profile) = require_auth(cli)?;
 
@@ -1,11 +1,14 @@
 use anyhow::{Result, anyhow};
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use clap::Args;
+use indicatif::{ProgressBar, ProgressStyle};
 use uuid::Uuid;
 
 use crate::Cli;
-use crate::api::models::{EventFilters, PaginationParams};
+use crate::api::ApiClient;
+use crate::api::models::{BulkRetryPost, EventFilters, PaginationParams};
 use crate::commands::require_auth;
+use crate::config::Profile;
 use crate::output::{OutputFormat, output_many, output_success, output_warning};
 
 #[derive(Args, Debug)]
@@ -44,6 +47,14 @@ pub struct ReplayArgs {
     /// Maximum number of events to replay
     #[arg(long, default_value = "100")]
     pub limit: i32,
+
+    /// Let the server retry every matching delivery in the background instead of replaying events one by one (--limit does not apply)
+    #[arg(long, requires = "all")]
+    pub server_side: bool,
+
+    /// Only retry deliveries of this subscription (requires --server-side)
+    #[arg(long, requires = "server_side")]
+    pub subscription: Option<Uuid>,
 }
 
 /// Parse a duration string (e.g., 1h, 24h, 7d)
@@ -127,26 +138,29 @@ pub async fn execute(cli: &Cli, args: &ReplayArgs) -> Result<()> {
         ));
     }
 
+    // Parse since/until
+    let since = match &args.since {
+        Some(since_str) => Some(Utc::now() - parse_duration(since_str)?),
+        None => None,
+    };
+    let until = match &args.until {
+        Some(until_str) => Some(Utc::now() - parse_duration(until_str)?),
+        None => None,
+    };
+
+    if args.server_side {
+        return execute_server_side(cli, &client, &profile, args, since, until).await;
+    }
+
     // Build filters
-    let mut filters = EventFilters {
+    let filters = EventFilters {
         event_type: args.event_type.clone(),
         status: args.status.clone(),
-        since: None,
-        until: None,
+        since,
+        until,
         labels: std::collections::HashMap::new(),
     };
 
-    // Parse since/until
-    if let Some(since_str) = &args.since {
-        let duration = parse_duration(since_str)?;
-        filters.since = Some(Utc::now() - duration);
-    }
-
-    if let Some(until_str) = &args.until {
-        let duration = parse_duration(until_str)?;
-        filters.until = Some(Utc::now() - duration);
-    }
-
     let pagination = PaginationParams::new(Some(1), Some(args.limit));
 
     // Get events matching criteria
@@ -232,6 +246,86 @@ pub async fn execute(cli: &Cli, args: &ReplayArgs) -> Result<()> {
     Ok(())
 }
 
+/// Bulk replay delegated to the API: the server walks the matching deliveries at its own pace
+async fn execute_server_side(
+    cli: &Cli,
+    client: &ApiClient,
+    profile: &Profile,
+    args: &ReplayArgs,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) -> Result<()> {
+    let status = match args.status.as_deref() {
+        None | Some("failed") => "failed",
+        Some("successful") => "successful",
+        Some(other) => {
+            return Err(anyhow!(
+                "Invalid status '{}' for a server-side replay. Use failed or successful",
+                other
+            ));
+        }
+    };
+    let bulk_retry = BulkRetryPost {
+        application_id: profile.application_id,
+        subscription_id: args.subscription,
+        event_type_names: args.event_type.clone().map(|event_type| vec![event_type]),
+        min_created_at: since,
+        max_created_at: until,
+        status: Some(status.to_owned()),
+    };
+
+    if args.dry_run {
+        let dry_run = client.dry_run_bulk_retry(&bulk_retry).await?;
+        if cli.output == OutputFormat::Json {
+            println!(
+                "{}",
+                serde_json::json!({"dry_run": true, "count": dry_run.count})
+            );
+        } else {
+            println!("Would retry {} delivery(ies)", dry_run.count);
+        }
+        return Ok(());
+    }
+
+    let mut bulk_retry = client.create_bulk_retry(&bulk_retry).await?;
+    if cli.output == OutputFormat::Json {
+        println!("{}", serde_json::to_string(&bulk_retry)?);
+        return Ok(());
+    }
+
+    println!(
+        "Bulk retry {} started ({} delivery(ies) to retry)",
+        bulk_retry.bulk_retry_id, bulk_retry.total_count
+    );
+    let pb = ProgressBar::new(bulk_retry.total_count.unsigned_abs());
+    pb.set_style(
+        ProgressStyle::with_template("  {bar:40.cyan/blue} {pos}/{len} {msg}")
+            .expect("valid template"),
+    );
+    while bulk_retry.is_running() {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        bulk_retry = client
+            .get_bulk_retry(&bulk_retry.bulk_retry_id, &profile.application_id)
+            .await?;
+        pb.set_position(bulk_retry.processed_count.unsigned_abs());
+    }
+    pb.finish_and_clear();
+
+    if bulk_retry.cancelled_at.is_some() {
+        output_warning(&format!(
+            "Bulk retry {} was cancelled after {} delivery(ies) were retried",
+            bulk_retry.bulk_retry_id, bulk_retry.retried_count
+        ));
+    } else {
+        output_success(&format!(
+            "Bulk retry complete!\n  Deliveries retried: {}",
+            bulk_retry.retried_count
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
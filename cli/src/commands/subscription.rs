@@ -5,7 +5,7 @@ use clap::{Args, Subcommand};
 use uuid::Uuid;
 
 use crate::Cli;
-use crate::api::models::{SubscriptionPost, SubscriptionPut, Target};
+use crate::api::models::{SecretRotationPost, SubscriptionPost, SubscriptionPut, Target};
 use crate::commands::require_auth;
 use crate::output::{OutputFormat, TableOutput, output_many, output_one, output_success};
 
@@ -31,6 +31,15 @@ pub enum SubscriptionCommands {
 
     /// Disable a subscription
     Disable(DisableArgs),
+
+    /// Rotate the secret of a subscription, keeping the previous one valid for a while
+    RotateSecret(RotateSecretArgs),
+
+    /// Finalize the secret rotation of a subscription: the previous secret stops being valid
+    FinalizeSecretRotation(SecretRotationArgs),
+
+    /// Roll back the secret rotation of a subscription: the previous secret becomes the secret again
+    RollbackSecretRotation(SecretRotationArgs),
 }
 
 #[derive(Args, Debug)]
@@ -55,6 +64,10 @@ pub struct CreateArgs {
     #[arg(long, short = 'H', value_parser = parse_label)]
     pub header: Vec<(String, String)>,
 
+    /// Only receive this version of an event type, in event_type=version format (can be repeated)
+    #[arg(long, value_parser = parse_pin)]
+    pub pin: Vec<(String, i32)>,
+
     /// Description
     #[arg(long, short = 'd')]
     pub description: Option<String>,
@@ -106,6 +119,14 @@ pub struct UpdateArgs {
     #[arg(long, short = 'H', value_parser = parse_label)]
     pub header: Vec<(String, String)>,
 
+    /// Pinned event type versions in event_type=version format (replaces existing)
+    #[arg(long, value_parser = parse_pin, conflicts_with = "no_pins")]
+    pub pin: Vec<(String, i32)>,
+
+    /// Remove all pinned event type versions
+    #[arg(long)]
+    pub no_pins: bool,
+
     /// Description
     #[arg(long, short = 'd')]
     pub description: Option<String>,
@@ -141,6 +162,22 @@ pub struct DisableArgs {
     pub subscription_id: Uuid,
 }
 
+#[derive(Args, Debug)]
+pub struct RotateSecretArgs {
+    /// Subscription ID
+    pub subscription_id: Uuid,
+
+    /// How long the previous secret stays valid, in seconds (default: one day)
+    #[arg(long)]
+    pub overlap_seconds: Option<i32>,
+}
+
+#[derive(Args, Debug)]
+pub struct SecretRotationArgs {
+    /// Subscription ID
+    pub subscription_id: Uuid,
+}
+
 /// Parse a label in key=value format
 fn parse_label(s: &str) -> Result<(String, String), String> {
     let parts: Vec<&str> = s.splitn(2, '=').collect();
@@ -150,6 +187,16 @@ fn parse_label(s: &str) -> Result<(String, String), String> {
     Ok((parts[0].to_string(), parts[1].to_string()))
 }
 
+fn parse_pin(s: &str) -> Result<(String, i32), String> {
+    let (event_type, version) = s
+        .split_once('=')
+        .ok_or_else(|| "Must be in event_type=version format".to_string())?;
+    let version = version
+        .parse()
+        .map_err(|_| format!("Invalid version '{version}'"))?;
+    Ok((event_type.to_string(), version))
+}
+
 pub async fn execute(cli: &Cli, cmd: &SubscriptionCommands) -> Result<()> {
     match cmd {
         SubscriptionCommands::Create(args) => create(cli, args).await,
@@ -159,6 +206,13 @@ pub async fn execute(cli: &Cli, cmd: &SubscriptionCommands) -> Result<()> {
         SubscriptionCommands::Delete(args) => delete(cli, args).await,
         SubscriptionCommands::Enable(args) => enable(cli, args).await,
         SubscriptionCommands::Disable(args) => disable(cli, args).await,
+        SubscriptionCommands::RotateSecret(args) => rotate_secret(cli, args).await,
+        SubscriptionCommands::FinalizeSecretRotation(args) => {
+            finalize_secret_rotation(cli, args).await
+        }
+        SubscriptionCommands::RollbackSecretRotation(args) => {
+            rollback_secret_rotation(cli, args).await
+        }
     }
 }
 
@@ -174,6 +228,11 @@ async fn create(cli: &Cli, args: &CreateArgs) -> Result<()> {
     let subscription = SubscriptionPost {
         application_id: profile.application_id,
         event_types: args.events.clone(),
+        event_type_versions: if args.pin.is_empty() {
+            None
+        } else {
+            Some(args.pin.iter().cloned().collect())
+        },
         is_enabled: !args.disabled,
         description: args.description.clone(),
         labels: if labels.is_empty() {
@@ -184,6 +243,14 @@ async fn create(cli: &Cli, args: &CreateArgs) -> Result<()> {
         metadata: None,
         target: Target::http_with_headers(args.url.clone(), args.method.clone(), headers),
         dedicated_workers: None,
+        payload_transform: None,
+        filter: None,
+        circuit_breaker: None,
+        retry_policy: None,
+        delivery_limits: None,
+        ordering_key: None,
+        batching: None,
+        response_rules: None,
     };
 
     let result = client.create_subscription(&subscription).await?;
@@ -279,6 +346,20 @@ async fn get(cli: &Cli, args: &GetArgs) -> Result<()> {
                     .unwrap_or_else(|| "-".to_string()),
             ),
             ("Event Types", subscription.event_types.join(", ")),
+            (
+                "Pinned Versions",
+                if subscription.event_type_versions.is_empty() {
+                    "-".to_string()
+                } else {
+                    let mut pins: Vec<String> = subscription
+                        .event_type_versions
+                        .iter()
+                        .map(|(event_type, version)| format!("{event_type}=v{version}"))
+                        .collect();
+                    pins.sort();
+                    pins.join(", ")
+                },
+            ),
             ("Target", target_info),
             (
                 "Labels",
@@ -288,6 +369,18 @@ async fn get(cli: &Cli, args: &GetArgs) -> Result<()> {
                     labels_str
                 },
             ),
+            (
+                "Disabled By Circuit Breaker",
+                match (
+                    &subscription.auto_disabled_at,
+                    &subscription.auto_disabled_reason,
+                ) {
+                    (Some(at), reason) => {
+                        format!("{} ({})", at.to_rfc3339(), reason.as_deref().unwrap_or("-"))
+                    }
+                    (None, _) => "-".to_string(),
+                },
+            ),
             ("Secret", subscription.secret.to_string()),
             ("Created At", subscription.created_at.to_rfc3339()),
         ]);
@@ -337,9 +430,24 @@ async fn update(cli: &Cli, args: &UpdateArgs) -> Result<()> {
         args.label.iter().cloned().collect()
     };
 
+    let event_types = args.events.clone().unwrap_or(current.event_types);
+    // Pins of event types the subscription no longer receives are dropped
+    let event_type_versions: HashMap<String, i32> = if args.no_pins {
+        HashMap::new()
+    } else if args.pin.is_empty() {
+        current
+            .event_type_versions
+            .into_iter()
+            .filter(|(event_type, _)| event_types.contains(event_type))
+            .collect()
+    } else {
+        args.pin.iter().cloned().collect()
+    };
+
     let update = SubscriptionPut {
         application_id: current.application_id,
-        event_types: args.events.clone().unwrap_or(current.event_types),
+        event_types,
+        event_type_versions: Some(event_type_versions),
         is_enabled,
         description: args.description.clone().or(current.description),
         labels: Some(new_labels),
@@ -350,6 +458,14 @@ async fn update(cli: &Cli, args: &UpdateArgs) -> Result<()> {
         } else {
             Some(current.dedicated_workers)
         },
+        payload_transform: current.payload_transform,
+        filter: current.filter,
+        circuit_breaker: current.circuit_breaker,
+        retry_policy: current.retry_policy,
+        delivery_limits: current.delivery_limits,
+        ordering_key: current.ordering_key,
+        batching: current.batching,
+        response_rules: current.response_rules,
     };
 
     let result = client
@@ -428,6 +544,60 @@ async fn disable(cli: &Cli, args: &DisableArgs) -> Result<()> {
     Ok(())
 }
 
+async fn rotate_secret(cli: &Cli, args: &RotateSecretArgs) -> Result<()> {
+    let (client, _, profile) = require_auth(cli)?;
+
+    let rotation = SecretRotationPost {
+        overlap_seconds: args.overlap_seconds,
+    };
+    let result = client
+        .rotate_subscription_secret(&args.subscription_id, &profile.application_id, &rotation)
+        .await?;
+
+    if cli.output == OutputFormat::Json {
+        output_one(&result, cli.output);
+    } else {
+        output_success(&format!(
+            "Secret of subscription {} rotated!\n  New secret: {}\n  Previous secret valid until: {}",
+            result.subscription_id,
+            result.secret,
+            result.previous_secret_expires_at.to_rfc3339()
+        ));
+    }
+
+    Ok(())
+}
+
+async fn finalize_secret_rotation(cli: &Cli, args: &SecretRotationArgs) -> Result<()> {
+    let (client, _, profile) = require_auth(cli)?;
+
+    client
+        .finalize_secret_rotation(&args.subscription_id, &profile.application_id)
+        .await?;
+
+    output_success(&format!(
+        "Secret rotation of subscription {} finalized: the previous secret is no longer valid",
+        args.subscription_id
+    ));
+
+    Ok(())
+}
+
+async fn rollback_secret_rotation(cli: &Cli, args: &SecretRotationArgs) -> Result<()> {
+    let (client, _, profile) = require_auth(cli)?;
+
+    client
+        .rollback_secret_rotation(&args.subscription_id, &profile.application_id)
+        .await?;
+
+    output_success(&format!(
+        "Secret rotation of subscription {} rolled back: the previous secret is the secret again",
+        args.subscription_id
+    ));
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
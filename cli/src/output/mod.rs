@@ -135,7 +135,15 @@ impl Outputable for Organization {
 
 impl Outputable for EventType {
     fn table_headers() -> Vec<&'static str> {
-        vec!["Event Type", "Service", "Resource", "Verb"]
+        vec![
+            "Event Type",
+            "Service",
+            "Resource",
+            "Verb",
+            "Schema",
+            "Default TTL",
+            "Versions",
+        ]
     }
 
     fn table_row(&self) -> Vec<String> {
@@ -144,6 +152,26 @@ impl Outputable for EventType {
             self.service_name.clone(),
             self.resource_type_name.clone(),
             self.verb_name.clone(),
+            match (&self.payload_schema, &self.payload_schema_mode) {
+                (Some(_), Some(mode)) => mode.clone(),
+                (Some(_), None) => "yes".to_string(),
+                (None, _) => "-".to_string(),
+            },
+            self.default_ttl_s
+                .map(|ttl| format!("{ttl}s"))
+                .unwrap_or_else(|| "-".to_string()),
+            if self.versions.is_empty() {
+                "-".to_string()
+            } else {
+                self.versions
+                    .iter()
+                    .map(|v| match v.deprecated_at {
+                        Some(_) => format!("v{} (deprecated)", v.version),
+                        None => format!("v{}", v.version),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
         ]
     }
 
@@ -152,6 +180,34 @@ impl Outputable for EventType {
     }
 }
 
+impl Outputable for EventTypeVersion {
+    fn table_headers() -> Vec<&'static str> {
+        vec!["Version", "Schema", "Deprecated At", "Sunset At"]
+    }
+
+    fn table_row(&self) -> Vec<String> {
+        let date = |d: Option<chrono::DateTime<chrono::Utc>>| {
+            d.map(|d| d.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_else(|| "-".to_string())
+        };
+        vec![
+            self.version.to_string(),
+            if self.payload_schema.is_some() {
+                "yes"
+            } else {
+                "-"
+            }
+            .to_string(),
+            date(self.deprecated_at),
+            date(self.sunset_at),
+        ]
+    }
+
+    fn compact_line(&self) -> String {
+        format!("v{}", self.version)
+    }
+}
+
 impl Outputable for Event {
     fn table_headers() -> Vec<&'static str> {
         vec!["ID", "Type", "Occurred At", "Labels"]
@@ -238,6 +294,37 @@ impl Outputable for Subscription {
     }
 }
 
+impl Outputable for SecretRotation {
+    fn table_headers() -> Vec<&'static str> {
+        vec![
+            "Subscription ID",
+            "Secret",
+            "Previous Secret",
+            "Previous Expires At",
+        ]
+    }
+
+    fn table_row(&self) -> Vec<String> {
+        vec![
+            self.subscription_id.to_string(),
+            self.secret.to_string(),
+            self.previous_secret.to_string(),
+            self.previous_secret_expires_at
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+        ]
+    }
+
+    fn compact_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}",
+            self.subscription_id,
+            self.secret,
+            self.previous_secret_expires_at.to_rfc3339()
+        )
+    }
+}
+
 impl Outputable for RequestAttempt {
     fn table_headers() -> Vec<&'static str> {
         vec!["ID", "Event ID", "Status", "Retry", "Created At"]
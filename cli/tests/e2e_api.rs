@@ -1019,6 +1019,26 @@ fn test_replay_dry_run() {
         .assert()
         .success();
 
+    // --all --server-side --dry-run counts the deliveries the API would retry
+    cli(cfg.path())
+        .args([
+            "--profile",
+            &prof,
+            "--output",
+            "json",
+            "replay",
+            "--all",
+            "--server-side",
+            "--dry-run",
+            "--since",
+            "1h",
+            "--status",
+            "failed",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"count\""));
+
     // Single event ID --dry-run
     cli(cfg.path())
         .args([
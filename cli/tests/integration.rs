@@ -118,6 +118,16 @@ fn test_subscription_list_requires_auth() {
         .stderr(predicate::str::contains("login"));
 }
 
+/// Test that a server-side replay only applies to bulk replays
+#[test]
+fn test_replay_server_side_requires_all() {
+    unauthenticated_cmd()
+        .args(["replay", "--server-side", "--dry-run"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--all"));
+}
+
 /// Test that event-type list requires authentication
 #[test]
 fn test_event_type_list_requires_auth() {
@@ -143,6 +143,233 @@ public sealed class ApplicationsApi(ITransport transport)
     }
 }
 
+/// <summary>What the API declares under <c>bulkRetries</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class BulkRetriesApi(ITransport transport)
+{
+    private readonly ITransport _transport = transport;
+
+    /// <summary>Cancel a bulk retry</summary>
+    /// <param name="bulkRetryId">Carries <c>bulk_retry_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public BulkRetry Cancel(string bulkRetryId, string applicationId)
+    {
+        return Problems.ReadAnswer<BulkRetry>(_transport.Request(
+            "POST",
+            Runtime.Path("/api/v1/bulk_retries/{bulk_retry_id}/cancel", [("bulk_retry_id", bulkRetryId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Create a bulk retry</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public BulkRetry Create(BulkRetryPost body)
+    {
+        return Problems.ReadAnswer<BulkRetry>(_transport.Request(
+            "POST",
+            "/api/v1/bulk_retries/",
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
+    /// <summary>Count what a bulk retry would redeliver</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public BulkRetryDryRun DryRun(BulkRetryPost body)
+    {
+        return Problems.ReadAnswer<BulkRetryDryRun>(_transport.Request(
+            "POST",
+            "/api/v1/bulk_retries/dry_run",
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
+    /// <summary>Get a bulk retry</summary>
+    /// <param name="bulkRetryId">Carries <c>bulk_retry_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public BulkRetry Get(string bulkRetryId, string applicationId)
+    {
+        return Problems.ReadAnswer<BulkRetry>(_transport.Request(
+            "GET",
+            Runtime.Path("/api/v1/bulk_retries/{bulk_retry_id}", [("bulk_retry_id", bulkRetryId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>List bulk retries</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public IReadOnlyList<BulkRetry> List(string applicationId)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<BulkRetry>>(_transport.Request(
+            "GET",
+            "/api/v1/bulk_retries/",
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+}
+
+/// <summary>What the API declares under <c>clientCertificates</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class ClientCertificatesApi(ITransport transport)
+{
+    private readonly ITransport _transport = transport;
+
+    /// <summary>Remove the client certificate of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    public void Delete(string subscriptionId, string applicationId)
+    {
+        Problems.CheckAnswer(_transport.Request(
+            "DELETE",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/client_certificate",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Get the client certificate of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public ClientCertificate Get(string subscriptionId, string applicationId)
+    {
+        return Problems.ReadAnswer<ClientCertificate>(_transport.Request(
+            "GET",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/client_certificate",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Set the client certificate of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public ClientCertificate Update(string subscriptionId, string applicationId, ClientCertificatePut body)
+    {
+        return Problems.ReadAnswer<ClientCertificate>(_transport.Request(
+            "PUT",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/client_certificate",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            body
+        ));
+    }
+}
+
+/// <summary>What the API declares under <c>deadLetters</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class DeadLettersApi(ITransport transport)
+{
+    private readonly ITransport _transport = transport;
+
+    /// <summary>Delete a dead letter</summary>
+    /// <param name="deadLetterId">Carries <c>dead_letter_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    public void Delete(string deadLetterId, string applicationId)
+    {
+        Problems.CheckAnswer(_transport.Request(
+            "DELETE",
+            Runtime.Path("/api/v1/dead_letters/{dead_letter_id}", [("dead_letter_id", deadLetterId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Get a dead letter by its ID</summary>
+    /// <param name="deadLetterId">Carries <c>dead_letter_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public DeadLetterDetails Get(string deadLetterId, string applicationId)
+    {
+        return Problems.ReadAnswer<DeadLetterDetails>(_transport.Request(
+            "GET",
+            Runtime.Path("/api/v1/dead_letters/{dead_letter_id}", [("dead_letter_id", deadLetterId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>List dead letters</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="paginationCursor">Carries <c>pagination_cursor</c>, when the caller passes one.</param>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>, when the caller passes one.</param>
+    /// <returns>What the API answered.</returns>
+    public IReadOnlyList<DeadLetter> List(
+        string applicationId,
+        string? paginationCursor = null,
+        string? subscriptionId = null)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<DeadLetter>>(_transport.Request(
+            "GET",
+            "/api/v1/dead_letters/",
+            Runtime.Query(
+                [("application_id", applicationId)],
+                [("pagination_cursor", paginationCursor), ("subscription_id", subscriptionId)]
+            ),
+            null
+        ));
+    }
+
+    /// <summary>Purge dead letters</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public PurgedDeadLetters Purge(PurgeDeadLetters body)
+    {
+        return Problems.ReadAnswer<PurgedDeadLetters>(_transport.Request(
+            "POST",
+            "/api/v1/dead_letters/purge",
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
+    /// <summary>Redeliver a dead letter</summary>
+    /// <param name="deadLetterId">Carries <c>dead_letter_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public RedeliveredDeadLetter Redeliver(string deadLetterId, RedeliverDeadLetter body)
+    {
+        return Problems.ReadAnswer<RedeliveredDeadLetter>(_transport.Request(
+            "POST",
+            Runtime.Path("/api/v1/dead_letters/{dead_letter_id}/redeliver", [("dead_letter_id", deadLetterId)]),
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
+    /// <summary>Redeliver dead letters</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public IReadOnlyList<RedeliveredDeadLetter> RedeliverMany(RedeliverDeadLetters body)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<RedeliveredDeadLetter>>(_transport.Request(
+            "POST",
+            "/api/v1/dead_letters/redeliver",
+            Runtime.Query([], []),
+            body
+        ));
+    }
+}
+
 /// <summary>What the API declares under <c>errors</c>, issued through the transport it is handed.</summary>
 /// <param name="transport">What one request is issued through.</param>
 public sealed class ErrorsApi(ITransport transport)
@@ -181,6 +408,20 @@ public sealed class EventTypesApi(ITransport transport)
         ));
     }
 
+    /// <summary>Create a new event type version</summary>
+    /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public EventTypeVersion CreateVersion(string eventTypeName, EventTypeVersionPost body)
+    {
+        return Problems.ReadAnswer<EventTypeVersion>(_transport.Request(
+            "POST",
+            Runtime.Path("/api/v1/event_types/{event_type_name}/versions", [("event_type_name", eventTypeName)]),
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
     /// <summary>Delete an event type</summary>
     /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
@@ -194,6 +435,20 @@ public sealed class EventTypesApi(ITransport transport)
         ));
     }
 
+    /// <summary>Deprecate an event type version</summary>
+    /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public EventTypeVersion DeprecateVersion(string eventTypeName, EventTypeVersionDeprecate body)
+    {
+        return Problems.ReadAnswer<EventTypeVersion>(_transport.Request(
+            "POST",
+            Runtime.Path("/api/v1/event_types/{event_type_name}/deprecate", [("event_type_name", eventTypeName)]),
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
     /// <summary>Get an event type by its name</summary>
     /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
@@ -220,6 +475,20 @@ public sealed class EventTypesApi(ITransport transport)
             null
         ));
     }
+
+    /// <summary>Update an event type</summary>
+    /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public EventType Update(string eventTypeName, EventTypePut body)
+    {
+        return Problems.ReadAnswer<EventType>(_transport.Request(
+            "PUT",
+            Runtime.Path("/api/v1/event_types/{event_type_name}", [("event_type_name", eventTypeName)]),
+            Runtime.Query([], []),
+            body
+        ));
+    }
 }
 
 /// <summary>What the API declares under <c>events</c>, issued through the transport it is handed.</summary>
@@ -228,6 +497,19 @@ public sealed class EventsApi(ITransport transport)
 {
     private readonly ITransport _transport = transport;
 
+    /// <summary>Cancel a scheduled event</summary>
+    /// <param name="eventId">Carries <c>event_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    public void Cancel(string eventId, CancelEvent body)
+    {
+        Problems.CheckAnswer(_transport.Request(
+            "POST",
+            Runtime.Path("/api/v1/events/{event_id}/cancel", [("event_id", eventId)]),
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
     /// <summary>Get an event by its ID</summary>
     /// <param name="eventId">Carries <c>event_id</c>.</param>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
@@ -255,15 +537,32 @@ public sealed class EventsApi(ITransport transport)
         ));
     }
 
+    /// <summary>Ingest a batch of events</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public IngestedEventsBatch IngestBatch(EventsBatchPost body)
+    {
+        return Problems.ReadAnswer<IngestedEventsBatch>(_transport.Request(
+            "POST",
+            "/api/v1/events/batch",
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
     /// <summary>List latest events</summary>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="scheduled">
+    /// Carries <c>scheduled</c>, when the caller passes one: Only list the events scheduled for a later delivery that
+    /// was not cancelled, soonest first
+    /// </param>
     /// <returns>What the API answered.</returns>
-    public IReadOnlyList<Event> List(string applicationId)
+    public IReadOnlyList<Event> List(string applicationId, bool? scheduled = null)
     {
         return Problems.ReadAnswer<IReadOnlyList<Event>>(_transport.Request(
             "GET",
             "/api/v1/events/",
-            Runtime.Query([("application_id", applicationId)], []),
+            Runtime.Query([("application_id", applicationId)], [("scheduled", scheduled)]),
             null
         ));
     }
@@ -335,6 +634,74 @@ public sealed class EventsPerDayApi(ITransport transport)
     }
 }
 
+/// <summary>What the API declares under <c>inbox</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class InboxApi(ITransport transport)
+{
+    private readonly ITransport _transport = transport;
+
+    /// <summary>Acknowledge events pulled from an inbox</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public InboxSettlement Ack(string subscriptionId, InboxAck body)
+    {
+        return Problems.ReadAnswer<InboxSettlement>(_transport.Request(
+            "POST",
+            Runtime.Path("/api/v1/inbox/{subscription_id}/ack", [("subscription_id", subscriptionId)]),
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
+    /// <summary>Reject events pulled from an inbox</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public InboxSettlement Nack(string subscriptionId, InboxNack body)
+    {
+        return Problems.ReadAnswer<InboxSettlement>(_transport.Request(
+            "POST",
+            Runtime.Path("/api/v1/inbox/{subscription_id}/nack", [("subscription_id", subscriptionId)]),
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
+    /// <summary>Pull events from an inbox</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cursor">
+    /// Carries <c>cursor</c>, when the caller passes one: Only lease events after this cursor, returned by a previous
+    /// pull
+    /// </param>
+    /// <param name="limit">
+    /// Carries <c>limit</c>, when the caller passes one: Maximum number of events to lease (10 by default)
+    /// </param>
+    /// <param name="visibilityTimeoutS">
+    /// Carries <c>visibility_timeout_s</c>, when the caller passes one: For how long in seconds the leased events are
+    /// not offered again (30 by default)
+    /// </param>
+    /// <returns>What the API answered.</returns>
+    public Inbox Pull(
+        string subscriptionId,
+        string applicationId,
+        string? cursor = null,
+        long? limit = null,
+        long? visibilityTimeoutS = null)
+    {
+        return Problems.ReadAnswer<Inbox>(_transport.Request(
+            "GET",
+            Runtime.Path("/api/v1/inbox/{subscription_id}", [("subscription_id", subscriptionId)]),
+            Runtime.Query(
+                [("application_id", applicationId)],
+                [("cursor", cursor), ("limit", limit), ("visibility_timeout_s", visibilityTimeoutS)]
+            ),
+            null
+        ));
+    }
+}
+
 /// <summary>What the API declares under <c>instance</c>, issued through the transport it is handed.</summary>
 /// <param name="transport">What one request is issued through.</param>
 public sealed class InstanceApi(ITransport transport)
@@ -354,6 +721,66 @@ public sealed class InstanceApi(ITransport transport)
     }
 }
 
+/// <summary>
+/// What the API declares under <c>oauth2ClientCredentials</c>, issued through the transport it is handed.
+/// </summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class Oauth2ClientCredentialsApi(ITransport transport)
+{
+    private readonly ITransport _transport = transport;
+
+    /// <summary>Remove the OAuth2 client credentials of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    public void Delete(string subscriptionId, string applicationId)
+    {
+        Problems.CheckAnswer(_transport.Request(
+            "DELETE",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Get the OAuth2 client credentials of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public Oauth2ClientCredentials Get(string subscriptionId, string applicationId)
+    {
+        return Problems.ReadAnswer<Oauth2ClientCredentials>(_transport.Request(
+            "GET",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Set the OAuth2 client credentials of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public Oauth2ClientCredentials Update(string subscriptionId, string applicationId, Oauth2ClientCredentialsPut body)
+    {
+        return Problems.ReadAnswer<Oauth2ClientCredentials>(_transport.Request(
+            "PUT",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            body
+        ));
+    }
+}
+
 /// <summary>
 /// What the API declares under <c>payload_content_types</c>, issued through the transport it is handed.
 /// </summary>
@@ -451,6 +878,23 @@ public sealed class RequestAttemptsApi(ITransport transport)
             null
         ));
     }
+
+    /// <summary>Retry a request attempt</summary>
+    /// <param name="requestAttemptId">Carries <c>request_attempt_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public RequestAttempt Retry(string requestAttemptId, RetryRequestAttempt body)
+    {
+        return Problems.ReadAnswer<RequestAttempt>(_transport.Request(
+            "POST",
+            Runtime.Path(
+                "/api/v1/request_attempts/{request_attempt_id}/retry",
+                [("request_attempt_id", requestAttemptId)]
+            ),
+            Runtime.Query([], []),
+            body
+        ));
+    }
 }
 
 /// <summary>What the API declares under <c>response</c>, issued through the transport it is handed.</summary>
@@ -474,6 +918,80 @@ public sealed class ResponseApi(ITransport transport)
     }
 }
 
+/// <summary>What the API declares under <c>secretRotations</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class SecretRotationsApi(ITransport transport)
+{
+    private readonly ITransport _transport = transport;
+
+    /// <summary>Rotate the secret of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public SecretRotation Create(string subscriptionId, string applicationId, SecretRotationPost body)
+    {
+        return Problems.ReadAnswer<SecretRotation>(_transport.Request(
+            "POST",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/secret_rotation",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            body
+        ));
+    }
+
+    /// <summary>Finalize the secret rotation of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    public void Finalize(string subscriptionId, string applicationId)
+    {
+        Problems.CheckAnswer(_transport.Request(
+            "POST",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Get the secret rotation of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public SecretRotation Get(string subscriptionId, string applicationId)
+    {
+        return Problems.ReadAnswer<SecretRotation>(_transport.Request(
+            "GET",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/secret_rotation",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Roll back the secret rotation of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    public void Rollback(string subscriptionId, string applicationId)
+    {
+        Problems.CheckAnswer(_transport.Request(
+            "POST",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+}
+
 /// <summary>What the API declares under <c>serviceToken</c>, issued through the transport it is handed.</summary>
 /// <param name="transport">What one request is issued through.</param>
 public sealed class ServiceTokenApi(ITransport transport)
@@ -548,6 +1066,65 @@ public sealed class ServiceTokenApi(ITransport transport)
     }
 }
 
+/// <summary>What the API declares under <c>signingKeys</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class SigningKeysApi(ITransport transport)
+{
+    private readonly ITransport _transport = transport;
+
+    /// <summary>Create a signing key</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <returns>What the API answered.</returns>
+    public SigningKey Create(SigningKeyPost body)
+    {
+        return Problems.ReadAnswer<SigningKey>(_transport.Request(
+            "POST",
+            "/api/v1/signing_keys/",
+            Runtime.Query([], []),
+            body
+        ));
+    }
+
+    /// <summary>Retire a signing key</summary>
+    /// <param name="signingKeyId">Carries <c>signing_key_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    public void Delete(string signingKeyId, string applicationId)
+    {
+        Problems.CheckAnswer(_transport.Request(
+            "DELETE",
+            Runtime.Path("/api/v1/signing_keys/{signing_key_id}", [("signing_key_id", signingKeyId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+
+    /// <summary>Get the public keys of an application</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public JsonWebKeySet Jwks(string applicationId)
+    {
+        return Problems.ReadAnswer<JsonWebKeySet>(_transport.Request(
+            "GET",
+            Runtime.Path("/api/v1/jwks/{application_id}", [("application_id", applicationId)]),
+            Runtime.Query([], []),
+            null
+        ));
+    }
+
+    /// <summary>List signing keys</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <returns>What the API answered.</returns>
+    public IReadOnlyList<SigningKey> List(string applicationId)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<SigningKey>>(_transport.Request(
+            "GET",
+            "/api/v1/signing_keys/",
+            Runtime.Query([("application_id", applicationId)], []),
+            null
+        ));
+    }
+}
+
 /// <summary>What the API declares under <c>subscriptions</c>, issued through the transport it is handed.</summary>
 /// <param name="transport">What one request is issued through.</param>
 public sealed class SubscriptionsApi(ITransport transport)
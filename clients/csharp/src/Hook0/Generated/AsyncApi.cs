@@ -180,6 +180,295 @@ public sealed class ApplicationsAsyncApi(IAsyncTransport transport)
     }
 }
 
+/// <summary>What the API declares under <c>bulkRetries</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class BulkRetriesAsyncApi(IAsyncTransport transport)
+{
+    private readonly IAsyncTransport _transport = transport;
+
+    /// <summary>Cancel a bulk retry</summary>
+    /// <param name="bulkRetryId">Carries <c>bulk_retry_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<BulkRetry> CancelAsync(
+        string bulkRetryId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<BulkRetry>(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path("/api/v1/bulk_retries/{bulk_retry_id}/cancel", [("bulk_retry_id", bulkRetryId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Create a bulk retry</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<BulkRetry> CreateAsync(BulkRetryPost body, CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<BulkRetry>(await _transport.RequestAsync(
+            "POST",
+            "/api/v1/bulk_retries/",
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Count what a bulk retry would redeliver</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<BulkRetryDryRun> DryRunAsync(BulkRetryPost body, CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<BulkRetryDryRun>(await _transport.RequestAsync(
+            "POST",
+            "/api/v1/bulk_retries/dry_run",
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Get a bulk retry</summary>
+    /// <param name="bulkRetryId">Carries <c>bulk_retry_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<BulkRetry> GetAsync(
+        string bulkRetryId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<BulkRetry>(await _transport.RequestAsync(
+            "GET",
+            Runtime.Path("/api/v1/bulk_retries/{bulk_retry_id}", [("bulk_retry_id", bulkRetryId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>List bulk retries</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<IReadOnlyList<BulkRetry>> ListAsync(
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<BulkRetry>>(await _transport.RequestAsync(
+            "GET",
+            "/api/v1/bulk_retries/",
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+}
+
+/// <summary>What the API declares under <c>clientCertificates</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class ClientCertificatesAsyncApi(IAsyncTransport transport)
+{
+    private readonly IAsyncTransport _transport = transport;
+
+    /// <summary>Remove the client certificate of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>The request, once the API has answered it.</returns>
+    public async Task DeleteAsync(
+        string subscriptionId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        Problems.CheckAnswer(await _transport.RequestAsync(
+            "DELETE",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/client_certificate",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Get the client certificate of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<ClientCertificate> GetAsync(
+        string subscriptionId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<ClientCertificate>(await _transport.RequestAsync(
+            "GET",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/client_certificate",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Set the client certificate of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<ClientCertificate> UpdateAsync(
+        string subscriptionId,
+        string applicationId,
+        ClientCertificatePut body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<ClientCertificate>(await _transport.RequestAsync(
+            "PUT",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/client_certificate",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+}
+
+/// <summary>What the API declares under <c>deadLetters</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class DeadLettersAsyncApi(IAsyncTransport transport)
+{
+    private readonly IAsyncTransport _transport = transport;
+
+    /// <summary>Delete a dead letter</summary>
+    /// <param name="deadLetterId">Carries <c>dead_letter_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>The request, once the API has answered it.</returns>
+    public async Task DeleteAsync(
+        string deadLetterId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        Problems.CheckAnswer(await _transport.RequestAsync(
+            "DELETE",
+            Runtime.Path("/api/v1/dead_letters/{dead_letter_id}", [("dead_letter_id", deadLetterId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Get a dead letter by its ID</summary>
+    /// <param name="deadLetterId">Carries <c>dead_letter_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<DeadLetterDetails> GetAsync(
+        string deadLetterId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<DeadLetterDetails>(await _transport.RequestAsync(
+            "GET",
+            Runtime.Path("/api/v1/dead_letters/{dead_letter_id}", [("dead_letter_id", deadLetterId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>List dead letters</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="paginationCursor">Carries <c>pagination_cursor</c>, when the caller passes one.</param>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>, when the caller passes one.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<IReadOnlyList<DeadLetter>> ListAsync(
+        string applicationId,
+        string? paginationCursor = null,
+        string? subscriptionId = null,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<DeadLetter>>(await _transport.RequestAsync(
+            "GET",
+            "/api/v1/dead_letters/",
+            Runtime.Query(
+                [("application_id", applicationId)],
+                [("pagination_cursor", paginationCursor), ("subscription_id", subscriptionId)]
+            ),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Purge dead letters</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<PurgedDeadLetters> PurgeAsync(
+        PurgeDeadLetters body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<PurgedDeadLetters>(await _transport.RequestAsync(
+            "POST",
+            "/api/v1/dead_letters/purge",
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Redeliver a dead letter</summary>
+    /// <param name="deadLetterId">Carries <c>dead_letter_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<RedeliveredDeadLetter> RedeliverAsync(
+        string deadLetterId,
+        RedeliverDeadLetter body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<RedeliveredDeadLetter>(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path("/api/v1/dead_letters/{dead_letter_id}/redeliver", [("dead_letter_id", deadLetterId)]),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Redeliver dead letters</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<IReadOnlyList<RedeliveredDeadLetter>> RedeliverManyAsync(
+        RedeliverDeadLetters body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<RedeliveredDeadLetter>>(await _transport.RequestAsync(
+            "POST",
+            "/api/v1/dead_letters/redeliver",
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+}
+
 /// <summary>What the API declares under <c>errors</c>, issued through the transport it is handed.</summary>
 /// <param name="transport">What one request is issued through.</param>
 public sealed class ErrorsAsyncApi(IAsyncTransport transport)
@@ -222,6 +511,25 @@ public sealed class EventTypesAsyncApi(IAsyncTransport transport)
         ).ConfigureAwait(false));
     }
 
+    /// <summary>Create a new event type version</summary>
+    /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<EventTypeVersion> CreateVersionAsync(
+        string eventTypeName,
+        EventTypeVersionPost body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<EventTypeVersion>(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path("/api/v1/event_types/{event_type_name}/versions", [("event_type_name", eventTypeName)]),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
     /// <summary>Delete an event type</summary>
     /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
@@ -241,6 +549,25 @@ public sealed class EventTypesAsyncApi(IAsyncTransport transport)
         ).ConfigureAwait(false));
     }
 
+    /// <summary>Deprecate an event type version</summary>
+    /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<EventTypeVersion> DeprecateVersionAsync(
+        string eventTypeName,
+        EventTypeVersionDeprecate body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<EventTypeVersion>(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path("/api/v1/event_types/{event_type_name}/deprecate", [("event_type_name", eventTypeName)]),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
     /// <summary>Get an event type by its name</summary>
     /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
@@ -276,6 +603,25 @@ public sealed class EventTypesAsyncApi(IAsyncTransport transport)
             cancellationToken
         ).ConfigureAwait(false));
     }
+
+    /// <summary>Update an event type</summary>
+    /// <param name="eventTypeName">Carries <c>event_type_name</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<EventType> UpdateAsync(
+        string eventTypeName,
+        EventTypePut body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<EventType>(await _transport.RequestAsync(
+            "PUT",
+            Runtime.Path("/api/v1/event_types/{event_type_name}", [("event_type_name", eventTypeName)]),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
 }
 
 /// <summary>What the API declares under <c>events</c>, issued through the transport it is handed.</summary>
@@ -284,6 +630,22 @@ public sealed class EventsAsyncApi(IAsyncTransport transport)
 {
     private readonly IAsyncTransport _transport = transport;
 
+    /// <summary>Cancel a scheduled event</summary>
+    /// <param name="eventId">Carries <c>event_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>The request, once the API has answered it.</returns>
+    public async Task CancelAsync(string eventId, CancelEvent body, CancellationToken cancellationToken = default)
+    {
+        Problems.CheckAnswer(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path("/api/v1/events/{event_id}/cancel", [("event_id", eventId)]),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
     /// <summary>Get an event by its ID</summary>
     /// <param name="eventId">Carries <c>event_id</c>.</param>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
@@ -318,115 +680,295 @@ public sealed class EventsAsyncApi(IAsyncTransport transport)
         ).ConfigureAwait(false));
     }
 
+    /// <summary>Ingest a batch of events</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<IngestedEventsBatch> IngestBatchAsync(
+        EventsBatchPost body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<IngestedEventsBatch>(await _transport.RequestAsync(
+            "POST",
+            "/api/v1/events/batch",
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
     /// <summary>List latest events</summary>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="scheduled">
+    /// Carries <c>scheduled</c>, when the caller passes one: Only list the events scheduled for a later delivery that
+    /// was not cancelled, soonest first
+    /// </param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<IReadOnlyList<Event>> ListAsync(
+        string applicationId,
+        bool? scheduled = null,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<Event>>(await _transport.RequestAsync(
+            "GET",
+            "/api/v1/events/",
+            Runtime.Query([("application_id", applicationId)], [("scheduled", scheduled)]),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Replay an event</summary>
+    /// <param name="eventId">Carries <c>event_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>The request, once the API has answered it.</returns>
+    public async Task ReplayAsync(string eventId, ReplayEvent body, CancellationToken cancellationToken = default)
+    {
+        Problems.CheckAnswer(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path("/api/v1/events/{event_id}/replay", [("event_id", eventId)]),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+}
+
+/// <summary>What the API declares under <c>events_per_day</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class EventsPerDayAsyncApi(IAsyncTransport transport)
+{
+    private readonly IAsyncTransport _transport = transport;
+
+    /// <summary>List events per day for an application</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="from">
+    /// Carries <c>from</c>, when the caller passes one: Start of date range (inclusive). Defaults to 30 days before
+    /// `to`.
+    /// </param>
+    /// <param name="to">
+    /// Carries <c>to</c>, when the caller passes one: End of date range (inclusive). Defaults to today.
+    /// </param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<IReadOnlyList<EventsPerDayEntry>> ListForApplicationAsync(
+        string applicationId,
+        string? from = null,
+        string? to = null,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<EventsPerDayEntry>>(await _transport.RequestAsync(
+            "GET",
+            "/api/v1/events_per_day/application",
+            Runtime.Query([("application_id", applicationId)], [("from", from), ("to", to)]),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>List events per day for an organization</summary>
+    /// <param name="organizationId">Carries <c>organization_id</c>.</param>
+    /// <param name="from">
+    /// Carries <c>from</c>, when the caller passes one: Start of date range (inclusive). Defaults to 30 days before
+    /// `to`.
+    /// </param>
+    /// <param name="to">
+    /// Carries <c>to</c>, when the caller passes one: End of date range (inclusive). Defaults to today.
+    /// </param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<IReadOnlyList<EventsPerDayEntry>> ListForOrganizationAsync(
+        string organizationId,
+        string? from = null,
+        string? to = null,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<EventsPerDayEntry>>(await _transport.RequestAsync(
+            "GET",
+            "/api/v1/events_per_day/organization",
+            Runtime.Query([("organization_id", organizationId)], [("from", from), ("to", to)]),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+}
+
+/// <summary>What the API declares under <c>inbox</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class InboxAsyncApi(IAsyncTransport transport)
+{
+    private readonly IAsyncTransport _transport = transport;
+
+    /// <summary>Acknowledge events pulled from an inbox</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<InboxSettlement> AckAsync(
+        string subscriptionId,
+        InboxAck body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<InboxSettlement>(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path("/api/v1/inbox/{subscription_id}/ack", [("subscription_id", subscriptionId)]),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Reject events pulled from an inbox</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<InboxSettlement> NackAsync(
+        string subscriptionId,
+        InboxNack body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<InboxSettlement>(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path("/api/v1/inbox/{subscription_id}/nack", [("subscription_id", subscriptionId)]),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Pull events from an inbox</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cursor">
+    /// Carries <c>cursor</c>, when the caller passes one: Only lease events after this cursor, returned by a previous
+    /// pull
+    /// </param>
+    /// <param name="limit">
+    /// Carries <c>limit</c>, when the caller passes one: Maximum number of events to lease (10 by default)
+    /// </param>
+    /// <param name="visibilityTimeoutS">
+    /// Carries <c>visibility_timeout_s</c>, when the caller passes one: For how long in seconds the leased events are
+    /// not offered again (30 by default)
+    /// </param>
     /// <param name="cancellationToken">What abandons the request before it is answered.</param>
     /// <returns>What the API answered, once it has.</returns>
-    public async Task<IReadOnlyList<Event>> ListAsync(
+    public async Task<Inbox> PullAsync(
+        string subscriptionId,
         string applicationId,
+        string? cursor = null,
+        long? limit = null,
+        long? visibilityTimeoutS = null,
         CancellationToken cancellationToken = default)
     {
-        return Problems.ReadAnswer<IReadOnlyList<Event>>(await _transport.RequestAsync(
+        return Problems.ReadAnswer<Inbox>(await _transport.RequestAsync(
             "GET",
-            "/api/v1/events/",
-            Runtime.Query([("application_id", applicationId)], []),
+            Runtime.Path("/api/v1/inbox/{subscription_id}", [("subscription_id", subscriptionId)]),
+            Runtime.Query(
+                [("application_id", applicationId)],
+                [("cursor", cursor), ("limit", limit), ("visibility_timeout_s", visibilityTimeoutS)]
+            ),
             null,
             cancellationToken
         ).ConfigureAwait(false));
     }
+}
 
-    /// <summary>Replay an event</summary>
-    /// <param name="eventId">Carries <c>event_id</c>.</param>
-    /// <param name="body">What the operation reads.</param>
+/// <summary>What the API declares under <c>instance</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class InstanceAsyncApi(IAsyncTransport transport)
+{
+    private readonly IAsyncTransport _transport = transport;
+
+    /// <summary>Get instance configuration</summary>
     /// <param name="cancellationToken">What abandons the request before it is answered.</param>
-    /// <returns>The request, once the API has answered it.</returns>
-    public async Task ReplayAsync(string eventId, ReplayEvent body, CancellationToken cancellationToken = default)
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<InstanceConfig> GetAsync(CancellationToken cancellationToken = default)
     {
-        Problems.CheckAnswer(await _transport.RequestAsync(
-            "POST",
-            Runtime.Path("/api/v1/events/{event_id}/replay", [("event_id", eventId)]),
+        return Problems.ReadAnswer<InstanceConfig>(await _transport.RequestAsync(
+            "GET",
+            "/api/v1/instance/",
             Runtime.Query([], []),
-            body,
+            null,
             cancellationToken
         ).ConfigureAwait(false));
     }
 }
 
-/// <summary>What the API declares under <c>events_per_day</c>, issued through the transport it is handed.</summary>
+/// <summary>
+/// What the API declares under <c>oauth2ClientCredentials</c>, issued through the transport it is handed.
+/// </summary>
 /// <param name="transport">What one request is issued through.</param>
-public sealed class EventsPerDayAsyncApi(IAsyncTransport transport)
+public sealed class Oauth2ClientCredentialsAsyncApi(IAsyncTransport transport)
 {
     private readonly IAsyncTransport _transport = transport;
 
-    /// <summary>List events per day for an application</summary>
+    /// <summary>Remove the OAuth2 client credentials of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
     /// <param name="applicationId">Carries <c>application_id</c>.</param>
-    /// <param name="from">
-    /// Carries <c>from</c>, when the caller passes one: Start of date range (inclusive). Defaults to 30 days before
-    /// `to`.
-    /// </param>
-    /// <param name="to">
-    /// Carries <c>to</c>, when the caller passes one: End of date range (inclusive). Defaults to today.
-    /// </param>
     /// <param name="cancellationToken">What abandons the request before it is answered.</param>
-    /// <returns>What the API answered, once it has.</returns>
-    public async Task<IReadOnlyList<EventsPerDayEntry>> ListForApplicationAsync(
+    /// <returns>The request, once the API has answered it.</returns>
+    public async Task DeleteAsync(
+        string subscriptionId,
         string applicationId,
-        string? from = null,
-        string? to = null,
         CancellationToken cancellationToken = default)
     {
-        return Problems.ReadAnswer<IReadOnlyList<EventsPerDayEntry>>(await _transport.RequestAsync(
-            "GET",
-            "/api/v1/events_per_day/application",
-            Runtime.Query([("application_id", applicationId)], [("from", from), ("to", to)]),
+        Problems.CheckAnswer(await _transport.RequestAsync(
+            "DELETE",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
             null,
             cancellationToken
         ).ConfigureAwait(false));
     }
 
-    /// <summary>List events per day for an organization</summary>
-    /// <param name="organizationId">Carries <c>organization_id</c>.</param>
-    /// <param name="from">
-    /// Carries <c>from</c>, when the caller passes one: Start of date range (inclusive). Defaults to 30 days before
-    /// `to`.
-    /// </param>
-    /// <param name="to">
-    /// Carries <c>to</c>, when the caller passes one: End of date range (inclusive). Defaults to today.
-    /// </param>
+    /// <summary>Get the OAuth2 client credentials of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
     /// <param name="cancellationToken">What abandons the request before it is answered.</param>
     /// <returns>What the API answered, once it has.</returns>
-    public async Task<IReadOnlyList<EventsPerDayEntry>> ListForOrganizationAsync(
-        string organizationId,
-        string? from = null,
-        string? to = null,
+    public async Task<Oauth2ClientCredentials> GetAsync(
+        string subscriptionId,
+        string applicationId,
         CancellationToken cancellationToken = default)
     {
-        return Problems.ReadAnswer<IReadOnlyList<EventsPerDayEntry>>(await _transport.RequestAsync(
+        return Problems.ReadAnswer<Oauth2ClientCredentials>(await _transport.RequestAsync(
             "GET",
-            "/api/v1/events_per_day/organization",
-            Runtime.Query([("organization_id", organizationId)], [("from", from), ("to", to)]),
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
             null,
             cancellationToken
         ).ConfigureAwait(false));
     }
-}
-
-/// <summary>What the API declares under <c>instance</c>, issued through the transport it is handed.</summary>
-/// <param name="transport">What one request is issued through.</param>
-public sealed class InstanceAsyncApi(IAsyncTransport transport)
-{
-    private readonly IAsyncTransport _transport = transport;
 
-    /// <summary>Get instance configuration</summary>
+    /// <summary>Set the OAuth2 client credentials of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
     /// <param name="cancellationToken">What abandons the request before it is answered.</param>
     /// <returns>What the API answered, once it has.</returns>
-    public async Task<InstanceConfig> GetAsync(CancellationToken cancellationToken = default)
+    public async Task<Oauth2ClientCredentials> UpdateAsync(
+        string subscriptionId,
+        string applicationId,
+        Oauth2ClientCredentialsPut body,
+        CancellationToken cancellationToken = default)
     {
-        return Problems.ReadAnswer<InstanceConfig>(await _transport.RequestAsync(
-            "GET",
-            "/api/v1/instance/",
-            Runtime.Query([], []),
-            null,
+        return Problems.ReadAnswer<Oauth2ClientCredentials>(await _transport.RequestAsync(
+            "PUT",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            body,
             cancellationToken
         ).ConfigureAwait(false));
     }
@@ -541,6 +1083,28 @@ public sealed class RequestAttemptsAsyncApi(IAsyncTransport transport)
             cancellationToken
         ).ConfigureAwait(false));
     }
+
+    /// <summary>Retry a request attempt</summary>
+    /// <param name="requestAttemptId">Carries <c>request_attempt_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<RequestAttempt> RetryAsync(
+        string requestAttemptId,
+        RetryRequestAttempt body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<RequestAttempt>(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path(
+                "/api/v1/request_attempts/{request_attempt_id}/retry",
+                [("request_attempt_id", requestAttemptId)]
+            ),
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
 }
 
 /// <summary>What the API declares under <c>response</c>, issued through the transport it is handed.</summary>
@@ -569,6 +1133,103 @@ public sealed class ResponseAsyncApi(IAsyncTransport transport)
     }
 }
 
+/// <summary>What the API declares under <c>secretRotations</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class SecretRotationsAsyncApi(IAsyncTransport transport)
+{
+    private readonly IAsyncTransport _transport = transport;
+
+    /// <summary>Rotate the secret of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<SecretRotation> CreateAsync(
+        string subscriptionId,
+        string applicationId,
+        SecretRotationPost body,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<SecretRotation>(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/secret_rotation",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Finalize the secret rotation of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>The request, once the API has answered it.</returns>
+    public async Task FinalizeAsync(
+        string subscriptionId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        Problems.CheckAnswer(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Get the secret rotation of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<SecretRotation> GetAsync(
+        string subscriptionId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<SecretRotation>(await _transport.RequestAsync(
+            "GET",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/secret_rotation",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Roll back the secret rotation of a subscription</summary>
+    /// <param name="subscriptionId">Carries <c>subscription_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>The request, once the API has answered it.</returns>
+    public async Task RollbackAsync(
+        string subscriptionId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        Problems.CheckAnswer(await _transport.RequestAsync(
+            "POST",
+            Runtime.Path(
+                "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback",
+                [("subscription_id", subscriptionId)]
+            ),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+}
+
 /// <summary>What the API declares under <c>serviceToken</c>, issued through the transport it is handed.</summary>
 /// <param name="transport">What one request is issued through.</param>
 public sealed class ServiceTokenAsyncApi(IAsyncTransport transport)
@@ -665,6 +1326,79 @@ public sealed class ServiceTokenAsyncApi(IAsyncTransport transport)
     }
 }
 
+/// <summary>What the API declares under <c>signingKeys</c>, issued through the transport it is handed.</summary>
+/// <param name="transport">What one request is issued through.</param>
+public sealed class SigningKeysAsyncApi(IAsyncTransport transport)
+{
+    private readonly IAsyncTransport _transport = transport;
+
+    /// <summary>Create a signing key</summary>
+    /// <param name="body">What the operation reads.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<SigningKey> CreateAsync(SigningKeyPost body, CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<SigningKey>(await _transport.RequestAsync(
+            "POST",
+            "/api/v1/signing_keys/",
+            Runtime.Query([], []),
+            body,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Retire a signing key</summary>
+    /// <param name="signingKeyId">Carries <c>signing_key_id</c>.</param>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>The request, once the API has answered it.</returns>
+    public async Task DeleteAsync(
+        string signingKeyId,
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        Problems.CheckAnswer(await _transport.RequestAsync(
+            "DELETE",
+            Runtime.Path("/api/v1/signing_keys/{signing_key_id}", [("signing_key_id", signingKeyId)]),
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>Get the public keys of an application</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<JsonWebKeySet> JwksAsync(string applicationId, CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<JsonWebKeySet>(await _transport.RequestAsync(
+            "GET",
+            Runtime.Path("/api/v1/jwks/{application_id}", [("application_id", applicationId)]),
+            Runtime.Query([], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+
+    /// <summary>List signing keys</summary>
+    /// <param name="applicationId">Carries <c>application_id</c>.</param>
+    /// <param name="cancellationToken">What abandons the request before it is answered.</param>
+    /// <returns>What the API answered, once it has.</returns>
+    public async Task<IReadOnlyList<SigningKey>> ListAsync(
+        string applicationId,
+        CancellationToken cancellationToken = default)
+    {
+        return Problems.ReadAnswer<IReadOnlyList<SigningKey>>(await _transport.RequestAsync(
+            "GET",
+            "/api/v1/signing_keys/",
+            Runtime.Query([("application_id", applicationId)], []),
+            null,
+            cancellationToken
+        ).ConfigureAwait(false));
+    }
+}
+
 /// <summary>What the API declares under <c>subscriptions</c>, issued through the transport it is handed.</summary>
 /// <param name="transport">What one request is issued through.</param>
 public sealed class SubscriptionsAsyncApi(IAsyncTransport transport)
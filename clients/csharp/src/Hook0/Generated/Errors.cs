@@ -102,6 +102,27 @@ public sealed class AuthInvalidBiscuitException(int status, Problem? problem, st
 public sealed class AuthNoAuthorizationHeaderException(int status, Problem? problem, string detail)
     : ProblemException(status, problem, detail);
 
+/// <summary>The API reported <c>BulkRetryAlreadyRunning</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class BulkRetryAlreadyRunningException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>BulkRetryFinished</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class BulkRetryFinishedException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>ClientCertificatesDisabled</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class ClientCertificatesDisabledException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
 /// <summary>The API reported <c>EventAlreadyIngested</c>.</summary>
 /// <param name="status">What the API answered under.</param>
 /// <param name="problem">The document it answered, when this client could read one.</param>
@@ -109,6 +130,13 @@ public sealed class AuthNoAuthorizationHeaderException(int status, Problem? prob
 public sealed class EventAlreadyIngestedException(int status, Problem? problem, string detail)
     : ProblemException(status, problem, detail);
 
+/// <summary>The API reported <c>EventDeliveryBeyondRetention</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class EventDeliveryBeyondRetentionException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
 /// <summary>The API reported <c>EventInvalidBase64Payload</c>.</summary>
 /// <param name="status">What the API answered under.</param>
 /// <param name="problem">The document it answered, when this client could read one.</param>
@@ -130,6 +158,20 @@ public sealed class EventInvalidJsonPayloadException(int status, Problem? proble
 public sealed class EventInvalidPayloadContentTypeException(int status, Problem? problem, string detail)
     : ProblemException(status, problem, detail);
 
+/// <summary>The API reported <c>EventNotScheduled</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class EventNotScheduledException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>EventPayloadSchemaViolation</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class EventPayloadSchemaViolationException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
 /// <summary>The API reported <c>EventTypeAlreadyExist</c>.</summary>
 /// <param name="status">What the API answered under.</param>
 /// <param name="problem">The document it answered, when this client could read one.</param>
@@ -144,6 +186,27 @@ public sealed class EventTypeAlreadyExistException(int status, Problem? problem,
 public sealed class EventTypeDoesNotExistException(int status, Problem? problem, string detail)
     : ProblemException(status, problem, detail);
 
+/// <summary>The API reported <c>EventTypeVersionAlreadyExist</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class EventTypeVersionAlreadyExistException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>EventTypeVersionDoesNotExist</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class EventTypeVersionDoesNotExistException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>EventTypeVersionSunset</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class EventTypeVersionSunsetException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
 /// <summary>The API reported <c>Forbidden</c>.</summary>
 /// <param name="status">What the API answered under.</param>
 /// <param name="problem">The document it answered, when this client could read one.</param>
@@ -158,6 +221,13 @@ public sealed class ForbiddenException(int status, Problem? problem, string deta
 public sealed class InternalServerErrorException(int status, Problem? problem, string detail)
     : ProblemException(status, problem, detail);
 
+/// <summary>The API reported <c>InvalidClientCertificate</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class InvalidClientCertificateException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
 /// <summary>The API reported <c>InvalidDateRange</c>.</summary>
 /// <param name="status">What the API answered under.</param>
 /// <param name="problem">The document it answered, when this client could read one.</param>
@@ -277,6 +347,34 @@ public sealed class RateLimitedException(int status, Problem? problem, string de
 public sealed class RegistrationDisabledException(int status, Problem? problem, string detail)
     : ProblemException(status, problem, detail);
 
+/// <summary>The API reported <c>RequestAttemptNotFailed</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class RequestAttemptNotFailedException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>RequestAttemptRetryPending</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class RequestAttemptRetryPendingException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>RetryPolicyOverLimit</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class RetryPolicyOverLimitException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>SecretRotationInProgress</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class SecretRotationInProgressException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
 /// <summary>The API reported <c>ServiceUnavailable</c>.</summary>
 /// <param name="status">What the API answered under.</param>
 /// <param name="problem">The document it answered, when this client could read one.</param>
@@ -284,6 +382,27 @@ public sealed class RegistrationDisabledException(int status, Problem? problem,
 public sealed class ServiceUnavailableException(int status, Problem? problem, string detail)
     : ProblemException(status, problem, detail);
 
+/// <summary>The API reported <c>SigningKeysDisabled</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class SigningKeysDisabledException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>SubscriptionDisabled</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class SubscriptionDisabledException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
+/// <summary>The API reported <c>SubscriptionNotInbox</c>.</summary>
+/// <param name="status">What the API answered under.</param>
+/// <param name="problem">The document it answered, when this client could read one.</param>
+/// <param name="detail">What to say about the failure.</param>
+public sealed class SubscriptionNotInboxException(int status, Problem? problem, string detail)
+    : ProblemException(status, problem, detail);
+
 /// <summary>The API reported <c>TooManyApplicationsPerOrganization</c>.</summary>
 /// <param name="status">What the API answered under.</param>
 /// <param name="problem">The document it answered, when this client could read one.</param>
@@ -401,22 +520,42 @@ public static class Problems
                 new AuthInvalidBiscuitException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.AuthNoAuthorizationHeader =>
                 new AuthNoAuthorizationHeaderException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.BulkRetryAlreadyRunning =>
+                new BulkRetryAlreadyRunningException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.BulkRetryFinished =>
+                new BulkRetryFinishedException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.ClientCertificatesDisabled =>
+                new ClientCertificatesDisabledException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.EventAlreadyIngested =>
                 new EventAlreadyIngestedException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.EventDeliveryBeyondRetention =>
+                new EventDeliveryBeyondRetentionException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.EventInvalidBase64Payload =>
                 new EventInvalidBase64PayloadException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.EventInvalidJsonPayload =>
                 new EventInvalidJsonPayloadException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.EventInvalidPayloadContentType =>
                 new EventInvalidPayloadContentTypeException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.EventNotScheduled =>
+                new EventNotScheduledException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.EventPayloadSchemaViolation =>
+                new EventPayloadSchemaViolationException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.EventTypeAlreadyExist =>
                 new EventTypeAlreadyExistException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.EventTypeDoesNotExist =>
                 new EventTypeDoesNotExistException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.EventTypeVersionAlreadyExist =>
+                new EventTypeVersionAlreadyExistException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.EventTypeVersionDoesNotExist =>
+                new EventTypeVersionDoesNotExistException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.EventTypeVersionSunset =>
+                new EventTypeVersionSunsetException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.Forbidden =>
                 new ForbiddenException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.InternalServerError =>
                 new InternalServerErrorException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.InvalidClientCertificate =>
+                new InvalidClientCertificateException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.InvalidDateRange =>
                 new InvalidDateRangeException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.InvalidRole =>
@@ -451,8 +590,22 @@ public static class Problems
                 new RateLimitedException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.RegistrationDisabled =>
                 new RegistrationDisabledException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.RequestAttemptNotFailed =>
+                new RequestAttemptNotFailedException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.RequestAttemptRetryPending =>
+                new RequestAttemptRetryPendingException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.RetryPolicyOverLimit =>
+                new RetryPolicyOverLimitException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.SecretRotationInProgress =>
+                new SecretRotationInProgressException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.ServiceUnavailable =>
                 new ServiceUnavailableException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.SigningKeysDisabled =>
+                new SigningKeysDisabledException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.SubscriptionDisabled =>
+                new SubscriptionDisabledException(status, problem, Runtime.Reported(status, problem)),
+            ProblemId.SubscriptionNotInbox =>
+                new SubscriptionNotInboxException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.TooManyApplicationsPerOrganization =>
                 new TooManyApplicationsPerOrganizationException(status, problem, Runtime.Reported(status, problem)),
             ProblemId.TooManyEventTypesPerApplication =>
@@ -69,15 +69,15 @@ public static class ApplicationInfoOnboardingStepsSubscription
 }
 
 /// <summary>One of the values the API answers with.</summary>
-public static class OrganizationInfoOnboardingStepsApplication
+public static class BulkRetryPostStatus
 {
-    /// <summary>The API answers <c>ToDo</c>.</summary>
-    public const string ToDo = "ToDo";
+    /// <summary>The API answers <c>failed</c>.</summary>
+    public const string Failed = "failed";
 
-    /// <summary>The API answers <c>Done</c>.</summary>
-    public const string Done = "Done";
+    /// <summary>The API answers <c>successful</c>.</summary>
+    public const string Successful = "successful";
 
-    private static readonly string[] Declared = ["ToDo", "Done"];
+    private static readonly string[] Declared = ["failed", "successful"];
 
     /// <summary>Every value the API declares for this list.</summary>
     public static IReadOnlyList<string> Values => Declared;
@@ -89,15 +89,15 @@ public static class OrganizationInfoOnboardingStepsApplication
 }
 
 /// <summary>One of the values the API answers with.</summary>
-public static class OrganizationInfoOnboardingStepsEvent
+public static class BulkRetryStatus
 {
-    /// <summary>The API answers <c>ToDo</c>.</summary>
-    public const string ToDo = "ToDo";
+    /// <summary>The API answers <c>failed</c>.</summary>
+    public const string Failed = "failed";
 
-    /// <summary>The API answers <c>Done</c>.</summary>
-    public const string Done = "Done";
+    /// <summary>The API answers <c>successful</c>.</summary>
+    public const string Successful = "successful";
 
-    private static readonly string[] Declared = ["ToDo", "Done"];
+    private static readonly string[] Declared = ["failed", "successful"];
 
     /// <summary>Every value the API declares for this list.</summary>
     public static IReadOnlyList<string> Values => Declared;
@@ -109,15 +109,26 @@ public static class OrganizationInfoOnboardingStepsEvent
 }
 
 /// <summary>One of the values the API answers with.</summary>
-public static class OrganizationInfoOnboardingStepsEventType
+public static class DeadLetterDetailsDeadLetterReason
 {
-    /// <summary>The API answers <c>ToDo</c>.</summary>
-    public const string ToDo = "ToDo";
+    /// <summary>The API answers <c>retries_exhausted</c>.</summary>
+    public const string RetriesExhausted = "retries_exhausted";
 
-    /// <summary>The API answers <c>Done</c>.</summary>
-    public const string Done = "Done";
+    /// <summary>The API answers <c>signature_failed</c>.</summary>
+    public const string SignatureFailed = "signature_failed";
 
-    private static readonly string[] Declared = ["ToDo", "Done"];
+    /// <summary>The API answers <c>transform_failed</c>.</summary>
+    public const string TransformFailed = "transform_failed";
+
+    /// <summary>The API answers <c>permanent_failure</c>.</summary>
+    public const string PermanentFailure = "permanent_failure";
+
+    private static readonly string[] Declared = [
+        "retries_exhausted",
+        "signature_failed",
+        "transform_failed",
+        "permanent_failure"
+    ];
 
     /// <summary>Every value the API declares for this list.</summary>
     public static IReadOnlyList<string> Values => Declared;
@@ -129,15 +140,26 @@ public static class OrganizationInfoOnboardingStepsEventType
 }
 
 /// <summary>One of the values the API answers with.</summary>
-public static class OrganizationInfoOnboardingStepsSubscription
+public static class DeadLetterReason
 {
-    /// <summary>The API answers <c>ToDo</c>.</summary>
-    public const string ToDo = "ToDo";
+    /// <summary>The API answers <c>retries_exhausted</c>.</summary>
+    public const string RetriesExhausted = "retries_exhausted";
 
-    /// <summary>The API answers <c>Done</c>.</summary>
-    public const string Done = "Done";
+    /// <summary>The API answers <c>signature_failed</c>.</summary>
+    public const string SignatureFailed = "signature_failed";
 
-    private static readonly string[] Declared = ["ToDo", "Done"];
+    /// <summary>The API answers <c>transform_failed</c>.</summary>
+    public const string TransformFailed = "transform_failed";
+
+    /// <summary>The API answers <c>permanent_failure</c>.</summary>
+    public const string PermanentFailure = "permanent_failure";
+
+    private static readonly string[] Declared = [
+        "retries_exhausted",
+        "signature_failed",
+        "transform_failed",
+        "permanent_failure"
+    ];
 
     /// <summary>Every value the API declares for this list.</summary>
     public static IReadOnlyList<string> Values => Declared;
@@ -149,7 +171,76 @@ public static class OrganizationInfoOnboardingStepsSubscription
 }
 
 /// <summary>One of the values the API answers with.</summary>
-public static class ProblemId
+public static class EventTypePayloadSchemaMode
+{
+    /// <summary>The API answers <c>enforce</c>.</summary>
+    public const string Enforce = "enforce";
+
+    /// <summary>The API answers <c>warn</c>.</summary>
+    public const string Warn = "warn";
+
+    /// <summary>The API answers <c>off</c>.</summary>
+    public const string Off = "off";
+
+    private static readonly string[] Declared = ["enforce", "warn", "off"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class EventTypePostPayloadSchemaMode
+{
+    /// <summary>The API answers <c>enforce</c>.</summary>
+    public const string Enforce = "enforce";
+
+    /// <summary>The API answers <c>warn</c>.</summary>
+    public const string Warn = "warn";
+
+    /// <summary>The API answers <c>off</c>.</summary>
+    public const string Off = "off";
+
+    private static readonly string[] Declared = ["enforce", "warn", "off"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class EventTypePutPayloadSchemaMode
+{
+    /// <summary>The API answers <c>enforce</c>.</summary>
+    public const string Enforce = "enforce";
+
+    /// <summary>The API answers <c>warn</c>.</summary>
+    public const string Warn = "warn";
+
+    /// <summary>The API answers <c>off</c>.</summary>
+    public const string Off = "off";
+
+    private static readonly string[] Declared = ["enforce", "warn", "off"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class IngestedEventsBatchEventsProblemId
 {
     /// <summary>The API answers <c>OrganizationNameMissing</c>.</summary>
     public const string OrganizationNameMissing = "OrganizationNameMissing";
@@ -199,6 +290,15 @@ public static class ProblemId
     /// <summary>The API answers <c>EventTypeDoesNotExist</c>.</summary>
     public const string EventTypeDoesNotExist = "EventTypeDoesNotExist";
 
+    /// <summary>The API answers <c>EventTypeVersionAlreadyExist</c>.</summary>
+    public const string EventTypeVersionAlreadyExist = "EventTypeVersionAlreadyExist";
+
+    /// <summary>The API answers <c>EventTypeVersionDoesNotExist</c>.</summary>
+    public const string EventTypeVersionDoesNotExist = "EventTypeVersionDoesNotExist";
+
+    /// <summary>The API answers <c>EventTypeVersionSunset</c>.</summary>
+    public const string EventTypeVersionSunset = "EventTypeVersionSunset";
+
     /// <summary>The API answers <c>UnauthorizedWorkers</c>.</summary>
     public const string UnauthorizedWorkers = "UnauthorizedWorkers";
 
@@ -214,12 +314,54 @@ public static class ProblemId
     /// <summary>The API answers <c>EventInvalidJsonPayload</c>.</summary>
     public const string EventInvalidJsonPayload = "EventInvalidJsonPayload";
 
+    /// <summary>The API answers <c>EventPayloadSchemaViolation</c>.</summary>
+    public const string EventPayloadSchemaViolation = "EventPayloadSchemaViolation";
+
+    /// <summary>The API answers <c>EventDeliveryBeyondRetention</c>.</summary>
+    public const string EventDeliveryBeyondRetention = "EventDeliveryBeyondRetention";
+
+    /// <summary>The API answers <c>EventNotScheduled</c>.</summary>
+    public const string EventNotScheduled = "EventNotScheduled";
+
     /// <summary>The API answers <c>LabelsAmbiguity</c>.</summary>
     public const string LabelsAmbiguity = "LabelsAmbiguity";
 
     /// <summary>The API answers <c>InvalidDateRange</c>.</summary>
     public const string InvalidDateRange = "InvalidDateRange";
 
+    /// <summary>The API answers <c>RequestAttemptNotFailed</c>.</summary>
+    public const string RequestAttemptNotFailed = "RequestAttemptNotFailed";
+
+    /// <summary>The API answers <c>RequestAttemptRetryPending</c>.</summary>
+    public const string RequestAttemptRetryPending = "RequestAttemptRetryPending";
+
+    /// <summary>The API answers <c>SubscriptionDisabled</c>.</summary>
+    public const string SubscriptionDisabled = "SubscriptionDisabled";
+
+    /// <summary>The API answers <c>SubscriptionNotInbox</c>.</summary>
+    public const string SubscriptionNotInbox = "SubscriptionNotInbox";
+
+    /// <summary>The API answers <c>SecretRotationInProgress</c>.</summary>
+    public const string SecretRotationInProgress = "SecretRotationInProgress";
+
+    /// <summary>The API answers <c>BulkRetryAlreadyRunning</c>.</summary>
+    public const string BulkRetryAlreadyRunning = "BulkRetryAlreadyRunning";
+
+    /// <summary>The API answers <c>BulkRetryFinished</c>.</summary>
+    public const string BulkRetryFinished = "BulkRetryFinished";
+
+    /// <summary>The API answers <c>RetryPolicyOverLimit</c>.</summary>
+    public const string RetryPolicyOverLimit = "RetryPolicyOverLimit";
+
+    /// <summary>The API answers <c>ClientCertificatesDisabled</c>.</summary>
+    public const string ClientCertificatesDisabled = "ClientCertificatesDisabled";
+
+    /// <summary>The API answers <c>InvalidClientCertificate</c>.</summary>
+    public const string InvalidClientCertificate = "InvalidClientCertificate";
+
+    /// <summary>The API answers <c>SigningKeysDisabled</c>.</summary>
+    public const string SigningKeysDisabled = "SigningKeysDisabled";
+
     /// <summary>The API answers <c>AuthNoAuthorizationHeader</c>.</summary>
     public const string AuthNoAuthorizationHeader = "AuthNoAuthorizationHeader";
 
@@ -306,13 +448,30 @@ public static class ProblemId
         "InvalidRole",
         "EventTypeAlreadyExist",
         "EventTypeDoesNotExist",
+        "EventTypeVersionAlreadyExist",
+        "EventTypeVersionDoesNotExist",
+        "EventTypeVersionSunset",
         "UnauthorizedWorkers",
         "EventAlreadyIngested",
         "EventInvalidPayloadContentType",
         "EventInvalidBase64Payload",
         "EventInvalidJsonPayload",
+        "EventPayloadSchemaViolation",
+        "EventDeliveryBeyondRetention",
+        "EventNotScheduled",
         "LabelsAmbiguity",
         "InvalidDateRange",
+        "RequestAttemptNotFailed",
+        "RequestAttemptRetryPending",
+        "SubscriptionDisabled",
+        "SubscriptionNotInbox",
+        "SecretRotationInProgress",
+        "BulkRetryAlreadyRunning",
+        "BulkRetryFinished",
+        "RetryPolicyOverLimit",
+        "ClientCertificatesDisabled",
+        "InvalidClientCertificate",
+        "SigningKeysDisabled",
         "AuthNoAuthorizationHeader",
         "AuthInvalidAuthorizationHeader",
         "AuthApplicationSecretLookupError",
@@ -348,24 +507,15 @@ public static class ProblemId
 }
 
 /// <summary>One of the values the API answers with.</summary>
-public static class RequestAttemptStatusType
+public static class IngestedEventsBatchEventsStatus
 {
-    /// <summary>The API answers <c>waiting</c>.</summary>
-    public const string Waiting = "waiting";
-
-    /// <summary>The API answers <c>pending</c>.</summary>
-    public const string Pending = "pending";
-
-    /// <summary>The API answers <c>in_progress</c>.</summary>
-    public const string InProgress = "in_progress";
-
-    /// <summary>The API answers <c>successful</c>.</summary>
-    public const string Successful = "successful";
+    /// <summary>The API answers <c>ingested</c>.</summary>
+    public const string Ingested = "ingested";
 
     /// <summary>The API answers <c>failed</c>.</summary>
     public const string Failed = "failed";
 
-    private static readonly string[] Declared = ["waiting", "pending", "in_progress", "successful", "failed"];
+    private static readonly string[] Declared = ["ingested", "failed"];
 
     /// <summary>Every value the API declares for this list.</summary>
     public static IReadOnlyList<string> Values => Declared;
@@ -376,136 +526,1402 @@ public static class RequestAttemptStatusType
     public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
 }
 
-/// <summary>The <c>Application</c> the API declares.</summary>
-public sealed record Application
+/// <summary>One of the values the API answers with.</summary>
+public static class OrganizationInfoOnboardingStepsApplication
 {
-    /// <summary>Carries <c>application_id</c>: Unique identifier of the application.</summary>
-    [JsonPropertyName("application_id")]
-    public required Guid ApplicationId { get; init; }
+    /// <summary>The API answers <c>ToDo</c>.</summary>
+    public const string ToDo = "ToDo";
 
-    /// <summary>Carries <c>name</c>: Name of the application. Length: 2-50 characters.</summary>
-    [JsonPropertyName("name")]
-    public required string Name { get; init; }
+    /// <summary>The API answers <c>Done</c>.</summary>
+    public const string Done = "Done";
 
-    /// <summary>Carries <c>organization_id</c>: UUID of the organization this application belongs to.</summary>
-    [JsonPropertyName("organization_id")]
-    public required Guid OrganizationId { get; init; }
+    private static readonly string[] Declared = ["ToDo", "Done"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
 }
 
-/// <summary>The <c>ApplicationInfo</c> the API declares.</summary>
-public sealed record ApplicationInfo
+/// <summary>One of the values the API answers with.</summary>
+public static class OrganizationInfoOnboardingStepsEvent
 {
-    /// <summary>Carries <c>application_id</c>: Unique identifier of the application.</summary>
-    [JsonPropertyName("application_id")]
-    public required Guid ApplicationId { get; init; }
-
-    /// <summary>Carries <c>consumption</c>: Current consumption metrics for this application.</summary>
-    [JsonPropertyName("consumption")]
-    public required ApplicationInfoConsumption Consumption { get; init; }
+    /// <summary>The API answers <c>ToDo</c>.</summary>
+    public const string ToDo = "ToDo";
 
-    /// <summary>Carries <c>name</c>: Name of the application. Length: 2-50 characters.</summary>
-    [JsonPropertyName("name")]
-    public required string Name { get; init; }
+    /// <summary>The API answers <c>Done</c>.</summary>
+    public const string Done = "Done";
 
-    /// <summary>Carries <c>onboarding_steps</c>: Onboarding completion status for this application.</summary>
-    [JsonPropertyName("onboarding_steps")]
-    public required ApplicationInfoOnboardingSteps OnboardingSteps { get; init; }
+    private static readonly string[] Declared = ["ToDo", "Done"];
 
-    /// <summary>Carries <c>organization_id</c>: UUID of the organization this application belongs to.</summary>
-    [JsonPropertyName("organization_id")]
-    public required Guid OrganizationId { get; init; }
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
 
-    /// <summary>Carries <c>quotas</c>: Quota limits for this application.</summary>
-    [JsonPropertyName("quotas")]
-    public required ApplicationInfoQuotas Quotas { get; init; }
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
 }
 
-/// <summary>The <c>ApplicationInfoConsumption</c> the API declares.</summary>
-public sealed record ApplicationInfoConsumption
+/// <summary>One of the values the API answers with.</summary>
+public static class OrganizationInfoOnboardingStepsEventType
 {
-    /// <summary>Carries <c>events_per_day</c>.</summary>
-    [JsonPropertyName("events_per_day")]
-    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
-    public int? EventsPerDay { get; init; }
-}
+    /// <summary>The API answers <c>ToDo</c>.</summary>
+    public const string ToDo = "ToDo";
 
-/// <summary>The <c>ApplicationInfoOnboardingSteps</c> the API declares.</summary>
-public sealed record ApplicationInfoOnboardingSteps
-{
-    /// <summary>Carries <c>event</c>.</summary>
-    [JsonPropertyName("event")]
-    public required string Event { get; init; }
+    /// <summary>The API answers <c>Done</c>.</summary>
+    public const string Done = "Done";
 
-    /// <summary>Carries <c>event_type</c>.</summary>
-    [JsonPropertyName("event_type")]
-    public required string EventType { get; init; }
+    private static readonly string[] Declared = ["ToDo", "Done"];
 
-    /// <summary>Carries <c>subscription</c>.</summary>
-    [JsonPropertyName("subscription")]
-    public required string Subscription { get; init; }
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
 }
 
-/// <summary>The <c>ApplicationInfoQuotas</c> the API declares.</summary>
-public sealed record ApplicationInfoQuotas
+/// <summary>One of the values the API answers with.</summary>
+public static class OrganizationInfoOnboardingStepsSubscription
 {
-    /// <summary>Carries <c>days_of_events_retention_limit</c>.</summary>
-    [JsonPropertyName("days_of_events_retention_limit")]
-    public required int DaysOfEventsRetentionLimit { get; init; }
+    /// <summary>The API answers <c>ToDo</c>.</summary>
+    public const string ToDo = "ToDo";
 
-    /// <summary>Carries <c>events_per_day_limit</c>.</summary>
-    [JsonPropertyName("events_per_day_limit")]
-    public required int EventsPerDayLimit { get; init; }
-}
+    /// <summary>The API answers <c>Done</c>.</summary>
+    public const string Done = "Done";
 
-/// <summary>The <c>ApplicationPost</c> the API declares.</summary>
-public sealed record ApplicationPost
-{
-    /// <summary>Carries <c>name</c>: Name of the application. Length: 2-50 characters.</summary>
-    [JsonPropertyName("name")]
-    public required string Name { get; init; }
+    private static readonly string[] Declared = ["ToDo", "Done"];
 
-    /// <summary>Carries <c>organization_id</c>: UUID of the organization this application belongs to.</summary>
-    [JsonPropertyName("organization_id")]
-    public required Guid OrganizationId { get; init; }
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
 }
 
-/// <summary>The <c>ApplicationSecret</c> the API declares.</summary>
-public sealed record ApplicationSecret
+/// <summary>One of the values the API answers with.</summary>
+public static class ProblemId
 {
-    /// <summary>Carries <c>created_at</c>.</summary>
-    [JsonPropertyName("created_at")]
-    public required DateTimeOffset CreatedAt { get; init; }
+    /// <summary>The API answers <c>OrganizationNameMissing</c>.</summary>
+    public const string OrganizationNameMissing = "OrganizationNameMissing";
 
-    /// <summary>Carries <c>token</c>.</summary>
-    [JsonPropertyName("token")]
-    public required Guid Token { get; init; }
+    /// <summary>The API answers <c>UserAlreadyExist</c>.</summary>
+    public const string UserAlreadyExist = "UserAlreadyExist";
 
-    /// <summary>Carries <c>deleted_at</c>.</summary>
-    [JsonPropertyName("deleted_at")]
+    /// <summary>The API answers <c>RegistrationDisabled</c>.</summary>
+    public const string RegistrationDisabled = "RegistrationDisabled";
+
+    /// <summary>The API answers <c>PasswordTooShort</c>.</summary>
+    public const string PasswordTooShort = "PasswordTooShort";
+
+    /// <summary>The API answers <c>PasswordTooLong</c>.</summary>
+    public const string PasswordTooLong = "PasswordTooLong";
+
+    /// <summary>The API answers <c>PasswordSimilarToEmail</c>.</summary>
+    public const string PasswordSimilarToEmail = "PasswordSimilarToEmail";
+
+    /// <summary>The API answers <c>PasswordSimilarToName</c>.</summary>
+    public const string PasswordSimilarToName = "PasswordSimilarToName";
+
+    /// <summary>The API answers <c>PasswordTooCommon</c>.</summary>
+    public const string PasswordTooCommon = "PasswordTooCommon";
+
+    /// <summary>The API answers <c>PasswordNotDiverseEnough</c>.</summary>
+    public const string PasswordNotDiverseEnough = "PasswordNotDiverseEnough";
+
+    /// <summary>The API answers <c>OrganizationIsNotEmpty</c>.</summary>
+    public const string OrganizationIsNotEmpty = "OrganizationIsNotEmpty";
+
+    /// <summary>The API answers <c>InvitedUserDoesNotExist</c>.</summary>
+    public const string InvitedUserDoesNotExist = "InvitedUserDoesNotExist";
+
+    /// <summary>The API answers <c>InvitedUserAlreadyInOrganization</c>.</summary>
+    public const string InvitedUserAlreadyInOrganization = "InvitedUserAlreadyInOrganization";
+
+    /// <summary>The API answers <c>ApplicationNameMissing</c>.</summary>
+    public const string ApplicationNameMissing = "ApplicationNameMissing";
+
+    /// <summary>The API answers <c>InvalidRole</c>.</summary>
+    public const string InvalidRole = "InvalidRole";
+
+    /// <summary>The API answers <c>EventTypeAlreadyExist</c>.</summary>
+    public const string EventTypeAlreadyExist = "EventTypeAlreadyExist";
+
+    /// <summary>The API answers <c>EventTypeDoesNotExist</c>.</summary>
+    public const string EventTypeDoesNotExist = "EventTypeDoesNotExist";
+
+    /// <summary>The API answers <c>EventTypeVersionAlreadyExist</c>.</summary>
+    public const string EventTypeVersionAlreadyExist = "EventTypeVersionAlreadyExist";
+
+    /// <summary>The API answers <c>EventTypeVersionDoesNotExist</c>.</summary>
+    public const string EventTypeVersionDoesNotExist = "EventTypeVersionDoesNotExist";
+
+    /// <summary>The API answers <c>EventTypeVersionSunset</c>.</summary>
+    public const string EventTypeVersionSunset = "EventTypeVersionSunset";
+
+    /// <summary>The API answers <c>UnauthorizedWorkers</c>.</summary>
+    public const string UnauthorizedWorkers = "UnauthorizedWorkers";
+
+    /// <summary>The API answers <c>EventAlreadyIngested</c>.</summary>
+    public const string EventAlreadyIngested = "EventAlreadyIngested";
+
+    /// <summary>The API answers <c>EventInvalidPayloadContentType</c>.</summary>
+    public const string EventInvalidPayloadContentType = "EventInvalidPayloadContentType";
+
+    /// <summary>The API answers <c>EventInvalidBase64Payload</c>.</summary>
+    public const string EventInvalidBase64Payload = "EventInvalidBase64Payload";
+
+    /// <summary>The API answers <c>EventInvalidJsonPayload</c>.</summary>
+    public const string EventInvalidJsonPayload = "EventInvalidJsonPayload";
+
+    /// <summary>The API answers <c>EventPayloadSchemaViolation</c>.</summary>
+    public const string EventPayloadSchemaViolation = "EventPayloadSchemaViolation";
+
+    /// <summary>The API answers <c>EventDeliveryBeyondRetention</c>.</summary>
+    public const string EventDeliveryBeyondRetention = "EventDeliveryBeyondRetention";
+
+    /// <summary>The API answers <c>EventNotScheduled</c>.</summary>
+    public const string EventNotScheduled = "EventNotScheduled";
+
+    /// <summary>The API answers <c>LabelsAmbiguity</c>.</summary>
+    public const string LabelsAmbiguity = "LabelsAmbiguity";
+
+    /// <summary>The API answers <c>InvalidDateRange</c>.</summary>
+    public const string InvalidDateRange = "InvalidDateRange";
+
+    /// <summary>The API answers <c>RequestAttemptNotFailed</c>.</summary>
+    public const string RequestAttemptNotFailed = "RequestAttemptNotFailed";
+
+    /// <summary>The API answers <c>RequestAttemptRetryPending</c>.</summary>
+    public const string RequestAttemptRetryPending = "RequestAttemptRetryPending";
+
+    /// <summary>The API answers <c>SubscriptionDisabled</c>.</summary>
+    public const string SubscriptionDisabled = "SubscriptionDisabled";
+
+    /// <summary>The API answers <c>SubscriptionNotInbox</c>.</summary>
+    public const string SubscriptionNotInbox = "SubscriptionNotInbox";
+
+    /// <summary>The API answers <c>SecretRotationInProgress</c>.</summary>
+    public const string SecretRotationInProgress = "SecretRotationInProgress";
+
+    /// <summary>The API answers <c>BulkRetryAlreadyRunning</c>.</summary>
+    public const string BulkRetryAlreadyRunning = "BulkRetryAlreadyRunning";
+
+    /// <summary>The API answers <c>BulkRetryFinished</c>.</summary>
+    public const string BulkRetryFinished = "BulkRetryFinished";
+
+    /// <summary>The API answers <c>RetryPolicyOverLimit</c>.</summary>
+    public const string RetryPolicyOverLimit = "RetryPolicyOverLimit";
+
+    /// <summary>The API answers <c>ClientCertificatesDisabled</c>.</summary>
+    public const string ClientCertificatesDisabled = "ClientCertificatesDisabled";
+
+    /// <summary>The API answers <c>InvalidClientCertificate</c>.</summary>
+    public const string InvalidClientCertificate = "InvalidClientCertificate";
+
+    /// <summary>The API answers <c>SigningKeysDisabled</c>.</summary>
+    public const string SigningKeysDisabled = "SigningKeysDisabled";
+
+    /// <summary>The API answers <c>AuthNoAuthorizationHeader</c>.</summary>
+    public const string AuthNoAuthorizationHeader = "AuthNoAuthorizationHeader";
+
+    /// <summary>The API answers <c>AuthInvalidAuthorizationHeader</c>.</summary>
+    public const string AuthInvalidAuthorizationHeader = "AuthInvalidAuthorizationHeader";
+
+    /// <summary>The API answers <c>AuthApplicationSecretLookupError</c>.</summary>
+    public const string AuthApplicationSecretLookupError = "AuthApplicationSecretLookupError";
+
+    /// <summary>The API answers <c>AuthInvalidApplicationSecret</c>.</summary>
+    public const string AuthInvalidApplicationSecret = "AuthInvalidApplicationSecret";
+
+    /// <summary>The API answers <c>AuthBiscuitLookupError</c>.</summary>
+    public const string AuthBiscuitLookupError = "AuthBiscuitLookupError";
+
+    /// <summary>The API answers <c>AuthInvalidBiscuit</c>.</summary>
+    public const string AuthInvalidBiscuit = "AuthInvalidBiscuit";
+
+    /// <summary>The API answers <c>AuthFailedLogin</c>.</summary>
+    public const string AuthFailedLogin = "AuthFailedLogin";
+
+    /// <summary>The API answers <c>AuthEmailNotVerified</c>.</summary>
+    public const string AuthEmailNotVerified = "AuthEmailNotVerified";
+
+    /// <summary>The API answers <c>AuthEmailAlreadyVerified</c>.</summary>
+    public const string AuthEmailAlreadyVerified = "AuthEmailAlreadyVerified";
+
+    /// <summary>The API answers <c>AuthFailedRefresh</c>.</summary>
+    public const string AuthFailedRefresh = "AuthFailedRefresh";
+
+    /// <summary>The API answers <c>AuthEmailExpired</c>.</summary>
+    public const string AuthEmailExpired = "AuthEmailExpired";
+
+    /// <summary>The API answers <c>TooManyMembersPerOrganization</c>.</summary>
+    public const string TooManyMembersPerOrganization = "TooManyMembersPerOrganization";
+
+    /// <summary>The API answers <c>TooManyApplicationsPerOrganization</c>.</summary>
+    public const string TooManyApplicationsPerOrganization = "TooManyApplicationsPerOrganization";
+
+    /// <summary>The API answers <c>TooManyEventsToday</c>.</summary>
+    public const string TooManyEventsToday = "TooManyEventsToday";
+
+    /// <summary>The API answers <c>TooManySubscriptionsPerApplication</c>.</summary>
+    public const string TooManySubscriptionsPerApplication = "TooManySubscriptionsPerApplication";
+
+    /// <summary>The API answers <c>TooManyEventTypesPerApplication</c>.</summary>
+    public const string TooManyEventTypesPerApplication = "TooManyEventTypesPerApplication";
+
+    /// <summary>The API answers <c>JsonPayload</c>.</summary>
+    public const string JsonPayload = "JsonPayload";
+
+    /// <summary>The API answers <c>Validation</c>.</summary>
+    public const string Validation = "Validation";
+
+    /// <summary>The API answers <c>NotFound</c>.</summary>
+    public const string NotFound = "NotFound";
+
+    /// <summary>The API answers <c>InternalServerError</c>.</summary>
+    public const string InternalServerError = "InternalServerError";
+
+    /// <summary>The API answers <c>Forbidden</c>.</summary>
+    public const string Forbidden = "Forbidden";
+
+    /// <summary>The API answers <c>RateLimited</c>.</summary>
+    public const string RateLimited = "RateLimited";
+
+    /// <summary>The API answers <c>ServiceUnavailable</c>.</summary>
+    public const string ServiceUnavailable = "ServiceUnavailable";
+
+    private static readonly string[] Declared = [
+        "OrganizationNameMissing",
+        "UserAlreadyExist",
+        "RegistrationDisabled",
+        "PasswordTooShort",
+        "PasswordTooLong",
+        "PasswordSimilarToEmail",
+        "PasswordSimilarToName",
+        "PasswordTooCommon",
+        "PasswordNotDiverseEnough",
+        "OrganizationIsNotEmpty",
+        "InvitedUserDoesNotExist",
+        "InvitedUserAlreadyInOrganization",
+        "ApplicationNameMissing",
+        "InvalidRole",
+        "EventTypeAlreadyExist",
+        "EventTypeDoesNotExist",
+        "EventTypeVersionAlreadyExist",
+        "EventTypeVersionDoesNotExist",
+        "EventTypeVersionSunset",
+        "UnauthorizedWorkers",
+        "EventAlreadyIngested",
+        "EventInvalidPayloadContentType",
+        "EventInvalidBase64Payload",
+        "EventInvalidJsonPayload",
+        "EventPayloadSchemaViolation",
+        "EventDeliveryBeyondRetention",
+        "EventNotScheduled",
+        "LabelsAmbiguity",
+        "InvalidDateRange",
+        "RequestAttemptNotFailed",
+        "RequestAttemptRetryPending",
+        "SubscriptionDisabled",
+        "SubscriptionNotInbox",
+        "SecretRotationInProgress",
+        "BulkRetryAlreadyRunning",
+        "BulkRetryFinished",
+        "RetryPolicyOverLimit",
+        "ClientCertificatesDisabled",
+        "InvalidClientCertificate",
+        "SigningKeysDisabled",
+        "AuthNoAuthorizationHeader",
+        "AuthInvalidAuthorizationHeader",
+        "AuthApplicationSecretLookupError",
+        "AuthInvalidApplicationSecret",
+        "AuthBiscuitLookupError",
+        "AuthInvalidBiscuit",
+        "AuthFailedLogin",
+        "AuthEmailNotVerified",
+        "AuthEmailAlreadyVerified",
+        "AuthFailedRefresh",
+        "AuthEmailExpired",
+        "TooManyMembersPerOrganization",
+        "TooManyApplicationsPerOrganization",
+        "TooManyEventsToday",
+        "TooManySubscriptionsPerApplication",
+        "TooManyEventTypesPerApplication",
+        "JsonPayload",
+        "Validation",
+        "NotFound",
+        "InternalServerError",
+        "Forbidden",
+        "RateLimited",
+        "ServiceUnavailable"
+    ];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class RequestAttemptStatusRuleOutcome
+{
+    /// <summary>The API answers <c>success</c>.</summary>
+    public const string Success = "success";
+
+    /// <summary>The API answers <c>retryable</c>.</summary>
+    public const string Retryable = "retryable";
+
+    /// <summary>The API answers <c>permanent_failure</c>.</summary>
+    public const string PermanentFailure = "permanent_failure";
+
+    /// <summary>The API answers <c>disable_subscription</c>.</summary>
+    public const string DisableSubscription = "disable_subscription";
+
+    private static readonly string[] Declared = ["success", "retryable", "permanent_failure", "disable_subscription"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class RequestAttemptStatusType
+{
+    /// <summary>The API answers <c>waiting</c>.</summary>
+    public const string Waiting = "waiting";
+
+    /// <summary>The API answers <c>pending</c>.</summary>
+    public const string Pending = "pending";
+
+    /// <summary>The API answers <c>in_progress</c>.</summary>
+    public const string InProgress = "in_progress";
+
+    /// <summary>The API answers <c>successful</c>.</summary>
+    public const string Successful = "successful";
+
+    /// <summary>The API answers <c>failed</c>.</summary>
+    public const string Failed = "failed";
+
+    /// <summary>The API answers <c>expired</c>.</summary>
+    public const string Expired = "expired";
+
+    /// <summary>The API answers <c>cancelled</c>.</summary>
+    public const string Cancelled = "cancelled";
+
+    private static readonly string[] Declared = [
+        "waiting",
+        "pending",
+        "in_progress",
+        "successful",
+        "failed",
+        "expired",
+        "cancelled"
+    ];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class SubscriptionPostResponseRulesOutcome
+{
+    /// <summary>The API answers <c>success</c>.</summary>
+    public const string Success = "success";
+
+    /// <summary>The API answers <c>retryable</c>.</summary>
+    public const string Retryable = "retryable";
+
+    /// <summary>The API answers <c>permanent_failure</c>.</summary>
+    public const string PermanentFailure = "permanent_failure";
+
+    /// <summary>The API answers <c>disable_subscription</c>.</summary>
+    public const string DisableSubscription = "disable_subscription";
+
+    private static readonly string[] Declared = ["success", "retryable", "permanent_failure", "disable_subscription"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class SubscriptionPostSignatureScheme
+{
+    /// <summary>The API answers <c>hook0</c>.</summary>
+    public const string Hook0 = "hook0";
+
+    /// <summary>The API answers <c>standard_webhooks</c>.</summary>
+    public const string StandardWebhooks = "standard_webhooks";
+
+    private static readonly string[] Declared = ["hook0", "standard_webhooks"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class SubscriptionPostTargetType
+{
+    /// <summary>The API answers <c>http</c>.</summary>
+    public const string Http = "http";
+
+    /// <summary>The API answers <c>amqp</c>.</summary>
+    public const string Amqp = "amqp";
+
+    /// <summary>The API answers <c>sqs</c>.</summary>
+    public const string Sqs = "sqs";
+
+    /// <summary>The API answers <c>inbox</c>.</summary>
+    public const string Inbox = "inbox";
+
+    private static readonly string[] Declared = ["http", "amqp", "sqs", "inbox"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class SubscriptionResponseRulesOutcome
+{
+    /// <summary>The API answers <c>success</c>.</summary>
+    public const string Success = "success";
+
+    /// <summary>The API answers <c>retryable</c>.</summary>
+    public const string Retryable = "retryable";
+
+    /// <summary>The API answers <c>permanent_failure</c>.</summary>
+    public const string PermanentFailure = "permanent_failure";
+
+    /// <summary>The API answers <c>disable_subscription</c>.</summary>
+    public const string DisableSubscription = "disable_subscription";
+
+    private static readonly string[] Declared = ["success", "retryable", "permanent_failure", "disable_subscription"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class SubscriptionSignatureScheme
+{
+    /// <summary>The API answers <c>hook0</c>.</summary>
+    public const string Hook0 = "hook0";
+
+    /// <summary>The API answers <c>standard_webhooks</c>.</summary>
+    public const string StandardWebhooks = "standard_webhooks";
+
+    private static readonly string[] Declared = ["hook0", "standard_webhooks"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>One of the values the API answers with.</summary>
+public static class SubscriptionTargetType
+{
+    /// <summary>The API answers <c>http</c>.</summary>
+    public const string Http = "http";
+
+    /// <summary>The API answers <c>amqp</c>.</summary>
+    public const string Amqp = "amqp";
+
+    /// <summary>The API answers <c>sqs</c>.</summary>
+    public const string Sqs = "sqs";
+
+    /// <summary>The API answers <c>inbox</c>.</summary>
+    public const string Inbox = "inbox";
+
+    private static readonly string[] Declared = ["http", "amqp", "sqs", "inbox"];
+
+    /// <summary>Every value the API declares for this list.</summary>
+    public static IReadOnlyList<string> Values => Declared;
+
+    /// <summary>Whether the API declares that value.</summary>
+    /// <param name="value">The text to look for.</param>
+    /// <returns>Whether this list carries it.</returns>
+    public static bool Contains(string value) => Array.IndexOf(Declared, value) >= 0;
+}
+
+/// <summary>The <c>Application</c> the API declares.</summary>
+public sealed record Application
+{
+    /// <summary>Carries <c>application_id</c>: Unique identifier of the application.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>name</c>: Name of the application. Length: 2-50 characters.</summary>
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    /// <summary>Carries <c>organization_id</c>: UUID of the organization this application belongs to.</summary>
+    [JsonPropertyName("organization_id")]
+    public required Guid OrganizationId { get; init; }
+}
+
+/// <summary>The <c>ApplicationInfo</c> the API declares.</summary>
+public sealed record ApplicationInfo
+{
+    /// <summary>Carries <c>application_id</c>: Unique identifier of the application.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>consumption</c>: Current consumption metrics for this application.</summary>
+    [JsonPropertyName("consumption")]
+    public required ApplicationInfoConsumption Consumption { get; init; }
+
+    /// <summary>Carries <c>name</c>: Name of the application. Length: 2-50 characters.</summary>
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    /// <summary>Carries <c>onboarding_steps</c>: Onboarding completion status for this application.</summary>
+    [JsonPropertyName("onboarding_steps")]
+    public required ApplicationInfoOnboardingSteps OnboardingSteps { get; init; }
+
+    /// <summary>Carries <c>organization_id</c>: UUID of the organization this application belongs to.</summary>
+    [JsonPropertyName("organization_id")]
+    public required Guid OrganizationId { get; init; }
+
+    /// <summary>Carries <c>quotas</c>: Quota limits for this application.</summary>
+    [JsonPropertyName("quotas")]
+    public required ApplicationInfoQuotas Quotas { get; init; }
+}
+
+/// <summary>The <c>ApplicationInfoConsumption</c> the API declares.</summary>
+public sealed record ApplicationInfoConsumption
+{
+    /// <summary>Carries <c>events_per_day</c>.</summary>
+    [JsonPropertyName("events_per_day")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? EventsPerDay { get; init; }
+}
+
+/// <summary>The <c>ApplicationInfoOnboardingSteps</c> the API declares.</summary>
+public sealed record ApplicationInfoOnboardingSteps
+{
+    /// <summary>Carries <c>event</c>.</summary>
+    [JsonPropertyName("event")]
+    public required string Event { get; init; }
+
+    /// <summary>Carries <c>event_type</c>.</summary>
+    [JsonPropertyName("event_type")]
+    public required string EventType { get; init; }
+
+    /// <summary>Carries <c>subscription</c>.</summary>
+    [JsonPropertyName("subscription")]
+    public required string Subscription { get; init; }
+}
+
+/// <summary>The <c>ApplicationInfoQuotas</c> the API declares.</summary>
+public sealed record ApplicationInfoQuotas
+{
+    /// <summary>Carries <c>days_of_events_retention_limit</c>.</summary>
+    [JsonPropertyName("days_of_events_retention_limit")]
+    public required int DaysOfEventsRetentionLimit { get; init; }
+
+    /// <summary>Carries <c>events_per_day_limit</c>.</summary>
+    [JsonPropertyName("events_per_day_limit")]
+    public required int EventsPerDayLimit { get; init; }
+}
+
+/// <summary>The <c>ApplicationPost</c> the API declares.</summary>
+public sealed record ApplicationPost
+{
+    /// <summary>Carries <c>name</c>: Name of the application. Length: 2-50 characters.</summary>
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    /// <summary>Carries <c>organization_id</c>: UUID of the organization this application belongs to.</summary>
+    [JsonPropertyName("organization_id")]
+    public required Guid OrganizationId { get; init; }
+}
+
+/// <summary>The <c>ApplicationSecret</c> the API declares.</summary>
+public sealed record ApplicationSecret
+{
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>Carries <c>token</c>.</summary>
+    [JsonPropertyName("token")]
+    public required Guid Token { get; init; }
+
+    /// <summary>Carries <c>deleted_at</c>.</summary>
+    [JsonPropertyName("deleted_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? DeletedAt { get; init; }
+
+    /// <summary>Carries <c>name</c>.</summary>
+    [JsonPropertyName("name")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Name { get; init; }
+}
+
+/// <summary>The <c>ApplicationSecretPost</c> the API declares.</summary>
+public sealed record ApplicationSecretPost
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>name</c>.</summary>
+    [JsonPropertyName("name")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Name { get; init; }
+}
+
+/// <summary>The <c>BulkRetry</c> the API declares.</summary>
+public sealed record BulkRetry
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>bulk_retry_id</c>.</summary>
+    [JsonPropertyName("bulk_retry_id")]
+    public required Guid BulkRetryId { get; init; }
+
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>Carries <c>event_type_names</c>.</summary>
+    [JsonPropertyName("event_type_names")]
+    public required IReadOnlyList<string> EventTypeNames { get; init; }
+
+    /// <summary>Carries <c>max_created_at</c>.</summary>
+    [JsonPropertyName("max_created_at")]
+    public required DateTimeOffset MaxCreatedAt { get; init; }
+
+    /// <summary>Carries <c>min_created_at</c>.</summary>
+    [JsonPropertyName("min_created_at")]
+    public required DateTimeOffset MinCreatedAt { get; init; }
+
+    /// <summary>Carries <c>processed_count</c>: Number of pairs handled so far</summary>
+    [JsonPropertyName("processed_count")]
+    public required long ProcessedCount { get; init; }
+
+    /// <summary>Carries <c>retried_count</c>: Number of request attempts created so far</summary>
+    [JsonPropertyName("retried_count")]
+    public required long RetriedCount { get; init; }
+
+    /// <summary>Carries <c>status</c>.</summary>
+    [JsonPropertyName("status")]
+    public required string Status { get; init; }
+
+    /// <summary>
+    /// Carries <c>total_count</c>: Number of (event, subscription) pairs that matched when the bulk retry was created
+    /// </summary>
+    [JsonPropertyName("total_count")]
+    public required long TotalCount { get; init; }
+
+    /// <summary>Carries <c>updated_at</c>.</summary>
+    [JsonPropertyName("updated_at")]
+    public required DateTimeOffset UpdatedAt { get; init; }
+
+    /// <summary>Carries <c>cancelled_at</c>.</summary>
+    [JsonPropertyName("cancelled_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? CancelledAt { get; init; }
+
+    /// <summary>Carries <c>completed_at</c>.</summary>
+    [JsonPropertyName("completed_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? CompletedAt { get; init; }
+
+    /// <summary>Carries <c>event_id</c>.</summary>
+    [JsonPropertyName("event_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? EventId { get; init; }
+
+    /// <summary>Carries <c>subscription_id</c>.</summary>
+    [JsonPropertyName("subscription_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? SubscriptionId { get; init; }
+}
+
+/// <summary>The <c>BulkRetryDryRun</c> the API declares.</summary>
+public sealed record BulkRetryDryRun
+{
+    /// <summary>Carries <c>count</c>: Number of (event, subscription) pairs a bulk retry would redeliver now</summary>
+    [JsonPropertyName("count")]
+    public required long Count { get; init; }
+}
+
+/// <summary>The <c>BulkRetryPost</c> the API declares.</summary>
+public sealed record BulkRetryPost
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>event_id</c>.</summary>
+    [JsonPropertyName("event_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? EventId { get; init; }
+
+    /// <summary>Carries <c>event_type_names</c>.</summary>
+    [JsonPropertyName("event_type_names")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<string>? EventTypeNames { get; init; }
+
+    /// <summary>Carries <c>max_created_at</c>.</summary>
+    [JsonPropertyName("max_created_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? MaxCreatedAt { get; init; }
+
+    /// <summary>Carries <c>min_created_at</c>.</summary>
+    [JsonPropertyName("min_created_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? MinCreatedAt { get; init; }
+
+    /// <summary>Carries <c>status</c>: Redeliver request attempts that failed (default) or that succeeded</summary>
+    [JsonPropertyName("status")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Status { get; init; }
+
+    /// <summary>Carries <c>subscription_id</c>.</summary>
+    [JsonPropertyName("subscription_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? SubscriptionId { get; init; }
+}
+
+/// <summary>The <c>CancelEvent</c> the API declares.</summary>
+public sealed record CancelEvent
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+}
+
+/// <summary>The <c>ClientCertificate</c> the API declares.</summary>
+public sealed record ClientCertificate
+{
+    /// <summary>Carries <c>certificate_pem</c>: Certificate chain, leaf certificate first</summary>
+    [JsonPropertyName("certificate_pem")]
+    public required string CertificatePem { get; init; }
+
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>fingerprint_sha256</c>: SHA-256 fingerprint of the leaf certificate, as hexadecimal
+    /// </summary>
+    [JsonPropertyName("fingerprint_sha256")]
+    public required string FingerprintSha256 { get; init; }
+
+    /// <summary>Carries <c>not_after</c>.</summary>
+    [JsonPropertyName("not_after")]
+    public required DateTimeOffset NotAfter { get; init; }
+
+    /// <summary>Carries <c>not_before</c>.</summary>
+    [JsonPropertyName("not_before")]
+    public required DateTimeOffset NotBefore { get; init; }
+
+    /// <summary>Carries <c>subject</c>.</summary>
+    [JsonPropertyName("subject")]
+    public required string Subject { get; init; }
+
+    /// <summary>Carries <c>subscription_id</c>.</summary>
+    [JsonPropertyName("subscription_id")]
+    public required Guid SubscriptionId { get; init; }
+
+    /// <summary>Carries <c>updated_at</c>.</summary>
+    [JsonPropertyName("updated_at")]
+    public required DateTimeOffset UpdatedAt { get; init; }
+}
+
+/// <summary>The <c>ClientCertificatePut</c> the API declares.</summary>
+public sealed record ClientCertificatePut
+{
+    /// <summary>Carries <c>certificate_pem</c>: PEM-encoded certificate chain, leaf certificate first</summary>
+    [JsonPropertyName("certificate_pem")]
+    public required string CertificatePem { get; init; }
+
+    /// <summary>
+    /// Carries <c>private_key_pem</c>: PEM-encoded unencrypted private key of the leaf certificate (PKCS#8, PKCS#1 or
+    /// SEC1)
+    /// </summary>
+    [JsonPropertyName("private_key_pem")]
+    public required string PrivateKeyPem { get; init; }
+}
+
+/// <summary>The <c>DeadLetter</c> the API declares.</summary>
+public sealed record DeadLetter
+{
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>Carries <c>dead_letter_id</c>.</summary>
+    [JsonPropertyName("dead_letter_id")]
+    public required Guid DeadLetterId { get; init; }
+
+    /// <summary>Carries <c>event</c>.</summary>
+    [JsonPropertyName("event")]
+    public required DeadLetterEvent Event { get; init; }
+
+    /// <summary>Carries <c>reason</c>.</summary>
+    [JsonPropertyName("reason")]
+    public required string Reason { get; init; }
+
+    /// <summary>Carries <c>request_attempt_id</c>: Last request attempt of the delivery</summary>
+    [JsonPropertyName("request_attempt_id")]
+    public required Guid RequestAttemptId { get; init; }
+
+    /// <summary>Carries <c>retry_count</c>.</summary>
+    [JsonPropertyName("retry_count")]
+    public required int RetryCount { get; init; }
+
+    /// <summary>Carries <c>subscription</c>.</summary>
+    [JsonPropertyName("subscription")]
+    public required DeadLetterSubscription Subscription { get; init; }
+
+    /// <summary>Carries <c>http_response_status</c>.</summary>
+    [JsonPropertyName("http_response_status")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? HttpResponseStatus { get; init; }
+
+    /// <summary>Carries <c>response_id</c>: Response to the last request attempt, if the target was reached</summary>
+    [JsonPropertyName("response_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? ResponseId { get; init; }
+}
+
+/// <summary>The <c>DeadLetterDetails</c> the API declares.</summary>
+public sealed record DeadLetterDetails
+{
+    /// <summary>Carries <c>dead_letter</c>: Delivery an output worker gave up on</summary>
+    [JsonPropertyName("dead_letter")]
+    public required DeadLetterDetailsDeadLetter DeadLetter { get; init; }
+
+    /// <summary>Carries <c>last_response</c>: Response to the last request attempt, if the target was reached</summary>
+    [JsonPropertyName("last_response")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DeadLetterDetailsLastResponse? LastResponse { get; init; }
+}
+
+/// <summary>The <c>DeadLetterDetailsDeadLetter</c> the API declares.</summary>
+public sealed record DeadLetterDetailsDeadLetter
+{
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>Carries <c>dead_letter_id</c>.</summary>
+    [JsonPropertyName("dead_letter_id")]
+    public required Guid DeadLetterId { get; init; }
+
+    /// <summary>Carries <c>event</c>.</summary>
+    [JsonPropertyName("event")]
+    public required DeadLetterDetailsDeadLetterEvent Event { get; init; }
+
+    /// <summary>Carries <c>reason</c>.</summary>
+    [JsonPropertyName("reason")]
+    public required string Reason { get; init; }
+
+    /// <summary>Carries <c>request_attempt_id</c>: Last request attempt of the delivery</summary>
+    [JsonPropertyName("request_attempt_id")]
+    public required Guid RequestAttemptId { get; init; }
+
+    /// <summary>Carries <c>retry_count</c>.</summary>
+    [JsonPropertyName("retry_count")]
+    public required int RetryCount { get; init; }
+
+    /// <summary>Carries <c>subscription</c>.</summary>
+    [JsonPropertyName("subscription")]
+    public required DeadLetterDetailsDeadLetterSubscription Subscription { get; init; }
+
+    /// <summary>Carries <c>http_response_status</c>.</summary>
+    [JsonPropertyName("http_response_status")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? HttpResponseStatus { get; init; }
+
+    /// <summary>Carries <c>response_id</c>: Response to the last request attempt, if the target was reached</summary>
+    [JsonPropertyName("response_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? ResponseId { get; init; }
+}
+
+/// <summary>The <c>DeadLetterDetailsDeadLetterEvent</c> the API declares.</summary>
+public sealed record DeadLetterDetailsDeadLetterEvent
+{
+    /// <summary>Carries <c>event_id</c>.</summary>
+    [JsonPropertyName("event_id")]
+    public required Guid EventId { get; init; }
+
+    /// <summary>Carries <c>event_type_name</c>.</summary>
+    [JsonPropertyName("event_type_name")]
+    public required string EventTypeName { get; init; }
+}
+
+/// <summary>The <c>DeadLetterDetailsDeadLetterSubscription</c> the API declares.</summary>
+public sealed record DeadLetterDetailsDeadLetterSubscription
+{
+    /// <summary>Carries <c>subscription_id</c>.</summary>
+    [JsonPropertyName("subscription_id")]
+    public required Guid SubscriptionId { get; init; }
+
+    /// <summary>Carries <c>description</c>.</summary>
+    [JsonPropertyName("description")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Description { get; init; }
+}
+
+/// <summary>The <c>DeadLetterDetailsLastResponse</c> the API declares.</summary>
+public sealed record DeadLetterDetailsLastResponse
+{
+    /// <summary>Carries <c>response_id</c>.</summary>
+    [JsonPropertyName("response_id")]
+    public required Guid ResponseId { get; init; }
+
+    /// <summary>Carries <c>body</c>.</summary>
+    [JsonPropertyName("body")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Body { get; init; }
+
+    /// <summary>Carries <c>elapsed_time_ms</c>.</summary>
+    [JsonPropertyName("elapsed_time_ms")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? ElapsedTimeMs { get; init; }
+
+    /// <summary>Carries <c>headers</c>.</summary>
+    [JsonPropertyName("headers")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyDictionary<string, string>? Headers { get; init; }
+
+    /// <summary>Carries <c>http_code</c>.</summary>
+    [JsonPropertyName("http_code")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? HttpCode { get; init; }
+
+    /// <summary>Carries <c>response_error_name</c>.</summary>
+    [JsonPropertyName("response_error_name")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? ResponseErrorName { get; init; }
+}
+
+/// <summary>The <c>DeadLetterEvent</c> the API declares.</summary>
+public sealed record DeadLetterEvent
+{
+    /// <summary>Carries <c>event_id</c>.</summary>
+    [JsonPropertyName("event_id")]
+    public required Guid EventId { get; init; }
+
+    /// <summary>Carries <c>event_type_name</c>.</summary>
+    [JsonPropertyName("event_type_name")]
+    public required string EventTypeName { get; init; }
+}
+
+/// <summary>The <c>DeadLetterSubscription</c> the API declares.</summary>
+public sealed record DeadLetterSubscription
+{
+    /// <summary>Carries <c>subscription_id</c>.</summary>
+    [JsonPropertyName("subscription_id")]
+    public required Guid SubscriptionId { get; init; }
+
+    /// <summary>Carries <c>description</c>.</summary>
+    [JsonPropertyName("description")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Description { get; init; }
+}
+
+/// <summary>The <c>Event</c> the API declares.</summary>
+public sealed record Event
+{
+    /// <summary>Carries <c>event_id</c>.</summary>
+    [JsonPropertyName("event_id")]
+    public required Guid EventId { get; init; }
+
+    /// <summary>Carries <c>event_type_name</c>.</summary>
+    [JsonPropertyName("event_type_name")]
+    public required string EventTypeName { get; init; }
+
+    /// <summary>Carries <c>ip</c>.</summary>
+    [JsonPropertyName("ip")]
+    public required string Ip { get; init; }
+
+    /// <summary>Carries <c>labels</c>.</summary>
+    [JsonPropertyName("labels")]
+    public required JsonNode Labels { get; init; }
+
+    /// <summary>Carries <c>occurred_at</c>.</summary>
+    [JsonPropertyName("occurred_at")]
+    public required DateTimeOffset OccurredAt { get; init; }
+
+    /// <summary>Carries <c>payload_content_type</c>.</summary>
+    [JsonPropertyName("payload_content_type")]
+    public required string PayloadContentType { get; init; }
+
+    /// <summary>Carries <c>received_at</c>.</summary>
+    [JsonPropertyName("received_at")]
+    public required DateTimeOffset ReceivedAt { get; init; }
+
+    /// <summary>Carries <c>cancelled_at</c>: Date the scheduled delivery of the event was cancelled</summary>
+    [JsonPropertyName("cancelled_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? CancelledAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>deliver_at</c>: Date from which the event is delivered, when it was scheduled for a later delivery
+    /// </summary>
+    [JsonPropertyName("deliver_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? DeliverAt { get; init; }
+
+    /// <summary>Carries <c>event_type_version</c>.</summary>
+    [JsonPropertyName("event_type_version")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? EventTypeVersion { get; init; }
+
+    /// <summary>Carries <c>expires_at</c>: Date after which the event is no longer delivered</summary>
+    [JsonPropertyName("expires_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? ExpiresAt { get; init; }
+
+    /// <summary>Carries <c>metadata</c>.</summary>
+    [JsonPropertyName("metadata")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? Metadata { get; init; }
+}
+
+/// <summary>The <c>EventPost</c> the API declares.</summary>
+public sealed record EventPost
+{
+    /// <summary>Carries <c>application_id</c>: UUID of the application this event belongs to.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>
+    /// Carries <c>event_type</c>: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200
+    /// characters.
+    /// </summary>
+    [JsonPropertyName("event_type")]
+    public required string EventType { get; init; }
+
+    /// <summary>Carries <c>labels</c>: Labels for event filtering and routing to subscriptions.</summary>
+    [JsonPropertyName("labels")]
+    public required IReadOnlyDictionary<string, string> Labels { get; init; }
+
+    /// <summary>Carries <c>occurred_at</c>: Timestamp when the event occurred.</summary>
+    [JsonPropertyName("occurred_at")]
+    public required DateTimeOffset OccurredAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload</c>: The event payload. For binary content, use base64 encoding. Max length: 699050
+    /// characters (512 KiB base64-encoded).
+    /// </summary>
+    [JsonPropertyName("payload")]
+    public required string Payload { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_content_type</c>: Content type of the payload. Valid values: text/plain, application/json,
+    /// application/octet-stream+base64. Length: 1-100 characters.
+    /// </summary>
+    [JsonPropertyName("payload_content_type")]
+    public required string PayloadContentType { get; init; }
+
+    /// <summary>
+    /// Carries <c>deliver_at</c>: Optional date from which the event is delivered to its subscriptions; it is delivered
+    /// right away when omitted or in the past. It can be at most as far in the future as the events retention of the
+    /// appl
+    /// </summary>
+    [JsonPropertyName("deliver_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? DeliverAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>event_id</c>: Optional unique identifier for this event (client-generated UUID). If not provided, a
+    /// UUIDv7 will be generated by the server.
+    /// </summary>
+    [JsonPropertyName("event_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? EventId { get; init; }
+
+    /// <summary>
+    /// Carries <c>event_type_version</c>: Optional version of the event type the payload follows. Subscriptions pinned
+    /// to another version of the event type do not receive the event.
+    /// </summary>
+    [JsonPropertyName("event_type_version")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? EventTypeVersion { get; init; }
+
+    /// <summary>
+    /// Carries <c>expires_at</c>: Optional date after which the event is no longer delivered: its deliveries that are
+    /// still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type,
+    /// counted f
+    /// </summary>
+    [JsonPropertyName("expires_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? ExpiresAt { get; init; }
+
+    /// <summary>Carries <c>metadata</c>: Optional metadata key-value pairs associated with the event.</summary>
+    [JsonPropertyName("metadata")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyDictionary<string, string>? Metadata { get; init; }
+}
+
+/// <summary>The <c>EventType</c> the API declares.</summary>
+public sealed record EventType
+{
+    /// <summary>Carries <c>event_type_name</c>.</summary>
+    [JsonPropertyName("event_type_name")]
+    public required string EventTypeName { get; init; }
+
+    /// <summary>Carries <c>payload_schema_mode</c>.</summary>
+    [JsonPropertyName("payload_schema_mode")]
+    public required string PayloadSchemaMode { get; init; }
+
+    /// <summary>Carries <c>resource_type_name</c>.</summary>
+    [JsonPropertyName("resource_type_name")]
+    public required string ResourceTypeName { get; init; }
+
+    /// <summary>Carries <c>service_name</c>.</summary>
+    [JsonPropertyName("service_name")]
+    public required string ServiceName { get; init; }
+
+    /// <summary>Carries <c>verb_name</c>.</summary>
+    [JsonPropertyName("verb_name")]
+    public required string VerbName { get; init; }
+
+    /// <summary>Carries <c>versions</c>.</summary>
+    [JsonPropertyName("versions")]
+    public required IReadOnlyList<EventTypeVersions> Versions { get; init; }
+
+    /// <summary>
+    /// Carries <c>default_ttl_s</c>: Time to live in seconds of the events of this type that do not give an expiry date
+    /// </summary>
+    [JsonPropertyName("default_ttl_s")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? DefaultTtlS { get; init; }
+
+    /// <summary>Carries <c>payload_schema</c>.</summary>
+    [JsonPropertyName("payload_schema")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? PayloadSchema { get; init; }
+}
+
+/// <summary>The <c>EventTypePost</c> the API declares.</summary>
+public sealed record EventTypePost
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>resource_type</c>.</summary>
+    [JsonPropertyName("resource_type")]
+    public required string ResourceType { get; init; }
+
+    /// <summary>Carries <c>service</c>.</summary>
+    [JsonPropertyName("service")]
+    public required string Service { get; init; }
+
+    /// <summary>Carries <c>verb</c>.</summary>
+    [JsonPropertyName("verb")]
+    public required string Verb { get; init; }
+
+    /// <summary>
+    /// Carries <c>default_ttl_s</c>: Time to live in seconds of the events of this type that do not give an expiry
+    /// date; their pending deliveries are cancelled once it is over
+    /// </summary>
+    [JsonPropertyName("default_ttl_s")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? DefaultTtlS { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_schema</c>: JSON Schema the payloads of the `application/json` events of this type must
+    /// match; remote references are not supported
+    /// </summary>
+    [JsonPropertyName("payload_schema")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? PayloadSchema { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_schema_mode</c>: How the payload schema is applied at ingestion (default: `enforce`)
+    /// </summary>
+    [JsonPropertyName("payload_schema_mode")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? PayloadSchemaMode { get; init; }
+}
+
+/// <summary>The <c>EventTypePut</c> the API declares.</summary>
+public sealed record EventTypePut
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>
+    /// Carries <c>default_ttl_s</c>: Time to live in seconds of the events of this type that do not give an expiry
+    /// date; their pending deliveries are cancelled once it is over
+    /// </summary>
+    [JsonPropertyName("default_ttl_s")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? DefaultTtlS { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_schema</c>: JSON Schema the payloads of the `application/json` events of this type must
+    /// match; remote references are not supported
+    /// </summary>
+    [JsonPropertyName("payload_schema")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? PayloadSchema { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_schema_mode</c>: How the payload schema is applied at ingestion (default: `enforce`)
+    /// </summary>
+    [JsonPropertyName("payload_schema_mode")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? PayloadSchemaMode { get; init; }
+}
+
+/// <summary>The <c>EventTypeVersion</c> the API declares.</summary>
+public sealed record EventTypeVersion
+{
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>Carries <c>version</c>.</summary>
+    [JsonPropertyName("version")]
+    public required int Version { get; init; }
+
+    /// <summary>Carries <c>deprecated_at</c>.</summary>
+    [JsonPropertyName("deprecated_at")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
-    public DateTimeOffset? DeletedAt { get; init; }
+    public DateTimeOffset? DeprecatedAt { get; init; }
 
-    /// <summary>Carries <c>name</c>.</summary>
-    [JsonPropertyName("name")]
+    /// <summary>
+    /// Carries <c>payload_schema</c>: JSON Schema the payloads of the events declaring this version must match, instead
+    /// of the one of the event type
+    /// </summary>
+    [JsonPropertyName("payload_schema")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
-    public string? Name { get; init; }
+    public JsonNode? PayloadSchema { get; init; }
+
+    /// <summary>Carries <c>sunset_at</c>: Date from which the events declaring this version are refused</summary>
+    [JsonPropertyName("sunset_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? SunsetAt { get; init; }
 }
 
-/// <summary>The <c>ApplicationSecretPost</c> the API declares.</summary>
-public sealed record ApplicationSecretPost
+/// <summary>The <c>EventTypeVersionDeprecate</c> the API declares.</summary>
+public sealed record EventTypeVersionDeprecate
 {
     /// <summary>Carries <c>application_id</c>.</summary>
     [JsonPropertyName("application_id")]
     public required Guid ApplicationId { get; init; }
 
-    /// <summary>Carries <c>name</c>.</summary>
-    [JsonPropertyName("name")]
+    /// <summary>Carries <c>version</c>.</summary>
+    [JsonPropertyName("version")]
+    public required int Version { get; init; }
+
+    /// <summary>
+    /// Carries <c>sunset_at</c>: Date from which the events declaring this version are refused; none keeps accepting
+    /// them
+    /// </summary>
+    [JsonPropertyName("sunset_at")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
-    public string? Name { get; init; }
+    public DateTimeOffset? SunsetAt { get; init; }
 }
 
-/// <summary>The <c>Event</c> the API declares.</summary>
-public sealed record Event
+/// <summary>The <c>EventTypeVersionPost</c> the API declares.</summary>
+public sealed record EventTypeVersionPost
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>version</c>.</summary>
+    [JsonPropertyName("version")]
+    public required int Version { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_schema</c>: JSON Schema the payloads of the `application/json` events declaring this version
+    /// must match, instead of the one of the event type; remote references are not supported
+    /// </summary>
+    [JsonPropertyName("payload_schema")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? PayloadSchema { get; init; }
+}
+
+/// <summary>The <c>EventTypeVersions</c> the API declares.</summary>
+public sealed record EventTypeVersions
+{
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>Carries <c>version</c>.</summary>
+    [JsonPropertyName("version")]
+    public required int Version { get; init; }
+
+    /// <summary>Carries <c>deprecated_at</c>.</summary>
+    [JsonPropertyName("deprecated_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? DeprecatedAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_schema</c>: JSON Schema the payloads of the events declaring this version must match, instead
+    /// of the one of the event type
+    /// </summary>
+    [JsonPropertyName("payload_schema")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? PayloadSchema { get; init; }
+
+    /// <summary>Carries <c>sunset_at</c>: Date from which the events declaring this version are refused</summary>
+    [JsonPropertyName("sunset_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? SunsetAt { get; init; }
+}
+
+/// <summary>The <c>EventWithPayload</c> the API declares.</summary>
+public sealed record EventWithPayload
 {
     /// <summary>Carries <c>event_id</c>.</summary>
     [JsonPropertyName("event_id")]
@@ -527,6 +1943,10 @@ public sealed record Event
     [JsonPropertyName("occurred_at")]
     public required DateTimeOffset OccurredAt { get; init; }
 
+    /// <summary>Carries <c>payload</c>.</summary>
+    [JsonPropertyName("payload")]
+    public required string Payload { get; init; }
+
     /// <summary>Carries <c>payload_content_type</c>.</summary>
     [JsonPropertyName("payload_content_type")]
     public required string PayloadContentType { get; init; }
@@ -535,14 +1955,54 @@ public sealed record Event
     [JsonPropertyName("received_at")]
     public required DateTimeOffset ReceivedAt { get; init; }
 
+    /// <summary>Carries <c>cancelled_at</c>: Date the scheduled delivery of the event was cancelled</summary>
+    [JsonPropertyName("cancelled_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? CancelledAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>deliver_at</c>: Date from which the event is delivered, when it was scheduled for a later delivery
+    /// </summary>
+    [JsonPropertyName("deliver_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? DeliverAt { get; init; }
+
+    /// <summary>Carries <c>event_type_version</c>.</summary>
+    [JsonPropertyName("event_type_version")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? EventTypeVersion { get; init; }
+
+    /// <summary>Carries <c>expires_at</c>: Date after which the event is no longer delivered</summary>
+    [JsonPropertyName("expires_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? ExpiresAt { get; init; }
+
     /// <summary>Carries <c>metadata</c>.</summary>
     [JsonPropertyName("metadata")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public JsonNode? Metadata { get; init; }
 }
 
-/// <summary>The <c>EventPost</c> the API declares.</summary>
-public sealed record EventPost
+/// <summary>The <c>EventsBatchPost</c> the API declares.</summary>
+public sealed record EventsBatchPost
+{
+    /// <summary>
+    /// Carries <c>application_id</c>: UUID of the application the events belong to. Events of the batch that carry
+    /// another application ID are not ingested.
+    /// </summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>
+    /// Carries <c>events</c>: Events to ingest. Length: 1-100 events; the whole request body is also subject to the
+    /// usual request size limit.
+    /// </summary>
+    [JsonPropertyName("events")]
+    public required IReadOnlyList<EventsBatchPostEvents> Events { get; init; }
+}
+
+/// <summary>The <c>EventsBatchPostEvents</c> the API declares.</summary>
+public sealed record EventsBatchPostEvents
 {
     /// <summary>Carries <c>application_id</c>: UUID of the application this event belongs to.</summary>
     [JsonPropertyName("application_id")]
@@ -577,6 +2037,15 @@ public sealed record EventPost
     [JsonPropertyName("payload_content_type")]
     public required string PayloadContentType { get; init; }
 
+    /// <summary>
+    /// Carries <c>deliver_at</c>: Optional date from which the event is delivered to its subscriptions; it is delivered
+    /// right away when omitted or in the past. It can be at most as far in the future as the events retention of the
+    /// appl
+    /// </summary>
+    [JsonPropertyName("deliver_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? DeliverAt { get; init; }
+
     /// <summary>
     /// Carries <c>event_id</c>: Optional unique identifier for this event (client-generated UUID). If not provided, a
     /// UUIDv7 will be generated by the server.
@@ -585,54 +2054,82 @@ public sealed record EventPost
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public Guid? EventId { get; init; }
 
+    /// <summary>
+    /// Carries <c>event_type_version</c>: Optional version of the event type the payload follows. Subscriptions pinned
+    /// to another version of the event type do not receive the event.
+    /// </summary>
+    [JsonPropertyName("event_type_version")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? EventTypeVersion { get; init; }
+
+    /// <summary>
+    /// Carries <c>expires_at</c>: Optional date after which the event is no longer delivered: its deliveries that are
+    /// still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type,
+    /// counted f
+    /// </summary>
+    [JsonPropertyName("expires_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? ExpiresAt { get; init; }
+
     /// <summary>Carries <c>metadata</c>: Optional metadata key-value pairs associated with the event.</summary>
     [JsonPropertyName("metadata")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public IReadOnlyDictionary<string, string>? Metadata { get; init; }
 }
 
-/// <summary>The <c>EventType</c> the API declares.</summary>
-public sealed record EventType
+/// <summary>The <c>EventsPerDayEntry</c> the API declares.</summary>
+public sealed record EventsPerDayEntry
 {
-    /// <summary>Carries <c>event_type_name</c>.</summary>
-    [JsonPropertyName("event_type_name")]
-    public required string EventTypeName { get; init; }
+    /// <summary>Carries <c>amount</c>.</summary>
+    [JsonPropertyName("amount")]
+    public required int Amount { get; init; }
 
-    /// <summary>Carries <c>resource_type_name</c>.</summary>
-    [JsonPropertyName("resource_type_name")]
-    public required string ResourceTypeName { get; init; }
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
 
-    /// <summary>Carries <c>service_name</c>.</summary>
-    [JsonPropertyName("service_name")]
-    public required string ServiceName { get; init; }
+    /// <summary>Carries <c>application_name</c>.</summary>
+    [JsonPropertyName("application_name")]
+    public required string ApplicationName { get; init; }
 
-    /// <summary>Carries <c>verb_name</c>.</summary>
-    [JsonPropertyName("verb_name")]
-    public required string VerbName { get; init; }
+    /// <summary>Carries <c>date</c>.</summary>
+    [JsonPropertyName("date")]
+    public required DateOnly Date { get; init; }
+
+    /// <summary>Carries <c>is_provisional</c>.</summary>
+    [JsonPropertyName("is_provisional")]
+    public required bool IsProvisional { get; init; }
 }
 
-/// <summary>The <c>EventTypePost</c> the API declares.</summary>
-public sealed record EventTypePost
+/// <summary>The <c>Inbox</c> the API declares.</summary>
+public sealed record Inbox
+{
+    /// <summary>Carries <c>messages</c>.</summary>
+    [JsonPropertyName("messages")]
+    public required IReadOnlyList<InboxMessages> Messages { get; init; }
+
+    /// <summary>
+    /// Carries <c>next_cursor</c>: Pass it as `cursor` to the next pull to continue after the last message of this one
+    /// </summary>
+    [JsonPropertyName("next_cursor")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? NextCursor { get; init; }
+}
+
+/// <summary>The <c>InboxAck</c> the API declares.</summary>
+public sealed record InboxAck
 {
     /// <summary>Carries <c>application_id</c>.</summary>
     [JsonPropertyName("application_id")]
     public required Guid ApplicationId { get; init; }
 
-    /// <summary>Carries <c>resource_type</c>.</summary>
-    [JsonPropertyName("resource_type")]
-    public required string ResourceType { get; init; }
-
-    /// <summary>Carries <c>service</c>.</summary>
-    [JsonPropertyName("service")]
-    public required string Service { get; init; }
-
-    /// <summary>Carries <c>verb</c>.</summary>
-    [JsonPropertyName("verb")]
-    public required string Verb { get; init; }
+    /// <summary>Carries <c>receipts</c>: Receipts of the leased events</summary>
+    [JsonPropertyName("receipts")]
+    public required IReadOnlyList<Guid> Receipts { get; init; }
 }
 
-/// <summary>The <c>EventWithPayload</c> the API declares.</summary>
-public sealed record EventWithPayload
+/// <summary>The <c>InboxMessages</c> the API declares.</summary>
+public sealed record InboxMessages
 {
     /// <summary>Carries <c>event_id</c>.</summary>
     [JsonPropertyName("event_id")]
@@ -642,19 +2139,21 @@ public sealed record EventWithPayload
     [JsonPropertyName("event_type_name")]
     public required string EventTypeName { get; init; }
 
-    /// <summary>Carries <c>ip</c>.</summary>
-    [JsonPropertyName("ip")]
-    public required string Ip { get; init; }
-
     /// <summary>Carries <c>labels</c>.</summary>
     [JsonPropertyName("labels")]
     public required JsonNode Labels { get; init; }
 
+    /// <summary>
+    /// Carries <c>leased_until</c>: When the event is offered again if it was neither acknowledged nor rejected
+    /// </summary>
+    [JsonPropertyName("leased_until")]
+    public required DateTimeOffset LeasedUntil { get; init; }
+
     /// <summary>Carries <c>occurred_at</c>.</summary>
     [JsonPropertyName("occurred_at")]
     public required DateTimeOffset OccurredAt { get; init; }
 
-    /// <summary>Carries <c>payload</c>.</summary>
+    /// <summary>Carries <c>payload</c>: Base64-encoded</summary>
     [JsonPropertyName("payload")]
     public required string Payload { get; init; }
 
@@ -662,54 +2161,205 @@ public sealed record EventWithPayload
     [JsonPropertyName("payload_content_type")]
     public required string PayloadContentType { get; init; }
 
+    /// <summary>
+    /// Carries <c>receipt</c>: Identifies this lease of the request attempt; a later lease of the same attempt gets
+    /// another one
+    /// </summary>
+    [JsonPropertyName("receipt")]
+    public required Guid Receipt { get; init; }
+
     /// <summary>Carries <c>received_at</c>.</summary>
     [JsonPropertyName("received_at")]
     public required DateTimeOffset ReceivedAt { get; init; }
 
-    /// <summary>Carries <c>metadata</c>.</summary>
-    [JsonPropertyName("metadata")]
+    /// <summary>Carries <c>request_attempt_id</c>.</summary>
+    [JsonPropertyName("request_attempt_id")]
+    public required Guid RequestAttemptId { get; init; }
+
+    /// <summary>Carries <c>retry_count</c>.</summary>
+    [JsonPropertyName("retry_count")]
+    public required int RetryCount { get; init; }
+}
+
+/// <summary>The <c>InboxNack</c> the API declares.</summary>
+public sealed record InboxNack
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>receipts</c>: Receipts of the leased events</summary>
+    [JsonPropertyName("receipts")]
+    public required IReadOnlyList<Guid> Receipts { get; init; }
+
+    /// <summary>
+    /// Carries <c>retry</c>: Whether the events are offered again, as new request attempts (true by default); they are
+    /// not beyond the maximum number of retries of the subscription, or of this instance if it has none
+    /// </summary>
+    [JsonPropertyName("retry")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
-    public JsonNode? Metadata { get; init; }
+    public bool? Retry { get; init; }
+
+    /// <summary>
+    /// Carries <c>retry_in_s</c>: Delay in seconds before the events are offered again (0 by default)
+    /// </summary>
+    [JsonPropertyName("retry_in_s")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? RetryInS { get; init; }
 }
 
-/// <summary>The <c>EventsPerDayEntry</c> the API declares.</summary>
-public sealed record EventsPerDayEntry
+/// <summary>The <c>InboxSettlement</c> the API declares.</summary>
+public sealed record InboxSettlement
 {
-    /// <summary>Carries <c>amount</c>.</summary>
-    [JsonPropertyName("amount")]
-    public required int Amount { get; init; }
+    /// <summary>Carries <c>settled</c>: Receipts of the events that were settled</summary>
+    [JsonPropertyName("settled")]
+    public required IReadOnlyList<Guid> Settled { get; init; }
+
+    /// <summary>
+    /// Carries <c>stale</c>: Receipts that settled nothing: their lease expired, or the event was already settled
+    /// </summary>
+    [JsonPropertyName("stale")]
+    public required IReadOnlyList<Guid> Stale { get; init; }
+}
 
+/// <summary>The <c>IngestedEvent</c> the API declares.</summary>
+public sealed record IngestedEvent
+{
     /// <summary>Carries <c>application_id</c>.</summary>
     [JsonPropertyName("application_id")]
     public required Guid ApplicationId { get; init; }
 
-    /// <summary>Carries <c>application_name</c>.</summary>
-    [JsonPropertyName("application_name")]
-    public required string ApplicationName { get; init; }
+    /// <summary>Carries <c>event_id</c>.</summary>
+    [JsonPropertyName("event_id")]
+    public required Guid EventId { get; init; }
 
-    /// <summary>Carries <c>date</c>.</summary>
-    [JsonPropertyName("date")]
-    public required DateOnly Date { get; init; }
+    /// <summary>Carries <c>received_at</c>.</summary>
+    [JsonPropertyName("received_at")]
+    public required DateTimeOffset ReceivedAt { get; init; }
 
-    /// <summary>Carries <c>is_provisional</c>.</summary>
-    [JsonPropertyName("is_provisional")]
-    public required bool IsProvisional { get; init; }
+    /// <summary>
+    /// Carries <c>deprecation</c>: Present when the event was ingested with a deprecated version of its event type
+    /// </summary>
+    [JsonPropertyName("deprecation")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IngestedEventDeprecation? Deprecation { get; init; }
+}
+
+/// <summary>The <c>IngestedEventDeprecation</c> the API declares.</summary>
+public sealed record IngestedEventDeprecation
+{
+    /// <summary>Carries <c>deprecated_at</c>.</summary>
+    [JsonPropertyName("deprecated_at")]
+    public required DateTimeOffset DeprecatedAt { get; init; }
+
+    /// <summary>Carries <c>event_type_version</c>.</summary>
+    [JsonPropertyName("event_type_version")]
+    public required int EventTypeVersion { get; init; }
+
+    /// <summary>Carries <c>sunset_at</c>.</summary>
+    [JsonPropertyName("sunset_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? SunsetAt { get; init; }
+}
+
+/// <summary>The <c>IngestedEventsBatch</c> the API declares.</summary>
+public sealed record IngestedEventsBatch
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>
+    /// Carries <c>events</c>: One outcome per submitted event, in the order the events were submitted
+    /// </summary>
+    [JsonPropertyName("events")]
+    public required IReadOnlyList<IngestedEventsBatchEvents> Events { get; init; }
+}
+
+/// <summary>The <c>IngestedEventsBatchEvents</c> the API declares.</summary>
+public sealed record IngestedEventsBatchEvents
+{
+    /// <summary>Carries <c>status</c>.</summary>
+    [JsonPropertyName("status")]
+    public required string Status { get; init; }
+
+    /// <summary>
+    /// Carries <c>deprecation</c>: Present when the event was ingested with a deprecated version of its event type
+    /// </summary>
+    [JsonPropertyName("deprecation")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IngestedEventsBatchEventsDeprecation? Deprecation { get; init; }
+
+    /// <summary>
+    /// Carries <c>event_id</c>: ID of the ingested event, or the one the event was submitted with when it was not
+    /// ingested
+    /// </summary>
+    [JsonPropertyName("event_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? EventId { get; init; }
+
+    /// <summary>Carries <c>problem</c>: Present when the event was not ingested</summary>
+    [JsonPropertyName("problem")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IngestedEventsBatchEventsProblem? Problem { get; init; }
+
+    /// <summary>Carries <c>received_at</c>: Present when the event was ingested</summary>
+    [JsonPropertyName("received_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? ReceivedAt { get; init; }
+}
+
+/// <summary>The <c>IngestedEventsBatchEventsDeprecation</c> the API declares.</summary>
+public sealed record IngestedEventsBatchEventsDeprecation
+{
+    /// <summary>Carries <c>deprecated_at</c>.</summary>
+    [JsonPropertyName("deprecated_at")]
+    public required DateTimeOffset DeprecatedAt { get; init; }
+
+    /// <summary>Carries <c>event_type_version</c>.</summary>
+    [JsonPropertyName("event_type_version")]
+    public required int EventTypeVersion { get; init; }
+
+    /// <summary>Carries <c>sunset_at</c>.</summary>
+    [JsonPropertyName("sunset_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? SunsetAt { get; init; }
 }
 
-/// <summary>The <c>IngestedEvent</c> the API declares.</summary>
-public sealed record IngestedEvent
-{
-    /// <summary>Carries <c>application_id</c>.</summary>
-    [JsonPropertyName("application_id")]
-    public required Guid ApplicationId { get; init; }
+/// <summary>The <c>IngestedEventsBatchEventsProblem</c> the API declares.</summary>
+public sealed record IngestedEventsBatchEventsProblem
+{
+    /// <summary>Carries <c>detail</c>.</summary>
+    [JsonPropertyName("detail")]
+    public required string Detail { get; init; }
+
+    /// <summary>Carries <c>id</c>: Identifier of the problem that occurred, stable across releases</summary>
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+
+    /// <summary>Carries <c>status</c>.</summary>
+    [JsonPropertyName("status")]
+    public required int Status { get; init; }
 
-    /// <summary>Carries <c>event_id</c>.</summary>
-    [JsonPropertyName("event_id")]
-    public required Guid EventId { get; init; }
+    /// <summary>Carries <c>title</c>.</summary>
+    [JsonPropertyName("title")]
+    public required string Title { get; init; }
 
-    /// <summary>Carries <c>received_at</c>.</summary>
-    [JsonPropertyName("received_at")]
-    public required DateTimeOffset ReceivedAt { get; init; }
+    /// <summary>
+    /// Carries <c>type</c>: Documentation page of this problem, which is also what distinguishes one problem type from
+    /// another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a URL.
+    /// </summary>
+    [JsonPropertyName("type")]
+    public required string Type { get; init; }
+
+    /// <summary>
+    /// Carries <c>validation</c>: Which submitted values were rejected and why, keyed by the name of the field they
+    /// were submitted under. Carried by validation failures (`Validation`, status 422) and `null` for every other
+    /// problem, as
+    /// </summary>
+    [JsonPropertyName("validation")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? Validation { get; init; }
 }
 
 /// <summary>The <c>InstanceConfig</c> the API declares.</summary>
@@ -783,6 +2433,91 @@ public sealed record InstanceConfigMatomo
     public required string Url { get; init; }
 }
 
+/// <summary>The <c>JsonWebKeySet</c> the API declares.</summary>
+public sealed record JsonWebKeySet
+{
+    /// <summary>Carries <c>keys</c>.</summary>
+    [JsonPropertyName("keys")]
+    public required IReadOnlyList<JsonWebKeySetKeys> Keys { get; init; }
+}
+
+/// <summary>The <c>JsonWebKeySetKeys</c> the API declares.</summary>
+public sealed record JsonWebKeySetKeys
+{
+    /// <summary>Carries <c>alg</c>: Always `EdDSA`</summary>
+    [JsonPropertyName("alg")]
+    public required string Alg { get; init; }
+
+    /// <summary>Carries <c>crv</c>: Always `Ed25519`</summary>
+    [JsonPropertyName("crv")]
+    public required string Crv { get; init; }
+
+    /// <summary>Carries <c>kid</c>: ID of the signing key, which signatures name in their `k` part</summary>
+    [JsonPropertyName("kid")]
+    public required string Kid { get; init; }
+
+    /// <summary>Carries <c>kty</c>: Always `OKP`</summary>
+    [JsonPropertyName("kty")]
+    public required string Kty { get; init; }
+
+    /// <summary>Carries <c>use</c>: Always `sig`</summary>
+    [JsonPropertyName("use")]
+    public required string Use { get; init; }
+
+    /// <summary>Carries <c>x</c>: Public key, encoded in base64url without padding</summary>
+    [JsonPropertyName("x")]
+    public required string X { get; init; }
+}
+
+/// <summary>The <c>OAuth2ClientCredentials</c> the API declares.</summary>
+public sealed record Oauth2ClientCredentials
+{
+    /// <summary>Carries <c>client_id</c>.</summary>
+    [JsonPropertyName("client_id")]
+    public required string ClientId { get; init; }
+
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>Carries <c>scopes</c>.</summary>
+    [JsonPropertyName("scopes")]
+    public required IReadOnlyList<string> Scopes { get; init; }
+
+    /// <summary>Carries <c>subscription_id</c>.</summary>
+    [JsonPropertyName("subscription_id")]
+    public required Guid SubscriptionId { get; init; }
+
+    /// <summary>Carries <c>token_url</c>.</summary>
+    [JsonPropertyName("token_url")]
+    public required string TokenUrl { get; init; }
+
+    /// <summary>Carries <c>updated_at</c>.</summary>
+    [JsonPropertyName("updated_at")]
+    public required DateTimeOffset UpdatedAt { get; init; }
+}
+
+/// <summary>The <c>OAuth2ClientCredentialsPut</c> the API declares.</summary>
+public sealed record Oauth2ClientCredentialsPut
+{
+    /// <summary>Carries <c>client_id</c>.</summary>
+    [JsonPropertyName("client_id")]
+    public required string ClientId { get; init; }
+
+    /// <summary>Carries <c>client_secret</c>.</summary>
+    [JsonPropertyName("client_secret")]
+    public required string ClientSecret { get; init; }
+
+    /// <summary>Carries <c>token_url</c>: Token endpoint of the authorization server</summary>
+    [JsonPropertyName("token_url")]
+    public required string TokenUrl { get; init; }
+
+    /// <summary>Carries <c>scopes</c>: Scopes to request; none by default</summary>
+    [JsonPropertyName("scopes")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<string>? Scopes { get; init; }
+}
+
 /// <summary>The <c>Organization</c> the API declares.</summary>
 public sealed record Organization
 {
@@ -980,6 +2715,27 @@ public sealed record Problem
     public JsonNode? Validation { get; init; }
 }
 
+/// <summary>The <c>PurgeDeadLetters</c> the API declares.</summary>
+public sealed record PurgeDeadLetters
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>subscription_id</c>: Only purge the dead letters of this subscription</summary>
+    [JsonPropertyName("subscription_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? SubscriptionId { get; init; }
+}
+
+/// <summary>The <c>PurgedDeadLetters</c> the API declares.</summary>
+public sealed record PurgedDeadLetters
+{
+    /// <summary>Carries <c>purged_count</c>.</summary>
+    [JsonPropertyName("purged_count")]
+    public required long PurgedCount { get; init; }
+}
+
 /// <summary>The <c>QuotasResponse</c> the API declares.</summary>
 public sealed record QuotasResponse
 {
@@ -1020,6 +2776,39 @@ public sealed record QuotasResponseLimits
     public required int GlobalSubscriptionsPerApplicationLimit { get; init; }
 }
 
+/// <summary>The <c>RedeliverDeadLetter</c> the API declares.</summary>
+public sealed record RedeliverDeadLetter
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+}
+
+/// <summary>The <c>RedeliverDeadLetters</c> the API declares.</summary>
+public sealed record RedeliverDeadLetters
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>subscription_id</c>: Only redeliver the dead letters of this subscription</summary>
+    [JsonPropertyName("subscription_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? SubscriptionId { get; init; }
+}
+
+/// <summary>The <c>RedeliveredDeadLetter</c> the API declares.</summary>
+public sealed record RedeliveredDeadLetter
+{
+    /// <summary>Carries <c>dead_letter_id</c>.</summary>
+    [JsonPropertyName("dead_letter_id")]
+    public required Guid DeadLetterId { get; init; }
+
+    /// <summary>Carries <c>request_attempt_id</c>.</summary>
+    [JsonPropertyName("request_attempt_id")]
+    public required Guid RequestAttemptId { get; init; }
+}
+
 /// <summary>The <c>ReplayEvent</c> the API declares.</summary>
 public sealed record ReplayEvent
 {
@@ -1063,11 +2852,27 @@ public sealed record RequestAttempt
     [JsonPropertyName("subscription")]
     public required RequestAttemptSubscription Subscription { get; init; }
 
+    /// <summary>
+    /// Carries <c>dead_letter_id</c>: Dead letter the request attempt became, when it was the last attempt of a
+    /// delivery an output worker gave up on
+    /// </summary>
+    [JsonPropertyName("dead_letter_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? DeadLetterId { get; init; }
+
     /// <summary>Carries <c>delay_until</c>.</summary>
     [JsonPropertyName("delay_until")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public DateTimeOffset? DelayUntil { get; init; }
 
+    /// <summary>
+    /// Carries <c>delivery_batch_id</c>: Batch the request attempt was delivered in, shared with the other request
+    /// attempts of the batch and their response
+    /// </summary>
+    [JsonPropertyName("delivery_batch_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Guid? DeliveryBatchId { get; init; }
+
     /// <summary>Carries <c>failed_at</c>.</summary>
     [JsonPropertyName("failed_at")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
@@ -1110,12 +2915,15 @@ public sealed record RequestAttemptEvent
 public sealed record RequestAttemptStatus
 {
     /// <summary>
-    /// Carries <c>type</c>: Status type discriminator. One of: waiting, pending, in_progress, successful, failed
+    /// Carries <c>type</c>: Status type discriminator. One of: waiting, pending, in_progress, successful, failed,
+    /// expired, cancelled
     /// </summary>
     [JsonPropertyName("type")]
     public required string Type { get; init; }
 
-    /// <summary>Carries <c>at</c>: Timestamp when completed (present in successful, failed)</summary>
+    /// <summary>
+    /// Carries <c>at</c>: Timestamp when completed (present in successful, failed, expired, cancelled)
+    /// </summary>
     [JsonPropertyName("at")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public DateTimeOffset? At { get; init; }
@@ -1127,6 +2935,14 @@ public sealed record RequestAttemptStatus
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public long? FullProcessingMs { get; init; }
 
+    /// <summary>
+    /// Carries <c>rule_outcome</c>: Outcome given by a response rule of the subscription, when one matched the response
+    /// (present in successful, failed). One of: success, retryable, permanent_failure, disable_subscription
+    /// </summary>
+    [JsonPropertyName("rule_outcome")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? RuleOutcome { get; init; }
+
     /// <summary>
     /// Carries <c>since</c>: Timestamp when the status started (present in waiting, pending, in_progress)
     /// </summary>
@@ -1186,6 +3002,45 @@ public sealed record Response
     public string? ResponseErrorName { get; init; }
 }
 
+/// <summary>The <c>RetryRequestAttempt</c> the API declares.</summary>
+public sealed record RetryRequestAttempt
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+}
+
+/// <summary>The <c>SecretRotation</c> the API declares.</summary>
+public sealed record SecretRotation
+{
+    /// <summary>Carries <c>previous_secret</c>: The secret the new one replaced</summary>
+    [JsonPropertyName("previous_secret")]
+    public required Guid PreviousSecret { get; init; }
+
+    /// <summary>Carries <c>previous_secret_expires_at</c>.</summary>
+    [JsonPropertyName("previous_secret_expires_at")]
+    public required DateTimeOffset PreviousSecretExpiresAt { get; init; }
+
+    /// <summary>Carries <c>secret</c>: The new secret of the subscription</summary>
+    [JsonPropertyName("secret")]
+    public required Guid Secret { get; init; }
+
+    /// <summary>Carries <c>subscription_id</c>.</summary>
+    [JsonPropertyName("subscription_id")]
+    public required Guid SubscriptionId { get; init; }
+}
+
+/// <summary>The <c>SecretRotationPost</c> the API declares.</summary>
+public sealed record SecretRotationPost
+{
+    /// <summary>
+    /// Carries <c>overlap_seconds</c>: How long the previous secret stays valid, in seconds (one day by default)
+    /// </summary>
+    [JsonPropertyName("overlap_seconds")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? OverlapSeconds { get; init; }
+}
+
 /// <summary>The <c>ServiceToken</c> the API declares.</summary>
 public sealed record ServiceToken
 {
@@ -1218,6 +3073,47 @@ public sealed record ServiceTokenPost
     public required Guid OrganizationId { get; init; }
 }
 
+/// <summary>The <c>SigningKey</c> the API declares.</summary>
+public sealed record SigningKey
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+
+    /// <summary>Carries <c>created_at</c>.</summary>
+    [JsonPropertyName("created_at")]
+    public required DateTimeOffset CreatedAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>is_current</c>: Whether deliveries are signed with this key, which only the newest key of the
+    /// application is
+    /// </summary>
+    [JsonPropertyName("is_current")]
+    public required bool IsCurrent { get; init; }
+
+    /// <summary>
+    /// Carries <c>public_key</c>: Public key, encoded in base64url without padding like the `x` member of its JSON Web
+    /// Key
+    /// </summary>
+    [JsonPropertyName("public_key")]
+    public required string PublicKey { get; init; }
+
+    /// <summary>
+    /// Carries <c>signing_key_id</c>: Identifies the key in the `k` part of signatures and as the `kid` of its JSON Web
+    /// Key
+    /// </summary>
+    [JsonPropertyName("signing_key_id")]
+    public required Guid SigningKeyId { get; init; }
+}
+
+/// <summary>The <c>SigningKeyPost</c> the API declares.</summary>
+public sealed record SigningKeyPost
+{
+    /// <summary>Carries <c>application_id</c>.</summary>
+    [JsonPropertyName("application_id")]
+    public required Guid ApplicationId { get; init; }
+}
+
 /// <summary>The <c>Subscription</c> the API declares.</summary>
 public sealed record Subscription
 {
@@ -1233,6 +3129,10 @@ public sealed record Subscription
     [JsonPropertyName("dedicated_workers")]
     public required IReadOnlyList<string> DedicatedWorkers { get; init; }
 
+    /// <summary>Carries <c>event_type_versions</c>: Versions the subscription is pinned to, by event type</summary>
+    [JsonPropertyName("event_type_versions")]
+    public required IReadOnlyDictionary<string, int> EventTypeVersions { get; init; }
+
     /// <summary>Carries <c>event_types</c>.</summary>
     [JsonPropertyName("event_types")]
     public required IReadOnlyList<string> EventTypes { get; init; }
@@ -1261,22 +3161,218 @@ public sealed record Subscription
     [JsonPropertyName("secret")]
     public required Guid Secret { get; init; }
 
-    /// <summary>Carries <c>subscription_id</c>.</summary>
-    [JsonPropertyName("subscription_id")]
-    public required Guid SubscriptionId { get; init; }
+    /// <summary>Carries <c>signature_scheme</c>.</summary>
+    [JsonPropertyName("signature_scheme")]
+    public required string SignatureScheme { get; init; }
+
+    /// <summary>Carries <c>subscription_id</c>.</summary>
+    [JsonPropertyName("subscription_id")]
+    public required Guid SubscriptionId { get; init; }
+
+    /// <summary>Carries <c>target</c>.</summary>
+    [JsonPropertyName("target")]
+    public required SubscriptionTarget Target { get; init; }
+
+    /// <summary>Carries <c>updated_at</c>.</summary>
+    [JsonPropertyName("updated_at")]
+    public required DateTimeOffset UpdatedAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>auto_disabled_at</c>: When the circuit breaker or a response rule last disabled the subscription;
+    /// cleared when it is enabled again
+    /// </summary>
+    [JsonPropertyName("auto_disabled_at")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? AutoDisabledAt { get; init; }
+
+    /// <summary>
+    /// Carries <c>auto_disabled_reason</c>: Why the circuit breaker or a response rule last disabled the subscription
+    /// </summary>
+    [JsonPropertyName("auto_disabled_reason")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? AutoDisabledReason { get; init; }
+
+    /// <summary>
+    /// Carries <c>batching</c>: Deliver the events of the subscription in batches: one HTTP request whose body is a
+    /// JSON array of the events, sent once it holds `max_events` events or once its oldest event has waited
+    /// `max_wait_s` se
+    /// </summary>
+    [JsonPropertyName("batching")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionBatching? Batching { get; init; }
+
+    /// <summary>
+    /// Carries <c>circuit_breaker</c>: Disables the subscription once its deliveries keep failing, whichever threshold
+    /// is crossed first; at least one threshold must be set
+    /// </summary>
+    [JsonPropertyName("circuit_breaker")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionCircuitBreaker? CircuitBreaker { get; init; }
+
+    /// <summary>
+    /// Carries <c>delivery_limits</c>: Limits on how fast deliveries of the subscription start; deliveries over them
+    /// wait for their turn, which does not count as a failure; at least one limit must be set
+    /// </summary>
+    [JsonPropertyName("delivery_limits")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionDeliveryLimits? DeliveryLimits { get; init; }
+
+    /// <summary>Carries <c>description</c>.</summary>
+    [JsonPropertyName("description")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Description { get; init; }
+
+    /// <summary>
+    /// Carries <c>filter</c>: An object with exactly one of: `and` or `or` (a list of filters), `not` (a filter),
+    /// `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload); `label` and `field` go
+    /// alon
+    /// </summary>
+    [JsonPropertyName("filter")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? Filter { get; init; }
+
+    /// <summary>
+    /// Carries <c>ordering_key</c>: Where the key of an event comes from, to deliver the events that share a key one at
+    /// a time and in the order they were received; exactly one of `label` or `field` must be set
+    /// </summary>
+    [JsonPropertyName("ordering_key")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionOrderingKey? OrderingKey { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_transform</c>: Declarative reshaping of an `application/json` event payload, applied by the
+    /// worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`,
+    /// `constants`) and a pay
+    /// </summary>
+    [JsonPropertyName("payload_transform")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionPayloadTransform? PayloadTransform { get; init; }
+
+    /// <summary>Carries <c>response_rules</c>.</summary>
+    [JsonPropertyName("response_rules")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<SubscriptionResponseRules>? ResponseRules { get; init; }
+
+    /// <summary>
+    /// Carries <c>retry_policy</c>: Retry schedule of the subscription, replacing the one of the instance within its
+    /// limits; every field left out keeps the value of the instance
+    /// </summary>
+    [JsonPropertyName("retry_policy")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionRetryPolicy? RetryPolicy { get; init; }
+}
+
+/// <summary>The <c>SubscriptionBatching</c> the API declares.</summary>
+public sealed record SubscriptionBatching
+{
+    /// <summary>Carries <c>max_events</c>: Largest number of events in a batch</summary>
+    [JsonPropertyName("max_events")]
+    public required int MaxEvents { get; init; }
+
+    /// <summary>Carries <c>max_wait_s</c>: Longest time in seconds an event waits for its batch to fill up</summary>
+    [JsonPropertyName("max_wait_s")]
+    public required int MaxWaitS { get; init; }
+}
+
+/// <summary>The <c>SubscriptionCircuitBreaker</c> the API declares.</summary>
+public sealed record SubscriptionCircuitBreaker
+{
+    /// <summary>
+    /// Carries <c>max_consecutive_failures</c>: Number of failed deliveries in a row after which the subscription is
+    /// disabled
+    /// </summary>
+    [JsonPropertyName("max_consecutive_failures")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxConsecutiveFailures { get; init; }
+
+    /// <summary>
+    /// Carries <c>max_failing_hours</c>: Number of hours without a single successful delivery after which the
+    /// subscription is disabled
+    /// </summary>
+    [JsonPropertyName("max_failing_hours")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxFailingHours { get; init; }
+}
+
+/// <summary>The <c>SubscriptionDeliveryLimits</c> the API declares.</summary>
+public sealed record SubscriptionDeliveryLimits
+{
+    /// <summary>Carries <c>max_in_flight</c>: Number of deliveries that can be in flight at once</summary>
+    [JsonPropertyName("max_in_flight")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxInFlight { get; init; }
+
+    /// <summary>Carries <c>max_per_second</c>: Number of deliveries that can start in a second</summary>
+    [JsonPropertyName("max_per_second")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxPerSecond { get; init; }
+
+    /// <summary>
+    /// Carries <c>per_host</c>: Share the limits with every subscription of the application that delivers to the same
+    /// host and sets this too, rather than counting the deliveries of this subscription alone
+    /// </summary>
+    [JsonPropertyName("per_host")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public bool? PerHost { get; init; }
+}
+
+/// <summary>The <c>SubscriptionOrderingKey</c> the API declares.</summary>
+public sealed record SubscriptionOrderingKey
+{
+    /// <summary>
+    /// Carries <c>field</c>: JSON pointer to the value holding the key in a JSON payload, for example `/customer/id`
+    /// </summary>
+    [JsonPropertyName("field")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Field { get; init; }
+
+    /// <summary>Carries <c>label</c>: Name of the event label holding the key</summary>
+    [JsonPropertyName("label")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Label { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPayloadTransform</c> the API declares.</summary>
+public sealed record SubscriptionPayloadTransform
+{
+    /// <summary>
+    /// Carries <c>constants</c>: Properties added at the top level of the result, overriding properties of the same
+    /// name (the result must then be an object)
+    /// </summary>
+    [JsonPropertyName("constants")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyDictionary<string, JsonNode>? Constants { get; init; }
+
+    /// <summary>Carries <c>envelope</c>: Name of a property the result is wrapped in</summary>
+    [JsonPropertyName("envelope")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Envelope { get; init; }
 
-    /// <summary>Carries <c>target</c>.</summary>
-    [JsonPropertyName("target")]
-    public required SubscriptionTarget Target { get; init; }
+    /// <summary>
+    /// Carries <c>fields</c>: Values to copy from the payload into a new object; when omitted, the whole payload is
+    /// kept
+    /// </summary>
+    [JsonPropertyName("fields")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<SubscriptionPayloadTransformFields>? Fields { get; init; }
+}
 
-    /// <summary>Carries <c>updated_at</c>.</summary>
-    [JsonPropertyName("updated_at")]
-    public required DateTimeOffset UpdatedAt { get; init; }
+/// <summary>The <c>SubscriptionPayloadTransformFields</c> the API declares.</summary>
+public sealed record SubscriptionPayloadTransformFields
+{
+    /// <summary>
+    /// Carries <c>from</c>: JSON pointer to a value of the payload, for example `/user/id` (values that are absent from
+    /// a payload are skipped)
+    /// </summary>
+    [JsonPropertyName("from")]
+    public required string From { get; init; }
 
-    /// <summary>Carries <c>description</c>.</summary>
-    [JsonPropertyName("description")]
+    /// <summary>
+    /// Carries <c>to</c>: JSON pointer where the value is written in the result, for example `/id`; defaults to `from`
+    /// </summary>
+    [JsonPropertyName("to")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
-    public string? Description { get; init; }
+    public string? To { get; init; }
 }
 
 /// <summary>The <c>SubscriptionPost</c> the API declares.</summary>
@@ -1298,16 +3394,55 @@ public sealed record SubscriptionPost
     [JsonPropertyName("target")]
     public required SubscriptionPostTarget Target { get; init; }
 
+    /// <summary>
+    /// Carries <c>batching</c>: Deliver the events in batches, as one HTTP request with a JSON array body, rather than
+    /// one by one
+    /// </summary>
+    [JsonPropertyName("batching")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionPostBatching? Batching { get; init; }
+
+    /// <summary>
+    /// Carries <c>circuit_breaker</c>: Disable the subscription once its deliveries keep failing; organization editors
+    /// are notified by email
+    /// </summary>
+    [JsonPropertyName("circuit_breaker")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionPostCircuitBreaker? CircuitBreaker { get; init; }
+
     /// <summary>Carries <c>dedicated_workers</c>.</summary>
     [JsonPropertyName("dedicated_workers")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public IReadOnlyList<string>? DedicatedWorkers { get; init; }
 
+    /// <summary>
+    /// Carries <c>delivery_limits</c>: Limit how fast deliveries start, for this subscription or for its host
+    /// </summary>
+    [JsonPropertyName("delivery_limits")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionPostDeliveryLimits? DeliveryLimits { get; init; }
+
     /// <summary>Carries <c>description</c>.</summary>
     [JsonPropertyName("description")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public string? Description { get; init; }
 
+    /// <summary>
+    /// Carries <c>event_type_versions</c>: Only receive the events of these event types that declare this version (or
+    /// no version at all)
+    /// </summary>
+    [JsonPropertyName("event_type_versions")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyDictionary<string, int>? EventTypeVersions { get; init; }
+
+    /// <summary>
+    /// Carries <c>filter</c>: Only deliver the events of the subscribed types that match this expression over labels
+    /// and JSON payload fields
+    /// </summary>
+    [JsonPropertyName("filter")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? Filter { get; init; }
+
     /// <summary>Carries <c>label_key</c>: _Kept for backward compatibility, you should use `labels`_</summary>
     [JsonPropertyName("label_key")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
@@ -1327,44 +3462,439 @@ public sealed record SubscriptionPost
     [JsonPropertyName("metadata")]
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public IReadOnlyDictionary<string, string>? Metadata { get; init; }
+
+    /// <summary>
+    /// Carries <c>ordering_key</c>: Deliver the events that share a key one at a time, in the order they were received:
+    /// the next one waits until the previous one succeeded or ran out of retries
+    /// </summary>
+    [JsonPropertyName("ordering_key")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionPostOrderingKey? OrderingKey { get; init; }
+
+    /// <summary>
+    /// Carries <c>payload_transform</c>: Declarative reshaping of an `application/json` event payload, applied by the
+    /// worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`,
+    /// `constants`) and a pay
+    /// </summary>
+    [JsonPropertyName("payload_transform")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionPostPayloadTransform? PayloadTransform { get; init; }
+
+    /// <summary>
+    /// Carries <c>response_rules</c>: Decide the outcome of HTTP deliveries from their response, for example to stop
+    /// retrying on a `410` or to fail on a `200` whose body reports an error; the first rule that matches wins, and a
+    /// response n
+    /// </summary>
+    [JsonPropertyName("response_rules")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<SubscriptionPostResponseRules>? ResponseRules { get; init; }
+
+    /// <summary>
+    /// Carries <c>retry_policy</c>: Retry failed deliveries on this schedule rather than the instance's one
+    /// </summary>
+    [JsonPropertyName("retry_policy")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionPostRetryPolicy? RetryPolicy { get; init; }
+
+    /// <summary>
+    /// Carries <c>signature_scheme</c>: How deliveries are signed (`hook0` by default); `standard_webhooks` follows the
+    /// Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of the
+    /// subscription s
+    /// </summary>
+    [JsonPropertyName("signature_scheme")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? SignatureScheme { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostBatching</c> the API declares.</summary>
+public sealed record SubscriptionPostBatching
+{
+    /// <summary>Carries <c>max_events</c>: Largest number of events in a batch</summary>
+    [JsonPropertyName("max_events")]
+    public required int MaxEvents { get; init; }
+
+    /// <summary>Carries <c>max_wait_s</c>: Longest time in seconds an event waits for its batch to fill up</summary>
+    [JsonPropertyName("max_wait_s")]
+    public required int MaxWaitS { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostCircuitBreaker</c> the API declares.</summary>
+public sealed record SubscriptionPostCircuitBreaker
+{
+    /// <summary>
+    /// Carries <c>max_consecutive_failures</c>: Number of failed deliveries in a row after which the subscription is
+    /// disabled
+    /// </summary>
+    [JsonPropertyName("max_consecutive_failures")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxConsecutiveFailures { get; init; }
+
+    /// <summary>
+    /// Carries <c>max_failing_hours</c>: Number of hours without a single successful delivery after which the
+    /// subscription is disabled
+    /// </summary>
+    [JsonPropertyName("max_failing_hours")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxFailingHours { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostDeliveryLimits</c> the API declares.</summary>
+public sealed record SubscriptionPostDeliveryLimits
+{
+    /// <summary>Carries <c>max_in_flight</c>: Number of deliveries that can be in flight at once</summary>
+    [JsonPropertyName("max_in_flight")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxInFlight { get; init; }
+
+    /// <summary>Carries <c>max_per_second</c>: Number of deliveries that can start in a second</summary>
+    [JsonPropertyName("max_per_second")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxPerSecond { get; init; }
+
+    /// <summary>
+    /// Carries <c>per_host</c>: Share the limits with every subscription of the application that delivers to the same
+    /// host and sets this too, rather than counting the deliveries of this subscription alone
+    /// </summary>
+    [JsonPropertyName("per_host")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public bool? PerHost { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostOrderingKey</c> the API declares.</summary>
+public sealed record SubscriptionPostOrderingKey
+{
+    /// <summary>
+    /// Carries <c>field</c>: JSON pointer to the value holding the key in a JSON payload, for example `/customer/id`
+    /// </summary>
+    [JsonPropertyName("field")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Field { get; init; }
+
+    /// <summary>Carries <c>label</c>: Name of the event label holding the key</summary>
+    [JsonPropertyName("label")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Label { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostPayloadTransform</c> the API declares.</summary>
+public sealed record SubscriptionPostPayloadTransform
+{
+    /// <summary>
+    /// Carries <c>constants</c>: Properties added at the top level of the result, overriding properties of the same
+    /// name (the result must then be an object)
+    /// </summary>
+    [JsonPropertyName("constants")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyDictionary<string, JsonNode>? Constants { get; init; }
+
+    /// <summary>Carries <c>envelope</c>: Name of a property the result is wrapped in</summary>
+    [JsonPropertyName("envelope")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Envelope { get; init; }
+
+    /// <summary>
+    /// Carries <c>fields</c>: Values to copy from the payload into a new object; when omitted, the whole payload is
+    /// kept
+    /// </summary>
+    [JsonPropertyName("fields")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<SubscriptionPostPayloadTransformFields>? Fields { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostPayloadTransformFields</c> the API declares.</summary>
+public sealed record SubscriptionPostPayloadTransformFields
+{
+    /// <summary>
+    /// Carries <c>from</c>: JSON pointer to a value of the payload, for example `/user/id` (values that are absent from
+    /// a payload are skipped)
+    /// </summary>
+    [JsonPropertyName("from")]
+    public required string From { get; init; }
+
+    /// <summary>
+    /// Carries <c>to</c>: JSON pointer where the value is written in the result, for example `/id`; defaults to `from`
+    /// </summary>
+    [JsonPropertyName("to")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? To { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostResponseRules</c> the API declares.</summary>
+public sealed record SubscriptionPostResponseRules
+{
+    /// <summary>
+    /// Carries <c>outcome</c>: What the delivery becomes when the rule matches: `success`, `retryable`,
+    /// `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+    /// </summary>
+    [JsonPropertyName("outcome")]
+    public required string Outcome { get; init; }
+
+    /// <summary>
+    /// Carries <c>status</c>: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`
+    /// </summary>
+    [JsonPropertyName("status")]
+    public required IReadOnlyList<string> Status { get; init; }
+
+    /// <summary>Carries <c>body_contains</c>: Text the response body must contain</summary>
+    [JsonPropertyName("body_contains")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? BodyContains { get; init; }
+
+    /// <summary>Carries <c>header</c>: Header the response must have</summary>
+    [JsonPropertyName("header")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionPostResponseRulesHeader? Header { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostResponseRulesHeader</c> the API declares.</summary>
+public sealed record SubscriptionPostResponseRulesHeader
+{
+    /// <summary>Carries <c>name</c>: Name of the header, regardless of case</summary>
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    /// <summary>Carries <c>value</c>: Value the header must have; when omitted, any value matches</summary>
+    [JsonPropertyName("value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Value { get; init; }
+}
+
+/// <summary>The <c>SubscriptionPostRetryPolicy</c> the API declares.</summary>
+public sealed record SubscriptionPostRetryPolicy
+{
+    /// <summary>
+    /// Carries <c>backoff_steps_s</c>: Delays in seconds to wait before each retry; the last one is used again for the
+    /// following retries
+    /// </summary>
+    [JsonPropertyName("backoff_steps_s")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<int>? BackoffStepsS { get; init; }
+
+    /// <summary>
+    /// Carries <c>max_retries</c>: Maximum number of retries after a failed delivery (0 disables retries)
+    /// </summary>
+    [JsonPropertyName("max_retries")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxRetries { get; init; }
+
+    /// <summary>
+    /// Carries <c>max_window_s</c>: Maximum duration in seconds the retries can span; a retry that would not fit in it
+    /// is not scheduled
+    /// </summary>
+    [JsonPropertyName("max_window_s")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxWindowS { get; init; }
 }
 
 /// <summary>The <c>SubscriptionPostTarget</c> the API declares.</summary>
 public sealed record SubscriptionPostTarget
 {
-    /// <summary>Carries <c>headers</c>.</summary>
+    /// <summary>
+    /// Carries <c>type</c>: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the
+    /// other properties depend on it
+    /// </summary>
+    [JsonPropertyName("type")]
+    public required string Type { get; init; }
+
+    /// <summary>Carries <c>access_key_id</c>: `sqs`: access key ID requests are signed with</summary>
+    [JsonPropertyName("access_key_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? AccessKeyId { get; init; }
+
+    /// <summary>
+    /// Carries <c>exchange</c>: `amqp`: exchange messages are published to; empty for the default exchange
+    /// </summary>
+    [JsonPropertyName("exchange")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Exchange { get; init; }
+
+    /// <summary>Carries <c>headers</c>: `http`: headers sent with every request</summary>
     [JsonPropertyName("headers")]
-    public required JsonNode Headers { get; init; }
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? Headers { get; init; }
 
-    /// <summary>Carries <c>method</c>.</summary>
+    /// <summary>Carries <c>method</c>: `http`: HTTP method of the requests</summary>
     [JsonPropertyName("method")]
-    public required string Method { get; init; }
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Method { get; init; }
 
-    /// <summary>Carries <c>type</c>.</summary>
-    [JsonPropertyName("type")]
-    public required string Type { get; init; }
+    /// <summary>Carries <c>password</c>: `amqp`: password to log in to the broker with; write-only</summary>
+    [JsonPropertyName("password")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Password { get; init; }
 
-    /// <summary>Carries <c>url</c>.</summary>
+    /// <summary>Carries <c>queue_url</c>: `sqs`: URL of the queue</summary>
+    [JsonPropertyName("queue_url")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? QueueUrl { get; init; }
+
+    /// <summary>Carries <c>region</c>: `sqs`: region requests are signed for</summary>
+    [JsonPropertyName("region")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Region { get; init; }
+
+    /// <summary>Carries <c>routing_key</c>: `amqp`: routing key messages are published with</summary>
+    [JsonPropertyName("routing_key")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? RoutingKey { get; init; }
+
+    /// <summary>
+    /// Carries <c>secret_access_key</c>: `sqs`: secret access key requests are signed with; write-only
+    /// </summary>
+    [JsonPropertyName("secret_access_key")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? SecretAccessKey { get; init; }
+
+    /// <summary>
+    /// Carries <c>url</c>: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without
+    /// credentials
+    /// </summary>
     [JsonPropertyName("url")]
-    public required string Url { get; init; }
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Url { get; init; }
+
+    /// <summary>Carries <c>username</c>: `amqp`: user name to log in to the broker with</summary>
+    [JsonPropertyName("username")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Username { get; init; }
+}
+
+/// <summary>The <c>SubscriptionResponseRules</c> the API declares.</summary>
+public sealed record SubscriptionResponseRules
+{
+    /// <summary>
+    /// Carries <c>outcome</c>: What the delivery becomes when the rule matches: `success`, `retryable`,
+    /// `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+    /// </summary>
+    [JsonPropertyName("outcome")]
+    public required string Outcome { get; init; }
+
+    /// <summary>
+    /// Carries <c>status</c>: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`
+    /// </summary>
+    [JsonPropertyName("status")]
+    public required IReadOnlyList<string> Status { get; init; }
+
+    /// <summary>Carries <c>body_contains</c>: Text the response body must contain</summary>
+    [JsonPropertyName("body_contains")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? BodyContains { get; init; }
+
+    /// <summary>Carries <c>header</c>: Header the response must have</summary>
+    [JsonPropertyName("header")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public SubscriptionResponseRulesHeader? Header { get; init; }
+}
+
+/// <summary>The <c>SubscriptionResponseRulesHeader</c> the API declares.</summary>
+public sealed record SubscriptionResponseRulesHeader
+{
+    /// <summary>Carries <c>name</c>: Name of the header, regardless of case</summary>
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    /// <summary>Carries <c>value</c>: Value the header must have; when omitted, any value matches</summary>
+    [JsonPropertyName("value")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Value { get; init; }
+}
+
+/// <summary>The <c>SubscriptionRetryPolicy</c> the API declares.</summary>
+public sealed record SubscriptionRetryPolicy
+{
+    /// <summary>
+    /// Carries <c>backoff_steps_s</c>: Delays in seconds to wait before each retry; the last one is used again for the
+    /// following retries
+    /// </summary>
+    [JsonPropertyName("backoff_steps_s")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<int>? BackoffStepsS { get; init; }
+
+    /// <summary>
+    /// Carries <c>max_retries</c>: Maximum number of retries after a failed delivery (0 disables retries)
+    /// </summary>
+    [JsonPropertyName("max_retries")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxRetries { get; init; }
+
+    /// <summary>
+    /// Carries <c>max_window_s</c>: Maximum duration in seconds the retries can span; a retry that would not fit in it
+    /// is not scheduled
+    /// </summary>
+    [JsonPropertyName("max_window_s")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? MaxWindowS { get; init; }
 }
 
 /// <summary>The <c>SubscriptionTarget</c> the API declares.</summary>
 public sealed record SubscriptionTarget
 {
-    /// <summary>Carries <c>headers</c>.</summary>
+    /// <summary>
+    /// Carries <c>type</c>: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the
+    /// other properties depend on it
+    /// </summary>
+    [JsonPropertyName("type")]
+    public required string Type { get; init; }
+
+    /// <summary>Carries <c>access_key_id</c>: `sqs`: access key ID requests are signed with</summary>
+    [JsonPropertyName("access_key_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? AccessKeyId { get; init; }
+
+    /// <summary>
+    /// Carries <c>exchange</c>: `amqp`: exchange messages are published to; empty for the default exchange
+    /// </summary>
+    [JsonPropertyName("exchange")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Exchange { get; init; }
+
+    /// <summary>Carries <c>headers</c>: `http`: headers sent with every request</summary>
     [JsonPropertyName("headers")]
-    public required JsonNode Headers { get; init; }
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonNode? Headers { get; init; }
 
-    /// <summary>Carries <c>method</c>.</summary>
+    /// <summary>Carries <c>method</c>: `http`: HTTP method of the requests</summary>
     [JsonPropertyName("method")]
-    public required string Method { get; init; }
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Method { get; init; }
 
-    /// <summary>Carries <c>type</c>.</summary>
-    [JsonPropertyName("type")]
-    public required string Type { get; init; }
+    /// <summary>Carries <c>password</c>: `amqp`: password to log in to the broker with; write-only</summary>
+    [JsonPropertyName("password")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Password { get; init; }
 
-    /// <summary>Carries <c>url</c>.</summary>
+    /// <summary>Carries <c>queue_url</c>: `sqs`: URL of the queue</summary>
+    [JsonPropertyName("queue_url")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? QueueUrl { get; init; }
+
+    /// <summary>Carries <c>region</c>: `sqs`: region requests are signed for</summary>
+    [JsonPropertyName("region")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Region { get; init; }
+
+    /// <summary>Carries <c>routing_key</c>: `amqp`: routing key messages are published with</summary>
+    [JsonPropertyName("routing_key")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? RoutingKey { get; init; }
+
+    /// <summary>
+    /// Carries <c>secret_access_key</c>: `sqs`: secret access key requests are signed with; write-only
+    /// </summary>
+    [JsonPropertyName("secret_access_key")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? SecretAccessKey { get; init; }
+
+    /// <summary>
+    /// Carries <c>url</c>: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without
+    /// credentials
+    /// </summary>
     [JsonPropertyName("url")]
-    public required string Url { get; init; }
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Url { get; init; }
+
+    /// <summary>Carries <c>username</c>: `amqp`: user name to log in to the broker with</summary>
+    [JsonPropertyName("username")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Username { get; init; }
 }
@@ -262,6 +262,408 @@ func (group *ApplicationsAPI) Update(
 	return &out, nil
 }
 
+// BulkRetriesAPI is what the API declares under `bulkRetries`.
+//
+// Every method of it is issued through the transport it is handed.
+type BulkRetriesAPI struct {
+	transport Transport
+}
+
+// NewBulkRetriesAPI reaches what the API declares under `bulkRetries`.
+func NewBulkRetriesAPI(transport Transport) *BulkRetriesAPI {
+	return &BulkRetriesAPI{transport: transport}
+}
+
+// Cancel is what the API declares as `bulkRetries.cancel`, `POST /api/v1/bulk_retries/{bulk_retry_id}/cancel`.
+//
+// Cancel a bulk retry
+func (group *BulkRetriesAPI) Cancel(
+	ctx context.Context,
+	bulkRetryId string,
+	applicationId string,
+) (*BulkRetry, error) {
+	path := "/api/v1/bulk_retries/{bulk_retry_id}/cancel"
+	path = strings.ReplaceAll(path, "{bulk_retry_id}", pathSegment(bulkRetryId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out BulkRetry
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, nil)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Create is what the API declares as `bulkRetries.create`, `POST /api/v1/bulk_retries/`.
+//
+// Create a bulk retry
+func (group *BulkRetriesAPI) Create(
+	ctx context.Context,
+	body BulkRetryPost,
+) (*BulkRetry, error) {
+	path := "/api/v1/bulk_retries/"
+	query := url.Values{}
+
+	var out BulkRetry
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// DryRun is what the API declares as `bulkRetries.dry_run`, `POST /api/v1/bulk_retries/dry_run`.
+//
+// Count what a bulk retry would redeliver
+func (group *BulkRetriesAPI) DryRun(
+	ctx context.Context,
+	body BulkRetryPost,
+) (*BulkRetryDryRun, error) {
+	path := "/api/v1/bulk_retries/dry_run"
+	query := url.Values{}
+
+	var out BulkRetryDryRun
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Get is what the API declares as `bulkRetries.get`, `GET /api/v1/bulk_retries/{bulk_retry_id}`.
+//
+// Get a bulk retry
+func (group *BulkRetriesAPI) Get(
+	ctx context.Context,
+	bulkRetryId string,
+	applicationId string,
+) (*BulkRetry, error) {
+	path := "/api/v1/bulk_retries/{bulk_retry_id}"
+	path = strings.ReplaceAll(path, "{bulk_retry_id}", pathSegment(bulkRetryId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out BulkRetry
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// List is what the API declares as `bulkRetries.list`, `GET /api/v1/bulk_retries/`.
+//
+// List bulk retries
+func (group *BulkRetriesAPI) List(
+	ctx context.Context,
+	applicationId string,
+) ([]BulkRetry, error) {
+	path := "/api/v1/bulk_retries/"
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out []BulkRetry
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return out, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return out, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return out, unreadable(status, payload, err)
+	}
+	return out, nil
+}
+
+// ClientCertificatesAPI is what the API declares under `clientCertificates`.
+//
+// Every method of it is issued through the transport it is handed.
+type ClientCertificatesAPI struct {
+	transport Transport
+}
+
+// NewClientCertificatesAPI reaches what the API declares under `clientCertificates`.
+func NewClientCertificatesAPI(transport Transport) *ClientCertificatesAPI {
+	return &ClientCertificatesAPI{transport: transport}
+}
+
+// Delete is what the API declares as `clientCertificates.delete`, `DELETE /api/v1/subscriptions/{subscription_id}/client_certificate`.
+//
+// Remove the client certificate of a subscription
+func (group *ClientCertificatesAPI) Delete(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+) error {
+	path := "/api/v1/subscriptions/{subscription_id}/client_certificate"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+	status, payload, err := group.transport.Request(ctx, "DELETE", path, query, nil)
+	if err != nil {
+		return err
+	}
+	return problemFor(status, payload)
+}
+
+// Get is what the API declares as `clientCertificates.get`, `GET /api/v1/subscriptions/{subscription_id}/client_certificate`.
+//
+// Get the client certificate of a subscription
+func (group *ClientCertificatesAPI) Get(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+) (*ClientCertificate, error) {
+	path := "/api/v1/subscriptions/{subscription_id}/client_certificate"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out ClientCertificate
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Update is what the API declares as `clientCertificates.update`, `PUT /api/v1/subscriptions/{subscription_id}/client_certificate`.
+//
+// Set the client certificate of a subscription
+func (group *ClientCertificatesAPI) Update(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+	body ClientCertificatePut,
+) (*ClientCertificate, error) {
+	path := "/api/v1/subscriptions/{subscription_id}/client_certificate"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out ClientCertificate
+	status, payload, err := group.transport.Request(ctx, "PUT", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// DeadLettersAPI is what the API declares under `deadLetters`.
+//
+// Every method of it is issued through the transport it is handed.
+type DeadLettersAPI struct {
+	transport Transport
+}
+
+// NewDeadLettersAPI reaches what the API declares under `deadLetters`.
+func NewDeadLettersAPI(transport Transport) *DeadLettersAPI {
+	return &DeadLettersAPI{transport: transport}
+}
+
+// Delete is what the API declares as `deadLetters.delete`, `DELETE /api/v1/dead_letters/{dead_letter_id}`.
+//
+// Delete a dead letter
+func (group *DeadLettersAPI) Delete(
+	ctx context.Context,
+	deadLetterId string,
+	applicationId string,
+) error {
+	path := "/api/v1/dead_letters/{dead_letter_id}"
+	path = strings.ReplaceAll(path, "{dead_letter_id}", pathSegment(deadLetterId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+	status, payload, err := group.transport.Request(ctx, "DELETE", path, query, nil)
+	if err != nil {
+		return err
+	}
+	return problemFor(status, payload)
+}
+
+// Get is what the API declares as `deadLetters.get`, `GET /api/v1/dead_letters/{dead_letter_id}`.
+//
+// Get a dead letter by its ID
+func (group *DeadLettersAPI) Get(
+	ctx context.Context,
+	deadLetterId string,
+	applicationId string,
+) (*DeadLetterDetails, error) {
+	path := "/api/v1/dead_letters/{dead_letter_id}"
+	path = strings.ReplaceAll(path, "{dead_letter_id}", pathSegment(deadLetterId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out DeadLetterDetails
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// List is what the API declares as `deadLetters.list`, `GET /api/v1/dead_letters/`.
+//
+// List dead letters
+func (group *DeadLettersAPI) List(
+	ctx context.Context,
+	applicationId string,
+	paginationCursor *string,
+	subscriptionId *string,
+) ([]DeadLetter, error) {
+	path := "/api/v1/dead_letters/"
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+	if paginationCursor != nil {
+		query.Set("pagination_cursor", queryValue(*paginationCursor))
+	}
+	if subscriptionId != nil {
+		query.Set("subscription_id", queryValue(*subscriptionId))
+	}
+
+	var out []DeadLetter
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return out, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return out, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return out, unreadable(status, payload, err)
+	}
+	return out, nil
+}
+
+// Purge is what the API declares as `deadLetters.purge`, `POST /api/v1/dead_letters/purge`.
+//
+// Purge dead letters
+func (group *DeadLettersAPI) Purge(
+	ctx context.Context,
+	body PurgeDeadLetters,
+) (*PurgedDeadLetters, error) {
+	path := "/api/v1/dead_letters/purge"
+	query := url.Values{}
+
+	var out PurgedDeadLetters
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Redeliver is what the API declares as `deadLetters.redeliver`, `POST /api/v1/dead_letters/{dead_letter_id}/redeliver`.
+//
+// Redeliver a dead letter
+func (group *DeadLettersAPI) Redeliver(
+	ctx context.Context,
+	deadLetterId string,
+	body RedeliverDeadLetter,
+) (*RedeliveredDeadLetter, error) {
+	path := "/api/v1/dead_letters/{dead_letter_id}/redeliver"
+	path = strings.ReplaceAll(path, "{dead_letter_id}", pathSegment(deadLetterId))
+	query := url.Values{}
+
+	var out RedeliveredDeadLetter
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// RedeliverMany is what the API declares as `deadLetters.redeliverMany`, `POST /api/v1/dead_letters/redeliver`.
+//
+// Redeliver dead letters
+func (group *DeadLettersAPI) RedeliverMany(
+	ctx context.Context,
+	body RedeliverDeadLetters,
+) ([]RedeliveredDeadLetter, error) {
+	path := "/api/v1/dead_letters/redeliver"
+	query := url.Values{}
+
+	var out []RedeliveredDeadLetter
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return out, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return out, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return out, unreadable(status, payload, err)
+	}
+	return out, nil
+}
+
 // ErrorsAPI is what the API declares under `errors`.
 //
 // Every method of it is issued through the transport it is handed.
@@ -335,6 +737,33 @@ func (group *EventTypesAPI) Create(
 	return &out, nil
 }
 
+// CreateVersion is what the API declares as `eventTypes.createVersion`, `POST /api/v1/event_types/{event_type_name}/versions`.
+//
+// Create a new event type version
+func (group *EventTypesAPI) CreateVersion(
+	ctx context.Context,
+	eventTypeName string,
+	body EventTypeVersionPost,
+) (*EventTypeVersion, error) {
+	path := "/api/v1/event_types/{event_type_name}/versions"
+	path = strings.ReplaceAll(path, "{event_type_name}", pathSegment(eventTypeName))
+	query := url.Values{}
+
+	var out EventTypeVersion
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
 // Delete is what the API declares as `eventTypes.delete`, `DELETE /api/v1/event_types/{event_type_name}`.
 //
 // Delete an event type
@@ -354,6 +783,33 @@ func (group *EventTypesAPI) Delete(
 	return problemFor(status, payload)
 }
 
+// DeprecateVersion is what the API declares as `eventTypes.deprecateVersion`, `POST /api/v1/event_types/{event_type_name}/deprecate`.
+//
+// Deprecate an event type version
+func (group *EventTypesAPI) DeprecateVersion(
+	ctx context.Context,
+	eventTypeName string,
+	body EventTypeVersionDeprecate,
+) (*EventTypeVersion, error) {
+	path := "/api/v1/event_types/{event_type_name}/deprecate"
+	path = strings.ReplaceAll(path, "{event_type_name}", pathSegment(eventTypeName))
+	query := url.Values{}
+
+	var out EventTypeVersion
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
 // Get is what the API declares as `eventTypes.get`, `GET /api/v1/event_types/{event_type_name}`.
 //
 // Get an event type by its name
@@ -408,6 +864,33 @@ func (group *EventTypesAPI) List(
 	return out, nil
 }
 
+// Update is what the API declares as `eventTypes.update`, `PUT /api/v1/event_types/{event_type_name}`.
+//
+// Update an event type
+func (group *EventTypesAPI) Update(
+	ctx context.Context,
+	eventTypeName string,
+	body EventTypePut,
+) (*EventType, error) {
+	path := "/api/v1/event_types/{event_type_name}"
+	path = strings.ReplaceAll(path, "{event_type_name}", pathSegment(eventTypeName))
+	query := url.Values{}
+
+	var out EventType
+	status, payload, err := group.transport.Request(ctx, "PUT", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
 // EventsAPI is what the API declares under `events`.
 //
 // Every method of it is issued through the transport it is handed.
@@ -420,6 +903,24 @@ func NewEventsAPI(transport Transport) *EventsAPI {
 	return &EventsAPI{transport: transport}
 }
 
+// Cancel is what the API declares as `events.cancel`, `POST /api/v1/events/{event_id}/cancel`.
+//
+// Cancel a scheduled event
+func (group *EventsAPI) Cancel(
+	ctx context.Context,
+	eventId string,
+	body CancelEvent,
+) error {
+	path := "/api/v1/events/{event_id}/cancel"
+	path = strings.ReplaceAll(path, "{event_id}", pathSegment(eventId))
+	query := url.Values{}
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return err
+	}
+	return problemFor(status, payload)
+}
+
 // Get is what the API declares as `events.get`, `GET /api/v1/events/{event_id}`.
 //
 // Get an event by its ID
@@ -473,16 +974,45 @@ func (group *EventsAPI) Ingest(
 	return &out, nil
 }
 
+// IngestBatch is what the API declares as `events.ingestBatch`, `POST /api/v1/events/batch`.
+//
+// Ingest a batch of events
+func (group *EventsAPI) IngestBatch(
+	ctx context.Context,
+	body EventsBatchPost,
+) (*IngestedEventsBatch, error) {
+	path := "/api/v1/events/batch"
+	query := url.Values{}
+
+	var out IngestedEventsBatch
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
 // List is what the API declares as `events.list`, `GET /api/v1/events/`.
 //
 // List latest events
 func (group *EventsAPI) List(
 	ctx context.Context,
 	applicationId string,
+	scheduled *bool,
 ) ([]Event, error) {
 	path := "/api/v1/events/"
 	query := url.Values{}
 	query.Set("application_id", queryValue(applicationId))
+	if scheduled != nil {
+		query.Set("scheduled", queryValue(*scheduled))
+	}
 
 	var out []Event
 	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
@@ -578,23 +1108,129 @@ func (group *EventsPerDayAPI) ListForOrganization(
 	if from != nil {
 		query.Set("from", queryValue(*from))
 	}
-	if to != nil {
-		query.Set("to", queryValue(*to))
+	if to != nil {
+		query.Set("to", queryValue(*to))
+	}
+
+	var out []EventsPerDayEntry
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return out, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return out, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return out, unreadable(status, payload, err)
+	}
+	return out, nil
+}
+
+// InboxAPI is what the API declares under `inbox`.
+//
+// Every method of it is issued through the transport it is handed.
+type InboxAPI struct {
+	transport Transport
+}
+
+// NewInboxAPI reaches what the API declares under `inbox`.
+func NewInboxAPI(transport Transport) *InboxAPI {
+	return &InboxAPI{transport: transport}
+}
+
+// Ack is what the API declares as `inbox.ack`, `POST /api/v1/inbox/{subscription_id}/ack`.
+//
+// Acknowledge events pulled from an inbox
+func (group *InboxAPI) Ack(
+	ctx context.Context,
+	subscriptionId string,
+	body InboxAck,
+) (*InboxSettlement, error) {
+	path := "/api/v1/inbox/{subscription_id}/ack"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+
+	var out InboxSettlement
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Nack is what the API declares as `inbox.nack`, `POST /api/v1/inbox/{subscription_id}/nack`.
+//
+// Reject events pulled from an inbox
+func (group *InboxAPI) Nack(
+	ctx context.Context,
+	subscriptionId string,
+	body InboxNack,
+) (*InboxSettlement, error) {
+	path := "/api/v1/inbox/{subscription_id}/nack"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+
+	var out InboxSettlement
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Pull is what the API declares as `inbox.pull`, `GET /api/v1/inbox/{subscription_id}`.
+//
+// Pull events from an inbox
+func (group *InboxAPI) Pull(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+	cursor *string,
+	limit *int64,
+	visibilityTimeoutS *int64,
+) (*Inbox, error) {
+	path := "/api/v1/inbox/{subscription_id}"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+	if cursor != nil {
+		query.Set("cursor", queryValue(*cursor))
+	}
+	if limit != nil {
+		query.Set("limit", queryValue(*limit))
+	}
+	if visibilityTimeoutS != nil {
+		query.Set("visibility_timeout_s", queryValue(*visibilityTimeoutS))
 	}
 
-	var out []EventsPerDayEntry
+	var out Inbox
 	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
 	if err != nil {
-		return out, err
+		return nil, err
 	}
 	if failure := problemFor(status, payload); failure != nil {
-		return out, failure
+		return nil, failure
 	}
 
 	if err := json.Unmarshal(payload, &out); err != nil {
-		return out, unreadable(status, payload, err)
+		return nil, unreadable(status, payload, err)
 	}
-	return out, nil
+	return &out, nil
 }
 
 // InstanceAPI is what the API declares under `instance`.
@@ -633,6 +1269,94 @@ func (group *InstanceAPI) Get(
 	return &out, nil
 }
 
+// Oauth2ClientCredentialsAPI is what the API declares under `oauth2ClientCredentials`.
+//
+// Every method of it is issued through the transport it is handed.
+type Oauth2ClientCredentialsAPI struct {
+	transport Transport
+}
+
+// NewOauth2ClientCredentialsAPI reaches what the API declares under `oauth2ClientCredentials`.
+func NewOauth2ClientCredentialsAPI(transport Transport) *Oauth2ClientCredentialsAPI {
+	return &Oauth2ClientCredentialsAPI{transport: transport}
+}
+
+// Delete is what the API declares as `oauth2ClientCredentials.delete`, `DELETE /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+//
+// Remove the OAuth2 client credentials of a subscription
+func (group *Oauth2ClientCredentialsAPI) Delete(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+) error {
+	path := "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+	status, payload, err := group.transport.Request(ctx, "DELETE", path, query, nil)
+	if err != nil {
+		return err
+	}
+	return problemFor(status, payload)
+}
+
+// Get is what the API declares as `oauth2ClientCredentials.get`, `GET /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+//
+// Get the OAuth2 client credentials of a subscription
+func (group *Oauth2ClientCredentialsAPI) Get(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+) (*Oauth2ClientCredentials, error) {
+	path := "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out Oauth2ClientCredentials
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Update is what the API declares as `oauth2ClientCredentials.update`, `PUT /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+//
+// Set the OAuth2 client credentials of a subscription
+func (group *Oauth2ClientCredentialsAPI) Update(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+	body Oauth2ClientCredentialsPut,
+) (*Oauth2ClientCredentials, error) {
+	path := "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out Oauth2ClientCredentials
+	status, payload, err := group.transport.Request(ctx, "PUT", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
 // PayloadContentTypesAPI is what the API declares under `payload_content_types`.
 //
 // Every method of it is issued through the transport it is handed.
@@ -795,6 +1519,33 @@ func (group *RequestAttemptsAPI) Read(
 	return out, nil
 }
 
+// Retry is what the API declares as `requestAttempts.retry`, `POST /api/v1/request_attempts/{request_attempt_id}/retry`.
+//
+// Retry a request attempt
+func (group *RequestAttemptsAPI) Retry(
+	ctx context.Context,
+	requestAttemptId string,
+	body RetryRequestAttempt,
+) (*RequestAttempt, error) {
+	path := "/api/v1/request_attempts/{request_attempt_id}/retry"
+	path = strings.ReplaceAll(path, "{request_attempt_id}", pathSegment(requestAttemptId))
+	query := url.Values{}
+
+	var out RequestAttempt
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
 // ResponseAPI is what the API declares under `response`.
 //
 // Every method of it is issued through the transport it is handed.
@@ -835,6 +1586,113 @@ func (group *ResponseAPI) Get(
 	return &out, nil
 }
 
+// SecretRotationsAPI is what the API declares under `secretRotations`.
+//
+// Every method of it is issued through the transport it is handed.
+type SecretRotationsAPI struct {
+	transport Transport
+}
+
+// NewSecretRotationsAPI reaches what the API declares under `secretRotations`.
+func NewSecretRotationsAPI(transport Transport) *SecretRotationsAPI {
+	return &SecretRotationsAPI{transport: transport}
+}
+
+// Create is what the API declares as `secretRotations.create`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation`.
+//
+// Rotate the secret of a subscription
+func (group *SecretRotationsAPI) Create(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+	body SecretRotationPost,
+) (*SecretRotation, error) {
+	path := "/api/v1/subscriptions/{subscription_id}/secret_rotation"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out SecretRotation
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Finalize is what the API declares as `secretRotations.finalize`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation/finalize`.
+//
+// Finalize the secret rotation of a subscription
+func (group *SecretRotationsAPI) Finalize(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+) error {
+	path := "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, nil)
+	if err != nil {
+		return err
+	}
+	return problemFor(status, payload)
+}
+
+// Get is what the API declares as `secretRotations.get`, `GET /api/v1/subscriptions/{subscription_id}/secret_rotation`.
+//
+// Get the secret rotation of a subscription
+func (group *SecretRotationsAPI) Get(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+) (*SecretRotation, error) {
+	path := "/api/v1/subscriptions/{subscription_id}/secret_rotation"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out SecretRotation
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Rollback is what the API declares as `secretRotations.rollback`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation/rollback`.
+//
+// Roll back the secret rotation of a subscription
+func (group *SecretRotationsAPI) Rollback(
+	ctx context.Context,
+	subscriptionId string,
+	applicationId string,
+) error {
+	path := "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback"
+	path = strings.ReplaceAll(path, "{subscription_id}", pathSegment(subscriptionId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, nil)
+	if err != nil {
+		return err
+	}
+	return problemFor(status, payload)
+}
+
 // ServiceTokenAPI is what the API declares under `serviceToken`.
 //
 // Every method of it is issued through the transport it is handed.
@@ -972,6 +1830,114 @@ func (group *ServiceTokenAPI) List(
 	return out, nil
 }
 
+// SigningKeysAPI is what the API declares under `signingKeys`.
+//
+// Every method of it is issued through the transport it is handed.
+type SigningKeysAPI struct {
+	transport Transport
+}
+
+// NewSigningKeysAPI reaches what the API declares under `signingKeys`.
+func NewSigningKeysAPI(transport Transport) *SigningKeysAPI {
+	return &SigningKeysAPI{transport: transport}
+}
+
+// Create is what the API declares as `signingKeys.create`, `POST /api/v1/signing_keys/`.
+//
+// Create a signing key
+func (group *SigningKeysAPI) Create(
+	ctx context.Context,
+	body SigningKeyPost,
+) (*SigningKey, error) {
+	path := "/api/v1/signing_keys/"
+	query := url.Values{}
+
+	var out SigningKey
+	status, payload, err := group.transport.Request(ctx, "POST", path, query, body)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// Delete is what the API declares as `signingKeys.delete`, `DELETE /api/v1/signing_keys/{signing_key_id}`.
+//
+// Retire a signing key
+func (group *SigningKeysAPI) Delete(
+	ctx context.Context,
+	signingKeyId string,
+	applicationId string,
+) error {
+	path := "/api/v1/signing_keys/{signing_key_id}"
+	path = strings.ReplaceAll(path, "{signing_key_id}", pathSegment(signingKeyId))
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+	status, payload, err := group.transport.Request(ctx, "DELETE", path, query, nil)
+	if err != nil {
+		return err
+	}
+	return problemFor(status, payload)
+}
+
+// Jwks is what the API declares as `signingKeys.jwks`, `GET /api/v1/jwks/{application_id}`.
+//
+// Get the public keys of an application
+func (group *SigningKeysAPI) Jwks(
+	ctx context.Context,
+	applicationId string,
+) (*JsonWebKeySet, error) {
+	path := "/api/v1/jwks/{application_id}"
+	path = strings.ReplaceAll(path, "{application_id}", pathSegment(applicationId))
+	query := url.Values{}
+
+	var out JsonWebKeySet
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return nil, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return nil, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return nil, unreadable(status, payload, err)
+	}
+	return &out, nil
+}
+
+// List is what the API declares as `signingKeys.list`, `GET /api/v1/signing_keys/`.
+//
+// List signing keys
+func (group *SigningKeysAPI) List(
+	ctx context.Context,
+	applicationId string,
+) ([]SigningKey, error) {
+	path := "/api/v1/signing_keys/"
+	query := url.Values{}
+	query.Set("application_id", queryValue(applicationId))
+
+	var out []SigningKey
+	status, payload, err := group.transport.Request(ctx, "GET", path, query, nil)
+	if err != nil {
+		return out, err
+	}
+	if failure := problemFor(status, payload); failure != nil {
+		return out, failure
+	}
+
+	if err := json.Unmarshal(payload, &out); err != nil {
+		return out, unreadable(status, payload, err)
+	}
+	return out, nil
+}
+
 // SubscriptionsAPI is what the API declares under `subscriptions`.
 //
 // Every method of it is issued through the transport it is handed.
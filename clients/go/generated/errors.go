@@ -83,9 +83,21 @@ var ErrAuthInvalidBiscuit error = problemSentinel(ProblemIdAuthInvalidBiscuit)
 // ErrAuthNoAuthorizationHeader is the `AuthNoAuthorizationHeader` the API reports.
 var ErrAuthNoAuthorizationHeader error = problemSentinel(ProblemIdAuthNoAuthorizationHeader)
 
+// ErrBulkRetryAlreadyRunning is the `BulkRetryAlreadyRunning` the API reports.
+var ErrBulkRetryAlreadyRunning error = problemSentinel(ProblemIdBulkRetryAlreadyRunning)
+
+// ErrBulkRetryFinished is the `BulkRetryFinished` the API reports.
+var ErrBulkRetryFinished error = problemSentinel(ProblemIdBulkRetryFinished)
+
+// ErrClientCertificatesDisabled is the `ClientCertificatesDisabled` the API reports.
+var ErrClientCertificatesDisabled error = problemSentinel(ProblemIdClientCertificatesDisabled)
+
 // ErrEventAlreadyIngested is the `EventAlreadyIngested` the API reports.
 var ErrEventAlreadyIngested error = problemSentinel(ProblemIdEventAlreadyIngested)
 
+// ErrEventDeliveryBeyondRetention is the `EventDeliveryBeyondRetention` the API reports.
+var ErrEventDeliveryBeyondRetention error = problemSentinel(ProblemIdEventDeliveryBeyondRetention)
+
 // ErrEventInvalidBase64Payload is the `EventInvalidBase64Payload` the API reports.
 var ErrEventInvalidBase64Payload error = problemSentinel(ProblemIdEventInvalidBase64Payload)
 
@@ -95,18 +107,36 @@ var ErrEventInvalidJsonPayload error = problemSentinel(ProblemIdEventInvalidJson
 // ErrEventInvalidPayloadContentType is the `EventInvalidPayloadContentType` the API reports.
 var ErrEventInvalidPayloadContentType error = problemSentinel(ProblemIdEventInvalidPayloadContentType)
 
+// ErrEventNotScheduled is the `EventNotScheduled` the API reports.
+var ErrEventNotScheduled error = problemSentinel(ProblemIdEventNotScheduled)
+
+// ErrEventPayloadSchemaViolation is the `EventPayloadSchemaViolation` the API reports.
+var ErrEventPayloadSchemaViolation error = problemSentinel(ProblemIdEventPayloadSchemaViolation)
+
 // ErrEventTypeAlreadyExist is the `EventTypeAlreadyExist` the API reports.
 var ErrEventTypeAlreadyExist error = problemSentinel(ProblemIdEventTypeAlreadyExist)
 
 // ErrEventTypeDoesNotExist is the `EventTypeDoesNotExist` the API reports.
 var ErrEventTypeDoesNotExist error = problemSentinel(ProblemIdEventTypeDoesNotExist)
 
+// ErrEventTypeVersionAlreadyExist is the `EventTypeVersionAlreadyExist` the API reports.
+var ErrEventTypeVersionAlreadyExist error = problemSentinel(ProblemIdEventTypeVersionAlreadyExist)
+
+// ErrEventTypeVersionDoesNotExist is the `EventTypeVersionDoesNotExist` the API reports.
+var ErrEventTypeVersionDoesNotExist error = problemSentinel(ProblemIdEventTypeVersionDoesNotExist)
+
+// ErrEventTypeVersionSunset is the `EventTypeVersionSunset` the API reports.
+var ErrEventTypeVersionSunset error = problemSentinel(ProblemIdEventTypeVersionSunset)
+
 // ErrForbidden is the `Forbidden` the API reports.
 var ErrForbidden error = problemSentinel(ProblemIdForbidden)
 
 // ErrInternalServerError is the `InternalServerError` the API reports.
 var ErrInternalServerError error = problemSentinel(ProblemIdInternalServerError)
 
+// ErrInvalidClientCertificate is the `InvalidClientCertificate` the API reports.
+var ErrInvalidClientCertificate error = problemSentinel(ProblemIdInvalidClientCertificate)
+
 // ErrInvalidDateRange is the `InvalidDateRange` the API reports.
 var ErrInvalidDateRange error = problemSentinel(ProblemIdInvalidDateRange)
 
@@ -158,9 +188,30 @@ var ErrRateLimited error = problemSentinel(ProblemIdRateLimited)
 // ErrRegistrationDisabled is the `RegistrationDisabled` the API reports.
 var ErrRegistrationDisabled error = problemSentinel(ProblemIdRegistrationDisabled)
 
+// ErrRequestAttemptNotFailed is the `RequestAttemptNotFailed` the API reports.
+var ErrRequestAttemptNotFailed error = problemSentinel(ProblemIdRequestAttemptNotFailed)
+
+// ErrRequestAttemptRetryPending is the `RequestAttemptRetryPending` the API reports.
+var ErrRequestAttemptRetryPending error = problemSentinel(ProblemIdRequestAttemptRetryPending)
+
+// ErrRetryPolicyOverLimit is the `RetryPolicyOverLimit` the API reports.
+var ErrRetryPolicyOverLimit error = problemSentinel(ProblemIdRetryPolicyOverLimit)
+
+// ErrSecretRotationInProgress is the `SecretRotationInProgress` the API reports.
+var ErrSecretRotationInProgress error = problemSentinel(ProblemIdSecretRotationInProgress)
+
 // ErrServiceUnavailable is the `ServiceUnavailable` the API reports.
 var ErrServiceUnavailable error = problemSentinel(ProblemIdServiceUnavailable)
 
+// ErrSigningKeysDisabled is the `SigningKeysDisabled` the API reports.
+var ErrSigningKeysDisabled error = problemSentinel(ProblemIdSigningKeysDisabled)
+
+// ErrSubscriptionDisabled is the `SubscriptionDisabled` the API reports.
+var ErrSubscriptionDisabled error = problemSentinel(ProblemIdSubscriptionDisabled)
+
+// ErrSubscriptionNotInbox is the `SubscriptionNotInbox` the API reports.
+var ErrSubscriptionNotInbox error = problemSentinel(ProblemIdSubscriptionNotInbox)
+
 // ErrTooManyApplicationsPerOrganization is the `TooManyApplicationsPerOrganization` the API reports.
 var ErrTooManyApplicationsPerOrganization error = problemSentinel(ProblemIdTooManyApplicationsPerOrganization)
 
@@ -37,6 +37,232 @@ const (
 	ApplicationInfoOnboardingStepsSubscriptionDone ApplicationInfoOnboardingStepsSubscription = "Done"
 )
 
+// BulkRetryPostStatus is one of the values the API answers with.
+type BulkRetryPostStatus string
+
+const (
+	// BulkRetryPostStatusFailed is the `failed` the API answers with.
+	BulkRetryPostStatusFailed BulkRetryPostStatus = "failed"
+	// BulkRetryPostStatusSuccessful is the `successful` the API answers with.
+	BulkRetryPostStatusSuccessful BulkRetryPostStatus = "successful"
+)
+
+// BulkRetryStatus is one of the values the API answers with.
+type BulkRetryStatus string
+
+const (
+	// BulkRetryStatusFailed is the `failed` the API answers with.
+	BulkRetryStatusFailed BulkRetryStatus = "failed"
+	// BulkRetryStatusSuccessful is the `successful` the API answers with.
+	BulkRetryStatusSuccessful BulkRetryStatus = "successful"
+)
+
+// DeadLetterDetailsDeadLetterReason is one of the values the API answers with.
+type DeadLetterDetailsDeadLetterReason string
+
+const (
+	// DeadLetterDetailsDeadLetterReasonRetriesExhausted is the `retries_exhausted` the API answers with.
+	DeadLetterDetailsDeadLetterReasonRetriesExhausted DeadLetterDetailsDeadLetterReason = "retries_exhausted"
+	// DeadLetterDetailsDeadLetterReasonSignatureFailed is the `signature_failed` the API answers with.
+	DeadLetterDetailsDeadLetterReasonSignatureFailed DeadLetterDetailsDeadLetterReason = "signature_failed"
+	// DeadLetterDetailsDeadLetterReasonTransformFailed is the `transform_failed` the API answers with.
+	DeadLetterDetailsDeadLetterReasonTransformFailed DeadLetterDetailsDeadLetterReason = "transform_failed"
+	// DeadLetterDetailsDeadLetterReasonPermanentFailure is the `permanent_failure` the API answers with.
+	DeadLetterDetailsDeadLetterReasonPermanentFailure DeadLetterDetailsDeadLetterReason = "permanent_failure"
+)
+
+// DeadLetterReason is one of the values the API answers with.
+type DeadLetterReason string
+
+const (
+	// DeadLetterReasonRetriesExhausted is the `retries_exhausted` the API answers with.
+	DeadLetterReasonRetriesExhausted DeadLetterReason = "retries_exhausted"
+	// DeadLetterReasonSignatureFailed is the `signature_failed` the API answers with.
+	DeadLetterReasonSignatureFailed DeadLetterReason = "signature_failed"
+	// DeadLetterReasonTransformFailed is the `transform_failed` the API answers with.
+	DeadLetterReasonTransformFailed DeadLetterReason = "transform_failed"
+	// DeadLetterReasonPermanentFailure is the `permanent_failure` the API answers with.
+	DeadLetterReasonPermanentFailure DeadLetterReason = "permanent_failure"
+)
+
+// EventTypePayloadSchemaMode is one of the values the API answers with.
+type EventTypePayloadSchemaMode string
+
+const (
+	// EventTypePayloadSchemaModeEnforce is the `enforce` the API answers with.
+	EventTypePayloadSchemaModeEnforce EventTypePayloadSchemaMode = "enforce"
+	// EventTypePayloadSchemaModeWarn is the `warn` the API answers with.
+	EventTypePayloadSchemaModeWarn EventTypePayloadSchemaMode = "warn"
+	// EventTypePayloadSchemaModeOff is the `off` the API answers with.
+	EventTypePayloadSchemaModeOff EventTypePayloadSchemaMode = "off"
+)
+
+// EventTypePostPayloadSchemaMode is one of the values the API answers with.
+type EventTypePostPayloadSchemaMode string
+
+const (
+	// EventTypePostPayloadSchemaModeEnforce is the `enforce` the API answers with.
+	EventTypePostPayloadSchemaModeEnforce EventTypePostPayloadSchemaMode = "enforce"
+	// EventTypePostPayloadSchemaModeWarn is the `warn` the API answers with.
+	EventTypePostPayloadSchemaModeWarn EventTypePostPayloadSchemaMode = "warn"
+	// EventTypePostPayloadSchemaModeOff is the `off` the API answers with.
+	EventTypePostPayloadSchemaModeOff EventTypePostPayloadSchemaMode = "off"
+)
+
+// EventTypePutPayloadSchemaMode is one of the values the API answers with.
+type EventTypePutPayloadSchemaMode string
+
+const (
+	// EventTypePutPayloadSchemaModeEnforce is the `enforce` the API answers with.
+	EventTypePutPayloadSchemaModeEnforce EventTypePutPayloadSchemaMode = "enforce"
+	// EventTypePutPayloadSchemaModeWarn is the `warn` the API answers with.
+	EventTypePutPayloadSchemaModeWarn EventTypePutPayloadSchemaMode = "warn"
+	// EventTypePutPayloadSchemaModeOff is the `off` the API answers with.
+	EventTypePutPayloadSchemaModeOff EventTypePutPayloadSchemaMode = "off"
+)
+
+// IngestedEventsBatchEventsProblemId is one of the values the API answers with.
+type IngestedEventsBatchEventsProblemId string
+
+const (
+	// IngestedEventsBatchEventsProblemIdOrganizationNameMissing is the `OrganizationNameMissing` the API answers with.
+	IngestedEventsBatchEventsProblemIdOrganizationNameMissing IngestedEventsBatchEventsProblemId = "OrganizationNameMissing"
+	// IngestedEventsBatchEventsProblemIdUserAlreadyExist is the `UserAlreadyExist` the API answers with.
+	IngestedEventsBatchEventsProblemIdUserAlreadyExist IngestedEventsBatchEventsProblemId = "UserAlreadyExist"
+	// IngestedEventsBatchEventsProblemIdRegistrationDisabled is the `RegistrationDisabled` the API answers with.
+	IngestedEventsBatchEventsProblemIdRegistrationDisabled IngestedEventsBatchEventsProblemId = "RegistrationDisabled"
+	// IngestedEventsBatchEventsProblemIdPasswordTooShort is the `PasswordTooShort` the API answers with.
+	IngestedEventsBatchEventsProblemIdPasswordTooShort IngestedEventsBatchEventsProblemId = "PasswordTooShort"
+	// IngestedEventsBatchEventsProblemIdPasswordTooLong is the `PasswordTooLong` the API answers with.
+	IngestedEventsBatchEventsProblemIdPasswordTooLong IngestedEventsBatchEventsProblemId = "PasswordTooLong"
+	// IngestedEventsBatchEventsProblemIdPasswordSimilarToEmail is the `PasswordSimilarToEmail` the API answers with.
+	IngestedEventsBatchEventsProblemIdPasswordSimilarToEmail IngestedEventsBatchEventsProblemId = "PasswordSimilarToEmail"
+	// IngestedEventsBatchEventsProblemIdPasswordSimilarToName is the `PasswordSimilarToName` the API answers with.
+	IngestedEventsBatchEventsProblemIdPasswordSimilarToName IngestedEventsBatchEventsProblemId = "PasswordSimilarToName"
+	// IngestedEventsBatchEventsProblemIdPasswordTooCommon is the `PasswordTooCommon` the API answers with.
+	IngestedEventsBatchEventsProblemIdPasswordTooCommon IngestedEventsBatchEventsProblemId = "PasswordTooCommon"
+	// IngestedEventsBatchEventsProblemIdPasswordNotDiverseEnough is the `PasswordNotDiverseEnough` the API answers with.
+	IngestedEventsBatchEventsProblemIdPasswordNotDiverseEnough IngestedEventsBatchEventsProblemId = "PasswordNotDiverseEnough"
+	// IngestedEventsBatchEventsProblemIdOrganizationIsNotEmpty is the `OrganizationIsNotEmpty` the API answers with.
+	IngestedEventsBatchEventsProblemIdOrganizationIsNotEmpty IngestedEventsBatchEventsProblemId = "OrganizationIsNotEmpty"
+	// IngestedEventsBatchEventsProblemIdInvitedUserDoesNotExist is the `InvitedUserDoesNotExist` the API answers with.
+	IngestedEventsBatchEventsProblemIdInvitedUserDoesNotExist IngestedEventsBatchEventsProblemId = "InvitedUserDoesNotExist"
+	// IngestedEventsBatchEventsProblemIdInvitedUserAlreadyInOrganization is the `InvitedUserAlreadyInOrganization` the API answers with.
+	IngestedEventsBatchEventsProblemIdInvitedUserAlreadyInOrganization IngestedEventsBatchEventsProblemId = "InvitedUserAlreadyInOrganization"
+	// IngestedEventsBatchEventsProblemIdApplicationNameMissing is the `ApplicationNameMissing` the API answers with.
+	IngestedEventsBatchEventsProblemIdApplicationNameMissing IngestedEventsBatchEventsProblemId = "ApplicationNameMissing"
+	// IngestedEventsBatchEventsProblemIdInvalidRole is the `InvalidRole` the API answers with.
+	IngestedEventsBatchEventsProblemIdInvalidRole IngestedEventsBatchEventsProblemId = "InvalidRole"
+	// IngestedEventsBatchEventsProblemIdEventTypeAlreadyExist is the `EventTypeAlreadyExist` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventTypeAlreadyExist IngestedEventsBatchEventsProblemId = "EventTypeAlreadyExist"
+	// IngestedEventsBatchEventsProblemIdEventTypeDoesNotExist is the `EventTypeDoesNotExist` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventTypeDoesNotExist IngestedEventsBatchEventsProblemId = "EventTypeDoesNotExist"
+	// IngestedEventsBatchEventsProblemIdEventTypeVersionAlreadyExist is the `EventTypeVersionAlreadyExist` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventTypeVersionAlreadyExist IngestedEventsBatchEventsProblemId = "EventTypeVersionAlreadyExist"
+	// IngestedEventsBatchEventsProblemIdEventTypeVersionDoesNotExist is the `EventTypeVersionDoesNotExist` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventTypeVersionDoesNotExist IngestedEventsBatchEventsProblemId = "EventTypeVersionDoesNotExist"
+	// IngestedEventsBatchEventsProblemIdEventTypeVersionSunset is the `EventTypeVersionSunset` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventTypeVersionSunset IngestedEventsBatchEventsProblemId = "EventTypeVersionSunset"
+	// IngestedEventsBatchEventsProblemIdUnauthorizedWorkers is the `UnauthorizedWorkers` the API answers with.
+	IngestedEventsBatchEventsProblemIdUnauthorizedWorkers IngestedEventsBatchEventsProblemId = "UnauthorizedWorkers"
+	// IngestedEventsBatchEventsProblemIdEventAlreadyIngested is the `EventAlreadyIngested` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventAlreadyIngested IngestedEventsBatchEventsProblemId = "EventAlreadyIngested"
+	// IngestedEventsBatchEventsProblemIdEventInvalidPayloadContentType is the `EventInvalidPayloadContentType` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventInvalidPayloadContentType IngestedEventsBatchEventsProblemId = "EventInvalidPayloadContentType"
+	// IngestedEventsBatchEventsProblemIdEventInvalidBase64Payload is the `EventInvalidBase64Payload` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventInvalidBase64Payload IngestedEventsBatchEventsProblemId = "EventInvalidBase64Payload"
+	// IngestedEventsBatchEventsProblemIdEventInvalidJsonPayload is the `EventInvalidJsonPayload` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventInvalidJsonPayload IngestedEventsBatchEventsProblemId = "EventInvalidJsonPayload"
+	// IngestedEventsBatchEventsProblemIdEventPayloadSchemaViolation is the `EventPayloadSchemaViolation` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventPayloadSchemaViolation IngestedEventsBatchEventsProblemId = "EventPayloadSchemaViolation"
+	// IngestedEventsBatchEventsProblemIdEventDeliveryBeyondRetention is the `EventDeliveryBeyondRetention` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventDeliveryBeyondRetention IngestedEventsBatchEventsProblemId = "EventDeliveryBeyondRetention"
+	// IngestedEventsBatchEventsProblemIdEventNotScheduled is the `EventNotScheduled` the API answers with.
+	IngestedEventsBatchEventsProblemIdEventNotScheduled IngestedEventsBatchEventsProblemId = "EventNotScheduled"
+	// IngestedEventsBatchEventsProblemIdLabelsAmbiguity is the `LabelsAmbiguity` the API answers with.
+	IngestedEventsBatchEventsProblemIdLabelsAmbiguity IngestedEventsBatchEventsProblemId = "LabelsAmbiguity"
+	// IngestedEventsBatchEventsProblemIdInvalidDateRange is the `InvalidDateRange` the API answers with.
+	IngestedEventsBatchEventsProblemIdInvalidDateRange IngestedEventsBatchEventsProblemId = "InvalidDateRange"
+	// IngestedEventsBatchEventsProblemIdRequestAttemptNotFailed is the `RequestAttemptNotFailed` the API answers with.
+	IngestedEventsBatchEventsProblemIdRequestAttemptNotFailed IngestedEventsBatchEventsProblemId = "RequestAttemptNotFailed"
+	// IngestedEventsBatchEventsProblemIdRequestAttemptRetryPending is the `RequestAttemptRetryPending` the API answers with.
+	IngestedEventsBatchEventsProblemIdRequestAttemptRetryPending IngestedEventsBatchEventsProblemId = "RequestAttemptRetryPending"
+	// IngestedEventsBatchEventsProblemIdSubscriptionDisabled is the `SubscriptionDisabled` the API answers with.
+	IngestedEventsBatchEventsProblemIdSubscriptionDisabled IngestedEventsBatchEventsProblemId = "SubscriptionDisabled"
+	// IngestedEventsBatchEventsProblemIdSubscriptionNotInbox is the `SubscriptionNotInbox` the API answers with.
+	IngestedEventsBatchEventsProblemIdSubscriptionNotInbox IngestedEventsBatchEventsProblemId = "SubscriptionNotInbox"
+	// IngestedEventsBatchEventsProblemIdSecretRotationInProgress is the `SecretRotationInProgress` the API answers with.
+	IngestedEventsBatchEventsProblemIdSecretRotationInProgress IngestedEventsBatchEventsProblemId = "SecretRotationInProgress"
+	// IngestedEventsBatchEventsProblemIdBulkRetryAlreadyRunning is the `BulkRetryAlreadyRunning` the API answers with.
+	IngestedEventsBatchEventsProblemIdBulkRetryAlreadyRunning IngestedEventsBatchEventsProblemId = "BulkRetryAlreadyRunning"
+	// IngestedEventsBatchEventsProblemIdBulkRetryFinished is the `BulkRetryFinished` the API answers with.
+	IngestedEventsBatchEventsProblemIdBulkRetryFinished IngestedEventsBatchEventsProblemId = "BulkRetryFinished"
+	// IngestedEventsBatchEventsProblemIdRetryPolicyOverLimit is the `RetryPolicyOverLimit` the API answers with.
+	IngestedEventsBatchEventsProblemIdRetryPolicyOverLimit IngestedEventsBatchEventsProblemId = "RetryPolicyOverLimit"
+	// IngestedEventsBatchEventsProblemIdClientCertificatesDisabled is the `ClientCertificatesDisabled` the API answers with.
+	IngestedEventsBatchEventsProblemIdClientCertificatesDisabled IngestedEventsBatchEventsProblemId = "ClientCertificatesDisabled"
+	// IngestedEventsBatchEventsProblemIdInvalidClientCertificate is the `InvalidClientCertificate` the API answers with.
+	IngestedEventsBatchEventsProblemIdInvalidClientCertificate IngestedEventsBatchEventsProblemId = "InvalidClientCertificate"
+	// IngestedEventsBatchEventsProblemIdSigningKeysDisabled is the `SigningKeysDisabled` the API answers with.
+	IngestedEventsBatchEventsProblemIdSigningKeysDisabled IngestedEventsBatchEventsProblemId = "SigningKeysDisabled"
+	// IngestedEventsBatchEventsProblemIdAuthNoAuthorizationHeader is the `AuthNoAuthorizationHeader` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthNoAuthorizationHeader IngestedEventsBatchEventsProblemId = "AuthNoAuthorizationHeader"
+	// IngestedEventsBatchEventsProblemIdAuthInvalidAuthorizationHeader is the `AuthInvalidAuthorizationHeader` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthInvalidAuthorizationHeader IngestedEventsBatchEventsProblemId = "AuthInvalidAuthorizationHeader"
+	// IngestedEventsBatchEventsProblemIdAuthApplicationSecretLookupError is the `AuthApplicationSecretLookupError` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthApplicationSecretLookupError IngestedEventsBatchEventsProblemId = "AuthApplicationSecretLookupError"
+	// IngestedEventsBatchEventsProblemIdAuthInvalidApplicationSecret is the `AuthInvalidApplicationSecret` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthInvalidApplicationSecret IngestedEventsBatchEventsProblemId = "AuthInvalidApplicationSecret"
+	// IngestedEventsBatchEventsProblemIdAuthBiscuitLookupError is the `AuthBiscuitLookupError` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthBiscuitLookupError IngestedEventsBatchEventsProblemId = "AuthBiscuitLookupError"
+	// IngestedEventsBatchEventsProblemIdAuthInvalidBiscuit is the `AuthInvalidBiscuit` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthInvalidBiscuit IngestedEventsBatchEventsProblemId = "AuthInvalidBiscuit"
+	// IngestedEventsBatchEventsProblemIdAuthFailedLogin is the `AuthFailedLogin` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthFailedLogin IngestedEventsBatchEventsProblemId = "AuthFailedLogin"
+	// IngestedEventsBatchEventsProblemIdAuthEmailNotVerified is the `AuthEmailNotVerified` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthEmailNotVerified IngestedEventsBatchEventsProblemId = "AuthEmailNotVerified"
+	// IngestedEventsBatchEventsProblemIdAuthEmailAlreadyVerified is the `AuthEmailAlreadyVerified` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthEmailAlreadyVerified IngestedEventsBatchEventsProblemId = "AuthEmailAlreadyVerified"
+	// IngestedEventsBatchEventsProblemIdAuthFailedRefresh is the `AuthFailedRefresh` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthFailedRefresh IngestedEventsBatchEventsProblemId = "AuthFailedRefresh"
+	// IngestedEventsBatchEventsProblemIdAuthEmailExpired is the `AuthEmailExpired` the API answers with.
+	IngestedEventsBatchEventsProblemIdAuthEmailExpired IngestedEventsBatchEventsProblemId = "AuthEmailExpired"
+	// IngestedEventsBatchEventsProblemIdTooManyMembersPerOrganization is the `TooManyMembersPerOrganization` the API answers with.
+	IngestedEventsBatchEventsProblemIdTooManyMembersPerOrganization IngestedEventsBatchEventsProblemId = "TooManyMembersPerOrganization"
+	// IngestedEventsBatchEventsProblemIdTooManyApplicationsPerOrganization is the `TooManyApplicationsPerOrganization` the API answers with.
+	IngestedEventsBatchEventsProblemIdTooManyApplicationsPerOrganization IngestedEventsBatchEventsProblemId = "TooManyApplicationsPerOrganization"
+	// IngestedEventsBatchEventsProblemIdTooManyEventsToday is the `TooManyEventsToday` the API answers with.
+	IngestedEventsBatchEventsProblemIdTooManyEventsToday IngestedEventsBatchEventsProblemId = "TooManyEventsToday"
+	// IngestedEventsBatchEventsProblemIdTooManySubscriptionsPerApplication is the `TooManySubscriptionsPerApplication` the API answers with.
+	IngestedEventsBatchEventsProblemIdTooManySubscriptionsPerApplication IngestedEventsBatchEventsProblemId = "TooManySubscriptionsPerApplication"
+	// IngestedEventsBatchEventsProblemIdTooManyEventTypesPerApplication is the `TooManyEventTypesPerApplication` the API answers with.
+	IngestedEventsBatchEventsProblemIdTooManyEventTypesPerApplication IngestedEventsBatchEventsProblemId = "TooManyEventTypesPerApplication"
+	// IngestedEventsBatchEventsProblemIdJsonPayload is the `JsonPayload` the API answers with.
+	IngestedEventsBatchEventsProblemIdJsonPayload IngestedEventsBatchEventsProblemId = "JsonPayload"
+	// IngestedEventsBatchEventsProblemIdValidation is the `Validation` the API answers with.
+	IngestedEventsBatchEventsProblemIdValidation IngestedEventsBatchEventsProblemId = "Validation"
+	// IngestedEventsBatchEventsProblemIdNotFound is the `NotFound` the API answers with.
+	IngestedEventsBatchEventsProblemIdNotFound IngestedEventsBatchEventsProblemId = "NotFound"
+	// IngestedEventsBatchEventsProblemIdInternalServerError is the `InternalServerError` the API answers with.
+	IngestedEventsBatchEventsProblemIdInternalServerError IngestedEventsBatchEventsProblemId = "InternalServerError"
+	// IngestedEventsBatchEventsProblemIdForbidden is the `Forbidden` the API answers with.
+	IngestedEventsBatchEventsProblemIdForbidden IngestedEventsBatchEventsProblemId = "Forbidden"
+	// IngestedEventsBatchEventsProblemIdRateLimited is the `RateLimited` the API answers with.
+	IngestedEventsBatchEventsProblemIdRateLimited IngestedEventsBatchEventsProblemId = "RateLimited"
+	// IngestedEventsBatchEventsProblemIdServiceUnavailable is the `ServiceUnavailable` the API answers with.
+	IngestedEventsBatchEventsProblemIdServiceUnavailable IngestedEventsBatchEventsProblemId = "ServiceUnavailable"
+)
+
+// IngestedEventsBatchEventsStatus is one of the values the API answers with.
+type IngestedEventsBatchEventsStatus string
+
+const (
+	// IngestedEventsBatchEventsStatusIngested is the `ingested` the API answers with.
+	IngestedEventsBatchEventsStatusIngested IngestedEventsBatchEventsStatus = "ingested"
+	// IngestedEventsBatchEventsStatusFailed is the `failed` the API answers with.
+	IngestedEventsBatchEventsStatusFailed IngestedEventsBatchEventsStatus = "failed"
+)
+
 // OrganizationInfoOnboardingStepsApplication is one of the values the API answers with.
 type OrganizationInfoOnboardingStepsApplication string
 
@@ -113,6 +339,12 @@ const (
 	ProblemIdEventTypeAlreadyExist ProblemId = "EventTypeAlreadyExist"
 	// ProblemIdEventTypeDoesNotExist is the `EventTypeDoesNotExist` the API answers with.
 	ProblemIdEventTypeDoesNotExist ProblemId = "EventTypeDoesNotExist"
+	// ProblemIdEventTypeVersionAlreadyExist is the `EventTypeVersionAlreadyExist` the API answers with.
+	ProblemIdEventTypeVersionAlreadyExist ProblemId = "EventTypeVersionAlreadyExist"
+	// ProblemIdEventTypeVersionDoesNotExist is the `EventTypeVersionDoesNotExist` the API answers with.
+	ProblemIdEventTypeVersionDoesNotExist ProblemId = "EventTypeVersionDoesNotExist"
+	// ProblemIdEventTypeVersionSunset is the `EventTypeVersionSunset` the API answers with.
+	ProblemIdEventTypeVersionSunset ProblemId = "EventTypeVersionSunset"
 	// ProblemIdUnauthorizedWorkers is the `UnauthorizedWorkers` the API answers with.
 	ProblemIdUnauthorizedWorkers ProblemId = "UnauthorizedWorkers"
 	// ProblemIdEventAlreadyIngested is the `EventAlreadyIngested` the API answers with.
@@ -123,10 +355,38 @@ const (
 	ProblemIdEventInvalidBase64Payload ProblemId = "EventInvalidBase64Payload"
 	// ProblemIdEventInvalidJsonPayload is the `EventInvalidJsonPayload` the API answers with.
 	ProblemIdEventInvalidJsonPayload ProblemId = "EventInvalidJsonPayload"
+	// ProblemIdEventPayloadSchemaViolation is the `EventPayloadSchemaViolation` the API answers with.
+	ProblemIdEventPayloadSchemaViolation ProblemId = "EventPayloadSchemaViolation"
+	// ProblemIdEventDeliveryBeyondRetention is the `EventDeliveryBeyondRetention` the API answers with.
+	ProblemIdEventDeliveryBeyondRetention ProblemId = "EventDeliveryBeyondRetention"
+	// ProblemIdEventNotScheduled is the `EventNotScheduled` the API answers with.
+	ProblemIdEventNotScheduled ProblemId = "EventNotScheduled"
 	// ProblemIdLabelsAmbiguity is the `LabelsAmbiguity` the API answers with.
 	ProblemIdLabelsAmbiguity ProblemId = "LabelsAmbiguity"
 	// ProblemIdInvalidDateRange is the `InvalidDateRange` the API answers with.
 	ProblemIdInvalidDateRange ProblemId = "InvalidDateRange"
+	// ProblemIdRequestAttemptNotFailed is the `RequestAttemptNotFailed` the API answers with.
+	ProblemIdRequestAttemptNotFailed ProblemId = "RequestAttemptNotFailed"
+	// ProblemIdRequestAttemptRetryPending is the `RequestAttemptRetryPending` the API answers with.
+	ProblemIdRequestAttemptRetryPending ProblemId = "RequestAttemptRetryPending"
+	// ProblemIdSubscriptionDisabled is the `SubscriptionDisabled` the API answers with.
+	ProblemIdSubscriptionDisabled ProblemId = "SubscriptionDisabled"
+	// ProblemIdSubscriptionNotInbox is the `SubscriptionNotInbox` the API answers with.
+	ProblemIdSubscriptionNotInbox ProblemId = "SubscriptionNotInbox"
+	// ProblemIdSecretRotationInProgress is the `SecretRotationInProgress` the API answers with.
+	ProblemIdSecretRotationInProgress ProblemId = "SecretRotationInProgress"
+	// ProblemIdBulkRetryAlreadyRunning is the `BulkRetryAlreadyRunning` the API answers with.
+	ProblemIdBulkRetryAlreadyRunning ProblemId = "BulkRetryAlreadyRunning"
+	// ProblemIdBulkRetryFinished is the `BulkRetryFinished` the API answers with.
+	ProblemIdBulkRetryFinished ProblemId = "BulkRetryFinished"
+	// ProblemIdRetryPolicyOverLimit is the `RetryPolicyOverLimit` the API answers with.
+	ProblemIdRetryPolicyOverLimit ProblemId = "RetryPolicyOverLimit"
+	// ProblemIdClientCertificatesDisabled is the `ClientCertificatesDisabled` the API answers with.
+	ProblemIdClientCertificatesDisabled ProblemId = "ClientCertificatesDisabled"
+	// ProblemIdInvalidClientCertificate is the `InvalidClientCertificate` the API answers with.
+	ProblemIdInvalidClientCertificate ProblemId = "InvalidClientCertificate"
+	// ProblemIdSigningKeysDisabled is the `SigningKeysDisabled` the API answers with.
+	ProblemIdSigningKeysDisabled ProblemId = "SigningKeysDisabled"
 	// ProblemIdAuthNoAuthorizationHeader is the `AuthNoAuthorizationHeader` the API answers with.
 	ProblemIdAuthNoAuthorizationHeader ProblemId = "AuthNoAuthorizationHeader"
 	// ProblemIdAuthInvalidAuthorizationHeader is the `AuthInvalidAuthorizationHeader` the API answers with.
@@ -175,6 +435,20 @@ const (
 	ProblemIdServiceUnavailable ProblemId = "ServiceUnavailable"
 )
 
+// RequestAttemptStatusRuleOutcome is one of the values the API answers with.
+type RequestAttemptStatusRuleOutcome string
+
+const (
+	// RequestAttemptStatusRuleOutcomeSuccess is the `success` the API answers with.
+	RequestAttemptStatusRuleOutcomeSuccess RequestAttemptStatusRuleOutcome = "success"
+	// RequestAttemptStatusRuleOutcomeRetryable is the `retryable` the API answers with.
+	RequestAttemptStatusRuleOutcomeRetryable RequestAttemptStatusRuleOutcome = "retryable"
+	// RequestAttemptStatusRuleOutcomePermanentFailure is the `permanent_failure` the API answers with.
+	RequestAttemptStatusRuleOutcomePermanentFailure RequestAttemptStatusRuleOutcome = "permanent_failure"
+	// RequestAttemptStatusRuleOutcomeDisableSubscription is the `disable_subscription` the API answers with.
+	RequestAttemptStatusRuleOutcomeDisableSubscription RequestAttemptStatusRuleOutcome = "disable_subscription"
+)
+
 // RequestAttemptStatusType is one of the values the API answers with.
 type RequestAttemptStatusType string
 
@@ -189,6 +463,86 @@ const (
 	RequestAttemptStatusTypeSuccessful RequestAttemptStatusType = "successful"
 	// RequestAttemptStatusTypeFailed is the `failed` the API answers with.
 	RequestAttemptStatusTypeFailed RequestAttemptStatusType = "failed"
+	// RequestAttemptStatusTypeExpired is the `expired` the API answers with.
+	RequestAttemptStatusTypeExpired RequestAttemptStatusType = "expired"
+	// RequestAttemptStatusTypeCancelled is the `cancelled` the API answers with.
+	RequestAttemptStatusTypeCancelled RequestAttemptStatusType = "cancelled"
+)
+
+// SubscriptionPostResponseRulesOutcome is one of the values the API answers with.
+type SubscriptionPostResponseRulesOutcome string
+
+const (
+	// SubscriptionPostResponseRulesOutcomeSuccess is the `success` the API answers with.
+	SubscriptionPostResponseRulesOutcomeSuccess SubscriptionPostResponseRulesOutcome = "success"
+	// SubscriptionPostResponseRulesOutcomeRetryable is the `retryable` the API answers with.
+	SubscriptionPostResponseRulesOutcomeRetryable SubscriptionPostResponseRulesOutcome = "retryable"
+	// SubscriptionPostResponseRulesOutcomePermanentFailure is the `permanent_failure` the API answers with.
+	SubscriptionPostResponseRulesOutcomePermanentFailure SubscriptionPostResponseRulesOutcome = "permanent_failure"
+	// SubscriptionPostResponseRulesOutcomeDisableSubscription is the `disable_subscription` the API answers with.
+	SubscriptionPostResponseRulesOutcomeDisableSubscription SubscriptionPostResponseRulesOutcome = "disable_subscription"
+)
+
+// SubscriptionPostSignatureScheme is one of the values the API answers with.
+type SubscriptionPostSignatureScheme string
+
+const (
+	// SubscriptionPostSignatureSchemeHook0 is the `hook0` the API answers with.
+	SubscriptionPostSignatureSchemeHook0 SubscriptionPostSignatureScheme = "hook0"
+	// SubscriptionPostSignatureSchemeStandardWebhooks is the `standard_webhooks` the API answers with.
+	SubscriptionPostSignatureSchemeStandardWebhooks SubscriptionPostSignatureScheme = "standard_webhooks"
+)
+
+// SubscriptionPostTargetType is one of the values the API answers with.
+type SubscriptionPostTargetType string
+
+const (
+	// SubscriptionPostTargetTypeHttp is the `http` the API answers with.
+	SubscriptionPostTargetTypeHttp SubscriptionPostTargetType = "http"
+	// SubscriptionPostTargetTypeAmqp is the `amqp` the API answers with.
+	SubscriptionPostTargetTypeAmqp SubscriptionPostTargetType = "amqp"
+	// SubscriptionPostTargetTypeSqs is the `sqs` the API answers with.
+	SubscriptionPostTargetTypeSqs SubscriptionPostTargetType = "sqs"
+	// SubscriptionPostTargetTypeInbox is the `inbox` the API answers with.
+	SubscriptionPostTargetTypeInbox SubscriptionPostTargetType = "inbox"
+)
+
+// SubscriptionResponseRulesOutcome is one of the values the API answers with.
+type SubscriptionResponseRulesOutcome string
+
+const (
+	// SubscriptionResponseRulesOutcomeSuccess is the `success` the API answers with.
+	SubscriptionResponseRulesOutcomeSuccess SubscriptionResponseRulesOutcome = "success"
+	// SubscriptionResponseRulesOutcomeRetryable is the `retryable` the API answers with.
+	SubscriptionResponseRulesOutcomeRetryable SubscriptionResponseRulesOutcome = "retryable"
+	// SubscriptionResponseRulesOutcomePermanentFailure is the `permanent_failure` the API answers with.
+	SubscriptionResponseRulesOutcomePermanentFailure SubscriptionResponseRulesOutcome = "permanent_failure"
+	// SubscriptionResponseRulesOutcomeDisableSubscription is the `disable_subscription` the API answers with.
+	SubscriptionResponseRulesOutcomeDisableSubscription SubscriptionResponseRulesOutcome = "disable_subscription"
+)
+
+// SubscriptionSignatureScheme is one of the values the API answers with.
+type SubscriptionSignatureScheme string
+
+const (
+	// SubscriptionSignatureSchemeHook0 is the `hook0` the API answers with.
+	SubscriptionSignatureSchemeHook0 SubscriptionSignatureScheme = "hook0"
+	// SubscriptionSignatureSchemeStandardWebhooks is the `standard_webhooks` the API answers with.
+	SubscriptionSignatureSchemeStandardWebhooks SubscriptionSignatureScheme = "standard_webhooks"
+)
+
+// SubscriptionTargetType is one of the values the API answers with.
+type SubscriptionTargetType string
+
+const (
+	// SubscriptionTargetTypeHttp is the `http` the API answers with.
+	SubscriptionTargetTypeHttp SubscriptionTargetType = "http"
+	// SubscriptionTargetTypeAmqp is the `amqp` the API answers with.
+	SubscriptionTargetTypeAmqp SubscriptionTargetType = "amqp"
+	// SubscriptionTargetTypeSqs is the `sqs` the API answers with.
+	SubscriptionTargetTypeSqs SubscriptionTargetType = "sqs"
+	// SubscriptionTargetTypeInbox is the `inbox` the API answers with.
+	SubscriptionTargetTypeInbox SubscriptionTargetType = "inbox"
 )
 
 // Application is the `Application` the API declares.
@@ -269,12 +623,212 @@ type ApplicationSecretPost struct {
 	Name *string `json:"name,omitempty"`
 }
 
+// BulkRetry is the `BulkRetry` the API declares.
+type BulkRetry struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// BulkRetryId carries `bulk_retry_id`.
+	BulkRetryId UUID `json:"bulk_retry_id"`
+	// CancelledAt carries `cancelled_at`.
+	CancelledAt *time.Time `json:"cancelled_at,omitempty"`
+	// CompletedAt carries `completed_at`.
+	CompletedAt *time.Time `json:"completed_at,omitempty"`
+	// CreatedAt carries `created_at`.
+	CreatedAt time.Time `json:"created_at"`
+	// EventId carries `event_id`.
+	EventId *UUID `json:"event_id,omitempty"`
+	// EventTypeNames carries `event_type_names`.
+	EventTypeNames []string `json:"event_type_names"`
+	// MaxCreatedAt carries `max_created_at`.
+	MaxCreatedAt time.Time `json:"max_created_at"`
+	// MinCreatedAt carries `min_created_at`.
+	MinCreatedAt time.Time `json:"min_created_at"`
+	// ProcessedCount carries `processed_count`: Number of pairs handled so far
+	ProcessedCount int64 `json:"processed_count"`
+	// RetriedCount carries `retried_count`: Number of request attempts created so far
+	RetriedCount int64 `json:"retried_count"`
+	// Status carries `status`.
+	Status BulkRetryStatus `json:"status"`
+	// SubscriptionId carries `subscription_id`.
+	SubscriptionId *UUID `json:"subscription_id,omitempty"`
+	// TotalCount carries `total_count`: Number of (event, subscription) pairs that matched when the bulk retry was created
+	TotalCount int64 `json:"total_count"`
+	// UpdatedAt carries `updated_at`.
+	UpdatedAt time.Time `json:"updated_at"`
+}
+
+// BulkRetryDryRun is the `BulkRetryDryRun` the API declares.
+type BulkRetryDryRun struct {
+	// Count carries `count`: Number of (event, subscription) pairs a bulk retry would redeliver now
+	Count int64 `json:"count"`
+}
+
+// BulkRetryPost is the `BulkRetryPost` the API declares.
+type BulkRetryPost struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// EventId carries `event_id`.
+	EventId *UUID `json:"event_id,omitempty"`
+	// EventTypeNames carries `event_type_names`.
+	EventTypeNames []string `json:"event_type_names,omitempty"`
+	// MaxCreatedAt carries `max_created_at`.
+	MaxCreatedAt *time.Time `json:"max_created_at,omitempty"`
+	// MinCreatedAt carries `min_created_at`.
+	MinCreatedAt *time.Time `json:"min_created_at,omitempty"`
+	// Status carries `status`: Redeliver request attempts that failed (default) or that succeeded
+	Status *BulkRetryPostStatus `json:"status,omitempty"`
+	// SubscriptionId carries `subscription_id`.
+	SubscriptionId *UUID `json:"subscription_id,omitempty"`
+}
+
+// CancelEvent is the `CancelEvent` the API declares.
+type CancelEvent struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+}
+
+// ClientCertificate is the `ClientCertificate` the API declares.
+type ClientCertificate struct {
+	// CertificatePem carries `certificate_pem`: Certificate chain, leaf certificate first
+	CertificatePem string `json:"certificate_pem"`
+	// CreatedAt carries `created_at`.
+	CreatedAt time.Time `json:"created_at"`
+	// FingerprintSha256 carries `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate, as hexadecimal
+	FingerprintSha256 string `json:"fingerprint_sha256"`
+	// NotAfter carries `not_after`.
+	NotAfter time.Time `json:"not_after"`
+	// NotBefore carries `not_before`.
+	NotBefore time.Time `json:"not_before"`
+	// Subject carries `subject`.
+	Subject string `json:"subject"`
+	// SubscriptionId carries `subscription_id`.
+	SubscriptionId UUID `json:"subscription_id"`
+	// UpdatedAt carries `updated_at`.
+	UpdatedAt time.Time `json:"updated_at"`
+}
+
+// ClientCertificatePut is the `ClientCertificatePut` the API declares.
+type ClientCertificatePut struct {
+	// CertificatePem carries `certificate_pem`: PEM-encoded certificate chain, leaf certificate first
+	CertificatePem string `json:"certificate_pem"`
+	// PrivateKeyPem carries `private_key_pem`: PEM-encoded unencrypted private key of the leaf certificate (PKCS#8, PKCS#1 or SEC1)
+	PrivateKeyPem string `json:"private_key_pem"`
+}
+
+// DeadLetter is the `DeadLetter` the API declares.
+type DeadLetter struct {
+	// CreatedAt carries `created_at`.
+	CreatedAt time.Time `json:"created_at"`
+	// DeadLetterId carries `dead_letter_id`.
+	DeadLetterId UUID `json:"dead_letter_id"`
+	// Event carries `event`.
+	Event DeadLetterEvent `json:"event"`
+	// HttpResponseStatus carries `http_response_status`.
+	HttpResponseStatus *int32 `json:"http_response_status,omitempty"`
+	// Reason carries `reason`.
+	Reason DeadLetterReason `json:"reason"`
+	// RequestAttemptId carries `request_attempt_id`: Last request attempt of the delivery
+	RequestAttemptId UUID `json:"request_attempt_id"`
+	// ResponseId carries `response_id`: Response to the last request attempt, if the target was reached
+	ResponseId *UUID `json:"response_id,omitempty"`
+	// RetryCount carries `retry_count`.
+	RetryCount int32 `json:"retry_count"`
+	// Subscription carries `subscription`.
+	Subscription DeadLetterSubscription `json:"subscription"`
+}
+
+// DeadLetterDetails is the `DeadLetterDetails` the API declares.
+type DeadLetterDetails struct {
+	// DeadLetter carries `dead_letter`: Delivery an output worker gave up on
+	DeadLetter DeadLetterDetailsDeadLetter `json:"dead_letter"`
+	// LastResponse carries `last_response`: Response to the last request attempt, if the target was reached
+	LastResponse *DeadLetterDetailsLastResponse `json:"last_response,omitempty"`
+}
+
+// DeadLetterDetailsDeadLetter is the `DeadLetterDetailsDeadLetter` the API declares.
+type DeadLetterDetailsDeadLetter struct {
+	// CreatedAt carries `created_at`.
+	CreatedAt time.Time `json:"created_at"`
+	// DeadLetterId carries `dead_letter_id`.
+	DeadLetterId UUID `json:"dead_letter_id"`
+	// Event carries `event`.
+	Event DeadLetterDetailsDeadLetterEvent `json:"event"`
+	// HttpResponseStatus carries `http_response_status`.
+	HttpResponseStatus *int32 `json:"http_response_status,omitempty"`
+	// Reason carries `reason`.
+	Reason DeadLetterDetailsDeadLetterReason `json:"reason"`
+	// RequestAttemptId carries `request_attempt_id`: Last request attempt of the delivery
+	RequestAttemptId UUID `json:"request_attempt_id"`
+	// ResponseId carries `response_id`: Response to the last request attempt, if the target was reached
+	ResponseId *UUID `json:"response_id,omitempty"`
+	// RetryCount carries `retry_count`.
+	RetryCount int32 `json:"retry_count"`
+	// Subscription carries `subscription`.
+	Subscription DeadLetterDetailsDeadLetterSubscription `json:"subscription"`
+}
+
+// DeadLetterDetailsDeadLetterEvent is the `DeadLetterDetailsDeadLetterEvent` the API declares.
+type DeadLetterDetailsDeadLetterEvent struct {
+	// EventId carries `event_id`.
+	EventId UUID `json:"event_id"`
+	// EventTypeName carries `event_type_name`.
+	EventTypeName string `json:"event_type_name"`
+}
+
+// DeadLetterDetailsDeadLetterSubscription is the `DeadLetterDetailsDeadLetterSubscription` the API declares.
+type DeadLetterDetailsDeadLetterSubscription struct {
+	// Description carries `description`.
+	Description *string `json:"description,omitempty"`
+	// SubscriptionId carries `subscription_id`.
+	SubscriptionId UUID `json:"subscription_id"`
+}
+
+// DeadLetterDetailsLastResponse is the `DeadLetterDetailsLastResponse` the API declares.
+type DeadLetterDetailsLastResponse struct {
+	// Body carries `body`.
+	Body *string `json:"body,omitempty"`
+	// ElapsedTimeMs carries `elapsed_time_ms`.
+	ElapsedTimeMs *int32 `json:"elapsed_time_ms,omitempty"`
+	// Headers carries `headers`.
+	Headers map[string]string `json:"headers,omitempty"`
+	// HttpCode carries `http_code`.
+	HttpCode *int32 `json:"http_code,omitempty"`
+	// ResponseErrorName carries `response_error_name`.
+	ResponseErrorName *string `json:"response_error_name,omitempty"`
+	// ResponseId carries `response_id`.
+	ResponseId UUID `json:"response_id"`
+}
+
+// DeadLetterEvent is the `DeadLetterEvent` the API declares.
+type DeadLetterEvent struct {
+	// EventId carries `event_id`.
+	EventId UUID `json:"event_id"`
+	// EventTypeName carries `event_type_name`.
+	EventTypeName string `json:"event_type_name"`
+}
+
+// DeadLetterSubscription is the `DeadLetterSubscription` the API declares.
+type DeadLetterSubscription struct {
+	// Description carries `description`.
+	Description *string `json:"description,omitempty"`
+	// SubscriptionId carries `subscription_id`.
+	SubscriptionId UUID `json:"subscription_id"`
+}
+
 // Event is the `Event` the API declares.
 type Event struct {
+	// CancelledAt carries `cancelled_at`: Date the scheduled delivery of the event was cancelled
+	CancelledAt *time.Time `json:"cancelled_at,omitempty"`
+	// DeliverAt carries `deliver_at`: Date from which the event is delivered, when it was scheduled for a later delivery
+	DeliverAt *time.Time `json:"deliver_at,omitempty"`
 	// EventId carries `event_id`.
 	EventId UUID `json:"event_id"`
 	// EventTypeName carries `event_type_name`.
 	EventTypeName string `json:"event_type_name"`
+	// EventTypeVersion carries `event_type_version`.
+	EventTypeVersion *int32 `json:"event_type_version,omitempty"`
+	// ExpiresAt carries `expires_at`: Date after which the event is no longer delivered
+	ExpiresAt *time.Time `json:"expires_at,omitempty"`
 	// Ip carries `ip`.
 	Ip string `json:"ip"`
 	// Labels carries `labels`.
@@ -293,10 +847,16 @@ type Event struct {
 type EventPost struct {
 	// ApplicationId carries `application_id`: UUID of the application this event belongs to.
 	ApplicationId UUID `json:"application_id"`
+	// DeliverAt carries `deliver_at`: Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the appl
+	DeliverAt *time.Time `json:"deliver_at,omitempty"`
 	// EventId carries `event_id`: Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
 	EventId *UUID `json:"event_id,omitempty"`
 	// EventType carries `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters.
 	EventType string `json:"event_type"`
+	// EventTypeVersion carries `event_type_version`: Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+	EventTypeVersion *int32 `json:"event_type_version,omitempty"`
+	// ExpiresAt carries `expires_at`: Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted f
+	ExpiresAt *time.Time `json:"expires_at,omitempty"`
 	// Labels carries `labels`: Labels for event filtering and routing to subscriptions.
 	Labels map[string]string `json:"labels"`
 	// Metadata carries `metadata`: Optional metadata key-value pairs associated with the event.
@@ -311,20 +871,34 @@ type EventPost struct {
 
 // EventType is the `EventType` the API declares.
 type EventType struct {
+	// DefaultTtlS carries `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date
+	DefaultTtlS *int32 `json:"default_ttl_s,omitempty"`
 	// EventTypeName carries `event_type_name`.
 	EventTypeName string `json:"event_type_name"`
+	// PayloadSchema carries `payload_schema`.
+	PayloadSchema any `json:"payload_schema,omitempty"`
+	// PayloadSchemaMode carries `payload_schema_mode`.
+	PayloadSchemaMode EventTypePayloadSchemaMode `json:"payload_schema_mode"`
 	// ResourceTypeName carries `resource_type_name`.
 	ResourceTypeName string `json:"resource_type_name"`
 	// ServiceName carries `service_name`.
 	ServiceName string `json:"service_name"`
 	// VerbName carries `verb_name`.
 	VerbName string `json:"verb_name"`
+	// Versions carries `versions`.
+	Versions []EventTypeVersions `json:"versions"`
 }
 
 // EventTypePost is the `EventTypePost` the API declares.
 type EventTypePost struct {
 	// ApplicationId carries `application_id`.
 	ApplicationId UUID `json:"application_id"`
+	// DefaultTtlS carries `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+	DefaultTtlS *int32 `json:"default_ttl_s,omitempty"`
+	// PayloadSchema carries `payload_schema`: JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported
+	PayloadSchema any `json:"payload_schema,omitempty"`
+	// PayloadSchemaMode carries `payload_schema_mode`: How the payload schema is applied at ingestion (default: `enforce`)
+	PayloadSchemaMode *EventTypePostPayloadSchemaMode `json:"payload_schema_mode,omitempty"`
 	// ResourceType carries `resource_type`.
 	ResourceType string `json:"resource_type"`
 	// Service carries `service`.
@@ -333,12 +907,80 @@ type EventTypePost struct {
 	Verb string `json:"verb"`
 }
 
+// EventTypePut is the `EventTypePut` the API declares.
+type EventTypePut struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// DefaultTtlS carries `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+	DefaultTtlS *int32 `json:"default_ttl_s,omitempty"`
+	// PayloadSchema carries `payload_schema`: JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported
+	PayloadSchema any `json:"payload_schema,omitempty"`
+	// PayloadSchemaMode carries `payload_schema_mode`: How the payload schema is applied at ingestion (default: `enforce`)
+	PayloadSchemaMode *EventTypePutPayloadSchemaMode `json:"payload_schema_mode,omitempty"`
+}
+
+// EventTypeVersion is the `EventTypeVersion` the API declares.
+type EventTypeVersion struct {
+	// CreatedAt carries `created_at`.
+	CreatedAt time.Time `json:"created_at"`
+	// DeprecatedAt carries `deprecated_at`.
+	DeprecatedAt *time.Time `json:"deprecated_at,omitempty"`
+	// PayloadSchema carries `payload_schema`: JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type
+	PayloadSchema any `json:"payload_schema,omitempty"`
+	// SunsetAt carries `sunset_at`: Date from which the events declaring this version are refused
+	SunsetAt *time.Time `json:"sunset_at,omitempty"`
+	// Version carries `version`.
+	Version int32 `json:"version"`
+}
+
+// EventTypeVersionDeprecate is the `EventTypeVersionDeprecate` the API declares.
+type EventTypeVersionDeprecate struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// SunsetAt carries `sunset_at`: Date from which the events declaring this version are refused; none keeps accepting them
+	SunsetAt *time.Time `json:"sunset_at,omitempty"`
+	// Version carries `version`.
+	Version int32 `json:"version"`
+}
+
+// EventTypeVersionPost is the `EventTypeVersionPost` the API declares.
+type EventTypeVersionPost struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// PayloadSchema carries `payload_schema`: JSON Schema the payloads of the `application/json` events declaring this version must match, instead of the one of the event type; remote references are not supported
+	PayloadSchema any `json:"payload_schema,omitempty"`
+	// Version carries `version`.
+	Version int32 `json:"version"`
+}
+
+// EventTypeVersions is the `EventTypeVersions` the API declares.
+type EventTypeVersions struct {
+	// CreatedAt carries `created_at`.
+	CreatedAt time.Time `json:"created_at"`
+	// DeprecatedAt carries `deprecated_at`.
+	DeprecatedAt *time.Time `json:"deprecated_at,omitempty"`
+	// PayloadSchema carries `payload_schema`: JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type
+	PayloadSchema any `json:"payload_schema,omitempty"`
+	// SunsetAt carries `sunset_at`: Date from which the events declaring this version are refused
+	SunsetAt *time.Time `json:"sunset_at,omitempty"`
+	// Version carries `version`.
+	Version int32 `json:"version"`
+}
+
 // EventWithPayload is the `EventWithPayload` the API declares.
 type EventWithPayload struct {
+	// CancelledAt carries `cancelled_at`: Date the scheduled delivery of the event was cancelled
+	CancelledAt *time.Time `json:"cancelled_at,omitempty"`
+	// DeliverAt carries `deliver_at`: Date from which the event is delivered, when it was scheduled for a later delivery
+	DeliverAt *time.Time `json:"deliver_at,omitempty"`
 	// EventId carries `event_id`.
 	EventId UUID `json:"event_id"`
 	// EventTypeName carries `event_type_name`.
 	EventTypeName string `json:"event_type_name"`
+	// EventTypeVersion carries `event_type_version`.
+	EventTypeVersion *int32 `json:"event_type_version,omitempty"`
+	// ExpiresAt carries `expires_at`: Date after which the event is no longer delivered
+	ExpiresAt *time.Time `json:"expires_at,omitempty"`
 	// Ip carries `ip`.
 	Ip string `json:"ip"`
 	// Labels carries `labels`.
@@ -355,6 +997,40 @@ type EventWithPayload struct {
 	ReceivedAt time.Time `json:"received_at"`
 }
 
+// EventsBatchPost is the `EventsBatchPost` the API declares.
+type EventsBatchPost struct {
+	// ApplicationId carries `application_id`: UUID of the application the events belong to. Events of the batch that carry another application ID are not ingested.
+	ApplicationId UUID `json:"application_id"`
+	// Events carries `events`: Events to ingest. Length: 1-100 events; the whole request body is also subject to the usual request size limit.
+	Events []EventsBatchPostEvents `json:"events"`
+}
+
+// EventsBatchPostEvents is the `EventsBatchPostEvents` the API declares.
+type EventsBatchPostEvents struct {
+	// ApplicationId carries `application_id`: UUID of the application this event belongs to.
+	ApplicationId UUID `json:"application_id"`
+	// DeliverAt carries `deliver_at`: Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the appl
+	DeliverAt *time.Time `json:"deliver_at,omitempty"`
+	// EventId carries `event_id`: Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
+	EventId *UUID `json:"event_id,omitempty"`
+	// EventType carries `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters.
+	EventType string `json:"event_type"`
+	// EventTypeVersion carries `event_type_version`: Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+	EventTypeVersion *int32 `json:"event_type_version,omitempty"`
+	// ExpiresAt carries `expires_at`: Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted f
+	ExpiresAt *time.Time `json:"expires_at,omitempty"`
+	// Labels carries `labels`: Labels for event filtering and routing to subscriptions.
+	Labels map[string]string `json:"labels"`
+	// Metadata carries `metadata`: Optional metadata key-value pairs associated with the event.
+	Metadata map[string]string `json:"metadata,omitempty"`
+	// OccurredAt carries `occurred_at`: Timestamp when the event occurred.
+	OccurredAt time.Time `json:"occurred_at"`
+	// Payload carries `payload`: The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded).
+	Payload string `json:"payload"`
+	// PayloadContentType carries `payload_content_type`: Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
+	PayloadContentType string `json:"payload_content_type"`
+}
+
 // EventsPerDayEntry is the `EventsPerDayEntry` the API declares.
 type EventsPerDayEntry struct {
 	// Amount carries `amount`.
@@ -369,16 +1045,138 @@ type EventsPerDayEntry struct {
 	IsProvisional bool `json:"is_provisional"`
 }
 
+// Inbox is the `Inbox` the API declares.
+type Inbox struct {
+	// Messages carries `messages`.
+	Messages []InboxMessages `json:"messages"`
+	// NextCursor carries `next_cursor`: Pass it as `cursor` to the next pull to continue after the last message of this one
+	NextCursor *string `json:"next_cursor,omitempty"`
+}
+
+// InboxAck is the `InboxAck` the API declares.
+type InboxAck struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// Receipts carries `receipts`: Receipts of the leased events
+	Receipts []UUID `json:"receipts"`
+}
+
+// InboxMessages is the `InboxMessages` the API declares.
+type InboxMessages struct {
+	// EventId carries `event_id`.
+	EventId UUID `json:"event_id"`
+	// EventTypeName carries `event_type_name`.
+	EventTypeName string `json:"event_type_name"`
+	// Labels carries `labels`.
+	Labels any `json:"labels"`
+	// LeasedUntil carries `leased_until`: When the event is offered again if it was neither acknowledged nor rejected
+	LeasedUntil time.Time `json:"leased_until"`
+	// OccurredAt carries `occurred_at`.
+	OccurredAt time.Time `json:"occurred_at"`
+	// Payload carries `payload`: Base64-encoded
+	Payload string `json:"payload"`
+	// PayloadContentType carries `payload_content_type`.
+	PayloadContentType string `json:"payload_content_type"`
+	// Receipt carries `receipt`: Identifies this lease of the request attempt; a later lease of the same attempt gets another one
+	Receipt UUID `json:"receipt"`
+	// ReceivedAt carries `received_at`.
+	ReceivedAt time.Time `json:"received_at"`
+	// RequestAttemptId carries `request_attempt_id`.
+	RequestAttemptId UUID `json:"request_attempt_id"`
+	// RetryCount carries `retry_count`.
+	RetryCount int32 `json:"retry_count"`
+}
+
+// InboxNack is the `InboxNack` the API declares.
+type InboxNack struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// Receipts carries `receipts`: Receipts of the leased events
+	Receipts []UUID `json:"receipts"`
+	// Retry carries `retry`: Whether the events are offered again, as new request attempts (true by default); they are not beyond the maximum number of retries of the subscription, or of this instance if it has none
+	Retry *bool `json:"retry,omitempty"`
+	// RetryInS carries `retry_in_s`: Delay in seconds before the events are offered again (0 by default)
+	RetryInS *int32 `json:"retry_in_s,omitempty"`
+}
+
+// InboxSettlement is the `InboxSettlement` the API declares.
+type InboxSettlement struct {
+	// Settled carries `settled`: Receipts of the events that were settled
+	Settled []UUID `json:"settled"`
+	// Stale carries `stale`: Receipts that settled nothing: their lease expired, or the event was already settled
+	Stale []UUID `json:"stale"`
+}
+
 // IngestedEvent is the `IngestedEvent` the API declares.
 type IngestedEvent struct {
 	// ApplicationId carries `application_id`.
 	ApplicationId UUID `json:"application_id"`
+	// Deprecation carries `deprecation`: Present when the event was ingested with a deprecated version of its event type
+	Deprecation *IngestedEventDeprecation `json:"deprecation,omitempty"`
 	// EventId carries `event_id`.
 	EventId UUID `json:"event_id"`
 	// ReceivedAt carries `received_at`.
 	ReceivedAt time.Time `json:"received_at"`
 }
 
+// IngestedEventDeprecation is the `IngestedEventDeprecation` the API declares.
+type IngestedEventDeprecation struct {
+	// DeprecatedAt carries `deprecated_at`.
+	DeprecatedAt time.Time `json:"deprecated_at"`
+	// EventTypeVersion carries `event_type_version`.
+	EventTypeVersion int32 `json:"event_type_version"`
+	// SunsetAt carries `sunset_at`.
+	SunsetAt *time.Time `json:"sunset_at,omitempty"`
+}
+
+// IngestedEventsBatch is the `IngestedEventsBatch` the API declares.
+type IngestedEventsBatch struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// Events carries `events`: One outcome per submitted event, in the order the events were submitted
+	Events []IngestedEventsBatchEvents `json:"events"`
+}
+
+// IngestedEventsBatchEvents is the `IngestedEventsBatchEvents` the API declares.
+type IngestedEventsBatchEvents struct {
+	// Deprecation carries `deprecation`: Present when the event was ingested with a deprecated version of its event type
+	Deprecation *IngestedEventsBatchEventsDeprecation `json:"deprecation,omitempty"`
+	// EventId carries `event_id`: ID of the ingested event, or the one the event was submitted with when it was not ingested
+	EventId *UUID `json:"event_id,omitempty"`
+	// Problem carries `problem`: Present when the event was not ingested
+	Problem *IngestedEventsBatchEventsProblem `json:"problem,omitempty"`
+	// ReceivedAt carries `received_at`: Present when the event was ingested
+	ReceivedAt *time.Time `json:"received_at,omitempty"`
+	// Status carries `status`.
+	Status IngestedEventsBatchEventsStatus `json:"status"`
+}
+
+// IngestedEventsBatchEventsDeprecation is the `IngestedEventsBatchEventsDeprecation` the API declares.
+type IngestedEventsBatchEventsDeprecation struct {
+	// DeprecatedAt carries `deprecated_at`.
+	DeprecatedAt time.Time `json:"deprecated_at"`
+	// EventTypeVersion carries `event_type_version`.
+	EventTypeVersion int32 `json:"event_type_version"`
+	// SunsetAt carries `sunset_at`.
+	SunsetAt *time.Time `json:"sunset_at,omitempty"`
+}
+
+// IngestedEventsBatchEventsProblem is the `IngestedEventsBatchEventsProblem` the API declares.
+type IngestedEventsBatchEventsProblem struct {
+	// Detail carries `detail`.
+	Detail string `json:"detail"`
+	// Id carries `id`: Identifier of the problem that occurred, stable across releases
+	Id IngestedEventsBatchEventsProblemId `json:"id"`
+	// Status carries `status`.
+	Status int32 `json:"status"`
+	// Title carries `title`.
+	Title string `json:"title"`
+	// Type carries `type`: Documentation page of this problem, which is also what distinguishes one problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a URL.
+	Type string `json:"type"`
+	// Validation carries `validation`: Which submitted values were rejected and why, keyed by the name of the field they were submitted under. Carried by validation failures (`Validation`, status 422) and `null` for every other problem, as
+	Validation any `json:"validation,omitempty"`
+}
+
 // InstanceConfig is the `InstanceConfig` the API declares.
 type InstanceConfig struct {
 	// ApplicationSecretCompatibility carries `application_secret_compatibility`.
@@ -419,6 +1217,56 @@ type InstanceConfigMatomo struct {
 	Url string `json:"url"`
 }
 
+// JsonWebKeySet is the `JsonWebKeySet` the API declares.
+type JsonWebKeySet struct {
+	// Keys carries `keys`.
+	Keys []JsonWebKeySetKeys `json:"keys"`
+}
+
+// JsonWebKeySetKeys is the `JsonWebKeySetKeys` the API declares.
+type JsonWebKeySetKeys struct {
+	// Alg carries `alg`: Always `EdDSA`
+	Alg string `json:"alg"`
+	// Crv carries `crv`: Always `Ed25519`
+	Crv string `json:"crv"`
+	// Kid carries `kid`: ID of the signing key, which signatures name in their `k` part
+	Kid string `json:"kid"`
+	// Kty carries `kty`: Always `OKP`
+	Kty string `json:"kty"`
+	// Use carries `use`: Always `sig`
+	Use string `json:"use"`
+	// X carries `x`: Public key, encoded in base64url without padding
+	X string `json:"x"`
+}
+
+// Oauth2ClientCredentials is the `OAuth2ClientCredentials` the API declares.
+type Oauth2ClientCredentials struct {
+	// ClientId carries `client_id`.
+	ClientId string `json:"client_id"`
+	// CreatedAt carries `created_at`.
+	CreatedAt time.Time `json:"created_at"`
+	// Scopes carries `scopes`.
+	Scopes []string `json:"scopes"`
+	// SubscriptionId carries `subscription_id`.
+	SubscriptionId UUID `json:"subscription_id"`
+	// TokenUrl carries `token_url`.
+	TokenUrl string `json:"token_url"`
+	// UpdatedAt carries `updated_at`.
+	UpdatedAt time.Time `json:"updated_at"`
+}
+
+// Oauth2ClientCredentialsPut is the `OAuth2ClientCredentialsPut` the API declares.
+type Oauth2ClientCredentialsPut struct {
+	// ClientId carries `client_id`.
+	ClientId string `json:"client_id"`
+	// ClientSecret carries `client_secret`.
+	ClientSecret string `json:"client_secret"`
+	// Scopes carries `scopes`: Scopes to request; none by default
+	Scopes []string `json:"scopes,omitempty"`
+	// TokenUrl carries `token_url`: Token endpoint of the authorization server
+	TokenUrl string `json:"token_url"`
+}
+
 // Organization is the `Organization` the API declares.
 type Organization struct {
 	// Name carries `name`.
@@ -529,6 +1377,20 @@ type Problem struct {
 	Validation any `json:"validation,omitempty"`
 }
 
+// PurgeDeadLetters is the `PurgeDeadLetters` the API declares.
+type PurgeDeadLetters struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// SubscriptionId carries `subscription_id`: Only purge the dead letters of this subscription
+	SubscriptionId *UUID `json:"subscription_id,omitempty"`
+}
+
+// PurgedDeadLetters is the `PurgedDeadLetters` the API declares.
+type PurgedDeadLetters struct {
+	// PurgedCount carries `purged_count`.
+	PurgedCount int64 `json:"purged_count"`
+}
+
 // QuotasResponse is the `QuotasResponse` the API declares.
 type QuotasResponse struct {
 	// Enabled carries `enabled`.
@@ -553,6 +1415,28 @@ type QuotasResponseLimits struct {
 	GlobalSubscriptionsPerApplicationLimit int32 `json:"global_subscriptions_per_application_limit"`
 }
 
+// RedeliverDeadLetter is the `RedeliverDeadLetter` the API declares.
+type RedeliverDeadLetter struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+}
+
+// RedeliverDeadLetters is the `RedeliverDeadLetters` the API declares.
+type RedeliverDeadLetters struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// SubscriptionId carries `subscription_id`: Only redeliver the dead letters of this subscription
+	SubscriptionId *UUID `json:"subscription_id,omitempty"`
+}
+
+// RedeliveredDeadLetter is the `RedeliveredDeadLetter` the API declares.
+type RedeliveredDeadLetter struct {
+	// DeadLetterId carries `dead_letter_id`.
+	DeadLetterId UUID `json:"dead_letter_id"`
+	// RequestAttemptId carries `request_attempt_id`.
+	RequestAttemptId UUID `json:"request_attempt_id"`
+}
+
 // ReplayEvent is the `ReplayEvent` the API declares.
 type ReplayEvent struct {
 	// ApplicationId carries `application_id`.
@@ -563,8 +1447,12 @@ type ReplayEvent struct {
 type RequestAttempt struct {
 	// CreatedAt carries `created_at`.
 	CreatedAt time.Time `json:"created_at"`
+	// DeadLetterId carries `dead_letter_id`: Dead letter the request attempt became, when it was the last attempt of a delivery an output worker gave up on
+	DeadLetterId *UUID `json:"dead_letter_id,omitempty"`
 	// DelayUntil carries `delay_until`.
 	DelayUntil *time.Time `json:"delay_until,omitempty"`
+	// DeliveryBatchId carries `delivery_batch_id`: Batch the request attempt was delivered in, shared with the other request attempts of the batch and their response
+	DeliveryBatchId *UUID `json:"delivery_batch_id,omitempty"`
 	// Event carries `event`.
 	Event RequestAttemptEvent `json:"event"`
 	// EventId carries `event_id`.
@@ -599,13 +1487,15 @@ type RequestAttemptEvent struct {
 
 // RequestAttemptStatus is the `RequestAttemptStatus` the API declares.
 type RequestAttemptStatus struct {
-	// At carries `at`: Timestamp when completed (present in successful, failed)
+	// At carries `at`: Timestamp when completed (present in successful, failed, expired, cancelled)
 	At *time.Time `json:"at,omitempty"`
 	// FullProcessingMs carries `full_processing_ms`: Total processing time in milliseconds (present in successful, failed)
 	FullProcessingMs *int64 `json:"full_processing_ms,omitempty"`
+	// RuleOutcome carries `rule_outcome`: Outcome given by a response rule of the subscription, when one matched the response (present in successful, failed). One of: success, retryable, permanent_failure, disable_subscription
+	RuleOutcome *RequestAttemptStatusRuleOutcome `json:"rule_outcome,omitempty"`
 	// Since carries `since`: Timestamp when the status started (present in waiting, pending, in_progress)
 	Since *time.Time `json:"since,omitempty"`
-	// Type carries `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed
+	// Type carries `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed, expired, cancelled
 	Type RequestAttemptStatusType `json:"type"`
 	// Until carries `until`: Timestamp until which waiting (only present in waiting status)
 	Until *time.Time `json:"until,omitempty"`
@@ -635,6 +1525,30 @@ type Response struct {
 	ResponseId UUID `json:"response_id"`
 }
 
+// RetryRequestAttempt is the `RetryRequestAttempt` the API declares.
+type RetryRequestAttempt struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+}
+
+// SecretRotation is the `SecretRotation` the API declares.
+type SecretRotation struct {
+	// PreviousSecret carries `previous_secret`: The secret the new one replaced
+	PreviousSecret UUID `json:"previous_secret"`
+	// PreviousSecretExpiresAt carries `previous_secret_expires_at`.
+	PreviousSecretExpiresAt time.Time `json:"previous_secret_expires_at"`
+	// Secret carries `secret`: The new secret of the subscription
+	Secret UUID `json:"secret"`
+	// SubscriptionId carries `subscription_id`.
+	SubscriptionId UUID `json:"subscription_id"`
+}
+
+// SecretRotationPost is the `SecretRotationPost` the API declares.
+type SecretRotationPost struct {
+	// OverlapSeconds carries `overlap_seconds`: How long the previous secret stays valid, in seconds (one day by default)
+	OverlapSeconds *int32 `json:"overlap_seconds,omitempty"`
+}
+
 // ServiceToken is the `ServiceToken` the API declares.
 type ServiceToken struct {
 	// Biscuit carries `biscuit`.
@@ -655,18 +1569,52 @@ type ServiceTokenPost struct {
 	OrganizationId UUID `json:"organization_id"`
 }
 
+// SigningKey is the `SigningKey` the API declares.
+type SigningKey struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+	// CreatedAt carries `created_at`.
+	CreatedAt time.Time `json:"created_at"`
+	// IsCurrent carries `is_current`: Whether deliveries are signed with this key, which only the newest key of the application is
+	IsCurrent bool `json:"is_current"`
+	// PublicKey carries `public_key`: Public key, encoded in base64url without padding like the `x` member of its JSON Web Key
+	PublicKey string `json:"public_key"`
+	// SigningKeyId carries `signing_key_id`: Identifies the key in the `k` part of signatures and as the `kid` of its JSON Web Key
+	SigningKeyId UUID `json:"signing_key_id"`
+}
+
+// SigningKeyPost is the `SigningKeyPost` the API declares.
+type SigningKeyPost struct {
+	// ApplicationId carries `application_id`.
+	ApplicationId UUID `json:"application_id"`
+}
+
 // Subscription is the `Subscription` the API declares.
 type Subscription struct {
 	// ApplicationId carries `application_id`.
 	ApplicationId UUID `json:"application_id"`
+	// AutoDisabledAt carries `auto_disabled_at`: When the circuit breaker or a response rule last disabled the subscription; cleared when it is enabled again
+	AutoDisabledAt *time.Time `json:"auto_disabled_at,omitempty"`
+	// AutoDisabledReason carries `auto_disabled_reason`: Why the circuit breaker or a response rule last disabled the subscription
+	AutoDisabledReason *string `json:"auto_disabled_reason,omitempty"`
+	// Batching carries `batching`: Deliver the events of the subscription in batches: one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or once its oldest event has waited `max_wait_s` se
+	Batching *SubscriptionBatching `json:"batching,omitempty"`
+	// CircuitBreaker carries `circuit_breaker`: Disables the subscription once its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set
+	CircuitBreaker *SubscriptionCircuitBreaker `json:"circuit_breaker,omitempty"`
 	// CreatedAt carries `created_at`.
 	CreatedAt time.Time `json:"created_at"`
 	// DedicatedWorkers carries `dedicated_workers`.
 	DedicatedWorkers []string `json:"dedicated_workers"`
+	// DeliveryLimits carries `delivery_limits`: Limits on how fast deliveries of the subscription start; deliveries over them wait for their turn, which does not count as a failure; at least one limit must be set
+	DeliveryLimits *SubscriptionDeliveryLimits `json:"delivery_limits,omitempty"`
 	// Description carries `description`.
 	Description *string `json:"description,omitempty"`
+	// EventTypeVersions carries `event_type_versions`: Versions the subscription is pinned to, by event type
+	EventTypeVersions map[string]int32 `json:"event_type_versions"`
 	// EventTypes carries `event_types`.
 	EventTypes []string `json:"event_types"`
+	// Filter carries `filter`: An object with exactly one of: `and` or `or` (a list of filters), `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload); `label` and `field` go alon
+	Filter any `json:"filter,omitempty"`
 	// IsEnabled carries `is_enabled`.
 	IsEnabled bool `json:"is_enabled"`
 	// LabelKey carries `label_key`: _Kept for backward compatibility, you should use `labels`_
@@ -677,8 +1625,18 @@ type Subscription struct {
 	Labels map[string]string `json:"labels"`
 	// Metadata carries `metadata`.
 	Metadata map[string]string `json:"metadata"`
+	// OrderingKey carries `ordering_key`: Where the key of an event comes from, to deliver the events that share a key one at a time and in the order they were received; exactly one of `label` or `field` must be set
+	OrderingKey *SubscriptionOrderingKey `json:"ordering_key,omitempty"`
+	// PayloadTransform carries `payload_transform`: Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a pay
+	PayloadTransform *SubscriptionPayloadTransform `json:"payload_transform,omitempty"`
+	// ResponseRules carries `response_rules`.
+	ResponseRules []SubscriptionResponseRules `json:"response_rules,omitempty"`
+	// RetryPolicy carries `retry_policy`: Retry schedule of the subscription, replacing the one of the instance within its limits; every field left out keeps the value of the instance
+	RetryPolicy *SubscriptionRetryPolicy `json:"retry_policy,omitempty"`
 	// Secret carries `secret`.
 	Secret UUID `json:"secret"`
+	// SignatureScheme carries `signature_scheme`.
+	SignatureScheme SubscriptionSignatureScheme `json:"signature_scheme"`
 	// SubscriptionId carries `subscription_id`.
 	SubscriptionId UUID `json:"subscription_id"`
 	// Target carries `target`.
@@ -687,16 +1645,78 @@ type Subscription struct {
 	UpdatedAt time.Time `json:"updated_at"`
 }
 
+// SubscriptionBatching is the `SubscriptionBatching` the API declares.
+type SubscriptionBatching struct {
+	// MaxEvents carries `max_events`: Largest number of events in a batch
+	MaxEvents int32 `json:"max_events"`
+	// MaxWaitS carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill up
+	MaxWaitS int32 `json:"max_wait_s"`
+}
+
+// SubscriptionCircuitBreaker is the `SubscriptionCircuitBreaker` the API declares.
+type SubscriptionCircuitBreaker struct {
+	// MaxConsecutiveFailures carries `max_consecutive_failures`: Number of failed deliveries in a row after which the subscription is disabled
+	MaxConsecutiveFailures *int32 `json:"max_consecutive_failures,omitempty"`
+	// MaxFailingHours carries `max_failing_hours`: Number of hours without a single successful delivery after which the subscription is disabled
+	MaxFailingHours *int32 `json:"max_failing_hours,omitempty"`
+}
+
+// SubscriptionDeliveryLimits is the `SubscriptionDeliveryLimits` the API declares.
+type SubscriptionDeliveryLimits struct {
+	// MaxInFlight carries `max_in_flight`: Number of deliveries that can be in flight at once
+	MaxInFlight *int32 `json:"max_in_flight,omitempty"`
+	// MaxPerSecond carries `max_per_second`: Number of deliveries that can start in a second
+	MaxPerSecond *int32 `json:"max_per_second,omitempty"`
+	// PerHost carries `per_host`: Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone
+	PerHost *bool `json:"per_host,omitempty"`
+}
+
+// SubscriptionOrderingKey is the `SubscriptionOrderingKey` the API declares.
+type SubscriptionOrderingKey struct {
+	// Field carries `field`: JSON pointer to the value holding the key in a JSON payload, for example `/customer/id`
+	Field *string `json:"field,omitempty"`
+	// Label carries `label`: Name of the event label holding the key
+	Label *string `json:"label,omitempty"`
+}
+
+// SubscriptionPayloadTransform is the `SubscriptionPayloadTransform` the API declares.
+type SubscriptionPayloadTransform struct {
+	// Constants carries `constants`: Properties added at the top level of the result, overriding properties of the same name (the result must then be an object)
+	Constants map[string]any `json:"constants,omitempty"`
+	// Envelope carries `envelope`: Name of a property the result is wrapped in
+	Envelope *string `json:"envelope,omitempty"`
+	// Fields carries `fields`: Values to copy from the payload into a new object; when omitted, the whole payload is kept
+	Fields []SubscriptionPayloadTransformFields `json:"fields,omitempty"`
+}
+
+// SubscriptionPayloadTransformFields is the `SubscriptionPayloadTransformFields` the API declares.
+type SubscriptionPayloadTransformFields struct {
+	// From carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped)
+	From string `json:"from"`
+	// To carries `to`: JSON pointer where the value is written in the result, for example `/id`; defaults to `from`
+	To *string `json:"to,omitempty"`
+}
+
 // SubscriptionPost is the `SubscriptionPost` the API declares.
 type SubscriptionPost struct {
 	// ApplicationId carries `application_id`.
 	ApplicationId UUID `json:"application_id"`
+	// Batching carries `batching`: Deliver the events in batches, as one HTTP request with a JSON array body, rather than one by one
+	Batching *SubscriptionPostBatching `json:"batching,omitempty"`
+	// CircuitBreaker carries `circuit_breaker`: Disable the subscription once its deliveries keep failing; organization editors are notified by email
+	CircuitBreaker *SubscriptionPostCircuitBreaker `json:"circuit_breaker,omitempty"`
 	// DedicatedWorkers carries `dedicated_workers`.
 	DedicatedWorkers []string `json:"dedicated_workers,omitempty"`
+	// DeliveryLimits carries `delivery_limits`: Limit how fast deliveries start, for this subscription or for its host
+	DeliveryLimits *SubscriptionPostDeliveryLimits `json:"delivery_limits,omitempty"`
 	// Description carries `description`.
 	Description *string `json:"description,omitempty"`
+	// EventTypeVersions carries `event_type_versions`: Only receive the events of these event types that declare this version (or no version at all)
+	EventTypeVersions map[string]int32 `json:"event_type_versions,omitempty"`
 	// EventTypes carries `event_types`.
 	EventTypes []string `json:"event_types"`
+	// Filter carries `filter`: Only deliver the events of the subscribed types that match this expression over labels and JSON payload fields
+	Filter any `json:"filter,omitempty"`
 	// IsEnabled carries `is_enabled`.
 	IsEnabled bool `json:"is_enabled"`
 	// LabelKey carries `label_key`: _Kept for backward compatibility, you should use `labels`_
@@ -707,30 +1727,184 @@ type SubscriptionPost struct {
 	Labels map[string]string `json:"labels,omitempty"`
 	// Metadata carries `metadata`.
 	Metadata map[string]string `json:"metadata,omitempty"`
+	// OrderingKey carries `ordering_key`: Deliver the events that share a key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out of retries
+	OrderingKey *SubscriptionPostOrderingKey `json:"ordering_key,omitempty"`
+	// PayloadTransform carries `payload_transform`: Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a pay
+	PayloadTransform *SubscriptionPostPayloadTransform `json:"payload_transform,omitempty"`
+	// ResponseRules carries `response_rules`: Decide the outcome of HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body reports an error; the first rule that matches wins, and a response n
+	ResponseRules []SubscriptionPostResponseRules `json:"response_rules,omitempty"`
+	// RetryPolicy carries `retry_policy`: Retry failed deliveries on this schedule rather than the instance's one
+	RetryPolicy *SubscriptionPostRetryPolicy `json:"retry_policy,omitempty"`
+	// SignatureScheme carries `signature_scheme`: How deliveries are signed (`hook0` by default); `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of the subscription s
+	SignatureScheme *SubscriptionPostSignatureScheme `json:"signature_scheme,omitempty"`
 	// Target carries `target`.
 	Target SubscriptionPostTarget `json:"target"`
 }
 
+// SubscriptionPostBatching is the `SubscriptionPostBatching` the API declares.
+type SubscriptionPostBatching struct {
+	// MaxEvents carries `max_events`: Largest number of events in a batch
+	MaxEvents int32 `json:"max_events"`
+	// MaxWaitS carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill up
+	MaxWaitS int32 `json:"max_wait_s"`
+}
+
+// SubscriptionPostCircuitBreaker is the `SubscriptionPostCircuitBreaker` the API declares.
+type SubscriptionPostCircuitBreaker struct {
+	// MaxConsecutiveFailures carries `max_consecutive_failures`: Number of failed deliveries in a row after which the subscription is disabled
+	MaxConsecutiveFailures *int32 `json:"max_consecutive_failures,omitempty"`
+	// MaxFailingHours carries `max_failing_hours`: Number of hours without a single successful delivery after which the subscription is disabled
+	MaxFailingHours *int32 `json:"max_failing_hours,omitempty"`
+}
+
+// SubscriptionPostDeliveryLimits is the `SubscriptionPostDeliveryLimits` the API declares.
+type SubscriptionPostDeliveryLimits struct {
+	// MaxInFlight carries `max_in_flight`: Number of deliveries that can be in flight at once
+	MaxInFlight *int32 `json:"max_in_flight,omitempty"`
+	// MaxPerSecond carries `max_per_second`: Number of deliveries that can start in a second
+	MaxPerSecond *int32 `json:"max_per_second,omitempty"`
+	// PerHost carries `per_host`: Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone
+	PerHost *bool `json:"per_host,omitempty"`
+}
+
+// SubscriptionPostOrderingKey is the `SubscriptionPostOrderingKey` the API declares.
+type SubscriptionPostOrderingKey struct {
+	// Field carries `field`: JSON pointer to the value holding the key in a JSON payload, for example `/customer/id`
+	Field *string `json:"field,omitempty"`
+	// Label carries `label`: Name of the event label holding the key
+	Label *string `json:"label,omitempty"`
+}
+
+// SubscriptionPostPayloadTransform is the `SubscriptionPostPayloadTransform` the API declares.
+type SubscriptionPostPayloadTransform struct {
+	// Constants carries `constants`: Properties added at the top level of the result, overriding properties of the same name (the result must then be an object)
+	Constants map[string]any `json:"constants,omitempty"`
+	// Envelope carries `envelope`: Name of a property the result is wrapped in
+	Envelope *string `json:"envelope,omitempty"`
+	// Fields carries `fields`: Values to copy from the payload into a new object; when omitted, the whole payload is kept
+	Fields []SubscriptionPostPayloadTransformFields `json:"fields,omitempty"`
+}
+
+// SubscriptionPostPayloadTransformFields is the `SubscriptionPostPayloadTransformFields` the API declares.
+type SubscriptionPostPayloadTransformFields struct {
+	// From carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped)
+	From string `json:"from"`
+	// To carries `to`: JSON pointer where the value is written in the result, for example `/id`; defaults to `from`
+	To *string `json:"to,omitempty"`
+}
+
+// SubscriptionPostResponseRules is the `SubscriptionPostResponseRules` the API declares.
+type SubscriptionPostResponseRules struct {
+	// BodyContains carries `body_contains`: Text the response body must contain
+	BodyContains *string `json:"body_contains,omitempty"`
+	// Header carries `header`: Header the response must have
+	Header *SubscriptionPostResponseRulesHeader `json:"header,omitempty"`
+	// Outcome carries `outcome`: What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+	Outcome SubscriptionPostResponseRulesOutcome `json:"outcome"`
+	// Status carries `status`: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`
+	Status []string `json:"status"`
+}
+
+// SubscriptionPostResponseRulesHeader is the `SubscriptionPostResponseRulesHeader` the API declares.
+type SubscriptionPostResponseRulesHeader struct {
+	// Name carries `name`: Name of the header, regardless of case
+	Name string `json:"name"`
+	// Value carries `value`: Value the header must have; when omitted, any value matches
+	Value *string `json:"value,omitempty"`
+}
+
+// SubscriptionPostRetryPolicy is the `SubscriptionPostRetryPolicy` the API declares.
+type SubscriptionPostRetryPolicy struct {
+	// BackoffStepsS carries `backoff_steps_s`: Delays in seconds to wait before each retry; the last one is used again for the following retries
+	BackoffStepsS []int32 `json:"backoff_steps_s,omitempty"`
+	// MaxRetries carries `max_retries`: Maximum number of retries after a failed delivery (0 disables retries)
+	MaxRetries *int32 `json:"max_retries,omitempty"`
+	// MaxWindowS carries `max_window_s`: Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled
+	MaxWindowS *int32 `json:"max_window_s,omitempty"`
+}
+
 // SubscriptionPostTarget is the `SubscriptionPostTarget` the API declares.
 type SubscriptionPostTarget struct {
-	// Headers carries `headers`.
-	Headers any `json:"headers"`
-	// Method carries `method`.
-	Method string `json:"method"`
-	// Type carries `type`.
-	Type string `json:"type"`
-	// Url carries `url`.
-	Url string `json:"url"`
+	// AccessKeyId carries `access_key_id`: `sqs`: access key ID requests are signed with
+	AccessKeyId *string `json:"access_key_id,omitempty"`
+	// Exchange carries `exchange`: `amqp`: exchange messages are published to; empty for the default exchange
+	Exchange *string `json:"exchange,omitempty"`
+	// Headers carries `headers`: `http`: headers sent with every request
+	Headers any `json:"headers,omitempty"`
+	// Method carries `method`: `http`: HTTP method of the requests
+	Method *string `json:"method,omitempty"`
+	// Password carries `password`: `amqp`: password to log in to the broker with; write-only
+	Password *string `json:"password,omitempty"`
+	// QueueUrl carries `queue_url`: `sqs`: URL of the queue
+	QueueUrl *string `json:"queue_url,omitempty"`
+	// Region carries `region`: `sqs`: region requests are signed for
+	Region *string `json:"region,omitempty"`
+	// RoutingKey carries `routing_key`: `amqp`: routing key messages are published with
+	RoutingKey *string `json:"routing_key,omitempty"`
+	// SecretAccessKey carries `secret_access_key`: `sqs`: secret access key requests are signed with; write-only
+	SecretAccessKey *string `json:"secret_access_key,omitempty"`
+	// Type carries `type`: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+	Type SubscriptionPostTargetType `json:"type"`
+	// Url carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials
+	Url *string `json:"url,omitempty"`
+	// Username carries `username`: `amqp`: user name to log in to the broker with
+	Username *string `json:"username,omitempty"`
+}
+
+// SubscriptionResponseRules is the `SubscriptionResponseRules` the API declares.
+type SubscriptionResponseRules struct {
+	// BodyContains carries `body_contains`: Text the response body must contain
+	BodyContains *string `json:"body_contains,omitempty"`
+	// Header carries `header`: Header the response must have
+	Header *SubscriptionResponseRulesHeader `json:"header,omitempty"`
+	// Outcome carries `outcome`: What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+	Outcome SubscriptionResponseRulesOutcome `json:"outcome"`
+	// Status carries `status`: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`
+	Status []string `json:"status"`
+}
+
+// SubscriptionResponseRulesHeader is the `SubscriptionResponseRulesHeader` the API declares.
+type SubscriptionResponseRulesHeader struct {
+	// Name carries `name`: Name of the header, regardless of case
+	Name string `json:"name"`
+	// Value carries `value`: Value the header must have; when omitted, any value matches
+	Value *string `json:"value,omitempty"`
+}
+
+// SubscriptionRetryPolicy is the `SubscriptionRetryPolicy` the API declares.
+type SubscriptionRetryPolicy struct {
+	// BackoffStepsS carries `backoff_steps_s`: Delays in seconds to wait before each retry; the last one is used again for the following retries
+	BackoffStepsS []int32 `json:"backoff_steps_s,omitempty"`
+	// MaxRetries carries `max_retries`: Maximum number of retries after a failed delivery (0 disables retries)
+	MaxRetries *int32 `json:"max_retries,omitempty"`
+	// MaxWindowS carries `max_window_s`: Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled
+	MaxWindowS *int32 `json:"max_window_s,omitempty"`
 }
 
 // SubscriptionTarget is the `SubscriptionTarget` the API declares.
 type SubscriptionTarget struct {
-	// Headers carries `headers`.
-	Headers any `json:"headers"`
-	// Method carries `method`.
-	Method string `json:"method"`
-	// Type carries `type`.
-	Type string `json:"type"`
-	// Url carries `url`.
-	Url string `json:"url"`
+	// AccessKeyId carries `access_key_id`: `sqs`: access key ID requests are signed with
+	AccessKeyId *string `json:"access_key_id,omitempty"`
+	// Exchange carries `exchange`: `amqp`: exchange messages are published to; empty for the default exchange
+	Exchange *string `json:"exchange,omitempty"`
+	// Headers carries `headers`: `http`: headers sent with every request
+	Headers any `json:"headers,omitempty"`
+	// Method carries `method`: `http`: HTTP method of the requests
+	Method *string `json:"method,omitempty"`
+	// Password carries `password`: `amqp`: password to log in to the broker with; write-only
+	Password *string `json:"password,omitempty"`
+	// QueueUrl carries `queue_url`: `sqs`: URL of the queue
+	QueueUrl *string `json:"queue_url,omitempty"`
+	// Region carries `region`: `sqs`: region requests are signed for
+	Region *string `json:"region,omitempty"`
+	// RoutingKey carries `routing_key`: `amqp`: routing key messages are published with
+	RoutingKey *string `json:"routing_key,omitempty"`
+	// SecretAccessKey carries `secret_access_key`: `sqs`: secret access key requests are signed with; write-only
+	SecretAccessKey *string `json:"secret_access_key,omitempty"`
+	// Type carries `type`: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+	Type SubscriptionTargetType `json:"type"`
+	// Url carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials
+	Url *string `json:"url,omitempty"`
+	// Username carries `username`: `amqp`: user name to log in to the broker with
+	Username *string `json:"username,omitempty"`
 }
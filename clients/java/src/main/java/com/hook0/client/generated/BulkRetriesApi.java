@@ -0,0 +1,96 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * What the API declares under `bulkRetries`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ */
+public final class BulkRetriesApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public BulkRetriesApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Cancel a bulk retry
+   *
+   * @param bulkRetryId carries `bulk_retry_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  public BulkRetry cancel(String bulkRetryId, String applicationId) {
+    String path = "/api/v1/bulk_retries/{bulk_retry_id}/cancel";
+    path = path.replace("{bulk_retry_id}", Wire.pathSegment(bulkRetryId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return Problems.readAnswer(transport.request("POST", path, query, null), BulkRetry::fromJson);
+  }
+
+  /**
+   * Create a bulk retry
+   *
+   * @param body the BulkRetryPost the operation reads
+   * @return what the API answered
+   */
+  public BulkRetry create(BulkRetryPost body) {
+    String path = "/api/v1/bulk_retries/";
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), BulkRetry::fromJson);
+  }
+
+  /**
+   * Count what a bulk retry would redeliver
+   *
+   * @param body the BulkRetryPost the operation reads
+   * @return what the API answered
+   */
+  public BulkRetryDryRun dryRun(BulkRetryPost body) {
+    String path = "/api/v1/bulk_retries/dry_run";
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), BulkRetryDryRun::fromJson);
+  }
+
+  /**
+   * Get a bulk retry
+   *
+   * @param bulkRetryId carries `bulk_retry_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  public BulkRetry get(String bulkRetryId, String applicationId) {
+    String path = "/api/v1/bulk_retries/{bulk_retry_id}";
+    path = path.replace("{bulk_retry_id}", Wire.pathSegment(bulkRetryId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return Problems.readAnswer(transport.request("GET", path, query, null), BulkRetry::fromJson);
+  }
+
+  /**
+   * List bulk retries
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  public List<BulkRetry> list(String applicationId) {
+    String path = "/api/v1/bulk_retries/";
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(BulkRetry::fromJson));
+  }
+}
@@ -0,0 +1,102 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+
+/**
+ * What the API declares under `bulkRetries`, issued through the transport it is handed.
+ *
+ * Every call hands back what the API will answer.
+ */
+public final class BulkRetriesAsyncApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public BulkRetriesAsyncApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Cancel a bulk retry
+   *
+   * @param bulkRetryId carries `bulk_retry_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API will answer
+   */
+  public CompletableFuture<BulkRetry> cancel(String bulkRetryId, String applicationId) {
+    String path = "/api/v1/bulk_retries/{bulk_retry_id}/cancel";
+    path = path.replace("{bulk_retry_id}", Wire.pathSegment(bulkRetryId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("POST", path, query, null)
+        .thenApply(Problems.readingWith(BulkRetry::fromJson));
+  }
+
+  /**
+   * Create a bulk retry
+   *
+   * @param body the BulkRetryPost the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<BulkRetry> create(BulkRetryPost body) {
+    String path = "/api/v1/bulk_retries/";
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(BulkRetry::fromJson));
+  }
+
+  /**
+   * Count what a bulk retry would redeliver
+   *
+   * @param body the BulkRetryPost the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<BulkRetryDryRun> dryRun(BulkRetryPost body) {
+    String path = "/api/v1/bulk_retries/dry_run";
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(BulkRetryDryRun::fromJson));
+  }
+
+  /**
+   * Get a bulk retry
+   *
+   * @param bulkRetryId carries `bulk_retry_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API will answer
+   */
+  public CompletableFuture<BulkRetry> get(String bulkRetryId, String applicationId) {
+    String path = "/api/v1/bulk_retries/{bulk_retry_id}";
+    path = path.replace("{bulk_retry_id}", Wire.pathSegment(bulkRetryId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(BulkRetry::fromJson));
+  }
+
+  /**
+   * List bulk retries
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API will answer
+   */
+  public CompletableFuture<List<BulkRetry>> list(String applicationId) {
+    String path = "/api/v1/bulk_retries/";
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(Wire.asList(BulkRetry::fromJson)));
+  }
+}
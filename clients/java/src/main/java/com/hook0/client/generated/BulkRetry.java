@@ -0,0 +1,108 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `BulkRetry` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ * @param bulkRetryId carries `bulk_retry_id`.
+ * @param createdAt carries `created_at`.
+ * @param eventTypeNames carries `event_type_names`.
+ * @param maxCreatedAt carries `max_created_at`.
+ * @param minCreatedAt carries `min_created_at`.
+ * @param processedCount carries `processed_count`: Number of pairs handled so far
+ * @param retriedCount carries `retried_count`: Number of request attempts created so far
+ * @param status carries `status`.
+ * @param totalCount carries `total_count`: Number of (event, subscription) pairs that matched when the bulk retry was
+ *     created
+ * @param updatedAt carries `updated_at`.
+ * @param cancelledAt carries `cancelled_at`, or nothing when the API answers none.
+ * @param completedAt carries `completed_at`, or nothing when the API answers none.
+ * @param eventId carries `event_id`, or nothing when the API answers none.
+ * @param subscriptionId carries `subscription_id`, or nothing when the API answers none.
+ */
+public record BulkRetry(
+    UUID applicationId,
+    UUID bulkRetryId,
+    OffsetDateTime createdAt,
+    List<String> eventTypeNames,
+    OffsetDateTime maxCreatedAt,
+    OffsetDateTime minCreatedAt,
+    Long processedCount,
+    Long retriedCount,
+    BulkRetryStatus status,
+    Long totalCount,
+    OffsetDateTime updatedAt,
+    OffsetDateTime cancelledAt,
+    OffsetDateTime completedAt,
+    UUID eventId,
+    UUID subscriptionId) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the BulkRetry the API declares
+   */
+  public static BulkRetry fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "BulkRetry");
+    return new BulkRetry(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "bulk_retry_id", Wire::asUuid),
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "event_type_names", Wire.asList(Wire::asText)),
+        Wire.read(fields, "max_created_at", Wire::asMoment),
+        Wire.read(fields, "min_created_at", Wire::asMoment),
+        Wire.read(fields, "processed_count", Wire::asLong),
+        Wire.read(fields, "retried_count", Wire::asLong),
+        Wire.read(fields, "status", BulkRetryStatus::fromJson),
+        Wire.read(fields, "total_count", Wire::asLong),
+        Wire.read(fields, "updated_at", Wire::asMoment),
+        Wire.maybe(fields, "cancelled_at", Wire::asMoment),
+        Wire.maybe(fields, "completed_at", Wire::asMoment),
+        Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "subscription_id", Wire::asUuid));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    out.put("bulk_retry_id", Wire.writeUuid(bulkRetryId));
+    out.put("created_at", Wire.writeMoment(createdAt));
+    out.put("event_type_names", eventTypeNames);
+    out.put("max_created_at", Wire.writeMoment(maxCreatedAt));
+    out.put("min_created_at", Wire.writeMoment(minCreatedAt));
+    out.put("processed_count", processedCount);
+    out.put("retried_count", retriedCount);
+    out.put("status", status.wireValue());
+    out.put("total_count", totalCount);
+    out.put("updated_at", Wire.writeMoment(updatedAt));
+    if (cancelledAt != null) {
+      out.put("cancelled_at", Wire.writeMoment(cancelledAt));
+    }
+    if (completedAt != null) {
+      out.put("completed_at", Wire.writeMoment(completedAt));
+    }
+    if (eventId != null) {
+      out.put("event_id", Wire.writeUuid(eventId));
+    }
+    if (subscriptionId != null) {
+      out.put("subscription_id", Wire.writeUuid(subscriptionId));
+    }
+    return out;
+  }
+}
@@ -0,0 +1,23 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+/**
+ * The API reported `BulkRetryAlreadyRunning`.
+ */
+public final class BulkRetryAlreadyRunningException extends ProblemException {
+
+  private static final long serialVersionUID = 1L;
+
+  /**
+   * Builds one out of what the API answered.
+   *
+   * @param status what the API answered under
+   * @param problem the document it answered, or {@code null} when none could be read
+   * @param detail what to say about the failure
+   */
+  public BulkRetryAlreadyRunningException(int status, Problem problem, String detail) {
+    super(status, problem, detail);
+  }
+}
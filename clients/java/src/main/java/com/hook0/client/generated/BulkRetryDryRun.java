@@ -0,0 +1,38 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `BulkRetryDryRun` the API declares.
+ *
+ * @param count carries `count`: Number of (event, subscription) pairs a bulk retry would redeliver now
+ */
+public record BulkRetryDryRun(Long count) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the BulkRetryDryRun the API declares
+   */
+  public static BulkRetryDryRun fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "BulkRetryDryRun");
+    return new BulkRetryDryRun(Wire.read(fields, "count", Wire::asLong));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("count", count);
+    return out;
+  }
+}
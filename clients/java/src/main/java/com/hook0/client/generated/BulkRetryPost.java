@@ -0,0 +1,80 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `BulkRetryPost` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ * @param eventId carries `event_id`, or nothing when the API answers none.
+ * @param eventTypeNames carries `event_type_names`, or nothing when the API answers none.
+ * @param maxCreatedAt carries `max_created_at`, or nothing when the API answers none.
+ * @param minCreatedAt carries `min_created_at`, or nothing when the API answers none.
+ * @param status carries `status`, or nothing when the API answers none: Redeliver request attempts that failed
+ *     (default) or that succeeded
+ * @param subscriptionId carries `subscription_id`, or nothing when the API answers none.
+ */
+public record BulkRetryPost(
+    UUID applicationId,
+    UUID eventId,
+    List<String> eventTypeNames,
+    OffsetDateTime maxCreatedAt,
+    OffsetDateTime minCreatedAt,
+    BulkRetryPostStatus status,
+    UUID subscriptionId) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the BulkRetryPost the API declares
+   */
+  public static BulkRetryPost fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "BulkRetryPost");
+    return new BulkRetryPost(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "event_type_names", Wire.asList(Wire::asText)),
+        Wire.maybe(fields, "max_created_at", Wire::asMoment),
+        Wire.maybe(fields, "min_created_at", Wire::asMoment),
+        Wire.maybe(fields, "status", BulkRetryPostStatus::fromJson),
+        Wire.maybe(fields, "subscription_id", Wire::asUuid));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    if (eventId != null) {
+      out.put("event_id", Wire.writeUuid(eventId));
+    }
+    if (eventTypeNames != null) {
+      out.put("event_type_names", eventTypeNames);
+    }
+    if (maxCreatedAt != null) {
+      out.put("max_created_at", Wire.writeMoment(maxCreatedAt));
+    }
+    if (minCreatedAt != null) {
+      out.put("min_created_at", Wire.writeMoment(minCreatedAt));
+    }
+    if (status != null) {
+      out.put("status", status.wireValue());
+    }
+    if (subscriptionId != null) {
+      out.put("subscription_id", Wire.writeUuid(subscriptionId));
+    }
+    return out;
+  }
+}
@@ -0,0 +1,50 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.DecodeException;
+import com.hook0.client.Wire;
+
+/**
+ * One of the values the API answers with.
+ */
+public enum BulkRetryPostStatus {
+  /** The API spells this one `failed`. */
+  FAILED("failed"),
+
+  /** The API spells this one `successful`. */
+  SUCCESSFUL("successful");
+
+  private final String wire;
+
+  BulkRetryPostStatus(String wire) {
+    this.wire = wire;
+  }
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the value it names
+   */
+  public static BulkRetryPostStatus fromJson(Object value) {
+    String named = Wire.asText(value);
+    for (BulkRetryPostStatus candidate : values()) {
+      if (candidate.wire.equals(named)) {
+        return candidate;
+      }
+    }
+    throw new DecodeException(
+        "`" + Wire.preview(named) + "` is not one of the values BulkRetryPostStatus declares");
+  }
+
+  /**
+   * The text this value travels as.
+   *
+   * @return what the API carries it as
+   */
+  public String wireValue() {
+    return wire;
+  }
+}
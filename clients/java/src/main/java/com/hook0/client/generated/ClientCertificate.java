@@ -0,0 +1,70 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `ClientCertificate` the API declares.
+ *
+ * @param certificatePem carries `certificate_pem`: Certificate chain, leaf certificate first
+ * @param createdAt carries `created_at`.
+ * @param fingerprintSha256 carries `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate, as hexadecimal
+ * @param notAfter carries `not_after`.
+ * @param notBefore carries `not_before`.
+ * @param subject carries `subject`.
+ * @param subscriptionId carries `subscription_id`.
+ * @param updatedAt carries `updated_at`.
+ */
+public record ClientCertificate(
+    String certificatePem,
+    OffsetDateTime createdAt,
+    String fingerprintSha256,
+    OffsetDateTime notAfter,
+    OffsetDateTime notBefore,
+    String subject,
+    UUID subscriptionId,
+    OffsetDateTime updatedAt) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the ClientCertificate the API declares
+   */
+  public static ClientCertificate fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "ClientCertificate");
+    return new ClientCertificate(
+        Wire.read(fields, "certificate_pem", Wire::asText),
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "fingerprint_sha256", Wire::asText),
+        Wire.read(fields, "not_after", Wire::asMoment),
+        Wire.read(fields, "not_before", Wire::asMoment),
+        Wire.read(fields, "subject", Wire::asText),
+        Wire.read(fields, "subscription_id", Wire::asUuid),
+        Wire.read(fields, "updated_at", Wire::asMoment));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("certificate_pem", certificatePem);
+    out.put("created_at", Wire.writeMoment(createdAt));
+    out.put("fingerprint_sha256", fingerprintSha256);
+    out.put("not_after", Wire.writeMoment(notAfter));
+    out.put("not_before", Wire.writeMoment(notBefore));
+    out.put("subject", subject);
+    out.put("subscription_id", Wire.writeUuid(subscriptionId));
+    out.put("updated_at", Wire.writeMoment(updatedAt));
+    return out;
+  }
+}
@@ -0,0 +1,43 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `ClientCertificatePut` the API declares.
+ *
+ * @param certificatePem carries `certificate_pem`: PEM-encoded certificate chain, leaf certificate first
+ * @param privateKeyPem carries `private_key_pem`: PEM-encoded unencrypted private key of the leaf certificate (PKCS#8,
+ *     PKCS#1 or SEC1)
+ */
+public record ClientCertificatePut(String certificatePem, String privateKeyPem) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the ClientCertificatePut the API declares
+   */
+  public static ClientCertificatePut fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "ClientCertificatePut");
+    return new ClientCertificatePut(
+        Wire.read(fields, "certificate_pem", Wire::asText),
+        Wire.read(fields, "private_key_pem", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("certificate_pem", certificatePem);
+    out.put("private_key_pem", privateKeyPem);
+    return out;
+  }
+}
@@ -0,0 +1,82 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+
+/**
+ * What the API declares under `clientCertificates`, issued through the transport it is handed.
+ *
+ * Every call hands back what the API will answer.
+ */
+public final class ClientCertificatesAsyncApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public ClientCertificatesAsyncApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Remove the client certificate of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return nothing, once the API has answered
+   */
+  public CompletableFuture<Void> delete(String subscriptionId, String applicationId) {
+    String path = "/api/v1/subscriptions/{subscription_id}/client_certificate";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("DELETE", path, query, null)
+        .thenAccept(Problems::checkAnswer);
+  }
+
+  /**
+   * Get the client certificate of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API will answer
+   */
+  public CompletableFuture<ClientCertificate> get(String subscriptionId, String applicationId) {
+    String path = "/api/v1/subscriptions/{subscription_id}/client_certificate";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(ClientCertificate::fromJson));
+  }
+
+  /**
+   * Set the client certificate of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @param body the ClientCertificatePut the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<ClientCertificate> update(
+      String subscriptionId,
+      String applicationId,
+      ClientCertificatePut body) {
+    String path = "/api/v1/subscriptions/{subscription_id}/client_certificate";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("PUT", path, query, body.toJson())
+        .thenApply(Problems.readingWith(ClientCertificate::fromJson));
+  }
+}
@@ -0,0 +1,79 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `DeadLetter` the API declares.
+ *
+ * @param createdAt carries `created_at`.
+ * @param deadLetterId carries `dead_letter_id`.
+ * @param event carries `event`.
+ * @param reason carries `reason`.
+ * @param requestAttemptId carries `request_attempt_id`: Last request attempt of the delivery
+ * @param retryCount carries `retry_count`.
+ * @param subscription carries `subscription`.
+ * @param httpResponseStatus carries `http_response_status`, or nothing when the API answers none.
+ * @param responseId carries `response_id`, or nothing when the API answers none: Response to the last request attempt,
+ *     if the target was reached
+ */
+public record DeadLetter(
+    OffsetDateTime createdAt,
+    UUID deadLetterId,
+    DeadLetterEvent event,
+    DeadLetterReason reason,
+    UUID requestAttemptId,
+    Integer retryCount,
+    DeadLetterSubscription subscription,
+    Integer httpResponseStatus,
+    UUID responseId) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the DeadLetter the API declares
+   */
+  public static DeadLetter fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "DeadLetter");
+    return new DeadLetter(
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "dead_letter_id", Wire::asUuid),
+        Wire.read(fields, "event", DeadLetterEvent::fromJson),
+        Wire.read(fields, "reason", DeadLetterReason::fromJson),
+        Wire.read(fields, "request_attempt_id", Wire::asUuid),
+        Wire.read(fields, "retry_count", Wire::asInteger),
+        Wire.read(fields, "subscription", DeadLetterSubscription::fromJson),
+        Wire.maybe(fields, "http_response_status", Wire::asInteger),
+        Wire.maybe(fields, "response_id", Wire::asUuid));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("created_at", Wire.writeMoment(createdAt));
+    out.put("dead_letter_id", Wire.writeUuid(deadLetterId));
+    out.put("event", event.toJson());
+    out.put("reason", reason.wireValue());
+    out.put("request_attempt_id", Wire.writeUuid(requestAttemptId));
+    out.put("retry_count", retryCount);
+    out.put("subscription", subscription.toJson());
+    if (httpResponseStatus != null) {
+      out.put("http_response_status", httpResponseStatus);
+    }
+    if (responseId != null) {
+      out.put("response_id", Wire.writeUuid(responseId));
+    }
+    return out;
+  }
+}
@@ -0,0 +1,45 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `DeadLetterDetails` the API declares.
+ *
+ * @param deadLetter carries `dead_letter`: Delivery an output worker gave up on
+ * @param lastResponse carries `last_response`, or nothing when the API answers none: Response to the last request
+ *     attempt, if the target was reached
+ */
+public record DeadLetterDetails(DeadLetterDetailsDeadLetter deadLetter, DeadLetterDetailsLastResponse lastResponse) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the DeadLetterDetails the API declares
+   */
+  public static DeadLetterDetails fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "DeadLetterDetails");
+    return new DeadLetterDetails(
+        Wire.read(fields, "dead_letter", DeadLetterDetailsDeadLetter::fromJson),
+        Wire.maybe(fields, "last_response", DeadLetterDetailsLastResponse::fromJson));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("dead_letter", deadLetter.toJson());
+    if (lastResponse != null) {
+      out.put("last_response", lastResponse.toJson());
+    }
+    return out;
+  }
+}
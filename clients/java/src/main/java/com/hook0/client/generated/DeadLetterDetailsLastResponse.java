@@ -0,0 +1,71 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `DeadLetterDetailsLastResponse` the API declares.
+ *
+ * @param responseId carries `response_id`.
+ * @param body_ carries `body`, or nothing when the API answers none.
+ * @param elapsedTimeMs carries `elapsed_time_ms`, or nothing when the API answers none.
+ * @param headers carries `headers`, or nothing when the API answers none.
+ * @param httpCode carries `http_code`, or nothing when the API answers none.
+ * @param responseErrorName carries `response_error_name`, or nothing when the API answers none.
+ */
+public record DeadLetterDetailsLastResponse(
+    UUID responseId,
+    String body_,
+    Integer elapsedTimeMs,
+    Map<String, String> headers,
+    Integer httpCode,
+    String responseErrorName) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the DeadLetterDetailsLastResponse the API declares
+   */
+  public static DeadLetterDetailsLastResponse fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "DeadLetterDetailsLastResponse");
+    return new DeadLetterDetailsLastResponse(
+        Wire.read(fields, "response_id", Wire::asUuid),
+        Wire.maybe(fields, "body", Wire::asText),
+        Wire.maybe(fields, "elapsed_time_ms", Wire::asInteger),
+        Wire.maybe(fields, "headers", Wire.asMap(Wire::asText)),
+        Wire.maybe(fields, "http_code", Wire::asInteger),
+        Wire.maybe(fields, "response_error_name", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("response_id", Wire.writeUuid(responseId));
+    if (body_ != null) {
+      out.put("body", body_);
+    }
+    if (elapsedTimeMs != null) {
+      out.put("elapsed_time_ms", elapsedTimeMs);
+    }
+    if (headers != null) {
+      out.put("headers", headers);
+    }
+    if (httpCode != null) {
+      out.put("http_code", httpCode);
+    }
+    if (responseErrorName != null) {
+      out.put("response_error_name", responseErrorName);
+    }
+    return out;
+  }
+}
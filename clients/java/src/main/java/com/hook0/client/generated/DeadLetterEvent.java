@@ -0,0 +1,43 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `DeadLetterEvent` the API declares.
+ *
+ * @param eventId carries `event_id`.
+ * @param eventTypeName carries `event_type_name`.
+ */
+public record DeadLetterEvent(UUID eventId, String eventTypeName) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the DeadLetterEvent the API declares
+   */
+  public static DeadLetterEvent fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "DeadLetterEvent");
+    return new DeadLetterEvent(
+        Wire.read(fields, "event_id", Wire::asUuid),
+        Wire.read(fields, "event_type_name", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("event_id", Wire.writeUuid(eventId));
+    out.put("event_type_name", eventTypeName);
+    return out;
+  }
+}
@@ -0,0 +1,56 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.DecodeException;
+import com.hook0.client.Wire;
+
+/**
+ * One of the values the API answers with.
+ */
+public enum DeadLetterReason {
+  /** The API spells this one `retries_exhausted`. */
+  RETRIES_EXHAUSTED("retries_exhausted"),
+
+  /** The API spells this one `signature_failed`. */
+  SIGNATURE_FAILED("signature_failed"),
+
+  /** The API spells this one `transform_failed`. */
+  TRANSFORM_FAILED("transform_failed"),
+
+  /** The API spells this one `permanent_failure`. */
+  PERMANENT_FAILURE("permanent_failure");
+
+  private final String wire;
+
+  DeadLetterReason(String wire) {
+    this.wire = wire;
+  }
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the value it names
+   */
+  public static DeadLetterReason fromJson(Object value) {
+    String named = Wire.asText(value);
+    for (DeadLetterReason candidate : values()) {
+      if (candidate.wire.equals(named)) {
+        return candidate;
+      }
+    }
+    throw new DecodeException(
+        "`" + Wire.preview(named) + "` is not one of the values DeadLetterReason declares");
+  }
+
+  /**
+   * The text this value travels as.
+   *
+   * @return what the API carries it as
+   */
+  public String wireValue() {
+    return wire;
+  }
+}
@@ -0,0 +1,45 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `DeadLetterSubscription` the API declares.
+ *
+ * @param subscriptionId carries `subscription_id`.
+ * @param description carries `description`, or nothing when the API answers none.
+ */
+public record DeadLetterSubscription(UUID subscriptionId, String description) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the DeadLetterSubscription the API declares
+   */
+  public static DeadLetterSubscription fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "DeadLetterSubscription");
+    return new DeadLetterSubscription(
+        Wire.read(fields, "subscription_id", Wire::asUuid),
+        Wire.maybe(fields, "description", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("subscription_id", Wire.writeUuid(subscriptionId));
+    if (description != null) {
+      out.put("description", description);
+    }
+    return out;
+  }
+}
@@ -0,0 +1,118 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * What the API declares under `deadLetters`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ */
+public final class DeadLettersApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public DeadLettersApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Delete a dead letter
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param applicationId carries `application_id`.
+   */
+  public void delete(String deadLetterId, String applicationId) {
+    String path = "/api/v1/dead_letters/{dead_letter_id}";
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    Problems.checkAnswer(transport.request("DELETE", path, query, null));
+  }
+
+  /**
+   * Get a dead letter by its ID
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  public DeadLetterDetails get(String deadLetterId, String applicationId) {
+    String path = "/api/v1/dead_letters/{dead_letter_id}";
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return Problems.readAnswer(transport.request("GET", path, query, null), DeadLetterDetails::fromJson);
+  }
+
+  /**
+   * List dead letters
+   *
+   * @param applicationId carries `application_id`.
+   * @param paginationCursor carries `pagination_cursor`, or nothing when the caller sends none.
+   * @param subscriptionId carries `subscription_id`, or nothing when the caller sends none.
+   * @return what the API answered
+   */
+  public List<DeadLetter> list(String applicationId, String paginationCursor, String subscriptionId) {
+    String path = "/api/v1/dead_letters/";
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    if (paginationCursor != null) {
+      query.add(new QueryParameter("pagination_cursor", Wire.queryValue(paginationCursor)));
+    }
+    if (subscriptionId != null) {
+      query.add(new QueryParameter("subscription_id", Wire.queryValue(subscriptionId)));
+    }
+    return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(DeadLetter::fromJson));
+  }
+
+  /**
+   * Purge dead letters
+   *
+   * @param body the PurgeDeadLetters the operation reads
+   * @return what the API answered
+   */
+  public PurgedDeadLetters purge(PurgeDeadLetters body) {
+    String path = "/api/v1/dead_letters/purge";
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), PurgedDeadLetters::fromJson);
+  }
+
+  /**
+   * Redeliver a dead letter
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param body the RedeliverDeadLetter the operation reads
+   * @return what the API answered
+   */
+  public RedeliveredDeadLetter redeliver(String deadLetterId, RedeliverDeadLetter body) {
+    String path = "/api/v1/dead_letters/{dead_letter_id}/redeliver";
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId));
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), RedeliveredDeadLetter::fromJson);
+  }
+
+  /**
+   * Redeliver dead letters
+   *
+   * @param body the RedeliverDeadLetters the operation reads
+   * @return what the API answered
+   */
+  public List<RedeliveredDeadLetter> redeliverMany(RedeliverDeadLetters body) {
+    String path = "/api/v1/dead_letters/redeliver";
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()),
+        Wire.asList(RedeliveredDeadLetter::fromJson));
+  }
+}
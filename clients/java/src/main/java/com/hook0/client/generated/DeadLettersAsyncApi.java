@@ -0,0 +1,128 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+
+/**
+ * What the API declares under `deadLetters`, issued through the transport it is handed.
+ *
+ * Every call hands back what the API will answer.
+ */
+public final class DeadLettersAsyncApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public DeadLettersAsyncApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Delete a dead letter
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param applicationId carries `application_id`.
+   * @return nothing, once the API has answered
+   */
+  public CompletableFuture<Void> delete(String deadLetterId, String applicationId) {
+    String path = "/api/v1/dead_letters/{dead_letter_id}";
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("DELETE", path, query, null)
+        .thenAccept(Problems::checkAnswer);
+  }
+
+  /**
+   * Get a dead letter by its ID
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API will answer
+   */
+  public CompletableFuture<DeadLetterDetails> get(String deadLetterId, String applicationId) {
+    String path = "/api/v1/dead_letters/{dead_letter_id}";
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(DeadLetterDetails::fromJson));
+  }
+
+  /**
+   * List dead letters
+   *
+   * @param applicationId carries `application_id`.
+   * @param paginationCursor carries `pagination_cursor`, or nothing when the caller sends none.
+   * @param subscriptionId carries `subscription_id`, or nothing when the caller sends none.
+   * @return what the API will answer
+   */
+  public CompletableFuture<List<DeadLetter>> list(
+      String applicationId,
+      String paginationCursor,
+      String subscriptionId) {
+    String path = "/api/v1/dead_letters/";
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    if (paginationCursor != null) {
+      query.add(new QueryParameter("pagination_cursor", Wire.queryValue(paginationCursor)));
+    }
+    if (subscriptionId != null) {
+      query.add(new QueryParameter("subscription_id", Wire.queryValue(subscriptionId)));
+    }
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(Wire.asList(DeadLetter::fromJson)));
+  }
+
+  /**
+   * Purge dead letters
+   *
+   * @param body the PurgeDeadLetters the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<PurgedDeadLetters> purge(PurgeDeadLetters body) {
+    String path = "/api/v1/dead_letters/purge";
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(PurgedDeadLetters::fromJson));
+  }
+
+  /**
+   * Redeliver a dead letter
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param body the RedeliverDeadLetter the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<RedeliveredDeadLetter> redeliver(String deadLetterId, RedeliverDeadLetter body) {
+    String path = "/api/v1/dead_letters/{dead_letter_id}/redeliver";
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(RedeliveredDeadLetter::fromJson));
+  }
+
+  /**
+   * Redeliver dead letters
+   *
+   * @param body the RedeliverDeadLetters the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<List<RedeliveredDeadLetter>> redeliverMany(RedeliverDeadLetters body) {
+    String path = "/api/v1/dead_letters/redeliver";
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(Wire.asList(RedeliveredDeadLetter::fromJson)));
+  }
+}
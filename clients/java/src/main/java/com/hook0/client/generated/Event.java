@@ -19,6 +19,13 @@ import java.util.UUID;
  * @param occurredAt carries `occurred_at`.
  * @param payloadContentType carries `payload_content_type`.
  * @param receivedAt carries `received_at`.
+ * @param cancelledAt carries `cancelled_at`, or nothing when the API answers none: Date the scheduled delivery of the
+ *     event was cancelled
+ * @param deliverAt carries `deliver_at`, or nothing when the API answers none: Date from which the event is delivered,
+ *     when it was scheduled for a later delivery
+ * @param eventTypeVersion carries `event_type_version`, or nothing when the API answers none.
+ * @param expiresAt carries `expires_at`, or nothing when the API answers none: Date after which the event is no longer
+ *     delivered
  * @param metadata carries `metadata`, or nothing when the API answers none.
  */
 public record Event(
@@ -29,6 +36,10 @@ public record Event(
     OffsetDateTime occurredAt,
     String payloadContentType,
     OffsetDateTime receivedAt,
+    OffsetDateTime cancelledAt,
+    OffsetDateTime deliverAt,
+    Integer eventTypeVersion,
+    OffsetDateTime expiresAt,
     Object metadata) {
 
   /**
@@ -47,6 +58,10 @@ public record Event(
         Wire.read(fields, "occurred_at", Wire::asMoment),
         Wire.read(fields, "payload_content_type", Wire::asText),
         Wire.read(fields, "received_at", Wire::asMoment),
+        Wire.maybe(fields, "cancelled_at", Wire::asMoment),
+        Wire.maybe(fields, "deliver_at", Wire::asMoment),
+        Wire.maybe(fields, "event_type_version", Wire::asInteger),
+        Wire.maybe(fields, "expires_at", Wire::asMoment),
         Wire.maybe(fields, "metadata", Wire::asJson));
   }
 
@@ -64,6 +79,18 @@ public record Event(
     out.put("occurred_at", Wire.writeMoment(occurredAt));
     out.put("payload_content_type", payloadContentType);
     out.put("received_at", Wire.writeMoment(receivedAt));
+    if (cancelledAt != null) {
+      out.put("cancelled_at", Wire.writeMoment(cancelledAt));
+    }
+    if (deliverAt != null) {
+      out.put("deliver_at", Wire.writeMoment(deliverAt));
+    }
+    if (eventTypeVersion != null) {
+      out.put("event_type_version", eventTypeVersion);
+    }
+    if (expiresAt != null) {
+      out.put("expires_at", Wire.writeMoment(expiresAt));
+    }
     if (metadata != null) {
       out.put("metadata", metadata);
     }
@@ -21,8 +21,17 @@ import java.util.UUID;
  *     characters (512 KiB base64-encoded).
  * @param payloadContentType carries `payload_content_type`: Content type of the payload. Valid values: text/plain,
  *     application/json, application/octet-stream+base64. Length: 1-100 characters.
+ * @param deliverAt carries `deliver_at`, or nothing when the API answers none: Optional date from which the event is
+ *     delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far
+ *     in the future as the events retention of the appl
  * @param eventId carries `event_id`, or nothing when the API answers none: Optional unique identifier for this event
  *     (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
+ * @param eventTypeVersion carries `event_type_version`, or nothing when the API answers none: Optional version of the
+ *     event type the payload follows. Subscriptions pinned to another version of the event type do not receive the
+ *     event.
+ * @param expiresAt carries `expires_at`, or nothing when the API answers none: Optional date after which the event is
+ *     no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the
+ *     default time to live of its event type, counted f
  * @param metadata carries `metadata`, or nothing when the API answers none: Optional metadata key-value pairs
  *     associated with the event.
  */
@@ -33,7 +42,10 @@ public record EventPost(
     OffsetDateTime occurredAt,
     String payload,
     String payloadContentType,
+    OffsetDateTime deliverAt,
     UUID eventId,
+    Integer eventTypeVersion,
+    OffsetDateTime expiresAt,
     Map<String, String> metadata) {
 
   /**
@@ -51,7 +63,10 @@ public record EventPost(
         Wire.read(fields, "occurred_at", Wire::asMoment),
         Wire.read(fields, "payload", Wire::asText),
         Wire.read(fields, "payload_content_type", Wire::asText),
+        Wire.maybe(fields, "deliver_at", Wire::asMoment),
         Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "event_type_version", Wire::asInteger),
+        Wire.maybe(fields, "expires_at", Wire::asMoment),
         Wire.maybe(fields, "metadata", Wire.asMap(Wire::asText)));
   }
 
@@ -68,9 +83,18 @@ public record EventPost(
     out.put("occurred_at", Wire.writeMoment(occurredAt));
     out.put("payload", payload);
     out.put("payload_content_type", payloadContentType);
+    if (deliverAt != null) {
+      out.put("deliver_at", Wire.writeMoment(deliverAt));
+    }
     if (eventId != null) {
       out.put("event_id", Wire.writeUuid(eventId));
     }
+    if (eventTypeVersion != null) {
+      out.put("event_type_version", eventTypeVersion);
+    }
+    if (expiresAt != null) {
+      out.put("expires_at", Wire.writeMoment(expiresAt));
+    }
     if (metadata != null) {
       out.put("metadata", metadata);
     }
@@ -5,17 +5,31 @@ package com.hook0.client.generated;
 
 import com.hook0.client.Wire;
 import java.util.LinkedHashMap;
+import java.util.List;
 import java.util.Map;
 
 /**
  * The `EventType` the API declares.
  *
  * @param eventTypeName carries `event_type_name`.
+ * @param payloadSchemaMode carries `payload_schema_mode`.
  * @param resourceTypeName carries `resource_type_name`.
  * @param serviceName carries `service_name`.
  * @param verbName carries `verb_name`.
+ * @param versions carries `versions`.
+ * @param defaultTtlS carries `default_ttl_s`, or nothing when the API answers none: Time to live in seconds of the
+ *     events of this type that do not give an expiry date
+ * @param payloadSchema carries `payload_schema`, or nothing when the API answers none.
  */
-public record EventType(String eventTypeName, String resourceTypeName, String serviceName, String verbName) {
+public record EventType(
+    String eventTypeName,
+    EventTypePayloadSchemaMode payloadSchemaMode,
+    String resourceTypeName,
+    String serviceName,
+    String verbName,
+    List<EventTypeVersions> versions,
+    Integer defaultTtlS,
+    Object payloadSchema) {
 
   /**
    * Reads one out of what the API answered.
@@ -27,9 +41,13 @@ public record EventType(String eventTypeName, String resourceTypeName, String se
     Map<String, Object> fields = Wire.asFields(value, "EventType");
     return new EventType(
         Wire.read(fields, "event_type_name", Wire::asText),
+        Wire.read(fields, "payload_schema_mode", EventTypePayloadSchemaMode::fromJson),
         Wire.read(fields, "resource_type_name", Wire::asText),
         Wire.read(fields, "service_name", Wire::asText),
-        Wire.read(fields, "verb_name", Wire::asText));
+        Wire.read(fields, "verb_name", Wire::asText),
+        Wire.read(fields, "versions", Wire.asList(EventTypeVersions::fromJson)),
+        Wire.maybe(fields, "default_ttl_s", Wire::asInteger),
+        Wire.maybe(fields, "payload_schema", Wire::asJson));
   }
 
   /**
@@ -40,9 +58,17 @@ public record EventType(String eventTypeName, String resourceTypeName, String se
   public Map<String, Object> toJson() {
     Map<String, Object> out = new LinkedHashMap<>();
     out.put("event_type_name", eventTypeName);
+    out.put("payload_schema_mode", payloadSchemaMode.wireValue());
     out.put("resource_type_name", resourceTypeName);
     out.put("service_name", serviceName);
     out.put("verb_name", verbName);
+    out.put("versions", Wire.writeList(versions, EventTypeVersions::toJson));
+    if (defaultTtlS != null) {
+      out.put("default_ttl_s", defaultTtlS);
+    }
+    if (payloadSchema != null) {
+      out.put("payload_schema", payloadSchema);
+    }
     return out;
   }
 }
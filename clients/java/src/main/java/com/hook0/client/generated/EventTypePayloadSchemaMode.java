@@ -0,0 +1,53 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.DecodeException;
+import com.hook0.client.Wire;
+
+/**
+ * One of the values the API answers with.
+ */
+public enum EventTypePayloadSchemaMode {
+  /** The API spells this one `enforce`. */
+  ENFORCE("enforce"),
+
+  /** The API spells this one `warn`. */
+  WARN("warn"),
+
+  /** The API spells this one `off`. */
+  OFF("off");
+
+  private final String wire;
+
+  EventTypePayloadSchemaMode(String wire) {
+    this.wire = wire;
+  }
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the value it names
+   */
+  public static EventTypePayloadSchemaMode fromJson(Object value) {
+    String named = Wire.asText(value);
+    for (EventTypePayloadSchemaMode candidate : values()) {
+      if (candidate.wire.equals(named)) {
+        return candidate;
+      }
+    }
+    throw new DecodeException(
+        "`" + Wire.preview(named) + "` is not one of the values EventTypePayloadSchemaMode declares");
+  }
+
+  /**
+   * The text this value travels as.
+   *
+   * @return what the API carries it as
+   */
+  public String wireValue() {
+    return wire;
+  }
+}
@@ -15,8 +15,21 @@ import java.util.UUID;
  * @param resourceType carries `resource_type`.
  * @param service carries `service`.
  * @param verb carries `verb`.
+ * @param defaultTtlS carries `default_ttl_s`, or nothing when the API answers none: Time to live in seconds of the
+ *     events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+ * @param payloadSchema carries `payload_schema`, or nothing when the API answers none: JSON Schema the payloads of the
+ *     `application/json` events of this type must match; remote references are not supported
+ * @param payloadSchemaMode carries `payload_schema_mode`, or nothing when the API answers none: How the payload schema
+ *     is applied at ingestion (default: `enforce`)
  */
-public record EventTypePost(UUID applicationId, String resourceType, String service, String verb) {
+public record EventTypePost(
+    UUID applicationId,
+    String resourceType,
+    String service,
+    String verb,
+    Integer defaultTtlS,
+    Object payloadSchema,
+    EventTypePostPayloadSchemaMode payloadSchemaMode) {
 
   /**
    * Reads one out of what the API answered.
@@ -30,7 +43,10 @@ public record EventTypePost(UUID applicationId, String resourceType, String serv
         Wire.read(fields, "application_id", Wire::asUuid),
         Wire.read(fields, "resource_type", Wire::asText),
         Wire.read(fields, "service", Wire::asText),
-        Wire.read(fields, "verb", Wire::asText));
+        Wire.read(fields, "verb", Wire::asText),
+        Wire.maybe(fields, "default_ttl_s", Wire::asInteger),
+        Wire.maybe(fields, "payload_schema", Wire::asJson),
+        Wire.maybe(fields, "payload_schema_mode", EventTypePostPayloadSchemaMode::fromJson));
   }
 
   /**
@@ -44,6 +60,15 @@ public record EventTypePost(UUID applicationId, String resourceType, String serv
     out.put("resource_type", resourceType);
     out.put("service", service);
     out.put("verb", verb);
+    if (defaultTtlS != null) {
+      out.put("default_ttl_s", defaultTtlS);
+    }
+    if (payloadSchema != null) {
+      out.put("payload_schema", payloadSchema);
+    }
+    if (payloadSchemaMode != null) {
+      out.put("payload_schema_mode", payloadSchemaMode.wireValue());
+    }
     return out;
   }
 }
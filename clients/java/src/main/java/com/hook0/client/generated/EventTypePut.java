@@ -0,0 +1,62 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `EventTypePut` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ * @param defaultTtlS carries `default_ttl_s`, or nothing when the API answers none: Time to live in seconds of the
+ *     events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+ * @param payloadSchema carries `payload_schema`, or nothing when the API answers none: JSON Schema the payloads of the
+ *     `application/json` events of this type must match; remote references are not supported
+ * @param payloadSchemaMode carries `payload_schema_mode`, or nothing when the API answers none: How the payload schema
+ *     is applied at ingestion (default: `enforce`)
+ */
+public record EventTypePut(
+    UUID applicationId,
+    Integer defaultTtlS,
+    Object payloadSchema,
+    EventTypePutPayloadSchemaMode payloadSchemaMode) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the EventTypePut the API declares
+   */
+  public static EventTypePut fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "EventTypePut");
+    return new EventTypePut(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.maybe(fields, "default_ttl_s", Wire::asInteger),
+        Wire.maybe(fields, "payload_schema", Wire::asJson),
+        Wire.maybe(fields, "payload_schema_mode", EventTypePutPayloadSchemaMode::fromJson));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    if (defaultTtlS != null) {
+      out.put("default_ttl_s", defaultTtlS);
+    }
+    if (payloadSchema != null) {
+      out.put("payload_schema", payloadSchema);
+    }
+    if (payloadSchemaMode != null) {
+      out.put("payload_schema_mode", payloadSchemaMode.wireValue());
+    }
+    return out;
+  }
+}
@@ -0,0 +1,65 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `EventTypeVersion` the API declares.
+ *
+ * @param createdAt carries `created_at`.
+ * @param version carries `version`.
+ * @param deprecatedAt carries `deprecated_at`, or nothing when the API answers none.
+ * @param payloadSchema carries `payload_schema`, or nothing when the API answers none: JSON Schema the payloads of the
+ *     events declaring this version must match, instead of the one of the event type
+ * @param sunsetAt carries `sunset_at`, or nothing when the API answers none: Date from which the events declaring this
+ *     version are refused
+ */
+public record EventTypeVersion(
+    OffsetDateTime createdAt,
+    Integer version,
+    OffsetDateTime deprecatedAt,
+    Object payloadSchema,
+    OffsetDateTime sunsetAt) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the EventTypeVersion the API declares
+   */
+  public static EventTypeVersion fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "EventTypeVersion");
+    return new EventTypeVersion(
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "version", Wire::asInteger),
+        Wire.maybe(fields, "deprecated_at", Wire::asMoment),
+        Wire.maybe(fields, "payload_schema", Wire::asJson),
+        Wire.maybe(fields, "sunset_at", Wire::asMoment));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("created_at", Wire.writeMoment(createdAt));
+    out.put("version", version);
+    if (deprecatedAt != null) {
+      out.put("deprecated_at", Wire.writeMoment(deprecatedAt));
+    }
+    if (payloadSchema != null) {
+      out.put("payload_schema", payloadSchema);
+    }
+    if (sunsetAt != null) {
+      out.put("sunset_at", Wire.writeMoment(sunsetAt));
+    }
+    return out;
+  }
+}
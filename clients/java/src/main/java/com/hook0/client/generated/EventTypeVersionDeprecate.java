@@ -0,0 +1,50 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `EventTypeVersionDeprecate` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ * @param version carries `version`.
+ * @param sunsetAt carries `sunset_at`, or nothing when the API answers none: Date from which the events declaring this
+ *     version are refused; none keeps accepting them
+ */
+public record EventTypeVersionDeprecate(UUID applicationId, Integer version, OffsetDateTime sunsetAt) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the EventTypeVersionDeprecate the API declares
+   */
+  public static EventTypeVersionDeprecate fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "EventTypeVersionDeprecate");
+    return new EventTypeVersionDeprecate(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "version", Wire::asInteger),
+        Wire.maybe(fields, "sunset_at", Wire::asMoment));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    out.put("version", version);
+    if (sunsetAt != null) {
+      out.put("sunset_at", Wire.writeMoment(sunsetAt));
+    }
+    return out;
+  }
+}
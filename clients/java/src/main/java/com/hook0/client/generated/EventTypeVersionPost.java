@@ -0,0 +1,50 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `EventTypeVersionPost` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ * @param version carries `version`.
+ * @param payloadSchema carries `payload_schema`, or nothing when the API answers none: JSON Schema the payloads of the
+ *     `application/json` events declaring this version must match, instead of the one of the event type; remote
+ *     references are not supported
+ */
+public record EventTypeVersionPost(UUID applicationId, Integer version, Object payloadSchema) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the EventTypeVersionPost the API declares
+   */
+  public static EventTypeVersionPost fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "EventTypeVersionPost");
+    return new EventTypeVersionPost(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "version", Wire::asInteger),
+        Wire.maybe(fields, "payload_schema", Wire::asJson));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    out.put("version", version);
+    if (payloadSchema != null) {
+      out.put("payload_schema", payloadSchema);
+    }
+    return out;
+  }
+}
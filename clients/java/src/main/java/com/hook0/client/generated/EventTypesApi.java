@@ -39,6 +39,20 @@ public final class EventTypesApi {
     return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), EventType::fromJson);
   }
 
+  /**
+   * Create a new event type version
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypeVersionPost the operation reads
+   * @return what the API answered
+   */
+  public EventTypeVersion createVersion(String eventTypeName, EventTypeVersionPost body) {
+    String path = "/api/v1/event_types/{event_type_name}/versions";
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName));
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), EventTypeVersion::fromJson);
+  }
+
   /**
    * Delete an event type
    *
@@ -53,6 +67,20 @@ public final class EventTypesApi {
     Problems.checkAnswer(transport.request("DELETE", path, query, null));
   }
 
+  /**
+   * Deprecate an event type version
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypeVersionDeprecate the operation reads
+   * @return what the API answered
+   */
+  public EventTypeVersion deprecateVersion(String eventTypeName, EventTypeVersionDeprecate body) {
+    String path = "/api/v1/event_types/{event_type_name}/deprecate";
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName));
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), EventTypeVersion::fromJson);
+  }
+
   /**
    * Get an event type by its name
    *
@@ -80,4 +108,18 @@ public final class EventTypesApi {
     query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
     return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(EventType::fromJson));
   }
+
+  /**
+   * Update an event type
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypePut the operation reads
+   * @return what the API answered
+   */
+  public EventType update(String eventTypeName, EventTypePut body) {
+    String path = "/api/v1/event_types/{event_type_name}";
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName));
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("PUT", path, query, body.toJson()), EventType::fromJson);
+  }
 }
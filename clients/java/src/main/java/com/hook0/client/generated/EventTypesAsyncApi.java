@@ -41,6 +41,21 @@ public final class EventTypesAsyncApi {
         .thenApply(Problems.readingWith(EventType::fromJson));
   }
 
+  /**
+   * Create a new event type version
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypeVersionPost the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<EventTypeVersion> createVersion(String eventTypeName, EventTypeVersionPost body) {
+    String path = "/api/v1/event_types/{event_type_name}/versions";
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(EventTypeVersion::fromJson));
+  }
+
   /**
    * Delete an event type
    *
@@ -57,6 +72,21 @@ public final class EventTypesAsyncApi {
         .thenAccept(Problems::checkAnswer);
   }
 
+  /**
+   * Deprecate an event type version
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypeVersionDeprecate the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<EventTypeVersion> deprecateVersion(String eventTypeName, EventTypeVersionDeprecate body) {
+    String path = "/api/v1/event_types/{event_type_name}/deprecate";
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(EventTypeVersion::fromJson));
+  }
+
   /**
    * Get an event type by its name
    *
@@ -86,4 +116,19 @@ public final class EventTypesAsyncApi {
     return transport.requestAsync("GET", path, query, null)
         .thenApply(Problems.readingWith(Wire.asList(EventType::fromJson)));
   }
+
+  /**
+   * Update an event type
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypePut the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<EventType> update(String eventTypeName, EventTypePut body) {
+    String path = "/api/v1/event_types/{event_type_name}";
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("PUT", path, query, body.toJson())
+        .thenApply(Problems.readingWith(EventType::fromJson));
+  }
 }
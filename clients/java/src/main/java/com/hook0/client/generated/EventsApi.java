@@ -27,6 +27,19 @@ public final class EventsApi {
     this.transport = transport;
   }
 
+  /**
+   * Cancel a scheduled event
+   *
+   * @param eventId carries `event_id`.
+   * @param body the CancelEvent the operation reads
+   */
+  public void cancel(String eventId, CancelEvent body) {
+    String path = "/api/v1/events/{event_id}/cancel";
+    path = path.replace("{event_id}", Wire.pathSegment(eventId));
+    List<QueryParameter> query = new ArrayList<>();
+    Problems.checkAnswer(transport.request("POST", path, query, body.toJson()));
+  }
+
   /**
    * Get an event by its ID
    *
@@ -54,16 +67,33 @@ public final class EventsApi {
     return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), IngestedEvent::fromJson);
   }
 
+  /**
+   * Ingest a batch of events
+   *
+   * @param body the EventsBatchPost the operation reads
+   * @return what the API answered
+   */
+  public IngestedEventsBatch ingestBatch(EventsBatchPost body) {
+    String path = "/api/v1/events/batch";
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), IngestedEventsBatch::fromJson);
+  }
+
   /**
    * List latest events
    *
    * @param applicationId carries `application_id`.
+   * @param scheduled carries `scheduled`, or nothing when the caller sends none: Only list the events scheduled for a
+   *     later delivery that was not cancelled, soonest first
    * @return what the API answered
    */
-  public List<Event> list(String applicationId) {
+  public List<Event> list(String applicationId, Boolean scheduled) {
     String path = "/api/v1/events/";
     List<QueryParameter> query = new ArrayList<>();
     query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    if (scheduled != null) {
+      query.add(new QueryParameter("scheduled", Wire.queryValue(scheduled)));
+    }
     return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(Event::fromJson));
   }
 
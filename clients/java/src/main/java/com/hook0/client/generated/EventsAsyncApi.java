@@ -28,6 +28,21 @@ public final class EventsAsyncApi {
     this.transport = transport;
   }
 
+  /**
+   * Cancel a scheduled event
+   *
+   * @param eventId carries `event_id`.
+   * @param body the CancelEvent the operation reads
+   * @return nothing, once the API has answered
+   */
+  public CompletableFuture<Void> cancel(String eventId, CancelEvent body) {
+    String path = "/api/v1/events/{event_id}/cancel";
+    path = path.replace("{event_id}", Wire.pathSegment(eventId));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenAccept(Problems::checkAnswer);
+  }
+
   /**
    * Get an event by its ID
    *
@@ -57,16 +72,34 @@ public final class EventsAsyncApi {
         .thenApply(Problems.readingWith(IngestedEvent::fromJson));
   }
 
+  /**
+   * Ingest a batch of events
+   *
+   * @param body the EventsBatchPost the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<IngestedEventsBatch> ingestBatch(EventsBatchPost body) {
+    String path = "/api/v1/events/batch";
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(IngestedEventsBatch::fromJson));
+  }
+
   /**
    * List latest events
    *
    * @param applicationId carries `application_id`.
+   * @param scheduled carries `scheduled`, or nothing when the caller sends none: Only list the events scheduled for a
+   *     later delivery that was not cancelled, soonest first
    * @return what the API will answer
    */
-  public CompletableFuture<List<Event>> list(String applicationId) {
+  public CompletableFuture<List<Event>> list(String applicationId, Boolean scheduled) {
     String path = "/api/v1/events/";
     List<QueryParameter> query = new ArrayList<>();
     query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    if (scheduled != null) {
+      query.add(new QueryParameter("scheduled", Wire.queryValue(scheduled)));
+    }
     return transport.requestAsync("GET", path, query, null)
         .thenApply(Problems.readingWith(Wire.asList(Event::fromJson)));
   }
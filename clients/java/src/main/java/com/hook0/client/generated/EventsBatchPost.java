@@ -0,0 +1,46 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `EventsBatchPost` the API declares.
+ *
+ * @param applicationId carries `application_id`: UUID of the application the events belong to. Events of the batch that
+ *     carry another application ID are not ingested.
+ * @param events carries `events`: Events to ingest. Length: 1-100 events; the whole request body is also subject to the
+ *     usual request size limit.
+ */
+public record EventsBatchPost(UUID applicationId, List<EventsBatchPostEvents> events) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the EventsBatchPost the API declares
+   */
+  public static EventsBatchPost fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "EventsBatchPost");
+    return new EventsBatchPost(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "events", Wire.asList(EventsBatchPostEvents::fromJson)));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    out.put("events", Wire.writeList(events, EventsBatchPostEvents::toJson));
+    return out;
+  }
+}
@@ -0,0 +1,103 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `EventsBatchPostEvents` the API declares.
+ *
+ * @param applicationId carries `application_id`: UUID of the application this event belongs to.
+ * @param eventType carries `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200
+ *     characters.
+ * @param labels carries `labels`: Labels for event filtering and routing to subscriptions.
+ * @param occurredAt carries `occurred_at`: Timestamp when the event occurred.
+ * @param payload carries `payload`: The event payload. For binary content, use base64 encoding. Max length: 699050
+ *     characters (512 KiB base64-encoded).
+ * @param payloadContentType carries `payload_content_type`: Content type of the payload. Valid values: text/plain,
+ *     application/json, application/octet-stream+base64. Length: 1-100 characters.
+ * @param deliverAt carries `deliver_at`, or nothing when the API answers none: Optional date from which the event is
+ *     delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far
+ *     in the future as the events retention of the appl
+ * @param eventId carries `event_id`, or nothing when the API answers none: Optional unique identifier for this event
+ *     (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
+ * @param eventTypeVersion carries `event_type_version`, or nothing when the API answers none: Optional version of the
+ *     event type the payload follows. Subscriptions pinned to another version of the event type do not receive the
+ *     event.
+ * @param expiresAt carries `expires_at`, or nothing when the API answers none: Optional date after which the event is
+ *     no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the
+ *     default time to live of its event type, counted f
+ * @param metadata carries `metadata`, or nothing when the API answers none: Optional metadata key-value pairs
+ *     associated with the event.
+ */
+public record EventsBatchPostEvents(
+    UUID applicationId,
+    String eventType,
+    Map<String, String> labels,
+    OffsetDateTime occurredAt,
+    String payload,
+    String payloadContentType,
+    OffsetDateTime deliverAt,
+    UUID eventId,
+    Integer eventTypeVersion,
+    OffsetDateTime expiresAt,
+    Map<String, String> metadata) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the EventsBatchPostEvents the API declares
+   */
+  public static EventsBatchPostEvents fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "EventsBatchPostEvents");
+    return new EventsBatchPostEvents(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "event_type", Wire::asText),
+        Wire.read(fields, "labels", Wire.asMap(Wire::asText)),
+        Wire.read(fields, "occurred_at", Wire::asMoment),
+        Wire.read(fields, "payload", Wire::asText),
+        Wire.read(fields, "payload_content_type", Wire::asText),
+        Wire.maybe(fields, "deliver_at", Wire::asMoment),
+        Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "event_type_version", Wire::asInteger),
+        Wire.maybe(fields, "expires_at", Wire::asMoment),
+        Wire.maybe(fields, "metadata", Wire.asMap(Wire::asText)));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    out.put("event_type", eventType);
+    out.put("labels", labels);
+    out.put("occurred_at", Wire.writeMoment(occurredAt));
+    out.put("payload", payload);
+    out.put("payload_content_type", payloadContentType);
+    if (deliverAt != null) {
+      out.put("deliver_at", Wire.writeMoment(deliverAt));
+    }
+    if (eventId != null) {
+      out.put("event_id", Wire.writeUuid(eventId));
+    }
+    if (eventTypeVersion != null) {
+      out.put("event_type_version", eventTypeVersion);
+    }
+    if (expiresAt != null) {
+      out.put("expires_at", Wire.writeMoment(expiresAt));
+    }
+    if (metadata != null) {
+      out.put("metadata", metadata);
+    }
+    return out;
+  }
+}
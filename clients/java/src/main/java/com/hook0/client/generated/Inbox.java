@@ -0,0 +1,46 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * The `Inbox` the API declares.
+ *
+ * @param messages carries `messages`.
+ * @param nextCursor carries `next_cursor`, or nothing when the API answers none: Pass it as `cursor` to the next pull
+ *     to continue after the last message of this one
+ */
+public record Inbox(List<InboxMessages> messages, String nextCursor) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the Inbox the API declares
+   */
+  public static Inbox fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "Inbox");
+    return new Inbox(
+        Wire.read(fields, "messages", Wire.asList(InboxMessages::fromJson)),
+        Wire.maybe(fields, "next_cursor", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("messages", Wire.writeList(messages, InboxMessages::toJson));
+    if (nextCursor != null) {
+      out.put("next_cursor", nextCursor);
+    }
+    return out;
+  }
+}
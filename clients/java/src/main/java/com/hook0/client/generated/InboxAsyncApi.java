@@ -0,0 +1,96 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+
+/**
+ * What the API declares under `inbox`, issued through the transport it is handed.
+ *
+ * Every call hands back what the API will answer.
+ */
+public final class InboxAsyncApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public InboxAsyncApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Acknowledge events pulled from an inbox
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param body the InboxAck the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<InboxSettlement> ack(String subscriptionId, InboxAck body) {
+    String path = "/api/v1/inbox/{subscription_id}/ack";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(InboxSettlement::fromJson));
+  }
+
+  /**
+   * Reject events pulled from an inbox
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param body the InboxNack the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<InboxSettlement> nack(String subscriptionId, InboxNack body) {
+    String path = "/api/v1/inbox/{subscription_id}/nack";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(InboxSettlement::fromJson));
+  }
+
+  /**
+   * Pull events from an inbox
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @param cursor carries `cursor`, or nothing when the caller sends none: Only lease events after this cursor,
+   *     returned by a previous pull
+   * @param limit carries `limit`, or nothing when the caller sends none: Maximum number of events to lease (10 by
+   *     default)
+   * @param visibilityTimeoutS carries `visibility_timeout_s`, or nothing when the caller sends none: For how long in
+   *     seconds the leased events are not offered again (30 by default)
+   * @return what the API will answer
+   */
+  public CompletableFuture<Inbox> pull(
+      String subscriptionId,
+      String applicationId,
+      String cursor,
+      Long limit,
+      Long visibilityTimeoutS) {
+    String path = "/api/v1/inbox/{subscription_id}";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    if (cursor != null) {
+      query.add(new QueryParameter("cursor", Wire.queryValue(cursor)));
+    }
+    if (limit != null) {
+      query.add(new QueryParameter("limit", Wire.queryValue(limit)));
+    }
+    if (visibilityTimeoutS != null) {
+      query.add(new QueryParameter("visibility_timeout_s", Wire.queryValue(visibilityTimeoutS)));
+    }
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(Inbox::fromJson));
+  }
+}
@@ -0,0 +1,84 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `InboxMessages` the API declares.
+ *
+ * @param eventId carries `event_id`.
+ * @param eventTypeName carries `event_type_name`.
+ * @param labels carries `labels`.
+ * @param leasedUntil carries `leased_until`: When the event is offered again if it was neither acknowledged nor
+ *     rejected
+ * @param occurredAt carries `occurred_at`.
+ * @param payload carries `payload`: Base64-encoded
+ * @param payloadContentType carries `payload_content_type`.
+ * @param receipt carries `receipt`: Identifies this lease of the request attempt; a later lease of the same attempt
+ *     gets another one
+ * @param receivedAt carries `received_at`.
+ * @param requestAttemptId carries `request_attempt_id`.
+ * @param retryCount carries `retry_count`.
+ */
+public record InboxMessages(
+    UUID eventId,
+    String eventTypeName,
+    Object labels,
+    OffsetDateTime leasedUntil,
+    OffsetDateTime occurredAt,
+    String payload,
+    String payloadContentType,
+    UUID receipt,
+    OffsetDateTime receivedAt,
+    UUID requestAttemptId,
+    Integer retryCount) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the InboxMessages the API declares
+   */
+  public static InboxMessages fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "InboxMessages");
+    return new InboxMessages(
+        Wire.read(fields, "event_id", Wire::asUuid),
+        Wire.read(fields, "event_type_name", Wire::asText),
+        Wire.read(fields, "labels", Wire::asJson),
+        Wire.read(fields, "leased_until", Wire::asMoment),
+        Wire.read(fields, "occurred_at", Wire::asMoment),
+        Wire.read(fields, "payload", Wire::asText),
+        Wire.read(fields, "payload_content_type", Wire::asText),
+        Wire.read(fields, "receipt", Wire::asUuid),
+        Wire.read(fields, "received_at", Wire::asMoment),
+        Wire.read(fields, "request_attempt_id", Wire::asUuid),
+        Wire.read(fields, "retry_count", Wire::asInteger));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("event_id", Wire.writeUuid(eventId));
+    out.put("event_type_name", eventTypeName);
+    out.put("labels", labels);
+    out.put("leased_until", Wire.writeMoment(leasedUntil));
+    out.put("occurred_at", Wire.writeMoment(occurredAt));
+    out.put("payload", payload);
+    out.put("payload_content_type", payloadContentType);
+    out.put("receipt", Wire.writeUuid(receipt));
+    out.put("received_at", Wire.writeMoment(receivedAt));
+    out.put("request_attempt_id", Wire.writeUuid(requestAttemptId));
+    out.put("retry_count", retryCount);
+    return out;
+  }
+}
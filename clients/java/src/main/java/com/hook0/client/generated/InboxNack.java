@@ -0,0 +1,57 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `InboxNack` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ * @param receipts carries `receipts`: Receipts of the leased events
+ * @param retry carries `retry`, or nothing when the API answers none: Whether the events are offered again, as new
+ *     request attempts (true by default); they are not beyond the maximum number of retries of the subscription, or of
+ *     this instance if it has none
+ * @param retryInS carries `retry_in_s`, or nothing when the API answers none: Delay in seconds before the events are
+ *     offered again (0 by default)
+ */
+public record InboxNack(UUID applicationId, List<UUID> receipts, Boolean retry, Integer retryInS) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the InboxNack the API declares
+   */
+  public static InboxNack fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "InboxNack");
+    return new InboxNack(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "receipts", Wire.asList(Wire::asUuid)),
+        Wire.maybe(fields, "retry", Wire::asBoolean),
+        Wire.maybe(fields, "retry_in_s", Wire::asInteger));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    out.put("receipts", Wire.writeList(receipts, Wire::writeUuid));
+    if (retry != null) {
+      out.put("retry", retry);
+    }
+    if (retryInS != null) {
+      out.put("retry_in_s", retryInS);
+    }
+    return out;
+  }
+}
@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `InboxSettlement` the API declares.
+ *
+ * @param settled carries `settled`: Receipts of the events that were settled
+ * @param stale carries `stale`: Receipts that settled nothing: their lease expired, or the event was already settled
+ */
+public record InboxSettlement(List<UUID> settled, List<UUID> stale) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the InboxSettlement the API declares
+   */
+  public static InboxSettlement fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "InboxSettlement");
+    return new InboxSettlement(
+        Wire.read(fields, "settled", Wire.asList(Wire::asUuid)),
+        Wire.read(fields, "stale", Wire.asList(Wire::asUuid)));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("settled", Wire.writeList(settled, Wire::writeUuid));
+    out.put("stale", Wire.writeList(stale, Wire::writeUuid));
+    return out;
+  }
+}
@@ -15,8 +15,14 @@ import java.util.UUID;
  * @param applicationId carries `application_id`.
  * @param eventId carries `event_id`.
  * @param receivedAt carries `received_at`.
+ * @param deprecation carries `deprecation`, or nothing when the API answers none: Present when the event was ingested
+ *     with a deprecated version of its event type
  */
-public record IngestedEvent(UUID applicationId, UUID eventId, OffsetDateTime receivedAt) {
+public record IngestedEvent(
+    UUID applicationId,
+    UUID eventId,
+    OffsetDateTime receivedAt,
+    IngestedEventDeprecation deprecation) {
 
   /**
    * Reads one out of what the API answered.
@@ -29,7 +35,8 @@ public record IngestedEvent(UUID applicationId, UUID eventId, OffsetDateTime rec
     return new IngestedEvent(
         Wire.read(fields, "application_id", Wire::asUuid),
         Wire.read(fields, "event_id", Wire::asUuid),
-        Wire.read(fields, "received_at", Wire::asMoment));
+        Wire.read(fields, "received_at", Wire::asMoment),
+        Wire.maybe(fields, "deprecation", IngestedEventDeprecation::fromJson));
   }
 
   /**
@@ -42,6 +49,9 @@ public record IngestedEvent(UUID applicationId, UUID eventId, OffsetDateTime rec
     out.put("application_id", Wire.writeUuid(applicationId));
     out.put("event_id", Wire.writeUuid(eventId));
     out.put("received_at", Wire.writeMoment(receivedAt));
+    if (deprecation != null) {
+      out.put("deprecation", deprecation.toJson());
+    }
     return out;
   }
 }
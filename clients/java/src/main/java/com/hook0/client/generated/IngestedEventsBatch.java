@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `IngestedEventsBatch` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ * @param events carries `events`: One outcome per submitted event, in the order the events were submitted
+ */
+public record IngestedEventsBatch(UUID applicationId, List<IngestedEventsBatchEvents> events) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the IngestedEventsBatch the API declares
+   */
+  public static IngestedEventsBatch fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "IngestedEventsBatch");
+    return new IngestedEventsBatch(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "events", Wire.asList(IngestedEventsBatchEvents::fromJson)));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    out.put("events", Wire.writeList(events, IngestedEventsBatchEvents::toJson));
+    return out;
+  }
+}
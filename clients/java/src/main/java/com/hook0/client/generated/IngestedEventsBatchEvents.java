@@ -0,0 +1,68 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `IngestedEventsBatchEvents` the API declares.
+ *
+ * @param status carries `status`.
+ * @param deprecation carries `deprecation`, or nothing when the API answers none: Present when the event was ingested
+ *     with a deprecated version of its event type
+ * @param eventId carries `event_id`, or nothing when the API answers none: ID of the ingested event, or the one the
+ *     event was submitted with when it was not ingested
+ * @param problem carries `problem`, or nothing when the API answers none: Present when the event was not ingested
+ * @param receivedAt carries `received_at`, or nothing when the API answers none: Present when the event was ingested
+ */
+public record IngestedEventsBatchEvents(
+    IngestedEventsBatchEventsStatus status,
+    IngestedEventsBatchEventsDeprecation deprecation,
+    UUID eventId,
+    IngestedEventsBatchEventsProblem problem,
+    OffsetDateTime receivedAt) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the IngestedEventsBatchEvents the API declares
+   */
+  public static IngestedEventsBatchEvents fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "IngestedEventsBatchEvents");
+    return new IngestedEventsBatchEvents(
+        Wire.read(fields, "status", IngestedEventsBatchEventsStatus::fromJson),
+        Wire.maybe(fields, "deprecation", IngestedEventsBatchEventsDeprecation::fromJson),
+        Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "problem", IngestedEventsBatchEventsProblem::fromJson),
+        Wire.maybe(fields, "received_at", Wire::asMoment));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("status", status.wireValue());
+    if (deprecation != null) {
+      out.put("deprecation", deprecation.toJson());
+    }
+    if (eventId != null) {
+      out.put("event_id", Wire.writeUuid(eventId));
+    }
+    if (problem != null) {
+      out.put("problem", problem.toJson());
+    }
+    if (receivedAt != null) {
+      out.put("received_at", Wire.writeMoment(receivedAt));
+    }
+    return out;
+  }
+}
@@ -0,0 +1,51 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `IngestedEventsBatchEventsDeprecation` the API declares.
+ *
+ * @param deprecatedAt carries `deprecated_at`.
+ * @param eventTypeVersion carries `event_type_version`.
+ * @param sunsetAt carries `sunset_at`, or nothing when the API answers none.
+ */
+public record IngestedEventsBatchEventsDeprecation(
+    OffsetDateTime deprecatedAt,
+    Integer eventTypeVersion,
+    OffsetDateTime sunsetAt) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the IngestedEventsBatchEventsDeprecation the API declares
+   */
+  public static IngestedEventsBatchEventsDeprecation fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "IngestedEventsBatchEventsDeprecation");
+    return new IngestedEventsBatchEventsDeprecation(
+        Wire.read(fields, "deprecated_at", Wire::asMoment),
+        Wire.read(fields, "event_type_version", Wire::asInteger),
+        Wire.maybe(fields, "sunset_at", Wire::asMoment));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("deprecated_at", Wire.writeMoment(deprecatedAt));
+    out.put("event_type_version", eventTypeVersion);
+    if (sunsetAt != null) {
+      out.put("sunset_at", Wire.writeMoment(sunsetAt));
+    }
+    return out;
+  }
+}
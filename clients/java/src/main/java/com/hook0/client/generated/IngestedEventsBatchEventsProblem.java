@@ -0,0 +1,65 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `IngestedEventsBatchEventsProblem` the API declares.
+ *
+ * @param detail carries `detail`.
+ * @param id carries `id`: Identifier of the problem that occurred, stable across releases
+ * @param status carries `status`.
+ * @param title carries `title`.
+ * @param type carries `type`: Documentation page of this problem, which is also what distinguishes one problem type
+ *     from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a URL.
+ * @param validation carries `validation`, or nothing when the API answers none: Which submitted values were rejected
+ *     and why, keyed by the name of the field they were submitted under. Carried by validation failures (`Validation`,
+ *     status 422) and `null` for every other problem, as
+ */
+public record IngestedEventsBatchEventsProblem(
+    String detail,
+    IngestedEventsBatchEventsProblemId id,
+    Integer status,
+    String title,
+    String type,
+    Object validation) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the IngestedEventsBatchEventsProblem the API declares
+   */
+  public static IngestedEventsBatchEventsProblem fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "IngestedEventsBatchEventsProblem");
+    return new IngestedEventsBatchEventsProblem(
+        Wire.read(fields, "detail", Wire::asText),
+        Wire.read(fields, "id", IngestedEventsBatchEventsProblemId::fromJson),
+        Wire.read(fields, "status", Wire::asInteger),
+        Wire.read(fields, "title", Wire::asText),
+        Wire.read(fields, "type", Wire::asText),
+        Wire.maybe(fields, "validation", Wire::asJson));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("detail", detail);
+    out.put("id", id.wireValue());
+    out.put("status", status);
+    out.put("title", title);
+    out.put("type", type);
+    if (validation != null) {
+      out.put("validation", validation);
+    }
+    return out;
+  }
+}
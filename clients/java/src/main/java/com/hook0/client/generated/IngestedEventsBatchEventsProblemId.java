@@ -0,0 +1,233 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.DecodeException;
+import com.hook0.client.Wire;
+
+/**
+ * One of the values the API answers with.
+ */
+public enum IngestedEventsBatchEventsProblemId {
+  /** The API spells this one `OrganizationNameMissing`. */
+  ORGANIZATION_NAME_MISSING("OrganizationNameMissing"),
+
+  /** The API spells this one `UserAlreadyExist`. */
+  USER_ALREADY_EXIST("UserAlreadyExist"),
+
+  /** The API spells this one `RegistrationDisabled`. */
+  REGISTRATION_DISABLED("RegistrationDisabled"),
+
+  /** The API spells this one `PasswordTooShort`. */
+  PASSWORD_TOO_SHORT("PasswordTooShort"),
+
+  /** The API spells this one `PasswordTooLong`. */
+  PASSWORD_TOO_LONG("PasswordTooLong"),
+
+  /** The API spells this one `PasswordSimilarToEmail`. */
+  PASSWORD_SIMILAR_TO_EMAIL("PasswordSimilarToEmail"),
+
+  /** The API spells this one `PasswordSimilarToName`. */
+  PASSWORD_SIMILAR_TO_NAME("PasswordSimilarToName"),
+
+  /** The API spells this one `PasswordTooCommon`. */
+  PASSWORD_TOO_COMMON("PasswordTooCommon"),
+
+  /** The API spells this one `PasswordNotDiverseEnough`. */
+  PASSWORD_NOT_DIVERSE_ENOUGH("PasswordNotDiverseEnough"),
+
+  /** The API spells this one `OrganizationIsNotEmpty`. */
+  ORGANIZATION_IS_NOT_EMPTY("OrganizationIsNotEmpty"),
+
+  /** The API spells this one `InvitedUserDoesNotExist`. */
+  INVITED_USER_DOES_NOT_EXIST("InvitedUserDoesNotExist"),
+
+  /** The API spells this one `InvitedUserAlreadyInOrganization`. */
+  INVITED_USER_ALREADY_IN_ORGANIZATION("InvitedUserAlreadyInOrganization"),
+
+  /** The API spells this one `ApplicationNameMissing`. */
+  APPLICATION_NAME_MISSING("ApplicationNameMissing"),
+
+  /** The API spells this one `InvalidRole`. */
+  INVALID_ROLE("InvalidRole"),
+
+  /** The API spells this one `EventTypeAlreadyExist`. */
+  EVENT_TYPE_ALREADY_EXIST("EventTypeAlreadyExist"),
+
+  /** The API spells this one `EventTypeDoesNotExist`. */
+  EVENT_TYPE_DOES_NOT_EXIST("EventTypeDoesNotExist"),
+
+  /** The API spells this one `EventTypeVersionAlreadyExist`. */
+  EVENT_TYPE_VERSION_ALREADY_EXIST("EventTypeVersionAlreadyExist"),
+
+  /** The API spells this one `EventTypeVersionDoesNotExist`. */
+  EVENT_TYPE_VERSION_DOES_NOT_EXIST("EventTypeVersionDoesNotExist"),
+
+  /** The API spells this one `EventTypeVersionSunset`. */
+  EVENT_TYPE_VERSION_SUNSET("EventTypeVersionSunset"),
+
+  /** The API spells this one `UnauthorizedWorkers`. */
+  UNAUTHORIZED_WORKERS("UnauthorizedWorkers"),
+
+  /** The API spells this one `EventAlreadyIngested`. */
+  EVENT_ALREADY_INGESTED("EventAlreadyIngested"),
+
+  /** The API spells this one `EventInvalidPayloadContentType`. */
+  EVENT_INVALID_PAYLOAD_CONTENT_TYPE("EventInvalidPayloadContentType"),
+
+  /** The API spells this one `EventInvalidBase64Payload`. */
+  EVENT_INVALID_BASE64_PAYLOAD("EventInvalidBase64Payload"),
+
+  /** The API spells this one `EventInvalidJsonPayload`. */
+  EVENT_INVALID_JSON_PAYLOAD("EventInvalidJsonPayload"),
+
+  /** The API spells this one `EventPayloadSchemaViolation`. */
+  EVENT_PAYLOAD_SCHEMA_VIOLATION("EventPayloadSchemaViolation"),
+
+  /** The API spells this one `EventDeliveryBeyondRetention`. */
+  EVENT_DELIVERY_BEYOND_RETENTION("EventDeliveryBeyondRetention"),
+
+  /** The API spells this one `EventNotScheduled`. */
+  EVENT_NOT_SCHEDULED("EventNotScheduled"),
+
+  /** The API spells this one `LabelsAmbiguity`. */
+  LABELS_AMBIGUITY("LabelsAmbiguity"),
+
+  /** The API spells this one `InvalidDateRange`. */
+  INVALID_DATE_RANGE("InvalidDateRange"),
+
+  /** The API spells this one `RequestAttemptNotFailed`. */
+  REQUEST_ATTEMPT_NOT_FAILED("RequestAttemptNotFailed"),
+
+  /** The API spells this one `RequestAttemptRetryPending`. */
+  REQUEST_ATTEMPT_RETRY_PENDING("RequestAttemptRetryPending"),
+
+  /** The API spells this one `SubscriptionDisabled`. */
+  SUBSCRIPTION_DISABLED("SubscriptionDisabled"),
+
+  /** The API spells this one `SubscriptionNotInbox`. */
+  SUBSCRIPTION_NOT_INBOX("SubscriptionNotInbox"),
+
+  /** The API spells this one `SecretRotationInProgress`. */
+  SECRET_ROTATION_IN_PROGRESS("SecretRotationInProgress"),
+
+  /** The API spells this one `BulkRetryAlreadyRunning`. */
+  BULK_RETRY_ALREADY_RUNNING("BulkRetryAlreadyRunning"),
+
+  /** The API spells this one `BulkRetryFinished`. */
+  BULK_RETRY_FINISHED("BulkRetryFinished"),
+
+  /** The API spells this one `RetryPolicyOverLimit`. */
+  RETRY_POLICY_OVER_LIMIT("RetryPolicyOverLimit"),
+
+  /** The API spells this one `ClientCertificatesDisabled`. */
+  CLIENT_CERTIFICATES_DISABLED("ClientCertificatesDisabled"),
+
+  /** The API spells this one `InvalidClientCertificate`. */
+  INVALID_CLIENT_CERTIFICATE("InvalidClientCertificate"),
+
+  /** The API spells this one `SigningKeysDisabled`. */
+  SIGNING_KEYS_DISABLED("SigningKeysDisabled"),
+
+  /** The API spells this one `AuthNoAuthorizationHeader`. */
+  AUTH_NO_AUTHORIZATION_HEADER("AuthNoAuthorizationHeader"),
+
+  /** The API spells this one `AuthInvalidAuthorizationHeader`. */
+  AUTH_INVALID_AUTHORIZATION_HEADER("AuthInvalidAuthorizationHeader"),
+
+  /** The API spells this one `AuthApplicationSecretLookupError`. */
+  AUTH_APPLICATION_SECRET_LOOKUP_ERROR("AuthApplicationSecretLookupError"),
+
+  /** The API spells this one `AuthInvalidApplicationSecret`. */
+  AUTH_INVALID_APPLICATION_SECRET("AuthInvalidApplicationSecret"),
+
+  /** The API spells this one `AuthBiscuitLookupError`. */
+  AUTH_BISCUIT_LOOKUP_ERROR("AuthBiscuitLookupError"),
+
+  /** The API spells this one `AuthInvalidBiscuit`. */
+  AUTH_INVALID_BISCUIT("AuthInvalidBiscuit"),
+
+  /** The API spells this one `AuthFailedLogin`. */
+  AUTH_FAILED_LOGIN("AuthFailedLogin"),
+
+  /** The API spells this one `AuthEmailNotVerified`. */
+  AUTH_EMAIL_NOT_VERIFIED("AuthEmailNotVerified"),
+
+  /** The API spells this one `AuthEmailAlreadyVerified`. */
+  AUTH_EMAIL_ALREADY_VERIFIED("AuthEmailAlreadyVerified"),
+
+  /** The API spells this one `AuthFailedRefresh`. */
+  AUTH_FAILED_REFRESH("AuthFailedRefresh"),
+
+  /** The API spells this one `AuthEmailExpired`. */
+  AUTH_EMAIL_EXPIRED("AuthEmailExpired"),
+
+  /** The API spells this one `TooManyMembersPerOrganization`. */
+  TOO_MANY_MEMBERS_PER_ORGANIZATION("TooManyMembersPerOrganization"),
+
+  /** The API spells this one `TooManyApplicationsPerOrganization`. */
+  TOO_MANY_APPLICATIONS_PER_ORGANIZATION("TooManyApplicationsPerOrganization"),
+
+  /** The API spells this one `TooManyEventsToday`. */
+  TOO_MANY_EVENTS_TODAY("TooManyEventsToday"),
+
+  /** The API spells this one `TooManySubscriptionsPerApplication`. */
+  TOO_MANY_SUBSCRIPTIONS_PER_APPLICATION("TooManySubscriptionsPerApplication"),
+
+  /** The API spells this one `TooManyEventTypesPerApplication`. */
+  TOO_MANY_EVENT_TYPES_PER_APPLICATION("TooManyEventTypesPerApplication"),
+
+  /** The API spells this one `JsonPayload`. */
+  JSON_PAYLOAD("JsonPayload"),
+
+  /** The API spells this one `Validation`. */
+  VALIDATION("Validation"),
+
+  /** The API spells this one `NotFound`. */
+  NOT_FOUND("NotFound"),
+
+  /** The API spells this one `InternalServerError`. */
+  INTERNAL_SERVER_ERROR("InternalServerError"),
+
+  /** The API spells this one `Forbidden`. */
+  FORBIDDEN("Forbidden"),
+
+  /** The API spells this one `RateLimited`. */
+  RATE_LIMITED("RateLimited"),
+
+  /** The API spells this one `ServiceUnavailable`. */
+  SERVICE_UNAVAILABLE("ServiceUnavailable");
+
+  private final String wire;
+
+  IngestedEventsBatchEventsProblemId(String wire) {
+    this.wire = wire;
+  }
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the value it names
+   */
+  public static IngestedEventsBatchEventsProblemId fromJson(Object value) {
+    String named = Wire.asText(value);
+    for (IngestedEventsBatchEventsProblemId candidate : values()) {
+      if (candidate.wire.equals(named)) {
+        return candidate;
+      }
+    }
+    throw new DecodeException(
+        "`" + Wire.preview(named) + "` is not one of the values IngestedEventsBatchEventsProblemId declares");
+  }
+
+  /**
+   * The text this value travels as.
+   *
+   * @return what the API carries it as
+   */
+  public String wireValue() {
+    return wire;
+  }
+}
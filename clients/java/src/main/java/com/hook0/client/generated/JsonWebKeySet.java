@@ -0,0 +1,39 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * The `JsonWebKeySet` the API declares.
+ *
+ * @param keys carries `keys`.
+ */
+public record JsonWebKeySet(List<JsonWebKeySetKeys> keys) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the JsonWebKeySet the API declares
+   */
+  public static JsonWebKeySet fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "JsonWebKeySet");
+    return new JsonWebKeySet(Wire.read(fields, "keys", Wire.asList(JsonWebKeySetKeys::fromJson)));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("keys", Wire.writeList(keys, JsonWebKeySetKeys::toJson));
+    return out;
+  }
+}
@@ -0,0 +1,54 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `JsonWebKeySetKeys` the API declares.
+ *
+ * @param alg carries `alg`: Always `EdDSA`
+ * @param crv carries `crv`: Always `Ed25519`
+ * @param kid carries `kid`: ID of the signing key, which signatures name in their `k` part
+ * @param kty carries `kty`: Always `OKP`
+ * @param use carries `use`: Always `sig`
+ * @param x carries `x`: Public key, encoded in base64url without padding
+ */
+public record JsonWebKeySetKeys(String alg, String crv, String kid, String kty, String use, String x) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the JsonWebKeySetKeys the API declares
+   */
+  public static JsonWebKeySetKeys fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "JsonWebKeySetKeys");
+    return new JsonWebKeySetKeys(
+        Wire.read(fields, "alg", Wire::asText),
+        Wire.read(fields, "crv", Wire::asText),
+        Wire.read(fields, "kid", Wire::asText),
+        Wire.read(fields, "kty", Wire::asText),
+        Wire.read(fields, "use", Wire::asText),
+        Wire.read(fields, "x", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("alg", alg);
+    out.put("crv", crv);
+    out.put("kid", kid);
+    out.put("kty", kty);
+    out.put("use", use);
+    out.put("x", x);
+    return out;
+  }
+}
@@ -0,0 +1,63 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `OAuth2ClientCredentials` the API declares.
+ *
+ * @param clientId carries `client_id`.
+ * @param createdAt carries `created_at`.
+ * @param scopes carries `scopes`.
+ * @param subscriptionId carries `subscription_id`.
+ * @param tokenUrl carries `token_url`.
+ * @param updatedAt carries `updated_at`.
+ */
+public record Oauth2ClientCredentials(
+    String clientId,
+    OffsetDateTime createdAt,
+    List<String> scopes,
+    UUID subscriptionId,
+    String tokenUrl,
+    OffsetDateTime updatedAt) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the OAuth2ClientCredentials the API declares
+   */
+  public static Oauth2ClientCredentials fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "OAuth2ClientCredentials");
+    return new Oauth2ClientCredentials(
+        Wire.read(fields, "client_id", Wire::asText),
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "scopes", Wire.asList(Wire::asText)),
+        Wire.read(fields, "subscription_id", Wire::asUuid),
+        Wire.read(fields, "token_url", Wire::asText),
+        Wire.read(fields, "updated_at", Wire::asMoment));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("client_id", clientId);
+    out.put("created_at", Wire.writeMoment(createdAt));
+    out.put("scopes", scopes);
+    out.put("subscription_id", Wire.writeUuid(subscriptionId));
+    out.put("token_url", tokenUrl);
+    out.put("updated_at", Wire.writeMoment(updatedAt));
+    return out;
+  }
+}
@@ -0,0 +1,51 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * The `OAuth2ClientCredentialsPut` the API declares.
+ *
+ * @param clientId carries `client_id`.
+ * @param clientSecret carries `client_secret`.
+ * @param tokenUrl carries `token_url`: Token endpoint of the authorization server
+ * @param scopes carries `scopes`, or nothing when the API answers none: Scopes to request; none by default
+ */
+public record Oauth2ClientCredentialsPut(String clientId, String clientSecret, String tokenUrl, List<String> scopes) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the OAuth2ClientCredentialsPut the API declares
+   */
+  public static Oauth2ClientCredentialsPut fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "OAuth2ClientCredentialsPut");
+    return new Oauth2ClientCredentialsPut(
+        Wire.read(fields, "client_id", Wire::asText),
+        Wire.read(fields, "client_secret", Wire::asText),
+        Wire.read(fields, "token_url", Wire::asText),
+        Wire.maybe(fields, "scopes", Wire.asList(Wire::asText)));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("client_id", clientId);
+    out.put("client_secret", clientSecret);
+    out.put("token_url", tokenUrl);
+    if (scopes != null) {
+      out.put("scopes", scopes);
+    }
+    return out;
+  }
+}
@@ -22,14 +22,24 @@ public sealed class ProblemException extends Hook0Exception
         AuthInvalidAuthorizationHeaderException,
         AuthInvalidBiscuitException,
         AuthNoAuthorizationHeaderException,
+        BulkRetryAlreadyRunningException,
+        BulkRetryFinishedException,
+        ClientCertificatesDisabledException,
         EventAlreadyIngestedException,
+        EventDeliveryBeyondRetentionException,
         EventInvalidBase64PayloadException,
         EventInvalidJsonPayloadException,
         EventInvalidPayloadContentTypeException,
+        EventNotScheduledException,
+        EventPayloadSchemaViolationException,
         EventTypeAlreadyExistException,
         EventTypeDoesNotExistException,
+        EventTypeVersionAlreadyExistException,
+        EventTypeVersionDoesNotExistException,
+        EventTypeVersionSunsetException,
         ForbiddenException,
         InternalServerErrorException,
+        InvalidClientCertificateException,
         InvalidDateRangeException,
         InvalidRoleException,
         InvitedUserAlreadyInOrganizationException,
@@ -47,7 +57,14 @@ public sealed class ProblemException extends Hook0Exception
         PasswordTooShortException,
         RateLimitedException,
         RegistrationDisabledException,
+        RequestAttemptNotFailedException,
+        RequestAttemptRetryPendingException,
+        RetryPolicyOverLimitException,
+        SecretRotationInProgressException,
         ServiceUnavailableException,
+        SigningKeysDisabledException,
+        SubscriptionDisabledException,
+        SubscriptionNotInboxException,
         TooManyApplicationsPerOrganizationException,
         TooManyEventTypesPerApplicationException,
         TooManyEventsTodayException,
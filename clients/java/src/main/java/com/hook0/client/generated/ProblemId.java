@@ -58,6 +58,15 @@ public enum ProblemId {
   /** The API spells this one `EventTypeDoesNotExist`. */
   EVENT_TYPE_DOES_NOT_EXIST("EventTypeDoesNotExist"),
 
+  /** The API spells this one `EventTypeVersionAlreadyExist`. */
+  EVENT_TYPE_VERSION_ALREADY_EXIST("EventTypeVersionAlreadyExist"),
+
+  /** The API spells this one `EventTypeVersionDoesNotExist`. */
+  EVENT_TYPE_VERSION_DOES_NOT_EXIST("EventTypeVersionDoesNotExist"),
+
+  /** The API spells this one `EventTypeVersionSunset`. */
+  EVENT_TYPE_VERSION_SUNSET("EventTypeVersionSunset"),
+
   /** The API spells this one `UnauthorizedWorkers`. */
   UNAUTHORIZED_WORKERS("UnauthorizedWorkers"),
 
@@ -73,12 +82,54 @@ public enum ProblemId {
   /** The API spells this one `EventInvalidJsonPayload`. */
   EVENT_INVALID_JSON_PAYLOAD("EventInvalidJsonPayload"),
 
+  /** The API spells this one `EventPayloadSchemaViolation`. */
+  EVENT_PAYLOAD_SCHEMA_VIOLATION("EventPayloadSchemaViolation"),
+
+  /** The API spells this one `EventDeliveryBeyondRetention`. */
+  EVENT_DELIVERY_BEYOND_RETENTION("EventDeliveryBeyondRetention"),
+
+  /** The API spells this one `EventNotScheduled`. */
+  EVENT_NOT_SCHEDULED("EventNotScheduled"),
+
   /** The API spells this one `LabelsAmbiguity`. */
   LABELS_AMBIGUITY("LabelsAmbiguity"),
 
   /** The API spells this one `InvalidDateRange`. */
   INVALID_DATE_RANGE("InvalidDateRange"),
 
+  /** The API spells this one `RequestAttemptNotFailed`. */
+  REQUEST_ATTEMPT_NOT_FAILED("RequestAttemptNotFailed"),
+
+  /** The API spells this one `RequestAttemptRetryPending`. */
+  REQUEST_ATTEMPT_RETRY_PENDING("RequestAttemptRetryPending"),
+
+  /** The API spells this one `SubscriptionDisabled`. */
+  SUBSCRIPTION_DISABLED("SubscriptionDisabled"),
+
+  /** The API spells this one `SubscriptionNotInbox`. */
+  SUBSCRIPTION_NOT_INBOX("SubscriptionNotInbox"),
+
+  /** The API spells this one `SecretRotationInProgress`. */
+  SECRET_ROTATION_IN_PROGRESS("SecretRotationInProgress"),
+
+  /** The API spells this one `BulkRetryAlreadyRunning`. */
+  BULK_RETRY_ALREADY_RUNNING("BulkRetryAlreadyRunning"),
+
+  /** The API spells this one `BulkRetryFinished`. */
+  BULK_RETRY_FINISHED("BulkRetryFinished"),
+
+  /** The API spells this one `RetryPolicyOverLimit`. */
+  RETRY_POLICY_OVER_LIMIT("RetryPolicyOverLimit"),
+
+  /** The API spells this one `ClientCertificatesDisabled`. */
+  CLIENT_CERTIFICATES_DISABLED("ClientCertificatesDisabled"),
+
+  /** The API spells this one `InvalidClientCertificate`. */
+  INVALID_CLIENT_CERTIFICATE("InvalidClientCertificate"),
+
+  /** The API spells this one `SigningKeysDisabled`. */
+  SIGNING_KEYS_DISABLED("SigningKeysDisabled"),
+
   /** The API spells this one `AuthNoAuthorizationHeader`. */
   AUTH_NO_AUTHORIZATION_HEADER("AuthNoAuthorizationHeader"),
 
@@ -57,14 +57,24 @@ public final class Problems {
       case AUTH_INVALID_AUTHORIZATION_HEADER -> new AuthInvalidAuthorizationHeaderException(status, problem, detail);
       case AUTH_INVALID_BISCUIT -> new AuthInvalidBiscuitException(status, problem, detail);
       case AUTH_NO_AUTHORIZATION_HEADER -> new AuthNoAuthorizationHeaderException(status, problem, detail);
+      case BULK_RETRY_ALREADY_RUNNING -> new BulkRetryAlreadyRunningException(status, problem, detail);
+      case BULK_RETRY_FINISHED -> new BulkRetryFinishedException(status, problem, detail);
+      case CLIENT_CERTIFICATES_DISABLED -> new ClientCertificatesDisabledException(status, problem, detail);
       case EVENT_ALREADY_INGESTED -> new EventAlreadyIngestedException(status, problem, detail);
+      case EVENT_DELIVERY_BEYOND_RETENTION -> new EventDeliveryBeyondRetentionException(status, problem, detail);
       case EVENT_INVALID_BASE64_PAYLOAD -> new EventInvalidBase64PayloadException(status, problem, detail);
       case EVENT_INVALID_JSON_PAYLOAD -> new EventInvalidJsonPayloadException(status, problem, detail);
       case EVENT_INVALID_PAYLOAD_CONTENT_TYPE -> new EventInvalidPayloadContentTypeException(status, problem, detail);
+      case EVENT_NOT_SCHEDULED -> new EventNotScheduledException(status, problem, detail);
+      case EVENT_PAYLOAD_SCHEMA_VIOLATION -> new EventPayloadSchemaViolationException(status, problem, detail);
       case EVENT_TYPE_ALREADY_EXIST -> new EventTypeAlreadyExistException(status, problem, detail);
       case EVENT_TYPE_DOES_NOT_EXIST -> new EventTypeDoesNotExistException(status, problem, detail);
+      case EVENT_TYPE_VERSION_ALREADY_EXIST -> new EventTypeVersionAlreadyExistException(status, problem, detail);
+      case EVENT_TYPE_VERSION_DOES_NOT_EXIST -> new EventTypeVersionDoesNotExistException(status, problem, detail);
+      case EVENT_TYPE_VERSION_SUNSET -> new EventTypeVersionSunsetException(status, problem, detail);
       case FORBIDDEN -> new ForbiddenException(status, problem, detail);
       case INTERNAL_SERVER_ERROR -> new InternalServerErrorException(status, problem, detail);
+      case INVALID_CLIENT_CERTIFICATE -> new InvalidClientCertificateException(status, problem, detail);
       case INVALID_DATE_RANGE -> new InvalidDateRangeException(status, problem, detail);
       case INVALID_ROLE -> new InvalidRoleException(status, problem, detail);
       case INVITED_USER_ALREADY_IN_ORGANIZATION ->
@@ -83,7 +93,14 @@ public final class Problems {
       case PASSWORD_TOO_SHORT -> new PasswordTooShortException(status, problem, detail);
       case RATE_LIMITED -> new RateLimitedException(status, problem, detail);
       case REGISTRATION_DISABLED -> new RegistrationDisabledException(status, problem, detail);
+      case REQUEST_ATTEMPT_NOT_FAILED -> new RequestAttemptNotFailedException(status, problem, detail);
+      case REQUEST_ATTEMPT_RETRY_PENDING -> new RequestAttemptRetryPendingException(status, problem, detail);
+      case RETRY_POLICY_OVER_LIMIT -> new RetryPolicyOverLimitException(status, problem, detail);
+      case SECRET_ROTATION_IN_PROGRESS -> new SecretRotationInProgressException(status, problem, detail);
       case SERVICE_UNAVAILABLE -> new ServiceUnavailableException(status, problem, detail);
+      case SIGNING_KEYS_DISABLED -> new SigningKeysDisabledException(status, problem, detail);
+      case SUBSCRIPTION_DISABLED -> new SubscriptionDisabledException(status, problem, detail);
+      case SUBSCRIPTION_NOT_INBOX -> new SubscriptionNotInboxException(status, problem, detail);
       case TOO_MANY_APPLICATIONS_PER_ORGANIZATION ->
           new TooManyApplicationsPerOrganizationException(status, problem, detail);
       case TOO_MANY_EVENT_TYPES_PER_APPLICATION ->
@@ -0,0 +1,38 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `PurgedDeadLetters` the API declares.
+ *
+ * @param purgedCount carries `purged_count`.
+ */
+public record PurgedDeadLetters(Long purgedCount) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the PurgedDeadLetters the API declares
+   */
+  public static PurgedDeadLetters fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "PurgedDeadLetters");
+    return new PurgedDeadLetters(Wire.read(fields, "purged_count", Wire::asLong));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("purged_count", purgedCount);
+    return out;
+  }
+}
@@ -0,0 +1,43 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `RedeliveredDeadLetter` the API declares.
+ *
+ * @param deadLetterId carries `dead_letter_id`.
+ * @param requestAttemptId carries `request_attempt_id`.
+ */
+public record RedeliveredDeadLetter(UUID deadLetterId, UUID requestAttemptId) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the RedeliveredDeadLetter the API declares
+   */
+  public static RedeliveredDeadLetter fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "RedeliveredDeadLetter");
+    return new RedeliveredDeadLetter(
+        Wire.read(fields, "dead_letter_id", Wire::asUuid),
+        Wire.read(fields, "request_attempt_id", Wire::asUuid));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("dead_letter_id", Wire.writeUuid(deadLetterId));
+    out.put("request_attempt_id", Wire.writeUuid(requestAttemptId));
+    return out;
+  }
+}
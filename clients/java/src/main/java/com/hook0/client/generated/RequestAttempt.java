@@ -21,7 +21,11 @@ import java.util.UUID;
  *     waiting: {type, since, until} - Scheduled for future delivery - pending: {type, since} - Ready to be processed -
  *     in_progre
  * @param subscription carries `subscription`.
+ * @param deadLetterId carries `dead_letter_id`, or nothing when the API answers none: Dead letter the request attempt
+ *     became, when it was the last attempt of a delivery an output worker gave up on
  * @param delayUntil carries `delay_until`, or nothing when the API answers none.
+ * @param deliveryBatchId carries `delivery_batch_id`, or nothing when the API answers none: Batch the request attempt
+ *     was delivered in, shared with the other request attempts of the batch and their response
  * @param failedAt carries `failed_at`, or nothing when the API answers none.
  * @param httpResponseStatus carries `http_response_status`, or nothing when the API answers none.
  * @param pickedAt carries `picked_at`, or nothing when the API answers none.
@@ -36,7 +40,9 @@ public record RequestAttempt(
     Integer retryCount,
     RequestAttemptStatus status,
     RequestAttemptSubscription subscription,
+    UUID deadLetterId,
     OffsetDateTime delayUntil,
+    UUID deliveryBatchId,
     OffsetDateTime failedAt,
     Integer httpResponseStatus,
     OffsetDateTime pickedAt,
@@ -59,7 +65,9 @@ public record RequestAttempt(
         Wire.read(fields, "retry_count", Wire::asInteger),
         Wire.read(fields, "status", RequestAttemptStatus::fromJson),
         Wire.read(fields, "subscription", RequestAttemptSubscription::fromJson),
+        Wire.maybe(fields, "dead_letter_id", Wire::asUuid),
         Wire.maybe(fields, "delay_until", Wire::asMoment),
+        Wire.maybe(fields, "delivery_batch_id", Wire::asUuid),
         Wire.maybe(fields, "failed_at", Wire::asMoment),
         Wire.maybe(fields, "http_response_status", Wire::asInteger),
         Wire.maybe(fields, "picked_at", Wire::asMoment),
@@ -81,9 +89,15 @@ public record RequestAttempt(
     out.put("retry_count", retryCount);
     out.put("status", status.toJson());
     out.put("subscription", subscription.toJson());
+    if (deadLetterId != null) {
+      out.put("dead_letter_id", Wire.writeUuid(deadLetterId));
+    }
     if (delayUntil != null) {
       out.put("delay_until", Wire.writeMoment(delayUntil));
     }
+    if (deliveryBatchId != null) {
+      out.put("delivery_batch_id", Wire.writeUuid(deliveryBatchId));
+    }
     if (failedAt != null) {
       out.put("failed_at", Wire.writeMoment(failedAt));
     }
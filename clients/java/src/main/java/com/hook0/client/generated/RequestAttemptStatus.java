@@ -11,11 +11,15 @@ import java.util.Map;
 /**
  * The `RequestAttemptStatus` the API declares.
  *
- * @param type carries `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed
+ * @param type carries `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed,
+ *     expired, cancelled
  * @param at carries `at`, or nothing when the API answers none: Timestamp when completed (present in successful,
- *     failed)
+ *     failed, expired, cancelled)
  * @param fullProcessingMs carries `full_processing_ms`, or nothing when the API answers none: Total processing time in
  *     milliseconds (present in successful, failed)
+ * @param ruleOutcome carries `rule_outcome`, or nothing when the API answers none: Outcome given by a response rule of
+ *     the subscription, when one matched the response (present in successful, failed). One of: success, retryable,
+ *     permanent_failure, disable_subscription
  * @param since carries `since`, or nothing when the API answers none: Timestamp when the status started (present in
  *     waiting, pending, in_progress)
  * @param until carries `until`, or nothing when the API answers none: Timestamp until which waiting (only present in
@@ -25,6 +29,7 @@ public record RequestAttemptStatus(
     RequestAttemptStatusType type,
     OffsetDateTime at,
     Long fullProcessingMs,
+    RequestAttemptStatusRuleOutcome ruleOutcome,
     OffsetDateTime since,
     OffsetDateTime until) {
 
@@ -40,6 +45,7 @@ public record RequestAttemptStatus(
         Wire.read(fields, "type", RequestAttemptStatusType::fromJson),
         Wire.maybe(fields, "at", Wire::asMoment),
         Wire.maybe(fields, "full_processing_ms", Wire::asLong),
+        Wire.maybe(fields, "rule_outcome", RequestAttemptStatusRuleOutcome::fromJson),
         Wire.maybe(fields, "since", Wire::asMoment),
         Wire.maybe(fields, "until", Wire::asMoment));
   }
@@ -58,6 +64,9 @@ public record RequestAttemptStatus(
     if (fullProcessingMs != null) {
       out.put("full_processing_ms", fullProcessingMs);
     }
+    if (ruleOutcome != null) {
+      out.put("rule_outcome", ruleOutcome.wireValue());
+    }
     if (since != null) {
       out.put("since", Wire.writeMoment(since));
     }
@@ -23,7 +23,13 @@ public enum RequestAttemptStatusType {
   SUCCESSFUL("successful"),
 
   /** The API spells this one `failed`. */
-  FAILED("failed");
+  FAILED("failed"),
+
+  /** The API spells this one `expired`. */
+  EXPIRED("expired"),
+
+  /** The API spells this one `cancelled`. */
+  CANCELLED("cancelled");
 
   private final String wire;
 
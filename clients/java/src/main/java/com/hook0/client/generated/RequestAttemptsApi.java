@@ -86,4 +86,18 @@ public final class RequestAttemptsApi {
     }
     return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(RequestAttempt::fromJson));
   }
+
+  /**
+   * Retry a request attempt
+   *
+   * @param requestAttemptId carries `request_attempt_id`.
+   * @param body the RetryRequestAttempt the operation reads
+   * @return what the API answered
+   */
+  public RequestAttempt retry(String requestAttemptId, RetryRequestAttempt body) {
+    String path = "/api/v1/request_attempts/{request_attempt_id}/retry";
+    path = path.replace("{request_attempt_id}", Wire.pathSegment(requestAttemptId));
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), RequestAttempt::fromJson);
+  }
 }
@@ -89,4 +89,19 @@ public final class RequestAttemptsAsyncApi {
     return transport.requestAsync("GET", path, query, null)
         .thenApply(Problems.readingWith(Wire.asList(RequestAttempt::fromJson)));
   }
+
+  /**
+   * Retry a request attempt
+   *
+   * @param requestAttemptId carries `request_attempt_id`.
+   * @param body the RetryRequestAttempt the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<RequestAttempt> retry(String requestAttemptId, RetryRequestAttempt body) {
+    String path = "/api/v1/request_attempts/{request_attempt_id}/retry";
+    path = path.replace("{request_attempt_id}", Wire.pathSegment(requestAttemptId));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(RequestAttempt::fromJson));
+  }
 }
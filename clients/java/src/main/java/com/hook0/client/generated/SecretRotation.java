@@ -0,0 +1,54 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `SecretRotation` the API declares.
+ *
+ * @param previousSecret carries `previous_secret`: The secret the new one replaced
+ * @param previousSecretExpiresAt carries `previous_secret_expires_at`.
+ * @param secret carries `secret`: The new secret of the subscription
+ * @param subscriptionId carries `subscription_id`.
+ */
+public record SecretRotation(
+    UUID previousSecret,
+    OffsetDateTime previousSecretExpiresAt,
+    UUID secret,
+    UUID subscriptionId) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SecretRotation the API declares
+   */
+  public static SecretRotation fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SecretRotation");
+    return new SecretRotation(
+        Wire.read(fields, "previous_secret", Wire::asUuid),
+        Wire.read(fields, "previous_secret_expires_at", Wire::asMoment),
+        Wire.read(fields, "secret", Wire::asUuid),
+        Wire.read(fields, "subscription_id", Wire::asUuid));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("previous_secret", Wire.writeUuid(previousSecret));
+    out.put("previous_secret_expires_at", Wire.writeMoment(previousSecretExpiresAt));
+    out.put("secret", Wire.writeUuid(secret));
+    out.put("subscription_id", Wire.writeUuid(subscriptionId));
+    return out;
+  }
+}
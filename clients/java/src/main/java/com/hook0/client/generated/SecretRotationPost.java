@@ -0,0 +1,41 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `SecretRotationPost` the API declares.
+ *
+ * @param overlapSeconds carries `overlap_seconds`, or nothing when the API answers none: How long the previous secret
+ *     stays valid, in seconds (one day by default)
+ */
+public record SecretRotationPost(Integer overlapSeconds) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SecretRotationPost the API declares
+   */
+  public static SecretRotationPost fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SecretRotationPost");
+    return new SecretRotationPost(Wire.maybe(fields, "overlap_seconds", Wire::asInteger));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    if (overlapSeconds != null) {
+      out.put("overlap_seconds", overlapSeconds);
+    }
+    return out;
+  }
+}
@@ -0,0 +1,88 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * What the API declares under `secretRotations`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ */
+public final class SecretRotationsApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public SecretRotationsApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Rotate the secret of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @param body the SecretRotationPost the operation reads
+   * @return what the API answered
+   */
+  public SecretRotation create(String subscriptionId, String applicationId, SecretRotationPost body) {
+    String path = "/api/v1/subscriptions/{subscription_id}/secret_rotation";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), SecretRotation::fromJson);
+  }
+
+  /**
+   * Finalize the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   */
+  public void finalize_(String subscriptionId, String applicationId) {
+    String path = "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    Problems.checkAnswer(transport.request("POST", path, query, null));
+  }
+
+  /**
+   * Get the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  public SecretRotation get(String subscriptionId, String applicationId) {
+    String path = "/api/v1/subscriptions/{subscription_id}/secret_rotation";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return Problems.readAnswer(transport.request("GET", path, query, null), SecretRotation::fromJson);
+  }
+
+  /**
+   * Roll back the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   */
+  public void rollback(String subscriptionId, String applicationId) {
+    String path = "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    Problems.checkAnswer(transport.request("POST", path, query, null));
+  }
+}
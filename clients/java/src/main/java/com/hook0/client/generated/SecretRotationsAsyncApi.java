@@ -0,0 +1,98 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+
+/**
+ * What the API declares under `secretRotations`, issued through the transport it is handed.
+ *
+ * Every call hands back what the API will answer.
+ */
+public final class SecretRotationsAsyncApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public SecretRotationsAsyncApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Rotate the secret of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @param body the SecretRotationPost the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<SecretRotation> create(
+      String subscriptionId,
+      String applicationId,
+      SecretRotationPost body) {
+    String path = "/api/v1/subscriptions/{subscription_id}/secret_rotation";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(SecretRotation::fromJson));
+  }
+
+  /**
+   * Finalize the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return nothing, once the API has answered
+   */
+  public CompletableFuture<Void> finalize_(String subscriptionId, String applicationId) {
+    String path = "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("POST", path, query, null)
+        .thenAccept(Problems::checkAnswer);
+  }
+
+  /**
+   * Get the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API will answer
+   */
+  public CompletableFuture<SecretRotation> get(String subscriptionId, String applicationId) {
+    String path = "/api/v1/subscriptions/{subscription_id}/secret_rotation";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(SecretRotation::fromJson));
+  }
+
+  /**
+   * Roll back the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return nothing, once the API has answered
+   */
+  public CompletableFuture<Void> rollback(String subscriptionId, String applicationId) {
+    String path = "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback";
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("POST", path, query, null)
+        .thenAccept(Problems::checkAnswer);
+  }
+}
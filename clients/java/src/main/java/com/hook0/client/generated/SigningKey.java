@@ -0,0 +1,61 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.time.OffsetDateTime;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `SigningKey` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ * @param createdAt carries `created_at`.
+ * @param isCurrent carries `is_current`: Whether deliveries are signed with this key, which only the newest key of the
+ *     application is
+ * @param publicKey carries `public_key`: Public key, encoded in base64url without padding like the `x` member of its
+ *     JSON Web Key
+ * @param signingKeyId carries `signing_key_id`: Identifies the key in the `k` part of signatures and as the `kid` of
+ *     its JSON Web Key
+ */
+public record SigningKey(
+    UUID applicationId,
+    OffsetDateTime createdAt,
+    Boolean isCurrent,
+    String publicKey,
+    UUID signingKeyId) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SigningKey the API declares
+   */
+  public static SigningKey fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SigningKey");
+    return new SigningKey(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "is_current", Wire::asBoolean),
+        Wire.read(fields, "public_key", Wire::asText),
+        Wire.read(fields, "signing_key_id", Wire::asUuid));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    out.put("created_at", Wire.writeMoment(createdAt));
+    out.put("is_current", isCurrent);
+    out.put("public_key", publicKey);
+    out.put("signing_key_id", Wire.writeUuid(signingKeyId));
+    return out;
+  }
+}
@@ -0,0 +1,39 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * The `SigningKeyPost` the API declares.
+ *
+ * @param applicationId carries `application_id`.
+ */
+public record SigningKeyPost(UUID applicationId) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SigningKeyPost the API declares
+   */
+  public static SigningKeyPost fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SigningKeyPost");
+    return new SigningKeyPost(Wire.read(fields, "application_id", Wire::asUuid));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("application_id", Wire.writeUuid(applicationId));
+    return out;
+  }
+}
@@ -0,0 +1,81 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * What the API declares under `signingKeys`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ */
+public final class SigningKeysApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public SigningKeysApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Create a signing key
+   *
+   * @param body the SigningKeyPost the operation reads
+   * @return what the API answered
+   */
+  public SigningKey create(SigningKeyPost body) {
+    String path = "/api/v1/signing_keys/";
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), SigningKey::fromJson);
+  }
+
+  /**
+   * Retire a signing key
+   *
+   * @param signingKeyId carries `signing_key_id`.
+   * @param applicationId carries `application_id`.
+   */
+  public void delete(String signingKeyId, String applicationId) {
+    String path = "/api/v1/signing_keys/{signing_key_id}";
+    path = path.replace("{signing_key_id}", Wire.pathSegment(signingKeyId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    Problems.checkAnswer(transport.request("DELETE", path, query, null));
+  }
+
+  /**
+   * Get the public keys of an application
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  public JsonWebKeySet jwks(String applicationId) {
+    String path = "/api/v1/jwks/{application_id}";
+    path = path.replace("{application_id}", Wire.pathSegment(applicationId));
+    List<QueryParameter> query = new ArrayList<>();
+    return Problems.readAnswer(transport.request("GET", path, query, null), JsonWebKeySet::fromJson);
+  }
+
+  /**
+   * List signing keys
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  public List<SigningKey> list(String applicationId) {
+    String path = "/api/v1/signing_keys/";
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(SigningKey::fromJson));
+  }
+}
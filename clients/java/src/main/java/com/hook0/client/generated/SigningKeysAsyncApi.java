@@ -0,0 +1,87 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.QueryParameter;
+import com.hook0.client.Transport;
+import com.hook0.client.Wire;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+
+/**
+ * What the API declares under `signingKeys`, issued through the transport it is handed.
+ *
+ * Every call hands back what the API will answer.
+ */
+public final class SigningKeysAsyncApi {
+
+  private final Transport transport;
+
+  /**
+   * Builds the group on what its requests are issued through.
+   *
+   * @param transport what one request is issued through
+   */
+  public SigningKeysAsyncApi(Transport transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * Create a signing key
+   *
+   * @param body the SigningKeyPost the operation reads
+   * @return what the API will answer
+   */
+  public CompletableFuture<SigningKey> create(SigningKeyPost body) {
+    String path = "/api/v1/signing_keys/";
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("POST", path, query, body.toJson())
+        .thenApply(Problems.readingWith(SigningKey::fromJson));
+  }
+
+  /**
+   * Retire a signing key
+   *
+   * @param signingKeyId carries `signing_key_id`.
+   * @param applicationId carries `application_id`.
+   * @return nothing, once the API has answered
+   */
+  public CompletableFuture<Void> delete(String signingKeyId, String applicationId) {
+    String path = "/api/v1/signing_keys/{signing_key_id}";
+    path = path.replace("{signing_key_id}", Wire.pathSegment(signingKeyId));
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("DELETE", path, query, null)
+        .thenAccept(Problems::checkAnswer);
+  }
+
+  /**
+   * Get the public keys of an application
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API will answer
+   */
+  public CompletableFuture<JsonWebKeySet> jwks(String applicationId) {
+    String path = "/api/v1/jwks/{application_id}";
+    path = path.replace("{application_id}", Wire.pathSegment(applicationId));
+    List<QueryParameter> query = new ArrayList<>();
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(JsonWebKeySet::fromJson));
+  }
+
+  /**
+   * List signing keys
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API will answer
+   */
+  public CompletableFuture<List<SigningKey>> list(String applicationId) {
+    String path = "/api/v1/signing_keys/";
+    List<QueryParameter> query = new ArrayList<>();
+    query.add(new QueryParameter("application_id", Wire.queryValue(applicationId)));
+    return transport.requestAsync("GET", path, query, null)
+        .thenApply(Problems.readingWith(Wire.asList(SigningKey::fromJson)));
+  }
+}
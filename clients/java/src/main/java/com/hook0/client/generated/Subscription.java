@@ -16,6 +16,7 @@ import java.util.UUID;
  * @param applicationId carries `application_id`.
  * @param createdAt carries `created_at`.
  * @param dedicatedWorkers carries `dedicated_workers`.
+ * @param eventTypeVersions carries `event_type_versions`: Versions the subscription is pinned to, by event type
  * @param eventTypes carries `event_types`.
  * @param isEnabled carries `is_enabled`.
  * @param labelKey carries `label_key`: _Kept for backward compatibility, you should use `labels`_
@@ -23,15 +24,41 @@ import java.util.UUID;
  * @param labels carries `labels`.
  * @param metadata carries `metadata`.
  * @param secret carries `secret`.
+ * @param signatureScheme carries `signature_scheme`.
  * @param subscriptionId carries `subscription_id`.
  * @param target carries `target`.
  * @param updatedAt carries `updated_at`.
+ * @param autoDisabledAt carries `auto_disabled_at`, or nothing when the API answers none: When the circuit breaker or a
+ *     response rule last disabled the subscription; cleared when it is enabled again
+ * @param autoDisabledReason carries `auto_disabled_reason`, or nothing when the API answers none: Why the circuit
+ *     breaker or a response rule last disabled the subscription
+ * @param batching carries `batching`, or nothing when the API answers none: Deliver the events of the subscription in
+ *     batches: one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or
+ *     once its oldest event has waited `max_wait_s` se
+ * @param circuitBreaker carries `circuit_breaker`, or nothing when the API answers none: Disables the subscription once
+ *     its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set
+ * @param deliveryLimits carries `delivery_limits`, or nothing when the API answers none: Limits on how fast deliveries
+ *     of the subscription start; deliveries over them wait for their turn, which does not count as a failure; at least
+ *     one limit must be set
  * @param description carries `description`, or nothing when the API answers none.
+ * @param filter carries `filter`, or nothing when the API answers none: An object with exactly one of: `and` or `or` (a
+ *     list of filters), `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a
+ *     JSON payload); `label` and `field` go alon
+ * @param orderingKey carries `ordering_key`, or nothing when the API answers none: Where the key of an event comes
+ *     from, to deliver the events that share a key one at a time and in the order they were received; exactly one of
+ *     `label` or `field` must be set
+ * @param payloadTransform carries `payload_transform`, or nothing when the API answers none: Declarative reshaping of
+ *     an `application/json` event payload, applied by the worker before the request is signed and sent; operations are
+ *     applied in order (`fields`, `envelope`, `constants`) and a pay
+ * @param responseRules carries `response_rules`, or nothing when the API answers none.
+ * @param retryPolicy carries `retry_policy`, or nothing when the API answers none: Retry schedule of the subscription,
+ *     replacing the one of the instance within its limits; every field left out keeps the value of the instance
  */
 public record Subscription(
     UUID applicationId,
     OffsetDateTime createdAt,
     List<String> dedicatedWorkers,
+    Map<String, Integer> eventTypeVersions,
     List<String> eventTypes,
     Boolean isEnabled,
     String labelKey,
@@ -39,10 +66,21 @@ public record Subscription(
     Map<String, String> labels,
     Map<String, String> metadata,
     UUID secret,
+    SubscriptionSignatureScheme signatureScheme,
     UUID subscriptionId,
     SubscriptionTarget target,
     OffsetDateTime updatedAt,
-    String description) {
+    OffsetDateTime autoDisabledAt,
+    String autoDisabledReason,
+    SubscriptionBatching batching,
+    SubscriptionCircuitBreaker circuitBreaker,
+    SubscriptionDeliveryLimits deliveryLimits,
+    String description,
+    Object filter,
+    SubscriptionOrderingKey orderingKey,
+    SubscriptionPayloadTransform payloadTransform,
+    List<SubscriptionResponseRules> responseRules,
+    SubscriptionRetryPolicy retryPolicy) {
 
   /**
    * Reads one out of what the API answered.
@@ -56,6 +94,7 @@ public record Subscription(
         Wire.read(fields, "application_id", Wire::asUuid),
         Wire.read(fields, "created_at", Wire::asMoment),
         Wire.read(fields, "dedicated_workers", Wire.asList(Wire::asText)),
+        Wire.read(fields, "event_type_versions", Wire.asMap(Wire::asInteger)),
         Wire.read(fields, "event_types", Wire.asList(Wire::asText)),
         Wire.read(fields, "is_enabled", Wire::asBoolean),
         Wire.read(fields, "label_key", Wire::asText),
@@ -63,10 +102,21 @@ public record Subscription(
         Wire.read(fields, "labels", Wire.asMap(Wire::asText)),
         Wire.read(fields, "metadata", Wire.asMap(Wire::asText)),
         Wire.read(fields, "secret", Wire::asUuid),
+        Wire.read(fields, "signature_scheme", SubscriptionSignatureScheme::fromJson),
         Wire.read(fields, "subscription_id", Wire::asUuid),
         Wire.read(fields, "target", SubscriptionTarget::fromJson),
         Wire.read(fields, "updated_at", Wire::asMoment),
-        Wire.maybe(fields, "description", Wire::asText));
+        Wire.maybe(fields, "auto_disabled_at", Wire::asMoment),
+        Wire.maybe(fields, "auto_disabled_reason", Wire::asText),
+        Wire.maybe(fields, "batching", SubscriptionBatching::fromJson),
+        Wire.maybe(fields, "circuit_breaker", SubscriptionCircuitBreaker::fromJson),
+        Wire.maybe(fields, "delivery_limits", SubscriptionDeliveryLimits::fromJson),
+        Wire.maybe(fields, "description", Wire::asText),
+        Wire.maybe(fields, "filter", Wire::asJson),
+        Wire.maybe(fields, "ordering_key", SubscriptionOrderingKey::fromJson),
+        Wire.maybe(fields, "payload_transform", SubscriptionPayloadTransform::fromJson),
+        Wire.maybe(fields, "response_rules", Wire.asList(SubscriptionResponseRules::fromJson)),
+        Wire.maybe(fields, "retry_policy", SubscriptionRetryPolicy::fromJson));
   }
 
   /**
@@ -79,6 +129,7 @@ public record Subscription(
     out.put("application_id", Wire.writeUuid(applicationId));
     out.put("created_at", Wire.writeMoment(createdAt));
     out.put("dedicated_workers", dedicatedWorkers);
+    out.put("event_type_versions", eventTypeVersions);
     out.put("event_types", eventTypes);
     out.put("is_enabled", isEnabled);
     out.put("label_key", labelKey);
@@ -86,12 +137,43 @@ public record Subscription(
     out.put("labels", labels);
     out.put("metadata", metadata);
     out.put("secret", Wire.writeUuid(secret));
+    out.put("signature_scheme", signatureScheme.wireValue());
     out.put("subscription_id", Wire.writeUuid(subscriptionId));
     out.put("target", target.toJson());
     out.put("updated_at", Wire.writeMoment(updatedAt));
+    if (autoDisabledAt != null) {
+      out.put("auto_disabled_at", Wire.writeMoment(autoDisabledAt));
+    }
+    if (autoDisabledReason != null) {
+      out.put("auto_disabled_reason", autoDisabledReason);
+    }
+    if (batching != null) {
+      out.put("batching", batching.toJson());
+    }
+    if (circuitBreaker != null) {
+      out.put("circuit_breaker", circuitBreaker.toJson());
+    }
+    if (deliveryLimits != null) {
+      out.put("delivery_limits", deliveryLimits.toJson());
+    }
     if (description != null) {
       out.put("description", description);
     }
+    if (filter != null) {
+      out.put("filter", filter);
+    }
+    if (orderingKey != null) {
+      out.put("ordering_key", orderingKey.toJson());
+    }
+    if (payloadTransform != null) {
+      out.put("payload_transform", payloadTransform.toJson());
+    }
+    if (responseRules != null) {
+      out.put("response_rules", Wire.writeList(responseRules, SubscriptionResponseRules::toJson));
+    }
+    if (retryPolicy != null) {
+      out.put("retry_policy", retryPolicy.toJson());
+    }
     return out;
   }
 }
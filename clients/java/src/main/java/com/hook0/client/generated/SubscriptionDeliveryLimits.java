@@ -0,0 +1,55 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `SubscriptionDeliveryLimits` the API declares.
+ *
+ * @param maxInFlight carries `max_in_flight`, or nothing when the API answers none: Number of deliveries that can be in
+ *     flight at once
+ * @param maxPerSecond carries `max_per_second`, or nothing when the API answers none: Number of deliveries that can
+ *     start in a second
+ * @param perHost carries `per_host`, or nothing when the API answers none: Share the limits with every subscription of
+ *     the application that delivers to the same host and sets this too, rather than counting the deliveries of this
+ *     subscription alone
+ */
+public record SubscriptionDeliveryLimits(Integer maxInFlight, Integer maxPerSecond, Boolean perHost) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionDeliveryLimits the API declares
+   */
+  public static SubscriptionDeliveryLimits fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionDeliveryLimits");
+    return new SubscriptionDeliveryLimits(
+        Wire.maybe(fields, "max_in_flight", Wire::asInteger),
+        Wire.maybe(fields, "max_per_second", Wire::asInteger),
+        Wire.maybe(fields, "per_host", Wire::asBoolean));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    if (maxInFlight != null) {
+      out.put("max_in_flight", maxInFlight);
+    }
+    if (maxPerSecond != null) {
+      out.put("max_per_second", maxPerSecond);
+    }
+    if (perHost != null) {
+      out.put("per_host", perHost);
+    }
+    return out;
+  }
+}
@@ -0,0 +1,47 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `SubscriptionOrderingKey` the API declares.
+ *
+ * @param field carries `field`, or nothing when the API answers none: JSON pointer to the value holding the key in a
+ *     JSON payload, for example `/customer/id`
+ * @param label carries `label`, or nothing when the API answers none: Name of the event label holding the key
+ */
+public record SubscriptionOrderingKey(String field, String label) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionOrderingKey the API declares
+   */
+  public static SubscriptionOrderingKey fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionOrderingKey");
+    return new SubscriptionOrderingKey(
+        Wire.maybe(fields, "field", Wire::asText),
+        Wire.maybe(fields, "label", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    if (field != null) {
+      out.put("field", field);
+    }
+    if (label != null) {
+      out.put("label", label);
+    }
+    return out;
+  }
+}
@@ -16,26 +16,60 @@ import java.util.UUID;
  * @param eventTypes carries `event_types`.
  * @param isEnabled carries `is_enabled`.
  * @param target carries `target`.
+ * @param batching carries `batching`, or nothing when the API answers none: Deliver the events in batches, as one HTTP
+ *     request with a JSON array body, rather than one by one
+ * @param circuitBreaker carries `circuit_breaker`, or nothing when the API answers none: Disable the subscription once
+ *     its deliveries keep failing; organization editors are notified by email
  * @param dedicatedWorkers carries `dedicated_workers`, or nothing when the API answers none.
+ * @param deliveryLimits carries `delivery_limits`, or nothing when the API answers none: Limit how fast deliveries
+ *     start, for this subscription or for its host
  * @param description carries `description`, or nothing when the API answers none.
+ * @param eventTypeVersions carries `event_type_versions`, or nothing when the API answers none: Only receive the events
+ *     of these event types that declare this version (or no version at all)
+ * @param filter carries `filter`, or nothing when the API answers none: Only deliver the events of the subscribed types
+ *     that match this expression over labels and JSON payload fields
  * @param labelKey carries `label_key`, or nothing when the API answers none: _Kept for backward compatibility, you
  *     should use `labels`_
  * @param labelValue carries `label_value`, or nothing when the API answers none: _Kept for backward compatibility, you
  *     should use `labels`_
  * @param labels carries `labels`, or nothing when the API answers none.
  * @param metadata carries `metadata`, or nothing when the API answers none.
+ * @param orderingKey carries `ordering_key`, or nothing when the API answers none: Deliver the events that share a key
+ *     one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out of
+ *     retries
+ * @param payloadTransform carries `payload_transform`, or nothing when the API answers none: Declarative reshaping of
+ *     an `application/json` event payload, applied by the worker before the request is signed and sent; operations are
+ *     applied in order (`fields`, `envelope`, `constants`) and a pay
+ * @param responseRules carries `response_rules`, or nothing when the API answers none: Decide the outcome of HTTP
+ *     deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body reports
+ *     an error; the first rule that matches wins, and a response n
+ * @param retryPolicy carries `retry_policy`, or nothing when the API answers none: Retry failed deliveries on this
+ *     schedule rather than the instance's one
+ * @param signatureScheme carries `signature_scheme`, or nothing when the API answers none: How deliveries are signed
+ *     (`hook0` by default); `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is
+ *     `whsec_` followed by the base64 encoding of the subscription s
  */
 public record SubscriptionPost(
     UUID applicationId,
     List<String> eventTypes,
     Boolean isEnabled,
     SubscriptionPostTarget target,
+    SubscriptionPostBatching batching,
+    SubscriptionPostCircuitBreaker circuitBreaker,
     List<String> dedicatedWorkers,
+    SubscriptionPostDeliveryLimits deliveryLimits,
     String description,
+    Map<String, Integer> eventTypeVersions,
+    Object filter,
     String labelKey,
     String labelValue,
     Map<String, String> labels,
-    Map<String, String> metadata) {
+    Map<String, String> metadata,
+    SubscriptionPostOrderingKey orderingKey,
+    SubscriptionPostPayloadTransform payloadTransform,
+    List<SubscriptionPostResponseRules> responseRules,
+    SubscriptionPostRetryPolicy retryPolicy,
+    SubscriptionPostSignatureScheme signatureScheme) {
 
   /**
    * Reads one out of what the API answered.
@@ -50,12 +84,22 @@ public record SubscriptionPost(
         Wire.read(fields, "event_types", Wire.asList(Wire::asText)),
         Wire.read(fields, "is_enabled", Wire::asBoolean),
         Wire.read(fields, "target", SubscriptionPostTarget::fromJson),
+        Wire.maybe(fields, "batching", SubscriptionPostBatching::fromJson),
+        Wire.maybe(fields, "circuit_breaker", SubscriptionPostCircuitBreaker::fromJson),
         Wire.maybe(fields, "dedicated_workers", Wire.asList(Wire::asText)),
+        Wire.maybe(fields, "delivery_limits", SubscriptionPostDeliveryLimits::fromJson),
         Wire.maybe(fields, "description", Wire::asText),
+        Wire.maybe(fields, "event_type_versions", Wire.asMap(Wire::asInteger)),
+        Wire.maybe(fields, "filter", Wire::asJson),
         Wire.maybe(fields, "label_key", Wire::asText),
         Wire.maybe(fields, "label_value", Wire::asText),
         Wire.maybe(fields, "labels", Wire.asMap(Wire::asText)),
-        Wire.maybe(fields, "metadata", Wire.asMap(Wire::asText)));
+        Wire.maybe(fields, "metadata", Wire.asMap(Wire::asText)),
+        Wire.maybe(fields, "ordering_key", SubscriptionPostOrderingKey::fromJson),
+        Wire.maybe(fields, "payload_transform", SubscriptionPostPayloadTransform::fromJson),
+        Wire.maybe(fields, "response_rules", Wire.asList(SubscriptionPostResponseRules::fromJson)),
+        Wire.maybe(fields, "retry_policy", SubscriptionPostRetryPolicy::fromJson),
+        Wire.maybe(fields, "signature_scheme", SubscriptionPostSignatureScheme::fromJson));
   }
 
   /**
@@ -69,12 +113,27 @@ public record SubscriptionPost(
     out.put("event_types", eventTypes);
     out.put("is_enabled", isEnabled);
     out.put("target", target.toJson());
+    if (batching != null) {
+      out.put("batching", batching.toJson());
+    }
+    if (circuitBreaker != null) {
+      out.put("circuit_breaker", circuitBreaker.toJson());
+    }
     if (dedicatedWorkers != null) {
       out.put("dedicated_workers", dedicatedWorkers);
     }
+    if (deliveryLimits != null) {
+      out.put("delivery_limits", deliveryLimits.toJson());
+    }
     if (description != null) {
       out.put("description", description);
     }
+    if (eventTypeVersions != null) {
+      out.put("event_type_versions", eventTypeVersions);
+    }
+    if (filter != null) {
+      out.put("filter", filter);
+    }
     if (labelKey != null) {
       out.put("label_key", labelKey);
     }
@@ -87,6 +146,21 @@ public record SubscriptionPost(
     if (metadata != null) {
       out.put("metadata", metadata);
     }
+    if (orderingKey != null) {
+      out.put("ordering_key", orderingKey.toJson());
+    }
+    if (payloadTransform != null) {
+      out.put("payload_transform", payloadTransform.toJson());
+    }
+    if (responseRules != null) {
+      out.put("response_rules", Wire.writeList(responseRules, SubscriptionPostResponseRules::toJson));
+    }
+    if (retryPolicy != null) {
+      out.put("retry_policy", retryPolicy.toJson());
+    }
+    if (signatureScheme != null) {
+      out.put("signature_scheme", signatureScheme.wireValue());
+    }
     return out;
   }
 }
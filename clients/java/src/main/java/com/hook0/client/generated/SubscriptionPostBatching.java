@@ -0,0 +1,42 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `SubscriptionPostBatching` the API declares.
+ *
+ * @param maxEvents carries `max_events`: Largest number of events in a batch
+ * @param maxWaitS carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill up
+ */
+public record SubscriptionPostBatching(Integer maxEvents, Integer maxWaitS) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionPostBatching the API declares
+   */
+  public static SubscriptionPostBatching fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionPostBatching");
+    return new SubscriptionPostBatching(
+        Wire.read(fields, "max_events", Wire::asInteger),
+        Wire.read(fields, "max_wait_s", Wire::asInteger));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("max_events", maxEvents);
+    out.put("max_wait_s", maxWaitS);
+    return out;
+  }
+}
@@ -0,0 +1,48 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `SubscriptionPostCircuitBreaker` the API declares.
+ *
+ * @param maxConsecutiveFailures carries `max_consecutive_failures`, or nothing when the API answers none: Number of
+ *     failed deliveries in a row after which the subscription is disabled
+ * @param maxFailingHours carries `max_failing_hours`, or nothing when the API answers none: Number of hours without a
+ *     single successful delivery after which the subscription is disabled
+ */
+public record SubscriptionPostCircuitBreaker(Integer maxConsecutiveFailures, Integer maxFailingHours) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionPostCircuitBreaker the API declares
+   */
+  public static SubscriptionPostCircuitBreaker fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionPostCircuitBreaker");
+    return new SubscriptionPostCircuitBreaker(
+        Wire.maybe(fields, "max_consecutive_failures", Wire::asInteger),
+        Wire.maybe(fields, "max_failing_hours", Wire::asInteger));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    if (maxConsecutiveFailures != null) {
+      out.put("max_consecutive_failures", maxConsecutiveFailures);
+    }
+    if (maxFailingHours != null) {
+      out.put("max_failing_hours", maxFailingHours);
+    }
+    return out;
+  }
+}
@@ -0,0 +1,57 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * The `SubscriptionPostPayloadTransform` the API declares.
+ *
+ * @param constants carries `constants`, or nothing when the API answers none: Properties added at the top level of the
+ *     result, overriding properties of the same name (the result must then be an object)
+ * @param envelope carries `envelope`, or nothing when the API answers none: Name of a property the result is wrapped in
+ * @param fields_ carries `fields`, or nothing when the API answers none: Values to copy from the payload into a new
+ *     object; when omitted, the whole payload is kept
+ */
+public record SubscriptionPostPayloadTransform(
+    Map<String, Object> constants,
+    String envelope,
+    List<SubscriptionPostPayloadTransformFields> fields_) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionPostPayloadTransform the API declares
+   */
+  public static SubscriptionPostPayloadTransform fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionPostPayloadTransform");
+    return new SubscriptionPostPayloadTransform(
+        Wire.maybe(fields, "constants", Wire.asMap(Wire::asJson)),
+        Wire.maybe(fields, "envelope", Wire::asText),
+        Wire.maybe(fields, "fields", Wire.asList(SubscriptionPostPayloadTransformFields::fromJson)));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    if (constants != null) {
+      out.put("constants", constants);
+    }
+    if (envelope != null) {
+      out.put("envelope", envelope);
+    }
+    if (fields_ != null) {
+      out.put("fields", Wire.writeList(fields_, SubscriptionPostPayloadTransformFields::toJson));
+    }
+    return out;
+  }
+}
@@ -0,0 +1,46 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `SubscriptionPostPayloadTransformFields` the API declares.
+ *
+ * @param from carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that are absent
+ *     from a payload are skipped)
+ * @param to carries `to`, or nothing when the API answers none: JSON pointer where the value is written in the result,
+ *     for example `/id`; defaults to `from`
+ */
+public record SubscriptionPostPayloadTransformFields(String from, String to) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionPostPayloadTransformFields the API declares
+   */
+  public static SubscriptionPostPayloadTransformFields fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionPostPayloadTransformFields");
+    return new SubscriptionPostPayloadTransformFields(
+        Wire.read(fields, "from", Wire::asText),
+        Wire.maybe(fields, "to", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("from", from);
+    if (to != null) {
+      out.put("to", to);
+    }
+    return out;
+  }
+}
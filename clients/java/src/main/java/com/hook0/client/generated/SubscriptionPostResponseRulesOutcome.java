@@ -0,0 +1,56 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.DecodeException;
+import com.hook0.client.Wire;
+
+/**
+ * One of the values the API answers with.
+ */
+public enum SubscriptionPostResponseRulesOutcome {
+  /** The API spells this one `success`. */
+  SUCCESS("success"),
+
+  /** The API spells this one `retryable`. */
+  RETRYABLE("retryable"),
+
+  /** The API spells this one `permanent_failure`. */
+  PERMANENT_FAILURE("permanent_failure"),
+
+  /** The API spells this one `disable_subscription`. */
+  DISABLE_SUBSCRIPTION("disable_subscription");
+
+  private final String wire;
+
+  SubscriptionPostResponseRulesOutcome(String wire) {
+    this.wire = wire;
+  }
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the value it names
+   */
+  public static SubscriptionPostResponseRulesOutcome fromJson(Object value) {
+    String named = Wire.asText(value);
+    for (SubscriptionPostResponseRulesOutcome candidate : values()) {
+      if (candidate.wire.equals(named)) {
+        return candidate;
+      }
+    }
+    throw new DecodeException(
+        "`" + Wire.preview(named) + "` is not one of the values SubscriptionPostResponseRulesOutcome declares");
+  }
+
+  /**
+   * The text this value travels as.
+   *
+   * @return what the API carries it as
+   */
+  public String wireValue() {
+    return wire;
+  }
+}
@@ -0,0 +1,55 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * The `SubscriptionPostRetryPolicy` the API declares.
+ *
+ * @param backoffStepsS carries `backoff_steps_s`, or nothing when the API answers none: Delays in seconds to wait
+ *     before each retry; the last one is used again for the following retries
+ * @param maxRetries carries `max_retries`, or nothing when the API answers none: Maximum number of retries after a
+ *     failed delivery (0 disables retries)
+ * @param maxWindowS carries `max_window_s`, or nothing when the API answers none: Maximum duration in seconds the
+ *     retries can span; a retry that would not fit in it is not scheduled
+ */
+public record SubscriptionPostRetryPolicy(List<Integer> backoffStepsS, Integer maxRetries, Integer maxWindowS) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionPostRetryPolicy the API declares
+   */
+  public static SubscriptionPostRetryPolicy fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionPostRetryPolicy");
+    return new SubscriptionPostRetryPolicy(
+        Wire.maybe(fields, "backoff_steps_s", Wire.asList(Wire::asInteger)),
+        Wire.maybe(fields, "max_retries", Wire::asInteger),
+        Wire.maybe(fields, "max_window_s", Wire::asInteger));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    if (backoffStepsS != null) {
+      out.put("backoff_steps_s", backoffStepsS);
+    }
+    if (maxRetries != null) {
+      out.put("max_retries", maxRetries);
+    }
+    if (maxWindowS != null) {
+      out.put("max_window_s", maxWindowS);
+    }
+    return out;
+  }
+}
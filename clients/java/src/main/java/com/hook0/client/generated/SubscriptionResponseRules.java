@@ -0,0 +1,59 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * The `SubscriptionResponseRules` the API declares.
+ *
+ * @param outcome carries `outcome`: What the delivery becomes when the rule matches: `success`, `retryable`,
+ *     `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+ * @param status carries `status`: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`
+ * @param bodyContains carries `body_contains`, or nothing when the API answers none: Text the response body must
+ *     contain
+ * @param header carries `header`, or nothing when the API answers none: Header the response must have
+ */
+public record SubscriptionResponseRules(
+    SubscriptionResponseRulesOutcome outcome,
+    List<String> status,
+    String bodyContains,
+    SubscriptionResponseRulesHeader header) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionResponseRules the API declares
+   */
+  public static SubscriptionResponseRules fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionResponseRules");
+    return new SubscriptionResponseRules(
+        Wire.read(fields, "outcome", SubscriptionResponseRulesOutcome::fromJson),
+        Wire.read(fields, "status", Wire.asList(Wire::asText)),
+        Wire.maybe(fields, "body_contains", Wire::asText),
+        Wire.maybe(fields, "header", SubscriptionResponseRulesHeader::fromJson));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("outcome", outcome.wireValue());
+    out.put("status", status);
+    if (bodyContains != null) {
+      out.put("body_contains", bodyContains);
+    }
+    if (header != null) {
+      out.put("header", header.toJson());
+    }
+    return out;
+  }
+}
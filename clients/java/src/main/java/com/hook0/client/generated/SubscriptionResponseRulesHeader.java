@@ -0,0 +1,45 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.Wire;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * The `SubscriptionResponseRulesHeader` the API declares.
+ *
+ * @param name carries `name`: Name of the header, regardless of case
+ * @param value carries `value`, or nothing when the API answers none: Value the header must have; when omitted, any
+ *     value matches
+ */
+public record SubscriptionResponseRulesHeader(String name, String value) {
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the SubscriptionResponseRulesHeader the API declares
+   */
+  public static SubscriptionResponseRulesHeader fromJson(Object value) {
+    Map<String, Object> fields = Wire.asFields(value, "SubscriptionResponseRulesHeader");
+    return new SubscriptionResponseRulesHeader(
+        Wire.read(fields, "name", Wire::asText),
+        Wire.maybe(fields, "value", Wire::asText));
+  }
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  public Map<String, Object> toJson() {
+    Map<String, Object> out = new LinkedHashMap<>();
+    out.put("name", name);
+    if (value != null) {
+      out.put("value", value);
+    }
+    return out;
+  }
+}
@@ -10,12 +10,40 @@ import java.util.Map;
 /**
  * The `SubscriptionTarget` the API declares.
  *
- * @param headers carries `headers`.
- * @param method carries `method`.
- * @param type carries `type`.
- * @param url carries `url`.
+ * @param type carries `type`: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the
+ *     other properties depend on it
+ * @param accessKeyId carries `access_key_id`, or nothing when the API answers none: `sqs`: access key ID requests are
+ *     signed with
+ * @param exchange carries `exchange`, or nothing when the API answers none: `amqp`: exchange messages are published to;
+ *     empty for the default exchange
+ * @param headers carries `headers`, or nothing when the API answers none: `http`: headers sent with every request
+ * @param method carries `method`, or nothing when the API answers none: `http`: HTTP method of the requests
+ * @param password carries `password`, or nothing when the API answers none: `amqp`: password to log in to the broker
+ *     with; write-only
+ * @param queueUrl carries `queue_url`, or nothing when the API answers none: `sqs`: URL of the queue
+ * @param region carries `region`, or nothing when the API answers none: `sqs`: region requests are signed for
+ * @param routingKey carries `routing_key`, or nothing when the API answers none: `amqp`: routing key messages are
+ *     published with
+ * @param secretAccessKey carries `secret_access_key`, or nothing when the API answers none: `sqs`: secret access key
+ *     requests are signed with; write-only
+ * @param url carries `url`, or nothing when the API answers none: `http`: URL of the endpoint; `amqp`: URL of the
+ *     broker (`amqp` or `amqps` scheme), without credentials
+ * @param username carries `username`, or nothing when the API answers none: `amqp`: user name to log in to the broker
+ *     with
  */
-public record SubscriptionTarget(Object headers, String method, String type, String url) {
+public record SubscriptionTarget(
+    SubscriptionTargetType type,
+    String accessKeyId,
+    String exchange,
+    Object headers,
+    String method,
+    String password,
+    String queueUrl,
+    String region,
+    String routingKey,
+    String secretAccessKey,
+    String url,
+    String username) {
 
   /**
    * Reads one out of what the API answered.
@@ -26,10 +54,18 @@ public record SubscriptionTarget(Object headers, String method, String type, Str
   public static SubscriptionTarget fromJson(Object value) {
     Map<String, Object> fields = Wire.asFields(value, "SubscriptionTarget");
     return new SubscriptionTarget(
-        Wire.read(fields, "headers", Wire::asJson),
-        Wire.read(fields, "method", Wire::asText),
-        Wire.read(fields, "type", Wire::asText),
-        Wire.read(fields, "url", Wire::asText));
+        Wire.read(fields, "type", SubscriptionTargetType::fromJson),
+        Wire.maybe(fields, "access_key_id", Wire::asText),
+        Wire.maybe(fields, "exchange", Wire::asText),
+        Wire.maybe(fields, "headers", Wire::asJson),
+        Wire.maybe(fields, "method", Wire::asText),
+        Wire.maybe(fields, "password", Wire::asText),
+        Wire.maybe(fields, "queue_url", Wire::asText),
+        Wire.maybe(fields, "region", Wire::asText),
+        Wire.maybe(fields, "routing_key", Wire::asText),
+        Wire.maybe(fields, "secret_access_key", Wire::asText),
+        Wire.maybe(fields, "url", Wire::asText),
+        Wire.maybe(fields, "username", Wire::asText));
   }
 
   /**
@@ -39,10 +75,40 @@ public record SubscriptionTarget(Object headers, String method, String type, Str
    */
   public Map<String, Object> toJson() {
     Map<String, Object> out = new LinkedHashMap<>();
-    out.put("headers", headers);
-    out.put("method", method);
-    out.put("type", type);
-    out.put("url", url);
+    out.put("type", type.wireValue());
+    if (accessKeyId != null) {
+      out.put("access_key_id", accessKeyId);
+    }
+    if (exchange != null) {
+      out.put("exchange", exchange);
+    }
+    if (headers != null) {
+      out.put("headers", headers);
+    }
+    if (method != null) {
+      out.put("method", method);
+    }
+    if (password != null) {
+      out.put("password", password);
+    }
+    if (queueUrl != null) {
+      out.put("queue_url", queueUrl);
+    }
+    if (region != null) {
+      out.put("region", region);
+    }
+    if (routingKey != null) {
+      out.put("routing_key", routingKey);
+    }
+    if (secretAccessKey != null) {
+      out.put("secret_access_key", secretAccessKey);
+    }
+    if (url != null) {
+      out.put("url", url);
+    }
+    if (username != null) {
+      out.put("username", username);
+    }
     return out;
   }
 }
@@ -0,0 +1,56 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=java cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.client.generated;
+
+import com.hook0.client.DecodeException;
+import com.hook0.client.Wire;
+
+/**
+ * One of the values the API answers with.
+ */
+public enum SubscriptionTargetType {
+  /** The API spells this one `http`. */
+  HTTP("http"),
+
+  /** The API spells this one `amqp`. */
+  AMQP("amqp"),
+
+  /** The API spells this one `sqs`. */
+  SQS("sqs"),
+
+  /** The API spells this one `inbox`. */
+  INBOX("inbox");
+
+  private final String wire;
+
+  SubscriptionTargetType(String wire) {
+    this.wire = wire;
+  }
+
+  /**
+   * Reads one out of what the API answered.
+   *
+   * @param value the JSON document the API answered
+   * @return the value it names
+   */
+  public static SubscriptionTargetType fromJson(Object value) {
+    String named = Wire.asText(value);
+    for (SubscriptionTargetType candidate : values()) {
+      if (candidate.wire.equals(named)) {
+        return candidate;
+      }
+    }
+    throw new DecodeException(
+        "`" + Wire.preview(named) + "` is not one of the values SubscriptionTargetType declares");
+  }
+
+  /**
+   * The text this value travels as.
+   *
+   * @return what the API carries it as
+   */
+  public String wireValue() {
+    return wire;
+  }
+}
@@ -0,0 +1,88 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.QueryParameter
+import com.hook0.kotlin.Transport
+import com.hook0.kotlin.Wire
+
+/**
+ * What the API declares under `bulkRetries`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ *
+ * @param transport what one request is issued through
+ */
+class BulkRetriesApi(private val transport: Transport) {
+
+  /**
+   * Cancel a bulk retry
+   *
+   * @param bulkRetryId carries `bulk_retry_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  fun cancel(bulkRetryId: String, applicationId: String): BulkRetry {
+    var path = "/api/v1/bulk_retries/{bulk_retry_id}/cancel"
+    path = path.replace("{bulk_retry_id}", Wire.pathSegment(bulkRetryId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(transport.request("POST", path, query, null), BulkRetry.Companion::fromJson)
+  }
+
+  /**
+   * Create a bulk retry
+   *
+   * @param body the BulkRetryPost the operation reads
+   * @return what the API answered
+   */
+  fun create(body: BulkRetryPost): BulkRetry {
+    val path = "/api/v1/bulk_retries/"
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), BulkRetry.Companion::fromJson)
+  }
+
+  /**
+   * Count what a bulk retry would redeliver
+   *
+   * @param body the BulkRetryPost the operation reads
+   * @return what the API answered
+   */
+  fun dryRun(body: BulkRetryPost): BulkRetryDryRun {
+    val path = "/api/v1/bulk_retries/dry_run"
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.request("POST", path, query, body.toJson()),
+      BulkRetryDryRun.Companion::fromJson
+    )
+  }
+
+  /**
+   * Get a bulk retry
+   *
+   * @param bulkRetryId carries `bulk_retry_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  fun get(bulkRetryId: String, applicationId: String): BulkRetry {
+    var path = "/api/v1/bulk_retries/{bulk_retry_id}"
+    path = path.replace("{bulk_retry_id}", Wire.pathSegment(bulkRetryId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(transport.request("GET", path, query, null), BulkRetry.Companion::fromJson)
+  }
+
+  /**
+   * List bulk retries
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  fun list(applicationId: String): List<BulkRetry> {
+    val path = "/api/v1/bulk_retries/"
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(BulkRetry.Companion::fromJson))
+  }
+}
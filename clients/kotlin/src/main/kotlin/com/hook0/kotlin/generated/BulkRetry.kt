@@ -0,0 +1,109 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `BulkRetry` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property bulkRetryId carries `bulk_retry_id`.
+ * @property createdAt carries `created_at`.
+ * @property eventTypeNames carries `event_type_names`.
+ * @property maxCreatedAt carries `max_created_at`.
+ * @property minCreatedAt carries `min_created_at`.
+ * @property processedCount carries `processed_count`: Number of pairs handled so far
+ * @property retriedCount carries `retried_count`: Number of request attempts created so far
+ * @property status carries `status`.
+ * @property totalCount carries `total_count`: Number of (event, subscription) pairs that matched when the bulk retry
+ *     was created
+ * @property updatedAt carries `updated_at`.
+ * @property cancelledAt carries `cancelled_at`, or nothing when the API answers none.
+ * @property completedAt carries `completed_at`, or nothing when the API answers none.
+ * @property eventId carries `event_id`, or nothing when the API answers none.
+ * @property subscriptionId carries `subscription_id`, or nothing when the API answers none.
+ */
+data class BulkRetry(
+  val applicationId: UUID,
+  val bulkRetryId: UUID,
+  val createdAt: OffsetDateTime,
+  val eventTypeNames: List<String>,
+  val maxCreatedAt: OffsetDateTime,
+  val minCreatedAt: OffsetDateTime,
+  val processedCount: Long,
+  val retriedCount: Long,
+  val status: BulkRetryStatus,
+  val totalCount: Long,
+  val updatedAt: OffsetDateTime,
+  val cancelledAt: OffsetDateTime? = null,
+  val completedAt: OffsetDateTime? = null,
+  val eventId: UUID? = null,
+  val subscriptionId: UUID? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["bulk_retry_id"] = Wire.writeUuid(bulkRetryId)
+    out["created_at"] = Wire.writeMoment(createdAt)
+    out["event_type_names"] = eventTypeNames
+    out["max_created_at"] = Wire.writeMoment(maxCreatedAt)
+    out["min_created_at"] = Wire.writeMoment(minCreatedAt)
+    out["processed_count"] = processedCount
+    out["retried_count"] = retriedCount
+    out["status"] = status.wireValue
+    out["total_count"] = totalCount
+    out["updated_at"] = Wire.writeMoment(updatedAt)
+    if (cancelledAt != null) {
+      out["cancelled_at"] = Wire.writeMoment(cancelledAt)
+    }
+    if (completedAt != null) {
+      out["completed_at"] = Wire.writeMoment(completedAt)
+    }
+    if (eventId != null) {
+      out["event_id"] = Wire.writeUuid(eventId)
+    }
+    if (subscriptionId != null) {
+      out["subscription_id"] = Wire.writeUuid(subscriptionId)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the BulkRetry the API declares
+     */
+    fun fromJson(value: Any?): BulkRetry {
+      val fields = Wire.asFields(value, "BulkRetry")
+      return BulkRetry(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "bulk_retry_id", Wire::asUuid),
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "event_type_names", Wire.asList(Wire::asText)),
+        Wire.read(fields, "max_created_at", Wire::asMoment),
+        Wire.read(fields, "min_created_at", Wire::asMoment),
+        Wire.read(fields, "processed_count", Wire::asLong),
+        Wire.read(fields, "retried_count", Wire::asLong),
+        Wire.read(fields, "status", BulkRetryStatus.Companion::fromJson),
+        Wire.read(fields, "total_count", Wire::asLong),
+        Wire.read(fields, "updated_at", Wire::asMoment),
+        Wire.maybe(fields, "cancelled_at", Wire::asMoment),
+        Wire.maybe(fields, "completed_at", Wire::asMoment),
+        Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "subscription_id", Wire::asUuid)
+      )
+    }
+  }
+}
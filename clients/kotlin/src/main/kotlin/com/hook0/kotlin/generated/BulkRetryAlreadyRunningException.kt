@@ -0,0 +1,14 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+/**
+ * The API reported `BulkRetryAlreadyRunning`.
+ *
+ * @param status what the API answered under
+ * @param problem the document it answered, or nothing when none could be read
+ * @param detail what to say about the failure
+ */
+class BulkRetryAlreadyRunningException(status: Int, problem: Problem?, detail: String) :
+  ProblemException(status, problem, detail)
@@ -0,0 +1,38 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `BulkRetryDryRun` the API declares.
+ *
+ * @property count carries `count`: Number of (event, subscription) pairs a bulk retry would redeliver now
+ */
+data class BulkRetryDryRun(val count: Long) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["count"] = count
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the BulkRetryDryRun the API declares
+     */
+    fun fromJson(value: Any?): BulkRetryDryRun {
+      val fields = Wire.asFields(value, "BulkRetryDryRun")
+      return BulkRetryDryRun(Wire.read(fields, "count", Wire::asLong))
+    }
+  }
+}
@@ -0,0 +1,81 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `BulkRetryPost` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property eventId carries `event_id`, or nothing when the API answers none.
+ * @property eventTypeNames carries `event_type_names`, or nothing when the API answers none.
+ * @property maxCreatedAt carries `max_created_at`, or nothing when the API answers none.
+ * @property minCreatedAt carries `min_created_at`, or nothing when the API answers none.
+ * @property status carries `status`, or nothing when the API answers none: Redeliver request attempts that failed
+ *     (default) or that succeeded
+ * @property subscriptionId carries `subscription_id`, or nothing when the API answers none.
+ */
+data class BulkRetryPost(
+  val applicationId: UUID,
+  val eventId: UUID? = null,
+  val eventTypeNames: List<String>? = null,
+  val maxCreatedAt: OffsetDateTime? = null,
+  val minCreatedAt: OffsetDateTime? = null,
+  val status: BulkRetryPostStatus? = null,
+  val subscriptionId: UUID? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    if (eventId != null) {
+      out["event_id"] = Wire.writeUuid(eventId)
+    }
+    if (eventTypeNames != null) {
+      out["event_type_names"] = eventTypeNames
+    }
+    if (maxCreatedAt != null) {
+      out["max_created_at"] = Wire.writeMoment(maxCreatedAt)
+    }
+    if (minCreatedAt != null) {
+      out["min_created_at"] = Wire.writeMoment(minCreatedAt)
+    }
+    if (status != null) {
+      out["status"] = status.wireValue
+    }
+    if (subscriptionId != null) {
+      out["subscription_id"] = Wire.writeUuid(subscriptionId)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the BulkRetryPost the API declares
+     */
+    fun fromJson(value: Any?): BulkRetryPost {
+      val fields = Wire.asFields(value, "BulkRetryPost")
+      return BulkRetryPost(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "event_type_names", Wire.asList(Wire::asText)),
+        Wire.maybe(fields, "max_created_at", Wire::asMoment),
+        Wire.maybe(fields, "min_created_at", Wire::asMoment),
+        Wire.maybe(fields, "status", BulkRetryPostStatus.Companion::fromJson),
+        Wire.maybe(fields, "subscription_id", Wire::asUuid)
+      )
+    }
+  }
+}
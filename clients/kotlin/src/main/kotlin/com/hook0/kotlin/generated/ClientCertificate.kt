@@ -0,0 +1,72 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `ClientCertificate` the API declares.
+ *
+ * @property certificatePem carries `certificate_pem`: Certificate chain, leaf certificate first
+ * @property createdAt carries `created_at`.
+ * @property fingerprintSha256 carries `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate, as hexadecimal
+ * @property notAfter carries `not_after`.
+ * @property notBefore carries `not_before`.
+ * @property subject carries `subject`.
+ * @property subscriptionId carries `subscription_id`.
+ * @property updatedAt carries `updated_at`.
+ */
+data class ClientCertificate(
+  val certificatePem: String,
+  val createdAt: OffsetDateTime,
+  val fingerprintSha256: String,
+  val notAfter: OffsetDateTime,
+  val notBefore: OffsetDateTime,
+  val subject: String,
+  val subscriptionId: UUID,
+  val updatedAt: OffsetDateTime
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["certificate_pem"] = certificatePem
+    out["created_at"] = Wire.writeMoment(createdAt)
+    out["fingerprint_sha256"] = fingerprintSha256
+    out["not_after"] = Wire.writeMoment(notAfter)
+    out["not_before"] = Wire.writeMoment(notBefore)
+    out["subject"] = subject
+    out["subscription_id"] = Wire.writeUuid(subscriptionId)
+    out["updated_at"] = Wire.writeMoment(updatedAt)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the ClientCertificate the API declares
+     */
+    fun fromJson(value: Any?): ClientCertificate {
+      val fields = Wire.asFields(value, "ClientCertificate")
+      return ClientCertificate(
+        Wire.read(fields, "certificate_pem", Wire::asText),
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "fingerprint_sha256", Wire::asText),
+        Wire.read(fields, "not_after", Wire::asMoment),
+        Wire.read(fields, "not_before", Wire::asMoment),
+        Wire.read(fields, "subject", Wire::asText),
+        Wire.read(fields, "subscription_id", Wire::asUuid),
+        Wire.read(fields, "updated_at", Wire::asMoment)
+      )
+    }
+  }
+}
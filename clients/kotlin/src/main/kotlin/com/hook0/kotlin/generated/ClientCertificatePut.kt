@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `ClientCertificatePut` the API declares.
+ *
+ * @property certificatePem carries `certificate_pem`: PEM-encoded certificate chain, leaf certificate first
+ * @property privateKeyPem carries `private_key_pem`: PEM-encoded unencrypted private key of the leaf certificate
+ *     (PKCS#8, PKCS#1 or SEC1)
+ */
+data class ClientCertificatePut(val certificatePem: String, val privateKeyPem: String) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["certificate_pem"] = certificatePem
+    out["private_key_pem"] = privateKeyPem
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the ClientCertificatePut the API declares
+     */
+    fun fromJson(value: Any?): ClientCertificatePut {
+      val fields = Wire.asFields(value, "ClientCertificatePut")
+      return ClientCertificatePut(
+        Wire.read(fields, "certificate_pem", Wire::asText),
+        Wire.read(fields, "private_key_pem", Wire::asText)
+      )
+    }
+  }
+}
@@ -0,0 +1,66 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.QueryParameter
+import com.hook0.kotlin.Transport
+import com.hook0.kotlin.Wire
+
+/**
+ * What the API declares under `clientCertificates`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ *
+ * @param transport what one request is issued through
+ */
+class ClientCertificatesApi(private val transport: Transport) {
+
+  /**
+   * Remove the client certificate of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   */
+  fun delete(subscriptionId: String, applicationId: String) {
+    var path = "/api/v1/subscriptions/{subscription_id}/client_certificate"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    Problems.checkAnswer(transport.request("DELETE", path, query, null))
+  }
+
+  /**
+   * Get the client certificate of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  fun get(subscriptionId: String, applicationId: String): ClientCertificate {
+    var path = "/api/v1/subscriptions/{subscription_id}/client_certificate"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(transport.request("GET", path, query, null), ClientCertificate.Companion::fromJson)
+  }
+
+  /**
+   * Set the client certificate of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @param body the ClientCertificatePut the operation reads
+   * @return what the API answered
+   */
+  fun update(subscriptionId: String, applicationId: String, body: ClientCertificatePut): ClientCertificate {
+    var path = "/api/v1/subscriptions/{subscription_id}/client_certificate"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(
+      transport.request("PUT", path, query, body.toJson()),
+      ClientCertificate.Companion::fromJson
+    )
+  }
+}
@@ -0,0 +1,49 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `DeadLetterDetails` the API declares.
+ *
+ * @property deadLetter carries `dead_letter`: Delivery an output worker gave up on
+ * @property lastResponse carries `last_response`, or nothing when the API answers none: Response to the last request
+ *     attempt, if the target was reached
+ */
+data class DeadLetterDetails(
+  val deadLetter: DeadLetterDetailsDeadLetter,
+  val lastResponse: DeadLetterDetailsLastResponse? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["dead_letter"] = deadLetter.toJson()
+    if (lastResponse != null) {
+      out["last_response"] = lastResponse.toJson()
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the DeadLetterDetails the API declares
+     */
+    fun fromJson(value: Any?): DeadLetterDetails {
+      val fields = Wire.asFields(value, "DeadLetterDetails")
+      return DeadLetterDetails(
+        Wire.read(fields, "dead_letter", DeadLetterDetailsDeadLetter.Companion::fromJson),
+        Wire.maybe(fields, "last_response", DeadLetterDetailsLastResponse.Companion::fromJson)
+      )
+    }
+  }
+}
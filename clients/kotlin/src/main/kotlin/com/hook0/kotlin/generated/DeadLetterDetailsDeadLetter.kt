@@ -0,0 +1,81 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `DeadLetterDetailsDeadLetter` the API declares.
+ *
+ * @property createdAt carries `created_at`.
+ * @property deadLetterId carries `dead_letter_id`.
+ * @property event carries `event`.
+ * @property reason carries `reason`.
+ * @property requestAttemptId carries `request_attempt_id`: Last request attempt of the delivery
+ * @property retryCount carries `retry_count`.
+ * @property subscription carries `subscription`.
+ * @property httpResponseStatus carries `http_response_status`, or nothing when the API answers none.
+ * @property responseId carries `response_id`, or nothing when the API answers none: Response to the last request
+ *     attempt, if the target was reached
+ */
+data class DeadLetterDetailsDeadLetter(
+  val createdAt: OffsetDateTime,
+  val deadLetterId: UUID,
+  val event: DeadLetterDetailsDeadLetterEvent,
+  val reason: DeadLetterDetailsDeadLetterReason,
+  val requestAttemptId: UUID,
+  val retryCount: Int,
+  val subscription: DeadLetterDetailsDeadLetterSubscription,
+  val httpResponseStatus: Int? = null,
+  val responseId: UUID? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["created_at"] = Wire.writeMoment(createdAt)
+    out["dead_letter_id"] = Wire.writeUuid(deadLetterId)
+    out["event"] = event.toJson()
+    out["reason"] = reason.wireValue
+    out["request_attempt_id"] = Wire.writeUuid(requestAttemptId)
+    out["retry_count"] = retryCount
+    out["subscription"] = subscription.toJson()
+    if (httpResponseStatus != null) {
+      out["http_response_status"] = httpResponseStatus
+    }
+    if (responseId != null) {
+      out["response_id"] = Wire.writeUuid(responseId)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the DeadLetterDetailsDeadLetter the API declares
+     */
+    fun fromJson(value: Any?): DeadLetterDetailsDeadLetter {
+      val fields = Wire.asFields(value, "DeadLetterDetailsDeadLetter")
+      return DeadLetterDetailsDeadLetter(
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "dead_letter_id", Wire::asUuid),
+        Wire.read(fields, "event", DeadLetterDetailsDeadLetterEvent.Companion::fromJson),
+        Wire.read(fields, "reason", DeadLetterDetailsDeadLetterReason.Companion::fromJson),
+        Wire.read(fields, "request_attempt_id", Wire::asUuid),
+        Wire.read(fields, "retry_count", Wire::asInteger),
+        Wire.read(fields, "subscription", DeadLetterDetailsDeadLetterSubscription.Companion::fromJson),
+        Wire.maybe(fields, "http_response_status", Wire::asInteger),
+        Wire.maybe(fields, "response_id", Wire::asUuid)
+      )
+    }
+  }
+}
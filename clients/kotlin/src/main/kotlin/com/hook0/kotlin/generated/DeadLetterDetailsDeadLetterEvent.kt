@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `DeadLetterDetailsDeadLetterEvent` the API declares.
+ *
+ * @property eventId carries `event_id`.
+ * @property eventTypeName carries `event_type_name`.
+ */
+data class DeadLetterDetailsDeadLetterEvent(val eventId: UUID, val eventTypeName: String) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["event_id"] = Wire.writeUuid(eventId)
+    out["event_type_name"] = eventTypeName
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the DeadLetterDetailsDeadLetterEvent the API declares
+     */
+    fun fromJson(value: Any?): DeadLetterDetailsDeadLetterEvent {
+      val fields = Wire.asFields(value, "DeadLetterDetailsDeadLetterEvent")
+      return DeadLetterDetailsDeadLetterEvent(
+        Wire.read(fields, "event_id", Wire::asUuid),
+        Wire.read(fields, "event_type_name", Wire::asText)
+      )
+    }
+  }
+}
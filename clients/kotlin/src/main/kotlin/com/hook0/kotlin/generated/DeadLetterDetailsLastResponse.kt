@@ -0,0 +1,73 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `DeadLetterDetailsLastResponse` the API declares.
+ *
+ * @property responseId carries `response_id`.
+ * @property body_ carries `body`, or nothing when the API answers none.
+ * @property elapsedTimeMs carries `elapsed_time_ms`, or nothing when the API answers none.
+ * @property headers carries `headers`, or nothing when the API answers none.
+ * @property httpCode carries `http_code`, or nothing when the API answers none.
+ * @property responseErrorName carries `response_error_name`, or nothing when the API answers none.
+ */
+data class DeadLetterDetailsLastResponse(
+  val responseId: UUID,
+  val body_: String? = null,
+  val elapsedTimeMs: Int? = null,
+  val headers: Map<String, String>? = null,
+  val httpCode: Int? = null,
+  val responseErrorName: String? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["response_id"] = Wire.writeUuid(responseId)
+    if (body_ != null) {
+      out["body"] = body_
+    }
+    if (elapsedTimeMs != null) {
+      out["elapsed_time_ms"] = elapsedTimeMs
+    }
+    if (headers != null) {
+      out["headers"] = headers
+    }
+    if (httpCode != null) {
+      out["http_code"] = httpCode
+    }
+    if (responseErrorName != null) {
+      out["response_error_name"] = responseErrorName
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the DeadLetterDetailsLastResponse the API declares
+     */
+    fun fromJson(value: Any?): DeadLetterDetailsLastResponse {
+      val fields = Wire.asFields(value, "DeadLetterDetailsLastResponse")
+      return DeadLetterDetailsLastResponse(
+        Wire.read(fields, "response_id", Wire::asUuid),
+        Wire.maybe(fields, "body", Wire::asText),
+        Wire.maybe(fields, "elapsed_time_ms", Wire::asInteger),
+        Wire.maybe(fields, "headers", Wire.asMap(Wire::asText)),
+        Wire.maybe(fields, "http_code", Wire::asInteger),
+        Wire.maybe(fields, "response_error_name", Wire::asText)
+      )
+    }
+  }
+}
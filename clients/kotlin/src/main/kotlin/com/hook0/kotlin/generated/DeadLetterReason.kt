@@ -0,0 +1,42 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.DecodeException
+import com.hook0.kotlin.Wire
+
+/**
+ * One of the values the API answers with.
+ */
+enum class DeadLetterReason(val wireValue: String) {
+  /** The API spells this one `retries_exhausted`. */
+  RETRIES_EXHAUSTED("retries_exhausted"),
+
+  /** The API spells this one `signature_failed`. */
+  SIGNATURE_FAILED("signature_failed"),
+
+  /** The API spells this one `transform_failed`. */
+  TRANSFORM_FAILED("transform_failed"),
+
+  /** The API spells this one `permanent_failure`. */
+  PERMANENT_FAILURE("permanent_failure");
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the value it names
+     */
+    fun fromJson(value: Any?): DeadLetterReason {
+      val named = Wire.asText(value)
+      for (candidate in DeadLetterReason.entries) {
+        if (candidate.wireValue == named) {
+          return candidate
+        }
+      }
+      throw DecodeException("`${Wire.preview(named)}` is not one of the values DeadLetterReason declares")
+    }
+  }
+}
@@ -0,0 +1,46 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `DeadLetterSubscription` the API declares.
+ *
+ * @property subscriptionId carries `subscription_id`.
+ * @property description carries `description`, or nothing when the API answers none.
+ */
+data class DeadLetterSubscription(val subscriptionId: UUID, val description: String? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["subscription_id"] = Wire.writeUuid(subscriptionId)
+    if (description != null) {
+      out["description"] = description
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the DeadLetterSubscription the API declares
+     */
+    fun fromJson(value: Any?): DeadLetterSubscription {
+      val fields = Wire.asFields(value, "DeadLetterSubscription")
+      return DeadLetterSubscription(
+        Wire.read(fields, "subscription_id", Wire::asUuid),
+        Wire.maybe(fields, "description", Wire::asText)
+      )
+    }
+  }
+}
@@ -0,0 +1,115 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.QueryParameter
+import com.hook0.kotlin.Transport
+import com.hook0.kotlin.Wire
+
+/**
+ * What the API declares under `deadLetters`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ *
+ * @param transport what one request is issued through
+ */
+class DeadLettersApi(private val transport: Transport) {
+
+  /**
+   * Delete a dead letter
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param applicationId carries `application_id`.
+   */
+  fun delete(deadLetterId: String, applicationId: String) {
+    var path = "/api/v1/dead_letters/{dead_letter_id}"
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    Problems.checkAnswer(transport.request("DELETE", path, query, null))
+  }
+
+  /**
+   * Get a dead letter by its ID
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  fun get(deadLetterId: String, applicationId: String): DeadLetterDetails {
+    var path = "/api/v1/dead_letters/{dead_letter_id}"
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(transport.request("GET", path, query, null), DeadLetterDetails.Companion::fromJson)
+  }
+
+  /**
+   * List dead letters
+   *
+   * @param applicationId carries `application_id`.
+   * @param paginationCursor carries `pagination_cursor`, or nothing when the caller sends none.
+   * @param subscriptionId carries `subscription_id`, or nothing when the caller sends none.
+   * @return what the API answered
+   */
+  fun list(applicationId: String, paginationCursor: String? = null, subscriptionId: String? = null): List<DeadLetter> {
+    val path = "/api/v1/dead_letters/"
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    if (paginationCursor != null) {
+      query.add(QueryParameter("pagination_cursor", Wire.queryValue(paginationCursor)))
+    }
+    if (subscriptionId != null) {
+      query.add(QueryParameter("subscription_id", Wire.queryValue(subscriptionId)))
+    }
+    return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(DeadLetter.Companion::fromJson))
+  }
+
+  /**
+   * Purge dead letters
+   *
+   * @param body the PurgeDeadLetters the operation reads
+   * @return what the API answered
+   */
+  fun purge(body: PurgeDeadLetters): PurgedDeadLetters {
+    val path = "/api/v1/dead_letters/purge"
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.request("POST", path, query, body.toJson()),
+      PurgedDeadLetters.Companion::fromJson
+    )
+  }
+
+  /**
+   * Redeliver a dead letter
+   *
+   * @param deadLetterId carries `dead_letter_id`.
+   * @param body the RedeliverDeadLetter the operation reads
+   * @return what the API answered
+   */
+  fun redeliver(deadLetterId: String, body: RedeliverDeadLetter): RedeliveredDeadLetter {
+    var path = "/api/v1/dead_letters/{dead_letter_id}/redeliver"
+    path = path.replace("{dead_letter_id}", Wire.pathSegment(deadLetterId))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.request("POST", path, query, body.toJson()),
+      RedeliveredDeadLetter.Companion::fromJson
+    )
+  }
+
+  /**
+   * Redeliver dead letters
+   *
+   * @param body the RedeliverDeadLetters the operation reads
+   * @return what the API answered
+   */
+  fun redeliverMany(body: RedeliverDeadLetters): List<RedeliveredDeadLetter> {
+    val path = "/api/v1/dead_letters/redeliver"
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.request("POST", path, query, body.toJson()),
+      Wire.asList(RedeliveredDeadLetter.Companion::fromJson)
+    )
+  }
+}
@@ -17,6 +17,13 @@ import java.util.UUID
  * @property occurredAt carries `occurred_at`.
  * @property payloadContentType carries `payload_content_type`.
  * @property receivedAt carries `received_at`.
+ * @property cancelledAt carries `cancelled_at`, or nothing when the API answers none: Date the scheduled delivery of
+ *     the event was cancelled
+ * @property deliverAt carries `deliver_at`, or nothing when the API answers none: Date from which the event is
+ *     delivered, when it was scheduled for a later delivery
+ * @property eventTypeVersion carries `event_type_version`, or nothing when the API answers none.
+ * @property expiresAt carries `expires_at`, or nothing when the API answers none: Date after which the event is no
+ *     longer delivered
  * @property metadata carries `metadata`, or nothing when the API answers none.
  */
 data class Event(
@@ -27,6 +34,10 @@ data class Event(
   val occurredAt: OffsetDateTime,
   val payloadContentType: String,
   val receivedAt: OffsetDateTime,
+  val cancelledAt: OffsetDateTime? = null,
+  val deliverAt: OffsetDateTime? = null,
+  val eventTypeVersion: Int? = null,
+  val expiresAt: OffsetDateTime? = null,
   val metadata: Any? = null
 ) {
 
@@ -44,6 +55,18 @@ data class Event(
     out["occurred_at"] = Wire.writeMoment(occurredAt)
     out["payload_content_type"] = payloadContentType
     out["received_at"] = Wire.writeMoment(receivedAt)
+    if (cancelledAt != null) {
+      out["cancelled_at"] = Wire.writeMoment(cancelledAt)
+    }
+    if (deliverAt != null) {
+      out["deliver_at"] = Wire.writeMoment(deliverAt)
+    }
+    if (eventTypeVersion != null) {
+      out["event_type_version"] = eventTypeVersion
+    }
+    if (expiresAt != null) {
+      out["expires_at"] = Wire.writeMoment(expiresAt)
+    }
     if (metadata != null) {
       out["metadata"] = metadata
     }
@@ -67,6 +90,10 @@ data class Event(
         Wire.read(fields, "occurred_at", Wire::asMoment),
         Wire.read(fields, "payload_content_type", Wire::asText),
         Wire.read(fields, "received_at", Wire::asMoment),
+        Wire.maybe(fields, "cancelled_at", Wire::asMoment),
+        Wire.maybe(fields, "deliver_at", Wire::asMoment),
+        Wire.maybe(fields, "event_type_version", Wire::asInteger),
+        Wire.maybe(fields, "expires_at", Wire::asMoment),
         Wire.maybe(fields, "metadata", Wire::asJson)
       )
     }
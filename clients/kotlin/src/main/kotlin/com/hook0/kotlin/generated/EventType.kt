@@ -9,15 +9,24 @@ import com.hook0.kotlin.Wire
  * The `EventType` the API declares.
  *
  * @property eventTypeName carries `event_type_name`.
+ * @property payloadSchemaMode carries `payload_schema_mode`.
  * @property resourceTypeName carries `resource_type_name`.
  * @property serviceName carries `service_name`.
  * @property verbName carries `verb_name`.
+ * @property versions carries `versions`.
+ * @property defaultTtlS carries `default_ttl_s`, or nothing when the API answers none: Time to live in seconds of the
+ *     events of this type that do not give an expiry date
+ * @property payloadSchema carries `payload_schema`, or nothing when the API answers none.
  */
 data class EventType(
   val eventTypeName: String,
+  val payloadSchemaMode: EventTypePayloadSchemaMode,
   val resourceTypeName: String,
   val serviceName: String,
-  val verbName: String
+  val verbName: String,
+  val versions: List<EventTypeVersions>,
+  val defaultTtlS: Int? = null,
+  val payloadSchema: Any? = null
 ) {
 
   /**
@@ -28,9 +37,17 @@ data class EventType(
   fun toJson(): Map<String, Any?> {
     val out = LinkedHashMap<String, Any?>()
     out["event_type_name"] = eventTypeName
+    out["payload_schema_mode"] = payloadSchemaMode.wireValue
     out["resource_type_name"] = resourceTypeName
     out["service_name"] = serviceName
     out["verb_name"] = verbName
+    out["versions"] = Wire.writeList(versions, EventTypeVersions::toJson)
+    if (defaultTtlS != null) {
+      out["default_ttl_s"] = defaultTtlS
+    }
+    if (payloadSchema != null) {
+      out["payload_schema"] = payloadSchema
+    }
     return out
   }
 
@@ -45,9 +62,13 @@ data class EventType(
       val fields = Wire.asFields(value, "EventType")
       return EventType(
         Wire.read(fields, "event_type_name", Wire::asText),
+        Wire.read(fields, "payload_schema_mode", EventTypePayloadSchemaMode.Companion::fromJson),
         Wire.read(fields, "resource_type_name", Wire::asText),
         Wire.read(fields, "service_name", Wire::asText),
-        Wire.read(fields, "verb_name", Wire::asText)
+        Wire.read(fields, "verb_name", Wire::asText),
+        Wire.read(fields, "versions", Wire.asList(EventTypeVersions.Companion::fromJson)),
+        Wire.maybe(fields, "default_ttl_s", Wire::asInteger),
+        Wire.maybe(fields, "payload_schema", Wire::asJson)
       )
     }
   }
@@ -13,8 +13,22 @@ import java.util.UUID
  * @property resourceType carries `resource_type`.
  * @property service carries `service`.
  * @property verb carries `verb`.
+ * @property defaultTtlS carries `default_ttl_s`, or nothing when the API answers none: Time to live in seconds of the
+ *     events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+ * @property payloadSchema carries `payload_schema`, or nothing when the API answers none: JSON Schema the payloads of
+ *     the `application/json` events of this type must match; remote references are not supported
+ * @property payloadSchemaMode carries `payload_schema_mode`, or nothing when the API answers none: How the payload
+ *     schema is applied at ingestion (default: `enforce`)
  */
-data class EventTypePost(val applicationId: UUID, val resourceType: String, val service: String, val verb: String) {
+data class EventTypePost(
+  val applicationId: UUID,
+  val resourceType: String,
+  val service: String,
+  val verb: String,
+  val defaultTtlS: Int? = null,
+  val payloadSchema: Any? = null,
+  val payloadSchemaMode: EventTypePostPayloadSchemaMode? = null
+) {
 
   /**
    * Writes one back the way the API reads it.
@@ -27,6 +41,15 @@ data class EventTypePost(val applicationId: UUID, val resourceType: String, val
     out["resource_type"] = resourceType
     out["service"] = service
     out["verb"] = verb
+    if (defaultTtlS != null) {
+      out["default_ttl_s"] = defaultTtlS
+    }
+    if (payloadSchema != null) {
+      out["payload_schema"] = payloadSchema
+    }
+    if (payloadSchemaMode != null) {
+      out["payload_schema_mode"] = payloadSchemaMode.wireValue
+    }
     return out
   }
 
@@ -43,7 +66,10 @@ data class EventTypePost(val applicationId: UUID, val resourceType: String, val
         Wire.read(fields, "application_id", Wire::asUuid),
         Wire.read(fields, "resource_type", Wire::asText),
         Wire.read(fields, "service", Wire::asText),
-        Wire.read(fields, "verb", Wire::asText)
+        Wire.read(fields, "verb", Wire::asText),
+        Wire.maybe(fields, "default_ttl_s", Wire::asInteger),
+        Wire.maybe(fields, "payload_schema", Wire::asJson),
+        Wire.maybe(fields, "payload_schema_mode", EventTypePostPayloadSchemaMode.Companion::fromJson)
       )
     }
   }
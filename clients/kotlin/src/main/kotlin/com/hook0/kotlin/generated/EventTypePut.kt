@@ -0,0 +1,64 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `EventTypePut` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property defaultTtlS carries `default_ttl_s`, or nothing when the API answers none: Time to live in seconds of the
+ *     events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+ * @property payloadSchema carries `payload_schema`, or nothing when the API answers none: JSON Schema the payloads of
+ *     the `application/json` events of this type must match; remote references are not supported
+ * @property payloadSchemaMode carries `payload_schema_mode`, or nothing when the API answers none: How the payload
+ *     schema is applied at ingestion (default: `enforce`)
+ */
+data class EventTypePut(
+  val applicationId: UUID,
+  val defaultTtlS: Int? = null,
+  val payloadSchema: Any? = null,
+  val payloadSchemaMode: EventTypePutPayloadSchemaMode? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    if (defaultTtlS != null) {
+      out["default_ttl_s"] = defaultTtlS
+    }
+    if (payloadSchema != null) {
+      out["payload_schema"] = payloadSchema
+    }
+    if (payloadSchemaMode != null) {
+      out["payload_schema_mode"] = payloadSchemaMode.wireValue
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the EventTypePut the API declares
+     */
+    fun fromJson(value: Any?): EventTypePut {
+      val fields = Wire.asFields(value, "EventTypePut")
+      return EventTypePut(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.maybe(fields, "default_ttl_s", Wire::asInteger),
+        Wire.maybe(fields, "payload_schema", Wire::asJson),
+        Wire.maybe(fields, "payload_schema_mode", EventTypePutPayloadSchemaMode.Companion::fromJson)
+      )
+    }
+  }
+}
@@ -0,0 +1,67 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+
+/**
+ * The `EventTypeVersion` the API declares.
+ *
+ * @property createdAt carries `created_at`.
+ * @property version carries `version`.
+ * @property deprecatedAt carries `deprecated_at`, or nothing when the API answers none.
+ * @property payloadSchema carries `payload_schema`, or nothing when the API answers none: JSON Schema the payloads of
+ *     the events declaring this version must match, instead of the one of the event type
+ * @property sunsetAt carries `sunset_at`, or nothing when the API answers none: Date from which the events declaring
+ *     this version are refused
+ */
+data class EventTypeVersion(
+  val createdAt: OffsetDateTime,
+  val version: Int,
+  val deprecatedAt: OffsetDateTime? = null,
+  val payloadSchema: Any? = null,
+  val sunsetAt: OffsetDateTime? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["created_at"] = Wire.writeMoment(createdAt)
+    out["version"] = version
+    if (deprecatedAt != null) {
+      out["deprecated_at"] = Wire.writeMoment(deprecatedAt)
+    }
+    if (payloadSchema != null) {
+      out["payload_schema"] = payloadSchema
+    }
+    if (sunsetAt != null) {
+      out["sunset_at"] = Wire.writeMoment(sunsetAt)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the EventTypeVersion the API declares
+     */
+    fun fromJson(value: Any?): EventTypeVersion {
+      val fields = Wire.asFields(value, "EventTypeVersion")
+      return EventTypeVersion(
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "version", Wire::asInteger),
+        Wire.maybe(fields, "deprecated_at", Wire::asMoment),
+        Wire.maybe(fields, "payload_schema", Wire::asJson),
+        Wire.maybe(fields, "sunset_at", Wire::asMoment)
+      )
+    }
+  }
+}
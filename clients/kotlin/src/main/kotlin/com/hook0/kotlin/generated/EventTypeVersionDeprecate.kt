@@ -0,0 +1,51 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `EventTypeVersionDeprecate` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property version carries `version`.
+ * @property sunsetAt carries `sunset_at`, or nothing when the API answers none: Date from which the events declaring
+ *     this version are refused; none keeps accepting them
+ */
+data class EventTypeVersionDeprecate(val applicationId: UUID, val version: Int, val sunsetAt: OffsetDateTime? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["version"] = version
+    if (sunsetAt != null) {
+      out["sunset_at"] = Wire.writeMoment(sunsetAt)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the EventTypeVersionDeprecate the API declares
+     */
+    fun fromJson(value: Any?): EventTypeVersionDeprecate {
+      val fields = Wire.asFields(value, "EventTypeVersionDeprecate")
+      return EventTypeVersionDeprecate(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "version", Wire::asInteger),
+        Wire.maybe(fields, "sunset_at", Wire::asMoment)
+      )
+    }
+  }
+}
@@ -0,0 +1,51 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `EventTypeVersionPost` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property version carries `version`.
+ * @property payloadSchema carries `payload_schema`, or nothing when the API answers none: JSON Schema the payloads of
+ *     the `application/json` events declaring this version must match, instead of the one of the event type; remote
+ *     references are not supported
+ */
+data class EventTypeVersionPost(val applicationId: UUID, val version: Int, val payloadSchema: Any? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["version"] = version
+    if (payloadSchema != null) {
+      out["payload_schema"] = payloadSchema
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the EventTypeVersionPost the API declares
+     */
+    fun fromJson(value: Any?): EventTypeVersionPost {
+      val fields = Wire.asFields(value, "EventTypeVersionPost")
+      return EventTypeVersionPost(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "version", Wire::asInteger),
+        Wire.maybe(fields, "payload_schema", Wire::asJson)
+      )
+    }
+  }
+}
@@ -28,6 +28,23 @@ class EventTypesApi(private val transport: Transport) {
     return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), EventType.Companion::fromJson)
   }
 
+  /**
+   * Create a new event type version
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypeVersionPost the operation reads
+   * @return what the API answered
+   */
+  fun createVersion(eventTypeName: String, body: EventTypeVersionPost): EventTypeVersion {
+    var path = "/api/v1/event_types/{event_type_name}/versions"
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.request("POST", path, query, body.toJson()),
+      EventTypeVersion.Companion::fromJson
+    )
+  }
+
   /**
    * Delete an event type
    *
@@ -42,6 +59,23 @@ class EventTypesApi(private val transport: Transport) {
     Problems.checkAnswer(transport.request("DELETE", path, query, null))
   }
 
+  /**
+   * Deprecate an event type version
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypeVersionDeprecate the operation reads
+   * @return what the API answered
+   */
+  fun deprecateVersion(eventTypeName: String, body: EventTypeVersionDeprecate): EventTypeVersion {
+    var path = "/api/v1/event_types/{event_type_name}/deprecate"
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.request("POST", path, query, body.toJson()),
+      EventTypeVersion.Companion::fromJson
+    )
+  }
+
   /**
    * Get an event type by its name
    *
@@ -69,4 +103,18 @@ class EventTypesApi(private val transport: Transport) {
     query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
     return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(EventType.Companion::fromJson))
   }
+
+  /**
+   * Update an event type
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypePut the operation reads
+   * @return what the API answered
+   */
+  fun update(eventTypeName: String, body: EventTypePut): EventType {
+    var path = "/api/v1/event_types/{event_type_name}"
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(transport.request("PUT", path, query, body.toJson()), EventType.Companion::fromJson)
+  }
 }
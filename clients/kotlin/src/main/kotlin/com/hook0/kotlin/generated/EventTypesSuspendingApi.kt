@@ -31,6 +31,23 @@ class EventTypesSuspendingApi(private val transport: Transport) {
     )
   }
 
+  /**
+   * Create a new event type version
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypeVersionPost the operation reads
+   * @return what the API answered, once it has
+   */
+  suspend fun createVersion(eventTypeName: String, body: EventTypeVersionPost): EventTypeVersion {
+    var path = "/api/v1/event_types/{event_type_name}/versions"
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.requestSuspending("POST", path, query, body.toJson()),
+      EventTypeVersion.Companion::fromJson
+    )
+  }
+
   /**
    * Delete an event type
    *
@@ -45,6 +62,23 @@ class EventTypesSuspendingApi(private val transport: Transport) {
     Problems.checkAnswer(transport.requestSuspending("DELETE", path, query, null))
   }
 
+  /**
+   * Deprecate an event type version
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypeVersionDeprecate the operation reads
+   * @return what the API answered, once it has
+   */
+  suspend fun deprecateVersion(eventTypeName: String, body: EventTypeVersionDeprecate): EventTypeVersion {
+    var path = "/api/v1/event_types/{event_type_name}/deprecate"
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.requestSuspending("POST", path, query, body.toJson()),
+      EventTypeVersion.Companion::fromJson
+    )
+  }
+
   /**
    * Get an event type by its name
    *
@@ -75,4 +109,21 @@ class EventTypesSuspendingApi(private val transport: Transport) {
       Wire.asList(EventType.Companion::fromJson)
     )
   }
+
+  /**
+   * Update an event type
+   *
+   * @param eventTypeName carries `event_type_name`.
+   * @param body the EventTypePut the operation reads
+   * @return what the API answered, once it has
+   */
+  suspend fun update(eventTypeName: String, body: EventTypePut): EventType {
+    var path = "/api/v1/event_types/{event_type_name}"
+    path = path.replace("{event_type_name}", Wire.pathSegment(eventTypeName))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.requestSuspending("PUT", path, query, body.toJson()),
+      EventType.Companion::fromJson
+    )
+  }
 }
@@ -16,6 +16,19 @@ import com.hook0.kotlin.Wire
  */
 class EventsApi(private val transport: Transport) {
 
+  /**
+   * Cancel a scheduled event
+   *
+   * @param eventId carries `event_id`.
+   * @param body the CancelEvent the operation reads
+   */
+  fun cancel(eventId: String, body: CancelEvent) {
+    var path = "/api/v1/events/{event_id}/cancel"
+    path = path.replace("{event_id}", Wire.pathSegment(eventId))
+    val query = ArrayList<QueryParameter>()
+    Problems.checkAnswer(transport.request("POST", path, query, body.toJson()))
+  }
+
   /**
    * Get an event by its ID
    *
@@ -43,16 +56,36 @@ class EventsApi(private val transport: Transport) {
     return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), IngestedEvent.Companion::fromJson)
   }
 
+  /**
+   * Ingest a batch of events
+   *
+   * @param body the EventsBatchPost the operation reads
+   * @return what the API answered
+   */
+  fun ingestBatch(body: EventsBatchPost): IngestedEventsBatch {
+    val path = "/api/v1/events/batch"
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.request("POST", path, query, body.toJson()),
+      IngestedEventsBatch.Companion::fromJson
+    )
+  }
+
   /**
    * List latest events
    *
    * @param applicationId carries `application_id`.
+   * @param scheduled carries `scheduled`, or nothing when the caller sends none: Only list the events scheduled for a
+   *     later delivery that was not cancelled, soonest first
    * @return what the API answered
    */
-  fun list(applicationId: String): List<Event> {
+  fun list(applicationId: String, scheduled: Boolean? = null): List<Event> {
     val path = "/api/v1/events/"
     val query = ArrayList<QueryParameter>()
     query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    if (scheduled != null) {
+      query.add(QueryParameter("scheduled", Wire.queryValue(scheduled)))
+    }
     return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(Event.Companion::fromJson))
   }
 
@@ -0,0 +1,46 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `EventsBatchPost` the API declares.
+ *
+ * @property applicationId carries `application_id`: UUID of the application the events belong to. Events of the batch
+ *     that carry another application ID are not ingested.
+ * @property events carries `events`: Events to ingest. Length: 1-100 events; the whole request body is also subject to
+ *     the usual request size limit.
+ */
+data class EventsBatchPost(val applicationId: UUID, val events: List<EventsBatchPostEvents>) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["events"] = Wire.writeList(events, EventsBatchPostEvents::toJson)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the EventsBatchPost the API declares
+     */
+    fun fromJson(value: Any?): EventsBatchPost {
+      val fields = Wire.asFields(value, "EventsBatchPost")
+      return EventsBatchPost(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "events", Wire.asList(EventsBatchPostEvents.Companion::fromJson))
+      )
+    }
+  }
+}
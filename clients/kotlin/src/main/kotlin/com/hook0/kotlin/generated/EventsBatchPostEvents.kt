@@ -0,0 +1,105 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `EventsBatchPostEvents` the API declares.
+ *
+ * @property applicationId carries `application_id`: UUID of the application this event belongs to.
+ * @property eventType carries `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200
+ *     characters.
+ * @property labels carries `labels`: Labels for event filtering and routing to subscriptions.
+ * @property occurredAt carries `occurred_at`: Timestamp when the event occurred.
+ * @property payload carries `payload`: The event payload. For binary content, use base64 encoding. Max length: 699050
+ *     characters (512 KiB base64-encoded).
+ * @property payloadContentType carries `payload_content_type`: Content type of the payload. Valid values: text/plain,
+ *     application/json, application/octet-stream+base64. Length: 1-100 characters.
+ * @property deliverAt carries `deliver_at`, or nothing when the API answers none: Optional date from which the event is
+ *     delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far
+ *     in the future as the events retention of the appl
+ * @property eventId carries `event_id`, or nothing when the API answers none: Optional unique identifier for this event
+ *     (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
+ * @property eventTypeVersion carries `event_type_version`, or nothing when the API answers none: Optional version of
+ *     the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the
+ *     event.
+ * @property expiresAt carries `expires_at`, or nothing when the API answers none: Optional date after which the event
+ *     is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to
+ *     the default time to live of its event type, counted f
+ * @property metadata carries `metadata`, or nothing when the API answers none: Optional metadata key-value pairs
+ *     associated with the event.
+ */
+data class EventsBatchPostEvents(
+  val applicationId: UUID,
+  val eventType: String,
+  val labels: Map<String, String>,
+  val occurredAt: OffsetDateTime,
+  val payload: String,
+  val payloadContentType: String,
+  val deliverAt: OffsetDateTime? = null,
+  val eventId: UUID? = null,
+  val eventTypeVersion: Int? = null,
+  val expiresAt: OffsetDateTime? = null,
+  val metadata: Map<String, String>? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["event_type"] = eventType
+    out["labels"] = labels
+    out["occurred_at"] = Wire.writeMoment(occurredAt)
+    out["payload"] = payload
+    out["payload_content_type"] = payloadContentType
+    if (deliverAt != null) {
+      out["deliver_at"] = Wire.writeMoment(deliverAt)
+    }
+    if (eventId != null) {
+      out["event_id"] = Wire.writeUuid(eventId)
+    }
+    if (eventTypeVersion != null) {
+      out["event_type_version"] = eventTypeVersion
+    }
+    if (expiresAt != null) {
+      out["expires_at"] = Wire.writeMoment(expiresAt)
+    }
+    if (metadata != null) {
+      out["metadata"] = metadata
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the EventsBatchPostEvents the API declares
+     */
+    fun fromJson(value: Any?): EventsBatchPostEvents {
+      val fields = Wire.asFields(value, "EventsBatchPostEvents")
+      return EventsBatchPostEvents(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "event_type", Wire::asText),
+        Wire.read(fields, "labels", Wire.asMap(Wire::asText)),
+        Wire.read(fields, "occurred_at", Wire::asMoment),
+        Wire.read(fields, "payload", Wire::asText),
+        Wire.read(fields, "payload_content_type", Wire::asText),
+        Wire.maybe(fields, "deliver_at", Wire::asMoment),
+        Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "event_type_version", Wire::asInteger),
+        Wire.maybe(fields, "expires_at", Wire::asMoment),
+        Wire.maybe(fields, "metadata", Wire.asMap(Wire::asText))
+      )
+    }
+  }
+}
@@ -0,0 +1,46 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `Inbox` the API declares.
+ *
+ * @property messages carries `messages`.
+ * @property nextCursor carries `next_cursor`, or nothing when the API answers none: Pass it as `cursor` to the next
+ *     pull to continue after the last message of this one
+ */
+data class Inbox(val messages: List<InboxMessages>, val nextCursor: String? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["messages"] = Wire.writeList(messages, InboxMessages::toJson)
+    if (nextCursor != null) {
+      out["next_cursor"] = nextCursor
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the Inbox the API declares
+     */
+    fun fromJson(value: Any?): Inbox {
+      val fields = Wire.asFields(value, "Inbox")
+      return Inbox(
+        Wire.read(fields, "messages", Wire.asList(InboxMessages.Companion::fromJson)),
+        Wire.maybe(fields, "next_cursor", Wire::asText)
+      )
+    }
+  }
+}
@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `InboxAck` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property receipts carries `receipts`: Receipts of the leased events
+ */
+data class InboxAck(val applicationId: UUID, val receipts: List<UUID>) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["receipts"] = Wire.writeList(receipts, Wire::writeUuid)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the InboxAck the API declares
+     */
+    fun fromJson(value: Any?): InboxAck {
+      val fields = Wire.asFields(value, "InboxAck")
+      return InboxAck(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "receipts", Wire.asList(Wire::asUuid))
+      )
+    }
+  }
+}
@@ -0,0 +1,86 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `InboxMessages` the API declares.
+ *
+ * @property eventId carries `event_id`.
+ * @property eventTypeName carries `event_type_name`.
+ * @property labels carries `labels`.
+ * @property leasedUntil carries `leased_until`: When the event is offered again if it was neither acknowledged nor
+ *     rejected
+ * @property occurredAt carries `occurred_at`.
+ * @property payload carries `payload`: Base64-encoded
+ * @property payloadContentType carries `payload_content_type`.
+ * @property receipt carries `receipt`: Identifies this lease of the request attempt; a later lease of the same attempt
+ *     gets another one
+ * @property receivedAt carries `received_at`.
+ * @property requestAttemptId carries `request_attempt_id`.
+ * @property retryCount carries `retry_count`.
+ */
+data class InboxMessages(
+  val eventId: UUID,
+  val eventTypeName: String,
+  val labels: Any,
+  val leasedUntil: OffsetDateTime,
+  val occurredAt: OffsetDateTime,
+  val payload: String,
+  val payloadContentType: String,
+  val receipt: UUID,
+  val receivedAt: OffsetDateTime,
+  val requestAttemptId: UUID,
+  val retryCount: Int
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["event_id"] = Wire.writeUuid(eventId)
+    out["event_type_name"] = eventTypeName
+    out["labels"] = labels
+    out["leased_until"] = Wire.writeMoment(leasedUntil)
+    out["occurred_at"] = Wire.writeMoment(occurredAt)
+    out["payload"] = payload
+    out["payload_content_type"] = payloadContentType
+    out["receipt"] = Wire.writeUuid(receipt)
+    out["received_at"] = Wire.writeMoment(receivedAt)
+    out["request_attempt_id"] = Wire.writeUuid(requestAttemptId)
+    out["retry_count"] = retryCount
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the InboxMessages the API declares
+     */
+    fun fromJson(value: Any?): InboxMessages {
+      val fields = Wire.asFields(value, "InboxMessages")
+      return InboxMessages(
+        Wire.read(fields, "event_id", Wire::asUuid),
+        Wire.read(fields, "event_type_name", Wire::asText),
+        Wire.read(fields, "labels", Wire::asJson),
+        Wire.read(fields, "leased_until", Wire::asMoment),
+        Wire.read(fields, "occurred_at", Wire::asMoment),
+        Wire.read(fields, "payload", Wire::asText),
+        Wire.read(fields, "payload_content_type", Wire::asText),
+        Wire.read(fields, "receipt", Wire::asUuid),
+        Wire.read(fields, "received_at", Wire::asMoment),
+        Wire.read(fields, "request_attempt_id", Wire::asUuid),
+        Wire.read(fields, "retry_count", Wire::asInteger)
+      )
+    }
+  }
+}
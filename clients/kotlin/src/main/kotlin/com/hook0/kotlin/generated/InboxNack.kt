@@ -0,0 +1,62 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `InboxNack` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property receipts carries `receipts`: Receipts of the leased events
+ * @property retry carries `retry`, or nothing when the API answers none: Whether the events are offered again, as new
+ *     request attempts (true by default); they are not beyond the maximum number of retries of the subscription, or of
+ *     this instance if it has none
+ * @property retryInS carries `retry_in_s`, or nothing when the API answers none: Delay in seconds before the events are
+ *     offered again (0 by default)
+ */
+data class InboxNack(
+  val applicationId: UUID,
+  val receipts: List<UUID>,
+  val retry: Boolean? = null,
+  val retryInS: Int? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["receipts"] = Wire.writeList(receipts, Wire::writeUuid)
+    if (retry != null) {
+      out["retry"] = retry
+    }
+    if (retryInS != null) {
+      out["retry_in_s"] = retryInS
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the InboxNack the API declares
+     */
+    fun fromJson(value: Any?): InboxNack {
+      val fields = Wire.asFields(value, "InboxNack")
+      return InboxNack(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "receipts", Wire.asList(Wire::asUuid)),
+        Wire.maybe(fields, "retry", Wire::asBoolean),
+        Wire.maybe(fields, "retry_in_s", Wire::asInteger)
+      )
+    }
+  }
+}
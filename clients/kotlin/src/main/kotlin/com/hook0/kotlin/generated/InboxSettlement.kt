@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `InboxSettlement` the API declares.
+ *
+ * @property settled carries `settled`: Receipts of the events that were settled
+ * @property stale carries `stale`: Receipts that settled nothing: their lease expired, or the event was already settled
+ */
+data class InboxSettlement(val settled: List<UUID>, val stale: List<UUID>) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["settled"] = Wire.writeList(settled, Wire::writeUuid)
+    out["stale"] = Wire.writeList(stale, Wire::writeUuid)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the InboxSettlement the API declares
+     */
+    fun fromJson(value: Any?): InboxSettlement {
+      val fields = Wire.asFields(value, "InboxSettlement")
+      return InboxSettlement(
+        Wire.read(fields, "settled", Wire.asList(Wire::asUuid)),
+        Wire.read(fields, "stale", Wire.asList(Wire::asUuid))
+      )
+    }
+  }
+}
@@ -0,0 +1,88 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.QueryParameter
+import com.hook0.kotlin.Transport
+import com.hook0.kotlin.Wire
+
+/**
+ * What the API declares under `inbox`, issued through the transport it is handed.
+ *
+ * Every call suspends until the API has answered, and holds no thread while it waits.
+ *
+ * @param transport what one request is issued through
+ */
+class InboxSuspendingApi(private val transport: Transport) {
+
+  /**
+   * Acknowledge events pulled from an inbox
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param body the InboxAck the operation reads
+   * @return what the API answered, once it has
+   */
+  suspend fun ack(subscriptionId: String, body: InboxAck): InboxSettlement {
+    var path = "/api/v1/inbox/{subscription_id}/ack"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.requestSuspending("POST", path, query, body.toJson()),
+      InboxSettlement.Companion::fromJson
+    )
+  }
+
+  /**
+   * Reject events pulled from an inbox
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param body the InboxNack the operation reads
+   * @return what the API answered, once it has
+   */
+  suspend fun nack(subscriptionId: String, body: InboxNack): InboxSettlement {
+    var path = "/api/v1/inbox/{subscription_id}/nack"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.requestSuspending("POST", path, query, body.toJson()),
+      InboxSettlement.Companion::fromJson
+    )
+  }
+
+  /**
+   * Pull events from an inbox
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @param cursor carries `cursor`, or nothing when the caller sends none: Only lease events after this cursor,
+   *     returned by a previous pull
+   * @param limit carries `limit`, or nothing when the caller sends none: Maximum number of events to lease (10 by
+   *     default)
+   * @param visibilityTimeoutS carries `visibility_timeout_s`, or nothing when the caller sends none: For how long in
+   *     seconds the leased events are not offered again (30 by default)
+   * @return what the API answered, once it has
+   */
+  suspend fun pull(
+    subscriptionId: String,
+    applicationId: String,
+    cursor: String? = null,
+    limit: Long? = null,
+    visibilityTimeoutS: Long? = null
+  ): Inbox {
+    var path = "/api/v1/inbox/{subscription_id}"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    if (cursor != null) {
+      query.add(QueryParameter("cursor", Wire.queryValue(cursor)))
+    }
+    if (limit != null) {
+      query.add(QueryParameter("limit", Wire.queryValue(limit)))
+    }
+    if (visibilityTimeoutS != null) {
+      query.add(QueryParameter("visibility_timeout_s", Wire.queryValue(visibilityTimeoutS)))
+    }
+    return Problems.readAnswer(transport.requestSuspending("GET", path, query, null), Inbox.Companion::fromJson)
+  }
+}
@@ -13,8 +13,15 @@ import java.util.UUID
  * @property applicationId carries `application_id`.
  * @property eventId carries `event_id`.
  * @property receivedAt carries `received_at`.
+ * @property deprecation carries `deprecation`, or nothing when the API answers none: Present when the event was
+ *     ingested with a deprecated version of its event type
  */
-data class IngestedEvent(val applicationId: UUID, val eventId: UUID, val receivedAt: OffsetDateTime) {
+data class IngestedEvent(
+  val applicationId: UUID,
+  val eventId: UUID,
+  val receivedAt: OffsetDateTime,
+  val deprecation: IngestedEventDeprecation? = null
+) {
 
   /**
    * Writes one back the way the API reads it.
@@ -26,6 +33,9 @@ data class IngestedEvent(val applicationId: UUID, val eventId: UUID, val receive
     out["application_id"] = Wire.writeUuid(applicationId)
     out["event_id"] = Wire.writeUuid(eventId)
     out["received_at"] = Wire.writeMoment(receivedAt)
+    if (deprecation != null) {
+      out["deprecation"] = deprecation.toJson()
+    }
     return out
   }
 
@@ -41,7 +51,8 @@ data class IngestedEvent(val applicationId: UUID, val eventId: UUID, val receive
       return IngestedEvent(
         Wire.read(fields, "application_id", Wire::asUuid),
         Wire.read(fields, "event_id", Wire::asUuid),
-        Wire.read(fields, "received_at", Wire::asMoment)
+        Wire.read(fields, "received_at", Wire::asMoment),
+        Wire.maybe(fields, "deprecation", IngestedEventDeprecation.Companion::fromJson)
       )
     }
   }
@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `IngestedEventsBatch` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property events carries `events`: One outcome per submitted event, in the order the events were submitted
+ */
+data class IngestedEventsBatch(val applicationId: UUID, val events: List<IngestedEventsBatchEvents>) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["events"] = Wire.writeList(events, IngestedEventsBatchEvents::toJson)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the IngestedEventsBatch the API declares
+     */
+    fun fromJson(value: Any?): IngestedEventsBatch {
+      val fields = Wire.asFields(value, "IngestedEventsBatch")
+      return IngestedEventsBatch(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "events", Wire.asList(IngestedEventsBatchEvents.Companion::fromJson))
+      )
+    }
+  }
+}
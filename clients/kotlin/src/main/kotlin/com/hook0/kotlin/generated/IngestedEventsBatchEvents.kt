@@ -0,0 +1,70 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `IngestedEventsBatchEvents` the API declares.
+ *
+ * @property status carries `status`.
+ * @property deprecation carries `deprecation`, or nothing when the API answers none: Present when the event was
+ *     ingested with a deprecated version of its event type
+ * @property eventId carries `event_id`, or nothing when the API answers none: ID of the ingested event, or the one the
+ *     event was submitted with when it was not ingested
+ * @property problem carries `problem`, or nothing when the API answers none: Present when the event was not ingested
+ * @property receivedAt carries `received_at`, or nothing when the API answers none: Present when the event was ingested
+ */
+data class IngestedEventsBatchEvents(
+  val status: IngestedEventsBatchEventsStatus,
+  val deprecation: IngestedEventsBatchEventsDeprecation? = null,
+  val eventId: UUID? = null,
+  val problem: IngestedEventsBatchEventsProblem? = null,
+  val receivedAt: OffsetDateTime? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["status"] = status.wireValue
+    if (deprecation != null) {
+      out["deprecation"] = deprecation.toJson()
+    }
+    if (eventId != null) {
+      out["event_id"] = Wire.writeUuid(eventId)
+    }
+    if (problem != null) {
+      out["problem"] = problem.toJson()
+    }
+    if (receivedAt != null) {
+      out["received_at"] = Wire.writeMoment(receivedAt)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the IngestedEventsBatchEvents the API declares
+     */
+    fun fromJson(value: Any?): IngestedEventsBatchEvents {
+      val fields = Wire.asFields(value, "IngestedEventsBatchEvents")
+      return IngestedEventsBatchEvents(
+        Wire.read(fields, "status", IngestedEventsBatchEventsStatus.Companion::fromJson),
+        Wire.maybe(fields, "deprecation", IngestedEventsBatchEventsDeprecation.Companion::fromJson),
+        Wire.maybe(fields, "event_id", Wire::asUuid),
+        Wire.maybe(fields, "problem", IngestedEventsBatchEventsProblem.Companion::fromJson),
+        Wire.maybe(fields, "received_at", Wire::asMoment)
+      )
+    }
+  }
+}
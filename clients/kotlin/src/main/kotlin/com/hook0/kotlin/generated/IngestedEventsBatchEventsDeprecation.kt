@@ -0,0 +1,53 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+
+/**
+ * The `IngestedEventsBatchEventsDeprecation` the API declares.
+ *
+ * @property deprecatedAt carries `deprecated_at`.
+ * @property eventTypeVersion carries `event_type_version`.
+ * @property sunsetAt carries `sunset_at`, or nothing when the API answers none.
+ */
+data class IngestedEventsBatchEventsDeprecation(
+  val deprecatedAt: OffsetDateTime,
+  val eventTypeVersion: Int,
+  val sunsetAt: OffsetDateTime? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["deprecated_at"] = Wire.writeMoment(deprecatedAt)
+    out["event_type_version"] = eventTypeVersion
+    if (sunsetAt != null) {
+      out["sunset_at"] = Wire.writeMoment(sunsetAt)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the IngestedEventsBatchEventsDeprecation the API declares
+     */
+    fun fromJson(value: Any?): IngestedEventsBatchEventsDeprecation {
+      val fields = Wire.asFields(value, "IngestedEventsBatchEventsDeprecation")
+      return IngestedEventsBatchEventsDeprecation(
+        Wire.read(fields, "deprecated_at", Wire::asMoment),
+        Wire.read(fields, "event_type_version", Wire::asInteger),
+        Wire.maybe(fields, "sunset_at", Wire::asMoment)
+      )
+    }
+  }
+}
@@ -0,0 +1,67 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `IngestedEventsBatchEventsProblem` the API declares.
+ *
+ * @property detail carries `detail`.
+ * @property id carries `id`: Identifier of the problem that occurred, stable across releases
+ * @property status carries `status`.
+ * @property title carries `title`.
+ * @property type carries `type`: Documentation page of this problem, which is also what distinguishes one problem type
+ *     from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a URL.
+ * @property validation carries `validation`, or nothing when the API answers none: Which submitted values were rejected
+ *     and why, keyed by the name of the field they were submitted under. Carried by validation failures (`Validation`,
+ *     status 422) and `null` for every other problem, as
+ */
+data class IngestedEventsBatchEventsProblem(
+  val detail: String,
+  val id: IngestedEventsBatchEventsProblemId,
+  val status: Int,
+  val title: String,
+  val type: String,
+  val validation: Any? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["detail"] = detail
+    out["id"] = id.wireValue
+    out["status"] = status
+    out["title"] = title
+    out["type"] = type
+    if (validation != null) {
+      out["validation"] = validation
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the IngestedEventsBatchEventsProblem the API declares
+     */
+    fun fromJson(value: Any?): IngestedEventsBatchEventsProblem {
+      val fields = Wire.asFields(value, "IngestedEventsBatchEventsProblem")
+      return IngestedEventsBatchEventsProblem(
+        Wire.read(fields, "detail", Wire::asText),
+        Wire.read(fields, "id", IngestedEventsBatchEventsProblemId.Companion::fromJson),
+        Wire.read(fields, "status", Wire::asInteger),
+        Wire.read(fields, "title", Wire::asText),
+        Wire.read(fields, "type", Wire::asText),
+        Wire.maybe(fields, "validation", Wire::asJson)
+      )
+    }
+  }
+}
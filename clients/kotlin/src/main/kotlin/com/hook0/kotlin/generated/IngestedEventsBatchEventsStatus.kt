@@ -0,0 +1,38 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.DecodeException
+import com.hook0.kotlin.Wire
+
+/**
+ * One of the values the API answers with.
+ */
+enum class IngestedEventsBatchEventsStatus(val wireValue: String) {
+  /** The API spells this one `ingested`. */
+  INGESTED("ingested"),
+
+  /** The API spells this one `failed`. */
+  FAILED("failed");
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the value it names
+     */
+    fun fromJson(value: Any?): IngestedEventsBatchEventsStatus {
+      val named = Wire.asText(value)
+      for (candidate in IngestedEventsBatchEventsStatus.entries) {
+        if (candidate.wireValue == named) {
+          return candidate
+        }
+      }
+      throw DecodeException(
+        "`${Wire.preview(named)}` is not one of the values IngestedEventsBatchEventsStatus declares"
+      )
+    }
+  }
+}
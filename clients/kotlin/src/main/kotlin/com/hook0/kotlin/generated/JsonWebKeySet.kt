@@ -0,0 +1,38 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `JsonWebKeySet` the API declares.
+ *
+ * @property keys carries `keys`.
+ */
+data class JsonWebKeySet(val keys: List<JsonWebKeySetKeys>) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["keys"] = Wire.writeList(keys, JsonWebKeySetKeys::toJson)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the JsonWebKeySet the API declares
+     */
+    fun fromJson(value: Any?): JsonWebKeySet {
+      val fields = Wire.asFields(value, "JsonWebKeySet")
+      return JsonWebKeySet(Wire.read(fields, "keys", Wire.asList(JsonWebKeySetKeys.Companion::fromJson)))
+    }
+  }
+}
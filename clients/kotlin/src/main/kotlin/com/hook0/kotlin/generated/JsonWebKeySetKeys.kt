@@ -0,0 +1,62 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `JsonWebKeySetKeys` the API declares.
+ *
+ * @property alg carries `alg`: Always `EdDSA`
+ * @property crv carries `crv`: Always `Ed25519`
+ * @property kid carries `kid`: ID of the signing key, which signatures name in their `k` part
+ * @property kty carries `kty`: Always `OKP`
+ * @property use carries `use`: Always `sig`
+ * @property x carries `x`: Public key, encoded in base64url without padding
+ */
+data class JsonWebKeySetKeys(
+  val alg: String,
+  val crv: String,
+  val kid: String,
+  val kty: String,
+  val use: String,
+  val x: String
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["alg"] = alg
+    out["crv"] = crv
+    out["kid"] = kid
+    out["kty"] = kty
+    out["use"] = use
+    out["x"] = x
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the JsonWebKeySetKeys the API declares
+     */
+    fun fromJson(value: Any?): JsonWebKeySetKeys {
+      val fields = Wire.asFields(value, "JsonWebKeySetKeys")
+      return JsonWebKeySetKeys(
+        Wire.read(fields, "alg", Wire::asText),
+        Wire.read(fields, "crv", Wire::asText),
+        Wire.read(fields, "kid", Wire::asText),
+        Wire.read(fields, "kty", Wire::asText),
+        Wire.read(fields, "use", Wire::asText),
+        Wire.read(fields, "x", Wire::asText)
+      )
+    }
+  }
+}
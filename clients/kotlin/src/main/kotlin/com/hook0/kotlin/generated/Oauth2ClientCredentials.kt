@@ -0,0 +1,64 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `OAuth2ClientCredentials` the API declares.
+ *
+ * @property clientId carries `client_id`.
+ * @property createdAt carries `created_at`.
+ * @property scopes carries `scopes`.
+ * @property subscriptionId carries `subscription_id`.
+ * @property tokenUrl carries `token_url`.
+ * @property updatedAt carries `updated_at`.
+ */
+data class Oauth2ClientCredentials(
+  val clientId: String,
+  val createdAt: OffsetDateTime,
+  val scopes: List<String>,
+  val subscriptionId: UUID,
+  val tokenUrl: String,
+  val updatedAt: OffsetDateTime
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["client_id"] = clientId
+    out["created_at"] = Wire.writeMoment(createdAt)
+    out["scopes"] = scopes
+    out["subscription_id"] = Wire.writeUuid(subscriptionId)
+    out["token_url"] = tokenUrl
+    out["updated_at"] = Wire.writeMoment(updatedAt)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the OAuth2ClientCredentials the API declares
+     */
+    fun fromJson(value: Any?): Oauth2ClientCredentials {
+      val fields = Wire.asFields(value, "OAuth2ClientCredentials")
+      return Oauth2ClientCredentials(
+        Wire.read(fields, "client_id", Wire::asText),
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "scopes", Wire.asList(Wire::asText)),
+        Wire.read(fields, "subscription_id", Wire::asUuid),
+        Wire.read(fields, "token_url", Wire::asText),
+        Wire.read(fields, "updated_at", Wire::asMoment)
+      )
+    }
+  }
+}
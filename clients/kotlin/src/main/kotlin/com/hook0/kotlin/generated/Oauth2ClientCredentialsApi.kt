@@ -0,0 +1,66 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.QueryParameter
+import com.hook0.kotlin.Transport
+import com.hook0.kotlin.Wire
+
+/**
+ * What the API declares under `oauth2ClientCredentials`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ *
+ * @param transport what one request is issued through
+ */
+class Oauth2ClientCredentialsApi(private val transport: Transport) {
+
+  /**
+   * Remove the OAuth2 client credentials of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   */
+  fun delete(subscriptionId: String, applicationId: String) {
+    var path = "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    Problems.checkAnswer(transport.request("DELETE", path, query, null))
+  }
+
+  /**
+   * Get the OAuth2 client credentials of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  fun get(subscriptionId: String, applicationId: String): Oauth2ClientCredentials {
+    var path = "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(transport.request("GET", path, query, null), Oauth2ClientCredentials.Companion::fromJson)
+  }
+
+  /**
+   * Set the OAuth2 client credentials of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @param body the Oauth2ClientCredentialsPut the operation reads
+   * @return what the API answered
+   */
+  fun update(subscriptionId: String, applicationId: String, body: Oauth2ClientCredentialsPut): Oauth2ClientCredentials {
+    var path = "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(
+      transport.request("PUT", path, query, body.toJson()),
+      Oauth2ClientCredentials.Companion::fromJson
+    )
+  }
+}
@@ -0,0 +1,56 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `OAuth2ClientCredentialsPut` the API declares.
+ *
+ * @property clientId carries `client_id`.
+ * @property clientSecret carries `client_secret`.
+ * @property tokenUrl carries `token_url`: Token endpoint of the authorization server
+ * @property scopes carries `scopes`, or nothing when the API answers none: Scopes to request; none by default
+ */
+data class Oauth2ClientCredentialsPut(
+  val clientId: String,
+  val clientSecret: String,
+  val tokenUrl: String,
+  val scopes: List<String>? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["client_id"] = clientId
+    out["client_secret"] = clientSecret
+    out["token_url"] = tokenUrl
+    if (scopes != null) {
+      out["scopes"] = scopes
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the OAuth2ClientCredentialsPut the API declares
+     */
+    fun fromJson(value: Any?): Oauth2ClientCredentialsPut {
+      val fields = Wire.asFields(value, "OAuth2ClientCredentialsPut")
+      return Oauth2ClientCredentialsPut(
+        Wire.read(fields, "client_id", Wire::asText),
+        Wire.read(fields, "client_secret", Wire::asText),
+        Wire.read(fields, "token_url", Wire::asText),
+        Wire.maybe(fields, "scopes", Wire.asList(Wire::asText))
+      )
+    }
+  }
+}
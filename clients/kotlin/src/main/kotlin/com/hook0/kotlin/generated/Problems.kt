@@ -65,22 +65,42 @@ object Problems {
 
       ProblemId.AUTH_NO_AUTHORIZATION_HEADER -> AuthNoAuthorizationHeaderException(status, problem, detail)
 
+      ProblemId.BULK_RETRY_ALREADY_RUNNING -> BulkRetryAlreadyRunningException(status, problem, detail)
+
+      ProblemId.BULK_RETRY_FINISHED -> BulkRetryFinishedException(status, problem, detail)
+
+      ProblemId.CLIENT_CERTIFICATES_DISABLED -> ClientCertificatesDisabledException(status, problem, detail)
+
       ProblemId.EVENT_ALREADY_INGESTED -> EventAlreadyIngestedException(status, problem, detail)
 
+      ProblemId.EVENT_DELIVERY_BEYOND_RETENTION -> EventDeliveryBeyondRetentionException(status, problem, detail)
+
       ProblemId.EVENT_INVALID_BASE64_PAYLOAD -> EventInvalidBase64PayloadException(status, problem, detail)
 
       ProblemId.EVENT_INVALID_JSON_PAYLOAD -> EventInvalidJsonPayloadException(status, problem, detail)
 
       ProblemId.EVENT_INVALID_PAYLOAD_CONTENT_TYPE -> EventInvalidPayloadContentTypeException(status, problem, detail)
 
+      ProblemId.EVENT_NOT_SCHEDULED -> EventNotScheduledException(status, problem, detail)
+
+      ProblemId.EVENT_PAYLOAD_SCHEMA_VIOLATION -> EventPayloadSchemaViolationException(status, problem, detail)
+
       ProblemId.EVENT_TYPE_ALREADY_EXIST -> EventTypeAlreadyExistException(status, problem, detail)
 
       ProblemId.EVENT_TYPE_DOES_NOT_EXIST -> EventTypeDoesNotExistException(status, problem, detail)
 
+      ProblemId.EVENT_TYPE_VERSION_ALREADY_EXIST -> EventTypeVersionAlreadyExistException(status, problem, detail)
+
+      ProblemId.EVENT_TYPE_VERSION_DOES_NOT_EXIST -> EventTypeVersionDoesNotExistException(status, problem, detail)
+
+      ProblemId.EVENT_TYPE_VERSION_SUNSET -> EventTypeVersionSunsetException(status, problem, detail)
+
       ProblemId.FORBIDDEN -> ForbiddenException(status, problem, detail)
 
       ProblemId.INTERNAL_SERVER_ERROR -> InternalServerErrorException(status, problem, detail)
 
+      ProblemId.INVALID_CLIENT_CERTIFICATE -> InvalidClientCertificateException(status, problem, detail)
+
       ProblemId.INVALID_DATE_RANGE -> InvalidDateRangeException(status, problem, detail)
 
       ProblemId.INVALID_ROLE -> InvalidRoleException(status, problem, detail)
@@ -116,8 +136,22 @@ object Problems {
 
       ProblemId.REGISTRATION_DISABLED -> RegistrationDisabledException(status, problem, detail)
 
+      ProblemId.REQUEST_ATTEMPT_NOT_FAILED -> RequestAttemptNotFailedException(status, problem, detail)
+
+      ProblemId.REQUEST_ATTEMPT_RETRY_PENDING -> RequestAttemptRetryPendingException(status, problem, detail)
+
+      ProblemId.RETRY_POLICY_OVER_LIMIT -> RetryPolicyOverLimitException(status, problem, detail)
+
+      ProblemId.SECRET_ROTATION_IN_PROGRESS -> SecretRotationInProgressException(status, problem, detail)
+
       ProblemId.SERVICE_UNAVAILABLE -> ServiceUnavailableException(status, problem, detail)
 
+      ProblemId.SIGNING_KEYS_DISABLED -> SigningKeysDisabledException(status, problem, detail)
+
+      ProblemId.SUBSCRIPTION_DISABLED -> SubscriptionDisabledException(status, problem, detail)
+
+      ProblemId.SUBSCRIPTION_NOT_INBOX -> SubscriptionNotInboxException(status, problem, detail)
+
       ProblemId.TOO_MANY_APPLICATIONS_PER_ORGANIZATION ->
         TooManyApplicationsPerOrganizationException(status, problem, detail)
 
@@ -0,0 +1,47 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `PurgeDeadLetters` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property subscriptionId carries `subscription_id`, or nothing when the API answers none: Only purge the dead letters
+ *     of this subscription
+ */
+data class PurgeDeadLetters(val applicationId: UUID, val subscriptionId: UUID? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    if (subscriptionId != null) {
+      out["subscription_id"] = Wire.writeUuid(subscriptionId)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the PurgeDeadLetters the API declares
+     */
+    fun fromJson(value: Any?): PurgeDeadLetters {
+      val fields = Wire.asFields(value, "PurgeDeadLetters")
+      return PurgeDeadLetters(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.maybe(fields, "subscription_id", Wire::asUuid)
+      )
+    }
+  }
+}
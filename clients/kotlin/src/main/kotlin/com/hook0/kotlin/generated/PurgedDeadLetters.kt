@@ -0,0 +1,38 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `PurgedDeadLetters` the API declares.
+ *
+ * @property purgedCount carries `purged_count`.
+ */
+data class PurgedDeadLetters(val purgedCount: Long) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["purged_count"] = purgedCount
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the PurgedDeadLetters the API declares
+     */
+    fun fromJson(value: Any?): PurgedDeadLetters {
+      val fields = Wire.asFields(value, "PurgedDeadLetters")
+      return PurgedDeadLetters(Wire.read(fields, "purged_count", Wire::asLong))
+    }
+  }
+}
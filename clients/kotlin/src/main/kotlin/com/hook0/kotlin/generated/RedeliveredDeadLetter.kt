@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.util.UUID
+
+/**
+ * The `RedeliveredDeadLetter` the API declares.
+ *
+ * @property deadLetterId carries `dead_letter_id`.
+ * @property requestAttemptId carries `request_attempt_id`.
+ */
+data class RedeliveredDeadLetter(val deadLetterId: UUID, val requestAttemptId: UUID) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["dead_letter_id"] = Wire.writeUuid(deadLetterId)
+    out["request_attempt_id"] = Wire.writeUuid(requestAttemptId)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the RedeliveredDeadLetter the API declares
+     */
+    fun fromJson(value: Any?): RedeliveredDeadLetter {
+      val fields = Wire.asFields(value, "RedeliveredDeadLetter")
+      return RedeliveredDeadLetter(
+        Wire.read(fields, "dead_letter_id", Wire::asUuid),
+        Wire.read(fields, "request_attempt_id", Wire::asUuid)
+      )
+    }
+  }
+}
@@ -19,7 +19,11 @@ import java.util.UUID
  *     waiting: {type, since, until} - Scheduled for future delivery - pending: {type, since} - Ready to be processed -
  *     in_progre
  * @property subscription carries `subscription`.
+ * @property deadLetterId carries `dead_letter_id`, or nothing when the API answers none: Dead letter the request
+ *     attempt became, when it was the last attempt of a delivery an output worker gave up on
  * @property delayUntil carries `delay_until`, or nothing when the API answers none.
+ * @property deliveryBatchId carries `delivery_batch_id`, or nothing when the API answers none: Batch the request
+ *     attempt was delivered in, shared with the other request attempts of the batch and their response
  * @property failedAt carries `failed_at`, or nothing when the API answers none.
  * @property httpResponseStatus carries `http_response_status`, or nothing when the API answers none.
  * @property pickedAt carries `picked_at`, or nothing when the API answers none.
@@ -34,7 +38,9 @@ data class RequestAttempt(
   val retryCount: Int,
   val status: RequestAttemptStatus,
   val subscription: RequestAttemptSubscription,
+  val deadLetterId: UUID? = null,
   val delayUntil: OffsetDateTime? = null,
+  val deliveryBatchId: UUID? = null,
   val failedAt: OffsetDateTime? = null,
   val httpResponseStatus: Int? = null,
   val pickedAt: OffsetDateTime? = null,
@@ -56,9 +62,15 @@ data class RequestAttempt(
     out["retry_count"] = retryCount
     out["status"] = status.toJson()
     out["subscription"] = subscription.toJson()
+    if (deadLetterId != null) {
+      out["dead_letter_id"] = Wire.writeUuid(deadLetterId)
+    }
     if (delayUntil != null) {
       out["delay_until"] = Wire.writeMoment(delayUntil)
     }
+    if (deliveryBatchId != null) {
+      out["delivery_batch_id"] = Wire.writeUuid(deliveryBatchId)
+    }
     if (failedAt != null) {
       out["failed_at"] = Wire.writeMoment(failedAt)
     }
@@ -94,7 +106,9 @@ data class RequestAttempt(
         Wire.read(fields, "retry_count", Wire::asInteger),
         Wire.read(fields, "status", RequestAttemptStatus.Companion::fromJson),
         Wire.read(fields, "subscription", RequestAttemptSubscription.Companion::fromJson),
+        Wire.maybe(fields, "dead_letter_id", Wire::asUuid),
         Wire.maybe(fields, "delay_until", Wire::asMoment),
+        Wire.maybe(fields, "delivery_batch_id", Wire::asUuid),
         Wire.maybe(fields, "failed_at", Wire::asMoment),
         Wire.maybe(fields, "http_response_status", Wire::asInteger),
         Wire.maybe(fields, "picked_at", Wire::asMoment),
@@ -9,11 +9,15 @@ import java.time.OffsetDateTime
 /**
  * The `RequestAttemptStatus` the API declares.
  *
- * @property type carries `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed
+ * @property type carries `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed,
+ *     expired, cancelled
  * @property at carries `at`, or nothing when the API answers none: Timestamp when completed (present in successful,
- *     failed)
+ *     failed, expired, cancelled)
  * @property fullProcessingMs carries `full_processing_ms`, or nothing when the API answers none: Total processing time
  *     in milliseconds (present in successful, failed)
+ * @property ruleOutcome carries `rule_outcome`, or nothing when the API answers none: Outcome given by a response rule
+ *     of the subscription, when one matched the response (present in successful, failed). One of: success, retryable,
+ *     permanent_failure, disable_subscription
  * @property since carries `since`, or nothing when the API answers none: Timestamp when the status started (present in
  *     waiting, pending, in_progress)
  * @property until carries `until`, or nothing when the API answers none: Timestamp until which waiting (only present in
@@ -23,6 +27,7 @@ data class RequestAttemptStatus(
   val type: RequestAttemptStatusType,
   val at: OffsetDateTime? = null,
   val fullProcessingMs: Long? = null,
+  val ruleOutcome: RequestAttemptStatusRuleOutcome? = null,
   val since: OffsetDateTime? = null,
   val until: OffsetDateTime? = null
 ) {
@@ -41,6 +46,9 @@ data class RequestAttemptStatus(
     if (fullProcessingMs != null) {
       out["full_processing_ms"] = fullProcessingMs
     }
+    if (ruleOutcome != null) {
+      out["rule_outcome"] = ruleOutcome.wireValue
+    }
     if (since != null) {
       out["since"] = Wire.writeMoment(since)
     }
@@ -63,6 +71,7 @@ data class RequestAttemptStatus(
         Wire.read(fields, "type", RequestAttemptStatusType.Companion::fromJson),
         Wire.maybe(fields, "at", Wire::asMoment),
         Wire.maybe(fields, "full_processing_ms", Wire::asLong),
+        Wire.maybe(fields, "rule_outcome", RequestAttemptStatusRuleOutcome.Companion::fromJson),
         Wire.maybe(fields, "since", Wire::asMoment),
         Wire.maybe(fields, "until", Wire::asMoment)
       )
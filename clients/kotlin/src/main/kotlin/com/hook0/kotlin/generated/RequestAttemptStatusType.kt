@@ -23,7 +23,13 @@ enum class RequestAttemptStatusType(val wireValue: String) {
   SUCCESSFUL("successful"),
 
   /** The API spells this one `failed`. */
-  FAILED("failed");
+  FAILED("failed"),
+
+  /** The API spells this one `expired`. */
+  EXPIRED("expired"),
+
+  /** The API spells this one `cancelled`. */
+  CANCELLED("cancelled");
 
   companion object {
     /**
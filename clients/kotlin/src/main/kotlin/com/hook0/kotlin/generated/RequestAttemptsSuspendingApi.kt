@@ -82,4 +82,21 @@ class RequestAttemptsSuspendingApi(private val transport: Transport) {
       Wire.asList(RequestAttempt.Companion::fromJson)
     )
   }
+
+  /**
+   * Retry a request attempt
+   *
+   * @param requestAttemptId carries `request_attempt_id`.
+   * @param body the RetryRequestAttempt the operation reads
+   * @return what the API answered, once it has
+   */
+  suspend fun retry(requestAttemptId: String, body: RetryRequestAttempt): RequestAttempt {
+    var path = "/api/v1/request_attempts/{request_attempt_id}/retry"
+    path = path.replace("{request_attempt_id}", Wire.pathSegment(requestAttemptId))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(
+      transport.requestSuspending("POST", path, query, body.toJson()),
+      RequestAttempt.Companion::fromJson
+    )
+  }
 }
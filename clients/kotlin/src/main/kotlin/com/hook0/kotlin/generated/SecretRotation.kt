@@ -0,0 +1,56 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `SecretRotation` the API declares.
+ *
+ * @property previousSecret carries `previous_secret`: The secret the new one replaced
+ * @property previousSecretExpiresAt carries `previous_secret_expires_at`.
+ * @property secret carries `secret`: The new secret of the subscription
+ * @property subscriptionId carries `subscription_id`.
+ */
+data class SecretRotation(
+  val previousSecret: UUID,
+  val previousSecretExpiresAt: OffsetDateTime,
+  val secret: UUID,
+  val subscriptionId: UUID
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["previous_secret"] = Wire.writeUuid(previousSecret)
+    out["previous_secret_expires_at"] = Wire.writeMoment(previousSecretExpiresAt)
+    out["secret"] = Wire.writeUuid(secret)
+    out["subscription_id"] = Wire.writeUuid(subscriptionId)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SecretRotation the API declares
+     */
+    fun fromJson(value: Any?): SecretRotation {
+      val fields = Wire.asFields(value, "SecretRotation")
+      return SecretRotation(
+        Wire.read(fields, "previous_secret", Wire::asUuid),
+        Wire.read(fields, "previous_secret_expires_at", Wire::asMoment),
+        Wire.read(fields, "secret", Wire::asUuid),
+        Wire.read(fields, "subscription_id", Wire::asUuid)
+      )
+    }
+  }
+}
@@ -0,0 +1,41 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SecretRotationPost` the API declares.
+ *
+ * @property overlapSeconds carries `overlap_seconds`, or nothing when the API answers none: How long the previous
+ *     secret stays valid, in seconds (one day by default)
+ */
+data class SecretRotationPost(val overlapSeconds: Int? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    if (overlapSeconds != null) {
+      out["overlap_seconds"] = overlapSeconds
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SecretRotationPost the API declares
+     */
+    fun fromJson(value: Any?): SecretRotationPost {
+      val fields = Wire.asFields(value, "SecretRotationPost")
+      return SecretRotationPost(Wire.maybe(fields, "overlap_seconds", Wire::asInteger))
+    }
+  }
+}
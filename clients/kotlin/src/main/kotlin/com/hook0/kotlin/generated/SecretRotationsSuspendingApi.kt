@@ -0,0 +1,83 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.QueryParameter
+import com.hook0.kotlin.Transport
+import com.hook0.kotlin.Wire
+
+/**
+ * What the API declares under `secretRotations`, issued through the transport it is handed.
+ *
+ * Every call suspends until the API has answered, and holds no thread while it waits.
+ *
+ * @param transport what one request is issued through
+ */
+class SecretRotationsSuspendingApi(private val transport: Transport) {
+
+  /**
+   * Rotate the secret of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @param body the SecretRotationPost the operation reads
+   * @return what the API answered, once it has
+   */
+  suspend fun create(subscriptionId: String, applicationId: String, body: SecretRotationPost): SecretRotation {
+    var path = "/api/v1/subscriptions/{subscription_id}/secret_rotation"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(
+      transport.requestSuspending("POST", path, query, body.toJson()),
+      SecretRotation.Companion::fromJson
+    )
+  }
+
+  /**
+   * Finalize the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   */
+  suspend fun finalize(subscriptionId: String, applicationId: String) {
+    var path = "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    Problems.checkAnswer(transport.requestSuspending("POST", path, query, null))
+  }
+
+  /**
+   * Get the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   * @return what the API answered, once it has
+   */
+  suspend fun get(subscriptionId: String, applicationId: String): SecretRotation {
+    var path = "/api/v1/subscriptions/{subscription_id}/secret_rotation"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(
+      transport.requestSuspending("GET", path, query, null),
+      SecretRotation.Companion::fromJson
+    )
+  }
+
+  /**
+   * Roll back the secret rotation of a subscription
+   *
+   * @param subscriptionId carries `subscription_id`.
+   * @param applicationId carries `application_id`.
+   */
+  suspend fun rollback(subscriptionId: String, applicationId: String) {
+    var path = "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback"
+    path = path.replace("{subscription_id}", Wire.pathSegment(subscriptionId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    Problems.checkAnswer(transport.requestSuspending("POST", path, query, null))
+  }
+}
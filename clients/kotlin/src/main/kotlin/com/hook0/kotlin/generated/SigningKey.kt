@@ -0,0 +1,63 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+import java.time.OffsetDateTime
+import java.util.UUID
+
+/**
+ * The `SigningKey` the API declares.
+ *
+ * @property applicationId carries `application_id`.
+ * @property createdAt carries `created_at`.
+ * @property isCurrent carries `is_current`: Whether deliveries are signed with this key, which only the newest key of
+ *     the application is
+ * @property publicKey carries `public_key`: Public key, encoded in base64url without padding like the `x` member of its
+ *     JSON Web Key
+ * @property signingKeyId carries `signing_key_id`: Identifies the key in the `k` part of signatures and as the `kid` of
+ *     its JSON Web Key
+ */
+data class SigningKey(
+  val applicationId: UUID,
+  val createdAt: OffsetDateTime,
+  val isCurrent: Boolean,
+  val publicKey: String,
+  val signingKeyId: UUID
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["application_id"] = Wire.writeUuid(applicationId)
+    out["created_at"] = Wire.writeMoment(createdAt)
+    out["is_current"] = isCurrent
+    out["public_key"] = publicKey
+    out["signing_key_id"] = Wire.writeUuid(signingKeyId)
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SigningKey the API declares
+     */
+    fun fromJson(value: Any?): SigningKey {
+      val fields = Wire.asFields(value, "SigningKey")
+      return SigningKey(
+        Wire.read(fields, "application_id", Wire::asUuid),
+        Wire.read(fields, "created_at", Wire::asMoment),
+        Wire.read(fields, "is_current", Wire::asBoolean),
+        Wire.read(fields, "public_key", Wire::asText),
+        Wire.read(fields, "signing_key_id", Wire::asUuid)
+      )
+    }
+  }
+}
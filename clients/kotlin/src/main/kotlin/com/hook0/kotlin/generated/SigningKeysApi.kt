@@ -0,0 +1,70 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.QueryParameter
+import com.hook0.kotlin.Transport
+import com.hook0.kotlin.Wire
+
+/**
+ * What the API declares under `signingKeys`, issued through the transport it is handed.
+ *
+ * Every call blocks until the API has answered.
+ *
+ * @param transport what one request is issued through
+ */
+class SigningKeysApi(private val transport: Transport) {
+
+  /**
+   * Create a signing key
+   *
+   * @param body the SigningKeyPost the operation reads
+   * @return what the API answered
+   */
+  fun create(body: SigningKeyPost): SigningKey {
+    val path = "/api/v1/signing_keys/"
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(transport.request("POST", path, query, body.toJson()), SigningKey.Companion::fromJson)
+  }
+
+  /**
+   * Retire a signing key
+   *
+   * @param signingKeyId carries `signing_key_id`.
+   * @param applicationId carries `application_id`.
+   */
+  fun delete(signingKeyId: String, applicationId: String) {
+    var path = "/api/v1/signing_keys/{signing_key_id}"
+    path = path.replace("{signing_key_id}", Wire.pathSegment(signingKeyId))
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    Problems.checkAnswer(transport.request("DELETE", path, query, null))
+  }
+
+  /**
+   * Get the public keys of an application
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  fun jwks(applicationId: String): JsonWebKeySet {
+    var path = "/api/v1/jwks/{application_id}"
+    path = path.replace("{application_id}", Wire.pathSegment(applicationId))
+    val query = ArrayList<QueryParameter>()
+    return Problems.readAnswer(transport.request("GET", path, query, null), JsonWebKeySet.Companion::fromJson)
+  }
+
+  /**
+   * List signing keys
+   *
+   * @param applicationId carries `application_id`.
+   * @return what the API answered
+   */
+  fun list(applicationId: String): List<SigningKey> {
+    val path = "/api/v1/signing_keys/"
+    val query = ArrayList<QueryParameter>()
+    query.add(QueryParameter("application_id", Wire.queryValue(applicationId)))
+    return Problems.readAnswer(transport.request("GET", path, query, null), Wire.asList(SigningKey.Companion::fromJson))
+  }
+}
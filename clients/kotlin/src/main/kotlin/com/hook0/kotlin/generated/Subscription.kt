@@ -13,6 +13,7 @@ import java.util.UUID
  * @property applicationId carries `application_id`.
  * @property createdAt carries `created_at`.
  * @property dedicatedWorkers carries `dedicated_workers`.
+ * @property eventTypeVersions carries `event_type_versions`: Versions the subscription is pinned to, by event type
  * @property eventTypes carries `event_types`.
  * @property isEnabled carries `is_enabled`.
  * @property labelKey carries `label_key`: _Kept for backward compatibility, you should use `labels`_
@@ -20,15 +21,42 @@ import java.util.UUID
  * @property labels carries `labels`.
  * @property metadata carries `metadata`.
  * @property secret carries `secret`.
+ * @property signatureScheme carries `signature_scheme`.
  * @property subscriptionId carries `subscription_id`.
  * @property target carries `target`.
  * @property updatedAt carries `updated_at`.
+ * @property autoDisabledAt carries `auto_disabled_at`, or nothing when the API answers none: When the circuit breaker
+ *     or a response rule last disabled the subscription; cleared when it is enabled again
+ * @property autoDisabledReason carries `auto_disabled_reason`, or nothing when the API answers none: Why the circuit
+ *     breaker or a response rule last disabled the subscription
+ * @property batching carries `batching`, or nothing when the API answers none: Deliver the events of the subscription
+ *     in batches: one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or
+ *     once its oldest event has waited `max_wait_s` se
+ * @property circuitBreaker carries `circuit_breaker`, or nothing when the API answers none: Disables the subscription
+ *     once its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set
+ * @property deliveryLimits carries `delivery_limits`, or nothing when the API answers none: Limits on how fast
+ *     deliveries of the subscription start; deliveries over them wait for their turn, which does not count as a
+ *     failure; at least one limit must be set
  * @property description carries `description`, or nothing when the API answers none.
+ * @property filter carries `filter`, or nothing when the API answers none: An object with exactly one of: `and` or `or`
+ *     (a list of filters), `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a
+ *     JSON payload); `label` and `field` go alon
+ * @property orderingKey carries `ordering_key`, or nothing when the API answers none: Where the key of an event comes
+ *     from, to deliver the events that share a key one at a time and in the order they were received; exactly one of
+ *     `label` or `field` must be set
+ * @property payloadTransform carries `payload_transform`, or nothing when the API answers none: Declarative reshaping
+ *     of an `application/json` event payload, applied by the worker before the request is signed and sent; operations
+ *     are applied in order (`fields`, `envelope`, `constants`) and a pay
+ * @property responseRules carries `response_rules`, or nothing when the API answers none.
+ * @property retryPolicy carries `retry_policy`, or nothing when the API answers none: Retry schedule of the
+ *     subscription, replacing the one of the instance within its limits; every field left out keeps the value of the
+ *     instance
  */
 data class Subscription(
   val applicationId: UUID,
   val createdAt: OffsetDateTime,
   val dedicatedWorkers: List<String>,
+  val eventTypeVersions: Map<String, Int>,
   val eventTypes: List<String>,
   val isEnabled: Boolean,
   val labelKey: String,
@@ -36,10 +64,21 @@ data class Subscription(
   val labels: Map<String, String>,
   val metadata: Map<String, String>,
   val secret: UUID,
+  val signatureScheme: SubscriptionSignatureScheme,
   val subscriptionId: UUID,
   val target: SubscriptionTarget,
   val updatedAt: OffsetDateTime,
-  val description: String? = null
+  val autoDisabledAt: OffsetDateTime? = null,
+  val autoDisabledReason: String? = null,
+  val batching: SubscriptionBatching? = null,
+  val circuitBreaker: SubscriptionCircuitBreaker? = null,
+  val deliveryLimits: SubscriptionDeliveryLimits? = null,
+  val description: String? = null,
+  val filter: Any? = null,
+  val orderingKey: SubscriptionOrderingKey? = null,
+  val payloadTransform: SubscriptionPayloadTransform? = null,
+  val responseRules: List<SubscriptionResponseRules>? = null,
+  val retryPolicy: SubscriptionRetryPolicy? = null
 ) {
 
   /**
@@ -52,6 +91,7 @@ data class Subscription(
     out["application_id"] = Wire.writeUuid(applicationId)
     out["created_at"] = Wire.writeMoment(createdAt)
     out["dedicated_workers"] = dedicatedWorkers
+    out["event_type_versions"] = eventTypeVersions
     out["event_types"] = eventTypes
     out["is_enabled"] = isEnabled
     out["label_key"] = labelKey
@@ -59,12 +99,43 @@ data class Subscription(
     out["labels"] = labels
     out["metadata"] = metadata
     out["secret"] = Wire.writeUuid(secret)
+    out["signature_scheme"] = signatureScheme.wireValue
     out["subscription_id"] = Wire.writeUuid(subscriptionId)
     out["target"] = target.toJson()
     out["updated_at"] = Wire.writeMoment(updatedAt)
+    if (autoDisabledAt != null) {
+      out["auto_disabled_at"] = Wire.writeMoment(autoDisabledAt)
+    }
+    if (autoDisabledReason != null) {
+      out["auto_disabled_reason"] = autoDisabledReason
+    }
+    if (batching != null) {
+      out["batching"] = batching.toJson()
+    }
+    if (circuitBreaker != null) {
+      out["circuit_breaker"] = circuitBreaker.toJson()
+    }
+    if (deliveryLimits != null) {
+      out["delivery_limits"] = deliveryLimits.toJson()
+    }
     if (description != null) {
       out["description"] = description
     }
+    if (filter != null) {
+      out["filter"] = filter
+    }
+    if (orderingKey != null) {
+      out["ordering_key"] = orderingKey.toJson()
+    }
+    if (payloadTransform != null) {
+      out["payload_transform"] = payloadTransform.toJson()
+    }
+    if (responseRules != null) {
+      out["response_rules"] = Wire.writeList(responseRules, SubscriptionResponseRules::toJson)
+    }
+    if (retryPolicy != null) {
+      out["retry_policy"] = retryPolicy.toJson()
+    }
     return out
   }
 
@@ -81,6 +152,7 @@ data class Subscription(
         Wire.read(fields, "application_id", Wire::asUuid),
         Wire.read(fields, "created_at", Wire::asMoment),
         Wire.read(fields, "dedicated_workers", Wire.asList(Wire::asText)),
+        Wire.read(fields, "event_type_versions", Wire.asMap(Wire::asInteger)),
         Wire.read(fields, "event_types", Wire.asList(Wire::asText)),
         Wire.read(fields, "is_enabled", Wire::asBoolean),
         Wire.read(fields, "label_key", Wire::asText),
@@ -88,10 +160,21 @@ data class Subscription(
         Wire.read(fields, "labels", Wire.asMap(Wire::asText)),
         Wire.read(fields, "metadata", Wire.asMap(Wire::asText)),
         Wire.read(fields, "secret", Wire::asUuid),
+        Wire.read(fields, "signature_scheme", SubscriptionSignatureScheme.Companion::fromJson),
         Wire.read(fields, "subscription_id", Wire::asUuid),
         Wire.read(fields, "target", SubscriptionTarget.Companion::fromJson),
         Wire.read(fields, "updated_at", Wire::asMoment),
-        Wire.maybe(fields, "description", Wire::asText)
+        Wire.maybe(fields, "auto_disabled_at", Wire::asMoment),
+        Wire.maybe(fields, "auto_disabled_reason", Wire::asText),
+        Wire.maybe(fields, "batching", SubscriptionBatching.Companion::fromJson),
+        Wire.maybe(fields, "circuit_breaker", SubscriptionCircuitBreaker.Companion::fromJson),
+        Wire.maybe(fields, "delivery_limits", SubscriptionDeliveryLimits.Companion::fromJson),
+        Wire.maybe(fields, "description", Wire::asText),
+        Wire.maybe(fields, "filter", Wire::asJson),
+        Wire.maybe(fields, "ordering_key", SubscriptionOrderingKey.Companion::fromJson),
+        Wire.maybe(fields, "payload_transform", SubscriptionPayloadTransform.Companion::fromJson),
+        Wire.maybe(fields, "response_rules", Wire.asList(SubscriptionResponseRules.Companion::fromJson)),
+        Wire.maybe(fields, "retry_policy", SubscriptionRetryPolicy.Companion::fromJson)
       )
     }
   }
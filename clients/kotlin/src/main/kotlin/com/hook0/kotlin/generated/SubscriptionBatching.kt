@@ -0,0 +1,43 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionBatching` the API declares.
+ *
+ * @property maxEvents carries `max_events`: Largest number of events in a batch
+ * @property maxWaitS carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill up
+ */
+data class SubscriptionBatching(val maxEvents: Int, val maxWaitS: Int) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["max_events"] = maxEvents
+    out["max_wait_s"] = maxWaitS
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionBatching the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionBatching {
+      val fields = Wire.asFields(value, "SubscriptionBatching")
+      return SubscriptionBatching(
+        Wire.read(fields, "max_events", Wire::asInteger),
+        Wire.read(fields, "max_wait_s", Wire::asInteger)
+      )
+    }
+  }
+}
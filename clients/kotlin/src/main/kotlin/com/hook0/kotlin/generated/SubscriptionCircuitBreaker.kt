@@ -0,0 +1,49 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionCircuitBreaker` the API declares.
+ *
+ * @property maxConsecutiveFailures carries `max_consecutive_failures`, or nothing when the API answers none: Number of
+ *     failed deliveries in a row after which the subscription is disabled
+ * @property maxFailingHours carries `max_failing_hours`, or nothing when the API answers none: Number of hours without
+ *     a single successful delivery after which the subscription is disabled
+ */
+data class SubscriptionCircuitBreaker(val maxConsecutiveFailures: Int? = null, val maxFailingHours: Int? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    if (maxConsecutiveFailures != null) {
+      out["max_consecutive_failures"] = maxConsecutiveFailures
+    }
+    if (maxFailingHours != null) {
+      out["max_failing_hours"] = maxFailingHours
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionCircuitBreaker the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionCircuitBreaker {
+      val fields = Wire.asFields(value, "SubscriptionCircuitBreaker")
+      return SubscriptionCircuitBreaker(
+        Wire.maybe(fields, "max_consecutive_failures", Wire::asInteger),
+        Wire.maybe(fields, "max_failing_hours", Wire::asInteger)
+      )
+    }
+  }
+}
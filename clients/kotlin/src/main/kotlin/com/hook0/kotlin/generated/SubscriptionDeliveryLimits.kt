@@ -0,0 +1,60 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionDeliveryLimits` the API declares.
+ *
+ * @property maxInFlight carries `max_in_flight`, or nothing when the API answers none: Number of deliveries that can be
+ *     in flight at once
+ * @property maxPerSecond carries `max_per_second`, or nothing when the API answers none: Number of deliveries that can
+ *     start in a second
+ * @property perHost carries `per_host`, or nothing when the API answers none: Share the limits with every subscription
+ *     of the application that delivers to the same host and sets this too, rather than counting the deliveries of this
+ *     subscription alone
+ */
+data class SubscriptionDeliveryLimits(
+  val maxInFlight: Int? = null,
+  val maxPerSecond: Int? = null,
+  val perHost: Boolean? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    if (maxInFlight != null) {
+      out["max_in_flight"] = maxInFlight
+    }
+    if (maxPerSecond != null) {
+      out["max_per_second"] = maxPerSecond
+    }
+    if (perHost != null) {
+      out["per_host"] = perHost
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionDeliveryLimits the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionDeliveryLimits {
+      val fields = Wire.asFields(value, "SubscriptionDeliveryLimits")
+      return SubscriptionDeliveryLimits(
+        Wire.maybe(fields, "max_in_flight", Wire::asInteger),
+        Wire.maybe(fields, "max_per_second", Wire::asInteger),
+        Wire.maybe(fields, "per_host", Wire::asBoolean)
+      )
+    }
+  }
+}
@@ -13,26 +13,60 @@ import java.util.UUID
  * @property eventTypes carries `event_types`.
  * @property isEnabled carries `is_enabled`.
  * @property target carries `target`.
+ * @property batching carries `batching`, or nothing when the API answers none: Deliver the events in batches, as one
+ *     HTTP request with a JSON array body, rather than one by one
+ * @property circuitBreaker carries `circuit_breaker`, or nothing when the API answers none: Disable the subscription
+ *     once its deliveries keep failing; organization editors are notified by email
  * @property dedicatedWorkers carries `dedicated_workers`, or nothing when the API answers none.
+ * @property deliveryLimits carries `delivery_limits`, or nothing when the API answers none: Limit how fast deliveries
+ *     start, for this subscription or for its host
  * @property description carries `description`, or nothing when the API answers none.
+ * @property eventTypeVersions carries `event_type_versions`, or nothing when the API answers none: Only receive the
+ *     events of these event types that declare this version (or no version at all)
+ * @property filter carries `filter`, or nothing when the API answers none: Only deliver the events of the subscribed
+ *     types that match this expression over labels and JSON payload fields
  * @property labelKey carries `label_key`, or nothing when the API answers none: _Kept for backward compatibility, you
  *     should use `labels`_
  * @property labelValue carries `label_value`, or nothing when the API answers none: _Kept for backward compatibility,
  *     you should use `labels`_
  * @property labels carries `labels`, or nothing when the API answers none.
  * @property metadata carries `metadata`, or nothing when the API answers none.
+ * @property orderingKey carries `ordering_key`, or nothing when the API answers none: Deliver the events that share a
+ *     key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran
+ *     out of retries
+ * @property payloadTransform carries `payload_transform`, or nothing when the API answers none: Declarative reshaping
+ *     of an `application/json` event payload, applied by the worker before the request is signed and sent; operations
+ *     are applied in order (`fields`, `envelope`, `constants`) and a pay
+ * @property responseRules carries `response_rules`, or nothing when the API answers none: Decide the outcome of HTTP
+ *     deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body reports
+ *     an error; the first rule that matches wins, and a response n
+ * @property retryPolicy carries `retry_policy`, or nothing when the API answers none: Retry failed deliveries on this
+ *     schedule rather than the instance's one
+ * @property signatureScheme carries `signature_scheme`, or nothing when the API answers none: How deliveries are signed
+ *     (`hook0` by default); `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is
+ *     `whsec_` followed by the base64 encoding of the subscription s
  */
 data class SubscriptionPost(
   val applicationId: UUID,
   val eventTypes: List<String>,
   val isEnabled: Boolean,
   val target: SubscriptionPostTarget,
+  val batching: SubscriptionPostBatching? = null,
+  val circuitBreaker: SubscriptionPostCircuitBreaker? = null,
   val dedicatedWorkers: List<String>? = null,
+  val deliveryLimits: SubscriptionPostDeliveryLimits? = null,
   val description: String? = null,
+  val eventTypeVersions: Map<String, Int>? = null,
+  val filter: Any? = null,
   val labelKey: String? = null,
   val labelValue: String? = null,
   val labels: Map<String, String>? = null,
-  val metadata: Map<String, String>? = null
+  val metadata: Map<String, String>? = null,
+  val orderingKey: SubscriptionPostOrderingKey? = null,
+  val payloadTransform: SubscriptionPostPayloadTransform? = null,
+  val responseRules: List<SubscriptionPostResponseRules>? = null,
+  val retryPolicy: SubscriptionPostRetryPolicy? = null,
+  val signatureScheme: SubscriptionPostSignatureScheme? = null
 ) {
 
   /**
@@ -46,12 +80,27 @@ data class SubscriptionPost(
     out["event_types"] = eventTypes
     out["is_enabled"] = isEnabled
     out["target"] = target.toJson()
+    if (batching != null) {
+      out["batching"] = batching.toJson()
+    }
+    if (circuitBreaker != null) {
+      out["circuit_breaker"] = circuitBreaker.toJson()
+    }
     if (dedicatedWorkers != null) {
       out["dedicated_workers"] = dedicatedWorkers
     }
+    if (deliveryLimits != null) {
+      out["delivery_limits"] = deliveryLimits.toJson()
+    }
     if (description != null) {
       out["description"] = description
     }
+    if (eventTypeVersions != null) {
+      out["event_type_versions"] = eventTypeVersions
+    }
+    if (filter != null) {
+      out["filter"] = filter
+    }
     if (labelKey != null) {
       out["label_key"] = labelKey
     }
@@ -64,6 +113,21 @@ data class SubscriptionPost(
     if (metadata != null) {
       out["metadata"] = metadata
     }
+    if (orderingKey != null) {
+      out["ordering_key"] = orderingKey.toJson()
+    }
+    if (payloadTransform != null) {
+      out["payload_transform"] = payloadTransform.toJson()
+    }
+    if (responseRules != null) {
+      out["response_rules"] = Wire.writeList(responseRules, SubscriptionPostResponseRules::toJson)
+    }
+    if (retryPolicy != null) {
+      out["retry_policy"] = retryPolicy.toJson()
+    }
+    if (signatureScheme != null) {
+      out["signature_scheme"] = signatureScheme.wireValue
+    }
     return out
   }
 
@@ -81,12 +145,22 @@ data class SubscriptionPost(
         Wire.read(fields, "event_types", Wire.asList(Wire::asText)),
         Wire.read(fields, "is_enabled", Wire::asBoolean),
         Wire.read(fields, "target", SubscriptionPostTarget.Companion::fromJson),
+        Wire.maybe(fields, "batching", SubscriptionPostBatching.Companion::fromJson),
+        Wire.maybe(fields, "circuit_breaker", SubscriptionPostCircuitBreaker.Companion::fromJson),
         Wire.maybe(fields, "dedicated_workers", Wire.asList(Wire::asText)),
+        Wire.maybe(fields, "delivery_limits", SubscriptionPostDeliveryLimits.Companion::fromJson),
         Wire.maybe(fields, "description", Wire::asText),
+        Wire.maybe(fields, "event_type_versions", Wire.asMap(Wire::asInteger)),
+        Wire.maybe(fields, "filter", Wire::asJson),
         Wire.maybe(fields, "label_key", Wire::asText),
         Wire.maybe(fields, "label_value", Wire::asText),
         Wire.maybe(fields, "labels", Wire.asMap(Wire::asText)),
-        Wire.maybe(fields, "metadata", Wire.asMap(Wire::asText))
+        Wire.maybe(fields, "metadata", Wire.asMap(Wire::asText)),
+        Wire.maybe(fields, "ordering_key", SubscriptionPostOrderingKey.Companion::fromJson),
+        Wire.maybe(fields, "payload_transform", SubscriptionPostPayloadTransform.Companion::fromJson),
+        Wire.maybe(fields, "response_rules", Wire.asList(SubscriptionPostResponseRules.Companion::fromJson)),
+        Wire.maybe(fields, "retry_policy", SubscriptionPostRetryPolicy.Companion::fromJson),
+        Wire.maybe(fields, "signature_scheme", SubscriptionPostSignatureScheme.Companion::fromJson)
       )
     }
   }
@@ -0,0 +1,48 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionPostOrderingKey` the API declares.
+ *
+ * @property field carries `field`, or nothing when the API answers none: JSON pointer to the value holding the key in a
+ *     JSON payload, for example `/customer/id`
+ * @property label carries `label`, or nothing when the API answers none: Name of the event label holding the key
+ */
+data class SubscriptionPostOrderingKey(val field: String? = null, val label: String? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    if (field != null) {
+      out["field"] = field
+    }
+    if (label != null) {
+      out["label"] = label
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionPostOrderingKey the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionPostOrderingKey {
+      val fields = Wire.asFields(value, "SubscriptionPostOrderingKey")
+      return SubscriptionPostOrderingKey(
+        Wire.maybe(fields, "field", Wire::asText),
+        Wire.maybe(fields, "label", Wire::asText)
+      )
+    }
+  }
+}
@@ -0,0 +1,59 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionPostPayloadTransform` the API declares.
+ *
+ * @property constants carries `constants`, or nothing when the API answers none: Properties added at the top level of
+ *     the result, overriding properties of the same name (the result must then be an object)
+ * @property envelope carries `envelope`, or nothing when the API answers none: Name of a property the result is wrapped
+ *     in
+ * @property fields carries `fields`, or nothing when the API answers none: Values to copy from the payload into a new
+ *     object; when omitted, the whole payload is kept
+ */
+data class SubscriptionPostPayloadTransform(
+  val constants: Map<String, Any>? = null,
+  val envelope: String? = null,
+  val fields: List<SubscriptionPostPayloadTransformFields>? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    if (constants != null) {
+      out["constants"] = constants
+    }
+    if (envelope != null) {
+      out["envelope"] = envelope
+    }
+    if (fields != null) {
+      out["fields"] = Wire.writeList(fields, SubscriptionPostPayloadTransformFields::toJson)
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionPostPayloadTransform the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionPostPayloadTransform {
+      val fields = Wire.asFields(value, "SubscriptionPostPayloadTransform")
+      return SubscriptionPostPayloadTransform(
+        Wire.maybe(fields, "constants", Wire.asMap(Wire::asJson)),
+        Wire.maybe(fields, "envelope", Wire::asText),
+        Wire.maybe(fields, "fields", Wire.asList(SubscriptionPostPayloadTransformFields.Companion::fromJson))
+      )
+    }
+  }
+}
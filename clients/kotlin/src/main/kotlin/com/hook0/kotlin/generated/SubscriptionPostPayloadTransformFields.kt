@@ -0,0 +1,47 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionPostPayloadTransformFields` the API declares.
+ *
+ * @property from carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that are absent
+ *     from a payload are skipped)
+ * @property to carries `to`, or nothing when the API answers none: JSON pointer where the value is written in the
+ *     result, for example `/id`; defaults to `from`
+ */
+data class SubscriptionPostPayloadTransformFields(val from: String, val to: String? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["from"] = from
+    if (to != null) {
+      out["to"] = to
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionPostPayloadTransformFields the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionPostPayloadTransformFields {
+      val fields = Wire.asFields(value, "SubscriptionPostPayloadTransformFields")
+      return SubscriptionPostPayloadTransformFields(
+        Wire.read(fields, "from", Wire::asText),
+        Wire.maybe(fields, "to", Wire::asText)
+      )
+    }
+  }
+}
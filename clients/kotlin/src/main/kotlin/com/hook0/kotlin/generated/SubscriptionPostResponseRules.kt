@@ -0,0 +1,61 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionPostResponseRules` the API declares.
+ *
+ * @property outcome carries `outcome`: What the delivery becomes when the rule matches: `success`, `retryable`,
+ *     `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+ * @property status carries `status`: Status codes the rule applies to: exact codes such as `410`, or classes such as
+ *     `4xx`
+ * @property bodyContains carries `body_contains`, or nothing when the API answers none: Text the response body must
+ *     contain
+ * @property header carries `header`, or nothing when the API answers none: Header the response must have
+ */
+data class SubscriptionPostResponseRules(
+  val outcome: SubscriptionPostResponseRulesOutcome,
+  val status: List<String>,
+  val bodyContains: String? = null,
+  val header: SubscriptionPostResponseRulesHeader? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["outcome"] = outcome.wireValue
+    out["status"] = status
+    if (bodyContains != null) {
+      out["body_contains"] = bodyContains
+    }
+    if (header != null) {
+      out["header"] = header.toJson()
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionPostResponseRules the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionPostResponseRules {
+      val fields = Wire.asFields(value, "SubscriptionPostResponseRules")
+      return SubscriptionPostResponseRules(
+        Wire.read(fields, "outcome", SubscriptionPostResponseRulesOutcome.Companion::fromJson),
+        Wire.read(fields, "status", Wire.asList(Wire::asText)),
+        Wire.maybe(fields, "body_contains", Wire::asText),
+        Wire.maybe(fields, "header", SubscriptionPostResponseRulesHeader.Companion::fromJson)
+      )
+    }
+  }
+}
@@ -0,0 +1,46 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionPostResponseRulesHeader` the API declares.
+ *
+ * @property name carries `name`: Name of the header, regardless of case
+ * @property value carries `value`, or nothing when the API answers none: Value the header must have; when omitted, any
+ *     value matches
+ */
+data class SubscriptionPostResponseRulesHeader(val name: String, val value: String? = null) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    out["name"] = name
+    if (value != null) {
+      out["value"] = value
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionPostResponseRulesHeader the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionPostResponseRulesHeader {
+      val fields = Wire.asFields(value, "SubscriptionPostResponseRulesHeader")
+      return SubscriptionPostResponseRulesHeader(
+        Wire.read(fields, "name", Wire::asText),
+        Wire.maybe(fields, "value", Wire::asText)
+      )
+    }
+  }
+}
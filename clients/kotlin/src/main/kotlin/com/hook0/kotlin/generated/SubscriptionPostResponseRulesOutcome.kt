@@ -0,0 +1,44 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.DecodeException
+import com.hook0.kotlin.Wire
+
+/**
+ * One of the values the API answers with.
+ */
+enum class SubscriptionPostResponseRulesOutcome(val wireValue: String) {
+  /** The API spells this one `success`. */
+  SUCCESS("success"),
+
+  /** The API spells this one `retryable`. */
+  RETRYABLE("retryable"),
+
+  /** The API spells this one `permanent_failure`. */
+  PERMANENT_FAILURE("permanent_failure"),
+
+  /** The API spells this one `disable_subscription`. */
+  DISABLE_SUBSCRIPTION("disable_subscription");
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the value it names
+     */
+    fun fromJson(value: Any?): SubscriptionPostResponseRulesOutcome {
+      val named = Wire.asText(value)
+      for (candidate in SubscriptionPostResponseRulesOutcome.entries) {
+        if (candidate.wireValue == named) {
+          return candidate
+        }
+      }
+      throw DecodeException(
+        "`${Wire.preview(named)}` is not one of the values SubscriptionPostResponseRulesOutcome declares"
+      )
+    }
+  }
+}
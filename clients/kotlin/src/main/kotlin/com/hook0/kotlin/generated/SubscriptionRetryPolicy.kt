@@ -0,0 +1,59 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.Wire
+
+/**
+ * The `SubscriptionRetryPolicy` the API declares.
+ *
+ * @property backoffStepsS carries `backoff_steps_s`, or nothing when the API answers none: Delays in seconds to wait
+ *     before each retry; the last one is used again for the following retries
+ * @property maxRetries carries `max_retries`, or nothing when the API answers none: Maximum number of retries after a
+ *     failed delivery (0 disables retries)
+ * @property maxWindowS carries `max_window_s`, or nothing when the API answers none: Maximum duration in seconds the
+ *     retries can span; a retry that would not fit in it is not scheduled
+ */
+data class SubscriptionRetryPolicy(
+  val backoffStepsS: List<Int>? = null,
+  val maxRetries: Int? = null,
+  val maxWindowS: Int? = null
+) {
+
+  /**
+   * Writes one back the way the API reads it.
+   *
+   * @return the document the API reads
+   */
+  fun toJson(): Map<String, Any?> {
+    val out = LinkedHashMap<String, Any?>()
+    if (backoffStepsS != null) {
+      out["backoff_steps_s"] = backoffStepsS
+    }
+    if (maxRetries != null) {
+      out["max_retries"] = maxRetries
+    }
+    if (maxWindowS != null) {
+      out["max_window_s"] = maxWindowS
+    }
+    return out
+  }
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the SubscriptionRetryPolicy the API declares
+     */
+    fun fromJson(value: Any?): SubscriptionRetryPolicy {
+      val fields = Wire.asFields(value, "SubscriptionRetryPolicy")
+      return SubscriptionRetryPolicy(
+        Wire.maybe(fields, "backoff_steps_s", Wire.asList(Wire::asInteger)),
+        Wire.maybe(fields, "max_retries", Wire::asInteger),
+        Wire.maybe(fields, "max_window_s", Wire::asInteger)
+      )
+    }
+  }
+}
@@ -0,0 +1,36 @@
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=kotlin cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+package com.hook0.kotlin.generated
+
+import com.hook0.kotlin.DecodeException
+import com.hook0.kotlin.Wire
+
+/**
+ * One of the values the API answers with.
+ */
+enum class SubscriptionSignatureScheme(val wireValue: String) {
+  /** The API spells this one `hook0`. */
+  HOOK0("hook0"),
+
+  /** The API spells this one `standard_webhooks`. */
+  STANDARD_WEBHOOKS("standard_webhooks");
+
+  companion object {
+    /**
+     * Reads one out of what the API answered.
+     *
+     * @param value the JSON document the API answered
+     * @return the value it names
+     */
+    fun fromJson(value: Any?): SubscriptionSignatureScheme {
+      val named = Wire.asText(value)
+      for (candidate in SubscriptionSignatureScheme.entries) {
+        if (candidate.wireValue == named) {
+          return candidate
+        }
+      }
+      throw DecodeException("`${Wire.preview(named)}` is not one of the values SubscriptionSignatureScheme declares")
+    }
+  }
+}
@@ -8,12 +8,41 @@ import com.hook0.kotlin.Wire
 /**
  * The `SubscriptionTarget` the API declares.
  *
- * @property headers carries `headers`.
- * @property method carries `method`.
- * @property type carries `type`.
- * @property url carries `url`.
+ * @property type carries `type`: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API);
+ *     the other properties depend on it
+ * @property accessKeyId carries `access_key_id`, or nothing when the API answers none: `sqs`: access key ID requests
+ *     are signed with
+ * @property exchange carries `exchange`, or nothing when the API answers none: `amqp`: exchange messages are published
+ *     to; empty for the default exchange
+ * @property headers carries `headers`, or nothing when the API answers none: `http`: headers sent with every request
+ * @property method carries `method`, or nothing when the API answers none: `http`: HTTP method of the requests
+ * @property password carries `password`, or nothing when the API answers none: `amqp`: password to log in to the broker
+ *     with; write-only
+ * @property queueUrl carries `queue_url`, or nothing when the API answers none: `sqs`: URL of the queue
+ * @property region carries `region`, or nothing when the API answers none: `sqs`: region requests are signed for
+ * @property routingKey carries `routing_key`, or nothing when the API answers none: `amqp`: routing key messages are
+ *     published with
+ * @property secretAccessKey carries `secret_access_key`, or nothing when the API answers none: `sqs`: secret access key
+ *     requests are signed with; write-only
+ * @property url carries `url`, or nothing when the API answers none: `http`: URL of the endpoint; `amqp`: URL of the
+ *     broker (`amqp` or `amqps` scheme), without credentials
+ * @property username carries `username`, or nothing when the API answers none: `amqp`: user name to log in to the
+ *     broker with
  */
-data class SubscriptionTarget(val headers: Any, val method: String, val type: String, val url: String) {
+data class SubscriptionTarget(
+  val type: SubscriptionTargetType,
+  val accessKeyId: String? = null,
+  val exchange: String? = null,
+  val headers: Any? = null,
+  val method: String? = null,
+  val password: String? = null,
+  val queueUrl: String? = null,
+  val region: String? = null,
+  val routingKey: String? = null,
+  val secretAccessKey: String? = null,
+  val url: String? = null,
+  val username: String? = null
+) {
 
   /**
    * Writes one back the way the API reads it.
@@ -22,10 +51,40 @@ data class SubscriptionTarget(val headers: Any, val method: String, val type: St
    */
   fun toJson(): Map<String, Any?> {
     val out = LinkedHashMap<String, Any?>()
-    out["headers"] = headers
-    out["method"] = method
-    out["type"] = type
-    out["url"] = url
+    out["type"] = type.wireValue
+    if (accessKeyId != null) {
+      out["access_key_id"] = accessKeyId
+    }
+    if (exchange != null) {
+      out["exchange"] = exchange
+    }
+    if (headers != null) {
+      out["headers"] = headers
+    }
+    if (method != null) {
+      out["method"] = method
+    }
+    if (password != null) {
+      out["password"] = password
+    }
+    if (queueUrl != null) {
+      out["queue_url"] = queueUrl
+    }
+    if (region != null) {
+      out["region"] = region
+    }
+    if (routingKey != null) {
+      out["routing_key"] = routingKey
+    }
+    if (secretAccessKey != null) {
+      out["secret_access_key"] = secretAccessKey
+    }
+    if (url != null) {
+      out["url"] = url
+    }
+    if (username != null) {
+      out["username"] = username
+    }
     return out
   }
 
@@ -39,10 +98,18 @@ data class SubscriptionTarget(val headers: Any, val method: String, val type: St
     fun fromJson(value: Any?): SubscriptionTarget {
       val fields = Wire.asFields(value, "SubscriptionTarget")
       return SubscriptionTarget(
-        Wire.read(fields, "headers", Wire::asJson),
-        Wire.read(fields, "method", Wire::asText),
-        Wire.read(fields, "type", Wire::asText),
-        Wire.read(fields, "url", Wire::asText)
+        Wire.read(fields, "type", SubscriptionTargetType.Companion::fromJson),
+        Wire.maybe(fields, "access_key_id", Wire::asText),
+        Wire.maybe(fields, "exchange", Wire::asText),
+        Wire.maybe(fields, "headers", Wire::asJson),
+        Wire.maybe(fields, "method", Wire::asText),
+        Wire.maybe(fields, "password", Wire::asText),
+        Wire.maybe(fields, "queue_url", Wire::asText),
+        Wire.maybe(fields, "region", Wire::asText),
+        Wire.maybe(fields, "routing_key", Wire::asText),
+        Wire.maybe(fields, "secret_access_key", Wire::asText),
+        Wire.maybe(fields, "url", Wire::asText),
+        Wire.maybe(fields, "username", Wire::asText)
       )
     }
   }
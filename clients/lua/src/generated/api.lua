@@ -198,6 +198,291 @@ function Api.ApplicationsApi:update(application_id, body)
   )
 end
 
+--- What the API declares under `bulkRetries`, issued through the transport it is handed.
+Api.BulkRetriesApi = {}
+Api.BulkRetriesApi.__index = Api.BulkRetriesApi
+
+--- @param transport table what one request is issued through
+--- @return BulkRetriesApi
+function Api.BulkRetriesApi.new(transport)
+  return setmetatable({ transport = transport }, Api.BulkRetriesApi)
+end
+
+--- Cancel a bulk retry
+--- @param bulk_retry_id string carries `bulk_retry_id`.
+--- @param application_id string carries `application_id`.
+--- @return BulkRetry
+function Api.BulkRetriesApi:cancel(bulk_retry_id, application_id)
+  return read_answer(
+    Models.BulkRetry.from_json,
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/bulk_retries/{bulk_retry_id}/cancel", {
+        ["bulk_retry_id"] = bulk_retry_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Create a bulk retry
+--- @param body BulkRetryPost what the operation reads
+--- @return BulkRetry
+function Api.BulkRetriesApi:create(body)
+  return read_answer(
+    Models.BulkRetry.from_json,
+    self.transport:request(
+      "POST",
+      "/api/v1/bulk_retries/",
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
+--- Count what a bulk retry would redeliver
+--- @param body BulkRetryPost what the operation reads
+--- @return BulkRetryDryRun
+function Api.BulkRetriesApi:dry_run(body)
+  return read_answer(
+    Models.BulkRetryDryRun.from_json,
+    self.transport:request(
+      "POST",
+      "/api/v1/bulk_retries/dry_run",
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
+--- Get a bulk retry
+--- @param bulk_retry_id string carries `bulk_retry_id`.
+--- @param application_id string carries `application_id`.
+--- @return BulkRetry
+function Api.BulkRetriesApi:get(bulk_retry_id, application_id)
+  return read_answer(
+    Models.BulkRetry.from_json,
+    self.transport:request(
+      "GET",
+      Runtime.path("/api/v1/bulk_retries/{bulk_retry_id}", {
+        ["bulk_retry_id"] = bulk_retry_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- List bulk retries
+--- @param application_id string carries `application_id`.
+--- @return BulkRetry[]
+function Api.BulkRetriesApi:list(application_id)
+  return read_answer(
+    Runtime.list(Models.BulkRetry.from_json),
+    self.transport:request(
+      "GET",
+      "/api/v1/bulk_retries/",
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- What the API declares under `clientCertificates`, issued through the transport it is handed.
+Api.ClientCertificatesApi = {}
+Api.ClientCertificatesApi.__index = Api.ClientCertificatesApi
+
+--- @param transport table what one request is issued through
+--- @return ClientCertificatesApi
+function Api.ClientCertificatesApi.new(transport)
+  return setmetatable({ transport = transport }, Api.ClientCertificatesApi)
+end
+
+--- Remove the client certificate of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @return nil
+function Api.ClientCertificatesApi:delete(subscription_id, application_id)
+  return check_answer(
+    self.transport:request(
+      "DELETE",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/client_certificate", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Get the client certificate of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @return ClientCertificate
+function Api.ClientCertificatesApi:get(subscription_id, application_id)
+  return read_answer(
+    Models.ClientCertificate.from_json,
+    self.transport:request(
+      "GET",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/client_certificate", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Set the client certificate of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @param body ClientCertificatePut what the operation reads
+--- @return ClientCertificate
+function Api.ClientCertificatesApi:update(subscription_id, application_id, body)
+  return read_answer(
+    Models.ClientCertificate.from_json,
+    self.transport:request(
+      "PUT",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/client_certificate", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      Runtime.written(body)
+    )
+  )
+end
+
+--- What the API declares under `deadLetters`, issued through the transport it is handed.
+Api.DeadLettersApi = {}
+Api.DeadLettersApi.__index = Api.DeadLettersApi
+
+--- @param transport table what one request is issued through
+--- @return DeadLettersApi
+function Api.DeadLettersApi.new(transport)
+  return setmetatable({ transport = transport }, Api.DeadLettersApi)
+end
+
+--- Delete a dead letter
+--- @param dead_letter_id string carries `dead_letter_id`.
+--- @param application_id string carries `application_id`.
+--- @return nil
+function Api.DeadLettersApi:delete(dead_letter_id, application_id)
+  return check_answer(
+    self.transport:request(
+      "DELETE",
+      Runtime.path("/api/v1/dead_letters/{dead_letter_id}", {
+        ["dead_letter_id"] = dead_letter_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Get a dead letter by its ID
+--- @param dead_letter_id string carries `dead_letter_id`.
+--- @param application_id string carries `application_id`.
+--- @return DeadLetterDetails
+function Api.DeadLettersApi:get(dead_letter_id, application_id)
+  return read_answer(
+    Models.DeadLetterDetails.from_json,
+    self.transport:request(
+      "GET",
+      Runtime.path("/api/v1/dead_letters/{dead_letter_id}", {
+        ["dead_letter_id"] = dead_letter_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- List dead letters
+--- @param application_id string carries `application_id`.
+--- @param pagination_cursor string|nil carries `pagination_cursor`.
+--- @param subscription_id string|nil carries `subscription_id`.
+--- @return DeadLetter[]
+function Api.DeadLettersApi:list(application_id, pagination_cursor, subscription_id)
+  return read_answer(
+    Runtime.list(Models.DeadLetter.from_json),
+    self.transport:request(
+      "GET",
+      "/api/v1/dead_letters/",
+      Runtime.query({
+        { "application_id", application_id },
+        { "pagination_cursor", pagination_cursor },
+        { "subscription_id", subscription_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Purge dead letters
+--- @param body PurgeDeadLetters what the operation reads
+--- @return PurgedDeadLetters
+function Api.DeadLettersApi:purge(body)
+  return read_answer(
+    Models.PurgedDeadLetters.from_json,
+    self.transport:request(
+      "POST",
+      "/api/v1/dead_letters/purge",
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
+--- Redeliver a dead letter
+--- @param dead_letter_id string carries `dead_letter_id`.
+--- @param body RedeliverDeadLetter what the operation reads
+--- @return RedeliveredDeadLetter
+function Api.DeadLettersApi:redeliver(dead_letter_id, body)
+  return read_answer(
+    Models.RedeliveredDeadLetter.from_json,
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/dead_letters/{dead_letter_id}/redeliver", {
+        ["dead_letter_id"] = dead_letter_id,
+      }),
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
+--- Redeliver dead letters
+--- @param body RedeliverDeadLetters what the operation reads
+--- @return RedeliveredDeadLetter[]
+function Api.DeadLettersApi:redeliver_many(body)
+  return read_answer(
+    Runtime.list(Models.RedeliveredDeadLetter.from_json),
+    self.transport:request(
+      "POST",
+      "/api/v1/dead_letters/redeliver",
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
 --- What the API declares under `errors`, issued through the transport it is handed.
 Api.ErrorsApi = {}
 Api.ErrorsApi.__index = Api.ErrorsApi
@@ -247,6 +532,24 @@ function Api.EventTypesApi:create(body)
   )
 end
 
+--- Create a new event type version
+--- @param event_type_name string carries `event_type_name`.
+--- @param body EventTypeVersionPost what the operation reads
+--- @return EventTypeVersion
+function Api.EventTypesApi:create_version(event_type_name, body)
+  return read_answer(
+    Models.EventTypeVersion.from_json,
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/event_types/{event_type_name}/versions", {
+        ["event_type_name"] = event_type_name,
+      }),
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
 --- Delete an event type
 --- @param event_type_name string carries `event_type_name`.
 --- @param application_id string carries `application_id`.
@@ -266,6 +569,24 @@ function Api.EventTypesApi:delete(event_type_name, application_id)
   )
 end
 
+--- Deprecate an event type version
+--- @param event_type_name string carries `event_type_name`.
+--- @param body EventTypeVersionDeprecate what the operation reads
+--- @return EventTypeVersion
+function Api.EventTypesApi:deprecate_version(event_type_name, body)
+  return read_answer(
+    Models.EventTypeVersion.from_json,
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/event_types/{event_type_name}/deprecate", {
+        ["event_type_name"] = event_type_name,
+      }),
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
 --- Get an event type by its name
 --- @param event_type_name string carries `event_type_name`.
 --- @param application_id string carries `application_id`.
@@ -303,6 +624,24 @@ function Api.EventTypesApi:list(application_id)
   )
 end
 
+--- Update an event type
+--- @param event_type_name string carries `event_type_name`.
+--- @param body EventTypePut what the operation reads
+--- @return EventType
+function Api.EventTypesApi:update(event_type_name, body)
+  return read_answer(
+    Models.EventType.from_json,
+    self.transport:request(
+      "PUT",
+      Runtime.path("/api/v1/event_types/{event_type_name}", {
+        ["event_type_name"] = event_type_name,
+      }),
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
 --- What the API declares under `events`, issued through the transport it is handed.
 Api.EventsApi = {}
 Api.EventsApi.__index = Api.EventsApi
@@ -313,6 +652,23 @@ function Api.EventsApi.new(transport)
   return setmetatable({ transport = transport }, Api.EventsApi)
 end
 
+--- Cancel a scheduled event
+--- @param event_id string carries `event_id`.
+--- @param body CancelEvent what the operation reads
+--- @return nil
+function Api.EventsApi:cancel(event_id, body)
+  return check_answer(
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/events/{event_id}/cancel", {
+        ["event_id"] = event_id,
+      }),
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
 --- Get an event by its ID
 --- @param event_id string carries `event_id`.
 --- @param application_id string carries `application_id`.
@@ -348,10 +704,27 @@ function Api.EventsApi:ingest(body)
   )
 end
 
+--- Ingest a batch of events
+--- @param body EventsBatchPost what the operation reads
+--- @return IngestedEventsBatch
+function Api.EventsApi:ingest_batch(body)
+  return read_answer(
+    Models.IngestedEventsBatch.from_json,
+    self.transport:request(
+      "POST",
+      "/api/v1/events/batch",
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
 --- List latest events
 --- @param application_id string carries `application_id`.
+--- @param scheduled boolean|nil carries `scheduled`: Only list the events scheduled for a later delivery that was not
+---   cancelled, soonest first
 --- @return Event[]
-function Api.EventsApi:list(application_id)
+function Api.EventsApi:list(application_id, scheduled)
   return read_answer(
     Runtime.list(Models.Event.from_json),
     self.transport:request(
@@ -359,6 +732,7 @@ function Api.EventsApi:list(application_id)
       "/api/v1/events/",
       Runtime.query({
         { "application_id", application_id },
+        { "scheduled", scheduled },
       }),
       nil
     )
@@ -434,6 +808,79 @@ function Api.EventsPerDayApi:list_for_organization(organization_id, from, to)
   )
 end
 
+--- What the API declares under `inbox`, issued through the transport it is handed.
+Api.InboxApi = {}
+Api.InboxApi.__index = Api.InboxApi
+
+--- @param transport table what one request is issued through
+--- @return InboxApi
+function Api.InboxApi.new(transport)
+  return setmetatable({ transport = transport }, Api.InboxApi)
+end
+
+--- Acknowledge events pulled from an inbox
+--- @param subscription_id string carries `subscription_id`.
+--- @param body InboxAck what the operation reads
+--- @return InboxSettlement
+function Api.InboxApi:ack(subscription_id, body)
+  return read_answer(
+    Models.InboxSettlement.from_json,
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/inbox/{subscription_id}/ack", {
+        ["subscription_id"] = subscription_id,
+      }),
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
+--- Reject events pulled from an inbox
+--- @param subscription_id string carries `subscription_id`.
+--- @param body InboxNack what the operation reads
+--- @return InboxSettlement
+function Api.InboxApi:nack(subscription_id, body)
+  return read_answer(
+    Models.InboxSettlement.from_json,
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/inbox/{subscription_id}/nack", {
+        ["subscription_id"] = subscription_id,
+      }),
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
+--- Pull events from an inbox
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @param cursor string|nil carries `cursor`: Only lease events after this cursor, returned by a previous pull
+--- @param limit integer|nil carries `limit`: Maximum number of events to lease (10 by default)
+--- @param visibility_timeout_s integer|nil carries `visibility_timeout_s`: For how long in seconds the leased events
+---   are not offered again (30 by default)
+--- @return Inbox
+function Api.InboxApi:pull(subscription_id, application_id, cursor, limit, visibility_timeout_s)
+  return read_answer(
+    Models.Inbox.from_json,
+    self.transport:request(
+      "GET",
+      Runtime.path("/api/v1/inbox/{subscription_id}", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+        { "cursor", cursor },
+        { "limit", limit },
+        { "visibility_timeout_s", visibility_timeout_s },
+      }),
+      nil
+    )
+  )
+end
+
 --- What the API declares under `instance`, issued through the transport it is handed.
 Api.InstanceApi = {}
 Api.InstanceApi.__index = Api.InstanceApi
@@ -458,6 +905,76 @@ function Api.InstanceApi:get()
   )
 end
 
+--- What the API declares under `oauth2ClientCredentials`, issued through the transport it is handed.
+Api.Oauth2ClientCredentialsApi = {}
+Api.Oauth2ClientCredentialsApi.__index = Api.Oauth2ClientCredentialsApi
+
+--- @param transport table what one request is issued through
+--- @return Oauth2ClientCredentialsApi
+function Api.Oauth2ClientCredentialsApi.new(transport)
+  return setmetatable({ transport = transport }, Api.Oauth2ClientCredentialsApi)
+end
+
+--- Remove the OAuth2 client credentials of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @return nil
+function Api.Oauth2ClientCredentialsApi:delete(subscription_id, application_id)
+  return check_answer(
+    self.transport:request(
+      "DELETE",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Get the OAuth2 client credentials of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @return Oauth2ClientCredentials
+function Api.Oauth2ClientCredentialsApi:get(subscription_id, application_id)
+  return read_answer(
+    Models.Oauth2ClientCredentials.from_json,
+    self.transport:request(
+      "GET",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Set the OAuth2 client credentials of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @param body Oauth2ClientCredentialsPut what the operation reads
+--- @return Oauth2ClientCredentials
+function Api.Oauth2ClientCredentialsApi:update(subscription_id, application_id, body)
+  return read_answer(
+    Models.Oauth2ClientCredentials.from_json,
+    self.transport:request(
+      "PUT",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      Runtime.written(body)
+    )
+  )
+end
+
 --- What the API declares under `payload_content_types`, issued through the transport it is handed.
 Api.PayloadContentTypesApi = {}
 Api.PayloadContentTypesApi.__index = Api.PayloadContentTypesApi
@@ -573,6 +1090,24 @@ function Api.RequestAttemptsApi:read(
   )
 end
 
+--- Retry a request attempt
+--- @param request_attempt_id string carries `request_attempt_id`.
+--- @param body RetryRequestAttempt what the operation reads
+--- @return RequestAttempt
+function Api.RequestAttemptsApi:retry(request_attempt_id, body)
+  return read_answer(
+    Models.RequestAttempt.from_json,
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/request_attempts/{request_attempt_id}/retry", {
+        ["request_attempt_id"] = request_attempt_id,
+      }),
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
 --- What the API declares under `response`, issued through the transport it is handed.
 Api.ResponseApi = {}
 Api.ResponseApi.__index = Api.ResponseApi
@@ -603,6 +1138,95 @@ function Api.ResponseApi:get(response_id, application_id)
   )
 end
 
+--- What the API declares under `secretRotations`, issued through the transport it is handed.
+Api.SecretRotationsApi = {}
+Api.SecretRotationsApi.__index = Api.SecretRotationsApi
+
+--- @param transport table what one request is issued through
+--- @return SecretRotationsApi
+function Api.SecretRotationsApi.new(transport)
+  return setmetatable({ transport = transport }, Api.SecretRotationsApi)
+end
+
+--- Rotate the secret of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @param body SecretRotationPost what the operation reads
+--- @return SecretRotation
+function Api.SecretRotationsApi:create(subscription_id, application_id, body)
+  return read_answer(
+    Models.SecretRotation.from_json,
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/secret_rotation", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      Runtime.written(body)
+    )
+  )
+end
+
+--- Finalize the secret rotation of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @return nil
+function Api.SecretRotationsApi:finalize(subscription_id, application_id)
+  return check_answer(
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Get the secret rotation of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @return SecretRotation
+function Api.SecretRotationsApi:get(subscription_id, application_id)
+  return read_answer(
+    Models.SecretRotation.from_json,
+    self.transport:request(
+      "GET",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/secret_rotation", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Roll back the secret rotation of a subscription
+--- @param subscription_id string carries `subscription_id`.
+--- @param application_id string carries `application_id`.
+--- @return nil
+function Api.SecretRotationsApi:rollback(subscription_id, application_id)
+  return check_answer(
+    self.transport:request(
+      "POST",
+      Runtime.path("/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback", {
+        ["subscription_id"] = subscription_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
 --- What the API declares under `serviceToken`, issued through the transport it is handed.
 Api.ServiceTokenApi = {}
 Api.ServiceTokenApi.__index = Api.ServiceTokenApi
@@ -702,6 +1326,84 @@ function Api.ServiceTokenApi:list(organization_id)
   )
 end
 
+--- What the API declares under `signingKeys`, issued through the transport it is handed.
+Api.SigningKeysApi = {}
+Api.SigningKeysApi.__index = Api.SigningKeysApi
+
+--- @param transport table what one request is issued through
+--- @return SigningKeysApi
+function Api.SigningKeysApi.new(transport)
+  return setmetatable({ transport = transport }, Api.SigningKeysApi)
+end
+
+--- Create a signing key
+--- @param body SigningKeyPost what the operation reads
+--- @return SigningKey
+function Api.SigningKeysApi:create(body)
+  return read_answer(
+    Models.SigningKey.from_json,
+    self.transport:request(
+      "POST",
+      "/api/v1/signing_keys/",
+      nil,
+      Runtime.written(body)
+    )
+  )
+end
+
+--- Retire a signing key
+--- @param signing_key_id string carries `signing_key_id`.
+--- @param application_id string carries `application_id`.
+--- @return nil
+function Api.SigningKeysApi:delete(signing_key_id, application_id)
+  return check_answer(
+    self.transport:request(
+      "DELETE",
+      Runtime.path("/api/v1/signing_keys/{signing_key_id}", {
+        ["signing_key_id"] = signing_key_id,
+      }),
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
+--- Get the public keys of an application
+--- @param application_id string carries `application_id`.
+--- @return JsonWebKeySet
+function Api.SigningKeysApi:jwks(application_id)
+  return read_answer(
+    Models.JsonWebKeySet.from_json,
+    self.transport:request(
+      "GET",
+      Runtime.path("/api/v1/jwks/{application_id}", {
+        ["application_id"] = application_id,
+      }),
+      nil,
+      nil
+    )
+  )
+end
+
+--- List signing keys
+--- @param application_id string carries `application_id`.
+--- @return SigningKey[]
+function Api.SigningKeysApi:list(application_id)
+  return read_answer(
+    Runtime.list(Models.SigningKey.from_json),
+    self.transport:request(
+      "GET",
+      "/api/v1/signing_keys/",
+      Runtime.query({
+        { "application_id", application_id },
+      }),
+      nil
+    )
+  )
+end
+
 --- What the API declares under `subscriptions`, issued through the transport it is handed.
 Api.SubscriptionsApi = {}
 Api.SubscriptionsApi.__index = Api.SubscriptionsApi
@@ -56,9 +56,21 @@ Generated.AuthInvalidBiscuitError = Errors.kind("AuthInvalidBiscuitError", Gener
 --- The API reported `AuthNoAuthorizationHeader`.
 Generated.AuthNoAuthorizationHeaderError = Errors.kind("AuthNoAuthorizationHeaderError", Generated.ProblemError)
 
+--- The API reported `BulkRetryAlreadyRunning`.
+Generated.BulkRetryAlreadyRunningError = Errors.kind("BulkRetryAlreadyRunningError", Generated.ProblemError)
+
+--- The API reported `BulkRetryFinished`.
+Generated.BulkRetryFinishedError = Errors.kind("BulkRetryFinishedError", Generated.ProblemError)
+
+--- The API reported `ClientCertificatesDisabled`.
+Generated.ClientCertificatesDisabledError = Errors.kind("ClientCertificatesDisabledError", Generated.ProblemError)
+
 --- The API reported `EventAlreadyIngested`.
 Generated.EventAlreadyIngestedError = Errors.kind("EventAlreadyIngestedError", Generated.ProblemError)
 
+--- The API reported `EventDeliveryBeyondRetention`.
+Generated.EventDeliveryBeyondRetentionError = Errors.kind("EventDeliveryBeyondRetentionError", Generated.ProblemError)
+
 --- The API reported `EventInvalidBase64Payload`.
 Generated.EventInvalidBase64PayloadError = Errors.kind("EventInvalidBase64PayloadError", Generated.ProblemError)
 
@@ -71,18 +83,36 @@ Generated.EventInvalidPayloadContentTypeError = Errors.kind(
   Generated.ProblemError
 )
 
+--- The API reported `EventNotScheduled`.
+Generated.EventNotScheduledError = Errors.kind("EventNotScheduledError", Generated.ProblemError)
+
+--- The API reported `EventPayloadSchemaViolation`.
+Generated.EventPayloadSchemaViolationError = Errors.kind("EventPayloadSchemaViolationError", Generated.ProblemError)
+
 --- The API reported `EventTypeAlreadyExist`.
 Generated.EventTypeAlreadyExistError = Errors.kind("EventTypeAlreadyExistError", Generated.ProblemError)
 
 --- The API reported `EventTypeDoesNotExist`.
 Generated.EventTypeDoesNotExistError = Errors.kind("EventTypeDoesNotExistError", Generated.ProblemError)
 
+--- The API reported `EventTypeVersionAlreadyExist`.
+Generated.EventTypeVersionAlreadyExistError = Errors.kind("EventTypeVersionAlreadyExistError", Generated.ProblemError)
+
+--- The API reported `EventTypeVersionDoesNotExist`.
+Generated.EventTypeVersionDoesNotExistError = Errors.kind("EventTypeVersionDoesNotExistError", Generated.ProblemError)
+
+--- The API reported `EventTypeVersionSunset`.
+Generated.EventTypeVersionSunsetError = Errors.kind("EventTypeVersionSunsetError", Generated.ProblemError)
+
 --- The API reported `Forbidden`.
 Generated.ForbiddenError = Errors.kind("ForbiddenError", Generated.ProblemError)
 
 --- The API reported `InternalServerError`.
 Generated.InternalServerErrorError = Errors.kind("InternalServerErrorError", Generated.ProblemError)
 
+--- The API reported `InvalidClientCertificate`.
+Generated.InvalidClientCertificateError = Errors.kind("InvalidClientCertificateError", Generated.ProblemError)
+
 --- The API reported `InvalidDateRange`.
 Generated.InvalidDateRangeError = Errors.kind("InvalidDateRangeError", Generated.ProblemError)
 
@@ -137,9 +167,30 @@ Generated.RateLimitedError = Errors.kind("RateLimitedError", Generated.ProblemEr
 --- The API reported `RegistrationDisabled`.
 Generated.RegistrationDisabledError = Errors.kind("RegistrationDisabledError", Generated.ProblemError)
 
+--- The API reported `RequestAttemptNotFailed`.
+Generated.RequestAttemptNotFailedError = Errors.kind("RequestAttemptNotFailedError", Generated.ProblemError)
+
+--- The API reported `RequestAttemptRetryPending`.
+Generated.RequestAttemptRetryPendingError = Errors.kind("RequestAttemptRetryPendingError", Generated.ProblemError)
+
+--- The API reported `RetryPolicyOverLimit`.
+Generated.RetryPolicyOverLimitError = Errors.kind("RetryPolicyOverLimitError", Generated.ProblemError)
+
+--- The API reported `SecretRotationInProgress`.
+Generated.SecretRotationInProgressError = Errors.kind("SecretRotationInProgressError", Generated.ProblemError)
+
 --- The API reported `ServiceUnavailable`.
 Generated.ServiceUnavailableError = Errors.kind("ServiceUnavailableError", Generated.ProblemError)
 
+--- The API reported `SigningKeysDisabled`.
+Generated.SigningKeysDisabledError = Errors.kind("SigningKeysDisabledError", Generated.ProblemError)
+
+--- The API reported `SubscriptionDisabled`.
+Generated.SubscriptionDisabledError = Errors.kind("SubscriptionDisabledError", Generated.ProblemError)
+
+--- The API reported `SubscriptionNotInbox`.
+Generated.SubscriptionNotInboxError = Errors.kind("SubscriptionNotInboxError", Generated.ProblemError)
+
 --- The API reported `TooManyApplicationsPerOrganization`.
 Generated.TooManyApplicationsPerOrganizationError = Errors.kind(
   "TooManyApplicationsPerOrganizationError",
@@ -187,14 +238,24 @@ Generated.PROBLEMS = {
   [Models.ProblemId.AUTH_INVALID_AUTHORIZATION_HEADER] = Generated.AuthInvalidAuthorizationHeaderError,
   [Models.ProblemId.AUTH_INVALID_BISCUIT] = Generated.AuthInvalidBiscuitError,
   [Models.ProblemId.AUTH_NO_AUTHORIZATION_HEADER] = Generated.AuthNoAuthorizationHeaderError,
+  [Models.ProblemId.BULK_RETRY_ALREADY_RUNNING] = Generated.BulkRetryAlreadyRunningError,
+  [Models.ProblemId.BULK_RETRY_FINISHED] = Generated.BulkRetryFinishedError,
+  [Models.ProblemId.CLIENT_CERTIFICATES_DISABLED] = Generated.ClientCertificatesDisabledError,
   [Models.ProblemId.EVENT_ALREADY_INGESTED] = Generated.EventAlreadyIngestedError,
+  [Models.ProblemId.EVENT_DELIVERY_BEYOND_RETENTION] = Generated.EventDeliveryBeyondRetentionError,
   [Models.ProblemId.EVENT_INVALID_BASE64_PAYLOAD] = Generated.EventInvalidBase64PayloadError,
   [Models.ProblemId.EVENT_INVALID_JSON_PAYLOAD] = Generated.EventInvalidJsonPayloadError,
   [Models.ProblemId.EVENT_INVALID_PAYLOAD_CONTENT_TYPE] = Generated.EventInvalidPayloadContentTypeError,
+  [Models.ProblemId.EVENT_NOT_SCHEDULED] = Generated.EventNotScheduledError,
+  [Models.ProblemId.EVENT_PAYLOAD_SCHEMA_VIOLATION] = Generated.EventPayloadSchemaViolationError,
   [Models.ProblemId.EVENT_TYPE_ALREADY_EXIST] = Generated.EventTypeAlreadyExistError,
   [Models.ProblemId.EVENT_TYPE_DOES_NOT_EXIST] = Generated.EventTypeDoesNotExistError,
+  [Models.ProblemId.EVENT_TYPE_VERSION_ALREADY_EXIST] = Generated.EventTypeVersionAlreadyExistError,
+  [Models.ProblemId.EVENT_TYPE_VERSION_DOES_NOT_EXIST] = Generated.EventTypeVersionDoesNotExistError,
+  [Models.ProblemId.EVENT_TYPE_VERSION_SUNSET] = Generated.EventTypeVersionSunsetError,
   [Models.ProblemId.FORBIDDEN] = Generated.ForbiddenError,
   [Models.ProblemId.INTERNAL_SERVER_ERROR] = Generated.InternalServerErrorError,
+  [Models.ProblemId.INVALID_CLIENT_CERTIFICATE] = Generated.InvalidClientCertificateError,
   [Models.ProblemId.INVALID_DATE_RANGE] = Generated.InvalidDateRangeError,
   [Models.ProblemId.INVALID_ROLE] = Generated.InvalidRoleError,
   [Models.ProblemId.INVITED_USER_ALREADY_IN_ORGANIZATION] = Generated.InvitedUserAlreadyInOrganizationError,
@@ -212,7 +273,14 @@ Generated.PROBLEMS = {
   [Models.ProblemId.PASSWORD_TOO_SHORT] = Generated.PasswordTooShortError,
   [Models.ProblemId.RATE_LIMITED] = Generated.RateLimitedError,
   [Models.ProblemId.REGISTRATION_DISABLED] = Generated.RegistrationDisabledError,
+  [Models.ProblemId.REQUEST_ATTEMPT_NOT_FAILED] = Generated.RequestAttemptNotFailedError,
+  [Models.ProblemId.REQUEST_ATTEMPT_RETRY_PENDING] = Generated.RequestAttemptRetryPendingError,
+  [Models.ProblemId.RETRY_POLICY_OVER_LIMIT] = Generated.RetryPolicyOverLimitError,
+  [Models.ProblemId.SECRET_ROTATION_IN_PROGRESS] = Generated.SecretRotationInProgressError,
   [Models.ProblemId.SERVICE_UNAVAILABLE] = Generated.ServiceUnavailableError,
+  [Models.ProblemId.SIGNING_KEYS_DISABLED] = Generated.SigningKeysDisabledError,
+  [Models.ProblemId.SUBSCRIPTION_DISABLED] = Generated.SubscriptionDisabledError,
+  [Models.ProblemId.SUBSCRIPTION_NOT_INBOX] = Generated.SubscriptionNotInboxError,
   [Models.ProblemId.TOO_MANY_APPLICATIONS_PER_ORGANIZATION] = Generated.TooManyApplicationsPerOrganizationError,
   [Models.ProblemId.TOO_MANY_EVENT_TYPES_PER_APPLICATION] = Generated.TooManyEventTypesPerApplicationError,
   [Models.ProblemId.TOO_MANY_EVENTS_TODAY] = Generated.TooManyEventsTodayError,
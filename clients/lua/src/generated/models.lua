@@ -63,6 +63,313 @@ function Models.ApplicationInfoOnboardingStepsSubscription.member(value)
   return Runtime.declares(Models.ApplicationInfoOnboardingStepsSubscription.VALUES, value)
 end
 
+--- One of the values the API answers with.
+Models.BulkRetryPostStatus = {
+  FAILED = "failed",
+  SUCCESSFUL = "successful",
+}
+
+--- Every value the API declares for this list.
+Models.BulkRetryPostStatus.VALUES = {
+  Models.BulkRetryPostStatus.FAILED,
+  Models.BulkRetryPostStatus.SUCCESSFUL,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.BulkRetryPostStatus.member(value)
+  return Runtime.declares(Models.BulkRetryPostStatus.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.BulkRetryStatus = {
+  FAILED = "failed",
+  SUCCESSFUL = "successful",
+}
+
+--- Every value the API declares for this list.
+Models.BulkRetryStatus.VALUES = {
+  Models.BulkRetryStatus.FAILED,
+  Models.BulkRetryStatus.SUCCESSFUL,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.BulkRetryStatus.member(value)
+  return Runtime.declares(Models.BulkRetryStatus.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.DeadLetterDetailsDeadLetterReason = {
+  RETRIES_EXHAUSTED = "retries_exhausted",
+  SIGNATURE_FAILED = "signature_failed",
+  TRANSFORM_FAILED = "transform_failed",
+  PERMANENT_FAILURE = "permanent_failure",
+}
+
+--- Every value the API declares for this list.
+Models.DeadLetterDetailsDeadLetterReason.VALUES = {
+  Models.DeadLetterDetailsDeadLetterReason.RETRIES_EXHAUSTED,
+  Models.DeadLetterDetailsDeadLetterReason.SIGNATURE_FAILED,
+  Models.DeadLetterDetailsDeadLetterReason.TRANSFORM_FAILED,
+  Models.DeadLetterDetailsDeadLetterReason.PERMANENT_FAILURE,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.DeadLetterDetailsDeadLetterReason.member(value)
+  return Runtime.declares(Models.DeadLetterDetailsDeadLetterReason.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.DeadLetterReason = {
+  RETRIES_EXHAUSTED = "retries_exhausted",
+  SIGNATURE_FAILED = "signature_failed",
+  TRANSFORM_FAILED = "transform_failed",
+  PERMANENT_FAILURE = "permanent_failure",
+}
+
+--- Every value the API declares for this list.
+Models.DeadLetterReason.VALUES = {
+  Models.DeadLetterReason.RETRIES_EXHAUSTED,
+  Models.DeadLetterReason.SIGNATURE_FAILED,
+  Models.DeadLetterReason.TRANSFORM_FAILED,
+  Models.DeadLetterReason.PERMANENT_FAILURE,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.DeadLetterReason.member(value)
+  return Runtime.declares(Models.DeadLetterReason.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.EventTypePayloadSchemaMode = {
+  ENFORCE = "enforce",
+  WARN = "warn",
+  OFF = "off",
+}
+
+--- Every value the API declares for this list.
+Models.EventTypePayloadSchemaMode.VALUES = {
+  Models.EventTypePayloadSchemaMode.ENFORCE,
+  Models.EventTypePayloadSchemaMode.WARN,
+  Models.EventTypePayloadSchemaMode.OFF,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.EventTypePayloadSchemaMode.member(value)
+  return Runtime.declares(Models.EventTypePayloadSchemaMode.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.EventTypePostPayloadSchemaMode = {
+  ENFORCE = "enforce",
+  WARN = "warn",
+  OFF = "off",
+}
+
+--- Every value the API declares for this list.
+Models.EventTypePostPayloadSchemaMode.VALUES = {
+  Models.EventTypePostPayloadSchemaMode.ENFORCE,
+  Models.EventTypePostPayloadSchemaMode.WARN,
+  Models.EventTypePostPayloadSchemaMode.OFF,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.EventTypePostPayloadSchemaMode.member(value)
+  return Runtime.declares(Models.EventTypePostPayloadSchemaMode.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.EventTypePutPayloadSchemaMode = {
+  ENFORCE = "enforce",
+  WARN = "warn",
+  OFF = "off",
+}
+
+--- Every value the API declares for this list.
+Models.EventTypePutPayloadSchemaMode.VALUES = {
+  Models.EventTypePutPayloadSchemaMode.ENFORCE,
+  Models.EventTypePutPayloadSchemaMode.WARN,
+  Models.EventTypePutPayloadSchemaMode.OFF,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.EventTypePutPayloadSchemaMode.member(value)
+  return Runtime.declares(Models.EventTypePutPayloadSchemaMode.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.IngestedEventsBatchEventsProblemId = {
+  ORGANIZATION_NAME_MISSING = "OrganizationNameMissing",
+  USER_ALREADY_EXIST = "UserAlreadyExist",
+  REGISTRATION_DISABLED = "RegistrationDisabled",
+  PASSWORD_TOO_SHORT = "PasswordTooShort",
+  PASSWORD_TOO_LONG = "PasswordTooLong",
+  PASSWORD_SIMILAR_TO_EMAIL = "PasswordSimilarToEmail",
+  PASSWORD_SIMILAR_TO_NAME = "PasswordSimilarToName",
+  PASSWORD_TOO_COMMON = "PasswordTooCommon",
+  PASSWORD_NOT_DIVERSE_ENOUGH = "PasswordNotDiverseEnough",
+  ORGANIZATION_IS_NOT_EMPTY = "OrganizationIsNotEmpty",
+  INVITED_USER_DOES_NOT_EXIST = "InvitedUserDoesNotExist",
+  INVITED_USER_ALREADY_IN_ORGANIZATION = "InvitedUserAlreadyInOrganization",
+  APPLICATION_NAME_MISSING = "ApplicationNameMissing",
+  INVALID_ROLE = "InvalidRole",
+  EVENT_TYPE_ALREADY_EXIST = "EventTypeAlreadyExist",
+  EVENT_TYPE_DOES_NOT_EXIST = "EventTypeDoesNotExist",
+  EVENT_TYPE_VERSION_ALREADY_EXIST = "EventTypeVersionAlreadyExist",
+  EVENT_TYPE_VERSION_DOES_NOT_EXIST = "EventTypeVersionDoesNotExist",
+  EVENT_TYPE_VERSION_SUNSET = "EventTypeVersionSunset",
+  UNAUTHORIZED_WORKERS = "UnauthorizedWorkers",
+  EVENT_ALREADY_INGESTED = "EventAlreadyIngested",
+  EVENT_INVALID_PAYLOAD_CONTENT_TYPE = "EventInvalidPayloadContentType",
+  EVENT_INVALID_BASE64_PAYLOAD = "EventInvalidBase64Payload",
+  EVENT_INVALID_JSON_PAYLOAD = "EventInvalidJsonPayload",
+  EVENT_PAYLOAD_SCHEMA_VIOLATION = "EventPayloadSchemaViolation",
+  EVENT_DELIVERY_BEYOND_RETENTION = "EventDeliveryBeyondRetention",
+  EVENT_NOT_SCHEDULED = "EventNotScheduled",
+  LABELS_AMBIGUITY = "LabelsAmbiguity",
+  INVALID_DATE_RANGE = "InvalidDateRange",
+  REQUEST_ATTEMPT_NOT_FAILED = "RequestAttemptNotFailed",
+  REQUEST_ATTEMPT_RETRY_PENDING = "RequestAttemptRetryPending",
+  SUBSCRIPTION_DISABLED = "SubscriptionDisabled",
+  SUBSCRIPTION_NOT_INBOX = "SubscriptionNotInbox",
+  SECRET_ROTATION_IN_PROGRESS = "SecretRotationInProgress",
+  BULK_RETRY_ALREADY_RUNNING = "BulkRetryAlreadyRunning",
+  BULK_RETRY_FINISHED = "BulkRetryFinished",
+  RETRY_POLICY_OVER_LIMIT = "RetryPolicyOverLimit",
+  CLIENT_CERTIFICATES_DISABLED = "ClientCertificatesDisabled",
+  INVALID_CLIENT_CERTIFICATE = "InvalidClientCertificate",
+  SIGNING_KEYS_DISABLED = "SigningKeysDisabled",
+  AUTH_NO_AUTHORIZATION_HEADER = "AuthNoAuthorizationHeader",
+  AUTH_INVALID_AUTHORIZATION_HEADER = "AuthInvalidAuthorizationHeader",
+  AUTH_APPLICATION_SECRET_LOOKUP_ERROR = "AuthApplicationSecretLookupError",
+  AUTH_INVALID_APPLICATION_SECRET = "AuthInvalidApplicationSecret",
+  AUTH_BISCUIT_LOOKUP_ERROR = "AuthBiscuitLookupError",
+  AUTH_INVALID_BISCUIT = "AuthInvalidBiscuit",
+  AUTH_FAILED_LOGIN = "AuthFailedLogin",
+  AUTH_EMAIL_NOT_VERIFIED = "AuthEmailNotVerified",
+  AUTH_EMAIL_ALREADY_VERIFIED = "AuthEmailAlreadyVerified",
+  AUTH_FAILED_REFRESH = "AuthFailedRefresh",
+  AUTH_EMAIL_EXPIRED = "AuthEmailExpired",
+  TOO_MANY_MEMBERS_PER_ORGANIZATION = "TooManyMembersPerOrganization",
+  TOO_MANY_APPLICATIONS_PER_ORGANIZATION = "TooManyApplicationsPerOrganization",
+  TOO_MANY_EVENTS_TODAY = "TooManyEventsToday",
+  TOO_MANY_SUBSCRIPTIONS_PER_APPLICATION = "TooManySubscriptionsPerApplication",
+  TOO_MANY_EVENT_TYPES_PER_APPLICATION = "TooManyEventTypesPerApplication",
+  JSON_PAYLOAD = "JsonPayload",
+  VALIDATION = "Validation",
+  NOT_FOUND = "NotFound",
+  INTERNAL_SERVER_ERROR = "InternalServerError",
+  FORBIDDEN = "Forbidden",
+  RATE_LIMITED = "RateLimited",
+  SERVICE_UNAVAILABLE = "ServiceUnavailable",
+}
+
+--- Every value the API declares for this list.
+Models.IngestedEventsBatchEventsProblemId.VALUES = {
+  Models.IngestedEventsBatchEventsProblemId.ORGANIZATION_NAME_MISSING,
+  Models.IngestedEventsBatchEventsProblemId.USER_ALREADY_EXIST,
+  Models.IngestedEventsBatchEventsProblemId.REGISTRATION_DISABLED,
+  Models.IngestedEventsBatchEventsProblemId.PASSWORD_TOO_SHORT,
+  Models.IngestedEventsBatchEventsProblemId.PASSWORD_TOO_LONG,
+  Models.IngestedEventsBatchEventsProblemId.PASSWORD_SIMILAR_TO_EMAIL,
+  Models.IngestedEventsBatchEventsProblemId.PASSWORD_SIMILAR_TO_NAME,
+  Models.IngestedEventsBatchEventsProblemId.PASSWORD_TOO_COMMON,
+  Models.IngestedEventsBatchEventsProblemId.PASSWORD_NOT_DIVERSE_ENOUGH,
+  Models.IngestedEventsBatchEventsProblemId.ORGANIZATION_IS_NOT_EMPTY,
+  Models.IngestedEventsBatchEventsProblemId.INVITED_USER_DOES_NOT_EXIST,
+  Models.IngestedEventsBatchEventsProblemId.INVITED_USER_ALREADY_IN_ORGANIZATION,
+  Models.IngestedEventsBatchEventsProblemId.APPLICATION_NAME_MISSING,
+  Models.IngestedEventsBatchEventsProblemId.INVALID_ROLE,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_TYPE_ALREADY_EXIST,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_TYPE_DOES_NOT_EXIST,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_TYPE_VERSION_ALREADY_EXIST,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_TYPE_VERSION_DOES_NOT_EXIST,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_TYPE_VERSION_SUNSET,
+  Models.IngestedEventsBatchEventsProblemId.UNAUTHORIZED_WORKERS,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_ALREADY_INGESTED,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_INVALID_PAYLOAD_CONTENT_TYPE,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_INVALID_BASE64_PAYLOAD,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_INVALID_JSON_PAYLOAD,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_PAYLOAD_SCHEMA_VIOLATION,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_DELIVERY_BEYOND_RETENTION,
+  Models.IngestedEventsBatchEventsProblemId.EVENT_NOT_SCHEDULED,
+  Models.IngestedEventsBatchEventsProblemId.LABELS_AMBIGUITY,
+  Models.IngestedEventsBatchEventsProblemId.INVALID_DATE_RANGE,
+  Models.IngestedEventsBatchEventsProblemId.REQUEST_ATTEMPT_NOT_FAILED,
+  Models.IngestedEventsBatchEventsProblemId.REQUEST_ATTEMPT_RETRY_PENDING,
+  Models.IngestedEventsBatchEventsProblemId.SUBSCRIPTION_DISABLED,
+  Models.IngestedEventsBatchEventsProblemId.SUBSCRIPTION_NOT_INBOX,
+  Models.IngestedEventsBatchEventsProblemId.SECRET_ROTATION_IN_PROGRESS,
+  Models.IngestedEventsBatchEventsProblemId.BULK_RETRY_ALREADY_RUNNING,
+  Models.IngestedEventsBatchEventsProblemId.BULK_RETRY_FINISHED,
+  Models.IngestedEventsBatchEventsProblemId.RETRY_POLICY_OVER_LIMIT,
+  Models.IngestedEventsBatchEventsProblemId.CLIENT_CERTIFICATES_DISABLED,
+  Models.IngestedEventsBatchEventsProblemId.INVALID_CLIENT_CERTIFICATE,
+  Models.IngestedEventsBatchEventsProblemId.SIGNING_KEYS_DISABLED,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_NO_AUTHORIZATION_HEADER,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_INVALID_AUTHORIZATION_HEADER,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_APPLICATION_SECRET_LOOKUP_ERROR,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_INVALID_APPLICATION_SECRET,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_BISCUIT_LOOKUP_ERROR,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_INVALID_BISCUIT,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_FAILED_LOGIN,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_EMAIL_NOT_VERIFIED,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_EMAIL_ALREADY_VERIFIED,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_FAILED_REFRESH,
+  Models.IngestedEventsBatchEventsProblemId.AUTH_EMAIL_EXPIRED,
+  Models.IngestedEventsBatchEventsProblemId.TOO_MANY_MEMBERS_PER_ORGANIZATION,
+  Models.IngestedEventsBatchEventsProblemId.TOO_MANY_APPLICATIONS_PER_ORGANIZATION,
+  Models.IngestedEventsBatchEventsProblemId.TOO_MANY_EVENTS_TODAY,
+  Models.IngestedEventsBatchEventsProblemId.TOO_MANY_SUBSCRIPTIONS_PER_APPLICATION,
+  Models.IngestedEventsBatchEventsProblemId.TOO_MANY_EVENT_TYPES_PER_APPLICATION,
+  Models.IngestedEventsBatchEventsProblemId.JSON_PAYLOAD,
+  Models.IngestedEventsBatchEventsProblemId.VALIDATION,
+  Models.IngestedEventsBatchEventsProblemId.NOT_FOUND,
+  Models.IngestedEventsBatchEventsProblemId.INTERNAL_SERVER_ERROR,
+  Models.IngestedEventsBatchEventsProblemId.FORBIDDEN,
+  Models.IngestedEventsBatchEventsProblemId.RATE_LIMITED,
+  Models.IngestedEventsBatchEventsProblemId.SERVICE_UNAVAILABLE,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.IngestedEventsBatchEventsProblemId.member(value)
+  return Runtime.declares(Models.IngestedEventsBatchEventsProblemId.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.IngestedEventsBatchEventsStatus = {
+  INGESTED = "ingested",
+  FAILED = "failed",
+}
+
+--- Every value the API declares for this list.
+Models.IngestedEventsBatchEventsStatus.VALUES = {
+  Models.IngestedEventsBatchEventsStatus.INGESTED,
+  Models.IngestedEventsBatchEventsStatus.FAILED,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.IngestedEventsBatchEventsStatus.member(value)
+  return Runtime.declares(Models.IngestedEventsBatchEventsStatus.VALUES, value)
+end
+
 --- One of the values the API answers with.
 Models.OrganizationInfoOnboardingStepsApplication = {
   TO_DO = "ToDo",
@@ -157,13 +464,30 @@ Models.ProblemId = {
   INVALID_ROLE = "InvalidRole",
   EVENT_TYPE_ALREADY_EXIST = "EventTypeAlreadyExist",
   EVENT_TYPE_DOES_NOT_EXIST = "EventTypeDoesNotExist",
+  EVENT_TYPE_VERSION_ALREADY_EXIST = "EventTypeVersionAlreadyExist",
+  EVENT_TYPE_VERSION_DOES_NOT_EXIST = "EventTypeVersionDoesNotExist",
+  EVENT_TYPE_VERSION_SUNSET = "EventTypeVersionSunset",
   UNAUTHORIZED_WORKERS = "UnauthorizedWorkers",
   EVENT_ALREADY_INGESTED = "EventAlreadyIngested",
   EVENT_INVALID_PAYLOAD_CONTENT_TYPE = "EventInvalidPayloadContentType",
   EVENT_INVALID_BASE64_PAYLOAD = "EventInvalidBase64Payload",
   EVENT_INVALID_JSON_PAYLOAD = "EventInvalidJsonPayload",
+  EVENT_PAYLOAD_SCHEMA_VIOLATION = "EventPayloadSchemaViolation",
+  EVENT_DELIVERY_BEYOND_RETENTION = "EventDeliveryBeyondRetention",
+  EVENT_NOT_SCHEDULED = "EventNotScheduled",
   LABELS_AMBIGUITY = "LabelsAmbiguity",
   INVALID_DATE_RANGE = "InvalidDateRange",
+  REQUEST_ATTEMPT_NOT_FAILED = "RequestAttemptNotFailed",
+  REQUEST_ATTEMPT_RETRY_PENDING = "RequestAttemptRetryPending",
+  SUBSCRIPTION_DISABLED = "SubscriptionDisabled",
+  SUBSCRIPTION_NOT_INBOX = "SubscriptionNotInbox",
+  SECRET_ROTATION_IN_PROGRESS = "SecretRotationInProgress",
+  BULK_RETRY_ALREADY_RUNNING = "BulkRetryAlreadyRunning",
+  BULK_RETRY_FINISHED = "BulkRetryFinished",
+  RETRY_POLICY_OVER_LIMIT = "RetryPolicyOverLimit",
+  CLIENT_CERTIFICATES_DISABLED = "ClientCertificatesDisabled",
+  INVALID_CLIENT_CERTIFICATE = "InvalidClientCertificate",
+  SIGNING_KEYS_DISABLED = "SigningKeysDisabled",
   AUTH_NO_AUTHORIZATION_HEADER = "AuthNoAuthorizationHeader",
   AUTH_INVALID_AUTHORIZATION_HEADER = "AuthInvalidAuthorizationHeader",
   AUTH_APPLICATION_SECRET_LOOKUP_ERROR = "AuthApplicationSecretLookupError",
@@ -207,13 +531,30 @@ Models.ProblemId.VALUES = {
   Models.ProblemId.INVALID_ROLE,
   Models.ProblemId.EVENT_TYPE_ALREADY_EXIST,
   Models.ProblemId.EVENT_TYPE_DOES_NOT_EXIST,
+  Models.ProblemId.EVENT_TYPE_VERSION_ALREADY_EXIST,
+  Models.ProblemId.EVENT_TYPE_VERSION_DOES_NOT_EXIST,
+  Models.ProblemId.EVENT_TYPE_VERSION_SUNSET,
   Models.ProblemId.UNAUTHORIZED_WORKERS,
   Models.ProblemId.EVENT_ALREADY_INGESTED,
   Models.ProblemId.EVENT_INVALID_PAYLOAD_CONTENT_TYPE,
   Models.ProblemId.EVENT_INVALID_BASE64_PAYLOAD,
   Models.ProblemId.EVENT_INVALID_JSON_PAYLOAD,
+  Models.ProblemId.EVENT_PAYLOAD_SCHEMA_VIOLATION,
+  Models.ProblemId.EVENT_DELIVERY_BEYOND_RETENTION,
+  Models.ProblemId.EVENT_NOT_SCHEDULED,
   Models.ProblemId.LABELS_AMBIGUITY,
   Models.ProblemId.INVALID_DATE_RANGE,
+  Models.ProblemId.REQUEST_ATTEMPT_NOT_FAILED,
+  Models.ProblemId.REQUEST_ATTEMPT_RETRY_PENDING,
+  Models.ProblemId.SUBSCRIPTION_DISABLED,
+  Models.ProblemId.SUBSCRIPTION_NOT_INBOX,
+  Models.ProblemId.SECRET_ROTATION_IN_PROGRESS,
+  Models.ProblemId.BULK_RETRY_ALREADY_RUNNING,
+  Models.ProblemId.BULK_RETRY_FINISHED,
+  Models.ProblemId.RETRY_POLICY_OVER_LIMIT,
+  Models.ProblemId.CLIENT_CERTIFICATES_DISABLED,
+  Models.ProblemId.INVALID_CLIENT_CERTIFICATE,
+  Models.ProblemId.SIGNING_KEYS_DISABLED,
   Models.ProblemId.AUTH_NO_AUTHORIZATION_HEADER,
   Models.ProblemId.AUTH_INVALID_AUTHORIZATION_HEADER,
   Models.ProblemId.AUTH_APPLICATION_SECRET_LOOKUP_ERROR,
@@ -246,6 +587,29 @@ function Models.ProblemId.member(value)
   return Runtime.declares(Models.ProblemId.VALUES, value)
 end
 
+--- One of the values the API answers with.
+Models.RequestAttemptStatusRuleOutcome = {
+  SUCCESS = "success",
+  RETRYABLE = "retryable",
+  PERMANENT_FAILURE = "permanent_failure",
+  DISABLE_SUBSCRIPTION = "disable_subscription",
+}
+
+--- Every value the API declares for this list.
+Models.RequestAttemptStatusRuleOutcome.VALUES = {
+  Models.RequestAttemptStatusRuleOutcome.SUCCESS,
+  Models.RequestAttemptStatusRuleOutcome.RETRYABLE,
+  Models.RequestAttemptStatusRuleOutcome.PERMANENT_FAILURE,
+  Models.RequestAttemptStatusRuleOutcome.DISABLE_SUBSCRIPTION,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.RequestAttemptStatusRuleOutcome.member(value)
+  return Runtime.declares(Models.RequestAttemptStatusRuleOutcome.VALUES, value)
+end
+
 --- One of the values the API answers with.
 Models.RequestAttemptStatusType = {
   WAITING = "waiting",
@@ -253,6 +617,8 @@ Models.RequestAttemptStatusType = {
   IN_PROGRESS = "in_progress",
   SUCCESSFUL = "successful",
   FAILED = "failed",
+  EXPIRED = "expired",
+  CANCELLED = "cancelled",
 }
 
 --- Every value the API declares for this list.
@@ -262,6 +628,8 @@ Models.RequestAttemptStatusType.VALUES = {
   Models.RequestAttemptStatusType.IN_PROGRESS,
   Models.RequestAttemptStatusType.SUCCESSFUL,
   Models.RequestAttemptStatusType.FAILED,
+  Models.RequestAttemptStatusType.EXPIRED,
+  Models.RequestAttemptStatusType.CANCELLED,
 }
 
 --- Whether the API declares that value.
@@ -271,6 +639,136 @@ function Models.RequestAttemptStatusType.member(value)
   return Runtime.declares(Models.RequestAttemptStatusType.VALUES, value)
 end
 
+--- One of the values the API answers with.
+Models.SubscriptionPostResponseRulesOutcome = {
+  SUCCESS = "success",
+  RETRYABLE = "retryable",
+  PERMANENT_FAILURE = "permanent_failure",
+  DISABLE_SUBSCRIPTION = "disable_subscription",
+}
+
+--- Every value the API declares for this list.
+Models.SubscriptionPostResponseRulesOutcome.VALUES = {
+  Models.SubscriptionPostResponseRulesOutcome.SUCCESS,
+  Models.SubscriptionPostResponseRulesOutcome.RETRYABLE,
+  Models.SubscriptionPostResponseRulesOutcome.PERMANENT_FAILURE,
+  Models.SubscriptionPostResponseRulesOutcome.DISABLE_SUBSCRIPTION,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.SubscriptionPostResponseRulesOutcome.member(value)
+  return Runtime.declares(Models.SubscriptionPostResponseRulesOutcome.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.SubscriptionPostSignatureScheme = {
+  HOOK0 = "hook0",
+  STANDARD_WEBHOOKS = "standard_webhooks",
+}
+
+--- Every value the API declares for this list.
+Models.SubscriptionPostSignatureScheme.VALUES = {
+  Models.SubscriptionPostSignatureScheme.HOOK0,
+  Models.SubscriptionPostSignatureScheme.STANDARD_WEBHOOKS,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.SubscriptionPostSignatureScheme.member(value)
+  return Runtime.declares(Models.SubscriptionPostSignatureScheme.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.SubscriptionPostTargetType = {
+  HTTP = "http",
+  AMQP = "amqp",
+  SQS = "sqs",
+  INBOX = "inbox",
+}
+
+--- Every value the API declares for this list.
+Models.SubscriptionPostTargetType.VALUES = {
+  Models.SubscriptionPostTargetType.HTTP,
+  Models.SubscriptionPostTargetType.AMQP,
+  Models.SubscriptionPostTargetType.SQS,
+  Models.SubscriptionPostTargetType.INBOX,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.SubscriptionPostTargetType.member(value)
+  return Runtime.declares(Models.SubscriptionPostTargetType.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.SubscriptionResponseRulesOutcome = {
+  SUCCESS = "success",
+  RETRYABLE = "retryable",
+  PERMANENT_FAILURE = "permanent_failure",
+  DISABLE_SUBSCRIPTION = "disable_subscription",
+}
+
+--- Every value the API declares for this list.
+Models.SubscriptionResponseRulesOutcome.VALUES = {
+  Models.SubscriptionResponseRulesOutcome.SUCCESS,
+  Models.SubscriptionResponseRulesOutcome.RETRYABLE,
+  Models.SubscriptionResponseRulesOutcome.PERMANENT_FAILURE,
+  Models.SubscriptionResponseRulesOutcome.DISABLE_SUBSCRIPTION,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.SubscriptionResponseRulesOutcome.member(value)
+  return Runtime.declares(Models.SubscriptionResponseRulesOutcome.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.SubscriptionSignatureScheme = {
+  HOOK0 = "hook0",
+  STANDARD_WEBHOOKS = "standard_webhooks",
+}
+
+--- Every value the API declares for this list.
+Models.SubscriptionSignatureScheme.VALUES = {
+  Models.SubscriptionSignatureScheme.HOOK0,
+  Models.SubscriptionSignatureScheme.STANDARD_WEBHOOKS,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.SubscriptionSignatureScheme.member(value)
+  return Runtime.declares(Models.SubscriptionSignatureScheme.VALUES, value)
+end
+
+--- One of the values the API answers with.
+Models.SubscriptionTargetType = {
+  HTTP = "http",
+  AMQP = "amqp",
+  SQS = "sqs",
+  INBOX = "inbox",
+}
+
+--- Every value the API declares for this list.
+Models.SubscriptionTargetType.VALUES = {
+  Models.SubscriptionTargetType.HTTP,
+  Models.SubscriptionTargetType.AMQP,
+  Models.SubscriptionTargetType.SQS,
+  Models.SubscriptionTargetType.INBOX,
+}
+
+--- Whether the API declares that value.
+--- @param value string
+--- @return boolean
+function Models.SubscriptionTargetType.member(value)
+  return Runtime.declares(Models.SubscriptionTargetType.VALUES, value)
+end
+
 --- The `Application` the API declares.
 Models.Application = {}
 Models.Application.__index = Models.Application
@@ -598,369 +1096,1949 @@ function Models.ApplicationSecretPost:to_table()
   })
 end
 
---- The `Event` the API declares.
-Models.Event = {}
-Models.Event.__index = Models.Event
-Models.Event.__eq = Runtime.equality
+--- The `BulkRetry` the API declares.
+Models.BulkRetry = {}
+Models.BulkRetry.__index = Models.BulkRetry
+Models.BulkRetry.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
---- @param fields.event_id string carries `event_id`.
---- @param fields.event_type_name string carries `event_type_name`.
---- @param fields.ip string carries `ip`.
---- @param fields.labels any carries `labels`.
---- @param fields.occurred_at string carries `occurred_at`.
---- @param fields.payload_content_type string carries `payload_content_type`.
---- @param fields.received_at string carries `received_at`.
---- @param fields.metadata any|nil carries `metadata`.
---- @return Event
-function Models.Event.new(fields)
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.bulk_retry_id string carries `bulk_retry_id`.
+--- @param fields.created_at string carries `created_at`.
+--- @param fields.event_type_names string[] carries `event_type_names`.
+--- @param fields.max_created_at string carries `max_created_at`.
+--- @param fields.min_created_at string carries `min_created_at`.
+--- @param fields.processed_count integer carries `processed_count`: Number of pairs handled so far
+--- @param fields.retried_count integer carries `retried_count`: Number of request attempts created so far
+--- @param fields.status string carries `status`, one of `Models.BulkRetryStatus.VALUES`.
+--- @param fields.total_count integer carries `total_count`: Number of (event, subscription) pairs that matched when the
+---   bulk retry was created
+--- @param fields.updated_at string carries `updated_at`.
+--- @param fields.cancelled_at string|nil carries `cancelled_at`.
+--- @param fields.completed_at string|nil carries `completed_at`.
+--- @param fields.event_id string|nil carries `event_id`.
+--- @param fields.subscription_id string|nil carries `subscription_id`.
+--- @return BulkRetry
+function Models.BulkRetry.new(fields)
   return setmetatable({
+    application_id = fields.application_id,
+    bulk_retry_id = fields.bulk_retry_id,
+    created_at = fields.created_at,
+    event_type_names = fields.event_type_names,
+    max_created_at = fields.max_created_at,
+    min_created_at = fields.min_created_at,
+    processed_count = fields.processed_count,
+    retried_count = fields.retried_count,
+    status = fields.status,
+    total_count = fields.total_count,
+    updated_at = fields.updated_at,
+    cancelled_at = fields.cancelled_at,
+    completed_at = fields.completed_at,
     event_id = fields.event_id,
-    event_type_name = fields.event_type_name,
-    ip = fields.ip,
-    labels = fields.labels,
-    occurred_at = fields.occurred_at,
-    payload_content_type = fields.payload_content_type,
-    received_at = fields.received_at,
-    metadata = fields.metadata,
-  }, Models.Event)
+    subscription_id = fields.subscription_id,
+  }, Models.BulkRetry)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return BulkRetry
+function Models.BulkRetry.from_json(value)
+  local fields = Runtime.as_fields(value, "BulkRetry")
+  return Models.BulkRetry.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    bulk_retry_id = Runtime.read(fields, "bulk_retry_id", Runtime.TEXT),
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    event_type_names = Runtime.read(fields, "event_type_names", Runtime.list(Runtime.TEXT)),
+    max_created_at = Runtime.read(fields, "max_created_at", Runtime.TEXT),
+    min_created_at = Runtime.read(fields, "min_created_at", Runtime.TEXT),
+    processed_count = Runtime.read(fields, "processed_count", Runtime.INTEGER),
+    retried_count = Runtime.read(fields, "retried_count", Runtime.INTEGER),
+    status = Runtime.read(fields, "status", Runtime.member_of(Models.BulkRetryStatus)),
+    total_count = Runtime.read(fields, "total_count", Runtime.INTEGER),
+    updated_at = Runtime.read(fields, "updated_at", Runtime.TEXT),
+    cancelled_at = Runtime.maybe(fields, "cancelled_at", Runtime.TEXT),
+    completed_at = Runtime.maybe(fields, "completed_at", Runtime.TEXT),
+    event_id = Runtime.maybe(fields, "event_id", Runtime.TEXT),
+    subscription_id = Runtime.maybe(fields, "subscription_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.BulkRetry:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["bulk_retry_id"] = self.bulk_retry_id,
+    ["created_at"] = self.created_at,
+    ["event_type_names"] = Runtime.written_list(self.event_type_names, Runtime.itself),
+    ["max_created_at"] = self.max_created_at,
+    ["min_created_at"] = self.min_created_at,
+    ["processed_count"] = self.processed_count,
+    ["retried_count"] = self.retried_count,
+    ["status"] = self.status,
+    ["total_count"] = self.total_count,
+    ["updated_at"] = self.updated_at,
+    ["cancelled_at"] = self.cancelled_at,
+    ["completed_at"] = self.completed_at,
+    ["event_id"] = self.event_id,
+    ["subscription_id"] = self.subscription_id,
+  })
+end
+
+--- The `BulkRetryDryRun` the API declares.
+Models.BulkRetryDryRun = {}
+Models.BulkRetryDryRun.__index = Models.BulkRetryDryRun
+Models.BulkRetryDryRun.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.count integer carries `count`: Number of (event, subscription) pairs a bulk retry would redeliver now
+--- @return BulkRetryDryRun
+function Models.BulkRetryDryRun.new(fields)
+  return setmetatable({
+    count = fields.count,
+  }, Models.BulkRetryDryRun)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return BulkRetryDryRun
+function Models.BulkRetryDryRun.from_json(value)
+  local fields = Runtime.as_fields(value, "BulkRetryDryRun")
+  return Models.BulkRetryDryRun.new({
+    count = Runtime.read(fields, "count", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.BulkRetryDryRun:to_table()
+  return Runtime.document({
+    ["count"] = self.count,
+  })
+end
+
+--- The `BulkRetryPost` the API declares.
+Models.BulkRetryPost = {}
+Models.BulkRetryPost.__index = Models.BulkRetryPost
+Models.BulkRetryPost.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.event_id string|nil carries `event_id`.
+--- @param fields.event_type_names string[]|nil carries `event_type_names`.
+--- @param fields.max_created_at string|nil carries `max_created_at`.
+--- @param fields.min_created_at string|nil carries `min_created_at`.
+--- @param fields.status string|nil carries `status`, one of `Models.BulkRetryPostStatus.VALUES`: Redeliver request
+---   attempts that failed (default) or that succeeded
+--- @param fields.subscription_id string|nil carries `subscription_id`.
+--- @return BulkRetryPost
+function Models.BulkRetryPost.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    event_id = fields.event_id,
+    event_type_names = fields.event_type_names,
+    max_created_at = fields.max_created_at,
+    min_created_at = fields.min_created_at,
+    status = fields.status,
+    subscription_id = fields.subscription_id,
+  }, Models.BulkRetryPost)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return BulkRetryPost
+function Models.BulkRetryPost.from_json(value)
+  local fields = Runtime.as_fields(value, "BulkRetryPost")
+  return Models.BulkRetryPost.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    event_id = Runtime.maybe(fields, "event_id", Runtime.TEXT),
+    event_type_names = Runtime.maybe(fields, "event_type_names", Runtime.list(Runtime.TEXT)),
+    max_created_at = Runtime.maybe(fields, "max_created_at", Runtime.TEXT),
+    min_created_at = Runtime.maybe(fields, "min_created_at", Runtime.TEXT),
+    status = Runtime.maybe(fields, "status", Runtime.member_of(Models.BulkRetryPostStatus)),
+    subscription_id = Runtime.maybe(fields, "subscription_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.BulkRetryPost:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["event_id"] = self.event_id,
+    ["event_type_names"] = Runtime.written_list(self.event_type_names, Runtime.itself),
+    ["max_created_at"] = self.max_created_at,
+    ["min_created_at"] = self.min_created_at,
+    ["status"] = self.status,
+    ["subscription_id"] = self.subscription_id,
+  })
+end
+
+--- The `CancelEvent` the API declares.
+Models.CancelEvent = {}
+Models.CancelEvent.__index = Models.CancelEvent
+Models.CancelEvent.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @return CancelEvent
+function Models.CancelEvent.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+  }, Models.CancelEvent)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return CancelEvent
+function Models.CancelEvent.from_json(value)
+  local fields = Runtime.as_fields(value, "CancelEvent")
+  return Models.CancelEvent.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.CancelEvent:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+  })
+end
+
+--- The `ClientCertificate` the API declares.
+Models.ClientCertificate = {}
+Models.ClientCertificate.__index = Models.ClientCertificate
+Models.ClientCertificate.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.certificate_pem string carries `certificate_pem`: Certificate chain, leaf certificate first
+--- @param fields.created_at string carries `created_at`.
+--- @param fields.fingerprint_sha256 string carries `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate,
+---   as hexadecimal
+--- @param fields.not_after string carries `not_after`.
+--- @param fields.not_before string carries `not_before`.
+--- @param fields.subject string carries `subject`.
+--- @param fields.subscription_id string carries `subscription_id`.
+--- @param fields.updated_at string carries `updated_at`.
+--- @return ClientCertificate
+function Models.ClientCertificate.new(fields)
+  return setmetatable({
+    certificate_pem = fields.certificate_pem,
+    created_at = fields.created_at,
+    fingerprint_sha256 = fields.fingerprint_sha256,
+    not_after = fields.not_after,
+    not_before = fields.not_before,
+    subject = fields.subject,
+    subscription_id = fields.subscription_id,
+    updated_at = fields.updated_at,
+  }, Models.ClientCertificate)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return ClientCertificate
+function Models.ClientCertificate.from_json(value)
+  local fields = Runtime.as_fields(value, "ClientCertificate")
+  return Models.ClientCertificate.new({
+    certificate_pem = Runtime.read(fields, "certificate_pem", Runtime.TEXT),
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    fingerprint_sha256 = Runtime.read(fields, "fingerprint_sha256", Runtime.TEXT),
+    not_after = Runtime.read(fields, "not_after", Runtime.TEXT),
+    not_before = Runtime.read(fields, "not_before", Runtime.TEXT),
+    subject = Runtime.read(fields, "subject", Runtime.TEXT),
+    subscription_id = Runtime.read(fields, "subscription_id", Runtime.TEXT),
+    updated_at = Runtime.read(fields, "updated_at", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.ClientCertificate:to_table()
+  return Runtime.document({
+    ["certificate_pem"] = self.certificate_pem,
+    ["created_at"] = self.created_at,
+    ["fingerprint_sha256"] = self.fingerprint_sha256,
+    ["not_after"] = self.not_after,
+    ["not_before"] = self.not_before,
+    ["subject"] = self.subject,
+    ["subscription_id"] = self.subscription_id,
+    ["updated_at"] = self.updated_at,
+  })
+end
+
+--- The `ClientCertificatePut` the API declares.
+Models.ClientCertificatePut = {}
+Models.ClientCertificatePut.__index = Models.ClientCertificatePut
+Models.ClientCertificatePut.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.certificate_pem string carries `certificate_pem`: PEM-encoded certificate chain, leaf certificate
+---   first
+--- @param fields.private_key_pem string carries `private_key_pem`: PEM-encoded unencrypted private key of the leaf
+---   certificate (PKCS#8, PKCS#1 or SEC1)
+--- @return ClientCertificatePut
+function Models.ClientCertificatePut.new(fields)
+  return setmetatable({
+    certificate_pem = fields.certificate_pem,
+    private_key_pem = fields.private_key_pem,
+  }, Models.ClientCertificatePut)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return ClientCertificatePut
+function Models.ClientCertificatePut.from_json(value)
+  local fields = Runtime.as_fields(value, "ClientCertificatePut")
+  return Models.ClientCertificatePut.new({
+    certificate_pem = Runtime.read(fields, "certificate_pem", Runtime.TEXT),
+    private_key_pem = Runtime.read(fields, "private_key_pem", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.ClientCertificatePut:to_table()
+  return Runtime.document({
+    ["certificate_pem"] = self.certificate_pem,
+    ["private_key_pem"] = self.private_key_pem,
+  })
+end
+
+--- The `DeadLetter` the API declares.
+Models.DeadLetter = {}
+Models.DeadLetter.__index = Models.DeadLetter
+Models.DeadLetter.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.created_at string carries `created_at`.
+--- @param fields.dead_letter_id string carries `dead_letter_id`.
+--- @param fields.event DeadLetterEvent carries `event`.
+--- @param fields.reason string carries `reason`, one of `Models.DeadLetterReason.VALUES`.
+--- @param fields.request_attempt_id string carries `request_attempt_id`: Last request attempt of the delivery
+--- @param fields.retry_count integer carries `retry_count`.
+--- @param fields.subscription DeadLetterSubscription carries `subscription`.
+--- @param fields.http_response_status integer|nil carries `http_response_status`.
+--- @param fields.response_id string|nil carries `response_id`: Response to the last request attempt, if the target was
+---   reached
+--- @return DeadLetter
+function Models.DeadLetter.new(fields)
+  return setmetatable({
+    created_at = fields.created_at,
+    dead_letter_id = fields.dead_letter_id,
+    event = fields.event,
+    reason = fields.reason,
+    request_attempt_id = fields.request_attempt_id,
+    retry_count = fields.retry_count,
+    subscription = fields.subscription,
+    http_response_status = fields.http_response_status,
+    response_id = fields.response_id,
+  }, Models.DeadLetter)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return DeadLetter
+function Models.DeadLetter.from_json(value)
+  local fields = Runtime.as_fields(value, "DeadLetter")
+  return Models.DeadLetter.new({
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    dead_letter_id = Runtime.read(fields, "dead_letter_id", Runtime.TEXT),
+    event = Runtime.read(fields, "event", Models.DeadLetterEvent.from_json),
+    reason = Runtime.read(fields, "reason", Runtime.member_of(Models.DeadLetterReason)),
+    request_attempt_id = Runtime.read(fields, "request_attempt_id", Runtime.TEXT),
+    retry_count = Runtime.read(fields, "retry_count", Runtime.INTEGER),
+    subscription = Runtime.read(fields, "subscription", Models.DeadLetterSubscription.from_json),
+    http_response_status = Runtime.maybe(fields, "http_response_status", Runtime.INTEGER),
+    response_id = Runtime.maybe(fields, "response_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.DeadLetter:to_table()
+  return Runtime.document({
+    ["created_at"] = self.created_at,
+    ["dead_letter_id"] = self.dead_letter_id,
+    ["event"] = Runtime.written(self.event),
+    ["reason"] = self.reason,
+    ["request_attempt_id"] = self.request_attempt_id,
+    ["retry_count"] = self.retry_count,
+    ["subscription"] = Runtime.written(self.subscription),
+    ["http_response_status"] = self.http_response_status,
+    ["response_id"] = self.response_id,
+  })
+end
+
+--- The `DeadLetterDetails` the API declares.
+Models.DeadLetterDetails = {}
+Models.DeadLetterDetails.__index = Models.DeadLetterDetails
+Models.DeadLetterDetails.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.dead_letter DeadLetterDetailsDeadLetter carries `dead_letter`: Delivery an output worker gave up on
+--- @param fields.last_response DeadLetterDetailsLastResponse|nil carries `last_response`: Response to the last request
+---   attempt, if the target was reached
+--- @return DeadLetterDetails
+function Models.DeadLetterDetails.new(fields)
+  return setmetatable({
+    dead_letter = fields.dead_letter,
+    last_response = fields.last_response,
+  }, Models.DeadLetterDetails)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return DeadLetterDetails
+function Models.DeadLetterDetails.from_json(value)
+  local fields = Runtime.as_fields(value, "DeadLetterDetails")
+  return Models.DeadLetterDetails.new({
+    dead_letter = Runtime.read(fields, "dead_letter", Models.DeadLetterDetailsDeadLetter.from_json),
+    last_response = Runtime.maybe(fields, "last_response", Models.DeadLetterDetailsLastResponse.from_json),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.DeadLetterDetails:to_table()
+  return Runtime.document({
+    ["dead_letter"] = Runtime.written(self.dead_letter),
+    ["last_response"] = Runtime.written(self.last_response),
+  })
+end
+
+--- The `DeadLetterDetailsDeadLetter` the API declares.
+Models.DeadLetterDetailsDeadLetter = {}
+Models.DeadLetterDetailsDeadLetter.__index = Models.DeadLetterDetailsDeadLetter
+Models.DeadLetterDetailsDeadLetter.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.created_at string carries `created_at`.
+--- @param fields.dead_letter_id string carries `dead_letter_id`.
+--- @param fields.event DeadLetterDetailsDeadLetterEvent carries `event`.
+--- @param fields.reason string carries `reason`, one of `Models.DeadLetterDetailsDeadLetterReason.VALUES`.
+--- @param fields.request_attempt_id string carries `request_attempt_id`: Last request attempt of the delivery
+--- @param fields.retry_count integer carries `retry_count`.
+--- @param fields.subscription DeadLetterDetailsDeadLetterSubscription carries `subscription`.
+--- @param fields.http_response_status integer|nil carries `http_response_status`.
+--- @param fields.response_id string|nil carries `response_id`: Response to the last request attempt, if the target was
+---   reached
+--- @return DeadLetterDetailsDeadLetter
+function Models.DeadLetterDetailsDeadLetter.new(fields)
+  return setmetatable({
+    created_at = fields.created_at,
+    dead_letter_id = fields.dead_letter_id,
+    event = fields.event,
+    reason = fields.reason,
+    request_attempt_id = fields.request_attempt_id,
+    retry_count = fields.retry_count,
+    subscription = fields.subscription,
+    http_response_status = fields.http_response_status,
+    response_id = fields.response_id,
+  }, Models.DeadLetterDetailsDeadLetter)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return DeadLetterDetailsDeadLetter
+function Models.DeadLetterDetailsDeadLetter.from_json(value)
+  local fields = Runtime.as_fields(value, "DeadLetterDetailsDeadLetter")
+  return Models.DeadLetterDetailsDeadLetter.new({
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    dead_letter_id = Runtime.read(fields, "dead_letter_id", Runtime.TEXT),
+    event = Runtime.read(fields, "event", Models.DeadLetterDetailsDeadLetterEvent.from_json),
+    reason = Runtime.read(fields, "reason", Runtime.member_of(Models.DeadLetterDetailsDeadLetterReason)),
+    request_attempt_id = Runtime.read(fields, "request_attempt_id", Runtime.TEXT),
+    retry_count = Runtime.read(fields, "retry_count", Runtime.INTEGER),
+    subscription = Runtime.read(fields, "subscription", Models.DeadLetterDetailsDeadLetterSubscription.from_json),
+    http_response_status = Runtime.maybe(fields, "http_response_status", Runtime.INTEGER),
+    response_id = Runtime.maybe(fields, "response_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.DeadLetterDetailsDeadLetter:to_table()
+  return Runtime.document({
+    ["created_at"] = self.created_at,
+    ["dead_letter_id"] = self.dead_letter_id,
+    ["event"] = Runtime.written(self.event),
+    ["reason"] = self.reason,
+    ["request_attempt_id"] = self.request_attempt_id,
+    ["retry_count"] = self.retry_count,
+    ["subscription"] = Runtime.written(self.subscription),
+    ["http_response_status"] = self.http_response_status,
+    ["response_id"] = self.response_id,
+  })
+end
+
+--- The `DeadLetterDetailsDeadLetterEvent` the API declares.
+Models.DeadLetterDetailsDeadLetterEvent = {}
+Models.DeadLetterDetailsDeadLetterEvent.__index = Models.DeadLetterDetailsDeadLetterEvent
+Models.DeadLetterDetailsDeadLetterEvent.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.event_id string carries `event_id`.
+--- @param fields.event_type_name string carries `event_type_name`.
+--- @return DeadLetterDetailsDeadLetterEvent
+function Models.DeadLetterDetailsDeadLetterEvent.new(fields)
+  return setmetatable({
+    event_id = fields.event_id,
+    event_type_name = fields.event_type_name,
+  }, Models.DeadLetterDetailsDeadLetterEvent)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return DeadLetterDetailsDeadLetterEvent
+function Models.DeadLetterDetailsDeadLetterEvent.from_json(value)
+  local fields = Runtime.as_fields(value, "DeadLetterDetailsDeadLetterEvent")
+  return Models.DeadLetterDetailsDeadLetterEvent.new({
+    event_id = Runtime.read(fields, "event_id", Runtime.TEXT),
+    event_type_name = Runtime.read(fields, "event_type_name", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.DeadLetterDetailsDeadLetterEvent:to_table()
+  return Runtime.document({
+    ["event_id"] = self.event_id,
+    ["event_type_name"] = self.event_type_name,
+  })
+end
+
+--- The `DeadLetterDetailsDeadLetterSubscription` the API declares.
+Models.DeadLetterDetailsDeadLetterSubscription = {}
+Models.DeadLetterDetailsDeadLetterSubscription.__index = Models.DeadLetterDetailsDeadLetterSubscription
+Models.DeadLetterDetailsDeadLetterSubscription.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.subscription_id string carries `subscription_id`.
+--- @param fields.description string|nil carries `description`.
+--- @return DeadLetterDetailsDeadLetterSubscription
+function Models.DeadLetterDetailsDeadLetterSubscription.new(fields)
+  return setmetatable({
+    subscription_id = fields.subscription_id,
+    description = fields.description,
+  }, Models.DeadLetterDetailsDeadLetterSubscription)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return DeadLetterDetailsDeadLetterSubscription
+function Models.DeadLetterDetailsDeadLetterSubscription.from_json(value)
+  local fields = Runtime.as_fields(value, "DeadLetterDetailsDeadLetterSubscription")
+  return Models.DeadLetterDetailsDeadLetterSubscription.new({
+    subscription_id = Runtime.read(fields, "subscription_id", Runtime.TEXT),
+    description = Runtime.maybe(fields, "description", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.DeadLetterDetailsDeadLetterSubscription:to_table()
+  return Runtime.document({
+    ["subscription_id"] = self.subscription_id,
+    ["description"] = self.description,
+  })
+end
+
+--- The `DeadLetterDetailsLastResponse` the API declares.
+Models.DeadLetterDetailsLastResponse = {}
+Models.DeadLetterDetailsLastResponse.__index = Models.DeadLetterDetailsLastResponse
+Models.DeadLetterDetailsLastResponse.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.response_id string carries `response_id`.
+--- @param fields.body string|nil carries `body`.
+--- @param fields.elapsed_time_ms integer|nil carries `elapsed_time_ms`.
+--- @param fields.headers table<string, string>|nil carries `headers`.
+--- @param fields.http_code integer|nil carries `http_code`.
+--- @param fields.response_error_name string|nil carries `response_error_name`.
+--- @return DeadLetterDetailsLastResponse
+function Models.DeadLetterDetailsLastResponse.new(fields)
+  return setmetatable({
+    response_id = fields.response_id,
+    body = fields.body,
+    elapsed_time_ms = fields.elapsed_time_ms,
+    headers = fields.headers,
+    http_code = fields.http_code,
+    response_error_name = fields.response_error_name,
+  }, Models.DeadLetterDetailsLastResponse)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return DeadLetterDetailsLastResponse
+function Models.DeadLetterDetailsLastResponse.from_json(value)
+  local fields = Runtime.as_fields(value, "DeadLetterDetailsLastResponse")
+  return Models.DeadLetterDetailsLastResponse.new({
+    response_id = Runtime.read(fields, "response_id", Runtime.TEXT),
+    body = Runtime.maybe(fields, "body", Runtime.TEXT),
+    elapsed_time_ms = Runtime.maybe(fields, "elapsed_time_ms", Runtime.INTEGER),
+    headers = Runtime.maybe(fields, "headers", Runtime.map(Runtime.TEXT)),
+    http_code = Runtime.maybe(fields, "http_code", Runtime.INTEGER),
+    response_error_name = Runtime.maybe(fields, "response_error_name", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.DeadLetterDetailsLastResponse:to_table()
+  return Runtime.document({
+    ["response_id"] = self.response_id,
+    ["body"] = self.body,
+    ["elapsed_time_ms"] = self.elapsed_time_ms,
+    ["headers"] = Runtime.written_map(self.headers, Runtime.itself),
+    ["http_code"] = self.http_code,
+    ["response_error_name"] = self.response_error_name,
+  })
+end
+
+--- The `DeadLetterEvent` the API declares.
+Models.DeadLetterEvent = {}
+Models.DeadLetterEvent.__index = Models.DeadLetterEvent
+Models.DeadLetterEvent.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.event_id string carries `event_id`.
+--- @param fields.event_type_name string carries `event_type_name`.
+--- @return DeadLetterEvent
+function Models.DeadLetterEvent.new(fields)
+  return setmetatable({
+    event_id = fields.event_id,
+    event_type_name = fields.event_type_name,
+  }, Models.DeadLetterEvent)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return DeadLetterEvent
+function Models.DeadLetterEvent.from_json(value)
+  local fields = Runtime.as_fields(value, "DeadLetterEvent")
+  return Models.DeadLetterEvent.new({
+    event_id = Runtime.read(fields, "event_id", Runtime.TEXT),
+    event_type_name = Runtime.read(fields, "event_type_name", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.DeadLetterEvent:to_table()
+  return Runtime.document({
+    ["event_id"] = self.event_id,
+    ["event_type_name"] = self.event_type_name,
+  })
+end
+
+--- The `DeadLetterSubscription` the API declares.
+Models.DeadLetterSubscription = {}
+Models.DeadLetterSubscription.__index = Models.DeadLetterSubscription
+Models.DeadLetterSubscription.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.subscription_id string carries `subscription_id`.
+--- @param fields.description string|nil carries `description`.
+--- @return DeadLetterSubscription
+function Models.DeadLetterSubscription.new(fields)
+  return setmetatable({
+    subscription_id = fields.subscription_id,
+    description = fields.description,
+  }, Models.DeadLetterSubscription)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return DeadLetterSubscription
+function Models.DeadLetterSubscription.from_json(value)
+  local fields = Runtime.as_fields(value, "DeadLetterSubscription")
+  return Models.DeadLetterSubscription.new({
+    subscription_id = Runtime.read(fields, "subscription_id", Runtime.TEXT),
+    description = Runtime.maybe(fields, "description", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.DeadLetterSubscription:to_table()
+  return Runtime.document({
+    ["subscription_id"] = self.subscription_id,
+    ["description"] = self.description,
+  })
+end
+
+--- The `Event` the API declares.
+Models.Event = {}
+Models.Event.__index = Models.Event
+Models.Event.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.event_id string carries `event_id`.
+--- @param fields.event_type_name string carries `event_type_name`.
+--- @param fields.ip string carries `ip`.
+--- @param fields.labels any carries `labels`.
+--- @param fields.occurred_at string carries `occurred_at`.
+--- @param fields.payload_content_type string carries `payload_content_type`.
+--- @param fields.received_at string carries `received_at`.
+--- @param fields.cancelled_at string|nil carries `cancelled_at`: Date the scheduled delivery of the event was cancelled
+--- @param fields.deliver_at string|nil carries `deliver_at`: Date from which the event is delivered, when it was
+---   scheduled for a later delivery
+--- @param fields.event_type_version integer|nil carries `event_type_version`.
+--- @param fields.expires_at string|nil carries `expires_at`: Date after which the event is no longer delivered
+--- @param fields.metadata any|nil carries `metadata`.
+--- @return Event
+function Models.Event.new(fields)
+  return setmetatable({
+    event_id = fields.event_id,
+    event_type_name = fields.event_type_name,
+    ip = fields.ip,
+    labels = fields.labels,
+    occurred_at = fields.occurred_at,
+    payload_content_type = fields.payload_content_type,
+    received_at = fields.received_at,
+    cancelled_at = fields.cancelled_at,
+    deliver_at = fields.deliver_at,
+    event_type_version = fields.event_type_version,
+    expires_at = fields.expires_at,
+    metadata = fields.metadata,
+  }, Models.Event)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return Event
+function Models.Event.from_json(value)
+  local fields = Runtime.as_fields(value, "Event")
+  return Models.Event.new({
+    event_id = Runtime.read(fields, "event_id", Runtime.TEXT),
+    event_type_name = Runtime.read(fields, "event_type_name", Runtime.TEXT),
+    ip = Runtime.read(fields, "ip", Runtime.TEXT),
+    labels = Runtime.read(fields, "labels", Runtime.JSON_VALUE),
+    occurred_at = Runtime.read(fields, "occurred_at", Runtime.TEXT),
+    payload_content_type = Runtime.read(fields, "payload_content_type", Runtime.TEXT),
+    received_at = Runtime.read(fields, "received_at", Runtime.TEXT),
+    cancelled_at = Runtime.maybe(fields, "cancelled_at", Runtime.TEXT),
+    deliver_at = Runtime.maybe(fields, "deliver_at", Runtime.TEXT),
+    event_type_version = Runtime.maybe(fields, "event_type_version", Runtime.INTEGER),
+    expires_at = Runtime.maybe(fields, "expires_at", Runtime.TEXT),
+    metadata = Runtime.maybe(fields, "metadata", Runtime.JSON_VALUE),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.Event:to_table()
+  return Runtime.document({
+    ["event_id"] = self.event_id,
+    ["event_type_name"] = self.event_type_name,
+    ["ip"] = self.ip,
+    ["labels"] = self.labels,
+    ["occurred_at"] = self.occurred_at,
+    ["payload_content_type"] = self.payload_content_type,
+    ["received_at"] = self.received_at,
+    ["cancelled_at"] = self.cancelled_at,
+    ["deliver_at"] = self.deliver_at,
+    ["event_type_version"] = self.event_type_version,
+    ["expires_at"] = self.expires_at,
+    ["metadata"] = self.metadata,
+  })
+end
+
+--- The `EventPost` the API declares.
+Models.EventPost = {}
+Models.EventPost.__index = Models.EventPost
+Models.EventPost.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`: UUID of the application this event belongs to.
+--- @param fields.event_type string carries `event_type`: The type of event (e.g., 'user.created', 'order.completed').
+---   Length: 1-200 characters.
+--- @param fields.labels table<string, string> carries `labels`: Labels for event filtering and routing to
+---   subscriptions.
+--- @param fields.occurred_at string carries `occurred_at`: Timestamp when the event occurred.
+--- @param fields.payload string carries `payload`: The event payload. For binary content, use base64 encoding. Max
+---   length: 699050 characters (512 KiB base64-encoded).
+--- @param fields.payload_content_type string carries `payload_content_type`: Content type of the payload. Valid values:
+---   text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
+--- @param fields.deliver_at string|nil carries `deliver_at`: Optional date from which the event is delivered to its
+---   subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as
+---   the events retention of the appl
+--- @param fields.event_id string|nil carries `event_id`: Optional unique identifier for this event (client-generated
+---   UUID). If not provided, a UUIDv7 will be generated by the server.
+--- @param fields.event_type_version integer|nil carries `event_type_version`: Optional version of the event type the
+---   payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+--- @param fields.expires_at string|nil carries `expires_at`: Optional date after which the event is no longer
+---   delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default
+---   time to live of its event type, counted f
+--- @param fields.metadata table<string, string>|nil carries `metadata`: Optional metadata key-value pairs associated
+---   with the event.
+--- @return EventPost
+function Models.EventPost.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    event_type = fields.event_type,
+    labels = fields.labels,
+    occurred_at = fields.occurred_at,
+    payload = fields.payload,
+    payload_content_type = fields.payload_content_type,
+    deliver_at = fields.deliver_at,
+    event_id = fields.event_id,
+    event_type_version = fields.event_type_version,
+    expires_at = fields.expires_at,
+    metadata = fields.metadata,
+  }, Models.EventPost)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventPost
+function Models.EventPost.from_json(value)
+  local fields = Runtime.as_fields(value, "EventPost")
+  return Models.EventPost.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    event_type = Runtime.read(fields, "event_type", Runtime.TEXT),
+    labels = Runtime.read(fields, "labels", Runtime.map(Runtime.TEXT)),
+    occurred_at = Runtime.read(fields, "occurred_at", Runtime.TEXT),
+    payload = Runtime.read(fields, "payload", Runtime.TEXT),
+    payload_content_type = Runtime.read(fields, "payload_content_type", Runtime.TEXT),
+    deliver_at = Runtime.maybe(fields, "deliver_at", Runtime.TEXT),
+    event_id = Runtime.maybe(fields, "event_id", Runtime.TEXT),
+    event_type_version = Runtime.maybe(fields, "event_type_version", Runtime.INTEGER),
+    expires_at = Runtime.maybe(fields, "expires_at", Runtime.TEXT),
+    metadata = Runtime.maybe(fields, "metadata", Runtime.map(Runtime.TEXT)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventPost:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["event_type"] = self.event_type,
+    ["labels"] = Runtime.written_map(self.labels, Runtime.itself),
+    ["occurred_at"] = self.occurred_at,
+    ["payload"] = self.payload,
+    ["payload_content_type"] = self.payload_content_type,
+    ["deliver_at"] = self.deliver_at,
+    ["event_id"] = self.event_id,
+    ["event_type_version"] = self.event_type_version,
+    ["expires_at"] = self.expires_at,
+    ["metadata"] = Runtime.written_map(self.metadata, Runtime.itself),
+  })
+end
+
+--- The `EventType` the API declares.
+Models.EventType = {}
+Models.EventType.__index = Models.EventType
+Models.EventType.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.event_type_name string carries `event_type_name`.
+--- @param fields.payload_schema_mode string carries `payload_schema_mode`, one of
+---   `Models.EventTypePayloadSchemaMode.VALUES`.
+--- @param fields.resource_type_name string carries `resource_type_name`.
+--- @param fields.service_name string carries `service_name`.
+--- @param fields.verb_name string carries `verb_name`.
+--- @param fields.versions EventTypeVersions[] carries `versions`.
+--- @param fields.default_ttl_s integer|nil carries `default_ttl_s`: Time to live in seconds of the events of this type
+---   that do not give an expiry date
+--- @param fields.payload_schema any|nil carries `payload_schema`.
+--- @return EventType
+function Models.EventType.new(fields)
+  return setmetatable({
+    event_type_name = fields.event_type_name,
+    payload_schema_mode = fields.payload_schema_mode,
+    resource_type_name = fields.resource_type_name,
+    service_name = fields.service_name,
+    verb_name = fields.verb_name,
+    versions = fields.versions,
+    default_ttl_s = fields.default_ttl_s,
+    payload_schema = fields.payload_schema,
+  }, Models.EventType)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventType
+function Models.EventType.from_json(value)
+  local fields = Runtime.as_fields(value, "EventType")
+  return Models.EventType.new({
+    event_type_name = Runtime.read(fields, "event_type_name", Runtime.TEXT),
+    payload_schema_mode = Runtime.read(
+      fields,
+      "payload_schema_mode",
+      Runtime.member_of(Models.EventTypePayloadSchemaMode)
+    ),
+    resource_type_name = Runtime.read(fields, "resource_type_name", Runtime.TEXT),
+    service_name = Runtime.read(fields, "service_name", Runtime.TEXT),
+    verb_name = Runtime.read(fields, "verb_name", Runtime.TEXT),
+    versions = Runtime.read(fields, "versions", Runtime.list(Models.EventTypeVersions.from_json)),
+    default_ttl_s = Runtime.maybe(fields, "default_ttl_s", Runtime.INTEGER),
+    payload_schema = Runtime.maybe(fields, "payload_schema", Runtime.JSON_VALUE),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventType:to_table()
+  return Runtime.document({
+    ["event_type_name"] = self.event_type_name,
+    ["payload_schema_mode"] = self.payload_schema_mode,
+    ["resource_type_name"] = self.resource_type_name,
+    ["service_name"] = self.service_name,
+    ["verb_name"] = self.verb_name,
+    ["versions"] = Runtime.written_list(self.versions, Runtime.written),
+    ["default_ttl_s"] = self.default_ttl_s,
+    ["payload_schema"] = self.payload_schema,
+  })
+end
+
+--- The `EventTypePost` the API declares.
+Models.EventTypePost = {}
+Models.EventTypePost.__index = Models.EventTypePost
+Models.EventTypePost.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.resource_type string carries `resource_type`.
+--- @param fields.service string carries `service`.
+--- @param fields.verb string carries `verb`.
+--- @param fields.default_ttl_s integer|nil carries `default_ttl_s`: Time to live in seconds of the events of this type
+---   that do not give an expiry date; their pending deliveries are cancelled once it is over
+--- @param fields.payload_schema any|nil carries `payload_schema`: JSON Schema the payloads of the `application/json`
+---   events of this type must match; remote references are not supported
+--- @param fields.payload_schema_mode string|nil carries `payload_schema_mode`, one of
+---   `Models.EventTypePostPayloadSchemaMode.VALUES`: How the payload schema is applied at ingestion (default:
+---   `enforce`)
+--- @return EventTypePost
+function Models.EventTypePost.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    resource_type = fields.resource_type,
+    service = fields.service,
+    verb = fields.verb,
+    default_ttl_s = fields.default_ttl_s,
+    payload_schema = fields.payload_schema,
+    payload_schema_mode = fields.payload_schema_mode,
+  }, Models.EventTypePost)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventTypePost
+function Models.EventTypePost.from_json(value)
+  local fields = Runtime.as_fields(value, "EventTypePost")
+  return Models.EventTypePost.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    resource_type = Runtime.read(fields, "resource_type", Runtime.TEXT),
+    service = Runtime.read(fields, "service", Runtime.TEXT),
+    verb = Runtime.read(fields, "verb", Runtime.TEXT),
+    default_ttl_s = Runtime.maybe(fields, "default_ttl_s", Runtime.INTEGER),
+    payload_schema = Runtime.maybe(fields, "payload_schema", Runtime.JSON_VALUE),
+    payload_schema_mode = Runtime.maybe(
+      fields,
+      "payload_schema_mode",
+      Runtime.member_of(Models.EventTypePostPayloadSchemaMode)
+    ),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventTypePost:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["resource_type"] = self.resource_type,
+    ["service"] = self.service,
+    ["verb"] = self.verb,
+    ["default_ttl_s"] = self.default_ttl_s,
+    ["payload_schema"] = self.payload_schema,
+    ["payload_schema_mode"] = self.payload_schema_mode,
+  })
+end
+
+--- The `EventTypePut` the API declares.
+Models.EventTypePut = {}
+Models.EventTypePut.__index = Models.EventTypePut
+Models.EventTypePut.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.default_ttl_s integer|nil carries `default_ttl_s`: Time to live in seconds of the events of this type
+---   that do not give an expiry date; their pending deliveries are cancelled once it is over
+--- @param fields.payload_schema any|nil carries `payload_schema`: JSON Schema the payloads of the `application/json`
+---   events of this type must match; remote references are not supported
+--- @param fields.payload_schema_mode string|nil carries `payload_schema_mode`, one of
+---   `Models.EventTypePutPayloadSchemaMode.VALUES`: How the payload schema is applied at ingestion (default: `enforce`)
+--- @return EventTypePut
+function Models.EventTypePut.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    default_ttl_s = fields.default_ttl_s,
+    payload_schema = fields.payload_schema,
+    payload_schema_mode = fields.payload_schema_mode,
+  }, Models.EventTypePut)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventTypePut
+function Models.EventTypePut.from_json(value)
+  local fields = Runtime.as_fields(value, "EventTypePut")
+  return Models.EventTypePut.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    default_ttl_s = Runtime.maybe(fields, "default_ttl_s", Runtime.INTEGER),
+    payload_schema = Runtime.maybe(fields, "payload_schema", Runtime.JSON_VALUE),
+    payload_schema_mode = Runtime.maybe(
+      fields,
+      "payload_schema_mode",
+      Runtime.member_of(Models.EventTypePutPayloadSchemaMode)
+    ),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventTypePut:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["default_ttl_s"] = self.default_ttl_s,
+    ["payload_schema"] = self.payload_schema,
+    ["payload_schema_mode"] = self.payload_schema_mode,
+  })
+end
+
+--- The `EventTypeVersion` the API declares.
+Models.EventTypeVersion = {}
+Models.EventTypeVersion.__index = Models.EventTypeVersion
+Models.EventTypeVersion.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.created_at string carries `created_at`.
+--- @param fields.version integer carries `version`.
+--- @param fields.deprecated_at string|nil carries `deprecated_at`.
+--- @param fields.payload_schema any|nil carries `payload_schema`: JSON Schema the payloads of the events declaring this
+---   version must match, instead of the one of the event type
+--- @param fields.sunset_at string|nil carries `sunset_at`: Date from which the events declaring this version are
+---   refused
+--- @return EventTypeVersion
+function Models.EventTypeVersion.new(fields)
+  return setmetatable({
+    created_at = fields.created_at,
+    version = fields.version,
+    deprecated_at = fields.deprecated_at,
+    payload_schema = fields.payload_schema,
+    sunset_at = fields.sunset_at,
+  }, Models.EventTypeVersion)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventTypeVersion
+function Models.EventTypeVersion.from_json(value)
+  local fields = Runtime.as_fields(value, "EventTypeVersion")
+  return Models.EventTypeVersion.new({
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    version = Runtime.read(fields, "version", Runtime.INTEGER),
+    deprecated_at = Runtime.maybe(fields, "deprecated_at", Runtime.TEXT),
+    payload_schema = Runtime.maybe(fields, "payload_schema", Runtime.JSON_VALUE),
+    sunset_at = Runtime.maybe(fields, "sunset_at", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventTypeVersion:to_table()
+  return Runtime.document({
+    ["created_at"] = self.created_at,
+    ["version"] = self.version,
+    ["deprecated_at"] = self.deprecated_at,
+    ["payload_schema"] = self.payload_schema,
+    ["sunset_at"] = self.sunset_at,
+  })
+end
+
+--- The `EventTypeVersionDeprecate` the API declares.
+Models.EventTypeVersionDeprecate = {}
+Models.EventTypeVersionDeprecate.__index = Models.EventTypeVersionDeprecate
+Models.EventTypeVersionDeprecate.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.version integer carries `version`.
+--- @param fields.sunset_at string|nil carries `sunset_at`: Date from which the events declaring this version are
+---   refused; none keeps accepting them
+--- @return EventTypeVersionDeprecate
+function Models.EventTypeVersionDeprecate.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    version = fields.version,
+    sunset_at = fields.sunset_at,
+  }, Models.EventTypeVersionDeprecate)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventTypeVersionDeprecate
+function Models.EventTypeVersionDeprecate.from_json(value)
+  local fields = Runtime.as_fields(value, "EventTypeVersionDeprecate")
+  return Models.EventTypeVersionDeprecate.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    version = Runtime.read(fields, "version", Runtime.INTEGER),
+    sunset_at = Runtime.maybe(fields, "sunset_at", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventTypeVersionDeprecate:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["version"] = self.version,
+    ["sunset_at"] = self.sunset_at,
+  })
+end
+
+--- The `EventTypeVersionPost` the API declares.
+Models.EventTypeVersionPost = {}
+Models.EventTypeVersionPost.__index = Models.EventTypeVersionPost
+Models.EventTypeVersionPost.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.version integer carries `version`.
+--- @param fields.payload_schema any|nil carries `payload_schema`: JSON Schema the payloads of the `application/json`
+---   events declaring this version must match, instead of the one of the event type; remote references are not
+---   supported
+--- @return EventTypeVersionPost
+function Models.EventTypeVersionPost.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    version = fields.version,
+    payload_schema = fields.payload_schema,
+  }, Models.EventTypeVersionPost)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventTypeVersionPost
+function Models.EventTypeVersionPost.from_json(value)
+  local fields = Runtime.as_fields(value, "EventTypeVersionPost")
+  return Models.EventTypeVersionPost.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    version = Runtime.read(fields, "version", Runtime.INTEGER),
+    payload_schema = Runtime.maybe(fields, "payload_schema", Runtime.JSON_VALUE),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventTypeVersionPost:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["version"] = self.version,
+    ["payload_schema"] = self.payload_schema,
+  })
+end
+
+--- The `EventTypeVersions` the API declares.
+Models.EventTypeVersions = {}
+Models.EventTypeVersions.__index = Models.EventTypeVersions
+Models.EventTypeVersions.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.created_at string carries `created_at`.
+--- @param fields.version integer carries `version`.
+--- @param fields.deprecated_at string|nil carries `deprecated_at`.
+--- @param fields.payload_schema any|nil carries `payload_schema`: JSON Schema the payloads of the events declaring this
+---   version must match, instead of the one of the event type
+--- @param fields.sunset_at string|nil carries `sunset_at`: Date from which the events declaring this version are
+---   refused
+--- @return EventTypeVersions
+function Models.EventTypeVersions.new(fields)
+  return setmetatable({
+    created_at = fields.created_at,
+    version = fields.version,
+    deprecated_at = fields.deprecated_at,
+    payload_schema = fields.payload_schema,
+    sunset_at = fields.sunset_at,
+  }, Models.EventTypeVersions)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventTypeVersions
+function Models.EventTypeVersions.from_json(value)
+  local fields = Runtime.as_fields(value, "EventTypeVersions")
+  return Models.EventTypeVersions.new({
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    version = Runtime.read(fields, "version", Runtime.INTEGER),
+    deprecated_at = Runtime.maybe(fields, "deprecated_at", Runtime.TEXT),
+    payload_schema = Runtime.maybe(fields, "payload_schema", Runtime.JSON_VALUE),
+    sunset_at = Runtime.maybe(fields, "sunset_at", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventTypeVersions:to_table()
+  return Runtime.document({
+    ["created_at"] = self.created_at,
+    ["version"] = self.version,
+    ["deprecated_at"] = self.deprecated_at,
+    ["payload_schema"] = self.payload_schema,
+    ["sunset_at"] = self.sunset_at,
+  })
+end
+
+--- The `EventWithPayload` the API declares.
+Models.EventWithPayload = {}
+Models.EventWithPayload.__index = Models.EventWithPayload
+Models.EventWithPayload.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.event_id string carries `event_id`.
+--- @param fields.event_type_name string carries `event_type_name`.
+--- @param fields.ip string carries `ip`.
+--- @param fields.labels any carries `labels`.
+--- @param fields.occurred_at string carries `occurred_at`.
+--- @param fields.payload string carries `payload`.
+--- @param fields.payload_content_type string carries `payload_content_type`.
+--- @param fields.received_at string carries `received_at`.
+--- @param fields.cancelled_at string|nil carries `cancelled_at`: Date the scheduled delivery of the event was cancelled
+--- @param fields.deliver_at string|nil carries `deliver_at`: Date from which the event is delivered, when it was
+---   scheduled for a later delivery
+--- @param fields.event_type_version integer|nil carries `event_type_version`.
+--- @param fields.expires_at string|nil carries `expires_at`: Date after which the event is no longer delivered
+--- @param fields.metadata any|nil carries `metadata`.
+--- @return EventWithPayload
+function Models.EventWithPayload.new(fields)
+  return setmetatable({
+    event_id = fields.event_id,
+    event_type_name = fields.event_type_name,
+    ip = fields.ip,
+    labels = fields.labels,
+    occurred_at = fields.occurred_at,
+    payload = fields.payload,
+    payload_content_type = fields.payload_content_type,
+    received_at = fields.received_at,
+    cancelled_at = fields.cancelled_at,
+    deliver_at = fields.deliver_at,
+    event_type_version = fields.event_type_version,
+    expires_at = fields.expires_at,
+    metadata = fields.metadata,
+  }, Models.EventWithPayload)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventWithPayload
+function Models.EventWithPayload.from_json(value)
+  local fields = Runtime.as_fields(value, "EventWithPayload")
+  return Models.EventWithPayload.new({
+    event_id = Runtime.read(fields, "event_id", Runtime.TEXT),
+    event_type_name = Runtime.read(fields, "event_type_name", Runtime.TEXT),
+    ip = Runtime.read(fields, "ip", Runtime.TEXT),
+    labels = Runtime.read(fields, "labels", Runtime.JSON_VALUE),
+    occurred_at = Runtime.read(fields, "occurred_at", Runtime.TEXT),
+    payload = Runtime.read(fields, "payload", Runtime.TEXT),
+    payload_content_type = Runtime.read(fields, "payload_content_type", Runtime.TEXT),
+    received_at = Runtime.read(fields, "received_at", Runtime.TEXT),
+    cancelled_at = Runtime.maybe(fields, "cancelled_at", Runtime.TEXT),
+    deliver_at = Runtime.maybe(fields, "deliver_at", Runtime.TEXT),
+    event_type_version = Runtime.maybe(fields, "event_type_version", Runtime.INTEGER),
+    expires_at = Runtime.maybe(fields, "expires_at", Runtime.TEXT),
+    metadata = Runtime.maybe(fields, "metadata", Runtime.JSON_VALUE),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventWithPayload:to_table()
+  return Runtime.document({
+    ["event_id"] = self.event_id,
+    ["event_type_name"] = self.event_type_name,
+    ["ip"] = self.ip,
+    ["labels"] = self.labels,
+    ["occurred_at"] = self.occurred_at,
+    ["payload"] = self.payload,
+    ["payload_content_type"] = self.payload_content_type,
+    ["received_at"] = self.received_at,
+    ["cancelled_at"] = self.cancelled_at,
+    ["deliver_at"] = self.deliver_at,
+    ["event_type_version"] = self.event_type_version,
+    ["expires_at"] = self.expires_at,
+    ["metadata"] = self.metadata,
+  })
+end
+
+--- The `EventsBatchPost` the API declares.
+Models.EventsBatchPost = {}
+Models.EventsBatchPost.__index = Models.EventsBatchPost
+Models.EventsBatchPost.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`: UUID of the application the events belong to. Events
+---   of the batch that carry another application ID are not ingested.
+--- @param fields.events EventsBatchPostEvents[] carries `events`: Events to ingest. Length: 1-100 events; the whole
+---   request body is also subject to the usual request size limit.
+--- @return EventsBatchPost
+function Models.EventsBatchPost.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    events = fields.events,
+  }, Models.EventsBatchPost)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventsBatchPost
+function Models.EventsBatchPost.from_json(value)
+  local fields = Runtime.as_fields(value, "EventsBatchPost")
+  return Models.EventsBatchPost.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    events = Runtime.read(fields, "events", Runtime.list(Models.EventsBatchPostEvents.from_json)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventsBatchPost:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["events"] = Runtime.written_list(self.events, Runtime.written),
+  })
+end
+
+--- The `EventsBatchPostEvents` the API declares.
+Models.EventsBatchPostEvents = {}
+Models.EventsBatchPostEvents.__index = Models.EventsBatchPostEvents
+Models.EventsBatchPostEvents.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`: UUID of the application this event belongs to.
+--- @param fields.event_type string carries `event_type`: The type of event (e.g., 'user.created', 'order.completed').
+---   Length: 1-200 characters.
+--- @param fields.labels table<string, string> carries `labels`: Labels for event filtering and routing to
+---   subscriptions.
+--- @param fields.occurred_at string carries `occurred_at`: Timestamp when the event occurred.
+--- @param fields.payload string carries `payload`: The event payload. For binary content, use base64 encoding. Max
+---   length: 699050 characters (512 KiB base64-encoded).
+--- @param fields.payload_content_type string carries `payload_content_type`: Content type of the payload. Valid values:
+---   text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
+--- @param fields.deliver_at string|nil carries `deliver_at`: Optional date from which the event is delivered to its
+---   subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as
+---   the events retention of the appl
+--- @param fields.event_id string|nil carries `event_id`: Optional unique identifier for this event (client-generated
+---   UUID). If not provided, a UUIDv7 will be generated by the server.
+--- @param fields.event_type_version integer|nil carries `event_type_version`: Optional version of the event type the
+---   payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+--- @param fields.expires_at string|nil carries `expires_at`: Optional date after which the event is no longer
+---   delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default
+---   time to live of its event type, counted f
+--- @param fields.metadata table<string, string>|nil carries `metadata`: Optional metadata key-value pairs associated
+---   with the event.
+--- @return EventsBatchPostEvents
+function Models.EventsBatchPostEvents.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    event_type = fields.event_type,
+    labels = fields.labels,
+    occurred_at = fields.occurred_at,
+    payload = fields.payload,
+    payload_content_type = fields.payload_content_type,
+    deliver_at = fields.deliver_at,
+    event_id = fields.event_id,
+    event_type_version = fields.event_type_version,
+    expires_at = fields.expires_at,
+    metadata = fields.metadata,
+  }, Models.EventsBatchPostEvents)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventsBatchPostEvents
+function Models.EventsBatchPostEvents.from_json(value)
+  local fields = Runtime.as_fields(value, "EventsBatchPostEvents")
+  return Models.EventsBatchPostEvents.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    event_type = Runtime.read(fields, "event_type", Runtime.TEXT),
+    labels = Runtime.read(fields, "labels", Runtime.map(Runtime.TEXT)),
+    occurred_at = Runtime.read(fields, "occurred_at", Runtime.TEXT),
+    payload = Runtime.read(fields, "payload", Runtime.TEXT),
+    payload_content_type = Runtime.read(fields, "payload_content_type", Runtime.TEXT),
+    deliver_at = Runtime.maybe(fields, "deliver_at", Runtime.TEXT),
+    event_id = Runtime.maybe(fields, "event_id", Runtime.TEXT),
+    event_type_version = Runtime.maybe(fields, "event_type_version", Runtime.INTEGER),
+    expires_at = Runtime.maybe(fields, "expires_at", Runtime.TEXT),
+    metadata = Runtime.maybe(fields, "metadata", Runtime.map(Runtime.TEXT)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventsBatchPostEvents:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["event_type"] = self.event_type,
+    ["labels"] = Runtime.written_map(self.labels, Runtime.itself),
+    ["occurred_at"] = self.occurred_at,
+    ["payload"] = self.payload,
+    ["payload_content_type"] = self.payload_content_type,
+    ["deliver_at"] = self.deliver_at,
+    ["event_id"] = self.event_id,
+    ["event_type_version"] = self.event_type_version,
+    ["expires_at"] = self.expires_at,
+    ["metadata"] = Runtime.written_map(self.metadata, Runtime.itself),
+  })
+end
+
+--- The `EventsPerDayEntry` the API declares.
+Models.EventsPerDayEntry = {}
+Models.EventsPerDayEntry.__index = Models.EventsPerDayEntry
+Models.EventsPerDayEntry.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.amount integer carries `amount`.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.application_name string carries `application_name`.
+--- @param fields.date string carries `date`.
+--- @param fields.is_provisional boolean carries `is_provisional`.
+--- @return EventsPerDayEntry
+function Models.EventsPerDayEntry.new(fields)
+  return setmetatable({
+    amount = fields.amount,
+    application_id = fields.application_id,
+    application_name = fields.application_name,
+    date = fields.date,
+    is_provisional = fields.is_provisional,
+  }, Models.EventsPerDayEntry)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return EventsPerDayEntry
+function Models.EventsPerDayEntry.from_json(value)
+  local fields = Runtime.as_fields(value, "EventsPerDayEntry")
+  return Models.EventsPerDayEntry.new({
+    amount = Runtime.read(fields, "amount", Runtime.INTEGER),
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    application_name = Runtime.read(fields, "application_name", Runtime.TEXT),
+    date = Runtime.read(fields, "date", Runtime.TEXT),
+    is_provisional = Runtime.read(fields, "is_provisional", Runtime.BOOLEAN),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.EventsPerDayEntry:to_table()
+  return Runtime.document({
+    ["amount"] = self.amount,
+    ["application_id"] = self.application_id,
+    ["application_name"] = self.application_name,
+    ["date"] = self.date,
+    ["is_provisional"] = self.is_provisional,
+  })
+end
+
+--- The `Inbox` the API declares.
+Models.Inbox = {}
+Models.Inbox.__index = Models.Inbox
+Models.Inbox.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.messages InboxMessages[] carries `messages`.
+--- @param fields.next_cursor string|nil carries `next_cursor`: Pass it as `cursor` to the next pull to continue after
+---   the last message of this one
+--- @return Inbox
+function Models.Inbox.new(fields)
+  return setmetatable({
+    messages = fields.messages,
+    next_cursor = fields.next_cursor,
+  }, Models.Inbox)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return Inbox
+function Models.Inbox.from_json(value)
+  local fields = Runtime.as_fields(value, "Inbox")
+  return Models.Inbox.new({
+    messages = Runtime.read(fields, "messages", Runtime.list(Models.InboxMessages.from_json)),
+    next_cursor = Runtime.maybe(fields, "next_cursor", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.Inbox:to_table()
+  return Runtime.document({
+    ["messages"] = Runtime.written_list(self.messages, Runtime.written),
+    ["next_cursor"] = self.next_cursor,
+  })
+end
+
+--- The `InboxAck` the API declares.
+Models.InboxAck = {}
+Models.InboxAck.__index = Models.InboxAck
+Models.InboxAck.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.receipts string[] carries `receipts`: Receipts of the leased events
+--- @return InboxAck
+function Models.InboxAck.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    receipts = fields.receipts,
+  }, Models.InboxAck)
 end
 
 --- Read one out of what the API answered.
 --- @param value table the JSON document the API answered
---- @return Event
-function Models.Event.from_json(value)
-  local fields = Runtime.as_fields(value, "Event")
-  return Models.Event.new({
+--- @return InboxAck
+function Models.InboxAck.from_json(value)
+  local fields = Runtime.as_fields(value, "InboxAck")
+  return Models.InboxAck.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    receipts = Runtime.read(fields, "receipts", Runtime.list(Runtime.TEXT)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.InboxAck:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["receipts"] = Runtime.written_list(self.receipts, Runtime.itself),
+  })
+end
+
+--- The `InboxMessages` the API declares.
+Models.InboxMessages = {}
+Models.InboxMessages.__index = Models.InboxMessages
+Models.InboxMessages.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.event_id string carries `event_id`.
+--- @param fields.event_type_name string carries `event_type_name`.
+--- @param fields.labels any carries `labels`.
+--- @param fields.leased_until string carries `leased_until`: When the event is offered again if it was neither
+---   acknowledged nor rejected
+--- @param fields.occurred_at string carries `occurred_at`.
+--- @param fields.payload string carries `payload`: Base64-encoded
+--- @param fields.payload_content_type string carries `payload_content_type`.
+--- @param fields.receipt string carries `receipt`: Identifies this lease of the request attempt; a later lease of the
+---   same attempt gets another one
+--- @param fields.received_at string carries `received_at`.
+--- @param fields.request_attempt_id string carries `request_attempt_id`.
+--- @param fields.retry_count integer carries `retry_count`.
+--- @return InboxMessages
+function Models.InboxMessages.new(fields)
+  return setmetatable({
+    event_id = fields.event_id,
+    event_type_name = fields.event_type_name,
+    labels = fields.labels,
+    leased_until = fields.leased_until,
+    occurred_at = fields.occurred_at,
+    payload = fields.payload,
+    payload_content_type = fields.payload_content_type,
+    receipt = fields.receipt,
+    received_at = fields.received_at,
+    request_attempt_id = fields.request_attempt_id,
+    retry_count = fields.retry_count,
+  }, Models.InboxMessages)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return InboxMessages
+function Models.InboxMessages.from_json(value)
+  local fields = Runtime.as_fields(value, "InboxMessages")
+  return Models.InboxMessages.new({
     event_id = Runtime.read(fields, "event_id", Runtime.TEXT),
     event_type_name = Runtime.read(fields, "event_type_name", Runtime.TEXT),
-    ip = Runtime.read(fields, "ip", Runtime.TEXT),
     labels = Runtime.read(fields, "labels", Runtime.JSON_VALUE),
+    leased_until = Runtime.read(fields, "leased_until", Runtime.TEXT),
     occurred_at = Runtime.read(fields, "occurred_at", Runtime.TEXT),
+    payload = Runtime.read(fields, "payload", Runtime.TEXT),
     payload_content_type = Runtime.read(fields, "payload_content_type", Runtime.TEXT),
+    receipt = Runtime.read(fields, "receipt", Runtime.TEXT),
     received_at = Runtime.read(fields, "received_at", Runtime.TEXT),
-    metadata = Runtime.maybe(fields, "metadata", Runtime.JSON_VALUE),
+    request_attempt_id = Runtime.read(fields, "request_attempt_id", Runtime.TEXT),
+    retry_count = Runtime.read(fields, "retry_count", Runtime.INTEGER),
   })
 end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.Event:to_table()
+function Models.InboxMessages:to_table()
   return Runtime.document({
     ["event_id"] = self.event_id,
     ["event_type_name"] = self.event_type_name,
-    ["ip"] = self.ip,
     ["labels"] = self.labels,
+    ["leased_until"] = self.leased_until,
     ["occurred_at"] = self.occurred_at,
+    ["payload"] = self.payload,
     ["payload_content_type"] = self.payload_content_type,
+    ["receipt"] = self.receipt,
     ["received_at"] = self.received_at,
-    ["metadata"] = self.metadata,
+    ["request_attempt_id"] = self.request_attempt_id,
+    ["retry_count"] = self.retry_count,
   })
 end
 
---- The `EventPost` the API declares.
-Models.EventPost = {}
-Models.EventPost.__index = Models.EventPost
-Models.EventPost.__eq = Runtime.equality
+--- The `InboxNack` the API declares.
+Models.InboxNack = {}
+Models.InboxNack.__index = Models.InboxNack
+Models.InboxNack.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
---- @param fields.application_id string carries `application_id`: UUID of the application this event belongs to.
---- @param fields.event_type string carries `event_type`: The type of event (e.g., 'user.created', 'order.completed').
----   Length: 1-200 characters.
---- @param fields.labels table<string, string> carries `labels`: Labels for event filtering and routing to
----   subscriptions.
---- @param fields.occurred_at string carries `occurred_at`: Timestamp when the event occurred.
---- @param fields.payload string carries `payload`: The event payload. For binary content, use base64 encoding. Max
----   length: 699050 characters (512 KiB base64-encoded).
---- @param fields.payload_content_type string carries `payload_content_type`: Content type of the payload. Valid values:
----   text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
---- @param fields.event_id string|nil carries `event_id`: Optional unique identifier for this event (client-generated
----   UUID). If not provided, a UUIDv7 will be generated by the server.
---- @param fields.metadata table<string, string>|nil carries `metadata`: Optional metadata key-value pairs associated
----   with the event.
---- @return EventPost
-function Models.EventPost.new(fields)
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.receipts string[] carries `receipts`: Receipts of the leased events
+--- @param fields.retry boolean|nil carries `retry`: Whether the events are offered again, as new request attempts (true
+---   by default); they are not beyond the maximum number of retries of the subscription, or of this instance if it has
+---   none
+--- @param fields.retry_in_s integer|nil carries `retry_in_s`: Delay in seconds before the events are offered again (0
+---   by default)
+--- @return InboxNack
+function Models.InboxNack.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    receipts = fields.receipts,
+    retry = fields.retry,
+    retry_in_s = fields.retry_in_s,
+  }, Models.InboxNack)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return InboxNack
+function Models.InboxNack.from_json(value)
+  local fields = Runtime.as_fields(value, "InboxNack")
+  return Models.InboxNack.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    receipts = Runtime.read(fields, "receipts", Runtime.list(Runtime.TEXT)),
+    retry = Runtime.maybe(fields, "retry", Runtime.BOOLEAN),
+    retry_in_s = Runtime.maybe(fields, "retry_in_s", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.InboxNack:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["receipts"] = Runtime.written_list(self.receipts, Runtime.itself),
+    ["retry"] = self.retry,
+    ["retry_in_s"] = self.retry_in_s,
+  })
+end
+
+--- The `InboxSettlement` the API declares.
+Models.InboxSettlement = {}
+Models.InboxSettlement.__index = Models.InboxSettlement
+Models.InboxSettlement.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.settled string[] carries `settled`: Receipts of the events that were settled
+--- @param fields.stale string[] carries `stale`: Receipts that settled nothing: their lease expired, or the event was
+---   already settled
+--- @return InboxSettlement
+function Models.InboxSettlement.new(fields)
+  return setmetatable({
+    settled = fields.settled,
+    stale = fields.stale,
+  }, Models.InboxSettlement)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return InboxSettlement
+function Models.InboxSettlement.from_json(value)
+  local fields = Runtime.as_fields(value, "InboxSettlement")
+  return Models.InboxSettlement.new({
+    settled = Runtime.read(fields, "settled", Runtime.list(Runtime.TEXT)),
+    stale = Runtime.read(fields, "stale", Runtime.list(Runtime.TEXT)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.InboxSettlement:to_table()
+  return Runtime.document({
+    ["settled"] = Runtime.written_list(self.settled, Runtime.itself),
+    ["stale"] = Runtime.written_list(self.stale, Runtime.itself),
+  })
+end
+
+--- The `IngestedEvent` the API declares.
+Models.IngestedEvent = {}
+Models.IngestedEvent.__index = Models.IngestedEvent
+Models.IngestedEvent.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.event_id string carries `event_id`.
+--- @param fields.received_at string carries `received_at`.
+--- @param fields.deprecation IngestedEventDeprecation|nil carries `deprecation`: Present when the event was ingested
+---   with a deprecated version of its event type
+--- @return IngestedEvent
+function Models.IngestedEvent.new(fields)
   return setmetatable({
     application_id = fields.application_id,
-    event_type = fields.event_type,
-    labels = fields.labels,
-    occurred_at = fields.occurred_at,
-    payload = fields.payload,
-    payload_content_type = fields.payload_content_type,
     event_id = fields.event_id,
-    metadata = fields.metadata,
-  }, Models.EventPost)
+    received_at = fields.received_at,
+    deprecation = fields.deprecation,
+  }, Models.IngestedEvent)
 end
 
 --- Read one out of what the API answered.
 --- @param value table the JSON document the API answered
---- @return EventPost
-function Models.EventPost.from_json(value)
-  local fields = Runtime.as_fields(value, "EventPost")
-  return Models.EventPost.new({
+--- @return IngestedEvent
+function Models.IngestedEvent.from_json(value)
+  local fields = Runtime.as_fields(value, "IngestedEvent")
+  return Models.IngestedEvent.new({
     application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
-    event_type = Runtime.read(fields, "event_type", Runtime.TEXT),
-    labels = Runtime.read(fields, "labels", Runtime.map(Runtime.TEXT)),
-    occurred_at = Runtime.read(fields, "occurred_at", Runtime.TEXT),
-    payload = Runtime.read(fields, "payload", Runtime.TEXT),
-    payload_content_type = Runtime.read(fields, "payload_content_type", Runtime.TEXT),
-    event_id = Runtime.maybe(fields, "event_id", Runtime.TEXT),
-    metadata = Runtime.maybe(fields, "metadata", Runtime.map(Runtime.TEXT)),
+    event_id = Runtime.read(fields, "event_id", Runtime.TEXT),
+    received_at = Runtime.read(fields, "received_at", Runtime.TEXT),
+    deprecation = Runtime.maybe(fields, "deprecation", Models.IngestedEventDeprecation.from_json),
   })
 end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.EventPost:to_table()
+function Models.IngestedEvent:to_table()
   return Runtime.document({
     ["application_id"] = self.application_id,
-    ["event_type"] = self.event_type,
-    ["labels"] = Runtime.written_map(self.labels, Runtime.itself),
-    ["occurred_at"] = self.occurred_at,
-    ["payload"] = self.payload,
-    ["payload_content_type"] = self.payload_content_type,
     ["event_id"] = self.event_id,
-    ["metadata"] = Runtime.written_map(self.metadata, Runtime.itself),
+    ["received_at"] = self.received_at,
+    ["deprecation"] = Runtime.written(self.deprecation),
   })
 end
 
---- The `EventType` the API declares.
-Models.EventType = {}
-Models.EventType.__index = Models.EventType
-Models.EventType.__eq = Runtime.equality
+--- The `IngestedEventDeprecation` the API declares.
+Models.IngestedEventDeprecation = {}
+Models.IngestedEventDeprecation.__index = Models.IngestedEventDeprecation
+Models.IngestedEventDeprecation.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
---- @param fields.event_type_name string carries `event_type_name`.
---- @param fields.resource_type_name string carries `resource_type_name`.
---- @param fields.service_name string carries `service_name`.
---- @param fields.verb_name string carries `verb_name`.
---- @return EventType
-function Models.EventType.new(fields)
+--- @param fields.deprecated_at string carries `deprecated_at`.
+--- @param fields.event_type_version integer carries `event_type_version`.
+--- @param fields.sunset_at string|nil carries `sunset_at`.
+--- @return IngestedEventDeprecation
+function Models.IngestedEventDeprecation.new(fields)
   return setmetatable({
-    event_type_name = fields.event_type_name,
-    resource_type_name = fields.resource_type_name,
-    service_name = fields.service_name,
-    verb_name = fields.verb_name,
-  }, Models.EventType)
+    deprecated_at = fields.deprecated_at,
+    event_type_version = fields.event_type_version,
+    sunset_at = fields.sunset_at,
+  }, Models.IngestedEventDeprecation)
 end
 
 --- Read one out of what the API answered.
 --- @param value table the JSON document the API answered
---- @return EventType
-function Models.EventType.from_json(value)
-  local fields = Runtime.as_fields(value, "EventType")
-  return Models.EventType.new({
-    event_type_name = Runtime.read(fields, "event_type_name", Runtime.TEXT),
-    resource_type_name = Runtime.read(fields, "resource_type_name", Runtime.TEXT),
-    service_name = Runtime.read(fields, "service_name", Runtime.TEXT),
-    verb_name = Runtime.read(fields, "verb_name", Runtime.TEXT),
+--- @return IngestedEventDeprecation
+function Models.IngestedEventDeprecation.from_json(value)
+  local fields = Runtime.as_fields(value, "IngestedEventDeprecation")
+  return Models.IngestedEventDeprecation.new({
+    deprecated_at = Runtime.read(fields, "deprecated_at", Runtime.TEXT),
+    event_type_version = Runtime.read(fields, "event_type_version", Runtime.INTEGER),
+    sunset_at = Runtime.maybe(fields, "sunset_at", Runtime.TEXT),
   })
 end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.EventType:to_table()
+function Models.IngestedEventDeprecation:to_table()
   return Runtime.document({
-    ["event_type_name"] = self.event_type_name,
-    ["resource_type_name"] = self.resource_type_name,
-    ["service_name"] = self.service_name,
-    ["verb_name"] = self.verb_name,
+    ["deprecated_at"] = self.deprecated_at,
+    ["event_type_version"] = self.event_type_version,
+    ["sunset_at"] = self.sunset_at,
   })
 end
 
---- The `EventTypePost` the API declares.
-Models.EventTypePost = {}
-Models.EventTypePost.__index = Models.EventTypePost
-Models.EventTypePost.__eq = Runtime.equality
+--- The `IngestedEventsBatch` the API declares.
+Models.IngestedEventsBatch = {}
+Models.IngestedEventsBatch.__index = Models.IngestedEventsBatch
+Models.IngestedEventsBatch.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
 --- @param fields.application_id string carries `application_id`.
---- @param fields.resource_type string carries `resource_type`.
---- @param fields.service string carries `service`.
---- @param fields.verb string carries `verb`.
---- @return EventTypePost
-function Models.EventTypePost.new(fields)
+--- @param fields.events IngestedEventsBatchEvents[] carries `events`: One outcome per submitted event, in the order the
+---   events were submitted
+--- @return IngestedEventsBatch
+function Models.IngestedEventsBatch.new(fields)
   return setmetatable({
     application_id = fields.application_id,
-    resource_type = fields.resource_type,
-    service = fields.service,
-    verb = fields.verb,
-  }, Models.EventTypePost)
+    events = fields.events,
+  }, Models.IngestedEventsBatch)
 end
 
 --- Read one out of what the API answered.
 --- @param value table the JSON document the API answered
---- @return EventTypePost
-function Models.EventTypePost.from_json(value)
-  local fields = Runtime.as_fields(value, "EventTypePost")
-  return Models.EventTypePost.new({
+--- @return IngestedEventsBatch
+function Models.IngestedEventsBatch.from_json(value)
+  local fields = Runtime.as_fields(value, "IngestedEventsBatch")
+  return Models.IngestedEventsBatch.new({
     application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
-    resource_type = Runtime.read(fields, "resource_type", Runtime.TEXT),
-    service = Runtime.read(fields, "service", Runtime.TEXT),
-    verb = Runtime.read(fields, "verb", Runtime.TEXT),
+    events = Runtime.read(fields, "events", Runtime.list(Models.IngestedEventsBatchEvents.from_json)),
   })
 end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.EventTypePost:to_table()
+function Models.IngestedEventsBatch:to_table()
   return Runtime.document({
     ["application_id"] = self.application_id,
-    ["resource_type"] = self.resource_type,
-    ["service"] = self.service,
-    ["verb"] = self.verb,
+    ["events"] = Runtime.written_list(self.events, Runtime.written),
   })
 end
 
---- The `EventWithPayload` the API declares.
-Models.EventWithPayload = {}
-Models.EventWithPayload.__index = Models.EventWithPayload
-Models.EventWithPayload.__eq = Runtime.equality
+--- The `IngestedEventsBatchEvents` the API declares.
+Models.IngestedEventsBatchEvents = {}
+Models.IngestedEventsBatchEvents.__index = Models.IngestedEventsBatchEvents
+Models.IngestedEventsBatchEvents.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
---- @param fields.event_id string carries `event_id`.
---- @param fields.event_type_name string carries `event_type_name`.
---- @param fields.ip string carries `ip`.
---- @param fields.labels any carries `labels`.
---- @param fields.occurred_at string carries `occurred_at`.
---- @param fields.payload string carries `payload`.
---- @param fields.payload_content_type string carries `payload_content_type`.
---- @param fields.received_at string carries `received_at`.
---- @param fields.metadata any|nil carries `metadata`.
---- @return EventWithPayload
-function Models.EventWithPayload.new(fields)
+--- @param fields.status string carries `status`, one of `Models.IngestedEventsBatchEventsStatus.VALUES`.
+--- @param fields.deprecation IngestedEventsBatchEventsDeprecation|nil carries `deprecation`: Present when the event was
+---   ingested with a deprecated version of its event type
+--- @param fields.event_id string|nil carries `event_id`: ID of the ingested event, or the one the event was submitted
+---   with when it was not ingested
+--- @param fields.problem IngestedEventsBatchEventsProblem|nil carries `problem`: Present when the event was not
+---   ingested
+--- @param fields.received_at string|nil carries `received_at`: Present when the event was ingested
+--- @return IngestedEventsBatchEvents
+function Models.IngestedEventsBatchEvents.new(fields)
   return setmetatable({
+    status = fields.status,
+    deprecation = fields.deprecation,
     event_id = fields.event_id,
-    event_type_name = fields.event_type_name,
-    ip = fields.ip,
-    labels = fields.labels,
-    occurred_at = fields.occurred_at,
-    payload = fields.payload,
-    payload_content_type = fields.payload_content_type,
+    problem = fields.problem,
     received_at = fields.received_at,
-    metadata = fields.metadata,
-  }, Models.EventWithPayload)
+  }, Models.IngestedEventsBatchEvents)
 end
 
 --- Read one out of what the API answered.
 --- @param value table the JSON document the API answered
---- @return EventWithPayload
-function Models.EventWithPayload.from_json(value)
-  local fields = Runtime.as_fields(value, "EventWithPayload")
-  return Models.EventWithPayload.new({
-    event_id = Runtime.read(fields, "event_id", Runtime.TEXT),
-    event_type_name = Runtime.read(fields, "event_type_name", Runtime.TEXT),
-    ip = Runtime.read(fields, "ip", Runtime.TEXT),
-    labels = Runtime.read(fields, "labels", Runtime.JSON_VALUE),
-    occurred_at = Runtime.read(fields, "occurred_at", Runtime.TEXT),
-    payload = Runtime.read(fields, "payload", Runtime.TEXT),
-    payload_content_type = Runtime.read(fields, "payload_content_type", Runtime.TEXT),
-    received_at = Runtime.read(fields, "received_at", Runtime.TEXT),
-    metadata = Runtime.maybe(fields, "metadata", Runtime.JSON_VALUE),
+--- @return IngestedEventsBatchEvents
+function Models.IngestedEventsBatchEvents.from_json(value)
+  local fields = Runtime.as_fields(value, "IngestedEventsBatchEvents")
+  return Models.IngestedEventsBatchEvents.new({
+    status = Runtime.read(fields, "status", Runtime.member_of(Models.IngestedEventsBatchEventsStatus)),
+    deprecation = Runtime.maybe(fields, "deprecation", Models.IngestedEventsBatchEventsDeprecation.from_json),
+    event_id = Runtime.maybe(fields, "event_id", Runtime.TEXT),
+    problem = Runtime.maybe(fields, "problem", Models.IngestedEventsBatchEventsProblem.from_json),
+    received_at = Runtime.maybe(fields, "received_at", Runtime.TEXT),
   })
 end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.EventWithPayload:to_table()
+function Models.IngestedEventsBatchEvents:to_table()
   return Runtime.document({
+    ["status"] = self.status,
+    ["deprecation"] = Runtime.written(self.deprecation),
     ["event_id"] = self.event_id,
-    ["event_type_name"] = self.event_type_name,
-    ["ip"] = self.ip,
-    ["labels"] = self.labels,
-    ["occurred_at"] = self.occurred_at,
-    ["payload"] = self.payload,
-    ["payload_content_type"] = self.payload_content_type,
+    ["problem"] = Runtime.written(self.problem),
     ["received_at"] = self.received_at,
-    ["metadata"] = self.metadata,
   })
 end
 
---- The `EventsPerDayEntry` the API declares.
-Models.EventsPerDayEntry = {}
-Models.EventsPerDayEntry.__index = Models.EventsPerDayEntry
-Models.EventsPerDayEntry.__eq = Runtime.equality
+--- The `IngestedEventsBatchEventsDeprecation` the API declares.
+Models.IngestedEventsBatchEventsDeprecation = {}
+Models.IngestedEventsBatchEventsDeprecation.__index = Models.IngestedEventsBatchEventsDeprecation
+Models.IngestedEventsBatchEventsDeprecation.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
---- @param fields.amount integer carries `amount`.
---- @param fields.application_id string carries `application_id`.
---- @param fields.application_name string carries `application_name`.
---- @param fields.date string carries `date`.
---- @param fields.is_provisional boolean carries `is_provisional`.
---- @return EventsPerDayEntry
-function Models.EventsPerDayEntry.new(fields)
+--- @param fields.deprecated_at string carries `deprecated_at`.
+--- @param fields.event_type_version integer carries `event_type_version`.
+--- @param fields.sunset_at string|nil carries `sunset_at`.
+--- @return IngestedEventsBatchEventsDeprecation
+function Models.IngestedEventsBatchEventsDeprecation.new(fields)
   return setmetatable({
-    amount = fields.amount,
-    application_id = fields.application_id,
-    application_name = fields.application_name,
-    date = fields.date,
-    is_provisional = fields.is_provisional,
-  }, Models.EventsPerDayEntry)
+    deprecated_at = fields.deprecated_at,
+    event_type_version = fields.event_type_version,
+    sunset_at = fields.sunset_at,
+  }, Models.IngestedEventsBatchEventsDeprecation)
 end
 
 --- Read one out of what the API answered.
 --- @param value table the JSON document the API answered
---- @return EventsPerDayEntry
-function Models.EventsPerDayEntry.from_json(value)
-  local fields = Runtime.as_fields(value, "EventsPerDayEntry")
-  return Models.EventsPerDayEntry.new({
-    amount = Runtime.read(fields, "amount", Runtime.INTEGER),
-    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
-    application_name = Runtime.read(fields, "application_name", Runtime.TEXT),
-    date = Runtime.read(fields, "date", Runtime.TEXT),
-    is_provisional = Runtime.read(fields, "is_provisional", Runtime.BOOLEAN),
+--- @return IngestedEventsBatchEventsDeprecation
+function Models.IngestedEventsBatchEventsDeprecation.from_json(value)
+  local fields = Runtime.as_fields(value, "IngestedEventsBatchEventsDeprecation")
+  return Models.IngestedEventsBatchEventsDeprecation.new({
+    deprecated_at = Runtime.read(fields, "deprecated_at", Runtime.TEXT),
+    event_type_version = Runtime.read(fields, "event_type_version", Runtime.INTEGER),
+    sunset_at = Runtime.maybe(fields, "sunset_at", Runtime.TEXT),
   })
 end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.EventsPerDayEntry:to_table()
+function Models.IngestedEventsBatchEventsDeprecation:to_table()
   return Runtime.document({
-    ["amount"] = self.amount,
-    ["application_id"] = self.application_id,
-    ["application_name"] = self.application_name,
-    ["date"] = self.date,
-    ["is_provisional"] = self.is_provisional,
+    ["deprecated_at"] = self.deprecated_at,
+    ["event_type_version"] = self.event_type_version,
+    ["sunset_at"] = self.sunset_at,
   })
 end
 
---- The `IngestedEvent` the API declares.
-Models.IngestedEvent = {}
-Models.IngestedEvent.__index = Models.IngestedEvent
-Models.IngestedEvent.__eq = Runtime.equality
+--- The `IngestedEventsBatchEventsProblem` the API declares.
+Models.IngestedEventsBatchEventsProblem = {}
+Models.IngestedEventsBatchEventsProblem.__index = Models.IngestedEventsBatchEventsProblem
+Models.IngestedEventsBatchEventsProblem.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
---- @param fields.application_id string carries `application_id`.
---- @param fields.event_id string carries `event_id`.
---- @param fields.received_at string carries `received_at`.
---- @return IngestedEvent
-function Models.IngestedEvent.new(fields)
+--- @param fields.detail string carries `detail`.
+--- @param fields.id string carries `id`, one of `Models.IngestedEventsBatchEventsProblemId.VALUES`: Identifier of the
+---   problem that occurred, stable across releases
+--- @param fields.status integer carries `status`.
+--- @param fields.title string carries `title`.
+--- @param fields.type string carries `type`: Documentation page of this problem, which is also what distinguishes one
+---   problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a URL.
+--- @param fields.validation any|nil carries `validation`: Which submitted values were rejected and why, keyed by the
+---   name of the field they were submitted under. Carried by validation failures (`Validation`, status 422) and `null`
+---   for every other problem, as
+--- @return IngestedEventsBatchEventsProblem
+function Models.IngestedEventsBatchEventsProblem.new(fields)
   return setmetatable({
-    application_id = fields.application_id,
-    event_id = fields.event_id,
-    received_at = fields.received_at,
-  }, Models.IngestedEvent)
+    detail = fields.detail,
+    id = fields.id,
+    status = fields.status,
+    title = fields.title,
+    type = fields.type,
+    validation = fields.validation,
+  }, Models.IngestedEventsBatchEventsProblem)
 end
 
 --- Read one out of what the API answered.
 --- @param value table the JSON document the API answered
---- @return IngestedEvent
-function Models.IngestedEvent.from_json(value)
-  local fields = Runtime.as_fields(value, "IngestedEvent")
-  return Models.IngestedEvent.new({
-    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
-    event_id = Runtime.read(fields, "event_id", Runtime.TEXT),
-    received_at = Runtime.read(fields, "received_at", Runtime.TEXT),
+--- @return IngestedEventsBatchEventsProblem
+function Models.IngestedEventsBatchEventsProblem.from_json(value)
+  local fields = Runtime.as_fields(value, "IngestedEventsBatchEventsProblem")
+  return Models.IngestedEventsBatchEventsProblem.new({
+    detail = Runtime.read(fields, "detail", Runtime.TEXT),
+    id = Runtime.read(fields, "id", Runtime.member_of(Models.IngestedEventsBatchEventsProblemId)),
+    status = Runtime.read(fields, "status", Runtime.INTEGER),
+    title = Runtime.read(fields, "title", Runtime.TEXT),
+    type = Runtime.read(fields, "type", Runtime.TEXT),
+    validation = Runtime.maybe(fields, "validation", Runtime.JSON_VALUE),
   })
 end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.IngestedEvent:to_table()
+function Models.IngestedEventsBatchEventsProblem:to_table()
   return Runtime.document({
-    ["application_id"] = self.application_id,
-    ["event_id"] = self.event_id,
-    ["received_at"] = self.received_at,
+    ["detail"] = self.detail,
+    ["id"] = self.id,
+    ["status"] = self.status,
+    ["title"] = self.title,
+    ["type"] = self.type,
+    ["validation"] = self.validation,
   })
 end
 
@@ -1104,6 +3182,186 @@ function Models.InstanceConfigMatomo:to_table()
   })
 end
 
+--- The `JsonWebKeySet` the API declares.
+Models.JsonWebKeySet = {}
+Models.JsonWebKeySet.__index = Models.JsonWebKeySet
+Models.JsonWebKeySet.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.keys JsonWebKeySetKeys[] carries `keys`.
+--- @return JsonWebKeySet
+function Models.JsonWebKeySet.new(fields)
+  return setmetatable({
+    keys = fields.keys,
+  }, Models.JsonWebKeySet)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return JsonWebKeySet
+function Models.JsonWebKeySet.from_json(value)
+  local fields = Runtime.as_fields(value, "JsonWebKeySet")
+  return Models.JsonWebKeySet.new({
+    keys = Runtime.read(fields, "keys", Runtime.list(Models.JsonWebKeySetKeys.from_json)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.JsonWebKeySet:to_table()
+  return Runtime.document({
+    ["keys"] = Runtime.written_list(self.keys, Runtime.written),
+  })
+end
+
+--- The `JsonWebKeySetKeys` the API declares.
+Models.JsonWebKeySetKeys = {}
+Models.JsonWebKeySetKeys.__index = Models.JsonWebKeySetKeys
+Models.JsonWebKeySetKeys.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.alg string carries `alg`: Always `EdDSA`
+--- @param fields.crv string carries `crv`: Always `Ed25519`
+--- @param fields.kid string carries `kid`: ID of the signing key, which signatures name in their `k` part
+--- @param fields.kty string carries `kty`: Always `OKP`
+--- @param fields.use string carries `use`: Always `sig`
+--- @param fields.x string carries `x`: Public key, encoded in base64url without padding
+--- @return JsonWebKeySetKeys
+function Models.JsonWebKeySetKeys.new(fields)
+  return setmetatable({
+    alg = fields.alg,
+    crv = fields.crv,
+    kid = fields.kid,
+    kty = fields.kty,
+    use = fields.use,
+    x = fields.x,
+  }, Models.JsonWebKeySetKeys)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return JsonWebKeySetKeys
+function Models.JsonWebKeySetKeys.from_json(value)
+  local fields = Runtime.as_fields(value, "JsonWebKeySetKeys")
+  return Models.JsonWebKeySetKeys.new({
+    alg = Runtime.read(fields, "alg", Runtime.TEXT),
+    crv = Runtime.read(fields, "crv", Runtime.TEXT),
+    kid = Runtime.read(fields, "kid", Runtime.TEXT),
+    kty = Runtime.read(fields, "kty", Runtime.TEXT),
+    use = Runtime.read(fields, "use", Runtime.TEXT),
+    x = Runtime.read(fields, "x", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.JsonWebKeySetKeys:to_table()
+  return Runtime.document({
+    ["alg"] = self.alg,
+    ["crv"] = self.crv,
+    ["kid"] = self.kid,
+    ["kty"] = self.kty,
+    ["use"] = self.use,
+    ["x"] = self.x,
+  })
+end
+
+--- The `OAuth2ClientCredentials` the API declares.
+Models.Oauth2ClientCredentials = {}
+Models.Oauth2ClientCredentials.__index = Models.Oauth2ClientCredentials
+Models.Oauth2ClientCredentials.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.client_id string carries `client_id`.
+--- @param fields.created_at string carries `created_at`.
+--- @param fields.scopes string[] carries `scopes`.
+--- @param fields.subscription_id string carries `subscription_id`.
+--- @param fields.token_url string carries `token_url`.
+--- @param fields.updated_at string carries `updated_at`.
+--- @return Oauth2ClientCredentials
+function Models.Oauth2ClientCredentials.new(fields)
+  return setmetatable({
+    client_id = fields.client_id,
+    created_at = fields.created_at,
+    scopes = fields.scopes,
+    subscription_id = fields.subscription_id,
+    token_url = fields.token_url,
+    updated_at = fields.updated_at,
+  }, Models.Oauth2ClientCredentials)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return Oauth2ClientCredentials
+function Models.Oauth2ClientCredentials.from_json(value)
+  local fields = Runtime.as_fields(value, "OAuth2ClientCredentials")
+  return Models.Oauth2ClientCredentials.new({
+    client_id = Runtime.read(fields, "client_id", Runtime.TEXT),
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    scopes = Runtime.read(fields, "scopes", Runtime.list(Runtime.TEXT)),
+    subscription_id = Runtime.read(fields, "subscription_id", Runtime.TEXT),
+    token_url = Runtime.read(fields, "token_url", Runtime.TEXT),
+    updated_at = Runtime.read(fields, "updated_at", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.Oauth2ClientCredentials:to_table()
+  return Runtime.document({
+    ["client_id"] = self.client_id,
+    ["created_at"] = self.created_at,
+    ["scopes"] = Runtime.written_list(self.scopes, Runtime.itself),
+    ["subscription_id"] = self.subscription_id,
+    ["token_url"] = self.token_url,
+    ["updated_at"] = self.updated_at,
+  })
+end
+
+--- The `OAuth2ClientCredentialsPut` the API declares.
+Models.Oauth2ClientCredentialsPut = {}
+Models.Oauth2ClientCredentialsPut.__index = Models.Oauth2ClientCredentialsPut
+Models.Oauth2ClientCredentialsPut.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.client_id string carries `client_id`.
+--- @param fields.client_secret string carries `client_secret`.
+--- @param fields.token_url string carries `token_url`: Token endpoint of the authorization server
+--- @param fields.scopes string[]|nil carries `scopes`: Scopes to request; none by default
+--- @return Oauth2ClientCredentialsPut
+function Models.Oauth2ClientCredentialsPut.new(fields)
+  return setmetatable({
+    client_id = fields.client_id,
+    client_secret = fields.client_secret,
+    token_url = fields.token_url,
+    scopes = fields.scopes,
+  }, Models.Oauth2ClientCredentialsPut)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return Oauth2ClientCredentialsPut
+function Models.Oauth2ClientCredentialsPut.from_json(value)
+  local fields = Runtime.as_fields(value, "OAuth2ClientCredentialsPut")
+  return Models.Oauth2ClientCredentialsPut.new({
+    client_id = Runtime.read(fields, "client_id", Runtime.TEXT),
+    client_secret = Runtime.read(fields, "client_secret", Runtime.TEXT),
+    token_url = Runtime.read(fields, "token_url", Runtime.TEXT),
+    scopes = Runtime.maybe(fields, "scopes", Runtime.list(Runtime.TEXT)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.Oauth2ClientCredentialsPut:to_table()
+  return Runtime.document({
+    ["client_id"] = self.client_id,
+    ["client_secret"] = self.client_secret,
+    ["token_url"] = self.token_url,
+    ["scopes"] = Runtime.written_list(self.scopes, Runtime.itself),
+  })
+end
+
 --- The `Organization` the API declares.
 Models.Organization = {}
 Models.Organization.__index = Models.Organization
@@ -1519,6 +3777,74 @@ function Models.Problem:to_table()
   })
 end
 
+--- The `PurgeDeadLetters` the API declares.
+Models.PurgeDeadLetters = {}
+Models.PurgeDeadLetters.__index = Models.PurgeDeadLetters
+Models.PurgeDeadLetters.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.subscription_id string|nil carries `subscription_id`: Only purge the dead letters of this subscription
+--- @return PurgeDeadLetters
+function Models.PurgeDeadLetters.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    subscription_id = fields.subscription_id,
+  }, Models.PurgeDeadLetters)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return PurgeDeadLetters
+function Models.PurgeDeadLetters.from_json(value)
+  local fields = Runtime.as_fields(value, "PurgeDeadLetters")
+  return Models.PurgeDeadLetters.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    subscription_id = Runtime.maybe(fields, "subscription_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.PurgeDeadLetters:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["subscription_id"] = self.subscription_id,
+  })
+end
+
+--- The `PurgedDeadLetters` the API declares.
+Models.PurgedDeadLetters = {}
+Models.PurgedDeadLetters.__index = Models.PurgedDeadLetters
+Models.PurgedDeadLetters.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.purged_count integer carries `purged_count`.
+--- @return PurgedDeadLetters
+function Models.PurgedDeadLetters.new(fields)
+  return setmetatable({
+    purged_count = fields.purged_count,
+  }, Models.PurgedDeadLetters)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return PurgedDeadLetters
+function Models.PurgedDeadLetters.from_json(value)
+  local fields = Runtime.as_fields(value, "PurgedDeadLetters")
+  return Models.PurgedDeadLetters.new({
+    purged_count = Runtime.read(fields, "purged_count", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.PurgedDeadLetters:to_table()
+  return Runtime.document({
+    ["purged_count"] = self.purged_count,
+  })
+end
+
 --- The `QuotasResponse` the API declares.
 Models.QuotasResponse = {}
 Models.QuotasResponse.__index = Models.QuotasResponse
@@ -1618,14 +3944,119 @@ end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.QuotasResponseLimits:to_table()
+function Models.QuotasResponseLimits:to_table()
+  return Runtime.document({
+    ["global_applications_per_organization_limit"] = self.global_applications_per_organization_limit,
+    ["global_days_of_events_retention_limit"] = self.global_days_of_events_retention_limit,
+    ["global_event_types_per_application_limit"] = self.global_event_types_per_application_limit,
+    ["global_events_per_day_limit"] = self.global_events_per_day_limit,
+    ["global_members_per_organization_limit"] = self.global_members_per_organization_limit,
+    ["global_subscriptions_per_application_limit"] = self.global_subscriptions_per_application_limit,
+  })
+end
+
+--- The `RedeliverDeadLetter` the API declares.
+Models.RedeliverDeadLetter = {}
+Models.RedeliverDeadLetter.__index = Models.RedeliverDeadLetter
+Models.RedeliverDeadLetter.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @return RedeliverDeadLetter
+function Models.RedeliverDeadLetter.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+  }, Models.RedeliverDeadLetter)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return RedeliverDeadLetter
+function Models.RedeliverDeadLetter.from_json(value)
+  local fields = Runtime.as_fields(value, "RedeliverDeadLetter")
+  return Models.RedeliverDeadLetter.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.RedeliverDeadLetter:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+  })
+end
+
+--- The `RedeliverDeadLetters` the API declares.
+Models.RedeliverDeadLetters = {}
+Models.RedeliverDeadLetters.__index = Models.RedeliverDeadLetters
+Models.RedeliverDeadLetters.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.subscription_id string|nil carries `subscription_id`: Only redeliver the dead letters of this
+---   subscription
+--- @return RedeliverDeadLetters
+function Models.RedeliverDeadLetters.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    subscription_id = fields.subscription_id,
+  }, Models.RedeliverDeadLetters)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return RedeliverDeadLetters
+function Models.RedeliverDeadLetters.from_json(value)
+  local fields = Runtime.as_fields(value, "RedeliverDeadLetters")
+  return Models.RedeliverDeadLetters.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    subscription_id = Runtime.maybe(fields, "subscription_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.RedeliverDeadLetters:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["subscription_id"] = self.subscription_id,
+  })
+end
+
+--- The `RedeliveredDeadLetter` the API declares.
+Models.RedeliveredDeadLetter = {}
+Models.RedeliveredDeadLetter.__index = Models.RedeliveredDeadLetter
+Models.RedeliveredDeadLetter.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.dead_letter_id string carries `dead_letter_id`.
+--- @param fields.request_attempt_id string carries `request_attempt_id`.
+--- @return RedeliveredDeadLetter
+function Models.RedeliveredDeadLetter.new(fields)
+  return setmetatable({
+    dead_letter_id = fields.dead_letter_id,
+    request_attempt_id = fields.request_attempt_id,
+  }, Models.RedeliveredDeadLetter)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return RedeliveredDeadLetter
+function Models.RedeliveredDeadLetter.from_json(value)
+  local fields = Runtime.as_fields(value, "RedeliveredDeadLetter")
+  return Models.RedeliveredDeadLetter.new({
+    dead_letter_id = Runtime.read(fields, "dead_letter_id", Runtime.TEXT),
+    request_attempt_id = Runtime.read(fields, "request_attempt_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.RedeliveredDeadLetter:to_table()
   return Runtime.document({
-    ["global_applications_per_organization_limit"] = self.global_applications_per_organization_limit,
-    ["global_days_of_events_retention_limit"] = self.global_days_of_events_retention_limit,
-    ["global_event_types_per_application_limit"] = self.global_event_types_per_application_limit,
-    ["global_events_per_day_limit"] = self.global_events_per_day_limit,
-    ["global_members_per_organization_limit"] = self.global_members_per_organization_limit,
-    ["global_subscriptions_per_application_limit"] = self.global_subscriptions_per_application_limit,
+    ["dead_letter_id"] = self.dead_letter_id,
+    ["request_attempt_id"] = self.request_attempt_id,
   })
 end
 
@@ -1676,7 +4107,11 @@ Models.RequestAttempt.__eq = Runtime.equality
 ---   the status variant. - waiting: {type, since, until} - Scheduled for future delivery - pending: {type, since} -
 ---   Ready to be processed - in_progre
 --- @param fields.subscription RequestAttemptSubscription carries `subscription`.
+--- @param fields.dead_letter_id string|nil carries `dead_letter_id`: Dead letter the request attempt became, when it
+---   was the last attempt of a delivery an output worker gave up on
 --- @param fields.delay_until string|nil carries `delay_until`.
+--- @param fields.delivery_batch_id string|nil carries `delivery_batch_id`: Batch the request attempt was delivered in,
+---   shared with the other request attempts of the batch and their response
 --- @param fields.failed_at string|nil carries `failed_at`.
 --- @param fields.http_response_status integer|nil carries `http_response_status`.
 --- @param fields.picked_at string|nil carries `picked_at`.
@@ -1692,7 +4127,9 @@ function Models.RequestAttempt.new(fields)
     retry_count = fields.retry_count,
     status = fields.status,
     subscription = fields.subscription,
+    dead_letter_id = fields.dead_letter_id,
     delay_until = fields.delay_until,
+    delivery_batch_id = fields.delivery_batch_id,
     failed_at = fields.failed_at,
     http_response_status = fields.http_response_status,
     picked_at = fields.picked_at,
@@ -1714,7 +4151,9 @@ function Models.RequestAttempt.from_json(value)
     retry_count = Runtime.read(fields, "retry_count", Runtime.INTEGER),
     status = Runtime.read(fields, "status", Models.RequestAttemptStatus.from_json),
     subscription = Runtime.read(fields, "subscription", Models.RequestAttemptSubscription.from_json),
+    dead_letter_id = Runtime.maybe(fields, "dead_letter_id", Runtime.TEXT),
     delay_until = Runtime.maybe(fields, "delay_until", Runtime.TEXT),
+    delivery_batch_id = Runtime.maybe(fields, "delivery_batch_id", Runtime.TEXT),
     failed_at = Runtime.maybe(fields, "failed_at", Runtime.TEXT),
     http_response_status = Runtime.maybe(fields, "http_response_status", Runtime.INTEGER),
     picked_at = Runtime.maybe(fields, "picked_at", Runtime.TEXT),
@@ -1734,7 +4173,9 @@ function Models.RequestAttempt:to_table()
     ["retry_count"] = self.retry_count,
     ["status"] = Runtime.written(self.status),
     ["subscription"] = Runtime.written(self.subscription),
+    ["dead_letter_id"] = self.dead_letter_id,
     ["delay_until"] = self.delay_until,
+    ["delivery_batch_id"] = self.delivery_batch_id,
     ["failed_at"] = self.failed_at,
     ["http_response_status"] = self.http_response_status,
     ["picked_at"] = self.picked_at,
@@ -1786,10 +4227,15 @@ Models.RequestAttemptStatus.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
 --- @param fields.type string carries `type`, one of `Models.RequestAttemptStatusType.VALUES`: Status type
----   discriminator. One of: waiting, pending, in_progress, successful, failed
---- @param fields.at string|nil carries `at`: Timestamp when completed (present in successful, failed)
+---   discriminator. One of: waiting, pending, in_progress, successful, failed, expired, cancelled
+--- @param fields.at string|nil carries `at`: Timestamp when completed (present in successful, failed, expired,
+---   cancelled)
 --- @param fields.full_processing_ms integer|nil carries `full_processing_ms`: Total processing time in milliseconds
 ---   (present in successful, failed)
+--- @param fields.rule_outcome string|nil carries `rule_outcome`, one of
+---   `Models.RequestAttemptStatusRuleOutcome.VALUES`: Outcome given by a response rule of the subscription, when one
+---   matched the response (present in successful, failed). One of: success, retryable, permanent_failure,
+---   disable_subscription
 --- @param fields.since string|nil carries `since`: Timestamp when the status started (present in waiting, pending,
 ---   in_progress)
 --- @param fields.until_ string|nil carries `until`: Timestamp until which waiting (only present in waiting status)
@@ -1799,6 +4245,7 @@ function Models.RequestAttemptStatus.new(fields)
     type = fields.type,
     at = fields.at,
     full_processing_ms = fields.full_processing_ms,
+    rule_outcome = fields.rule_outcome,
     since = fields.since,
     until_ = fields.until_,
   }, Models.RequestAttemptStatus)
@@ -1813,6 +4260,7 @@ function Models.RequestAttemptStatus.from_json(value)
     type = Runtime.read(fields, "type", Runtime.member_of(Models.RequestAttemptStatusType)),
     at = Runtime.maybe(fields, "at", Runtime.TEXT),
     full_processing_ms = Runtime.maybe(fields, "full_processing_ms", Runtime.INTEGER),
+    rule_outcome = Runtime.maybe(fields, "rule_outcome", Runtime.member_of(Models.RequestAttemptStatusRuleOutcome)),
     since = Runtime.maybe(fields, "since", Runtime.TEXT),
     until_ = Runtime.maybe(fields, "until", Runtime.TEXT),
   })
@@ -1825,6 +4273,7 @@ function Models.RequestAttemptStatus:to_table()
     ["type"] = self.type,
     ["at"] = self.at,
     ["full_processing_ms"] = self.full_processing_ms,
+    ["rule_outcome"] = self.rule_outcome,
     ["since"] = self.since,
     ["until"] = self.until_,
   })
@@ -1918,6 +4367,115 @@ function Models.Response:to_table()
   })
 end
 
+--- The `RetryRequestAttempt` the API declares.
+Models.RetryRequestAttempt = {}
+Models.RetryRequestAttempt.__index = Models.RetryRequestAttempt
+Models.RetryRequestAttempt.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @return RetryRequestAttempt
+function Models.RetryRequestAttempt.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+  }, Models.RetryRequestAttempt)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return RetryRequestAttempt
+function Models.RetryRequestAttempt.from_json(value)
+  local fields = Runtime.as_fields(value, "RetryRequestAttempt")
+  return Models.RetryRequestAttempt.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.RetryRequestAttempt:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+  })
+end
+
+--- The `SecretRotation` the API declares.
+Models.SecretRotation = {}
+Models.SecretRotation.__index = Models.SecretRotation
+Models.SecretRotation.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.previous_secret string carries `previous_secret`: The secret the new one replaced
+--- @param fields.previous_secret_expires_at string carries `previous_secret_expires_at`.
+--- @param fields.secret string carries `secret`: The new secret of the subscription
+--- @param fields.subscription_id string carries `subscription_id`.
+--- @return SecretRotation
+function Models.SecretRotation.new(fields)
+  return setmetatable({
+    previous_secret = fields.previous_secret,
+    previous_secret_expires_at = fields.previous_secret_expires_at,
+    secret = fields.secret,
+    subscription_id = fields.subscription_id,
+  }, Models.SecretRotation)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SecretRotation
+function Models.SecretRotation.from_json(value)
+  local fields = Runtime.as_fields(value, "SecretRotation")
+  return Models.SecretRotation.new({
+    previous_secret = Runtime.read(fields, "previous_secret", Runtime.TEXT),
+    previous_secret_expires_at = Runtime.read(fields, "previous_secret_expires_at", Runtime.TEXT),
+    secret = Runtime.read(fields, "secret", Runtime.TEXT),
+    subscription_id = Runtime.read(fields, "subscription_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SecretRotation:to_table()
+  return Runtime.document({
+    ["previous_secret"] = self.previous_secret,
+    ["previous_secret_expires_at"] = self.previous_secret_expires_at,
+    ["secret"] = self.secret,
+    ["subscription_id"] = self.subscription_id,
+  })
+end
+
+--- The `SecretRotationPost` the API declares.
+Models.SecretRotationPost = {}
+Models.SecretRotationPost.__index = Models.SecretRotationPost
+Models.SecretRotationPost.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.overlap_seconds integer|nil carries `overlap_seconds`: How long the previous secret stays valid, in
+---   seconds (one day by default)
+--- @return SecretRotationPost
+function Models.SecretRotationPost.new(fields)
+  return setmetatable({
+    overlap_seconds = fields.overlap_seconds,
+  }, Models.SecretRotationPost)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SecretRotationPost
+function Models.SecretRotationPost.from_json(value)
+  local fields = Runtime.as_fields(value, "SecretRotationPost")
+  return Models.SecretRotationPost.new({
+    overlap_seconds = Runtime.maybe(fields, "overlap_seconds", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SecretRotationPost:to_table()
+  return Runtime.document({
+    ["overlap_seconds"] = self.overlap_seconds,
+  })
+end
+
 --- The `ServiceToken` the API declares.
 Models.ServiceToken = {}
 Models.ServiceToken.__index = Models.ServiceToken
@@ -1998,6 +4556,89 @@ function Models.ServiceTokenPost:to_table()
   })
 end
 
+--- The `SigningKey` the API declares.
+Models.SigningKey = {}
+Models.SigningKey.__index = Models.SigningKey
+Models.SigningKey.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @param fields.created_at string carries `created_at`.
+--- @param fields.is_current boolean carries `is_current`: Whether deliveries are signed with this key, which only the
+---   newest key of the application is
+--- @param fields.public_key string carries `public_key`: Public key, encoded in base64url without padding like the `x`
+---   member of its JSON Web Key
+--- @param fields.signing_key_id string carries `signing_key_id`: Identifies the key in the `k` part of signatures and
+---   as the `kid` of its JSON Web Key
+--- @return SigningKey
+function Models.SigningKey.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+    created_at = fields.created_at,
+    is_current = fields.is_current,
+    public_key = fields.public_key,
+    signing_key_id = fields.signing_key_id,
+  }, Models.SigningKey)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SigningKey
+function Models.SigningKey.from_json(value)
+  local fields = Runtime.as_fields(value, "SigningKey")
+  return Models.SigningKey.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    is_current = Runtime.read(fields, "is_current", Runtime.BOOLEAN),
+    public_key = Runtime.read(fields, "public_key", Runtime.TEXT),
+    signing_key_id = Runtime.read(fields, "signing_key_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SigningKey:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["created_at"] = self.created_at,
+    ["is_current"] = self.is_current,
+    ["public_key"] = self.public_key,
+    ["signing_key_id"] = self.signing_key_id,
+  })
+end
+
+--- The `SigningKeyPost` the API declares.
+Models.SigningKeyPost = {}
+Models.SigningKeyPost.__index = Models.SigningKeyPost
+Models.SigningKeyPost.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.application_id string carries `application_id`.
+--- @return SigningKeyPost
+function Models.SigningKeyPost.new(fields)
+  return setmetatable({
+    application_id = fields.application_id,
+  }, Models.SigningKeyPost)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SigningKeyPost
+function Models.SigningKeyPost.from_json(value)
+  local fields = Runtime.as_fields(value, "SigningKeyPost")
+  return Models.SigningKeyPost.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SigningKeyPost:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+  })
+end
+
 --- The `Subscription` the API declares.
 Models.Subscription = {}
 Models.Subscription.__index = Models.Subscription
@@ -2007,6 +4648,8 @@ Models.Subscription.__eq = Runtime.equality
 --- @param fields.application_id string carries `application_id`.
 --- @param fields.created_at string carries `created_at`.
 --- @param fields.dedicated_workers string[] carries `dedicated_workers`.
+--- @param fields.event_type_versions table<string, integer> carries `event_type_versions`: Versions the subscription is
+---   pinned to, by event type
 --- @param fields.event_types string[] carries `event_types`.
 --- @param fields.is_enabled boolean carries `is_enabled`.
 --- @param fields.label_key string carries `label_key`: _Kept for backward compatibility, you should use `labels`_
@@ -2014,16 +4657,43 @@ Models.Subscription.__eq = Runtime.equality
 --- @param fields.labels table<string, string> carries `labels`.
 --- @param fields.metadata table<string, string> carries `metadata`.
 --- @param fields.secret string carries `secret`.
+--- @param fields.signature_scheme string carries `signature_scheme`, one of
+---   `Models.SubscriptionSignatureScheme.VALUES`.
 --- @param fields.subscription_id string carries `subscription_id`.
 --- @param fields.target SubscriptionTarget carries `target`.
 --- @param fields.updated_at string carries `updated_at`.
+--- @param fields.auto_disabled_at string|nil carries `auto_disabled_at`: When the circuit breaker or a response rule
+---   last disabled the subscription; cleared when it is enabled again
+--- @param fields.auto_disabled_reason string|nil carries `auto_disabled_reason`: Why the circuit breaker or a response
+---   rule last disabled the subscription
+--- @param fields.batching SubscriptionBatching|nil carries `batching`: Deliver the events of the subscription in
+---   batches: one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or once
+---   its oldest event has waited `max_wait_s` se
+--- @param fields.circuit_breaker SubscriptionCircuitBreaker|nil carries `circuit_breaker`: Disables the subscription
+---   once its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set
+--- @param fields.delivery_limits SubscriptionDeliveryLimits|nil carries `delivery_limits`: Limits on how fast
+---   deliveries of the subscription start; deliveries over them wait for their turn, which does not count as a failure;
+---   at least one limit must be set
 --- @param fields.description string|nil carries `description`.
+--- @param fields.filter any|nil carries `filter`: An object with exactly one of: `and` or `or` (a list of filters),
+---   `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload); `label`
+---   and `field` go alon
+--- @param fields.ordering_key SubscriptionOrderingKey|nil carries `ordering_key`: Where the key of an event comes from,
+---   to deliver the events that share a key one at a time and in the order they were received; exactly one of `label`
+---   or `field` must be set
+--- @param fields.payload_transform SubscriptionPayloadTransform|nil carries `payload_transform`: Declarative reshaping
+---   of an `application/json` event payload, applied by the worker before the request is signed and sent; operations
+---   are applied in order (`fields`, `envelope`, `constants`) and a pay
+--- @param fields.response_rules SubscriptionResponseRules[]|nil carries `response_rules`.
+--- @param fields.retry_policy SubscriptionRetryPolicy|nil carries `retry_policy`: Retry schedule of the subscription,
+---   replacing the one of the instance within its limits; every field left out keeps the value of the instance
 --- @return Subscription
 function Models.Subscription.new(fields)
   return setmetatable({
     application_id = fields.application_id,
     created_at = fields.created_at,
     dedicated_workers = fields.dedicated_workers,
+    event_type_versions = fields.event_type_versions,
     event_types = fields.event_types,
     is_enabled = fields.is_enabled,
     label_key = fields.label_key,
@@ -2031,54 +4701,322 @@ function Models.Subscription.new(fields)
     labels = fields.labels,
     metadata = fields.metadata,
     secret = fields.secret,
+    signature_scheme = fields.signature_scheme,
     subscription_id = fields.subscription_id,
     target = fields.target,
     updated_at = fields.updated_at,
+    auto_disabled_at = fields.auto_disabled_at,
+    auto_disabled_reason = fields.auto_disabled_reason,
+    batching = fields.batching,
+    circuit_breaker = fields.circuit_breaker,
+    delivery_limits = fields.delivery_limits,
     description = fields.description,
+    filter = fields.filter,
+    ordering_key = fields.ordering_key,
+    payload_transform = fields.payload_transform,
+    response_rules = fields.response_rules,
+    retry_policy = fields.retry_policy,
   }, Models.Subscription)
 end
 
 --- Read one out of what the API answered.
 --- @param value table the JSON document the API answered
---- @return Subscription
-function Models.Subscription.from_json(value)
-  local fields = Runtime.as_fields(value, "Subscription")
-  return Models.Subscription.new({
-    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
-    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
-    dedicated_workers = Runtime.read(fields, "dedicated_workers", Runtime.list(Runtime.TEXT)),
-    event_types = Runtime.read(fields, "event_types", Runtime.list(Runtime.TEXT)),
-    is_enabled = Runtime.read(fields, "is_enabled", Runtime.BOOLEAN),
-    label_key = Runtime.read(fields, "label_key", Runtime.TEXT),
-    label_value = Runtime.read(fields, "label_value", Runtime.TEXT),
-    labels = Runtime.read(fields, "labels", Runtime.map(Runtime.TEXT)),
-    metadata = Runtime.read(fields, "metadata", Runtime.map(Runtime.TEXT)),
-    secret = Runtime.read(fields, "secret", Runtime.TEXT),
-    subscription_id = Runtime.read(fields, "subscription_id", Runtime.TEXT),
-    target = Runtime.read(fields, "target", Models.SubscriptionTarget.from_json),
-    updated_at = Runtime.read(fields, "updated_at", Runtime.TEXT),
-    description = Runtime.maybe(fields, "description", Runtime.TEXT),
+--- @return Subscription
+function Models.Subscription.from_json(value)
+  local fields = Runtime.as_fields(value, "Subscription")
+  return Models.Subscription.new({
+    application_id = Runtime.read(fields, "application_id", Runtime.TEXT),
+    created_at = Runtime.read(fields, "created_at", Runtime.TEXT),
+    dedicated_workers = Runtime.read(fields, "dedicated_workers", Runtime.list(Runtime.TEXT)),
+    event_type_versions = Runtime.read(fields, "event_type_versions", Runtime.map(Runtime.INTEGER)),
+    event_types = Runtime.read(fields, "event_types", Runtime.list(Runtime.TEXT)),
+    is_enabled = Runtime.read(fields, "is_enabled", Runtime.BOOLEAN),
+    label_key = Runtime.read(fields, "label_key", Runtime.TEXT),
+    label_value = Runtime.read(fields, "label_value", Runtime.TEXT),
+    labels = Runtime.read(fields, "labels", Runtime.map(Runtime.TEXT)),
+    metadata = Runtime.read(fields, "metadata", Runtime.map(Runtime.TEXT)),
+    secret = Runtime.read(fields, "secret", Runtime.TEXT),
+    signature_scheme = Runtime.read(fields, "signature_scheme", Runtime.member_of(Models.SubscriptionSignatureScheme)),
+    subscription_id = Runtime.read(fields, "subscription_id", Runtime.TEXT),
+    target = Runtime.read(fields, "target", Models.SubscriptionTarget.from_json),
+    updated_at = Runtime.read(fields, "updated_at", Runtime.TEXT),
+    auto_disabled_at = Runtime.maybe(fields, "auto_disabled_at", Runtime.TEXT),
+    auto_disabled_reason = Runtime.maybe(fields, "auto_disabled_reason", Runtime.TEXT),
+    batching = Runtime.maybe(fields, "batching", Models.SubscriptionBatching.from_json),
+    circuit_breaker = Runtime.maybe(fields, "circuit_breaker", Models.SubscriptionCircuitBreaker.from_json),
+    delivery_limits = Runtime.maybe(fields, "delivery_limits", Models.SubscriptionDeliveryLimits.from_json),
+    description = Runtime.maybe(fields, "description", Runtime.TEXT),
+    filter = Runtime.maybe(fields, "filter", Runtime.JSON_VALUE),
+    ordering_key = Runtime.maybe(fields, "ordering_key", Models.SubscriptionOrderingKey.from_json),
+    payload_transform = Runtime.maybe(fields, "payload_transform", Models.SubscriptionPayloadTransform.from_json),
+    response_rules = Runtime.maybe(fields, "response_rules", Runtime.list(Models.SubscriptionResponseRules.from_json)),
+    retry_policy = Runtime.maybe(fields, "retry_policy", Models.SubscriptionRetryPolicy.from_json),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.Subscription:to_table()
+  return Runtime.document({
+    ["application_id"] = self.application_id,
+    ["created_at"] = self.created_at,
+    ["dedicated_workers"] = Runtime.written_list(self.dedicated_workers, Runtime.itself),
+    ["event_type_versions"] = Runtime.written_map(self.event_type_versions, Runtime.itself),
+    ["event_types"] = Runtime.written_list(self.event_types, Runtime.itself),
+    ["is_enabled"] = self.is_enabled,
+    ["label_key"] = self.label_key,
+    ["label_value"] = self.label_value,
+    ["labels"] = Runtime.written_map(self.labels, Runtime.itself),
+    ["metadata"] = Runtime.written_map(self.metadata, Runtime.itself),
+    ["secret"] = self.secret,
+    ["signature_scheme"] = self.signature_scheme,
+    ["subscription_id"] = self.subscription_id,
+    ["target"] = Runtime.written(self.target),
+    ["updated_at"] = self.updated_at,
+    ["auto_disabled_at"] = self.auto_disabled_at,
+    ["auto_disabled_reason"] = self.auto_disabled_reason,
+    ["batching"] = Runtime.written(self.batching),
+    ["circuit_breaker"] = Runtime.written(self.circuit_breaker),
+    ["delivery_limits"] = Runtime.written(self.delivery_limits),
+    ["description"] = self.description,
+    ["filter"] = self.filter,
+    ["ordering_key"] = Runtime.written(self.ordering_key),
+    ["payload_transform"] = Runtime.written(self.payload_transform),
+    ["response_rules"] = Runtime.written_list(self.response_rules, Runtime.written),
+    ["retry_policy"] = Runtime.written(self.retry_policy),
+  })
+end
+
+--- The `SubscriptionBatching` the API declares.
+Models.SubscriptionBatching = {}
+Models.SubscriptionBatching.__index = Models.SubscriptionBatching
+Models.SubscriptionBatching.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.max_events integer carries `max_events`: Largest number of events in a batch
+--- @param fields.max_wait_s integer carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill
+---   up
+--- @return SubscriptionBatching
+function Models.SubscriptionBatching.new(fields)
+  return setmetatable({
+    max_events = fields.max_events,
+    max_wait_s = fields.max_wait_s,
+  }, Models.SubscriptionBatching)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionBatching
+function Models.SubscriptionBatching.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionBatching")
+  return Models.SubscriptionBatching.new({
+    max_events = Runtime.read(fields, "max_events", Runtime.INTEGER),
+    max_wait_s = Runtime.read(fields, "max_wait_s", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionBatching:to_table()
+  return Runtime.document({
+    ["max_events"] = self.max_events,
+    ["max_wait_s"] = self.max_wait_s,
+  })
+end
+
+--- The `SubscriptionCircuitBreaker` the API declares.
+Models.SubscriptionCircuitBreaker = {}
+Models.SubscriptionCircuitBreaker.__index = Models.SubscriptionCircuitBreaker
+Models.SubscriptionCircuitBreaker.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.max_consecutive_failures integer|nil carries `max_consecutive_failures`: Number of failed deliveries
+---   in a row after which the subscription is disabled
+--- @param fields.max_failing_hours integer|nil carries `max_failing_hours`: Number of hours without a single successful
+---   delivery after which the subscription is disabled
+--- @return SubscriptionCircuitBreaker
+function Models.SubscriptionCircuitBreaker.new(fields)
+  return setmetatable({
+    max_consecutive_failures = fields.max_consecutive_failures,
+    max_failing_hours = fields.max_failing_hours,
+  }, Models.SubscriptionCircuitBreaker)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionCircuitBreaker
+function Models.SubscriptionCircuitBreaker.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionCircuitBreaker")
+  return Models.SubscriptionCircuitBreaker.new({
+    max_consecutive_failures = Runtime.maybe(fields, "max_consecutive_failures", Runtime.INTEGER),
+    max_failing_hours = Runtime.maybe(fields, "max_failing_hours", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionCircuitBreaker:to_table()
+  return Runtime.document({
+    ["max_consecutive_failures"] = self.max_consecutive_failures,
+    ["max_failing_hours"] = self.max_failing_hours,
+  })
+end
+
+--- The `SubscriptionDeliveryLimits` the API declares.
+Models.SubscriptionDeliveryLimits = {}
+Models.SubscriptionDeliveryLimits.__index = Models.SubscriptionDeliveryLimits
+Models.SubscriptionDeliveryLimits.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.max_in_flight integer|nil carries `max_in_flight`: Number of deliveries that can be in flight at once
+--- @param fields.max_per_second integer|nil carries `max_per_second`: Number of deliveries that can start in a second
+--- @param fields.per_host boolean|nil carries `per_host`: Share the limits with every subscription of the application
+---   that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone
+--- @return SubscriptionDeliveryLimits
+function Models.SubscriptionDeliveryLimits.new(fields)
+  return setmetatable({
+    max_in_flight = fields.max_in_flight,
+    max_per_second = fields.max_per_second,
+    per_host = fields.per_host,
+  }, Models.SubscriptionDeliveryLimits)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionDeliveryLimits
+function Models.SubscriptionDeliveryLimits.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionDeliveryLimits")
+  return Models.SubscriptionDeliveryLimits.new({
+    max_in_flight = Runtime.maybe(fields, "max_in_flight", Runtime.INTEGER),
+    max_per_second = Runtime.maybe(fields, "max_per_second", Runtime.INTEGER),
+    per_host = Runtime.maybe(fields, "per_host", Runtime.BOOLEAN),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionDeliveryLimits:to_table()
+  return Runtime.document({
+    ["max_in_flight"] = self.max_in_flight,
+    ["max_per_second"] = self.max_per_second,
+    ["per_host"] = self.per_host,
+  })
+end
+
+--- The `SubscriptionOrderingKey` the API declares.
+Models.SubscriptionOrderingKey = {}
+Models.SubscriptionOrderingKey.__index = Models.SubscriptionOrderingKey
+Models.SubscriptionOrderingKey.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.field string|nil carries `field`: JSON pointer to the value holding the key in a JSON payload, for
+---   example `/customer/id`
+--- @param fields.label string|nil carries `label`: Name of the event label holding the key
+--- @return SubscriptionOrderingKey
+function Models.SubscriptionOrderingKey.new(fields)
+  return setmetatable({
+    field = fields.field,
+    label = fields.label,
+  }, Models.SubscriptionOrderingKey)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionOrderingKey
+function Models.SubscriptionOrderingKey.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionOrderingKey")
+  return Models.SubscriptionOrderingKey.new({
+    field = Runtime.maybe(fields, "field", Runtime.TEXT),
+    label = Runtime.maybe(fields, "label", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionOrderingKey:to_table()
+  return Runtime.document({
+    ["field"] = self.field,
+    ["label"] = self.label,
+  })
+end
+
+--- The `SubscriptionPayloadTransform` the API declares.
+Models.SubscriptionPayloadTransform = {}
+Models.SubscriptionPayloadTransform.__index = Models.SubscriptionPayloadTransform
+Models.SubscriptionPayloadTransform.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.constants table<string, any>|nil carries `constants`: Properties added at the top level of the result,
+---   overriding properties of the same name (the result must then be an object)
+--- @param fields.envelope string|nil carries `envelope`: Name of a property the result is wrapped in
+--- @param fields.fields SubscriptionPayloadTransformFields[]|nil carries `fields`: Values to copy from the payload into
+---   a new object; when omitted, the whole payload is kept
+--- @return SubscriptionPayloadTransform
+function Models.SubscriptionPayloadTransform.new(fields)
+  return setmetatable({
+    constants = fields.constants,
+    envelope = fields.envelope,
+    fields = fields.fields,
+  }, Models.SubscriptionPayloadTransform)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPayloadTransform
+function Models.SubscriptionPayloadTransform.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPayloadTransform")
+  return Models.SubscriptionPayloadTransform.new({
+    constants = Runtime.maybe(fields, "constants", Runtime.map(Runtime.JSON_VALUE)),
+    envelope = Runtime.maybe(fields, "envelope", Runtime.TEXT),
+    fields = Runtime.maybe(fields, "fields", Runtime.list(Models.SubscriptionPayloadTransformFields.from_json)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPayloadTransform:to_table()
+  return Runtime.document({
+    ["constants"] = Runtime.written_map(self.constants, Runtime.itself),
+    ["envelope"] = self.envelope,
+    ["fields"] = Runtime.written_list(self.fields, Runtime.written),
+  })
+end
+
+--- The `SubscriptionPayloadTransformFields` the API declares.
+Models.SubscriptionPayloadTransformFields = {}
+Models.SubscriptionPayloadTransformFields.__index = Models.SubscriptionPayloadTransformFields
+Models.SubscriptionPayloadTransformFields.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.from string carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values
+---   that are absent from a payload are skipped)
+--- @param fields.to string|nil carries `to`: JSON pointer where the value is written in the result, for example `/id`;
+---   defaults to `from`
+--- @return SubscriptionPayloadTransformFields
+function Models.SubscriptionPayloadTransformFields.new(fields)
+  return setmetatable({
+    from = fields.from,
+    to = fields.to,
+  }, Models.SubscriptionPayloadTransformFields)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPayloadTransformFields
+function Models.SubscriptionPayloadTransformFields.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPayloadTransformFields")
+  return Models.SubscriptionPayloadTransformFields.new({
+    from = Runtime.read(fields, "from", Runtime.TEXT),
+    to = Runtime.maybe(fields, "to", Runtime.TEXT),
   })
 end
 
 --- Write one back the way the API reads it.
 --- @return table
-function Models.Subscription:to_table()
+function Models.SubscriptionPayloadTransformFields:to_table()
   return Runtime.document({
-    ["application_id"] = self.application_id,
-    ["created_at"] = self.created_at,
-    ["dedicated_workers"] = Runtime.written_list(self.dedicated_workers, Runtime.itself),
-    ["event_types"] = Runtime.written_list(self.event_types, Runtime.itself),
-    ["is_enabled"] = self.is_enabled,
-    ["label_key"] = self.label_key,
-    ["label_value"] = self.label_value,
-    ["labels"] = Runtime.written_map(self.labels, Runtime.itself),
-    ["metadata"] = Runtime.written_map(self.metadata, Runtime.itself),
-    ["secret"] = self.secret,
-    ["subscription_id"] = self.subscription_id,
-    ["target"] = Runtime.written(self.target),
-    ["updated_at"] = self.updated_at,
-    ["description"] = self.description,
+    ["from"] = self.from,
+    ["to"] = self.to,
   })
 end
 
@@ -2092,13 +5030,38 @@ Models.SubscriptionPost.__eq = Runtime.equality
 --- @param fields.event_types string[] carries `event_types`.
 --- @param fields.is_enabled boolean carries `is_enabled`.
 --- @param fields.target SubscriptionPostTarget carries `target`.
+--- @param fields.batching SubscriptionPostBatching|nil carries `batching`: Deliver the events in batches, as one HTTP
+---   request with a JSON array body, rather than one by one
+--- @param fields.circuit_breaker SubscriptionPostCircuitBreaker|nil carries `circuit_breaker`: Disable the subscription
+---   once its deliveries keep failing; organization editors are notified by email
 --- @param fields.dedicated_workers string[]|nil carries `dedicated_workers`.
+--- @param fields.delivery_limits SubscriptionPostDeliveryLimits|nil carries `delivery_limits`: Limit how fast
+---   deliveries start, for this subscription or for its host
 --- @param fields.description string|nil carries `description`.
+--- @param fields.event_type_versions table<string, integer>|nil carries `event_type_versions`: Only receive the events
+---   of these event types that declare this version (or no version at all)
+--- @param fields.filter any|nil carries `filter`: Only deliver the events of the subscribed types that match this
+---   expression over labels and JSON payload fields
 --- @param fields.label_key string|nil carries `label_key`: _Kept for backward compatibility, you should use `labels`_
 --- @param fields.label_value string|nil carries `label_value`: _Kept for backward compatibility, you should use
 ---   `labels`_
 --- @param fields.labels table<string, string>|nil carries `labels`.
 --- @param fields.metadata table<string, string>|nil carries `metadata`.
+--- @param fields.ordering_key SubscriptionPostOrderingKey|nil carries `ordering_key`: Deliver the events that share a
+---   key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out
+---   of retries
+--- @param fields.payload_transform SubscriptionPostPayloadTransform|nil carries `payload_transform`: Declarative
+---   reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent;
+---   operations are applied in order (`fields`, `envelope`, `constants`) and a pay
+--- @param fields.response_rules SubscriptionPostResponseRules[]|nil carries `response_rules`: Decide the outcome of
+---   HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body
+---   reports an error; the first rule that matches wins, and a response n
+--- @param fields.retry_policy SubscriptionPostRetryPolicy|nil carries `retry_policy`: Retry failed deliveries on this
+---   schedule rather than the instance's one
+--- @param fields.signature_scheme string|nil carries `signature_scheme`, one of
+---   `Models.SubscriptionPostSignatureScheme.VALUES`: How deliveries are signed (`hook0` by default);
+---   `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the
+---   base64 encoding of the subscription s
 --- @return SubscriptionPost
 function Models.SubscriptionPost.new(fields)
   return setmetatable({
@@ -2106,12 +5069,22 @@ function Models.SubscriptionPost.new(fields)
     event_types = fields.event_types,
     is_enabled = fields.is_enabled,
     target = fields.target,
+    batching = fields.batching,
+    circuit_breaker = fields.circuit_breaker,
     dedicated_workers = fields.dedicated_workers,
+    delivery_limits = fields.delivery_limits,
     description = fields.description,
+    event_type_versions = fields.event_type_versions,
+    filter = fields.filter,
     label_key = fields.label_key,
     label_value = fields.label_value,
     labels = fields.labels,
     metadata = fields.metadata,
+    ordering_key = fields.ordering_key,
+    payload_transform = fields.payload_transform,
+    response_rules = fields.response_rules,
+    retry_policy = fields.retry_policy,
+    signature_scheme = fields.signature_scheme,
   }, Models.SubscriptionPost)
 end
 
@@ -2125,12 +5098,30 @@ function Models.SubscriptionPost.from_json(value)
     event_types = Runtime.read(fields, "event_types", Runtime.list(Runtime.TEXT)),
     is_enabled = Runtime.read(fields, "is_enabled", Runtime.BOOLEAN),
     target = Runtime.read(fields, "target", Models.SubscriptionPostTarget.from_json),
+    batching = Runtime.maybe(fields, "batching", Models.SubscriptionPostBatching.from_json),
+    circuit_breaker = Runtime.maybe(fields, "circuit_breaker", Models.SubscriptionPostCircuitBreaker.from_json),
     dedicated_workers = Runtime.maybe(fields, "dedicated_workers", Runtime.list(Runtime.TEXT)),
+    delivery_limits = Runtime.maybe(fields, "delivery_limits", Models.SubscriptionPostDeliveryLimits.from_json),
     description = Runtime.maybe(fields, "description", Runtime.TEXT),
+    event_type_versions = Runtime.maybe(fields, "event_type_versions", Runtime.map(Runtime.INTEGER)),
+    filter = Runtime.maybe(fields, "filter", Runtime.JSON_VALUE),
     label_key = Runtime.maybe(fields, "label_key", Runtime.TEXT),
     label_value = Runtime.maybe(fields, "label_value", Runtime.TEXT),
     labels = Runtime.maybe(fields, "labels", Runtime.map(Runtime.TEXT)),
     metadata = Runtime.maybe(fields, "metadata", Runtime.map(Runtime.TEXT)),
+    ordering_key = Runtime.maybe(fields, "ordering_key", Models.SubscriptionPostOrderingKey.from_json),
+    payload_transform = Runtime.maybe(fields, "payload_transform", Models.SubscriptionPostPayloadTransform.from_json),
+    response_rules = Runtime.maybe(
+      fields,
+      "response_rules",
+      Runtime.list(Models.SubscriptionPostResponseRules.from_json)
+    ),
+    retry_policy = Runtime.maybe(fields, "retry_policy", Models.SubscriptionPostRetryPolicy.from_json),
+    signature_scheme = Runtime.maybe(
+      fields,
+      "signature_scheme",
+      Runtime.member_of(Models.SubscriptionPostSignatureScheme)
+    ),
   })
 end
 
@@ -2142,12 +5133,381 @@ function Models.SubscriptionPost:to_table()
     ["event_types"] = Runtime.written_list(self.event_types, Runtime.itself),
     ["is_enabled"] = self.is_enabled,
     ["target"] = Runtime.written(self.target),
+    ["batching"] = Runtime.written(self.batching),
+    ["circuit_breaker"] = Runtime.written(self.circuit_breaker),
     ["dedicated_workers"] = Runtime.written_list(self.dedicated_workers, Runtime.itself),
+    ["delivery_limits"] = Runtime.written(self.delivery_limits),
     ["description"] = self.description,
+    ["event_type_versions"] = Runtime.written_map(self.event_type_versions, Runtime.itself),
+    ["filter"] = self.filter,
     ["label_key"] = self.label_key,
     ["label_value"] = self.label_value,
     ["labels"] = Runtime.written_map(self.labels, Runtime.itself),
     ["metadata"] = Runtime.written_map(self.metadata, Runtime.itself),
+    ["ordering_key"] = Runtime.written(self.ordering_key),
+    ["payload_transform"] = Runtime.written(self.payload_transform),
+    ["response_rules"] = Runtime.written_list(self.response_rules, Runtime.written),
+    ["retry_policy"] = Runtime.written(self.retry_policy),
+    ["signature_scheme"] = self.signature_scheme,
+  })
+end
+
+--- The `SubscriptionPostBatching` the API declares.
+Models.SubscriptionPostBatching = {}
+Models.SubscriptionPostBatching.__index = Models.SubscriptionPostBatching
+Models.SubscriptionPostBatching.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.max_events integer carries `max_events`: Largest number of events in a batch
+--- @param fields.max_wait_s integer carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill
+---   up
+--- @return SubscriptionPostBatching
+function Models.SubscriptionPostBatching.new(fields)
+  return setmetatable({
+    max_events = fields.max_events,
+    max_wait_s = fields.max_wait_s,
+  }, Models.SubscriptionPostBatching)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostBatching
+function Models.SubscriptionPostBatching.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostBatching")
+  return Models.SubscriptionPostBatching.new({
+    max_events = Runtime.read(fields, "max_events", Runtime.INTEGER),
+    max_wait_s = Runtime.read(fields, "max_wait_s", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostBatching:to_table()
+  return Runtime.document({
+    ["max_events"] = self.max_events,
+    ["max_wait_s"] = self.max_wait_s,
+  })
+end
+
+--- The `SubscriptionPostCircuitBreaker` the API declares.
+Models.SubscriptionPostCircuitBreaker = {}
+Models.SubscriptionPostCircuitBreaker.__index = Models.SubscriptionPostCircuitBreaker
+Models.SubscriptionPostCircuitBreaker.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.max_consecutive_failures integer|nil carries `max_consecutive_failures`: Number of failed deliveries
+---   in a row after which the subscription is disabled
+--- @param fields.max_failing_hours integer|nil carries `max_failing_hours`: Number of hours without a single successful
+---   delivery after which the subscription is disabled
+--- @return SubscriptionPostCircuitBreaker
+function Models.SubscriptionPostCircuitBreaker.new(fields)
+  return setmetatable({
+    max_consecutive_failures = fields.max_consecutive_failures,
+    max_failing_hours = fields.max_failing_hours,
+  }, Models.SubscriptionPostCircuitBreaker)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostCircuitBreaker
+function Models.SubscriptionPostCircuitBreaker.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostCircuitBreaker")
+  return Models.SubscriptionPostCircuitBreaker.new({
+    max_consecutive_failures = Runtime.maybe(fields, "max_consecutive_failures", Runtime.INTEGER),
+    max_failing_hours = Runtime.maybe(fields, "max_failing_hours", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostCircuitBreaker:to_table()
+  return Runtime.document({
+    ["max_consecutive_failures"] = self.max_consecutive_failures,
+    ["max_failing_hours"] = self.max_failing_hours,
+  })
+end
+
+--- The `SubscriptionPostDeliveryLimits` the API declares.
+Models.SubscriptionPostDeliveryLimits = {}
+Models.SubscriptionPostDeliveryLimits.__index = Models.SubscriptionPostDeliveryLimits
+Models.SubscriptionPostDeliveryLimits.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.max_in_flight integer|nil carries `max_in_flight`: Number of deliveries that can be in flight at once
+--- @param fields.max_per_second integer|nil carries `max_per_second`: Number of deliveries that can start in a second
+--- @param fields.per_host boolean|nil carries `per_host`: Share the limits with every subscription of the application
+---   that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone
+--- @return SubscriptionPostDeliveryLimits
+function Models.SubscriptionPostDeliveryLimits.new(fields)
+  return setmetatable({
+    max_in_flight = fields.max_in_flight,
+    max_per_second = fields.max_per_second,
+    per_host = fields.per_host,
+  }, Models.SubscriptionPostDeliveryLimits)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostDeliveryLimits
+function Models.SubscriptionPostDeliveryLimits.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostDeliveryLimits")
+  return Models.SubscriptionPostDeliveryLimits.new({
+    max_in_flight = Runtime.maybe(fields, "max_in_flight", Runtime.INTEGER),
+    max_per_second = Runtime.maybe(fields, "max_per_second", Runtime.INTEGER),
+    per_host = Runtime.maybe(fields, "per_host", Runtime.BOOLEAN),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostDeliveryLimits:to_table()
+  return Runtime.document({
+    ["max_in_flight"] = self.max_in_flight,
+    ["max_per_second"] = self.max_per_second,
+    ["per_host"] = self.per_host,
+  })
+end
+
+--- The `SubscriptionPostOrderingKey` the API declares.
+Models.SubscriptionPostOrderingKey = {}
+Models.SubscriptionPostOrderingKey.__index = Models.SubscriptionPostOrderingKey
+Models.SubscriptionPostOrderingKey.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.field string|nil carries `field`: JSON pointer to the value holding the key in a JSON payload, for
+---   example `/customer/id`
+--- @param fields.label string|nil carries `label`: Name of the event label holding the key
+--- @return SubscriptionPostOrderingKey
+function Models.SubscriptionPostOrderingKey.new(fields)
+  return setmetatable({
+    field = fields.field,
+    label = fields.label,
+  }, Models.SubscriptionPostOrderingKey)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostOrderingKey
+function Models.SubscriptionPostOrderingKey.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostOrderingKey")
+  return Models.SubscriptionPostOrderingKey.new({
+    field = Runtime.maybe(fields, "field", Runtime.TEXT),
+    label = Runtime.maybe(fields, "label", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostOrderingKey:to_table()
+  return Runtime.document({
+    ["field"] = self.field,
+    ["label"] = self.label,
+  })
+end
+
+--- The `SubscriptionPostPayloadTransform` the API declares.
+Models.SubscriptionPostPayloadTransform = {}
+Models.SubscriptionPostPayloadTransform.__index = Models.SubscriptionPostPayloadTransform
+Models.SubscriptionPostPayloadTransform.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.constants table<string, any>|nil carries `constants`: Properties added at the top level of the result,
+---   overriding properties of the same name (the result must then be an object)
+--- @param fields.envelope string|nil carries `envelope`: Name of a property the result is wrapped in
+--- @param fields.fields SubscriptionPostPayloadTransformFields[]|nil carries `fields`: Values to copy from the payload
+---   into a new object; when omitted, the whole payload is kept
+--- @return SubscriptionPostPayloadTransform
+function Models.SubscriptionPostPayloadTransform.new(fields)
+  return setmetatable({
+    constants = fields.constants,
+    envelope = fields.envelope,
+    fields = fields.fields,
+  }, Models.SubscriptionPostPayloadTransform)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostPayloadTransform
+function Models.SubscriptionPostPayloadTransform.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostPayloadTransform")
+  return Models.SubscriptionPostPayloadTransform.new({
+    constants = Runtime.maybe(fields, "constants", Runtime.map(Runtime.JSON_VALUE)),
+    envelope = Runtime.maybe(fields, "envelope", Runtime.TEXT),
+    fields = Runtime.maybe(fields, "fields", Runtime.list(Models.SubscriptionPostPayloadTransformFields.from_json)),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostPayloadTransform:to_table()
+  return Runtime.document({
+    ["constants"] = Runtime.written_map(self.constants, Runtime.itself),
+    ["envelope"] = self.envelope,
+    ["fields"] = Runtime.written_list(self.fields, Runtime.written),
+  })
+end
+
+--- The `SubscriptionPostPayloadTransformFields` the API declares.
+Models.SubscriptionPostPayloadTransformFields = {}
+Models.SubscriptionPostPayloadTransformFields.__index = Models.SubscriptionPostPayloadTransformFields
+Models.SubscriptionPostPayloadTransformFields.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.from string carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values
+---   that are absent from a payload are skipped)
+--- @param fields.to string|nil carries `to`: JSON pointer where the value is written in the result, for example `/id`;
+---   defaults to `from`
+--- @return SubscriptionPostPayloadTransformFields
+function Models.SubscriptionPostPayloadTransformFields.new(fields)
+  return setmetatable({
+    from = fields.from,
+    to = fields.to,
+  }, Models.SubscriptionPostPayloadTransformFields)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostPayloadTransformFields
+function Models.SubscriptionPostPayloadTransformFields.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostPayloadTransformFields")
+  return Models.SubscriptionPostPayloadTransformFields.new({
+    from = Runtime.read(fields, "from", Runtime.TEXT),
+    to = Runtime.maybe(fields, "to", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostPayloadTransformFields:to_table()
+  return Runtime.document({
+    ["from"] = self.from,
+    ["to"] = self.to,
+  })
+end
+
+--- The `SubscriptionPostResponseRules` the API declares.
+Models.SubscriptionPostResponseRules = {}
+Models.SubscriptionPostResponseRules.__index = Models.SubscriptionPostResponseRules
+Models.SubscriptionPostResponseRules.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.outcome string carries `outcome`, one of `Models.SubscriptionPostResponseRulesOutcome.VALUES`: What
+---   the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or
+---   `disable_subscription` (not retried, and the subscription is disabled)
+--- @param fields.status string[] carries `status`: Status codes the rule applies to: exact codes such as `410`, or
+---   classes such as `4xx`
+--- @param fields.body_contains string|nil carries `body_contains`: Text the response body must contain
+--- @param fields.header SubscriptionPostResponseRulesHeader|nil carries `header`: Header the response must have
+--- @return SubscriptionPostResponseRules
+function Models.SubscriptionPostResponseRules.new(fields)
+  return setmetatable({
+    outcome = fields.outcome,
+    status = fields.status,
+    body_contains = fields.body_contains,
+    header = fields.header,
+  }, Models.SubscriptionPostResponseRules)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostResponseRules
+function Models.SubscriptionPostResponseRules.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostResponseRules")
+  return Models.SubscriptionPostResponseRules.new({
+    outcome = Runtime.read(fields, "outcome", Runtime.member_of(Models.SubscriptionPostResponseRulesOutcome)),
+    status = Runtime.read(fields, "status", Runtime.list(Runtime.TEXT)),
+    body_contains = Runtime.maybe(fields, "body_contains", Runtime.TEXT),
+    header = Runtime.maybe(fields, "header", Models.SubscriptionPostResponseRulesHeader.from_json),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostResponseRules:to_table()
+  return Runtime.document({
+    ["outcome"] = self.outcome,
+    ["status"] = Runtime.written_list(self.status, Runtime.itself),
+    ["body_contains"] = self.body_contains,
+    ["header"] = Runtime.written(self.header),
+  })
+end
+
+--- The `SubscriptionPostResponseRulesHeader` the API declares.
+Models.SubscriptionPostResponseRulesHeader = {}
+Models.SubscriptionPostResponseRulesHeader.__index = Models.SubscriptionPostResponseRulesHeader
+Models.SubscriptionPostResponseRulesHeader.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.name string carries `name`: Name of the header, regardless of case
+--- @param fields.value string|nil carries `value`: Value the header must have; when omitted, any value matches
+--- @return SubscriptionPostResponseRulesHeader
+function Models.SubscriptionPostResponseRulesHeader.new(fields)
+  return setmetatable({
+    name = fields.name,
+    value = fields.value,
+  }, Models.SubscriptionPostResponseRulesHeader)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostResponseRulesHeader
+function Models.SubscriptionPostResponseRulesHeader.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostResponseRulesHeader")
+  return Models.SubscriptionPostResponseRulesHeader.new({
+    name = Runtime.read(fields, "name", Runtime.TEXT),
+    value = Runtime.maybe(fields, "value", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostResponseRulesHeader:to_table()
+  return Runtime.document({
+    ["name"] = self.name,
+    ["value"] = self.value,
+  })
+end
+
+--- The `SubscriptionPostRetryPolicy` the API declares.
+Models.SubscriptionPostRetryPolicy = {}
+Models.SubscriptionPostRetryPolicy.__index = Models.SubscriptionPostRetryPolicy
+Models.SubscriptionPostRetryPolicy.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.backoff_steps_s integer[]|nil carries `backoff_steps_s`: Delays in seconds to wait before each retry;
+---   the last one is used again for the following retries
+--- @param fields.max_retries integer|nil carries `max_retries`: Maximum number of retries after a failed delivery (0
+---   disables retries)
+--- @param fields.max_window_s integer|nil carries `max_window_s`: Maximum duration in seconds the retries can span; a
+---   retry that would not fit in it is not scheduled
+--- @return SubscriptionPostRetryPolicy
+function Models.SubscriptionPostRetryPolicy.new(fields)
+  return setmetatable({
+    backoff_steps_s = fields.backoff_steps_s,
+    max_retries = fields.max_retries,
+    max_window_s = fields.max_window_s,
+  }, Models.SubscriptionPostRetryPolicy)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionPostRetryPolicy
+function Models.SubscriptionPostRetryPolicy.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionPostRetryPolicy")
+  return Models.SubscriptionPostRetryPolicy.new({
+    backoff_steps_s = Runtime.maybe(fields, "backoff_steps_s", Runtime.list(Runtime.INTEGER)),
+    max_retries = Runtime.maybe(fields, "max_retries", Runtime.INTEGER),
+    max_window_s = Runtime.maybe(fields, "max_window_s", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionPostRetryPolicy:to_table()
+  return Runtime.document({
+    ["backoff_steps_s"] = Runtime.written_list(self.backoff_steps_s, Runtime.itself),
+    ["max_retries"] = self.max_retries,
+    ["max_window_s"] = self.max_window_s,
   })
 end
 
@@ -2157,17 +5517,37 @@ Models.SubscriptionPostTarget.__index = Models.SubscriptionPostTarget
 Models.SubscriptionPostTarget.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
---- @param fields.headers any carries `headers`.
---- @param fields.method string carries `method`.
---- @param fields.type string carries `type`.
---- @param fields.url string carries `url`.
+--- @param fields.type string carries `type`, one of `Models.SubscriptionPostTargetType.VALUES`: Kind of target: `http`,
+---   `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+--- @param fields.access_key_id string|nil carries `access_key_id`: `sqs`: access key ID requests are signed with
+--- @param fields.exchange string|nil carries `exchange`: `amqp`: exchange messages are published to; empty for the
+---   default exchange
+--- @param fields.headers any|nil carries `headers`: `http`: headers sent with every request
+--- @param fields.method string|nil carries `method`: `http`: HTTP method of the requests
+--- @param fields.password string|nil carries `password`: `amqp`: password to log in to the broker with; write-only
+--- @param fields.queue_url string|nil carries `queue_url`: `sqs`: URL of the queue
+--- @param fields.region string|nil carries `region`: `sqs`: region requests are signed for
+--- @param fields.routing_key string|nil carries `routing_key`: `amqp`: routing key messages are published with
+--- @param fields.secret_access_key string|nil carries `secret_access_key`: `sqs`: secret access key requests are signed
+---   with; write-only
+--- @param fields.url string|nil carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or
+---   `amqps` scheme), without credentials
+--- @param fields.username string|nil carries `username`: `amqp`: user name to log in to the broker with
 --- @return SubscriptionPostTarget
 function Models.SubscriptionPostTarget.new(fields)
   return setmetatable({
+    type = fields.type,
+    access_key_id = fields.access_key_id,
+    exchange = fields.exchange,
     headers = fields.headers,
     method = fields.method,
-    type = fields.type,
+    password = fields.password,
+    queue_url = fields.queue_url,
+    region = fields.region,
+    routing_key = fields.routing_key,
+    secret_access_key = fields.secret_access_key,
     url = fields.url,
+    username = fields.username,
   }, Models.SubscriptionPostTarget)
 end
 
@@ -2177,10 +5557,18 @@ end
 function Models.SubscriptionPostTarget.from_json(value)
   local fields = Runtime.as_fields(value, "SubscriptionPostTarget")
   return Models.SubscriptionPostTarget.new({
-    headers = Runtime.read(fields, "headers", Runtime.JSON_VALUE),
-    method = Runtime.read(fields, "method", Runtime.TEXT),
-    type = Runtime.read(fields, "type", Runtime.TEXT),
-    url = Runtime.read(fields, "url", Runtime.TEXT),
+    type = Runtime.read(fields, "type", Runtime.member_of(Models.SubscriptionPostTargetType)),
+    access_key_id = Runtime.maybe(fields, "access_key_id", Runtime.TEXT),
+    exchange = Runtime.maybe(fields, "exchange", Runtime.TEXT),
+    headers = Runtime.maybe(fields, "headers", Runtime.JSON_VALUE),
+    method = Runtime.maybe(fields, "method", Runtime.TEXT),
+    password = Runtime.maybe(fields, "password", Runtime.TEXT),
+    queue_url = Runtime.maybe(fields, "queue_url", Runtime.TEXT),
+    region = Runtime.maybe(fields, "region", Runtime.TEXT),
+    routing_key = Runtime.maybe(fields, "routing_key", Runtime.TEXT),
+    secret_access_key = Runtime.maybe(fields, "secret_access_key", Runtime.TEXT),
+    url = Runtime.maybe(fields, "url", Runtime.TEXT),
+    username = Runtime.maybe(fields, "username", Runtime.TEXT),
   })
 end
 
@@ -2188,10 +5576,144 @@ end
 --- @return table
 function Models.SubscriptionPostTarget:to_table()
   return Runtime.document({
+    ["type"] = self.type,
+    ["access_key_id"] = self.access_key_id,
+    ["exchange"] = self.exchange,
     ["headers"] = self.headers,
     ["method"] = self.method,
-    ["type"] = self.type,
+    ["password"] = self.password,
+    ["queue_url"] = self.queue_url,
+    ["region"] = self.region,
+    ["routing_key"] = self.routing_key,
+    ["secret_access_key"] = self.secret_access_key,
     ["url"] = self.url,
+    ["username"] = self.username,
+  })
+end
+
+--- The `SubscriptionResponseRules` the API declares.
+Models.SubscriptionResponseRules = {}
+Models.SubscriptionResponseRules.__index = Models.SubscriptionResponseRules
+Models.SubscriptionResponseRules.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.outcome string carries `outcome`, one of `Models.SubscriptionResponseRulesOutcome.VALUES`: What the
+---   delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or
+---   `disable_subscription` (not retried, and the subscription is disabled)
+--- @param fields.status string[] carries `status`: Status codes the rule applies to: exact codes such as `410`, or
+---   classes such as `4xx`
+--- @param fields.body_contains string|nil carries `body_contains`: Text the response body must contain
+--- @param fields.header SubscriptionResponseRulesHeader|nil carries `header`: Header the response must have
+--- @return SubscriptionResponseRules
+function Models.SubscriptionResponseRules.new(fields)
+  return setmetatable({
+    outcome = fields.outcome,
+    status = fields.status,
+    body_contains = fields.body_contains,
+    header = fields.header,
+  }, Models.SubscriptionResponseRules)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionResponseRules
+function Models.SubscriptionResponseRules.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionResponseRules")
+  return Models.SubscriptionResponseRules.new({
+    outcome = Runtime.read(fields, "outcome", Runtime.member_of(Models.SubscriptionResponseRulesOutcome)),
+    status = Runtime.read(fields, "status", Runtime.list(Runtime.TEXT)),
+    body_contains = Runtime.maybe(fields, "body_contains", Runtime.TEXT),
+    header = Runtime.maybe(fields, "header", Models.SubscriptionResponseRulesHeader.from_json),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionResponseRules:to_table()
+  return Runtime.document({
+    ["outcome"] = self.outcome,
+    ["status"] = Runtime.written_list(self.status, Runtime.itself),
+    ["body_contains"] = self.body_contains,
+    ["header"] = Runtime.written(self.header),
+  })
+end
+
+--- The `SubscriptionResponseRulesHeader` the API declares.
+Models.SubscriptionResponseRulesHeader = {}
+Models.SubscriptionResponseRulesHeader.__index = Models.SubscriptionResponseRulesHeader
+Models.SubscriptionResponseRulesHeader.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.name string carries `name`: Name of the header, regardless of case
+--- @param fields.value string|nil carries `value`: Value the header must have; when omitted, any value matches
+--- @return SubscriptionResponseRulesHeader
+function Models.SubscriptionResponseRulesHeader.new(fields)
+  return setmetatable({
+    name = fields.name,
+    value = fields.value,
+  }, Models.SubscriptionResponseRulesHeader)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionResponseRulesHeader
+function Models.SubscriptionResponseRulesHeader.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionResponseRulesHeader")
+  return Models.SubscriptionResponseRulesHeader.new({
+    name = Runtime.read(fields, "name", Runtime.TEXT),
+    value = Runtime.maybe(fields, "value", Runtime.TEXT),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionResponseRulesHeader:to_table()
+  return Runtime.document({
+    ["name"] = self.name,
+    ["value"] = self.value,
+  })
+end
+
+--- The `SubscriptionRetryPolicy` the API declares.
+Models.SubscriptionRetryPolicy = {}
+Models.SubscriptionRetryPolicy.__index = Models.SubscriptionRetryPolicy
+Models.SubscriptionRetryPolicy.__eq = Runtime.equality
+
+--- Build one out of the members it carries.
+--- @param fields.backoff_steps_s integer[]|nil carries `backoff_steps_s`: Delays in seconds to wait before each retry;
+---   the last one is used again for the following retries
+--- @param fields.max_retries integer|nil carries `max_retries`: Maximum number of retries after a failed delivery (0
+---   disables retries)
+--- @param fields.max_window_s integer|nil carries `max_window_s`: Maximum duration in seconds the retries can span; a
+---   retry that would not fit in it is not scheduled
+--- @return SubscriptionRetryPolicy
+function Models.SubscriptionRetryPolicy.new(fields)
+  return setmetatable({
+    backoff_steps_s = fields.backoff_steps_s,
+    max_retries = fields.max_retries,
+    max_window_s = fields.max_window_s,
+  }, Models.SubscriptionRetryPolicy)
+end
+
+--- Read one out of what the API answered.
+--- @param value table the JSON document the API answered
+--- @return SubscriptionRetryPolicy
+function Models.SubscriptionRetryPolicy.from_json(value)
+  local fields = Runtime.as_fields(value, "SubscriptionRetryPolicy")
+  return Models.SubscriptionRetryPolicy.new({
+    backoff_steps_s = Runtime.maybe(fields, "backoff_steps_s", Runtime.list(Runtime.INTEGER)),
+    max_retries = Runtime.maybe(fields, "max_retries", Runtime.INTEGER),
+    max_window_s = Runtime.maybe(fields, "max_window_s", Runtime.INTEGER),
+  })
+end
+
+--- Write one back the way the API reads it.
+--- @return table
+function Models.SubscriptionRetryPolicy:to_table()
+  return Runtime.document({
+    ["backoff_steps_s"] = Runtime.written_list(self.backoff_steps_s, Runtime.itself),
+    ["max_retries"] = self.max_retries,
+    ["max_window_s"] = self.max_window_s,
   })
 end
 
@@ -2201,17 +5723,37 @@ Models.SubscriptionTarget.__index = Models.SubscriptionTarget
 Models.SubscriptionTarget.__eq = Runtime.equality
 
 --- Build one out of the members it carries.
---- @param fields.headers any carries `headers`.
---- @param fields.method string carries `method`.
---- @param fields.type string carries `type`.
---- @param fields.url string carries `url`.
+--- @param fields.type string carries `type`, one of `Models.SubscriptionTargetType.VALUES`: Kind of target: `http`,
+---   `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+--- @param fields.access_key_id string|nil carries `access_key_id`: `sqs`: access key ID requests are signed with
+--- @param fields.exchange string|nil carries `exchange`: `amqp`: exchange messages are published to; empty for the
+---   default exchange
+--- @param fields.headers any|nil carries `headers`: `http`: headers sent with every request
+--- @param fields.method string|nil carries `method`: `http`: HTTP method of the requests
+--- @param fields.password string|nil carries `password`: `amqp`: password to log in to the broker with; write-only
+--- @param fields.queue_url string|nil carries `queue_url`: `sqs`: URL of the queue
+--- @param fields.region string|nil carries `region`: `sqs`: region requests are signed for
+--- @param fields.routing_key string|nil carries `routing_key`: `amqp`: routing key messages are published with
+--- @param fields.secret_access_key string|nil carries `secret_access_key`: `sqs`: secret access key requests are signed
+---   with; write-only
+--- @param fields.url string|nil carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or
+---   `amqps` scheme), without credentials
+--- @param fields.username string|nil carries `username`: `amqp`: user name to log in to the broker with
 --- @return SubscriptionTarget
 function Models.SubscriptionTarget.new(fields)
   return setmetatable({
+    type = fields.type,
+    access_key_id = fields.access_key_id,
+    exchange = fields.exchange,
     headers = fields.headers,
     method = fields.method,
-    type = fields.type,
+    password = fields.password,
+    queue_url = fields.queue_url,
+    region = fields.region,
+    routing_key = fields.routing_key,
+    secret_access_key = fields.secret_access_key,
     url = fields.url,
+    username = fields.username,
   }, Models.SubscriptionTarget)
 end
 
@@ -2221,10 +5763,18 @@ end
 function Models.SubscriptionTarget.from_json(value)
   local fields = Runtime.as_fields(value, "SubscriptionTarget")
   return Models.SubscriptionTarget.new({
-    headers = Runtime.read(fields, "headers", Runtime.JSON_VALUE),
-    method = Runtime.read(fields, "method", Runtime.TEXT),
-    type = Runtime.read(fields, "type", Runtime.TEXT),
-    url = Runtime.read(fields, "url", Runtime.TEXT),
+    type = Runtime.read(fields, "type", Runtime.member_of(Models.SubscriptionTargetType)),
+    access_key_id = Runtime.maybe(fields, "access_key_id", Runtime.TEXT),
+    exchange = Runtime.maybe(fields, "exchange", Runtime.TEXT),
+    headers = Runtime.maybe(fields, "headers", Runtime.JSON_VALUE),
+    method = Runtime.maybe(fields, "method", Runtime.TEXT),
+    password = Runtime.maybe(fields, "password", Runtime.TEXT),
+    queue_url = Runtime.maybe(fields, "queue_url", Runtime.TEXT),
+    region = Runtime.maybe(fields, "region", Runtime.TEXT),
+    routing_key = Runtime.maybe(fields, "routing_key", Runtime.TEXT),
+    secret_access_key = Runtime.maybe(fields, "secret_access_key", Runtime.TEXT),
+    url = Runtime.maybe(fields, "url", Runtime.TEXT),
+    username = Runtime.maybe(fields, "username", Runtime.TEXT),
   })
 end
 
@@ -2232,10 +5782,18 @@ end
 --- @return table
 function Models.SubscriptionTarget:to_table()
   return Runtime.document({
+    ["type"] = self.type,
+    ["access_key_id"] = self.access_key_id,
+    ["exchange"] = self.exchange,
     ["headers"] = self.headers,
     ["method"] = self.method,
-    ["type"] = self.type,
+    ["password"] = self.password,
+    ["queue_url"] = self.queue_url,
+    ["region"] = self.region,
+    ["routing_key"] = self.routing_key,
+    ["secret_access_key"] = self.secret_access_key,
     ["url"] = self.url,
+    ["username"] = self.username,
   })
 end
 
@@ -68,12 +68,60 @@ pub const GENERATED_TOOLS: &[GeneratedToolInfo] = &[
         input_schema: "{\"properties\":{\"application_id\":{\"type\":\"string\"},\"name\":{\"description\":\"Name of the application. Length: 2-50 characters.\",\"type\":\"string\"},\"organization_id\":{\"description\":\"UUID of the organization this application belongs to.\",\"format\":\"uuid\",\"type\":\"string\"}},\"required\":[\"application_id\",\"name\",\"organization_id\"],\"type\":\"object\"}",
         query_parameters: &[],
     },
+    GeneratedToolInfo {
+        name: "bulkRetries.cancel",
+        description: "Cancel a bulk retry",
+        method: "POST",
+        path_template: "/api/v1/bulk_retries/{bulk_retry_id}/cancel",
+        input_schema: "{\"properties\":{\"application_id\":{\"type\":\"string\"},\"bulk_retry_id\":{\"type\":\"string\"}},\"required\":[\"bulk_retry_id\",\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &["application_id"],
+    },
+    GeneratedToolInfo {
+        name: "bulkRetries.create",
+        description: "Create a bulk retry",
+        method: "POST",
+        path_template: "/api/v1/bulk_retries/",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"event_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"event_type_names\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"max_created_at\":{\"format\":\"date-time\",\"type\":\"string\"},\"min_created_at\":{\"format\":\"date-time\",\"type\":\"string\"},\"status\":{\"description\":\"Redeliver request attempts that failed (default) or that succeeded\",\"enum\":[\"failed\",\"successful\"],\"type\":\"string\"},\"subscription_id\":{\"format\":\"uuid\",\"type\":\"string\"}},\"required\":[\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &[],
+    },
+    GeneratedToolInfo {
+        name: "bulkRetries.dry_run",
+        description: "Count what a bulk retry would redeliver",
+        method: "POST",
+        path_template: "/api/v1/bulk_retries/dry_run",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"event_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"event_type_names\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"max_created_at\":{\"format\":\"date-time\",\"type\":\"string\"},\"min_created_at\":{\"format\":\"date-time\",\"type\":\"string\"},\"status\":{\"description\":\"Redeliver request attempts that failed (default) or that succeeded\",\"enum\":[\"failed\",\"successful\"],\"type\":\"string\"},\"subscription_id\":{\"format\":\"uuid\",\"type\":\"string\"}},\"required\":[\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &[],
+    },
+    GeneratedToolInfo {
+        name: "bulkRetries.get",
+        description: "Get a bulk retry",
+        method: "GET",
+        path_template: "/api/v1/bulk_retries/{bulk_retry_id}",
+        input_schema: "{\"properties\":{\"application_id\":{\"type\":\"string\"},\"bulk_retry_id\":{\"type\":\"string\"}},\"required\":[\"bulk_retry_id\",\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &["application_id"],
+    },
+    GeneratedToolInfo {
+        name: "bulkRetries.list",
+        description: "List bulk retries",
+        method: "GET",
+        path_template: "/api/v1/bulk_retries/",
+        input_schema: "{\"properties\":{\"application_id\":{\"type\":\"string\"}},\"required\":[\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &["application_id"],
+    },
     GeneratedToolInfo {
         name: "eventTypes.create",
         description: "Create a new event type",
         method: "POST",
         path_template: "/api/v1/event_types/",
-        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"resource_type\":{\"type\":\"string\"},\"service\":{\"type\":\"string\"},\"verb\":{\"type\":\"string\"}},\"required\":[\"application_id\",\"resource_type\",\"service\",\"verb\"],\"type\":\"object\"}",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"default_ttl_s\":{\"description\":\"Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over\",\"format\":\"int32\",\"type\":\"integer\"},\"payload_schema\":{\"description\":\"JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported\",\"type\":\"object\"},\"payload_schema_mode\":{\"description\":\"How the payload schema is applied at ingestion (default: `enforce`)\",\"enum\":[\"enforce\",\"warn\",\"off\"],\"type\":\"string\"},\"resource_type\":{\"type\":\"string\"},\"service\":{\"type\":\"string\"},\"verb\":{\"type\":\"string\"}},\"required\":[\"application_id\",\"resource_type\",\"service\",\"verb\"],\"type\":\"object\"}",
+        query_parameters: &[],
+    },
+    GeneratedToolInfo {
+        name: "eventTypes.createVersion",
+        description: "Create a new event type version",
+        method: "POST",
+        path_template: "/api/v1/event_types/{event_type_name}/versions",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"event_type_name\":{\"type\":\"string\"},\"payload_schema\":{\"description\":\"JSON Schema the payloads of the `application/json` events declaring this version must match, instead of the one of the event type; remote references are not supported\",\"type\":\"object\"},\"version\":{\"format\":\"int32\",\"type\":\"integer\"}},\"required\":[\"event_type_name\",\"application_id\",\"version\"],\"type\":\"object\"}",
         query_parameters: &[],
     },
     GeneratedToolInfo {
@@ -84,6 +132,14 @@ pub const GENERATED_TOOLS: &[GeneratedToolInfo] = &[
         input_schema: "{\"properties\":{\"application_id\":{\"type\":\"string\"},\"event_type_name\":{\"type\":\"string\"}},\"required\":[\"event_type_name\",\"application_id\"],\"type\":\"object\"}",
         query_parameters: &["application_id"],
     },
+    GeneratedToolInfo {
+        name: "eventTypes.deprecateVersion",
+        description: "Deprecate an event type version",
+        method: "POST",
+        path_template: "/api/v1/event_types/{event_type_name}/deprecate",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"event_type_name\":{\"type\":\"string\"},\"sunset_at\":{\"description\":\"Date from which the events declaring this version are refused; none keeps accepting them\",\"format\":\"date-time\",\"type\":\"string\"},\"version\":{\"format\":\"int32\",\"type\":\"integer\"}},\"required\":[\"event_type_name\",\"application_id\",\"version\"],\"type\":\"object\"}",
+        query_parameters: &[],
+    },
     GeneratedToolInfo {
         name: "eventTypes.get",
         description: "Get an event type by its name",
@@ -100,6 +156,22 @@ pub const GENERATED_TOOLS: &[GeneratedToolInfo] = &[
         input_schema: "{\"properties\":{\"application_id\":{\"type\":\"string\"}},\"required\":[\"application_id\"],\"type\":\"object\"}",
         query_parameters: &["application_id"],
     },
+    GeneratedToolInfo {
+        name: "eventTypes.update",
+        description: "Update an event type",
+        method: "PUT",
+        path_template: "/api/v1/event_types/{event_type_name}",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"default_ttl_s\":{\"description\":\"Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over\",\"format\":\"int32\",\"type\":\"integer\"},\"event_type_name\":{\"type\":\"string\"},\"payload_schema\":{\"description\":\"JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported\",\"type\":\"object\"},\"payload_schema_mode\":{\"description\":\"How the payload schema is applied at ingestion (default: `enforce`)\",\"enum\":[\"enforce\",\"warn\",\"off\"],\"type\":\"string\"}},\"required\":[\"event_type_name\",\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &[],
+    },
+    GeneratedToolInfo {
+        name: "events.cancel",
+        description: "Cancel a scheduled event",
+        method: "POST",
+        path_template: "/api/v1/events/{event_id}/cancel",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"event_id\":{\"type\":\"string\"}},\"required\":[\"event_id\",\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &[],
+    },
     GeneratedToolInfo {
         name: "events.get",
         description: "Get an event by its ID",
@@ -113,7 +185,15 @@ pub const GENERATED_TOOLS: &[GeneratedToolInfo] = &[
         description: "Ingest an event",
         method: "POST",
         path_template: "/api/v1/event/",
-        input_schema: "{\"properties\":{\"application_id\":{\"description\":\"UUID of the application this event belongs to.\",\"format\":\"uuid\",\"type\":\"string\"},\"event_id\":{\"description\":\"Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.\",\"format\":\"uuid\",\"type\":\"string\"},\"event_type\":{\"description\":\"The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters.\",\"type\":\"string\"},\"labels\":{\"additionalProperties\":{\"type\":\"string\"},\"description\":\"Labels for event filtering and routing to subscriptions.\",\"type\":\"object\"},\"metadata\":{\"additionalProperties\":{\"type\":\"string\"},\"description\":\"Optional metadata key-value pairs associated with the event.\",\"type\":\"object\"},\"occurred_at\":{\"description\":\"Timestamp when the event occurred.\",\"format\":\"date-time\",\"type\":\"string\"},\"payload\":{\"description\":\"The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded).\",\"type\":\"string\"},\"payload_content_type\":{\"description\":\"Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.\",\"type\":\"string\"}},\"required\":[\"application_id\",\"event_type\",\"labels\",\"occurred_at\",\"payload\",\"payload_content_type\"],\"type\":\"object\"}",
+        input_schema: "{\"properties\":{\"application_id\":{\"description\":\"UUID of the application this event belongs to.\",\"format\":\"uuid\",\"type\":\"string\"},\"deliver_at\":{\"description\":\"Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the application.\",\"format\":\"date-time\",\"type\":\"string\"},\"event_id\":{\"description\":\"Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.\",\"format\":\"uuid\",\"type\":\"string\"},\"event_type\":{\"description\":\"The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters.\",\"type\":\"string\"},\"event_type_version\":{\"description\":\"Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.\",\"format\":\"int32\",\"type\":\"integer\"},\"expires_at\":{\"description\":\"Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted from its delivery.\",\"format\":\"date-time\",\"type\":\"string\"},\"labels\":{\"additionalProperties\":{\"type\":\"string\"},\"description\":\"Labels for event filtering and routing to subscriptions.\",\"type\":\"object\"},\"metadata\":{\"additionalProperties\":{\"type\":\"string\"},\"description\":\"Optional metadata key-value pairs associated with the event.\",\"type\":\"object\"},\"occurred_at\":{\"description\":\"Timestamp when the event occurred.\",\"format\":\"date-time\",\"type\":\"string\"},\"payload\":{\"description\":\"The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded).\",\"type\":\"string\"},\"payload_content_type\":{\"description\":\"Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.\",\"type\":\"string\"}},\"required\":[\"application_id\",\"event_type\",\"labels\",\"occurred_at\",\"payload\",\"payload_content_type\"],\"type\":\"object\"}",
+        query_parameters: &[],
+    },
+    GeneratedToolInfo {
+        name: "events.ingestBatch",
+        description: "Ingest a batch of events",
+        method: "POST",
+        path_template: "/api/v1/events/batch",
+        input_schema: "{\"properties\":{\"application_id\":{\"description\":\"UUID of the application the events belong to. Events of the batch that carry another application ID are not ingested.\",\"format\":\"uuid\",\"type\":\"string\"},\"events\":{\"description\":\"Events to ingest. Length: 1-100 events; the whole request body is also subject to the usual request size limit.\",\"items\":{\"description\":\"Event to be ingested into Hook0.\",\"properties\":{\"application_id\":{\"description\":\"UUID of the application this event belongs to.\",\"format\":\"uuid\",\"type\":\"string\"},\"deliver_at\":{\"description\":\"Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the application.\",\"format\":\"date-time\",\"type\":\"string\"},\"event_id\":{\"description\":\"Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.\",\"format\":\"uuid\",\"type\":\"string\"},\"event_type\":{\"description\":\"The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters.\",\"type\":\"string\"},\"event_type_version\":{\"description\":\"Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.\",\"format\":\"int32\",\"type\":\"integer\"},\"expires_at\":{\"description\":\"Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted from its delivery.\",\"format\":\"date-time\",\"type\":\"string\"},\"labels\":{\"additionalProperties\":{\"type\":\"string\"},\"description\":\"Labels for event filtering and routing to subscriptions.\",\"type\":\"object\"},\"metadata\":{\"additionalProperties\":{\"type\":\"string\"},\"description\":\"Optional metadata key-value pairs associated with the event.\",\"type\":\"object\"},\"occurred_at\":{\"description\":\"Timestamp when the event occurred.\",\"format\":\"date-time\",\"type\":\"string\"},\"payload\":{\"description\":\"The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded).\",\"type\":\"string\"},\"payload_content_type\":{\"description\":\"Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.\",\"type\":\"string\"}},\"required\":[\"application_id\",\"event_type\",\"labels\",\"occurred_at\",\"payload\",\"payload_content_type\"],\"type\":\"object\"},\"type\":\"array\"}},\"required\":[\"application_id\",\"events\"],\"type\":\"object\"}",
         query_parameters: &[],
     },
     GeneratedToolInfo {
@@ -121,8 +201,8 @@ pub const GENERATED_TOOLS: &[GeneratedToolInfo] = &[
         description: "List latest events",
         method: "GET",
         path_template: "/api/v1/events/",
-        input_schema: "{\"properties\":{\"application_id\":{\"type\":\"string\"}},\"required\":[\"application_id\"],\"type\":\"object\"}",
-        query_parameters: &["application_id"],
+        input_schema: "{\"properties\":{\"application_id\":{\"type\":\"string\"},\"scheduled\":{\"description\":\"Only list the events scheduled for a later delivery that was not cancelled, soonest first\",\"type\":\"boolean\"}},\"required\":[\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &["application_id", "scheduled"],
     },
     GeneratedToolInfo {
         name: "events.replay",
@@ -180,12 +260,20 @@ pub const GENERATED_TOOLS: &[GeneratedToolInfo] = &[
             "subscription_id",
         ],
     },
+    GeneratedToolInfo {
+        name: "requestAttempts.retry",
+        description: "Retry a request attempt",
+        method: "POST",
+        path_template: "/api/v1/request_attempts/{request_attempt_id}/retry",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"request_attempt_id\":{\"type\":\"string\"}},\"required\":[\"request_attempt_id\",\"application_id\"],\"type\":\"object\"}",
+        query_parameters: &[],
+    },
     GeneratedToolInfo {
         name: "subscriptions.create",
         description: "Create a new subscription",
         method: "POST",
         path_template: "/api/v1/subscriptions/",
-        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"dedicated_workers\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"description\":{\"type\":\"string\"},\"event_types\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"is_enabled\":{\"type\":\"boolean\"},\"label_key\":{\"description\":\"_Kept for backward compatibility, you should use `labels`_\",\"type\":\"string\"},\"label_value\":{\"description\":\"_Kept for backward compatibility, you should use `labels`_\",\"type\":\"string\"},\"labels\":{\"additionalProperties\":{\"type\":\"string\"},\"type\":\"object\"},\"metadata\":{\"additionalProperties\":{\"type\":\"string\"},\"type\":\"object\"},\"target\":{\"properties\":{\"headers\":{\"type\":\"object\"},\"method\":{\"type\":\"string\"},\"type\":{\"example\":\"http\",\"type\":\"string\"},\"url\":{\"format\":\"url\",\"type\":\"string\"}},\"required\":[\"headers\",\"method\",\"type\",\"url\"],\"type\":\"object\"}},\"required\":[\"application_id\",\"event_types\",\"is_enabled\",\"target\"],\"type\":\"object\"}",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"batching\":{\"description\":\"Deliver the events in batches, as one HTTP request with a JSON array body, rather than one by one\",\"properties\":{\"max_events\":{\"description\":\"Largest number of events in a batch\",\"format\":\"int32\",\"type\":\"integer\"},\"max_wait_s\":{\"description\":\"Longest time in seconds an event waits for its batch to fill up\",\"format\":\"int32\",\"type\":\"integer\"}},\"required\":[\"max_events\",\"max_wait_s\"],\"type\":\"object\"},\"circuit_breaker\":{\"description\":\"Disable the subscription once its deliveries keep failing; organization editors are notified by email\",\"properties\":{\"max_consecutive_failures\":{\"description\":\"Number of failed deliveries in a row after which the subscription is disabled\",\"format\":\"int32\",\"type\":\"integer\"},\"max_failing_hours\":{\"description\":\"Number of hours without a single successful delivery after which the subscription is disabled\",\"format\":\"int32\",\"type\":\"integer\"}},\"type\":\"object\"},\"dedicated_workers\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"delivery_limits\":{\"description\":\"Limit how fast deliveries start, for this subscription or for its host\",\"properties\":{\"max_in_flight\":{\"description\":\"Number of deliveries that can be in flight at once\",\"format\":\"int32\",\"type\":\"integer\"},\"max_per_second\":{\"description\":\"Number of deliveries that can start in a second\",\"format\":\"int32\",\"type\":\"integer\"},\"per_host\":{\"description\":\"Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone\",\"type\":\"boolean\"}},\"type\":\"object\"},\"description\":{\"type\":\"string\"},\"event_type_versions\":{\"additionalProperties\":{\"format\":\"int32\",\"type\":\"integer\"},\"description\":\"Only receive the events of these event types that declare this version (or no version at all)\",\"type\":\"object\"},\"event_types\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"filter\":{\"description\":\"Only deliver the events of the subscribed types that match this expression over labels and JSON payload fields\",\"example\":{\"and\":[{\"field\":\"/amount\",\"gt\":1000},{\"in\":[\"FR\",\"DE\"],\"label\":\"country\"}]},\"type\":\"object\"},\"is_enabled\":{\"type\":\"boolean\"},\"label_key\":{\"description\":\"_Kept for backward compatibility, you should use `labels`_\",\"type\":\"string\"},\"label_value\":{\"description\":\"_Kept for backward compatibility, you should use `labels`_\",\"type\":\"string\"},\"labels\":{\"additionalProperties\":{\"type\":\"string\"},\"type\":\"object\"},\"metadata\":{\"additionalProperties\":{\"type\":\"string\"},\"type\":\"object\"},\"ordering_key\":{\"description\":\"Deliver the events that share a key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out of retries\",\"properties\":{\"field\":{\"description\":\"JSON pointer to the value holding the key in a JSON payload, for example `/customer/id`\",\"type\":\"string\"},\"label\":{\"description\":\"Name of the event label holding the key\",\"type\":\"string\"}},\"type\":\"object\"},\"payload_transform\":{\"description\":\"Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a payload that cannot be transformed fails without being retried\",\"properties\":{\"constants\":{\"additionalProperties\":{\"type\":\"object\"},\"description\":\"Properties added at the top level of the result, overriding properties of the same name (the result must then be an object)\",\"type\":\"object\"},\"envelope\":{\"description\":\"Name of a property the result is wrapped in\",\"type\":\"string\"},\"fields\":{\"description\":\"Values to copy from the payload into a new object; when omitted, the whole payload is kept\",\"items\":{\"properties\":{\"from\":{\"description\":\"JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped)\",\"type\":\"string\"},\"to\":{\"description\":\"JSON pointer where the value is written in the result, for example `/id`; defaults to `from`\",\"type\":\"string\"}},\"required\":[\"from\"],\"type\":\"object\"},\"type\":\"array\"}},\"type\":\"object\"},\"response_rules\":{\"description\":\"Decide the outcome of HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body reports an error; the first rule that matches wins, and a response no rule matches keeps the default behaviour (a `2xx` is a success, anything else is retried)\",\"items\":{\"description\":\"Decides the outcome of an HTTP delivery from its response; a rule matches when the status code, and the body and header when they are set, all match\",\"properties\":{\"body_contains\":{\"description\":\"Text the response body must contain\",\"type\":\"string\"},\"header\":{\"description\":\"Header the response must have\",\"properties\":{\"name\":{\"description\":\"Name of the header, regardless of case\",\"type\":\"string\"},\"value\":{\"description\":\"Value the header must have; when omitted, any value matches\",\"type\":\"string\"}},\"required\":[\"name\"],\"type\":\"object\"},\"outcome\":{\"description\":\"What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)\",\"enum\":[\"success\",\"retryable\",\"permanent_failure\",\"disable_subscription\"],\"type\":\"string\"},\"status\":{\"description\":\"Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`\",\"items\":{\"type\":\"string\"},\"type\":\"array\"}},\"required\":[\"outcome\",\"status\"],\"type\":\"object\"},\"type\":\"array\"},\"retry_policy\":{\"description\":\"Retry failed deliveries on this schedule rather than the instance's one\",\"properties\":{\"backoff_steps_s\":{\"description\":\"Delays in seconds to wait before each retry; the last one is used again for the following retries\",\"items\":{\"format\":\"int32\",\"type\":\"integer\"},\"type\":\"array\"},\"max_retries\":{\"description\":\"Maximum number of retries after a failed delivery (0 disables retries)\",\"format\":\"int32\",\"type\":\"integer\"},\"max_window_s\":{\"description\":\"Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled\",\"format\":\"int32\",\"type\":\"integer\"}},\"type\":\"object\"},\"signature_scheme\":{\"description\":\"How deliveries are signed (`hook0` by default); `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of the subscription secret\",\"enum\":[\"hook0\",\"standard_webhooks\"],\"type\":\"string\"},\"target\":{\"properties\":{\"access_key_id\":{\"description\":\"`sqs`: access key ID requests are signed with\",\"type\":\"string\"},\"exchange\":{\"description\":\"`amqp`: exchange messages are published to; empty for the default exchange\",\"type\":\"string\"},\"headers\":{\"description\":\"`http`: headers sent with every request\",\"type\":\"object\"},\"method\":{\"description\":\"`http`: HTTP method of the requests\",\"type\":\"string\"},\"password\":{\"description\":\"`amqp`: password to log in to the broker with; write-only\",\"type\":\"string\"},\"queue_url\":{\"description\":\"`sqs`: URL of the queue\",\"format\":\"url\",\"type\":\"string\"},\"region\":{\"description\":\"`sqs`: region requests are signed for\",\"type\":\"string\"},\"routing_key\":{\"description\":\"`amqp`: routing key messages are published with\",\"type\":\"string\"},\"secret_access_key\":{\"description\":\"`sqs`: secret access key requests are signed with; write-only\",\"type\":\"string\"},\"type\":{\"description\":\"Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it\",\"enum\":[\"http\",\"amqp\",\"sqs\",\"inbox\"],\"example\":\"http\",\"type\":\"string\"},\"url\":{\"description\":\"`http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials\",\"format\":\"url\",\"type\":\"string\"},\"username\":{\"description\":\"`amqp`: user name to log in to the broker with\",\"type\":\"string\"}},\"required\":[\"type\"],\"type\":\"object\"}},\"required\":[\"application_id\",\"event_types\",\"is_enabled\",\"target\"],\"type\":\"object\"}",
         query_parameters: &[],
     },
     GeneratedToolInfo {
@@ -217,7 +305,7 @@ pub const GENERATED_TOOLS: &[GeneratedToolInfo] = &[
         description: "Update a subscription",
         method: "PUT",
         path_template: "/api/v1/subscriptions/{subscription_id}",
-        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"dedicated_workers\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"description\":{\"type\":\"string\"},\"event_types\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"is_enabled\":{\"type\":\"boolean\"},\"label_key\":{\"description\":\"_Kept for backward compatibility, you should use `labels`_\",\"type\":\"string\"},\"label_value\":{\"description\":\"_Kept for backward compatibility, you should use `labels`_\",\"type\":\"string\"},\"labels\":{\"additionalProperties\":{\"type\":\"string\"},\"type\":\"object\"},\"metadata\":{\"additionalProperties\":{\"type\":\"string\"},\"type\":\"object\"},\"subscription_id\":{\"type\":\"string\"},\"target\":{\"properties\":{\"headers\":{\"type\":\"object\"},\"method\":{\"type\":\"string\"},\"type\":{\"example\":\"http\",\"type\":\"string\"},\"url\":{\"format\":\"url\",\"type\":\"string\"}},\"required\":[\"headers\",\"method\",\"type\",\"url\"],\"type\":\"object\"}},\"required\":[\"subscription_id\",\"application_id\",\"event_types\",\"is_enabled\",\"target\"],\"type\":\"object\"}",
+        input_schema: "{\"properties\":{\"application_id\":{\"format\":\"uuid\",\"type\":\"string\"},\"batching\":{\"description\":\"Deliver the events in batches, as one HTTP request with a JSON array body, rather than one by one\",\"properties\":{\"max_events\":{\"description\":\"Largest number of events in a batch\",\"format\":\"int32\",\"type\":\"integer\"},\"max_wait_s\":{\"description\":\"Longest time in seconds an event waits for its batch to fill up\",\"format\":\"int32\",\"type\":\"integer\"}},\"required\":[\"max_events\",\"max_wait_s\"],\"type\":\"object\"},\"circuit_breaker\":{\"description\":\"Disable the subscription once its deliveries keep failing; organization editors are notified by email\",\"properties\":{\"max_consecutive_failures\":{\"description\":\"Number of failed deliveries in a row after which the subscription is disabled\",\"format\":\"int32\",\"type\":\"integer\"},\"max_failing_hours\":{\"description\":\"Number of hours without a single successful delivery after which the subscription is disabled\",\"format\":\"int32\",\"type\":\"integer\"}},\"type\":\"object\"},\"dedicated_workers\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"delivery_limits\":{\"description\":\"Limit how fast deliveries start, for this subscription or for its host\",\"properties\":{\"max_in_flight\":{\"description\":\"Number of deliveries that can be in flight at once\",\"format\":\"int32\",\"type\":\"integer\"},\"max_per_second\":{\"description\":\"Number of deliveries that can start in a second\",\"format\":\"int32\",\"type\":\"integer\"},\"per_host\":{\"description\":\"Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone\",\"type\":\"boolean\"}},\"type\":\"object\"},\"description\":{\"type\":\"string\"},\"event_type_versions\":{\"additionalProperties\":{\"format\":\"int32\",\"type\":\"integer\"},\"description\":\"Only receive the events of these event types that declare this version (or no version at all)\",\"type\":\"object\"},\"event_types\":{\"items\":{\"type\":\"string\"},\"type\":\"array\"},\"filter\":{\"description\":\"Only deliver the events of the subscribed types that match this expression over labels and JSON payload fields\",\"example\":{\"and\":[{\"field\":\"/amount\",\"gt\":1000},{\"in\":[\"FR\",\"DE\"],\"label\":\"country\"}]},\"type\":\"object\"},\"is_enabled\":{\"type\":\"boolean\"},\"label_key\":{\"description\":\"_Kept for backward compatibility, you should use `labels`_\",\"type\":\"string\"},\"label_value\":{\"description\":\"_Kept for backward compatibility, you should use `labels`_\",\"type\":\"string\"},\"labels\":{\"additionalProperties\":{\"type\":\"string\"},\"type\":\"object\"},\"metadata\":{\"additionalProperties\":{\"type\":\"string\"},\"type\":\"object\"},\"ordering_key\":{\"description\":\"Deliver the events that share a key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out of retries\",\"properties\":{\"field\":{\"description\":\"JSON pointer to the value holding the key in a JSON payload, for example `/customer/id`\",\"type\":\"string\"},\"label\":{\"description\":\"Name of the event label holding the key\",\"type\":\"string\"}},\"type\":\"object\"},\"payload_transform\":{\"description\":\"Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a payload that cannot be transformed fails without being retried\",\"properties\":{\"constants\":{\"additionalProperties\":{\"type\":\"object\"},\"description\":\"Properties added at the top level of the result, overriding properties of the same name (the result must then be an object)\",\"type\":\"object\"},\"envelope\":{\"description\":\"Name of a property the result is wrapped in\",\"type\":\"string\"},\"fields\":{\"description\":\"Values to copy from the payload into a new object; when omitted, the whole payload is kept\",\"items\":{\"properties\":{\"from\":{\"description\":\"JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped)\",\"type\":\"string\"},\"to\":{\"description\":\"JSON pointer where the value is written in the result, for example `/id`; defaults to `from`\",\"type\":\"string\"}},\"required\":[\"from\"],\"type\":\"object\"},\"type\":\"array\"}},\"type\":\"object\"},\"response_rules\":{\"description\":\"Decide the outcome of HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body reports an error; the first rule that matches wins, and a response no rule matches keeps the default behaviour (a `2xx` is a success, anything else is retried)\",\"items\":{\"description\":\"Decides the outcome of an HTTP delivery from its response; a rule matches when the status code, and the body and header when they are set, all match\",\"properties\":{\"body_contains\":{\"description\":\"Text the response body must contain\",\"type\":\"string\"},\"header\":{\"description\":\"Header the response must have\",\"properties\":{\"name\":{\"description\":\"Name of the header, regardless of case\",\"type\":\"string\"},\"value\":{\"description\":\"Value the header must have; when omitted, any value matches\",\"type\":\"string\"}},\"required\":[\"name\"],\"type\":\"object\"},\"outcome\":{\"description\":\"What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)\",\"enum\":[\"success\",\"retryable\",\"permanent_failure\",\"disable_subscription\"],\"type\":\"string\"},\"status\":{\"description\":\"Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`\",\"items\":{\"type\":\"string\"},\"type\":\"array\"}},\"required\":[\"outcome\",\"status\"],\"type\":\"object\"},\"type\":\"array\"},\"retry_policy\":{\"description\":\"Retry failed deliveries on this schedule rather than the instance's one\",\"properties\":{\"backoff_steps_s\":{\"description\":\"Delays in seconds to wait before each retry; the last one is used again for the following retries\",\"items\":{\"format\":\"int32\",\"type\":\"integer\"},\"type\":\"array\"},\"max_retries\":{\"description\":\"Maximum number of retries after a failed delivery (0 disables retries)\",\"format\":\"int32\",\"type\":\"integer\"},\"max_window_s\":{\"description\":\"Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled\",\"format\":\"int32\",\"type\":\"integer\"}},\"type\":\"object\"},\"signature_scheme\":{\"description\":\"How deliveries are signed (`hook0` by default); `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of the subscription secret\",\"enum\":[\"hook0\",\"standard_webhooks\"],\"type\":\"string\"},\"subscription_id\":{\"type\":\"string\"},\"target\":{\"properties\":{\"access_key_id\":{\"description\":\"`sqs`: access key ID requests are signed with\",\"type\":\"string\"},\"exchange\":{\"description\":\"`amqp`: exchange messages are published to; empty for the default exchange\",\"type\":\"string\"},\"headers\":{\"description\":\"`http`: headers sent with every request\",\"type\":\"object\"},\"method\":{\"description\":\"`http`: HTTP method of the requests\",\"type\":\"string\"},\"password\":{\"description\":\"`amqp`: password to log in to the broker with; write-only\",\"type\":\"string\"},\"queue_url\":{\"description\":\"`sqs`: URL of the queue\",\"format\":\"url\",\"type\":\"string\"},\"region\":{\"description\":\"`sqs`: region requests are signed for\",\"type\":\"string\"},\"routing_key\":{\"description\":\"`amqp`: routing key messages are published with\",\"type\":\"string\"},\"secret_access_key\":{\"description\":\"`sqs`: secret access key requests are signed with; write-only\",\"type\":\"string\"},\"type\":{\"description\":\"Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it\",\"enum\":[\"http\",\"amqp\",\"sqs\",\"inbox\"],\"example\":\"http\",\"type\":\"string\"},\"url\":{\"description\":\"`http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials\",\"format\":\"url\",\"type\":\"string\"},\"username\":{\"description\":\"`amqp`: user name to log in to the broker with\",\"type\":\"string\"}},\"required\":[\"type\"],\"type\":\"object\"}},\"required\":[\"subscription_id\",\"application_id\",\"event_types\",\"is_enabled\",\"target\"],\"type\":\"object\"}",
         query_parameters: &[],
     },
 ];
@@ -0,0 +1,156 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+use Hook0\Transport;
+
+/**
+ * What the API declares under `bulkRetries`, issued through the transport it is handed.
+ */
+final class BulkRetriesApi
+{
+    public function __construct(private readonly Transport $transport)
+    {
+    }
+
+    /**
+     * Cancel a bulk retry
+     *
+     * @param string $bulkRetryId carries `bulk_retry_id`.
+     * @param string $applicationId carries `application_id`.
+     * @return BulkRetry
+     */
+    public function cancel(string $bulkRetryId, string $applicationId): BulkRetry
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/bulk_retries/{bulk_retry_id}/cancel',
+                    [
+                        'bulk_retry_id' => $bulkRetryId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+            BulkRetry::fromJson(...),
+        );
+    }
+
+    /**
+     * Create a bulk retry
+     *
+     * @param BulkRetryPost $body what the operation reads
+     * @return BulkRetry
+     */
+    public function create(BulkRetryPost $body): BulkRetry
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                '/api/v1/bulk_retries/',
+                [],
+                $body->toArray(),
+            ),
+            BulkRetry::fromJson(...),
+        );
+    }
+
+    /**
+     * Count what a bulk retry would redeliver
+     *
+     * @param BulkRetryPost $body what the operation reads
+     * @return BulkRetryDryRun
+     */
+    public function dryRun(BulkRetryPost $body): BulkRetryDryRun
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                '/api/v1/bulk_retries/dry_run',
+                [],
+                $body->toArray(),
+            ),
+            BulkRetryDryRun::fromJson(...),
+        );
+    }
+
+    /**
+     * Get a bulk retry
+     *
+     * @param string $bulkRetryId carries `bulk_retry_id`.
+     * @param string $applicationId carries `application_id`.
+     * @return BulkRetry
+     */
+    public function get(string $bulkRetryId, string $applicationId): BulkRetry
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                Runtime::path(
+                    '/api/v1/bulk_retries/{bulk_retry_id}',
+                    [
+                        'bulk_retry_id' => $bulkRetryId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+            BulkRetry::fromJson(...),
+        );
+    }
+
+    /**
+     * List bulk retries
+     *
+     * @param string $applicationId carries `application_id`.
+     * @return list<BulkRetry>
+     */
+    public function list(string $applicationId): array
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                '/api/v1/bulk_retries/',
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+            Runtime::listOf(BulkRetry::fromJson(...)),
+        );
+    }
+
+    /**
+     * Raise what the API reported, or read back the value it answered.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     * @param \Closure $reader what turns that body into the value the API declares
+     */
+    private function readAnswer(array $answered, \Closure $reader): mixed
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+
+        return $reader(Runtime::decodePayload($answered[1]));
+    }
+}
@@ -0,0 +1,131 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `BulkRetry` the API declares.
+ */
+final class BulkRetry
+{
+    /**
+     * @param string $applicationId carries `application_id`.
+     * @param string $bulkRetryId carries `bulk_retry_id`.
+     * @param \DateTimeImmutable $createdAt carries `created_at`.
+     * @param list<string> $eventTypeNames carries `event_type_names`.
+     * @param \DateTimeImmutable $maxCreatedAt carries `max_created_at`.
+     * @param \DateTimeImmutable $minCreatedAt carries `min_created_at`.
+     * @param int $processedCount carries `processed_count`: Number of pairs handled so far
+     * @param int $retriedCount carries `retried_count`: Number of request attempts created so far
+     * @param BulkRetryStatus $status carries `status`, one of `BulkRetryStatus`.
+     * @param int $totalCount carries `total_count`: Number of (event, subscription) pairs that matched when the bulk
+     *   retry was created
+     * @param \DateTimeImmutable $updatedAt carries `updated_at`.
+     * @param \DateTimeImmutable|null $cancelledAt carries `cancelled_at`.
+     * @param \DateTimeImmutable|null $completedAt carries `completed_at`.
+     * @param string|null $eventId carries `event_id`.
+     * @param string|null $subscriptionId carries `subscription_id`.
+     */
+    public function __construct(
+        public readonly string $applicationId,
+        public readonly string $bulkRetryId,
+        public readonly \DateTimeImmutable $createdAt,
+        public readonly array $eventTypeNames,
+        public readonly \DateTimeImmutable $maxCreatedAt,
+        public readonly \DateTimeImmutable $minCreatedAt,
+        public readonly int $processedCount,
+        public readonly int $retriedCount,
+        public readonly BulkRetryStatus $status,
+        public readonly int $totalCount,
+        public readonly \DateTimeImmutable $updatedAt,
+        public readonly ?\DateTimeImmutable $cancelledAt = null,
+        public readonly ?\DateTimeImmutable $completedAt = null,
+        public readonly ?string $eventId = null,
+        public readonly ?string $subscriptionId = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'BulkRetry');
+        $read = [
+            'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
+            'bulkRetryId' => Runtime::read($fields, 'bulk_retry_id', Runtime::uuid(...)),
+            'createdAt' => Runtime::read($fields, 'created_at', Runtime::dateTime(...)),
+            'eventTypeNames' => Runtime::read($fields, 'event_type_names', Runtime::listOf(Runtime::text(...))),
+            'maxCreatedAt' => Runtime::read($fields, 'max_created_at', Runtime::dateTime(...)),
+            'minCreatedAt' => Runtime::read($fields, 'min_created_at', Runtime::dateTime(...)),
+            'processedCount' => Runtime::read($fields, 'processed_count', Runtime::integer(...)),
+            'retriedCount' => Runtime::read($fields, 'retried_count', Runtime::integer(...)),
+            'status' => Runtime::read($fields, 'status', Runtime::memberOf(BulkRetryStatus::class)),
+            'totalCount' => Runtime::read($fields, 'total_count', Runtime::integer(...)),
+            'updatedAt' => Runtime::read($fields, 'updated_at', Runtime::dateTime(...)),
+            'cancelledAt' => Runtime::maybe($fields, 'cancelled_at', Runtime::dateTime(...)),
+            'completedAt' => Runtime::maybe($fields, 'completed_at', Runtime::dateTime(...)),
+            'eventId' => Runtime::maybe($fields, 'event_id', Runtime::uuid(...)),
+            'subscriptionId' => Runtime::maybe($fields, 'subscription_id', Runtime::uuid(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['application_id'] = $this->applicationId;
+        $out['bulk_retry_id'] = $this->bulkRetryId;
+        $out['created_at'] = Runtime::moment($this->createdAt);
+        $out['event_type_names'] = $this->eventTypeNames;
+        $out['max_created_at'] = Runtime::moment($this->maxCreatedAt);
+        $out['min_created_at'] = Runtime::moment($this->minCreatedAt);
+        $out['processed_count'] = $this->processedCount;
+        $out['retried_count'] = $this->retriedCount;
+        $out['status'] = $this->status->value;
+        $out['total_count'] = $this->totalCount;
+        $out['updated_at'] = Runtime::moment($this->updatedAt);
+        if ($this->cancelledAt !== null) {
+            $out['cancelled_at'] = Runtime::moment($this->cancelledAt);
+        }
+        if ($this->completedAt !== null) {
+            $out['completed_at'] = Runtime::moment($this->completedAt);
+        }
+        if ($this->eventId !== null) {
+            $out['event_id'] = $this->eventId;
+        }
+        if ($this->subscriptionId !== null) {
+            $out['subscription_id'] = $this->subscriptionId;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
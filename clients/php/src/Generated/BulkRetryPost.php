@@ -0,0 +1,103 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `BulkRetryPost` the API declares.
+ */
+final class BulkRetryPost
+{
+    /**
+     * @param string $applicationId carries `application_id`.
+     * @param string|null $eventId carries `event_id`.
+     * @param list<string>|null $eventTypeNames carries `event_type_names`.
+     * @param \DateTimeImmutable|null $maxCreatedAt carries `max_created_at`.
+     * @param \DateTimeImmutable|null $minCreatedAt carries `min_created_at`.
+     * @param BulkRetryPostStatus|null $status carries `status`, one of `BulkRetryPostStatus`: Redeliver request
+     *   attempts that failed (default) or that succeeded
+     * @param string|null $subscriptionId carries `subscription_id`.
+     */
+    public function __construct(
+        public readonly string $applicationId,
+        public readonly ?string $eventId = null,
+        public readonly ?array $eventTypeNames = null,
+        public readonly ?\DateTimeImmutable $maxCreatedAt = null,
+        public readonly ?\DateTimeImmutable $minCreatedAt = null,
+        public readonly ?BulkRetryPostStatus $status = null,
+        public readonly ?string $subscriptionId = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'BulkRetryPost');
+        $read = [
+            'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
+            'eventId' => Runtime::maybe($fields, 'event_id', Runtime::uuid(...)),
+            'eventTypeNames' => Runtime::maybe($fields, 'event_type_names', Runtime::listOf(Runtime::text(...))),
+            'maxCreatedAt' => Runtime::maybe($fields, 'max_created_at', Runtime::dateTime(...)),
+            'minCreatedAt' => Runtime::maybe($fields, 'min_created_at', Runtime::dateTime(...)),
+            'status' => Runtime::maybe($fields, 'status', Runtime::memberOf(BulkRetryPostStatus::class)),
+            'subscriptionId' => Runtime::maybe($fields, 'subscription_id', Runtime::uuid(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['application_id'] = $this->applicationId;
+        if ($this->eventId !== null) {
+            $out['event_id'] = $this->eventId;
+        }
+        if ($this->eventTypeNames !== null) {
+            $out['event_type_names'] = $this->eventTypeNames;
+        }
+        if ($this->maxCreatedAt !== null) {
+            $out['max_created_at'] = Runtime::moment($this->maxCreatedAt);
+        }
+        if ($this->minCreatedAt !== null) {
+            $out['min_created_at'] = Runtime::moment($this->minCreatedAt);
+        }
+        if ($this->status !== null) {
+            $out['status'] = $this->status->value;
+        }
+        if ($this->subscriptionId !== null) {
+            $out['subscription_id'] = $this->subscriptionId;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
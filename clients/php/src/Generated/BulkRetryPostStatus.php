@@ -0,0 +1,17 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+/**
+ * One of the values the API answers with.
+ */
+enum BulkRetryPostStatus: string
+{
+    case Failed = 'failed';
+    case Successful = 'successful';
+}
@@ -0,0 +1,95 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `ClientCertificate` the API declares.
+ */
+final class ClientCertificate
+{
+    /**
+     * @param string $certificatePem carries `certificate_pem`: Certificate chain, leaf certificate first
+     * @param \DateTimeImmutable $createdAt carries `created_at`.
+     * @param string $fingerprintSha256 carries `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate, as
+     *   hexadecimal
+     * @param \DateTimeImmutable $notAfter carries `not_after`.
+     * @param \DateTimeImmutable $notBefore carries `not_before`.
+     * @param string $subject carries `subject`.
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param \DateTimeImmutable $updatedAt carries `updated_at`.
+     */
+    public function __construct(
+        public readonly string $certificatePem,
+        public readonly \DateTimeImmutable $createdAt,
+        public readonly string $fingerprintSha256,
+        public readonly \DateTimeImmutable $notAfter,
+        public readonly \DateTimeImmutable $notBefore,
+        public readonly string $subject,
+        public readonly string $subscriptionId,
+        public readonly \DateTimeImmutable $updatedAt,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'ClientCertificate');
+        $read = [
+            'certificatePem' => Runtime::read($fields, 'certificate_pem', Runtime::text(...)),
+            'createdAt' => Runtime::read($fields, 'created_at', Runtime::dateTime(...)),
+            'fingerprintSha256' => Runtime::read($fields, 'fingerprint_sha256', Runtime::text(...)),
+            'notAfter' => Runtime::read($fields, 'not_after', Runtime::dateTime(...)),
+            'notBefore' => Runtime::read($fields, 'not_before', Runtime::dateTime(...)),
+            'subject' => Runtime::read($fields, 'subject', Runtime::text(...)),
+            'subscriptionId' => Runtime::read($fields, 'subscription_id', Runtime::uuid(...)),
+            'updatedAt' => Runtime::read($fields, 'updated_at', Runtime::dateTime(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['certificate_pem'] = $this->certificatePem;
+        $out['created_at'] = Runtime::moment($this->createdAt);
+        $out['fingerprint_sha256'] = $this->fingerprintSha256;
+        $out['not_after'] = Runtime::moment($this->notAfter);
+        $out['not_before'] = Runtime::moment($this->notBefore);
+        $out['subject'] = $this->subject;
+        $out['subscription_id'] = $this->subscriptionId;
+        $out['updated_at'] = Runtime::moment($this->updatedAt);
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,71 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `ClientCertificatePut` the API declares.
+ */
+final class ClientCertificatePut
+{
+    /**
+     * @param string $certificatePem carries `certificate_pem`: PEM-encoded certificate chain, leaf certificate first
+     * @param string $privateKeyPem carries `private_key_pem`: PEM-encoded unencrypted private key of the leaf
+     *   certificate (PKCS#8, PKCS#1 or SEC1)
+     */
+    public function __construct(
+        public readonly string $certificatePem,
+        public readonly string $privateKeyPem,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'ClientCertificatePut');
+        $read = [
+            'certificatePem' => Runtime::read($fields, 'certificate_pem', Runtime::text(...)),
+            'privateKeyPem' => Runtime::read($fields, 'private_key_pem', Runtime::text(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['certificate_pem'] = $this->certificatePem;
+        $out['private_key_pem'] = $this->privateKeyPem;
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
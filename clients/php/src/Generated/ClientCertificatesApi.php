@@ -0,0 +1,133 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+use Hook0\Transport;
+
+/**
+ * What the API declares under `clientCertificates`, issued through the transport it is handed.
+ */
+final class ClientCertificatesApi
+{
+    public function __construct(private readonly Transport $transport)
+    {
+    }
+
+    /**
+     * Remove the client certificate of a subscription
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $applicationId carries `application_id`.
+     */
+    public function delete(string $subscriptionId, string $applicationId): void
+    {
+        $this->checkAnswer(
+            $this->transport->request(
+                'DELETE',
+                Runtime::path(
+                    '/api/v1/subscriptions/{subscription_id}/client_certificate',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+        );
+    }
+
+    /**
+     * Get the client certificate of a subscription
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $applicationId carries `application_id`.
+     * @return ClientCertificate
+     */
+    public function get(string $subscriptionId, string $applicationId): ClientCertificate
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                Runtime::path(
+                    '/api/v1/subscriptions/{subscription_id}/client_certificate',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+            ClientCertificate::fromJson(...),
+        );
+    }
+
+    /**
+     * Set the client certificate of a subscription
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $applicationId carries `application_id`.
+     * @param ClientCertificatePut $body what the operation reads
+     * @return ClientCertificate
+     */
+    public function update(string $subscriptionId, string $applicationId, ClientCertificatePut $body): ClientCertificate
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'PUT',
+                Runtime::path(
+                    '/api/v1/subscriptions/{subscription_id}/client_certificate',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                $body->toArray(),
+            ),
+            ClientCertificate::fromJson(...),
+        );
+    }
+
+    /**
+     * Raise what the API reported, and answer nothing when it reported nothing.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     */
+    private function checkAnswer(array $answered): void
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+    }
+
+    /**
+     * Raise what the API reported, or read back the value it answered.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     * @param \Closure $reader what turns that body into the value the API declares
+     */
+    private function readAnswer(array $answered, \Closure $reader): mixed
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+
+        return $reader(Runtime::decodePayload($answered[1]));
+    }
+}
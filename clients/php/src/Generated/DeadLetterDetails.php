@@ -0,0 +1,73 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `DeadLetterDetails` the API declares.
+ */
+final class DeadLetterDetails
+{
+    /**
+     * @param DeadLetterDetailsDeadLetter $deadLetter carries `dead_letter`: Delivery an output worker gave up on
+     * @param DeadLetterDetailsLastResponse|null $lastResponse carries `last_response`: Response to the last request
+     *   attempt, if the target was reached
+     */
+    public function __construct(
+        public readonly DeadLetterDetailsDeadLetter $deadLetter,
+        public readonly ?DeadLetterDetailsLastResponse $lastResponse = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'DeadLetterDetails');
+        $read = [
+            'deadLetter' => Runtime::read($fields, 'dead_letter', DeadLetterDetailsDeadLetter::fromJson(...)),
+            'lastResponse' => Runtime::maybe($fields, 'last_response', DeadLetterDetailsLastResponse::fromJson(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['dead_letter'] = $this->deadLetter->toArray();
+        if ($this->lastResponse !== null) {
+            $out['last_response'] = $this->lastResponse->toArray();
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,107 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `DeadLetterDetailsDeadLetter` the API declares.
+ */
+final class DeadLetterDetailsDeadLetter
+{
+    /**
+     * @param \DateTimeImmutable $createdAt carries `created_at`.
+     * @param string $deadLetterId carries `dead_letter_id`.
+     * @param DeadLetterDetailsDeadLetterEvent $event carries `event`.
+     * @param DeadLetterDetailsDeadLetterReason $reason carries `reason`, one of `DeadLetterDetailsDeadLetterReason`.
+     * @param string $requestAttemptId carries `request_attempt_id`: Last request attempt of the delivery
+     * @param int $retryCount carries `retry_count`.
+     * @param DeadLetterDetailsDeadLetterSubscription $subscription carries `subscription`.
+     * @param int|null $httpResponseStatus carries `http_response_status`.
+     * @param string|null $responseId carries `response_id`: Response to the last request attempt, if the target was
+     *   reached
+     */
+    public function __construct(
+        public readonly \DateTimeImmutable $createdAt,
+        public readonly string $deadLetterId,
+        public readonly DeadLetterDetailsDeadLetterEvent $event,
+        public readonly DeadLetterDetailsDeadLetterReason $reason,
+        public readonly string $requestAttemptId,
+        public readonly int $retryCount,
+        public readonly DeadLetterDetailsDeadLetterSubscription $subscription,
+        public readonly ?int $httpResponseStatus = null,
+        public readonly ?string $responseId = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'DeadLetterDetailsDeadLetter');
+        $read = [
+            'createdAt' => Runtime::read($fields, 'created_at', Runtime::dateTime(...)),
+            'deadLetterId' => Runtime::read($fields, 'dead_letter_id', Runtime::uuid(...)),
+            'event' => Runtime::read($fields, 'event', DeadLetterDetailsDeadLetterEvent::fromJson(...)),
+            'reason' => Runtime::read($fields, 'reason', Runtime::memberOf(DeadLetterDetailsDeadLetterReason::class)),
+            'requestAttemptId' => Runtime::read($fields, 'request_attempt_id', Runtime::uuid(...)),
+            'retryCount' => Runtime::read($fields, 'retry_count', Runtime::integer(...)),
+            'subscription' => Runtime::read(
+                $fields,
+                'subscription',
+                DeadLetterDetailsDeadLetterSubscription::fromJson(...),
+            ),
+            'httpResponseStatus' => Runtime::maybe($fields, 'http_response_status', Runtime::integer(...)),
+            'responseId' => Runtime::maybe($fields, 'response_id', Runtime::uuid(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['created_at'] = Runtime::moment($this->createdAt);
+        $out['dead_letter_id'] = $this->deadLetterId;
+        $out['event'] = $this->event->toArray();
+        $out['reason'] = $this->reason->value;
+        $out['request_attempt_id'] = $this->requestAttemptId;
+        $out['retry_count'] = $this->retryCount;
+        $out['subscription'] = $this->subscription->toArray();
+        if ($this->httpResponseStatus !== null) {
+            $out['http_response_status'] = $this->httpResponseStatus;
+        }
+        if ($this->responseId !== null) {
+            $out['response_id'] = $this->responseId;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
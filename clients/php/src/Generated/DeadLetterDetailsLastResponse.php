@@ -0,0 +1,96 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `DeadLetterDetailsLastResponse` the API declares.
+ */
+final class DeadLetterDetailsLastResponse
+{
+    /**
+     * @param string $responseId carries `response_id`.
+     * @param string|null $body carries `body`.
+     * @param int|null $elapsedTimeMs carries `elapsed_time_ms`.
+     * @param array<string, string>|null $headers carries `headers`.
+     * @param int|null $httpCode carries `http_code`.
+     * @param string|null $responseErrorName carries `response_error_name`.
+     */
+    public function __construct(
+        public readonly string $responseId,
+        public readonly ?string $body = null,
+        public readonly ?int $elapsedTimeMs = null,
+        public readonly ?array $headers = null,
+        public readonly ?int $httpCode = null,
+        public readonly ?string $responseErrorName = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'DeadLetterDetailsLastResponse');
+        $read = [
+            'responseId' => Runtime::read($fields, 'response_id', Runtime::uuid(...)),
+            'body' => Runtime::maybe($fields, 'body', Runtime::text(...)),
+            'elapsedTimeMs' => Runtime::maybe($fields, 'elapsed_time_ms', Runtime::integer(...)),
+            'headers' => Runtime::maybe($fields, 'headers', Runtime::mapOf(Runtime::text(...))),
+            'httpCode' => Runtime::maybe($fields, 'http_code', Runtime::integer(...)),
+            'responseErrorName' => Runtime::maybe($fields, 'response_error_name', Runtime::text(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['response_id'] = $this->responseId;
+        if ($this->body !== null) {
+            $out['body'] = $this->body;
+        }
+        if ($this->elapsedTimeMs !== null) {
+            $out['elapsed_time_ms'] = $this->elapsedTimeMs;
+        }
+        if ($this->headers !== null) {
+            $out['headers'] = Runtime::mapping($this->headers);
+        }
+        if ($this->httpCode !== null) {
+            $out['http_code'] = $this->httpCode;
+        }
+        if ($this->responseErrorName !== null) {
+            $out['response_error_name'] = $this->responseErrorName;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
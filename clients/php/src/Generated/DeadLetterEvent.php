@@ -0,0 +1,70 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `DeadLetterEvent` the API declares.
+ */
+final class DeadLetterEvent
+{
+    /**
+     * @param string $eventId carries `event_id`.
+     * @param string $eventTypeName carries `event_type_name`.
+     */
+    public function __construct(
+        public readonly string $eventId,
+        public readonly string $eventTypeName,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'DeadLetterEvent');
+        $read = [
+            'eventId' => Runtime::read($fields, 'event_id', Runtime::uuid(...)),
+            'eventTypeName' => Runtime::read($fields, 'event_type_name', Runtime::text(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['event_id'] = $this->eventId;
+        $out['event_type_name'] = $this->eventTypeName;
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
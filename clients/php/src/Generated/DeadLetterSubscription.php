@@ -0,0 +1,72 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `DeadLetterSubscription` the API declares.
+ */
+final class DeadLetterSubscription
+{
+    /**
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string|null $description carries `description`.
+     */
+    public function __construct(
+        public readonly string $subscriptionId,
+        public readonly ?string $description = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'DeadLetterSubscription');
+        $read = [
+            'subscriptionId' => Runtime::read($fields, 'subscription_id', Runtime::uuid(...)),
+            'description' => Runtime::maybe($fields, 'description', Runtime::text(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['subscription_id'] = $this->subscriptionId;
+        if ($this->description !== null) {
+            $out['description'] = $this->description;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
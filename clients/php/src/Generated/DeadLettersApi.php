@@ -0,0 +1,194 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+use Hook0\Transport;
+
+/**
+ * What the API declares under `deadLetters`, issued through the transport it is handed.
+ */
+final class DeadLettersApi
+{
+    public function __construct(private readonly Transport $transport)
+    {
+    }
+
+    /**
+     * Delete a dead letter
+     *
+     * @param string $deadLetterId carries `dead_letter_id`.
+     * @param string $applicationId carries `application_id`.
+     */
+    public function delete(string $deadLetterId, string $applicationId): void
+    {
+        $this->checkAnswer(
+            $this->transport->request(
+                'DELETE',
+                Runtime::path(
+                    '/api/v1/dead_letters/{dead_letter_id}',
+                    [
+                        'dead_letter_id' => $deadLetterId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+        );
+    }
+
+    /**
+     * Get a dead letter by its ID
+     *
+     * @param string $deadLetterId carries `dead_letter_id`.
+     * @param string $applicationId carries `application_id`.
+     * @return DeadLetterDetails
+     */
+    public function get(string $deadLetterId, string $applicationId): DeadLetterDetails
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                Runtime::path(
+                    '/api/v1/dead_letters/{dead_letter_id}',
+                    [
+                        'dead_letter_id' => $deadLetterId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+            DeadLetterDetails::fromJson(...),
+        );
+    }
+
+    /**
+     * List dead letters
+     *
+     * @param string $applicationId carries `application_id`.
+     * @param string|null $paginationCursor carries `pagination_cursor`.
+     * @param string|null $subscriptionId carries `subscription_id`.
+     * @return list<DeadLetter>
+     */
+    public function list(string $applicationId, ?string $paginationCursor = null, ?string $subscriptionId = null): array
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                '/api/v1/dead_letters/',
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [
+                        ['pagination_cursor', $paginationCursor],
+                        ['subscription_id', $subscriptionId],
+                    ],
+                ),
+                null,
+            ),
+            Runtime::listOf(DeadLetter::fromJson(...)),
+        );
+    }
+
+    /**
+     * Purge dead letters
+     *
+     * @param PurgeDeadLetters $body what the operation reads
+     * @return PurgedDeadLetters
+     */
+    public function purge(PurgeDeadLetters $body): PurgedDeadLetters
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                '/api/v1/dead_letters/purge',
+                [],
+                $body->toArray(),
+            ),
+            PurgedDeadLetters::fromJson(...),
+        );
+    }
+
+    /**
+     * Redeliver a dead letter
+     *
+     * @param string $deadLetterId carries `dead_letter_id`.
+     * @param RedeliverDeadLetter $body what the operation reads
+     * @return RedeliveredDeadLetter
+     */
+    public function redeliver(string $deadLetterId, RedeliverDeadLetter $body): RedeliveredDeadLetter
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/dead_letters/{dead_letter_id}/redeliver',
+                    [
+                        'dead_letter_id' => $deadLetterId,
+                    ],
+                ),
+                [],
+                $body->toArray(),
+            ),
+            RedeliveredDeadLetter::fromJson(...),
+        );
+    }
+
+    /**
+     * Redeliver dead letters
+     *
+     * @param RedeliverDeadLetters $body what the operation reads
+     * @return list<RedeliveredDeadLetter>
+     */
+    public function redeliverMany(RedeliverDeadLetters $body): array
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                '/api/v1/dead_letters/redeliver',
+                [],
+                $body->toArray(),
+            ),
+            Runtime::listOf(RedeliveredDeadLetter::fromJson(...)),
+        );
+    }
+
+    /**
+     * Raise what the API reported, and answer nothing when it reported nothing.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     */
+    private function checkAnswer(array $answered): void
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+    }
+
+    /**
+     * Raise what the API reported, or read back the value it answered.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     * @param \Closure $reader what turns that body into the value the API declares
+     */
+    private function readAnswer(array $answered, \Closure $reader): mixed
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+
+        return $reader(Runtime::decodePayload($answered[1]));
+    }
+}
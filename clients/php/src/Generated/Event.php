@@ -22,6 +22,12 @@ final class Event
      * @param \DateTimeImmutable $occurredAt carries `occurred_at`.
      * @param string $payloadContentType carries `payload_content_type`.
      * @param \DateTimeImmutable $receivedAt carries `received_at`.
+     * @param \DateTimeImmutable|null $cancelledAt carries `cancelled_at`: Date the scheduled delivery of the event was
+     *   cancelled
+     * @param \DateTimeImmutable|null $deliverAt carries `deliver_at`: Date from which the event is delivered, when it
+     *   was scheduled for a later delivery
+     * @param int|null $eventTypeVersion carries `event_type_version`.
+     * @param \DateTimeImmutable|null $expiresAt carries `expires_at`: Date after which the event is no longer delivered
      * @param mixed $metadata carries `metadata`.
      */
     public function __construct(
@@ -32,6 +38,10 @@ final class Event
         public readonly \DateTimeImmutable $occurredAt,
         public readonly string $payloadContentType,
         public readonly \DateTimeImmutable $receivedAt,
+        public readonly ?\DateTimeImmutable $cancelledAt = null,
+        public readonly ?\DateTimeImmutable $deliverAt = null,
+        public readonly ?int $eventTypeVersion = null,
+        public readonly ?\DateTimeImmutable $expiresAt = null,
         public readonly mixed $metadata = null,
     ) {
     }
@@ -57,6 +67,10 @@ final class Event
             'occurredAt' => Runtime::read($fields, 'occurred_at', Runtime::dateTime(...)),
             'payloadContentType' => Runtime::read($fields, 'payload_content_type', Runtime::text(...)),
             'receivedAt' => Runtime::read($fields, 'received_at', Runtime::dateTime(...)),
+            'cancelledAt' => Runtime::maybe($fields, 'cancelled_at', Runtime::dateTime(...)),
+            'deliverAt' => Runtime::maybe($fields, 'deliver_at', Runtime::dateTime(...)),
+            'eventTypeVersion' => Runtime::maybe($fields, 'event_type_version', Runtime::integer(...)),
+            'expiresAt' => Runtime::maybe($fields, 'expires_at', Runtime::dateTime(...)),
             'metadata' => Runtime::maybe($fields, 'metadata', Runtime::jsonValue(...)),
         ];
 
@@ -78,6 +92,18 @@ final class Event
         $out['occurred_at'] = Runtime::moment($this->occurredAt);
         $out['payload_content_type'] = $this->payloadContentType;
         $out['received_at'] = Runtime::moment($this->receivedAt);
+        if ($this->cancelledAt !== null) {
+            $out['cancelled_at'] = Runtime::moment($this->cancelledAt);
+        }
+        if ($this->deliverAt !== null) {
+            $out['deliver_at'] = Runtime::moment($this->deliverAt);
+        }
+        if ($this->eventTypeVersion !== null) {
+            $out['event_type_version'] = $this->eventTypeVersion;
+        }
+        if ($this->expiresAt !== null) {
+            $out['expires_at'] = Runtime::moment($this->expiresAt);
+        }
         if ($this->metadata !== null) {
             $out['metadata'] = $this->metadata;
         }
@@ -24,8 +24,16 @@ final class EventPost
      *   699050 characters (512 KiB base64-encoded).
      * @param string $payloadContentType carries `payload_content_type`: Content type of the payload. Valid values:
      *   text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
+     * @param \DateTimeImmutable|null $deliverAt carries `deliver_at`: Optional date from which the event is delivered
+     *   to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the
+     *   future as the events retention of the appl
      * @param string|null $eventId carries `event_id`: Optional unique identifier for this event (client-generated
      *   UUID). If not provided, a UUIDv7 will be generated by the server.
+     * @param int|null $eventTypeVersion carries `event_type_version`: Optional version of the event type the payload
+     *   follows. Subscriptions pinned to another version of the event type do not receive the event.
+     * @param \DateTimeImmutable|null $expiresAt carries `expires_at`: Optional date after which the event is no longer
+     *   delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default
+     *   time to live of its event type, counted f
      * @param array<string, string>|null $metadata carries `metadata`: Optional metadata key-value pairs associated with
      *   the event.
      */
@@ -36,7 +44,10 @@ final class EventPost
         public readonly \DateTimeImmutable $occurredAt,
         public readonly string $payload,
         public readonly string $payloadContentType,
+        public readonly ?\DateTimeImmutable $deliverAt = null,
         public readonly ?string $eventId = null,
+        public readonly ?int $eventTypeVersion = null,
+        public readonly ?\DateTimeImmutable $expiresAt = null,
         public readonly ?array $metadata = null,
     ) {
     }
@@ -61,7 +72,10 @@ final class EventPost
             'occurredAt' => Runtime::read($fields, 'occurred_at', Runtime::dateTime(...)),
             'payload' => Runtime::read($fields, 'payload', Runtime::text(...)),
             'payloadContentType' => Runtime::read($fields, 'payload_content_type', Runtime::text(...)),
+            'deliverAt' => Runtime::maybe($fields, 'deliver_at', Runtime::dateTime(...)),
             'eventId' => Runtime::maybe($fields, 'event_id', Runtime::uuid(...)),
+            'eventTypeVersion' => Runtime::maybe($fields, 'event_type_version', Runtime::integer(...)),
+            'expiresAt' => Runtime::maybe($fields, 'expires_at', Runtime::dateTime(...)),
             'metadata' => Runtime::maybe($fields, 'metadata', Runtime::mapOf(Runtime::text(...))),
         ];
 
@@ -82,9 +96,18 @@ final class EventPost
         $out['occurred_at'] = Runtime::moment($this->occurredAt);
         $out['payload'] = $this->payload;
         $out['payload_content_type'] = $this->payloadContentType;
+        if ($this->deliverAt !== null) {
+            $out['deliver_at'] = Runtime::moment($this->deliverAt);
+        }
         if ($this->eventId !== null) {
             $out['event_id'] = $this->eventId;
         }
+        if ($this->eventTypeVersion !== null) {
+            $out['event_type_version'] = $this->eventTypeVersion;
+        }
+        if ($this->expiresAt !== null) {
+            $out['expires_at'] = Runtime::moment($this->expiresAt);
+        }
         if ($this->metadata !== null) {
             $out['metadata'] = Runtime::mapping($this->metadata);
         }
@@ -16,15 +16,25 @@ final class EventType
 {
     /**
      * @param string $eventTypeName carries `event_type_name`.
+     * @param EventTypePayloadSchemaMode $payloadSchemaMode carries `payload_schema_mode`, one of
+     *   `EventTypePayloadSchemaMode`.
      * @param string $resourceTypeName carries `resource_type_name`.
      * @param string $serviceName carries `service_name`.
      * @param string $verbName carries `verb_name`.
+     * @param list<EventTypeVersions> $versions carries `versions`.
+     * @param int|null $defaultTtlS carries `default_ttl_s`: Time to live in seconds of the events of this type that do
+     *   not give an expiry date
+     * @param mixed $payloadSchema carries `payload_schema`.
      */
     public function __construct(
         public readonly string $eventTypeName,
+        public readonly EventTypePayloadSchemaMode $payloadSchemaMode,
         public readonly string $resourceTypeName,
         public readonly string $serviceName,
         public readonly string $verbName,
+        public readonly array $versions,
+        public readonly ?int $defaultTtlS = null,
+        public readonly mixed $payloadSchema = null,
     ) {
     }
 
@@ -43,9 +53,17 @@ final class EventType
         $fields = Runtime::asFields($value, 'EventType');
         $read = [
             'eventTypeName' => Runtime::read($fields, 'event_type_name', Runtime::text(...)),
+            'payloadSchemaMode' => Runtime::read(
+                $fields,
+                'payload_schema_mode',
+                Runtime::memberOf(EventTypePayloadSchemaMode::class),
+            ),
             'resourceTypeName' => Runtime::read($fields, 'resource_type_name', Runtime::text(...)),
             'serviceName' => Runtime::read($fields, 'service_name', Runtime::text(...)),
             'verbName' => Runtime::read($fields, 'verb_name', Runtime::text(...)),
+            'versions' => Runtime::read($fields, 'versions', Runtime::listOf(EventTypeVersions::fromJson(...))),
+            'defaultTtlS' => Runtime::maybe($fields, 'default_ttl_s', Runtime::integer(...)),
+            'payloadSchema' => Runtime::maybe($fields, 'payload_schema', Runtime::jsonValue(...)),
         ];
 
         return new self(...$read);
@@ -60,9 +78,17 @@ final class EventType
     {
         $out = [];
         $out['event_type_name'] = $this->eventTypeName;
+        $out['payload_schema_mode'] = $this->payloadSchemaMode->value;
         $out['resource_type_name'] = $this->resourceTypeName;
         $out['service_name'] = $this->serviceName;
         $out['verb_name'] = $this->verbName;
+        $out['versions'] = array_map(static fn ($item0) => $item0->toArray(), $this->versions);
+        if ($this->defaultTtlS !== null) {
+            $out['default_ttl_s'] = $this->defaultTtlS;
+        }
+        if ($this->payloadSchema !== null) {
+            $out['payload_schema'] = $this->payloadSchema;
+        }
 
         return $out;
     }
@@ -19,12 +19,21 @@ final class EventTypePost
      * @param string $resourceType carries `resource_type`.
      * @param string $service carries `service`.
      * @param string $verb carries `verb`.
+     * @param int|null $defaultTtlS carries `default_ttl_s`: Time to live in seconds of the events of this type that do
+     *   not give an expiry date; their pending deliveries are cancelled once it is over
+     * @param mixed $payloadSchema carries `payload_schema`: JSON Schema the payloads of the `application/json` events
+     *   of this type must match; remote references are not supported
+     * @param EventTypePostPayloadSchemaMode|null $payloadSchemaMode carries `payload_schema_mode`, one of
+     *   `EventTypePostPayloadSchemaMode`: How the payload schema is applied at ingestion (default: `enforce`)
      */
     public function __construct(
         public readonly string $applicationId,
         public readonly string $resourceType,
         public readonly string $service,
         public readonly string $verb,
+        public readonly ?int $defaultTtlS = null,
+        public readonly mixed $payloadSchema = null,
+        public readonly ?EventTypePostPayloadSchemaMode $payloadSchemaMode = null,
     ) {
     }
 
@@ -46,6 +55,13 @@ final class EventTypePost
             'resourceType' => Runtime::read($fields, 'resource_type', Runtime::text(...)),
             'service' => Runtime::read($fields, 'service', Runtime::text(...)),
             'verb' => Runtime::read($fields, 'verb', Runtime::text(...)),
+            'defaultTtlS' => Runtime::maybe($fields, 'default_ttl_s', Runtime::integer(...)),
+            'payloadSchema' => Runtime::maybe($fields, 'payload_schema', Runtime::jsonValue(...)),
+            'payloadSchemaMode' => Runtime::maybe(
+                $fields,
+                'payload_schema_mode',
+                Runtime::memberOf(EventTypePostPayloadSchemaMode::class),
+            ),
         ];
 
         return new self(...$read);
@@ -63,6 +79,15 @@ final class EventTypePost
         $out['resource_type'] = $this->resourceType;
         $out['service'] = $this->service;
         $out['verb'] = $this->verb;
+        if ($this->defaultTtlS !== null) {
+            $out['default_ttl_s'] = $this->defaultTtlS;
+        }
+        if ($this->payloadSchema !== null) {
+            $out['payload_schema'] = $this->payloadSchema;
+        }
+        if ($this->payloadSchemaMode !== null) {
+            $out['payload_schema_mode'] = $this->payloadSchemaMode->value;
+        }
 
         return $out;
     }
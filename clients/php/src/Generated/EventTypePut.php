@@ -0,0 +1,91 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `EventTypePut` the API declares.
+ */
+final class EventTypePut
+{
+    /**
+     * @param string $applicationId carries `application_id`.
+     * @param int|null $defaultTtlS carries `default_ttl_s`: Time to live in seconds of the events of this type that do
+     *   not give an expiry date; their pending deliveries are cancelled once it is over
+     * @param mixed $payloadSchema carries `payload_schema`: JSON Schema the payloads of the `application/json` events
+     *   of this type must match; remote references are not supported
+     * @param EventTypePutPayloadSchemaMode|null $payloadSchemaMode carries `payload_schema_mode`, one of
+     *   `EventTypePutPayloadSchemaMode`: How the payload schema is applied at ingestion (default: `enforce`)
+     */
+    public function __construct(
+        public readonly string $applicationId,
+        public readonly ?int $defaultTtlS = null,
+        public readonly mixed $payloadSchema = null,
+        public readonly ?EventTypePutPayloadSchemaMode $payloadSchemaMode = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'EventTypePut');
+        $read = [
+            'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
+            'defaultTtlS' => Runtime::maybe($fields, 'default_ttl_s', Runtime::integer(...)),
+            'payloadSchema' => Runtime::maybe($fields, 'payload_schema', Runtime::jsonValue(...)),
+            'payloadSchemaMode' => Runtime::maybe(
+                $fields,
+                'payload_schema_mode',
+                Runtime::memberOf(EventTypePutPayloadSchemaMode::class),
+            ),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['application_id'] = $this->applicationId;
+        if ($this->defaultTtlS !== null) {
+            $out['default_ttl_s'] = $this->defaultTtlS;
+        }
+        if ($this->payloadSchema !== null) {
+            $out['payload_schema'] = $this->payloadSchema;
+        }
+        if ($this->payloadSchemaMode !== null) {
+            $out['payload_schema_mode'] = $this->payloadSchemaMode->value;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,90 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `EventTypeVersion` the API declares.
+ */
+final class EventTypeVersion
+{
+    /**
+     * @param \DateTimeImmutable $createdAt carries `created_at`.
+     * @param int $version carries `version`.
+     * @param \DateTimeImmutable|null $deprecatedAt carries `deprecated_at`.
+     * @param mixed $payloadSchema carries `payload_schema`: JSON Schema the payloads of the events declaring this
+     *   version must match, instead of the one of the event type
+     * @param \DateTimeImmutable|null $sunsetAt carries `sunset_at`: Date from which the events declaring this version
+     *   are refused
+     */
+    public function __construct(
+        public readonly \DateTimeImmutable $createdAt,
+        public readonly int $version,
+        public readonly ?\DateTimeImmutable $deprecatedAt = null,
+        public readonly mixed $payloadSchema = null,
+        public readonly ?\DateTimeImmutable $sunsetAt = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'EventTypeVersion');
+        $read = [
+            'createdAt' => Runtime::read($fields, 'created_at', Runtime::dateTime(...)),
+            'version' => Runtime::read($fields, 'version', Runtime::integer(...)),
+            'deprecatedAt' => Runtime::maybe($fields, 'deprecated_at', Runtime::dateTime(...)),
+            'payloadSchema' => Runtime::maybe($fields, 'payload_schema', Runtime::jsonValue(...)),
+            'sunsetAt' => Runtime::maybe($fields, 'sunset_at', Runtime::dateTime(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['created_at'] = Runtime::moment($this->createdAt);
+        $out['version'] = $this->version;
+        if ($this->deprecatedAt !== null) {
+            $out['deprecated_at'] = Runtime::moment($this->deprecatedAt);
+        }
+        if ($this->payloadSchema !== null) {
+            $out['payload_schema'] = $this->payloadSchema;
+        }
+        if ($this->sunsetAt !== null) {
+            $out['sunset_at'] = Runtime::moment($this->sunsetAt);
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
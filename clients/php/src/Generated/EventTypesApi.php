@@ -38,6 +38,31 @@ final class EventTypesApi
         );
     }
 
+    /**
+     * Create a new event type version
+     *
+     * @param string $eventTypeName carries `event_type_name`.
+     * @param EventTypeVersionPost $body what the operation reads
+     * @return EventTypeVersion
+     */
+    public function createVersion(string $eventTypeName, EventTypeVersionPost $body): EventTypeVersion
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/event_types/{event_type_name}/versions',
+                    [
+                        'event_type_name' => $eventTypeName,
+                    ],
+                ),
+                [],
+                $body->toArray(),
+            ),
+            EventTypeVersion::fromJson(...),
+        );
+    }
+
     /**
      * Delete an event type
      *
@@ -66,6 +91,31 @@ final class EventTypesApi
         );
     }
 
+    /**
+     * Deprecate an event type version
+     *
+     * @param string $eventTypeName carries `event_type_name`.
+     * @param EventTypeVersionDeprecate $body what the operation reads
+     * @return EventTypeVersion
+     */
+    public function deprecateVersion(string $eventTypeName, EventTypeVersionDeprecate $body): EventTypeVersion
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/event_types/{event_type_name}/deprecate',
+                    [
+                        'event_type_name' => $eventTypeName,
+                    ],
+                ),
+                [],
+                $body->toArray(),
+            ),
+            EventTypeVersion::fromJson(...),
+        );
+    }
+
     /**
      * Get an event type by its name
      *
@@ -120,6 +170,31 @@ final class EventTypesApi
         );
     }
 
+    /**
+     * Update an event type
+     *
+     * @param string $eventTypeName carries `event_type_name`.
+     * @param EventTypePut $body what the operation reads
+     * @return EventType
+     */
+    public function update(string $eventTypeName, EventTypePut $body): EventType
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'PUT',
+                Runtime::path(
+                    '/api/v1/event_types/{event_type_name}',
+                    [
+                        'event_type_name' => $eventTypeName,
+                    ],
+                ),
+                [],
+                $body->toArray(),
+            ),
+            EventType::fromJson(...),
+        );
+    }
+
     /**
      * Raise what the API reported, and answer nothing when it reported nothing.
      *
@@ -23,6 +23,12 @@ final class EventWithPayload
      * @param string $payload carries `payload`.
      * @param string $payloadContentType carries `payload_content_type`.
      * @param \DateTimeImmutable $receivedAt carries `received_at`.
+     * @param \DateTimeImmutable|null $cancelledAt carries `cancelled_at`: Date the scheduled delivery of the event was
+     *   cancelled
+     * @param \DateTimeImmutable|null $deliverAt carries `deliver_at`: Date from which the event is delivered, when it
+     *   was scheduled for a later delivery
+     * @param int|null $eventTypeVersion carries `event_type_version`.
+     * @param \DateTimeImmutable|null $expiresAt carries `expires_at`: Date after which the event is no longer delivered
      * @param mixed $metadata carries `metadata`.
      */
     public function __construct(
@@ -34,6 +40,10 @@ final class EventWithPayload
         public readonly string $payload,
         public readonly string $payloadContentType,
         public readonly \DateTimeImmutable $receivedAt,
+        public readonly ?\DateTimeImmutable $cancelledAt = null,
+        public readonly ?\DateTimeImmutable $deliverAt = null,
+        public readonly ?int $eventTypeVersion = null,
+        public readonly ?\DateTimeImmutable $expiresAt = null,
         public readonly mixed $metadata = null,
     ) {
     }
@@ -60,6 +70,10 @@ final class EventWithPayload
             'payload' => Runtime::read($fields, 'payload', Runtime::text(...)),
             'payloadContentType' => Runtime::read($fields, 'payload_content_type', Runtime::text(...)),
             'receivedAt' => Runtime::read($fields, 'received_at', Runtime::dateTime(...)),
+            'cancelledAt' => Runtime::maybe($fields, 'cancelled_at', Runtime::dateTime(...)),
+            'deliverAt' => Runtime::maybe($fields, 'deliver_at', Runtime::dateTime(...)),
+            'eventTypeVersion' => Runtime::maybe($fields, 'event_type_version', Runtime::integer(...)),
+            'expiresAt' => Runtime::maybe($fields, 'expires_at', Runtime::dateTime(...)),
             'metadata' => Runtime::maybe($fields, 'metadata', Runtime::jsonValue(...)),
         ];
 
@@ -82,6 +96,18 @@ final class EventWithPayload
         $out['payload'] = $this->payload;
         $out['payload_content_type'] = $this->payloadContentType;
         $out['received_at'] = Runtime::moment($this->receivedAt);
+        if ($this->cancelledAt !== null) {
+            $out['cancelled_at'] = Runtime::moment($this->cancelledAt);
+        }
+        if ($this->deliverAt !== null) {
+            $out['deliver_at'] = Runtime::moment($this->deliverAt);
+        }
+        if ($this->eventTypeVersion !== null) {
+            $out['event_type_version'] = $this->eventTypeVersion;
+        }
+        if ($this->expiresAt !== null) {
+            $out['expires_at'] = Runtime::moment($this->expiresAt);
+        }
         if ($this->metadata !== null) {
             $out['metadata'] = $this->metadata;
         }
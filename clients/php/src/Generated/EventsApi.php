@@ -19,6 +19,29 @@ final class EventsApi
     {
     }
 
+    /**
+     * Cancel a scheduled event
+     *
+     * @param string $eventId carries `event_id`.
+     * @param CancelEvent $body what the operation reads
+     */
+    public function cancel(string $eventId, CancelEvent $body): void
+    {
+        $this->checkAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/events/{event_id}/cancel',
+                    [
+                        'event_id' => $eventId,
+                    ],
+                ),
+                [],
+                $body->toArray(),
+            ),
+        );
+    }
+
     /**
      * Get an event by its ID
      *
@@ -68,13 +91,34 @@ final class EventsApi
         );
     }
 
+    /**
+     * Ingest a batch of events
+     *
+     * @param EventsBatchPost $body what the operation reads
+     * @return IngestedEventsBatch
+     */
+    public function ingestBatch(EventsBatchPost $body): IngestedEventsBatch
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                '/api/v1/events/batch',
+                [],
+                $body->toArray(),
+            ),
+            IngestedEventsBatch::fromJson(...),
+        );
+    }
+
     /**
      * List latest events
      *
      * @param string $applicationId carries `application_id`.
+     * @param bool|null $scheduled carries `scheduled`: Only list the events scheduled for a later delivery that was not
+     *   cancelled, soonest first
      * @return list<Event>
      */
-    public function list(string $applicationId): array
+    public function list(string $applicationId, ?bool $scheduled = null): array
     {
         return $this->readAnswer(
             $this->transport->request(
@@ -84,7 +128,9 @@ final class EventsApi
                     [
                         ['application_id', $applicationId],
                     ],
-                    [],
+                    [
+                        ['scheduled', $scheduled],
+                    ],
                 ),
                 null,
             ),
@@ -0,0 +1,72 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `EventsBatchPost` the API declares.
+ */
+final class EventsBatchPost
+{
+    /**
+     * @param string $applicationId carries `application_id`: UUID of the application the events belong to. Events of
+     *   the batch that carry another application ID are not ingested.
+     * @param list<EventsBatchPostEvents> $events carries `events`: Events to ingest. Length: 1-100 events; the whole
+     *   request body is also subject to the usual request size limit.
+     */
+    public function __construct(
+        public readonly string $applicationId,
+        public readonly array $events,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'EventsBatchPost');
+        $read = [
+            'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
+            'events' => Runtime::read($fields, 'events', Runtime::listOf(EventsBatchPostEvents::fromJson(...))),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['application_id'] = $this->applicationId;
+        $out['events'] = array_map(static fn ($item0) => $item0->toArray(), $this->events);
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,126 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `EventsBatchPostEvents` the API declares.
+ */
+final class EventsBatchPostEvents
+{
+    /**
+     * @param string $applicationId carries `application_id`: UUID of the application this event belongs to.
+     * @param string $eventType carries `event_type`: The type of event (e.g., 'user.created', 'order.completed').
+     *   Length: 1-200 characters.
+     * @param array<string, string> $labels carries `labels`: Labels for event filtering and routing to subscriptions.
+     * @param \DateTimeImmutable $occurredAt carries `occurred_at`: Timestamp when the event occurred.
+     * @param string $payload carries `payload`: The event payload. For binary content, use base64 encoding. Max length:
+     *   699050 characters (512 KiB base64-encoded).
+     * @param string $payloadContentType carries `payload_content_type`: Content type of the payload. Valid values:
+     *   text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
+     * @param \DateTimeImmutable|null $deliverAt carries `deliver_at`: Optional date from which the event is delivered
+     *   to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the
+     *   future as the events retention of the appl
+     * @param string|null $eventId carries `event_id`: Optional unique identifier for this event (client-generated
+     *   UUID). If not provided, a UUIDv7 will be generated by the server.
+     * @param int|null $eventTypeVersion carries `event_type_version`: Optional version of the event type the payload
+     *   follows. Subscriptions pinned to another version of the event type do not receive the event.
+     * @param \DateTimeImmutable|null $expiresAt carries `expires_at`: Optional date after which the event is no longer
+     *   delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default
+     *   time to live of its event type, counted f
+     * @param array<string, string>|null $metadata carries `metadata`: Optional metadata key-value pairs associated with
+     *   the event.
+     */
+    public function __construct(
+        public readonly string $applicationId,
+        public readonly string $eventType,
+        public readonly array $labels,
+        public readonly \DateTimeImmutable $occurredAt,
+        public readonly string $payload,
+        public readonly string $payloadContentType,
+        public readonly ?\DateTimeImmutable $deliverAt = null,
+        public readonly ?string $eventId = null,
+        public readonly ?int $eventTypeVersion = null,
+        public readonly ?\DateTimeImmutable $expiresAt = null,
+        public readonly ?array $metadata = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'EventsBatchPostEvents');
+        $read = [
+            'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
+            'eventType' => Runtime::read($fields, 'event_type', Runtime::text(...)),
+            'labels' => Runtime::read($fields, 'labels', Runtime::mapOf(Runtime::text(...))),
+            'occurredAt' => Runtime::read($fields, 'occurred_at', Runtime::dateTime(...)),
+            'payload' => Runtime::read($fields, 'payload', Runtime::text(...)),
+            'payloadContentType' => Runtime::read($fields, 'payload_content_type', Runtime::text(...)),
+            'deliverAt' => Runtime::maybe($fields, 'deliver_at', Runtime::dateTime(...)),
+            'eventId' => Runtime::maybe($fields, 'event_id', Runtime::uuid(...)),
+            'eventTypeVersion' => Runtime::maybe($fields, 'event_type_version', Runtime::integer(...)),
+            'expiresAt' => Runtime::maybe($fields, 'expires_at', Runtime::dateTime(...)),
+            'metadata' => Runtime::maybe($fields, 'metadata', Runtime::mapOf(Runtime::text(...))),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['application_id'] = $this->applicationId;
+        $out['event_type'] = $this->eventType;
+        $out['labels'] = Runtime::mapping($this->labels);
+        $out['occurred_at'] = Runtime::moment($this->occurredAt);
+        $out['payload'] = $this->payload;
+        $out['payload_content_type'] = $this->payloadContentType;
+        if ($this->deliverAt !== null) {
+            $out['deliver_at'] = Runtime::moment($this->deliverAt);
+        }
+        if ($this->eventId !== null) {
+            $out['event_id'] = $this->eventId;
+        }
+        if ($this->eventTypeVersion !== null) {
+            $out['event_type_version'] = $this->eventTypeVersion;
+        }
+        if ($this->expiresAt !== null) {
+            $out['expires_at'] = Runtime::moment($this->expiresAt);
+        }
+        if ($this->metadata !== null) {
+            $out['metadata'] = Runtime::mapping($this->metadata);
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,73 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `Inbox` the API declares.
+ */
+final class Inbox
+{
+    /**
+     * @param list<InboxMessages> $messages carries `messages`.
+     * @param string|null $nextCursor carries `next_cursor`: Pass it as `cursor` to the next pull to continue after the
+     *   last message of this one
+     */
+    public function __construct(
+        public readonly array $messages,
+        public readonly ?string $nextCursor = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'Inbox');
+        $read = [
+            'messages' => Runtime::read($fields, 'messages', Runtime::listOf(InboxMessages::fromJson(...))),
+            'nextCursor' => Runtime::maybe($fields, 'next_cursor', Runtime::text(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['messages'] = array_map(static fn ($item0) => $item0->toArray(), $this->messages);
+        if ($this->nextCursor !== null) {
+            $out['next_cursor'] = $this->nextCursor;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,127 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+use Hook0\Transport;
+
+/**
+ * What the API declares under `inbox`, issued through the transport it is handed.
+ */
+final class InboxApi
+{
+    public function __construct(private readonly Transport $transport)
+    {
+    }
+
+    /**
+     * Acknowledge events pulled from an inbox
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param InboxAck $body what the operation reads
+     * @return InboxSettlement
+     */
+    public function ack(string $subscriptionId, InboxAck $body): InboxSettlement
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/inbox/{subscription_id}/ack',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                [],
+                $body->toArray(),
+            ),
+            InboxSettlement::fromJson(...),
+        );
+    }
+
+    /**
+     * Reject events pulled from an inbox
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param InboxNack $body what the operation reads
+     * @return InboxSettlement
+     */
+    public function nack(string $subscriptionId, InboxNack $body): InboxSettlement
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/inbox/{subscription_id}/nack',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                [],
+                $body->toArray(),
+            ),
+            InboxSettlement::fromJson(...),
+        );
+    }
+
+    /**
+     * Pull events from an inbox
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $applicationId carries `application_id`.
+     * @param string|null $cursor carries `cursor`: Only lease events after this cursor, returned by a previous pull
+     * @param int|null $limit carries `limit`: Maximum number of events to lease (10 by default)
+     * @param int|null $visibilityTimeoutS carries `visibility_timeout_s`: For how long in seconds the leased events are
+     *   not offered again (30 by default)
+     * @return Inbox
+     */
+    public function pull(
+        string $subscriptionId,
+        string $applicationId,
+        ?string $cursor = null,
+        ?int $limit = null,
+        ?int $visibilityTimeoutS = null,
+    ): Inbox {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                Runtime::path(
+                    '/api/v1/inbox/{subscription_id}',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [
+                        ['cursor', $cursor],
+                        ['limit', $limit],
+                        ['visibility_timeout_s', $visibilityTimeoutS],
+                    ],
+                ),
+                null,
+            ),
+            Inbox::fromJson(...),
+        );
+    }
+
+    /**
+     * Raise what the API reported, or read back the value it answered.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     * @param \Closure $reader what turns that body into the value the API declares
+     */
+    private function readAnswer(array $answered, \Closure $reader): mixed
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+
+        return $reader(Runtime::decodePayload($answered[1]));
+    }
+}
@@ -0,0 +1,108 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `InboxMessages` the API declares.
+ */
+final class InboxMessages
+{
+    /**
+     * @param string $eventId carries `event_id`.
+     * @param string $eventTypeName carries `event_type_name`.
+     * @param mixed $labels carries `labels`.
+     * @param \DateTimeImmutable $leasedUntil carries `leased_until`: When the event is offered again if it was neither
+     *   acknowledged nor rejected
+     * @param \DateTimeImmutable $occurredAt carries `occurred_at`.
+     * @param string $payload carries `payload`: Base64-encoded
+     * @param string $payloadContentType carries `payload_content_type`.
+     * @param string $receipt carries `receipt`: Identifies this lease of the request attempt; a later lease of the same
+     *   attempt gets another one
+     * @param \DateTimeImmutable $receivedAt carries `received_at`.
+     * @param string $requestAttemptId carries `request_attempt_id`.
+     * @param int $retryCount carries `retry_count`.
+     */
+    public function __construct(
+        public readonly string $eventId,
+        public readonly string $eventTypeName,
+        public readonly mixed $labels,
+        public readonly \DateTimeImmutable $leasedUntil,
+        public readonly \DateTimeImmutable $occurredAt,
+        public readonly string $payload,
+        public readonly string $payloadContentType,
+        public readonly string $receipt,
+        public readonly \DateTimeImmutable $receivedAt,
+        public readonly string $requestAttemptId,
+        public readonly int $retryCount,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'InboxMessages');
+        $read = [
+            'eventId' => Runtime::read($fields, 'event_id', Runtime::uuid(...)),
+            'eventTypeName' => Runtime::read($fields, 'event_type_name', Runtime::text(...)),
+            'labels' => Runtime::read($fields, 'labels', Runtime::jsonValue(...)),
+            'leasedUntil' => Runtime::read($fields, 'leased_until', Runtime::dateTime(...)),
+            'occurredAt' => Runtime::read($fields, 'occurred_at', Runtime::dateTime(...)),
+            'payload' => Runtime::read($fields, 'payload', Runtime::text(...)),
+            'payloadContentType' => Runtime::read($fields, 'payload_content_type', Runtime::text(...)),
+            'receipt' => Runtime::read($fields, 'receipt', Runtime::uuid(...)),
+            'receivedAt' => Runtime::read($fields, 'received_at', Runtime::dateTime(...)),
+            'requestAttemptId' => Runtime::read($fields, 'request_attempt_id', Runtime::uuid(...)),
+            'retryCount' => Runtime::read($fields, 'retry_count', Runtime::integer(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['event_id'] = $this->eventId;
+        $out['event_type_name'] = $this->eventTypeName;
+        $out['labels'] = $this->labels;
+        $out['leased_until'] = Runtime::moment($this->leasedUntil);
+        $out['occurred_at'] = Runtime::moment($this->occurredAt);
+        $out['payload'] = $this->payload;
+        $out['payload_content_type'] = $this->payloadContentType;
+        $out['receipt'] = $this->receipt;
+        $out['received_at'] = Runtime::moment($this->receivedAt);
+        $out['request_attempt_id'] = $this->requestAttemptId;
+        $out['retry_count'] = $this->retryCount;
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
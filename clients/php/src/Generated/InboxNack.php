@@ -0,0 +1,85 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `InboxNack` the API declares.
+ */
+final class InboxNack
+{
+    /**
+     * @param string $applicationId carries `application_id`.
+     * @param list<string> $receipts carries `receipts`: Receipts of the leased events
+     * @param bool|null $retry carries `retry`: Whether the events are offered again, as new request attempts (true by
+     *   default); they are not beyond the maximum number of retries of the subscription, or of this instance if it has
+     *   none
+     * @param int|null $retryInS carries `retry_in_s`: Delay in seconds before the events are offered again (0 by
+     *   default)
+     */
+    public function __construct(
+        public readonly string $applicationId,
+        public readonly array $receipts,
+        public readonly ?bool $retry = null,
+        public readonly ?int $retryInS = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'InboxNack');
+        $read = [
+            'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
+            'receipts' => Runtime::read($fields, 'receipts', Runtime::listOf(Runtime::uuid(...))),
+            'retry' => Runtime::maybe($fields, 'retry', Runtime::boolean(...)),
+            'retryInS' => Runtime::maybe($fields, 'retry_in_s', Runtime::integer(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['application_id'] = $this->applicationId;
+        $out['receipts'] = $this->receipts;
+        if ($this->retry !== null) {
+            $out['retry'] = $this->retry;
+        }
+        if ($this->retryInS !== null) {
+            $out['retry_in_s'] = $this->retryInS;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,71 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `InboxSettlement` the API declares.
+ */
+final class InboxSettlement
+{
+    /**
+     * @param list<string> $settled carries `settled`: Receipts of the events that were settled
+     * @param list<string> $stale carries `stale`: Receipts that settled nothing: their lease expired, or the event was
+     *   already settled
+     */
+    public function __construct(
+        public readonly array $settled,
+        public readonly array $stale,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'InboxSettlement');
+        $read = [
+            'settled' => Runtime::read($fields, 'settled', Runtime::listOf(Runtime::uuid(...))),
+            'stale' => Runtime::read($fields, 'stale', Runtime::listOf(Runtime::uuid(...))),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['settled'] = $this->settled;
+        $out['stale'] = $this->stale;
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -18,11 +18,14 @@ final class IngestedEvent
      * @param string $applicationId carries `application_id`.
      * @param string $eventId carries `event_id`.
      * @param \DateTimeImmutable $receivedAt carries `received_at`.
+     * @param IngestedEventDeprecation|null $deprecation carries `deprecation`: Present when the event was ingested with
+     *   a deprecated version of its event type
      */
     public function __construct(
         public readonly string $applicationId,
         public readonly string $eventId,
         public readonly \DateTimeImmutable $receivedAt,
+        public readonly ?IngestedEventDeprecation $deprecation = null,
     ) {
     }
 
@@ -43,6 +46,7 @@ final class IngestedEvent
             'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
             'eventId' => Runtime::read($fields, 'event_id', Runtime::uuid(...)),
             'receivedAt' => Runtime::read($fields, 'received_at', Runtime::dateTime(...)),
+            'deprecation' => Runtime::maybe($fields, 'deprecation', IngestedEventDeprecation::fromJson(...)),
         ];
 
         return new self(...$read);
@@ -59,6 +63,9 @@ final class IngestedEvent
         $out['application_id'] = $this->applicationId;
         $out['event_id'] = $this->eventId;
         $out['received_at'] = Runtime::moment($this->receivedAt);
+        if ($this->deprecation !== null) {
+            $out['deprecation'] = $this->deprecation->toArray();
+        }
 
         return $out;
     }
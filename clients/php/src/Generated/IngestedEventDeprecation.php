@@ -0,0 +1,76 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `IngestedEventDeprecation` the API declares.
+ */
+final class IngestedEventDeprecation
+{
+    /**
+     * @param \DateTimeImmutable $deprecatedAt carries `deprecated_at`.
+     * @param int $eventTypeVersion carries `event_type_version`.
+     * @param \DateTimeImmutable|null $sunsetAt carries `sunset_at`.
+     */
+    public function __construct(
+        public readonly \DateTimeImmutable $deprecatedAt,
+        public readonly int $eventTypeVersion,
+        public readonly ?\DateTimeImmutable $sunsetAt = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'IngestedEventDeprecation');
+        $read = [
+            'deprecatedAt' => Runtime::read($fields, 'deprecated_at', Runtime::dateTime(...)),
+            'eventTypeVersion' => Runtime::read($fields, 'event_type_version', Runtime::integer(...)),
+            'sunsetAt' => Runtime::maybe($fields, 'sunset_at', Runtime::dateTime(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['deprecated_at'] = Runtime::moment($this->deprecatedAt);
+        $out['event_type_version'] = $this->eventTypeVersion;
+        if ($this->sunsetAt !== null) {
+            $out['sunset_at'] = Runtime::moment($this->sunsetAt);
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
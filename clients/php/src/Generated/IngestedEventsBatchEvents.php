@@ -0,0 +1,96 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `IngestedEventsBatchEvents` the API declares.
+ */
+final class IngestedEventsBatchEvents
+{
+    /**
+     * @param IngestedEventsBatchEventsStatus $status carries `status`, one of `IngestedEventsBatchEventsStatus`.
+     * @param IngestedEventsBatchEventsDeprecation|null $deprecation carries `deprecation`: Present when the event was
+     *   ingested with a deprecated version of its event type
+     * @param string|null $eventId carries `event_id`: ID of the ingested event, or the one the event was submitted with
+     *   when it was not ingested
+     * @param IngestedEventsBatchEventsProblem|null $problem carries `problem`: Present when the event was not ingested
+     * @param \DateTimeImmutable|null $receivedAt carries `received_at`: Present when the event was ingested
+     */
+    public function __construct(
+        public readonly IngestedEventsBatchEventsStatus $status,
+        public readonly ?IngestedEventsBatchEventsDeprecation $deprecation = null,
+        public readonly ?string $eventId = null,
+        public readonly ?IngestedEventsBatchEventsProblem $problem = null,
+        public readonly ?\DateTimeImmutable $receivedAt = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'IngestedEventsBatchEvents');
+        $read = [
+            'status' => Runtime::read($fields, 'status', Runtime::memberOf(IngestedEventsBatchEventsStatus::class)),
+            'deprecation' => Runtime::maybe(
+                $fields,
+                'deprecation',
+                IngestedEventsBatchEventsDeprecation::fromJson(...),
+            ),
+            'eventId' => Runtime::maybe($fields, 'event_id', Runtime::uuid(...)),
+            'problem' => Runtime::maybe($fields, 'problem', IngestedEventsBatchEventsProblem::fromJson(...)),
+            'receivedAt' => Runtime::maybe($fields, 'received_at', Runtime::dateTime(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['status'] = $this->status->value;
+        if ($this->deprecation !== null) {
+            $out['deprecation'] = $this->deprecation->toArray();
+        }
+        if ($this->eventId !== null) {
+            $out['event_id'] = $this->eventId;
+        }
+        if ($this->problem !== null) {
+            $out['problem'] = $this->problem->toArray();
+        }
+        if ($this->receivedAt !== null) {
+            $out['received_at'] = Runtime::moment($this->receivedAt);
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
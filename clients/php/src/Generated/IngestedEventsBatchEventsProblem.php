@@ -0,0 +1,93 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `IngestedEventsBatchEventsProblem` the API declares.
+ */
+final class IngestedEventsBatchEventsProblem
+{
+    /**
+     * @param string $detail carries `detail`.
+     * @param IngestedEventsBatchEventsProblemId $id carries `id`, one of `IngestedEventsBatchEventsProblemId`:
+     *   Identifier of the problem that occurred, stable across releases
+     * @param int $status carries `status`.
+     * @param string $title carries `title`.
+     * @param string $type carries `type`: Documentation page of this problem, which is also what distinguishes one
+     *   problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a
+     *   URL.
+     * @param mixed $validation carries `validation`: Which submitted values were rejected and why, keyed by the name of
+     *   the field they were submitted under. Carried by validation failures (`Validation`, status 422) and `null` for
+     *   every other problem, as
+     */
+    public function __construct(
+        public readonly string $detail,
+        public readonly IngestedEventsBatchEventsProblemId $id,
+        public readonly int $status,
+        public readonly string $title,
+        public readonly string $type,
+        public readonly mixed $validation = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'IngestedEventsBatchEventsProblem');
+        $read = [
+            'detail' => Runtime::read($fields, 'detail', Runtime::text(...)),
+            'id' => Runtime::read($fields, 'id', Runtime::memberOf(IngestedEventsBatchEventsProblemId::class)),
+            'status' => Runtime::read($fields, 'status', Runtime::integer(...)),
+            'title' => Runtime::read($fields, 'title', Runtime::text(...)),
+            'type' => Runtime::read($fields, 'type', Runtime::text(...)),
+            'validation' => Runtime::maybe($fields, 'validation', Runtime::jsonValue(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['detail'] = $this->detail;
+        $out['id'] = $this->id->value;
+        $out['status'] = $this->status;
+        $out['title'] = $this->title;
+        $out['type'] = $this->type;
+        if ($this->validation !== null) {
+            $out['validation'] = $this->validation;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,78 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+/**
+ * One of the values the API answers with.
+ */
+enum IngestedEventsBatchEventsProblemId: string
+{
+    case OrganizationNameMissing = 'OrganizationNameMissing';
+    case UserAlreadyExist = 'UserAlreadyExist';
+    case RegistrationDisabled = 'RegistrationDisabled';
+    case PasswordTooShort = 'PasswordTooShort';
+    case PasswordTooLong = 'PasswordTooLong';
+    case PasswordSimilarToEmail = 'PasswordSimilarToEmail';
+    case PasswordSimilarToName = 'PasswordSimilarToName';
+    case PasswordTooCommon = 'PasswordTooCommon';
+    case PasswordNotDiverseEnough = 'PasswordNotDiverseEnough';
+    case OrganizationIsNotEmpty = 'OrganizationIsNotEmpty';
+    case InvitedUserDoesNotExist = 'InvitedUserDoesNotExist';
+    case InvitedUserAlreadyInOrganization = 'InvitedUserAlreadyInOrganization';
+    case ApplicationNameMissing = 'ApplicationNameMissing';
+    case InvalidRole = 'InvalidRole';
+    case EventTypeAlreadyExist = 'EventTypeAlreadyExist';
+    case EventTypeDoesNotExist = 'EventTypeDoesNotExist';
+    case EventTypeVersionAlreadyExist = 'EventTypeVersionAlreadyExist';
+    case EventTypeVersionDoesNotExist = 'EventTypeVersionDoesNotExist';
+    case EventTypeVersionSunset = 'EventTypeVersionSunset';
+    case UnauthorizedWorkers = 'UnauthorizedWorkers';
+    case EventAlreadyIngested = 'EventAlreadyIngested';
+    case EventInvalidPayloadContentType = 'EventInvalidPayloadContentType';
+    case EventInvalidBase64Payload = 'EventInvalidBase64Payload';
+    case EventInvalidJsonPayload = 'EventInvalidJsonPayload';
+    case EventPayloadSchemaViolation = 'EventPayloadSchemaViolation';
+    case EventDeliveryBeyondRetention = 'EventDeliveryBeyondRetention';
+    case EventNotScheduled = 'EventNotScheduled';
+    case LabelsAmbiguity = 'LabelsAmbiguity';
+    case InvalidDateRange = 'InvalidDateRange';
+    case RequestAttemptNotFailed = 'RequestAttemptNotFailed';
+    case RequestAttemptRetryPending = 'RequestAttemptRetryPending';
+    case SubscriptionDisabled = 'SubscriptionDisabled';
+    case SubscriptionNotInbox = 'SubscriptionNotInbox';
+    case SecretRotationInProgress = 'SecretRotationInProgress';
+    case BulkRetryAlreadyRunning = 'BulkRetryAlreadyRunning';
+    case BulkRetryFinished = 'BulkRetryFinished';
+    case RetryPolicyOverLimit = 'RetryPolicyOverLimit';
+    case ClientCertificatesDisabled = 'ClientCertificatesDisabled';
+    case InvalidClientCertificate = 'InvalidClientCertificate';
+    case SigningKeysDisabled = 'SigningKeysDisabled';
+    case AuthNoAuthorizationHeader = 'AuthNoAuthorizationHeader';
+    case AuthInvalidAuthorizationHeader = 'AuthInvalidAuthorizationHeader';
+    case AuthApplicationSecretLookupError = 'AuthApplicationSecretLookupError';
+    case AuthInvalidApplicationSecret = 'AuthInvalidApplicationSecret';
+    case AuthBiscuitLookupError = 'AuthBiscuitLookupError';
+    case AuthInvalidBiscuit = 'AuthInvalidBiscuit';
+    case AuthFailedLogin = 'AuthFailedLogin';
+    case AuthEmailNotVerified = 'AuthEmailNotVerified';
+    case AuthEmailAlreadyVerified = 'AuthEmailAlreadyVerified';
+    case AuthFailedRefresh = 'AuthFailedRefresh';
+    case AuthEmailExpired = 'AuthEmailExpired';
+    case TooManyMembersPerOrganization = 'TooManyMembersPerOrganization';
+    case TooManyApplicationsPerOrganization = 'TooManyApplicationsPerOrganization';
+    case TooManyEventsToday = 'TooManyEventsToday';
+    case TooManySubscriptionsPerApplication = 'TooManySubscriptionsPerApplication';
+    case TooManyEventTypesPerApplication = 'TooManyEventTypesPerApplication';
+    case JsonPayload = 'JsonPayload';
+    case Validation = 'Validation';
+    case NotFound = 'NotFound';
+    case InternalServerError = 'InternalServerError';
+    case Forbidden = 'Forbidden';
+    case RateLimited = 'RateLimited';
+    case ServiceUnavailable = 'ServiceUnavailable';
+}
@@ -0,0 +1,86 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `JsonWebKeySetKeys` the API declares.
+ */
+final class JsonWebKeySetKeys
+{
+    /**
+     * @param string $alg carries `alg`: Always `EdDSA`
+     * @param string $crv carries `crv`: Always `Ed25519`
+     * @param string $kid carries `kid`: ID of the signing key, which signatures name in their `k` part
+     * @param string $kty carries `kty`: Always `OKP`
+     * @param string $use carries `use`: Always `sig`
+     * @param string $x carries `x`: Public key, encoded in base64url without padding
+     */
+    public function __construct(
+        public readonly string $alg,
+        public readonly string $crv,
+        public readonly string $kid,
+        public readonly string $kty,
+        public readonly string $use,
+        public readonly string $x,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'JsonWebKeySetKeys');
+        $read = [
+            'alg' => Runtime::read($fields, 'alg', Runtime::text(...)),
+            'crv' => Runtime::read($fields, 'crv', Runtime::text(...)),
+            'kid' => Runtime::read($fields, 'kid', Runtime::text(...)),
+            'kty' => Runtime::read($fields, 'kty', Runtime::text(...)),
+            'use' => Runtime::read($fields, 'use', Runtime::text(...)),
+            'x' => Runtime::read($fields, 'x', Runtime::text(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['alg'] = $this->alg;
+        $out['crv'] = $this->crv;
+        $out['kid'] = $this->kid;
+        $out['kty'] = $this->kty;
+        $out['use'] = $this->use;
+        $out['x'] = $this->x;
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
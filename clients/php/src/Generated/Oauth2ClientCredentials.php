@@ -0,0 +1,86 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `OAuth2ClientCredentials` the API declares.
+ */
+final class Oauth2ClientCredentials
+{
+    /**
+     * @param string $clientId carries `client_id`.
+     * @param \DateTimeImmutable $createdAt carries `created_at`.
+     * @param list<string> $scopes carries `scopes`.
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $tokenUrl carries `token_url`.
+     * @param \DateTimeImmutable $updatedAt carries `updated_at`.
+     */
+    public function __construct(
+        public readonly string $clientId,
+        public readonly \DateTimeImmutable $createdAt,
+        public readonly array $scopes,
+        public readonly string $subscriptionId,
+        public readonly string $tokenUrl,
+        public readonly \DateTimeImmutable $updatedAt,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'OAuth2ClientCredentials');
+        $read = [
+            'clientId' => Runtime::read($fields, 'client_id', Runtime::text(...)),
+            'createdAt' => Runtime::read($fields, 'created_at', Runtime::dateTime(...)),
+            'scopes' => Runtime::read($fields, 'scopes', Runtime::listOf(Runtime::text(...))),
+            'subscriptionId' => Runtime::read($fields, 'subscription_id', Runtime::uuid(...)),
+            'tokenUrl' => Runtime::read($fields, 'token_url', Runtime::text(...)),
+            'updatedAt' => Runtime::read($fields, 'updated_at', Runtime::dateTime(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['client_id'] = $this->clientId;
+        $out['created_at'] = Runtime::moment($this->createdAt);
+        $out['scopes'] = $this->scopes;
+        $out['subscription_id'] = $this->subscriptionId;
+        $out['token_url'] = $this->tokenUrl;
+        $out['updated_at'] = Runtime::moment($this->updatedAt);
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,80 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `OAuth2ClientCredentialsPut` the API declares.
+ */
+final class Oauth2ClientCredentialsPut
+{
+    /**
+     * @param string $clientId carries `client_id`.
+     * @param string $clientSecret carries `client_secret`.
+     * @param string $tokenUrl carries `token_url`: Token endpoint of the authorization server
+     * @param list<string>|null $scopes carries `scopes`: Scopes to request; none by default
+     */
+    public function __construct(
+        public readonly string $clientId,
+        public readonly string $clientSecret,
+        public readonly string $tokenUrl,
+        public readonly ?array $scopes = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'OAuth2ClientCredentialsPut');
+        $read = [
+            'clientId' => Runtime::read($fields, 'client_id', Runtime::text(...)),
+            'clientSecret' => Runtime::read($fields, 'client_secret', Runtime::text(...)),
+            'tokenUrl' => Runtime::read($fields, 'token_url', Runtime::text(...)),
+            'scopes' => Runtime::maybe($fields, 'scopes', Runtime::listOf(Runtime::text(...))),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['client_id'] = $this->clientId;
+        $out['client_secret'] = $this->clientSecret;
+        $out['token_url'] = $this->tokenUrl;
+        if ($this->scopes !== null) {
+            $out['scopes'] = $this->scopes;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
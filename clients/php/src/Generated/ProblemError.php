@@ -37,14 +37,24 @@ class ProblemError extends \RuntimeException
         ProblemId::AuthInvalidAuthorizationHeader->value => AuthInvalidAuthorizationHeaderError::class,
         ProblemId::AuthInvalidBiscuit->value => AuthInvalidBiscuitError::class,
         ProblemId::AuthNoAuthorizationHeader->value => AuthNoAuthorizationHeaderError::class,
+        ProblemId::BulkRetryAlreadyRunning->value => BulkRetryAlreadyRunningError::class,
+        ProblemId::BulkRetryFinished->value => BulkRetryFinishedError::class,
+        ProblemId::ClientCertificatesDisabled->value => ClientCertificatesDisabledError::class,
         ProblemId::EventAlreadyIngested->value => EventAlreadyIngestedError::class,
+        ProblemId::EventDeliveryBeyondRetention->value => EventDeliveryBeyondRetentionError::class,
         ProblemId::EventInvalidBase64Payload->value => EventInvalidBase64PayloadError::class,
         ProblemId::EventInvalidJsonPayload->value => EventInvalidJsonPayloadError::class,
         ProblemId::EventInvalidPayloadContentType->value => EventInvalidPayloadContentTypeError::class,
+        ProblemId::EventNotScheduled->value => EventNotScheduledError::class,
+        ProblemId::EventPayloadSchemaViolation->value => EventPayloadSchemaViolationError::class,
         ProblemId::EventTypeAlreadyExist->value => EventTypeAlreadyExistError::class,
         ProblemId::EventTypeDoesNotExist->value => EventTypeDoesNotExistError::class,
+        ProblemId::EventTypeVersionAlreadyExist->value => EventTypeVersionAlreadyExistError::class,
+        ProblemId::EventTypeVersionDoesNotExist->value => EventTypeVersionDoesNotExistError::class,
+        ProblemId::EventTypeVersionSunset->value => EventTypeVersionSunsetError::class,
         ProblemId::Forbidden->value => ForbiddenError::class,
         ProblemId::InternalServerError->value => InternalServerErrorError::class,
+        ProblemId::InvalidClientCertificate->value => InvalidClientCertificateError::class,
         ProblemId::InvalidDateRange->value => InvalidDateRangeError::class,
         ProblemId::InvalidRole->value => InvalidRoleError::class,
         ProblemId::InvitedUserAlreadyInOrganization->value => InvitedUserAlreadyInOrganizationError::class,
@@ -62,7 +72,14 @@ class ProblemError extends \RuntimeException
         ProblemId::PasswordTooShort->value => PasswordTooShortError::class,
         ProblemId::RateLimited->value => RateLimitedError::class,
         ProblemId::RegistrationDisabled->value => RegistrationDisabledError::class,
+        ProblemId::RequestAttemptNotFailed->value => RequestAttemptNotFailedError::class,
+        ProblemId::RequestAttemptRetryPending->value => RequestAttemptRetryPendingError::class,
+        ProblemId::RetryPolicyOverLimit->value => RetryPolicyOverLimitError::class,
+        ProblemId::SecretRotationInProgress->value => SecretRotationInProgressError::class,
         ProblemId::ServiceUnavailable->value => ServiceUnavailableError::class,
+        ProblemId::SigningKeysDisabled->value => SigningKeysDisabledError::class,
+        ProblemId::SubscriptionDisabled->value => SubscriptionDisabledError::class,
+        ProblemId::SubscriptionNotInbox->value => SubscriptionNotInboxError::class,
         ProblemId::TooManyApplicationsPerOrganization->value => TooManyApplicationsPerOrganizationError::class,
         ProblemId::TooManyEventTypesPerApplication->value => TooManyEventTypesPerApplicationError::class,
         ProblemId::TooManyEventsToday->value => TooManyEventsTodayError::class,
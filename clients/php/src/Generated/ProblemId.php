@@ -28,13 +28,30 @@ enum ProblemId: string
     case InvalidRole = 'InvalidRole';
     case EventTypeAlreadyExist = 'EventTypeAlreadyExist';
     case EventTypeDoesNotExist = 'EventTypeDoesNotExist';
+    case EventTypeVersionAlreadyExist = 'EventTypeVersionAlreadyExist';
+    case EventTypeVersionDoesNotExist = 'EventTypeVersionDoesNotExist';
+    case EventTypeVersionSunset = 'EventTypeVersionSunset';
     case UnauthorizedWorkers = 'UnauthorizedWorkers';
     case EventAlreadyIngested = 'EventAlreadyIngested';
     case EventInvalidPayloadContentType = 'EventInvalidPayloadContentType';
     case EventInvalidBase64Payload = 'EventInvalidBase64Payload';
     case EventInvalidJsonPayload = 'EventInvalidJsonPayload';
+    case EventPayloadSchemaViolation = 'EventPayloadSchemaViolation';
+    case EventDeliveryBeyondRetention = 'EventDeliveryBeyondRetention';
+    case EventNotScheduled = 'EventNotScheduled';
     case LabelsAmbiguity = 'LabelsAmbiguity';
     case InvalidDateRange = 'InvalidDateRange';
+    case RequestAttemptNotFailed = 'RequestAttemptNotFailed';
+    case RequestAttemptRetryPending = 'RequestAttemptRetryPending';
+    case SubscriptionDisabled = 'SubscriptionDisabled';
+    case SubscriptionNotInbox = 'SubscriptionNotInbox';
+    case SecretRotationInProgress = 'SecretRotationInProgress';
+    case BulkRetryAlreadyRunning = 'BulkRetryAlreadyRunning';
+    case BulkRetryFinished = 'BulkRetryFinished';
+    case RetryPolicyOverLimit = 'RetryPolicyOverLimit';
+    case ClientCertificatesDisabled = 'ClientCertificatesDisabled';
+    case InvalidClientCertificate = 'InvalidClientCertificate';
+    case SigningKeysDisabled = 'SigningKeysDisabled';
     case AuthNoAuthorizationHeader = 'AuthNoAuthorizationHeader';
     case AuthInvalidAuthorizationHeader = 'AuthInvalidAuthorizationHeader';
     case AuthApplicationSecretLookupError = 'AuthApplicationSecretLookupError';
@@ -0,0 +1,70 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `RedeliveredDeadLetter` the API declares.
+ */
+final class RedeliveredDeadLetter
+{
+    /**
+     * @param string $deadLetterId carries `dead_letter_id`.
+     * @param string $requestAttemptId carries `request_attempt_id`.
+     */
+    public function __construct(
+        public readonly string $deadLetterId,
+        public readonly string $requestAttemptId,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'RedeliveredDeadLetter');
+        $read = [
+            'deadLetterId' => Runtime::read($fields, 'dead_letter_id', Runtime::uuid(...)),
+            'requestAttemptId' => Runtime::read($fields, 'request_attempt_id', Runtime::uuid(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['dead_letter_id'] = $this->deadLetterId;
+        $out['request_attempt_id'] = $this->requestAttemptId;
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
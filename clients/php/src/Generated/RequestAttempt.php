@@ -24,7 +24,11 @@ final class RequestAttempt
      *   status variant. - waiting: {type, since, until} - Scheduled for future delivery - pending: {type, since} -
      *   Ready to be processed - in_progre
      * @param RequestAttemptSubscription $subscription carries `subscription`.
+     * @param string|null $deadLetterId carries `dead_letter_id`: Dead letter the request attempt became, when it was
+     *   the last attempt of a delivery an output worker gave up on
      * @param \DateTimeImmutable|null $delayUntil carries `delay_until`.
+     * @param string|null $deliveryBatchId carries `delivery_batch_id`: Batch the request attempt was delivered in,
+     *   shared with the other request attempts of the batch and their response
      * @param \DateTimeImmutable|null $failedAt carries `failed_at`.
      * @param int|null $httpResponseStatus carries `http_response_status`.
      * @param \DateTimeImmutable|null $pickedAt carries `picked_at`.
@@ -39,7 +43,9 @@ final class RequestAttempt
         public readonly int $retryCount,
         public readonly RequestAttemptStatus $status,
         public readonly RequestAttemptSubscription $subscription,
+        public readonly ?string $deadLetterId = null,
         public readonly ?\DateTimeImmutable $delayUntil = null,
+        public readonly ?string $deliveryBatchId = null,
         public readonly ?\DateTimeImmutable $failedAt = null,
         public readonly ?int $httpResponseStatus = null,
         public readonly ?\DateTimeImmutable $pickedAt = null,
@@ -69,7 +75,9 @@ final class RequestAttempt
             'retryCount' => Runtime::read($fields, 'retry_count', Runtime::integer(...)),
             'status' => Runtime::read($fields, 'status', RequestAttemptStatus::fromJson(...)),
             'subscription' => Runtime::read($fields, 'subscription', RequestAttemptSubscription::fromJson(...)),
+            'deadLetterId' => Runtime::maybe($fields, 'dead_letter_id', Runtime::uuid(...)),
             'delayUntil' => Runtime::maybe($fields, 'delay_until', Runtime::dateTime(...)),
+            'deliveryBatchId' => Runtime::maybe($fields, 'delivery_batch_id', Runtime::uuid(...)),
             'failedAt' => Runtime::maybe($fields, 'failed_at', Runtime::dateTime(...)),
             'httpResponseStatus' => Runtime::maybe($fields, 'http_response_status', Runtime::integer(...)),
             'pickedAt' => Runtime::maybe($fields, 'picked_at', Runtime::dateTime(...)),
@@ -95,9 +103,15 @@ final class RequestAttempt
         $out['retry_count'] = $this->retryCount;
         $out['status'] = $this->status->toArray();
         $out['subscription'] = $this->subscription->toArray();
+        if ($this->deadLetterId !== null) {
+            $out['dead_letter_id'] = $this->deadLetterId;
+        }
         if ($this->delayUntil !== null) {
             $out['delay_until'] = Runtime::moment($this->delayUntil);
         }
+        if ($this->deliveryBatchId !== null) {
+            $out['delivery_batch_id'] = $this->deliveryBatchId;
+        }
         if ($this->failedAt !== null) {
             $out['failed_at'] = Runtime::moment($this->failedAt);
         }
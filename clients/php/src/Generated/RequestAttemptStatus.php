@@ -16,10 +16,14 @@ final class RequestAttemptStatus
 {
     /**
      * @param RequestAttemptStatusType $type carries `type`, one of `RequestAttemptStatusType`: Status type
-     *   discriminator. One of: waiting, pending, in_progress, successful, failed
-     * @param \DateTimeImmutable|null $at carries `at`: Timestamp when completed (present in successful, failed)
+     *   discriminator. One of: waiting, pending, in_progress, successful, failed, expired, cancelled
+     * @param \DateTimeImmutable|null $at carries `at`: Timestamp when completed (present in successful, failed,
+     *   expired, cancelled)
      * @param int|null $fullProcessingMs carries `full_processing_ms`: Total processing time in milliseconds (present in
      *   successful, failed)
+     * @param RequestAttemptStatusRuleOutcome|null $ruleOutcome carries `rule_outcome`, one of
+     *   `RequestAttemptStatusRuleOutcome`: Outcome given by a response rule of the subscription, when one matched the
+     *   response (present in successful, failed). One of: success, retryable, permanent_failure, disable_subscription
      * @param \DateTimeImmutable|null $since carries `since`: Timestamp when the status started (present in waiting,
      *   pending, in_progress)
      * @param \DateTimeImmutable|null $until carries `until`: Timestamp until which waiting (only present in waiting
@@ -29,6 +33,7 @@ final class RequestAttemptStatus
         public readonly RequestAttemptStatusType $type,
         public readonly ?\DateTimeImmutable $at = null,
         public readonly ?int $fullProcessingMs = null,
+        public readonly ?RequestAttemptStatusRuleOutcome $ruleOutcome = null,
         public readonly ?\DateTimeImmutable $since = null,
         public readonly ?\DateTimeImmutable $until = null,
     ) {
@@ -51,6 +56,11 @@ final class RequestAttemptStatus
             'type' => Runtime::read($fields, 'type', Runtime::memberOf(RequestAttemptStatusType::class)),
             'at' => Runtime::maybe($fields, 'at', Runtime::dateTime(...)),
             'fullProcessingMs' => Runtime::maybe($fields, 'full_processing_ms', Runtime::integer(...)),
+            'ruleOutcome' => Runtime::maybe(
+                $fields,
+                'rule_outcome',
+                Runtime::memberOf(RequestAttemptStatusRuleOutcome::class),
+            ),
             'since' => Runtime::maybe($fields, 'since', Runtime::dateTime(...)),
             'until' => Runtime::maybe($fields, 'until', Runtime::dateTime(...)),
         ];
@@ -73,6 +83,9 @@ final class RequestAttemptStatus
         if ($this->fullProcessingMs !== null) {
             $out['full_processing_ms'] = $this->fullProcessingMs;
         }
+        if ($this->ruleOutcome !== null) {
+            $out['rule_outcome'] = $this->ruleOutcome->value;
+        }
         if ($this->since !== null) {
             $out['since'] = Runtime::moment($this->since);
         }
@@ -17,4 +17,6 @@ enum RequestAttemptStatusType: string
     case InProgress = 'in_progress';
     case Successful = 'successful';
     case Failed = 'failed';
+    case Expired = 'expired';
+    case Cancelled = 'cancelled';
 }
@@ -93,6 +93,31 @@ final class RequestAttemptsApi
         );
     }
 
+    /**
+     * Retry a request attempt
+     *
+     * @param string $requestAttemptId carries `request_attempt_id`.
+     * @param RetryRequestAttempt $body what the operation reads
+     * @return RequestAttempt
+     */
+    public function retry(string $requestAttemptId, RetryRequestAttempt $body): RequestAttempt
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/request_attempts/{request_attempt_id}/retry',
+                    [
+                        'request_attempt_id' => $requestAttemptId,
+                    ],
+                ),
+                [],
+                $body->toArray(),
+            ),
+            RequestAttempt::fromJson(...),
+        );
+    }
+
     /**
      * Raise what the API reported, or read back the value it answered.
      *
@@ -0,0 +1,78 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SecretRotation` the API declares.
+ */
+final class SecretRotation
+{
+    /**
+     * @param string $previousSecret carries `previous_secret`: The secret the new one replaced
+     * @param \DateTimeImmutable $previousSecretExpiresAt carries `previous_secret_expires_at`.
+     * @param string $secret carries `secret`: The new secret of the subscription
+     * @param string $subscriptionId carries `subscription_id`.
+     */
+    public function __construct(
+        public readonly string $previousSecret,
+        public readonly \DateTimeImmutable $previousSecretExpiresAt,
+        public readonly string $secret,
+        public readonly string $subscriptionId,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SecretRotation');
+        $read = [
+            'previousSecret' => Runtime::read($fields, 'previous_secret', Runtime::uuid(...)),
+            'previousSecretExpiresAt' => Runtime::read($fields, 'previous_secret_expires_at', Runtime::dateTime(...)),
+            'secret' => Runtime::read($fields, 'secret', Runtime::uuid(...)),
+            'subscriptionId' => Runtime::read($fields, 'subscription_id', Runtime::uuid(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['previous_secret'] = $this->previousSecret;
+        $out['previous_secret_expires_at'] = Runtime::moment($this->previousSecretExpiresAt);
+        $out['secret'] = $this->secret;
+        $out['subscription_id'] = $this->subscriptionId;
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,161 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+use Hook0\Transport;
+
+/**
+ * What the API declares under `secretRotations`, issued through the transport it is handed.
+ */
+final class SecretRotationsApi
+{
+    public function __construct(private readonly Transport $transport)
+    {
+    }
+
+    /**
+     * Rotate the secret of a subscription
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $applicationId carries `application_id`.
+     * @param SecretRotationPost $body what the operation reads
+     * @return SecretRotation
+     */
+    public function create(string $subscriptionId, string $applicationId, SecretRotationPost $body): SecretRotation
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/subscriptions/{subscription_id}/secret_rotation',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                $body->toArray(),
+            ),
+            SecretRotation::fromJson(...),
+        );
+    }
+
+    /**
+     * Finalize the secret rotation of a subscription
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $applicationId carries `application_id`.
+     */
+    public function finalize(string $subscriptionId, string $applicationId): void
+    {
+        $this->checkAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+        );
+    }
+
+    /**
+     * Get the secret rotation of a subscription
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $applicationId carries `application_id`.
+     * @return SecretRotation
+     */
+    public function get(string $subscriptionId, string $applicationId): SecretRotation
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                Runtime::path(
+                    '/api/v1/subscriptions/{subscription_id}/secret_rotation',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+            SecretRotation::fromJson(...),
+        );
+    }
+
+    /**
+     * Roll back the secret rotation of a subscription
+     *
+     * @param string $subscriptionId carries `subscription_id`.
+     * @param string $applicationId carries `application_id`.
+     */
+    public function rollback(string $subscriptionId, string $applicationId): void
+    {
+        $this->checkAnswer(
+            $this->transport->request(
+                'POST',
+                Runtime::path(
+                    '/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback',
+                    [
+                        'subscription_id' => $subscriptionId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+        );
+    }
+
+    /**
+     * Raise what the API reported, and answer nothing when it reported nothing.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     */
+    private function checkAnswer(array $answered): void
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+    }
+
+    /**
+     * Raise what the API reported, or read back the value it answered.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     * @param \Closure $reader what turns that body into the value the API declares
+     */
+    private function readAnswer(array $answered, \Closure $reader): mixed
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+
+        return $reader(Runtime::decodePayload($answered[1]));
+    }
+}
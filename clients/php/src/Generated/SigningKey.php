@@ -0,0 +1,85 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SigningKey` the API declares.
+ */
+final class SigningKey
+{
+    /**
+     * @param string $applicationId carries `application_id`.
+     * @param \DateTimeImmutable $createdAt carries `created_at`.
+     * @param bool $isCurrent carries `is_current`: Whether deliveries are signed with this key, which only the newest
+     *   key of the application is
+     * @param string $publicKey carries `public_key`: Public key, encoded in base64url without padding like the `x`
+     *   member of its JSON Web Key
+     * @param string $signingKeyId carries `signing_key_id`: Identifies the key in the `k` part of signatures and as the
+     *   `kid` of its JSON Web Key
+     */
+    public function __construct(
+        public readonly string $applicationId,
+        public readonly \DateTimeImmutable $createdAt,
+        public readonly bool $isCurrent,
+        public readonly string $publicKey,
+        public readonly string $signingKeyId,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SigningKey');
+        $read = [
+            'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
+            'createdAt' => Runtime::read($fields, 'created_at', Runtime::dateTime(...)),
+            'isCurrent' => Runtime::read($fields, 'is_current', Runtime::boolean(...)),
+            'publicKey' => Runtime::read($fields, 'public_key', Runtime::text(...)),
+            'signingKeyId' => Runtime::read($fields, 'signing_key_id', Runtime::uuid(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['application_id'] = $this->applicationId;
+        $out['created_at'] = Runtime::moment($this->createdAt);
+        $out['is_current'] = $this->isCurrent;
+        $out['public_key'] = $this->publicKey;
+        $out['signing_key_id'] = $this->signingKeyId;
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
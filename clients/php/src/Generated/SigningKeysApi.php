@@ -0,0 +1,139 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+use Hook0\Transport;
+
+/**
+ * What the API declares under `signingKeys`, issued through the transport it is handed.
+ */
+final class SigningKeysApi
+{
+    public function __construct(private readonly Transport $transport)
+    {
+    }
+
+    /**
+     * Create a signing key
+     *
+     * @param SigningKeyPost $body what the operation reads
+     * @return SigningKey
+     */
+    public function create(SigningKeyPost $body): SigningKey
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'POST',
+                '/api/v1/signing_keys/',
+                [],
+                $body->toArray(),
+            ),
+            SigningKey::fromJson(...),
+        );
+    }
+
+    /**
+     * Retire a signing key
+     *
+     * @param string $signingKeyId carries `signing_key_id`.
+     * @param string $applicationId carries `application_id`.
+     */
+    public function delete(string $signingKeyId, string $applicationId): void
+    {
+        $this->checkAnswer(
+            $this->transport->request(
+                'DELETE',
+                Runtime::path(
+                    '/api/v1/signing_keys/{signing_key_id}',
+                    [
+                        'signing_key_id' => $signingKeyId,
+                    ],
+                ),
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+        );
+    }
+
+    /**
+     * Get the public keys of an application
+     *
+     * @param string $applicationId carries `application_id`.
+     * @return JsonWebKeySet
+     */
+    public function jwks(string $applicationId): JsonWebKeySet
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                Runtime::path(
+                    '/api/v1/jwks/{application_id}',
+                    [
+                        'application_id' => $applicationId,
+                    ],
+                ),
+                [],
+                null,
+            ),
+            JsonWebKeySet::fromJson(...),
+        );
+    }
+
+    /**
+     * List signing keys
+     *
+     * @param string $applicationId carries `application_id`.
+     * @return list<SigningKey>
+     */
+    public function list(string $applicationId): array
+    {
+        return $this->readAnswer(
+            $this->transport->request(
+                'GET',
+                '/api/v1/signing_keys/',
+                Runtime::query(
+                    [
+                        ['application_id', $applicationId],
+                    ],
+                    [],
+                ),
+                null,
+            ),
+            Runtime::listOf(SigningKey::fromJson(...)),
+        );
+    }
+
+    /**
+     * Raise what the API reported, and answer nothing when it reported nothing.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     */
+    private function checkAnswer(array $answered): void
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+    }
+
+    /**
+     * Raise what the API reported, or read back the value it answered.
+     *
+     * @param array{0: int, 1: string} $answered the status and the body the transport answered
+     * @param \Closure $reader what turns that body into the value the API declares
+     */
+    private function readAnswer(array $answered, \Closure $reader): mixed
+    {
+        ProblemError::raiseForStatus($answered[0], $answered[1]);
+
+        return $reader(Runtime::decodePayload($answered[1]));
+    }
+}
@@ -18,6 +18,8 @@ final class Subscription
      * @param string $applicationId carries `application_id`.
      * @param \DateTimeImmutable $createdAt carries `created_at`.
      * @param list<string> $dedicatedWorkers carries `dedicated_workers`.
+     * @param array<string, int> $eventTypeVersions carries `event_type_versions`: Versions the subscription is pinned
+     *   to, by event type
      * @param list<string> $eventTypes carries `event_types`.
      * @param bool $isEnabled carries `is_enabled`.
      * @param string $labelKey carries `label_key`: _Kept for backward compatibility, you should use `labels`_
@@ -25,15 +27,42 @@ final class Subscription
      * @param array<string, string> $labels carries `labels`.
      * @param array<string, string> $metadata carries `metadata`.
      * @param string $secret carries `secret`.
+     * @param SubscriptionSignatureScheme $signatureScheme carries `signature_scheme`, one of
+     *   `SubscriptionSignatureScheme`.
      * @param string $subscriptionId carries `subscription_id`.
      * @param SubscriptionTarget $target carries `target`.
      * @param \DateTimeImmutable $updatedAt carries `updated_at`.
+     * @param \DateTimeImmutable|null $autoDisabledAt carries `auto_disabled_at`: When the circuit breaker or a response
+     *   rule last disabled the subscription; cleared when it is enabled again
+     * @param string|null $autoDisabledReason carries `auto_disabled_reason`: Why the circuit breaker or a response rule
+     *   last disabled the subscription
+     * @param SubscriptionBatching|null $batching carries `batching`: Deliver the events of the subscription in batches:
+     *   one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or once its
+     *   oldest event has waited `max_wait_s` se
+     * @param SubscriptionCircuitBreaker|null $circuitBreaker carries `circuit_breaker`: Disables the subscription once
+     *   its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set
+     * @param SubscriptionDeliveryLimits|null $deliveryLimits carries `delivery_limits`: Limits on how fast deliveries
+     *   of the subscription start; deliveries over them wait for their turn, which does not count as a failure; at
+     *   least one limit must be set
      * @param string|null $description carries `description`.
+     * @param mixed $filter carries `filter`: An object with exactly one of: `and` or `or` (a list of filters), `not` (a
+     *   filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload); `label` and
+     *   `field` go alon
+     * @param SubscriptionOrderingKey|null $orderingKey carries `ordering_key`: Where the key of an event comes from, to
+     *   deliver the events that share a key one at a time and in the order they were received; exactly one of `label`
+     *   or `field` must be set
+     * @param SubscriptionPayloadTransform|null $payloadTransform carries `payload_transform`: Declarative reshaping of
+     *   an `application/json` event payload, applied by the worker before the request is signed and sent; operations
+     *   are applied in order (`fields`, `envelope`, `constants`) and a pay
+     * @param list<SubscriptionResponseRules>|null $responseRules carries `response_rules`.
+     * @param SubscriptionRetryPolicy|null $retryPolicy carries `retry_policy`: Retry schedule of the subscription,
+     *   replacing the one of the instance within its limits; every field left out keeps the value of the instance
      */
     public function __construct(
         public readonly string $applicationId,
         public readonly \DateTimeImmutable $createdAt,
         public readonly array $dedicatedWorkers,
+        public readonly array $eventTypeVersions,
         public readonly array $eventTypes,
         public readonly bool $isEnabled,
         public readonly string $labelKey,
@@ -41,10 +70,21 @@ final class Subscription
         public readonly array $labels,
         public readonly array $metadata,
         public readonly string $secret,
+        public readonly SubscriptionSignatureScheme $signatureScheme,
         public readonly string $subscriptionId,
         public readonly SubscriptionTarget $target,
         public readonly \DateTimeImmutable $updatedAt,
+        public readonly ?\DateTimeImmutable $autoDisabledAt = null,
+        public readonly ?string $autoDisabledReason = null,
+        public readonly ?SubscriptionBatching $batching = null,
+        public readonly ?SubscriptionCircuitBreaker $circuitBreaker = null,
+        public readonly ?SubscriptionDeliveryLimits $deliveryLimits = null,
         public readonly ?string $description = null,
+        public readonly mixed $filter = null,
+        public readonly ?SubscriptionOrderingKey $orderingKey = null,
+        public readonly ?SubscriptionPayloadTransform $payloadTransform = null,
+        public readonly ?array $responseRules = null,
+        public readonly ?SubscriptionRetryPolicy $retryPolicy = null,
     ) {
     }
 
@@ -65,6 +105,7 @@ final class Subscription
             'applicationId' => Runtime::read($fields, 'application_id', Runtime::uuid(...)),
             'createdAt' => Runtime::read($fields, 'created_at', Runtime::dateTime(...)),
             'dedicatedWorkers' => Runtime::read($fields, 'dedicated_workers', Runtime::listOf(Runtime::text(...))),
+            'eventTypeVersions' => Runtime::read($fields, 'event_type_versions', Runtime::mapOf(Runtime::integer(...))),
             'eventTypes' => Runtime::read($fields, 'event_types', Runtime::listOf(Runtime::text(...))),
             'isEnabled' => Runtime::read($fields, 'is_enabled', Runtime::boolean(...)),
             'labelKey' => Runtime::read($fields, 'label_key', Runtime::text(...)),
@@ -72,10 +113,33 @@ final class Subscription
             'labels' => Runtime::read($fields, 'labels', Runtime::mapOf(Runtime::text(...))),
             'metadata' => Runtime::read($fields, 'metadata', Runtime::mapOf(Runtime::text(...))),
             'secret' => Runtime::read($fields, 'secret', Runtime::uuid(...)),
+            'signatureScheme' => Runtime::read(
+                $fields,
+                'signature_scheme',
+                Runtime::memberOf(SubscriptionSignatureScheme::class),
+            ),
             'subscriptionId' => Runtime::read($fields, 'subscription_id', Runtime::uuid(...)),
             'target' => Runtime::read($fields, 'target', SubscriptionTarget::fromJson(...)),
             'updatedAt' => Runtime::read($fields, 'updated_at', Runtime::dateTime(...)),
+            'autoDisabledAt' => Runtime::maybe($fields, 'auto_disabled_at', Runtime::dateTime(...)),
+            'autoDisabledReason' => Runtime::maybe($fields, 'auto_disabled_reason', Runtime::text(...)),
+            'batching' => Runtime::maybe($fields, 'batching', SubscriptionBatching::fromJson(...)),
+            'circuitBreaker' => Runtime::maybe($fields, 'circuit_breaker', SubscriptionCircuitBreaker::fromJson(...)),
+            'deliveryLimits' => Runtime::maybe($fields, 'delivery_limits', SubscriptionDeliveryLimits::fromJson(...)),
             'description' => Runtime::maybe($fields, 'description', Runtime::text(...)),
+            'filter' => Runtime::maybe($fields, 'filter', Runtime::jsonValue(...)),
+            'orderingKey' => Runtime::maybe($fields, 'ordering_key', SubscriptionOrderingKey::fromJson(...)),
+            'payloadTransform' => Runtime::maybe(
+                $fields,
+                'payload_transform',
+                SubscriptionPayloadTransform::fromJson(...),
+            ),
+            'responseRules' => Runtime::maybe(
+                $fields,
+                'response_rules',
+                Runtime::listOf(SubscriptionResponseRules::fromJson(...)),
+            ),
+            'retryPolicy' => Runtime::maybe($fields, 'retry_policy', SubscriptionRetryPolicy::fromJson(...)),
         ];
 
         return new self(...$read);
@@ -92,6 +156,7 @@ final class Subscription
         $out['application_id'] = $this->applicationId;
         $out['created_at'] = Runtime::moment($this->createdAt);
         $out['dedicated_workers'] = $this->dedicatedWorkers;
+        $out['event_type_versions'] = Runtime::mapping($this->eventTypeVersions);
         $out['event_types'] = $this->eventTypes;
         $out['is_enabled'] = $this->isEnabled;
         $out['label_key'] = $this->labelKey;
@@ -99,12 +164,43 @@ final class Subscription
         $out['labels'] = Runtime::mapping($this->labels);
         $out['metadata'] = Runtime::mapping($this->metadata);
         $out['secret'] = $this->secret;
+        $out['signature_scheme'] = $this->signatureScheme->value;
         $out['subscription_id'] = $this->subscriptionId;
         $out['target'] = $this->target->toArray();
         $out['updated_at'] = Runtime::moment($this->updatedAt);
+        if ($this->autoDisabledAt !== null) {
+            $out['auto_disabled_at'] = Runtime::moment($this->autoDisabledAt);
+        }
+        if ($this->autoDisabledReason !== null) {
+            $out['auto_disabled_reason'] = $this->autoDisabledReason;
+        }
+        if ($this->batching !== null) {
+            $out['batching'] = $this->batching->toArray();
+        }
+        if ($this->circuitBreaker !== null) {
+            $out['circuit_breaker'] = $this->circuitBreaker->toArray();
+        }
+        if ($this->deliveryLimits !== null) {
+            $out['delivery_limits'] = $this->deliveryLimits->toArray();
+        }
         if ($this->description !== null) {
             $out['description'] = $this->description;
         }
+        if ($this->filter !== null) {
+            $out['filter'] = $this->filter;
+        }
+        if ($this->orderingKey !== null) {
+            $out['ordering_key'] = $this->orderingKey->toArray();
+        }
+        if ($this->payloadTransform !== null) {
+            $out['payload_transform'] = $this->payloadTransform->toArray();
+        }
+        if ($this->responseRules !== null) {
+            $out['response_rules'] = array_map(static fn ($item0) => $item0->toArray(), $this->responseRules);
+        }
+        if ($this->retryPolicy !== null) {
+            $out['retry_policy'] = $this->retryPolicy->toArray();
+        }
 
         return $out;
     }
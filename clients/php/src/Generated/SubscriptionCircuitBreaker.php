@@ -0,0 +1,76 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SubscriptionCircuitBreaker` the API declares.
+ */
+final class SubscriptionCircuitBreaker
+{
+    /**
+     * @param int|null $maxConsecutiveFailures carries `max_consecutive_failures`: Number of failed deliveries in a row
+     *   after which the subscription is disabled
+     * @param int|null $maxFailingHours carries `max_failing_hours`: Number of hours without a single successful
+     *   delivery after which the subscription is disabled
+     */
+    public function __construct(
+        public readonly ?int $maxConsecutiveFailures = null,
+        public readonly ?int $maxFailingHours = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SubscriptionCircuitBreaker');
+        $read = [
+            'maxConsecutiveFailures' => Runtime::maybe($fields, 'max_consecutive_failures', Runtime::integer(...)),
+            'maxFailingHours' => Runtime::maybe($fields, 'max_failing_hours', Runtime::integer(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        if ($this->maxConsecutiveFailures !== null) {
+            $out['max_consecutive_failures'] = $this->maxConsecutiveFailures;
+        }
+        if ($this->maxFailingHours !== null) {
+            $out['max_failing_hours'] = $this->maxFailingHours;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,74 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SubscriptionPayloadTransformFields` the API declares.
+ */
+final class SubscriptionPayloadTransformFields
+{
+    /**
+     * @param string $from carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that
+     *   are absent from a payload are skipped)
+     * @param string|null $to carries `to`: JSON pointer where the value is written in the result, for example `/id`;
+     *   defaults to `from`
+     */
+    public function __construct(
+        public readonly string $from,
+        public readonly ?string $to = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SubscriptionPayloadTransformFields');
+        $read = [
+            'from' => Runtime::read($fields, 'from', Runtime::text(...)),
+            'to' => Runtime::maybe($fields, 'to', Runtime::text(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['from'] = $this->from;
+        if ($this->to !== null) {
+            $out['to'] = $this->to;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -19,24 +19,59 @@ final class SubscriptionPost
      * @param list<string> $eventTypes carries `event_types`.
      * @param bool $isEnabled carries `is_enabled`.
      * @param SubscriptionPostTarget $target carries `target`.
+     * @param SubscriptionPostBatching|null $batching carries `batching`: Deliver the events in batches, as one HTTP
+     *   request with a JSON array body, rather than one by one
+     * @param SubscriptionPostCircuitBreaker|null $circuitBreaker carries `circuit_breaker`: Disable the subscription
+     *   once its deliveries keep failing; organization editors are notified by email
      * @param list<string>|null $dedicatedWorkers carries `dedicated_workers`.
+     * @param SubscriptionPostDeliveryLimits|null $deliveryLimits carries `delivery_limits`: Limit how fast deliveries
+     *   start, for this subscription or for its host
      * @param string|null $description carries `description`.
+     * @param array<string, int>|null $eventTypeVersions carries `event_type_versions`: Only receive the events of these
+     *   event types that declare this version (or no version at all)
+     * @param mixed $filter carries `filter`: Only deliver the events of the subscribed types that match this expression
+     *   over labels and JSON payload fields
      * @param string|null $labelKey carries `label_key`: _Kept for backward compatibility, you should use `labels`_
      * @param string|null $labelValue carries `label_value`: _Kept for backward compatibility, you should use `labels`_
      * @param array<string, string>|null $labels carries `labels`.
      * @param array<string, string>|null $metadata carries `metadata`.
+     * @param SubscriptionPostOrderingKey|null $orderingKey carries `ordering_key`: Deliver the events that share a key
+     *   one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out
+     *   of retries
+     * @param SubscriptionPostPayloadTransform|null $payloadTransform carries `payload_transform`: Declarative reshaping
+     *   of an `application/json` event payload, applied by the worker before the request is signed and sent; operations
+     *   are applied in order (`fields`, `envelope`, `constants`) and a pay
+     * @param list<SubscriptionPostResponseRules>|null $responseRules carries `response_rules`: Decide the outcome of
+     *   HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body
+     *   reports an error; the first rule that matches wins, and a response n
+     * @param SubscriptionPostRetryPolicy|null $retryPolicy carries `retry_policy`: Retry failed deliveries on this
+     *   schedule rather than the instance's one
+     * @param SubscriptionPostSignatureScheme|null $signatureScheme carries `signature_scheme`, one of
+     *   `SubscriptionPostSignatureScheme`: How deliveries are signed (`hook0` by default); `standard_webhooks` follows
+     *   the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of the
+     *   subscription s
      */
     public function __construct(
         public readonly string $applicationId,
         public readonly array $eventTypes,
         public readonly bool $isEnabled,
         public readonly SubscriptionPostTarget $target,
+        public readonly ?SubscriptionPostBatching $batching = null,
+        public readonly ?SubscriptionPostCircuitBreaker $circuitBreaker = null,
         public readonly ?array $dedicatedWorkers = null,
+        public readonly ?SubscriptionPostDeliveryLimits $deliveryLimits = null,
         public readonly ?string $description = null,
+        public readonly ?array $eventTypeVersions = null,
+        public readonly mixed $filter = null,
         public readonly ?string $labelKey = null,
         public readonly ?string $labelValue = null,
         public readonly ?array $labels = null,
         public readonly ?array $metadata = null,
+        public readonly ?SubscriptionPostOrderingKey $orderingKey = null,
+        public readonly ?SubscriptionPostPayloadTransform $payloadTransform = null,
+        public readonly ?array $responseRules = null,
+        public readonly ?SubscriptionPostRetryPolicy $retryPolicy = null,
+        public readonly ?SubscriptionPostSignatureScheme $signatureScheme = null,
     ) {
     }
 
@@ -58,12 +93,46 @@ final class SubscriptionPost
             'eventTypes' => Runtime::read($fields, 'event_types', Runtime::listOf(Runtime::text(...))),
             'isEnabled' => Runtime::read($fields, 'is_enabled', Runtime::boolean(...)),
             'target' => Runtime::read($fields, 'target', SubscriptionPostTarget::fromJson(...)),
+            'batching' => Runtime::maybe($fields, 'batching', SubscriptionPostBatching::fromJson(...)),
+            'circuitBreaker' => Runtime::maybe(
+                $fields,
+                'circuit_breaker',
+                SubscriptionPostCircuitBreaker::fromJson(...),
+            ),
             'dedicatedWorkers' => Runtime::maybe($fields, 'dedicated_workers', Runtime::listOf(Runtime::text(...))),
+            'deliveryLimits' => Runtime::maybe(
+                $fields,
+                'delivery_limits',
+                SubscriptionPostDeliveryLimits::fromJson(...),
+            ),
             'description' => Runtime::maybe($fields, 'description', Runtime::text(...)),
+            'eventTypeVersions' => Runtime::maybe(
+                $fields,
+                'event_type_versions',
+                Runtime::mapOf(Runtime::integer(...)),
+            ),
+            'filter' => Runtime::maybe($fields, 'filter', Runtime::jsonValue(...)),
             'labelKey' => Runtime::maybe($fields, 'label_key', Runtime::text(...)),
             'labelValue' => Runtime::maybe($fields, 'label_value', Runtime::text(...)),
             'labels' => Runtime::maybe($fields, 'labels', Runtime::mapOf(Runtime::text(...))),
             'metadata' => Runtime::maybe($fields, 'metadata', Runtime::mapOf(Runtime::text(...))),
+            'orderingKey' => Runtime::maybe($fields, 'ordering_key', SubscriptionPostOrderingKey::fromJson(...)),
+            'payloadTransform' => Runtime::maybe(
+                $fields,
+                'payload_transform',
+                SubscriptionPostPayloadTransform::fromJson(...),
+            ),
+            'responseRules' => Runtime::maybe(
+                $fields,
+                'response_rules',
+                Runtime::listOf(SubscriptionPostResponseRules::fromJson(...)),
+            ),
+            'retryPolicy' => Runtime::maybe($fields, 'retry_policy', SubscriptionPostRetryPolicy::fromJson(...)),
+            'signatureScheme' => Runtime::maybe(
+                $fields,
+                'signature_scheme',
+                Runtime::memberOf(SubscriptionPostSignatureScheme::class),
+            ),
         ];
 
         return new self(...$read);
@@ -81,12 +150,27 @@ final class SubscriptionPost
         $out['event_types'] = $this->eventTypes;
         $out['is_enabled'] = $this->isEnabled;
         $out['target'] = $this->target->toArray();
+        if ($this->batching !== null) {
+            $out['batching'] = $this->batching->toArray();
+        }
+        if ($this->circuitBreaker !== null) {
+            $out['circuit_breaker'] = $this->circuitBreaker->toArray();
+        }
         if ($this->dedicatedWorkers !== null) {
             $out['dedicated_workers'] = $this->dedicatedWorkers;
         }
+        if ($this->deliveryLimits !== null) {
+            $out['delivery_limits'] = $this->deliveryLimits->toArray();
+        }
         if ($this->description !== null) {
             $out['description'] = $this->description;
         }
+        if ($this->eventTypeVersions !== null) {
+            $out['event_type_versions'] = Runtime::mapping($this->eventTypeVersions);
+        }
+        if ($this->filter !== null) {
+            $out['filter'] = $this->filter;
+        }
         if ($this->labelKey !== null) {
             $out['label_key'] = $this->labelKey;
         }
@@ -99,6 +183,21 @@ final class SubscriptionPost
         if ($this->metadata !== null) {
             $out['metadata'] = Runtime::mapping($this->metadata);
         }
+        if ($this->orderingKey !== null) {
+            $out['ordering_key'] = $this->orderingKey->toArray();
+        }
+        if ($this->payloadTransform !== null) {
+            $out['payload_transform'] = $this->payloadTransform->toArray();
+        }
+        if ($this->responseRules !== null) {
+            $out['response_rules'] = array_map(static fn ($item0) => $item0->toArray(), $this->responseRules);
+        }
+        if ($this->retryPolicy !== null) {
+            $out['retry_policy'] = $this->retryPolicy->toArray();
+        }
+        if ($this->signatureScheme !== null) {
+            $out['signature_scheme'] = $this->signatureScheme->value;
+        }
 
         return $out;
     }
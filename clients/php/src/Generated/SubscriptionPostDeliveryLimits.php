@@ -0,0 +1,81 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SubscriptionPostDeliveryLimits` the API declares.
+ */
+final class SubscriptionPostDeliveryLimits
+{
+    /**
+     * @param int|null $maxInFlight carries `max_in_flight`: Number of deliveries that can be in flight at once
+     * @param int|null $maxPerSecond carries `max_per_second`: Number of deliveries that can start in a second
+     * @param bool|null $perHost carries `per_host`: Share the limits with every subscription of the application that
+     *   delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone
+     */
+    public function __construct(
+        public readonly ?int $maxInFlight = null,
+        public readonly ?int $maxPerSecond = null,
+        public readonly ?bool $perHost = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SubscriptionPostDeliveryLimits');
+        $read = [
+            'maxInFlight' => Runtime::maybe($fields, 'max_in_flight', Runtime::integer(...)),
+            'maxPerSecond' => Runtime::maybe($fields, 'max_per_second', Runtime::integer(...)),
+            'perHost' => Runtime::maybe($fields, 'per_host', Runtime::boolean(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        if ($this->maxInFlight !== null) {
+            $out['max_in_flight'] = $this->maxInFlight;
+        }
+        if ($this->maxPerSecond !== null) {
+            $out['max_per_second'] = $this->maxPerSecond;
+        }
+        if ($this->perHost !== null) {
+            $out['per_host'] = $this->perHost;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
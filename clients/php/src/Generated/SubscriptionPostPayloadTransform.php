@@ -0,0 +1,86 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SubscriptionPostPayloadTransform` the API declares.
+ */
+final class SubscriptionPostPayloadTransform
+{
+    /**
+     * @param array<string, mixed>|null $constants carries `constants`: Properties added at the top level of the result,
+     *   overriding properties of the same name (the result must then be an object)
+     * @param string|null $envelope carries `envelope`: Name of a property the result is wrapped in
+     * @param list<SubscriptionPostPayloadTransformFields>|null $fields carries `fields`: Values to copy from the
+     *   payload into a new object; when omitted, the whole payload is kept
+     */
+    public function __construct(
+        public readonly ?array $constants = null,
+        public readonly ?string $envelope = null,
+        public readonly ?array $fields = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SubscriptionPostPayloadTransform');
+        $read = [
+            'constants' => Runtime::maybe($fields, 'constants', Runtime::mapOf(Runtime::jsonValue(...))),
+            'envelope' => Runtime::maybe($fields, 'envelope', Runtime::text(...)),
+            'fields' => Runtime::maybe(
+                $fields,
+                'fields',
+                Runtime::listOf(SubscriptionPostPayloadTransformFields::fromJson(...)),
+            ),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        if ($this->constants !== null) {
+            $out['constants'] = Runtime::mapping($this->constants);
+        }
+        if ($this->envelope !== null) {
+            $out['envelope'] = $this->envelope;
+        }
+        if ($this->fields !== null) {
+            $out['fields'] = array_map(static fn ($item0) => $item0->toArray(), $this->fields);
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
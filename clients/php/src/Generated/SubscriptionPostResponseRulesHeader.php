@@ -0,0 +1,72 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SubscriptionPostResponseRulesHeader` the API declares.
+ */
+final class SubscriptionPostResponseRulesHeader
+{
+    /**
+     * @param string $name carries `name`: Name of the header, regardless of case
+     * @param string|null $value carries `value`: Value the header must have; when omitted, any value matches
+     */
+    public function __construct(
+        public readonly string $name,
+        public readonly ?string $value = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SubscriptionPostResponseRulesHeader');
+        $read = [
+            'name' => Runtime::read($fields, 'name', Runtime::text(...)),
+            'value' => Runtime::maybe($fields, 'value', Runtime::text(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['name'] = $this->name;
+        if ($this->value !== null) {
+            $out['value'] = $this->value;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -0,0 +1,83 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SubscriptionPostRetryPolicy` the API declares.
+ */
+final class SubscriptionPostRetryPolicy
+{
+    /**
+     * @param list<int>|null $backoffStepsS carries `backoff_steps_s`: Delays in seconds to wait before each retry; the
+     *   last one is used again for the following retries
+     * @param int|null $maxRetries carries `max_retries`: Maximum number of retries after a failed delivery (0 disables
+     *   retries)
+     * @param int|null $maxWindowS carries `max_window_s`: Maximum duration in seconds the retries can span; a retry
+     *   that would not fit in it is not scheduled
+     */
+    public function __construct(
+        public readonly ?array $backoffStepsS = null,
+        public readonly ?int $maxRetries = null,
+        public readonly ?int $maxWindowS = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SubscriptionPostRetryPolicy');
+        $read = [
+            'backoffStepsS' => Runtime::maybe($fields, 'backoff_steps_s', Runtime::listOf(Runtime::integer(...))),
+            'maxRetries' => Runtime::maybe($fields, 'max_retries', Runtime::integer(...)),
+            'maxWindowS' => Runtime::maybe($fields, 'max_window_s', Runtime::integer(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        if ($this->backoffStepsS !== null) {
+            $out['backoff_steps_s'] = $this->backoffStepsS;
+        }
+        if ($this->maxRetries !== null) {
+            $out['max_retries'] = $this->maxRetries;
+        }
+        if ($this->maxWindowS !== null) {
+            $out['max_window_s'] = $this->maxWindowS;
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
@@ -15,16 +15,36 @@ use Hook0\Runtime;
 final class SubscriptionPostTarget
 {
     /**
-     * @param mixed $headers carries `headers`.
-     * @param string $method carries `method`.
-     * @param string $type carries `type`.
-     * @param string $url carries `url`.
+     * @param SubscriptionPostTargetType $type carries `type`, one of `SubscriptionPostTargetType`: Kind of target:
+     *   `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+     * @param string|null $accessKeyId carries `access_key_id`: `sqs`: access key ID requests are signed with
+     * @param string|null $exchange carries `exchange`: `amqp`: exchange messages are published to; empty for the
+     *   default exchange
+     * @param mixed $headers carries `headers`: `http`: headers sent with every request
+     * @param string|null $method carries `method`: `http`: HTTP method of the requests
+     * @param string|null $password carries `password`: `amqp`: password to log in to the broker with; write-only
+     * @param string|null $queueUrl carries `queue_url`: `sqs`: URL of the queue
+     * @param string|null $region carries `region`: `sqs`: region requests are signed for
+     * @param string|null $routingKey carries `routing_key`: `amqp`: routing key messages are published with
+     * @param string|null $secretAccessKey carries `secret_access_key`: `sqs`: secret access key requests are signed
+     *   with; write-only
+     * @param string|null $url carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps`
+     *   scheme), without credentials
+     * @param string|null $username carries `username`: `amqp`: user name to log in to the broker with
      */
     public function __construct(
-        public readonly mixed $headers,
-        public readonly string $method,
-        public readonly string $type,
-        public readonly string $url,
+        public readonly SubscriptionPostTargetType $type,
+        public readonly ?string $accessKeyId = null,
+        public readonly ?string $exchange = null,
+        public readonly mixed $headers = null,
+        public readonly ?string $method = null,
+        public readonly ?string $password = null,
+        public readonly ?string $queueUrl = null,
+        public readonly ?string $region = null,
+        public readonly ?string $routingKey = null,
+        public readonly ?string $secretAccessKey = null,
+        public readonly ?string $url = null,
+        public readonly ?string $username = null,
     ) {
     }
 
@@ -42,10 +62,18 @@ final class SubscriptionPostTarget
     {
         $fields = Runtime::asFields($value, 'SubscriptionPostTarget');
         $read = [
-            'headers' => Runtime::read($fields, 'headers', Runtime::jsonValue(...)),
-            'method' => Runtime::read($fields, 'method', Runtime::text(...)),
-            'type' => Runtime::read($fields, 'type', Runtime::text(...)),
-            'url' => Runtime::read($fields, 'url', Runtime::text(...)),
+            'type' => Runtime::read($fields, 'type', Runtime::memberOf(SubscriptionPostTargetType::class)),
+            'accessKeyId' => Runtime::maybe($fields, 'access_key_id', Runtime::text(...)),
+            'exchange' => Runtime::maybe($fields, 'exchange', Runtime::text(...)),
+            'headers' => Runtime::maybe($fields, 'headers', Runtime::jsonValue(...)),
+            'method' => Runtime::maybe($fields, 'method', Runtime::text(...)),
+            'password' => Runtime::maybe($fields, 'password', Runtime::text(...)),
+            'queueUrl' => Runtime::maybe($fields, 'queue_url', Runtime::text(...)),
+            'region' => Runtime::maybe($fields, 'region', Runtime::text(...)),
+            'routingKey' => Runtime::maybe($fields, 'routing_key', Runtime::text(...)),
+            'secretAccessKey' => Runtime::maybe($fields, 'secret_access_key', Runtime::text(...)),
+            'url' => Runtime::maybe($fields, 'url', Runtime::text(...)),
+            'username' => Runtime::maybe($fields, 'username', Runtime::text(...)),
         ];
 
         return new self(...$read);
@@ -59,10 +87,40 @@ final class SubscriptionPostTarget
     public function toArray(): array
     {
         $out = [];
-        $out['headers'] = $this->headers;
-        $out['method'] = $this->method;
-        $out['type'] = $this->type;
-        $out['url'] = $this->url;
+        $out['type'] = $this->type->value;
+        if ($this->accessKeyId !== null) {
+            $out['access_key_id'] = $this->accessKeyId;
+        }
+        if ($this->exchange !== null) {
+            $out['exchange'] = $this->exchange;
+        }
+        if ($this->headers !== null) {
+            $out['headers'] = $this->headers;
+        }
+        if ($this->method !== null) {
+            $out['method'] = $this->method;
+        }
+        if ($this->password !== null) {
+            $out['password'] = $this->password;
+        }
+        if ($this->queueUrl !== null) {
+            $out['queue_url'] = $this->queueUrl;
+        }
+        if ($this->region !== null) {
+            $out['region'] = $this->region;
+        }
+        if ($this->routingKey !== null) {
+            $out['routing_key'] = $this->routingKey;
+        }
+        if ($this->secretAccessKey !== null) {
+            $out['secret_access_key'] = $this->secretAccessKey;
+        }
+        if ($this->url !== null) {
+            $out['url'] = $this->url;
+        }
+        if ($this->username !== null) {
+            $out['username'] = $this->username;
+        }
 
         return $out;
     }
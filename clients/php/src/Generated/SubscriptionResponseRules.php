@@ -0,0 +1,85 @@
+<?php
+
+// Generated by hook0-sdkgen from the OpenAPI snapshot the API crate commits.
+// Do not edit by hand: run `UPDATE_SDK=php cargo test -p hook0-sdkgen sdk_targets` and commit the result.
+
+declare(strict_types=1);
+
+namespace Hook0\Generated;
+
+use Hook0\Runtime;
+
+/**
+ * The `SubscriptionResponseRules` the API declares.
+ */
+final class SubscriptionResponseRules
+{
+    /**
+     * @param SubscriptionResponseRulesOutcome $outcome carries `outcome`, one of `SubscriptionResponseRulesOutcome`:
+     *   What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or
+     *   `disable_subscription` (not retried, and the subscription is disabled)
+     * @param list<string> $status carries `status`: Status codes the rule applies to: exact codes such as `410`, or
+     *   classes such as `4xx`
+     * @param string|null $bodyContains carries `body_contains`: Text the response body must contain
+     * @param SubscriptionResponseRulesHeader|null $header carries `header`: Header the response must have
+     */
+    public function __construct(
+        public readonly SubscriptionResponseRulesOutcome $outcome,
+        public readonly array $status,
+        public readonly ?string $bodyContains = null,
+        public readonly ?SubscriptionResponseRulesHeader $header = null,
+    ) {
+    }
+
+    /**
+     * Read one out of what the API answered.
+     *
+     * Every member is read before the value is built, and the members are then
+     * spread into the constructor under the names it declares. A member that
+     * does not read stops the read where it is, rather than while a half-built
+     * value is on the stack.
+     *
+     * @param mixed $value the JSON document the API answered
+     */
+    public static function fromJson(mixed $value): self
+    {
+        $fields = Runtime::asFields($value, 'SubscriptionResponseRules');
+        $read = [
+            'outcome' => Runtime::read($fields, 'outcome', Runtime::memberOf(SubscriptionResponseRulesOutcome::class)),
+            'status' => Runtime::read($fields, 'status', Runtime::listOf(Runtime::text(...))),
+            'bodyContains' => Runtime::maybe($fields, 'body_contains', Runtime::text(...)),
+            'header' => Runtime::maybe($fields, 'header', SubscriptionResponseRulesHeader::fromJson(...)),
+        ];
+
+        return new self(...$read);
+    }
+
+    /**
+     * Write one back the way the API reads it.
+     *
+     * @return array<string, mixed>
+     */
+    public function toArray(): array
+    {
+        $out = [];
+        $out['outcome'] = $this->outcome->value;
+        $out['status'] = $this->status;
+        if ($this->bodyContains !== null) {
+            $out['body_contains'] = $this->bodyContains;
+        }
+        if ($this->header !== null) {
+            $out['header'] = $this->header->toArray();
+        }
+
+        return $out;
+    }
+
+    /**
+     * Whether that value carries the same members as this one.
+     */
+    public function equals(mixed $other): bool
+    {
+        return $other instanceof self
+            && Runtime::encode($this->toArray()) === Runtime::encode($other->toArray());
+    }
+}
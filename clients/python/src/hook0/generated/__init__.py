@@ -7,31 +7,45 @@ from __future__ import annotations
 from .aio import (
     ApplicationSecretsAsyncApi,
     ApplicationsAsyncApi,
+    BulkRetriesAsyncApi,
+    ClientCertificatesAsyncApi,
+    DeadLettersAsyncApi,
     ErrorsAsyncApi,
     EventTypesAsyncApi,
     EventsAsyncApi,
     EventsPerDayAsyncApi,
+    InboxAsyncApi,
     InstanceAsyncApi,
+    Oauth2ClientCredentialsAsyncApi,
     PayloadContentTypesAsyncApi,
     QuotasAsyncApi,
     RequestAttemptsAsyncApi,
     ResponseAsyncApi,
+    SecretRotationsAsyncApi,
     ServiceTokenAsyncApi,
+    SigningKeysAsyncApi,
     SubscriptionsAsyncApi,
 )
 from .api import (
     ApplicationSecretsApi,
     ApplicationsApi,
+    BulkRetriesApi,
+    ClientCertificatesApi,
+    DeadLettersApi,
     ErrorsApi,
     EventTypesApi,
     EventsApi,
     EventsPerDayApi,
+    InboxApi,
     InstanceApi,
+    Oauth2ClientCredentialsApi,
     PayloadContentTypesApi,
     QuotasApi,
     RequestAttemptsApi,
     ResponseApi,
+    SecretRotationsApi,
     ServiceTokenApi,
+    SigningKeysApi,
     SubscriptionsApi,
 )
 from .errors import (
@@ -47,14 +61,24 @@ from .errors import (
     AuthInvalidAuthorizationHeaderError,
     AuthInvalidBiscuitError,
     AuthNoAuthorizationHeaderError,
+    BulkRetryAlreadyRunningError,
+    BulkRetryFinishedError,
+    ClientCertificatesDisabledError,
     EventAlreadyIngestedError,
+    EventDeliveryBeyondRetentionError,
     EventInvalidBase64PayloadError,
     EventInvalidJsonPayloadError,
     EventInvalidPayloadContentTypeError,
+    EventNotScheduledError,
+    EventPayloadSchemaViolationError,
     EventTypeAlreadyExistError,
     EventTypeDoesNotExistError,
+    EventTypeVersionAlreadyExistError,
+    EventTypeVersionDoesNotExistError,
+    EventTypeVersionSunsetError,
     ForbiddenError,
     InternalServerErrorError,
+    InvalidClientCertificateError,
     InvalidDateRangeError,
     InvalidRoleError,
     InvitedUserAlreadyInOrganizationError,
@@ -73,7 +97,14 @@ from .errors import (
     ProblemError,
     RateLimitedError,
     RegistrationDisabledError,
+    RequestAttemptNotFailedError,
+    RequestAttemptRetryPendingError,
+    RetryPolicyOverLimitError,
+    SecretRotationInProgressError,
     ServiceUnavailableError,
+    SigningKeysDisabledError,
+    SubscriptionDisabledError,
+    SubscriptionNotInboxError,
     TooManyApplicationsPerOrganizationError,
     TooManyEventTypesPerApplicationError,
     TooManyEventsTodayError,
@@ -95,16 +126,60 @@ from .models import (
     ApplicationPost,
     ApplicationSecret,
     ApplicationSecretPost,
+    BulkRetry,
+    BulkRetryDryRun,
+    BulkRetryPost,
+    BulkRetryPostStatus,
+    BulkRetryStatus,
+    CancelEvent,
+    ClientCertificate,
+    ClientCertificatePut,
+    DeadLetter,
+    DeadLetterDetails,
+    DeadLetterDetailsDeadLetter,
+    DeadLetterDetailsDeadLetterEvent,
+    DeadLetterDetailsDeadLetterReason,
+    DeadLetterDetailsDeadLetterSubscription,
+    DeadLetterDetailsLastResponse,
+    DeadLetterEvent,
+    DeadLetterReason,
+    DeadLetterSubscription,
     Event,
     EventPost,
     EventType,
+    EventTypePayloadSchemaMode,
     EventTypePost,
+    EventTypePostPayloadSchemaMode,
+    EventTypePut,
+    EventTypePutPayloadSchemaMode,
+    EventTypeVersion,
+    EventTypeVersionDeprecate,
+    EventTypeVersionPost,
+    EventTypeVersions,
     EventWithPayload,
+    EventsBatchPost,
+    EventsBatchPostEvents,
     EventsPerDayEntry,
+    Inbox,
+    InboxAck,
+    InboxMessages,
+    InboxNack,
+    InboxSettlement,
     IngestedEvent,
+    IngestedEventDeprecation,
+    IngestedEventsBatch,
+    IngestedEventsBatchEvents,
+    IngestedEventsBatchEventsDeprecation,
+    IngestedEventsBatchEventsProblem,
+    IngestedEventsBatchEventsProblemId,
+    IngestedEventsBatchEventsStatus,
     InstanceConfig,
     InstanceConfigFormbricks,
     InstanceConfigMatomo,
+    JsonWebKeySet,
+    JsonWebKeySetKeys,
+    Oauth2ClientCredentials,
+    Oauth2ClientCredentialsPut,
     Organization,
     OrganizationInfo,
     OrganizationInfoConsumption,
@@ -119,21 +194,56 @@ from .models import (
     OrganizationPlan,
     Problem,
     ProblemId,
+    PurgeDeadLetters,
+    PurgedDeadLetters,
     QuotasResponse,
     QuotasResponseLimits,
+    RedeliverDeadLetter,
+    RedeliverDeadLetters,
+    RedeliveredDeadLetter,
     ReplayEvent,
     RequestAttempt,
     RequestAttemptEvent,
     RequestAttemptStatus,
+    RequestAttemptStatusRuleOutcome,
     RequestAttemptStatusType,
     RequestAttemptSubscription,
     Response,
+    RetryRequestAttempt,
+    SecretRotation,
+    SecretRotationPost,
     ServiceToken,
     ServiceTokenPost,
+    SigningKey,
+    SigningKeyPost,
     Subscription,
+    SubscriptionBatching,
+    SubscriptionCircuitBreaker,
+    SubscriptionDeliveryLimits,
+    SubscriptionOrderingKey,
+    SubscriptionPayloadTransform,
+    SubscriptionPayloadTransformFields,
     SubscriptionPost,
+    SubscriptionPostBatching,
+    SubscriptionPostCircuitBreaker,
+    SubscriptionPostDeliveryLimits,
+    SubscriptionPostOrderingKey,
+    SubscriptionPostPayloadTransform,
+    SubscriptionPostPayloadTransformFields,
+    SubscriptionPostResponseRules,
+    SubscriptionPostResponseRulesHeader,
+    SubscriptionPostResponseRulesOutcome,
+    SubscriptionPostRetryPolicy,
+    SubscriptionPostSignatureScheme,
     SubscriptionPostTarget,
+    SubscriptionPostTargetType,
+    SubscriptionResponseRules,
+    SubscriptionResponseRulesHeader,
+    SubscriptionResponseRulesOutcome,
+    SubscriptionRetryPolicy,
+    SubscriptionSignatureScheme,
     SubscriptionTarget,
+    SubscriptionTargetType,
 )
 
 __all__ = [
@@ -164,41 +274,105 @@ __all__ = [
     "AuthInvalidAuthorizationHeaderError",
     "AuthInvalidBiscuitError",
     "AuthNoAuthorizationHeaderError",
+    "BulkRetriesApi",
+    "BulkRetriesAsyncApi",
+    "BulkRetry",
+    "BulkRetryAlreadyRunningError",
+    "BulkRetryDryRun",
+    "BulkRetryFinishedError",
+    "BulkRetryPost",
+    "BulkRetryPostStatus",
+    "BulkRetryStatus",
+    "CancelEvent",
+    "ClientCertificate",
+    "ClientCertificatePut",
+    "ClientCertificatesApi",
+    "ClientCertificatesAsyncApi",
+    "ClientCertificatesDisabledError",
+    "DeadLetter",
+    "DeadLetterDetails",
+    "DeadLetterDetailsDeadLetter",
+    "DeadLetterDetailsDeadLetterEvent",
+    "DeadLetterDetailsDeadLetterReason",
+    "DeadLetterDetailsDeadLetterSubscription",
+    "DeadLetterDetailsLastResponse",
+    "DeadLetterEvent",
+    "DeadLetterReason",
+    "DeadLetterSubscription",
+    "DeadLettersApi",
+    "DeadLettersAsyncApi",
     "ErrorsApi",
     "ErrorsAsyncApi",
     "Event",
     "EventAlreadyIngestedError",
+    "EventDeliveryBeyondRetentionError",
     "EventInvalidBase64PayloadError",
     "EventInvalidJsonPayloadError",
     "EventInvalidPayloadContentTypeError",
+    "EventNotScheduledError",
+    "EventPayloadSchemaViolationError",
     "EventPost",
     "EventType",
     "EventTypeAlreadyExistError",
     "EventTypeDoesNotExistError",
+    "EventTypePayloadSchemaMode",
     "EventTypePost",
+    "EventTypePostPayloadSchemaMode",
+    "EventTypePut",
+    "EventTypePutPayloadSchemaMode",
+    "EventTypeVersion",
+    "EventTypeVersionAlreadyExistError",
+    "EventTypeVersionDeprecate",
+    "EventTypeVersionDoesNotExistError",
+    "EventTypeVersionPost",
+    "EventTypeVersionSunsetError",
+    "EventTypeVersions",
     "EventTypesApi",
     "EventTypesAsyncApi",
     "EventWithPayload",
     "EventsApi",
     "EventsAsyncApi",
+    "EventsBatchPost",
+    "EventsBatchPostEvents",
     "EventsPerDayApi",
     "EventsPerDayAsyncApi",
     "EventsPerDayEntry",
     "ForbiddenError",
+    "Inbox",
+    "InboxAck",
+    "InboxApi",
+    "InboxAsyncApi",
+    "InboxMessages",
+    "InboxNack",
+    "InboxSettlement",
     "IngestedEvent",
+    "IngestedEventDeprecation",
+    "IngestedEventsBatch",
+    "IngestedEventsBatchEvents",
+    "IngestedEventsBatchEventsDeprecation",
+    "IngestedEventsBatchEventsProblem",
+    "IngestedEventsBatchEventsProblemId",
+    "IngestedEventsBatchEventsStatus",
     "InstanceApi",
     "InstanceAsyncApi",
     "InstanceConfig",
     "InstanceConfigFormbricks",
     "InstanceConfigMatomo",
     "InternalServerErrorError",
+    "InvalidClientCertificateError",
     "InvalidDateRangeError",
     "InvalidRoleError",
     "InvitedUserAlreadyInOrganizationError",
     "InvitedUserDoesNotExistError",
     "JsonPayloadError",
+    "JsonWebKeySet",
+    "JsonWebKeySetKeys",
     "LabelsAmbiguityError",
     "NotFoundError",
+    "Oauth2ClientCredentials",
+    "Oauth2ClientCredentialsApi",
+    "Oauth2ClientCredentialsAsyncApi",
+    "Oauth2ClientCredentialsPut",
     "Organization",
     "OrganizationInfo",
     "OrganizationInfoConsumption",
@@ -224,16 +398,24 @@ __all__ = [
     "Problem",
     "ProblemError",
     "ProblemId",
+    "PurgeDeadLetters",
+    "PurgedDeadLetters",
     "QuotasApi",
     "QuotasAsyncApi",
     "QuotasResponse",
     "QuotasResponseLimits",
     "RateLimitedError",
+    "RedeliverDeadLetter",
+    "RedeliverDeadLetters",
+    "RedeliveredDeadLetter",
     "RegistrationDisabledError",
     "ReplayEvent",
     "RequestAttempt",
     "RequestAttemptEvent",
+    "RequestAttemptNotFailedError",
+    "RequestAttemptRetryPendingError",
     "RequestAttemptStatus",
+    "RequestAttemptStatusRuleOutcome",
     "RequestAttemptStatusType",
     "RequestAttemptSubscription",
     "RequestAttemptsApi",
@@ -241,15 +423,53 @@ __all__ = [
     "Response",
     "ResponseApi",
     "ResponseAsyncApi",
+    "RetryPolicyOverLimitError",
+    "RetryRequestAttempt",
+    "SecretRotation",
+    "SecretRotationInProgressError",
+    "SecretRotationPost",
+    "SecretRotationsApi",
+    "SecretRotationsAsyncApi",
     "ServiceToken",
     "ServiceTokenApi",
     "ServiceTokenAsyncApi",
     "ServiceTokenPost",
     "ServiceUnavailableError",
+    "SigningKey",
+    "SigningKeyPost",
+    "SigningKeysApi",
+    "SigningKeysAsyncApi",
+    "SigningKeysDisabledError",
     "Subscription",
+    "SubscriptionBatching",
+    "SubscriptionCircuitBreaker",
+    "SubscriptionDeliveryLimits",
+    "SubscriptionDisabledError",
+    "SubscriptionNotInboxError",
+    "SubscriptionOrderingKey",
+    "SubscriptionPayloadTransform",
+    "SubscriptionPayloadTransformFields",
     "SubscriptionPost",
+    "SubscriptionPostBatching",
+    "SubscriptionPostCircuitBreaker",
+    "SubscriptionPostDeliveryLimits",
+    "SubscriptionPostOrderingKey",
+    "SubscriptionPostPayloadTransform",
+    "SubscriptionPostPayloadTransformFields",
+    "SubscriptionPostResponseRules",
+    "SubscriptionPostResponseRulesHeader",
+    "SubscriptionPostResponseRulesOutcome",
+    "SubscriptionPostRetryPolicy",
+    "SubscriptionPostSignatureScheme",
     "SubscriptionPostTarget",
+    "SubscriptionPostTargetType",
+    "SubscriptionResponseRules",
+    "SubscriptionResponseRulesHeader",
+    "SubscriptionResponseRulesOutcome",
+    "SubscriptionRetryPolicy",
+    "SubscriptionSignatureScheme",
     "SubscriptionTarget",
+    "SubscriptionTargetType",
     "SubscriptionsApi",
     "SubscriptionsAsyncApi",
     "TooManyApplicationsPerOrganizationError",
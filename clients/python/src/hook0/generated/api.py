@@ -20,21 +20,52 @@ from .models import (
     ApplicationPost,
     ApplicationSecret,
     ApplicationSecretPost,
+    BulkRetry,
+    BulkRetryDryRun,
+    BulkRetryPost,
+    CancelEvent,
+    ClientCertificate,
+    ClientCertificatePut,
+    DeadLetter,
+    DeadLetterDetails,
     Event,
     EventPost,
     EventType,
     EventTypePost,
+    EventTypePut,
+    EventTypeVersion,
+    EventTypeVersionDeprecate,
+    EventTypeVersionPost,
     EventWithPayload,
+    EventsBatchPost,
     EventsPerDayEntry,
+    Inbox,
+    InboxAck,
+    InboxNack,
+    InboxSettlement,
     IngestedEvent,
+    IngestedEventsBatch,
     InstanceConfig,
+    JsonWebKeySet,
+    Oauth2ClientCredentials,
+    Oauth2ClientCredentialsPut,
     Problem,
+    PurgeDeadLetters,
+    PurgedDeadLetters,
     QuotasResponse,
+    RedeliverDeadLetter,
+    RedeliverDeadLetters,
+    RedeliveredDeadLetter,
     ReplayEvent,
     RequestAttempt,
     Response,
+    RetryRequestAttempt,
+    SecretRotation,
+    SecretRotationPost,
     ServiceToken,
     ServiceTokenPost,
+    SigningKey,
+    SigningKeyPost,
     Subscription,
     SubscriptionPost,
 )
@@ -209,6 +240,283 @@ class ApplicationsApi:
         return Application.from_json(decode_payload(payload))
 
 
+class BulkRetriesApi:
+    """What the API declares under `bulkRetries`, issued through the transport it is handed."""
+
+    def __init__(self, transport: Any) -> None:
+        self._transport = transport
+
+    def cancel(
+        self,
+        bulk_retry_id: str,
+        application_id: str,
+    ) -> BulkRetry:
+        """Cancel a bulk retry"""
+        path = "/api/v1/bulk_retries/{bulk_retry_id}/cancel"
+        path = path.replace("{bulk_retry_id}", path_segment(bulk_retry_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return BulkRetry.from_json(decode_payload(payload))
+
+    def create(
+        self,
+        body: BulkRetryPost,
+    ) -> BulkRetry:
+        """Create a bulk retry"""
+        path = "/api/v1/bulk_retries/"
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return BulkRetry.from_json(decode_payload(payload))
+
+    def dry_run(
+        self,
+        body: BulkRetryPost,
+    ) -> BulkRetryDryRun:
+        """Count what a bulk retry would redeliver"""
+        path = "/api/v1/bulk_retries/dry_run"
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return BulkRetryDryRun.from_json(decode_payload(payload))
+
+    def get(
+        self,
+        bulk_retry_id: str,
+        application_id: str,
+    ) -> BulkRetry:
+        """Get a bulk retry"""
+        path = "/api/v1/bulk_retries/{bulk_retry_id}"
+        path = path.replace("{bulk_retry_id}", path_segment(bulk_retry_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return BulkRetry.from_json(decode_payload(payload))
+
+    def list(
+        self,
+        application_id: str,
+    ) -> list[BulkRetry]:
+        """List bulk retries"""
+        path = "/api/v1/bulk_retries/"
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return as_list(BulkRetry.from_json)(decode_payload(payload))
+
+
+class ClientCertificatesApi:
+    """What the API declares under `clientCertificates`, issued through the transport it is handed."""
+
+    def __init__(self, transport: Any) -> None:
+        self._transport = transport
+
+    def delete(
+        self,
+        subscription_id: str,
+        application_id: str,
+    ) -> None:
+        """Remove the client certificate of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/client_certificate"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "DELETE",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return None
+
+    def get(
+        self,
+        subscription_id: str,
+        application_id: str,
+    ) -> ClientCertificate:
+        """Get the client certificate of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/client_certificate"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return ClientCertificate.from_json(decode_payload(payload))
+
+    def update(
+        self,
+        subscription_id: str,
+        application_id: str,
+        body: ClientCertificatePut,
+    ) -> ClientCertificate:
+        """Set the client certificate of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/client_certificate"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "PUT",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return ClientCertificate.from_json(decode_payload(payload))
+
+
+class DeadLettersApi:
+    """What the API declares under `deadLetters`, issued through the transport it is handed."""
+
+    def __init__(self, transport: Any) -> None:
+        self._transport = transport
+
+    def delete(
+        self,
+        dead_letter_id: str,
+        application_id: str,
+    ) -> None:
+        """Delete a dead letter"""
+        path = "/api/v1/dead_letters/{dead_letter_id}"
+        path = path.replace("{dead_letter_id}", path_segment(dead_letter_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "DELETE",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return None
+
+    def get(
+        self,
+        dead_letter_id: str,
+        application_id: str,
+    ) -> DeadLetterDetails:
+        """Get a dead letter by its ID"""
+        path = "/api/v1/dead_letters/{dead_letter_id}"
+        path = path.replace("{dead_letter_id}", path_segment(dead_letter_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return DeadLetterDetails.from_json(decode_payload(payload))
+
+    def list(
+        self,
+        application_id: str,
+        pagination_cursor: str | None = None,
+        subscription_id: str | None = None,
+    ) -> list[DeadLetter]:
+        """List dead letters"""
+        path = "/api/v1/dead_letters/"
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        if pagination_cursor is not None:
+            query.append(("pagination_cursor", query_value(pagination_cursor)))
+        if subscription_id is not None:
+            query.append(("subscription_id", query_value(subscription_id)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return as_list(DeadLetter.from_json)(decode_payload(payload))
+
+    def purge(
+        self,
+        body: PurgeDeadLetters,
+    ) -> PurgedDeadLetters:
+        """Purge dead letters"""
+        path = "/api/v1/dead_letters/purge"
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return PurgedDeadLetters.from_json(decode_payload(payload))
+
+    def redeliver(
+        self,
+        dead_letter_id: str,
+        body: RedeliverDeadLetter,
+    ) -> RedeliveredDeadLetter:
+        """Redeliver a dead letter"""
+        path = "/api/v1/dead_letters/{dead_letter_id}/redeliver"
+        path = path.replace("{dead_letter_id}", path_segment(dead_letter_id))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return RedeliveredDeadLetter.from_json(decode_payload(payload))
+
+    def redeliver_many(
+        self,
+        body: RedeliverDeadLetters,
+    ) -> list[RedeliveredDeadLetter]:
+        """Redeliver dead letters"""
+        path = "/api/v1/dead_letters/redeliver"
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return as_list(RedeliveredDeadLetter.from_json)(decode_payload(payload))
+
+
 class ErrorsApi:
     """What the API declares under `errors`, issued through the transport it is handed."""
 
@@ -253,6 +561,24 @@ class EventTypesApi:
         raise_for_status(status, payload)
         return EventType.from_json(decode_payload(payload))
 
+    def create_version(
+        self,
+        event_type_name: str,
+        body: EventTypeVersionPost,
+    ) -> EventTypeVersion:
+        """Create a new event type version"""
+        path = "/api/v1/event_types/{event_type_name}/versions"
+        path = path.replace("{event_type_name}", path_segment(event_type_name))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return EventTypeVersion.from_json(decode_payload(payload))
+
     def delete(
         self,
         event_type_name: str,
@@ -272,6 +598,24 @@ class EventTypesApi:
         raise_for_status(status, payload)
         return None
 
+    def deprecate_version(
+        self,
+        event_type_name: str,
+        body: EventTypeVersionDeprecate,
+    ) -> EventTypeVersion:
+        """Deprecate an event type version"""
+        path = "/api/v1/event_types/{event_type_name}/deprecate"
+        path = path.replace("{event_type_name}", path_segment(event_type_name))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return EventTypeVersion.from_json(decode_payload(payload))
+
     def get(
         self,
         event_type_name: str,
@@ -308,6 +652,24 @@ class EventTypesApi:
         raise_for_status(status, payload)
         return as_list(EventType.from_json)(decode_payload(payload))
 
+    def update(
+        self,
+        event_type_name: str,
+        body: EventTypePut,
+    ) -> EventType:
+        """Update an event type"""
+        path = "/api/v1/event_types/{event_type_name}"
+        path = path.replace("{event_type_name}", path_segment(event_type_name))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "PUT",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return EventType.from_json(decode_payload(payload))
+
 
 class EventsApi:
     """What the API declares under `events`, issued through the transport it is handed."""
@@ -315,6 +677,24 @@ class EventsApi:
     def __init__(self, transport: Any) -> None:
         self._transport = transport
 
+    def cancel(
+        self,
+        event_id: str,
+        body: CancelEvent,
+    ) -> None:
+        """Cancel a scheduled event"""
+        path = "/api/v1/events/{event_id}/cancel"
+        path = path.replace("{event_id}", path_segment(event_id))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return None
+
     def get(
         self,
         event_id: str,
@@ -350,14 +730,33 @@ class EventsApi:
         raise_for_status(status, payload)
         return IngestedEvent.from_json(decode_payload(payload))
 
+    def ingest_batch(
+        self,
+        body: EventsBatchPost,
+    ) -> IngestedEventsBatch:
+        """Ingest a batch of events"""
+        path = "/api/v1/events/batch"
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return IngestedEventsBatch.from_json(decode_payload(payload))
+
     def list(
         self,
         application_id: str,
+        scheduled: bool | None = None,
     ) -> list[Event]:
         """List latest events"""
         path = "/api/v1/events/"
         query: list[tuple[str, str]] = []
         query.append(("application_id", query_value(application_id)))
+        if scheduled is not None:
+            query.append(("scheduled", query_value(scheduled)))
         status, payload = self._transport.request(
             "GET",
             path,
@@ -439,6 +838,77 @@ class EventsPerDayApi:
         return as_list(EventsPerDayEntry.from_json)(decode_payload(payload))
 
 
+class InboxApi:
+    """What the API declares under `inbox`, issued through the transport it is handed."""
+
+    def __init__(self, transport: Any) -> None:
+        self._transport = transport
+
+    def ack(
+        self,
+        subscription_id: str,
+        body: InboxAck,
+    ) -> InboxSettlement:
+        """Acknowledge events pulled from an inbox"""
+        path = "/api/v1/inbox/{subscription_id}/ack"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return InboxSettlement.from_json(decode_payload(payload))
+
+    def nack(
+        self,
+        subscription_id: str,
+        body: InboxNack,
+    ) -> InboxSettlement:
+        """Reject events pulled from an inbox"""
+        path = "/api/v1/inbox/{subscription_id}/nack"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return InboxSettlement.from_json(decode_payload(payload))
+
+    def pull(
+        self,
+        subscription_id: str,
+        application_id: str,
+        cursor: str | None = None,
+        limit: int | None = None,
+        visibility_timeout_s: int | None = None,
+    ) -> Inbox:
+        """Pull events from an inbox"""
+        path = "/api/v1/inbox/{subscription_id}"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        if cursor is not None:
+            query.append(("cursor", query_value(cursor)))
+        if limit is not None:
+            query.append(("limit", query_value(limit)))
+        if visibility_timeout_s is not None:
+            query.append(("visibility_timeout_s", query_value(visibility_timeout_s)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return Inbox.from_json(decode_payload(payload))
+
+
 class InstanceApi:
     """What the API declares under `instance`, issued through the transport it is handed."""
 
@@ -461,6 +931,71 @@ class InstanceApi:
         return InstanceConfig.from_json(decode_payload(payload))
 
 
+class Oauth2ClientCredentialsApi:
+    """What the API declares under `oauth2ClientCredentials`, issued through the transport it is handed."""
+
+    def __init__(self, transport: Any) -> None:
+        self._transport = transport
+
+    def delete(
+        self,
+        subscription_id: str,
+        application_id: str,
+    ) -> None:
+        """Remove the OAuth2 client credentials of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "DELETE",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return None
+
+    def get(
+        self,
+        subscription_id: str,
+        application_id: str,
+    ) -> Oauth2ClientCredentials:
+        """Get the OAuth2 client credentials of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return Oauth2ClientCredentials.from_json(decode_payload(payload))
+
+    def update(
+        self,
+        subscription_id: str,
+        application_id: str,
+        body: Oauth2ClientCredentialsPut,
+    ) -> Oauth2ClientCredentials:
+        """Set the OAuth2 client credentials of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "PUT",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return Oauth2ClientCredentials.from_json(decode_payload(payload))
+
+
 class PayloadContentTypesApi:
     """What the API declares under `payload_content_types`, issued through the transport it is handed."""
 
@@ -565,6 +1100,24 @@ class RequestAttemptsApi:
         raise_for_status(status, payload)
         return as_list(RequestAttempt.from_json)(decode_payload(payload))
 
+    def retry(
+        self,
+        request_attempt_id: str,
+        body: RetryRequestAttempt,
+    ) -> RequestAttempt:
+        """Retry a request attempt"""
+        path = "/api/v1/request_attempts/{request_attempt_id}/retry"
+        path = path.replace("{request_attempt_id}", path_segment(request_attempt_id))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return RequestAttempt.from_json(decode_payload(payload))
+
 
 class ResponseApi:
     """What the API declares under `response`, issued through the transport it is handed."""
@@ -592,6 +1145,90 @@ class ResponseApi:
         return Response.from_json(decode_payload(payload))
 
 
+class SecretRotationsApi:
+    """What the API declares under `secretRotations`, issued through the transport it is handed."""
+
+    def __init__(self, transport: Any) -> None:
+        self._transport = transport
+
+    def create(
+        self,
+        subscription_id: str,
+        application_id: str,
+        body: SecretRotationPost,
+    ) -> SecretRotation:
+        """Rotate the secret of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/secret_rotation"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return SecretRotation.from_json(decode_payload(payload))
+
+    def finalize(
+        self,
+        subscription_id: str,
+        application_id: str,
+    ) -> None:
+        """Finalize the secret rotation of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return None
+
+    def get(
+        self,
+        subscription_id: str,
+        application_id: str,
+    ) -> SecretRotation:
+        """Get the secret rotation of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/secret_rotation"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return SecretRotation.from_json(decode_payload(payload))
+
+    def rollback(
+        self,
+        subscription_id: str,
+        application_id: str,
+    ) -> None:
+        """Roll back the secret rotation of a subscription"""
+        path = "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback"
+        path = path.replace("{subscription_id}", path_segment(subscription_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return None
+
+
 class ServiceTokenApi:
     """What the API declares under `serviceToken`, issued through the transport it is handed."""
 
@@ -688,6 +1325,82 @@ class ServiceTokenApi:
         return as_list(ServiceToken.from_json)(decode_payload(payload))
 
 
+class SigningKeysApi:
+    """What the API declares under `signingKeys`, issued through the transport it is handed."""
+
+    def __init__(self, transport: Any) -> None:
+        self._transport = transport
+
+    def create(
+        self,
+        body: SigningKeyPost,
+    ) -> SigningKey:
+        """Create a signing key"""
+        path = "/api/v1/signing_keys/"
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "POST",
+            path,
+            query,
+            body.to_json(),
+        )
+        raise_for_status(status, payload)
+        return SigningKey.from_json(decode_payload(payload))
+
+    def delete(
+        self,
+        signing_key_id: str,
+        application_id: str,
+    ) -> None:
+        """Retire a signing key"""
+        path = "/api/v1/signing_keys/{signing_key_id}"
+        path = path.replace("{signing_key_id}", path_segment(signing_key_id))
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "DELETE",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return None
+
+    def jwks(
+        self,
+        application_id: str,
+    ) -> JsonWebKeySet:
+        """Get the public keys of an application"""
+        path = "/api/v1/jwks/{application_id}"
+        path = path.replace("{application_id}", path_segment(application_id))
+        query: list[tuple[str, str]] = []
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return JsonWebKeySet.from_json(decode_payload(payload))
+
+    def list(
+        self,
+        application_id: str,
+    ) -> list[SigningKey]:
+        """List signing keys"""
+        path = "/api/v1/signing_keys/"
+        query: list[tuple[str, str]] = []
+        query.append(("application_id", query_value(application_id)))
+        status, payload = self._transport.request(
+            "GET",
+            path,
+            query,
+            None,
+        )
+        raise_for_status(status, payload)
+        return as_list(SigningKey.from_json)(decode_payload(payload))
+
+
 class SubscriptionsApi:
     """What the API declares under `subscriptions`, issued through the transport it is handed."""
 
@@ -65,10 +65,26 @@ class AuthNoAuthorizationHeaderError(ProblemError):
     """The API reported `AuthNoAuthorizationHeader`."""
 
 
+class BulkRetryAlreadyRunningError(ProblemError):
+    """The API reported `BulkRetryAlreadyRunning`."""
+
+
+class BulkRetryFinishedError(ProblemError):
+    """The API reported `BulkRetryFinished`."""
+
+
+class ClientCertificatesDisabledError(ProblemError):
+    """The API reported `ClientCertificatesDisabled`."""
+
+
 class EventAlreadyIngestedError(ProblemError):
     """The API reported `EventAlreadyIngested`."""
 
 
+class EventDeliveryBeyondRetentionError(ProblemError):
+    """The API reported `EventDeliveryBeyondRetention`."""
+
+
 class EventInvalidBase64PayloadError(ProblemError):
     """The API reported `EventInvalidBase64Payload`."""
 
@@ -81,6 +97,14 @@ class EventInvalidPayloadContentTypeError(ProblemError):
     """The API reported `EventInvalidPayloadContentType`."""
 
 
+class EventNotScheduledError(ProblemError):
+    """The API reported `EventNotScheduled`."""
+
+
+class EventPayloadSchemaViolationError(ProblemError):
+    """The API reported `EventPayloadSchemaViolation`."""
+
+
 class EventTypeAlreadyExistError(ProblemError):
     """The API reported `EventTypeAlreadyExist`."""
 
@@ -89,6 +113,18 @@ class EventTypeDoesNotExistError(ProblemError):
     """The API reported `EventTypeDoesNotExist`."""
 
 
+class EventTypeVersionAlreadyExistError(ProblemError):
+    """The API reported `EventTypeVersionAlreadyExist`."""
+
+
+class EventTypeVersionDoesNotExistError(ProblemError):
+    """The API reported `EventTypeVersionDoesNotExist`."""
+
+
+class EventTypeVersionSunsetError(ProblemError):
+    """The API reported `EventTypeVersionSunset`."""
+
+
 class ForbiddenError(ProblemError):
     """The API reported `Forbidden`."""
 
@@ -97,6 +133,10 @@ class InternalServerErrorError(ProblemError):
     """The API reported `InternalServerError`."""
 
 
+class InvalidClientCertificateError(ProblemError):
+    """The API reported `InvalidClientCertificate`."""
+
+
 class InvalidDateRangeError(ProblemError):
     """The API reported `InvalidDateRange`."""
 
@@ -165,10 +205,38 @@ class RegistrationDisabledError(ProblemError):
     """The API reported `RegistrationDisabled`."""
 
 
+class RequestAttemptNotFailedError(ProblemError):
+    """The API reported `RequestAttemptNotFailed`."""
+
+
+class RequestAttemptRetryPendingError(ProblemError):
+    """The API reported `RequestAttemptRetryPending`."""
+
+
+class RetryPolicyOverLimitError(ProblemError):
+    """The API reported `RetryPolicyOverLimit`."""
+
+
+class SecretRotationInProgressError(ProblemError):
+    """The API reported `SecretRotationInProgress`."""
+
+
 class ServiceUnavailableError(ProblemError):
     """The API reported `ServiceUnavailable`."""
 
 
+class SigningKeysDisabledError(ProblemError):
+    """The API reported `SigningKeysDisabled`."""
+
+
+class SubscriptionDisabledError(ProblemError):
+    """The API reported `SubscriptionDisabled`."""
+
+
+class SubscriptionNotInboxError(ProblemError):
+    """The API reported `SubscriptionNotInbox`."""
+
+
 class TooManyApplicationsPerOrganizationError(ProblemError):
     """The API reported `TooManyApplicationsPerOrganization`."""
 
@@ -214,14 +282,24 @@ PROBLEMS: dict[ProblemId, type[ProblemError]] = {
     ProblemId.AUTH_INVALID_AUTHORIZATION_HEADER: AuthInvalidAuthorizationHeaderError,
     ProblemId.AUTH_INVALID_BISCUIT: AuthInvalidBiscuitError,
     ProblemId.AUTH_NO_AUTHORIZATION_HEADER: AuthNoAuthorizationHeaderError,
+    ProblemId.BULK_RETRY_ALREADY_RUNNING: BulkRetryAlreadyRunningError,
+    ProblemId.BULK_RETRY_FINISHED: BulkRetryFinishedError,
+    ProblemId.CLIENT_CERTIFICATES_DISABLED: ClientCertificatesDisabledError,
     ProblemId.EVENT_ALREADY_INGESTED: EventAlreadyIngestedError,
+    ProblemId.EVENT_DELIVERY_BEYOND_RETENTION: EventDeliveryBeyondRetentionError,
     ProblemId.EVENT_INVALID_BASE64_PAYLOAD: EventInvalidBase64PayloadError,
     ProblemId.EVENT_INVALID_JSON_PAYLOAD: EventInvalidJsonPayloadError,
     ProblemId.EVENT_INVALID_PAYLOAD_CONTENT_TYPE: EventInvalidPayloadContentTypeError,
+    ProblemId.EVENT_NOT_SCHEDULED: EventNotScheduledError,
+    ProblemId.EVENT_PAYLOAD_SCHEMA_VIOLATION: EventPayloadSchemaViolationError,
     ProblemId.EVENT_TYPE_ALREADY_EXIST: EventTypeAlreadyExistError,
     ProblemId.EVENT_TYPE_DOES_NOT_EXIST: EventTypeDoesNotExistError,
+    ProblemId.EVENT_TYPE_VERSION_ALREADY_EXIST: EventTypeVersionAlreadyExistError,
+    ProblemId.EVENT_TYPE_VERSION_DOES_NOT_EXIST: EventTypeVersionDoesNotExistError,
+    ProblemId.EVENT_TYPE_VERSION_SUNSET: EventTypeVersionSunsetError,
     ProblemId.FORBIDDEN: ForbiddenError,
     ProblemId.INTERNAL_SERVER_ERROR: InternalServerErrorError,
+    ProblemId.INVALID_CLIENT_CERTIFICATE: InvalidClientCertificateError,
     ProblemId.INVALID_DATE_RANGE: InvalidDateRangeError,
     ProblemId.INVALID_ROLE: InvalidRoleError,
     ProblemId.INVITED_USER_ALREADY_IN_ORGANIZATION: InvitedUserAlreadyInOrganizationError,
@@ -239,7 +317,14 @@ PROBLEMS: dict[ProblemId, type[ProblemError]] = {
     ProblemId.PASSWORD_TOO_SHORT: PasswordTooShortError,
     ProblemId.RATE_LIMITED: RateLimitedError,
     ProblemId.REGISTRATION_DISABLED: RegistrationDisabledError,
+    ProblemId.REQUEST_ATTEMPT_NOT_FAILED: RequestAttemptNotFailedError,
+    ProblemId.REQUEST_ATTEMPT_RETRY_PENDING: RequestAttemptRetryPendingError,
+    ProblemId.RETRY_POLICY_OVER_LIMIT: RetryPolicyOverLimitError,
+    ProblemId.SECRET_ROTATION_IN_PROGRESS: SecretRotationInProgressError,
     ProblemId.SERVICE_UNAVAILABLE: ServiceUnavailableError,
+    ProblemId.SIGNING_KEYS_DISABLED: SigningKeysDisabledError,
+    ProblemId.SUBSCRIPTION_DISABLED: SubscriptionDisabledError,
+    ProblemId.SUBSCRIPTION_NOT_INBOX: SubscriptionNotInboxError,
     ProblemId.TOO_MANY_APPLICATIONS_PER_ORGANIZATION: TooManyApplicationsPerOrganizationError,
     ProblemId.TOO_MANY_EVENT_TYPES_PER_APPLICATION: TooManyEventTypesPerApplicationError,
     ProblemId.TOO_MANY_EVENTS_TODAY: TooManyEventsTodayError,
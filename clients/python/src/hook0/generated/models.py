@@ -48,6 +48,137 @@ class ApplicationInfoOnboardingStepsSubscription(StrEnum):
     DONE = "Done"
 
 
+class BulkRetryPostStatus(StrEnum):
+    """One of the values the API answers with."""
+
+    FAILED = "failed"
+    SUCCESSFUL = "successful"
+
+
+class BulkRetryStatus(StrEnum):
+    """One of the values the API answers with."""
+
+    FAILED = "failed"
+    SUCCESSFUL = "successful"
+
+
+class DeadLetterDetailsDeadLetterReason(StrEnum):
+    """One of the values the API answers with."""
+
+    RETRIES_EXHAUSTED = "retries_exhausted"
+    SIGNATURE_FAILED = "signature_failed"
+    TRANSFORM_FAILED = "transform_failed"
+    PERMANENT_FAILURE = "permanent_failure"
+
+
+class DeadLetterReason(StrEnum):
+    """One of the values the API answers with."""
+
+    RETRIES_EXHAUSTED = "retries_exhausted"
+    SIGNATURE_FAILED = "signature_failed"
+    TRANSFORM_FAILED = "transform_failed"
+    PERMANENT_FAILURE = "permanent_failure"
+
+
+class EventTypePayloadSchemaMode(StrEnum):
+    """One of the values the API answers with."""
+
+    ENFORCE = "enforce"
+    WARN = "warn"
+    OFF = "off"
+
+
+class EventTypePostPayloadSchemaMode(StrEnum):
+    """One of the values the API answers with."""
+
+    ENFORCE = "enforce"
+    WARN = "warn"
+    OFF = "off"
+
+
+class EventTypePutPayloadSchemaMode(StrEnum):
+    """One of the values the API answers with."""
+
+    ENFORCE = "enforce"
+    WARN = "warn"
+    OFF = "off"
+
+
+class IngestedEventsBatchEventsProblemId(StrEnum):
+    """One of the values the API answers with."""
+
+    ORGANIZATION_NAME_MISSING = "OrganizationNameMissing"
+    USER_ALREADY_EXIST = "UserAlreadyExist"
+    REGISTRATION_DISABLED = "RegistrationDisabled"
+    PASSWORD_TOO_SHORT = "PasswordTooShort"
+    PASSWORD_TOO_LONG = "PasswordTooLong"
+    PASSWORD_SIMILAR_TO_EMAIL = "PasswordSimilarToEmail"
+    PASSWORD_SIMILAR_TO_NAME = "PasswordSimilarToName"
+    PASSWORD_TOO_COMMON = "PasswordTooCommon"
+    PASSWORD_NOT_DIVERSE_ENOUGH = "PasswordNotDiverseEnough"
+    ORGANIZATION_IS_NOT_EMPTY = "OrganizationIsNotEmpty"
+    INVITED_USER_DOES_NOT_EXIST = "InvitedUserDoesNotExist"
+    INVITED_USER_ALREADY_IN_ORGANIZATION = "InvitedUserAlreadyInOrganization"
+    APPLICATION_NAME_MISSING = "ApplicationNameMissing"
+    INVALID_ROLE = "InvalidRole"
+    EVENT_TYPE_ALREADY_EXIST = "EventTypeAlreadyExist"
+    EVENT_TYPE_DOES_NOT_EXIST = "EventTypeDoesNotExist"
+    EVENT_TYPE_VERSION_ALREADY_EXIST = "EventTypeVersionAlreadyExist"
+    EVENT_TYPE_VERSION_DOES_NOT_EXIST = "EventTypeVersionDoesNotExist"
+    EVENT_TYPE_VERSION_SUNSET = "EventTypeVersionSunset"
+    UNAUTHORIZED_WORKERS = "UnauthorizedWorkers"
+    EVENT_ALREADY_INGESTED = "EventAlreadyIngested"
+    EVENT_INVALID_PAYLOAD_CONTENT_TYPE = "EventInvalidPayloadContentType"
+    EVENT_INVALID_BASE64_PAYLOAD = "EventInvalidBase64Payload"
+    EVENT_INVALID_JSON_PAYLOAD = "EventInvalidJsonPayload"
+    EVENT_PAYLOAD_SCHEMA_VIOLATION = "EventPayloadSchemaViolation"
+    EVENT_DELIVERY_BEYOND_RETENTION = "EventDeliveryBeyondRetention"
+    EVENT_NOT_SCHEDULED = "EventNotScheduled"
+    LABELS_AMBIGUITY = "LabelsAmbiguity"
+    INVALID_DATE_RANGE = "InvalidDateRange"
+    REQUEST_ATTEMPT_NOT_FAILED = "RequestAttemptNotFailed"
+    REQUEST_ATTEMPT_RETRY_PENDING = "RequestAttemptRetryPending"
+    SUBSCRIPTION_DISABLED = "SubscriptionDisabled"
+    SUBSCRIPTION_NOT_INBOX = "SubscriptionNotInbox"
+    SECRET_ROTATION_IN_PROGRESS = "SecretRotationInProgress"
+    BULK_RETRY_ALREADY_RUNNING = "BulkRetryAlreadyRunning"
+    BULK_RETRY_FINISHED = "BulkRetryFinished"
+    RETRY_POLICY_OVER_LIMIT = "RetryPolicyOverLimit"
+    CLIENT_CERTIFICATES_DISABLED = "ClientCertificatesDisabled"
+    INVALID_CLIENT_CERTIFICATE = "InvalidClientCertificate"
+    SIGNING_KEYS_DISABLED = "SigningKeysDisabled"
+    AUTH_NO_AUTHORIZATION_HEADER = "AuthNoAuthorizationHeader"
+    AUTH_INVALID_AUTHORIZATION_HEADER = "AuthInvalidAuthorizationHeader"
+    AUTH_APPLICATION_SECRET_LOOKUP_ERROR = "AuthApplicationSecretLookupError"
+    AUTH_INVALID_APPLICATION_SECRET = "AuthInvalidApplicationSecret"
+    AUTH_BISCUIT_LOOKUP_ERROR = "AuthBiscuitLookupError"
+    AUTH_INVALID_BISCUIT = "AuthInvalidBiscuit"
+    AUTH_FAILED_LOGIN = "AuthFailedLogin"
+    AUTH_EMAIL_NOT_VERIFIED = "AuthEmailNotVerified"
+    AUTH_EMAIL_ALREADY_VERIFIED = "AuthEmailAlreadyVerified"
+    AUTH_FAILED_REFRESH = "AuthFailedRefresh"
+    AUTH_EMAIL_EXPIRED = "AuthEmailExpired"
+    TOO_MANY_MEMBERS_PER_ORGANIZATION = "TooManyMembersPerOrganization"
+    TOO_MANY_APPLICATIONS_PER_ORGANIZATION = "TooManyApplicationsPerOrganization"
+    TOO_MANY_EVENTS_TODAY = "TooManyEventsToday"
+    TOO_MANY_SUBSCRIPTIONS_PER_APPLICATION = "TooManySubscriptionsPerApplication"
+    TOO_MANY_EVENT_TYPES_PER_APPLICATION = "TooManyEventTypesPerApplication"
+    JSON_PAYLOAD = "JsonPayload"
+    VALIDATION = "Validation"
+    NOT_FOUND = "NotFound"
+    INTERNAL_SERVER_ERROR = "InternalServerError"
+    FORBIDDEN = "Forbidden"
+    RATE_LIMITED = "RateLimited"
+    SERVICE_UNAVAILABLE = "ServiceUnavailable"
+
+
+class IngestedEventsBatchEventsStatus(StrEnum):
+    """One of the values the API answers with."""
+
+    INGESTED = "ingested"
+    FAILED = "failed"
+
+
 class OrganizationInfoOnboardingStepsApplication(StrEnum):
     """One of the values the API answers with."""
 
@@ -95,13 +226,30 @@ class ProblemId(StrEnum):
     INVALID_ROLE = "InvalidRole"
     EVENT_TYPE_ALREADY_EXIST = "EventTypeAlreadyExist"
     EVENT_TYPE_DOES_NOT_EXIST = "EventTypeDoesNotExist"
+    EVENT_TYPE_VERSION_ALREADY_EXIST = "EventTypeVersionAlreadyExist"
+    EVENT_TYPE_VERSION_DOES_NOT_EXIST = "EventTypeVersionDoesNotExist"
+    EVENT_TYPE_VERSION_SUNSET = "EventTypeVersionSunset"
     UNAUTHORIZED_WORKERS = "UnauthorizedWorkers"
     EVENT_ALREADY_INGESTED = "EventAlreadyIngested"
     EVENT_INVALID_PAYLOAD_CONTENT_TYPE = "EventInvalidPayloadContentType"
     EVENT_INVALID_BASE64_PAYLOAD = "EventInvalidBase64Payload"
     EVENT_INVALID_JSON_PAYLOAD = "EventInvalidJsonPayload"
+    EVENT_PAYLOAD_SCHEMA_VIOLATION = "EventPayloadSchemaViolation"
+    EVENT_DELIVERY_BEYOND_RETENTION = "EventDeliveryBeyondRetention"
+    EVENT_NOT_SCHEDULED = "EventNotScheduled"
     LABELS_AMBIGUITY = "LabelsAmbiguity"
     INVALID_DATE_RANGE = "InvalidDateRange"
+    REQUEST_ATTEMPT_NOT_FAILED = "RequestAttemptNotFailed"
+    REQUEST_ATTEMPT_RETRY_PENDING = "RequestAttemptRetryPending"
+    SUBSCRIPTION_DISABLED = "SubscriptionDisabled"
+    SUBSCRIPTION_NOT_INBOX = "SubscriptionNotInbox"
+    SECRET_ROTATION_IN_PROGRESS = "SecretRotationInProgress"
+    BULK_RETRY_ALREADY_RUNNING = "BulkRetryAlreadyRunning"
+    BULK_RETRY_FINISHED = "BulkRetryFinished"
+    RETRY_POLICY_OVER_LIMIT = "RetryPolicyOverLimit"
+    CLIENT_CERTIFICATES_DISABLED = "ClientCertificatesDisabled"
+    INVALID_CLIENT_CERTIFICATE = "InvalidClientCertificate"
+    SIGNING_KEYS_DISABLED = "SigningKeysDisabled"
     AUTH_NO_AUTHORIZATION_HEADER = "AuthNoAuthorizationHeader"
     AUTH_INVALID_AUTHORIZATION_HEADER = "AuthInvalidAuthorizationHeader"
     AUTH_APPLICATION_SECRET_LOOKUP_ERROR = "AuthApplicationSecretLookupError"
@@ -127,6 +275,15 @@ class ProblemId(StrEnum):
     SERVICE_UNAVAILABLE = "ServiceUnavailable"
 
 
+class RequestAttemptStatusRuleOutcome(StrEnum):
+    """One of the values the API answers with."""
+
+    SUCCESS = "success"
+    RETRYABLE = "retryable"
+    PERMANENT_FAILURE = "permanent_failure"
+    DISABLE_SUBSCRIPTION = "disable_subscription"
+
+
 class RequestAttemptStatusType(StrEnum):
     """One of the values the API answers with."""
 
@@ -135,6 +292,58 @@ class RequestAttemptStatusType(StrEnum):
     IN_PROGRESS = "in_progress"
     SUCCESSFUL = "successful"
     FAILED = "failed"
+    EXPIRED = "expired"
+    CANCELLED = "cancelled"
+
+
+class SubscriptionPostResponseRulesOutcome(StrEnum):
+    """One of the values the API answers with."""
+
+    SUCCESS = "success"
+    RETRYABLE = "retryable"
+    PERMANENT_FAILURE = "permanent_failure"
+    DISABLE_SUBSCRIPTION = "disable_subscription"
+
+
+class SubscriptionPostSignatureScheme(StrEnum):
+    """One of the values the API answers with."""
+
+    HOOK0 = "hook0"
+    STANDARD_WEBHOOKS = "standard_webhooks"
+
+
+class SubscriptionPostTargetType(StrEnum):
+    """One of the values the API answers with."""
+
+    HTTP = "http"
+    AMQP = "amqp"
+    SQS = "sqs"
+    INBOX = "inbox"
+
+
+class SubscriptionResponseRulesOutcome(StrEnum):
+    """One of the values the API answers with."""
+
+    SUCCESS = "success"
+    RETRYABLE = "retryable"
+    PERMANENT_FAILURE = "permanent_failure"
+    DISABLE_SUBSCRIPTION = "disable_subscription"
+
+
+class SubscriptionSignatureScheme(StrEnum):
+    """One of the values the API answers with."""
+
+    HOOK0 = "hook0"
+    STANDARD_WEBHOOKS = "standard_webhooks"
+
+
+class SubscriptionTargetType(StrEnum):
+    """One of the values the API answers with."""
+
+    HTTP = "http"
+    AMQP = "amqp"
+    SQS = "sqs"
+    INBOX = "inbox"
 
 
 @dataclass(frozen=True)
@@ -355,1103 +564,3202 @@ class ApplicationSecretPost:
 
 
 @dataclass(frozen=True)
-class Event:
-    """The `Event` the API declares."""
+class BulkRetry:
+    """The `BulkRetry` the API declares."""
 
-    event_id: uuid.UUID
-    event_type_name: str
-    ip: str
-    labels: Any
-    occurred_at: datetime.datetime
-    payload_content_type: str
-    received_at: datetime.datetime
-    metadata: Any | None = None
+    application_id: uuid.UUID
+    bulk_retry_id: uuid.UUID
+    created_at: datetime.datetime
+    event_type_names: list[str]
+    max_created_at: datetime.datetime
+    min_created_at: datetime.datetime
+    processed_count: int
+    retried_count: int
+    status: BulkRetryStatus
+    total_count: int
+    updated_at: datetime.datetime
+    cancelled_at: datetime.datetime | None = None
+    completed_at: datetime.datetime | None = None
+    event_id: uuid.UUID | None = None
+    subscription_id: uuid.UUID | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> Event:
+    def from_json(cls, value: Any) -> BulkRetry:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "Event")
+        fields = as_fields(value, "BulkRetry")
         return cls(
-            read(fields, "event_id", as_uuid),
-            read(fields, "event_type_name", as_text),
-            read(fields, "ip", as_text),
-            read(fields, "labels", as_json),
-            read(fields, "occurred_at", as_datetime),
-            read(fields, "payload_content_type", as_text),
-            read(fields, "received_at", as_datetime),
-            maybe(fields, "metadata", as_json),
+            read(fields, "application_id", as_uuid),
+            read(fields, "bulk_retry_id", as_uuid),
+            read(fields, "created_at", as_datetime),
+            read(fields, "event_type_names", as_list(as_text)),
+            read(fields, "max_created_at", as_datetime),
+            read(fields, "min_created_at", as_datetime),
+            read(fields, "processed_count", as_int),
+            read(fields, "retried_count", as_int),
+            read(fields, "status", as_enum(BulkRetryStatus)),
+            read(fields, "total_count", as_int),
+            read(fields, "updated_at", as_datetime),
+            maybe(fields, "cancelled_at", as_datetime),
+            maybe(fields, "completed_at", as_datetime),
+            maybe(fields, "event_id", as_uuid),
+            maybe(fields, "subscription_id", as_uuid),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["event_id"] = str(self.event_id)
-        out["event_type_name"] = self.event_type_name
-        out["ip"] = self.ip
-        out["labels"] = self.labels
-        out["occurred_at"] = self.occurred_at.isoformat()
-        out["payload_content_type"] = self.payload_content_type
-        out["received_at"] = self.received_at.isoformat()
-        if self.metadata is not None:
-            out["metadata"] = self.metadata
+        out["application_id"] = str(self.application_id)
+        out["bulk_retry_id"] = str(self.bulk_retry_id)
+        out["created_at"] = self.created_at.isoformat()
+        out["event_type_names"] = list(self.event_type_names)
+        out["max_created_at"] = self.max_created_at.isoformat()
+        out["min_created_at"] = self.min_created_at.isoformat()
+        out["processed_count"] = self.processed_count
+        out["retried_count"] = self.retried_count
+        out["status"] = self.status.value
+        out["total_count"] = self.total_count
+        out["updated_at"] = self.updated_at.isoformat()
+        if self.cancelled_at is not None:
+            out["cancelled_at"] = self.cancelled_at.isoformat()
+        if self.completed_at is not None:
+            out["completed_at"] = self.completed_at.isoformat()
+        if self.event_id is not None:
+            out["event_id"] = str(self.event_id)
+        if self.subscription_id is not None:
+            out["subscription_id"] = str(self.subscription_id)
         return out
 
 
 @dataclass(frozen=True)
-class EventPost:
-    """The `EventPost` the API declares."""
+class BulkRetryDryRun:
+    """The `BulkRetryDryRun` the API declares."""
 
-    application_id: uuid.UUID
-    event_type: str
-    labels: dict[str, str]
-    occurred_at: datetime.datetime
-    payload: str
-    payload_content_type: str
-    event_id: uuid.UUID | None = None
-    metadata: dict[str, str] | None = None
+    count: int
 
     @classmethod
-    def from_json(cls, value: Any) -> EventPost:
+    def from_json(cls, value: Any) -> BulkRetryDryRun:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "EventPost")
+        fields = as_fields(value, "BulkRetryDryRun")
         return cls(
-            read(fields, "application_id", as_uuid),
-            read(fields, "event_type", as_text),
-            read(fields, "labels", as_map(as_text)),
-            read(fields, "occurred_at", as_datetime),
-            read(fields, "payload", as_text),
-            read(fields, "payload_content_type", as_text),
-            maybe(fields, "event_id", as_uuid),
-            maybe(fields, "metadata", as_map(as_text)),
+            read(fields, "count", as_int),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["application_id"] = str(self.application_id)
-        out["event_type"] = self.event_type
-        out["labels"] = dict(self.labels)
-        out["occurred_at"] = self.occurred_at.isoformat()
-        out["payload"] = self.payload
-        out["payload_content_type"] = self.payload_content_type
-        if self.event_id is not None:
-            out["event_id"] = str(self.event_id)
-        if self.metadata is not None:
-            out["metadata"] = dict(self.metadata)
+        out["count"] = self.count
         return out
 
 
 @dataclass(frozen=True)
-class EventType:
-    """The `EventType` the API declares."""
+class BulkRetryPost:
+    """The `BulkRetryPost` the API declares."""
 
-    event_type_name: str
-    resource_type_name: str
-    service_name: str
-    verb_name: str
+    application_id: uuid.UUID
+    event_id: uuid.UUID | None = None
+    event_type_names: list[str] | None = None
+    max_created_at: datetime.datetime | None = None
+    min_created_at: datetime.datetime | None = None
+    status: BulkRetryPostStatus | None = None
+    subscription_id: uuid.UUID | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> EventType:
+    def from_json(cls, value: Any) -> BulkRetryPost:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "EventType")
+        fields = as_fields(value, "BulkRetryPost")
         return cls(
-            read(fields, "event_type_name", as_text),
-            read(fields, "resource_type_name", as_text),
-            read(fields, "service_name", as_text),
-            read(fields, "verb_name", as_text),
+            read(fields, "application_id", as_uuid),
+            maybe(fields, "event_id", as_uuid),
+            maybe(fields, "event_type_names", as_list(as_text)),
+            maybe(fields, "max_created_at", as_datetime),
+            maybe(fields, "min_created_at", as_datetime),
+            maybe(fields, "status", as_enum(BulkRetryPostStatus)),
+            maybe(fields, "subscription_id", as_uuid),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["event_type_name"] = self.event_type_name
-        out["resource_type_name"] = self.resource_type_name
-        out["service_name"] = self.service_name
-        out["verb_name"] = self.verb_name
+        out["application_id"] = str(self.application_id)
+        if self.event_id is not None:
+            out["event_id"] = str(self.event_id)
+        if self.event_type_names is not None:
+            out["event_type_names"] = list(self.event_type_names)
+        if self.max_created_at is not None:
+            out["max_created_at"] = self.max_created_at.isoformat()
+        if self.min_created_at is not None:
+            out["min_created_at"] = self.min_created_at.isoformat()
+        if self.status is not None:
+            out["status"] = self.status.value
+        if self.subscription_id is not None:
+            out["subscription_id"] = str(self.subscription_id)
         return out
 
 
 @dataclass(frozen=True)
-class EventTypePost:
-    """The `EventTypePost` the API declares."""
+class CancelEvent:
+    """The `CancelEvent` the API declares."""
 
     application_id: uuid.UUID
-    resource_type: str
-    service: str
-    verb: str
 
     @classmethod
-    def from_json(cls, value: Any) -> EventTypePost:
+    def from_json(cls, value: Any) -> CancelEvent:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "EventTypePost")
+        fields = as_fields(value, "CancelEvent")
         return cls(
             read(fields, "application_id", as_uuid),
-            read(fields, "resource_type", as_text),
-            read(fields, "service", as_text),
-            read(fields, "verb", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
         out["application_id"] = str(self.application_id)
-        out["resource_type"] = self.resource_type
-        out["service"] = self.service
-        out["verb"] = self.verb
         return out
 
 
 @dataclass(frozen=True)
-class EventWithPayload:
-    """The `EventWithPayload` the API declares."""
+class ClientCertificate:
+    """The `ClientCertificate` the API declares."""
 
-    event_id: uuid.UUID
-    event_type_name: str
-    ip: str
-    labels: Any
-    occurred_at: datetime.datetime
-    payload: str
-    payload_content_type: str
-    received_at: datetime.datetime
-    metadata: Any | None = None
+    certificate_pem: str
+    created_at: datetime.datetime
+    fingerprint_sha256: str
+    not_after: datetime.datetime
+    not_before: datetime.datetime
+    subject: str
+    subscription_id: uuid.UUID
+    updated_at: datetime.datetime
 
     @classmethod
-    def from_json(cls, value: Any) -> EventWithPayload:
+    def from_json(cls, value: Any) -> ClientCertificate:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "EventWithPayload")
+        fields = as_fields(value, "ClientCertificate")
         return cls(
-            read(fields, "event_id", as_uuid),
-            read(fields, "event_type_name", as_text),
-            read(fields, "ip", as_text),
-            read(fields, "labels", as_json),
-            read(fields, "occurred_at", as_datetime),
-            read(fields, "payload", as_text),
-            read(fields, "payload_content_type", as_text),
-            read(fields, "received_at", as_datetime),
-            maybe(fields, "metadata", as_json),
+            read(fields, "certificate_pem", as_text),
+            read(fields, "created_at", as_datetime),
+            read(fields, "fingerprint_sha256", as_text),
+            read(fields, "not_after", as_datetime),
+            read(fields, "not_before", as_datetime),
+            read(fields, "subject", as_text),
+            read(fields, "subscription_id", as_uuid),
+            read(fields, "updated_at", as_datetime),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["event_id"] = str(self.event_id)
-        out["event_type_name"] = self.event_type_name
-        out["ip"] = self.ip
-        out["labels"] = self.labels
-        out["occurred_at"] = self.occurred_at.isoformat()
-        out["payload"] = self.payload
+        out["certificate_pem"] = self.certificate_pem
+        out["created_at"] = self.created_at.isoformat()
+        out["fingerprint_sha256"] = self.fingerprint_sha256
+        out["not_after"] = self.not_after.isoformat()
+        out["not_before"] = self.not_before.isoformat()
+        out["subject"] = self.subject
+        out["subscription_id"] = str(self.subscription_id)
+        out["updated_at"] = self.updated_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class ClientCertificatePut:
+    """The `ClientCertificatePut` the API declares."""
+
+    certificate_pem: str
+    private_key_pem: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> ClientCertificatePut:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "ClientCertificatePut")
+        return cls(
+            read(fields, "certificate_pem", as_text),
+            read(fields, "private_key_pem", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["certificate_pem"] = self.certificate_pem
+        out["private_key_pem"] = self.private_key_pem
+        return out
+
+
+@dataclass(frozen=True)
+class DeadLetter:
+    """The `DeadLetter` the API declares."""
+
+    created_at: datetime.datetime
+    dead_letter_id: uuid.UUID
+    event: DeadLetterEvent
+    reason: DeadLetterReason
+    request_attempt_id: uuid.UUID
+    retry_count: int
+    subscription: DeadLetterSubscription
+    http_response_status: int | None = None
+    response_id: uuid.UUID | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> DeadLetter:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "DeadLetter")
+        return cls(
+            read(fields, "created_at", as_datetime),
+            read(fields, "dead_letter_id", as_uuid),
+            read(fields, "event", DeadLetterEvent.from_json),
+            read(fields, "reason", as_enum(DeadLetterReason)),
+            read(fields, "request_attempt_id", as_uuid),
+            read(fields, "retry_count", as_int),
+            read(fields, "subscription", DeadLetterSubscription.from_json),
+            maybe(fields, "http_response_status", as_int),
+            maybe(fields, "response_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["created_at"] = self.created_at.isoformat()
+        out["dead_letter_id"] = str(self.dead_letter_id)
+        out["event"] = self.event.to_json()
+        out["reason"] = self.reason.value
+        out["request_attempt_id"] = str(self.request_attempt_id)
+        out["retry_count"] = self.retry_count
+        out["subscription"] = self.subscription.to_json()
+        if self.http_response_status is not None:
+            out["http_response_status"] = self.http_response_status
+        if self.response_id is not None:
+            out["response_id"] = str(self.response_id)
+        return out
+
+
+@dataclass(frozen=True)
+class DeadLetterDetails:
+    """The `DeadLetterDetails` the API declares."""
+
+    dead_letter: DeadLetterDetailsDeadLetter
+    last_response: DeadLetterDetailsLastResponse | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> DeadLetterDetails:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "DeadLetterDetails")
+        return cls(
+            read(fields, "dead_letter", DeadLetterDetailsDeadLetter.from_json),
+            maybe(fields, "last_response", DeadLetterDetailsLastResponse.from_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["dead_letter"] = self.dead_letter.to_json()
+        if self.last_response is not None:
+            out["last_response"] = self.last_response.to_json()
+        return out
+
+
+@dataclass(frozen=True)
+class DeadLetterDetailsDeadLetter:
+    """The `DeadLetterDetailsDeadLetter` the API declares."""
+
+    created_at: datetime.datetime
+    dead_letter_id: uuid.UUID
+    event: DeadLetterDetailsDeadLetterEvent
+    reason: DeadLetterDetailsDeadLetterReason
+    request_attempt_id: uuid.UUID
+    retry_count: int
+    subscription: DeadLetterDetailsDeadLetterSubscription
+    http_response_status: int | None = None
+    response_id: uuid.UUID | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> DeadLetterDetailsDeadLetter:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "DeadLetterDetailsDeadLetter")
+        return cls(
+            read(fields, "created_at", as_datetime),
+            read(fields, "dead_letter_id", as_uuid),
+            read(fields, "event", DeadLetterDetailsDeadLetterEvent.from_json),
+            read(fields, "reason", as_enum(DeadLetterDetailsDeadLetterReason)),
+            read(fields, "request_attempt_id", as_uuid),
+            read(fields, "retry_count", as_int),
+            read(fields, "subscription", DeadLetterDetailsDeadLetterSubscription.from_json),
+            maybe(fields, "http_response_status", as_int),
+            maybe(fields, "response_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["created_at"] = self.created_at.isoformat()
+        out["dead_letter_id"] = str(self.dead_letter_id)
+        out["event"] = self.event.to_json()
+        out["reason"] = self.reason.value
+        out["request_attempt_id"] = str(self.request_attempt_id)
+        out["retry_count"] = self.retry_count
+        out["subscription"] = self.subscription.to_json()
+        if self.http_response_status is not None:
+            out["http_response_status"] = self.http_response_status
+        if self.response_id is not None:
+            out["response_id"] = str(self.response_id)
+        return out
+
+
+@dataclass(frozen=True)
+class DeadLetterDetailsDeadLetterEvent:
+    """The `DeadLetterDetailsDeadLetterEvent` the API declares."""
+
+    event_id: uuid.UUID
+    event_type_name: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> DeadLetterDetailsDeadLetterEvent:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "DeadLetterDetailsDeadLetterEvent")
+        return cls(
+            read(fields, "event_id", as_uuid),
+            read(fields, "event_type_name", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["event_id"] = str(self.event_id)
+        out["event_type_name"] = self.event_type_name
+        return out
+
+
+@dataclass(frozen=True)
+class DeadLetterDetailsDeadLetterSubscription:
+    """The `DeadLetterDetailsDeadLetterSubscription` the API declares."""
+
+    subscription_id: uuid.UUID
+    description: str | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> DeadLetterDetailsDeadLetterSubscription:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "DeadLetterDetailsDeadLetterSubscription")
+        return cls(
+            read(fields, "subscription_id", as_uuid),
+            maybe(fields, "description", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["subscription_id"] = str(self.subscription_id)
+        if self.description is not None:
+            out["description"] = self.description
+        return out
+
+
+@dataclass(frozen=True)
+class DeadLetterDetailsLastResponse:
+    """The `DeadLetterDetailsLastResponse` the API declares."""
+
+    response_id: uuid.UUID
+    body: str | None = None
+    elapsed_time_ms: int | None = None
+    headers: dict[str, str] | None = None
+    http_code: int | None = None
+    response_error_name: str | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> DeadLetterDetailsLastResponse:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "DeadLetterDetailsLastResponse")
+        return cls(
+            read(fields, "response_id", as_uuid),
+            maybe(fields, "body", as_text),
+            maybe(fields, "elapsed_time_ms", as_int),
+            maybe(fields, "headers", as_map(as_text)),
+            maybe(fields, "http_code", as_int),
+            maybe(fields, "response_error_name", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["response_id"] = str(self.response_id)
+        if self.body is not None:
+            out["body"] = self.body
+        if self.elapsed_time_ms is not None:
+            out["elapsed_time_ms"] = self.elapsed_time_ms
+        if self.headers is not None:
+            out["headers"] = dict(self.headers)
+        if self.http_code is not None:
+            out["http_code"] = self.http_code
+        if self.response_error_name is not None:
+            out["response_error_name"] = self.response_error_name
+        return out
+
+
+@dataclass(frozen=True)
+class DeadLetterEvent:
+    """The `DeadLetterEvent` the API declares."""
+
+    event_id: uuid.UUID
+    event_type_name: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> DeadLetterEvent:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "DeadLetterEvent")
+        return cls(
+            read(fields, "event_id", as_uuid),
+            read(fields, "event_type_name", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["event_id"] = str(self.event_id)
+        out["event_type_name"] = self.event_type_name
+        return out
+
+
+@dataclass(frozen=True)
+class DeadLetterSubscription:
+    """The `DeadLetterSubscription` the API declares."""
+
+    subscription_id: uuid.UUID
+    description: str | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> DeadLetterSubscription:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "DeadLetterSubscription")
+        return cls(
+            read(fields, "subscription_id", as_uuid),
+            maybe(fields, "description", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["subscription_id"] = str(self.subscription_id)
+        if self.description is not None:
+            out["description"] = self.description
+        return out
+
+
+@dataclass(frozen=True)
+class Event:
+    """The `Event` the API declares."""
+
+    event_id: uuid.UUID
+    event_type_name: str
+    ip: str
+    labels: Any
+    occurred_at: datetime.datetime
+    payload_content_type: str
+    received_at: datetime.datetime
+    cancelled_at: datetime.datetime | None = None
+    deliver_at: datetime.datetime | None = None
+    event_type_version: int | None = None
+    expires_at: datetime.datetime | None = None
+    metadata: Any | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> Event:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "Event")
+        return cls(
+            read(fields, "event_id", as_uuid),
+            read(fields, "event_type_name", as_text),
+            read(fields, "ip", as_text),
+            read(fields, "labels", as_json),
+            read(fields, "occurred_at", as_datetime),
+            read(fields, "payload_content_type", as_text),
+            read(fields, "received_at", as_datetime),
+            maybe(fields, "cancelled_at", as_datetime),
+            maybe(fields, "deliver_at", as_datetime),
+            maybe(fields, "event_type_version", as_int),
+            maybe(fields, "expires_at", as_datetime),
+            maybe(fields, "metadata", as_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["event_id"] = str(self.event_id)
+        out["event_type_name"] = self.event_type_name
+        out["ip"] = self.ip
+        out["labels"] = self.labels
+        out["occurred_at"] = self.occurred_at.isoformat()
         out["payload_content_type"] = self.payload_content_type
         out["received_at"] = self.received_at.isoformat()
+        if self.cancelled_at is not None:
+            out["cancelled_at"] = self.cancelled_at.isoformat()
+        if self.deliver_at is not None:
+            out["deliver_at"] = self.deliver_at.isoformat()
+        if self.event_type_version is not None:
+            out["event_type_version"] = self.event_type_version
+        if self.expires_at is not None:
+            out["expires_at"] = self.expires_at.isoformat()
         if self.metadata is not None:
             out["metadata"] = self.metadata
         return out
 
 
 @dataclass(frozen=True)
-class EventsPerDayEntry:
-    """The `EventsPerDayEntry` the API declares."""
+class EventPost:
+    """The `EventPost` the API declares."""
+
+    application_id: uuid.UUID
+    event_type: str
+    labels: dict[str, str]
+    occurred_at: datetime.datetime
+    payload: str
+    payload_content_type: str
+    deliver_at: datetime.datetime | None = None
+    event_id: uuid.UUID | None = None
+    event_type_version: int | None = None
+    expires_at: datetime.datetime | None = None
+    metadata: dict[str, str] | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventPost:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventPost")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "event_type", as_text),
+            read(fields, "labels", as_map(as_text)),
+            read(fields, "occurred_at", as_datetime),
+            read(fields, "payload", as_text),
+            read(fields, "payload_content_type", as_text),
+            maybe(fields, "deliver_at", as_datetime),
+            maybe(fields, "event_id", as_uuid),
+            maybe(fields, "event_type_version", as_int),
+            maybe(fields, "expires_at", as_datetime),
+            maybe(fields, "metadata", as_map(as_text)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["event_type"] = self.event_type
+        out["labels"] = dict(self.labels)
+        out["occurred_at"] = self.occurred_at.isoformat()
+        out["payload"] = self.payload
+        out["payload_content_type"] = self.payload_content_type
+        if self.deliver_at is not None:
+            out["deliver_at"] = self.deliver_at.isoformat()
+        if self.event_id is not None:
+            out["event_id"] = str(self.event_id)
+        if self.event_type_version is not None:
+            out["event_type_version"] = self.event_type_version
+        if self.expires_at is not None:
+            out["expires_at"] = self.expires_at.isoformat()
+        if self.metadata is not None:
+            out["metadata"] = dict(self.metadata)
+        return out
+
+
+@dataclass(frozen=True)
+class EventType:
+    """The `EventType` the API declares."""
+
+    event_type_name: str
+    payload_schema_mode: EventTypePayloadSchemaMode
+    resource_type_name: str
+    service_name: str
+    verb_name: str
+    versions: list[EventTypeVersions]
+    default_ttl_s: int | None = None
+    payload_schema: Any | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventType:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventType")
+        return cls(
+            read(fields, "event_type_name", as_text),
+            read(fields, "payload_schema_mode", as_enum(EventTypePayloadSchemaMode)),
+            read(fields, "resource_type_name", as_text),
+            read(fields, "service_name", as_text),
+            read(fields, "verb_name", as_text),
+            read(fields, "versions", as_list(EventTypeVersions.from_json)),
+            maybe(fields, "default_ttl_s", as_int),
+            maybe(fields, "payload_schema", as_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["event_type_name"] = self.event_type_name
+        out["payload_schema_mode"] = self.payload_schema_mode.value
+        out["resource_type_name"] = self.resource_type_name
+        out["service_name"] = self.service_name
+        out["verb_name"] = self.verb_name
+        out["versions"] = [item0.to_json() for item0 in self.versions]
+        if self.default_ttl_s is not None:
+            out["default_ttl_s"] = self.default_ttl_s
+        if self.payload_schema is not None:
+            out["payload_schema"] = self.payload_schema
+        return out
+
+
+@dataclass(frozen=True)
+class EventTypePost:
+    """The `EventTypePost` the API declares."""
+
+    application_id: uuid.UUID
+    resource_type: str
+    service: str
+    verb: str
+    default_ttl_s: int | None = None
+    payload_schema: Any | None = None
+    payload_schema_mode: EventTypePostPayloadSchemaMode | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventTypePost:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventTypePost")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "resource_type", as_text),
+            read(fields, "service", as_text),
+            read(fields, "verb", as_text),
+            maybe(fields, "default_ttl_s", as_int),
+            maybe(fields, "payload_schema", as_json),
+            maybe(fields, "payload_schema_mode", as_enum(EventTypePostPayloadSchemaMode)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["resource_type"] = self.resource_type
+        out["service"] = self.service
+        out["verb"] = self.verb
+        if self.default_ttl_s is not None:
+            out["default_ttl_s"] = self.default_ttl_s
+        if self.payload_schema is not None:
+            out["payload_schema"] = self.payload_schema
+        if self.payload_schema_mode is not None:
+            out["payload_schema_mode"] = self.payload_schema_mode.value
+        return out
+
+
+@dataclass(frozen=True)
+class EventTypePut:
+    """The `EventTypePut` the API declares."""
+
+    application_id: uuid.UUID
+    default_ttl_s: int | None = None
+    payload_schema: Any | None = None
+    payload_schema_mode: EventTypePutPayloadSchemaMode | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventTypePut:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventTypePut")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            maybe(fields, "default_ttl_s", as_int),
+            maybe(fields, "payload_schema", as_json),
+            maybe(fields, "payload_schema_mode", as_enum(EventTypePutPayloadSchemaMode)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        if self.default_ttl_s is not None:
+            out["default_ttl_s"] = self.default_ttl_s
+        if self.payload_schema is not None:
+            out["payload_schema"] = self.payload_schema
+        if self.payload_schema_mode is not None:
+            out["payload_schema_mode"] = self.payload_schema_mode.value
+        return out
+
+
+@dataclass(frozen=True)
+class EventTypeVersion:
+    """The `EventTypeVersion` the API declares."""
+
+    created_at: datetime.datetime
+    version: int
+    deprecated_at: datetime.datetime | None = None
+    payload_schema: Any | None = None
+    sunset_at: datetime.datetime | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventTypeVersion:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventTypeVersion")
+        return cls(
+            read(fields, "created_at", as_datetime),
+            read(fields, "version", as_int),
+            maybe(fields, "deprecated_at", as_datetime),
+            maybe(fields, "payload_schema", as_json),
+            maybe(fields, "sunset_at", as_datetime),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["created_at"] = self.created_at.isoformat()
+        out["version"] = self.version
+        if self.deprecated_at is not None:
+            out["deprecated_at"] = self.deprecated_at.isoformat()
+        if self.payload_schema is not None:
+            out["payload_schema"] = self.payload_schema
+        if self.sunset_at is not None:
+            out["sunset_at"] = self.sunset_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class EventTypeVersionDeprecate:
+    """The `EventTypeVersionDeprecate` the API declares."""
+
+    application_id: uuid.UUID
+    version: int
+    sunset_at: datetime.datetime | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventTypeVersionDeprecate:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventTypeVersionDeprecate")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "version", as_int),
+            maybe(fields, "sunset_at", as_datetime),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["version"] = self.version
+        if self.sunset_at is not None:
+            out["sunset_at"] = self.sunset_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class EventTypeVersionPost:
+    """The `EventTypeVersionPost` the API declares."""
+
+    application_id: uuid.UUID
+    version: int
+    payload_schema: Any | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventTypeVersionPost:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventTypeVersionPost")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "version", as_int),
+            maybe(fields, "payload_schema", as_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["version"] = self.version
+        if self.payload_schema is not None:
+            out["payload_schema"] = self.payload_schema
+        return out
+
+
+@dataclass(frozen=True)
+class EventTypeVersions:
+    """The `EventTypeVersions` the API declares."""
+
+    created_at: datetime.datetime
+    version: int
+    deprecated_at: datetime.datetime | None = None
+    payload_schema: Any | None = None
+    sunset_at: datetime.datetime | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventTypeVersions:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventTypeVersions")
+        return cls(
+            read(fields, "created_at", as_datetime),
+            read(fields, "version", as_int),
+            maybe(fields, "deprecated_at", as_datetime),
+            maybe(fields, "payload_schema", as_json),
+            maybe(fields, "sunset_at", as_datetime),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["created_at"] = self.created_at.isoformat()
+        out["version"] = self.version
+        if self.deprecated_at is not None:
+            out["deprecated_at"] = self.deprecated_at.isoformat()
+        if self.payload_schema is not None:
+            out["payload_schema"] = self.payload_schema
+        if self.sunset_at is not None:
+            out["sunset_at"] = self.sunset_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class EventWithPayload:
+    """The `EventWithPayload` the API declares."""
+
+    event_id: uuid.UUID
+    event_type_name: str
+    ip: str
+    labels: Any
+    occurred_at: datetime.datetime
+    payload: str
+    payload_content_type: str
+    received_at: datetime.datetime
+    cancelled_at: datetime.datetime | None = None
+    deliver_at: datetime.datetime | None = None
+    event_type_version: int | None = None
+    expires_at: datetime.datetime | None = None
+    metadata: Any | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventWithPayload:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventWithPayload")
+        return cls(
+            read(fields, "event_id", as_uuid),
+            read(fields, "event_type_name", as_text),
+            read(fields, "ip", as_text),
+            read(fields, "labels", as_json),
+            read(fields, "occurred_at", as_datetime),
+            read(fields, "payload", as_text),
+            read(fields, "payload_content_type", as_text),
+            read(fields, "received_at", as_datetime),
+            maybe(fields, "cancelled_at", as_datetime),
+            maybe(fields, "deliver_at", as_datetime),
+            maybe(fields, "event_type_version", as_int),
+            maybe(fields, "expires_at", as_datetime),
+            maybe(fields, "metadata", as_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["event_id"] = str(self.event_id)
+        out["event_type_name"] = self.event_type_name
+        out["ip"] = self.ip
+        out["labels"] = self.labels
+        out["occurred_at"] = self.occurred_at.isoformat()
+        out["payload"] = self.payload
+        out["payload_content_type"] = self.payload_content_type
+        out["received_at"] = self.received_at.isoformat()
+        if self.cancelled_at is not None:
+            out["cancelled_at"] = self.cancelled_at.isoformat()
+        if self.deliver_at is not None:
+            out["deliver_at"] = self.deliver_at.isoformat()
+        if self.event_type_version is not None:
+            out["event_type_version"] = self.event_type_version
+        if self.expires_at is not None:
+            out["expires_at"] = self.expires_at.isoformat()
+        if self.metadata is not None:
+            out["metadata"] = self.metadata
+        return out
+
+
+@dataclass(frozen=True)
+class EventsBatchPost:
+    """The `EventsBatchPost` the API declares."""
+
+    application_id: uuid.UUID
+    events: list[EventsBatchPostEvents]
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventsBatchPost:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventsBatchPost")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "events", as_list(EventsBatchPostEvents.from_json)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["events"] = [item0.to_json() for item0 in self.events]
+        return out
+
+
+@dataclass(frozen=True)
+class EventsBatchPostEvents:
+    """The `EventsBatchPostEvents` the API declares."""
+
+    application_id: uuid.UUID
+    event_type: str
+    labels: dict[str, str]
+    occurred_at: datetime.datetime
+    payload: str
+    payload_content_type: str
+    deliver_at: datetime.datetime | None = None
+    event_id: uuid.UUID | None = None
+    event_type_version: int | None = None
+    expires_at: datetime.datetime | None = None
+    metadata: dict[str, str] | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventsBatchPostEvents:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventsBatchPostEvents")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "event_type", as_text),
+            read(fields, "labels", as_map(as_text)),
+            read(fields, "occurred_at", as_datetime),
+            read(fields, "payload", as_text),
+            read(fields, "payload_content_type", as_text),
+            maybe(fields, "deliver_at", as_datetime),
+            maybe(fields, "event_id", as_uuid),
+            maybe(fields, "event_type_version", as_int),
+            maybe(fields, "expires_at", as_datetime),
+            maybe(fields, "metadata", as_map(as_text)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["event_type"] = self.event_type
+        out["labels"] = dict(self.labels)
+        out["occurred_at"] = self.occurred_at.isoformat()
+        out["payload"] = self.payload
+        out["payload_content_type"] = self.payload_content_type
+        if self.deliver_at is not None:
+            out["deliver_at"] = self.deliver_at.isoformat()
+        if self.event_id is not None:
+            out["event_id"] = str(self.event_id)
+        if self.event_type_version is not None:
+            out["event_type_version"] = self.event_type_version
+        if self.expires_at is not None:
+            out["expires_at"] = self.expires_at.isoformat()
+        if self.metadata is not None:
+            out["metadata"] = dict(self.metadata)
+        return out
+
+
+@dataclass(frozen=True)
+class EventsPerDayEntry:
+    """The `EventsPerDayEntry` the API declares."""
+
+    amount: int
+    application_id: uuid.UUID
+    application_name: str
+    date: datetime.date
+    is_provisional: bool
+
+    @classmethod
+    def from_json(cls, value: Any) -> EventsPerDayEntry:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "EventsPerDayEntry")
+        return cls(
+            read(fields, "amount", as_int),
+            read(fields, "application_id", as_uuid),
+            read(fields, "application_name", as_text),
+            read(fields, "date", as_date),
+            read(fields, "is_provisional", as_bool),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["amount"] = self.amount
+        out["application_id"] = str(self.application_id)
+        out["application_name"] = self.application_name
+        out["date"] = self.date.isoformat()
+        out["is_provisional"] = self.is_provisional
+        return out
+
+
+@dataclass(frozen=True)
+class Inbox:
+    """The `Inbox` the API declares."""
+
+    messages: list[InboxMessages]
+    next_cursor: str | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> Inbox:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "Inbox")
+        return cls(
+            read(fields, "messages", as_list(InboxMessages.from_json)),
+            maybe(fields, "next_cursor", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["messages"] = [item0.to_json() for item0 in self.messages]
+        if self.next_cursor is not None:
+            out["next_cursor"] = self.next_cursor
+        return out
+
+
+@dataclass(frozen=True)
+class InboxAck:
+    """The `InboxAck` the API declares."""
+
+    application_id: uuid.UUID
+    receipts: list[uuid.UUID]
+
+    @classmethod
+    def from_json(cls, value: Any) -> InboxAck:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "InboxAck")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "receipts", as_list(as_uuid)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["receipts"] = [str(item0) for item0 in self.receipts]
+        return out
+
+
+@dataclass(frozen=True)
+class InboxMessages:
+    """The `InboxMessages` the API declares."""
+
+    event_id: uuid.UUID
+    event_type_name: str
+    labels: Any
+    leased_until: datetime.datetime
+    occurred_at: datetime.datetime
+    payload: str
+    payload_content_type: str
+    receipt: uuid.UUID
+    received_at: datetime.datetime
+    request_attempt_id: uuid.UUID
+    retry_count: int
+
+    @classmethod
+    def from_json(cls, value: Any) -> InboxMessages:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "InboxMessages")
+        return cls(
+            read(fields, "event_id", as_uuid),
+            read(fields, "event_type_name", as_text),
+            read(fields, "labels", as_json),
+            read(fields, "leased_until", as_datetime),
+            read(fields, "occurred_at", as_datetime),
+            read(fields, "payload", as_text),
+            read(fields, "payload_content_type", as_text),
+            read(fields, "receipt", as_uuid),
+            read(fields, "received_at", as_datetime),
+            read(fields, "request_attempt_id", as_uuid),
+            read(fields, "retry_count", as_int),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["event_id"] = str(self.event_id)
+        out["event_type_name"] = self.event_type_name
+        out["labels"] = self.labels
+        out["leased_until"] = self.leased_until.isoformat()
+        out["occurred_at"] = self.occurred_at.isoformat()
+        out["payload"] = self.payload
+        out["payload_content_type"] = self.payload_content_type
+        out["receipt"] = str(self.receipt)
+        out["received_at"] = self.received_at.isoformat()
+        out["request_attempt_id"] = str(self.request_attempt_id)
+        out["retry_count"] = self.retry_count
+        return out
+
+
+@dataclass(frozen=True)
+class InboxNack:
+    """The `InboxNack` the API declares."""
+
+    application_id: uuid.UUID
+    receipts: list[uuid.UUID]
+    retry: bool | None = None
+    retry_in_s: int | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> InboxNack:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "InboxNack")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "receipts", as_list(as_uuid)),
+            maybe(fields, "retry", as_bool),
+            maybe(fields, "retry_in_s", as_int),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["receipts"] = [str(item0) for item0 in self.receipts]
+        if self.retry is not None:
+            out["retry"] = self.retry
+        if self.retry_in_s is not None:
+            out["retry_in_s"] = self.retry_in_s
+        return out
+
+
+@dataclass(frozen=True)
+class InboxSettlement:
+    """The `InboxSettlement` the API declares."""
+
+    settled: list[uuid.UUID]
+    stale: list[uuid.UUID]
+
+    @classmethod
+    def from_json(cls, value: Any) -> InboxSettlement:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "InboxSettlement")
+        return cls(
+            read(fields, "settled", as_list(as_uuid)),
+            read(fields, "stale", as_list(as_uuid)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["settled"] = [str(item0) for item0 in self.settled]
+        out["stale"] = [str(item0) for item0 in self.stale]
+        return out
+
+
+@dataclass(frozen=True)
+class IngestedEvent:
+    """The `IngestedEvent` the API declares."""
+
+    application_id: uuid.UUID
+    event_id: uuid.UUID
+    received_at: datetime.datetime
+    deprecation: IngestedEventDeprecation | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> IngestedEvent:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "IngestedEvent")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "event_id", as_uuid),
+            read(fields, "received_at", as_datetime),
+            maybe(fields, "deprecation", IngestedEventDeprecation.from_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["event_id"] = str(self.event_id)
+        out["received_at"] = self.received_at.isoformat()
+        if self.deprecation is not None:
+            out["deprecation"] = self.deprecation.to_json()
+        return out
+
+
+@dataclass(frozen=True)
+class IngestedEventDeprecation:
+    """The `IngestedEventDeprecation` the API declares."""
+
+    deprecated_at: datetime.datetime
+    event_type_version: int
+    sunset_at: datetime.datetime | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> IngestedEventDeprecation:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "IngestedEventDeprecation")
+        return cls(
+            read(fields, "deprecated_at", as_datetime),
+            read(fields, "event_type_version", as_int),
+            maybe(fields, "sunset_at", as_datetime),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["deprecated_at"] = self.deprecated_at.isoformat()
+        out["event_type_version"] = self.event_type_version
+        if self.sunset_at is not None:
+            out["sunset_at"] = self.sunset_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class IngestedEventsBatch:
+    """The `IngestedEventsBatch` the API declares."""
+
+    application_id: uuid.UUID
+    events: list[IngestedEventsBatchEvents]
+
+    @classmethod
+    def from_json(cls, value: Any) -> IngestedEventsBatch:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "IngestedEventsBatch")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "events", as_list(IngestedEventsBatchEvents.from_json)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["events"] = [item0.to_json() for item0 in self.events]
+        return out
+
+
+@dataclass(frozen=True)
+class IngestedEventsBatchEvents:
+    """The `IngestedEventsBatchEvents` the API declares."""
+
+    status: IngestedEventsBatchEventsStatus
+    deprecation: IngestedEventsBatchEventsDeprecation | None = None
+    event_id: uuid.UUID | None = None
+    problem: IngestedEventsBatchEventsProblem | None = None
+    received_at: datetime.datetime | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> IngestedEventsBatchEvents:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "IngestedEventsBatchEvents")
+        return cls(
+            read(fields, "status", as_enum(IngestedEventsBatchEventsStatus)),
+            maybe(fields, "deprecation", IngestedEventsBatchEventsDeprecation.from_json),
+            maybe(fields, "event_id", as_uuid),
+            maybe(fields, "problem", IngestedEventsBatchEventsProblem.from_json),
+            maybe(fields, "received_at", as_datetime),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["status"] = self.status.value
+        if self.deprecation is not None:
+            out["deprecation"] = self.deprecation.to_json()
+        if self.event_id is not None:
+            out["event_id"] = str(self.event_id)
+        if self.problem is not None:
+            out["problem"] = self.problem.to_json()
+        if self.received_at is not None:
+            out["received_at"] = self.received_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class IngestedEventsBatchEventsDeprecation:
+    """The `IngestedEventsBatchEventsDeprecation` the API declares."""
+
+    deprecated_at: datetime.datetime
+    event_type_version: int
+    sunset_at: datetime.datetime | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> IngestedEventsBatchEventsDeprecation:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "IngestedEventsBatchEventsDeprecation")
+        return cls(
+            read(fields, "deprecated_at", as_datetime),
+            read(fields, "event_type_version", as_int),
+            maybe(fields, "sunset_at", as_datetime),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["deprecated_at"] = self.deprecated_at.isoformat()
+        out["event_type_version"] = self.event_type_version
+        if self.sunset_at is not None:
+            out["sunset_at"] = self.sunset_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class IngestedEventsBatchEventsProblem:
+    """The `IngestedEventsBatchEventsProblem` the API declares."""
+
+    detail: str
+    id_: IngestedEventsBatchEventsProblemId
+    status: int
+    title: str
+    type_: str
+    validation: Any | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> IngestedEventsBatchEventsProblem:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "IngestedEventsBatchEventsProblem")
+        return cls(
+            read(fields, "detail", as_text),
+            read(fields, "id", as_enum(IngestedEventsBatchEventsProblemId)),
+            read(fields, "status", as_int),
+            read(fields, "title", as_text),
+            read(fields, "type", as_text),
+            maybe(fields, "validation", as_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["detail"] = self.detail
+        out["id"] = self.id_.value
+        out["status"] = self.status
+        out["title"] = self.title
+        out["type"] = self.type_
+        if self.validation is not None:
+            out["validation"] = self.validation
+        return out
+
+
+@dataclass(frozen=True)
+class InstanceConfig:
+    """The `InstanceConfig` the API declares."""
+
+    application_secret_compatibility: bool
+    auto_db_migration: bool
+    biscuit_public_key: str
+    password_minimum_length: int
+    quota_enforcement: bool
+    registration_disabled: bool
+    support_email_address: str
+    cloudflare_turnstile_site_key: str | None = None
+    formbricks: InstanceConfigFormbricks | None = None
+    matomo: InstanceConfigMatomo | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> InstanceConfig:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "InstanceConfig")
+        return cls(
+            read(fields, "application_secret_compatibility", as_bool),
+            read(fields, "auto_db_migration", as_bool),
+            read(fields, "biscuit_public_key", as_text),
+            read(fields, "password_minimum_length", as_int),
+            read(fields, "quota_enforcement", as_bool),
+            read(fields, "registration_disabled", as_bool),
+            read(fields, "support_email_address", as_text),
+            maybe(fields, "cloudflare_turnstile_site_key", as_text),
+            maybe(fields, "formbricks", InstanceConfigFormbricks.from_json),
+            maybe(fields, "matomo", InstanceConfigMatomo.from_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_secret_compatibility"] = self.application_secret_compatibility
+        out["auto_db_migration"] = self.auto_db_migration
+        out["biscuit_public_key"] = self.biscuit_public_key
+        out["password_minimum_length"] = self.password_minimum_length
+        out["quota_enforcement"] = self.quota_enforcement
+        out["registration_disabled"] = self.registration_disabled
+        out["support_email_address"] = self.support_email_address
+        if self.cloudflare_turnstile_site_key is not None:
+            out["cloudflare_turnstile_site_key"] = self.cloudflare_turnstile_site_key
+        if self.formbricks is not None:
+            out["formbricks"] = self.formbricks.to_json()
+        if self.matomo is not None:
+            out["matomo"] = self.matomo.to_json()
+        return out
+
+
+@dataclass(frozen=True)
+class InstanceConfigFormbricks:
+    """The `InstanceConfigFormbricks` the API declares."""
+
+    api_host: str
+    environment_id: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> InstanceConfigFormbricks:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "InstanceConfigFormbricks")
+        return cls(
+            read(fields, "api_host", as_text),
+            read(fields, "environment_id", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["api_host"] = self.api_host
+        out["environment_id"] = self.environment_id
+        return out
+
+
+@dataclass(frozen=True)
+class InstanceConfigMatomo:
+    """The `InstanceConfigMatomo` the API declares."""
+
+    site_id: int
+    url: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> InstanceConfigMatomo:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "InstanceConfigMatomo")
+        return cls(
+            read(fields, "site_id", as_int),
+            read(fields, "url", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["site_id"] = self.site_id
+        out["url"] = self.url
+        return out
+
+
+@dataclass(frozen=True)
+class JsonWebKeySet:
+    """The `JsonWebKeySet` the API declares."""
+
+    keys: list[JsonWebKeySetKeys]
+
+    @classmethod
+    def from_json(cls, value: Any) -> JsonWebKeySet:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "JsonWebKeySet")
+        return cls(
+            read(fields, "keys", as_list(JsonWebKeySetKeys.from_json)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["keys"] = [item0.to_json() for item0 in self.keys]
+        return out
+
+
+@dataclass(frozen=True)
+class JsonWebKeySetKeys:
+    """The `JsonWebKeySetKeys` the API declares."""
+
+    alg: str
+    crv: str
+    kid: str
+    kty: str
+    use: str
+    x: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> JsonWebKeySetKeys:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "JsonWebKeySetKeys")
+        return cls(
+            read(fields, "alg", as_text),
+            read(fields, "crv", as_text),
+            read(fields, "kid", as_text),
+            read(fields, "kty", as_text),
+            read(fields, "use", as_text),
+            read(fields, "x", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["alg"] = self.alg
+        out["crv"] = self.crv
+        out["kid"] = self.kid
+        out["kty"] = self.kty
+        out["use"] = self.use
+        out["x"] = self.x
+        return out
+
+
+@dataclass(frozen=True)
+class Oauth2ClientCredentials:
+    """The `OAuth2ClientCredentials` the API declares."""
+
+    client_id: str
+    created_at: datetime.datetime
+    scopes: list[str]
+    subscription_id: uuid.UUID
+    token_url: str
+    updated_at: datetime.datetime
+
+    @classmethod
+    def from_json(cls, value: Any) -> Oauth2ClientCredentials:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OAuth2ClientCredentials")
+        return cls(
+            read(fields, "client_id", as_text),
+            read(fields, "created_at", as_datetime),
+            read(fields, "scopes", as_list(as_text)),
+            read(fields, "subscription_id", as_uuid),
+            read(fields, "token_url", as_text),
+            read(fields, "updated_at", as_datetime),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["client_id"] = self.client_id
+        out["created_at"] = self.created_at.isoformat()
+        out["scopes"] = list(self.scopes)
+        out["subscription_id"] = str(self.subscription_id)
+        out["token_url"] = self.token_url
+        out["updated_at"] = self.updated_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class Oauth2ClientCredentialsPut:
+    """The `OAuth2ClientCredentialsPut` the API declares."""
+
+    client_id: str
+    client_secret: str
+    token_url: str
+    scopes: list[str] | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> Oauth2ClientCredentialsPut:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OAuth2ClientCredentialsPut")
+        return cls(
+            read(fields, "client_id", as_text),
+            read(fields, "client_secret", as_text),
+            read(fields, "token_url", as_text),
+            maybe(fields, "scopes", as_list(as_text)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["client_id"] = self.client_id
+        out["client_secret"] = self.client_secret
+        out["token_url"] = self.token_url
+        if self.scopes is not None:
+            out["scopes"] = list(self.scopes)
+        return out
+
+
+@dataclass(frozen=True)
+class Organization:
+    """The `Organization` the API declares."""
+
+    name: str
+    organization_id: uuid.UUID
+    role: str
+    plan: OrganizationPlan | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> Organization:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "Organization")
+        return cls(
+            read(fields, "name", as_text),
+            read(fields, "organization_id", as_uuid),
+            read(fields, "role", as_text),
+            maybe(fields, "plan", OrganizationPlan.from_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["name"] = self.name
+        out["organization_id"] = str(self.organization_id)
+        out["role"] = self.role
+        if self.plan is not None:
+            out["plan"] = self.plan.to_json()
+        return out
+
+
+@dataclass(frozen=True)
+class OrganizationInfo:
+    """The `OrganizationInfo` the API declares."""
+
+    consumption: OrganizationInfoConsumption
+    name: str
+    onboarding_steps: OrganizationInfoOnboardingSteps
+    organization_id: uuid.UUID
+    quotas: OrganizationInfoQuotas
+    users: list[OrganizationInfoUsers]
+    plan: OrganizationInfoPlan | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> OrganizationInfo:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OrganizationInfo")
+        return cls(
+            read(fields, "consumption", OrganizationInfoConsumption.from_json),
+            read(fields, "name", as_text),
+            read(fields, "onboarding_steps", OrganizationInfoOnboardingSteps.from_json),
+            read(fields, "organization_id", as_uuid),
+            read(fields, "quotas", OrganizationInfoQuotas.from_json),
+            read(fields, "users", as_list(OrganizationInfoUsers.from_json)),
+            maybe(fields, "plan", OrganizationInfoPlan.from_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["consumption"] = self.consumption.to_json()
+        out["name"] = self.name
+        out["onboarding_steps"] = self.onboarding_steps.to_json()
+        out["organization_id"] = str(self.organization_id)
+        out["quotas"] = self.quotas.to_json()
+        out["users"] = [item0.to_json() for item0 in self.users]
+        if self.plan is not None:
+            out["plan"] = self.plan.to_json()
+        return out
+
+
+@dataclass(frozen=True)
+class OrganizationInfoConsumption:
+    """The `OrganizationInfoConsumption` the API declares."""
+
+    applications: int | None = None
+    events_per_day: int | None = None
+    members: int | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> OrganizationInfoConsumption:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OrganizationInfoConsumption")
+        return cls(
+            maybe(fields, "applications", as_int),
+            maybe(fields, "events_per_day", as_int),
+            maybe(fields, "members", as_int),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        if self.applications is not None:
+            out["applications"] = self.applications
+        if self.events_per_day is not None:
+            out["events_per_day"] = self.events_per_day
+        if self.members is not None:
+            out["members"] = self.members
+        return out
+
+
+@dataclass(frozen=True)
+class OrganizationInfoOnboardingSteps:
+    """The `OrganizationInfoOnboardingSteps` the API declares."""
+
+    application: OrganizationInfoOnboardingStepsApplication
+    event: OrganizationInfoOnboardingStepsEvent
+    event_type: OrganizationInfoOnboardingStepsEventType
+    subscription: OrganizationInfoOnboardingStepsSubscription
+
+    @classmethod
+    def from_json(cls, value: Any) -> OrganizationInfoOnboardingSteps:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OrganizationInfoOnboardingSteps")
+        return cls(
+            read(fields, "application", as_enum(OrganizationInfoOnboardingStepsApplication)),
+            read(fields, "event", as_enum(OrganizationInfoOnboardingStepsEvent)),
+            read(fields, "event_type", as_enum(OrganizationInfoOnboardingStepsEventType)),
+            read(fields, "subscription", as_enum(OrganizationInfoOnboardingStepsSubscription)),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application"] = self.application.value
+        out["event"] = self.event.value
+        out["event_type"] = self.event_type.value
+        out["subscription"] = self.subscription.value
+        return out
+
+
+@dataclass(frozen=True)
+class OrganizationInfoPlan:
+    """The `OrganizationInfoPlan` the API declares."""
+
+    label: str
+    name: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> OrganizationInfoPlan:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OrganizationInfoPlan")
+        return cls(
+            read(fields, "label", as_text),
+            read(fields, "name", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["label"] = self.label
+        out["name"] = self.name
+        return out
+
+
+@dataclass(frozen=True)
+class OrganizationInfoQuotas:
+    """The `OrganizationInfoQuotas` the API declares."""
+
+    applications_per_organization_limit: int
+    days_of_events_retention_limit: int
+    events_per_day_limit: int
+    members_per_organization_limit: int
+
+    @classmethod
+    def from_json(cls, value: Any) -> OrganizationInfoQuotas:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OrganizationInfoQuotas")
+        return cls(
+            read(fields, "applications_per_organization_limit", as_int),
+            read(fields, "days_of_events_retention_limit", as_int),
+            read(fields, "events_per_day_limit", as_int),
+            read(fields, "members_per_organization_limit", as_int),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["applications_per_organization_limit"] = self.applications_per_organization_limit
+        out["days_of_events_retention_limit"] = self.days_of_events_retention_limit
+        out["events_per_day_limit"] = self.events_per_day_limit
+        out["members_per_organization_limit"] = self.members_per_organization_limit
+        return out
+
+
+@dataclass(frozen=True)
+class OrganizationInfoUsers:
+    """The `OrganizationInfoUsers` the API declares."""
+
+    email: str
+    first_name: str
+    last_name: str
+    role: str
+    user_id: uuid.UUID
+
+    @classmethod
+    def from_json(cls, value: Any) -> OrganizationInfoUsers:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OrganizationInfoUsers")
+        return cls(
+            read(fields, "email", as_text),
+            read(fields, "first_name", as_text),
+            read(fields, "last_name", as_text),
+            read(fields, "role", as_text),
+            read(fields, "user_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["email"] = self.email
+        out["first_name"] = self.first_name
+        out["last_name"] = self.last_name
+        out["role"] = self.role
+        out["user_id"] = str(self.user_id)
+        return out
+
+
+@dataclass(frozen=True)
+class OrganizationPlan:
+    """The `OrganizationPlan` the API declares."""
+
+    label: str
+    name: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> OrganizationPlan:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "OrganizationPlan")
+        return cls(
+            read(fields, "label", as_text),
+            read(fields, "name", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["label"] = self.label
+        out["name"] = self.name
+        return out
+
+
+@dataclass(frozen=True)
+class Problem:
+    """The `Problem` the API declares."""
+
+    detail: str
+    id_: ProblemId
+    status: int
+    title: str
+    type_: str
+    validation: Any | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> Problem:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "Problem")
+        return cls(
+            read(fields, "detail", as_text),
+            read(fields, "id", as_enum(ProblemId)),
+            read(fields, "status", as_int),
+            read(fields, "title", as_text),
+            read(fields, "type", as_text),
+            maybe(fields, "validation", as_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["detail"] = self.detail
+        out["id"] = self.id_.value
+        out["status"] = self.status
+        out["title"] = self.title
+        out["type"] = self.type_
+        if self.validation is not None:
+            out["validation"] = self.validation
+        return out
+
+
+@dataclass(frozen=True)
+class PurgeDeadLetters:
+    """The `PurgeDeadLetters` the API declares."""
+
+    application_id: uuid.UUID
+    subscription_id: uuid.UUID | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> PurgeDeadLetters:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "PurgeDeadLetters")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            maybe(fields, "subscription_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        if self.subscription_id is not None:
+            out["subscription_id"] = str(self.subscription_id)
+        return out
+
+
+@dataclass(frozen=True)
+class PurgedDeadLetters:
+    """The `PurgedDeadLetters` the API declares."""
+
+    purged_count: int
+
+    @classmethod
+    def from_json(cls, value: Any) -> PurgedDeadLetters:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "PurgedDeadLetters")
+        return cls(
+            read(fields, "purged_count", as_int),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["purged_count"] = self.purged_count
+        return out
+
+
+@dataclass(frozen=True)
+class QuotasResponse:
+    """The `QuotasResponse` the API declares."""
+
+    enabled: bool
+    limits: QuotasResponseLimits
+
+    @classmethod
+    def from_json(cls, value: Any) -> QuotasResponse:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "QuotasResponse")
+        return cls(
+            read(fields, "enabled", as_bool),
+            read(fields, "limits", QuotasResponseLimits.from_json),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["enabled"] = self.enabled
+        out["limits"] = self.limits.to_json()
+        return out
+
+
+@dataclass(frozen=True)
+class QuotasResponseLimits:
+    """The `QuotasResponseLimits` the API declares."""
+
+    global_applications_per_organization_limit: int
+    global_days_of_events_retention_limit: int
+    global_event_types_per_application_limit: int
+    global_events_per_day_limit: int
+    global_members_per_organization_limit: int
+    global_subscriptions_per_application_limit: int
+
+    @classmethod
+    def from_json(cls, value: Any) -> QuotasResponseLimits:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "QuotasResponseLimits")
+        return cls(
+            read(fields, "global_applications_per_organization_limit", as_int),
+            read(fields, "global_days_of_events_retention_limit", as_int),
+            read(fields, "global_event_types_per_application_limit", as_int),
+            read(fields, "global_events_per_day_limit", as_int),
+            read(fields, "global_members_per_organization_limit", as_int),
+            read(fields, "global_subscriptions_per_application_limit", as_int),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["global_applications_per_organization_limit"] = self.global_applications_per_organization_limit
+        out["global_days_of_events_retention_limit"] = self.global_days_of_events_retention_limit
+        out["global_event_types_per_application_limit"] = self.global_event_types_per_application_limit
+        out["global_events_per_day_limit"] = self.global_events_per_day_limit
+        out["global_members_per_organization_limit"] = self.global_members_per_organization_limit
+        out["global_subscriptions_per_application_limit"] = self.global_subscriptions_per_application_limit
+        return out
+
+
+@dataclass(frozen=True)
+class RedeliverDeadLetter:
+    """The `RedeliverDeadLetter` the API declares."""
+
+    application_id: uuid.UUID
+
+    @classmethod
+    def from_json(cls, value: Any) -> RedeliverDeadLetter:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "RedeliverDeadLetter")
+        return cls(
+            read(fields, "application_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        return out
+
+
+@dataclass(frozen=True)
+class RedeliverDeadLetters:
+    """The `RedeliverDeadLetters` the API declares."""
+
+    application_id: uuid.UUID
+    subscription_id: uuid.UUID | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> RedeliverDeadLetters:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "RedeliverDeadLetters")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            maybe(fields, "subscription_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        if self.subscription_id is not None:
+            out["subscription_id"] = str(self.subscription_id)
+        return out
+
+
+@dataclass(frozen=True)
+class RedeliveredDeadLetter:
+    """The `RedeliveredDeadLetter` the API declares."""
+
+    dead_letter_id: uuid.UUID
+    request_attempt_id: uuid.UUID
+
+    @classmethod
+    def from_json(cls, value: Any) -> RedeliveredDeadLetter:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "RedeliveredDeadLetter")
+        return cls(
+            read(fields, "dead_letter_id", as_uuid),
+            read(fields, "request_attempt_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["dead_letter_id"] = str(self.dead_letter_id)
+        out["request_attempt_id"] = str(self.request_attempt_id)
+        return out
+
+
+@dataclass(frozen=True)
+class ReplayEvent:
+    """The `ReplayEvent` the API declares."""
+
+    application_id: uuid.UUID
+
+    @classmethod
+    def from_json(cls, value: Any) -> ReplayEvent:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "ReplayEvent")
+        return cls(
+            read(fields, "application_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        return out
+
+
+@dataclass(frozen=True)
+class RequestAttempt:
+    """The `RequestAttempt` the API declares."""
+
+    created_at: datetime.datetime
+    event: RequestAttemptEvent
+    event_id: uuid.UUID
+    request_attempt_id: uuid.UUID
+    retry_count: int
+    status: RequestAttemptStatus
+    subscription: RequestAttemptSubscription
+    dead_letter_id: uuid.UUID | None = None
+    delay_until: datetime.datetime | None = None
+    delivery_batch_id: uuid.UUID | None = None
+    failed_at: datetime.datetime | None = None
+    http_response_status: int | None = None
+    picked_at: datetime.datetime | None = None
+    response_id: uuid.UUID | None = None
+    succeeded_at: datetime.datetime | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> RequestAttempt:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "RequestAttempt")
+        return cls(
+            read(fields, "created_at", as_datetime),
+            read(fields, "event", RequestAttemptEvent.from_json),
+            read(fields, "event_id", as_uuid),
+            read(fields, "request_attempt_id", as_uuid),
+            read(fields, "retry_count", as_int),
+            read(fields, "status", RequestAttemptStatus.from_json),
+            read(fields, "subscription", RequestAttemptSubscription.from_json),
+            maybe(fields, "dead_letter_id", as_uuid),
+            maybe(fields, "delay_until", as_datetime),
+            maybe(fields, "delivery_batch_id", as_uuid),
+            maybe(fields, "failed_at", as_datetime),
+            maybe(fields, "http_response_status", as_int),
+            maybe(fields, "picked_at", as_datetime),
+            maybe(fields, "response_id", as_uuid),
+            maybe(fields, "succeeded_at", as_datetime),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["created_at"] = self.created_at.isoformat()
+        out["event"] = self.event.to_json()
+        out["event_id"] = str(self.event_id)
+        out["request_attempt_id"] = str(self.request_attempt_id)
+        out["retry_count"] = self.retry_count
+        out["status"] = self.status.to_json()
+        out["subscription"] = self.subscription.to_json()
+        if self.dead_letter_id is not None:
+            out["dead_letter_id"] = str(self.dead_letter_id)
+        if self.delay_until is not None:
+            out["delay_until"] = self.delay_until.isoformat()
+        if self.delivery_batch_id is not None:
+            out["delivery_batch_id"] = str(self.delivery_batch_id)
+        if self.failed_at is not None:
+            out["failed_at"] = self.failed_at.isoformat()
+        if self.http_response_status is not None:
+            out["http_response_status"] = self.http_response_status
+        if self.picked_at is not None:
+            out["picked_at"] = self.picked_at.isoformat()
+        if self.response_id is not None:
+            out["response_id"] = str(self.response_id)
+        if self.succeeded_at is not None:
+            out["succeeded_at"] = self.succeeded_at.isoformat()
+        return out
+
+
+@dataclass(frozen=True)
+class RequestAttemptEvent:
+    """The `RequestAttemptEvent` the API declares."""
+
+    event_id: uuid.UUID
+    event_type_name: str
+
+    @classmethod
+    def from_json(cls, value: Any) -> RequestAttemptEvent:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "RequestAttemptEvent")
+        return cls(
+            read(fields, "event_id", as_uuid),
+            read(fields, "event_type_name", as_text),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["event_id"] = str(self.event_id)
+        out["event_type_name"] = self.event_type_name
+        return out
+
+
+@dataclass(frozen=True)
+class RequestAttemptStatus:
+    """The `RequestAttemptStatus` the API declares."""
 
-    amount: int
-    application_id: uuid.UUID
-    application_name: str
-    date: datetime.date
-    is_provisional: bool
+    type_: RequestAttemptStatusType
+    at: datetime.datetime | None = None
+    full_processing_ms: int | None = None
+    rule_outcome: RequestAttemptStatusRuleOutcome | None = None
+    since: datetime.datetime | None = None
+    until: datetime.datetime | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> EventsPerDayEntry:
+    def from_json(cls, value: Any) -> RequestAttemptStatus:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "EventsPerDayEntry")
+        fields = as_fields(value, "RequestAttemptStatus")
         return cls(
-            read(fields, "amount", as_int),
-            read(fields, "application_id", as_uuid),
-            read(fields, "application_name", as_text),
-            read(fields, "date", as_date),
-            read(fields, "is_provisional", as_bool),
+            read(fields, "type", as_enum(RequestAttemptStatusType)),
+            maybe(fields, "at", as_datetime),
+            maybe(fields, "full_processing_ms", as_int),
+            maybe(fields, "rule_outcome", as_enum(RequestAttemptStatusRuleOutcome)),
+            maybe(fields, "since", as_datetime),
+            maybe(fields, "until", as_datetime),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["amount"] = self.amount
-        out["application_id"] = str(self.application_id)
-        out["application_name"] = self.application_name
-        out["date"] = self.date.isoformat()
-        out["is_provisional"] = self.is_provisional
+        out["type"] = self.type_.value
+        if self.at is not None:
+            out["at"] = self.at.isoformat()
+        if self.full_processing_ms is not None:
+            out["full_processing_ms"] = self.full_processing_ms
+        if self.rule_outcome is not None:
+            out["rule_outcome"] = self.rule_outcome.value
+        if self.since is not None:
+            out["since"] = self.since.isoformat()
+        if self.until is not None:
+            out["until"] = self.until.isoformat()
         return out
 
 
 @dataclass(frozen=True)
-class IngestedEvent:
-    """The `IngestedEvent` the API declares."""
+class RequestAttemptSubscription:
+    """The `RequestAttemptSubscription` the API declares."""
 
-    application_id: uuid.UUID
-    event_id: uuid.UUID
-    received_at: datetime.datetime
+    subscription_id: uuid.UUID
+    description: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> IngestedEvent:
+    def from_json(cls, value: Any) -> RequestAttemptSubscription:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "IngestedEvent")
+        fields = as_fields(value, "RequestAttemptSubscription")
         return cls(
-            read(fields, "application_id", as_uuid),
-            read(fields, "event_id", as_uuid),
-            read(fields, "received_at", as_datetime),
+            read(fields, "subscription_id", as_uuid),
+            maybe(fields, "description", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["application_id"] = str(self.application_id)
-        out["event_id"] = str(self.event_id)
-        out["received_at"] = self.received_at.isoformat()
+        out["subscription_id"] = str(self.subscription_id)
+        if self.description is not None:
+            out["description"] = self.description
         return out
 
 
 @dataclass(frozen=True)
-class InstanceConfig:
-    """The `InstanceConfig` the API declares."""
+class Response:
+    """The `Response` the API declares."""
 
-    application_secret_compatibility: bool
-    auto_db_migration: bool
-    biscuit_public_key: str
-    password_minimum_length: int
-    quota_enforcement: bool
-    registration_disabled: bool
-    support_email_address: str
-    cloudflare_turnstile_site_key: str | None = None
-    formbricks: InstanceConfigFormbricks | None = None
-    matomo: InstanceConfigMatomo | None = None
+    response_id: uuid.UUID
+    body: str | None = None
+    elapsed_time_ms: int | None = None
+    headers: dict[str, str] | None = None
+    http_code: int | None = None
+    response_error_name: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> InstanceConfig:
+    def from_json(cls, value: Any) -> Response:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "InstanceConfig")
+        fields = as_fields(value, "Response")
         return cls(
-            read(fields, "application_secret_compatibility", as_bool),
-            read(fields, "auto_db_migration", as_bool),
-            read(fields, "biscuit_public_key", as_text),
-            read(fields, "password_minimum_length", as_int),
-            read(fields, "quota_enforcement", as_bool),
-            read(fields, "registration_disabled", as_bool),
-            read(fields, "support_email_address", as_text),
-            maybe(fields, "cloudflare_turnstile_site_key", as_text),
-            maybe(fields, "formbricks", InstanceConfigFormbricks.from_json),
-            maybe(fields, "matomo", InstanceConfigMatomo.from_json),
+            read(fields, "response_id", as_uuid),
+            maybe(fields, "body", as_text),
+            maybe(fields, "elapsed_time_ms", as_int),
+            maybe(fields, "headers", as_map(as_text)),
+            maybe(fields, "http_code", as_int),
+            maybe(fields, "response_error_name", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["application_secret_compatibility"] = self.application_secret_compatibility
-        out["auto_db_migration"] = self.auto_db_migration
-        out["biscuit_public_key"] = self.biscuit_public_key
-        out["password_minimum_length"] = self.password_minimum_length
-        out["quota_enforcement"] = self.quota_enforcement
-        out["registration_disabled"] = self.registration_disabled
-        out["support_email_address"] = self.support_email_address
-        if self.cloudflare_turnstile_site_key is not None:
-            out["cloudflare_turnstile_site_key"] = self.cloudflare_turnstile_site_key
-        if self.formbricks is not None:
-            out["formbricks"] = self.formbricks.to_json()
-        if self.matomo is not None:
-            out["matomo"] = self.matomo.to_json()
+        out["response_id"] = str(self.response_id)
+        if self.body is not None:
+            out["body"] = self.body
+        if self.elapsed_time_ms is not None:
+            out["elapsed_time_ms"] = self.elapsed_time_ms
+        if self.headers is not None:
+            out["headers"] = dict(self.headers)
+        if self.http_code is not None:
+            out["http_code"] = self.http_code
+        if self.response_error_name is not None:
+            out["response_error_name"] = self.response_error_name
         return out
 
 
 @dataclass(frozen=True)
-class InstanceConfigFormbricks:
-    """The `InstanceConfigFormbricks` the API declares."""
+class RetryRequestAttempt:
+    """The `RetryRequestAttempt` the API declares."""
 
-    api_host: str
-    environment_id: str
+    application_id: uuid.UUID
 
     @classmethod
-    def from_json(cls, value: Any) -> InstanceConfigFormbricks:
+    def from_json(cls, value: Any) -> RetryRequestAttempt:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "InstanceConfigFormbricks")
+        fields = as_fields(value, "RetryRequestAttempt")
         return cls(
-            read(fields, "api_host", as_text),
-            read(fields, "environment_id", as_text),
+            read(fields, "application_id", as_uuid),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["api_host"] = self.api_host
-        out["environment_id"] = self.environment_id
+        out["application_id"] = str(self.application_id)
         return out
 
 
 @dataclass(frozen=True)
-class InstanceConfigMatomo:
-    """The `InstanceConfigMatomo` the API declares."""
+class SecretRotation:
+    """The `SecretRotation` the API declares."""
 
-    site_id: int
-    url: str
+    previous_secret: uuid.UUID
+    previous_secret_expires_at: datetime.datetime
+    secret: uuid.UUID
+    subscription_id: uuid.UUID
 
     @classmethod
-    def from_json(cls, value: Any) -> InstanceConfigMatomo:
+    def from_json(cls, value: Any) -> SecretRotation:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "InstanceConfigMatomo")
+        fields = as_fields(value, "SecretRotation")
         return cls(
-            read(fields, "site_id", as_int),
-            read(fields, "url", as_text),
+            read(fields, "previous_secret", as_uuid),
+            read(fields, "previous_secret_expires_at", as_datetime),
+            read(fields, "secret", as_uuid),
+            read(fields, "subscription_id", as_uuid),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["site_id"] = self.site_id
-        out["url"] = self.url
+        out["previous_secret"] = str(self.previous_secret)
+        out["previous_secret_expires_at"] = self.previous_secret_expires_at.isoformat()
+        out["secret"] = str(self.secret)
+        out["subscription_id"] = str(self.subscription_id)
         return out
 
 
 @dataclass(frozen=True)
-class Organization:
-    """The `Organization` the API declares."""
+class SecretRotationPost:
+    """The `SecretRotationPost` the API declares."""
+
+    overlap_seconds: int | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> SecretRotationPost:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "SecretRotationPost")
+        return cls(
+            maybe(fields, "overlap_seconds", as_int),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        if self.overlap_seconds is not None:
+            out["overlap_seconds"] = self.overlap_seconds
+        return out
+
+
+@dataclass(frozen=True)
+class ServiceToken:
+    """The `ServiceToken` the API declares."""
 
+    biscuit: str
+    created_at: datetime.datetime
     name: str
-    organization_id: uuid.UUID
-    role: str
-    plan: OrganizationPlan | None = None
+    token_id: uuid.UUID
 
     @classmethod
-    def from_json(cls, value: Any) -> Organization:
+    def from_json(cls, value: Any) -> ServiceToken:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "Organization")
+        fields = as_fields(value, "ServiceToken")
         return cls(
+            read(fields, "biscuit", as_text),
+            read(fields, "created_at", as_datetime),
             read(fields, "name", as_text),
-            read(fields, "organization_id", as_uuid),
-            read(fields, "role", as_text),
-            maybe(fields, "plan", OrganizationPlan.from_json),
+            read(fields, "token_id", as_uuid),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
+        out["biscuit"] = self.biscuit
+        out["created_at"] = self.created_at.isoformat()
         out["name"] = self.name
-        out["organization_id"] = str(self.organization_id)
-        out["role"] = self.role
-        if self.plan is not None:
-            out["plan"] = self.plan.to_json()
+        out["token_id"] = str(self.token_id)
         return out
 
 
 @dataclass(frozen=True)
-class OrganizationInfo:
-    """The `OrganizationInfo` the API declares."""
+class ServiceTokenPost:
+    """The `ServiceTokenPost` the API declares."""
 
-    consumption: OrganizationInfoConsumption
     name: str
-    onboarding_steps: OrganizationInfoOnboardingSteps
     organization_id: uuid.UUID
-    quotas: OrganizationInfoQuotas
-    users: list[OrganizationInfoUsers]
-    plan: OrganizationInfoPlan | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> OrganizationInfo:
+    def from_json(cls, value: Any) -> ServiceTokenPost:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "OrganizationInfo")
+        fields = as_fields(value, "ServiceTokenPost")
         return cls(
-            read(fields, "consumption", OrganizationInfoConsumption.from_json),
             read(fields, "name", as_text),
-            read(fields, "onboarding_steps", OrganizationInfoOnboardingSteps.from_json),
             read(fields, "organization_id", as_uuid),
-            read(fields, "quotas", OrganizationInfoQuotas.from_json),
-            read(fields, "users", as_list(OrganizationInfoUsers.from_json)),
-            maybe(fields, "plan", OrganizationInfoPlan.from_json),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["consumption"] = self.consumption.to_json()
         out["name"] = self.name
-        out["onboarding_steps"] = self.onboarding_steps.to_json()
         out["organization_id"] = str(self.organization_id)
-        out["quotas"] = self.quotas.to_json()
-        out["users"] = [item0.to_json() for item0 in self.users]
-        if self.plan is not None:
-            out["plan"] = self.plan.to_json()
         return out
 
 
 @dataclass(frozen=True)
-class OrganizationInfoConsumption:
-    """The `OrganizationInfoConsumption` the API declares."""
+class SigningKey:
+    """The `SigningKey` the API declares."""
+
+    application_id: uuid.UUID
+    created_at: datetime.datetime
+    is_current: bool
+    public_key: str
+    signing_key_id: uuid.UUID
+
+    @classmethod
+    def from_json(cls, value: Any) -> SigningKey:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "SigningKey")
+        return cls(
+            read(fields, "application_id", as_uuid),
+            read(fields, "created_at", as_datetime),
+            read(fields, "is_current", as_bool),
+            read(fields, "public_key", as_text),
+            read(fields, "signing_key_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        out["created_at"] = self.created_at.isoformat()
+        out["is_current"] = self.is_current
+        out["public_key"] = self.public_key
+        out["signing_key_id"] = str(self.signing_key_id)
+        return out
+
+
+@dataclass(frozen=True)
+class SigningKeyPost:
+    """The `SigningKeyPost` the API declares."""
+
+    application_id: uuid.UUID
+
+    @classmethod
+    def from_json(cls, value: Any) -> SigningKeyPost:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "SigningKeyPost")
+        return cls(
+            read(fields, "application_id", as_uuid),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        out["application_id"] = str(self.application_id)
+        return out
+
+
+@dataclass(frozen=True)
+class Subscription:
+    """The `Subscription` the API declares."""
 
-    applications: int | None = None
-    events_per_day: int | None = None
-    members: int | None = None
+    application_id: uuid.UUID
+    created_at: datetime.datetime
+    dedicated_workers: list[str]
+    event_type_versions: dict[str, int]
+    event_types: list[str]
+    is_enabled: bool
+    label_key: str
+    label_value: str
+    labels: dict[str, str]
+    metadata: dict[str, str]
+    secret: uuid.UUID
+    signature_scheme: SubscriptionSignatureScheme
+    subscription_id: uuid.UUID
+    target: SubscriptionTarget
+    updated_at: datetime.datetime
+    auto_disabled_at: datetime.datetime | None = None
+    auto_disabled_reason: str | None = None
+    batching: SubscriptionBatching | None = None
+    circuit_breaker: SubscriptionCircuitBreaker | None = None
+    delivery_limits: SubscriptionDeliveryLimits | None = None
+    description: str | None = None
+    filter: Any | None = None
+    ordering_key: SubscriptionOrderingKey | None = None
+    payload_transform: SubscriptionPayloadTransform | None = None
+    response_rules: list[SubscriptionResponseRules] | None = None
+    retry_policy: SubscriptionRetryPolicy | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> OrganizationInfoConsumption:
+    def from_json(cls, value: Any) -> Subscription:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "OrganizationInfoConsumption")
+        fields = as_fields(value, "Subscription")
         return cls(
-            maybe(fields, "applications", as_int),
-            maybe(fields, "events_per_day", as_int),
-            maybe(fields, "members", as_int),
+            read(fields, "application_id", as_uuid),
+            read(fields, "created_at", as_datetime),
+            read(fields, "dedicated_workers", as_list(as_text)),
+            read(fields, "event_type_versions", as_map(as_int)),
+            read(fields, "event_types", as_list(as_text)),
+            read(fields, "is_enabled", as_bool),
+            read(fields, "label_key", as_text),
+            read(fields, "label_value", as_text),
+            read(fields, "labels", as_map(as_text)),
+            read(fields, "metadata", as_map(as_text)),
+            read(fields, "secret", as_uuid),
+            read(fields, "signature_scheme", as_enum(SubscriptionSignatureScheme)),
+            read(fields, "subscription_id", as_uuid),
+            read(fields, "target", SubscriptionTarget.from_json),
+            read(fields, "updated_at", as_datetime),
+            maybe(fields, "auto_disabled_at", as_datetime),
+            maybe(fields, "auto_disabled_reason", as_text),
+            maybe(fields, "batching", SubscriptionBatching.from_json),
+            maybe(fields, "circuit_breaker", SubscriptionCircuitBreaker.from_json),
+            maybe(fields, "delivery_limits", SubscriptionDeliveryLimits.from_json),
+            maybe(fields, "description", as_text),
+            maybe(fields, "filter", as_json),
+            maybe(fields, "ordering_key", SubscriptionOrderingKey.from_json),
+            maybe(fields, "payload_transform", SubscriptionPayloadTransform.from_json),
+            maybe(fields, "response_rules", as_list(SubscriptionResponseRules.from_json)),
+            maybe(fields, "retry_policy", SubscriptionRetryPolicy.from_json),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        if self.applications is not None:
-            out["applications"] = self.applications
-        if self.events_per_day is not None:
-            out["events_per_day"] = self.events_per_day
-        if self.members is not None:
-            out["members"] = self.members
+        out["application_id"] = str(self.application_id)
+        out["created_at"] = self.created_at.isoformat()
+        out["dedicated_workers"] = list(self.dedicated_workers)
+        out["event_type_versions"] = dict(self.event_type_versions)
+        out["event_types"] = list(self.event_types)
+        out["is_enabled"] = self.is_enabled
+        out["label_key"] = self.label_key
+        out["label_value"] = self.label_value
+        out["labels"] = dict(self.labels)
+        out["metadata"] = dict(self.metadata)
+        out["secret"] = str(self.secret)
+        out["signature_scheme"] = self.signature_scheme.value
+        out["subscription_id"] = str(self.subscription_id)
+        out["target"] = self.target.to_json()
+        out["updated_at"] = self.updated_at.isoformat()
+        if self.auto_disabled_at is not None:
+            out["auto_disabled_at"] = self.auto_disabled_at.isoformat()
+        if self.auto_disabled_reason is not None:
+            out["auto_disabled_reason"] = self.auto_disabled_reason
+        if self.batching is not None:
+            out["batching"] = self.batching.to_json()
+        if self.circuit_breaker is not None:
+            out["circuit_breaker"] = self.circuit_breaker.to_json()
+        if self.delivery_limits is not None:
+            out["delivery_limits"] = self.delivery_limits.to_json()
+        if self.description is not None:
+            out["description"] = self.description
+        if self.filter is not None:
+            out["filter"] = self.filter
+        if self.ordering_key is not None:
+            out["ordering_key"] = self.ordering_key.to_json()
+        if self.payload_transform is not None:
+            out["payload_transform"] = self.payload_transform.to_json()
+        if self.response_rules is not None:
+            out["response_rules"] = [item0.to_json() for item0 in self.response_rules]
+        if self.retry_policy is not None:
+            out["retry_policy"] = self.retry_policy.to_json()
         return out
 
 
 @dataclass(frozen=True)
-class OrganizationInfoOnboardingSteps:
-    """The `OrganizationInfoOnboardingSteps` the API declares."""
+class SubscriptionBatching:
+    """The `SubscriptionBatching` the API declares."""
 
-    application: OrganizationInfoOnboardingStepsApplication
-    event: OrganizationInfoOnboardingStepsEvent
-    event_type: OrganizationInfoOnboardingStepsEventType
-    subscription: OrganizationInfoOnboardingStepsSubscription
+    max_events: int
+    max_wait_s: int
 
     @classmethod
-    def from_json(cls, value: Any) -> OrganizationInfoOnboardingSteps:
+    def from_json(cls, value: Any) -> SubscriptionBatching:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "OrganizationInfoOnboardingSteps")
+        fields = as_fields(value, "SubscriptionBatching")
         return cls(
-            read(fields, "application", as_enum(OrganizationInfoOnboardingStepsApplication)),
-            read(fields, "event", as_enum(OrganizationInfoOnboardingStepsEvent)),
-            read(fields, "event_type", as_enum(OrganizationInfoOnboardingStepsEventType)),
-            read(fields, "subscription", as_enum(OrganizationInfoOnboardingStepsSubscription)),
+            read(fields, "max_events", as_int),
+            read(fields, "max_wait_s", as_int),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["application"] = self.application.value
-        out["event"] = self.event.value
-        out["event_type"] = self.event_type.value
-        out["subscription"] = self.subscription.value
+        out["max_events"] = self.max_events
+        out["max_wait_s"] = self.max_wait_s
         return out
 
 
 @dataclass(frozen=True)
-class OrganizationInfoPlan:
-    """The `OrganizationInfoPlan` the API declares."""
+class SubscriptionCircuitBreaker:
+    """The `SubscriptionCircuitBreaker` the API declares."""
 
-    label: str
-    name: str
+    max_consecutive_failures: int | None = None
+    max_failing_hours: int | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> OrganizationInfoPlan:
+    def from_json(cls, value: Any) -> SubscriptionCircuitBreaker:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "OrganizationInfoPlan")
+        fields = as_fields(value, "SubscriptionCircuitBreaker")
         return cls(
-            read(fields, "label", as_text),
-            read(fields, "name", as_text),
+            maybe(fields, "max_consecutive_failures", as_int),
+            maybe(fields, "max_failing_hours", as_int),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["label"] = self.label
-        out["name"] = self.name
+        if self.max_consecutive_failures is not None:
+            out["max_consecutive_failures"] = self.max_consecutive_failures
+        if self.max_failing_hours is not None:
+            out["max_failing_hours"] = self.max_failing_hours
         return out
 
 
 @dataclass(frozen=True)
-class OrganizationInfoQuotas:
-    """The `OrganizationInfoQuotas` the API declares."""
+class SubscriptionDeliveryLimits:
+    """The `SubscriptionDeliveryLimits` the API declares."""
 
-    applications_per_organization_limit: int
-    days_of_events_retention_limit: int
-    events_per_day_limit: int
-    members_per_organization_limit: int
+    max_in_flight: int | None = None
+    max_per_second: int | None = None
+    per_host: bool | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> OrganizationInfoQuotas:
+    def from_json(cls, value: Any) -> SubscriptionDeliveryLimits:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "OrganizationInfoQuotas")
+        fields = as_fields(value, "SubscriptionDeliveryLimits")
         return cls(
-            read(fields, "applications_per_organization_limit", as_int),
-            read(fields, "days_of_events_retention_limit", as_int),
-            read(fields, "events_per_day_limit", as_int),
-            read(fields, "members_per_organization_limit", as_int),
+            maybe(fields, "max_in_flight", as_int),
+            maybe(fields, "max_per_second", as_int),
+            maybe(fields, "per_host", as_bool),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["applications_per_organization_limit"] = self.applications_per_organization_limit
-        out["days_of_events_retention_limit"] = self.days_of_events_retention_limit
-        out["events_per_day_limit"] = self.events_per_day_limit
-        out["members_per_organization_limit"] = self.members_per_organization_limit
+        if self.max_in_flight is not None:
+            out["max_in_flight"] = self.max_in_flight
+        if self.max_per_second is not None:
+            out["max_per_second"] = self.max_per_second
+        if self.per_host is not None:
+            out["per_host"] = self.per_host
         return out
 
 
 @dataclass(frozen=True)
-class OrganizationInfoUsers:
-    """The `OrganizationInfoUsers` the API declares."""
+class SubscriptionOrderingKey:
+    """The `SubscriptionOrderingKey` the API declares."""
 
-    email: str
-    first_name: str
-    last_name: str
-    role: str
-    user_id: uuid.UUID
+    field: str | None = None
+    label: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> OrganizationInfoUsers:
+    def from_json(cls, value: Any) -> SubscriptionOrderingKey:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "OrganizationInfoUsers")
+        fields = as_fields(value, "SubscriptionOrderingKey")
         return cls(
-            read(fields, "email", as_text),
-            read(fields, "first_name", as_text),
-            read(fields, "last_name", as_text),
-            read(fields, "role", as_text),
-            read(fields, "user_id", as_uuid),
+            maybe(fields, "field", as_text),
+            maybe(fields, "label", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["email"] = self.email
-        out["first_name"] = self.first_name
-        out["last_name"] = self.last_name
-        out["role"] = self.role
-        out["user_id"] = str(self.user_id)
+        if self.field is not None:
+            out["field"] = self.field
+        if self.label is not None:
+            out["label"] = self.label
         return out
 
 
 @dataclass(frozen=True)
-class OrganizationPlan:
-    """The `OrganizationPlan` the API declares."""
+class SubscriptionPayloadTransform:
+    """The `SubscriptionPayloadTransform` the API declares."""
 
-    label: str
-    name: str
+    constants: dict[str, Any] | None = None
+    envelope: str | None = None
+    fields: list[SubscriptionPayloadTransformFields] | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> OrganizationPlan:
+    def from_json(cls, value: Any) -> SubscriptionPayloadTransform:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "OrganizationPlan")
+        fields = as_fields(value, "SubscriptionPayloadTransform")
         return cls(
-            read(fields, "label", as_text),
-            read(fields, "name", as_text),
+            maybe(fields, "constants", as_map(as_json)),
+            maybe(fields, "envelope", as_text),
+            maybe(fields, "fields", as_list(SubscriptionPayloadTransformFields.from_json)),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["label"] = self.label
-        out["name"] = self.name
+        if self.constants is not None:
+            out["constants"] = dict(self.constants)
+        if self.envelope is not None:
+            out["envelope"] = self.envelope
+        if self.fields is not None:
+            out["fields"] = [item0.to_json() for item0 in self.fields]
         return out
 
 
 @dataclass(frozen=True)
-class Problem:
-    """The `Problem` the API declares."""
+class SubscriptionPayloadTransformFields:
+    """The `SubscriptionPayloadTransformFields` the API declares."""
 
-    detail: str
-    id_: ProblemId
-    status: int
-    title: str
-    type_: str
-    validation: Any | None = None
+    from_: str
+    to: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> Problem:
+    def from_json(cls, value: Any) -> SubscriptionPayloadTransformFields:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "Problem")
+        fields = as_fields(value, "SubscriptionPayloadTransformFields")
         return cls(
-            read(fields, "detail", as_text),
-            read(fields, "id", as_enum(ProblemId)),
-            read(fields, "status", as_int),
-            read(fields, "title", as_text),
-            read(fields, "type", as_text),
-            maybe(fields, "validation", as_json),
+            read(fields, "from", as_text),
+            maybe(fields, "to", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["detail"] = self.detail
-        out["id"] = self.id_.value
-        out["status"] = self.status
-        out["title"] = self.title
-        out["type"] = self.type_
-        if self.validation is not None:
-            out["validation"] = self.validation
+        out["from"] = self.from_
+        if self.to is not None:
+            out["to"] = self.to
         return out
 
 
 @dataclass(frozen=True)
-class QuotasResponse:
-    """The `QuotasResponse` the API declares."""
+class SubscriptionPost:
+    """The `SubscriptionPost` the API declares."""
 
-    enabled: bool
-    limits: QuotasResponseLimits
+    application_id: uuid.UUID
+    event_types: list[str]
+    is_enabled: bool
+    target: SubscriptionPostTarget
+    batching: SubscriptionPostBatching | None = None
+    circuit_breaker: SubscriptionPostCircuitBreaker | None = None
+    dedicated_workers: list[str] | None = None
+    delivery_limits: SubscriptionPostDeliveryLimits | None = None
+    description: str | None = None
+    event_type_versions: dict[str, int] | None = None
+    filter: Any | None = None
+    label_key: str | None = None
+    label_value: str | None = None
+    labels: dict[str, str] | None = None
+    metadata: dict[str, str] | None = None
+    ordering_key: SubscriptionPostOrderingKey | None = None
+    payload_transform: SubscriptionPostPayloadTransform | None = None
+    response_rules: list[SubscriptionPostResponseRules] | None = None
+    retry_policy: SubscriptionPostRetryPolicy | None = None
+    signature_scheme: SubscriptionPostSignatureScheme | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> QuotasResponse:
+    def from_json(cls, value: Any) -> SubscriptionPost:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "QuotasResponse")
+        fields = as_fields(value, "SubscriptionPost")
         return cls(
-            read(fields, "enabled", as_bool),
-            read(fields, "limits", QuotasResponseLimits.from_json),
+            read(fields, "application_id", as_uuid),
+            read(fields, "event_types", as_list(as_text)),
+            read(fields, "is_enabled", as_bool),
+            read(fields, "target", SubscriptionPostTarget.from_json),
+            maybe(fields, "batching", SubscriptionPostBatching.from_json),
+            maybe(fields, "circuit_breaker", SubscriptionPostCircuitBreaker.from_json),
+            maybe(fields, "dedicated_workers", as_list(as_text)),
+            maybe(fields, "delivery_limits", SubscriptionPostDeliveryLimits.from_json),
+            maybe(fields, "description", as_text),
+            maybe(fields, "event_type_versions", as_map(as_int)),
+            maybe(fields, "filter", as_json),
+            maybe(fields, "label_key", as_text),
+            maybe(fields, "label_value", as_text),
+            maybe(fields, "labels", as_map(as_text)),
+            maybe(fields, "metadata", as_map(as_text)),
+            maybe(fields, "ordering_key", SubscriptionPostOrderingKey.from_json),
+            maybe(fields, "payload_transform", SubscriptionPostPayloadTransform.from_json),
+            maybe(fields, "response_rules", as_list(SubscriptionPostResponseRules.from_json)),
+            maybe(fields, "retry_policy", SubscriptionPostRetryPolicy.from_json),
+            maybe(fields, "signature_scheme", as_enum(SubscriptionPostSignatureScheme)),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["enabled"] = self.enabled
-        out["limits"] = self.limits.to_json()
+        out["application_id"] = str(self.application_id)
+        out["event_types"] = list(self.event_types)
+        out["is_enabled"] = self.is_enabled
+        out["target"] = self.target.to_json()
+        if self.batching is not None:
+            out["batching"] = self.batching.to_json()
+        if self.circuit_breaker is not None:
+            out["circuit_breaker"] = self.circuit_breaker.to_json()
+        if self.dedicated_workers is not None:
+            out["dedicated_workers"] = list(self.dedicated_workers)
+        if self.delivery_limits is not None:
+            out["delivery_limits"] = self.delivery_limits.to_json()
+        if self.description is not None:
+            out["description"] = self.description
+        if self.event_type_versions is not None:
+            out["event_type_versions"] = dict(self.event_type_versions)
+        if self.filter is not None:
+            out["filter"] = self.filter
+        if self.label_key is not None:
+            out["label_key"] = self.label_key
+        if self.label_value is not None:
+            out["label_value"] = self.label_value
+        if self.labels is not None:
+            out["labels"] = dict(self.labels)
+        if self.metadata is not None:
+            out["metadata"] = dict(self.metadata)
+        if self.ordering_key is not None:
+            out["ordering_key"] = self.ordering_key.to_json()
+        if self.payload_transform is not None:
+            out["payload_transform"] = self.payload_transform.to_json()
+        if self.response_rules is not None:
+            out["response_rules"] = [item0.to_json() for item0 in self.response_rules]
+        if self.retry_policy is not None:
+            out["retry_policy"] = self.retry_policy.to_json()
+        if self.signature_scheme is not None:
+            out["signature_scheme"] = self.signature_scheme.value
         return out
 
 
 @dataclass(frozen=True)
-class QuotasResponseLimits:
-    """The `QuotasResponseLimits` the API declares."""
+class SubscriptionPostBatching:
+    """The `SubscriptionPostBatching` the API declares."""
 
-    global_applications_per_organization_limit: int
-    global_days_of_events_retention_limit: int
-    global_event_types_per_application_limit: int
-    global_events_per_day_limit: int
-    global_members_per_organization_limit: int
-    global_subscriptions_per_application_limit: int
+    max_events: int
+    max_wait_s: int
 
     @classmethod
-    def from_json(cls, value: Any) -> QuotasResponseLimits:
+    def from_json(cls, value: Any) -> SubscriptionPostBatching:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "QuotasResponseLimits")
+        fields = as_fields(value, "SubscriptionPostBatching")
         return cls(
-            read(fields, "global_applications_per_organization_limit", as_int),
-            read(fields, "global_days_of_events_retention_limit", as_int),
-            read(fields, "global_event_types_per_application_limit", as_int),
-            read(fields, "global_events_per_day_limit", as_int),
-            read(fields, "global_members_per_organization_limit", as_int),
-            read(fields, "global_subscriptions_per_application_limit", as_int),
+            read(fields, "max_events", as_int),
+            read(fields, "max_wait_s", as_int),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["global_applications_per_organization_limit"] = self.global_applications_per_organization_limit
-        out["global_days_of_events_retention_limit"] = self.global_days_of_events_retention_limit
-        out["global_event_types_per_application_limit"] = self.global_event_types_per_application_limit
-        out["global_events_per_day_limit"] = self.global_events_per_day_limit
-        out["global_members_per_organization_limit"] = self.global_members_per_organization_limit
-        out["global_subscriptions_per_application_limit"] = self.global_subscriptions_per_application_limit
+        out["max_events"] = self.max_events
+        out["max_wait_s"] = self.max_wait_s
         return out
 
 
 @dataclass(frozen=True)
-class ReplayEvent:
-    """The `ReplayEvent` the API declares."""
+class SubscriptionPostCircuitBreaker:
+    """The `SubscriptionPostCircuitBreaker` the API declares."""
 
-    application_id: uuid.UUID
+    max_consecutive_failures: int | None = None
+    max_failing_hours: int | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> ReplayEvent:
+    def from_json(cls, value: Any) -> SubscriptionPostCircuitBreaker:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "ReplayEvent")
+        fields = as_fields(value, "SubscriptionPostCircuitBreaker")
         return cls(
-            read(fields, "application_id", as_uuid),
+            maybe(fields, "max_consecutive_failures", as_int),
+            maybe(fields, "max_failing_hours", as_int),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["application_id"] = str(self.application_id)
+        if self.max_consecutive_failures is not None:
+            out["max_consecutive_failures"] = self.max_consecutive_failures
+        if self.max_failing_hours is not None:
+            out["max_failing_hours"] = self.max_failing_hours
         return out
 
 
 @dataclass(frozen=True)
-class RequestAttempt:
-    """The `RequestAttempt` the API declares."""
+class SubscriptionPostDeliveryLimits:
+    """The `SubscriptionPostDeliveryLimits` the API declares."""
 
-    created_at: datetime.datetime
-    event: RequestAttemptEvent
-    event_id: uuid.UUID
-    request_attempt_id: uuid.UUID
-    retry_count: int
-    status: RequestAttemptStatus
-    subscription: RequestAttemptSubscription
-    delay_until: datetime.datetime | None = None
-    failed_at: datetime.datetime | None = None
-    http_response_status: int | None = None
-    picked_at: datetime.datetime | None = None
-    response_id: uuid.UUID | None = None
-    succeeded_at: datetime.datetime | None = None
+    max_in_flight: int | None = None
+    max_per_second: int | None = None
+    per_host: bool | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> RequestAttempt:
+    def from_json(cls, value: Any) -> SubscriptionPostDeliveryLimits:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "RequestAttempt")
+        fields = as_fields(value, "SubscriptionPostDeliveryLimits")
         return cls(
-            read(fields, "created_at", as_datetime),
-            read(fields, "event", RequestAttemptEvent.from_json),
-            read(fields, "event_id", as_uuid),
-            read(fields, "request_attempt_id", as_uuid),
-            read(fields, "retry_count", as_int),
-            read(fields, "status", RequestAttemptStatus.from_json),
-            read(fields, "subscription", RequestAttemptSubscription.from_json),
-            maybe(fields, "delay_until", as_datetime),
-            maybe(fields, "failed_at", as_datetime),
-            maybe(fields, "http_response_status", as_int),
-            maybe(fields, "picked_at", as_datetime),
-            maybe(fields, "response_id", as_uuid),
-            maybe(fields, "succeeded_at", as_datetime),
+            maybe(fields, "max_in_flight", as_int),
+            maybe(fields, "max_per_second", as_int),
+            maybe(fields, "per_host", as_bool),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["created_at"] = self.created_at.isoformat()
-        out["event"] = self.event.to_json()
-        out["event_id"] = str(self.event_id)
-        out["request_attempt_id"] = str(self.request_attempt_id)
-        out["retry_count"] = self.retry_count
-        out["status"] = self.status.to_json()
-        out["subscription"] = self.subscription.to_json()
-        if self.delay_until is not None:
-            out["delay_until"] = self.delay_until.isoformat()
-        if self.failed_at is not None:
-            out["failed_at"] = self.failed_at.isoformat()
-        if self.http_response_status is not None:
-            out["http_response_status"] = self.http_response_status
-        if self.picked_at is not None:
-            out["picked_at"] = self.picked_at.isoformat()
-        if self.response_id is not None:
-            out["response_id"] = str(self.response_id)
-        if self.succeeded_at is not None:
-            out["succeeded_at"] = self.succeeded_at.isoformat()
+        if self.max_in_flight is not None:
+            out["max_in_flight"] = self.max_in_flight
+        if self.max_per_second is not None:
+            out["max_per_second"] = self.max_per_second
+        if self.per_host is not None:
+            out["per_host"] = self.per_host
         return out
 
 
 @dataclass(frozen=True)
-class RequestAttemptEvent:
-    """The `RequestAttemptEvent` the API declares."""
+class SubscriptionPostOrderingKey:
+    """The `SubscriptionPostOrderingKey` the API declares."""
 
-    event_id: uuid.UUID
-    event_type_name: str
+    field: str | None = None
+    label: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> RequestAttemptEvent:
+    def from_json(cls, value: Any) -> SubscriptionPostOrderingKey:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "RequestAttemptEvent")
+        fields = as_fields(value, "SubscriptionPostOrderingKey")
         return cls(
-            read(fields, "event_id", as_uuid),
-            read(fields, "event_type_name", as_text),
+            maybe(fields, "field", as_text),
+            maybe(fields, "label", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["event_id"] = str(self.event_id)
-        out["event_type_name"] = self.event_type_name
+        if self.field is not None:
+            out["field"] = self.field
+        if self.label is not None:
+            out["label"] = self.label
         return out
 
 
 @dataclass(frozen=True)
-class RequestAttemptStatus:
-    """The `RequestAttemptStatus` the API declares."""
+class SubscriptionPostPayloadTransform:
+    """The `SubscriptionPostPayloadTransform` the API declares."""
 
-    type_: RequestAttemptStatusType
-    at: datetime.datetime | None = None
-    full_processing_ms: int | None = None
-    since: datetime.datetime | None = None
-    until: datetime.datetime | None = None
+    constants: dict[str, Any] | None = None
+    envelope: str | None = None
+    fields: list[SubscriptionPostPayloadTransformFields] | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> RequestAttemptStatus:
+    def from_json(cls, value: Any) -> SubscriptionPostPayloadTransform:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "RequestAttemptStatus")
+        fields = as_fields(value, "SubscriptionPostPayloadTransform")
         return cls(
-            read(fields, "type", as_enum(RequestAttemptStatusType)),
-            maybe(fields, "at", as_datetime),
-            maybe(fields, "full_processing_ms", as_int),
-            maybe(fields, "since", as_datetime),
-            maybe(fields, "until", as_datetime),
+            maybe(fields, "constants", as_map(as_json)),
+            maybe(fields, "envelope", as_text),
+            maybe(fields, "fields", as_list(SubscriptionPostPayloadTransformFields.from_json)),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["type"] = self.type_.value
-        if self.at is not None:
-            out["at"] = self.at.isoformat()
-        if self.full_processing_ms is not None:
-            out["full_processing_ms"] = self.full_processing_ms
-        if self.since is not None:
-            out["since"] = self.since.isoformat()
-        if self.until is not None:
-            out["until"] = self.until.isoformat()
+        if self.constants is not None:
+            out["constants"] = dict(self.constants)
+        if self.envelope is not None:
+            out["envelope"] = self.envelope
+        if self.fields is not None:
+            out["fields"] = [item0.to_json() for item0 in self.fields]
         return out
 
 
 @dataclass(frozen=True)
-class RequestAttemptSubscription:
-    """The `RequestAttemptSubscription` the API declares."""
+class SubscriptionPostPayloadTransformFields:
+    """The `SubscriptionPostPayloadTransformFields` the API declares."""
 
-    subscription_id: uuid.UUID
-    description: str | None = None
+    from_: str
+    to: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> RequestAttemptSubscription:
+    def from_json(cls, value: Any) -> SubscriptionPostPayloadTransformFields:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "RequestAttemptSubscription")
+        fields = as_fields(value, "SubscriptionPostPayloadTransformFields")
         return cls(
-            read(fields, "subscription_id", as_uuid),
-            maybe(fields, "description", as_text),
+            read(fields, "from", as_text),
+            maybe(fields, "to", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["subscription_id"] = str(self.subscription_id)
-        if self.description is not None:
-            out["description"] = self.description
+        out["from"] = self.from_
+        if self.to is not None:
+            out["to"] = self.to
         return out
 
 
 @dataclass(frozen=True)
-class Response:
-    """The `Response` the API declares."""
+class SubscriptionPostResponseRules:
+    """The `SubscriptionPostResponseRules` the API declares."""
 
-    response_id: uuid.UUID
-    body: str | None = None
-    elapsed_time_ms: int | None = None
-    headers: dict[str, str] | None = None
-    http_code: int | None = None
-    response_error_name: str | None = None
+    outcome: SubscriptionPostResponseRulesOutcome
+    status: list[str]
+    body_contains: str | None = None
+    header: SubscriptionPostResponseRulesHeader | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> Response:
+    def from_json(cls, value: Any) -> SubscriptionPostResponseRules:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "Response")
+        fields = as_fields(value, "SubscriptionPostResponseRules")
         return cls(
-            read(fields, "response_id", as_uuid),
-            maybe(fields, "body", as_text),
-            maybe(fields, "elapsed_time_ms", as_int),
-            maybe(fields, "headers", as_map(as_text)),
-            maybe(fields, "http_code", as_int),
-            maybe(fields, "response_error_name", as_text),
+            read(fields, "outcome", as_enum(SubscriptionPostResponseRulesOutcome)),
+            read(fields, "status", as_list(as_text)),
+            maybe(fields, "body_contains", as_text),
+            maybe(fields, "header", SubscriptionPostResponseRulesHeader.from_json),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["response_id"] = str(self.response_id)
-        if self.body is not None:
-            out["body"] = self.body
-        if self.elapsed_time_ms is not None:
-            out["elapsed_time_ms"] = self.elapsed_time_ms
-        if self.headers is not None:
-            out["headers"] = dict(self.headers)
-        if self.http_code is not None:
-            out["http_code"] = self.http_code
-        if self.response_error_name is not None:
-            out["response_error_name"] = self.response_error_name
+        out["outcome"] = self.outcome.value
+        out["status"] = list(self.status)
+        if self.body_contains is not None:
+            out["body_contains"] = self.body_contains
+        if self.header is not None:
+            out["header"] = self.header.to_json()
         return out
 
 
 @dataclass(frozen=True)
-class ServiceToken:
-    """The `ServiceToken` the API declares."""
+class SubscriptionPostResponseRulesHeader:
+    """The `SubscriptionPostResponseRulesHeader` the API declares."""
 
-    biscuit: str
-    created_at: datetime.datetime
     name: str
-    token_id: uuid.UUID
+    value: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> ServiceToken:
+    def from_json(cls, value: Any) -> SubscriptionPostResponseRulesHeader:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "ServiceToken")
+        fields = as_fields(value, "SubscriptionPostResponseRulesHeader")
         return cls(
-            read(fields, "biscuit", as_text),
-            read(fields, "created_at", as_datetime),
             read(fields, "name", as_text),
-            read(fields, "token_id", as_uuid),
+            maybe(fields, "value", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["biscuit"] = self.biscuit
-        out["created_at"] = self.created_at.isoformat()
         out["name"] = self.name
-        out["token_id"] = str(self.token_id)
+        if self.value is not None:
+            out["value"] = self.value
         return out
 
 
 @dataclass(frozen=True)
-class ServiceTokenPost:
-    """The `ServiceTokenPost` the API declares."""
+class SubscriptionPostRetryPolicy:
+    """The `SubscriptionPostRetryPolicy` the API declares."""
 
-    name: str
-    organization_id: uuid.UUID
+    backoff_steps_s: list[int] | None = None
+    max_retries: int | None = None
+    max_window_s: int | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> ServiceTokenPost:
+    def from_json(cls, value: Any) -> SubscriptionPostRetryPolicy:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "ServiceTokenPost")
+        fields = as_fields(value, "SubscriptionPostRetryPolicy")
         return cls(
-            read(fields, "name", as_text),
-            read(fields, "organization_id", as_uuid),
+            maybe(fields, "backoff_steps_s", as_list(as_int)),
+            maybe(fields, "max_retries", as_int),
+            maybe(fields, "max_window_s", as_int),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["name"] = self.name
-        out["organization_id"] = str(self.organization_id)
+        if self.backoff_steps_s is not None:
+            out["backoff_steps_s"] = list(self.backoff_steps_s)
+        if self.max_retries is not None:
+            out["max_retries"] = self.max_retries
+        if self.max_window_s is not None:
+            out["max_window_s"] = self.max_window_s
         return out
 
 
 @dataclass(frozen=True)
-class Subscription:
-    """The `Subscription` the API declares."""
+class SubscriptionPostTarget:
+    """The `SubscriptionPostTarget` the API declares."""
 
-    application_id: uuid.UUID
-    created_at: datetime.datetime
-    dedicated_workers: list[str]
-    event_types: list[str]
-    is_enabled: bool
-    label_key: str
-    label_value: str
-    labels: dict[str, str]
-    metadata: dict[str, str]
-    secret: uuid.UUID
-    subscription_id: uuid.UUID
-    target: SubscriptionTarget
-    updated_at: datetime.datetime
-    description: str | None = None
+    type_: SubscriptionPostTargetType
+    access_key_id: str | None = None
+    exchange: str | None = None
+    headers: Any | None = None
+    method: str | None = None
+    password: str | None = None
+    queue_url: str | None = None
+    region: str | None = None
+    routing_key: str | None = None
+    secret_access_key: str | None = None
+    url: str | None = None
+    username: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> Subscription:
+    def from_json(cls, value: Any) -> SubscriptionPostTarget:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "Subscription")
+        fields = as_fields(value, "SubscriptionPostTarget")
         return cls(
-            read(fields, "application_id", as_uuid),
-            read(fields, "created_at", as_datetime),
-            read(fields, "dedicated_workers", as_list(as_text)),
-            read(fields, "event_types", as_list(as_text)),
-            read(fields, "is_enabled", as_bool),
-            read(fields, "label_key", as_text),
-            read(fields, "label_value", as_text),
-            read(fields, "labels", as_map(as_text)),
-            read(fields, "metadata", as_map(as_text)),
-            read(fields, "secret", as_uuid),
-            read(fields, "subscription_id", as_uuid),
-            read(fields, "target", SubscriptionTarget.from_json),
-            read(fields, "updated_at", as_datetime),
-            maybe(fields, "description", as_text),
+            read(fields, "type", as_enum(SubscriptionPostTargetType)),
+            maybe(fields, "access_key_id", as_text),
+            maybe(fields, "exchange", as_text),
+            maybe(fields, "headers", as_json),
+            maybe(fields, "method", as_text),
+            maybe(fields, "password", as_text),
+            maybe(fields, "queue_url", as_text),
+            maybe(fields, "region", as_text),
+            maybe(fields, "routing_key", as_text),
+            maybe(fields, "secret_access_key", as_text),
+            maybe(fields, "url", as_text),
+            maybe(fields, "username", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["application_id"] = str(self.application_id)
-        out["created_at"] = self.created_at.isoformat()
-        out["dedicated_workers"] = list(self.dedicated_workers)
-        out["event_types"] = list(self.event_types)
-        out["is_enabled"] = self.is_enabled
-        out["label_key"] = self.label_key
-        out["label_value"] = self.label_value
-        out["labels"] = dict(self.labels)
-        out["metadata"] = dict(self.metadata)
-        out["secret"] = str(self.secret)
-        out["subscription_id"] = str(self.subscription_id)
-        out["target"] = self.target.to_json()
-        out["updated_at"] = self.updated_at.isoformat()
-        if self.description is not None:
-            out["description"] = self.description
+        out["type"] = self.type_.value
+        if self.access_key_id is not None:
+            out["access_key_id"] = self.access_key_id
+        if self.exchange is not None:
+            out["exchange"] = self.exchange
+        if self.headers is not None:
+            out["headers"] = self.headers
+        if self.method is not None:
+            out["method"] = self.method
+        if self.password is not None:
+            out["password"] = self.password
+        if self.queue_url is not None:
+            out["queue_url"] = self.queue_url
+        if self.region is not None:
+            out["region"] = self.region
+        if self.routing_key is not None:
+            out["routing_key"] = self.routing_key
+        if self.secret_access_key is not None:
+            out["secret_access_key"] = self.secret_access_key
+        if self.url is not None:
+            out["url"] = self.url
+        if self.username is not None:
+            out["username"] = self.username
         return out
 
 
 @dataclass(frozen=True)
-class SubscriptionPost:
-    """The `SubscriptionPost` the API declares."""
+class SubscriptionResponseRules:
+    """The `SubscriptionResponseRules` the API declares."""
 
-    application_id: uuid.UUID
-    event_types: list[str]
-    is_enabled: bool
-    target: SubscriptionPostTarget
-    dedicated_workers: list[str] | None = None
-    description: str | None = None
-    label_key: str | None = None
-    label_value: str | None = None
-    labels: dict[str, str] | None = None
-    metadata: dict[str, str] | None = None
+    outcome: SubscriptionResponseRulesOutcome
+    status: list[str]
+    body_contains: str | None = None
+    header: SubscriptionResponseRulesHeader | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> SubscriptionPost:
+    def from_json(cls, value: Any) -> SubscriptionResponseRules:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "SubscriptionPost")
+        fields = as_fields(value, "SubscriptionResponseRules")
         return cls(
-            read(fields, "application_id", as_uuid),
-            read(fields, "event_types", as_list(as_text)),
-            read(fields, "is_enabled", as_bool),
-            read(fields, "target", SubscriptionPostTarget.from_json),
-            maybe(fields, "dedicated_workers", as_list(as_text)),
-            maybe(fields, "description", as_text),
-            maybe(fields, "label_key", as_text),
-            maybe(fields, "label_value", as_text),
-            maybe(fields, "labels", as_map(as_text)),
-            maybe(fields, "metadata", as_map(as_text)),
+            read(fields, "outcome", as_enum(SubscriptionResponseRulesOutcome)),
+            read(fields, "status", as_list(as_text)),
+            maybe(fields, "body_contains", as_text),
+            maybe(fields, "header", SubscriptionResponseRulesHeader.from_json),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["application_id"] = str(self.application_id)
-        out["event_types"] = list(self.event_types)
-        out["is_enabled"] = self.is_enabled
-        out["target"] = self.target.to_json()
-        if self.dedicated_workers is not None:
-            out["dedicated_workers"] = list(self.dedicated_workers)
-        if self.description is not None:
-            out["description"] = self.description
-        if self.label_key is not None:
-            out["label_key"] = self.label_key
-        if self.label_value is not None:
-            out["label_value"] = self.label_value
-        if self.labels is not None:
-            out["labels"] = dict(self.labels)
-        if self.metadata is not None:
-            out["metadata"] = dict(self.metadata)
+        out["outcome"] = self.outcome.value
+        out["status"] = list(self.status)
+        if self.body_contains is not None:
+            out["body_contains"] = self.body_contains
+        if self.header is not None:
+            out["header"] = self.header.to_json()
         return out
 
 
 @dataclass(frozen=True)
-class SubscriptionPostTarget:
-    """The `SubscriptionPostTarget` the API declares."""
+class SubscriptionResponseRulesHeader:
+    """The `SubscriptionResponseRulesHeader` the API declares."""
 
-    headers: Any
-    method: str
-    type_: str
-    url: str
+    name: str
+    value: str | None = None
 
     @classmethod
-    def from_json(cls, value: Any) -> SubscriptionPostTarget:
+    def from_json(cls, value: Any) -> SubscriptionResponseRulesHeader:
         """Read one out of what the API answered."""
-        fields = as_fields(value, "SubscriptionPostTarget")
+        fields = as_fields(value, "SubscriptionResponseRulesHeader")
         return cls(
-            read(fields, "headers", as_json),
-            read(fields, "method", as_text),
-            read(fields, "type", as_text),
-            read(fields, "url", as_text),
+            read(fields, "name", as_text),
+            maybe(fields, "value", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["headers"] = self.headers
-        out["method"] = self.method
-        out["type"] = self.type_
-        out["url"] = self.url
+        out["name"] = self.name
+        if self.value is not None:
+            out["value"] = self.value
+        return out
+
+
+@dataclass(frozen=True)
+class SubscriptionRetryPolicy:
+    """The `SubscriptionRetryPolicy` the API declares."""
+
+    backoff_steps_s: list[int] | None = None
+    max_retries: int | None = None
+    max_window_s: int | None = None
+
+    @classmethod
+    def from_json(cls, value: Any) -> SubscriptionRetryPolicy:
+        """Read one out of what the API answered."""
+        fields = as_fields(value, "SubscriptionRetryPolicy")
+        return cls(
+            maybe(fields, "backoff_steps_s", as_list(as_int)),
+            maybe(fields, "max_retries", as_int),
+            maybe(fields, "max_window_s", as_int),
+        )
+
+    def to_json(self) -> dict[str, Any]:
+        """Write one back the way the API reads it."""
+        out: dict[str, Any] = {}
+        if self.backoff_steps_s is not None:
+            out["backoff_steps_s"] = list(self.backoff_steps_s)
+        if self.max_retries is not None:
+            out["max_retries"] = self.max_retries
+        if self.max_window_s is not None:
+            out["max_window_s"] = self.max_window_s
         return out
 
 
@@ -1459,27 +3767,62 @@ class SubscriptionPostTarget:
 class SubscriptionTarget:
     """The `SubscriptionTarget` the API declares."""
 
-    headers: Any
-    method: str
-    type_: str
-    url: str
+    type_: SubscriptionTargetType
+    access_key_id: str | None = None
+    exchange: str | None = None
+    headers: Any | None = None
+    method: str | None = None
+    password: str | None = None
+    queue_url: str | None = None
+    region: str | None = None
+    routing_key: str | None = None
+    secret_access_key: str | None = None
+    url: str | None = None
+    username: str | None = None
 
     @classmethod
     def from_json(cls, value: Any) -> SubscriptionTarget:
         """Read one out of what the API answered."""
         fields = as_fields(value, "SubscriptionTarget")
         return cls(
-            read(fields, "headers", as_json),
-            read(fields, "method", as_text),
-            read(fields, "type", as_text),
-            read(fields, "url", as_text),
+            read(fields, "type", as_enum(SubscriptionTargetType)),
+            maybe(fields, "access_key_id", as_text),
+            maybe(fields, "exchange", as_text),
+            maybe(fields, "headers", as_json),
+            maybe(fields, "method", as_text),
+            maybe(fields, "password", as_text),
+            maybe(fields, "queue_url", as_text),
+            maybe(fields, "region", as_text),
+            maybe(fields, "routing_key", as_text),
+            maybe(fields, "secret_access_key", as_text),
+            maybe(fields, "url", as_text),
+            maybe(fields, "username", as_text),
         )
 
     def to_json(self) -> dict[str, Any]:
         """Write one back the way the API reads it."""
         out: dict[str, Any] = {}
-        out["headers"] = self.headers
-        out["method"] = self.method
-        out["type"] = self.type_
-        out["url"] = self.url
+        out["type"] = self.type_.value
+        if self.access_key_id is not None:
+            out["access_key_id"] = self.access_key_id
+        if self.exchange is not None:
+            out["exchange"] = self.exchange
+        if self.headers is not None:
+            out["headers"] = self.headers
+        if self.method is not None:
+            out["method"] = self.method
+        if self.password is not None:
+            out["password"] = self.password
+        if self.queue_url is not None:
+            out["queue_url"] = self.queue_url
+        if self.region is not None:
+            out["region"] = self.region
+        if self.routing_key is not None:
+            out["routing_key"] = self.routing_key
+        if self.secret_access_key is not None:
+            out["secret_access_key"] = self.secret_access_key
+        if self.url is not None:
+            out["url"] = self.url
+        if self.username is not None:
+            out["username"] = self.username
         return out
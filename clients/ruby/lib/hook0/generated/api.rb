@@ -246,6 +246,394 @@ module Hook0
       end
     end
 
+    # What the API declares under `bulkRetries`, issued through the transport it is handed.
+    class BulkRetriesApi
+      # @param transport [Object] what one request is issued through
+      def initialize(transport)
+        @transport = transport
+      end
+
+      # Cancel a bulk retry
+      #
+      # @param bulk_retry_id [String] carries `bulk_retry_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [BulkRetry]
+      def cancel(bulk_retry_id, application_id)
+        read_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/bulk_retries/{bulk_retry_id}/cancel",
+              "bulk_retry_id" => bulk_retry_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          ),
+          BulkRetry.method(:from_json)
+        )
+      end
+
+      # Create a bulk retry
+      #
+      # @param body [BulkRetryPost] what the operation reads
+      # @return [BulkRetry]
+      def create(body)
+        read_answer(
+          @transport.request(
+            "POST",
+            "/api/v1/bulk_retries/",
+            [],
+            body.to_h
+          ),
+          BulkRetry.method(:from_json)
+        )
+      end
+
+      # Count what a bulk retry would redeliver
+      #
+      # @param body [BulkRetryPost] what the operation reads
+      # @return [BulkRetryDryRun]
+      def dry_run(body)
+        read_answer(
+          @transport.request(
+            "POST",
+            "/api/v1/bulk_retries/dry_run",
+            [],
+            body.to_h
+          ),
+          BulkRetryDryRun.method(:from_json)
+        )
+      end
+
+      # Get a bulk retry
+      #
+      # @param bulk_retry_id [String] carries `bulk_retry_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [BulkRetry]
+      def get(bulk_retry_id, application_id)
+        read_answer(
+          @transport.request(
+            "GET",
+            Runtime.path(
+              "/api/v1/bulk_retries/{bulk_retry_id}",
+              "bulk_retry_id" => bulk_retry_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          ),
+          BulkRetry.method(:from_json)
+        )
+      end
+
+      # List bulk retries
+      #
+      # @param application_id [String] carries `application_id`.
+      # @return [Array<BulkRetry>]
+      def list(application_id)
+        read_answer(
+          @transport.request(
+            "GET",
+            "/api/v1/bulk_retries/",
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          ),
+          Runtime.list(BulkRetry.method(:from_json))
+        )
+      end
+
+      private
+
+      # Raise what the API reported, or read back the value it answered.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @param reader [#call] what turns that body into the value the API declares
+      # @return [Object]
+      def read_answer(answered, reader)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        reader.call(Runtime.decode_payload(payload))
+      end
+    end
+
+    # What the API declares under `clientCertificates`, issued through the transport it is handed.
+    class ClientCertificatesApi
+      # @param transport [Object] what one request is issued through
+      def initialize(transport)
+        @transport = transport
+      end
+
+      # Remove the client certificate of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [void]
+      def delete(subscription_id, application_id)
+        check_answer(
+          @transport.request(
+            "DELETE",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/client_certificate",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          )
+        )
+      end
+
+      # Get the client certificate of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [ClientCertificate]
+      def get(subscription_id, application_id)
+        read_answer(
+          @transport.request(
+            "GET",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/client_certificate",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          ),
+          ClientCertificate.method(:from_json)
+        )
+      end
+
+      # Set the client certificate of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @param body [ClientCertificatePut] what the operation reads
+      # @return [ClientCertificate]
+      def update(subscription_id, application_id, body)
+        read_answer(
+          @transport.request(
+            "PUT",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/client_certificate",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            body.to_h
+          ),
+          ClientCertificate.method(:from_json)
+        )
+      end
+
+      private
+
+      # Raise what the API reported, and answer nothing when it reported nothing.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @return [void]
+      def check_answer(answered)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        nil
+      end
+
+      # Raise what the API reported, or read back the value it answered.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @param reader [#call] what turns that body into the value the API declares
+      # @return [Object]
+      def read_answer(answered, reader)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        reader.call(Runtime.decode_payload(payload))
+      end
+    end
+
+    # What the API declares under `deadLetters`, issued through the transport it is handed.
+    class DeadLettersApi
+      # @param transport [Object] what one request is issued through
+      def initialize(transport)
+        @transport = transport
+      end
+
+      # Delete a dead letter
+      #
+      # @param dead_letter_id [String] carries `dead_letter_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [void]
+      def delete(dead_letter_id, application_id)
+        check_answer(
+          @transport.request(
+            "DELETE",
+            Runtime.path(
+              "/api/v1/dead_letters/{dead_letter_id}",
+              "dead_letter_id" => dead_letter_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          )
+        )
+      end
+
+      # Get a dead letter by its ID
+      #
+      # @param dead_letter_id [String] carries `dead_letter_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [DeadLetterDetails]
+      def get(dead_letter_id, application_id)
+        read_answer(
+          @transport.request(
+            "GET",
+            Runtime.path(
+              "/api/v1/dead_letters/{dead_letter_id}",
+              "dead_letter_id" => dead_letter_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          ),
+          DeadLetterDetails.method(:from_json)
+        )
+      end
+
+      # List dead letters
+      #
+      # @param application_id [String] carries `application_id`.
+      # @param pagination_cursor [String, nil] carries `pagination_cursor`.
+      # @param subscription_id [String, nil] carries `subscription_id`.
+      # @return [Array<DeadLetter>]
+      def list(application_id, pagination_cursor: nil, subscription_id: nil)
+        read_answer(
+          @transport.request(
+            "GET",
+            "/api/v1/dead_letters/",
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              [
+                ["pagination_cursor", pagination_cursor],
+                ["subscription_id", subscription_id]
+              ]
+            ),
+            nil
+          ),
+          Runtime.list(DeadLetter.method(:from_json))
+        )
+      end
+
+      # Purge dead letters
+      #
+      # @param body [PurgeDeadLetters] what the operation reads
+      # @return [PurgedDeadLetters]
+      def purge(body)
+        read_answer(
+          @transport.request(
+            "POST",
+            "/api/v1/dead_letters/purge",
+            [],
+            body.to_h
+          ),
+          PurgedDeadLetters.method(:from_json)
+        )
+      end
+
+      # Redeliver a dead letter
+      #
+      # @param dead_letter_id [String] carries `dead_letter_id`.
+      # @param body [RedeliverDeadLetter] what the operation reads
+      # @return [RedeliveredDeadLetter]
+      def redeliver(dead_letter_id, body)
+        read_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/dead_letters/{dead_letter_id}/redeliver",
+              "dead_letter_id" => dead_letter_id
+            ),
+            [],
+            body.to_h
+          ),
+          RedeliveredDeadLetter.method(:from_json)
+        )
+      end
+
+      # Redeliver dead letters
+      #
+      # @param body [RedeliverDeadLetters] what the operation reads
+      # @return [Array<RedeliveredDeadLetter>]
+      def redeliver_many(body)
+        read_answer(
+          @transport.request(
+            "POST",
+            "/api/v1/dead_letters/redeliver",
+            [],
+            body.to_h
+          ),
+          Runtime.list(RedeliveredDeadLetter.method(:from_json))
+        )
+      end
+
+      private
+
+      # Raise what the API reported, and answer nothing when it reported nothing.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @return [void]
+      def check_answer(answered)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        nil
+      end
+
+      # Raise what the API reported, or read back the value it answered.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @param reader [#call] what turns that body into the value the API declares
+      # @return [Object]
+      def read_answer(answered, reader)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        reader.call(Runtime.decode_payload(payload))
+      end
+    end
+
     # What the API declares under `errors`, issued through the transport it is handed.
     class ErrorsApi
       # @param transport [Object] what one request is issued through
@@ -289,19 +677,39 @@ module Hook0
         @transport = transport
       end
 
-      # Create a new event type
+      # Create a new event type
+      #
+      # @param body [EventTypePost] what the operation reads
+      # @return [EventType]
+      def create(body)
+        read_answer(
+          @transport.request(
+            "POST",
+            "/api/v1/event_types/",
+            [],
+            body.to_h
+          ),
+          EventType.method(:from_json)
+        )
+      end
+
+      # Create a new event type version
       #
-      # @param body [EventTypePost] what the operation reads
-      # @return [EventType]
-      def create(body)
+      # @param event_type_name [String] carries `event_type_name`.
+      # @param body [EventTypeVersionPost] what the operation reads
+      # @return [EventTypeVersion]
+      def create_version(event_type_name, body)
         read_answer(
           @transport.request(
             "POST",
-            "/api/v1/event_types/",
+            Runtime.path(
+              "/api/v1/event_types/{event_type_name}/versions",
+              "event_type_name" => event_type_name
+            ),
             [],
             body.to_h
           ),
-          EventType.method(:from_json)
+          EventTypeVersion.method(:from_json)
         )
       end
 
@@ -329,6 +737,26 @@ module Hook0
         )
       end
 
+      # Deprecate an event type version
+      #
+      # @param event_type_name [String] carries `event_type_name`.
+      # @param body [EventTypeVersionDeprecate] what the operation reads
+      # @return [EventTypeVersion]
+      def deprecate_version(event_type_name, body)
+        read_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/event_types/{event_type_name}/deprecate",
+              "event_type_name" => event_type_name
+            ),
+            [],
+            body.to_h
+          ),
+          EventTypeVersion.method(:from_json)
+        )
+      end
+
       # Get an event type by its name
       #
       # @param event_type_name [String] carries `event_type_name`.
@@ -375,6 +803,26 @@ module Hook0
         )
       end
 
+      # Update an event type
+      #
+      # @param event_type_name [String] carries `event_type_name`.
+      # @param body [EventTypePut] what the operation reads
+      # @return [EventType]
+      def update(event_type_name, body)
+        read_answer(
+          @transport.request(
+            "PUT",
+            Runtime.path(
+              "/api/v1/event_types/{event_type_name}",
+              "event_type_name" => event_type_name
+            ),
+            [],
+            body.to_h
+          ),
+          EventType.method(:from_json)
+        )
+      end
+
       private
 
       # Raise what the API reported, and answer nothing when it reported nothing.
@@ -406,6 +854,25 @@ module Hook0
         @transport = transport
       end
 
+      # Cancel a scheduled event
+      #
+      # @param event_id [String] carries `event_id`.
+      # @param body [CancelEvent] what the operation reads
+      # @return [void]
+      def cancel(event_id, body)
+        check_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/events/{event_id}/cancel",
+              "event_id" => event_id
+            ),
+            [],
+            body.to_h
+          )
+        )
+      end
+
       # Get an event by its ID
       #
       # @param event_id [String] carries `event_id`.
@@ -447,11 +914,29 @@ module Hook0
         )
       end
 
+      # Ingest a batch of events
+      #
+      # @param body [EventsBatchPost] what the operation reads
+      # @return [IngestedEventsBatch]
+      def ingest_batch(body)
+        read_answer(
+          @transport.request(
+            "POST",
+            "/api/v1/events/batch",
+            [],
+            body.to_h
+          ),
+          IngestedEventsBatch.method(:from_json)
+        )
+      end
+
       # List latest events
       #
       # @param application_id [String] carries `application_id`.
+      # @param scheduled [Boolean, nil] carries `scheduled`: Only list the events scheduled for a later delivery that
+      #   was not cancelled, soonest first
       # @return [Array<Event>]
-      def list(application_id)
+      def list(application_id, scheduled: nil)
         read_answer(
           @transport.request(
             "GET",
@@ -460,7 +945,9 @@ module Hook0
               [
                 ["application_id", application_id]
               ],
-              []
+              [
+                ["scheduled", scheduled]
+              ]
             ),
             nil
           ),
@@ -564,50 +1051,250 @@ module Hook0
                 ["to", to]
               ]
             ),
-            nil
+            nil
+          ),
+          Runtime.list(EventsPerDayEntry.method(:from_json))
+        )
+      end
+
+      private
+
+      # Raise what the API reported, or read back the value it answered.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @param reader [#call] what turns that body into the value the API declares
+      # @return [Object]
+      def read_answer(answered, reader)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        reader.call(Runtime.decode_payload(payload))
+      end
+    end
+
+    # What the API declares under `inbox`, issued through the transport it is handed.
+    class InboxApi
+      # @param transport [Object] what one request is issued through
+      def initialize(transport)
+        @transport = transport
+      end
+
+      # Acknowledge events pulled from an inbox
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param body [InboxAck] what the operation reads
+      # @return [InboxSettlement]
+      def ack(subscription_id, body)
+        read_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/inbox/{subscription_id}/ack",
+              "subscription_id" => subscription_id
+            ),
+            [],
+            body.to_h
+          ),
+          InboxSettlement.method(:from_json)
+        )
+      end
+
+      # Reject events pulled from an inbox
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param body [InboxNack] what the operation reads
+      # @return [InboxSettlement]
+      def nack(subscription_id, body)
+        read_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/inbox/{subscription_id}/nack",
+              "subscription_id" => subscription_id
+            ),
+            [],
+            body.to_h
+          ),
+          InboxSettlement.method(:from_json)
+        )
+      end
+
+      # Pull events from an inbox
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @param cursor [String, nil] carries `cursor`: Only lease events after this cursor, returned by a previous pull
+      # @param limit [Integer, nil] carries `limit`: Maximum number of events to lease (10 by default)
+      # @param visibility_timeout_s [Integer, nil] carries `visibility_timeout_s`: For how long in seconds the leased
+      #   events are not offered again (30 by default)
+      # @return [Inbox]
+      def pull(subscription_id, application_id, cursor: nil, limit: nil, visibility_timeout_s: nil)
+        read_answer(
+          @transport.request(
+            "GET",
+            Runtime.path(
+              "/api/v1/inbox/{subscription_id}",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              [
+                ["cursor", cursor],
+                ["limit", limit],
+                ["visibility_timeout_s", visibility_timeout_s]
+              ]
+            ),
+            nil
+          ),
+          Inbox.method(:from_json)
+        )
+      end
+
+      private
+
+      # Raise what the API reported, or read back the value it answered.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @param reader [#call] what turns that body into the value the API declares
+      # @return [Object]
+      def read_answer(answered, reader)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        reader.call(Runtime.decode_payload(payload))
+      end
+    end
+
+    # What the API declares under `instance`, issued through the transport it is handed.
+    class InstanceApi
+      # @param transport [Object] what one request is issued through
+      def initialize(transport)
+        @transport = transport
+      end
+
+      # Get instance configuration
+      #
+      # @return [InstanceConfig]
+      def get
+        read_answer(
+          @transport.request(
+            "GET",
+            "/api/v1/instance/",
+            [],
+            nil
+          ),
+          InstanceConfig.method(:from_json)
+        )
+      end
+
+      private
+
+      # Raise what the API reported, or read back the value it answered.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @param reader [#call] what turns that body into the value the API declares
+      # @return [Object]
+      def read_answer(answered, reader)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        reader.call(Runtime.decode_payload(payload))
+      end
+    end
+
+    # What the API declares under `oauth2ClientCredentials`, issued through the transport it is handed.
+    class Oauth2ClientCredentialsApi
+      # @param transport [Object] what one request is issued through
+      def initialize(transport)
+        @transport = transport
+      end
+
+      # Remove the OAuth2 client credentials of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [void]
+      def delete(subscription_id, application_id)
+        check_answer(
+          @transport.request(
+            "DELETE",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          )
+        )
+      end
+
+      # Get the OAuth2 client credentials of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [Oauth2ClientCredentials]
+      def get(subscription_id, application_id)
+        read_answer(
+          @transport.request(
+            "GET",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          ),
+          Oauth2ClientCredentials.method(:from_json)
+        )
+      end
+
+      # Set the OAuth2 client credentials of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @param body [Oauth2ClientCredentialsPut] what the operation reads
+      # @return [Oauth2ClientCredentials]
+      def update(subscription_id, application_id, body)
+        read_answer(
+          @transport.request(
+            "PUT",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            body.to_h
           ),
-          Runtime.list(EventsPerDayEntry.method(:from_json))
+          Oauth2ClientCredentials.method(:from_json)
         )
       end
 
       private
 
-      # Raise what the API reported, or read back the value it answered.
+      # Raise what the API reported, and answer nothing when it reported nothing.
       #
       # @param answered [Array] the status and the body the transport answered
-      # @param reader [#call] what turns that body into the value the API declares
-      # @return [Object]
-      def read_answer(answered, reader)
+      # @return [void]
+      def check_answer(answered)
         status, payload = answered
         Generated.raise_for_status(status, payload)
-        reader.call(Runtime.decode_payload(payload))
-      end
-    end
-
-    # What the API declares under `instance`, issued through the transport it is handed.
-    class InstanceApi
-      # @param transport [Object] what one request is issued through
-      def initialize(transport)
-        @transport = transport
-      end
-
-      # Get instance configuration
-      #
-      # @return [InstanceConfig]
-      def get
-        read_answer(
-          @transport.request(
-            "GET",
-            "/api/v1/instance/",
-            [],
-            nil
-          ),
-          InstanceConfig.method(:from_json)
-        )
+        nil
       end
 
-      private
-
       # Raise what the API reported, or read back the value it answered.
       #
       # @param answered [Array] the status and the body the transport answered
@@ -766,6 +1453,26 @@ module Hook0
         )
       end
 
+      # Retry a request attempt
+      #
+      # @param request_attempt_id [String] carries `request_attempt_id`.
+      # @param body [RetryRequestAttempt] what the operation reads
+      # @return [RequestAttempt]
+      def retry(request_attempt_id, body)
+        read_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/request_attempts/{request_attempt_id}/retry",
+              "request_attempt_id" => request_attempt_id
+            ),
+            [],
+            body.to_h
+          ),
+          RequestAttempt.method(:from_json)
+        )
+      end
+
       private
 
       # Raise what the API reported, or read back the value it answered.
@@ -826,6 +1533,136 @@ module Hook0
       end
     end
 
+    # What the API declares under `secretRotations`, issued through the transport it is handed.
+    class SecretRotationsApi
+      # @param transport [Object] what one request is issued through
+      def initialize(transport)
+        @transport = transport
+      end
+
+      # Rotate the secret of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @param body [SecretRotationPost] what the operation reads
+      # @return [SecretRotation]
+      def create(subscription_id, application_id, body)
+        read_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/secret_rotation",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            body.to_h
+          ),
+          SecretRotation.method(:from_json)
+        )
+      end
+
+      # Finalize the secret rotation of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [void]
+      def finalize(subscription_id, application_id)
+        check_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          )
+        )
+      end
+
+      # Get the secret rotation of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [SecretRotation]
+      def get(subscription_id, application_id)
+        read_answer(
+          @transport.request(
+            "GET",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/secret_rotation",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          ),
+          SecretRotation.method(:from_json)
+        )
+      end
+
+      # Roll back the secret rotation of a subscription
+      #
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [void]
+      def rollback(subscription_id, application_id)
+        check_answer(
+          @transport.request(
+            "POST",
+            Runtime.path(
+              "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback",
+              "subscription_id" => subscription_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          )
+        )
+      end
+
+      private
+
+      # Raise what the API reported, and answer nothing when it reported nothing.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @return [void]
+      def check_answer(answered)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        nil
+      end
+
+      # Raise what the API reported, or read back the value it answered.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @param reader [#call] what turns that body into the value the API declares
+      # @return [Object]
+      def read_answer(answered, reader)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        reader.call(Runtime.decode_payload(payload))
+      end
+    end
+
     # What the API declares under `serviceToken`, issued through the transport it is handed.
     class ServiceTokenApi
       # @param transport [Object] what one request is issued through
@@ -963,6 +1800,117 @@ module Hook0
       end
     end
 
+    # What the API declares under `signingKeys`, issued through the transport it is handed.
+    class SigningKeysApi
+      # @param transport [Object] what one request is issued through
+      def initialize(transport)
+        @transport = transport
+      end
+
+      # Create a signing key
+      #
+      # @param body [SigningKeyPost] what the operation reads
+      # @return [SigningKey]
+      def create(body)
+        read_answer(
+          @transport.request(
+            "POST",
+            "/api/v1/signing_keys/",
+            [],
+            body.to_h
+          ),
+          SigningKey.method(:from_json)
+        )
+      end
+
+      # Retire a signing key
+      #
+      # @param signing_key_id [String] carries `signing_key_id`.
+      # @param application_id [String] carries `application_id`.
+      # @return [void]
+      def delete(signing_key_id, application_id)
+        check_answer(
+          @transport.request(
+            "DELETE",
+            Runtime.path(
+              "/api/v1/signing_keys/{signing_key_id}",
+              "signing_key_id" => signing_key_id
+            ),
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          )
+        )
+      end
+
+      # Get the public keys of an application
+      #
+      # @param application_id [String] carries `application_id`.
+      # @return [JsonWebKeySet]
+      def jwks(application_id)
+        read_answer(
+          @transport.request(
+            "GET",
+            Runtime.path(
+              "/api/v1/jwks/{application_id}",
+              "application_id" => application_id
+            ),
+            [],
+            nil
+          ),
+          JsonWebKeySet.method(:from_json)
+        )
+      end
+
+      # List signing keys
+      #
+      # @param application_id [String] carries `application_id`.
+      # @return [Array<SigningKey>]
+      def list(application_id)
+        read_answer(
+          @transport.request(
+            "GET",
+            "/api/v1/signing_keys/",
+            Runtime.query(
+              [
+                ["application_id", application_id]
+              ],
+              []
+            ),
+            nil
+          ),
+          Runtime.list(SigningKey.method(:from_json))
+        )
+      end
+
+      private
+
+      # Raise what the API reported, and answer nothing when it reported nothing.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @return [void]
+      def check_answer(answered)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        nil
+      end
+
+      # Raise what the API reported, or read back the value it answered.
+      #
+      # @param answered [Array] the status and the body the transport answered
+      # @param reader [#call] what turns that body into the value the API declares
+      # @return [Object]
+      def read_answer(answered, reader)
+        status, payload = answered
+        Generated.raise_for_status(status, payload)
+        reader.call(Runtime.decode_payload(payload))
+      end
+    end
+
     # What the API declares under `subscriptions`, issued through the transport it is handed.
     class SubscriptionsApi
       # @param transport [Object] what one request is issued through
@@ -58,9 +58,21 @@ module Hook0
     # The API reported `AuthNoAuthorizationHeader`.
     class AuthNoAuthorizationHeaderError < ProblemError; end
 
+    # The API reported `BulkRetryAlreadyRunning`.
+    class BulkRetryAlreadyRunningError < ProblemError; end
+
+    # The API reported `BulkRetryFinished`.
+    class BulkRetryFinishedError < ProblemError; end
+
+    # The API reported `ClientCertificatesDisabled`.
+    class ClientCertificatesDisabledError < ProblemError; end
+
     # The API reported `EventAlreadyIngested`.
     class EventAlreadyIngestedError < ProblemError; end
 
+    # The API reported `EventDeliveryBeyondRetention`.
+    class EventDeliveryBeyondRetentionError < ProblemError; end
+
     # The API reported `EventInvalidBase64Payload`.
     class EventInvalidBase64PayloadError < ProblemError; end
 
@@ -70,18 +82,36 @@ module Hook0
     # The API reported `EventInvalidPayloadContentType`.
     class EventInvalidPayloadContentTypeError < ProblemError; end
 
+    # The API reported `EventNotScheduled`.
+    class EventNotScheduledError < ProblemError; end
+
+    # The API reported `EventPayloadSchemaViolation`.
+    class EventPayloadSchemaViolationError < ProblemError; end
+
     # The API reported `EventTypeAlreadyExist`.
     class EventTypeAlreadyExistError < ProblemError; end
 
     # The API reported `EventTypeDoesNotExist`.
     class EventTypeDoesNotExistError < ProblemError; end
 
+    # The API reported `EventTypeVersionAlreadyExist`.
+    class EventTypeVersionAlreadyExistError < ProblemError; end
+
+    # The API reported `EventTypeVersionDoesNotExist`.
+    class EventTypeVersionDoesNotExistError < ProblemError; end
+
+    # The API reported `EventTypeVersionSunset`.
+    class EventTypeVersionSunsetError < ProblemError; end
+
     # The API reported `Forbidden`.
     class ForbiddenError < ProblemError; end
 
     # The API reported `InternalServerError`.
     class InternalServerErrorError < ProblemError; end
 
+    # The API reported `InvalidClientCertificate`.
+    class InvalidClientCertificateError < ProblemError; end
+
     # The API reported `InvalidDateRange`.
     class InvalidDateRangeError < ProblemError; end
 
@@ -133,9 +163,30 @@ module Hook0
     # The API reported `RegistrationDisabled`.
     class RegistrationDisabledError < ProblemError; end
 
+    # The API reported `RequestAttemptNotFailed`.
+    class RequestAttemptNotFailedError < ProblemError; end
+
+    # The API reported `RequestAttemptRetryPending`.
+    class RequestAttemptRetryPendingError < ProblemError; end
+
+    # The API reported `RetryPolicyOverLimit`.
+    class RetryPolicyOverLimitError < ProblemError; end
+
+    # The API reported `SecretRotationInProgress`.
+    class SecretRotationInProgressError < ProblemError; end
+
     # The API reported `ServiceUnavailable`.
     class ServiceUnavailableError < ProblemError; end
 
+    # The API reported `SigningKeysDisabled`.
+    class SigningKeysDisabledError < ProblemError; end
+
+    # The API reported `SubscriptionDisabled`.
+    class SubscriptionDisabledError < ProblemError; end
+
+    # The API reported `SubscriptionNotInbox`.
+    class SubscriptionNotInboxError < ProblemError; end
+
     # The API reported `TooManyApplicationsPerOrganization`.
     class TooManyApplicationsPerOrganizationError < ProblemError; end
 
@@ -174,14 +225,24 @@ module Hook0
       ProblemId::AUTH_INVALID_AUTHORIZATION_HEADER => AuthInvalidAuthorizationHeaderError,
       ProblemId::AUTH_INVALID_BISCUIT => AuthInvalidBiscuitError,
       ProblemId::AUTH_NO_AUTHORIZATION_HEADER => AuthNoAuthorizationHeaderError,
+      ProblemId::BULK_RETRY_ALREADY_RUNNING => BulkRetryAlreadyRunningError,
+      ProblemId::BULK_RETRY_FINISHED => BulkRetryFinishedError,
+      ProblemId::CLIENT_CERTIFICATES_DISABLED => ClientCertificatesDisabledError,
       ProblemId::EVENT_ALREADY_INGESTED => EventAlreadyIngestedError,
+      ProblemId::EVENT_DELIVERY_BEYOND_RETENTION => EventDeliveryBeyondRetentionError,
       ProblemId::EVENT_INVALID_BASE64_PAYLOAD => EventInvalidBase64PayloadError,
       ProblemId::EVENT_INVALID_JSON_PAYLOAD => EventInvalidJsonPayloadError,
       ProblemId::EVENT_INVALID_PAYLOAD_CONTENT_TYPE => EventInvalidPayloadContentTypeError,
+      ProblemId::EVENT_NOT_SCHEDULED => EventNotScheduledError,
+      ProblemId::EVENT_PAYLOAD_SCHEMA_VIOLATION => EventPayloadSchemaViolationError,
       ProblemId::EVENT_TYPE_ALREADY_EXIST => EventTypeAlreadyExistError,
       ProblemId::EVENT_TYPE_DOES_NOT_EXIST => EventTypeDoesNotExistError,
+      ProblemId::EVENT_TYPE_VERSION_ALREADY_EXIST => EventTypeVersionAlreadyExistError,
+      ProblemId::EVENT_TYPE_VERSION_DOES_NOT_EXIST => EventTypeVersionDoesNotExistError,
+      ProblemId::EVENT_TYPE_VERSION_SUNSET => EventTypeVersionSunsetError,
       ProblemId::FORBIDDEN => ForbiddenError,
       ProblemId::INTERNAL_SERVER_ERROR => InternalServerErrorError,
+      ProblemId::INVALID_CLIENT_CERTIFICATE => InvalidClientCertificateError,
       ProblemId::INVALID_DATE_RANGE => InvalidDateRangeError,
       ProblemId::INVALID_ROLE => InvalidRoleError,
       ProblemId::INVITED_USER_ALREADY_IN_ORGANIZATION => InvitedUserAlreadyInOrganizationError,
@@ -199,7 +260,14 @@ module Hook0
       ProblemId::PASSWORD_TOO_SHORT => PasswordTooShortError,
       ProblemId::RATE_LIMITED => RateLimitedError,
       ProblemId::REGISTRATION_DISABLED => RegistrationDisabledError,
+      ProblemId::REQUEST_ATTEMPT_NOT_FAILED => RequestAttemptNotFailedError,
+      ProblemId::REQUEST_ATTEMPT_RETRY_PENDING => RequestAttemptRetryPendingError,
+      ProblemId::RETRY_POLICY_OVER_LIMIT => RetryPolicyOverLimitError,
+      ProblemId::SECRET_ROTATION_IN_PROGRESS => SecretRotationInProgressError,
       ProblemId::SERVICE_UNAVAILABLE => ServiceUnavailableError,
+      ProblemId::SIGNING_KEYS_DISABLED => SigningKeysDisabledError,
+      ProblemId::SUBSCRIPTION_DISABLED => SubscriptionDisabledError,
+      ProblemId::SUBSCRIPTION_NOT_INBOX => SubscriptionNotInboxError,
       ProblemId::TOO_MANY_APPLICATIONS_PER_ORGANIZATION => TooManyApplicationsPerOrganizationError,
       ProblemId::TOO_MANY_EVENT_TYPES_PER_APPLICATION => TooManyEventTypesPerApplicationError,
       ProblemId::TOO_MANY_EVENTS_TODAY => TooManyEventsTodayError,
@@ -61,6 +61,295 @@ module Hook0
       end
     end
 
+    # One of the values the API answers with.
+    module BulkRetryPostStatus
+      FAILED = "failed"
+      SUCCESSFUL = "successful"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        FAILED,
+        SUCCESSFUL
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module BulkRetryStatus
+      FAILED = "failed"
+      SUCCESSFUL = "successful"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        FAILED,
+        SUCCESSFUL
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module DeadLetterDetailsDeadLetterReason
+      RETRIES_EXHAUSTED = "retries_exhausted"
+      SIGNATURE_FAILED = "signature_failed"
+      TRANSFORM_FAILED = "transform_failed"
+      PERMANENT_FAILURE = "permanent_failure"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        RETRIES_EXHAUSTED,
+        SIGNATURE_FAILED,
+        TRANSFORM_FAILED,
+        PERMANENT_FAILURE
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module DeadLetterReason
+      RETRIES_EXHAUSTED = "retries_exhausted"
+      SIGNATURE_FAILED = "signature_failed"
+      TRANSFORM_FAILED = "transform_failed"
+      PERMANENT_FAILURE = "permanent_failure"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        RETRIES_EXHAUSTED,
+        SIGNATURE_FAILED,
+        TRANSFORM_FAILED,
+        PERMANENT_FAILURE
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module EventTypePayloadSchemaMode
+      ENFORCE = "enforce"
+      WARN = "warn"
+      OFF = "off"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        ENFORCE,
+        WARN,
+        OFF
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module EventTypePostPayloadSchemaMode
+      ENFORCE = "enforce"
+      WARN = "warn"
+      OFF = "off"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        ENFORCE,
+        WARN,
+        OFF
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module EventTypePutPayloadSchemaMode
+      ENFORCE = "enforce"
+      WARN = "warn"
+      OFF = "off"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        ENFORCE,
+        WARN,
+        OFF
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module IngestedEventsBatchEventsProblemId
+      ORGANIZATION_NAME_MISSING = "OrganizationNameMissing"
+      USER_ALREADY_EXIST = "UserAlreadyExist"
+      REGISTRATION_DISABLED = "RegistrationDisabled"
+      PASSWORD_TOO_SHORT = "PasswordTooShort"
+      PASSWORD_TOO_LONG = "PasswordTooLong"
+      PASSWORD_SIMILAR_TO_EMAIL = "PasswordSimilarToEmail"
+      PASSWORD_SIMILAR_TO_NAME = "PasswordSimilarToName"
+      PASSWORD_TOO_COMMON = "PasswordTooCommon"
+      PASSWORD_NOT_DIVERSE_ENOUGH = "PasswordNotDiverseEnough"
+      ORGANIZATION_IS_NOT_EMPTY = "OrganizationIsNotEmpty"
+      INVITED_USER_DOES_NOT_EXIST = "InvitedUserDoesNotExist"
+      INVITED_USER_ALREADY_IN_ORGANIZATION = "InvitedUserAlreadyInOrganization"
+      APPLICATION_NAME_MISSING = "ApplicationNameMissing"
+      INVALID_ROLE = "InvalidRole"
+      EVENT_TYPE_ALREADY_EXIST = "EventTypeAlreadyExist"
+      EVENT_TYPE_DOES_NOT_EXIST = "EventTypeDoesNotExist"
+      EVENT_TYPE_VERSION_ALREADY_EXIST = "EventTypeVersionAlreadyExist"
+      EVENT_TYPE_VERSION_DOES_NOT_EXIST = "EventTypeVersionDoesNotExist"
+      EVENT_TYPE_VERSION_SUNSET = "EventTypeVersionSunset"
+      UNAUTHORIZED_WORKERS = "UnauthorizedWorkers"
+      EVENT_ALREADY_INGESTED = "EventAlreadyIngested"
+      EVENT_INVALID_PAYLOAD_CONTENT_TYPE = "EventInvalidPayloadContentType"
+      EVENT_INVALID_BASE64_PAYLOAD = "EventInvalidBase64Payload"
+      EVENT_INVALID_JSON_PAYLOAD = "EventInvalidJsonPayload"
+      EVENT_PAYLOAD_SCHEMA_VIOLATION = "EventPayloadSchemaViolation"
+      EVENT_DELIVERY_BEYOND_RETENTION = "EventDeliveryBeyondRetention"
+      EVENT_NOT_SCHEDULED = "EventNotScheduled"
+      LABELS_AMBIGUITY = "LabelsAmbiguity"
+      INVALID_DATE_RANGE = "InvalidDateRange"
+      REQUEST_ATTEMPT_NOT_FAILED = "RequestAttemptNotFailed"
+      REQUEST_ATTEMPT_RETRY_PENDING = "RequestAttemptRetryPending"
+      SUBSCRIPTION_DISABLED = "SubscriptionDisabled"
+      SUBSCRIPTION_NOT_INBOX = "SubscriptionNotInbox"
+      SECRET_ROTATION_IN_PROGRESS = "SecretRotationInProgress"
+      BULK_RETRY_ALREADY_RUNNING = "BulkRetryAlreadyRunning"
+      BULK_RETRY_FINISHED = "BulkRetryFinished"
+      RETRY_POLICY_OVER_LIMIT = "RetryPolicyOverLimit"
+      CLIENT_CERTIFICATES_DISABLED = "ClientCertificatesDisabled"
+      INVALID_CLIENT_CERTIFICATE = "InvalidClientCertificate"
+      SIGNING_KEYS_DISABLED = "SigningKeysDisabled"
+      AUTH_NO_AUTHORIZATION_HEADER = "AuthNoAuthorizationHeader"
+      AUTH_INVALID_AUTHORIZATION_HEADER = "AuthInvalidAuthorizationHeader"
+      AUTH_APPLICATION_SECRET_LOOKUP_ERROR = "AuthApplicationSecretLookupError"
+      AUTH_INVALID_APPLICATION_SECRET = "AuthInvalidApplicationSecret"
+      AUTH_BISCUIT_LOOKUP_ERROR = "AuthBiscuitLookupError"
+      AUTH_INVALID_BISCUIT = "AuthInvalidBiscuit"
+      AUTH_FAILED_LOGIN = "AuthFailedLogin"
+      AUTH_EMAIL_NOT_VERIFIED = "AuthEmailNotVerified"
+      AUTH_EMAIL_ALREADY_VERIFIED = "AuthEmailAlreadyVerified"
+      AUTH_FAILED_REFRESH = "AuthFailedRefresh"
+      AUTH_EMAIL_EXPIRED = "AuthEmailExpired"
+      TOO_MANY_MEMBERS_PER_ORGANIZATION = "TooManyMembersPerOrganization"
+      TOO_MANY_APPLICATIONS_PER_ORGANIZATION = "TooManyApplicationsPerOrganization"
+      TOO_MANY_EVENTS_TODAY = "TooManyEventsToday"
+      TOO_MANY_SUBSCRIPTIONS_PER_APPLICATION = "TooManySubscriptionsPerApplication"
+      TOO_MANY_EVENT_TYPES_PER_APPLICATION = "TooManyEventTypesPerApplication"
+      JSON_PAYLOAD = "JsonPayload"
+      VALIDATION = "Validation"
+      NOT_FOUND = "NotFound"
+      INTERNAL_SERVER_ERROR = "InternalServerError"
+      FORBIDDEN = "Forbidden"
+      RATE_LIMITED = "RateLimited"
+      SERVICE_UNAVAILABLE = "ServiceUnavailable"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        ORGANIZATION_NAME_MISSING,
+        USER_ALREADY_EXIST,
+        REGISTRATION_DISABLED,
+        PASSWORD_TOO_SHORT,
+        PASSWORD_TOO_LONG,
+        PASSWORD_SIMILAR_TO_EMAIL,
+        PASSWORD_SIMILAR_TO_NAME,
+        PASSWORD_TOO_COMMON,
+        PASSWORD_NOT_DIVERSE_ENOUGH,
+        ORGANIZATION_IS_NOT_EMPTY,
+        INVITED_USER_DOES_NOT_EXIST,
+        INVITED_USER_ALREADY_IN_ORGANIZATION,
+        APPLICATION_NAME_MISSING,
+        INVALID_ROLE,
+        EVENT_TYPE_ALREADY_EXIST,
+        EVENT_TYPE_DOES_NOT_EXIST,
+        EVENT_TYPE_VERSION_ALREADY_EXIST,
+        EVENT_TYPE_VERSION_DOES_NOT_EXIST,
+        EVENT_TYPE_VERSION_SUNSET,
+        UNAUTHORIZED_WORKERS,
+        EVENT_ALREADY_INGESTED,
+        EVENT_INVALID_PAYLOAD_CONTENT_TYPE,
+        EVENT_INVALID_BASE64_PAYLOAD,
+        EVENT_INVALID_JSON_PAYLOAD,
+        EVENT_PAYLOAD_SCHEMA_VIOLATION,
+        EVENT_DELIVERY_BEYOND_RETENTION,
+        EVENT_NOT_SCHEDULED,
+        LABELS_AMBIGUITY,
+        INVALID_DATE_RANGE,
+        REQUEST_ATTEMPT_NOT_FAILED,
+        REQUEST_ATTEMPT_RETRY_PENDING,
+        SUBSCRIPTION_DISABLED,
+        SUBSCRIPTION_NOT_INBOX,
+        SECRET_ROTATION_IN_PROGRESS,
+        BULK_RETRY_ALREADY_RUNNING,
+        BULK_RETRY_FINISHED,
+        RETRY_POLICY_OVER_LIMIT,
+        CLIENT_CERTIFICATES_DISABLED,
+        INVALID_CLIENT_CERTIFICATE,
+        SIGNING_KEYS_DISABLED,
+        AUTH_NO_AUTHORIZATION_HEADER,
+        AUTH_INVALID_AUTHORIZATION_HEADER,
+        AUTH_APPLICATION_SECRET_LOOKUP_ERROR,
+        AUTH_INVALID_APPLICATION_SECRET,
+        AUTH_BISCUIT_LOOKUP_ERROR,
+        AUTH_INVALID_BISCUIT,
+        AUTH_FAILED_LOGIN,
+        AUTH_EMAIL_NOT_VERIFIED,
+        AUTH_EMAIL_ALREADY_VERIFIED,
+        AUTH_FAILED_REFRESH,
+        AUTH_EMAIL_EXPIRED,
+        TOO_MANY_MEMBERS_PER_ORGANIZATION,
+        TOO_MANY_APPLICATIONS_PER_ORGANIZATION,
+        TOO_MANY_EVENTS_TODAY,
+        TOO_MANY_SUBSCRIPTIONS_PER_APPLICATION,
+        TOO_MANY_EVENT_TYPES_PER_APPLICATION,
+        JSON_PAYLOAD,
+        VALIDATION,
+        NOT_FOUND,
+        INTERNAL_SERVER_ERROR,
+        FORBIDDEN,
+        RATE_LIMITED,
+        SERVICE_UNAVAILABLE
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module IngestedEventsBatchEventsStatus
+      INGESTED = "ingested"
+      FAILED = "failed"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        INGESTED,
+        FAILED
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
     # One of the values the API answers with.
     module OrganizationInfoOnboardingStepsApplication
       TO_DO = "ToDo"
@@ -147,13 +436,30 @@ module Hook0
       INVALID_ROLE = "InvalidRole"
       EVENT_TYPE_ALREADY_EXIST = "EventTypeAlreadyExist"
       EVENT_TYPE_DOES_NOT_EXIST = "EventTypeDoesNotExist"
+      EVENT_TYPE_VERSION_ALREADY_EXIST = "EventTypeVersionAlreadyExist"
+      EVENT_TYPE_VERSION_DOES_NOT_EXIST = "EventTypeVersionDoesNotExist"
+      EVENT_TYPE_VERSION_SUNSET = "EventTypeVersionSunset"
       UNAUTHORIZED_WORKERS = "UnauthorizedWorkers"
       EVENT_ALREADY_INGESTED = "EventAlreadyIngested"
       EVENT_INVALID_PAYLOAD_CONTENT_TYPE = "EventInvalidPayloadContentType"
       EVENT_INVALID_BASE64_PAYLOAD = "EventInvalidBase64Payload"
       EVENT_INVALID_JSON_PAYLOAD = "EventInvalidJsonPayload"
+      EVENT_PAYLOAD_SCHEMA_VIOLATION = "EventPayloadSchemaViolation"
+      EVENT_DELIVERY_BEYOND_RETENTION = "EventDeliveryBeyondRetention"
+      EVENT_NOT_SCHEDULED = "EventNotScheduled"
       LABELS_AMBIGUITY = "LabelsAmbiguity"
       INVALID_DATE_RANGE = "InvalidDateRange"
+      REQUEST_ATTEMPT_NOT_FAILED = "RequestAttemptNotFailed"
+      REQUEST_ATTEMPT_RETRY_PENDING = "RequestAttemptRetryPending"
+      SUBSCRIPTION_DISABLED = "SubscriptionDisabled"
+      SUBSCRIPTION_NOT_INBOX = "SubscriptionNotInbox"
+      SECRET_ROTATION_IN_PROGRESS = "SecretRotationInProgress"
+      BULK_RETRY_ALREADY_RUNNING = "BulkRetryAlreadyRunning"
+      BULK_RETRY_FINISHED = "BulkRetryFinished"
+      RETRY_POLICY_OVER_LIMIT = "RetryPolicyOverLimit"
+      CLIENT_CERTIFICATES_DISABLED = "ClientCertificatesDisabled"
+      INVALID_CLIENT_CERTIFICATE = "InvalidClientCertificate"
+      SIGNING_KEYS_DISABLED = "SigningKeysDisabled"
       AUTH_NO_AUTHORIZATION_HEADER = "AuthNoAuthorizationHeader"
       AUTH_INVALID_AUTHORIZATION_HEADER = "AuthInvalidAuthorizationHeader"
       AUTH_APPLICATION_SECRET_LOOKUP_ERROR = "AuthApplicationSecretLookupError"
@@ -196,13 +502,30 @@ module Hook0
         INVALID_ROLE,
         EVENT_TYPE_ALREADY_EXIST,
         EVENT_TYPE_DOES_NOT_EXIST,
+        EVENT_TYPE_VERSION_ALREADY_EXIST,
+        EVENT_TYPE_VERSION_DOES_NOT_EXIST,
+        EVENT_TYPE_VERSION_SUNSET,
         UNAUTHORIZED_WORKERS,
         EVENT_ALREADY_INGESTED,
         EVENT_INVALID_PAYLOAD_CONTENT_TYPE,
         EVENT_INVALID_BASE64_PAYLOAD,
         EVENT_INVALID_JSON_PAYLOAD,
+        EVENT_PAYLOAD_SCHEMA_VIOLATION,
+        EVENT_DELIVERY_BEYOND_RETENTION,
+        EVENT_NOT_SCHEDULED,
         LABELS_AMBIGUITY,
         INVALID_DATE_RANGE,
+        REQUEST_ATTEMPT_NOT_FAILED,
+        REQUEST_ATTEMPT_RETRY_PENDING,
+        SUBSCRIPTION_DISABLED,
+        SUBSCRIPTION_NOT_INBOX,
+        SECRET_ROTATION_IN_PROGRESS,
+        BULK_RETRY_ALREADY_RUNNING,
+        BULK_RETRY_FINISHED,
+        RETRY_POLICY_OVER_LIMIT,
+        CLIENT_CERTIFICATES_DISABLED,
+        INVALID_CLIENT_CERTIFICATE,
+        SIGNING_KEYS_DISABLED,
         AUTH_NO_AUTHORIZATION_HEADER,
         AUTH_INVALID_AUTHORIZATION_HEADER,
         AUTH_APPLICATION_SECRET_LOOKUP_ERROR,
@@ -234,6 +557,27 @@ module Hook0
       end
     end
 
+    # One of the values the API answers with.
+    module RequestAttemptStatusRuleOutcome
+      SUCCESS = "success"
+      RETRYABLE = "retryable"
+      PERMANENT_FAILURE = "permanent_failure"
+      DISABLE_SUBSCRIPTION = "disable_subscription"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        SUCCESS,
+        RETRYABLE,
+        PERMANENT_FAILURE,
+        DISABLE_SUBSCRIPTION
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
     # One of the values the API answers with.
     module RequestAttemptStatusType
       WAITING = "waiting"
@@ -241,6 +585,8 @@ module Hook0
       IN_PROGRESS = "in_progress"
       SUCCESSFUL = "successful"
       FAILED = "failed"
+      EXPIRED = "expired"
+      CANCELLED = "cancelled"
 
       # Every value the API declares for this list.
       VALUES = [
@@ -248,7 +594,9 @@ module Hook0
         PENDING,
         IN_PROGRESS,
         SUCCESSFUL,
-        FAILED
+        FAILED,
+        EXPIRED,
+        CANCELLED
       ].freeze
 
       # Whether the API declares that value.
@@ -257,39 +605,157 @@ module Hook0
       end
     end
 
-    # The `Application` the API declares.
-    class Application
-      attr_reader :application_id,
-                  :name,
-                  :organization_id
+    # One of the values the API answers with.
+    module SubscriptionPostResponseRulesOutcome
+      SUCCESS = "success"
+      RETRYABLE = "retryable"
+      PERMANENT_FAILURE = "permanent_failure"
+      DISABLE_SUBSCRIPTION = "disable_subscription"
 
-      # @param application_id [String] carries `application_id`: Unique identifier of the application.
-      # @param name [String] carries `name`: Name of the application. Length: 2-50 characters.
-      # @param organization_id [String] carries `organization_id`: UUID of the organization this application belongs to.
-      def initialize(application_id:, name:, organization_id:)
-        @application_id = application_id
-        @name = name
-        @organization_id = organization_id
-        freeze
-      end
+      # Every value the API declares for this list.
+      VALUES = [
+        SUCCESS,
+        RETRYABLE,
+        PERMANENT_FAILURE,
+        DISABLE_SUBSCRIPTION
+      ].freeze
 
-      # Read one out of what the API answered.
-      #
-      # @param value [Object] the JSON document the API answered
-      # @return [Application]
-      def self.from_json(value)
-        fields = Runtime.as_fields(value, "Application")
-        new(
-          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
-          name: Runtime.read(fields, "name", Runtime::TEXT),
-          organization_id: Runtime.read(fields, "organization_id", Runtime::UUID)
-        )
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
       end
+    end
 
-      # Write one back the way the API reads it.
-      #
-      # @return [Hash{String => Object}]
-      def to_h
+    # One of the values the API answers with.
+    module SubscriptionPostSignatureScheme
+      HOOK0 = "hook0"
+      STANDARD_WEBHOOKS = "standard_webhooks"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        HOOK0,
+        STANDARD_WEBHOOKS
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module SubscriptionPostTargetType
+      HTTP = "http"
+      AMQP = "amqp"
+      SQS = "sqs"
+      INBOX = "inbox"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        HTTP,
+        AMQP,
+        SQS,
+        INBOX
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module SubscriptionResponseRulesOutcome
+      SUCCESS = "success"
+      RETRYABLE = "retryable"
+      PERMANENT_FAILURE = "permanent_failure"
+      DISABLE_SUBSCRIPTION = "disable_subscription"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        SUCCESS,
+        RETRYABLE,
+        PERMANENT_FAILURE,
+        DISABLE_SUBSCRIPTION
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module SubscriptionSignatureScheme
+      HOOK0 = "hook0"
+      STANDARD_WEBHOOKS = "standard_webhooks"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        HOOK0,
+        STANDARD_WEBHOOKS
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # One of the values the API answers with.
+    module SubscriptionTargetType
+      HTTP = "http"
+      AMQP = "amqp"
+      SQS = "sqs"
+      INBOX = "inbox"
+
+      # Every value the API declares for this list.
+      VALUES = [
+        HTTP,
+        AMQP,
+        SQS,
+        INBOX
+      ].freeze
+
+      # Whether the API declares that value.
+      def self.member?(value)
+        VALUES.include?(value)
+      end
+    end
+
+    # The `Application` the API declares.
+    class Application
+      attr_reader :application_id,
+                  :name,
+                  :organization_id
+
+      # @param application_id [String] carries `application_id`: Unique identifier of the application.
+      # @param name [String] carries `name`: Name of the application. Length: 2-50 characters.
+      # @param organization_id [String] carries `organization_id`: UUID of the organization this application belongs to.
+      def initialize(application_id:, name:, organization_id:)
+        @application_id = application_id
+        @name = name
+        @organization_id = organization_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [Application]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "Application")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          name: Runtime.read(fields, "name", Runtime::TEXT),
+          organization_id: Runtime.read(fields, "organization_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
         out = {}
         out["application_id"] = @application_id
         out["name"] = @name
@@ -715,61 +1181,97 @@ module Hook0
       end
     end
 
-    # The `Event` the API declares.
-    class Event
-      attr_reader :event_id,
-                  :event_type_name,
-                  :ip,
-                  :labels,
-                  :occurred_at,
-                  :payload_content_type,
-                  :received_at,
-                  :metadata
+    # The `BulkRetry` the API declares.
+    class BulkRetry
+      attr_reader :application_id,
+                  :bulk_retry_id,
+                  :created_at,
+                  :event_type_names,
+                  :max_created_at,
+                  :min_created_at,
+                  :processed_count,
+                  :retried_count,
+                  :status,
+                  :total_count,
+                  :updated_at,
+                  :cancelled_at,
+                  :completed_at,
+                  :event_id,
+                  :subscription_id
 
-      # @param event_id [String] carries `event_id`.
-      # @param event_type_name [String] carries `event_type_name`.
-      # @param ip [String] carries `ip`.
-      # @param labels [Object] carries `labels`.
-      # @param occurred_at [Time] carries `occurred_at`.
-      # @param payload_content_type [String] carries `payload_content_type`.
-      # @param received_at [Time] carries `received_at`.
-      # @param metadata [Object, nil] carries `metadata`.
+      # @param application_id [String] carries `application_id`.
+      # @param bulk_retry_id [String] carries `bulk_retry_id`.
+      # @param created_at [Time] carries `created_at`.
+      # @param event_type_names [Array<String>] carries `event_type_names`.
+      # @param max_created_at [Time] carries `max_created_at`.
+      # @param min_created_at [Time] carries `min_created_at`.
+      # @param processed_count [Integer] carries `processed_count`: Number of pairs handled so far
+      # @param retried_count [Integer] carries `retried_count`: Number of request attempts created so far
+      # @param status [String] carries `status`, one of `BulkRetryStatus::VALUES`.
+      # @param total_count [Integer] carries `total_count`: Number of (event, subscription) pairs that matched when the
+      #   bulk retry was created
+      # @param updated_at [Time] carries `updated_at`.
+      # @param cancelled_at [Time, nil] carries `cancelled_at`.
+      # @param completed_at [Time, nil] carries `completed_at`.
+      # @param event_id [String, nil] carries `event_id`.
+      # @param subscription_id [String, nil] carries `subscription_id`.
       def initialize(
-        event_id:,
-        event_type_name:,
-        ip:,
-        labels:,
-        occurred_at:,
-        payload_content_type:,
-        received_at:,
-        metadata: nil
+        application_id:,
+        bulk_retry_id:,
+        created_at:,
+        event_type_names:,
+        max_created_at:,
+        min_created_at:,
+        processed_count:,
+        retried_count:,
+        status:,
+        total_count:,
+        updated_at:,
+        cancelled_at: nil,
+        completed_at: nil,
+        event_id: nil,
+        subscription_id: nil
       )
+        @application_id = application_id
+        @bulk_retry_id = bulk_retry_id
+        @created_at = created_at
+        @event_type_names = event_type_names
+        @max_created_at = max_created_at
+        @min_created_at = min_created_at
+        @processed_count = processed_count
+        @retried_count = retried_count
+        @status = status
+        @total_count = total_count
+        @updated_at = updated_at
+        @cancelled_at = cancelled_at
+        @completed_at = completed_at
         @event_id = event_id
-        @event_type_name = event_type_name
-        @ip = ip
-        @labels = labels
-        @occurred_at = occurred_at
-        @payload_content_type = payload_content_type
-        @received_at = received_at
-        @metadata = metadata
+        @subscription_id = subscription_id
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [Event]
+      # @return [BulkRetry]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "Event")
+        fields = Runtime.as_fields(value, "BulkRetry")
         new(
-          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
-          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT),
-          ip: Runtime.read(fields, "ip", Runtime::TEXT),
-          labels: Runtime.read(fields, "labels", Runtime::JSON_VALUE),
-          occurred_at: Runtime.read(fields, "occurred_at", Runtime::DATE_TIME),
-          payload_content_type: Runtime.read(fields, "payload_content_type", Runtime::TEXT),
-          received_at: Runtime.read(fields, "received_at", Runtime::DATE_TIME),
-          metadata: Runtime.maybe(fields, "metadata", Runtime::JSON_VALUE)
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          bulk_retry_id: Runtime.read(fields, "bulk_retry_id", Runtime::UUID),
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          event_type_names: Runtime.read(fields, "event_type_names", Runtime.list(Runtime::TEXT)),
+          max_created_at: Runtime.read(fields, "max_created_at", Runtime::DATE_TIME),
+          min_created_at: Runtime.read(fields, "min_created_at", Runtime::DATE_TIME),
+          processed_count: Runtime.read(fields, "processed_count", Runtime::INTEGER),
+          retried_count: Runtime.read(fields, "retried_count", Runtime::INTEGER),
+          status: Runtime.read(fields, "status", Runtime.member_of(BulkRetryStatus)),
+          total_count: Runtime.read(fields, "total_count", Runtime::INTEGER),
+          updated_at: Runtime.read(fields, "updated_at", Runtime::DATE_TIME),
+          cancelled_at: Runtime.maybe(fields, "cancelled_at", Runtime::DATE_TIME),
+          completed_at: Runtime.maybe(fields, "completed_at", Runtime::DATE_TIME),
+          event_id: Runtime.maybe(fields, "event_id", Runtime::UUID),
+          subscription_id: Runtime.maybe(fields, "subscription_id", Runtime::UUID)
         )
       end
 
@@ -778,14 +1280,21 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["event_id"] = @event_id
-        out["event_type_name"] = @event_type_name
-        out["ip"] = @ip
-        out["labels"] = @labels
-        out["occurred_at"] = Runtime.moment(@occurred_at)
-        out["payload_content_type"] = @payload_content_type
-        out["received_at"] = Runtime.moment(@received_at)
-        out["metadata"] = @metadata unless @metadata.nil?
+        out["application_id"] = @application_id
+        out["bulk_retry_id"] = @bulk_retry_id
+        out["created_at"] = Runtime.moment(@created_at)
+        out["event_type_names"] = @event_type_names
+        out["max_created_at"] = Runtime.moment(@max_created_at)
+        out["min_created_at"] = Runtime.moment(@min_created_at)
+        out["processed_count"] = @processed_count
+        out["retried_count"] = @retried_count
+        out["status"] = @status
+        out["total_count"] = @total_count
+        out["updated_at"] = Runtime.moment(@updated_at)
+        out["cancelled_at"] = Runtime.moment(@cancelled_at) unless @cancelled_at.nil?
+        out["completed_at"] = Runtime.moment(@completed_at) unless @completed_at.nil?
+        out["event_id"] = @event_id unless @event_id.nil?
+        out["subscription_id"] = @subscription_id unless @subscription_id.nil?
         out
       end
 
@@ -794,7 +1303,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(Event) && to_h == other.to_h
+        other.is_a?(BulkRetry) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -806,67 +1315,24 @@ module Hook0
       end
     end
 
-    # The `EventPost` the API declares.
-    class EventPost
-      attr_reader :application_id,
-                  :event_type,
-                  :labels,
-                  :occurred_at,
-                  :payload,
-                  :payload_content_type,
-                  :event_id,
-                  :metadata
+    # The `BulkRetryDryRun` the API declares.
+    class BulkRetryDryRun
+      attr_reader :count
 
-      # @param application_id [String] carries `application_id`: UUID of the application this event belongs to.
-      # @param event_type [String] carries `event_type`: The type of event (e.g., 'user.created', 'order.completed').
-      #   Length: 1-200 characters.
-      # @param labels [Hash{String => String}] carries `labels`: Labels for event filtering and routing to
-      #   subscriptions.
-      # @param occurred_at [Time] carries `occurred_at`: Timestamp when the event occurred.
-      # @param payload [String] carries `payload`: The event payload. For binary content, use base64 encoding. Max
-      #   length: 699050 characters (512 KiB base64-encoded).
-      # @param payload_content_type [String] carries `payload_content_type`: Content type of the payload. Valid values:
-      #   text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
-      # @param event_id [String, nil] carries `event_id`: Optional unique identifier for this event (client-generated
-      #   UUID). If not provided, a UUIDv7 will be generated by the server.
-      # @param metadata [Hash{String => String}, nil] carries `metadata`: Optional metadata key-value pairs associated
-      #   with the event.
-      def initialize(
-        application_id:,
-        event_type:,
-        labels:,
-        occurred_at:,
-        payload:,
-        payload_content_type:,
-        event_id: nil,
-        metadata: nil
-      )
-        @application_id = application_id
-        @event_type = event_type
-        @labels = labels
-        @occurred_at = occurred_at
-        @payload = payload
-        @payload_content_type = payload_content_type
-        @event_id = event_id
-        @metadata = metadata
+      # @param count [Integer] carries `count`: Number of (event, subscription) pairs a bulk retry would redeliver now
+      def initialize(count:)
+        @count = count
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [EventPost]
+      # @return [BulkRetryDryRun]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "EventPost")
+        fields = Runtime.as_fields(value, "BulkRetryDryRun")
         new(
-          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
-          event_type: Runtime.read(fields, "event_type", Runtime::TEXT),
-          labels: Runtime.read(fields, "labels", Runtime.map(Runtime::TEXT)),
-          occurred_at: Runtime.read(fields, "occurred_at", Runtime::DATE_TIME),
-          payload: Runtime.read(fields, "payload", Runtime::TEXT),
-          payload_content_type: Runtime.read(fields, "payload_content_type", Runtime::TEXT),
-          event_id: Runtime.maybe(fields, "event_id", Runtime::UUID),
-          metadata: Runtime.maybe(fields, "metadata", Runtime.map(Runtime::TEXT))
+          count: Runtime.read(fields, "count", Runtime::INTEGER)
         )
       end
 
@@ -875,14 +1341,7 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["application_id"] = @application_id
-        out["event_type"] = @event_type
-        out["labels"] = @labels
-        out["occurred_at"] = Runtime.moment(@occurred_at)
-        out["payload"] = @payload
-        out["payload_content_type"] = @payload_content_type
-        out["event_id"] = @event_id unless @event_id.nil?
-        out["metadata"] = @metadata unless @metadata.nil?
+        out["count"] = @count
         out
       end
 
@@ -891,7 +1350,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(EventPost) && to_h == other.to_h
+        other.is_a?(BulkRetryDryRun) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -903,36 +1362,57 @@ module Hook0
       end
     end
 
-    # The `EventType` the API declares.
-    class EventType
-      attr_reader :event_type_name,
-                  :resource_type_name,
-                  :service_name,
-                  :verb_name
-
-      # @param event_type_name [String] carries `event_type_name`.
-      # @param resource_type_name [String] carries `resource_type_name`.
-      # @param service_name [String] carries `service_name`.
-      # @param verb_name [String] carries `verb_name`.
-      def initialize(event_type_name:, resource_type_name:, service_name:, verb_name:)
-        @event_type_name = event_type_name
-        @resource_type_name = resource_type_name
-        @service_name = service_name
-        @verb_name = verb_name
-        freeze
-      end
+    # The `BulkRetryPost` the API declares.
+    class BulkRetryPost
+      attr_reader :application_id,
+                  :event_id,
+                  :event_type_names,
+                  :max_created_at,
+                  :min_created_at,
+                  :status,
+                  :subscription_id
 
-      # Read one out of what the API answered.
-      #
-      # @param value [Object] the JSON document the API answered
-      # @return [EventType]
+      # @param application_id [String] carries `application_id`.
+      # @param event_id [String, nil] carries `event_id`.
+      # @param event_type_names [Array<String>, nil] carries `event_type_names`.
+      # @param max_created_at [Time, nil] carries `max_created_at`.
+      # @param min_created_at [Time, nil] carries `min_created_at`.
+      # @param status [String, nil] carries `status`, one of `BulkRetryPostStatus::VALUES`: Redeliver request attempts
+      #   that failed (default) or that succeeded
+      # @param subscription_id [String, nil] carries `subscription_id`.
+      def initialize(
+        application_id:,
+        event_id: nil,
+        event_type_names: nil,
+        max_created_at: nil,
+        min_created_at: nil,
+        status: nil,
+        subscription_id: nil
+      )
+        @application_id = application_id
+        @event_id = event_id
+        @event_type_names = event_type_names
+        @max_created_at = max_created_at
+        @min_created_at = min_created_at
+        @status = status
+        @subscription_id = subscription_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [BulkRetryPost]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "EventType")
+        fields = Runtime.as_fields(value, "BulkRetryPost")
         new(
-          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT),
-          resource_type_name: Runtime.read(fields, "resource_type_name", Runtime::TEXT),
-          service_name: Runtime.read(fields, "service_name", Runtime::TEXT),
-          verb_name: Runtime.read(fields, "verb_name", Runtime::TEXT)
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          event_id: Runtime.maybe(fields, "event_id", Runtime::UUID),
+          event_type_names: Runtime.maybe(fields, "event_type_names", Runtime.list(Runtime::TEXT)),
+          max_created_at: Runtime.maybe(fields, "max_created_at", Runtime::DATE_TIME),
+          min_created_at: Runtime.maybe(fields, "min_created_at", Runtime::DATE_TIME),
+          status: Runtime.maybe(fields, "status", Runtime.member_of(BulkRetryPostStatus)),
+          subscription_id: Runtime.maybe(fields, "subscription_id", Runtime::UUID)
         )
       end
 
@@ -941,10 +1421,13 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["event_type_name"] = @event_type_name
-        out["resource_type_name"] = @resource_type_name
-        out["service_name"] = @service_name
-        out["verb_name"] = @verb_name
+        out["application_id"] = @application_id
+        out["event_id"] = @event_id unless @event_id.nil?
+        out["event_type_names"] = @event_type_names unless @event_type_names.nil?
+        out["max_created_at"] = Runtime.moment(@max_created_at) unless @max_created_at.nil?
+        out["min_created_at"] = Runtime.moment(@min_created_at) unless @min_created_at.nil?
+        out["status"] = @status unless @status.nil?
+        out["subscription_id"] = @subscription_id unless @subscription_id.nil?
         out
       end
 
@@ -953,7 +1436,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(EventType) && to_h == other.to_h
+        other.is_a?(BulkRetryPost) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -965,36 +1448,24 @@ module Hook0
       end
     end
 
-    # The `EventTypePost` the API declares.
-    class EventTypePost
-      attr_reader :application_id,
-                  :resource_type,
-                  :service,
-                  :verb
+    # The `CancelEvent` the API declares.
+    class CancelEvent
+      attr_reader :application_id
 
       # @param application_id [String] carries `application_id`.
-      # @param resource_type [String] carries `resource_type`.
-      # @param service [String] carries `service`.
-      # @param verb [String] carries `verb`.
-      def initialize(application_id:, resource_type:, service:, verb:)
+      def initialize(application_id:)
         @application_id = application_id
-        @resource_type = resource_type
-        @service = service
-        @verb = verb
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [EventTypePost]
+      # @return [CancelEvent]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "EventTypePost")
+        fields = Runtime.as_fields(value, "CancelEvent")
         new(
-          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
-          resource_type: Runtime.read(fields, "resource_type", Runtime::TEXT),
-          service: Runtime.read(fields, "service", Runtime::TEXT),
-          verb: Runtime.read(fields, "verb", Runtime::TEXT)
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID)
         )
       end
 
@@ -1004,9 +1475,6 @@ module Hook0
       def to_h
         out = {}
         out["application_id"] = @application_id
-        out["resource_type"] = @resource_type
-        out["service"] = @service
-        out["verb"] = @verb
         out
       end
 
@@ -1015,7 +1483,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(EventTypePost) && to_h == other.to_h
+        other.is_a?(CancelEvent) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1027,66 +1495,62 @@ module Hook0
       end
     end
 
-    # The `EventWithPayload` the API declares.
-    class EventWithPayload
-      attr_reader :event_id,
-                  :event_type_name,
-                  :ip,
-                  :labels,
-                  :occurred_at,
-                  :payload,
-                  :payload_content_type,
-                  :received_at,
-                  :metadata
+    # The `ClientCertificate` the API declares.
+    class ClientCertificate
+      attr_reader :certificate_pem,
+                  :created_at,
+                  :fingerprint_sha256,
+                  :not_after,
+                  :not_before,
+                  :subject,
+                  :subscription_id,
+                  :updated_at
 
-      # @param event_id [String] carries `event_id`.
-      # @param event_type_name [String] carries `event_type_name`.
-      # @param ip [String] carries `ip`.
-      # @param labels [Object] carries `labels`.
-      # @param occurred_at [Time] carries `occurred_at`.
-      # @param payload [String] carries `payload`.
-      # @param payload_content_type [String] carries `payload_content_type`.
-      # @param received_at [Time] carries `received_at`.
-      # @param metadata [Object, nil] carries `metadata`.
+      # @param certificate_pem [String] carries `certificate_pem`: Certificate chain, leaf certificate first
+      # @param created_at [Time] carries `created_at`.
+      # @param fingerprint_sha256 [String] carries `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate, as
+      #   hexadecimal
+      # @param not_after [Time] carries `not_after`.
+      # @param not_before [Time] carries `not_before`.
+      # @param subject [String] carries `subject`.
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param updated_at [Time] carries `updated_at`.
       def initialize(
-        event_id:,
-        event_type_name:,
-        ip:,
-        labels:,
-        occurred_at:,
-        payload:,
-        payload_content_type:,
-        received_at:,
-        metadata: nil
+        certificate_pem:,
+        created_at:,
+        fingerprint_sha256:,
+        not_after:,
+        not_before:,
+        subject:,
+        subscription_id:,
+        updated_at:
       )
-        @event_id = event_id
-        @event_type_name = event_type_name
-        @ip = ip
-        @labels = labels
-        @occurred_at = occurred_at
-        @payload = payload
-        @payload_content_type = payload_content_type
-        @received_at = received_at
-        @metadata = metadata
+        @certificate_pem = certificate_pem
+        @created_at = created_at
+        @fingerprint_sha256 = fingerprint_sha256
+        @not_after = not_after
+        @not_before = not_before
+        @subject = subject
+        @subscription_id = subscription_id
+        @updated_at = updated_at
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [EventWithPayload]
+      # @return [ClientCertificate]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "EventWithPayload")
+        fields = Runtime.as_fields(value, "ClientCertificate")
         new(
-          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
-          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT),
-          ip: Runtime.read(fields, "ip", Runtime::TEXT),
-          labels: Runtime.read(fields, "labels", Runtime::JSON_VALUE),
-          occurred_at: Runtime.read(fields, "occurred_at", Runtime::DATE_TIME),
-          payload: Runtime.read(fields, "payload", Runtime::TEXT),
-          payload_content_type: Runtime.read(fields, "payload_content_type", Runtime::TEXT),
-          received_at: Runtime.read(fields, "received_at", Runtime::DATE_TIME),
-          metadata: Runtime.maybe(fields, "metadata", Runtime::JSON_VALUE)
+          certificate_pem: Runtime.read(fields, "certificate_pem", Runtime::TEXT),
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          fingerprint_sha256: Runtime.read(fields, "fingerprint_sha256", Runtime::TEXT),
+          not_after: Runtime.read(fields, "not_after", Runtime::DATE_TIME),
+          not_before: Runtime.read(fields, "not_before", Runtime::DATE_TIME),
+          subject: Runtime.read(fields, "subject", Runtime::TEXT),
+          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID),
+          updated_at: Runtime.read(fields, "updated_at", Runtime::DATE_TIME)
         )
       end
 
@@ -1095,15 +1559,14 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["event_id"] = @event_id
-        out["event_type_name"] = @event_type_name
-        out["ip"] = @ip
-        out["labels"] = @labels
-        out["occurred_at"] = Runtime.moment(@occurred_at)
-        out["payload"] = @payload
-        out["payload_content_type"] = @payload_content_type
-        out["received_at"] = Runtime.moment(@received_at)
-        out["metadata"] = @metadata unless @metadata.nil?
+        out["certificate_pem"] = @certificate_pem
+        out["created_at"] = Runtime.moment(@created_at)
+        out["fingerprint_sha256"] = @fingerprint_sha256
+        out["not_after"] = Runtime.moment(@not_after)
+        out["not_before"] = Runtime.moment(@not_before)
+        out["subject"] = @subject
+        out["subscription_id"] = @subscription_id
+        out["updated_at"] = Runtime.moment(@updated_at)
         out
       end
 
@@ -1112,7 +1575,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(EventWithPayload) && to_h == other.to_h
+        other.is_a?(ClientCertificate) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1124,40 +1587,29 @@ module Hook0
       end
     end
 
-    # The `EventsPerDayEntry` the API declares.
-    class EventsPerDayEntry
-      attr_reader :amount,
-                  :application_id,
-                  :application_name,
-                  :date,
-                  :is_provisional
-
-      # @param amount [Integer] carries `amount`.
-      # @param application_id [String] carries `application_id`.
-      # @param application_name [String] carries `application_name`.
-      # @param date [Date] carries `date`.
-      # @param is_provisional [Boolean] carries `is_provisional`.
-      def initialize(amount:, application_id:, application_name:, date:, is_provisional:)
-        @amount = amount
-        @application_id = application_id
-        @application_name = application_name
-        @date = date
-        @is_provisional = is_provisional
+    # The `ClientCertificatePut` the API declares.
+    class ClientCertificatePut
+      attr_reader :certificate_pem,
+                  :private_key_pem
+
+      # @param certificate_pem [String] carries `certificate_pem`: PEM-encoded certificate chain, leaf certificate first
+      # @param private_key_pem [String] carries `private_key_pem`: PEM-encoded unencrypted private key of the leaf
+      #   certificate (PKCS#8, PKCS#1 or SEC1)
+      def initialize(certificate_pem:, private_key_pem:)
+        @certificate_pem = certificate_pem
+        @private_key_pem = private_key_pem
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [EventsPerDayEntry]
+      # @return [ClientCertificatePut]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "EventsPerDayEntry")
+        fields = Runtime.as_fields(value, "ClientCertificatePut")
         new(
-          amount: Runtime.read(fields, "amount", Runtime::INTEGER),
-          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
-          application_name: Runtime.read(fields, "application_name", Runtime::TEXT),
-          date: Runtime.read(fields, "date", Runtime::DATE),
-          is_provisional: Runtime.read(fields, "is_provisional", Runtime::BOOLEAN)
+          certificate_pem: Runtime.read(fields, "certificate_pem", Runtime::TEXT),
+          private_key_pem: Runtime.read(fields, "private_key_pem", Runtime::TEXT)
         )
       end
 
@@ -1166,11 +1618,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["amount"] = @amount
-        out["application_id"] = @application_id
-        out["application_name"] = @application_name
-        out["date"] = Runtime.day(@date)
-        out["is_provisional"] = @is_provisional
+        out["certificate_pem"] = @certificate_pem
+        out["private_key_pem"] = @private_key_pem
         out
       end
 
@@ -1179,7 +1628,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(EventsPerDayEntry) && to_h == other.to_h
+        other.is_a?(ClientCertificatePut) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1191,32 +1640,67 @@ module Hook0
       end
     end
 
-    # The `IngestedEvent` the API declares.
-    class IngestedEvent
-      attr_reader :application_id,
-                  :event_id,
-                  :received_at
+    # The `DeadLetter` the API declares.
+    class DeadLetter
+      attr_reader :created_at,
+                  :dead_letter_id,
+                  :event,
+                  :reason,
+                  :request_attempt_id,
+                  :retry_count,
+                  :subscription,
+                  :http_response_status,
+                  :response_id
 
-      # @param application_id [String] carries `application_id`.
-      # @param event_id [String] carries `event_id`.
-      # @param received_at [Time] carries `received_at`.
-      def initialize(application_id:, event_id:, received_at:)
-        @application_id = application_id
-        @event_id = event_id
-        @received_at = received_at
+      # @param created_at [Time] carries `created_at`.
+      # @param dead_letter_id [String] carries `dead_letter_id`.
+      # @param event [DeadLetterEvent] carries `event`.
+      # @param reason [String] carries `reason`, one of `DeadLetterReason::VALUES`.
+      # @param request_attempt_id [String] carries `request_attempt_id`: Last request attempt of the delivery
+      # @param retry_count [Integer] carries `retry_count`.
+      # @param subscription [DeadLetterSubscription] carries `subscription`.
+      # @param http_response_status [Integer, nil] carries `http_response_status`.
+      # @param response_id [String, nil] carries `response_id`: Response to the last request attempt, if the target was
+      #   reached
+      def initialize(
+        created_at:,
+        dead_letter_id:,
+        event:,
+        reason:,
+        request_attempt_id:,
+        retry_count:,
+        subscription:,
+        http_response_status: nil,
+        response_id: nil
+      )
+        @created_at = created_at
+        @dead_letter_id = dead_letter_id
+        @event = event
+        @reason = reason
+        @request_attempt_id = request_attempt_id
+        @retry_count = retry_count
+        @subscription = subscription
+        @http_response_status = http_response_status
+        @response_id = response_id
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [IngestedEvent]
+      # @return [DeadLetter]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "IngestedEvent")
+        fields = Runtime.as_fields(value, "DeadLetter")
         new(
-          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
-          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
-          received_at: Runtime.read(fields, "received_at", Runtime::DATE_TIME)
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          dead_letter_id: Runtime.read(fields, "dead_letter_id", Runtime::UUID),
+          event: Runtime.read(fields, "event", DeadLetterEvent.method(:from_json)),
+          reason: Runtime.read(fields, "reason", Runtime.member_of(DeadLetterReason)),
+          request_attempt_id: Runtime.read(fields, "request_attempt_id", Runtime::UUID),
+          retry_count: Runtime.read(fields, "retry_count", Runtime::INTEGER),
+          subscription: Runtime.read(fields, "subscription", DeadLetterSubscription.method(:from_json)),
+          http_response_status: Runtime.maybe(fields, "http_response_status", Runtime::INTEGER),
+          response_id: Runtime.maybe(fields, "response_id", Runtime::UUID)
         )
       end
 
@@ -1225,9 +1709,15 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["application_id"] = @application_id
-        out["event_id"] = @event_id
-        out["received_at"] = Runtime.moment(@received_at)
+        out["created_at"] = Runtime.moment(@created_at)
+        out["dead_letter_id"] = @dead_letter_id
+        out["event"] = @event.to_h
+        out["reason"] = @reason
+        out["request_attempt_id"] = @request_attempt_id
+        out["retry_count"] = @retry_count
+        out["subscription"] = @subscription.to_h
+        out["http_response_status"] = @http_response_status unless @http_response_status.nil?
+        out["response_id"] = @response_id unless @response_id.nil?
         out
       end
 
@@ -1236,7 +1726,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(IngestedEvent) && to_h == other.to_h
+        other.is_a?(DeadLetter) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1248,71 +1738,29 @@ module Hook0
       end
     end
 
-    # The `InstanceConfig` the API declares.
-    class InstanceConfig
-      attr_reader :application_secret_compatibility,
-                  :auto_db_migration,
-                  :biscuit_public_key,
-                  :password_minimum_length,
-                  :quota_enforcement,
-                  :registration_disabled,
-                  :support_email_address,
-                  :cloudflare_turnstile_site_key,
-                  :formbricks,
-                  :matomo
-
-      # @param application_secret_compatibility [Boolean] carries `application_secret_compatibility`.
-      # @param auto_db_migration [Boolean] carries `auto_db_migration`.
-      # @param biscuit_public_key [String] carries `biscuit_public_key`.
-      # @param password_minimum_length [Integer] carries `password_minimum_length`.
-      # @param quota_enforcement [Boolean] carries `quota_enforcement`.
-      # @param registration_disabled [Boolean] carries `registration_disabled`.
-      # @param support_email_address [String] carries `support_email_address`.
-      # @param cloudflare_turnstile_site_key [String, nil] carries `cloudflare_turnstile_site_key`.
-      # @param formbricks [InstanceConfigFormbricks, nil] carries `formbricks`.
-      # @param matomo [InstanceConfigMatomo, nil] carries `matomo`.
-      def initialize(
-        application_secret_compatibility:,
-        auto_db_migration:,
-        biscuit_public_key:,
-        password_minimum_length:,
-        quota_enforcement:,
-        registration_disabled:,
-        support_email_address:,
-        cloudflare_turnstile_site_key: nil,
-        formbricks: nil,
-        matomo: nil
-      )
-        @application_secret_compatibility = application_secret_compatibility
-        @auto_db_migration = auto_db_migration
-        @biscuit_public_key = biscuit_public_key
-        @password_minimum_length = password_minimum_length
-        @quota_enforcement = quota_enforcement
-        @registration_disabled = registration_disabled
-        @support_email_address = support_email_address
-        @cloudflare_turnstile_site_key = cloudflare_turnstile_site_key
-        @formbricks = formbricks
-        @matomo = matomo
+    # The `DeadLetterDetails` the API declares.
+    class DeadLetterDetails
+      attr_reader :dead_letter,
+                  :last_response
+
+      # @param dead_letter [DeadLetterDetailsDeadLetter] carries `dead_letter`: Delivery an output worker gave up on
+      # @param last_response [DeadLetterDetailsLastResponse, nil] carries `last_response`: Response to the last request
+      #   attempt, if the target was reached
+      def initialize(dead_letter:, last_response: nil)
+        @dead_letter = dead_letter
+        @last_response = last_response
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [InstanceConfig]
+      # @return [DeadLetterDetails]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "InstanceConfig")
+        fields = Runtime.as_fields(value, "DeadLetterDetails")
         new(
-          application_secret_compatibility: Runtime.read(fields, "application_secret_compatibility", Runtime::BOOLEAN),
-          auto_db_migration: Runtime.read(fields, "auto_db_migration", Runtime::BOOLEAN),
-          biscuit_public_key: Runtime.read(fields, "biscuit_public_key", Runtime::TEXT),
-          password_minimum_length: Runtime.read(fields, "password_minimum_length", Runtime::INTEGER),
-          quota_enforcement: Runtime.read(fields, "quota_enforcement", Runtime::BOOLEAN),
-          registration_disabled: Runtime.read(fields, "registration_disabled", Runtime::BOOLEAN),
-          support_email_address: Runtime.read(fields, "support_email_address", Runtime::TEXT),
-          cloudflare_turnstile_site_key: Runtime.maybe(fields, "cloudflare_turnstile_site_key", Runtime::TEXT),
-          formbricks: Runtime.maybe(fields, "formbricks", InstanceConfigFormbricks.method(:from_json)),
-          matomo: Runtime.maybe(fields, "matomo", InstanceConfigMatomo.method(:from_json))
+          dead_letter: Runtime.read(fields, "dead_letter", DeadLetterDetailsDeadLetter.method(:from_json)),
+          last_response: Runtime.maybe(fields, "last_response", DeadLetterDetailsLastResponse.method(:from_json))
         )
       end
 
@@ -1321,16 +1769,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["application_secret_compatibility"] = @application_secret_compatibility
-        out["auto_db_migration"] = @auto_db_migration
-        out["biscuit_public_key"] = @biscuit_public_key
-        out["password_minimum_length"] = @password_minimum_length
-        out["quota_enforcement"] = @quota_enforcement
-        out["registration_disabled"] = @registration_disabled
-        out["support_email_address"] = @support_email_address
-        out["cloudflare_turnstile_site_key"] = @cloudflare_turnstile_site_key unless @cloudflare_turnstile_site_key.nil?
-        out["formbricks"] = @formbricks.to_h unless @formbricks.nil?
-        out["matomo"] = @matomo.to_h unless @matomo.nil?
+        out["dead_letter"] = @dead_letter.to_h
+        out["last_response"] = @last_response.to_h unless @last_response.nil?
         out
       end
 
@@ -1339,7 +1779,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(InstanceConfig) && to_h == other.to_h
+        other.is_a?(DeadLetterDetails) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1351,28 +1791,71 @@ module Hook0
       end
     end
 
-    # The `InstanceConfigFormbricks` the API declares.
-    class InstanceConfigFormbricks
-      attr_reader :api_host,
-                  :environment_id
+    # The `DeadLetterDetailsDeadLetter` the API declares.
+    class DeadLetterDetailsDeadLetter
+      attr_reader :created_at,
+                  :dead_letter_id,
+                  :event,
+                  :reason,
+                  :request_attempt_id,
+                  :retry_count,
+                  :subscription,
+                  :http_response_status,
+                  :response_id
 
-      # @param api_host [String] carries `api_host`.
-      # @param environment_id [String] carries `environment_id`.
-      def initialize(api_host:, environment_id:)
-        @api_host = api_host
-        @environment_id = environment_id
+      # @param created_at [Time] carries `created_at`.
+      # @param dead_letter_id [String] carries `dead_letter_id`.
+      # @param event [DeadLetterDetailsDeadLetterEvent] carries `event`.
+      # @param reason [String] carries `reason`, one of `DeadLetterDetailsDeadLetterReason::VALUES`.
+      # @param request_attempt_id [String] carries `request_attempt_id`: Last request attempt of the delivery
+      # @param retry_count [Integer] carries `retry_count`.
+      # @param subscription [DeadLetterDetailsDeadLetterSubscription] carries `subscription`.
+      # @param http_response_status [Integer, nil] carries `http_response_status`.
+      # @param response_id [String, nil] carries `response_id`: Response to the last request attempt, if the target was
+      #   reached
+      def initialize(
+        created_at:,
+        dead_letter_id:,
+        event:,
+        reason:,
+        request_attempt_id:,
+        retry_count:,
+        subscription:,
+        http_response_status: nil,
+        response_id: nil
+      )
+        @created_at = created_at
+        @dead_letter_id = dead_letter_id
+        @event = event
+        @reason = reason
+        @request_attempt_id = request_attempt_id
+        @retry_count = retry_count
+        @subscription = subscription
+        @http_response_status = http_response_status
+        @response_id = response_id
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [InstanceConfigFormbricks]
+      # @return [DeadLetterDetailsDeadLetter]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "InstanceConfigFormbricks")
+        fields = Runtime.as_fields(value, "DeadLetterDetailsDeadLetter")
         new(
-          api_host: Runtime.read(fields, "api_host", Runtime::TEXT),
-          environment_id: Runtime.read(fields, "environment_id", Runtime::TEXT)
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          dead_letter_id: Runtime.read(fields, "dead_letter_id", Runtime::UUID),
+          event: Runtime.read(fields, "event", DeadLetterDetailsDeadLetterEvent.method(:from_json)),
+          reason: Runtime.read(fields, "reason", Runtime.member_of(DeadLetterDetailsDeadLetterReason)),
+          request_attempt_id: Runtime.read(fields, "request_attempt_id", Runtime::UUID),
+          retry_count: Runtime.read(fields, "retry_count", Runtime::INTEGER),
+          subscription: Runtime.read(
+            fields,
+            "subscription",
+            DeadLetterDetailsDeadLetterSubscription.method(:from_json)
+          ),
+          http_response_status: Runtime.maybe(fields, "http_response_status", Runtime::INTEGER),
+          response_id: Runtime.maybe(fields, "response_id", Runtime::UUID)
         )
       end
 
@@ -1381,8 +1864,15 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["api_host"] = @api_host
-        out["environment_id"] = @environment_id
+        out["created_at"] = Runtime.moment(@created_at)
+        out["dead_letter_id"] = @dead_letter_id
+        out["event"] = @event.to_h
+        out["reason"] = @reason
+        out["request_attempt_id"] = @request_attempt_id
+        out["retry_count"] = @retry_count
+        out["subscription"] = @subscription.to_h
+        out["http_response_status"] = @http_response_status unless @http_response_status.nil?
+        out["response_id"] = @response_id unless @response_id.nil?
         out
       end
 
@@ -1391,7 +1881,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(InstanceConfigFormbricks) && to_h == other.to_h
+        other.is_a?(DeadLetterDetailsDeadLetter) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1403,28 +1893,28 @@ module Hook0
       end
     end
 
-    # The `InstanceConfigMatomo` the API declares.
-    class InstanceConfigMatomo
-      attr_reader :site_id,
-                  :url
+    # The `DeadLetterDetailsDeadLetterEvent` the API declares.
+    class DeadLetterDetailsDeadLetterEvent
+      attr_reader :event_id,
+                  :event_type_name
 
-      # @param site_id [Integer] carries `site_id`.
-      # @param url [String] carries `url`.
-      def initialize(site_id:, url:)
-        @site_id = site_id
-        @url = url
+      # @param event_id [String] carries `event_id`.
+      # @param event_type_name [String] carries `event_type_name`.
+      def initialize(event_id:, event_type_name:)
+        @event_id = event_id
+        @event_type_name = event_type_name
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [InstanceConfigMatomo]
+      # @return [DeadLetterDetailsDeadLetterEvent]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "InstanceConfigMatomo")
+        fields = Runtime.as_fields(value, "DeadLetterDetailsDeadLetterEvent")
         new(
-          site_id: Runtime.read(fields, "site_id", Runtime::INTEGER),
-          url: Runtime.read(fields, "url", Runtime::TEXT)
+          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
+          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT)
         )
       end
 
@@ -1433,8 +1923,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["site_id"] = @site_id
-        out["url"] = @url
+        out["event_id"] = @event_id
+        out["event_type_name"] = @event_type_name
         out
       end
 
@@ -1443,7 +1933,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(InstanceConfigMatomo) && to_h == other.to_h
+        other.is_a?(DeadLetterDetailsDeadLetterEvent) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1455,36 +1945,28 @@ module Hook0
       end
     end
 
-    # The `Organization` the API declares.
-    class Organization
-      attr_reader :name,
-                  :organization_id,
-                  :role,
-                  :plan
+    # The `DeadLetterDetailsDeadLetterSubscription` the API declares.
+    class DeadLetterDetailsDeadLetterSubscription
+      attr_reader :subscription_id,
+                  :description
 
-      # @param name [String] carries `name`.
-      # @param organization_id [String] carries `organization_id`.
-      # @param role [String] carries `role`.
-      # @param plan [OrganizationPlan, nil] carries `plan`.
-      def initialize(name:, organization_id:, role:, plan: nil)
-        @name = name
-        @organization_id = organization_id
-        @role = role
-        @plan = plan
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param description [String, nil] carries `description`.
+      def initialize(subscription_id:, description: nil)
+        @subscription_id = subscription_id
+        @description = description
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [Organization]
+      # @return [DeadLetterDetailsDeadLetterSubscription]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "Organization")
+        fields = Runtime.as_fields(value, "DeadLetterDetailsDeadLetterSubscription")
         new(
-          name: Runtime.read(fields, "name", Runtime::TEXT),
-          organization_id: Runtime.read(fields, "organization_id", Runtime::UUID),
-          role: Runtime.read(fields, "role", Runtime::TEXT),
-          plan: Runtime.maybe(fields, "plan", OrganizationPlan.method(:from_json))
+          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID),
+          description: Runtime.maybe(fields, "description", Runtime::TEXT)
         )
       end
 
@@ -1493,10 +1975,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["name"] = @name
-        out["organization_id"] = @organization_id
-        out["role"] = @role
-        out["plan"] = @plan.to_h unless @plan.nil?
+        out["subscription_id"] = @subscription_id
+        out["description"] = @description unless @description.nil?
         out
       end
 
@@ -1505,7 +1985,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(Organization) && to_h == other.to_h
+        other.is_a?(DeadLetterDetailsDeadLetterSubscription) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1517,52 +1997,51 @@ module Hook0
       end
     end
 
-    # The `OrganizationInfo` the API declares.
-    class OrganizationInfo
-      attr_reader :consumption,
-                  :name,
-                  :onboarding_steps,
-                  :organization_id,
-                  :quotas,
-                  :users,
-                  :plan
+    # The `DeadLetterDetailsLastResponse` the API declares.
+    class DeadLetterDetailsLastResponse
+      attr_reader :response_id,
+                  :body,
+                  :elapsed_time_ms,
+                  :headers,
+                  :http_code,
+                  :response_error_name
 
-      # @param consumption [OrganizationInfoConsumption] carries `consumption`.
-      # @param name [String] carries `name`.
-      # @param onboarding_steps [OrganizationInfoOnboardingSteps] carries `onboarding_steps`.
-      # @param organization_id [String] carries `organization_id`.
-      # @param quotas [OrganizationInfoQuotas] carries `quotas`.
-      # @param users [Array<OrganizationInfoUsers>] carries `users`.
-      # @param plan [OrganizationInfoPlan, nil] carries `plan`.
-      def initialize(consumption:, name:, onboarding_steps:, organization_id:, quotas:, users:, plan: nil)
-        @consumption = consumption
-        @name = name
-        @onboarding_steps = onboarding_steps
-        @organization_id = organization_id
-        @quotas = quotas
-        @users = users
-        @plan = plan
+      # @param response_id [String] carries `response_id`.
+      # @param body [String, nil] carries `body`.
+      # @param elapsed_time_ms [Integer, nil] carries `elapsed_time_ms`.
+      # @param headers [Hash{String => String}, nil] carries `headers`.
+      # @param http_code [Integer, nil] carries `http_code`.
+      # @param response_error_name [String, nil] carries `response_error_name`.
+      def initialize(
+        response_id:,
+        body: nil,
+        elapsed_time_ms: nil,
+        headers: nil,
+        http_code: nil,
+        response_error_name: nil
+      )
+        @response_id = response_id
+        @body = body
+        @elapsed_time_ms = elapsed_time_ms
+        @headers = headers
+        @http_code = http_code
+        @response_error_name = response_error_name
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [OrganizationInfo]
+      # @return [DeadLetterDetailsLastResponse]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "OrganizationInfo")
+        fields = Runtime.as_fields(value, "DeadLetterDetailsLastResponse")
         new(
-          consumption: Runtime.read(fields, "consumption", OrganizationInfoConsumption.method(:from_json)),
-          name: Runtime.read(fields, "name", Runtime::TEXT),
-          onboarding_steps: Runtime.read(
-            fields,
-            "onboarding_steps",
-            OrganizationInfoOnboardingSteps.method(:from_json)
-          ),
-          organization_id: Runtime.read(fields, "organization_id", Runtime::UUID),
-          quotas: Runtime.read(fields, "quotas", OrganizationInfoQuotas.method(:from_json)),
-          users: Runtime.read(fields, "users", Runtime.list(OrganizationInfoUsers.method(:from_json))),
-          plan: Runtime.maybe(fields, "plan", OrganizationInfoPlan.method(:from_json))
+          response_id: Runtime.read(fields, "response_id", Runtime::UUID),
+          body: Runtime.maybe(fields, "body", Runtime::TEXT),
+          elapsed_time_ms: Runtime.maybe(fields, "elapsed_time_ms", Runtime::INTEGER),
+          headers: Runtime.maybe(fields, "headers", Runtime.map(Runtime::TEXT)),
+          http_code: Runtime.maybe(fields, "http_code", Runtime::INTEGER),
+          response_error_name: Runtime.maybe(fields, "response_error_name", Runtime::TEXT)
         )
       end
 
@@ -1571,13 +2050,12 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["consumption"] = @consumption.to_h
-        out["name"] = @name
-        out["onboarding_steps"] = @onboarding_steps.to_h
-        out["organization_id"] = @organization_id
-        out["quotas"] = @quotas.to_h
-        out["users"] = @users.map(&:to_h)
-        out["plan"] = @plan.to_h unless @plan.nil?
+        out["response_id"] = @response_id
+        out["body"] = @body unless @body.nil?
+        out["elapsed_time_ms"] = @elapsed_time_ms unless @elapsed_time_ms.nil?
+        out["headers"] = @headers unless @headers.nil?
+        out["http_code"] = @http_code unless @http_code.nil?
+        out["response_error_name"] = @response_error_name unless @response_error_name.nil?
         out
       end
 
@@ -1586,7 +2064,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(OrganizationInfo) && to_h == other.to_h
+        other.is_a?(DeadLetterDetailsLastResponse) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1598,32 +2076,28 @@ module Hook0
       end
     end
 
-    # The `OrganizationInfoConsumption` the API declares.
-    class OrganizationInfoConsumption
-      attr_reader :applications,
-                  :events_per_day,
-                  :members
+    # The `DeadLetterEvent` the API declares.
+    class DeadLetterEvent
+      attr_reader :event_id,
+                  :event_type_name
 
-      # @param applications [Integer, nil] carries `applications`.
-      # @param events_per_day [Integer, nil] carries `events_per_day`.
-      # @param members [Integer, nil] carries `members`.
-      def initialize(applications: nil, events_per_day: nil, members: nil)
-        @applications = applications
-        @events_per_day = events_per_day
-        @members = members
+      # @param event_id [String] carries `event_id`.
+      # @param event_type_name [String] carries `event_type_name`.
+      def initialize(event_id:, event_type_name:)
+        @event_id = event_id
+        @event_type_name = event_type_name
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [OrganizationInfoConsumption]
+      # @return [DeadLetterEvent]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "OrganizationInfoConsumption")
+        fields = Runtime.as_fields(value, "DeadLetterEvent")
         new(
-          applications: Runtime.maybe(fields, "applications", Runtime::INTEGER),
-          events_per_day: Runtime.maybe(fields, "events_per_day", Runtime::INTEGER),
-          members: Runtime.maybe(fields, "members", Runtime::INTEGER)
+          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
+          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT)
         )
       end
 
@@ -1632,9 +2106,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["applications"] = @applications unless @applications.nil?
-        out["events_per_day"] = @events_per_day unless @events_per_day.nil?
-        out["members"] = @members unless @members.nil?
+        out["event_id"] = @event_id
+        out["event_type_name"] = @event_type_name
         out
       end
 
@@ -1643,7 +2116,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(OrganizationInfoConsumption) && to_h == other.to_h
+        other.is_a?(DeadLetterEvent) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1655,45 +2128,28 @@ module Hook0
       end
     end
 
-    # The `OrganizationInfoOnboardingSteps` the API declares.
-    class OrganizationInfoOnboardingSteps
-      attr_reader :application,
-                  :event,
-                  :event_type,
-                  :subscription
+    # The `DeadLetterSubscription` the API declares.
+    class DeadLetterSubscription
+      attr_reader :subscription_id,
+                  :description
 
-      # @param application [String] carries `application`, one of `OrganizationInfoOnboardingStepsApplication::VALUES`.
-      # @param event [String] carries `event`, one of `OrganizationInfoOnboardingStepsEvent::VALUES`.
-      # @param event_type [String] carries `event_type`, one of `OrganizationInfoOnboardingStepsEventType::VALUES`.
-      # @param subscription [String] carries `subscription`, one of
-      #   `OrganizationInfoOnboardingStepsSubscription::VALUES`.
-      def initialize(application:, event:, event_type:, subscription:)
-        @application = application
-        @event = event
-        @event_type = event_type
-        @subscription = subscription
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param description [String, nil] carries `description`.
+      def initialize(subscription_id:, description: nil)
+        @subscription_id = subscription_id
+        @description = description
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [OrganizationInfoOnboardingSteps]
+      # @return [DeadLetterSubscription]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "OrganizationInfoOnboardingSteps")
+        fields = Runtime.as_fields(value, "DeadLetterSubscription")
         new(
-          application: Runtime.read(
-            fields,
-            "application",
-            Runtime.member_of(OrganizationInfoOnboardingStepsApplication)
-          ),
-          event: Runtime.read(fields, "event", Runtime.member_of(OrganizationInfoOnboardingStepsEvent)),
-          event_type: Runtime.read(fields, "event_type", Runtime.member_of(OrganizationInfoOnboardingStepsEventType)),
-          subscription: Runtime.read(
-            fields,
-            "subscription",
-            Runtime.member_of(OrganizationInfoOnboardingStepsSubscription)
-          )
+          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID),
+          description: Runtime.maybe(fields, "description", Runtime::TEXT)
         )
       end
 
@@ -1702,10 +2158,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["application"] = @application
-        out["event"] = @event
-        out["event_type"] = @event_type
-        out["subscription"] = @subscription
+        out["subscription_id"] = @subscription_id
+        out["description"] = @description unless @description.nil?
         out
       end
 
@@ -1714,7 +2168,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(OrganizationInfoOnboardingSteps) && to_h == other.to_h
+        other.is_a?(DeadLetterSubscription) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1726,28 +2180,82 @@ module Hook0
       end
     end
 
-    # The `OrganizationInfoPlan` the API declares.
-    class OrganizationInfoPlan
-      attr_reader :label,
-                  :name
+    # The `Event` the API declares.
+    class Event
+      attr_reader :event_id,
+                  :event_type_name,
+                  :ip,
+                  :labels,
+                  :occurred_at,
+                  :payload_content_type,
+                  :received_at,
+                  :cancelled_at,
+                  :deliver_at,
+                  :event_type_version,
+                  :expires_at,
+                  :metadata
 
-      # @param label [String] carries `label`.
-      # @param name [String] carries `name`.
-      def initialize(label:, name:)
-        @label = label
-        @name = name
+      # @param event_id [String] carries `event_id`.
+      # @param event_type_name [String] carries `event_type_name`.
+      # @param ip [String] carries `ip`.
+      # @param labels [Object] carries `labels`.
+      # @param occurred_at [Time] carries `occurred_at`.
+      # @param payload_content_type [String] carries `payload_content_type`.
+      # @param received_at [Time] carries `received_at`.
+      # @param cancelled_at [Time, nil] carries `cancelled_at`: Date the scheduled delivery of the event was cancelled
+      # @param deliver_at [Time, nil] carries `deliver_at`: Date from which the event is delivered, when it was
+      #   scheduled for a later delivery
+      # @param event_type_version [Integer, nil] carries `event_type_version`.
+      # @param expires_at [Time, nil] carries `expires_at`: Date after which the event is no longer delivered
+      # @param metadata [Object, nil] carries `metadata`.
+      def initialize(
+        event_id:,
+        event_type_name:,
+        ip:,
+        labels:,
+        occurred_at:,
+        payload_content_type:,
+        received_at:,
+        cancelled_at: nil,
+        deliver_at: nil,
+        event_type_version: nil,
+        expires_at: nil,
+        metadata: nil
+      )
+        @event_id = event_id
+        @event_type_name = event_type_name
+        @ip = ip
+        @labels = labels
+        @occurred_at = occurred_at
+        @payload_content_type = payload_content_type
+        @received_at = received_at
+        @cancelled_at = cancelled_at
+        @deliver_at = deliver_at
+        @event_type_version = event_type_version
+        @expires_at = expires_at
+        @metadata = metadata
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [OrganizationInfoPlan]
+      # @return [Event]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "OrganizationInfoPlan")
+        fields = Runtime.as_fields(value, "Event")
         new(
-          label: Runtime.read(fields, "label", Runtime::TEXT),
-          name: Runtime.read(fields, "name", Runtime::TEXT)
+          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
+          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT),
+          ip: Runtime.read(fields, "ip", Runtime::TEXT),
+          labels: Runtime.read(fields, "labels", Runtime::JSON_VALUE),
+          occurred_at: Runtime.read(fields, "occurred_at", Runtime::DATE_TIME),
+          payload_content_type: Runtime.read(fields, "payload_content_type", Runtime::TEXT),
+          received_at: Runtime.read(fields, "received_at", Runtime::DATE_TIME),
+          cancelled_at: Runtime.maybe(fields, "cancelled_at", Runtime::DATE_TIME),
+          deliver_at: Runtime.maybe(fields, "deliver_at", Runtime::DATE_TIME),
+          event_type_version: Runtime.maybe(fields, "event_type_version", Runtime::INTEGER),
+          expires_at: Runtime.maybe(fields, "expires_at", Runtime::DATE_TIME),
+          metadata: Runtime.maybe(fields, "metadata", Runtime::JSON_VALUE)
         )
       end
 
@@ -1756,8 +2264,18 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["label"] = @label
-        out["name"] = @name
+        out["event_id"] = @event_id
+        out["event_type_name"] = @event_type_name
+        out["ip"] = @ip
+        out["labels"] = @labels
+        out["occurred_at"] = Runtime.moment(@occurred_at)
+        out["payload_content_type"] = @payload_content_type
+        out["received_at"] = Runtime.moment(@received_at)
+        out["cancelled_at"] = Runtime.moment(@cancelled_at) unless @cancelled_at.nil?
+        out["deliver_at"] = Runtime.moment(@deliver_at) unless @deliver_at.nil?
+        out["event_type_version"] = @event_type_version unless @event_type_version.nil?
+        out["expires_at"] = Runtime.moment(@expires_at) unless @expires_at.nil?
+        out["metadata"] = @metadata unless @metadata.nil?
         out
       end
 
@@ -1766,7 +2284,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(OrganizationInfoPlan) && to_h == other.to_h
+        other.is_a?(Event) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1778,45 +2296,87 @@ module Hook0
       end
     end
 
-    # The `OrganizationInfoQuotas` the API declares.
-    class OrganizationInfoQuotas
-      attr_reader :applications_per_organization_limit,
-                  :days_of_events_retention_limit,
-                  :events_per_day_limit,
-                  :members_per_organization_limit
+    # The `EventPost` the API declares.
+    class EventPost
+      attr_reader :application_id,
+                  :event_type,
+                  :labels,
+                  :occurred_at,
+                  :payload,
+                  :payload_content_type,
+                  :deliver_at,
+                  :event_id,
+                  :event_type_version,
+                  :expires_at,
+                  :metadata
 
-      # @param applications_per_organization_limit [Integer] carries `applications_per_organization_limit`.
-      # @param days_of_events_retention_limit [Integer] carries `days_of_events_retention_limit`.
-      # @param events_per_day_limit [Integer] carries `events_per_day_limit`.
-      # @param members_per_organization_limit [Integer] carries `members_per_organization_limit`.
+      # @param application_id [String] carries `application_id`: UUID of the application this event belongs to.
+      # @param event_type [String] carries `event_type`: The type of event (e.g., 'user.created', 'order.completed').
+      #   Length: 1-200 characters.
+      # @param labels [Hash{String => String}] carries `labels`: Labels for event filtering and routing to
+      #   subscriptions.
+      # @param occurred_at [Time] carries `occurred_at`: Timestamp when the event occurred.
+      # @param payload [String] carries `payload`: The event payload. For binary content, use base64 encoding. Max
+      #   length: 699050 characters (512 KiB base64-encoded).
+      # @param payload_content_type [String] carries `payload_content_type`: Content type of the payload. Valid values:
+      #   text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
+      # @param deliver_at [Time, nil] carries `deliver_at`: Optional date from which the event is delivered to its
+      #   subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future
+      #   as the events retention of the appl
+      # @param event_id [String, nil] carries `event_id`: Optional unique identifier for this event (client-generated
+      #   UUID). If not provided, a UUIDv7 will be generated by the server.
+      # @param event_type_version [Integer, nil] carries `event_type_version`: Optional version of the event type the
+      #   payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+      # @param expires_at [Time, nil] carries `expires_at`: Optional date after which the event is no longer delivered:
+      #   its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to
+      #   live of its event type, counted f
+      # @param metadata [Hash{String => String}, nil] carries `metadata`: Optional metadata key-value pairs associated
+      #   with the event.
       def initialize(
-        applications_per_organization_limit:,
-        days_of_events_retention_limit:,
-        events_per_day_limit:,
-        members_per_organization_limit:
+        application_id:,
+        event_type:,
+        labels:,
+        occurred_at:,
+        payload:,
+        payload_content_type:,
+        deliver_at: nil,
+        event_id: nil,
+        event_type_version: nil,
+        expires_at: nil,
+        metadata: nil
       )
-        @applications_per_organization_limit = applications_per_organization_limit
-        @days_of_events_retention_limit = days_of_events_retention_limit
-        @events_per_day_limit = events_per_day_limit
-        @members_per_organization_limit = members_per_organization_limit
+        @application_id = application_id
+        @event_type = event_type
+        @labels = labels
+        @occurred_at = occurred_at
+        @payload = payload
+        @payload_content_type = payload_content_type
+        @deliver_at = deliver_at
+        @event_id = event_id
+        @event_type_version = event_type_version
+        @expires_at = expires_at
+        @metadata = metadata
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [OrganizationInfoQuotas]
+      # @return [EventPost]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "OrganizationInfoQuotas")
+        fields = Runtime.as_fields(value, "EventPost")
         new(
-          applications_per_organization_limit: Runtime.read(
-            fields,
-            "applications_per_organization_limit",
-            Runtime::INTEGER
-          ),
-          days_of_events_retention_limit: Runtime.read(fields, "days_of_events_retention_limit", Runtime::INTEGER),
-          events_per_day_limit: Runtime.read(fields, "events_per_day_limit", Runtime::INTEGER),
-          members_per_organization_limit: Runtime.read(fields, "members_per_organization_limit", Runtime::INTEGER)
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          event_type: Runtime.read(fields, "event_type", Runtime::TEXT),
+          labels: Runtime.read(fields, "labels", Runtime.map(Runtime::TEXT)),
+          occurred_at: Runtime.read(fields, "occurred_at", Runtime::DATE_TIME),
+          payload: Runtime.read(fields, "payload", Runtime::TEXT),
+          payload_content_type: Runtime.read(fields, "payload_content_type", Runtime::TEXT),
+          deliver_at: Runtime.maybe(fields, "deliver_at", Runtime::DATE_TIME),
+          event_id: Runtime.maybe(fields, "event_id", Runtime::UUID),
+          event_type_version: Runtime.maybe(fields, "event_type_version", Runtime::INTEGER),
+          expires_at: Runtime.maybe(fields, "expires_at", Runtime::DATE_TIME),
+          metadata: Runtime.maybe(fields, "metadata", Runtime.map(Runtime::TEXT))
         )
       end
 
@@ -1825,10 +2385,4275 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["applications_per_organization_limit"] = @applications_per_organization_limit
-        out["days_of_events_retention_limit"] = @days_of_events_retention_limit
-        out["events_per_day_limit"] = @events_per_day_limit
-        out["members_per_organization_limit"] = @members_per_organization_limit
+        out["application_id"] = @application_id
+        out["event_type"] = @event_type
+        out["labels"] = @labels
+        out["occurred_at"] = Runtime.moment(@occurred_at)
+        out["payload"] = @payload
+        out["payload_content_type"] = @payload_content_type
+        out["deliver_at"] = Runtime.moment(@deliver_at) unless @deliver_at.nil?
+        out["event_id"] = @event_id unless @event_id.nil?
+        out["event_type_version"] = @event_type_version unless @event_type_version.nil?
+        out["expires_at"] = Runtime.moment(@expires_at) unless @expires_at.nil?
+        out["metadata"] = @metadata unless @metadata.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventPost) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventType` the API declares.
+    class EventType
+      attr_reader :event_type_name,
+                  :payload_schema_mode,
+                  :resource_type_name,
+                  :service_name,
+                  :verb_name,
+                  :versions,
+                  :default_ttl_s,
+                  :payload_schema
+
+      # @param event_type_name [String] carries `event_type_name`.
+      # @param payload_schema_mode [String] carries `payload_schema_mode`, one of `EventTypePayloadSchemaMode::VALUES`.
+      # @param resource_type_name [String] carries `resource_type_name`.
+      # @param service_name [String] carries `service_name`.
+      # @param verb_name [String] carries `verb_name`.
+      # @param versions [Array<EventTypeVersions>] carries `versions`.
+      # @param default_ttl_s [Integer, nil] carries `default_ttl_s`: Time to live in seconds of the events of this type
+      #   that do not give an expiry date
+      # @param payload_schema [Object, nil] carries `payload_schema`.
+      def initialize(
+        event_type_name:,
+        payload_schema_mode:,
+        resource_type_name:,
+        service_name:,
+        verb_name:,
+        versions:,
+        default_ttl_s: nil,
+        payload_schema: nil
+      )
+        @event_type_name = event_type_name
+        @payload_schema_mode = payload_schema_mode
+        @resource_type_name = resource_type_name
+        @service_name = service_name
+        @verb_name = verb_name
+        @versions = versions
+        @default_ttl_s = default_ttl_s
+        @payload_schema = payload_schema
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventType]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventType")
+        new(
+          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT),
+          payload_schema_mode: Runtime.read(
+            fields,
+            "payload_schema_mode",
+            Runtime.member_of(EventTypePayloadSchemaMode)
+          ),
+          resource_type_name: Runtime.read(fields, "resource_type_name", Runtime::TEXT),
+          service_name: Runtime.read(fields, "service_name", Runtime::TEXT),
+          verb_name: Runtime.read(fields, "verb_name", Runtime::TEXT),
+          versions: Runtime.read(fields, "versions", Runtime.list(EventTypeVersions.method(:from_json))),
+          default_ttl_s: Runtime.maybe(fields, "default_ttl_s", Runtime::INTEGER),
+          payload_schema: Runtime.maybe(fields, "payload_schema", Runtime::JSON_VALUE)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["event_type_name"] = @event_type_name
+        out["payload_schema_mode"] = @payload_schema_mode
+        out["resource_type_name"] = @resource_type_name
+        out["service_name"] = @service_name
+        out["verb_name"] = @verb_name
+        out["versions"] = @versions.map(&:to_h)
+        out["default_ttl_s"] = @default_ttl_s unless @default_ttl_s.nil?
+        out["payload_schema"] = @payload_schema unless @payload_schema.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventType) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventTypePost` the API declares.
+    class EventTypePost
+      attr_reader :application_id,
+                  :resource_type,
+                  :service,
+                  :verb,
+                  :default_ttl_s,
+                  :payload_schema,
+                  :payload_schema_mode
+
+      # @param application_id [String] carries `application_id`.
+      # @param resource_type [String] carries `resource_type`.
+      # @param service [String] carries `service`.
+      # @param verb [String] carries `verb`.
+      # @param default_ttl_s [Integer, nil] carries `default_ttl_s`: Time to live in seconds of the events of this type
+      #   that do not give an expiry date; their pending deliveries are cancelled once it is over
+      # @param payload_schema [Object, nil] carries `payload_schema`: JSON Schema the payloads of the `application/json`
+      #   events of this type must match; remote references are not supported
+      # @param payload_schema_mode [String, nil] carries `payload_schema_mode`, one of
+      #   `EventTypePostPayloadSchemaMode::VALUES`: How the payload schema is applied at ingestion (default: `enforce`)
+      def initialize(
+        application_id:,
+        resource_type:,
+        service:,
+        verb:,
+        default_ttl_s: nil,
+        payload_schema: nil,
+        payload_schema_mode: nil
+      )
+        @application_id = application_id
+        @resource_type = resource_type
+        @service = service
+        @verb = verb
+        @default_ttl_s = default_ttl_s
+        @payload_schema = payload_schema
+        @payload_schema_mode = payload_schema_mode
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventTypePost]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventTypePost")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          resource_type: Runtime.read(fields, "resource_type", Runtime::TEXT),
+          service: Runtime.read(fields, "service", Runtime::TEXT),
+          verb: Runtime.read(fields, "verb", Runtime::TEXT),
+          default_ttl_s: Runtime.maybe(fields, "default_ttl_s", Runtime::INTEGER),
+          payload_schema: Runtime.maybe(fields, "payload_schema", Runtime::JSON_VALUE),
+          payload_schema_mode: Runtime.maybe(
+            fields,
+            "payload_schema_mode",
+            Runtime.member_of(EventTypePostPayloadSchemaMode)
+          )
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["resource_type"] = @resource_type
+        out["service"] = @service
+        out["verb"] = @verb
+        out["default_ttl_s"] = @default_ttl_s unless @default_ttl_s.nil?
+        out["payload_schema"] = @payload_schema unless @payload_schema.nil?
+        out["payload_schema_mode"] = @payload_schema_mode unless @payload_schema_mode.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventTypePost) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventTypePut` the API declares.
+    class EventTypePut
+      attr_reader :application_id,
+                  :default_ttl_s,
+                  :payload_schema,
+                  :payload_schema_mode
+
+      # @param application_id [String] carries `application_id`.
+      # @param default_ttl_s [Integer, nil] carries `default_ttl_s`: Time to live in seconds of the events of this type
+      #   that do not give an expiry date; their pending deliveries are cancelled once it is over
+      # @param payload_schema [Object, nil] carries `payload_schema`: JSON Schema the payloads of the `application/json`
+      #   events of this type must match; remote references are not supported
+      # @param payload_schema_mode [String, nil] carries `payload_schema_mode`, one of
+      #   `EventTypePutPayloadSchemaMode::VALUES`: How the payload schema is applied at ingestion (default: `enforce`)
+      def initialize(application_id:, default_ttl_s: nil, payload_schema: nil, payload_schema_mode: nil)
+        @application_id = application_id
+        @default_ttl_s = default_ttl_s
+        @payload_schema = payload_schema
+        @payload_schema_mode = payload_schema_mode
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventTypePut]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventTypePut")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          default_ttl_s: Runtime.maybe(fields, "default_ttl_s", Runtime::INTEGER),
+          payload_schema: Runtime.maybe(fields, "payload_schema", Runtime::JSON_VALUE),
+          payload_schema_mode: Runtime.maybe(
+            fields,
+            "payload_schema_mode",
+            Runtime.member_of(EventTypePutPayloadSchemaMode)
+          )
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["default_ttl_s"] = @default_ttl_s unless @default_ttl_s.nil?
+        out["payload_schema"] = @payload_schema unless @payload_schema.nil?
+        out["payload_schema_mode"] = @payload_schema_mode unless @payload_schema_mode.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventTypePut) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventTypeVersion` the API declares.
+    class EventTypeVersion
+      attr_reader :created_at,
+                  :version,
+                  :deprecated_at,
+                  :payload_schema,
+                  :sunset_at
+
+      # @param created_at [Time] carries `created_at`.
+      # @param version [Integer] carries `version`.
+      # @param deprecated_at [Time, nil] carries `deprecated_at`.
+      # @param payload_schema [Object, nil] carries `payload_schema`: JSON Schema the payloads of the events declaring
+      #   this version must match, instead of the one of the event type
+      # @param sunset_at [Time, nil] carries `sunset_at`: Date from which the events declaring this version are refused
+      def initialize(created_at:, version:, deprecated_at: nil, payload_schema: nil, sunset_at: nil)
+        @created_at = created_at
+        @version = version
+        @deprecated_at = deprecated_at
+        @payload_schema = payload_schema
+        @sunset_at = sunset_at
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventTypeVersion]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventTypeVersion")
+        new(
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          version: Runtime.read(fields, "version", Runtime::INTEGER),
+          deprecated_at: Runtime.maybe(fields, "deprecated_at", Runtime::DATE_TIME),
+          payload_schema: Runtime.maybe(fields, "payload_schema", Runtime::JSON_VALUE),
+          sunset_at: Runtime.maybe(fields, "sunset_at", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["created_at"] = Runtime.moment(@created_at)
+        out["version"] = @version
+        out["deprecated_at"] = Runtime.moment(@deprecated_at) unless @deprecated_at.nil?
+        out["payload_schema"] = @payload_schema unless @payload_schema.nil?
+        out["sunset_at"] = Runtime.moment(@sunset_at) unless @sunset_at.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventTypeVersion) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventTypeVersionDeprecate` the API declares.
+    class EventTypeVersionDeprecate
+      attr_reader :application_id,
+                  :version,
+                  :sunset_at
+
+      # @param application_id [String] carries `application_id`.
+      # @param version [Integer] carries `version`.
+      # @param sunset_at [Time, nil] carries `sunset_at`: Date from which the events declaring this version are refused;
+      #   none keeps accepting them
+      def initialize(application_id:, version:, sunset_at: nil)
+        @application_id = application_id
+        @version = version
+        @sunset_at = sunset_at
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventTypeVersionDeprecate]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventTypeVersionDeprecate")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          version: Runtime.read(fields, "version", Runtime::INTEGER),
+          sunset_at: Runtime.maybe(fields, "sunset_at", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["version"] = @version
+        out["sunset_at"] = Runtime.moment(@sunset_at) unless @sunset_at.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventTypeVersionDeprecate) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventTypeVersionPost` the API declares.
+    class EventTypeVersionPost
+      attr_reader :application_id,
+                  :version,
+                  :payload_schema
+
+      # @param application_id [String] carries `application_id`.
+      # @param version [Integer] carries `version`.
+      # @param payload_schema [Object, nil] carries `payload_schema`: JSON Schema the payloads of the `application/json`
+      #   events declaring this version must match, instead of the one of the event type; remote references are not
+      #   supported
+      def initialize(application_id:, version:, payload_schema: nil)
+        @application_id = application_id
+        @version = version
+        @payload_schema = payload_schema
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventTypeVersionPost]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventTypeVersionPost")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          version: Runtime.read(fields, "version", Runtime::INTEGER),
+          payload_schema: Runtime.maybe(fields, "payload_schema", Runtime::JSON_VALUE)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["version"] = @version
+        out["payload_schema"] = @payload_schema unless @payload_schema.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventTypeVersionPost) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventTypeVersions` the API declares.
+    class EventTypeVersions
+      attr_reader :created_at,
+                  :version,
+                  :deprecated_at,
+                  :payload_schema,
+                  :sunset_at
+
+      # @param created_at [Time] carries `created_at`.
+      # @param version [Integer] carries `version`.
+      # @param deprecated_at [Time, nil] carries `deprecated_at`.
+      # @param payload_schema [Object, nil] carries `payload_schema`: JSON Schema the payloads of the events declaring
+      #   this version must match, instead of the one of the event type
+      # @param sunset_at [Time, nil] carries `sunset_at`: Date from which the events declaring this version are refused
+      def initialize(created_at:, version:, deprecated_at: nil, payload_schema: nil, sunset_at: nil)
+        @created_at = created_at
+        @version = version
+        @deprecated_at = deprecated_at
+        @payload_schema = payload_schema
+        @sunset_at = sunset_at
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventTypeVersions]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventTypeVersions")
+        new(
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          version: Runtime.read(fields, "version", Runtime::INTEGER),
+          deprecated_at: Runtime.maybe(fields, "deprecated_at", Runtime::DATE_TIME),
+          payload_schema: Runtime.maybe(fields, "payload_schema", Runtime::JSON_VALUE),
+          sunset_at: Runtime.maybe(fields, "sunset_at", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["created_at"] = Runtime.moment(@created_at)
+        out["version"] = @version
+        out["deprecated_at"] = Runtime.moment(@deprecated_at) unless @deprecated_at.nil?
+        out["payload_schema"] = @payload_schema unless @payload_schema.nil?
+        out["sunset_at"] = Runtime.moment(@sunset_at) unless @sunset_at.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventTypeVersions) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventWithPayload` the API declares.
+    class EventWithPayload
+      attr_reader :event_id,
+                  :event_type_name,
+                  :ip,
+                  :labels,
+                  :occurred_at,
+                  :payload,
+                  :payload_content_type,
+                  :received_at,
+                  :cancelled_at,
+                  :deliver_at,
+                  :event_type_version,
+                  :expires_at,
+                  :metadata
+
+      # @param event_id [String] carries `event_id`.
+      # @param event_type_name [String] carries `event_type_name`.
+      # @param ip [String] carries `ip`.
+      # @param labels [Object] carries `labels`.
+      # @param occurred_at [Time] carries `occurred_at`.
+      # @param payload [String] carries `payload`.
+      # @param payload_content_type [String] carries `payload_content_type`.
+      # @param received_at [Time] carries `received_at`.
+      # @param cancelled_at [Time, nil] carries `cancelled_at`: Date the scheduled delivery of the event was cancelled
+      # @param deliver_at [Time, nil] carries `deliver_at`: Date from which the event is delivered, when it was
+      #   scheduled for a later delivery
+      # @param event_type_version [Integer, nil] carries `event_type_version`.
+      # @param expires_at [Time, nil] carries `expires_at`: Date after which the event is no longer delivered
+      # @param metadata [Object, nil] carries `metadata`.
+      def initialize(
+        event_id:,
+        event_type_name:,
+        ip:,
+        labels:,
+        occurred_at:,
+        payload:,
+        payload_content_type:,
+        received_at:,
+        cancelled_at: nil,
+        deliver_at: nil,
+        event_type_version: nil,
+        expires_at: nil,
+        metadata: nil
+      )
+        @event_id = event_id
+        @event_type_name = event_type_name
+        @ip = ip
+        @labels = labels
+        @occurred_at = occurred_at
+        @payload = payload
+        @payload_content_type = payload_content_type
+        @received_at = received_at
+        @cancelled_at = cancelled_at
+        @deliver_at = deliver_at
+        @event_type_version = event_type_version
+        @expires_at = expires_at
+        @metadata = metadata
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventWithPayload]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventWithPayload")
+        new(
+          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
+          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT),
+          ip: Runtime.read(fields, "ip", Runtime::TEXT),
+          labels: Runtime.read(fields, "labels", Runtime::JSON_VALUE),
+          occurred_at: Runtime.read(fields, "occurred_at", Runtime::DATE_TIME),
+          payload: Runtime.read(fields, "payload", Runtime::TEXT),
+          payload_content_type: Runtime.read(fields, "payload_content_type", Runtime::TEXT),
+          received_at: Runtime.read(fields, "received_at", Runtime::DATE_TIME),
+          cancelled_at: Runtime.maybe(fields, "cancelled_at", Runtime::DATE_TIME),
+          deliver_at: Runtime.maybe(fields, "deliver_at", Runtime::DATE_TIME),
+          event_type_version: Runtime.maybe(fields, "event_type_version", Runtime::INTEGER),
+          expires_at: Runtime.maybe(fields, "expires_at", Runtime::DATE_TIME),
+          metadata: Runtime.maybe(fields, "metadata", Runtime::JSON_VALUE)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["event_id"] = @event_id
+        out["event_type_name"] = @event_type_name
+        out["ip"] = @ip
+        out["labels"] = @labels
+        out["occurred_at"] = Runtime.moment(@occurred_at)
+        out["payload"] = @payload
+        out["payload_content_type"] = @payload_content_type
+        out["received_at"] = Runtime.moment(@received_at)
+        out["cancelled_at"] = Runtime.moment(@cancelled_at) unless @cancelled_at.nil?
+        out["deliver_at"] = Runtime.moment(@deliver_at) unless @deliver_at.nil?
+        out["event_type_version"] = @event_type_version unless @event_type_version.nil?
+        out["expires_at"] = Runtime.moment(@expires_at) unless @expires_at.nil?
+        out["metadata"] = @metadata unless @metadata.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventWithPayload) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventsBatchPost` the API declares.
+    class EventsBatchPost
+      attr_reader :application_id,
+                  :events
+
+      # @param application_id [String] carries `application_id`: UUID of the application the events belong to. Events of
+      #   the batch that carry another application ID are not ingested.
+      # @param events [Array<EventsBatchPostEvents>] carries `events`: Events to ingest. Length: 1-100 events; the whole
+      #   request body is also subject to the usual request size limit.
+      def initialize(application_id:, events:)
+        @application_id = application_id
+        @events = events
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventsBatchPost]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventsBatchPost")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          events: Runtime.read(fields, "events", Runtime.list(EventsBatchPostEvents.method(:from_json)))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["events"] = @events.map(&:to_h)
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventsBatchPost) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventsBatchPostEvents` the API declares.
+    class EventsBatchPostEvents
+      attr_reader :application_id,
+                  :event_type,
+                  :labels,
+                  :occurred_at,
+                  :payload,
+                  :payload_content_type,
+                  :deliver_at,
+                  :event_id,
+                  :event_type_version,
+                  :expires_at,
+                  :metadata
+
+      # @param application_id [String] carries `application_id`: UUID of the application this event belongs to.
+      # @param event_type [String] carries `event_type`: The type of event (e.g., 'user.created', 'order.completed').
+      #   Length: 1-200 characters.
+      # @param labels [Hash{String => String}] carries `labels`: Labels for event filtering and routing to
+      #   subscriptions.
+      # @param occurred_at [Time] carries `occurred_at`: Timestamp when the event occurred.
+      # @param payload [String] carries `payload`: The event payload. For binary content, use base64 encoding. Max
+      #   length: 699050 characters (512 KiB base64-encoded).
+      # @param payload_content_type [String] carries `payload_content_type`: Content type of the payload. Valid values:
+      #   text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
+      # @param deliver_at [Time, nil] carries `deliver_at`: Optional date from which the event is delivered to its
+      #   subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future
+      #   as the events retention of the appl
+      # @param event_id [String, nil] carries `event_id`: Optional unique identifier for this event (client-generated
+      #   UUID). If not provided, a UUIDv7 will be generated by the server.
+      # @param event_type_version [Integer, nil] carries `event_type_version`: Optional version of the event type the
+      #   payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+      # @param expires_at [Time, nil] carries `expires_at`: Optional date after which the event is no longer delivered:
+      #   its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to
+      #   live of its event type, counted f
+      # @param metadata [Hash{String => String}, nil] carries `metadata`: Optional metadata key-value pairs associated
+      #   with the event.
+      def initialize(
+        application_id:,
+        event_type:,
+        labels:,
+        occurred_at:,
+        payload:,
+        payload_content_type:,
+        deliver_at: nil,
+        event_id: nil,
+        event_type_version: nil,
+        expires_at: nil,
+        metadata: nil
+      )
+        @application_id = application_id
+        @event_type = event_type
+        @labels = labels
+        @occurred_at = occurred_at
+        @payload = payload
+        @payload_content_type = payload_content_type
+        @deliver_at = deliver_at
+        @event_id = event_id
+        @event_type_version = event_type_version
+        @expires_at = expires_at
+        @metadata = metadata
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventsBatchPostEvents]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventsBatchPostEvents")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          event_type: Runtime.read(fields, "event_type", Runtime::TEXT),
+          labels: Runtime.read(fields, "labels", Runtime.map(Runtime::TEXT)),
+          occurred_at: Runtime.read(fields, "occurred_at", Runtime::DATE_TIME),
+          payload: Runtime.read(fields, "payload", Runtime::TEXT),
+          payload_content_type: Runtime.read(fields, "payload_content_type", Runtime::TEXT),
+          deliver_at: Runtime.maybe(fields, "deliver_at", Runtime::DATE_TIME),
+          event_id: Runtime.maybe(fields, "event_id", Runtime::UUID),
+          event_type_version: Runtime.maybe(fields, "event_type_version", Runtime::INTEGER),
+          expires_at: Runtime.maybe(fields, "expires_at", Runtime::DATE_TIME),
+          metadata: Runtime.maybe(fields, "metadata", Runtime.map(Runtime::TEXT))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["event_type"] = @event_type
+        out["labels"] = @labels
+        out["occurred_at"] = Runtime.moment(@occurred_at)
+        out["payload"] = @payload
+        out["payload_content_type"] = @payload_content_type
+        out["deliver_at"] = Runtime.moment(@deliver_at) unless @deliver_at.nil?
+        out["event_id"] = @event_id unless @event_id.nil?
+        out["event_type_version"] = @event_type_version unless @event_type_version.nil?
+        out["expires_at"] = Runtime.moment(@expires_at) unless @expires_at.nil?
+        out["metadata"] = @metadata unless @metadata.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventsBatchPostEvents) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `EventsPerDayEntry` the API declares.
+    class EventsPerDayEntry
+      attr_reader :amount,
+                  :application_id,
+                  :application_name,
+                  :date,
+                  :is_provisional
+
+      # @param amount [Integer] carries `amount`.
+      # @param application_id [String] carries `application_id`.
+      # @param application_name [String] carries `application_name`.
+      # @param date [Date] carries `date`.
+      # @param is_provisional [Boolean] carries `is_provisional`.
+      def initialize(amount:, application_id:, application_name:, date:, is_provisional:)
+        @amount = amount
+        @application_id = application_id
+        @application_name = application_name
+        @date = date
+        @is_provisional = is_provisional
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [EventsPerDayEntry]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "EventsPerDayEntry")
+        new(
+          amount: Runtime.read(fields, "amount", Runtime::INTEGER),
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          application_name: Runtime.read(fields, "application_name", Runtime::TEXT),
+          date: Runtime.read(fields, "date", Runtime::DATE),
+          is_provisional: Runtime.read(fields, "is_provisional", Runtime::BOOLEAN)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["amount"] = @amount
+        out["application_id"] = @application_id
+        out["application_name"] = @application_name
+        out["date"] = Runtime.day(@date)
+        out["is_provisional"] = @is_provisional
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(EventsPerDayEntry) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `Inbox` the API declares.
+    class Inbox
+      attr_reader :messages,
+                  :next_cursor
+
+      # @param messages [Array<InboxMessages>] carries `messages`.
+      # @param next_cursor [String, nil] carries `next_cursor`: Pass it as `cursor` to the next pull to continue after
+      #   the last message of this one
+      def initialize(messages:, next_cursor: nil)
+        @messages = messages
+        @next_cursor = next_cursor
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [Inbox]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "Inbox")
+        new(
+          messages: Runtime.read(fields, "messages", Runtime.list(InboxMessages.method(:from_json))),
+          next_cursor: Runtime.maybe(fields, "next_cursor", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["messages"] = @messages.map(&:to_h)
+        out["next_cursor"] = @next_cursor unless @next_cursor.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(Inbox) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `InboxAck` the API declares.
+    class InboxAck
+      attr_reader :application_id,
+                  :receipts
+
+      # @param application_id [String] carries `application_id`.
+      # @param receipts [Array<String>] carries `receipts`: Receipts of the leased events
+      def initialize(application_id:, receipts:)
+        @application_id = application_id
+        @receipts = receipts
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [InboxAck]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "InboxAck")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          receipts: Runtime.read(fields, "receipts", Runtime.list(Runtime::UUID))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["receipts"] = @receipts
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(InboxAck) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `InboxMessages` the API declares.
+    class InboxMessages
+      attr_reader :event_id,
+                  :event_type_name,
+                  :labels,
+                  :leased_until,
+                  :occurred_at,
+                  :payload,
+                  :payload_content_type,
+                  :receipt,
+                  :received_at,
+                  :request_attempt_id,
+                  :retry_count
+
+      # @param event_id [String] carries `event_id`.
+      # @param event_type_name [String] carries `event_type_name`.
+      # @param labels [Object] carries `labels`.
+      # @param leased_until [Time] carries `leased_until`: When the event is offered again if it was neither
+      #   acknowledged nor rejected
+      # @param occurred_at [Time] carries `occurred_at`.
+      # @param payload [String] carries `payload`: Base64-encoded
+      # @param payload_content_type [String] carries `payload_content_type`.
+      # @param receipt [String] carries `receipt`: Identifies this lease of the request attempt; a later lease of the
+      #   same attempt gets another one
+      # @param received_at [Time] carries `received_at`.
+      # @param request_attempt_id [String] carries `request_attempt_id`.
+      # @param retry_count [Integer] carries `retry_count`.
+      def initialize(
+        event_id:,
+        event_type_name:,
+        labels:,
+        leased_until:,
+        occurred_at:,
+        payload:,
+        payload_content_type:,
+        receipt:,
+        received_at:,
+        request_attempt_id:,
+        retry_count:
+      )
+        @event_id = event_id
+        @event_type_name = event_type_name
+        @labels = labels
+        @leased_until = leased_until
+        @occurred_at = occurred_at
+        @payload = payload
+        @payload_content_type = payload_content_type
+        @receipt = receipt
+        @received_at = received_at
+        @request_attempt_id = request_attempt_id
+        @retry_count = retry_count
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [InboxMessages]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "InboxMessages")
+        new(
+          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
+          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT),
+          labels: Runtime.read(fields, "labels", Runtime::JSON_VALUE),
+          leased_until: Runtime.read(fields, "leased_until", Runtime::DATE_TIME),
+          occurred_at: Runtime.read(fields, "occurred_at", Runtime::DATE_TIME),
+          payload: Runtime.read(fields, "payload", Runtime::TEXT),
+          payload_content_type: Runtime.read(fields, "payload_content_type", Runtime::TEXT),
+          receipt: Runtime.read(fields, "receipt", Runtime::UUID),
+          received_at: Runtime.read(fields, "received_at", Runtime::DATE_TIME),
+          request_attempt_id: Runtime.read(fields, "request_attempt_id", Runtime::UUID),
+          retry_count: Runtime.read(fields, "retry_count", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["event_id"] = @event_id
+        out["event_type_name"] = @event_type_name
+        out["labels"] = @labels
+        out["leased_until"] = Runtime.moment(@leased_until)
+        out["occurred_at"] = Runtime.moment(@occurred_at)
+        out["payload"] = @payload
+        out["payload_content_type"] = @payload_content_type
+        out["receipt"] = @receipt
+        out["received_at"] = Runtime.moment(@received_at)
+        out["request_attempt_id"] = @request_attempt_id
+        out["retry_count"] = @retry_count
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(InboxMessages) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `InboxNack` the API declares.
+    class InboxNack
+      attr_reader :application_id,
+                  :receipts,
+                  :retry_,
+                  :retry_in_s
+
+      # @param application_id [String] carries `application_id`.
+      # @param receipts [Array<String>] carries `receipts`: Receipts of the leased events
+      # @param retry_ [Boolean, nil] carries `retry`: Whether the events are offered again, as new request attempts
+      #   (true by default); they are not beyond the maximum number of retries of the subscription, or of this instance
+      #   if it has none
+      # @param retry_in_s [Integer, nil] carries `retry_in_s`: Delay in seconds before the events are offered again (0
+      #   by default)
+      def initialize(application_id:, receipts:, retry_: nil, retry_in_s: nil)
+        @application_id = application_id
+        @receipts = receipts
+        @retry_ = retry_
+        @retry_in_s = retry_in_s
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [InboxNack]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "InboxNack")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          receipts: Runtime.read(fields, "receipts", Runtime.list(Runtime::UUID)),
+          retry_: Runtime.maybe(fields, "retry", Runtime::BOOLEAN),
+          retry_in_s: Runtime.maybe(fields, "retry_in_s", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["receipts"] = @receipts
+        out["retry"] = @retry_ unless @retry_.nil?
+        out["retry_in_s"] = @retry_in_s unless @retry_in_s.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(InboxNack) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `InboxSettlement` the API declares.
+    class InboxSettlement
+      attr_reader :settled,
+                  :stale
+
+      # @param settled [Array<String>] carries `settled`: Receipts of the events that were settled
+      # @param stale [Array<String>] carries `stale`: Receipts that settled nothing: their lease expired, or the event
+      #   was already settled
+      def initialize(settled:, stale:)
+        @settled = settled
+        @stale = stale
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [InboxSettlement]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "InboxSettlement")
+        new(
+          settled: Runtime.read(fields, "settled", Runtime.list(Runtime::UUID)),
+          stale: Runtime.read(fields, "stale", Runtime.list(Runtime::UUID))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["settled"] = @settled
+        out["stale"] = @stale
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(InboxSettlement) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `IngestedEvent` the API declares.
+    class IngestedEvent
+      attr_reader :application_id,
+                  :event_id,
+                  :received_at,
+                  :deprecation
+
+      # @param application_id [String] carries `application_id`.
+      # @param event_id [String] carries `event_id`.
+      # @param received_at [Time] carries `received_at`.
+      # @param deprecation [IngestedEventDeprecation, nil] carries `deprecation`: Present when the event was ingested
+      #   with a deprecated version of its event type
+      def initialize(application_id:, event_id:, received_at:, deprecation: nil)
+        @application_id = application_id
+        @event_id = event_id
+        @received_at = received_at
+        @deprecation = deprecation
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [IngestedEvent]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "IngestedEvent")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
+          received_at: Runtime.read(fields, "received_at", Runtime::DATE_TIME),
+          deprecation: Runtime.maybe(fields, "deprecation", IngestedEventDeprecation.method(:from_json))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["event_id"] = @event_id
+        out["received_at"] = Runtime.moment(@received_at)
+        out["deprecation"] = @deprecation.to_h unless @deprecation.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(IngestedEvent) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `IngestedEventDeprecation` the API declares.
+    class IngestedEventDeprecation
+      attr_reader :deprecated_at,
+                  :event_type_version,
+                  :sunset_at
+
+      # @param deprecated_at [Time] carries `deprecated_at`.
+      # @param event_type_version [Integer] carries `event_type_version`.
+      # @param sunset_at [Time, nil] carries `sunset_at`.
+      def initialize(deprecated_at:, event_type_version:, sunset_at: nil)
+        @deprecated_at = deprecated_at
+        @event_type_version = event_type_version
+        @sunset_at = sunset_at
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [IngestedEventDeprecation]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "IngestedEventDeprecation")
+        new(
+          deprecated_at: Runtime.read(fields, "deprecated_at", Runtime::DATE_TIME),
+          event_type_version: Runtime.read(fields, "event_type_version", Runtime::INTEGER),
+          sunset_at: Runtime.maybe(fields, "sunset_at", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["deprecated_at"] = Runtime.moment(@deprecated_at)
+        out["event_type_version"] = @event_type_version
+        out["sunset_at"] = Runtime.moment(@sunset_at) unless @sunset_at.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(IngestedEventDeprecation) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `IngestedEventsBatch` the API declares.
+    class IngestedEventsBatch
+      attr_reader :application_id,
+                  :events
+
+      # @param application_id [String] carries `application_id`.
+      # @param events [Array<IngestedEventsBatchEvents>] carries `events`: One outcome per submitted event, in the order
+      #   the events were submitted
+      def initialize(application_id:, events:)
+        @application_id = application_id
+        @events = events
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [IngestedEventsBatch]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "IngestedEventsBatch")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          events: Runtime.read(fields, "events", Runtime.list(IngestedEventsBatchEvents.method(:from_json)))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["events"] = @events.map(&:to_h)
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(IngestedEventsBatch) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `IngestedEventsBatchEvents` the API declares.
+    class IngestedEventsBatchEvents
+      attr_reader :status,
+                  :deprecation,
+                  :event_id,
+                  :problem,
+                  :received_at
+
+      # @param status [String] carries `status`, one of `IngestedEventsBatchEventsStatus::VALUES`.
+      # @param deprecation [IngestedEventsBatchEventsDeprecation, nil] carries `deprecation`: Present when the event was
+      #   ingested with a deprecated version of its event type
+      # @param event_id [String, nil] carries `event_id`: ID of the ingested event, or the one the event was submitted
+      #   with when it was not ingested
+      # @param problem [IngestedEventsBatchEventsProblem, nil] carries `problem`: Present when the event was not
+      #   ingested
+      # @param received_at [Time, nil] carries `received_at`: Present when the event was ingested
+      def initialize(status:, deprecation: nil, event_id: nil, problem: nil, received_at: nil)
+        @status = status
+        @deprecation = deprecation
+        @event_id = event_id
+        @problem = problem
+        @received_at = received_at
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [IngestedEventsBatchEvents]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "IngestedEventsBatchEvents")
+        new(
+          status: Runtime.read(fields, "status", Runtime.member_of(IngestedEventsBatchEventsStatus)),
+          deprecation: Runtime.maybe(fields, "deprecation", IngestedEventsBatchEventsDeprecation.method(:from_json)),
+          event_id: Runtime.maybe(fields, "event_id", Runtime::UUID),
+          problem: Runtime.maybe(fields, "problem", IngestedEventsBatchEventsProblem.method(:from_json)),
+          received_at: Runtime.maybe(fields, "received_at", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["status"] = @status
+        out["deprecation"] = @deprecation.to_h unless @deprecation.nil?
+        out["event_id"] = @event_id unless @event_id.nil?
+        out["problem"] = @problem.to_h unless @problem.nil?
+        out["received_at"] = Runtime.moment(@received_at) unless @received_at.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(IngestedEventsBatchEvents) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `IngestedEventsBatchEventsDeprecation` the API declares.
+    class IngestedEventsBatchEventsDeprecation
+      attr_reader :deprecated_at,
+                  :event_type_version,
+                  :sunset_at
+
+      # @param deprecated_at [Time] carries `deprecated_at`.
+      # @param event_type_version [Integer] carries `event_type_version`.
+      # @param sunset_at [Time, nil] carries `sunset_at`.
+      def initialize(deprecated_at:, event_type_version:, sunset_at: nil)
+        @deprecated_at = deprecated_at
+        @event_type_version = event_type_version
+        @sunset_at = sunset_at
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [IngestedEventsBatchEventsDeprecation]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "IngestedEventsBatchEventsDeprecation")
+        new(
+          deprecated_at: Runtime.read(fields, "deprecated_at", Runtime::DATE_TIME),
+          event_type_version: Runtime.read(fields, "event_type_version", Runtime::INTEGER),
+          sunset_at: Runtime.maybe(fields, "sunset_at", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["deprecated_at"] = Runtime.moment(@deprecated_at)
+        out["event_type_version"] = @event_type_version
+        out["sunset_at"] = Runtime.moment(@sunset_at) unless @sunset_at.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(IngestedEventsBatchEventsDeprecation) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `IngestedEventsBatchEventsProblem` the API declares.
+    class IngestedEventsBatchEventsProblem
+      attr_reader :detail,
+                  :id,
+                  :status,
+                  :title,
+                  :type,
+                  :validation
+
+      # @param detail [String] carries `detail`.
+      # @param id [String] carries `id`, one of `IngestedEventsBatchEventsProblemId::VALUES`: Identifier of the problem
+      #   that occurred, stable across releases
+      # @param status [Integer] carries `status`.
+      # @param title [String] carries `title`.
+      # @param type [String] carries `type`: Documentation page of this problem, which is also what distinguishes one
+      #   problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a
+      #   URL.
+      # @param validation [Object, nil] carries `validation`: Which submitted values were rejected and why, keyed by the
+      #   name of the field they were submitted under. Carried by validation failures (`Validation`, status 422) and
+      #   `null` for every other problem, as
+      def initialize(detail:, id:, status:, title:, type:, validation: nil)
+        @detail = detail
+        @id = id
+        @status = status
+        @title = title
+        @type = type
+        @validation = validation
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [IngestedEventsBatchEventsProblem]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "IngestedEventsBatchEventsProblem")
+        new(
+          detail: Runtime.read(fields, "detail", Runtime::TEXT),
+          id: Runtime.read(fields, "id", Runtime.member_of(IngestedEventsBatchEventsProblemId)),
+          status: Runtime.read(fields, "status", Runtime::INTEGER),
+          title: Runtime.read(fields, "title", Runtime::TEXT),
+          type: Runtime.read(fields, "type", Runtime::TEXT),
+          validation: Runtime.maybe(fields, "validation", Runtime::JSON_VALUE)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["detail"] = @detail
+        out["id"] = @id
+        out["status"] = @status
+        out["title"] = @title
+        out["type"] = @type
+        out["validation"] = @validation unless @validation.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(IngestedEventsBatchEventsProblem) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `InstanceConfig` the API declares.
+    class InstanceConfig
+      attr_reader :application_secret_compatibility,
+                  :auto_db_migration,
+                  :biscuit_public_key,
+                  :password_minimum_length,
+                  :quota_enforcement,
+                  :registration_disabled,
+                  :support_email_address,
+                  :cloudflare_turnstile_site_key,
+                  :formbricks,
+                  :matomo
+
+      # @param application_secret_compatibility [Boolean] carries `application_secret_compatibility`.
+      # @param auto_db_migration [Boolean] carries `auto_db_migration`.
+      # @param biscuit_public_key [String] carries `biscuit_public_key`.
+      # @param password_minimum_length [Integer] carries `password_minimum_length`.
+      # @param quota_enforcement [Boolean] carries `quota_enforcement`.
+      # @param registration_disabled [Boolean] carries `registration_disabled`.
+      # @param support_email_address [String] carries `support_email_address`.
+      # @param cloudflare_turnstile_site_key [String, nil] carries `cloudflare_turnstile_site_key`.
+      # @param formbricks [InstanceConfigFormbricks, nil] carries `formbricks`.
+      # @param matomo [InstanceConfigMatomo, nil] carries `matomo`.
+      def initialize(
+        application_secret_compatibility:,
+        auto_db_migration:,
+        biscuit_public_key:,
+        password_minimum_length:,
+        quota_enforcement:,
+        registration_disabled:,
+        support_email_address:,
+        cloudflare_turnstile_site_key: nil,
+        formbricks: nil,
+        matomo: nil
+      )
+        @application_secret_compatibility = application_secret_compatibility
+        @auto_db_migration = auto_db_migration
+        @biscuit_public_key = biscuit_public_key
+        @password_minimum_length = password_minimum_length
+        @quota_enforcement = quota_enforcement
+        @registration_disabled = registration_disabled
+        @support_email_address = support_email_address
+        @cloudflare_turnstile_site_key = cloudflare_turnstile_site_key
+        @formbricks = formbricks
+        @matomo = matomo
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [InstanceConfig]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "InstanceConfig")
+        new(
+          application_secret_compatibility: Runtime.read(fields, "application_secret_compatibility", Runtime::BOOLEAN),
+          auto_db_migration: Runtime.read(fields, "auto_db_migration", Runtime::BOOLEAN),
+          biscuit_public_key: Runtime.read(fields, "biscuit_public_key", Runtime::TEXT),
+          password_minimum_length: Runtime.read(fields, "password_minimum_length", Runtime::INTEGER),
+          quota_enforcement: Runtime.read(fields, "quota_enforcement", Runtime::BOOLEAN),
+          registration_disabled: Runtime.read(fields, "registration_disabled", Runtime::BOOLEAN),
+          support_email_address: Runtime.read(fields, "support_email_address", Runtime::TEXT),
+          cloudflare_turnstile_site_key: Runtime.maybe(fields, "cloudflare_turnstile_site_key", Runtime::TEXT),
+          formbricks: Runtime.maybe(fields, "formbricks", InstanceConfigFormbricks.method(:from_json)),
+          matomo: Runtime.maybe(fields, "matomo", InstanceConfigMatomo.method(:from_json))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_secret_compatibility"] = @application_secret_compatibility
+        out["auto_db_migration"] = @auto_db_migration
+        out["biscuit_public_key"] = @biscuit_public_key
+        out["password_minimum_length"] = @password_minimum_length
+        out["quota_enforcement"] = @quota_enforcement
+        out["registration_disabled"] = @registration_disabled
+        out["support_email_address"] = @support_email_address
+        out["cloudflare_turnstile_site_key"] = @cloudflare_turnstile_site_key unless @cloudflare_turnstile_site_key.nil?
+        out["formbricks"] = @formbricks.to_h unless @formbricks.nil?
+        out["matomo"] = @matomo.to_h unless @matomo.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(InstanceConfig) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `InstanceConfigFormbricks` the API declares.
+    class InstanceConfigFormbricks
+      attr_reader :api_host,
+                  :environment_id
+
+      # @param api_host [String] carries `api_host`.
+      # @param environment_id [String] carries `environment_id`.
+      def initialize(api_host:, environment_id:)
+        @api_host = api_host
+        @environment_id = environment_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [InstanceConfigFormbricks]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "InstanceConfigFormbricks")
+        new(
+          api_host: Runtime.read(fields, "api_host", Runtime::TEXT),
+          environment_id: Runtime.read(fields, "environment_id", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["api_host"] = @api_host
+        out["environment_id"] = @environment_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(InstanceConfigFormbricks) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `InstanceConfigMatomo` the API declares.
+    class InstanceConfigMatomo
+      attr_reader :site_id,
+                  :url
+
+      # @param site_id [Integer] carries `site_id`.
+      # @param url [String] carries `url`.
+      def initialize(site_id:, url:)
+        @site_id = site_id
+        @url = url
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [InstanceConfigMatomo]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "InstanceConfigMatomo")
+        new(
+          site_id: Runtime.read(fields, "site_id", Runtime::INTEGER),
+          url: Runtime.read(fields, "url", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["site_id"] = @site_id
+        out["url"] = @url
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(InstanceConfigMatomo) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `JsonWebKeySet` the API declares.
+    class JsonWebKeySet
+      attr_reader :keys
+
+      # @param keys [Array<JsonWebKeySetKeys>] carries `keys`.
+      def initialize(keys:)
+        @keys = keys
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [JsonWebKeySet]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "JsonWebKeySet")
+        new(
+          keys: Runtime.read(fields, "keys", Runtime.list(JsonWebKeySetKeys.method(:from_json)))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["keys"] = @keys.map(&:to_h)
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(JsonWebKeySet) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `JsonWebKeySetKeys` the API declares.
+    class JsonWebKeySetKeys
+      attr_reader :alg,
+                  :crv,
+                  :kid,
+                  :kty,
+                  :use,
+                  :x
+
+      # @param alg [String] carries `alg`: Always `EdDSA`
+      # @param crv [String] carries `crv`: Always `Ed25519`
+      # @param kid [String] carries `kid`: ID of the signing key, which signatures name in their `k` part
+      # @param kty [String] carries `kty`: Always `OKP`
+      # @param use [String] carries `use`: Always `sig`
+      # @param x [String] carries `x`: Public key, encoded in base64url without padding
+      def initialize(alg:, crv:, kid:, kty:, use:, x:)
+        @alg = alg
+        @crv = crv
+        @kid = kid
+        @kty = kty
+        @use = use
+        @x = x
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [JsonWebKeySetKeys]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "JsonWebKeySetKeys")
+        new(
+          alg: Runtime.read(fields, "alg", Runtime::TEXT),
+          crv: Runtime.read(fields, "crv", Runtime::TEXT),
+          kid: Runtime.read(fields, "kid", Runtime::TEXT),
+          kty: Runtime.read(fields, "kty", Runtime::TEXT),
+          use: Runtime.read(fields, "use", Runtime::TEXT),
+          x: Runtime.read(fields, "x", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["alg"] = @alg
+        out["crv"] = @crv
+        out["kid"] = @kid
+        out["kty"] = @kty
+        out["use"] = @use
+        out["x"] = @x
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(JsonWebKeySetKeys) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OAuth2ClientCredentials` the API declares.
+    class Oauth2ClientCredentials
+      attr_reader :client_id,
+                  :created_at,
+                  :scopes,
+                  :subscription_id,
+                  :token_url,
+                  :updated_at
+
+      # @param client_id [String] carries `client_id`.
+      # @param created_at [Time] carries `created_at`.
+      # @param scopes [Array<String>] carries `scopes`.
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param token_url [String] carries `token_url`.
+      # @param updated_at [Time] carries `updated_at`.
+      def initialize(client_id:, created_at:, scopes:, subscription_id:, token_url:, updated_at:)
+        @client_id = client_id
+        @created_at = created_at
+        @scopes = scopes
+        @subscription_id = subscription_id
+        @token_url = token_url
+        @updated_at = updated_at
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [Oauth2ClientCredentials]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OAuth2ClientCredentials")
+        new(
+          client_id: Runtime.read(fields, "client_id", Runtime::TEXT),
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          scopes: Runtime.read(fields, "scopes", Runtime.list(Runtime::TEXT)),
+          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID),
+          token_url: Runtime.read(fields, "token_url", Runtime::TEXT),
+          updated_at: Runtime.read(fields, "updated_at", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["client_id"] = @client_id
+        out["created_at"] = Runtime.moment(@created_at)
+        out["scopes"] = @scopes
+        out["subscription_id"] = @subscription_id
+        out["token_url"] = @token_url
+        out["updated_at"] = Runtime.moment(@updated_at)
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(Oauth2ClientCredentials) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OAuth2ClientCredentialsPut` the API declares.
+    class Oauth2ClientCredentialsPut
+      attr_reader :client_id,
+                  :client_secret,
+                  :token_url,
+                  :scopes
+
+      # @param client_id [String] carries `client_id`.
+      # @param client_secret [String] carries `client_secret`.
+      # @param token_url [String] carries `token_url`: Token endpoint of the authorization server
+      # @param scopes [Array<String>, nil] carries `scopes`: Scopes to request; none by default
+      def initialize(client_id:, client_secret:, token_url:, scopes: nil)
+        @client_id = client_id
+        @client_secret = client_secret
+        @token_url = token_url
+        @scopes = scopes
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [Oauth2ClientCredentialsPut]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OAuth2ClientCredentialsPut")
+        new(
+          client_id: Runtime.read(fields, "client_id", Runtime::TEXT),
+          client_secret: Runtime.read(fields, "client_secret", Runtime::TEXT),
+          token_url: Runtime.read(fields, "token_url", Runtime::TEXT),
+          scopes: Runtime.maybe(fields, "scopes", Runtime.list(Runtime::TEXT))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["client_id"] = @client_id
+        out["client_secret"] = @client_secret
+        out["token_url"] = @token_url
+        out["scopes"] = @scopes unless @scopes.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(Oauth2ClientCredentialsPut) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `Organization` the API declares.
+    class Organization
+      attr_reader :name,
+                  :organization_id,
+                  :role,
+                  :plan
+
+      # @param name [String] carries `name`.
+      # @param organization_id [String] carries `organization_id`.
+      # @param role [String] carries `role`.
+      # @param plan [OrganizationPlan, nil] carries `plan`.
+      def initialize(name:, organization_id:, role:, plan: nil)
+        @name = name
+        @organization_id = organization_id
+        @role = role
+        @plan = plan
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [Organization]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "Organization")
+        new(
+          name: Runtime.read(fields, "name", Runtime::TEXT),
+          organization_id: Runtime.read(fields, "organization_id", Runtime::UUID),
+          role: Runtime.read(fields, "role", Runtime::TEXT),
+          plan: Runtime.maybe(fields, "plan", OrganizationPlan.method(:from_json))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["name"] = @name
+        out["organization_id"] = @organization_id
+        out["role"] = @role
+        out["plan"] = @plan.to_h unless @plan.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(Organization) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OrganizationInfo` the API declares.
+    class OrganizationInfo
+      attr_reader :consumption,
+                  :name,
+                  :onboarding_steps,
+                  :organization_id,
+                  :quotas,
+                  :users,
+                  :plan
+
+      # @param consumption [OrganizationInfoConsumption] carries `consumption`.
+      # @param name [String] carries `name`.
+      # @param onboarding_steps [OrganizationInfoOnboardingSteps] carries `onboarding_steps`.
+      # @param organization_id [String] carries `organization_id`.
+      # @param quotas [OrganizationInfoQuotas] carries `quotas`.
+      # @param users [Array<OrganizationInfoUsers>] carries `users`.
+      # @param plan [OrganizationInfoPlan, nil] carries `plan`.
+      def initialize(consumption:, name:, onboarding_steps:, organization_id:, quotas:, users:, plan: nil)
+        @consumption = consumption
+        @name = name
+        @onboarding_steps = onboarding_steps
+        @organization_id = organization_id
+        @quotas = quotas
+        @users = users
+        @plan = plan
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [OrganizationInfo]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OrganizationInfo")
+        new(
+          consumption: Runtime.read(fields, "consumption", OrganizationInfoConsumption.method(:from_json)),
+          name: Runtime.read(fields, "name", Runtime::TEXT),
+          onboarding_steps: Runtime.read(
+            fields,
+            "onboarding_steps",
+            OrganizationInfoOnboardingSteps.method(:from_json)
+          ),
+          organization_id: Runtime.read(fields, "organization_id", Runtime::UUID),
+          quotas: Runtime.read(fields, "quotas", OrganizationInfoQuotas.method(:from_json)),
+          users: Runtime.read(fields, "users", Runtime.list(OrganizationInfoUsers.method(:from_json))),
+          plan: Runtime.maybe(fields, "plan", OrganizationInfoPlan.method(:from_json))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["consumption"] = @consumption.to_h
+        out["name"] = @name
+        out["onboarding_steps"] = @onboarding_steps.to_h
+        out["organization_id"] = @organization_id
+        out["quotas"] = @quotas.to_h
+        out["users"] = @users.map(&:to_h)
+        out["plan"] = @plan.to_h unless @plan.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(OrganizationInfo) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OrganizationInfoConsumption` the API declares.
+    class OrganizationInfoConsumption
+      attr_reader :applications,
+                  :events_per_day,
+                  :members
+
+      # @param applications [Integer, nil] carries `applications`.
+      # @param events_per_day [Integer, nil] carries `events_per_day`.
+      # @param members [Integer, nil] carries `members`.
+      def initialize(applications: nil, events_per_day: nil, members: nil)
+        @applications = applications
+        @events_per_day = events_per_day
+        @members = members
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [OrganizationInfoConsumption]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OrganizationInfoConsumption")
+        new(
+          applications: Runtime.maybe(fields, "applications", Runtime::INTEGER),
+          events_per_day: Runtime.maybe(fields, "events_per_day", Runtime::INTEGER),
+          members: Runtime.maybe(fields, "members", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["applications"] = @applications unless @applications.nil?
+        out["events_per_day"] = @events_per_day unless @events_per_day.nil?
+        out["members"] = @members unless @members.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(OrganizationInfoConsumption) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OrganizationInfoOnboardingSteps` the API declares.
+    class OrganizationInfoOnboardingSteps
+      attr_reader :application,
+                  :event,
+                  :event_type,
+                  :subscription
+
+      # @param application [String] carries `application`, one of `OrganizationInfoOnboardingStepsApplication::VALUES`.
+      # @param event [String] carries `event`, one of `OrganizationInfoOnboardingStepsEvent::VALUES`.
+      # @param event_type [String] carries `event_type`, one of `OrganizationInfoOnboardingStepsEventType::VALUES`.
+      # @param subscription [String] carries `subscription`, one of
+      #   `OrganizationInfoOnboardingStepsSubscription::VALUES`.
+      def initialize(application:, event:, event_type:, subscription:)
+        @application = application
+        @event = event
+        @event_type = event_type
+        @subscription = subscription
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [OrganizationInfoOnboardingSteps]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OrganizationInfoOnboardingSteps")
+        new(
+          application: Runtime.read(
+            fields,
+            "application",
+            Runtime.member_of(OrganizationInfoOnboardingStepsApplication)
+          ),
+          event: Runtime.read(fields, "event", Runtime.member_of(OrganizationInfoOnboardingStepsEvent)),
+          event_type: Runtime.read(fields, "event_type", Runtime.member_of(OrganizationInfoOnboardingStepsEventType)),
+          subscription: Runtime.read(
+            fields,
+            "subscription",
+            Runtime.member_of(OrganizationInfoOnboardingStepsSubscription)
+          )
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application"] = @application
+        out["event"] = @event
+        out["event_type"] = @event_type
+        out["subscription"] = @subscription
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(OrganizationInfoOnboardingSteps) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OrganizationInfoPlan` the API declares.
+    class OrganizationInfoPlan
+      attr_reader :label,
+                  :name
+
+      # @param label [String] carries `label`.
+      # @param name [String] carries `name`.
+      def initialize(label:, name:)
+        @label = label
+        @name = name
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [OrganizationInfoPlan]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OrganizationInfoPlan")
+        new(
+          label: Runtime.read(fields, "label", Runtime::TEXT),
+          name: Runtime.read(fields, "name", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["label"] = @label
+        out["name"] = @name
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(OrganizationInfoPlan) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OrganizationInfoQuotas` the API declares.
+    class OrganizationInfoQuotas
+      attr_reader :applications_per_organization_limit,
+                  :days_of_events_retention_limit,
+                  :events_per_day_limit,
+                  :members_per_organization_limit
+
+      # @param applications_per_organization_limit [Integer] carries `applications_per_organization_limit`.
+      # @param days_of_events_retention_limit [Integer] carries `days_of_events_retention_limit`.
+      # @param events_per_day_limit [Integer] carries `events_per_day_limit`.
+      # @param members_per_organization_limit [Integer] carries `members_per_organization_limit`.
+      def initialize(
+        applications_per_organization_limit:,
+        days_of_events_retention_limit:,
+        events_per_day_limit:,
+        members_per_organization_limit:
+      )
+        @applications_per_organization_limit = applications_per_organization_limit
+        @days_of_events_retention_limit = days_of_events_retention_limit
+        @events_per_day_limit = events_per_day_limit
+        @members_per_organization_limit = members_per_organization_limit
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [OrganizationInfoQuotas]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OrganizationInfoQuotas")
+        new(
+          applications_per_organization_limit: Runtime.read(
+            fields,
+            "applications_per_organization_limit",
+            Runtime::INTEGER
+          ),
+          days_of_events_retention_limit: Runtime.read(fields, "days_of_events_retention_limit", Runtime::INTEGER),
+          events_per_day_limit: Runtime.read(fields, "events_per_day_limit", Runtime::INTEGER),
+          members_per_organization_limit: Runtime.read(fields, "members_per_organization_limit", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["applications_per_organization_limit"] = @applications_per_organization_limit
+        out["days_of_events_retention_limit"] = @days_of_events_retention_limit
+        out["events_per_day_limit"] = @events_per_day_limit
+        out["members_per_organization_limit"] = @members_per_organization_limit
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(OrganizationInfoQuotas) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OrganizationInfoUsers` the API declares.
+    class OrganizationInfoUsers
+      attr_reader :email,
+                  :first_name,
+                  :last_name,
+                  :role,
+                  :user_id
+
+      # @param email [String] carries `email`.
+      # @param first_name [String] carries `first_name`.
+      # @param last_name [String] carries `last_name`.
+      # @param role [String] carries `role`.
+      # @param user_id [String] carries `user_id`.
+      def initialize(email:, first_name:, last_name:, role:, user_id:)
+        @email = email
+        @first_name = first_name
+        @last_name = last_name
+        @role = role
+        @user_id = user_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [OrganizationInfoUsers]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OrganizationInfoUsers")
+        new(
+          email: Runtime.read(fields, "email", Runtime::TEXT),
+          first_name: Runtime.read(fields, "first_name", Runtime::TEXT),
+          last_name: Runtime.read(fields, "last_name", Runtime::TEXT),
+          role: Runtime.read(fields, "role", Runtime::TEXT),
+          user_id: Runtime.read(fields, "user_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["email"] = @email
+        out["first_name"] = @first_name
+        out["last_name"] = @last_name
+        out["role"] = @role
+        out["user_id"] = @user_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(OrganizationInfoUsers) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `OrganizationPlan` the API declares.
+    class OrganizationPlan
+      attr_reader :label,
+                  :name
+
+      # @param label [String] carries `label`.
+      # @param name [String] carries `name`.
+      def initialize(label:, name:)
+        @label = label
+        @name = name
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [OrganizationPlan]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "OrganizationPlan")
+        new(
+          label: Runtime.read(fields, "label", Runtime::TEXT),
+          name: Runtime.read(fields, "name", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["label"] = @label
+        out["name"] = @name
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(OrganizationPlan) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `Problem` the API declares.
+    class Problem
+      attr_reader :detail,
+                  :id,
+                  :status,
+                  :title,
+                  :type,
+                  :validation
+
+      # @param detail [String] carries `detail`.
+      # @param id [String] carries `id`, one of `ProblemId::VALUES`: Identifier of the problem that occurred, stable
+      #   across releases
+      # @param status [Integer] carries `status`.
+      # @param title [String] carries `title`.
+      # @param type [String] carries `type`: Documentation page of this problem, which is also what distinguishes one
+      #   problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a
+      #   URL.
+      # @param validation [Object, nil] carries `validation`: Which submitted values were rejected and why, keyed by the
+      #   name of the field they were submitted under. Carried by validation failures (`Validation`, status 422) and
+      #   `null` for every other problem, as
+      def initialize(detail:, id:, status:, title:, type:, validation: nil)
+        @detail = detail
+        @id = id
+        @status = status
+        @title = title
+        @type = type
+        @validation = validation
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [Problem]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "Problem")
+        new(
+          detail: Runtime.read(fields, "detail", Runtime::TEXT),
+          id: Runtime.read(fields, "id", Runtime.member_of(ProblemId)),
+          status: Runtime.read(fields, "status", Runtime::INTEGER),
+          title: Runtime.read(fields, "title", Runtime::TEXT),
+          type: Runtime.read(fields, "type", Runtime::TEXT),
+          validation: Runtime.maybe(fields, "validation", Runtime::JSON_VALUE)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["detail"] = @detail
+        out["id"] = @id
+        out["status"] = @status
+        out["title"] = @title
+        out["type"] = @type
+        out["validation"] = @validation unless @validation.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(Problem) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `PurgeDeadLetters` the API declares.
+    class PurgeDeadLetters
+      attr_reader :application_id,
+                  :subscription_id
+
+      # @param application_id [String] carries `application_id`.
+      # @param subscription_id [String, nil] carries `subscription_id`: Only purge the dead letters of this subscription
+      def initialize(application_id:, subscription_id: nil)
+        @application_id = application_id
+        @subscription_id = subscription_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [PurgeDeadLetters]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "PurgeDeadLetters")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          subscription_id: Runtime.maybe(fields, "subscription_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["subscription_id"] = @subscription_id unless @subscription_id.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(PurgeDeadLetters) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `PurgedDeadLetters` the API declares.
+    class PurgedDeadLetters
+      attr_reader :purged_count
+
+      # @param purged_count [Integer] carries `purged_count`.
+      def initialize(purged_count:)
+        @purged_count = purged_count
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [PurgedDeadLetters]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "PurgedDeadLetters")
+        new(
+          purged_count: Runtime.read(fields, "purged_count", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["purged_count"] = @purged_count
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(PurgedDeadLetters) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `QuotasResponse` the API declares.
+    class QuotasResponse
+      attr_reader :enabled,
+                  :limits
+
+      # @param enabled [Boolean] carries `enabled`.
+      # @param limits [QuotasResponseLimits] carries `limits`.
+      def initialize(enabled:, limits:)
+        @enabled = enabled
+        @limits = limits
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [QuotasResponse]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "QuotasResponse")
+        new(
+          enabled: Runtime.read(fields, "enabled", Runtime::BOOLEAN),
+          limits: Runtime.read(fields, "limits", QuotasResponseLimits.method(:from_json))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["enabled"] = @enabled
+        out["limits"] = @limits.to_h
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(QuotasResponse) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `QuotasResponseLimits` the API declares.
+    class QuotasResponseLimits
+      attr_reader :global_applications_per_organization_limit,
+                  :global_days_of_events_retention_limit,
+                  :global_event_types_per_application_limit,
+                  :global_events_per_day_limit,
+                  :global_members_per_organization_limit,
+                  :global_subscriptions_per_application_limit
+
+      # @param global_applications_per_organization_limit [Integer] carries
+      #   `global_applications_per_organization_limit`.
+      # @param global_days_of_events_retention_limit [Integer] carries `global_days_of_events_retention_limit`.
+      # @param global_event_types_per_application_limit [Integer] carries `global_event_types_per_application_limit`.
+      # @param global_events_per_day_limit [Integer] carries `global_events_per_day_limit`.
+      # @param global_members_per_organization_limit [Integer] carries `global_members_per_organization_limit`.
+      # @param global_subscriptions_per_application_limit [Integer] carries
+      #   `global_subscriptions_per_application_limit`.
+      def initialize(
+        global_applications_per_organization_limit:,
+        global_days_of_events_retention_limit:,
+        global_event_types_per_application_limit:,
+        global_events_per_day_limit:,
+        global_members_per_organization_limit:,
+        global_subscriptions_per_application_limit:
+      )
+        @global_applications_per_organization_limit = global_applications_per_organization_limit
+        @global_days_of_events_retention_limit = global_days_of_events_retention_limit
+        @global_event_types_per_application_limit = global_event_types_per_application_limit
+        @global_events_per_day_limit = global_events_per_day_limit
+        @global_members_per_organization_limit = global_members_per_organization_limit
+        @global_subscriptions_per_application_limit = global_subscriptions_per_application_limit
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [QuotasResponseLimits]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "QuotasResponseLimits")
+        new(
+          global_applications_per_organization_limit: Runtime.read(
+            fields,
+            "global_applications_per_organization_limit",
+            Runtime::INTEGER
+          ),
+          global_days_of_events_retention_limit: Runtime.read(
+            fields,
+            "global_days_of_events_retention_limit",
+            Runtime::INTEGER
+          ),
+          global_event_types_per_application_limit: Runtime.read(
+            fields,
+            "global_event_types_per_application_limit",
+            Runtime::INTEGER
+          ),
+          global_events_per_day_limit: Runtime.read(fields, "global_events_per_day_limit", Runtime::INTEGER),
+          global_members_per_organization_limit: Runtime.read(
+            fields,
+            "global_members_per_organization_limit",
+            Runtime::INTEGER
+          ),
+          global_subscriptions_per_application_limit: Runtime.read(
+            fields,
+            "global_subscriptions_per_application_limit",
+            Runtime::INTEGER
+          )
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["global_applications_per_organization_limit"] = @global_applications_per_organization_limit
+        out["global_days_of_events_retention_limit"] = @global_days_of_events_retention_limit
+        out["global_event_types_per_application_limit"] = @global_event_types_per_application_limit
+        out["global_events_per_day_limit"] = @global_events_per_day_limit
+        out["global_members_per_organization_limit"] = @global_members_per_organization_limit
+        out["global_subscriptions_per_application_limit"] = @global_subscriptions_per_application_limit
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(QuotasResponseLimits) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `RedeliverDeadLetter` the API declares.
+    class RedeliverDeadLetter
+      attr_reader :application_id
+
+      # @param application_id [String] carries `application_id`.
+      def initialize(application_id:)
+        @application_id = application_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [RedeliverDeadLetter]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "RedeliverDeadLetter")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(RedeliverDeadLetter) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `RedeliverDeadLetters` the API declares.
+    class RedeliverDeadLetters
+      attr_reader :application_id,
+                  :subscription_id
+
+      # @param application_id [String] carries `application_id`.
+      # @param subscription_id [String, nil] carries `subscription_id`: Only redeliver the dead letters of this
+      #   subscription
+      def initialize(application_id:, subscription_id: nil)
+        @application_id = application_id
+        @subscription_id = subscription_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [RedeliverDeadLetters]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "RedeliverDeadLetters")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          subscription_id: Runtime.maybe(fields, "subscription_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["subscription_id"] = @subscription_id unless @subscription_id.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(RedeliverDeadLetters) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `RedeliveredDeadLetter` the API declares.
+    class RedeliveredDeadLetter
+      attr_reader :dead_letter_id,
+                  :request_attempt_id
+
+      # @param dead_letter_id [String] carries `dead_letter_id`.
+      # @param request_attempt_id [String] carries `request_attempt_id`.
+      def initialize(dead_letter_id:, request_attempt_id:)
+        @dead_letter_id = dead_letter_id
+        @request_attempt_id = request_attempt_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [RedeliveredDeadLetter]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "RedeliveredDeadLetter")
+        new(
+          dead_letter_id: Runtime.read(fields, "dead_letter_id", Runtime::UUID),
+          request_attempt_id: Runtime.read(fields, "request_attempt_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["dead_letter_id"] = @dead_letter_id
+        out["request_attempt_id"] = @request_attempt_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(RedeliveredDeadLetter) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `ReplayEvent` the API declares.
+    class ReplayEvent
+      attr_reader :application_id
+
+      # @param application_id [String] carries `application_id`.
+      def initialize(application_id:)
+        @application_id = application_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [ReplayEvent]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "ReplayEvent")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(ReplayEvent) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `RequestAttempt` the API declares.
+    class RequestAttempt
+      attr_reader :created_at,
+                  :event,
+                  :event_id,
+                  :request_attempt_id,
+                  :retry_count,
+                  :status,
+                  :subscription,
+                  :dead_letter_id,
+                  :delay_until,
+                  :delivery_batch_id,
+                  :failed_at,
+                  :http_response_status,
+                  :picked_at,
+                  :response_id,
+                  :succeeded_at
+
+      # @param created_at [Time] carries `created_at`.
+      # @param event [RequestAttemptEvent] carries `event`.
+      # @param event_id [String] carries `event_id`.
+      # @param request_attempt_id [String] carries `request_attempt_id`.
+      # @param retry_count [Integer] carries `retry_count`.
+      # @param status [RequestAttemptStatus] carries `status`: Status of a request attempt. The 'type' field indicates
+      #   the status variant. - waiting: {type, since, until} - Scheduled for future delivery - pending: {type, since} -
+      #   Ready to be processed - in_progre
+      # @param subscription [RequestAttemptSubscription] carries `subscription`.
+      # @param dead_letter_id [String, nil] carries `dead_letter_id`: Dead letter the request attempt became, when it
+      #   was the last attempt of a delivery an output worker gave up on
+      # @param delay_until [Time, nil] carries `delay_until`.
+      # @param delivery_batch_id [String, nil] carries `delivery_batch_id`: Batch the request attempt was delivered in,
+      #   shared with the other request attempts of the batch and their response
+      # @param failed_at [Time, nil] carries `failed_at`.
+      # @param http_response_status [Integer, nil] carries `http_response_status`.
+      # @param picked_at [Time, nil] carries `picked_at`.
+      # @param response_id [String, nil] carries `response_id`.
+      # @param succeeded_at [Time, nil] carries `succeeded_at`.
+      def initialize(
+        created_at:,
+        event:,
+        event_id:,
+        request_attempt_id:,
+        retry_count:,
+        status:,
+        subscription:,
+        dead_letter_id: nil,
+        delay_until: nil,
+        delivery_batch_id: nil,
+        failed_at: nil,
+        http_response_status: nil,
+        picked_at: nil,
+        response_id: nil,
+        succeeded_at: nil
+      )
+        @created_at = created_at
+        @event = event
+        @event_id = event_id
+        @request_attempt_id = request_attempt_id
+        @retry_count = retry_count
+        @status = status
+        @subscription = subscription
+        @dead_letter_id = dead_letter_id
+        @delay_until = delay_until
+        @delivery_batch_id = delivery_batch_id
+        @failed_at = failed_at
+        @http_response_status = http_response_status
+        @picked_at = picked_at
+        @response_id = response_id
+        @succeeded_at = succeeded_at
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [RequestAttempt]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "RequestAttempt")
+        new(
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          event: Runtime.read(fields, "event", RequestAttemptEvent.method(:from_json)),
+          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
+          request_attempt_id: Runtime.read(fields, "request_attempt_id", Runtime::UUID),
+          retry_count: Runtime.read(fields, "retry_count", Runtime::INTEGER),
+          status: Runtime.read(fields, "status", RequestAttemptStatus.method(:from_json)),
+          subscription: Runtime.read(fields, "subscription", RequestAttemptSubscription.method(:from_json)),
+          dead_letter_id: Runtime.maybe(fields, "dead_letter_id", Runtime::UUID),
+          delay_until: Runtime.maybe(fields, "delay_until", Runtime::DATE_TIME),
+          delivery_batch_id: Runtime.maybe(fields, "delivery_batch_id", Runtime::UUID),
+          failed_at: Runtime.maybe(fields, "failed_at", Runtime::DATE_TIME),
+          http_response_status: Runtime.maybe(fields, "http_response_status", Runtime::INTEGER),
+          picked_at: Runtime.maybe(fields, "picked_at", Runtime::DATE_TIME),
+          response_id: Runtime.maybe(fields, "response_id", Runtime::UUID),
+          succeeded_at: Runtime.maybe(fields, "succeeded_at", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["created_at"] = Runtime.moment(@created_at)
+        out["event"] = @event.to_h
+        out["event_id"] = @event_id
+        out["request_attempt_id"] = @request_attempt_id
+        out["retry_count"] = @retry_count
+        out["status"] = @status.to_h
+        out["subscription"] = @subscription.to_h
+        out["dead_letter_id"] = @dead_letter_id unless @dead_letter_id.nil?
+        out["delay_until"] = Runtime.moment(@delay_until) unless @delay_until.nil?
+        out["delivery_batch_id"] = @delivery_batch_id unless @delivery_batch_id.nil?
+        out["failed_at"] = Runtime.moment(@failed_at) unless @failed_at.nil?
+        out["http_response_status"] = @http_response_status unless @http_response_status.nil?
+        out["picked_at"] = Runtime.moment(@picked_at) unless @picked_at.nil?
+        out["response_id"] = @response_id unless @response_id.nil?
+        out["succeeded_at"] = Runtime.moment(@succeeded_at) unless @succeeded_at.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(RequestAttempt) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `RequestAttemptEvent` the API declares.
+    class RequestAttemptEvent
+      attr_reader :event_id,
+                  :event_type_name
+
+      # @param event_id [String] carries `event_id`.
+      # @param event_type_name [String] carries `event_type_name`.
+      def initialize(event_id:, event_type_name:)
+        @event_id = event_id
+        @event_type_name = event_type_name
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [RequestAttemptEvent]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "RequestAttemptEvent")
+        new(
+          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
+          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["event_id"] = @event_id
+        out["event_type_name"] = @event_type_name
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(RequestAttemptEvent) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `RequestAttemptStatus` the API declares.
+    class RequestAttemptStatus
+      attr_reader :type,
+                  :at,
+                  :full_processing_ms,
+                  :rule_outcome,
+                  :since,
+                  :until_
+
+      # @param type [String] carries `type`, one of `RequestAttemptStatusType::VALUES`: Status type discriminator. One
+      #   of: waiting, pending, in_progress, successful, failed, expired, cancelled
+      # @param at [Time, nil] carries `at`: Timestamp when completed (present in successful, failed, expired, cancelled)
+      # @param full_processing_ms [Integer, nil] carries `full_processing_ms`: Total processing time in milliseconds
+      #   (present in successful, failed)
+      # @param rule_outcome [String, nil] carries `rule_outcome`, one of `RequestAttemptStatusRuleOutcome::VALUES`:
+      #   Outcome given by a response rule of the subscription, when one matched the response (present in successful,
+      #   failed). One of: success, retryable, permanent_failure, disable_subscription
+      # @param since [Time, nil] carries `since`: Timestamp when the status started (present in waiting, pending,
+      #   in_progress)
+      # @param until_ [Time, nil] carries `until`: Timestamp until which waiting (only present in waiting status)
+      def initialize(type:, at: nil, full_processing_ms: nil, rule_outcome: nil, since: nil, until_: nil)
+        @type = type
+        @at = at
+        @full_processing_ms = full_processing_ms
+        @rule_outcome = rule_outcome
+        @since = since
+        @until_ = until_
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [RequestAttemptStatus]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "RequestAttemptStatus")
+        new(
+          type: Runtime.read(fields, "type", Runtime.member_of(RequestAttemptStatusType)),
+          at: Runtime.maybe(fields, "at", Runtime::DATE_TIME),
+          full_processing_ms: Runtime.maybe(fields, "full_processing_ms", Runtime::INTEGER),
+          rule_outcome: Runtime.maybe(fields, "rule_outcome", Runtime.member_of(RequestAttemptStatusRuleOutcome)),
+          since: Runtime.maybe(fields, "since", Runtime::DATE_TIME),
+          until_: Runtime.maybe(fields, "until", Runtime::DATE_TIME)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["type"] = @type
+        out["at"] = Runtime.moment(@at) unless @at.nil?
+        out["full_processing_ms"] = @full_processing_ms unless @full_processing_ms.nil?
+        out["rule_outcome"] = @rule_outcome unless @rule_outcome.nil?
+        out["since"] = Runtime.moment(@since) unless @since.nil?
+        out["until"] = Runtime.moment(@until_) unless @until_.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(RequestAttemptStatus) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `RequestAttemptSubscription` the API declares.
+    class RequestAttemptSubscription
+      attr_reader :subscription_id,
+                  :description
+
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param description [String, nil] carries `description`.
+      def initialize(subscription_id:, description: nil)
+        @subscription_id = subscription_id
+        @description = description
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [RequestAttemptSubscription]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "RequestAttemptSubscription")
+        new(
+          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID),
+          description: Runtime.maybe(fields, "description", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["subscription_id"] = @subscription_id
+        out["description"] = @description unless @description.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(RequestAttemptSubscription) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `Response` the API declares.
+    class Response
+      attr_reader :response_id,
+                  :body,
+                  :elapsed_time_ms,
+                  :headers,
+                  :http_code,
+                  :response_error_name
+
+      # @param response_id [String] carries `response_id`.
+      # @param body [String, nil] carries `body`.
+      # @param elapsed_time_ms [Integer, nil] carries `elapsed_time_ms`.
+      # @param headers [Hash{String => String}, nil] carries `headers`.
+      # @param http_code [Integer, nil] carries `http_code`.
+      # @param response_error_name [String, nil] carries `response_error_name`.
+      def initialize(
+        response_id:,
+        body: nil,
+        elapsed_time_ms: nil,
+        headers: nil,
+        http_code: nil,
+        response_error_name: nil
+      )
+        @response_id = response_id
+        @body = body
+        @elapsed_time_ms = elapsed_time_ms
+        @headers = headers
+        @http_code = http_code
+        @response_error_name = response_error_name
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [Response]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "Response")
+        new(
+          response_id: Runtime.read(fields, "response_id", Runtime::UUID),
+          body: Runtime.maybe(fields, "body", Runtime::TEXT),
+          elapsed_time_ms: Runtime.maybe(fields, "elapsed_time_ms", Runtime::INTEGER),
+          headers: Runtime.maybe(fields, "headers", Runtime.map(Runtime::TEXT)),
+          http_code: Runtime.maybe(fields, "http_code", Runtime::INTEGER),
+          response_error_name: Runtime.maybe(fields, "response_error_name", Runtime::TEXT)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["response_id"] = @response_id
+        out["body"] = @body unless @body.nil?
+        out["elapsed_time_ms"] = @elapsed_time_ms unless @elapsed_time_ms.nil?
+        out["headers"] = @headers unless @headers.nil?
+        out["http_code"] = @http_code unless @http_code.nil?
+        out["response_error_name"] = @response_error_name unless @response_error_name.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(Response) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `RetryRequestAttempt` the API declares.
+    class RetryRequestAttempt
+      attr_reader :application_id
+
+      # @param application_id [String] carries `application_id`.
+      def initialize(application_id:)
+        @application_id = application_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [RetryRequestAttempt]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "RetryRequestAttempt")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(RetryRequestAttempt) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `SecretRotation` the API declares.
+    class SecretRotation
+      attr_reader :previous_secret,
+                  :previous_secret_expires_at,
+                  :secret,
+                  :subscription_id
+
+      # @param previous_secret [String] carries `previous_secret`: The secret the new one replaced
+      # @param previous_secret_expires_at [Time] carries `previous_secret_expires_at`.
+      # @param secret [String] carries `secret`: The new secret of the subscription
+      # @param subscription_id [String] carries `subscription_id`.
+      def initialize(previous_secret:, previous_secret_expires_at:, secret:, subscription_id:)
+        @previous_secret = previous_secret
+        @previous_secret_expires_at = previous_secret_expires_at
+        @secret = secret
+        @subscription_id = subscription_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [SecretRotation]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "SecretRotation")
+        new(
+          previous_secret: Runtime.read(fields, "previous_secret", Runtime::UUID),
+          previous_secret_expires_at: Runtime.read(fields, "previous_secret_expires_at", Runtime::DATE_TIME),
+          secret: Runtime.read(fields, "secret", Runtime::UUID),
+          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["previous_secret"] = @previous_secret
+        out["previous_secret_expires_at"] = Runtime.moment(@previous_secret_expires_at)
+        out["secret"] = @secret
+        out["subscription_id"] = @subscription_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(SecretRotation) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `SecretRotationPost` the API declares.
+    class SecretRotationPost
+      attr_reader :overlap_seconds
+
+      # @param overlap_seconds [Integer, nil] carries `overlap_seconds`: How long the previous secret stays valid, in
+      #   seconds (one day by default)
+      def initialize(overlap_seconds: nil)
+        @overlap_seconds = overlap_seconds
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [SecretRotationPost]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "SecretRotationPost")
+        new(
+          overlap_seconds: Runtime.maybe(fields, "overlap_seconds", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["overlap_seconds"] = @overlap_seconds unless @overlap_seconds.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(SecretRotationPost) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `ServiceToken` the API declares.
+    class ServiceToken
+      attr_reader :biscuit,
+                  :created_at,
+                  :name,
+                  :token_id
+
+      # @param biscuit [String] carries `biscuit`.
+      # @param created_at [Time] carries `created_at`.
+      # @param name [String] carries `name`.
+      # @param token_id [String] carries `token_id`.
+      def initialize(biscuit:, created_at:, name:, token_id:)
+        @biscuit = biscuit
+        @created_at = created_at
+        @name = name
+        @token_id = token_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [ServiceToken]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "ServiceToken")
+        new(
+          biscuit: Runtime.read(fields, "biscuit", Runtime::TEXT),
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          name: Runtime.read(fields, "name", Runtime::TEXT),
+          token_id: Runtime.read(fields, "token_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["biscuit"] = @biscuit
+        out["created_at"] = Runtime.moment(@created_at)
+        out["name"] = @name
+        out["token_id"] = @token_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(ServiceToken) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `ServiceTokenPost` the API declares.
+    class ServiceTokenPost
+      attr_reader :name,
+                  :organization_id
+
+      # @param name [String] carries `name`.
+      # @param organization_id [String] carries `organization_id`.
+      def initialize(name:, organization_id:)
+        @name = name
+        @organization_id = organization_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [ServiceTokenPost]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "ServiceTokenPost")
+        new(
+          name: Runtime.read(fields, "name", Runtime::TEXT),
+          organization_id: Runtime.read(fields, "organization_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["name"] = @name
+        out["organization_id"] = @organization_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(ServiceTokenPost) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `SigningKey` the API declares.
+    class SigningKey
+      attr_reader :application_id,
+                  :created_at,
+                  :is_current,
+                  :public_key,
+                  :signing_key_id
+
+      # @param application_id [String] carries `application_id`.
+      # @param created_at [Time] carries `created_at`.
+      # @param is_current [Boolean] carries `is_current`: Whether deliveries are signed with this key, which only the
+      #   newest key of the application is
+      # @param public_key [String] carries `public_key`: Public key, encoded in base64url without padding like the `x`
+      #   member of its JSON Web Key
+      # @param signing_key_id [String] carries `signing_key_id`: Identifies the key in the `k` part of signatures and as
+      #   the `kid` of its JSON Web Key
+      def initialize(application_id:, created_at:, is_current:, public_key:, signing_key_id:)
+        @application_id = application_id
+        @created_at = created_at
+        @is_current = is_current
+        @public_key = public_key
+        @signing_key_id = signing_key_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [SigningKey]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "SigningKey")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          is_current: Runtime.read(fields, "is_current", Runtime::BOOLEAN),
+          public_key: Runtime.read(fields, "public_key", Runtime::TEXT),
+          signing_key_id: Runtime.read(fields, "signing_key_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["created_at"] = Runtime.moment(@created_at)
+        out["is_current"] = @is_current
+        out["public_key"] = @public_key
+        out["signing_key_id"] = @signing_key_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(SigningKey) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `SigningKeyPost` the API declares.
+    class SigningKeyPost
+      attr_reader :application_id
+
+      # @param application_id [String] carries `application_id`.
+      def initialize(application_id:)
+        @application_id = application_id
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [SigningKeyPost]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "SigningKeyPost")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(SigningKeyPost) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `Subscription` the API declares.
+    class Subscription
+      attr_reader :application_id,
+                  :created_at,
+                  :dedicated_workers,
+                  :event_type_versions,
+                  :event_types,
+                  :is_enabled,
+                  :label_key,
+                  :label_value,
+                  :labels,
+                  :metadata,
+                  :secret,
+                  :signature_scheme,
+                  :subscription_id,
+                  :target,
+                  :updated_at,
+                  :auto_disabled_at,
+                  :auto_disabled_reason,
+                  :batching,
+                  :circuit_breaker,
+                  :delivery_limits,
+                  :description,
+                  :filter,
+                  :ordering_key,
+                  :payload_transform,
+                  :response_rules,
+                  :retry_policy
+
+      # @param application_id [String] carries `application_id`.
+      # @param created_at [Time] carries `created_at`.
+      # @param dedicated_workers [Array<String>] carries `dedicated_workers`.
+      # @param event_type_versions [Hash{String => Integer}] carries `event_type_versions`: Versions the subscription is
+      #   pinned to, by event type
+      # @param event_types [Array<String>] carries `event_types`.
+      # @param is_enabled [Boolean] carries `is_enabled`.
+      # @param label_key [String] carries `label_key`: _Kept for backward compatibility, you should use `labels`_
+      # @param label_value [String] carries `label_value`: _Kept for backward compatibility, you should use `labels`_
+      # @param labels [Hash{String => String}] carries `labels`.
+      # @param metadata [Hash{String => String}] carries `metadata`.
+      # @param secret [String] carries `secret`.
+      # @param signature_scheme [String] carries `signature_scheme`, one of `SubscriptionSignatureScheme::VALUES`.
+      # @param subscription_id [String] carries `subscription_id`.
+      # @param target [SubscriptionTarget] carries `target`.
+      # @param updated_at [Time] carries `updated_at`.
+      # @param auto_disabled_at [Time, nil] carries `auto_disabled_at`: When the circuit breaker or a response rule last
+      #   disabled the subscription; cleared when it is enabled again
+      # @param auto_disabled_reason [String, nil] carries `auto_disabled_reason`: Why the circuit breaker or a response
+      #   rule last disabled the subscription
+      # @param batching [SubscriptionBatching, nil] carries `batching`: Deliver the events of the subscription in
+      #   batches: one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or
+      #   once its oldest event has waited `max_wait_s` se
+      # @param circuit_breaker [SubscriptionCircuitBreaker, nil] carries `circuit_breaker`: Disables the subscription
+      #   once its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set
+      # @param delivery_limits [SubscriptionDeliveryLimits, nil] carries `delivery_limits`: Limits on how fast
+      #   deliveries of the subscription start; deliveries over them wait for their turn, which does not count as a
+      #   failure; at least one limit must be set
+      # @param description [String, nil] carries `description`.
+      # @param filter [Object, nil] carries `filter`: An object with exactly one of: `and` or `or` (a list of filters),
+      #   `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload);
+      #   `label` and `field` go alon
+      # @param ordering_key [SubscriptionOrderingKey, nil] carries `ordering_key`: Where the key of an event comes from,
+      #   to deliver the events that share a key one at a time and in the order they were received; exactly one of
+      #   `label` or `field` must be set
+      # @param payload_transform [SubscriptionPayloadTransform, nil] carries `payload_transform`: Declarative reshaping
+      #   of an `application/json` event payload, applied by the worker before the request is signed and sent;
+      #   operations are applied in order (`fields`, `envelope`, `constants`) and a pay
+      # @param response_rules [Array<SubscriptionResponseRules>, nil] carries `response_rules`.
+      # @param retry_policy [SubscriptionRetryPolicy, nil] carries `retry_policy`: Retry schedule of the subscription,
+      #   replacing the one of the instance within its limits; every field left out keeps the value of the instance
+      def initialize(
+        application_id:,
+        created_at:,
+        dedicated_workers:,
+        event_type_versions:,
+        event_types:,
+        is_enabled:,
+        label_key:,
+        label_value:,
+        labels:,
+        metadata:,
+        secret:,
+        signature_scheme:,
+        subscription_id:,
+        target:,
+        updated_at:,
+        auto_disabled_at: nil,
+        auto_disabled_reason: nil,
+        batching: nil,
+        circuit_breaker: nil,
+        delivery_limits: nil,
+        description: nil,
+        filter: nil,
+        ordering_key: nil,
+        payload_transform: nil,
+        response_rules: nil,
+        retry_policy: nil
+      )
+        @application_id = application_id
+        @created_at = created_at
+        @dedicated_workers = dedicated_workers
+        @event_type_versions = event_type_versions
+        @event_types = event_types
+        @is_enabled = is_enabled
+        @label_key = label_key
+        @label_value = label_value
+        @labels = labels
+        @metadata = metadata
+        @secret = secret
+        @signature_scheme = signature_scheme
+        @subscription_id = subscription_id
+        @target = target
+        @updated_at = updated_at
+        @auto_disabled_at = auto_disabled_at
+        @auto_disabled_reason = auto_disabled_reason
+        @batching = batching
+        @circuit_breaker = circuit_breaker
+        @delivery_limits = delivery_limits
+        @description = description
+        @filter = filter
+        @ordering_key = ordering_key
+        @payload_transform = payload_transform
+        @response_rules = response_rules
+        @retry_policy = retry_policy
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [Subscription]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "Subscription")
+        new(
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
+          dedicated_workers: Runtime.read(fields, "dedicated_workers", Runtime.list(Runtime::TEXT)),
+          event_type_versions: Runtime.read(fields, "event_type_versions", Runtime.map(Runtime::INTEGER)),
+          event_types: Runtime.read(fields, "event_types", Runtime.list(Runtime::TEXT)),
+          is_enabled: Runtime.read(fields, "is_enabled", Runtime::BOOLEAN),
+          label_key: Runtime.read(fields, "label_key", Runtime::TEXT),
+          label_value: Runtime.read(fields, "label_value", Runtime::TEXT),
+          labels: Runtime.read(fields, "labels", Runtime.map(Runtime::TEXT)),
+          metadata: Runtime.read(fields, "metadata", Runtime.map(Runtime::TEXT)),
+          secret: Runtime.read(fields, "secret", Runtime::UUID),
+          signature_scheme: Runtime.read(fields, "signature_scheme", Runtime.member_of(SubscriptionSignatureScheme)),
+          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID),
+          target: Runtime.read(fields, "target", SubscriptionTarget.method(:from_json)),
+          updated_at: Runtime.read(fields, "updated_at", Runtime::DATE_TIME),
+          auto_disabled_at: Runtime.maybe(fields, "auto_disabled_at", Runtime::DATE_TIME),
+          auto_disabled_reason: Runtime.maybe(fields, "auto_disabled_reason", Runtime::TEXT),
+          batching: Runtime.maybe(fields, "batching", SubscriptionBatching.method(:from_json)),
+          circuit_breaker: Runtime.maybe(fields, "circuit_breaker", SubscriptionCircuitBreaker.method(:from_json)),
+          delivery_limits: Runtime.maybe(fields, "delivery_limits", SubscriptionDeliveryLimits.method(:from_json)),
+          description: Runtime.maybe(fields, "description", Runtime::TEXT),
+          filter: Runtime.maybe(fields, "filter", Runtime::JSON_VALUE),
+          ordering_key: Runtime.maybe(fields, "ordering_key", SubscriptionOrderingKey.method(:from_json)),
+          payload_transform: Runtime.maybe(
+            fields,
+            "payload_transform",
+            SubscriptionPayloadTransform.method(:from_json)
+          ),
+          response_rules: Runtime.maybe(
+            fields,
+            "response_rules",
+            Runtime.list(SubscriptionResponseRules.method(:from_json))
+          ),
+          retry_policy: Runtime.maybe(fields, "retry_policy", SubscriptionRetryPolicy.method(:from_json))
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["application_id"] = @application_id
+        out["created_at"] = Runtime.moment(@created_at)
+        out["dedicated_workers"] = @dedicated_workers
+        out["event_type_versions"] = @event_type_versions
+        out["event_types"] = @event_types
+        out["is_enabled"] = @is_enabled
+        out["label_key"] = @label_key
+        out["label_value"] = @label_value
+        out["labels"] = @labels
+        out["metadata"] = @metadata
+        out["secret"] = @secret
+        out["signature_scheme"] = @signature_scheme
+        out["subscription_id"] = @subscription_id
+        out["target"] = @target.to_h
+        out["updated_at"] = Runtime.moment(@updated_at)
+        out["auto_disabled_at"] = Runtime.moment(@auto_disabled_at) unless @auto_disabled_at.nil?
+        out["auto_disabled_reason"] = @auto_disabled_reason unless @auto_disabled_reason.nil?
+        out["batching"] = @batching.to_h unless @batching.nil?
+        out["circuit_breaker"] = @circuit_breaker.to_h unless @circuit_breaker.nil?
+        out["delivery_limits"] = @delivery_limits.to_h unless @delivery_limits.nil?
+        out["description"] = @description unless @description.nil?
+        out["filter"] = @filter unless @filter.nil?
+        out["ordering_key"] = @ordering_key.to_h unless @ordering_key.nil?
+        out["payload_transform"] = @payload_transform.to_h unless @payload_transform.nil?
+        out["response_rules"] = @response_rules.map(&:to_h) unless @response_rules.nil?
+        out["retry_policy"] = @retry_policy.to_h unless @retry_policy.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(Subscription) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `SubscriptionBatching` the API declares.
+    class SubscriptionBatching
+      attr_reader :max_events,
+                  :max_wait_s
+
+      # @param max_events [Integer] carries `max_events`: Largest number of events in a batch
+      # @param max_wait_s [Integer] carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill
+      #   up
+      def initialize(max_events:, max_wait_s:)
+        @max_events = max_events
+        @max_wait_s = max_wait_s
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [SubscriptionBatching]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "SubscriptionBatching")
+        new(
+          max_events: Runtime.read(fields, "max_events", Runtime::INTEGER),
+          max_wait_s: Runtime.read(fields, "max_wait_s", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["max_events"] = @max_events
+        out["max_wait_s"] = @max_wait_s
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(SubscriptionBatching) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `SubscriptionCircuitBreaker` the API declares.
+    class SubscriptionCircuitBreaker
+      attr_reader :max_consecutive_failures,
+                  :max_failing_hours
+
+      # @param max_consecutive_failures [Integer, nil] carries `max_consecutive_failures`: Number of failed deliveries
+      #   in a row after which the subscription is disabled
+      # @param max_failing_hours [Integer, nil] carries `max_failing_hours`: Number of hours without a single successful
+      #   delivery after which the subscription is disabled
+      def initialize(max_consecutive_failures: nil, max_failing_hours: nil)
+        @max_consecutive_failures = max_consecutive_failures
+        @max_failing_hours = max_failing_hours
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [SubscriptionCircuitBreaker]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "SubscriptionCircuitBreaker")
+        new(
+          max_consecutive_failures: Runtime.maybe(fields, "max_consecutive_failures", Runtime::INTEGER),
+          max_failing_hours: Runtime.maybe(fields, "max_failing_hours", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["max_consecutive_failures"] = @max_consecutive_failures unless @max_consecutive_failures.nil?
+        out["max_failing_hours"] = @max_failing_hours unless @max_failing_hours.nil?
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(SubscriptionCircuitBreaker) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `SubscriptionDeliveryLimits` the API declares.
+    class SubscriptionDeliveryLimits
+      attr_reader :max_in_flight,
+                  :max_per_second,
+                  :per_host
+
+      # @param max_in_flight [Integer, nil] carries `max_in_flight`: Number of deliveries that can be in flight at once
+      # @param max_per_second [Integer, nil] carries `max_per_second`: Number of deliveries that can start in a second
+      # @param per_host [Boolean, nil] carries `per_host`: Share the limits with every subscription of the application
+      #   that delivers to the same host and sets this too, rather than counting the deliveries of this subscription
+      #   alone
+      def initialize(max_in_flight: nil, max_per_second: nil, per_host: nil)
+        @max_in_flight = max_in_flight
+        @max_per_second = max_per_second
+        @per_host = per_host
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [SubscriptionDeliveryLimits]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "SubscriptionDeliveryLimits")
+        new(
+          max_in_flight: Runtime.maybe(fields, "max_in_flight", Runtime::INTEGER),
+          max_per_second: Runtime.maybe(fields, "max_per_second", Runtime::INTEGER),
+          per_host: Runtime.maybe(fields, "per_host", Runtime::BOOLEAN)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["max_in_flight"] = @max_in_flight unless @max_in_flight.nil?
+        out["max_per_second"] = @max_per_second unless @max_per_second.nil?
+        out["per_host"] = @per_host unless @per_host.nil?
         out
       end
 
@@ -1837,7 +6662,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(OrganizationInfoQuotas) && to_h == other.to_h
+        other.is_a?(SubscriptionDeliveryLimits) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1849,40 +6674,29 @@ module Hook0
       end
     end
 
-    # The `OrganizationInfoUsers` the API declares.
-    class OrganizationInfoUsers
-      attr_reader :email,
-                  :first_name,
-                  :last_name,
-                  :role,
-                  :user_id
+    # The `SubscriptionOrderingKey` the API declares.
+    class SubscriptionOrderingKey
+      attr_reader :field,
+                  :label
 
-      # @param email [String] carries `email`.
-      # @param first_name [String] carries `first_name`.
-      # @param last_name [String] carries `last_name`.
-      # @param role [String] carries `role`.
-      # @param user_id [String] carries `user_id`.
-      def initialize(email:, first_name:, last_name:, role:, user_id:)
-        @email = email
-        @first_name = first_name
-        @last_name = last_name
-        @role = role
-        @user_id = user_id
+      # @param field [String, nil] carries `field`: JSON pointer to the value holding the key in a JSON payload, for
+      #   example `/customer/id`
+      # @param label [String, nil] carries `label`: Name of the event label holding the key
+      def initialize(field: nil, label: nil)
+        @field = field
+        @label = label
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [OrganizationInfoUsers]
+      # @return [SubscriptionOrderingKey]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "OrganizationInfoUsers")
+        fields = Runtime.as_fields(value, "SubscriptionOrderingKey")
         new(
-          email: Runtime.read(fields, "email", Runtime::TEXT),
-          first_name: Runtime.read(fields, "first_name", Runtime::TEXT),
-          last_name: Runtime.read(fields, "last_name", Runtime::TEXT),
-          role: Runtime.read(fields, "role", Runtime::TEXT),
-          user_id: Runtime.read(fields, "user_id", Runtime::UUID)
+          field: Runtime.maybe(fields, "field", Runtime::TEXT),
+          label: Runtime.maybe(fields, "label", Runtime::TEXT)
         )
       end
 
@@ -1891,11 +6705,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["email"] = @email
-        out["first_name"] = @first_name
-        out["last_name"] = @last_name
-        out["role"] = @role
-        out["user_id"] = @user_id
+        out["field"] = @field unless @field.nil?
+        out["label"] = @label unless @label.nil?
         out
       end
 
@@ -1904,7 +6715,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(OrganizationInfoUsers) && to_h == other.to_h
+        other.is_a?(SubscriptionOrderingKey) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1916,28 +6727,34 @@ module Hook0
       end
     end
 
-    # The `OrganizationPlan` the API declares.
-    class OrganizationPlan
-      attr_reader :label,
-                  :name
-
-      # @param label [String] carries `label`.
-      # @param name [String] carries `name`.
-      def initialize(label:, name:)
-        @label = label
-        @name = name
+    # The `SubscriptionPayloadTransform` the API declares.
+    class SubscriptionPayloadTransform
+      attr_reader :constants,
+                  :envelope,
+                  :fields
+
+      # @param constants [Hash{String => Object}, nil] carries `constants`: Properties added at the top level of the
+      #   result, overriding properties of the same name (the result must then be an object)
+      # @param envelope [String, nil] carries `envelope`: Name of a property the result is wrapped in
+      # @param fields [Array<SubscriptionPayloadTransformFields>, nil] carries `fields`: Values to copy from the payload
+      #   into a new object; when omitted, the whole payload is kept
+      def initialize(constants: nil, envelope: nil, fields: nil)
+        @constants = constants
+        @envelope = envelope
+        @fields = fields
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [OrganizationPlan]
+      # @return [SubscriptionPayloadTransform]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "OrganizationPlan")
+        fields = Runtime.as_fields(value, "SubscriptionPayloadTransform")
         new(
-          label: Runtime.read(fields, "label", Runtime::TEXT),
-          name: Runtime.read(fields, "name", Runtime::TEXT)
+          constants: Runtime.maybe(fields, "constants", Runtime.map(Runtime::JSON_VALUE)),
+          envelope: Runtime.maybe(fields, "envelope", Runtime::TEXT),
+          fields: Runtime.maybe(fields, "fields", Runtime.list(SubscriptionPayloadTransformFields.method(:from_json)))
         )
       end
 
@@ -1946,8 +6763,9 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["label"] = @label
-        out["name"] = @name
+        out["constants"] = @constants unless @constants.nil?
+        out["envelope"] = @envelope unless @envelope.nil?
+        out["fields"] = @fields.map(&:to_h) unless @fields.nil?
         out
       end
 
@@ -1956,7 +6774,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(OrganizationPlan) && to_h == other.to_h
+        other.is_a?(SubscriptionPayloadTransform) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -1968,49 +6786,30 @@ module Hook0
       end
     end
 
-    # The `Problem` the API declares.
-    class Problem
-      attr_reader :detail,
-                  :id,
-                  :status,
-                  :title,
-                  :type,
-                  :validation
-
-      # @param detail [String] carries `detail`.
-      # @param id [String] carries `id`, one of `ProblemId::VALUES`: Identifier of the problem that occurred, stable
-      #   across releases
-      # @param status [Integer] carries `status`.
-      # @param title [String] carries `title`.
-      # @param type [String] carries `type`: Documentation page of this problem, which is also what distinguishes one
-      #   problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a
-      #   URL.
-      # @param validation [Object, nil] carries `validation`: Which submitted values were rejected and why, keyed by the
-      #   name of the field they were submitted under. Carried by validation failures (`Validation`, status 422) and
-      #   `null` for every other problem, as
-      def initialize(detail:, id:, status:, title:, type:, validation: nil)
-        @detail = detail
-        @id = id
-        @status = status
-        @title = title
-        @type = type
-        @validation = validation
+    # The `SubscriptionPayloadTransformFields` the API declares.
+    class SubscriptionPayloadTransformFields
+      attr_reader :from,
+                  :to
+
+      # @param from [String] carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that
+      #   are absent from a payload are skipped)
+      # @param to [String, nil] carries `to`: JSON pointer where the value is written in the result, for example `/id`;
+      #   defaults to `from`
+      def initialize(from:, to: nil)
+        @from = from
+        @to = to
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [Problem]
+      # @return [SubscriptionPayloadTransformFields]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "Problem")
+        fields = Runtime.as_fields(value, "SubscriptionPayloadTransformFields")
         new(
-          detail: Runtime.read(fields, "detail", Runtime::TEXT),
-          id: Runtime.read(fields, "id", Runtime.member_of(ProblemId)),
-          status: Runtime.read(fields, "status", Runtime::INTEGER),
-          title: Runtime.read(fields, "title", Runtime::TEXT),
-          type: Runtime.read(fields, "type", Runtime::TEXT),
-          validation: Runtime.maybe(fields, "validation", Runtime::JSON_VALUE)
+          from: Runtime.read(fields, "from", Runtime::TEXT),
+          to: Runtime.maybe(fields, "to", Runtime::TEXT)
         )
       end
 
@@ -2019,12 +6818,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["detail"] = @detail
-        out["id"] = @id
-        out["status"] = @status
-        out["title"] = @title
-        out["type"] = @type
-        out["validation"] = @validation unless @validation.nil?
+        out["from"] = @from
+        out["to"] = @to unless @to.nil?
         out
       end
 
@@ -2033,7 +6828,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(Problem) && to_h == other.to_h
+        other.is_a?(SubscriptionPayloadTransformFields) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2045,28 +6840,149 @@ module Hook0
       end
     end
 
-    # The `QuotasResponse` the API declares.
-    class QuotasResponse
-      attr_reader :enabled,
-                  :limits
+    # The `SubscriptionPost` the API declares.
+    class SubscriptionPost
+      attr_reader :application_id,
+                  :event_types,
+                  :is_enabled,
+                  :target,
+                  :batching,
+                  :circuit_breaker,
+                  :dedicated_workers,
+                  :delivery_limits,
+                  :description,
+                  :event_type_versions,
+                  :filter,
+                  :label_key,
+                  :label_value,
+                  :labels,
+                  :metadata,
+                  :ordering_key,
+                  :payload_transform,
+                  :response_rules,
+                  :retry_policy,
+                  :signature_scheme
 
-      # @param enabled [Boolean] carries `enabled`.
-      # @param limits [QuotasResponseLimits] carries `limits`.
-      def initialize(enabled:, limits:)
-        @enabled = enabled
-        @limits = limits
+      # @param application_id [String] carries `application_id`.
+      # @param event_types [Array<String>] carries `event_types`.
+      # @param is_enabled [Boolean] carries `is_enabled`.
+      # @param target [SubscriptionPostTarget] carries `target`.
+      # @param batching [SubscriptionPostBatching, nil] carries `batching`: Deliver the events in batches, as one HTTP
+      #   request with a JSON array body, rather than one by one
+      # @param circuit_breaker [SubscriptionPostCircuitBreaker, nil] carries `circuit_breaker`: Disable the subscription
+      #   once its deliveries keep failing; organization editors are notified by email
+      # @param dedicated_workers [Array<String>, nil] carries `dedicated_workers`.
+      # @param delivery_limits [SubscriptionPostDeliveryLimits, nil] carries `delivery_limits`: Limit how fast
+      #   deliveries start, for this subscription or for its host
+      # @param description [String, nil] carries `description`.
+      # @param event_type_versions [Hash{String => Integer}, nil] carries `event_type_versions`: Only receive the events
+      #   of these event types that declare this version (or no version at all)
+      # @param filter [Object, nil] carries `filter`: Only deliver the events of the subscribed types that match this
+      #   expression over labels and JSON payload fields
+      # @param label_key [String, nil] carries `label_key`: _Kept for backward compatibility, you should use `labels`_
+      # @param label_value [String, nil] carries `label_value`: _Kept for backward compatibility, you should use
+      #   `labels`_
+      # @param labels [Hash{String => String}, nil] carries `labels`.
+      # @param metadata [Hash{String => String}, nil] carries `metadata`.
+      # @param ordering_key [SubscriptionPostOrderingKey, nil] carries `ordering_key`: Deliver the events that share a
+      #   key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran
+      #   out of retries
+      # @param payload_transform [SubscriptionPostPayloadTransform, nil] carries `payload_transform`: Declarative
+      #   reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent;
+      #   operations are applied in order (`fields`, `envelope`, `constants`) and a pay
+      # @param response_rules [Array<SubscriptionPostResponseRules>, nil] carries `response_rules`: Decide the outcome
+      #   of HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose
+      #   body reports an error; the first rule that matches wins, and a response n
+      # @param retry_policy [SubscriptionPostRetryPolicy, nil] carries `retry_policy`: Retry failed deliveries on this
+      #   schedule rather than the instance's one
+      # @param signature_scheme [String, nil] carries `signature_scheme`, one of
+      #   `SubscriptionPostSignatureScheme::VALUES`: How deliveries are signed (`hook0` by default); `standard_webhooks`
+      #   follows the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding
+      #   of the subscription s
+      def initialize(
+        application_id:,
+        event_types:,
+        is_enabled:,
+        target:,
+        batching: nil,
+        circuit_breaker: nil,
+        dedicated_workers: nil,
+        delivery_limits: nil,
+        description: nil,
+        event_type_versions: nil,
+        filter: nil,
+        label_key: nil,
+        label_value: nil,
+        labels: nil,
+        metadata: nil,
+        ordering_key: nil,
+        payload_transform: nil,
+        response_rules: nil,
+        retry_policy: nil,
+        signature_scheme: nil
+      )
+        @application_id = application_id
+        @event_types = event_types
+        @is_enabled = is_enabled
+        @target = target
+        @batching = batching
+        @circuit_breaker = circuit_breaker
+        @dedicated_workers = dedicated_workers
+        @delivery_limits = delivery_limits
+        @description = description
+        @event_type_versions = event_type_versions
+        @filter = filter
+        @label_key = label_key
+        @label_value = label_value
+        @labels = labels
+        @metadata = metadata
+        @ordering_key = ordering_key
+        @payload_transform = payload_transform
+        @response_rules = response_rules
+        @retry_policy = retry_policy
+        @signature_scheme = signature_scheme
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [QuotasResponse]
+      # @return [SubscriptionPost]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "QuotasResponse")
+        fields = Runtime.as_fields(value, "SubscriptionPost")
         new(
-          enabled: Runtime.read(fields, "enabled", Runtime::BOOLEAN),
-          limits: Runtime.read(fields, "limits", QuotasResponseLimits.method(:from_json))
+          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
+          event_types: Runtime.read(fields, "event_types", Runtime.list(Runtime::TEXT)),
+          is_enabled: Runtime.read(fields, "is_enabled", Runtime::BOOLEAN),
+          target: Runtime.read(fields, "target", SubscriptionPostTarget.method(:from_json)),
+          batching: Runtime.maybe(fields, "batching", SubscriptionPostBatching.method(:from_json)),
+          circuit_breaker: Runtime.maybe(fields, "circuit_breaker", SubscriptionPostCircuitBreaker.method(:from_json)),
+          dedicated_workers: Runtime.maybe(fields, "dedicated_workers", Runtime.list(Runtime::TEXT)),
+          delivery_limits: Runtime.maybe(fields, "delivery_limits", SubscriptionPostDeliveryLimits.method(:from_json)),
+          description: Runtime.maybe(fields, "description", Runtime::TEXT),
+          event_type_versions: Runtime.maybe(fields, "event_type_versions", Runtime.map(Runtime::INTEGER)),
+          filter: Runtime.maybe(fields, "filter", Runtime::JSON_VALUE),
+          label_key: Runtime.maybe(fields, "label_key", Runtime::TEXT),
+          label_value: Runtime.maybe(fields, "label_value", Runtime::TEXT),
+          labels: Runtime.maybe(fields, "labels", Runtime.map(Runtime::TEXT)),
+          metadata: Runtime.maybe(fields, "metadata", Runtime.map(Runtime::TEXT)),
+          ordering_key: Runtime.maybe(fields, "ordering_key", SubscriptionPostOrderingKey.method(:from_json)),
+          payload_transform: Runtime.maybe(
+            fields,
+            "payload_transform",
+            SubscriptionPostPayloadTransform.method(:from_json)
+          ),
+          response_rules: Runtime.maybe(
+            fields,
+            "response_rules",
+            Runtime.list(SubscriptionPostResponseRules.method(:from_json))
+          ),
+          retry_policy: Runtime.maybe(fields, "retry_policy", SubscriptionPostRetryPolicy.method(:from_json)),
+          signature_scheme: Runtime.maybe(
+            fields,
+            "signature_scheme",
+            Runtime.member_of(SubscriptionPostSignatureScheme)
+          )
         )
       end
 
@@ -2075,8 +6991,26 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["enabled"] = @enabled
-        out["limits"] = @limits.to_h
+        out["application_id"] = @application_id
+        out["event_types"] = @event_types
+        out["is_enabled"] = @is_enabled
+        out["target"] = @target.to_h
+        out["batching"] = @batching.to_h unless @batching.nil?
+        out["circuit_breaker"] = @circuit_breaker.to_h unless @circuit_breaker.nil?
+        out["dedicated_workers"] = @dedicated_workers unless @dedicated_workers.nil?
+        out["delivery_limits"] = @delivery_limits.to_h unless @delivery_limits.nil?
+        out["description"] = @description unless @description.nil?
+        out["event_type_versions"] = @event_type_versions unless @event_type_versions.nil?
+        out["filter"] = @filter unless @filter.nil?
+        out["label_key"] = @label_key unless @label_key.nil?
+        out["label_value"] = @label_value unless @label_value.nil?
+        out["labels"] = @labels unless @labels.nil?
+        out["metadata"] = @metadata unless @metadata.nil?
+        out["ordering_key"] = @ordering_key.to_h unless @ordering_key.nil?
+        out["payload_transform"] = @payload_transform.to_h unless @payload_transform.nil?
+        out["response_rules"] = @response_rules.map(&:to_h) unless @response_rules.nil?
+        out["retry_policy"] = @retry_policy.to_h unless @retry_policy.nil?
+        out["signature_scheme"] = @signature_scheme unless @signature_scheme.nil?
         out
       end
 
@@ -2085,7 +7019,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(QuotasResponse) && to_h == other.to_h
+        other.is_a?(SubscriptionPost) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2097,73 +7031,83 @@ module Hook0
       end
     end
 
-    # The `QuotasResponseLimits` the API declares.
-    class QuotasResponseLimits
-      attr_reader :global_applications_per_organization_limit,
-                  :global_days_of_events_retention_limit,
-                  :global_event_types_per_application_limit,
-                  :global_events_per_day_limit,
-                  :global_members_per_organization_limit,
-                  :global_subscriptions_per_application_limit
-
-      # @param global_applications_per_organization_limit [Integer] carries
-      #   `global_applications_per_organization_limit`.
-      # @param global_days_of_events_retention_limit [Integer] carries `global_days_of_events_retention_limit`.
-      # @param global_event_types_per_application_limit [Integer] carries `global_event_types_per_application_limit`.
-      # @param global_events_per_day_limit [Integer] carries `global_events_per_day_limit`.
-      # @param global_members_per_organization_limit [Integer] carries `global_members_per_organization_limit`.
-      # @param global_subscriptions_per_application_limit [Integer] carries
-      #   `global_subscriptions_per_application_limit`.
-      def initialize(
-        global_applications_per_organization_limit:,
-        global_days_of_events_retention_limit:,
-        global_event_types_per_application_limit:,
-        global_events_per_day_limit:,
-        global_members_per_organization_limit:,
-        global_subscriptions_per_application_limit:
-      )
-        @global_applications_per_organization_limit = global_applications_per_organization_limit
-        @global_days_of_events_retention_limit = global_days_of_events_retention_limit
-        @global_event_types_per_application_limit = global_event_types_per_application_limit
-        @global_events_per_day_limit = global_events_per_day_limit
-        @global_members_per_organization_limit = global_members_per_organization_limit
-        @global_subscriptions_per_application_limit = global_subscriptions_per_application_limit
+    # The `SubscriptionPostBatching` the API declares.
+    class SubscriptionPostBatching
+      attr_reader :max_events,
+                  :max_wait_s
+
+      # @param max_events [Integer] carries `max_events`: Largest number of events in a batch
+      # @param max_wait_s [Integer] carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill
+      #   up
+      def initialize(max_events:, max_wait_s:)
+        @max_events = max_events
+        @max_wait_s = max_wait_s
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [QuotasResponseLimits]
+      # @return [SubscriptionPostBatching]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "QuotasResponseLimits")
+        fields = Runtime.as_fields(value, "SubscriptionPostBatching")
         new(
-          global_applications_per_organization_limit: Runtime.read(
-            fields,
-            "global_applications_per_organization_limit",
-            Runtime::INTEGER
-          ),
-          global_days_of_events_retention_limit: Runtime.read(
-            fields,
-            "global_days_of_events_retention_limit",
-            Runtime::INTEGER
-          ),
-          global_event_types_per_application_limit: Runtime.read(
-            fields,
-            "global_event_types_per_application_limit",
-            Runtime::INTEGER
-          ),
-          global_events_per_day_limit: Runtime.read(fields, "global_events_per_day_limit", Runtime::INTEGER),
-          global_members_per_organization_limit: Runtime.read(
-            fields,
-            "global_members_per_organization_limit",
-            Runtime::INTEGER
-          ),
-          global_subscriptions_per_application_limit: Runtime.read(
-            fields,
-            "global_subscriptions_per_application_limit",
-            Runtime::INTEGER
-          )
+          max_events: Runtime.read(fields, "max_events", Runtime::INTEGER),
+          max_wait_s: Runtime.read(fields, "max_wait_s", Runtime::INTEGER)
+        )
+      end
+
+      # Write one back the way the API reads it.
+      #
+      # @return [Hash{String => Object}]
+      def to_h
+        out = {}
+        out["max_events"] = @max_events
+        out["max_wait_s"] = @max_wait_s
+        out
+      end
+
+      # Whether that value carries the same members as this one.
+      #
+      # @param other [Object]
+      # @return [Boolean]
+      def ==(other)
+        other.is_a?(SubscriptionPostBatching) && to_h == other.to_h
+      end
+      alias eql? ==
+
+      # A value two equal instances share, so that either may key a hash.
+      #
+      # @return [Integer]
+      def hash
+        to_h.hash
+      end
+    end
+
+    # The `SubscriptionPostCircuitBreaker` the API declares.
+    class SubscriptionPostCircuitBreaker
+      attr_reader :max_consecutive_failures,
+                  :max_failing_hours
+
+      # @param max_consecutive_failures [Integer, nil] carries `max_consecutive_failures`: Number of failed deliveries
+      #   in a row after which the subscription is disabled
+      # @param max_failing_hours [Integer, nil] carries `max_failing_hours`: Number of hours without a single successful
+      #   delivery after which the subscription is disabled
+      def initialize(max_consecutive_failures: nil, max_failing_hours: nil)
+        @max_consecutive_failures = max_consecutive_failures
+        @max_failing_hours = max_failing_hours
+        freeze
+      end
+
+      # Read one out of what the API answered.
+      #
+      # @param value [Object] the JSON document the API answered
+      # @return [SubscriptionPostCircuitBreaker]
+      def self.from_json(value)
+        fields = Runtime.as_fields(value, "SubscriptionPostCircuitBreaker")
+        new(
+          max_consecutive_failures: Runtime.maybe(fields, "max_consecutive_failures", Runtime::INTEGER),
+          max_failing_hours: Runtime.maybe(fields, "max_failing_hours", Runtime::INTEGER)
         )
       end
 
@@ -2172,12 +7116,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["global_applications_per_organization_limit"] = @global_applications_per_organization_limit
-        out["global_days_of_events_retention_limit"] = @global_days_of_events_retention_limit
-        out["global_event_types_per_application_limit"] = @global_event_types_per_application_limit
-        out["global_events_per_day_limit"] = @global_events_per_day_limit
-        out["global_members_per_organization_limit"] = @global_members_per_organization_limit
-        out["global_subscriptions_per_application_limit"] = @global_subscriptions_per_application_limit
+        out["max_consecutive_failures"] = @max_consecutive_failures unless @max_consecutive_failures.nil?
+        out["max_failing_hours"] = @max_failing_hours unless @max_failing_hours.nil?
         out
       end
 
@@ -2186,7 +7126,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(QuotasResponseLimits) && to_h == other.to_h
+        other.is_a?(SubscriptionPostCircuitBreaker) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2198,24 +7138,34 @@ module Hook0
       end
     end
 
-    # The `ReplayEvent` the API declares.
-    class ReplayEvent
-      attr_reader :application_id
-
-      # @param application_id [String] carries `application_id`.
-      def initialize(application_id:)
-        @application_id = application_id
+    # The `SubscriptionPostDeliveryLimits` the API declares.
+    class SubscriptionPostDeliveryLimits
+      attr_reader :max_in_flight,
+                  :max_per_second,
+                  :per_host
+
+      # @param max_in_flight [Integer, nil] carries `max_in_flight`: Number of deliveries that can be in flight at once
+      # @param max_per_second [Integer, nil] carries `max_per_second`: Number of deliveries that can start in a second
+      # @param per_host [Boolean, nil] carries `per_host`: Share the limits with every subscription of the application
+      #   that delivers to the same host and sets this too, rather than counting the deliveries of this subscription
+      #   alone
+      def initialize(max_in_flight: nil, max_per_second: nil, per_host: nil)
+        @max_in_flight = max_in_flight
+        @max_per_second = max_per_second
+        @per_host = per_host
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [ReplayEvent]
+      # @return [SubscriptionPostDeliveryLimits]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "ReplayEvent")
+        fields = Runtime.as_fields(value, "SubscriptionPostDeliveryLimits")
         new(
-          application_id: Runtime.read(fields, "application_id", Runtime::UUID)
+          max_in_flight: Runtime.maybe(fields, "max_in_flight", Runtime::INTEGER),
+          max_per_second: Runtime.maybe(fields, "max_per_second", Runtime::INTEGER),
+          per_host: Runtime.maybe(fields, "per_host", Runtime::BOOLEAN)
         )
       end
 
@@ -2224,7 +7174,9 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["application_id"] = @application_id
+        out["max_in_flight"] = @max_in_flight unless @max_in_flight.nil?
+        out["max_per_second"] = @max_per_second unless @max_per_second.nil?
+        out["per_host"] = @per_host unless @per_host.nil?
         out
       end
 
@@ -2233,7 +7185,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(ReplayEvent) && to_h == other.to_h
+        other.is_a?(SubscriptionPostDeliveryLimits) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2245,88 +7197,29 @@ module Hook0
       end
     end
 
-    # The `RequestAttempt` the API declares.
-    class RequestAttempt
-      attr_reader :created_at,
-                  :event,
-                  :event_id,
-                  :request_attempt_id,
-                  :retry_count,
-                  :status,
-                  :subscription,
-                  :delay_until,
-                  :failed_at,
-                  :http_response_status,
-                  :picked_at,
-                  :response_id,
-                  :succeeded_at
+    # The `SubscriptionPostOrderingKey` the API declares.
+    class SubscriptionPostOrderingKey
+      attr_reader :field,
+                  :label
 
-      # @param created_at [Time] carries `created_at`.
-      # @param event [RequestAttemptEvent] carries `event`.
-      # @param event_id [String] carries `event_id`.
-      # @param request_attempt_id [String] carries `request_attempt_id`.
-      # @param retry_count [Integer] carries `retry_count`.
-      # @param status [RequestAttemptStatus] carries `status`: Status of a request attempt. The 'type' field indicates
-      #   the status variant. - waiting: {type, since, until} - Scheduled for future delivery - pending: {type, since} -
-      #   Ready to be processed - in_progre
-      # @param subscription [RequestAttemptSubscription] carries `subscription`.
-      # @param delay_until [Time, nil] carries `delay_until`.
-      # @param failed_at [Time, nil] carries `failed_at`.
-      # @param http_response_status [Integer, nil] carries `http_response_status`.
-      # @param picked_at [Time, nil] carries `picked_at`.
-      # @param response_id [String, nil] carries `response_id`.
-      # @param succeeded_at [Time, nil] carries `succeeded_at`.
-      def initialize(
-        created_at:,
-        event:,
-        event_id:,
-        request_attempt_id:,
-        retry_count:,
-        status:,
-        subscription:,
-        delay_until: nil,
-        failed_at: nil,
-        http_response_status: nil,
-        picked_at: nil,
-        response_id: nil,
-        succeeded_at: nil
-      )
-        @created_at = created_at
-        @event = event
-        @event_id = event_id
-        @request_attempt_id = request_attempt_id
-        @retry_count = retry_count
-        @status = status
-        @subscription = subscription
-        @delay_until = delay_until
-        @failed_at = failed_at
-        @http_response_status = http_response_status
-        @picked_at = picked_at
-        @response_id = response_id
-        @succeeded_at = succeeded_at
+      # @param field [String, nil] carries `field`: JSON pointer to the value holding the key in a JSON payload, for
+      #   example `/customer/id`
+      # @param label [String, nil] carries `label`: Name of the event label holding the key
+      def initialize(field: nil, label: nil)
+        @field = field
+        @label = label
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [RequestAttempt]
+      # @return [SubscriptionPostOrderingKey]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "RequestAttempt")
+        fields = Runtime.as_fields(value, "SubscriptionPostOrderingKey")
         new(
-          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
-          event: Runtime.read(fields, "event", RequestAttemptEvent.method(:from_json)),
-          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
-          request_attempt_id: Runtime.read(fields, "request_attempt_id", Runtime::UUID),
-          retry_count: Runtime.read(fields, "retry_count", Runtime::INTEGER),
-          status: Runtime.read(fields, "status", RequestAttemptStatus.method(:from_json)),
-          subscription: Runtime.read(fields, "subscription", RequestAttemptSubscription.method(:from_json)),
-          delay_until: Runtime.maybe(fields, "delay_until", Runtime::DATE_TIME),
-          failed_at: Runtime.maybe(fields, "failed_at", Runtime::DATE_TIME),
-          http_response_status: Runtime.maybe(fields, "http_response_status", Runtime::INTEGER),
-          picked_at: Runtime.maybe(fields, "picked_at", Runtime::DATE_TIME),
-          response_id: Runtime.maybe(fields, "response_id", Runtime::UUID),
-          succeeded_at: Runtime.maybe(fields, "succeeded_at", Runtime::DATE_TIME)
+          field: Runtime.maybe(fields, "field", Runtime::TEXT),
+          label: Runtime.maybe(fields, "label", Runtime::TEXT)
         )
       end
 
@@ -2335,19 +7228,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["created_at"] = Runtime.moment(@created_at)
-        out["event"] = @event.to_h
-        out["event_id"] = @event_id
-        out["request_attempt_id"] = @request_attempt_id
-        out["retry_count"] = @retry_count
-        out["status"] = @status.to_h
-        out["subscription"] = @subscription.to_h
-        out["delay_until"] = Runtime.moment(@delay_until) unless @delay_until.nil?
-        out["failed_at"] = Runtime.moment(@failed_at) unless @failed_at.nil?
-        out["http_response_status"] = @http_response_status unless @http_response_status.nil?
-        out["picked_at"] = Runtime.moment(@picked_at) unless @picked_at.nil?
-        out["response_id"] = @response_id unless @response_id.nil?
-        out["succeeded_at"] = Runtime.moment(@succeeded_at) unless @succeeded_at.nil?
+        out["field"] = @field unless @field.nil?
+        out["label"] = @label unless @label.nil?
         out
       end
 
@@ -2356,7 +7238,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(RequestAttempt) && to_h == other.to_h
+        other.is_a?(SubscriptionPostOrderingKey) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2368,28 +7250,38 @@ module Hook0
       end
     end
 
-    # The `RequestAttemptEvent` the API declares.
-    class RequestAttemptEvent
-      attr_reader :event_id,
-                  :event_type_name
-
-      # @param event_id [String] carries `event_id`.
-      # @param event_type_name [String] carries `event_type_name`.
-      def initialize(event_id:, event_type_name:)
-        @event_id = event_id
-        @event_type_name = event_type_name
+    # The `SubscriptionPostPayloadTransform` the API declares.
+    class SubscriptionPostPayloadTransform
+      attr_reader :constants,
+                  :envelope,
+                  :fields
+
+      # @param constants [Hash{String => Object}, nil] carries `constants`: Properties added at the top level of the
+      #   result, overriding properties of the same name (the result must then be an object)
+      # @param envelope [String, nil] carries `envelope`: Name of a property the result is wrapped in
+      # @param fields [Array<SubscriptionPostPayloadTransformFields>, nil] carries `fields`: Values to copy from the
+      #   payload into a new object; when omitted, the whole payload is kept
+      def initialize(constants: nil, envelope: nil, fields: nil)
+        @constants = constants
+        @envelope = envelope
+        @fields = fields
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [RequestAttemptEvent]
+      # @return [SubscriptionPostPayloadTransform]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "RequestAttemptEvent")
+        fields = Runtime.as_fields(value, "SubscriptionPostPayloadTransform")
         new(
-          event_id: Runtime.read(fields, "event_id", Runtime::UUID),
-          event_type_name: Runtime.read(fields, "event_type_name", Runtime::TEXT)
+          constants: Runtime.maybe(fields, "constants", Runtime.map(Runtime::JSON_VALUE)),
+          envelope: Runtime.maybe(fields, "envelope", Runtime::TEXT),
+          fields: Runtime.maybe(
+            fields,
+            "fields",
+            Runtime.list(SubscriptionPostPayloadTransformFields.method(:from_json))
+          )
         )
       end
 
@@ -2398,8 +7290,9 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["event_id"] = @event_id
-        out["event_type_name"] = @event_type_name
+        out["constants"] = @constants unless @constants.nil?
+        out["envelope"] = @envelope unless @envelope.nil?
+        out["fields"] = @fields.map(&:to_h) unless @fields.nil?
         out
       end
 
@@ -2408,7 +7301,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(RequestAttemptEvent) && to_h == other.to_h
+        other.is_a?(SubscriptionPostPayloadTransform) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2420,43 +7313,30 @@ module Hook0
       end
     end
 
-    # The `RequestAttemptStatus` the API declares.
-    class RequestAttemptStatus
-      attr_reader :type,
-                  :at,
-                  :full_processing_ms,
-                  :since,
-                  :until_
-
-      # @param type [String] carries `type`, one of `RequestAttemptStatusType::VALUES`: Status type discriminator. One
-      #   of: waiting, pending, in_progress, successful, failed
-      # @param at [Time, nil] carries `at`: Timestamp when completed (present in successful, failed)
-      # @param full_processing_ms [Integer, nil] carries `full_processing_ms`: Total processing time in milliseconds
-      #   (present in successful, failed)
-      # @param since [Time, nil] carries `since`: Timestamp when the status started (present in waiting, pending,
-      #   in_progress)
-      # @param until_ [Time, nil] carries `until`: Timestamp until which waiting (only present in waiting status)
-      def initialize(type:, at: nil, full_processing_ms: nil, since: nil, until_: nil)
-        @type = type
-        @at = at
-        @full_processing_ms = full_processing_ms
-        @since = since
-        @until_ = until_
+    # The `SubscriptionPostPayloadTransformFields` the API declares.
+    class SubscriptionPostPayloadTransformFields
+      attr_reader :from,
+                  :to
+
+      # @param from [String] carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that
+      #   are absent from a payload are skipped)
+      # @param to [String, nil] carries `to`: JSON pointer where the value is written in the result, for example `/id`;
+      #   defaults to `from`
+      def initialize(from:, to: nil)
+        @from = from
+        @to = to
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [RequestAttemptStatus]
+      # @return [SubscriptionPostPayloadTransformFields]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "RequestAttemptStatus")
+        fields = Runtime.as_fields(value, "SubscriptionPostPayloadTransformFields")
         new(
-          type: Runtime.read(fields, "type", Runtime.member_of(RequestAttemptStatusType)),
-          at: Runtime.maybe(fields, "at", Runtime::DATE_TIME),
-          full_processing_ms: Runtime.maybe(fields, "full_processing_ms", Runtime::INTEGER),
-          since: Runtime.maybe(fields, "since", Runtime::DATE_TIME),
-          until_: Runtime.maybe(fields, "until", Runtime::DATE_TIME)
+          from: Runtime.read(fields, "from", Runtime::TEXT),
+          to: Runtime.maybe(fields, "to", Runtime::TEXT)
         )
       end
 
@@ -2465,11 +7345,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["type"] = @type
-        out["at"] = Runtime.moment(@at) unless @at.nil?
-        out["full_processing_ms"] = @full_processing_ms unless @full_processing_ms.nil?
-        out["since"] = Runtime.moment(@since) unless @since.nil?
-        out["until"] = Runtime.moment(@until_) unless @until_.nil?
+        out["from"] = @from
+        out["to"] = @to unless @to.nil?
         out
       end
 
@@ -2478,7 +7355,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(RequestAttemptStatus) && to_h == other.to_h
+        other.is_a?(SubscriptionPostPayloadTransformFields) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2490,28 +7367,39 @@ module Hook0
       end
     end
 
-    # The `RequestAttemptSubscription` the API declares.
-    class RequestAttemptSubscription
-      attr_reader :subscription_id,
-                  :description
-
-      # @param subscription_id [String] carries `subscription_id`.
-      # @param description [String, nil] carries `description`.
-      def initialize(subscription_id:, description: nil)
-        @subscription_id = subscription_id
-        @description = description
+    # The `SubscriptionPostResponseRules` the API declares.
+    class SubscriptionPostResponseRules
+      attr_reader :outcome,
+                  :status,
+                  :body_contains,
+                  :header
+
+      # @param outcome [String] carries `outcome`, one of `SubscriptionPostResponseRulesOutcome::VALUES`: What the
+      #   delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or
+      #   `disable_subscription` (not retried, and the subscription is disabled)
+      # @param status [Array<String>] carries `status`: Status codes the rule applies to: exact codes such as `410`, or
+      #   classes such as `4xx`
+      # @param body_contains [String, nil] carries `body_contains`: Text the response body must contain
+      # @param header [SubscriptionPostResponseRulesHeader, nil] carries `header`: Header the response must have
+      def initialize(outcome:, status:, body_contains: nil, header: nil)
+        @outcome = outcome
+        @status = status
+        @body_contains = body_contains
+        @header = header
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [RequestAttemptSubscription]
+      # @return [SubscriptionPostResponseRules]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "RequestAttemptSubscription")
+        fields = Runtime.as_fields(value, "SubscriptionPostResponseRules")
         new(
-          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID),
-          description: Runtime.maybe(fields, "description", Runtime::TEXT)
+          outcome: Runtime.read(fields, "outcome", Runtime.member_of(SubscriptionPostResponseRulesOutcome)),
+          status: Runtime.read(fields, "status", Runtime.list(Runtime::TEXT)),
+          body_contains: Runtime.maybe(fields, "body_contains", Runtime::TEXT),
+          header: Runtime.maybe(fields, "header", SubscriptionPostResponseRulesHeader.method(:from_json))
         )
       end
 
@@ -2520,8 +7408,10 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["subscription_id"] = @subscription_id
-        out["description"] = @description unless @description.nil?
+        out["outcome"] = @outcome
+        out["status"] = @status
+        out["body_contains"] = @body_contains unless @body_contains.nil?
+        out["header"] = @header.to_h unless @header.nil?
         out
       end
 
@@ -2530,7 +7420,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(RequestAttemptSubscription) && to_h == other.to_h
+        other.is_a?(SubscriptionPostResponseRules) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2542,51 +7432,28 @@ module Hook0
       end
     end
 
-    # The `Response` the API declares.
-    class Response
-      attr_reader :response_id,
-                  :body,
-                  :elapsed_time_ms,
-                  :headers,
-                  :http_code,
-                  :response_error_name
+    # The `SubscriptionPostResponseRulesHeader` the API declares.
+    class SubscriptionPostResponseRulesHeader
+      attr_reader :name,
+                  :value
 
-      # @param response_id [String] carries `response_id`.
-      # @param body [String, nil] carries `body`.
-      # @param elapsed_time_ms [Integer, nil] carries `elapsed_time_ms`.
-      # @param headers [Hash{String => String}, nil] carries `headers`.
-      # @param http_code [Integer, nil] carries `http_code`.
-      # @param response_error_name [String, nil] carries `response_error_name`.
-      def initialize(
-        response_id:,
-        body: nil,
-        elapsed_time_ms: nil,
-        headers: nil,
-        http_code: nil,
-        response_error_name: nil
-      )
-        @response_id = response_id
-        @body = body
-        @elapsed_time_ms = elapsed_time_ms
-        @headers = headers
-        @http_code = http_code
-        @response_error_name = response_error_name
+      # @param name [String] carries `name`: Name of the header, regardless of case
+      # @param value [String, nil] carries `value`: Value the header must have; when omitted, any value matches
+      def initialize(name:, value: nil)
+        @name = name
+        @value = value
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [Response]
+      # @return [SubscriptionPostResponseRulesHeader]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "Response")
+        fields = Runtime.as_fields(value, "SubscriptionPostResponseRulesHeader")
         new(
-          response_id: Runtime.read(fields, "response_id", Runtime::UUID),
-          body: Runtime.maybe(fields, "body", Runtime::TEXT),
-          elapsed_time_ms: Runtime.maybe(fields, "elapsed_time_ms", Runtime::INTEGER),
-          headers: Runtime.maybe(fields, "headers", Runtime.map(Runtime::TEXT)),
-          http_code: Runtime.maybe(fields, "http_code", Runtime::INTEGER),
-          response_error_name: Runtime.maybe(fields, "response_error_name", Runtime::TEXT)
+          name: Runtime.read(fields, "name", Runtime::TEXT),
+          value: Runtime.maybe(fields, "value", Runtime::TEXT)
         )
       end
 
@@ -2595,12 +7462,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["response_id"] = @response_id
-        out["body"] = @body unless @body.nil?
-        out["elapsed_time_ms"] = @elapsed_time_ms unless @elapsed_time_ms.nil?
-        out["headers"] = @headers unless @headers.nil?
-        out["http_code"] = @http_code unless @http_code.nil?
-        out["response_error_name"] = @response_error_name unless @response_error_name.nil?
+        out["name"] = @name
+        out["value"] = @value unless @value.nil?
         out
       end
 
@@ -2609,7 +7472,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(Response) && to_h == other.to_h
+        other.is_a?(SubscriptionPostResponseRulesHeader) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2617,40 +7480,39 @@ module Hook0
       #
       # @return [Integer]
       def hash
-        to_h.hash
-      end
-    end
-
-    # The `ServiceToken` the API declares.
-    class ServiceToken
-      attr_reader :biscuit,
-                  :created_at,
-                  :name,
-                  :token_id
+        to_h.hash
+      end
+    end
 
-      # @param biscuit [String] carries `biscuit`.
-      # @param created_at [Time] carries `created_at`.
-      # @param name [String] carries `name`.
-      # @param token_id [String] carries `token_id`.
-      def initialize(biscuit:, created_at:, name:, token_id:)
-        @biscuit = biscuit
-        @created_at = created_at
-        @name = name
-        @token_id = token_id
+    # The `SubscriptionPostRetryPolicy` the API declares.
+    class SubscriptionPostRetryPolicy
+      attr_reader :backoff_steps_s,
+                  :max_retries,
+                  :max_window_s
+
+      # @param backoff_steps_s [Array<Integer>, nil] carries `backoff_steps_s`: Delays in seconds to wait before each
+      #   retry; the last one is used again for the following retries
+      # @param max_retries [Integer, nil] carries `max_retries`: Maximum number of retries after a failed delivery (0
+      #   disables retries)
+      # @param max_window_s [Integer, nil] carries `max_window_s`: Maximum duration in seconds the retries can span; a
+      #   retry that would not fit in it is not scheduled
+      def initialize(backoff_steps_s: nil, max_retries: nil, max_window_s: nil)
+        @backoff_steps_s = backoff_steps_s
+        @max_retries = max_retries
+        @max_window_s = max_window_s
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [ServiceToken]
+      # @return [SubscriptionPostRetryPolicy]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "ServiceToken")
+        fields = Runtime.as_fields(value, "SubscriptionPostRetryPolicy")
         new(
-          biscuit: Runtime.read(fields, "biscuit", Runtime::TEXT),
-          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
-          name: Runtime.read(fields, "name", Runtime::TEXT),
-          token_id: Runtime.read(fields, "token_id", Runtime::UUID)
+          backoff_steps_s: Runtime.maybe(fields, "backoff_steps_s", Runtime.list(Runtime::INTEGER)),
+          max_retries: Runtime.maybe(fields, "max_retries", Runtime::INTEGER),
+          max_window_s: Runtime.maybe(fields, "max_window_s", Runtime::INTEGER)
         )
       end
 
@@ -2659,10 +7521,9 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["biscuit"] = @biscuit
-        out["created_at"] = Runtime.moment(@created_at)
-        out["name"] = @name
-        out["token_id"] = @token_id
+        out["backoff_steps_s"] = @backoff_steps_s unless @backoff_steps_s.nil?
+        out["max_retries"] = @max_retries unless @max_retries.nil?
+        out["max_window_s"] = @max_window_s unless @max_window_s.nil?
         out
       end
 
@@ -2671,7 +7532,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(ServiceToken) && to_h == other.to_h
+        other.is_a?(SubscriptionPostRetryPolicy) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2683,28 +7544,85 @@ module Hook0
       end
     end
 
-    # The `ServiceTokenPost` the API declares.
-    class ServiceTokenPost
-      attr_reader :name,
-                  :organization_id
-
-      # @param name [String] carries `name`.
-      # @param organization_id [String] carries `organization_id`.
-      def initialize(name:, organization_id:)
-        @name = name
-        @organization_id = organization_id
+    # The `SubscriptionPostTarget` the API declares.
+    class SubscriptionPostTarget
+      attr_reader :type,
+                  :access_key_id,
+                  :exchange,
+                  :headers,
+                  :method_,
+                  :password,
+                  :queue_url,
+                  :region,
+                  :routing_key,
+                  :secret_access_key,
+                  :url,
+                  :username
+
+      # @param type [String] carries `type`, one of `SubscriptionPostTargetType::VALUES`: Kind of target: `http`,
+      #   `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+      # @param access_key_id [String, nil] carries `access_key_id`: `sqs`: access key ID requests are signed with
+      # @param exchange [String, nil] carries `exchange`: `amqp`: exchange messages are published to; empty for the
+      #   default exchange
+      # @param headers [Object, nil] carries `headers`: `http`: headers sent with every request
+      # @param method_ [String, nil] carries `method`: `http`: HTTP method of the requests
+      # @param password [String, nil] carries `password`: `amqp`: password to log in to the broker with; write-only
+      # @param queue_url [String, nil] carries `queue_url`: `sqs`: URL of the queue
+      # @param region [String, nil] carries `region`: `sqs`: region requests are signed for
+      # @param routing_key [String, nil] carries `routing_key`: `amqp`: routing key messages are published with
+      # @param secret_access_key [String, nil] carries `secret_access_key`: `sqs`: secret access key requests are signed
+      #   with; write-only
+      # @param url [String, nil] carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or
+      #   `amqps` scheme), without credentials
+      # @param username [String, nil] carries `username`: `amqp`: user name to log in to the broker with
+      def initialize(
+        type:,
+        access_key_id: nil,
+        exchange: nil,
+        headers: nil,
+        method_: nil,
+        password: nil,
+        queue_url: nil,
+        region: nil,
+        routing_key: nil,
+        secret_access_key: nil,
+        url: nil,
+        username: nil
+      )
+        @type = type
+        @access_key_id = access_key_id
+        @exchange = exchange
+        @headers = headers
+        @method_ = method_
+        @password = password
+        @queue_url = queue_url
+        @region = region
+        @routing_key = routing_key
+        @secret_access_key = secret_access_key
+        @url = url
+        @username = username
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [ServiceTokenPost]
+      # @return [SubscriptionPostTarget]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "ServiceTokenPost")
+        fields = Runtime.as_fields(value, "SubscriptionPostTarget")
         new(
-          name: Runtime.read(fields, "name", Runtime::TEXT),
-          organization_id: Runtime.read(fields, "organization_id", Runtime::UUID)
+          type: Runtime.read(fields, "type", Runtime.member_of(SubscriptionPostTargetType)),
+          access_key_id: Runtime.maybe(fields, "access_key_id", Runtime::TEXT),
+          exchange: Runtime.maybe(fields, "exchange", Runtime::TEXT),
+          headers: Runtime.maybe(fields, "headers", Runtime::JSON_VALUE),
+          method_: Runtime.maybe(fields, "method", Runtime::TEXT),
+          password: Runtime.maybe(fields, "password", Runtime::TEXT),
+          queue_url: Runtime.maybe(fields, "queue_url", Runtime::TEXT),
+          region: Runtime.maybe(fields, "region", Runtime::TEXT),
+          routing_key: Runtime.maybe(fields, "routing_key", Runtime::TEXT),
+          secret_access_key: Runtime.maybe(fields, "secret_access_key", Runtime::TEXT),
+          url: Runtime.maybe(fields, "url", Runtime::TEXT),
+          username: Runtime.maybe(fields, "username", Runtime::TEXT)
         )
       end
 
@@ -2713,8 +7631,18 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["name"] = @name
-        out["organization_id"] = @organization_id
+        out["type"] = @type
+        out["access_key_id"] = @access_key_id unless @access_key_id.nil?
+        out["exchange"] = @exchange unless @exchange.nil?
+        out["headers"] = @headers unless @headers.nil?
+        out["method"] = @method_ unless @method_.nil?
+        out["password"] = @password unless @password.nil?
+        out["queue_url"] = @queue_url unless @queue_url.nil?
+        out["region"] = @region unless @region.nil?
+        out["routing_key"] = @routing_key unless @routing_key.nil?
+        out["secret_access_key"] = @secret_access_key unless @secret_access_key.nil?
+        out["url"] = @url unless @url.nil?
+        out["username"] = @username unless @username.nil?
         out
       end
 
@@ -2723,7 +7651,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(ServiceTokenPost) && to_h == other.to_h
+        other.is_a?(SubscriptionPostTarget) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2735,91 +7663,39 @@ module Hook0
       end
     end
 
-    # The `Subscription` the API declares.
-    class Subscription
-      attr_reader :application_id,
-                  :created_at,
-                  :dedicated_workers,
-                  :event_types,
-                  :is_enabled,
-                  :label_key,
-                  :label_value,
-                  :labels,
-                  :metadata,
-                  :secret,
-                  :subscription_id,
-                  :target,
-                  :updated_at,
-                  :description
-
-      # @param application_id [String] carries `application_id`.
-      # @param created_at [Time] carries `created_at`.
-      # @param dedicated_workers [Array<String>] carries `dedicated_workers`.
-      # @param event_types [Array<String>] carries `event_types`.
-      # @param is_enabled [Boolean] carries `is_enabled`.
-      # @param label_key [String] carries `label_key`: _Kept for backward compatibility, you should use `labels`_
-      # @param label_value [String] carries `label_value`: _Kept for backward compatibility, you should use `labels`_
-      # @param labels [Hash{String => String}] carries `labels`.
-      # @param metadata [Hash{String => String}] carries `metadata`.
-      # @param secret [String] carries `secret`.
-      # @param subscription_id [String] carries `subscription_id`.
-      # @param target [SubscriptionTarget] carries `target`.
-      # @param updated_at [Time] carries `updated_at`.
-      # @param description [String, nil] carries `description`.
-      def initialize(
-        application_id:,
-        created_at:,
-        dedicated_workers:,
-        event_types:,
-        is_enabled:,
-        label_key:,
-        label_value:,
-        labels:,
-        metadata:,
-        secret:,
-        subscription_id:,
-        target:,
-        updated_at:,
-        description: nil
-      )
-        @application_id = application_id
-        @created_at = created_at
-        @dedicated_workers = dedicated_workers
-        @event_types = event_types
-        @is_enabled = is_enabled
-        @label_key = label_key
-        @label_value = label_value
-        @labels = labels
-        @metadata = metadata
-        @secret = secret
-        @subscription_id = subscription_id
-        @target = target
-        @updated_at = updated_at
-        @description = description
+    # The `SubscriptionResponseRules` the API declares.
+    class SubscriptionResponseRules
+      attr_reader :outcome,
+                  :status,
+                  :body_contains,
+                  :header
+
+      # @param outcome [String] carries `outcome`, one of `SubscriptionResponseRulesOutcome::VALUES`: What the delivery
+      #   becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or
+      #   `disable_subscription` (not retried, and the subscription is disabled)
+      # @param status [Array<String>] carries `status`: Status codes the rule applies to: exact codes such as `410`, or
+      #   classes such as `4xx`
+      # @param body_contains [String, nil] carries `body_contains`: Text the response body must contain
+      # @param header [SubscriptionResponseRulesHeader, nil] carries `header`: Header the response must have
+      def initialize(outcome:, status:, body_contains: nil, header: nil)
+        @outcome = outcome
+        @status = status
+        @body_contains = body_contains
+        @header = header
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [Subscription]
+      # @return [SubscriptionResponseRules]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "Subscription")
+        fields = Runtime.as_fields(value, "SubscriptionResponseRules")
         new(
-          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
-          created_at: Runtime.read(fields, "created_at", Runtime::DATE_TIME),
-          dedicated_workers: Runtime.read(fields, "dedicated_workers", Runtime.list(Runtime::TEXT)),
-          event_types: Runtime.read(fields, "event_types", Runtime.list(Runtime::TEXT)),
-          is_enabled: Runtime.read(fields, "is_enabled", Runtime::BOOLEAN),
-          label_key: Runtime.read(fields, "label_key", Runtime::TEXT),
-          label_value: Runtime.read(fields, "label_value", Runtime::TEXT),
-          labels: Runtime.read(fields, "labels", Runtime.map(Runtime::TEXT)),
-          metadata: Runtime.read(fields, "metadata", Runtime.map(Runtime::TEXT)),
-          secret: Runtime.read(fields, "secret", Runtime::UUID),
-          subscription_id: Runtime.read(fields, "subscription_id", Runtime::UUID),
-          target: Runtime.read(fields, "target", SubscriptionTarget.method(:from_json)),
-          updated_at: Runtime.read(fields, "updated_at", Runtime::DATE_TIME),
-          description: Runtime.maybe(fields, "description", Runtime::TEXT)
+          outcome: Runtime.read(fields, "outcome", Runtime.member_of(SubscriptionResponseRulesOutcome)),
+          status: Runtime.read(fields, "status", Runtime.list(Runtime::TEXT)),
+          body_contains: Runtime.maybe(fields, "body_contains", Runtime::TEXT),
+          header: Runtime.maybe(fields, "header", SubscriptionResponseRulesHeader.method(:from_json))
         )
       end
 
@@ -2828,20 +7704,10 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["application_id"] = @application_id
-        out["created_at"] = Runtime.moment(@created_at)
-        out["dedicated_workers"] = @dedicated_workers
-        out["event_types"] = @event_types
-        out["is_enabled"] = @is_enabled
-        out["label_key"] = @label_key
-        out["label_value"] = @label_value
-        out["labels"] = @labels
-        out["metadata"] = @metadata
-        out["secret"] = @secret
-        out["subscription_id"] = @subscription_id
-        out["target"] = @target.to_h
-        out["updated_at"] = Runtime.moment(@updated_at)
-        out["description"] = @description unless @description.nil?
+        out["outcome"] = @outcome
+        out["status"] = @status
+        out["body_contains"] = @body_contains unless @body_contains.nil?
+        out["header"] = @header.to_h unless @header.nil?
         out
       end
 
@@ -2850,7 +7716,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(Subscription) && to_h == other.to_h
+        other.is_a?(SubscriptionResponseRules) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2862,72 +7728,28 @@ module Hook0
       end
     end
 
-    # The `SubscriptionPost` the API declares.
-    class SubscriptionPost
-      attr_reader :application_id,
-                  :event_types,
-                  :is_enabled,
-                  :target,
-                  :dedicated_workers,
-                  :description,
-                  :label_key,
-                  :label_value,
-                  :labels,
-                  :metadata
+    # The `SubscriptionResponseRulesHeader` the API declares.
+    class SubscriptionResponseRulesHeader
+      attr_reader :name,
+                  :value
 
-      # @param application_id [String] carries `application_id`.
-      # @param event_types [Array<String>] carries `event_types`.
-      # @param is_enabled [Boolean] carries `is_enabled`.
-      # @param target [SubscriptionPostTarget] carries `target`.
-      # @param dedicated_workers [Array<String>, nil] carries `dedicated_workers`.
-      # @param description [String, nil] carries `description`.
-      # @param label_key [String, nil] carries `label_key`: _Kept for backward compatibility, you should use `labels`_
-      # @param label_value [String, nil] carries `label_value`: _Kept for backward compatibility, you should use
-      #   `labels`_
-      # @param labels [Hash{String => String}, nil] carries `labels`.
-      # @param metadata [Hash{String => String}, nil] carries `metadata`.
-      def initialize(
-        application_id:,
-        event_types:,
-        is_enabled:,
-        target:,
-        dedicated_workers: nil,
-        description: nil,
-        label_key: nil,
-        label_value: nil,
-        labels: nil,
-        metadata: nil
-      )
-        @application_id = application_id
-        @event_types = event_types
-        @is_enabled = is_enabled
-        @target = target
-        @dedicated_workers = dedicated_workers
-        @description = description
-        @label_key = label_key
-        @label_value = label_value
-        @labels = labels
-        @metadata = metadata
+      # @param name [String] carries `name`: Name of the header, regardless of case
+      # @param value [String, nil] carries `value`: Value the header must have; when omitted, any value matches
+      def initialize(name:, value: nil)
+        @name = name
+        @value = value
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [SubscriptionPost]
+      # @return [SubscriptionResponseRulesHeader]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "SubscriptionPost")
+        fields = Runtime.as_fields(value, "SubscriptionResponseRulesHeader")
         new(
-          application_id: Runtime.read(fields, "application_id", Runtime::UUID),
-          event_types: Runtime.read(fields, "event_types", Runtime.list(Runtime::TEXT)),
-          is_enabled: Runtime.read(fields, "is_enabled", Runtime::BOOLEAN),
-          target: Runtime.read(fields, "target", SubscriptionPostTarget.method(:from_json)),
-          dedicated_workers: Runtime.maybe(fields, "dedicated_workers", Runtime.list(Runtime::TEXT)),
-          description: Runtime.maybe(fields, "description", Runtime::TEXT),
-          label_key: Runtime.maybe(fields, "label_key", Runtime::TEXT),
-          label_value: Runtime.maybe(fields, "label_value", Runtime::TEXT),
-          labels: Runtime.maybe(fields, "labels", Runtime.map(Runtime::TEXT)),
-          metadata: Runtime.maybe(fields, "metadata", Runtime.map(Runtime::TEXT))
+          name: Runtime.read(fields, "name", Runtime::TEXT),
+          value: Runtime.maybe(fields, "value", Runtime::TEXT)
         )
       end
 
@@ -2936,16 +7758,8 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["application_id"] = @application_id
-        out["event_types"] = @event_types
-        out["is_enabled"] = @is_enabled
-        out["target"] = @target.to_h
-        out["dedicated_workers"] = @dedicated_workers unless @dedicated_workers.nil?
-        out["description"] = @description unless @description.nil?
-        out["label_key"] = @label_key unless @label_key.nil?
-        out["label_value"] = @label_value unless @label_value.nil?
-        out["labels"] = @labels unless @labels.nil?
-        out["metadata"] = @metadata unless @metadata.nil?
+        out["name"] = @name
+        out["value"] = @value unless @value.nil?
         out
       end
 
@@ -2954,7 +7768,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(SubscriptionPost) && to_h == other.to_h
+        other.is_a?(SubscriptionResponseRulesHeader) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -2966,36 +7780,35 @@ module Hook0
       end
     end
 
-    # The `SubscriptionPostTarget` the API declares.
-    class SubscriptionPostTarget
-      attr_reader :headers,
-                  :method_,
-                  :type,
-                  :url
-
-      # @param headers [Object] carries `headers`.
-      # @param method_ [String] carries `method`.
-      # @param type [String] carries `type`.
-      # @param url [String] carries `url`.
-      def initialize(headers:, method_:, type:, url:)
-        @headers = headers
-        @method_ = method_
-        @type = type
-        @url = url
+    # The `SubscriptionRetryPolicy` the API declares.
+    class SubscriptionRetryPolicy
+      attr_reader :backoff_steps_s,
+                  :max_retries,
+                  :max_window_s
+
+      # @param backoff_steps_s [Array<Integer>, nil] carries `backoff_steps_s`: Delays in seconds to wait before each
+      #   retry; the last one is used again for the following retries
+      # @param max_retries [Integer, nil] carries `max_retries`: Maximum number of retries after a failed delivery (0
+      #   disables retries)
+      # @param max_window_s [Integer, nil] carries `max_window_s`: Maximum duration in seconds the retries can span; a
+      #   retry that would not fit in it is not scheduled
+      def initialize(backoff_steps_s: nil, max_retries: nil, max_window_s: nil)
+        @backoff_steps_s = backoff_steps_s
+        @max_retries = max_retries
+        @max_window_s = max_window_s
         freeze
       end
 
       # Read one out of what the API answered.
       #
       # @param value [Object] the JSON document the API answered
-      # @return [SubscriptionPostTarget]
+      # @return [SubscriptionRetryPolicy]
       def self.from_json(value)
-        fields = Runtime.as_fields(value, "SubscriptionPostTarget")
+        fields = Runtime.as_fields(value, "SubscriptionRetryPolicy")
         new(
-          headers: Runtime.read(fields, "headers", Runtime::JSON_VALUE),
-          method_: Runtime.read(fields, "method", Runtime::TEXT),
-          type: Runtime.read(fields, "type", Runtime::TEXT),
-          url: Runtime.read(fields, "url", Runtime::TEXT)
+          backoff_steps_s: Runtime.maybe(fields, "backoff_steps_s", Runtime.list(Runtime::INTEGER)),
+          max_retries: Runtime.maybe(fields, "max_retries", Runtime::INTEGER),
+          max_window_s: Runtime.maybe(fields, "max_window_s", Runtime::INTEGER)
         )
       end
 
@@ -3004,10 +7817,9 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["headers"] = @headers
-        out["method"] = @method_
-        out["type"] = @type
-        out["url"] = @url
+        out["backoff_steps_s"] = @backoff_steps_s unless @backoff_steps_s.nil?
+        out["max_retries"] = @max_retries unless @max_retries.nil?
+        out["max_window_s"] = @max_window_s unless @max_window_s.nil?
         out
       end
 
@@ -3016,7 +7828,7 @@ module Hook0
       # @param other [Object]
       # @return [Boolean]
       def ==(other)
-        other.is_a?(SubscriptionPostTarget) && to_h == other.to_h
+        other.is_a?(SubscriptionRetryPolicy) && to_h == other.to_h
       end
       alias eql? ==
 
@@ -3030,20 +7842,61 @@ module Hook0
 
     # The `SubscriptionTarget` the API declares.
     class SubscriptionTarget
-      attr_reader :headers,
+      attr_reader :type,
+                  :access_key_id,
+                  :exchange,
+                  :headers,
                   :method_,
-                  :type,
-                  :url
-
-      # @param headers [Object] carries `headers`.
-      # @param method_ [String] carries `method`.
-      # @param type [String] carries `type`.
-      # @param url [String] carries `url`.
-      def initialize(headers:, method_:, type:, url:)
+                  :password,
+                  :queue_url,
+                  :region,
+                  :routing_key,
+                  :secret_access_key,
+                  :url,
+                  :username
+
+      # @param type [String] carries `type`, one of `SubscriptionTargetType::VALUES`: Kind of target: `http`, `amqp`,
+      #   `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+      # @param access_key_id [String, nil] carries `access_key_id`: `sqs`: access key ID requests are signed with
+      # @param exchange [String, nil] carries `exchange`: `amqp`: exchange messages are published to; empty for the
+      #   default exchange
+      # @param headers [Object, nil] carries `headers`: `http`: headers sent with every request
+      # @param method_ [String, nil] carries `method`: `http`: HTTP method of the requests
+      # @param password [String, nil] carries `password`: `amqp`: password to log in to the broker with; write-only
+      # @param queue_url [String, nil] carries `queue_url`: `sqs`: URL of the queue
+      # @param region [String, nil] carries `region`: `sqs`: region requests are signed for
+      # @param routing_key [String, nil] carries `routing_key`: `amqp`: routing key messages are published with
+      # @param secret_access_key [String, nil] carries `secret_access_key`: `sqs`: secret access key requests are signed
+      #   with; write-only
+      # @param url [String, nil] carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or
+      #   `amqps` scheme), without credentials
+      # @param username [String, nil] carries `username`: `amqp`: user name to log in to the broker with
+      def initialize(
+        type:,
+        access_key_id: nil,
+        exchange: nil,
+        headers: nil,
+        method_: nil,
+        password: nil,
+        queue_url: nil,
+        region: nil,
+        routing_key: nil,
+        secret_access_key: nil,
+        url: nil,
+        username: nil
+      )
+        @type = type
+        @access_key_id = access_key_id
+        @exchange = exchange
         @headers = headers
         @method_ = method_
-        @type = type
+        @password = password
+        @queue_url = queue_url
+        @region = region
+        @routing_key = routing_key
+        @secret_access_key = secret_access_key
         @url = url
+        @username = username
         freeze
       end
 
@@ -3054,10 +7907,18 @@ module Hook0
       def self.from_json(value)
         fields = Runtime.as_fields(value, "SubscriptionTarget")
         new(
-          headers: Runtime.read(fields, "headers", Runtime::JSON_VALUE),
-          method_: Runtime.read(fields, "method", Runtime::TEXT),
-          type: Runtime.read(fields, "type", Runtime::TEXT),
-          url: Runtime.read(fields, "url", Runtime::TEXT)
+          type: Runtime.read(fields, "type", Runtime.member_of(SubscriptionTargetType)),
+          access_key_id: Runtime.maybe(fields, "access_key_id", Runtime::TEXT),
+          exchange: Runtime.maybe(fields, "exchange", Runtime::TEXT),
+          headers: Runtime.maybe(fields, "headers", Runtime::JSON_VALUE),
+          method_: Runtime.maybe(fields, "method", Runtime::TEXT),
+          password: Runtime.maybe(fields, "password", Runtime::TEXT),
+          queue_url: Runtime.maybe(fields, "queue_url", Runtime::TEXT),
+          region: Runtime.maybe(fields, "region", Runtime::TEXT),
+          routing_key: Runtime.maybe(fields, "routing_key", Runtime::TEXT),
+          secret_access_key: Runtime.maybe(fields, "secret_access_key", Runtime::TEXT),
+          url: Runtime.maybe(fields, "url", Runtime::TEXT),
+          username: Runtime.maybe(fields, "username", Runtime::TEXT)
         )
       end
 
@@ -3066,10 +7927,18 @@ module Hook0
       # @return [Hash{String => Object}]
       def to_h
         out = {}
-        out["headers"] = @headers
-        out["method"] = @method_
         out["type"] = @type
-        out["url"] = @url
+        out["access_key_id"] = @access_key_id unless @access_key_id.nil?
+        out["exchange"] = @exchange unless @exchange.nil?
+        out["headers"] = @headers unless @headers.nil?
+        out["method"] = @method_ unless @method_.nil?
+        out["password"] = @password unless @password.nil?
+        out["queue_url"] = @queue_url unless @queue_url.nil?
+        out["region"] = @region unless @region.nil?
+        out["routing_key"] = @routing_key unless @routing_key.nil?
+        out["secret_access_key"] = @secret_access_key unless @secret_access_key.nil?
+        out["url"] = @url unless @url.nil?
+        out["username"] = @username unless @username.nil?
         out
       end
 
@@ -14,21 +14,52 @@ use super::models::ApplicationInfo;
 use super::models::ApplicationPost;
 use super::models::ApplicationSecret;
 use super::models::ApplicationSecretPost;
+use super::models::BulkRetry;
+use super::models::BulkRetryDryRun;
+use super::models::BulkRetryPost;
+use super::models::CancelEvent;
+use super::models::ClientCertificate;
+use super::models::ClientCertificatePut;
+use super::models::DeadLetter;
+use super::models::DeadLetterDetails;
 use super::models::Event;
 use super::models::EventPost;
 use super::models::EventType;
 use super::models::EventTypePost;
+use super::models::EventTypePut;
+use super::models::EventTypeVersion;
+use super::models::EventTypeVersionDeprecate;
+use super::models::EventTypeVersionPost;
 use super::models::EventWithPayload;
+use super::models::EventsBatchPost;
 use super::models::EventsPerDayEntry;
+use super::models::Inbox;
+use super::models::InboxAck;
+use super::models::InboxNack;
+use super::models::InboxSettlement;
 use super::models::IngestedEvent;
+use super::models::IngestedEventsBatch;
 use super::models::InstanceConfig;
+use super::models::JsonWebKeySet;
+use super::models::Oauth2ClientCredentials;
+use super::models::Oauth2ClientCredentialsPut;
 use super::models::Problem;
+use super::models::PurgeDeadLetters;
+use super::models::PurgedDeadLetters;
 use super::models::QuotasResponse;
+use super::models::RedeliverDeadLetter;
+use super::models::RedeliverDeadLetters;
+use super::models::RedeliveredDeadLetter;
 use super::models::ReplayEvent;
 use super::models::RequestAttempt;
 use super::models::Response;
+use super::models::RetryRequestAttempt;
+use super::models::SecretRotation;
+use super::models::SecretRotationPost;
 use super::models::ServiceToken;
 use super::models::ServiceTokenPost;
+use super::models::SigningKey;
+use super::models::SigningKeyPost;
 use super::models::Subscription;
 use super::models::SubscriptionPost;
 
@@ -286,6 +317,348 @@ impl<T: Transport> ApplicationsApi<T> {
     }
 }
 
+/// What the API declares under `bulkRetries`.
+///
+/// Every method of it is issued through the transport it is handed.
+#[derive(Debug, Clone)]
+pub struct BulkRetriesApi<T> {
+    transport: T,
+}
+
+impl<T: Transport> BulkRetriesApi<T> {
+    /// Reaches what the API declares under `bulkRetries`.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// `bulkRetries.cancel`, `POST /api/v1/bulk_retries/{bulk_retry_id}/cancel`.
+    ///
+    /// Cancel a bulk retry
+    pub async fn cancel(
+        &self,
+        bulk_retry_id: &str,
+        application_id: &str,
+    ) -> Result<BulkRetry, RequestError> {
+        let mut path = "/api/v1/bulk_retries/{bulk_retry_id}/cancel".to_owned();
+        path = path.replace("{bulk_retry_id}", &path_segment(&bulk_retry_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("POST", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `bulkRetries.create`, `POST /api/v1/bulk_retries/`.
+    ///
+    /// Create a bulk retry
+    pub async fn create(&self, body: BulkRetryPost) -> Result<BulkRetry, RequestError> {
+        let path = "/api/v1/bulk_retries/".to_owned();
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `bulkRetries.dry_run`, `POST /api/v1/bulk_retries/dry_run`.
+    ///
+    /// Count what a bulk retry would redeliver
+    pub async fn dry_run(&self, body: BulkRetryPost) -> Result<BulkRetryDryRun, RequestError> {
+        let path = "/api/v1/bulk_retries/dry_run".to_owned();
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `bulkRetries.get`, `GET /api/v1/bulk_retries/{bulk_retry_id}`.
+    ///
+    /// Get a bulk retry
+    pub async fn get(
+        &self,
+        bulk_retry_id: &str,
+        application_id: &str,
+    ) -> Result<BulkRetry, RequestError> {
+        let mut path = "/api/v1/bulk_retries/{bulk_retry_id}".to_owned();
+        path = path.replace("{bulk_retry_id}", &path_segment(&bulk_retry_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `bulkRetries.list`, `GET /api/v1/bulk_retries/`.
+    ///
+    /// List bulk retries
+    pub async fn list(&self, application_id: &str) -> Result<Vec<BulkRetry>, RequestError> {
+        let path = "/api/v1/bulk_retries/".to_owned();
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+}
+
+/// What the API declares under `clientCertificates`.
+///
+/// Every method of it is issued through the transport it is handed.
+#[derive(Debug, Clone)]
+pub struct ClientCertificatesApi<T> {
+    transport: T,
+}
+
+impl<T: Transport> ClientCertificatesApi<T> {
+    /// Reaches what the API declares under `clientCertificates`.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// `clientCertificates.delete`, `DELETE /api/v1/subscriptions/{subscription_id}/client_certificate`.
+    ///
+    /// Remove the client certificate of a subscription
+    pub async fn delete(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+    ) -> Result<(), RequestError> {
+        let mut path = "/api/v1/subscriptions/{subscription_id}/client_certificate".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("DELETE", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        Ok(())
+    }
+
+    /// `clientCertificates.get`, `GET /api/v1/subscriptions/{subscription_id}/client_certificate`.
+    ///
+    /// Get the client certificate of a subscription
+    pub async fn get(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+    ) -> Result<ClientCertificate, RequestError> {
+        let mut path = "/api/v1/subscriptions/{subscription_id}/client_certificate".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `clientCertificates.update`, `PUT /api/v1/subscriptions/{subscription_id}/client_certificate`.
+    ///
+    /// Set the client certificate of a subscription
+    pub async fn update(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+        body: ClientCertificatePut,
+    ) -> Result<ClientCertificate, RequestError> {
+        let mut path = "/api/v1/subscriptions/{subscription_id}/client_certificate".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("PUT", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+}
+
+/// What the API declares under `deadLetters`.
+///
+/// Every method of it is issued through the transport it is handed.
+#[derive(Debug, Clone)]
+pub struct DeadLettersApi<T> {
+    transport: T,
+}
+
+impl<T: Transport> DeadLettersApi<T> {
+    /// Reaches what the API declares under `deadLetters`.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// `deadLetters.delete`, `DELETE /api/v1/dead_letters/{dead_letter_id}`.
+    ///
+    /// Delete a dead letter
+    pub async fn delete(
+        &self,
+        dead_letter_id: &str,
+        application_id: &str,
+    ) -> Result<(), RequestError> {
+        let mut path = "/api/v1/dead_letters/{dead_letter_id}".to_owned();
+        path = path.replace("{dead_letter_id}", &path_segment(&dead_letter_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("DELETE", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        Ok(())
+    }
+
+    /// `deadLetters.get`, `GET /api/v1/dead_letters/{dead_letter_id}`.
+    ///
+    /// Get a dead letter by its ID
+    pub async fn get(
+        &self,
+        dead_letter_id: &str,
+        application_id: &str,
+    ) -> Result<DeadLetterDetails, RequestError> {
+        let mut path = "/api/v1/dead_letters/{dead_letter_id}".to_owned();
+        path = path.replace("{dead_letter_id}", &path_segment(&dead_letter_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `deadLetters.list`, `GET /api/v1/dead_letters/`.
+    ///
+    /// List dead letters
+    pub async fn list(
+        &self,
+        application_id: &str,
+        pagination_cursor: Option<&str>,
+        subscription_id: Option<&str>,
+    ) -> Result<Vec<DeadLetter>, RequestError> {
+        let path = "/api/v1/dead_letters/".to_owned();
+        let mut query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        if let Some(pagination_cursor) = pagination_cursor {
+            query.push(("pagination_cursor", query_value(&pagination_cursor)));
+        }
+        if let Some(subscription_id) = subscription_id {
+            query.push(("subscription_id", query_value(&subscription_id)));
+        }
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `deadLetters.purge`, `POST /api/v1/dead_letters/purge`.
+    ///
+    /// Purge dead letters
+    pub async fn purge(&self, body: PurgeDeadLetters) -> Result<PurgedDeadLetters, RequestError> {
+        let path = "/api/v1/dead_letters/purge".to_owned();
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `deadLetters.redeliver`, `POST /api/v1/dead_letters/{dead_letter_id}/redeliver`.
+    ///
+    /// Redeliver a dead letter
+    pub async fn redeliver(
+        &self,
+        dead_letter_id: &str,
+        body: RedeliverDeadLetter,
+    ) -> Result<RedeliveredDeadLetter, RequestError> {
+        let mut path = "/api/v1/dead_letters/{dead_letter_id}/redeliver".to_owned();
+        path = path.replace("{dead_letter_id}", &path_segment(&dead_letter_id));
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `deadLetters.redeliverMany`, `POST /api/v1/dead_letters/redeliver`.
+    ///
+    /// Redeliver dead letters
+    pub async fn redeliver_many(
+        &self,
+        body: RedeliverDeadLetters,
+    ) -> Result<Vec<RedeliveredDeadLetter>, RequestError> {
+        let path = "/api/v1/dead_letters/redeliver".to_owned();
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+}
+
 /// What the API declares under `errors`.
 ///
 /// Every method of it is issued through the transport it is handed.
@@ -350,6 +723,29 @@ impl<T: Transport> EventTypesApi<T> {
         read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
     }
 
+    /// `eventTypes.createVersion`, `POST /api/v1/event_types/{event_type_name}/versions`.
+    ///
+    /// Create a new event type version
+    pub async fn create_version(
+        &self,
+        event_type_name: &str,
+        body: EventTypeVersionPost,
+    ) -> Result<EventTypeVersion, RequestError> {
+        let mut path = "/api/v1/event_types/{event_type_name}/versions".to_owned();
+        path = path.replace("{event_type_name}", &path_segment(&event_type_name));
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
     /// `eventTypes.delete`, `DELETE /api/v1/event_types/{event_type_name}`.
     ///
     /// Delete an event type
@@ -371,6 +767,29 @@ impl<T: Transport> EventTypesApi<T> {
         Ok(())
     }
 
+    /// `eventTypes.deprecateVersion`, `POST /api/v1/event_types/{event_type_name}/deprecate`.
+    ///
+    /// Deprecate an event type version
+    pub async fn deprecate_version(
+        &self,
+        event_type_name: &str,
+        body: EventTypeVersionDeprecate,
+    ) -> Result<EventTypeVersion, RequestError> {
+        let mut path = "/api/v1/event_types/{event_type_name}/deprecate".to_owned();
+        path = path.replace("{event_type_name}", &path_segment(&event_type_name));
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
     /// `eventTypes.get`, `GET /api/v1/event_types/{event_type_name}`.
     ///
     /// Get an event type by its name
@@ -409,6 +828,29 @@ impl<T: Transport> EventTypesApi<T> {
         let read = serde_json::from_slice(&payload);
         read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
     }
+
+    /// `eventTypes.update`, `PUT /api/v1/event_types/{event_type_name}`.
+    ///
+    /// Update an event type
+    pub async fn update(
+        &self,
+        event_type_name: &str,
+        body: EventTypePut,
+    ) -> Result<EventType, RequestError> {
+        let mut path = "/api/v1/event_types/{event_type_name}".to_owned();
+        path = path.replace("{event_type_name}", &path_segment(&event_type_name));
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("PUT", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
 }
 
 /// What the API declares under `events`.
@@ -425,6 +867,24 @@ impl<T: Transport> EventsApi<T> {
         Self { transport }
     }
 
+    /// `events.cancel`, `POST /api/v1/events/{event_id}/cancel`.
+    ///
+    /// Cancel a scheduled event
+    pub async fn cancel(&self, event_id: &str, body: CancelEvent) -> Result<(), RequestError> {
+        let mut path = "/api/v1/events/{event_id}/cancel".to_owned();
+        path = path.replace("{event_id}", &path_segment(&event_id));
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        Ok(())
+    }
+
     /// `events.get`, `GET /api/v1/events/{event_id}`.
     ///
     /// Get an event by its ID
@@ -465,12 +925,40 @@ impl<T: Transport> EventsApi<T> {
         read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
     }
 
+    /// `events.ingestBatch`, `POST /api/v1/events/batch`.
+    ///
+    /// Ingest a batch of events
+    pub async fn ingest_batch(
+        &self,
+        body: EventsBatchPost,
+    ) -> Result<IngestedEventsBatch, RequestError> {
+        let path = "/api/v1/events/batch".to_owned();
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
     /// `events.list`, `GET /api/v1/events/`.
     ///
     /// List latest events
-    pub async fn list(&self, application_id: &str) -> Result<Vec<Event>, RequestError> {
+    pub async fn list(
+        &self,
+        application_id: &str,
+        scheduled: Option<bool>,
+    ) -> Result<Vec<Event>, RequestError> {
         let path = "/api/v1/events/".to_owned();
-        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let mut query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        if let Some(scheduled) = scheduled {
+            query.push(("scheduled", query_value(&scheduled)));
+        }
         let issued = self.transport.request("GET", &path, &query, None);
         let (status, payload) = issued.await.map_err(RequestError::transport)?;
 
@@ -543,23 +1031,118 @@ impl<T: Transport> EventsPerDayApi<T> {
         read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
     }
 
-    /// `events_per_day.list_for_organization`, `GET /api/v1/events_per_day/organization`.
+    /// `events_per_day.list_for_organization`, `GET /api/v1/events_per_day/organization`.
+    ///
+    /// List events per day for an organization
+    pub async fn list_for_organization(
+        &self,
+        organization_id: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<Vec<EventsPerDayEntry>, RequestError> {
+        let path = "/api/v1/events_per_day/organization".to_owned();
+        let mut query: Vec<(&str, String)> =
+            vec![("organization_id", query_value(&organization_id))];
+        if let Some(from) = from {
+            query.push(("from", query_value(&from)));
+        }
+        if let Some(to) = to {
+            query.push(("to", query_value(&to)));
+        }
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+}
+
+/// What the API declares under `inbox`.
+///
+/// Every method of it is issued through the transport it is handed.
+#[derive(Debug, Clone)]
+pub struct InboxApi<T> {
+    transport: T,
+}
+
+impl<T: Transport> InboxApi<T> {
+    /// Reaches what the API declares under `inbox`.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// `inbox.ack`, `POST /api/v1/inbox/{subscription_id}/ack`.
+    ///
+    /// Acknowledge events pulled from an inbox
+    pub async fn ack(
+        &self,
+        subscription_id: &str,
+        body: InboxAck,
+    ) -> Result<InboxSettlement, RequestError> {
+        let mut path = "/api/v1/inbox/{subscription_id}/ack".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `inbox.nack`, `POST /api/v1/inbox/{subscription_id}/nack`.
+    ///
+    /// Reject events pulled from an inbox
+    pub async fn nack(
+        &self,
+        subscription_id: &str,
+        body: InboxNack,
+    ) -> Result<InboxSettlement, RequestError> {
+        let mut path = "/api/v1/inbox/{subscription_id}/nack".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `inbox.pull`, `GET /api/v1/inbox/{subscription_id}`.
     ///
-    /// List events per day for an organization
-    pub async fn list_for_organization(
+    /// Pull events from an inbox
+    pub async fn pull(
         &self,
-        organization_id: &str,
-        from: Option<&str>,
-        to: Option<&str>,
-    ) -> Result<Vec<EventsPerDayEntry>, RequestError> {
-        let path = "/api/v1/events_per_day/organization".to_owned();
-        let mut query: Vec<(&str, String)> =
-            vec![("organization_id", query_value(&organization_id))];
-        if let Some(from) = from {
-            query.push(("from", query_value(&from)));
+        subscription_id: &str,
+        application_id: &str,
+        cursor: Option<&str>,
+        limit: Option<i64>,
+        visibility_timeout_s: Option<i64>,
+    ) -> Result<Inbox, RequestError> {
+        let mut path = "/api/v1/inbox/{subscription_id}".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let mut query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        if let Some(cursor) = cursor {
+            query.push(("cursor", query_value(&cursor)));
         }
-        if let Some(to) = to {
-            query.push(("to", query_value(&to)));
+        if let Some(limit) = limit {
+            query.push(("limit", query_value(&limit)));
+        }
+        if let Some(visibility_timeout_s) = visibility_timeout_s {
+            query.push(("visibility_timeout_s", query_value(&visibility_timeout_s)));
         }
         let issued = self.transport.request("GET", &path, &query, None);
         let (status, payload) = issued.await.map_err(RequestError::transport)?;
@@ -605,6 +1188,91 @@ impl<T: Transport> InstanceApi<T> {
     }
 }
 
+/// What the API declares under `oauth2ClientCredentials`.
+///
+/// Every method of it is issued through the transport it is handed.
+#[derive(Debug, Clone)]
+pub struct Oauth2ClientCredentialsApi<T> {
+    transport: T,
+}
+
+impl<T: Transport> Oauth2ClientCredentialsApi<T> {
+    /// Reaches what the API declares under `oauth2ClientCredentials`.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// `oauth2ClientCredentials.delete`, `DELETE /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+    ///
+    /// Remove the OAuth2 client credentials of a subscription
+    pub async fn delete(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+    ) -> Result<(), RequestError> {
+        let mut path =
+            "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("DELETE", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        Ok(())
+    }
+
+    /// `oauth2ClientCredentials.get`, `GET /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+    ///
+    /// Get the OAuth2 client credentials of a subscription
+    pub async fn get(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+    ) -> Result<Oauth2ClientCredentials, RequestError> {
+        let mut path =
+            "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `oauth2ClientCredentials.update`, `PUT /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+    ///
+    /// Set the OAuth2 client credentials of a subscription
+    pub async fn update(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+        body: Oauth2ClientCredentialsPut,
+    ) -> Result<Oauth2ClientCredentials, RequestError> {
+        let mut path =
+            "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("PUT", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+}
+
 /// What the API declares under `payload_content_types`.
 ///
 /// Every method of it is issued through the transport it is handed.
@@ -751,6 +1419,29 @@ impl<T: Transport> RequestAttemptsApi<T> {
         let read = serde_json::from_slice(&payload);
         read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
     }
+
+    /// `requestAttempts.retry`, `POST /api/v1/request_attempts/{request_attempt_id}/retry`.
+    ///
+    /// Retry a request attempt
+    pub async fn retry(
+        &self,
+        request_attempt_id: &str,
+        body: RetryRequestAttempt,
+    ) -> Result<RequestAttempt, RequestError> {
+        let mut path = "/api/v1/request_attempts/{request_attempt_id}/retry".to_owned();
+        path = path.replace("{request_attempt_id}", &path_segment(&request_attempt_id));
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
 }
 
 /// What the API declares under `response`.
@@ -790,6 +1481,111 @@ impl<T: Transport> ResponseApi<T> {
     }
 }
 
+/// What the API declares under `secretRotations`.
+///
+/// Every method of it is issued through the transport it is handed.
+#[derive(Debug, Clone)]
+pub struct SecretRotationsApi<T> {
+    transport: T,
+}
+
+impl<T: Transport> SecretRotationsApi<T> {
+    /// Reaches what the API declares under `secretRotations`.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// `secretRotations.create`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation`.
+    ///
+    /// Rotate the secret of a subscription
+    pub async fn create(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+        body: SecretRotationPost,
+    ) -> Result<SecretRotation, RequestError> {
+        let mut path = "/api/v1/subscriptions/{subscription_id}/secret_rotation".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `secretRotations.finalize`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation/finalize`.
+    ///
+    /// Finalize the secret rotation of a subscription
+    pub async fn finalize(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+    ) -> Result<(), RequestError> {
+        let mut path =
+            "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("POST", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        Ok(())
+    }
+
+    /// `secretRotations.get`, `GET /api/v1/subscriptions/{subscription_id}/secret_rotation`.
+    ///
+    /// Get the secret rotation of a subscription
+    pub async fn get(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+    ) -> Result<SecretRotation, RequestError> {
+        let mut path = "/api/v1/subscriptions/{subscription_id}/secret_rotation".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `secretRotations.rollback`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation/rollback`.
+    ///
+    /// Roll back the secret rotation of a subscription
+    pub async fn rollback(
+        &self,
+        subscription_id: &str,
+        application_id: &str,
+    ) -> Result<(), RequestError> {
+        let mut path =
+            "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback".to_owned();
+        path = path.replace("{subscription_id}", &path_segment(&subscription_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("POST", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        Ok(())
+    }
+}
+
 /// What the API declares under `serviceToken`.
 ///
 /// Every method of it is issued through the transport it is handed.
@@ -906,6 +1702,95 @@ impl<T: Transport> ServiceTokenApi<T> {
     }
 }
 
+/// What the API declares under `signingKeys`.
+///
+/// Every method of it is issued through the transport it is handed.
+#[derive(Debug, Clone)]
+pub struct SigningKeysApi<T> {
+    transport: T,
+}
+
+impl<T: Transport> SigningKeysApi<T> {
+    /// Reaches what the API declares under `signingKeys`.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// `signingKeys.create`, `POST /api/v1/signing_keys/`.
+    ///
+    /// Create a signing key
+    pub async fn create(&self, body: SigningKeyPost) -> Result<SigningKey, RequestError> {
+        let path = "/api/v1/signing_keys/".to_owned();
+        let query: Vec<(&str, String)> = Vec::new();
+        let body = serde_json::to_vec(&body).map_err(RequestError::unwritable)?;
+        let issued = self.transport.request("POST", &path, &query, Some(body));
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `signingKeys.delete`, `DELETE /api/v1/signing_keys/{signing_key_id}`.
+    ///
+    /// Retire a signing key
+    pub async fn delete(
+        &self,
+        signing_key_id: &str,
+        application_id: &str,
+    ) -> Result<(), RequestError> {
+        let mut path = "/api/v1/signing_keys/{signing_key_id}".to_owned();
+        path = path.replace("{signing_key_id}", &path_segment(&signing_key_id));
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("DELETE", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        Ok(())
+    }
+
+    /// `signingKeys.jwks`, `GET /api/v1/jwks/{application_id}`.
+    ///
+    /// Get the public keys of an application
+    pub async fn jwks(&self, application_id: &str) -> Result<JsonWebKeySet, RequestError> {
+        let mut path = "/api/v1/jwks/{application_id}".to_owned();
+        path = path.replace("{application_id}", &path_segment(&application_id));
+        let query: Vec<(&str, String)> = Vec::new();
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+
+    /// `signingKeys.list`, `GET /api/v1/signing_keys/`.
+    ///
+    /// List signing keys
+    pub async fn list(&self, application_id: &str) -> Result<Vec<SigningKey>, RequestError> {
+        let path = "/api/v1/signing_keys/".to_owned();
+        let query: Vec<(&str, String)> = vec![("application_id", query_value(&application_id))];
+        let issued = self.transport.request("GET", &path, &query, None);
+        let (status, payload) = issued.await.map_err(RequestError::transport)?;
+
+        if let Some(failure) = problem_for(status, &payload) {
+            return Err(RequestError::Api(Box::new(failure)));
+        }
+
+        let read = serde_json::from_slice(&payload);
+        read.map_err(|cause| RequestError::unreadable(status, &payload, &cause))
+    }
+}
+
 /// What the API declares under `subscriptions`.
 ///
 /// Every method of it is issued through the transport it is handed.
@@ -15,16 +15,23 @@ mod models;
 
 pub use api::ApplicationSecretsApi;
 pub use api::ApplicationsApi;
+pub use api::BulkRetriesApi;
+pub use api::ClientCertificatesApi;
+pub use api::DeadLettersApi;
 pub use api::ErrorsApi;
 pub use api::EventTypesApi;
 pub use api::EventsApi;
 pub use api::EventsPerDayApi;
+pub use api::InboxApi;
 pub use api::InstanceApi;
+pub use api::Oauth2ClientCredentialsApi;
 pub use api::PayloadContentTypesApi;
 pub use api::QuotasApi;
 pub use api::RequestAttemptsApi;
 pub use api::ResponseApi;
+pub use api::SecretRotationsApi;
 pub use api::ServiceTokenApi;
+pub use api::SigningKeysApi;
 pub use api::SubscriptionsApi;
 pub use api::Transport;
 
@@ -43,16 +50,60 @@ pub use models::ApplicationInfoQuotas;
 pub use models::ApplicationPost;
 pub use models::ApplicationSecret;
 pub use models::ApplicationSecretPost;
+pub use models::BulkRetry;
+pub use models::BulkRetryDryRun;
+pub use models::BulkRetryPost;
+pub use models::BulkRetryPostStatus;
+pub use models::BulkRetryStatus;
+pub use models::CancelEvent;
+pub use models::ClientCertificate;
+pub use models::ClientCertificatePut;
+pub use models::DeadLetter;
+pub use models::DeadLetterDetails;
+pub use models::DeadLetterDetailsDeadLetter;
+pub use models::DeadLetterDetailsDeadLetterEvent;
+pub use models::DeadLetterDetailsDeadLetterReason;
+pub use models::DeadLetterDetailsDeadLetterSubscription;
+pub use models::DeadLetterDetailsLastResponse;
+pub use models::DeadLetterEvent;
+pub use models::DeadLetterReason;
+pub use models::DeadLetterSubscription;
 pub use models::Event;
 pub use models::EventPost;
 pub use models::EventType;
+pub use models::EventTypePayloadSchemaMode;
 pub use models::EventTypePost;
+pub use models::EventTypePostPayloadSchemaMode;
+pub use models::EventTypePut;
+pub use models::EventTypePutPayloadSchemaMode;
+pub use models::EventTypeVersion;
+pub use models::EventTypeVersionDeprecate;
+pub use models::EventTypeVersionPost;
+pub use models::EventTypeVersions;
 pub use models::EventWithPayload;
+pub use models::EventsBatchPost;
+pub use models::EventsBatchPostEvents;
 pub use models::EventsPerDayEntry;
+pub use models::Inbox;
+pub use models::InboxAck;
+pub use models::InboxMessages;
+pub use models::InboxNack;
+pub use models::InboxSettlement;
 pub use models::IngestedEvent;
+pub use models::IngestedEventDeprecation;
+pub use models::IngestedEventsBatch;
+pub use models::IngestedEventsBatchEvents;
+pub use models::IngestedEventsBatchEventsDeprecation;
+pub use models::IngestedEventsBatchEventsProblem;
+pub use models::IngestedEventsBatchEventsProblemId;
+pub use models::IngestedEventsBatchEventsStatus;
 pub use models::InstanceConfig;
 pub use models::InstanceConfigFormbricks;
 pub use models::InstanceConfigMatomo;
+pub use models::JsonWebKeySet;
+pub use models::JsonWebKeySetKeys;
+pub use models::Oauth2ClientCredentials;
+pub use models::Oauth2ClientCredentialsPut;
 pub use models::Organization;
 pub use models::OrganizationInfo;
 pub use models::OrganizationInfoConsumption;
@@ -67,18 +118,53 @@ pub use models::OrganizationInfoUsers;
 pub use models::OrganizationPlan;
 pub use models::Problem;
 pub use models::ProblemId;
+pub use models::PurgeDeadLetters;
+pub use models::PurgedDeadLetters;
 pub use models::QuotasResponse;
 pub use models::QuotasResponseLimits;
+pub use models::RedeliverDeadLetter;
+pub use models::RedeliverDeadLetters;
+pub use models::RedeliveredDeadLetter;
 pub use models::ReplayEvent;
 pub use models::RequestAttempt;
 pub use models::RequestAttemptEvent;
 pub use models::RequestAttemptStatus;
+pub use models::RequestAttemptStatusRuleOutcome;
 pub use models::RequestAttemptStatusType;
 pub use models::RequestAttemptSubscription;
 pub use models::Response;
+pub use models::RetryRequestAttempt;
+pub use models::SecretRotation;
+pub use models::SecretRotationPost;
 pub use models::ServiceToken;
 pub use models::ServiceTokenPost;
+pub use models::SigningKey;
+pub use models::SigningKeyPost;
 pub use models::Subscription;
+pub use models::SubscriptionBatching;
+pub use models::SubscriptionCircuitBreaker;
+pub use models::SubscriptionDeliveryLimits;
+pub use models::SubscriptionOrderingKey;
+pub use models::SubscriptionPayloadTransform;
+pub use models::SubscriptionPayloadTransformFields;
 pub use models::SubscriptionPost;
+pub use models::SubscriptionPostBatching;
+pub use models::SubscriptionPostCircuitBreaker;
+pub use models::SubscriptionPostDeliveryLimits;
+pub use models::SubscriptionPostOrderingKey;
+pub use models::SubscriptionPostPayloadTransform;
+pub use models::SubscriptionPostPayloadTransformFields;
+pub use models::SubscriptionPostResponseRules;
+pub use models::SubscriptionPostResponseRulesHeader;
+pub use models::SubscriptionPostResponseRulesOutcome;
+pub use models::SubscriptionPostRetryPolicy;
+pub use models::SubscriptionPostSignatureScheme;
 pub use models::SubscriptionPostTarget;
+pub use models::SubscriptionPostTargetType;
+pub use models::SubscriptionResponseRules;
+pub use models::SubscriptionResponseRulesHeader;
+pub use models::SubscriptionResponseRulesOutcome;
+pub use models::SubscriptionRetryPolicy;
+pub use models::SubscriptionSignatureScheme;
 pub use models::SubscriptionTarget;
+pub use models::SubscriptionTargetType;
@@ -93,26 +93,26 @@ impl std::fmt::Display for ApplicationInfoOnboardingStepsSubscription {
 
 /// One of the values the API answers with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-pub enum OrganizationInfoOnboardingStepsApplication {
-    /// The `ToDo` the API answers with.
-    #[serde(rename = "ToDo")]
-    ToDo,
-    /// The `Done` the API answers with.
-    #[serde(rename = "Done")]
-    Done,
+pub enum BulkRetryPostStatus {
+    /// The `failed` the API answers with.
+    #[serde(rename = "failed")]
+    Failed,
+    /// The `successful` the API answers with.
+    #[serde(rename = "successful")]
+    Successful,
 }
 
-impl OrganizationInfoOnboardingStepsApplication {
+impl BulkRetryPostStatus {
     /// The text this value travels as.
     pub const fn as_str(&self) -> &'static str {
         match self {
-            Self::ToDo => "ToDo",
-            Self::Done => "Done",
+            Self::Failed => "failed",
+            Self::Successful => "successful",
         }
     }
 }
 
-impl std::fmt::Display for OrganizationInfoOnboardingStepsApplication {
+impl std::fmt::Display for BulkRetryPostStatus {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         formatter.write_str(self.as_str())
     }
@@ -120,26 +120,26 @@ impl std::fmt::Display for OrganizationInfoOnboardingStepsApplication {
 
 /// One of the values the API answers with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-pub enum OrganizationInfoOnboardingStepsEvent {
-    /// The `ToDo` the API answers with.
-    #[serde(rename = "ToDo")]
-    ToDo,
-    /// The `Done` the API answers with.
-    #[serde(rename = "Done")]
-    Done,
+pub enum BulkRetryStatus {
+    /// The `failed` the API answers with.
+    #[serde(rename = "failed")]
+    Failed,
+    /// The `successful` the API answers with.
+    #[serde(rename = "successful")]
+    Successful,
 }
 
-impl OrganizationInfoOnboardingStepsEvent {
+impl BulkRetryStatus {
     /// The text this value travels as.
     pub const fn as_str(&self) -> &'static str {
         match self {
-            Self::ToDo => "ToDo",
-            Self::Done => "Done",
+            Self::Failed => "failed",
+            Self::Successful => "successful",
         }
     }
 }
 
-impl std::fmt::Display for OrganizationInfoOnboardingStepsEvent {
+impl std::fmt::Display for BulkRetryStatus {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         formatter.write_str(self.as_str())
     }
@@ -147,26 +147,69 @@ impl std::fmt::Display for OrganizationInfoOnboardingStepsEvent {
 
 /// One of the values the API answers with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-pub enum OrganizationInfoOnboardingStepsEventType {
-    /// The `ToDo` the API answers with.
-    #[serde(rename = "ToDo")]
-    ToDo,
-    /// The `Done` the API answers with.
-    #[serde(rename = "Done")]
-    Done,
+pub enum DeadLetterDetailsDeadLetterReason {
+    /// The `retries_exhausted` the API answers with.
+    #[serde(rename = "retries_exhausted")]
+    RetriesExhausted,
+    /// The `signature_failed` the API answers with.
+    #[serde(rename = "signature_failed")]
+    SignatureFailed,
+    /// The `transform_failed` the API answers with.
+    #[serde(rename = "transform_failed")]
+    TransformFailed,
+    /// The `permanent_failure` the API answers with.
+    #[serde(rename = "permanent_failure")]
+    PermanentFailure,
+}
+
+impl DeadLetterDetailsDeadLetterReason {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::RetriesExhausted => "retries_exhausted",
+            Self::SignatureFailed => "signature_failed",
+            Self::TransformFailed => "transform_failed",
+            Self::PermanentFailure => "permanent_failure",
+        }
+    }
 }
 
-impl OrganizationInfoOnboardingStepsEventType {
+impl std::fmt::Display for DeadLetterDetailsDeadLetterReason {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum DeadLetterReason {
+    /// The `retries_exhausted` the API answers with.
+    #[serde(rename = "retries_exhausted")]
+    RetriesExhausted,
+    /// The `signature_failed` the API answers with.
+    #[serde(rename = "signature_failed")]
+    SignatureFailed,
+    /// The `transform_failed` the API answers with.
+    #[serde(rename = "transform_failed")]
+    TransformFailed,
+    /// The `permanent_failure` the API answers with.
+    #[serde(rename = "permanent_failure")]
+    PermanentFailure,
+}
+
+impl DeadLetterReason {
     /// The text this value travels as.
     pub const fn as_str(&self) -> &'static str {
         match self {
-            Self::ToDo => "ToDo",
-            Self::Done => "Done",
+            Self::RetriesExhausted => "retries_exhausted",
+            Self::SignatureFailed => "signature_failed",
+            Self::TransformFailed => "transform_failed",
+            Self::PermanentFailure => "permanent_failure",
         }
     }
 }
 
-impl std::fmt::Display for OrganizationInfoOnboardingStepsEventType {
+impl std::fmt::Display for DeadLetterReason {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         formatter.write_str(self.as_str())
     }
@@ -174,26 +217,61 @@ impl std::fmt::Display for OrganizationInfoOnboardingStepsEventType {
 
 /// One of the values the API answers with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-pub enum OrganizationInfoOnboardingStepsSubscription {
-    /// The `ToDo` the API answers with.
-    #[serde(rename = "ToDo")]
-    ToDo,
-    /// The `Done` the API answers with.
-    #[serde(rename = "Done")]
-    Done,
+pub enum EventTypePayloadSchemaMode {
+    /// The `enforce` the API answers with.
+    #[serde(rename = "enforce")]
+    Enforce,
+    /// The `warn` the API answers with.
+    #[serde(rename = "warn")]
+    Warn,
+    /// The `off` the API answers with.
+    #[serde(rename = "off")]
+    Off,
+}
+
+impl EventTypePayloadSchemaMode {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Enforce => "enforce",
+            Self::Warn => "warn",
+            Self::Off => "off",
+        }
+    }
 }
 
-impl OrganizationInfoOnboardingStepsSubscription {
+impl std::fmt::Display for EventTypePayloadSchemaMode {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum EventTypePostPayloadSchemaMode {
+    /// The `enforce` the API answers with.
+    #[serde(rename = "enforce")]
+    Enforce,
+    /// The `warn` the API answers with.
+    #[serde(rename = "warn")]
+    Warn,
+    /// The `off` the API answers with.
+    #[serde(rename = "off")]
+    Off,
+}
+
+impl EventTypePostPayloadSchemaMode {
     /// The text this value travels as.
     pub const fn as_str(&self) -> &'static str {
         match self {
-            Self::ToDo => "ToDo",
-            Self::Done => "Done",
+            Self::Enforce => "enforce",
+            Self::Warn => "warn",
+            Self::Off => "off",
         }
     }
 }
 
-impl std::fmt::Display for OrganizationInfoOnboardingStepsSubscription {
+impl std::fmt::Display for EventTypePostPayloadSchemaMode {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         formatter.write_str(self.as_str())
     }
@@ -201,7 +279,38 @@ impl std::fmt::Display for OrganizationInfoOnboardingStepsSubscription {
 
 /// One of the values the API answers with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-pub enum ProblemId {
+pub enum EventTypePutPayloadSchemaMode {
+    /// The `enforce` the API answers with.
+    #[serde(rename = "enforce")]
+    Enforce,
+    /// The `warn` the API answers with.
+    #[serde(rename = "warn")]
+    Warn,
+    /// The `off` the API answers with.
+    #[serde(rename = "off")]
+    Off,
+}
+
+impl EventTypePutPayloadSchemaMode {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Enforce => "enforce",
+            Self::Warn => "warn",
+            Self::Off => "off",
+        }
+    }
+}
+
+impl std::fmt::Display for EventTypePutPayloadSchemaMode {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum IngestedEventsBatchEventsProblemId {
     /// The `OrganizationNameMissing` the API answers with.
     #[serde(rename = "OrganizationNameMissing")]
     OrganizationNameMissing,
@@ -250,6 +359,15 @@ pub enum ProblemId {
     /// The `EventTypeDoesNotExist` the API answers with.
     #[serde(rename = "EventTypeDoesNotExist")]
     EventTypeDoesNotExist,
+    /// The `EventTypeVersionAlreadyExist` the API answers with.
+    #[serde(rename = "EventTypeVersionAlreadyExist")]
+    EventTypeVersionAlreadyExist,
+    /// The `EventTypeVersionDoesNotExist` the API answers with.
+    #[serde(rename = "EventTypeVersionDoesNotExist")]
+    EventTypeVersionDoesNotExist,
+    /// The `EventTypeVersionSunset` the API answers with.
+    #[serde(rename = "EventTypeVersionSunset")]
+    EventTypeVersionSunset,
     /// The `UnauthorizedWorkers` the API answers with.
     #[serde(rename = "UnauthorizedWorkers")]
     UnauthorizedWorkers,
@@ -265,12 +383,54 @@ pub enum ProblemId {
     /// The `EventInvalidJsonPayload` the API answers with.
     #[serde(rename = "EventInvalidJsonPayload")]
     EventInvalidJsonPayload,
+    /// The `EventPayloadSchemaViolation` the API answers with.
+    #[serde(rename = "EventPayloadSchemaViolation")]
+    EventPayloadSchemaViolation,
+    /// The `EventDeliveryBeyondRetention` the API answers with.
+    #[serde(rename = "EventDeliveryBeyondRetention")]
+    EventDeliveryBeyondRetention,
+    /// The `EventNotScheduled` the API answers with.
+    #[serde(rename = "EventNotScheduled")]
+    EventNotScheduled,
     /// The `LabelsAmbiguity` the API answers with.
     #[serde(rename = "LabelsAmbiguity")]
     LabelsAmbiguity,
     /// The `InvalidDateRange` the API answers with.
     #[serde(rename = "InvalidDateRange")]
     InvalidDateRange,
+    /// The `RequestAttemptNotFailed` the API answers with.
+    #[serde(rename = "RequestAttemptNotFailed")]
+    RequestAttemptNotFailed,
+    /// The `RequestAttemptRetryPending` the API answers with.
+    #[serde(rename = "RequestAttemptRetryPending")]
+    RequestAttemptRetryPending,
+    /// The `SubscriptionDisabled` the API answers with.
+    #[serde(rename = "SubscriptionDisabled")]
+    SubscriptionDisabled,
+    /// The `SubscriptionNotInbox` the API answers with.
+    #[serde(rename = "SubscriptionNotInbox")]
+    SubscriptionNotInbox,
+    /// The `SecretRotationInProgress` the API answers with.
+    #[serde(rename = "SecretRotationInProgress")]
+    SecretRotationInProgress,
+    /// The `BulkRetryAlreadyRunning` the API answers with.
+    #[serde(rename = "BulkRetryAlreadyRunning")]
+    BulkRetryAlreadyRunning,
+    /// The `BulkRetryFinished` the API answers with.
+    #[serde(rename = "BulkRetryFinished")]
+    BulkRetryFinished,
+    /// The `RetryPolicyOverLimit` the API answers with.
+    #[serde(rename = "RetryPolicyOverLimit")]
+    RetryPolicyOverLimit,
+    /// The `ClientCertificatesDisabled` the API answers with.
+    #[serde(rename = "ClientCertificatesDisabled")]
+    ClientCertificatesDisabled,
+    /// The `InvalidClientCertificate` the API answers with.
+    #[serde(rename = "InvalidClientCertificate")]
+    InvalidClientCertificate,
+    /// The `SigningKeysDisabled` the API answers with.
+    #[serde(rename = "SigningKeysDisabled")]
+    SigningKeysDisabled,
     /// The `AuthNoAuthorizationHeader` the API answers with.
     #[serde(rename = "AuthNoAuthorizationHeader")]
     AuthNoAuthorizationHeader,
@@ -342,7 +502,7 @@ pub enum ProblemId {
     ServiceUnavailable,
 }
 
-impl ProblemId {
+impl IngestedEventsBatchEventsProblemId {
     /// The text this value travels as.
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -362,13 +522,30 @@ impl ProblemId {
             Self::InvalidRole => "InvalidRole",
             Self::EventTypeAlreadyExist => "EventTypeAlreadyExist",
             Self::EventTypeDoesNotExist => "EventTypeDoesNotExist",
+            Self::EventTypeVersionAlreadyExist => "EventTypeVersionAlreadyExist",
+            Self::EventTypeVersionDoesNotExist => "EventTypeVersionDoesNotExist",
+            Self::EventTypeVersionSunset => "EventTypeVersionSunset",
             Self::UnauthorizedWorkers => "UnauthorizedWorkers",
             Self::EventAlreadyIngested => "EventAlreadyIngested",
             Self::EventInvalidPayloadContentType => "EventInvalidPayloadContentType",
             Self::EventInvalidBase64Payload => "EventInvalidBase64Payload",
             Self::EventInvalidJsonPayload => "EventInvalidJsonPayload",
+            Self::EventPayloadSchemaViolation => "EventPayloadSchemaViolation",
+            Self::EventDeliveryBeyondRetention => "EventDeliveryBeyondRetention",
+            Self::EventNotScheduled => "EventNotScheduled",
             Self::LabelsAmbiguity => "LabelsAmbiguity",
             Self::InvalidDateRange => "InvalidDateRange",
+            Self::RequestAttemptNotFailed => "RequestAttemptNotFailed",
+            Self::RequestAttemptRetryPending => "RequestAttemptRetryPending",
+            Self::SubscriptionDisabled => "SubscriptionDisabled",
+            Self::SubscriptionNotInbox => "SubscriptionNotInbox",
+            Self::SecretRotationInProgress => "SecretRotationInProgress",
+            Self::BulkRetryAlreadyRunning => "BulkRetryAlreadyRunning",
+            Self::BulkRetryFinished => "BulkRetryFinished",
+            Self::RetryPolicyOverLimit => "RetryPolicyOverLimit",
+            Self::ClientCertificatesDisabled => "ClientCertificatesDisabled",
+            Self::InvalidClientCertificate => "InvalidClientCertificate",
+            Self::SigningKeysDisabled => "SigningKeysDisabled",
             Self::AuthNoAuthorizationHeader => "AuthNoAuthorizationHeader",
             Self::AuthInvalidAuthorizationHeader => "AuthInvalidAuthorizationHeader",
             Self::AuthApplicationSecretLookupError => "AuthApplicationSecretLookupError",
@@ -396,7 +573,7 @@ impl ProblemId {
     }
 }
 
-impl std::fmt::Display for ProblemId {
+impl std::fmt::Display for IngestedEventsBatchEventsProblemId {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         formatter.write_str(self.as_str())
     }
@@ -404,165 +581,1120 @@ impl std::fmt::Display for ProblemId {
 
 /// One of the values the API answers with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-pub enum RequestAttemptStatusType {
-    /// The `waiting` the API answers with.
-    #[serde(rename = "waiting")]
-    Waiting,
-    /// The `pending` the API answers with.
-    #[serde(rename = "pending")]
-    Pending,
-    /// The `in_progress` the API answers with.
-    #[serde(rename = "in_progress")]
-    InProgress,
-    /// The `successful` the API answers with.
-    #[serde(rename = "successful")]
-    Successful,
+pub enum IngestedEventsBatchEventsStatus {
+    /// The `ingested` the API answers with.
+    #[serde(rename = "ingested")]
+    Ingested,
     /// The `failed` the API answers with.
     #[serde(rename = "failed")]
     Failed,
 }
 
-impl RequestAttemptStatusType {
+impl IngestedEventsBatchEventsStatus {
     /// The text this value travels as.
     pub const fn as_str(&self) -> &'static str {
         match self {
-            Self::Waiting => "waiting",
-            Self::Pending => "pending",
-            Self::InProgress => "in_progress",
-            Self::Successful => "successful",
+            Self::Ingested => "ingested",
             Self::Failed => "failed",
         }
     }
 }
 
-impl std::fmt::Display for RequestAttemptStatusType {
+impl std::fmt::Display for IngestedEventsBatchEventsStatus {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         formatter.write_str(self.as_str())
     }
 }
 
-/// The `Application` the API declares.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct Application {
-    /// `application_id`: Unique identifier of the application.
-    #[serde(rename = "application_id")]
-    pub application_id: Uuid,
-    /// `name`: Name of the application. Length: 2-50 characters.
-    #[serde(rename = "name")]
-    pub name: String,
-    /// `organization_id`: UUID of the organization this application belongs to.
-    #[serde(rename = "organization_id")]
-    pub organization_id: Uuid,
-}
-
-/// The `ApplicationInfo` the API declares.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ApplicationInfo {
-    /// `application_id`: Unique identifier of the application.
-    #[serde(rename = "application_id")]
-    pub application_id: Uuid,
-    /// `consumption`: Current consumption metrics for this application.
-    #[serde(rename = "consumption")]
-    pub consumption: ApplicationInfoConsumption,
-    /// `name`: Name of the application. Length: 2-50 characters.
-    #[serde(rename = "name")]
-    pub name: String,
-    /// `onboarding_steps`: Onboarding completion status for this application.
-    #[serde(rename = "onboarding_steps")]
-    pub onboarding_steps: ApplicationInfoOnboardingSteps,
-    /// `organization_id`: UUID of the organization this application belongs to.
-    #[serde(rename = "organization_id")]
-    pub organization_id: Uuid,
-    /// `quotas`: Quota limits for this application.
-    #[serde(rename = "quotas")]
-    pub quotas: ApplicationInfoQuotas,
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum OrganizationInfoOnboardingStepsApplication {
+    /// The `ToDo` the API answers with.
+    #[serde(rename = "ToDo")]
+    ToDo,
+    /// The `Done` the API answers with.
+    #[serde(rename = "Done")]
+    Done,
 }
 
-/// The `ApplicationInfoConsumption` the API declares.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ApplicationInfoConsumption {
-    /// `events_per_day`.
-    #[serde(rename = "events_per_day")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub events_per_day: Option<i32>,
+impl OrganizationInfoOnboardingStepsApplication {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ToDo => "ToDo",
+            Self::Done => "Done",
+        }
+    }
 }
 
-/// The `ApplicationInfoOnboardingSteps` the API declares.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ApplicationInfoOnboardingSteps {
-    /// `event`.
-    #[serde(rename = "event")]
-    pub event: ApplicationInfoOnboardingStepsEvent,
-    /// `event_type`.
-    #[serde(rename = "event_type")]
-    pub event_type: ApplicationInfoOnboardingStepsEventType,
-    /// `subscription`.
-    #[serde(rename = "subscription")]
-    pub subscription: ApplicationInfoOnboardingStepsSubscription,
+impl std::fmt::Display for OrganizationInfoOnboardingStepsApplication {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
 }
 
-/// The `ApplicationInfoQuotas` the API declares.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ApplicationInfoQuotas {
-    /// `days_of_events_retention_limit`.
-    #[serde(rename = "days_of_events_retention_limit")]
-    pub days_of_events_retention_limit: i32,
-    /// `events_per_day_limit`.
-    #[serde(rename = "events_per_day_limit")]
-    pub events_per_day_limit: i32,
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum OrganizationInfoOnboardingStepsEvent {
+    /// The `ToDo` the API answers with.
+    #[serde(rename = "ToDo")]
+    ToDo,
+    /// The `Done` the API answers with.
+    #[serde(rename = "Done")]
+    Done,
 }
 
-/// The `ApplicationPost` the API declares.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ApplicationPost {
-    /// `name`: Name of the application. Length: 2-50 characters.
-    #[serde(rename = "name")]
-    pub name: String,
-    /// `organization_id`: UUID of the organization this application belongs to.
-    #[serde(rename = "organization_id")]
-    pub organization_id: Uuid,
+impl OrganizationInfoOnboardingStepsEvent {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ToDo => "ToDo",
+            Self::Done => "Done",
+        }
+    }
 }
 
-/// The `ApplicationSecret` the API declares.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ApplicationSecret {
-    /// `created_at`.
-    #[serde(rename = "created_at")]
-    pub created_at: DateTime<Utc>,
-    /// `deleted_at`.
-    #[serde(rename = "deleted_at")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deleted_at: Option<DateTime<Utc>>,
-    /// `name`.
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// `token`.
-    #[serde(rename = "token")]
-    pub token: Uuid,
+impl std::fmt::Display for OrganizationInfoOnboardingStepsEvent {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
 }
 
-/// The `ApplicationSecretPost` the API declares.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ApplicationSecretPost {
-    /// `application_id`.
-    #[serde(rename = "application_id")]
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum OrganizationInfoOnboardingStepsEventType {
+    /// The `ToDo` the API answers with.
+    #[serde(rename = "ToDo")]
+    ToDo,
+    /// The `Done` the API answers with.
+    #[serde(rename = "Done")]
+    Done,
+}
+
+impl OrganizationInfoOnboardingStepsEventType {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ToDo => "ToDo",
+            Self::Done => "Done",
+        }
+    }
+}
+
+impl std::fmt::Display for OrganizationInfoOnboardingStepsEventType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum OrganizationInfoOnboardingStepsSubscription {
+    /// The `ToDo` the API answers with.
+    #[serde(rename = "ToDo")]
+    ToDo,
+    /// The `Done` the API answers with.
+    #[serde(rename = "Done")]
+    Done,
+}
+
+impl OrganizationInfoOnboardingStepsSubscription {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ToDo => "ToDo",
+            Self::Done => "Done",
+        }
+    }
+}
+
+impl std::fmt::Display for OrganizationInfoOnboardingStepsSubscription {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum ProblemId {
+    /// The `OrganizationNameMissing` the API answers with.
+    #[serde(rename = "OrganizationNameMissing")]
+    OrganizationNameMissing,
+    /// The `UserAlreadyExist` the API answers with.
+    #[serde(rename = "UserAlreadyExist")]
+    UserAlreadyExist,
+    /// The `RegistrationDisabled` the API answers with.
+    #[serde(rename = "RegistrationDisabled")]
+    RegistrationDisabled,
+    /// The `PasswordTooShort` the API answers with.
+    #[serde(rename = "PasswordTooShort")]
+    PasswordTooShort,
+    /// The `PasswordTooLong` the API answers with.
+    #[serde(rename = "PasswordTooLong")]
+    PasswordTooLong,
+    /// The `PasswordSimilarToEmail` the API answers with.
+    #[serde(rename = "PasswordSimilarToEmail")]
+    PasswordSimilarToEmail,
+    /// The `PasswordSimilarToName` the API answers with.
+    #[serde(rename = "PasswordSimilarToName")]
+    PasswordSimilarToName,
+    /// The `PasswordTooCommon` the API answers with.
+    #[serde(rename = "PasswordTooCommon")]
+    PasswordTooCommon,
+    /// The `PasswordNotDiverseEnough` the API answers with.
+    #[serde(rename = "PasswordNotDiverseEnough")]
+    PasswordNotDiverseEnough,
+    /// The `OrganizationIsNotEmpty` the API answers with.
+    #[serde(rename = "OrganizationIsNotEmpty")]
+    OrganizationIsNotEmpty,
+    /// The `InvitedUserDoesNotExist` the API answers with.
+    #[serde(rename = "InvitedUserDoesNotExist")]
+    InvitedUserDoesNotExist,
+    /// The `InvitedUserAlreadyInOrganization` the API answers with.
+    #[serde(rename = "InvitedUserAlreadyInOrganization")]
+    InvitedUserAlreadyInOrganization,
+    /// The `ApplicationNameMissing` the API answers with.
+    #[serde(rename = "ApplicationNameMissing")]
+    ApplicationNameMissing,
+    /// The `InvalidRole` the API answers with.
+    #[serde(rename = "InvalidRole")]
+    InvalidRole,
+    /// The `EventTypeAlreadyExist` the API answers with.
+    #[serde(rename = "EventTypeAlreadyExist")]
+    EventTypeAlreadyExist,
+    /// The `EventTypeDoesNotExist` the API answers with.
+    #[serde(rename = "EventTypeDoesNotExist")]
+    EventTypeDoesNotExist,
+    /// The `EventTypeVersionAlreadyExist` the API answers with.
+    #[serde(rename = "EventTypeVersionAlreadyExist")]
+    EventTypeVersionAlreadyExist,
+    /// The `EventTypeVersionDoesNotExist` the API answers with.
+    #[serde(rename = "EventTypeVersionDoesNotExist")]
+    EventTypeVersionDoesNotExist,
+    /// The `EventTypeVersionSunset` the API answers with.
+    #[serde(rename = "EventTypeVersionSunset")]
+    EventTypeVersionSunset,
+    /// The `UnauthorizedWorkers` the API answers with.
+    #[serde(rename = "UnauthorizedWorkers")]
+    UnauthorizedWorkers,
+    /// The `EventAlreadyIngested` the API answers with.
+    #[serde(rename = "EventAlreadyIngested")]
+    EventAlreadyIngested,
+    /// The `EventInvalidPayloadContentType` the API answers with.
+    #[serde(rename = "EventInvalidPayloadContentType")]
+    EventInvalidPayloadContentType,
+    /// The `EventInvalidBase64Payload` the API answers with.
+    #[serde(rename = "EventInvalidBase64Payload")]
+    EventInvalidBase64Payload,
+    /// The `EventInvalidJsonPayload` the API answers with.
+    #[serde(rename = "EventInvalidJsonPayload")]
+    EventInvalidJsonPayload,
+    /// The `EventPayloadSchemaViolation` the API answers with.
+    #[serde(rename = "EventPayloadSchemaViolation")]
+    EventPayloadSchemaViolation,
+    /// The `EventDeliveryBeyondRetention` the API answers with.
+    #[serde(rename = "EventDeliveryBeyondRetention")]
+    EventDeliveryBeyondRetention,
+    /// The `EventNotScheduled` the API answers with.
+    #[serde(rename = "EventNotScheduled")]
+    EventNotScheduled,
+    /// The `LabelsAmbiguity` the API answers with.
+    #[serde(rename = "LabelsAmbiguity")]
+    LabelsAmbiguity,
+    /// The `InvalidDateRange` the API answers with.
+    #[serde(rename = "InvalidDateRange")]
+    InvalidDateRange,
+    /// The `RequestAttemptNotFailed` the API answers with.
+    #[serde(rename = "RequestAttemptNotFailed")]
+    RequestAttemptNotFailed,
+    /// The `RequestAttemptRetryPending` the API answers with.
+    #[serde(rename = "RequestAttemptRetryPending")]
+    RequestAttemptRetryPending,
+    /// The `SubscriptionDisabled` the API answers with.
+    #[serde(rename = "SubscriptionDisabled")]
+    SubscriptionDisabled,
+    /// The `SubscriptionNotInbox` the API answers with.
+    #[serde(rename = "SubscriptionNotInbox")]
+    SubscriptionNotInbox,
+    /// The `SecretRotationInProgress` the API answers with.
+    #[serde(rename = "SecretRotationInProgress")]
+    SecretRotationInProgress,
+    /// The `BulkRetryAlreadyRunning` the API answers with.
+    #[serde(rename = "BulkRetryAlreadyRunning")]
+    BulkRetryAlreadyRunning,
+    /// The `BulkRetryFinished` the API answers with.
+    #[serde(rename = "BulkRetryFinished")]
+    BulkRetryFinished,
+    /// The `RetryPolicyOverLimit` the API answers with.
+    #[serde(rename = "RetryPolicyOverLimit")]
+    RetryPolicyOverLimit,
+    /// The `ClientCertificatesDisabled` the API answers with.
+    #[serde(rename = "ClientCertificatesDisabled")]
+    ClientCertificatesDisabled,
+    /// The `InvalidClientCertificate` the API answers with.
+    #[serde(rename = "InvalidClientCertificate")]
+    InvalidClientCertificate,
+    /// The `SigningKeysDisabled` the API answers with.
+    #[serde(rename = "SigningKeysDisabled")]
+    SigningKeysDisabled,
+    /// The `AuthNoAuthorizationHeader` the API answers with.
+    #[serde(rename = "AuthNoAuthorizationHeader")]
+    AuthNoAuthorizationHeader,
+    /// The `AuthInvalidAuthorizationHeader` the API answers with.
+    #[serde(rename = "AuthInvalidAuthorizationHeader")]
+    AuthInvalidAuthorizationHeader,
+    /// The `AuthApplicationSecretLookupError` the API answers with.
+    #[serde(rename = "AuthApplicationSecretLookupError")]
+    AuthApplicationSecretLookupError,
+    /// The `AuthInvalidApplicationSecret` the API answers with.
+    #[serde(rename = "AuthInvalidApplicationSecret")]
+    AuthInvalidApplicationSecret,
+    /// The `AuthBiscuitLookupError` the API answers with.
+    #[serde(rename = "AuthBiscuitLookupError")]
+    AuthBiscuitLookupError,
+    /// The `AuthInvalidBiscuit` the API answers with.
+    #[serde(rename = "AuthInvalidBiscuit")]
+    AuthInvalidBiscuit,
+    /// The `AuthFailedLogin` the API answers with.
+    #[serde(rename = "AuthFailedLogin")]
+    AuthFailedLogin,
+    /// The `AuthEmailNotVerified` the API answers with.
+    #[serde(rename = "AuthEmailNotVerified")]
+    AuthEmailNotVerified,
+    /// The `AuthEmailAlreadyVerified` the API answers with.
+    #[serde(rename = "AuthEmailAlreadyVerified")]
+    AuthEmailAlreadyVerified,
+    /// The `AuthFailedRefresh` the API answers with.
+    #[serde(rename = "AuthFailedRefresh")]
+    AuthFailedRefresh,
+    /// The `AuthEmailExpired` the API answers with.
+    #[serde(rename = "AuthEmailExpired")]
+    AuthEmailExpired,
+    /// The `TooManyMembersPerOrganization` the API answers with.
+    #[serde(rename = "TooManyMembersPerOrganization")]
+    TooManyMembersPerOrganization,
+    /// The `TooManyApplicationsPerOrganization` the API answers with.
+    #[serde(rename = "TooManyApplicationsPerOrganization")]
+    TooManyApplicationsPerOrganization,
+    /// The `TooManyEventsToday` the API answers with.
+    #[serde(rename = "TooManyEventsToday")]
+    TooManyEventsToday,
+    /// The `TooManySubscriptionsPerApplication` the API answers with.
+    #[serde(rename = "TooManySubscriptionsPerApplication")]
+    TooManySubscriptionsPerApplication,
+    /// The `TooManyEventTypesPerApplication` the API answers with.
+    #[serde(rename = "TooManyEventTypesPerApplication")]
+    TooManyEventTypesPerApplication,
+    /// The `JsonPayload` the API answers with.
+    #[serde(rename = "JsonPayload")]
+    JsonPayload,
+    /// The `Validation` the API answers with.
+    #[serde(rename = "Validation")]
+    Validation,
+    /// The `NotFound` the API answers with.
+    #[serde(rename = "NotFound")]
+    NotFound,
+    /// The `InternalServerError` the API answers with.
+    #[serde(rename = "InternalServerError")]
+    InternalServerError,
+    /// The `Forbidden` the API answers with.
+    #[serde(rename = "Forbidden")]
+    Forbidden,
+    /// The `RateLimited` the API answers with.
+    #[serde(rename = "RateLimited")]
+    RateLimited,
+    /// The `ServiceUnavailable` the API answers with.
+    #[serde(rename = "ServiceUnavailable")]
+    ServiceUnavailable,
+}
+
+impl ProblemId {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::OrganizationNameMissing => "OrganizationNameMissing",
+            Self::UserAlreadyExist => "UserAlreadyExist",
+            Self::RegistrationDisabled => "RegistrationDisabled",
+            Self::PasswordTooShort => "PasswordTooShort",
+            Self::PasswordTooLong => "PasswordTooLong",
+            Self::PasswordSimilarToEmail => "PasswordSimilarToEmail",
+            Self::PasswordSimilarToName => "PasswordSimilarToName",
+            Self::PasswordTooCommon => "PasswordTooCommon",
+            Self::PasswordNotDiverseEnough => "PasswordNotDiverseEnough",
+            Self::OrganizationIsNotEmpty => "OrganizationIsNotEmpty",
+            Self::InvitedUserDoesNotExist => "InvitedUserDoesNotExist",
+            Self::InvitedUserAlreadyInOrganization => "InvitedUserAlreadyInOrganization",
+            Self::ApplicationNameMissing => "ApplicationNameMissing",
+            Self::InvalidRole => "InvalidRole",
+            Self::EventTypeAlreadyExist => "EventTypeAlreadyExist",
+            Self::EventTypeDoesNotExist => "EventTypeDoesNotExist",
+            Self::EventTypeVersionAlreadyExist => "EventTypeVersionAlreadyExist",
+            Self::EventTypeVersionDoesNotExist => "EventTypeVersionDoesNotExist",
+            Self::EventTypeVersionSunset => "EventTypeVersionSunset",
+            Self::UnauthorizedWorkers => "UnauthorizedWorkers",
+            Self::EventAlreadyIngested => "EventAlreadyIngested",
+            Self::EventInvalidPayloadContentType => "EventInvalidPayloadContentType",
+            Self::EventInvalidBase64Payload => "EventInvalidBase64Payload",
+            Self::EventInvalidJsonPayload => "EventInvalidJsonPayload",
+            Self::EventPayloadSchemaViolation => "EventPayloadSchemaViolation",
+            Self::EventDeliveryBeyondRetention => "EventDeliveryBeyondRetention",
+            Self::EventNotScheduled => "EventNotScheduled",
+            Self::LabelsAmbiguity => "LabelsAmbiguity",
+            Self::InvalidDateRange => "InvalidDateRange",
+            Self::RequestAttemptNotFailed => "RequestAttemptNotFailed",
+            Self::RequestAttemptRetryPending => "RequestAttemptRetryPending",
+            Self::SubscriptionDisabled => "SubscriptionDisabled",
+            Self::SubscriptionNotInbox => "SubscriptionNotInbox",
+            Self::SecretRotationInProgress => "SecretRotationInProgress",
+            Self::BulkRetryAlreadyRunning => "BulkRetryAlreadyRunning",
+            Self::BulkRetryFinished => "BulkRetryFinished",
+            Self::RetryPolicyOverLimit => "RetryPolicyOverLimit",
+            Self::ClientCertificatesDisabled => "ClientCertificatesDisabled",
+            Self::InvalidClientCertificate => "InvalidClientCertificate",
+            Self::SigningKeysDisabled => "SigningKeysDisabled",
+            Self::AuthNoAuthorizationHeader => "AuthNoAuthorizationHeader",
+            Self::AuthInvalidAuthorizationHeader => "AuthInvalidAuthorizationHeader",
+            Self::AuthApplicationSecretLookupError => "AuthApplicationSecretLookupError",
+            Self::AuthInvalidApplicationSecret => "AuthInvalidApplicationSecret",
+            Self::AuthBiscuitLookupError => "AuthBiscuitLookupError",
+            Self::AuthInvalidBiscuit => "AuthInvalidBiscuit",
+            Self::AuthFailedLogin => "AuthFailedLogin",
+            Self::AuthEmailNotVerified => "AuthEmailNotVerified",
+            Self::AuthEmailAlreadyVerified => "AuthEmailAlreadyVerified",
+            Self::AuthFailedRefresh => "AuthFailedRefresh",
+            Self::AuthEmailExpired => "AuthEmailExpired",
+            Self::TooManyMembersPerOrganization => "TooManyMembersPerOrganization",
+            Self::TooManyApplicationsPerOrganization => "TooManyApplicationsPerOrganization",
+            Self::TooManyEventsToday => "TooManyEventsToday",
+            Self::TooManySubscriptionsPerApplication => "TooManySubscriptionsPerApplication",
+            Self::TooManyEventTypesPerApplication => "TooManyEventTypesPerApplication",
+            Self::JsonPayload => "JsonPayload",
+            Self::Validation => "Validation",
+            Self::NotFound => "NotFound",
+            Self::InternalServerError => "InternalServerError",
+            Self::Forbidden => "Forbidden",
+            Self::RateLimited => "RateLimited",
+            Self::ServiceUnavailable => "ServiceUnavailable",
+        }
+    }
+}
+
+impl std::fmt::Display for ProblemId {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum RequestAttemptStatusRuleOutcome {
+    /// The `success` the API answers with.
+    #[serde(rename = "success")]
+    Success,
+    /// The `retryable` the API answers with.
+    #[serde(rename = "retryable")]
+    Retryable,
+    /// The `permanent_failure` the API answers with.
+    #[serde(rename = "permanent_failure")]
+    PermanentFailure,
+    /// The `disable_subscription` the API answers with.
+    #[serde(rename = "disable_subscription")]
+    DisableSubscription,
+}
+
+impl RequestAttemptStatusRuleOutcome {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Retryable => "retryable",
+            Self::PermanentFailure => "permanent_failure",
+            Self::DisableSubscription => "disable_subscription",
+        }
+    }
+}
+
+impl std::fmt::Display for RequestAttemptStatusRuleOutcome {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum RequestAttemptStatusType {
+    /// The `waiting` the API answers with.
+    #[serde(rename = "waiting")]
+    Waiting,
+    /// The `pending` the API answers with.
+    #[serde(rename = "pending")]
+    Pending,
+    /// The `in_progress` the API answers with.
+    #[serde(rename = "in_progress")]
+    InProgress,
+    /// The `successful` the API answers with.
+    #[serde(rename = "successful")]
+    Successful,
+    /// The `failed` the API answers with.
+    #[serde(rename = "failed")]
+    Failed,
+    /// The `expired` the API answers with.
+    #[serde(rename = "expired")]
+    Expired,
+    /// The `cancelled` the API answers with.
+    #[serde(rename = "cancelled")]
+    Cancelled,
+}
+
+impl RequestAttemptStatusType {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Waiting => "waiting",
+            Self::Pending => "pending",
+            Self::InProgress => "in_progress",
+            Self::Successful => "successful",
+            Self::Failed => "failed",
+            Self::Expired => "expired",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl std::fmt::Display for RequestAttemptStatusType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SubscriptionPostResponseRulesOutcome {
+    /// The `success` the API answers with.
+    #[serde(rename = "success")]
+    Success,
+    /// The `retryable` the API answers with.
+    #[serde(rename = "retryable")]
+    Retryable,
+    /// The `permanent_failure` the API answers with.
+    #[serde(rename = "permanent_failure")]
+    PermanentFailure,
+    /// The `disable_subscription` the API answers with.
+    #[serde(rename = "disable_subscription")]
+    DisableSubscription,
+}
+
+impl SubscriptionPostResponseRulesOutcome {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Retryable => "retryable",
+            Self::PermanentFailure => "permanent_failure",
+            Self::DisableSubscription => "disable_subscription",
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionPostResponseRulesOutcome {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SubscriptionPostSignatureScheme {
+    /// The `hook0` the API answers with.
+    #[serde(rename = "hook0")]
+    Hook0,
+    /// The `standard_webhooks` the API answers with.
+    #[serde(rename = "standard_webhooks")]
+    StandardWebhooks,
+}
+
+impl SubscriptionPostSignatureScheme {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hook0 => "hook0",
+            Self::StandardWebhooks => "standard_webhooks",
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionPostSignatureScheme {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SubscriptionPostTargetType {
+    /// The `http` the API answers with.
+    #[serde(rename = "http")]
+    Http,
+    /// The `amqp` the API answers with.
+    #[serde(rename = "amqp")]
+    Amqp,
+    /// The `sqs` the API answers with.
+    #[serde(rename = "sqs")]
+    Sqs,
+    /// The `inbox` the API answers with.
+    #[serde(rename = "inbox")]
+    Inbox,
+}
+
+impl SubscriptionPostTargetType {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Http => "http",
+            Self::Amqp => "amqp",
+            Self::Sqs => "sqs",
+            Self::Inbox => "inbox",
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionPostTargetType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SubscriptionResponseRulesOutcome {
+    /// The `success` the API answers with.
+    #[serde(rename = "success")]
+    Success,
+    /// The `retryable` the API answers with.
+    #[serde(rename = "retryable")]
+    Retryable,
+    /// The `permanent_failure` the API answers with.
+    #[serde(rename = "permanent_failure")]
+    PermanentFailure,
+    /// The `disable_subscription` the API answers with.
+    #[serde(rename = "disable_subscription")]
+    DisableSubscription,
+}
+
+impl SubscriptionResponseRulesOutcome {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Retryable => "retryable",
+            Self::PermanentFailure => "permanent_failure",
+            Self::DisableSubscription => "disable_subscription",
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionResponseRulesOutcome {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SubscriptionSignatureScheme {
+    /// The `hook0` the API answers with.
+    #[serde(rename = "hook0")]
+    Hook0,
+    /// The `standard_webhooks` the API answers with.
+    #[serde(rename = "standard_webhooks")]
+    StandardWebhooks,
+}
+
+impl SubscriptionSignatureScheme {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hook0 => "hook0",
+            Self::StandardWebhooks => "standard_webhooks",
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionSignatureScheme {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One of the values the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SubscriptionTargetType {
+    /// The `http` the API answers with.
+    #[serde(rename = "http")]
+    Http,
+    /// The `amqp` the API answers with.
+    #[serde(rename = "amqp")]
+    Amqp,
+    /// The `sqs` the API answers with.
+    #[serde(rename = "sqs")]
+    Sqs,
+    /// The `inbox` the API answers with.
+    #[serde(rename = "inbox")]
+    Inbox,
+}
+
+impl SubscriptionTargetType {
+    /// The text this value travels as.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Http => "http",
+            Self::Amqp => "amqp",
+            Self::Sqs => "sqs",
+            Self::Inbox => "inbox",
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionTargetType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// The `Application` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Application {
+    /// `application_id`: Unique identifier of the application.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `name`: Name of the application. Length: 2-50 characters.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// `organization_id`: UUID of the organization this application belongs to.
+    #[serde(rename = "organization_id")]
+    pub organization_id: Uuid,
+}
+
+/// The `ApplicationInfo` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplicationInfo {
+    /// `application_id`: Unique identifier of the application.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `consumption`: Current consumption metrics for this application.
+    #[serde(rename = "consumption")]
+    pub consumption: ApplicationInfoConsumption,
+    /// `name`: Name of the application. Length: 2-50 characters.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// `onboarding_steps`: Onboarding completion status for this application.
+    #[serde(rename = "onboarding_steps")]
+    pub onboarding_steps: ApplicationInfoOnboardingSteps,
+    /// `organization_id`: UUID of the organization this application belongs to.
+    #[serde(rename = "organization_id")]
+    pub organization_id: Uuid,
+    /// `quotas`: Quota limits for this application.
+    #[serde(rename = "quotas")]
+    pub quotas: ApplicationInfoQuotas,
+}
+
+/// The `ApplicationInfoConsumption` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplicationInfoConsumption {
+    /// `events_per_day`.
+    #[serde(rename = "events_per_day")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events_per_day: Option<i32>,
+}
+
+/// The `ApplicationInfoOnboardingSteps` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplicationInfoOnboardingSteps {
+    /// `event`.
+    #[serde(rename = "event")]
+    pub event: ApplicationInfoOnboardingStepsEvent,
+    /// `event_type`.
+    #[serde(rename = "event_type")]
+    pub event_type: ApplicationInfoOnboardingStepsEventType,
+    /// `subscription`.
+    #[serde(rename = "subscription")]
+    pub subscription: ApplicationInfoOnboardingStepsSubscription,
+}
+
+/// The `ApplicationInfoQuotas` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplicationInfoQuotas {
+    /// `days_of_events_retention_limit`.
+    #[serde(rename = "days_of_events_retention_limit")]
+    pub days_of_events_retention_limit: i32,
+    /// `events_per_day_limit`.
+    #[serde(rename = "events_per_day_limit")]
+    pub events_per_day_limit: i32,
+}
+
+/// The `ApplicationPost` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplicationPost {
+    /// `name`: Name of the application. Length: 2-50 characters.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// `organization_id`: UUID of the organization this application belongs to.
+    #[serde(rename = "organization_id")]
+    pub organization_id: Uuid,
+}
+
+/// The `ApplicationSecret` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplicationSecret {
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `deleted_at`.
+    #[serde(rename = "deleted_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// `name`.
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// `token`.
+    #[serde(rename = "token")]
+    pub token: Uuid,
+}
+
+/// The `ApplicationSecretPost` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ApplicationSecretPost {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
     pub application_id: Uuid,
     /// `name`.
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<String>,
+}
+
+/// The `BulkRetry` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BulkRetry {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `bulk_retry_id`.
+    #[serde(rename = "bulk_retry_id")]
+    pub bulk_retry_id: Uuid,
+    /// `cancelled_at`.
+    #[serde(rename = "cancelled_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// `completed_at`.
+    #[serde(rename = "completed_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `event_id`.
+    #[serde(rename = "event_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<Uuid>,
+    /// `event_type_names`.
+    #[serde(rename = "event_type_names")]
+    pub event_type_names: Vec<String>,
+    /// `max_created_at`.
+    #[serde(rename = "max_created_at")]
+    pub max_created_at: DateTime<Utc>,
+    /// `min_created_at`.
+    #[serde(rename = "min_created_at")]
+    pub min_created_at: DateTime<Utc>,
+    /// `processed_count`: Number of pairs handled so far
+    #[serde(rename = "processed_count")]
+    pub processed_count: i64,
+    /// `retried_count`: Number of request attempts created so far
+    #[serde(rename = "retried_count")]
+    pub retried_count: i64,
+    /// `status`.
+    #[serde(rename = "status")]
+    pub status: BulkRetryStatus,
+    /// `subscription_id`.
+    #[serde(rename = "subscription_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<Uuid>,
+    /// `total_count`: Number of (event, subscription) pairs that matched when the bulk retry was created
+    #[serde(rename = "total_count")]
+    pub total_count: i64,
+    /// `updated_at`.
+    #[serde(rename = "updated_at")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The `BulkRetryDryRun` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BulkRetryDryRun {
+    /// `count`: Number of (event, subscription) pairs a bulk retry would redeliver now
+    #[serde(rename = "count")]
+    pub count: i64,
+}
+
+/// The `BulkRetryPost` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BulkRetryPost {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `event_id`.
+    #[serde(rename = "event_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<Uuid>,
+    /// `event_type_names`.
+    #[serde(rename = "event_type_names")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_names: Option<Vec<String>>,
+    /// `max_created_at`.
+    #[serde(rename = "max_created_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_created_at: Option<DateTime<Utc>>,
+    /// `min_created_at`.
+    #[serde(rename = "min_created_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_created_at: Option<DateTime<Utc>>,
+    /// `status`: Redeliver request attempts that failed (default) or that succeeded
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<BulkRetryPostStatus>,
+    /// `subscription_id`.
+    #[serde(rename = "subscription_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<Uuid>,
+}
+
+/// The `CancelEvent` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CancelEvent {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+}
+
+/// The `ClientCertificate` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClientCertificate {
+    /// `certificate_pem`: Certificate chain, leaf certificate first
+    #[serde(rename = "certificate_pem")]
+    pub certificate_pem: String,
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate, as hexadecimal
+    #[serde(rename = "fingerprint_sha256")]
+    pub fingerprint_sha256: String,
+    /// `not_after`.
+    #[serde(rename = "not_after")]
+    pub not_after: DateTime<Utc>,
+    /// `not_before`.
+    #[serde(rename = "not_before")]
+    pub not_before: DateTime<Utc>,
+    /// `subject`.
+    #[serde(rename = "subject")]
+    pub subject: String,
+    /// `subscription_id`.
+    #[serde(rename = "subscription_id")]
+    pub subscription_id: Uuid,
+    /// `updated_at`.
+    #[serde(rename = "updated_at")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The `ClientCertificatePut` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClientCertificatePut {
+    /// `certificate_pem`: PEM-encoded certificate chain, leaf certificate first
+    #[serde(rename = "certificate_pem")]
+    pub certificate_pem: String,
+    /// `private_key_pem`: PEM-encoded unencrypted private key of the leaf certificate (PKCS#8, PKCS#1 or SEC1)
+    #[serde(rename = "private_key_pem")]
+    pub private_key_pem: String,
+}
+
+/// The `DeadLetter` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetter {
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `dead_letter_id`.
+    #[serde(rename = "dead_letter_id")]
+    pub dead_letter_id: Uuid,
+    /// `event`.
+    #[serde(rename = "event")]
+    pub event: DeadLetterEvent,
+    /// `http_response_status`.
+    #[serde(rename = "http_response_status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response_status: Option<i32>,
+    /// `reason`.
+    #[serde(rename = "reason")]
+    pub reason: DeadLetterReason,
+    /// `request_attempt_id`: Last request attempt of the delivery
+    #[serde(rename = "request_attempt_id")]
+    pub request_attempt_id: Uuid,
+    /// `response_id`: Response to the last request attempt, if the target was reached
+    #[serde(rename = "response_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_id: Option<Uuid>,
+    /// `retry_count`.
+    #[serde(rename = "retry_count")]
+    pub retry_count: i32,
+    /// `subscription`.
+    #[serde(rename = "subscription")]
+    pub subscription: DeadLetterSubscription,
+}
+
+/// The `DeadLetterDetails` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterDetails {
+    /// `dead_letter`: Delivery an output worker gave up on
+    #[serde(rename = "dead_letter")]
+    pub dead_letter: DeadLetterDetailsDeadLetter,
+    /// `last_response`: Response to the last request attempt, if the target was reached
+    #[serde(rename = "last_response")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_response: Option<DeadLetterDetailsLastResponse>,
+}
+
+/// The `DeadLetterDetailsDeadLetter` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterDetailsDeadLetter {
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `dead_letter_id`.
+    #[serde(rename = "dead_letter_id")]
+    pub dead_letter_id: Uuid,
+    /// `event`.
+    #[serde(rename = "event")]
+    pub event: DeadLetterDetailsDeadLetterEvent,
+    /// `http_response_status`.
+    #[serde(rename = "http_response_status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response_status: Option<i32>,
+    /// `reason`.
+    #[serde(rename = "reason")]
+    pub reason: DeadLetterDetailsDeadLetterReason,
+    /// `request_attempt_id`: Last request attempt of the delivery
+    #[serde(rename = "request_attempt_id")]
+    pub request_attempt_id: Uuid,
+    /// `response_id`: Response to the last request attempt, if the target was reached
+    #[serde(rename = "response_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_id: Option<Uuid>,
+    /// `retry_count`.
+    #[serde(rename = "retry_count")]
+    pub retry_count: i32,
+    /// `subscription`.
+    #[serde(rename = "subscription")]
+    pub subscription: DeadLetterDetailsDeadLetterSubscription,
+}
+
+/// The `DeadLetterDetailsDeadLetterEvent` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterDetailsDeadLetterEvent {
+    /// `event_id`.
+    #[serde(rename = "event_id")]
+    pub event_id: Uuid,
+    /// `event_type_name`.
+    #[serde(rename = "event_type_name")]
+    pub event_type_name: String,
+}
+
+/// The `DeadLetterDetailsDeadLetterSubscription` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterDetailsDeadLetterSubscription {
+    /// `description`.
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `subscription_id`.
+    #[serde(rename = "subscription_id")]
+    pub subscription_id: Uuid,
+}
+
+/// The `DeadLetterDetailsLastResponse` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterDetailsLastResponse {
+    /// `body`.
+    #[serde(rename = "body")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// `elapsed_time_ms`.
+    #[serde(rename = "elapsed_time_ms")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_time_ms: Option<i32>,
+    /// `headers`.
+    #[serde(rename = "headers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<HashMap<String, String>>,
+    /// `http_code`.
+    #[serde(rename = "http_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_code: Option<i32>,
+    /// `response_error_name`.
+    #[serde(rename = "response_error_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_error_name: Option<String>,
+    /// `response_id`.
+    #[serde(rename = "response_id")]
+    pub response_id: Uuid,
+}
+
+/// The `DeadLetterEvent` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterEvent {
+    /// `event_id`.
+    #[serde(rename = "event_id")]
+    pub event_id: Uuid,
+    /// `event_type_name`.
+    #[serde(rename = "event_type_name")]
+    pub event_type_name: String,
+}
+
+/// The `DeadLetterSubscription` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterSubscription {
+    /// `description`.
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `subscription_id`.
+    #[serde(rename = "subscription_id")]
+    pub subscription_id: Uuid,
 }
 
 /// The `Event` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Event {
+    /// `cancelled_at`: Date the scheduled delivery of the event was cancelled
+    #[serde(rename = "cancelled_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// `deliver_at`: Date from which the event is delivered, when it was scheduled for a later delivery
+    #[serde(rename = "deliver_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliver_at: Option<DateTime<Utc>>,
     /// `event_id`.
     #[serde(rename = "event_id")]
     pub event_id: Uuid,
     /// `event_type_name`.
     #[serde(rename = "event_type_name")]
     pub event_type_name: String,
+    /// `event_type_version`.
+    #[serde(rename = "event_type_version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_version: Option<i32>,
+    /// `expires_at`: Date after which the event is no longer delivered
+    #[serde(rename = "expires_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
     /// `ip`.
     #[serde(rename = "ip")]
     pub ip: String,
@@ -590,6 +1722,10 @@ pub struct EventPost {
     /// `application_id`: UUID of the application this event belongs to.
     #[serde(rename = "application_id")]
     pub application_id: Uuid,
+    /// `deliver_at`: Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the appl
+    #[serde(rename = "deliver_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliver_at: Option<DateTime<Utc>>,
     /// `event_id`: Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
     #[serde(rename = "event_id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -597,6 +1733,14 @@ pub struct EventPost {
     /// `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters.
     #[serde(rename = "event_type")]
     pub event_type: String,
+    /// `event_type_version`: Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+    #[serde(rename = "event_type_version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_version: Option<i32>,
+    /// `expires_at`: Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted f
+    #[serde(rename = "expires_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
     /// `labels`: Labels for event filtering and routing to subscriptions.
     #[serde(rename = "labels")]
     pub labels: HashMap<String, String>,
@@ -618,9 +1762,20 @@ pub struct EventPost {
 /// The `EventType` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EventType {
+    /// `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date
+    #[serde(rename = "default_ttl_s")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_ttl_s: Option<i32>,
     /// `event_type_name`.
     #[serde(rename = "event_type_name")]
     pub event_type_name: String,
+    /// `payload_schema`.
+    #[serde(rename = "payload_schema")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<Value>,
+    /// `payload_schema_mode`.
+    #[serde(rename = "payload_schema_mode")]
+    pub payload_schema_mode: EventTypePayloadSchemaMode,
     /// `resource_type_name`.
     #[serde(rename = "resource_type_name")]
     pub resource_type_name: String,
@@ -630,6 +1785,9 @@ pub struct EventType {
     /// `verb_name`.
     #[serde(rename = "verb_name")]
     pub verb_name: String,
+    /// `versions`.
+    #[serde(rename = "versions")]
+    pub versions: Vec<EventTypeVersions>,
 }
 
 /// The `EventTypePost` the API declares.
@@ -638,6 +1796,18 @@ pub struct EventTypePost {
     /// `application_id`.
     #[serde(rename = "application_id")]
     pub application_id: Uuid,
+    /// `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+    #[serde(rename = "default_ttl_s")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_ttl_s: Option<i32>,
+    /// `payload_schema`: JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported
+    #[serde(rename = "payload_schema")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<Value>,
+    /// `payload_schema_mode`: How the payload schema is applied at ingestion (default: `enforce`)
+    #[serde(rename = "payload_schema_mode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema_mode: Option<EventTypePostPayloadSchemaMode>,
     /// `resource_type`.
     #[serde(rename = "resource_type")]
     pub resource_type: String,
@@ -649,15 +1819,127 @@ pub struct EventTypePost {
     pub verb: String,
 }
 
+/// The `EventTypePut` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventTypePut {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+    #[serde(rename = "default_ttl_s")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_ttl_s: Option<i32>,
+    /// `payload_schema`: JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported
+    #[serde(rename = "payload_schema")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<Value>,
+    /// `payload_schema_mode`: How the payload schema is applied at ingestion (default: `enforce`)
+    #[serde(rename = "payload_schema_mode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema_mode: Option<EventTypePutPayloadSchemaMode>,
+}
+
+/// The `EventTypeVersion` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventTypeVersion {
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `deprecated_at`.
+    #[serde(rename = "deprecated_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated_at: Option<DateTime<Utc>>,
+    /// `payload_schema`: JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type
+    #[serde(rename = "payload_schema")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<Value>,
+    /// `sunset_at`: Date from which the events declaring this version are refused
+    #[serde(rename = "sunset_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset_at: Option<DateTime<Utc>>,
+    /// `version`.
+    #[serde(rename = "version")]
+    pub version: i32,
+}
+
+/// The `EventTypeVersionDeprecate` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventTypeVersionDeprecate {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `sunset_at`: Date from which the events declaring this version are refused; none keeps accepting them
+    #[serde(rename = "sunset_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset_at: Option<DateTime<Utc>>,
+    /// `version`.
+    #[serde(rename = "version")]
+    pub version: i32,
+}
+
+/// The `EventTypeVersionPost` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventTypeVersionPost {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `payload_schema`: JSON Schema the payloads of the `application/json` events declaring this version must match, instead of the one of the event type; remote references are not supported
+    #[serde(rename = "payload_schema")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<Value>,
+    /// `version`.
+    #[serde(rename = "version")]
+    pub version: i32,
+}
+
+/// The `EventTypeVersions` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventTypeVersions {
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `deprecated_at`.
+    #[serde(rename = "deprecated_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated_at: Option<DateTime<Utc>>,
+    /// `payload_schema`: JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type
+    #[serde(rename = "payload_schema")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_schema: Option<Value>,
+    /// `sunset_at`: Date from which the events declaring this version are refused
+    #[serde(rename = "sunset_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset_at: Option<DateTime<Utc>>,
+    /// `version`.
+    #[serde(rename = "version")]
+    pub version: i32,
+}
+
 /// The `EventWithPayload` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EventWithPayload {
+    /// `cancelled_at`: Date the scheduled delivery of the event was cancelled
+    #[serde(rename = "cancelled_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// `deliver_at`: Date from which the event is delivered, when it was scheduled for a later delivery
+    #[serde(rename = "deliver_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliver_at: Option<DateTime<Utc>>,
     /// `event_id`.
     #[serde(rename = "event_id")]
     pub event_id: Uuid,
     /// `event_type_name`.
     #[serde(rename = "event_type_name")]
     pub event_type_name: String,
+    /// `event_type_version`.
+    #[serde(rename = "event_type_version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_version: Option<i32>,
+    /// `expires_at`: Date after which the event is no longer delivered
+    #[serde(rename = "expires_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
     /// `ip`.
     #[serde(rename = "ip")]
     pub ip: String,
@@ -682,6 +1964,60 @@ pub struct EventWithPayload {
     pub received_at: DateTime<Utc>,
 }
 
+/// The `EventsBatchPost` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventsBatchPost {
+    /// `application_id`: UUID of the application the events belong to. Events of the batch that carry another application ID are not ingested.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `events`: Events to ingest. Length: 1-100 events; the whole request body is also subject to the usual request size limit.
+    #[serde(rename = "events")]
+    pub events: Vec<EventsBatchPostEvents>,
+}
+
+/// The `EventsBatchPostEvents` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventsBatchPostEvents {
+    /// `application_id`: UUID of the application this event belongs to.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `deliver_at`: Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the appl
+    #[serde(rename = "deliver_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliver_at: Option<DateTime<Utc>>,
+    /// `event_id`: Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
+    #[serde(rename = "event_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<Uuid>,
+    /// `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters.
+    #[serde(rename = "event_type")]
+    pub event_type: String,
+    /// `event_type_version`: Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+    #[serde(rename = "event_type_version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_version: Option<i32>,
+    /// `expires_at`: Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted f
+    #[serde(rename = "expires_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// `labels`: Labels for event filtering and routing to subscriptions.
+    #[serde(rename = "labels")]
+    pub labels: HashMap<String, String>,
+    /// `metadata`: Optional metadata key-value pairs associated with the event.
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    /// `occurred_at`: Timestamp when the event occurred.
+    #[serde(rename = "occurred_at")]
+    pub occurred_at: DateTime<Utc>,
+    /// `payload`: The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded).
+    #[serde(rename = "payload")]
+    pub payload: String,
+    /// `payload_content_type`: Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters.
+    #[serde(rename = "payload_content_type")]
+    pub payload_content_type: String,
+}
+
 /// The `EventsPerDayEntry` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EventsPerDayEntry {
@@ -702,12 +2038,107 @@ pub struct EventsPerDayEntry {
     pub is_provisional: bool,
 }
 
+/// The `Inbox` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Inbox {
+    /// `messages`.
+    #[serde(rename = "messages")]
+    pub messages: Vec<InboxMessages>,
+    /// `next_cursor`: Pass it as `cursor` to the next pull to continue after the last message of this one
+    #[serde(rename = "next_cursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// The `InboxAck` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InboxAck {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `receipts`: Receipts of the leased events
+    #[serde(rename = "receipts")]
+    pub receipts: Vec<Uuid>,
+}
+
+/// The `InboxMessages` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InboxMessages {
+    /// `event_id`.
+    #[serde(rename = "event_id")]
+    pub event_id: Uuid,
+    /// `event_type_name`.
+    #[serde(rename = "event_type_name")]
+    pub event_type_name: String,
+    /// `labels`.
+    #[serde(rename = "labels")]
+    pub labels: Value,
+    /// `leased_until`: When the event is offered again if it was neither acknowledged nor rejected
+    #[serde(rename = "leased_until")]
+    pub leased_until: DateTime<Utc>,
+    /// `occurred_at`.
+    #[serde(rename = "occurred_at")]
+    pub occurred_at: DateTime<Utc>,
+    /// `payload`: Base64-encoded
+    #[serde(rename = "payload")]
+    pub payload: String,
+    /// `payload_content_type`.
+    #[serde(rename = "payload_content_type")]
+    pub payload_content_type: String,
+    /// `receipt`: Identifies this lease of the request attempt; a later lease of the same attempt gets another one
+    #[serde(rename = "receipt")]
+    pub receipt: Uuid,
+    /// `received_at`.
+    #[serde(rename = "received_at")]
+    pub received_at: DateTime<Utc>,
+    /// `request_attempt_id`.
+    #[serde(rename = "request_attempt_id")]
+    pub request_attempt_id: Uuid,
+    /// `retry_count`.
+    #[serde(rename = "retry_count")]
+    pub retry_count: i32,
+}
+
+/// The `InboxNack` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InboxNack {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `receipts`: Receipts of the leased events
+    #[serde(rename = "receipts")]
+    pub receipts: Vec<Uuid>,
+    /// `retry`: Whether the events are offered again, as new request attempts (true by default); they are not beyond the maximum number of retries of the subscription, or of this instance if it has none
+    #[serde(rename = "retry")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<bool>,
+    /// `retry_in_s`: Delay in seconds before the events are offered again (0 by default)
+    #[serde(rename = "retry_in_s")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_in_s: Option<i32>,
+}
+
+/// The `InboxSettlement` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InboxSettlement {
+    /// `settled`: Receipts of the events that were settled
+    #[serde(rename = "settled")]
+    pub settled: Vec<Uuid>,
+    /// `stale`: Receipts that settled nothing: their lease expired, or the event was already settled
+    #[serde(rename = "stale")]
+    pub stale: Vec<Uuid>,
+}
+
 /// The `IngestedEvent` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct IngestedEvent {
     /// `application_id`.
     #[serde(rename = "application_id")]
     pub application_id: Uuid,
+    /// `deprecation`: Present when the event was ingested with a deprecated version of its event type
+    #[serde(rename = "deprecation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<IngestedEventDeprecation>,
     /// `event_id`.
     #[serde(rename = "event_id")]
     pub event_id: Uuid,
@@ -716,6 +2147,95 @@ pub struct IngestedEvent {
     pub received_at: DateTime<Utc>,
 }
 
+/// The `IngestedEventDeprecation` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct IngestedEventDeprecation {
+    /// `deprecated_at`.
+    #[serde(rename = "deprecated_at")]
+    pub deprecated_at: DateTime<Utc>,
+    /// `event_type_version`.
+    #[serde(rename = "event_type_version")]
+    pub event_type_version: i32,
+    /// `sunset_at`.
+    #[serde(rename = "sunset_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
+/// The `IngestedEventsBatch` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct IngestedEventsBatch {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `events`: One outcome per submitted event, in the order the events were submitted
+    #[serde(rename = "events")]
+    pub events: Vec<IngestedEventsBatchEvents>,
+}
+
+/// The `IngestedEventsBatchEvents` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct IngestedEventsBatchEvents {
+    /// `deprecation`: Present when the event was ingested with a deprecated version of its event type
+    #[serde(rename = "deprecation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<IngestedEventsBatchEventsDeprecation>,
+    /// `event_id`: ID of the ingested event, or the one the event was submitted with when it was not ingested
+    #[serde(rename = "event_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<Uuid>,
+    /// `problem`: Present when the event was not ingested
+    #[serde(rename = "problem")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub problem: Option<IngestedEventsBatchEventsProblem>,
+    /// `received_at`: Present when the event was ingested
+    #[serde(rename = "received_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_at: Option<DateTime<Utc>>,
+    /// `status`.
+    #[serde(rename = "status")]
+    pub status: IngestedEventsBatchEventsStatus,
+}
+
+/// The `IngestedEventsBatchEventsDeprecation` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct IngestedEventsBatchEventsDeprecation {
+    /// `deprecated_at`.
+    #[serde(rename = "deprecated_at")]
+    pub deprecated_at: DateTime<Utc>,
+    /// `event_type_version`.
+    #[serde(rename = "event_type_version")]
+    pub event_type_version: i32,
+    /// `sunset_at`.
+    #[serde(rename = "sunset_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
+/// The `IngestedEventsBatchEventsProblem` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct IngestedEventsBatchEventsProblem {
+    /// `detail`.
+    #[serde(rename = "detail")]
+    pub detail: String,
+    /// `id`: Identifier of the problem that occurred, stable across releases
+    #[serde(rename = "id")]
+    pub id: IngestedEventsBatchEventsProblemId,
+    /// `status`.
+    #[serde(rename = "status")]
+    pub status: i32,
+    /// `title`.
+    #[serde(rename = "title")]
+    pub title: String,
+    /// `type`: Documentation page of this problem, which is also what distinguishes one problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a URL.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// `validation`: Which submitted values were rejected and why, keyed by the name of the field they were submitted under. Carried by validation failures (`Validation`, status 422) and `null` for every other problem, as
+    #[serde(rename = "validation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<Value>,
+}
+
 /// The `InstanceConfig` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct InstanceConfig {
@@ -776,6 +2296,78 @@ pub struct InstanceConfigMatomo {
     pub url: String,
 }
 
+/// The `JsonWebKeySet` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct JsonWebKeySet {
+    /// `keys`.
+    #[serde(rename = "keys")]
+    pub keys: Vec<JsonWebKeySetKeys>,
+}
+
+/// The `JsonWebKeySetKeys` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct JsonWebKeySetKeys {
+    /// `alg`: Always `EdDSA`
+    #[serde(rename = "alg")]
+    pub alg: String,
+    /// `crv`: Always `Ed25519`
+    #[serde(rename = "crv")]
+    pub crv: String,
+    /// `kid`: ID of the signing key, which signatures name in their `k` part
+    #[serde(rename = "kid")]
+    pub kid: String,
+    /// `kty`: Always `OKP`
+    #[serde(rename = "kty")]
+    pub kty: String,
+    /// `use`: Always `sig`
+    #[serde(rename = "use")]
+    pub use_: String,
+    /// `x`: Public key, encoded in base64url without padding
+    #[serde(rename = "x")]
+    pub x: String,
+}
+
+/// The `OAuth2ClientCredentials` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Oauth2ClientCredentials {
+    /// `client_id`.
+    #[serde(rename = "client_id")]
+    pub client_id: String,
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `scopes`.
+    #[serde(rename = "scopes")]
+    pub scopes: Vec<String>,
+    /// `subscription_id`.
+    #[serde(rename = "subscription_id")]
+    pub subscription_id: Uuid,
+    /// `token_url`.
+    #[serde(rename = "token_url")]
+    pub token_url: String,
+    /// `updated_at`.
+    #[serde(rename = "updated_at")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The `OAuth2ClientCredentialsPut` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Oauth2ClientCredentialsPut {
+    /// `client_id`.
+    #[serde(rename = "client_id")]
+    pub client_id: String,
+    /// `client_secret`.
+    #[serde(rename = "client_secret")]
+    pub client_secret: String,
+    /// `scopes`: Scopes to request; none by default
+    #[serde(rename = "scopes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+    /// `token_url`: Token endpoint of the authorization server
+    #[serde(rename = "token_url")]
+    pub token_url: String,
+}
+
 /// The `Organization` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Organization {
@@ -938,6 +2530,26 @@ pub struct Problem {
     pub validation: Option<Value>,
 }
 
+/// The `PurgeDeadLetters` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PurgeDeadLetters {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `subscription_id`: Only purge the dead letters of this subscription
+    #[serde(rename = "subscription_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<Uuid>,
+}
+
+/// The `PurgedDeadLetters` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PurgedDeadLetters {
+    /// `purged_count`.
+    #[serde(rename = "purged_count")]
+    pub purged_count: i64,
+}
+
 /// The `QuotasResponse` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct QuotasResponse {
@@ -972,6 +2584,37 @@ pub struct QuotasResponseLimits {
     pub global_subscriptions_per_application_limit: i32,
 }
 
+/// The `RedeliverDeadLetter` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RedeliverDeadLetter {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+}
+
+/// The `RedeliverDeadLetters` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RedeliverDeadLetters {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `subscription_id`: Only redeliver the dead letters of this subscription
+    #[serde(rename = "subscription_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<Uuid>,
+}
+
+/// The `RedeliveredDeadLetter` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RedeliveredDeadLetter {
+    /// `dead_letter_id`.
+    #[serde(rename = "dead_letter_id")]
+    pub dead_letter_id: Uuid,
+    /// `request_attempt_id`.
+    #[serde(rename = "request_attempt_id")]
+    pub request_attempt_id: Uuid,
+}
+
 /// The `ReplayEvent` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ReplayEvent {
@@ -986,10 +2629,18 @@ pub struct RequestAttempt {
     /// `created_at`.
     #[serde(rename = "created_at")]
     pub created_at: DateTime<Utc>,
+    /// `dead_letter_id`: Dead letter the request attempt became, when it was the last attempt of a delivery an output worker gave up on
+    #[serde(rename = "dead_letter_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_letter_id: Option<Uuid>,
     /// `delay_until`.
     #[serde(rename = "delay_until")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub delay_until: Option<DateTime<Utc>>,
+    /// `delivery_batch_id`: Batch the request attempt was delivered in, shared with the other request attempts of the batch and their response
+    #[serde(rename = "delivery_batch_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_batch_id: Option<Uuid>,
     /// `event`.
     #[serde(rename = "event")]
     pub event: RequestAttemptEvent,
@@ -1044,7 +2695,7 @@ pub struct RequestAttemptEvent {
 /// The `RequestAttemptStatus` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RequestAttemptStatus {
-    /// `at`: Timestamp when completed (present in successful, failed)
+    /// `at`: Timestamp when completed (present in successful, failed, expired, cancelled)
     #[serde(rename = "at")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub at: Option<DateTime<Utc>>,
@@ -1052,11 +2703,15 @@ pub struct RequestAttemptStatus {
     #[serde(rename = "full_processing_ms")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub full_processing_ms: Option<i64>,
+    /// `rule_outcome`: Outcome given by a response rule of the subscription, when one matched the response (present in successful, failed). One of: success, retryable, permanent_failure, disable_subscription
+    #[serde(rename = "rule_outcome")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_outcome: Option<RequestAttemptStatusRuleOutcome>,
     /// `since`: Timestamp when the status started (present in waiting, pending, in_progress)
     #[serde(rename = "since")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub since: Option<DateTime<Utc>>,
-    /// `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed
+    /// `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed, expired, cancelled
     #[serde(rename = "type")]
     pub type_: RequestAttemptStatusType,
     /// `until`: Timestamp until which waiting (only present in waiting status)
@@ -1105,6 +2760,40 @@ pub struct Response {
     pub response_id: Uuid,
 }
 
+/// The `RetryRequestAttempt` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RetryRequestAttempt {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+}
+
+/// The `SecretRotation` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SecretRotation {
+    /// `previous_secret`: The secret the new one replaced
+    #[serde(rename = "previous_secret")]
+    pub previous_secret: Uuid,
+    /// `previous_secret_expires_at`.
+    #[serde(rename = "previous_secret_expires_at")]
+    pub previous_secret_expires_at: DateTime<Utc>,
+    /// `secret`: The new secret of the subscription
+    #[serde(rename = "secret")]
+    pub secret: Uuid,
+    /// `subscription_id`.
+    #[serde(rename = "subscription_id")]
+    pub subscription_id: Uuid,
+}
+
+/// The `SecretRotationPost` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SecretRotationPost {
+    /// `overlap_seconds`: How long the previous secret stays valid, in seconds (one day by default)
+    #[serde(rename = "overlap_seconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overlap_seconds: Option<i32>,
+}
+
 /// The `ServiceToken` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ServiceToken {
@@ -1133,25 +2822,80 @@ pub struct ServiceTokenPost {
     pub organization_id: Uuid,
 }
 
+/// The `SigningKey` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SigningKey {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+    /// `created_at`.
+    #[serde(rename = "created_at")]
+    pub created_at: DateTime<Utc>,
+    /// `is_current`: Whether deliveries are signed with this key, which only the newest key of the application is
+    #[serde(rename = "is_current")]
+    pub is_current: bool,
+    /// `public_key`: Public key, encoded in base64url without padding like the `x` member of its JSON Web Key
+    #[serde(rename = "public_key")]
+    pub public_key: String,
+    /// `signing_key_id`: Identifies the key in the `k` part of signatures and as the `kid` of its JSON Web Key
+    #[serde(rename = "signing_key_id")]
+    pub signing_key_id: Uuid,
+}
+
+/// The `SigningKeyPost` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SigningKeyPost {
+    /// `application_id`.
+    #[serde(rename = "application_id")]
+    pub application_id: Uuid,
+}
+
 /// The `Subscription` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Subscription {
     /// `application_id`.
     #[serde(rename = "application_id")]
     pub application_id: Uuid,
+    /// `auto_disabled_at`: When the circuit breaker or a response rule last disabled the subscription; cleared when it is enabled again
+    #[serde(rename = "auto_disabled_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_disabled_at: Option<DateTime<Utc>>,
+    /// `auto_disabled_reason`: Why the circuit breaker or a response rule last disabled the subscription
+    #[serde(rename = "auto_disabled_reason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_disabled_reason: Option<String>,
+    /// `batching`: Deliver the events of the subscription in batches: one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or once its oldest event has waited `max_wait_s` se
+    #[serde(rename = "batching")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batching: Option<SubscriptionBatching>,
+    /// `circuit_breaker`: Disables the subscription once its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set
+    #[serde(rename = "circuit_breaker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<SubscriptionCircuitBreaker>,
     /// `created_at`.
     #[serde(rename = "created_at")]
     pub created_at: DateTime<Utc>,
     /// `dedicated_workers`.
     #[serde(rename = "dedicated_workers")]
     pub dedicated_workers: Vec<String>,
+    /// `delivery_limits`: Limits on how fast deliveries of the subscription start; deliveries over them wait for their turn, which does not count as a failure; at least one limit must be set
+    #[serde(rename = "delivery_limits")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_limits: Option<SubscriptionDeliveryLimits>,
     /// `description`.
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// `event_type_versions`: Versions the subscription is pinned to, by event type
+    #[serde(rename = "event_type_versions")]
+    pub event_type_versions: HashMap<String, i32>,
     /// `event_types`.
     #[serde(rename = "event_types")]
     pub event_types: Vec<String>,
+    /// `filter`: An object with exactly one of: `and` or `or` (a list of filters), `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload); `label` and `field` go alon
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Value>,
     /// `is_enabled`.
     #[serde(rename = "is_enabled")]
     pub is_enabled: bool,
@@ -1167,9 +2911,28 @@ pub struct Subscription {
     /// `metadata`.
     #[serde(rename = "metadata")]
     pub metadata: HashMap<String, String>,
+    /// `ordering_key`: Where the key of an event comes from, to deliver the events that share a key one at a time and in the order they were received; exactly one of `label` or `field` must be set
+    #[serde(rename = "ordering_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordering_key: Option<SubscriptionOrderingKey>,
+    /// `payload_transform`: Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a pay
+    #[serde(rename = "payload_transform")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_transform: Option<SubscriptionPayloadTransform>,
+    /// `response_rules`.
+    #[serde(rename = "response_rules")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_rules: Option<Vec<SubscriptionResponseRules>>,
+    /// `retry_policy`: Retry schedule of the subscription, replacing the one of the instance within its limits; every field left out keeps the value of the instance
+    #[serde(rename = "retry_policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_policy: Option<SubscriptionRetryPolicy>,
     /// `secret`.
     #[serde(rename = "secret")]
     pub secret: Uuid,
+    /// `signature_scheme`.
+    #[serde(rename = "signature_scheme")]
+    pub signature_scheme: SubscriptionSignatureScheme,
     /// `subscription_id`.
     #[serde(rename = "subscription_id")]
     pub subscription_id: Uuid,
@@ -1181,23 +2944,126 @@ pub struct Subscription {
     pub updated_at: DateTime<Utc>,
 }
 
+/// The `SubscriptionBatching` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionBatching {
+    /// `max_events`: Largest number of events in a batch
+    #[serde(rename = "max_events")]
+    pub max_events: i32,
+    /// `max_wait_s`: Longest time in seconds an event waits for its batch to fill up
+    #[serde(rename = "max_wait_s")]
+    pub max_wait_s: i32,
+}
+
+/// The `SubscriptionCircuitBreaker` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionCircuitBreaker {
+    /// `max_consecutive_failures`: Number of failed deliveries in a row after which the subscription is disabled
+    #[serde(rename = "max_consecutive_failures")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_consecutive_failures: Option<i32>,
+    /// `max_failing_hours`: Number of hours without a single successful delivery after which the subscription is disabled
+    #[serde(rename = "max_failing_hours")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_failing_hours: Option<i32>,
+}
+
+/// The `SubscriptionDeliveryLimits` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionDeliveryLimits {
+    /// `max_in_flight`: Number of deliveries that can be in flight at once
+    #[serde(rename = "max_in_flight")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_in_flight: Option<i32>,
+    /// `max_per_second`: Number of deliveries that can start in a second
+    #[serde(rename = "max_per_second")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_per_second: Option<i32>,
+    /// `per_host`: Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone
+    #[serde(rename = "per_host")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_host: Option<bool>,
+}
+
+/// The `SubscriptionOrderingKey` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionOrderingKey {
+    /// `field`: JSON pointer to the value holding the key in a JSON payload, for example `/customer/id`
+    #[serde(rename = "field")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// `label`: Name of the event label holding the key
+    #[serde(rename = "label")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// The `SubscriptionPayloadTransform` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPayloadTransform {
+    /// `constants`: Properties added at the top level of the result, overriding properties of the same name (the result must then be an object)
+    #[serde(rename = "constants")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constants: Option<HashMap<String, Value>>,
+    /// `envelope`: Name of a property the result is wrapped in
+    #[serde(rename = "envelope")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub envelope: Option<String>,
+    /// `fields`: Values to copy from the payload into a new object; when omitted, the whole payload is kept
+    #[serde(rename = "fields")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<SubscriptionPayloadTransformFields>>,
+}
+
+/// The `SubscriptionPayloadTransformFields` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPayloadTransformFields {
+    /// `from`: JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped)
+    #[serde(rename = "from")]
+    pub from: String,
+    /// `to`: JSON pointer where the value is written in the result, for example `/id`; defaults to `from`
+    #[serde(rename = "to")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
 /// The `SubscriptionPost` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SubscriptionPost {
     /// `application_id`.
     #[serde(rename = "application_id")]
     pub application_id: Uuid,
+    /// `batching`: Deliver the events in batches, as one HTTP request with a JSON array body, rather than one by one
+    #[serde(rename = "batching")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batching: Option<SubscriptionPostBatching>,
+    /// `circuit_breaker`: Disable the subscription once its deliveries keep failing; organization editors are notified by email
+    #[serde(rename = "circuit_breaker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<SubscriptionPostCircuitBreaker>,
     /// `dedicated_workers`.
     #[serde(rename = "dedicated_workers")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dedicated_workers: Option<Vec<String>>,
+    /// `delivery_limits`: Limit how fast deliveries start, for this subscription or for its host
+    #[serde(rename = "delivery_limits")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_limits: Option<SubscriptionPostDeliveryLimits>,
     /// `description`.
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// `event_type_versions`: Only receive the events of these event types that declare this version (or no version at all)
+    #[serde(rename = "event_type_versions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type_versions: Option<HashMap<String, i32>>,
     /// `event_types`.
     #[serde(rename = "event_types")]
     pub event_types: Vec<String>,
+    /// `filter`: Only deliver the events of the subscribed types that match this expression over labels and JSON payload fields
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Value>,
     /// `is_enabled`.
     #[serde(rename = "is_enabled")]
     pub is_enabled: bool,
@@ -1217,41 +3083,310 @@ pub struct SubscriptionPost {
     #[serde(rename = "metadata")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
+    /// `ordering_key`: Deliver the events that share a key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out of retries
+    #[serde(rename = "ordering_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordering_key: Option<SubscriptionPostOrderingKey>,
+    /// `payload_transform`: Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a pay
+    #[serde(rename = "payload_transform")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_transform: Option<SubscriptionPostPayloadTransform>,
+    /// `response_rules`: Decide the outcome of HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body reports an error; the first rule that matches wins, and a response n
+    #[serde(rename = "response_rules")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_rules: Option<Vec<SubscriptionPostResponseRules>>,
+    /// `retry_policy`: Retry failed deliveries on this schedule rather than the instance's one
+    #[serde(rename = "retry_policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_policy: Option<SubscriptionPostRetryPolicy>,
+    /// `signature_scheme`: How deliveries are signed (`hook0` by default); `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of the subscription s
+    #[serde(rename = "signature_scheme")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_scheme: Option<SubscriptionPostSignatureScheme>,
     /// `target`.
     #[serde(rename = "target")]
     pub target: SubscriptionPostTarget,
 }
 
+/// The `SubscriptionPostBatching` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostBatching {
+    /// `max_events`: Largest number of events in a batch
+    #[serde(rename = "max_events")]
+    pub max_events: i32,
+    /// `max_wait_s`: Longest time in seconds an event waits for its batch to fill up
+    #[serde(rename = "max_wait_s")]
+    pub max_wait_s: i32,
+}
+
+/// The `SubscriptionPostCircuitBreaker` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostCircuitBreaker {
+    /// `max_consecutive_failures`: Number of failed deliveries in a row after which the subscription is disabled
+    #[serde(rename = "max_consecutive_failures")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_consecutive_failures: Option<i32>,
+    /// `max_failing_hours`: Number of hours without a single successful delivery after which the subscription is disabled
+    #[serde(rename = "max_failing_hours")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_failing_hours: Option<i32>,
+}
+
+/// The `SubscriptionPostDeliveryLimits` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostDeliveryLimits {
+    /// `max_in_flight`: Number of deliveries that can be in flight at once
+    #[serde(rename = "max_in_flight")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_in_flight: Option<i32>,
+    /// `max_per_second`: Number of deliveries that can start in a second
+    #[serde(rename = "max_per_second")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_per_second: Option<i32>,
+    /// `per_host`: Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone
+    #[serde(rename = "per_host")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_host: Option<bool>,
+}
+
+/// The `SubscriptionPostOrderingKey` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostOrderingKey {
+    /// `field`: JSON pointer to the value holding the key in a JSON payload, for example `/customer/id`
+    #[serde(rename = "field")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// `label`: Name of the event label holding the key
+    #[serde(rename = "label")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// The `SubscriptionPostPayloadTransform` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostPayloadTransform {
+    /// `constants`: Properties added at the top level of the result, overriding properties of the same name (the result must then be an object)
+    #[serde(rename = "constants")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constants: Option<HashMap<String, Value>>,
+    /// `envelope`: Name of a property the result is wrapped in
+    #[serde(rename = "envelope")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub envelope: Option<String>,
+    /// `fields`: Values to copy from the payload into a new object; when omitted, the whole payload is kept
+    #[serde(rename = "fields")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<SubscriptionPostPayloadTransformFields>>,
+}
+
+/// The `SubscriptionPostPayloadTransformFields` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostPayloadTransformFields {
+    /// `from`: JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped)
+    #[serde(rename = "from")]
+    pub from: String,
+    /// `to`: JSON pointer where the value is written in the result, for example `/id`; defaults to `from`
+    #[serde(rename = "to")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+/// The `SubscriptionPostResponseRules` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostResponseRules {
+    /// `body_contains`: Text the response body must contain
+    #[serde(rename = "body_contains")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_contains: Option<String>,
+    /// `header`: Header the response must have
+    #[serde(rename = "header")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<SubscriptionPostResponseRulesHeader>,
+    /// `outcome`: What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+    #[serde(rename = "outcome")]
+    pub outcome: SubscriptionPostResponseRulesOutcome,
+    /// `status`: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`
+    #[serde(rename = "status")]
+    pub status: Vec<String>,
+}
+
+/// The `SubscriptionPostResponseRulesHeader` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostResponseRulesHeader {
+    /// `name`: Name of the header, regardless of case
+    #[serde(rename = "name")]
+    pub name: String,
+    /// `value`: Value the header must have; when omitted, any value matches
+    #[serde(rename = "value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// The `SubscriptionPostRetryPolicy` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionPostRetryPolicy {
+    /// `backoff_steps_s`: Delays in seconds to wait before each retry; the last one is used again for the following retries
+    #[serde(rename = "backoff_steps_s")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff_steps_s: Option<Vec<i32>>,
+    /// `max_retries`: Maximum number of retries after a failed delivery (0 disables retries)
+    #[serde(rename = "max_retries")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<i32>,
+    /// `max_window_s`: Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled
+    #[serde(rename = "max_window_s")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_window_s: Option<i32>,
+}
+
 /// The `SubscriptionPostTarget` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SubscriptionPostTarget {
-    /// `headers`.
+    /// `access_key_id`: `sqs`: access key ID requests are signed with
+    #[serde(rename = "access_key_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_key_id: Option<String>,
+    /// `exchange`: `amqp`: exchange messages are published to; empty for the default exchange
+    #[serde(rename = "exchange")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange: Option<String>,
+    /// `headers`: `http`: headers sent with every request
     #[serde(rename = "headers")]
-    pub headers: Value,
-    /// `method`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Value>,
+    /// `method`: `http`: HTTP method of the requests
     #[serde(rename = "method")]
-    pub method: String,
-    /// `type`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// `password`: `amqp`: password to log in to the broker with; write-only
+    #[serde(rename = "password")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// `queue_url`: `sqs`: URL of the queue
+    #[serde(rename = "queue_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_url: Option<Url>,
+    /// `region`: `sqs`: region requests are signed for
+    #[serde(rename = "region")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// `routing_key`: `amqp`: routing key messages are published with
+    #[serde(rename = "routing_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_key: Option<String>,
+    /// `secret_access_key`: `sqs`: secret access key requests are signed with; write-only
+    #[serde(rename = "secret_access_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_access_key: Option<String>,
+    /// `type`: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
     #[serde(rename = "type")]
-    pub type_: String,
-    /// `url`.
+    pub type_: SubscriptionPostTargetType,
+    /// `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials
     #[serde(rename = "url")]
-    pub url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// `username`: `amqp`: user name to log in to the broker with
+    #[serde(rename = "username")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+/// The `SubscriptionResponseRules` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionResponseRules {
+    /// `body_contains`: Text the response body must contain
+    #[serde(rename = "body_contains")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_contains: Option<String>,
+    /// `header`: Header the response must have
+    #[serde(rename = "header")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<SubscriptionResponseRulesHeader>,
+    /// `outcome`: What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+    #[serde(rename = "outcome")]
+    pub outcome: SubscriptionResponseRulesOutcome,
+    /// `status`: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx`
+    #[serde(rename = "status")]
+    pub status: Vec<String>,
+}
+
+/// The `SubscriptionResponseRulesHeader` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionResponseRulesHeader {
+    /// `name`: Name of the header, regardless of case
+    #[serde(rename = "name")]
+    pub name: String,
+    /// `value`: Value the header must have; when omitted, any value matches
+    #[serde(rename = "value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// The `SubscriptionRetryPolicy` the API declares.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionRetryPolicy {
+    /// `backoff_steps_s`: Delays in seconds to wait before each retry; the last one is used again for the following retries
+    #[serde(rename = "backoff_steps_s")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff_steps_s: Option<Vec<i32>>,
+    /// `max_retries`: Maximum number of retries after a failed delivery (0 disables retries)
+    #[serde(rename = "max_retries")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<i32>,
+    /// `max_window_s`: Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled
+    #[serde(rename = "max_window_s")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_window_s: Option<i32>,
 }
 
 /// The `SubscriptionTarget` the API declares.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SubscriptionTarget {
-    /// `headers`.
+    /// `access_key_id`: `sqs`: access key ID requests are signed with
+    #[serde(rename = "access_key_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_key_id: Option<String>,
+    /// `exchange`: `amqp`: exchange messages are published to; empty for the default exchange
+    #[serde(rename = "exchange")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange: Option<String>,
+    /// `headers`: `http`: headers sent with every request
     #[serde(rename = "headers")]
-    pub headers: Value,
-    /// `method`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Value>,
+    /// `method`: `http`: HTTP method of the requests
     #[serde(rename = "method")]
-    pub method: String,
-    /// `type`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// `password`: `amqp`: password to log in to the broker with; write-only
+    #[serde(rename = "password")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// `queue_url`: `sqs`: URL of the queue
+    #[serde(rename = "queue_url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_url: Option<Url>,
+    /// `region`: `sqs`: region requests are signed for
+    #[serde(rename = "region")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// `routing_key`: `amqp`: routing key messages are published with
+    #[serde(rename = "routing_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_key: Option<String>,
+    /// `secret_access_key`: `sqs`: secret access key requests are signed with; write-only
+    #[serde(rename = "secret_access_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_access_key: Option<String>,
+    /// `type`: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
     #[serde(rename = "type")]
-    pub type_: String,
-    /// `url`.
+    pub type_: SubscriptionTargetType,
+    /// `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials
     #[serde(rename = "url")]
-    pub url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// `username`: `amqp`: user name to log in to the broker with
+    #[serde(rename = "username")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
 }
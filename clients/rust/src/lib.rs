@@ -39,6 +39,8 @@ use reqwest::header::{
 #[cfg(feature = "producer")]
 use reqwest::{Client, ResponseBuilderExt, Url};
 #[cfg(feature = "producer")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "producer")]
 use serde::ser::Error as SerializationError;
 #[cfg(feature = "producer")]
 use serde::{Deserialize, Serialize, Serializer};
@@ -67,6 +69,8 @@ use chrono::{Duration, OutOfRangeError};
 use std::time::Duration as StdDuration;
 #[cfg(feature = "consumer")]
 mod signature;
+#[cfg(feature = "consumer")]
+pub use signature::{PublicKeys, VerificationKey};
 
 /// Everything the API document describes, written by the SDK generator and never by hand.
 ///
@@ -532,10 +536,12 @@ impl Hook0Client {
 
         loop {
             attempts += 1;
-            let outcome = self.attempt_event_send(&event_ingestion_url, &body).await;
+            let outcome = self
+                .attempt_send::<IngestedEvent>(&event_ingestion_url, &body)
+                .await;
 
             let failure = match outcome {
-                Attempt::Ingested(id) => return Ok(id),
+                Attempt::Ingested(ingested) => return Ok(ingested.event_id),
                 Attempt::AlreadyIngested { error, body } => {
                     if attempts > 1 {
                         debug!(
@@ -572,8 +578,101 @@ impl Hook0Client {
         }
     }
 
-    /// Perform one attempt at sending an already-bounded event to Hook0
-    async fn attempt_event_send(&self, url: &Url, body: &BoundedEvent<'_>) -> Attempt {
+    /// Send several events to Hook0 in one request
+    ///
+    /// Hook0 checks each event of a batch on its own, so a batch is not all-or-nothing: what comes
+    /// back is one outcome per event, in the order of `events`, which is either the ID the event was
+    /// ingested under or the [`EventRefusal`] Hook0 answered it with. The API takes at most 100
+    /// events per batch.
+    ///
+    /// What [`Hook0Client::send_event`] says about IDs, bounds and retries holds for the batch as a
+    /// whole. Every event is sent under an ID this client knows, and the request is retried when it
+    /// fails in a way a repetition could fix; an event that a retry finds already ingested is
+    /// reported as ingested, since an earlier attempt of this very send is what ingested it. An error
+    /// is only returned when the request as a whole did not go through.
+    pub async fn send_events(
+        &self,
+        events: &[Event<'_>],
+    ) -> Result<Vec<Result<Uuid, EventRefusal>>, Hook0ClientError> {
+        let batch_ingestion_url = self.mk_url(&["events", "batch"])?;
+        let event_ids = events
+            .iter()
+            .map(|event| match event.event_id {
+                Some(event_id) => event_id.to_owned(),
+                None => Uuid::now_v7(),
+            })
+            .collect::<Vec<_>>();
+        let full_events = events
+            .iter()
+            .zip(&event_ids)
+            .map(|(event, event_id)| FullEvent::from_event(event, &self.application_id, event_id))
+            .collect::<Vec<_>>();
+        let body = EventsBatch {
+            application_id: self.application_id,
+            events: full_events
+                .iter()
+                .map(|event| BoundedEvent {
+                    event,
+                    max_payload_bytes: self.max_payload_bytes,
+                })
+                .collect(),
+        };
+
+        let delays = self.retry_policy.delays(&jitter_draws(
+            self.retry_policy.attempts().saturating_sub(1) as usize,
+        ));
+        let mut waited = StdDuration::ZERO;
+        let mut attempts = 0u32;
+
+        loop {
+            attempts += 1;
+            let outcome = self
+                .attempt_send::<IngestedEventsBatch>(&batch_ingestion_url, &body)
+                .await;
+
+            let failure = match outcome {
+                Attempt::Ingested(batch) => {
+                    return Ok(event_ids
+                        .iter()
+                        .zip(batch.events)
+                        .map(|(event_id, outcome)| outcome.into_result(*event_id, attempts > 1))
+                        .collect());
+                }
+                // A batch is never refused whole for one of its events being taken, so this is a
+                // conflict on something else, which no repetition clears.
+                Attempt::AlreadyIngested { error, body } => Failure {
+                    error,
+                    body,
+                    retryable: false,
+                    named_delay: None,
+                },
+                Attempt::Failed(failure) => failure,
+            };
+
+            match delays.get((attempts - 1) as usize) {
+                Some(delay) if failure.retryable => {
+                    trace!("Attempt {attempts} at sending a batch of events failed, retrying");
+                    let wait = wait_before_retry(&self.retry_policy, &failure, *delay, waited);
+                    waited = waited.saturating_add(wait);
+                    tokio::time::sleep(wait).await;
+                }
+                _ => {
+                    return Err(Hook0ClientError::EventsSending {
+                        error: failure.error,
+                        body: give_up_reason(attempts, waited, failure.body),
+                    }
+                    .log_and_return());
+                }
+            }
+        }
+    }
+
+    /// Perform one attempt at sending already-bounded events to Hook0, reading what it ingested as `T`
+    async fn attempt_send<T: DeserializeOwned>(
+        &self,
+        url: &Url,
+        body: &impl Serialize,
+    ) -> Attempt<T> {
         let response = self
             .client
             .post(url.as_str())
@@ -616,12 +715,8 @@ impl Hook0Client {
 
         match res.error_for_status_ref() {
             Ok(_) => {
-                #[derive(Debug, Deserialize)]
-                struct Response {
-                    event_id: Uuid,
-                }
-                match res.json::<Response>().await {
-                    Ok(response) => Attempt::Ingested(response.event_id),
+                match res.json::<T>().await {
+                    Ok(response) => Attempt::Ingested(response),
                     // Hook0 accepted the event but answered something this client cannot read — an
                     // answer above a ceiling it set for itself is one of those; repeating the
                     // request would meet the same answer.
@@ -757,24 +852,52 @@ impl Hook0Client {
 /// - `signature` - The value of the `X-Hook0-Signature` header.
 /// - `payload` - The raw body of the webhook request.
 /// - `headers` - Headers of the webhook request.
-/// - `subscription_secret` - The signing secret used to validate the signature.
+/// - `key` - What the signature is verified with: the secret of the subscription (`v0` and `v1` signatures) or the [`PublicKeys`] of the application (`v2` Ed25519 signatures).
 /// - `tolerance` - The maximum allowed time difference for the timestamp, in either direction (5 minutes is a good trade-off between flexibility and protecting against replay attacks). A timestamp that is too far in the future is rejected just like one that is too far in the past, so that the acceptance window of any given webhook stays bounded.
 /// - `current_time` - The current time (used to check the timestamp).
 pub fn verify_webhook_signature_with_current_time<
+    'a,
     HeaderKey: AsRef<[u8]>,
     HeaderValue: AsRef<[u8]>,
 >(
     signature: &str,
     payload: &[u8],
     headers: &[(HeaderKey, HeaderValue)],
-    subscription_secret: &str,
+    key: impl Into<VerificationKey<'a>>,
     tolerance: StdDuration,
     current_time: DateTime<Utc>,
 ) -> Result<(), Hook0ClientError> {
     let parsed_sig =
         signature::Signature::parse(signature).map_err(|_| Hook0ClientError::InvalidSignature)?;
 
-    let headers_with_parsed_name = headers
+    let headers_with_parsed_name = parse_header_names(headers)?;
+    let headers_vec = parsed_sig
+        .h
+        .iter()
+        .map(|expected| header_value(&headers_with_parsed_name, expected))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let verified = match key.into() {
+        VerificationKey::SubscriptionSecret(secret) => {
+            parsed_sig.verify(payload, &headers_vec, secret)?
+        }
+        VerificationKey::PublicKeys(keys) => {
+            parsed_sig.verify_with_public_keys(payload, &headers_vec, keys)?
+        }
+    };
+    if !verified {
+        Err(Hook0ClientError::InvalidSignature)
+    } else {
+        check_tolerance(parsed_sig.timestamp, tolerance, current_time)
+    }
+}
+
+#[cfg(feature = "consumer")]
+/// The headers of a webhook request, by name.
+fn parse_header_names<HeaderKey: AsRef<[u8]>, HeaderValue: AsRef<[u8]>>(
+    headers: &[(HeaderKey, HeaderValue)],
+) -> Result<std::collections::HashMap<http::HeaderName, &HeaderValue>, Hook0ClientError> {
+    headers
         .iter()
         .map(|(k, v)| {
             let name = http::HeaderName::from_bytes(k.as_ref()).map_err(|error| {
@@ -785,51 +908,57 @@ pub fn verify_webhook_signature_with_current_time<
             });
             name.map(|n| (n, v))
         })
-        .collect::<Result<std::collections::HashMap<_, _>, _>>()?;
-    let headers_vec = parsed_sig
-        .h
-        .iter()
-        .map(|expected| {
-            headers_with_parsed_name
-                .get(expected)
-                .ok_or_else(|| Hook0ClientError::MissingHeader(expected.to_owned()))
-                .and_then(|v| {
-                    String::from_utf8(v.as_ref().to_vec()).map_err(|error| {
-                        Hook0ClientError::InvalidHeaderValue {
-                            header_name: expected.to_owned(),
-                            header_value: String::from_utf8_lossy(v.as_ref()).into_owned(),
-                            error,
-                        }
-                    })
-                })
+        .collect()
+}
+
+#[cfg(feature = "consumer")]
+/// The value of a header the signature needs.
+fn header_value<HeaderValue: AsRef<[u8]>>(
+    headers: &std::collections::HashMap<http::HeaderName, &HeaderValue>,
+    expected: &http::HeaderName,
+) -> Result<String, Hook0ClientError> {
+    headers
+        .get(expected)
+        .ok_or_else(|| Hook0ClientError::MissingHeader(expected.to_owned()))
+        .and_then(|v| {
+            String::from_utf8(v.as_ref().to_vec()).map_err(|error| {
+                Hook0ClientError::InvalidHeaderValue {
+                    header_name: expected.to_owned(),
+                    header_value: String::from_utf8_lossy(v.as_ref()).into_owned(),
+                    error,
+                }
+            })
         })
-        .collect::<Result<Vec<_>, _>>()?;
+}
 
-    if !parsed_sig.verify(payload, &headers_vec, subscription_secret)? {
-        Err(Hook0ClientError::InvalidSignature)
-    } else {
-        let signed_at = DateTime::from_timestamp(parsed_sig.timestamp, 0);
-
-        match signed_at {
-            Some(signed_at) => {
-                let tolerance = Duration::from_std(tolerance);
-                match tolerance {
-                    Ok(tolerance) => {
-                        if (current_time - signed_at).abs() > tolerance {
-                            Err(Hook0ClientError::ExpiredWebhook {
-                                signed_at,
-                                tolerance,
-                                current_time,
-                            })
-                        } else {
-                            Ok(())
-                        }
+#[cfg(feature = "consumer")]
+/// Refuses a webhook signed further from `current_time` than `tolerance`, in either direction.
+fn check_tolerance(
+    timestamp: i64,
+    tolerance: StdDuration,
+    current_time: DateTime<Utc>,
+) -> Result<(), Hook0ClientError> {
+    let signed_at = DateTime::from_timestamp(timestamp, 0);
+
+    match signed_at {
+        Some(signed_at) => {
+            let tolerance = Duration::from_std(tolerance);
+            match tolerance {
+                Ok(tolerance) => {
+                    if (current_time - signed_at).abs() > tolerance {
+                        Err(Hook0ClientError::ExpiredWebhook {
+                            signed_at,
+                            tolerance,
+                            current_time,
+                        })
+                    } else {
+                        Ok(())
                     }
-                    Err(e) => Err(Hook0ClientError::InvalidTolerance(e)),
                 }
+                Err(e) => Err(Hook0ClientError::InvalidTolerance(e)),
             }
-            None => Err(Hook0ClientError::InvalidSignature),
         }
+        None => Err(Hook0ClientError::InvalidSignature),
     }
 }
 
@@ -839,17 +968,75 @@ pub fn verify_webhook_signature_with_current_time<
 /// - `signature` - The value of the `X-Hook0-Signature` header.
 /// - `payload` - The raw body of the webhook request.
 /// - `headers` - Headers of the webhook request.
-/// - `subscription_secret` - The signing secret used to validate the signature.
+/// - `key` - What the signature is verified with: the secret of the subscription (`v0` and `v1` signatures) or the [`PublicKeys`] of the application (`v2` Ed25519 signatures).
 /// - `tolerance` - The maximum allowed time difference for the timestamp, in either direction (5 minutes is a good trade-off between flexibility and protecting against replay attacks). A timestamp that is too far in the future is rejected just like one that is too far in the past, so that the acceptance window of any given webhook stays bounded.
-pub fn verify_webhook_signature<HeaderKey: AsRef<[u8]>, HeaderValue: AsRef<[u8]>>(
+pub fn verify_webhook_signature<'a, HeaderKey: AsRef<[u8]>, HeaderValue: AsRef<[u8]>>(
     signature: &str,
     payload: &[u8],
     headers: &[(HeaderKey, HeaderValue)],
-    subscription_secret: &str,
+    key: impl Into<VerificationKey<'a>>,
     tolerance: StdDuration,
 ) -> Result<(), Hook0ClientError> {
     verify_webhook_signature_with_current_time(
         signature,
+        payload,
+        headers,
+        key,
+        tolerance,
+        Utc::now(),
+    )
+}
+
+#[cfg(feature = "consumer")]
+/// Verifies the signature of a webhook sent by a subscription whose signature scheme is `standard_webhooks`
+///
+/// - `payload` - The raw body of the webhook request.
+/// - `headers` - Headers of the webhook request, which carry `webhook-id`, `webhook-timestamp` and `webhook-signature`.
+/// - `subscription_secret` - The signing secret used to validate the signature, as Hook0 gives it or in the `whsec_` form of the Standard Webhooks specification.
+/// - `tolerance` - The maximum allowed time difference for the timestamp, in either direction (5 minutes is a good trade-off between flexibility and protecting against replay attacks).
+/// - `current_time` - The current time (used to check the timestamp).
+pub fn verify_standard_webhooks_signature_with_current_time<
+    HeaderKey: AsRef<[u8]>,
+    HeaderValue: AsRef<[u8]>,
+>(
+    payload: &[u8],
+    headers: &[(HeaderKey, HeaderValue)],
+    subscription_secret: &str,
+    tolerance: StdDuration,
+    current_time: DateTime<Utc>,
+) -> Result<(), Hook0ClientError> {
+    let headers_with_parsed_name = parse_header_names(headers)?;
+    let [id, timestamp, signature] =
+        ["webhook-id", "webhook-timestamp", "webhook-signature"].map(|name| {
+            header_value(
+                &headers_with_parsed_name,
+                &http::HeaderName::from_static(name),
+            )
+        });
+    let parsed_sig = signature::StandardWebhooksSignature::parse(&id?, &timestamp?, &signature?)
+        .map_err(|_| Hook0ClientError::InvalidSignature)?;
+
+    if !parsed_sig.verify(payload, subscription_secret)? {
+        Err(Hook0ClientError::InvalidSignature)
+    } else {
+        check_tolerance(parsed_sig.timestamp, tolerance, current_time)
+    }
+}
+
+#[cfg(feature = "consumer")]
+/// Verifies the signature of a webhook sent by a subscription whose signature scheme is `standard_webhooks`
+///
+/// - `payload` - The raw body of the webhook request.
+/// - `headers` - Headers of the webhook request, which carry `webhook-id`, `webhook-timestamp` and `webhook-signature`.
+/// - `subscription_secret` - The signing secret used to validate the signature, as Hook0 gives it or in the `whsec_` form of the Standard Webhooks specification.
+/// - `tolerance` - The maximum allowed time difference for the timestamp, in either direction (5 minutes is a good trade-off between flexibility and protecting against replay attacks).
+pub fn verify_standard_webhooks_signature<HeaderKey: AsRef<[u8]>, HeaderValue: AsRef<[u8]>>(
+    payload: &[u8],
+    headers: &[(HeaderKey, HeaderValue)],
+    subscription_secret: &str,
+    tolerance: StdDuration,
+) -> Result<(), Hook0ClientError> {
+    verify_standard_webhooks_signature_with_current_time(
         payload,
         headers,
         subscription_secret,
@@ -979,10 +1166,85 @@ impl Serialize for BoundedEvent<'_> {
 }
 
 #[cfg(feature = "producer")]
-/// What one attempt at sending an event ended with.
-enum Attempt {
-    /// Hook0 ingested the event and answered with its ID.
-    Ingested(Uuid),
+/// A batch of events, each refusing a payload larger than the client accepts the way a single
+/// [`BoundedEvent`] does.
+#[derive(Serialize)]
+struct EventsBatch<'a> {
+    application_id: Uuid,
+    events: Vec<BoundedEvent<'a>>,
+}
+
+#[cfg(feature = "producer")]
+/// What Hook0 answers when it ingested an event.
+#[derive(Debug, Deserialize)]
+struct IngestedEvent {
+    event_id: Uuid,
+}
+
+#[cfg(feature = "producer")]
+/// What Hook0 answers when it went through a batch of events.
+#[derive(Debug, Deserialize)]
+struct IngestedEventsBatch {
+    events: Vec<BatchedEvent>,
+}
+
+#[cfg(feature = "producer")]
+/// What Hook0 made of one event of a batch.
+#[derive(Debug, Deserialize)]
+struct BatchedEvent {
+    status: String,
+    problem: Option<BatchedEventProblem>,
+}
+
+#[cfg(feature = "producer")]
+#[derive(Debug, Deserialize)]
+struct BatchedEventProblem {
+    id: String,
+    detail: String,
+}
+
+#[cfg(feature = "producer")]
+impl BatchedEvent {
+    /// The outcome of the event sent under `event_id`; `retried` says whether an earlier attempt of
+    /// the same send may have ingested it already.
+    fn into_result(self, event_id: Uuid, retried: bool) -> Result<Uuid, EventRefusal> {
+        match self.problem {
+            None if self.status == "ingested" => Ok(event_id),
+            Some(problem) if retried && problem.id == ALREADY_INGESTED => {
+                debug!("Event {event_id} was already ingested by a previous attempt of this send");
+                Ok(event_id)
+            }
+            Some(problem) => Err(EventRefusal {
+                event_id,
+                problem_id: problem.id,
+                detail: problem.detail,
+            }),
+            None => Err(EventRefusal {
+                event_id,
+                problem_id: self.status,
+                detail: String::new(),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "producer")]
+/// Why Hook0 did not ingest one event of a batch sent with [`Hook0Client::send_events`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventRefusal {
+    /// ID the event was sent under
+    pub event_id: Uuid,
+    /// Identifier of the problem Hook0 refused the event with, such as `EventInvalidJsonPayload`
+    pub problem_id: String,
+    /// What Hook0 said about the problem
+    pub detail: String,
+}
+
+#[cfg(feature = "producer")]
+/// What one attempt at sending events ended with.
+enum Attempt<T> {
+    /// Hook0 accepted the request and answered with what it ingested.
+    Ingested(T),
 
     /// Hook0 refused the event because it already holds one under the same ID.
     AlreadyIngested {
@@ -1267,6 +1529,22 @@ pub enum Hook0ClientError {
         body: Option<String>,
     },
 
+    #[cfg(feature = "producer")]
+    /// Something went wrong when sending a batch of events to Hook0
+    ///
+    /// The batch as a whole did not go through. An event Hook0 refused on its own is not an error
+    /// of the send: it is reported as an [`EventRefusal`] among the outcomes of the batch.
+    #[error("Sending a batch of events failed: {error} [body={}]", body.as_deref().unwrap_or(""))]
+    EventsSending {
+        /// Error as reported by Reqwest
+        ///
+        /// For a send that was retried, this is what the last attempt ran into.
+        error: reqwest::Error,
+
+        /// Body of the HTTP response, or why the client gave up
+        body: Option<String>,
+    },
+
     #[cfg(feature = "producer")]
     /// Provided event type does not have a valid syntax
     #[error("Provided event type '{0}' does not have a valid syntax (service.resource_type.verb)")]
@@ -1394,6 +1672,18 @@ pub enum Hook0ClientError {
     /// Invalid tolerance Duration
     #[error("Invalid tolerance Duration: {0}")]
     InvalidTolerance(OutOfRangeError),
+
+    #[cfg(feature = "consumer")]
+    /// The webhook was signed with a key that is not among the public keys it was verified with
+    ///
+    /// The key may be newer than them: fetching the public keys of the application again picks it up. A key that is still missing then was retired, or never existed.
+    #[error("The webhook was signed with key `{0}`, which is not among the public keys provided")]
+    UnknownSigningKey(String),
+
+    #[cfg(feature = "consumer")]
+    /// Could not read the public keys of an application
+    #[error("Could not read public keys: {0}")]
+    PublicKeysParsing(String),
 }
 
 #[cfg(feature = "producer")]
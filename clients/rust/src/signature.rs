@@ -1,5 +1,9 @@
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD as Base64, URL_SAFE_NO_PAD as Base64Url};
+use ed25519_dalek::{Signature as Ed25519Signature, VerifyingKey};
 use hmac::{Hmac, KeyInit, Mac};
 use http::HeaderName;
+use serde::Deserialize;
 use sha2::Sha256;
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -7,11 +11,101 @@ use tracing::trace;
 
 use crate::Hook0ClientError;
 
+/// What the signature of a webhook is verified with
+#[derive(Debug, Clone, Copy)]
+pub enum VerificationKey<'a> {
+    /// The secret of the subscription, which verifies the `v0` and `v1` parts of signatures
+    SubscriptionSecret(&'a str),
+    /// The public keys of the application, which verify the `v2` part of signatures
+    PublicKeys(&'a PublicKeys),
+}
+
+impl<'a> From<&'a str> for VerificationKey<'a> {
+    fn from(secret: &'a str) -> Self {
+        Self::SubscriptionSecret(secret)
+    }
+}
+
+impl<'a> From<&'a String> for VerificationKey<'a> {
+    fn from(secret: &'a String) -> Self {
+        Self::SubscriptionSecret(secret)
+    }
+}
+
+impl<'a> From<&'a PublicKeys> for VerificationKey<'a> {
+    fn from(keys: &'a PublicKeys) -> Self {
+        Self::PublicKeys(keys)
+    }
+}
+
+/// The Ed25519 public keys an application signs its webhooks with, by key ID
+///
+/// Hook0 publishes them as a JSON Web Key Set at `/api/v1/jwks/{application_id}`, which needs no
+/// authentication. They can be kept and reused; when a webhook names a key they do not hold,
+/// verifying it fails with [`Hook0ClientError::UnknownSigningKey`], and fetching them again picks
+/// up a key that was created since.
+#[derive(Debug, Clone, Default)]
+pub struct PublicKeys(HashMap<String, VerifyingKey>);
+
+#[derive(Deserialize)]
+struct JsonWebKeySet {
+    keys: Vec<JsonWebKey>,
+}
+
+#[derive(Deserialize)]
+struct JsonWebKey {
+    kty: String,
+    crv: Option<String>,
+    kid: Option<String>,
+    x: Option<String>,
+}
+
+impl PublicKeys {
+    const KEY_TYPE: &'static str = "OKP";
+    const CURVE: &'static str = "Ed25519";
+
+    /// Reads a JSON Web Key Set, as the JWKS endpoint of Hook0 serves it; keys that are not
+    /// Ed25519 keys with an ID are passed over.
+    pub fn from_jwks(jwks: &[u8]) -> Result<Self, Hook0ClientError> {
+        let set: JsonWebKeySet = serde_json::from_slice(jwks)
+            .map_err(|e| Hook0ClientError::PublicKeysParsing(e.to_string()))?;
+
+        set.keys
+            .into_iter()
+            .filter(|key| key.kty == Self::KEY_TYPE && key.crv.as_deref() == Some(Self::CURVE))
+            .filter_map(|key| key.kid.map(|kid| (kid, key.x)))
+            .map(|(kid, x)| {
+                let key = x
+                    .and_then(|x| Base64Url.decode(x).ok())
+                    .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                    .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok())
+                    .ok_or_else(|| {
+                        Hook0ClientError::PublicKeysParsing(format!(
+                            "Key `{kid}` is not a valid Ed25519 public key"
+                        ))
+                    })?;
+                Ok((kid, key))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()
+            .map(Self)
+    }
+
+    /// Whether a key of that ID is among them.
+    pub fn contains(&self, key_id: &str) -> bool {
+        self.0.contains_key(key_id)
+    }
+}
+
 pub struct Signature {
     pub timestamp: i64,
-    pub v0: Option<Vec<u8>>,
+    /// Every `v0` code the signature carries; there is one per secret while a secret is rotated.
+    pub v0: Vec<Vec<u8>>,
     pub h: Vec<HeaderName>,
-    pub v1: Option<Vec<u8>>,
+    /// Every `v1` code the signature carries; there is one per secret while a secret is rotated.
+    pub v1: Vec<Vec<u8>>,
+    /// ID of the key that made `v2`
+    pub k: Option<String>,
+    pub v2: Option<Vec<u8>>,
 }
 
 impl Signature {
@@ -22,14 +116,21 @@ impl Signature {
     const SIGNATURE_PART_HEADER_NAMES_SEPARATOR: &'static str = " ";
 
     pub fn parse(signature: &str) -> Result<Self, Hook0ClientError> {
-        let parts = signature
+        let all_parts = signature
             .split(Self::SIGNATURE_PART_SEPARATOR)
             .flat_map(|part| {
                 part.split_once(Self::SIGNATURE_PART_ASSIGNATOR)
                     .map(|(k, v)| vec![(k.trim(), v.trim())])
                     .unwrap_or_default()
             })
-            .collect::<HashMap<_, _>>();
+            .collect::<Vec<_>>();
+        let parts = all_parts.iter().copied().collect::<HashMap<_, _>>();
+        let codes = |version: &'static str| {
+            all_parts
+                .iter()
+                .filter(move |(k, _)| *k == version)
+                .map(|(_, v)| *v)
+        };
 
         if parts.len() >= 2 {
             let t = parts.get("t").copied().ok_or_else(|| {
@@ -41,15 +142,14 @@ impl Signature {
                     error,
                 })?;
 
-            let v0 = match parts.get("v0").copied() {
-                Some(v0_str) => Some(hex::decode(v0_str).map_err(|error| {
-                    Hook0ClientError::V0SignatureParsing {
+            let v0 = codes("v0")
+                .map(|v0_str| {
+                    hex::decode(v0_str).map_err(|error| Hook0ClientError::V0SignatureParsing {
                         signature: v0_str.to_owned(),
                         error,
-                    }
-                })?),
-                None => None,
-            };
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
 
             let h = match parts.get("h").copied() {
                 Some(h_str) => h_str
@@ -66,19 +166,32 @@ impl Signature {
                 None => Vec::new(),
             };
 
-            let v1 = match parts.get("v1").copied() {
-                Some(v1_str) => Some(hex::decode(v1_str).map_err(|error| {
-                    Hook0ClientError::V1SignatureParsing {
+            let v1 = codes("v1")
+                .map(|v1_str| {
+                    hex::decode(v1_str).map_err(|error| Hook0ClientError::V1SignatureParsing {
                         signature: v1_str.to_owned(),
                         error,
-                    }
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let k = parts.get("k").map(|k| (*k).to_owned());
+            let v2 = match parts.get("v2").copied() {
+                Some(v2_str) => Some(hex::decode(v2_str).map_err(|error| {
+                    Hook0ClientError::SignatureHeaderParsing(format!(
+                        "Could not decode v2 signature `{v2_str}`: {error}"
+                    ))
                 })?),
                 None => None,
             };
 
-            if v0.is_none() && v1.is_none() {
+            if v0.is_empty() && v1.is_empty() && v2.is_none() {
+                Err(Hook0ClientError::SignatureHeaderParsing(
+                    "There must be at least one of 'v0', 'v1' or 'v2' field".to_owned(),
+                ))
+            } else if v2.is_some() && k.is_none() {
                 Err(Hook0ClientError::SignatureHeaderParsing(
-                    "There must be at least one of 'v0' or 'v1' field".to_owned(),
+                    "Missing 'k' field, which names the key of the 'v2' field".to_owned(),
                 ))
             } else {
                 Ok(Self {
@@ -86,6 +199,8 @@ impl Signature {
                     v0,
                     h,
                     v1,
+                    k,
+                    v2,
                 })
             }
         } else {
@@ -95,7 +210,9 @@ impl Signature {
         }
     }
 
-    /// Whether the signature was produced over that payload with that secret.
+    /// Whether the signature was produced over that payload with that secret; one code of the
+    /// strongest version it carries is enough, as a signature carries one per secret while the
+    /// secret is rotated.
     ///
     /// Keying the MAC is fallible in the type system even though HMAC accepts a key of any size, and
     /// a verifier that panics on it takes down the webhook handler it was called from. The refusal
@@ -117,7 +234,7 @@ impl Signature {
         mac.update(timestamp_str_bytes);
         mac.update(Self::PAYLOAD_SEPARATOR_BYTES);
 
-        if let Some(v1) = self.v1.as_ref() {
+        if !self.v1.is_empty() {
             trace!("Verifying v1 signature...");
 
             mac.update(
@@ -133,16 +250,145 @@ impl Signature {
             );
             mac.update(Self::PAYLOAD_SEPARATOR_BYTES);
             mac.update(payload);
-            Ok(mac.verify_slice(v1).is_ok())
-        } else if let Some(v0) = self.v0.as_ref() {
+            Ok(self
+                .v1
+                .iter()
+                .any(|code| mac.clone().verify_slice(code).is_ok()))
+        } else if !self.v0.is_empty() {
             trace!("Verifying v0 signature...");
 
             mac.update(payload);
-            Ok(mac.verify_slice(v0).is_ok())
+            Ok(self
+                .v0
+                .iter()
+                .any(|code| mac.clone().verify_slice(code).is_ok()))
         } else {
-            // This cannot happen because this error would be raised while parsing the signature
+            // A signature that only has a v2 field cannot be verified with a secret
             trace!("Failed to decode signature: no v0 nor v1 field");
             Ok(false)
         }
     }
+
+    /// Whether the `v2` part was produced over that payload by one of those keys. It covers the
+    /// same message as `v1`.
+    ///
+    /// A signature naming a key they do not hold is refused as
+    /// [`Hook0ClientError::UnknownSigningKey`], so that the keys can be fetched again.
+    pub fn verify_with_public_keys(
+        &self,
+        payload: &[u8],
+        ordered_header_values: &[String],
+        keys: &PublicKeys,
+    ) -> Result<bool, Hook0ClientError> {
+        let (Some(k), Some(v2)) = (self.k.as_deref(), self.v2.as_deref()) else {
+            trace!("Failed to decode signature: no v2 field");
+            return Ok(false);
+        };
+        let key = keys
+            .0
+            .get(k)
+            .ok_or_else(|| Hook0ClientError::UnknownSigningKey(k.to_owned()))?;
+        let Ok(signature) = Ed25519Signature::from_slice(v2) else {
+            return Ok(false);
+        };
+
+        let message = [
+            self.timestamp.to_string().as_bytes(),
+            Self::PAYLOAD_SEPARATOR_BYTES,
+            self.h
+                .join(Self::SIGNATURE_PART_HEADER_NAMES_SEPARATOR)
+                .as_bytes(),
+            Self::PAYLOAD_SEPARATOR_BYTES,
+            ordered_header_values
+                .join(Self::PAYLOAD_SEPARATOR)
+                .as_bytes(),
+            Self::PAYLOAD_SEPARATOR_BYTES,
+            payload,
+        ]
+        .concat();
+
+        trace!("Verifying v2 signature...");
+        Ok(key.verify_strict(&message, &signature).is_ok())
+    }
+}
+
+/// A signature of the Standard Webhooks specification, as its `webhook-id`, `webhook-timestamp` and
+/// `webhook-signature` headers carry it.
+pub struct StandardWebhooksSignature {
+    pub id: String,
+    pub timestamp: i64,
+    /// Every `v1` code the signature header carries; a header may carry several, for example while
+    /// a secret is rotated, and any one of them verifying is enough.
+    pub v1: Vec<Vec<u8>>,
+}
+
+impl StandardWebhooksSignature {
+    const PAYLOAD_SEPARATOR_BYTES: &'static [u8] = b".";
+    const SIGNATURE_SEPARATOR: char = ' ';
+    const VERSION_SEPARATOR: char = ',';
+    const V1: &'static str = "v1";
+    const SECRET_PREFIX: &'static str = "whsec_";
+
+    /// Reads the values of the three headers. Codes of versions other than `v1` are passed over.
+    pub fn parse(id: &str, timestamp: &str, signature: &str) -> Result<Self, Hook0ClientError> {
+        let timestamp = i64::from_str(timestamp.trim()).map_err(|error| {
+            Hook0ClientError::TimestampParsing {
+                timestamp: timestamp.to_owned(),
+                error,
+            }
+        })?;
+
+        let v1 = signature
+            .split(Self::SIGNATURE_SEPARATOR)
+            .filter_map(|entry| entry.split_once(Self::VERSION_SEPARATOR))
+            .filter(|(version, _)| *version == Self::V1)
+            .map(|(_, code)| {
+                Base64.decode(code).map_err(|error| {
+                    Hook0ClientError::SignatureHeaderParsing(format!(
+                        "Could not decode v1 signature `{code}`: {error}"
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if v1.is_empty() {
+            Err(Hook0ClientError::SignatureHeaderParsing(
+                "There must be at least one 'v1' signature".to_owned(),
+            ))
+        } else {
+            Ok(Self {
+                id: id.to_owned(),
+                timestamp,
+                v1,
+            })
+        }
+    }
+
+    /// Whether one of the codes was produced over that payload with that secret.
+    ///
+    /// The secret is either the subscription secret as Hook0 gives it, or the same secret in the
+    /// `whsec_` form of the specification: `whsec_` followed by its base64 encoding.
+    pub fn verify(&self, payload: &[u8], secret: &str) -> Result<bool, Hook0ClientError> {
+        let key = match secret.strip_prefix(Self::SECRET_PREFIX) {
+            Some(encoded) => Base64
+                .decode(encoded)
+                .map_err(|_| Hook0ClientError::InvalidSignature)?,
+            None => secret.as_bytes().to_vec(),
+        };
+
+        type HmacSha256 = Hmac<Sha256>;
+        let mut mac =
+            HmacSha256::new_from_slice(&key).map_err(|_| Hook0ClientError::InvalidSignature)?;
+        mac.update(self.id.as_bytes());
+        mac.update(Self::PAYLOAD_SEPARATOR_BYTES);
+        mac.update(self.timestamp.to_string().as_bytes());
+        mac.update(Self::PAYLOAD_SEPARATOR_BYTES);
+        mac.update(payload);
+
+        trace!("Verifying Standard Webhooks signature...");
+        Ok(self
+            .v1
+            .iter()
+            .any(|code| mac.clone().verify_slice(code).is_ok()))
+    }
 }
@@ -838,8 +838,11 @@ mod sending {
 #[cfg(feature = "consumer")]
 mod verifying {
     use super::{corpus, entries, text};
-    use chrono::DateTime;
-    use hook0_client::{Hook0ClientError, verify_webhook_signature_with_current_time};
+    use chrono::{DateTime, Utc};
+    use hook0_client::{
+        Hook0ClientError, PublicKeys, verify_standard_webhooks_signature_with_current_time,
+        verify_webhook_signature_with_current_time,
+    };
     use serde_json::Value;
     use std::time::Duration;
 
@@ -847,35 +850,43 @@ mod verifying {
     /// declares is looked up here, so one added there stops this suite until it is mapped rather
     /// than passing under whatever the client happened to answer.
     ///
-    /// A code that is not hexadecimal and a code that does not match are both refused as
+    /// A code that cannot be decoded and a code that does not match are both refused as
     /// [`Hook0ClientError::InvalidSignature`]: this client tells its caller that the signature is
     /// invalid without saying which of the two it was, and the variants that would say so are never
     /// returned by the entry point.
     fn refused_as(refusal: &str, error: &Hook0ClientError) -> bool {
         match refusal {
-            "code_not_hexadecimal" | "code_mismatch" => {
+            "code_not_hexadecimal" | "code_not_base64" | "code_mismatch" => {
                 matches!(error, Hook0ClientError::InvalidSignature)
             }
             "header_not_delivered" => matches!(error, Hook0ClientError::MissingHeader(_)),
             "outside_tolerance" => matches!(error, Hook0ClientError::ExpiredWebhook { .. }),
+            "unknown_key" => matches!(error, Hook0ClientError::UnknownSigningKey(_)),
             _ => false,
         }
     }
 
     /// The refusals this suite knows how to read, which every one the corpus declares has to be
     /// among.
-    const MAPPED: [&str; 4] = [
+    const MAPPED: [&str; 6] = [
         "code_not_hexadecimal",
+        "code_not_base64",
         "code_mismatch",
         "header_not_delivered",
         "outside_tolerance",
+        "unknown_key",
     ];
 
     #[test]
     fn every_refusal_the_corpus_declares_reads_as_one_of_this_client_s() {
         let contract = corpus("signature.json");
 
-        for refusal in entries(&contract, "/refusals") {
+        for refusal in entries(&contract, "/refusals")
+            .into_iter()
+            .chain(entries(&contract, "/standard_webhooks/refusals"))
+            .chain(entries(&contract, "/public_keys/refusals"))
+            .chain(entries(&contract, "/secret_rotation/refusals"))
+        {
             let refusal = refusal
                 .as_str()
                 .expect("a refusal the corpus declares is a name")
@@ -887,14 +898,23 @@ mod verifying {
         }
     }
 
-    #[test]
-    fn every_delivery_of_the_corpus_is_verified_as_it_says() {
-        // A refused delivery has to be refused for the reason the corpus names: a client that
-        // computed a code over a header that never arrived and reported a mismatch would otherwise
-        // look right.
+    /// Verifies every delivery the corpus carries at `at` with `verify`, which is given the vector,
+    /// the headers it delivers, the moment it is held against and the tolerance it is held within.
+    ///
+    /// A refused delivery has to be refused for the reason the corpus names: a client that computed
+    /// a code over a header that never arrived and reported a mismatch would otherwise look right.
+    fn verify_every_delivery(
+        at: &str,
+        verify: impl Fn(
+            &Value,
+            &[(String, String)],
+            DateTime<Utc>,
+            Duration,
+        ) -> Result<(), Hook0ClientError>,
+    ) {
         let contract = corpus("signature.json");
 
-        for vector in entries(&contract, "/vectors") {
+        for vector in entries(&contract, at) {
             let name = text(&vector, "name");
             let delivered = vector
                 .get("headers")
@@ -926,14 +946,7 @@ mod verifying {
                     .unwrap_or_else(|| panic!("`{name}` is held within no readable tolerance")),
             );
 
-            let verdict = verify_webhook_signature_with_current_time(
-                text(&vector, "signature"),
-                text(&vector, "payload").as_bytes(),
-                &delivered,
-                text(&vector, "secret"),
-                tolerance,
-                current_time,
-            );
+            let verdict = verify(&vector, &delivered, current_time, tolerance);
             let reason = text(&vector, "reason");
 
             if text(&vector, "verdict") == "accepted" {
@@ -952,4 +965,77 @@ mod verifying {
             );
         }
     }
+
+    #[test]
+    fn every_delivery_of_the_corpus_is_verified_as_it_says() {
+        verify_every_delivery("/vectors", |vector, delivered, current_time, tolerance| {
+            verify_webhook_signature_with_current_time(
+                text(vector, "signature"),
+                text(vector, "payload").as_bytes(),
+                delivered,
+                text(vector, "secret"),
+                tolerance,
+                current_time,
+            )
+        });
+    }
+
+    #[test]
+    fn every_delivery_signed_during_a_secret_rotation_is_verified_as_it_says() {
+        verify_every_delivery(
+            "/secret_rotation/vectors",
+            |vector, delivered, current_time, tolerance| {
+                verify_webhook_signature_with_current_time(
+                    text(vector, "signature"),
+                    text(vector, "payload").as_bytes(),
+                    delivered,
+                    text(vector, "secret"),
+                    tolerance,
+                    current_time,
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn every_ed25519_delivery_of_the_corpus_is_verified_as_it_says() {
+        verify_every_delivery(
+            "/public_keys/vectors",
+            |vector, delivered, current_time, tolerance| {
+                // The secret of these vectors is the key set the application publishes
+                let keys = PublicKeys::from_jwks(text(vector, "secret").as_bytes())
+                    .expect("the corpus publishes readable public keys");
+                verify_webhook_signature_with_current_time(
+                    text(vector, "signature"),
+                    text(vector, "payload").as_bytes(),
+                    delivered,
+                    &keys,
+                    tolerance,
+                    current_time,
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn every_standard_webhooks_delivery_of_the_corpus_is_verified_as_it_says() {
+        verify_every_delivery(
+            "/standard_webhooks/vectors",
+            |vector, delivered, current_time, tolerance| {
+                // The signature arrives as one more header, beside the identifier and the moment
+                let mut delivered = delivered.to_vec();
+                delivered.push((
+                    "webhook-signature".to_owned(),
+                    text(vector, "signature").to_owned(),
+                ));
+                verify_standard_webhooks_signature_with_current_time(
+                    text(vector, "payload").as_bytes(),
+                    &delivered,
+                    text(vector, "secret"),
+                    tolerance,
+                    current_time,
+                )
+            },
+        );
+    }
 }
@@ -82,6 +82,27 @@ fn already_ingested() -> ScriptedResponse {
     )
 }
 
+/// The answer to a batch, one outcome per event: `None` for an event that was ingested, and the
+/// problem it was refused with otherwise.
+fn batch_outcomes(outcomes: &[Option<&str>]) -> ScriptedResponse {
+    ScriptedResponse::new(
+        200,
+        json!({
+            "application_id": Uuid::nil(),
+            "events": outcomes
+                .iter()
+                .map(|problem| match problem {
+                    None => json!({ "status": "ingested", "received_at": "2026-01-01T00:00:00Z" }),
+                    Some(id) => json!({
+                        "status": "failed",
+                        "problem": { "id": id, "title": id, "detail": "refused", "status": 400 },
+                    }),
+                })
+                .collect::<Vec<_>>(),
+        }),
+    )
+}
+
 #[derive(Clone)]
 struct ApiState {
     /// The body of every request that reached the API, in the order it reached it.
@@ -165,6 +186,7 @@ impl TestApi {
             App::new()
                 .app_data(web::Data::new(server_state.clone()))
                 .route("/event", web::post().to(ingest))
+                .route("/events/batch", web::post().to(ingest))
         })
         .listen(listener)
         .expect("the bound listener is usable as a server socket")
@@ -451,6 +473,88 @@ async fn a_client_that_does_not_retry_issues_a_single_request() {
     api.stop().await;
 }
 
+#[actix_web::test]
+async fn a_batch_reports_one_outcome_per_event_in_the_order_they_were_given() {
+    let chosen = Uuid::now_v7();
+    let api = TestApi::start(vec![batch_outcomes(&[
+        None,
+        Some("EventInvalidJsonPayload"),
+    ])]);
+
+    let outcomes = api
+        .client()
+        .send_events(&[
+            Event {
+                event_id: Some(&chosen),
+                ..an_event()
+            },
+            an_event(),
+        ])
+        .await
+        .expect("the API went through the batch");
+
+    let received = api.received();
+    assert_eq!(
+        received.len(),
+        1,
+        "expected exactly one request, got {received:?}"
+    );
+    let sent_ids = received[0]["events"]
+        .as_array()
+        .expect("the request carries the events")
+        .iter()
+        .map(|event| {
+            event["event_id"]
+                .as_str()
+                .and_then(|id| Uuid::parse_str(id).ok())
+                .expect("every event is sent under an ID the client knows")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sent_ids[0], chosen);
+    assert_eq!(outcomes[0], Ok(chosen));
+    match &outcomes[1] {
+        Err(refusal) => {
+            assert_eq!(refusal.event_id, sent_ids[1]);
+            assert_eq!(refusal.problem_id, "EventInvalidJsonPayload");
+        }
+        other => panic!("the second event was refused, got {other:?}"),
+    }
+
+    api.stop().await;
+}
+
+#[actix_web::test]
+async fn a_retried_batch_reports_the_events_an_earlier_attempt_ingested() {
+    let api = TestApi::start(vec![
+        unscripted(),
+        batch_outcomes(&[Some("EventAlreadyIngested"), None]),
+    ]);
+
+    let outcomes = api
+        .client()
+        .with_retry_policy(prompt_retries(3))
+        .send_events(&[an_event(), an_event()])
+        .await
+        .expect("the retry went through the batch");
+
+    let received = api.received();
+    assert_eq!(
+        received.len(),
+        2,
+        "expected the failed attempt and its retry, got {received:?}"
+    );
+    assert_eq!(
+        received[0]["events"], received[1]["events"],
+        "the retry carries the same events under the same IDs"
+    );
+    assert!(
+        outcomes.iter().all(Result::is_ok),
+        "an event taken by the attempt this one repeats was ingested by this send, got {outcomes:?}"
+    );
+
+    api.stop().await;
+}
+
 #[actix_web::test]
 async fn a_payload_above_the_maximum_is_refused_before_any_request_is_issued() {
     const MAXIMUM: usize = 16;
@@ -546,8 +650,11 @@ async fn labels_read_off_an_event_are_carried_into_the_next_one() {
 
     let posted = generated::EventPost {
         application_id: Uuid::nil(),
+        deliver_at: read.deliver_at,
         event_id: None,
         event_type: read.event_type_name.clone(),
+        event_type_version: read.event_type_version,
+        expires_at: read.expires_at,
         labels: carried.clone(),
         metadata: None,
         occurred_at: read.occurred_at,
@@ -31,6 +31,14 @@ use url::Url;
 /// leave as they are, so a value reaching a path proves it was escaped rather than pasted.
 const A_STRING: &str = "a value/with a space";
 
+/// What every integer-shaped argument is given, and so what a query parameter the document types as
+/// an integer has to carry.
+const A_NUMBER: &str = "1";
+
+/// What every boolean-shaped argument is given, and so what a query parameter the document types as
+/// a boolean has to carry.
+const A_BOOLEAN: &str = "true";
+
 /// No repository nests this crate deeper than this below its root; the bound turns a checkout that
 /// is missing the API document into a failure rather than a walk up to the root of the filesystem.
 const MAX_ANCESTORS: usize = 8;
@@ -72,6 +80,10 @@ struct Declared {
     template: String,
     required_query: BTreeSet<String>,
     optional_query: BTreeSet<String>,
+    /// The query parameters the document types as integers rather than strings.
+    numeric_query: BTreeSet<String>,
+    /// The query parameters the document types as booleans rather than strings.
+    boolean_query: BTreeSet<String>,
     /// The schema a success carries, absent when the operation answers no content at all.
     answers: Option<Value>,
     /// The schema the body of the request carries, absent when the operation reads no body.
@@ -190,6 +202,8 @@ fn declared_operations(document: &Value) -> BTreeMap<String, Declared> {
 
             let mut required_query = BTreeSet::new();
             let mut optional_query = BTreeSet::new();
+            let mut numeric_query = BTreeSet::new();
+            let mut boolean_query = BTreeSet::new();
             if let Some(parameters) = operation.get("parameters").and_then(Value::as_array) {
                 for parameter in parameters {
                     if parameter.get("in").and_then(Value::as_str) != Some("query") {
@@ -198,6 +212,15 @@ fn declared_operations(document: &Value) -> BTreeMap<String, Declared> {
                     let Some(name) = parameter.get("name").and_then(Value::as_str) else {
                         continue;
                     };
+                    match parameter.pointer("/schema/type").and_then(Value::as_str) {
+                        Some("integer") => {
+                            numeric_query.insert(name.to_owned());
+                        }
+                        Some("boolean") => {
+                            boolean_query.insert(name.to_owned());
+                        }
+                        _ => {}
+                    }
                     if parameter.get("required") == Some(&Value::Bool(true)) {
                         required_query.insert(name.to_owned());
                     } else {
@@ -227,6 +250,8 @@ fn declared_operations(document: &Value) -> BTreeMap<String, Declared> {
                 template: (*template).to_owned(),
                 required_query,
                 optional_query,
+                numeric_query,
+                boolean_query,
                 answers,
                 reads,
             };
@@ -569,7 +594,14 @@ impl Walk {
         let optionals = matches!(mode, Mode::ReadsBack { optionals: true });
         let carried: BTreeSet<String> = url::form_urlencoded::parse(request.query.as_bytes())
             .map(|(name, value)| {
-                assert_eq!(value, A_STRING, "`{id}` carried `{name}` altered");
+                let given = if declared.numeric_query.contains(name.as_ref()) {
+                    A_NUMBER
+                } else if declared.boolean_query.contains(name.as_ref()) {
+                    A_BOOLEAN
+                } else {
+                    A_STRING
+                };
+                assert_eq!(value, given, "`{id}` carried `{name}` altered");
                 name.into_owned()
             })
             .collect();
@@ -677,16 +709,23 @@ async fn every_operation_the_document_declares_is_reached_the_way_it_declares_it
 
     let application_secrets = generated::ApplicationSecretsApi::new(transport.clone());
     let applications = generated::ApplicationsApi::new(transport.clone());
+    let bulk_retries = generated::BulkRetriesApi::new(transport.clone());
+    let client_certificates = generated::ClientCertificatesApi::new(transport.clone());
+    let dead_letters = generated::DeadLettersApi::new(transport.clone());
     let errors = generated::ErrorsApi::new(transport.clone());
     let event_types = generated::EventTypesApi::new(transport.clone());
     let events = generated::EventsApi::new(transport.clone());
     let events_per_day = generated::EventsPerDayApi::new(transport.clone());
+    let inbox = generated::InboxApi::new(transport.clone());
     let instance = generated::InstanceApi::new(transport.clone());
+    let oauth2_client_credentials = generated::Oauth2ClientCredentialsApi::new(transport.clone());
     let payload_content_types = generated::PayloadContentTypesApi::new(transport.clone());
     let quotas = generated::QuotasApi::new(transport.clone());
     let request_attempts = generated::RequestAttemptsApi::new(transport.clone());
     let response = generated::ResponseApi::new(transport.clone());
+    let secret_rotations = generated::SecretRotationsApi::new(transport.clone());
     let service_token = generated::ServiceTokenApi::new(transport.clone());
+    let signing_keys = generated::SigningKeysApi::new(transport.clone());
     let subscriptions = generated::SubscriptionsApi::new(transport);
 
     for mode in [
@@ -697,6 +736,9 @@ async fn every_operation_the_document_declares_is_reached_the_way_it_declares_it
         Mode::Unreachable,
     ] {
         walk.under(mode);
+        let optionals = matches!(mode, Mode::ReadsBack { optionals: true });
+        let named = if optionals { Some(A_STRING) } else { None };
+        let flag = if optionals { Some(true) } else { None };
 
         reach!(
             walk,
@@ -733,6 +775,75 @@ async fn every_operation_the_document_declares_is_reached_the_way_it_declares_it
             applications.update(A_STRING, walk.body("applications.update"))
         );
 
+        reach!(
+            walk,
+            "bulkRetries.cancel",
+            bulk_retries.cancel(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "bulkRetries.create",
+            bulk_retries.create(walk.body("bulkRetries.create"))
+        );
+        reach!(
+            walk,
+            "bulkRetries.dry_run",
+            bulk_retries.dry_run(walk.body("bulkRetries.dry_run"))
+        );
+        reach!(
+            walk,
+            "bulkRetries.get",
+            bulk_retries.get(A_STRING, A_STRING)
+        );
+        reach!(walk, "bulkRetries.list", bulk_retries.list(A_STRING));
+
+        reach!(
+            walk,
+            "clientCertificates.delete",
+            client_certificates.delete(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "clientCertificates.get",
+            client_certificates.get(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "clientCertificates.update",
+            client_certificates.update(A_STRING, A_STRING, walk.body("clientCertificates.update"))
+        );
+
+        reach!(
+            walk,
+            "deadLetters.delete",
+            dead_letters.delete(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "deadLetters.get",
+            dead_letters.get(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "deadLetters.list",
+            dead_letters.list(A_STRING, named, named)
+        );
+        reach!(
+            walk,
+            "deadLetters.purge",
+            dead_letters.purge(walk.body("deadLetters.purge"))
+        );
+        reach!(
+            walk,
+            "deadLetters.redeliver",
+            dead_letters.redeliver(A_STRING, walk.body("deadLetters.redeliver"))
+        );
+        reach!(
+            walk,
+            "deadLetters.redeliverMany",
+            dead_letters.redeliver_many(walk.body("deadLetters.redeliverMany"))
+        );
+
         reach!(walk, "errors.list", errors.list());
 
         reach!(
@@ -740,30 +851,52 @@ async fn every_operation_the_document_declares_is_reached_the_way_it_declares_it
             "eventTypes.create",
             event_types.create(walk.body("eventTypes.create"))
         );
+        reach!(
+            walk,
+            "eventTypes.createVersion",
+            event_types.create_version(A_STRING, walk.body("eventTypes.createVersion"))
+        );
         reach!(
             walk,
             "eventTypes.delete",
             event_types.delete(A_STRING, A_STRING)
         );
+        reach!(
+            walk,
+            "eventTypes.deprecateVersion",
+            event_types.deprecate_version(A_STRING, walk.body("eventTypes.deprecateVersion"))
+        );
         reach!(walk, "eventTypes.get", event_types.get(A_STRING, A_STRING));
         reach!(walk, "eventTypes.list", event_types.list(A_STRING));
+        reach!(
+            walk,
+            "eventTypes.update",
+            event_types.update(A_STRING, walk.body("eventTypes.update"))
+        );
 
+        reach!(
+            walk,
+            "events.cancel",
+            events.cancel(A_STRING, walk.body("events.cancel"))
+        );
         reach!(walk, "events.get", events.get(A_STRING, A_STRING));
         reach!(
             walk,
             "events.ingest",
             events.ingest(walk.body("events.ingest"))
         );
-        reach!(walk, "events.list", events.list(A_STRING));
+        reach!(
+            walk,
+            "events.ingestBatch",
+            events.ingest_batch(walk.body("events.ingestBatch"))
+        );
+        reach!(walk, "events.list", events.list(A_STRING, flag));
         reach!(
             walk,
             "events.replay",
             events.replay(A_STRING, walk.body("events.replay"))
         );
 
-        let optionals = matches!(mode, Mode::ReadsBack { optionals: true });
-        let named = if optionals { Some(A_STRING) } else { None };
-
         reach!(
             walk,
             "events_per_day.list_for_application",
@@ -775,7 +908,44 @@ async fn every_operation_the_document_declares_is_reached_the_way_it_declares_it
             events_per_day.list_for_organization(A_STRING, named, named)
         );
 
+        let number = if optionals { Some(1) } else { None };
+        reach!(
+            walk,
+            "inbox.ack",
+            inbox.ack(A_STRING, walk.body("inbox.ack"))
+        );
+        reach!(
+            walk,
+            "inbox.nack",
+            inbox.nack(A_STRING, walk.body("inbox.nack"))
+        );
+        reach!(
+            walk,
+            "inbox.pull",
+            inbox.pull(A_STRING, A_STRING, named, number, number)
+        );
+
         reach!(walk, "instance.get", instance.get());
+
+        reach!(
+            walk,
+            "oauth2ClientCredentials.delete",
+            oauth2_client_credentials.delete(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "oauth2ClientCredentials.get",
+            oauth2_client_credentials.get(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "oauth2ClientCredentials.update",
+            oauth2_client_credentials.update(
+                A_STRING,
+                A_STRING,
+                walk.body("oauth2ClientCredentials.update")
+            )
+        );
         reach!(
             walk,
             "payload_content_types.list",
@@ -793,9 +963,35 @@ async fn every_operation_the_document_declares_is_reached_the_way_it_declares_it
             "requestAttempts.read",
             request_attempts.read(A_STRING, named, named, named, named, named, named)
         );
+        reach!(
+            walk,
+            "requestAttempts.retry",
+            request_attempts.retry(A_STRING, walk.body("requestAttempts.retry"))
+        );
 
         reach!(walk, "response.get", response.get(A_STRING, A_STRING));
 
+        reach!(
+            walk,
+            "secretRotations.create",
+            secret_rotations.create(A_STRING, A_STRING, walk.body("secretRotations.create"))
+        );
+        reach!(
+            walk,
+            "secretRotations.finalize",
+            secret_rotations.finalize(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "secretRotations.get",
+            secret_rotations.get(A_STRING, A_STRING)
+        );
+        reach!(
+            walk,
+            "secretRotations.rollback",
+            secret_rotations.rollback(A_STRING, A_STRING)
+        );
+
         reach!(
             walk,
             "serviceToken.create",
@@ -818,6 +1014,19 @@ async fn every_operation_the_document_declares_is_reached_the_way_it_declares_it
             service_token.edit(A_STRING, walk.body("serviceToken.edit"))
         );
 
+        reach!(
+            walk,
+            "signingKeys.create",
+            signing_keys.create(walk.body("signingKeys.create"))
+        );
+        reach!(
+            walk,
+            "signingKeys.delete",
+            signing_keys.delete(A_STRING, A_STRING)
+        );
+        reach!(walk, "signingKeys.jwks", signing_keys.jwks(A_STRING));
+        reach!(walk, "signingKeys.list", signing_keys.list(A_STRING));
+
         reach!(
             walk,
             "subscriptions.create",
@@ -1092,12 +1301,28 @@ closed_lists!(
     generated::ApplicationInfoOnboardingStepsEvent,
     generated::ApplicationInfoOnboardingStepsEventType,
     generated::ApplicationInfoOnboardingStepsSubscription,
+    generated::BulkRetryPostStatus,
+    generated::BulkRetryStatus,
+    generated::DeadLetterDetailsDeadLetterReason,
+    generated::DeadLetterReason,
+    generated::EventTypePayloadSchemaMode,
+    generated::EventTypePostPayloadSchemaMode,
+    generated::EventTypePutPayloadSchemaMode,
+    generated::IngestedEventsBatchEventsProblemId,
+    generated::IngestedEventsBatchEventsStatus,
     generated::OrganizationInfoOnboardingStepsApplication,
     generated::OrganizationInfoOnboardingStepsEvent,
     generated::OrganizationInfoOnboardingStepsEventType,
     generated::OrganizationInfoOnboardingStepsSubscription,
     generated::ProblemId,
+    generated::RequestAttemptStatusRuleOutcome,
     generated::RequestAttemptStatusType,
+    generated::SubscriptionPostResponseRulesOutcome,
+    generated::SubscriptionPostSignatureScheme,
+    generated::SubscriptionPostTargetType,
+    generated::SubscriptionResponseRulesOutcome,
+    generated::SubscriptionSignatureScheme,
+    generated::SubscriptionTargetType,
 );
 
 /// One closed list, driven against every value the document declares it carries.
@@ -43,6 +43,18 @@ const DOCUMENTS: [&str; 4] = [
     REQUEST_DOCUMENT,
 ];
 
+/// Member of the signature document holding the deliveries signed the way the Standard Webhooks
+/// specification signs them, with refusals of their own.
+const STANDARD_WEBHOOKS: &str = "standard_webhooks";
+
+/// Member of the signature document holding the deliveries signed with the Ed25519 key of an
+/// application, verified with its public keys, with refusals of their own.
+const PUBLIC_KEYS: &str = "public_keys";
+
+/// Member of the signature document holding the deliveries signed while the secret of their
+/// subscription is rotated, which carry a code per secret, with refusals of their own.
+const SECRET_ROTATION: &str = "secret_rotation";
+
 /// Verdict a vector carrying a delivery that verifies is written under.
 const ACCEPTED: &str = "accepted";
 
@@ -205,6 +217,20 @@ pub struct Corpus {
     /// The names a refused vector may be refused under, as the corpus declares them.
     pub refusals: Vec<String>,
     pub vectors: Vec<Vector>,
+    /// The names a refused Standard Webhooks vector may be refused under.
+    pub standard_webhooks_refusals: Vec<String>,
+    /// Deliveries signed with the Standard Webhooks scheme, whose `signature` is the value of the
+    /// `webhook-signature` header.
+    pub standard_webhooks_vectors: Vec<Vector>,
+    /// The names a refused Ed25519 vector may be refused under.
+    pub public_keys_refusals: Vec<String>,
+    /// Deliveries signed with the Ed25519 key of an application, whose `secret` is the JSON Web Key
+    /// Set of its public keys.
+    pub public_keys_vectors: Vec<Vector>,
+    /// The names a refused vector signed during a secret rotation may be refused under.
+    pub secret_rotation_refusals: Vec<String>,
+    /// Deliveries signed during a secret rotation, whose signature carries a code per secret.
+    pub secret_rotation_vectors: Vec<Vector>,
     pub request: RequestFormat,
 }
 
@@ -224,6 +250,9 @@ impl Corpus {
         let bounds = document(directory, BOUNDS_DOCUMENT, limits)?;
         let signature = document(directory, SIGNATURE_DOCUMENT, limits)?;
         let request = document(directory, REQUEST_DOCUMENT, limits)?;
+        let standard_webhooks = member(SIGNATURE_DOCUMENT, &signature, STANDARD_WEBHOOKS)?;
+        let public_keys = member(SIGNATURE_DOCUMENT, &signature, PUBLIC_KEYS)?;
+        let secret_rotation = member(SIGNATURE_DOCUMENT, &signature, SECRET_ROTATION)?;
 
         let corpus = Self {
             transport: transport_rules(RETRY_DOCUMENT, &retry, limits)?,
@@ -233,6 +262,12 @@ impl Corpus {
             bounds: bounds_of(BOUNDS_DOCUMENT, &bounds)?,
             refusals: refusals(SIGNATURE_DOCUMENT, &signature, limits)?,
             vectors: vectors(SIGNATURE_DOCUMENT, &signature, limits)?,
+            standard_webhooks_refusals: refusals(SIGNATURE_DOCUMENT, standard_webhooks, limits)?,
+            standard_webhooks_vectors: vectors(SIGNATURE_DOCUMENT, standard_webhooks, limits)?,
+            public_keys_refusals: refusals(SIGNATURE_DOCUMENT, public_keys, limits)?,
+            public_keys_vectors: vectors(SIGNATURE_DOCUMENT, public_keys, limits)?,
+            secret_rotation_refusals: refusals(SIGNATURE_DOCUMENT, secret_rotation, limits)?,
+            secret_rotation_vectors: vectors(SIGNATURE_DOCUMENT, secret_rotation, limits)?,
             request: request_format(REQUEST_DOCUMENT, &request, limits)?,
         };
         corpus.is_consistent()?;
@@ -329,35 +364,16 @@ impl Corpus {
             });
         }
 
-        let declared: BTreeSet<&str> = self.refusals.iter().map(String::as_str).collect();
-        let exercised: BTreeSet<&str> = self
-            .vectors
-            .iter()
-            .filter_map(|vector| vector.refusal.as_deref())
-            .collect();
-
-        if let Some(unknown) = exercised.difference(&declared).next() {
-            let vector = self
-                .vectors
-                .iter()
-                .find(|vector| vector.refusal.as_deref() == Some(*unknown))
-                .map(|vector| vector.name.clone())
-                .unwrap_or_default();
-            return Err(ConformanceError::UnknownRefusal {
-                vector,
-                refusal: (*unknown).to_owned(),
-            });
-        }
-
-        if !self.vectors.iter().any(Vector::verifies) {
-            return Err(ConformanceError::NothingVerifies);
-        }
-
-        if let Some(unexercised) = declared.difference(&exercised).next() {
-            return Err(ConformanceError::UnexercisedRefusal {
-                refusal: (*unexercised).to_owned(),
-            });
-        }
+        vectors_are_consistent(&self.refusals, &self.vectors)?;
+        vectors_are_consistent(
+            &self.standard_webhooks_refusals,
+            &self.standard_webhooks_vectors,
+        )?;
+        vectors_are_consistent(&self.public_keys_refusals, &self.public_keys_vectors)?;
+        vectors_are_consistent(
+            &self.secret_rotation_refusals,
+            &self.secret_rotation_vectors,
+        )?;
 
         let occasions: BTreeSet<&str> = self.request.occasions.iter().map(String::as_str).collect();
         let carried: BTreeSet<&str> = self
@@ -915,6 +931,40 @@ fn bounds_of(document: &str, holder: &Value) -> Result<Bounds, ConformanceError>
     })
 }
 
+/// Whether the vectors of one scheme are refused under the names it declares, exercise every one of
+/// them, and accept at least one delivery.
+fn vectors_are_consistent(refusals: &[String], vectors: &[Vector]) -> Result<(), ConformanceError> {
+    let declared: BTreeSet<&str> = refusals.iter().map(String::as_str).collect();
+    let exercised: BTreeSet<&str> = vectors
+        .iter()
+        .filter_map(|vector| vector.refusal.as_deref())
+        .collect();
+
+    if let Some(unknown) = exercised.difference(&declared).next() {
+        let vector = vectors
+            .iter()
+            .find(|vector| vector.refusal.as_deref() == Some(*unknown))
+            .map(|vector| vector.name.clone())
+            .unwrap_or_default();
+        return Err(ConformanceError::UnknownRefusal {
+            vector,
+            refusal: (*unknown).to_owned(),
+        });
+    }
+
+    if !vectors.iter().any(Vector::verifies) {
+        return Err(ConformanceError::NothingVerifies);
+    }
+
+    if let Some(unexercised) = declared.difference(&exercised).next() {
+        return Err(ConformanceError::UnexercisedRefusal {
+            refusal: (*unexercised).to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
 fn refusals(
     document: &str,
     holder: &Value,
@@ -482,6 +482,93 @@ fn a_corpus_where_nothing_verifies_is_refused() {
     );
 }
 
+/// The Standard Webhooks deliveries are held to their own refusals: one refused under a name only
+/// the other scheme declares is refused as well.
+#[test]
+fn a_standard_webhooks_vector_refused_under_a_name_it_does_not_declare_is_refused() {
+    let mut documents = committed();
+    let vectors = documents
+        .get_mut("signature.json")
+        .and_then(|document| document.pointer_mut("/standard_webhooks/vectors"))
+        .and_then(Value::as_array_mut)
+        .expect("the corpus carries Standard Webhooks deliveries");
+    let refused = vectors
+        .iter_mut()
+        .find(|vector| vector["verdict"] == json!("refused"))
+        .expect("the corpus refuses at least one Standard Webhooks delivery");
+    let name = refused["name"]
+        .as_str()
+        .expect("every vector is named")
+        .to_owned();
+    refused["refusal"] = json!("code_not_hexadecimal");
+
+    assert_eq!(
+        read(&laid_out(&documents)).expect_err("an undeclared refusal is refused"),
+        ConformanceError::UnknownRefusal {
+            vector: name,
+            refusal: "code_not_hexadecimal".to_owned(),
+        }
+    );
+}
+
+/// The Ed25519 deliveries are held to their own refusals too, which a refusal of the HMAC schemes
+/// alone is not one of.
+#[test]
+fn a_public_keys_vector_refused_under_a_name_it_does_not_declare_is_refused() {
+    let mut documents = committed();
+    let vectors = documents
+        .get_mut("signature.json")
+        .and_then(|document| document.pointer_mut("/public_keys/vectors"))
+        .and_then(Value::as_array_mut)
+        .expect("the corpus carries Ed25519 deliveries");
+    let refused = vectors
+        .iter_mut()
+        .find(|vector| vector["verdict"] == json!("refused"))
+        .expect("the corpus refuses at least one Ed25519 delivery");
+    let name = refused["name"]
+        .as_str()
+        .expect("every vector is named")
+        .to_owned();
+    refused["refusal"] = json!("code_not_base64");
+
+    assert_eq!(
+        read(&laid_out(&documents)).expect_err("an undeclared refusal is refused"),
+        ConformanceError::UnknownRefusal {
+            vector: name,
+            refusal: "code_not_base64".to_owned(),
+        }
+    );
+}
+
+/// The deliveries signed during a secret rotation are held to their own refusals, which do not
+/// include a header that was not delivered.
+#[test]
+fn a_secret_rotation_vector_refused_under_a_name_it_does_not_declare_is_refused() {
+    let mut documents = committed();
+    let vectors = documents
+        .get_mut("signature.json")
+        .and_then(|document| document.pointer_mut("/secret_rotation/vectors"))
+        .and_then(Value::as_array_mut)
+        .expect("the corpus carries deliveries signed during a secret rotation");
+    let refused = vectors
+        .iter_mut()
+        .find(|vector| vector["verdict"] == json!("refused"))
+        .expect("the corpus refuses at least one delivery signed during a secret rotation");
+    let name = refused["name"]
+        .as_str()
+        .expect("every vector is named")
+        .to_owned();
+    refused["refusal"] = json!("header_not_delivered");
+
+    assert_eq!(
+        read(&laid_out(&documents)).expect_err("an undeclared refusal is refused"),
+        ConformanceError::UnknownRefusal {
+            vector: name,
+            refusal: "header_not_delivered".to_owned(),
+        }
+    );
+}
+
 /// A refusal the corpus declares and no vector exercises is a name every target would have to map
 /// and none would ever meet.
 #[test]
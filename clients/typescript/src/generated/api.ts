@@ -13,21 +13,52 @@ import {
   ApplicationPost,
   ApplicationSecret,
   ApplicationSecretPost,
+  BulkRetry,
+  BulkRetryDryRun,
+  BulkRetryPost,
+  CancelEvent,
+  ClientCertificate,
+  ClientCertificatePut,
+  DeadLetter,
+  DeadLetterDetails,
   Event,
   EventPost,
   EventType,
   EventTypePost,
+  EventTypePut,
+  EventTypeVersion,
+  EventTypeVersionDeprecate,
+  EventTypeVersionPost,
   EventWithPayload,
+  EventsBatchPost,
   EventsPerDayEntry,
+  Inbox,
+  InboxAck,
+  InboxNack,
+  InboxSettlement,
   IngestedEvent,
+  IngestedEventsBatch,
   InstanceConfig,
+  JsonWebKeySet,
+  Oauth2ClientCredentials,
+  Oauth2ClientCredentialsPut,
   Problem,
+  PurgeDeadLetters,
+  PurgedDeadLetters,
   QuotasResponse,
+  RedeliverDeadLetter,
+  RedeliverDeadLetters,
+  RedeliveredDeadLetter,
   ReplayEvent,
   RequestAttempt,
   Response,
+  RetryRequestAttempt,
+  SecretRotation,
+  SecretRotationPost,
   ServiceToken,
   ServiceTokenPost,
+  SigningKey,
+  SigningKeyPost,
   Subscription,
   SubscriptionPost,
 } from './models';
@@ -269,6 +300,312 @@ export class ApplicationsApi {
   }
 }
 
+/**
+ * What the API declares under `bulkRetries`.
+ *
+ * Every method of it is issued through the transport it is handed.
+ */
+export class BulkRetriesApi {
+  private readonly transport: Transport;
+
+  /** Reaches what the API declares under `bulkRetries`. */
+  constructor(transport: Transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * `bulkRetries.cancel`, `POST /api/v1/bulk_retries/{bulk_retry_id}/cancel`.
+   *
+   * Cancel a bulk retry
+   */
+  async cancel(bulkRetryId: string, applicationId: string): Promise<BulkRetry> {
+    let path = '/api/v1/bulk_retries/{bulk_retry_id}/cancel';
+    path = path.replace('{bulk_retry_id}', pathSegment(bulkRetryId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<BulkRetry>(issued.status, issued.payload);
+  }
+
+  /**
+   * `bulkRetries.create`, `POST /api/v1/bulk_retries/`.
+   *
+   * Create a bulk retry
+   */
+  async create(body: BulkRetryPost): Promise<BulkRetry> {
+    const path = '/api/v1/bulk_retries/';
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<BulkRetry>(issued.status, issued.payload);
+  }
+
+  /**
+   * `bulkRetries.dry_run`, `POST /api/v1/bulk_retries/dry_run`.
+   *
+   * Count what a bulk retry would redeliver
+   */
+  async dryRun(body: BulkRetryPost): Promise<BulkRetryDryRun> {
+    const path = '/api/v1/bulk_retries/dry_run';
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<BulkRetryDryRun>(issued.status, issued.payload);
+  }
+
+  /**
+   * `bulkRetries.get`, `GET /api/v1/bulk_retries/{bulk_retry_id}`.
+   *
+   * Get a bulk retry
+   */
+  async get(bulkRetryId: string, applicationId: string): Promise<BulkRetry> {
+    let path = '/api/v1/bulk_retries/{bulk_retry_id}';
+    path = path.replace('{bulk_retry_id}', pathSegment(bulkRetryId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<BulkRetry>(issued.status, issued.payload);
+  }
+
+  /**
+   * `bulkRetries.list`, `GET /api/v1/bulk_retries/`.
+   *
+   * List bulk retries
+   */
+  async list(applicationId: string): Promise<BulkRetry[]> {
+    const path = '/api/v1/bulk_retries/';
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<BulkRetry[]>(issued.status, issued.payload);
+  }
+}
+
+/**
+ * What the API declares under `clientCertificates`.
+ *
+ * Every method of it is issued through the transport it is handed.
+ */
+export class ClientCertificatesApi {
+  private readonly transport: Transport;
+
+  /** Reaches what the API declares under `clientCertificates`. */
+  constructor(transport: Transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * `clientCertificates.delete`, `DELETE /api/v1/subscriptions/{subscription_id}/client_certificate`.
+   *
+   * Remove the client certificate of a subscription
+   */
+  async delete(subscriptionId: string, applicationId: string): Promise<void> {
+    let path = '/api/v1/subscriptions/{subscription_id}/client_certificate';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'DELETE',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+  }
+
+  /**
+   * `clientCertificates.get`, `GET /api/v1/subscriptions/{subscription_id}/client_certificate`.
+   *
+   * Get the client certificate of a subscription
+   */
+  async get(subscriptionId: string, applicationId: string): Promise<ClientCertificate> {
+    let path = '/api/v1/subscriptions/{subscription_id}/client_certificate';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<ClientCertificate>(issued.status, issued.payload);
+  }
+
+  /**
+   * `clientCertificates.update`, `PUT /api/v1/subscriptions/{subscription_id}/client_certificate`.
+   *
+   * Set the client certificate of a subscription
+   */
+  async update(
+    subscriptionId: string,
+    applicationId: string,
+    body: ClientCertificatePut
+  ): Promise<ClientCertificate> {
+    let path = '/api/v1/subscriptions/{subscription_id}/client_certificate';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'PUT',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<ClientCertificate>(issued.status, issued.payload);
+  }
+}
+
+/**
+ * What the API declares under `deadLetters`.
+ *
+ * Every method of it is issued through the transport it is handed.
+ */
+export class DeadLettersApi {
+  private readonly transport: Transport;
+
+  /** Reaches what the API declares under `deadLetters`. */
+  constructor(transport: Transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * `deadLetters.delete`, `DELETE /api/v1/dead_letters/{dead_letter_id}`.
+   *
+   * Delete a dead letter
+   */
+  async delete(deadLetterId: string, applicationId: string): Promise<void> {
+    let path = '/api/v1/dead_letters/{dead_letter_id}';
+    path = path.replace('{dead_letter_id}', pathSegment(deadLetterId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'DELETE',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+  }
+
+  /**
+   * `deadLetters.get`, `GET /api/v1/dead_letters/{dead_letter_id}`.
+   *
+   * Get a dead letter by its ID
+   */
+  async get(deadLetterId: string, applicationId: string): Promise<DeadLetterDetails> {
+    let path = '/api/v1/dead_letters/{dead_letter_id}';
+    path = path.replace('{dead_letter_id}', pathSegment(deadLetterId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<DeadLetterDetails>(issued.status, issued.payload);
+  }
+
+  /**
+   * `deadLetters.list`, `GET /api/v1/dead_letters/`.
+   *
+   * List dead letters
+   */
+  async list(
+    applicationId: string,
+    paginationCursor?: string,
+    subscriptionId?: string
+  ): Promise<DeadLetter[]> {
+    const path = '/api/v1/dead_letters/';
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    if (paginationCursor !== undefined) {
+      query.push(['pagination_cursor', queryValue(paginationCursor)]);
+    }
+    if (subscriptionId !== undefined) {
+      query.push(['subscription_id', queryValue(subscriptionId)]);
+    }
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<DeadLetter[]>(issued.status, issued.payload);
+  }
+
+  /**
+   * `deadLetters.purge`, `POST /api/v1/dead_letters/purge`.
+   *
+   * Purge dead letters
+   */
+  async purge(body: PurgeDeadLetters): Promise<PurgedDeadLetters> {
+    const path = '/api/v1/dead_letters/purge';
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<PurgedDeadLetters>(issued.status, issued.payload);
+  }
+
+  /**
+   * `deadLetters.redeliver`, `POST /api/v1/dead_letters/{dead_letter_id}/redeliver`.
+   *
+   * Redeliver a dead letter
+   */
+  async redeliver(deadLetterId: string, body: RedeliverDeadLetter): Promise<RedeliveredDeadLetter> {
+    let path = '/api/v1/dead_letters/{dead_letter_id}/redeliver';
+    path = path.replace('{dead_letter_id}', pathSegment(deadLetterId));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<RedeliveredDeadLetter>(issued.status, issued.payload);
+  }
+
+  /**
+   * `deadLetters.redeliverMany`, `POST /api/v1/dead_letters/redeliver`.
+   *
+   * Redeliver dead letters
+   */
+  async redeliverMany(body: RedeliverDeadLetters): Promise<RedeliveredDeadLetter[]> {
+    const path = '/api/v1/dead_letters/redeliver';
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<RedeliveredDeadLetter[]>(issued.status, issued.payload);
+  }
+}
+
 /**
  * What the API declares under `errors`.
  *
@@ -331,6 +668,28 @@ export class EventTypesApi {
     return readPayload<EventType>(issued.status, issued.payload);
   }
 
+  /**
+   * `eventTypes.createVersion`, `POST /api/v1/event_types/{event_type_name}/versions`.
+   *
+   * Create a new event type version
+   */
+  async createVersion(
+    eventTypeName: string,
+    body: EventTypeVersionPost
+  ): Promise<EventTypeVersion> {
+    let path = '/api/v1/event_types/{event_type_name}/versions';
+    path = path.replace('{event_type_name}', pathSegment(eventTypeName));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<EventTypeVersion>(issued.status, issued.payload);
+  }
+
   /**
    * `eventTypes.delete`, `DELETE /api/v1/event_types/{event_type_name}`.
    *
@@ -348,6 +707,28 @@ export class EventTypesApi {
     raiseForStatus(issued.status, issued.payload);
   }
 
+  /**
+   * `eventTypes.deprecateVersion`, `POST /api/v1/event_types/{event_type_name}/deprecate`.
+   *
+   * Deprecate an event type version
+   */
+  async deprecateVersion(
+    eventTypeName: string,
+    body: EventTypeVersionDeprecate
+  ): Promise<EventTypeVersion> {
+    let path = '/api/v1/event_types/{event_type_name}/deprecate';
+    path = path.replace('{event_type_name}', pathSegment(eventTypeName));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<EventTypeVersion>(issued.status, issued.payload);
+  }
+
   /**
    * `eventTypes.get`, `GET /api/v1/event_types/{event_type_name}`.
    *
@@ -382,6 +763,25 @@ export class EventTypesApi {
     raiseForStatus(issued.status, issued.payload);
     return readPayload<EventType[]>(issued.status, issued.payload);
   }
+
+  /**
+   * `eventTypes.update`, `PUT /api/v1/event_types/{event_type_name}`.
+   *
+   * Update an event type
+   */
+  async update(eventTypeName: string, body: EventTypePut): Promise<EventType> {
+    let path = '/api/v1/event_types/{event_type_name}';
+    path = path.replace('{event_type_name}', pathSegment(eventTypeName));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'PUT',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<EventType>(issued.status, issued.payload);
+  }
 }
 
 /**
@@ -397,6 +797,24 @@ export class EventsApi {
     this.transport = transport;
   }
 
+  /**
+   * `events.cancel`, `POST /api/v1/events/{event_id}/cancel`.
+   *
+   * Cancel a scheduled event
+   */
+  async cancel(eventId: string, body: CancelEvent): Promise<void> {
+    let path = '/api/v1/events/{event_id}/cancel';
+    path = path.replace('{event_id}', pathSegment(eventId));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+  }
+
   /**
    * `events.get`, `GET /api/v1/events/{event_id}`.
    *
@@ -418,10 +836,28 @@ export class EventsApi {
   /**
    * `events.ingest`, `POST /api/v1/event/`.
    *
-   * Ingest an event
+   * Ingest an event
+   */
+  async ingest(body: EventPost): Promise<IngestedEvent> {
+    const path = '/api/v1/event/';
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<IngestedEvent>(issued.status, issued.payload);
+  }
+
+  /**
+   * `events.ingestBatch`, `POST /api/v1/events/batch`.
+   *
+   * Ingest a batch of events
    */
-  async ingest(body: EventPost): Promise<IngestedEvent> {
-    const path = '/api/v1/event/';
+  async ingestBatch(body: EventsBatchPost): Promise<IngestedEventsBatch> {
+    const path = '/api/v1/events/batch';
     const query: [string, string][] = [];
     const issued = await this.transport.request({
       method: 'POST',
@@ -430,7 +866,7 @@ export class EventsApi {
       body: JSON.stringify(body),
     });
     raiseForStatus(issued.status, issued.payload);
-    return readPayload<IngestedEvent>(issued.status, issued.payload);
+    return readPayload<IngestedEventsBatch>(issued.status, issued.payload);
   }
 
   /**
@@ -438,9 +874,12 @@ export class EventsApi {
    *
    * List latest events
    */
-  async list(applicationId: string): Promise<Event[]> {
+  async list(applicationId: string, scheduled?: boolean): Promise<Event[]> {
     const path = '/api/v1/events/';
     const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    if (scheduled !== undefined) {
+      query.push(['scheduled', queryValue(scheduled)]);
+    }
     const issued = await this.transport.request({
       method: 'GET',
       path,
@@ -537,6 +976,91 @@ export class EventsPerDayApi {
   }
 }
 
+/**
+ * What the API declares under `inbox`.
+ *
+ * Every method of it is issued through the transport it is handed.
+ */
+export class InboxApi {
+  private readonly transport: Transport;
+
+  /** Reaches what the API declares under `inbox`. */
+  constructor(transport: Transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * `inbox.ack`, `POST /api/v1/inbox/{subscription_id}/ack`.
+   *
+   * Acknowledge events pulled from an inbox
+   */
+  async ack(subscriptionId: string, body: InboxAck): Promise<InboxSettlement> {
+    let path = '/api/v1/inbox/{subscription_id}/ack';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<InboxSettlement>(issued.status, issued.payload);
+  }
+
+  /**
+   * `inbox.nack`, `POST /api/v1/inbox/{subscription_id}/nack`.
+   *
+   * Reject events pulled from an inbox
+   */
+  async nack(subscriptionId: string, body: InboxNack): Promise<InboxSettlement> {
+    let path = '/api/v1/inbox/{subscription_id}/nack';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<InboxSettlement>(issued.status, issued.payload);
+  }
+
+  /**
+   * `inbox.pull`, `GET /api/v1/inbox/{subscription_id}`.
+   *
+   * Pull events from an inbox
+   */
+  async pull(
+    subscriptionId: string,
+    applicationId: string,
+    cursor?: string,
+    limit?: number,
+    visibilityTimeoutS?: number
+  ): Promise<Inbox> {
+    let path = '/api/v1/inbox/{subscription_id}';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    if (cursor !== undefined) {
+      query.push(['cursor', queryValue(cursor)]);
+    }
+    if (limit !== undefined) {
+      query.push(['limit', queryValue(limit)]);
+    }
+    if (visibilityTimeoutS !== undefined) {
+      query.push(['visibility_timeout_s', queryValue(visibilityTimeoutS)]);
+    }
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<Inbox>(issued.status, issued.payload);
+  }
+}
+
 /**
  * What the API declares under `instance`.
  *
@@ -568,6 +1092,78 @@ export class InstanceApi {
   }
 }
 
+/**
+ * What the API declares under `oauth2ClientCredentials`.
+ *
+ * Every method of it is issued through the transport it is handed.
+ */
+export class Oauth2ClientCredentialsApi {
+  private readonly transport: Transport;
+
+  /** Reaches what the API declares under `oauth2ClientCredentials`. */
+  constructor(transport: Transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * `oauth2ClientCredentials.delete`, `DELETE /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+   *
+   * Remove the OAuth2 client credentials of a subscription
+   */
+  async delete(subscriptionId: string, applicationId: string): Promise<void> {
+    let path = '/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'DELETE',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+  }
+
+  /**
+   * `oauth2ClientCredentials.get`, `GET /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+   *
+   * Get the OAuth2 client credentials of a subscription
+   */
+  async get(subscriptionId: string, applicationId: string): Promise<Oauth2ClientCredentials> {
+    let path = '/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<Oauth2ClientCredentials>(issued.status, issued.payload);
+  }
+
+  /**
+   * `oauth2ClientCredentials.update`, `PUT /api/v1/subscriptions/{subscription_id}/oauth2_client_credentials`.
+   *
+   * Set the OAuth2 client credentials of a subscription
+   */
+  async update(
+    subscriptionId: string,
+    applicationId: string,
+    body: Oauth2ClientCredentialsPut
+  ): Promise<Oauth2ClientCredentials> {
+    let path = '/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'PUT',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<Oauth2ClientCredentials>(issued.status, issued.payload);
+  }
+}
+
 /**
  * What the API declares under `payload_content_types`.
  *
@@ -703,6 +1299,25 @@ export class RequestAttemptsApi {
     raiseForStatus(issued.status, issued.payload);
     return readPayload<RequestAttempt[]>(issued.status, issued.payload);
   }
+
+  /**
+   * `requestAttempts.retry`, `POST /api/v1/request_attempts/{request_attempt_id}/retry`.
+   *
+   * Retry a request attempt
+   */
+  async retry(requestAttemptId: string, body: RetryRequestAttempt): Promise<RequestAttempt> {
+    let path = '/api/v1/request_attempts/{request_attempt_id}/retry';
+    path = path.replace('{request_attempt_id}', pathSegment(requestAttemptId));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<RequestAttempt>(issued.status, issued.payload);
+  }
 }
 
 /**
@@ -737,6 +1352,95 @@ export class ResponseApi {
   }
 }
 
+/**
+ * What the API declares under `secretRotations`.
+ *
+ * Every method of it is issued through the transport it is handed.
+ */
+export class SecretRotationsApi {
+  private readonly transport: Transport;
+
+  /** Reaches what the API declares under `secretRotations`. */
+  constructor(transport: Transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * `secretRotations.create`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation`.
+   *
+   * Rotate the secret of a subscription
+   */
+  async create(
+    subscriptionId: string,
+    applicationId: string,
+    body: SecretRotationPost
+  ): Promise<SecretRotation> {
+    let path = '/api/v1/subscriptions/{subscription_id}/secret_rotation';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<SecretRotation>(issued.status, issued.payload);
+  }
+
+  /**
+   * `secretRotations.finalize`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation/finalize`.
+   *
+   * Finalize the secret rotation of a subscription
+   */
+  async finalize(subscriptionId: string, applicationId: string): Promise<void> {
+    let path = '/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+  }
+
+  /**
+   * `secretRotations.get`, `GET /api/v1/subscriptions/{subscription_id}/secret_rotation`.
+   *
+   * Get the secret rotation of a subscription
+   */
+  async get(subscriptionId: string, applicationId: string): Promise<SecretRotation> {
+    let path = '/api/v1/subscriptions/{subscription_id}/secret_rotation';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<SecretRotation>(issued.status, issued.payload);
+  }
+
+  /**
+   * `secretRotations.rollback`, `POST /api/v1/subscriptions/{subscription_id}/secret_rotation/rollback`.
+   *
+   * Roll back the secret rotation of a subscription
+   */
+  async rollback(subscriptionId: string, applicationId: string): Promise<void> {
+    let path = '/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback';
+    path = path.replace('{subscription_id}', pathSegment(subscriptionId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+  }
+}
+
 /**
  * What the API declares under `serviceToken`.
  *
@@ -840,6 +1544,90 @@ export class ServiceTokenApi {
   }
 }
 
+/**
+ * What the API declares under `signingKeys`.
+ *
+ * Every method of it is issued through the transport it is handed.
+ */
+export class SigningKeysApi {
+  private readonly transport: Transport;
+
+  /** Reaches what the API declares under `signingKeys`. */
+  constructor(transport: Transport) {
+    this.transport = transport;
+  }
+
+  /**
+   * `signingKeys.create`, `POST /api/v1/signing_keys/`.
+   *
+   * Create a signing key
+   */
+  async create(body: SigningKeyPost): Promise<SigningKey> {
+    const path = '/api/v1/signing_keys/';
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'POST',
+      path,
+      query,
+      body: JSON.stringify(body),
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<SigningKey>(issued.status, issued.payload);
+  }
+
+  /**
+   * `signingKeys.delete`, `DELETE /api/v1/signing_keys/{signing_key_id}`.
+   *
+   * Retire a signing key
+   */
+  async delete(signingKeyId: string, applicationId: string): Promise<void> {
+    let path = '/api/v1/signing_keys/{signing_key_id}';
+    path = path.replace('{signing_key_id}', pathSegment(signingKeyId));
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'DELETE',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+  }
+
+  /**
+   * `signingKeys.jwks`, `GET /api/v1/jwks/{application_id}`.
+   *
+   * Get the public keys of an application
+   */
+  async jwks(applicationId: string): Promise<JsonWebKeySet> {
+    let path = '/api/v1/jwks/{application_id}';
+    path = path.replace('{application_id}', pathSegment(applicationId));
+    const query: [string, string][] = [];
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<JsonWebKeySet>(issued.status, issued.payload);
+  }
+
+  /**
+   * `signingKeys.list`, `GET /api/v1/signing_keys/`.
+   *
+   * List signing keys
+   */
+  async list(applicationId: string): Promise<SigningKey[]> {
+    const path = '/api/v1/signing_keys/';
+    const query: [string, string][] = [['application_id', queryValue(applicationId)]];
+    const issued = await this.transport.request({
+      method: 'GET',
+      path,
+      query,
+    });
+    raiseForStatus(issued.status, issued.payload);
+    return readPayload<SigningKey[]>(issued.status, issued.payload);
+  }
+}
+
 /**
  * What the API declares under `subscriptions`.
  *
@@ -14,16 +14,23 @@
 
 export { ApplicationSecretsApi } from './api';
 export { ApplicationsApi } from './api';
+export { BulkRetriesApi } from './api';
+export { ClientCertificatesApi } from './api';
+export { DeadLettersApi } from './api';
 export { ErrorsApi } from './api';
 export { EventTypesApi } from './api';
 export { EventsApi } from './api';
 export { EventsPerDayApi } from './api';
+export { InboxApi } from './api';
 export { InstanceApi } from './api';
+export { Oauth2ClientCredentialsApi } from './api';
 export { PayloadContentTypesApi } from './api';
 export { QuotasApi } from './api';
 export { RequestAttemptsApi } from './api';
 export { ResponseApi } from './api';
+export { SecretRotationsApi } from './api';
 export { ServiceTokenApi } from './api';
+export { SigningKeysApi } from './api';
 export { SubscriptionsApi } from './api';
 export { Transport } from './api';
 export { TransportRequest } from './api';
@@ -44,16 +51,60 @@ export { ApplicationInfoQuotas } from './models';
 export { ApplicationPost } from './models';
 export { ApplicationSecret } from './models';
 export { ApplicationSecretPost } from './models';
+export { BulkRetry } from './models';
+export { BulkRetryDryRun } from './models';
+export { BulkRetryPost } from './models';
+export { BulkRetryPostStatus } from './models';
+export { BulkRetryStatus } from './models';
+export { CancelEvent } from './models';
+export { ClientCertificate } from './models';
+export { ClientCertificatePut } from './models';
+export { DeadLetter } from './models';
+export { DeadLetterDetails } from './models';
+export { DeadLetterDetailsDeadLetter } from './models';
+export { DeadLetterDetailsDeadLetterEvent } from './models';
+export { DeadLetterDetailsDeadLetterReason } from './models';
+export { DeadLetterDetailsDeadLetterSubscription } from './models';
+export { DeadLetterDetailsLastResponse } from './models';
+export { DeadLetterEvent } from './models';
+export { DeadLetterReason } from './models';
+export { DeadLetterSubscription } from './models';
 export { Event } from './models';
 export { EventPost } from './models';
 export { EventType } from './models';
+export { EventTypePayloadSchemaMode } from './models';
 export { EventTypePost } from './models';
+export { EventTypePostPayloadSchemaMode } from './models';
+export { EventTypePut } from './models';
+export { EventTypePutPayloadSchemaMode } from './models';
+export { EventTypeVersion } from './models';
+export { EventTypeVersionDeprecate } from './models';
+export { EventTypeVersionPost } from './models';
+export { EventTypeVersions } from './models';
 export { EventWithPayload } from './models';
+export { EventsBatchPost } from './models';
+export { EventsBatchPostEvents } from './models';
 export { EventsPerDayEntry } from './models';
+export { Inbox } from './models';
+export { InboxAck } from './models';
+export { InboxMessages } from './models';
+export { InboxNack } from './models';
+export { InboxSettlement } from './models';
 export { IngestedEvent } from './models';
+export { IngestedEventDeprecation } from './models';
+export { IngestedEventsBatch } from './models';
+export { IngestedEventsBatchEvents } from './models';
+export { IngestedEventsBatchEventsDeprecation } from './models';
+export { IngestedEventsBatchEventsProblem } from './models';
+export { IngestedEventsBatchEventsProblemId } from './models';
+export { IngestedEventsBatchEventsStatus } from './models';
 export { InstanceConfig } from './models';
 export { InstanceConfigFormbricks } from './models';
 export { InstanceConfigMatomo } from './models';
+export { JsonWebKeySet } from './models';
+export { JsonWebKeySetKeys } from './models';
+export { Oauth2ClientCredentials } from './models';
+export { Oauth2ClientCredentialsPut } from './models';
 export { Organization } from './models';
 export { OrganizationInfo } from './models';
 export { OrganizationInfoConsumption } from './models';
@@ -68,18 +119,53 @@ export { OrganizationInfoUsers } from './models';
 export { OrganizationPlan } from './models';
 export { Problem } from './models';
 export { ProblemId } from './models';
+export { PurgeDeadLetters } from './models';
+export { PurgedDeadLetters } from './models';
 export { QuotasResponse } from './models';
 export { QuotasResponseLimits } from './models';
+export { RedeliverDeadLetter } from './models';
+export { RedeliverDeadLetters } from './models';
+export { RedeliveredDeadLetter } from './models';
 export { ReplayEvent } from './models';
 export { RequestAttempt } from './models';
 export { RequestAttemptEvent } from './models';
 export { RequestAttemptStatus } from './models';
+export { RequestAttemptStatusRuleOutcome } from './models';
 export { RequestAttemptStatusType } from './models';
 export { RequestAttemptSubscription } from './models';
 export { Response } from './models';
+export { RetryRequestAttempt } from './models';
+export { SecretRotation } from './models';
+export { SecretRotationPost } from './models';
 export { ServiceToken } from './models';
 export { ServiceTokenPost } from './models';
+export { SigningKey } from './models';
+export { SigningKeyPost } from './models';
 export { Subscription } from './models';
+export { SubscriptionBatching } from './models';
+export { SubscriptionCircuitBreaker } from './models';
+export { SubscriptionDeliveryLimits } from './models';
+export { SubscriptionOrderingKey } from './models';
+export { SubscriptionPayloadTransform } from './models';
+export { SubscriptionPayloadTransformFields } from './models';
 export { SubscriptionPost } from './models';
+export { SubscriptionPostBatching } from './models';
+export { SubscriptionPostCircuitBreaker } from './models';
+export { SubscriptionPostDeliveryLimits } from './models';
+export { SubscriptionPostOrderingKey } from './models';
+export { SubscriptionPostPayloadTransform } from './models';
+export { SubscriptionPostPayloadTransformFields } from './models';
+export { SubscriptionPostResponseRules } from './models';
+export { SubscriptionPostResponseRulesHeader } from './models';
+export { SubscriptionPostResponseRulesOutcome } from './models';
+export { SubscriptionPostRetryPolicy } from './models';
+export { SubscriptionPostSignatureScheme } from './models';
 export { SubscriptionPostTarget } from './models';
+export { SubscriptionPostTargetType } from './models';
+export { SubscriptionResponseRules } from './models';
+export { SubscriptionResponseRulesHeader } from './models';
+export { SubscriptionResponseRulesOutcome } from './models';
+export { SubscriptionRetryPolicy } from './models';
+export { SubscriptionSignatureScheme } from './models';
 export { SubscriptionTarget } from './models';
+export { SubscriptionTargetType } from './models';
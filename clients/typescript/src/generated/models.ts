@@ -33,6 +33,161 @@ export const ApplicationInfoOnboardingStepsSubscription = {
 export type ApplicationInfoOnboardingStepsSubscription =
   (typeof ApplicationInfoOnboardingStepsSubscription)[keyof typeof ApplicationInfoOnboardingStepsSubscription];
 
+/** One of the values the API answers with. */
+export const BulkRetryPostStatus = {
+  Failed: 'failed',
+  Successful: 'successful',
+} as const;
+
+/** One of the values the API answers with. */
+export type BulkRetryPostStatus = (typeof BulkRetryPostStatus)[keyof typeof BulkRetryPostStatus];
+
+/** One of the values the API answers with. */
+export const BulkRetryStatus = {
+  Failed: 'failed',
+  Successful: 'successful',
+} as const;
+
+/** One of the values the API answers with. */
+export type BulkRetryStatus = (typeof BulkRetryStatus)[keyof typeof BulkRetryStatus];
+
+/** One of the values the API answers with. */
+export const DeadLetterDetailsDeadLetterReason = {
+  RetriesExhausted: 'retries_exhausted',
+  SignatureFailed: 'signature_failed',
+  TransformFailed: 'transform_failed',
+  PermanentFailure: 'permanent_failure',
+} as const;
+
+/** One of the values the API answers with. */
+export type DeadLetterDetailsDeadLetterReason =
+  (typeof DeadLetterDetailsDeadLetterReason)[keyof typeof DeadLetterDetailsDeadLetterReason];
+
+/** One of the values the API answers with. */
+export const DeadLetterReason = {
+  RetriesExhausted: 'retries_exhausted',
+  SignatureFailed: 'signature_failed',
+  TransformFailed: 'transform_failed',
+  PermanentFailure: 'permanent_failure',
+} as const;
+
+/** One of the values the API answers with. */
+export type DeadLetterReason = (typeof DeadLetterReason)[keyof typeof DeadLetterReason];
+
+/** One of the values the API answers with. */
+export const EventTypePayloadSchemaMode = {
+  Enforce: 'enforce',
+  Warn: 'warn',
+  Off: 'off',
+} as const;
+
+/** One of the values the API answers with. */
+export type EventTypePayloadSchemaMode =
+  (typeof EventTypePayloadSchemaMode)[keyof typeof EventTypePayloadSchemaMode];
+
+/** One of the values the API answers with. */
+export const EventTypePostPayloadSchemaMode = {
+  Enforce: 'enforce',
+  Warn: 'warn',
+  Off: 'off',
+} as const;
+
+/** One of the values the API answers with. */
+export type EventTypePostPayloadSchemaMode =
+  (typeof EventTypePostPayloadSchemaMode)[keyof typeof EventTypePostPayloadSchemaMode];
+
+/** One of the values the API answers with. */
+export const EventTypePutPayloadSchemaMode = {
+  Enforce: 'enforce',
+  Warn: 'warn',
+  Off: 'off',
+} as const;
+
+/** One of the values the API answers with. */
+export type EventTypePutPayloadSchemaMode =
+  (typeof EventTypePutPayloadSchemaMode)[keyof typeof EventTypePutPayloadSchemaMode];
+
+/** One of the values the API answers with. */
+export const IngestedEventsBatchEventsProblemId = {
+  OrganizationNameMissing: 'OrganizationNameMissing',
+  UserAlreadyExist: 'UserAlreadyExist',
+  RegistrationDisabled: 'RegistrationDisabled',
+  PasswordTooShort: 'PasswordTooShort',
+  PasswordTooLong: 'PasswordTooLong',
+  PasswordSimilarToEmail: 'PasswordSimilarToEmail',
+  PasswordSimilarToName: 'PasswordSimilarToName',
+  PasswordTooCommon: 'PasswordTooCommon',
+  PasswordNotDiverseEnough: 'PasswordNotDiverseEnough',
+  OrganizationIsNotEmpty: 'OrganizationIsNotEmpty',
+  InvitedUserDoesNotExist: 'InvitedUserDoesNotExist',
+  InvitedUserAlreadyInOrganization: 'InvitedUserAlreadyInOrganization',
+  ApplicationNameMissing: 'ApplicationNameMissing',
+  InvalidRole: 'InvalidRole',
+  EventTypeAlreadyExist: 'EventTypeAlreadyExist',
+  EventTypeDoesNotExist: 'EventTypeDoesNotExist',
+  EventTypeVersionAlreadyExist: 'EventTypeVersionAlreadyExist',
+  EventTypeVersionDoesNotExist: 'EventTypeVersionDoesNotExist',
+  EventTypeVersionSunset: 'EventTypeVersionSunset',
+  UnauthorizedWorkers: 'UnauthorizedWorkers',
+  EventAlreadyIngested: 'EventAlreadyIngested',
+  EventInvalidPayloadContentType: 'EventInvalidPayloadContentType',
+  EventInvalidBase64Payload: 'EventInvalidBase64Payload',
+  EventInvalidJsonPayload: 'EventInvalidJsonPayload',
+  EventPayloadSchemaViolation: 'EventPayloadSchemaViolation',
+  EventDeliveryBeyondRetention: 'EventDeliveryBeyondRetention',
+  EventNotScheduled: 'EventNotScheduled',
+  LabelsAmbiguity: 'LabelsAmbiguity',
+  InvalidDateRange: 'InvalidDateRange',
+  RequestAttemptNotFailed: 'RequestAttemptNotFailed',
+  RequestAttemptRetryPending: 'RequestAttemptRetryPending',
+  SubscriptionDisabled: 'SubscriptionDisabled',
+  SubscriptionNotInbox: 'SubscriptionNotInbox',
+  SecretRotationInProgress: 'SecretRotationInProgress',
+  BulkRetryAlreadyRunning: 'BulkRetryAlreadyRunning',
+  BulkRetryFinished: 'BulkRetryFinished',
+  RetryPolicyOverLimit: 'RetryPolicyOverLimit',
+  ClientCertificatesDisabled: 'ClientCertificatesDisabled',
+  InvalidClientCertificate: 'InvalidClientCertificate',
+  SigningKeysDisabled: 'SigningKeysDisabled',
+  AuthNoAuthorizationHeader: 'AuthNoAuthorizationHeader',
+  AuthInvalidAuthorizationHeader: 'AuthInvalidAuthorizationHeader',
+  AuthApplicationSecretLookupError: 'AuthApplicationSecretLookupError',
+  AuthInvalidApplicationSecret: 'AuthInvalidApplicationSecret',
+  AuthBiscuitLookupError: 'AuthBiscuitLookupError',
+  AuthInvalidBiscuit: 'AuthInvalidBiscuit',
+  AuthFailedLogin: 'AuthFailedLogin',
+  AuthEmailNotVerified: 'AuthEmailNotVerified',
+  AuthEmailAlreadyVerified: 'AuthEmailAlreadyVerified',
+  AuthFailedRefresh: 'AuthFailedRefresh',
+  AuthEmailExpired: 'AuthEmailExpired',
+  TooManyMembersPerOrganization: 'TooManyMembersPerOrganization',
+  TooManyApplicationsPerOrganization: 'TooManyApplicationsPerOrganization',
+  TooManyEventsToday: 'TooManyEventsToday',
+  TooManySubscriptionsPerApplication: 'TooManySubscriptionsPerApplication',
+  TooManyEventTypesPerApplication: 'TooManyEventTypesPerApplication',
+  JsonPayload: 'JsonPayload',
+  Validation: 'Validation',
+  NotFound: 'NotFound',
+  InternalServerError: 'InternalServerError',
+  Forbidden: 'Forbidden',
+  RateLimited: 'RateLimited',
+  ServiceUnavailable: 'ServiceUnavailable',
+} as const;
+
+/** One of the values the API answers with. */
+export type IngestedEventsBatchEventsProblemId =
+  (typeof IngestedEventsBatchEventsProblemId)[keyof typeof IngestedEventsBatchEventsProblemId];
+
+/** One of the values the API answers with. */
+export const IngestedEventsBatchEventsStatus = {
+  Ingested: 'ingested',
+  Failed: 'failed',
+} as const;
+
+/** One of the values the API answers with. */
+export type IngestedEventsBatchEventsStatus =
+  (typeof IngestedEventsBatchEventsStatus)[keyof typeof IngestedEventsBatchEventsStatus];
+
 /** One of the values the API answers with. */
 export const OrganizationInfoOnboardingStepsApplication = {
   ToDo: 'ToDo',
@@ -91,13 +246,30 @@ export const ProblemId = {
   InvalidRole: 'InvalidRole',
   EventTypeAlreadyExist: 'EventTypeAlreadyExist',
   EventTypeDoesNotExist: 'EventTypeDoesNotExist',
+  EventTypeVersionAlreadyExist: 'EventTypeVersionAlreadyExist',
+  EventTypeVersionDoesNotExist: 'EventTypeVersionDoesNotExist',
+  EventTypeVersionSunset: 'EventTypeVersionSunset',
   UnauthorizedWorkers: 'UnauthorizedWorkers',
   EventAlreadyIngested: 'EventAlreadyIngested',
   EventInvalidPayloadContentType: 'EventInvalidPayloadContentType',
   EventInvalidBase64Payload: 'EventInvalidBase64Payload',
   EventInvalidJsonPayload: 'EventInvalidJsonPayload',
+  EventPayloadSchemaViolation: 'EventPayloadSchemaViolation',
+  EventDeliveryBeyondRetention: 'EventDeliveryBeyondRetention',
+  EventNotScheduled: 'EventNotScheduled',
   LabelsAmbiguity: 'LabelsAmbiguity',
   InvalidDateRange: 'InvalidDateRange',
+  RequestAttemptNotFailed: 'RequestAttemptNotFailed',
+  RequestAttemptRetryPending: 'RequestAttemptRetryPending',
+  SubscriptionDisabled: 'SubscriptionDisabled',
+  SubscriptionNotInbox: 'SubscriptionNotInbox',
+  SecretRotationInProgress: 'SecretRotationInProgress',
+  BulkRetryAlreadyRunning: 'BulkRetryAlreadyRunning',
+  BulkRetryFinished: 'BulkRetryFinished',
+  RetryPolicyOverLimit: 'RetryPolicyOverLimit',
+  ClientCertificatesDisabled: 'ClientCertificatesDisabled',
+  InvalidClientCertificate: 'InvalidClientCertificate',
+  SigningKeysDisabled: 'SigningKeysDisabled',
   AuthNoAuthorizationHeader: 'AuthNoAuthorizationHeader',
   AuthInvalidAuthorizationHeader: 'AuthInvalidAuthorizationHeader',
   AuthApplicationSecretLookupError: 'AuthApplicationSecretLookupError',
@@ -126,6 +298,18 @@ export const ProblemId = {
 /** One of the values the API answers with. */
 export type ProblemId = (typeof ProblemId)[keyof typeof ProblemId];
 
+/** One of the values the API answers with. */
+export const RequestAttemptStatusRuleOutcome = {
+  Success: 'success',
+  Retryable: 'retryable',
+  PermanentFailure: 'permanent_failure',
+  DisableSubscription: 'disable_subscription',
+} as const;
+
+/** One of the values the API answers with. */
+export type RequestAttemptStatusRuleOutcome =
+  (typeof RequestAttemptStatusRuleOutcome)[keyof typeof RequestAttemptStatusRuleOutcome];
+
 /** One of the values the API answers with. */
 export const RequestAttemptStatusType = {
   Waiting: 'waiting',
@@ -133,12 +317,82 @@ export const RequestAttemptStatusType = {
   InProgress: 'in_progress',
   Successful: 'successful',
   Failed: 'failed',
+  Expired: 'expired',
+  Cancelled: 'cancelled',
 } as const;
 
 /** One of the values the API answers with. */
 export type RequestAttemptStatusType =
   (typeof RequestAttemptStatusType)[keyof typeof RequestAttemptStatusType];
 
+/** One of the values the API answers with. */
+export const SubscriptionPostResponseRulesOutcome = {
+  Success: 'success',
+  Retryable: 'retryable',
+  PermanentFailure: 'permanent_failure',
+  DisableSubscription: 'disable_subscription',
+} as const;
+
+/** One of the values the API answers with. */
+export type SubscriptionPostResponseRulesOutcome =
+  (typeof SubscriptionPostResponseRulesOutcome)[keyof typeof SubscriptionPostResponseRulesOutcome];
+
+/** One of the values the API answers with. */
+export const SubscriptionPostSignatureScheme = {
+  Hook0: 'hook0',
+  StandardWebhooks: 'standard_webhooks',
+} as const;
+
+/** One of the values the API answers with. */
+export type SubscriptionPostSignatureScheme =
+  (typeof SubscriptionPostSignatureScheme)[keyof typeof SubscriptionPostSignatureScheme];
+
+/** One of the values the API answers with. */
+export const SubscriptionPostTargetType = {
+  Http: 'http',
+  Amqp: 'amqp',
+  Sqs: 'sqs',
+  Inbox: 'inbox',
+} as const;
+
+/** One of the values the API answers with. */
+export type SubscriptionPostTargetType =
+  (typeof SubscriptionPostTargetType)[keyof typeof SubscriptionPostTargetType];
+
+/** One of the values the API answers with. */
+export const SubscriptionResponseRulesOutcome = {
+  Success: 'success',
+  Retryable: 'retryable',
+  PermanentFailure: 'permanent_failure',
+  DisableSubscription: 'disable_subscription',
+} as const;
+
+/** One of the values the API answers with. */
+export type SubscriptionResponseRulesOutcome =
+  (typeof SubscriptionResponseRulesOutcome)[keyof typeof SubscriptionResponseRulesOutcome];
+
+/** One of the values the API answers with. */
+export const SubscriptionSignatureScheme = {
+  Hook0: 'hook0',
+  StandardWebhooks: 'standard_webhooks',
+} as const;
+
+/** One of the values the API answers with. */
+export type SubscriptionSignatureScheme =
+  (typeof SubscriptionSignatureScheme)[keyof typeof SubscriptionSignatureScheme];
+
+/** One of the values the API answers with. */
+export const SubscriptionTargetType = {
+  Http: 'http',
+  Amqp: 'amqp',
+  Sqs: 'sqs',
+  Inbox: 'inbox',
+} as const;
+
+/** One of the values the API answers with. */
+export type SubscriptionTargetType =
+  (typeof SubscriptionTargetType)[keyof typeof SubscriptionTargetType];
+
 /** The `Application` the API declares. */
 export interface Application {
   /** `application_id`: Unique identifier of the application. */
@@ -217,12 +471,212 @@ export interface ApplicationSecretPost {
   readonly name?: string;
 }
 
+/** The `BulkRetry` the API declares. */
+export interface BulkRetry {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `bulk_retry_id`. */
+  readonly bulk_retry_id: string;
+  /** `cancelled_at`. */
+  readonly cancelled_at?: string;
+  /** `completed_at`. */
+  readonly completed_at?: string;
+  /** `created_at`. */
+  readonly created_at: string;
+  /** `event_id`. */
+  readonly event_id?: string;
+  /** `event_type_names`. */
+  readonly event_type_names: string[];
+  /** `max_created_at`. */
+  readonly max_created_at: string;
+  /** `min_created_at`. */
+  readonly min_created_at: string;
+  /** `processed_count`: Number of pairs handled so far */
+  readonly processed_count: number;
+  /** `retried_count`: Number of request attempts created so far */
+  readonly retried_count: number;
+  /** `status`. */
+  readonly status: BulkRetryStatus;
+  /** `subscription_id`. */
+  readonly subscription_id?: string;
+  /** `total_count`: Number of (event, subscription) pairs that matched when the bulk retry was created */
+  readonly total_count: number;
+  /** `updated_at`. */
+  readonly updated_at: string;
+}
+
+/** The `BulkRetryDryRun` the API declares. */
+export interface BulkRetryDryRun {
+  /** `count`: Number of (event, subscription) pairs a bulk retry would redeliver now */
+  readonly count: number;
+}
+
+/** The `BulkRetryPost` the API declares. */
+export interface BulkRetryPost {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `event_id`. */
+  readonly event_id?: string;
+  /** `event_type_names`. */
+  readonly event_type_names?: string[];
+  /** `max_created_at`. */
+  readonly max_created_at?: string;
+  /** `min_created_at`. */
+  readonly min_created_at?: string;
+  /** `status`: Redeliver request attempts that failed (default) or that succeeded */
+  readonly status?: BulkRetryPostStatus;
+  /** `subscription_id`. */
+  readonly subscription_id?: string;
+}
+
+/** The `CancelEvent` the API declares. */
+export interface CancelEvent {
+  /** `application_id`. */
+  readonly application_id: string;
+}
+
+/** The `ClientCertificate` the API declares. */
+export interface ClientCertificate {
+  /** `certificate_pem`: Certificate chain, leaf certificate first */
+  readonly certificate_pem: string;
+  /** `created_at`. */
+  readonly created_at: string;
+  /** `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate, as hexadecimal */
+  readonly fingerprint_sha256: string;
+  /** `not_after`. */
+  readonly not_after: string;
+  /** `not_before`. */
+  readonly not_before: string;
+  /** `subject`. */
+  readonly subject: string;
+  /** `subscription_id`. */
+  readonly subscription_id: string;
+  /** `updated_at`. */
+  readonly updated_at: string;
+}
+
+/** The `ClientCertificatePut` the API declares. */
+export interface ClientCertificatePut {
+  /** `certificate_pem`: PEM-encoded certificate chain, leaf certificate first */
+  readonly certificate_pem: string;
+  /** `private_key_pem`: PEM-encoded unencrypted private key of the leaf certificate (PKCS#8, PKCS#1 or SEC1) */
+  readonly private_key_pem: string;
+}
+
+/** The `DeadLetter` the API declares. */
+export interface DeadLetter {
+  /** `created_at`. */
+  readonly created_at: string;
+  /** `dead_letter_id`. */
+  readonly dead_letter_id: string;
+  /** `event`. */
+  readonly event: DeadLetterEvent;
+  /** `http_response_status`. */
+  readonly http_response_status?: number;
+  /** `reason`. */
+  readonly reason: DeadLetterReason;
+  /** `request_attempt_id`: Last request attempt of the delivery */
+  readonly request_attempt_id: string;
+  /** `response_id`: Response to the last request attempt, if the target was reached */
+  readonly response_id?: string;
+  /** `retry_count`. */
+  readonly retry_count: number;
+  /** `subscription`. */
+  readonly subscription: DeadLetterSubscription;
+}
+
+/** The `DeadLetterDetails` the API declares. */
+export interface DeadLetterDetails {
+  /** `dead_letter`: Delivery an output worker gave up on */
+  readonly dead_letter: DeadLetterDetailsDeadLetter;
+  /** `last_response`: Response to the last request attempt, if the target was reached */
+  readonly last_response?: DeadLetterDetailsLastResponse;
+}
+
+/** The `DeadLetterDetailsDeadLetter` the API declares. */
+export interface DeadLetterDetailsDeadLetter {
+  /** `created_at`. */
+  readonly created_at: string;
+  /** `dead_letter_id`. */
+  readonly dead_letter_id: string;
+  /** `event`. */
+  readonly event: DeadLetterDetailsDeadLetterEvent;
+  /** `http_response_status`. */
+  readonly http_response_status?: number;
+  /** `reason`. */
+  readonly reason: DeadLetterDetailsDeadLetterReason;
+  /** `request_attempt_id`: Last request attempt of the delivery */
+  readonly request_attempt_id: string;
+  /** `response_id`: Response to the last request attempt, if the target was reached */
+  readonly response_id?: string;
+  /** `retry_count`. */
+  readonly retry_count: number;
+  /** `subscription`. */
+  readonly subscription: DeadLetterDetailsDeadLetterSubscription;
+}
+
+/** The `DeadLetterDetailsDeadLetterEvent` the API declares. */
+export interface DeadLetterDetailsDeadLetterEvent {
+  /** `event_id`. */
+  readonly event_id: string;
+  /** `event_type_name`. */
+  readonly event_type_name: string;
+}
+
+/** The `DeadLetterDetailsDeadLetterSubscription` the API declares. */
+export interface DeadLetterDetailsDeadLetterSubscription {
+  /** `description`. */
+  readonly description?: string;
+  /** `subscription_id`. */
+  readonly subscription_id: string;
+}
+
+/** The `DeadLetterDetailsLastResponse` the API declares. */
+export interface DeadLetterDetailsLastResponse {
+  /** `body`. */
+  readonly body?: string;
+  /** `elapsed_time_ms`. */
+  readonly elapsed_time_ms?: number;
+  /** `headers`. */
+  readonly headers?: Record<string, string>;
+  /** `http_code`. */
+  readonly http_code?: number;
+  /** `response_error_name`. */
+  readonly response_error_name?: string;
+  /** `response_id`. */
+  readonly response_id: string;
+}
+
+/** The `DeadLetterEvent` the API declares. */
+export interface DeadLetterEvent {
+  /** `event_id`. */
+  readonly event_id: string;
+  /** `event_type_name`. */
+  readonly event_type_name: string;
+}
+
+/** The `DeadLetterSubscription` the API declares. */
+export interface DeadLetterSubscription {
+  /** `description`. */
+  readonly description?: string;
+  /** `subscription_id`. */
+  readonly subscription_id: string;
+}
+
 /** The `Event` the API declares. */
 export interface Event {
+  /** `cancelled_at`: Date the scheduled delivery of the event was cancelled */
+  readonly cancelled_at?: string;
+  /** `deliver_at`: Date from which the event is delivered, when it was scheduled for a later delivery */
+  readonly deliver_at?: string;
   /** `event_id`. */
   readonly event_id: string;
   /** `event_type_name`. */
   readonly event_type_name: string;
+  /** `event_type_version`. */
+  readonly event_type_version?: number;
+  /** `expires_at`: Date after which the event is no longer delivered */
+  readonly expires_at?: string;
   /** `ip`. */
   readonly ip: string;
   /** `labels`. */
@@ -241,10 +695,16 @@ export interface Event {
 export interface EventPost {
   /** `application_id`: UUID of the application this event belongs to. */
   readonly application_id: string;
+  /** `deliver_at`: Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the appl */
+  readonly deliver_at?: string;
   /** `event_id`: Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server. */
   readonly event_id?: string;
   /** `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters. */
   readonly event_type: string;
+  /** `event_type_version`: Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event. */
+  readonly event_type_version?: number;
+  /** `expires_at`: Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted f */
+  readonly expires_at?: string;
   /** `labels`: Labels for event filtering and routing to subscriptions. */
   readonly labels: Record<string, string>;
   /** `metadata`: Optional metadata key-value pairs associated with the event. */
@@ -259,20 +719,34 @@ export interface EventPost {
 
 /** The `EventType` the API declares. */
 export interface EventType {
+  /** `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date */
+  readonly default_ttl_s?: number;
   /** `event_type_name`. */
   readonly event_type_name: string;
+  /** `payload_schema`. */
+  readonly payload_schema?: unknown;
+  /** `payload_schema_mode`. */
+  readonly payload_schema_mode: EventTypePayloadSchemaMode;
   /** `resource_type_name`. */
   readonly resource_type_name: string;
   /** `service_name`. */
   readonly service_name: string;
   /** `verb_name`. */
   readonly verb_name: string;
+  /** `versions`. */
+  readonly versions: EventTypeVersions[];
 }
 
 /** The `EventTypePost` the API declares. */
 export interface EventTypePost {
   /** `application_id`. */
   readonly application_id: string;
+  /** `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over */
+  readonly default_ttl_s?: number;
+  /** `payload_schema`: JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported */
+  readonly payload_schema?: unknown;
+  /** `payload_schema_mode`: How the payload schema is applied at ingestion (default: `enforce`) */
+  readonly payload_schema_mode?: EventTypePostPayloadSchemaMode;
   /** `resource_type`. */
   readonly resource_type: string;
   /** `service`. */
@@ -281,12 +755,80 @@ export interface EventTypePost {
   readonly verb: string;
 }
 
+/** The `EventTypePut` the API declares. */
+export interface EventTypePut {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `default_ttl_s`: Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over */
+  readonly default_ttl_s?: number;
+  /** `payload_schema`: JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported */
+  readonly payload_schema?: unknown;
+  /** `payload_schema_mode`: How the payload schema is applied at ingestion (default: `enforce`) */
+  readonly payload_schema_mode?: EventTypePutPayloadSchemaMode;
+}
+
+/** The `EventTypeVersion` the API declares. */
+export interface EventTypeVersion {
+  /** `created_at`. */
+  readonly created_at: string;
+  /** `deprecated_at`. */
+  readonly deprecated_at?: string;
+  /** `payload_schema`: JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type */
+  readonly payload_schema?: unknown;
+  /** `sunset_at`: Date from which the events declaring this version are refused */
+  readonly sunset_at?: string;
+  /** `version`. */
+  readonly version: number;
+}
+
+/** The `EventTypeVersionDeprecate` the API declares. */
+export interface EventTypeVersionDeprecate {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `sunset_at`: Date from which the events declaring this version are refused; none keeps accepting them */
+  readonly sunset_at?: string;
+  /** `version`. */
+  readonly version: number;
+}
+
+/** The `EventTypeVersionPost` the API declares. */
+export interface EventTypeVersionPost {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `payload_schema`: JSON Schema the payloads of the `application/json` events declaring this version must match, instead of the one of the event type; remote references are not supported */
+  readonly payload_schema?: unknown;
+  /** `version`. */
+  readonly version: number;
+}
+
+/** The `EventTypeVersions` the API declares. */
+export interface EventTypeVersions {
+  /** `created_at`. */
+  readonly created_at: string;
+  /** `deprecated_at`. */
+  readonly deprecated_at?: string;
+  /** `payload_schema`: JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type */
+  readonly payload_schema?: unknown;
+  /** `sunset_at`: Date from which the events declaring this version are refused */
+  readonly sunset_at?: string;
+  /** `version`. */
+  readonly version: number;
+}
+
 /** The `EventWithPayload` the API declares. */
 export interface EventWithPayload {
+  /** `cancelled_at`: Date the scheduled delivery of the event was cancelled */
+  readonly cancelled_at?: string;
+  /** `deliver_at`: Date from which the event is delivered, when it was scheduled for a later delivery */
+  readonly deliver_at?: string;
   /** `event_id`. */
   readonly event_id: string;
   /** `event_type_name`. */
   readonly event_type_name: string;
+  /** `event_type_version`. */
+  readonly event_type_version?: number;
+  /** `expires_at`: Date after which the event is no longer delivered */
+  readonly expires_at?: string;
   /** `ip`. */
   readonly ip: string;
   /** `labels`. */
@@ -303,6 +845,40 @@ export interface EventWithPayload {
   readonly received_at: string;
 }
 
+/** The `EventsBatchPost` the API declares. */
+export interface EventsBatchPost {
+  /** `application_id`: UUID of the application the events belong to. Events of the batch that carry another application ID are not ingested. */
+  readonly application_id: string;
+  /** `events`: Events to ingest. Length: 1-100 events; the whole request body is also subject to the usual request size limit. */
+  readonly events: EventsBatchPostEvents[];
+}
+
+/** The `EventsBatchPostEvents` the API declares. */
+export interface EventsBatchPostEvents {
+  /** `application_id`: UUID of the application this event belongs to. */
+  readonly application_id: string;
+  /** `deliver_at`: Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the appl */
+  readonly deliver_at?: string;
+  /** `event_id`: Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server. */
+  readonly event_id?: string;
+  /** `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters. */
+  readonly event_type: string;
+  /** `event_type_version`: Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event. */
+  readonly event_type_version?: number;
+  /** `expires_at`: Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted f */
+  readonly expires_at?: string;
+  /** `labels`: Labels for event filtering and routing to subscriptions. */
+  readonly labels: Record<string, string>;
+  /** `metadata`: Optional metadata key-value pairs associated with the event. */
+  readonly metadata?: Record<string, string>;
+  /** `occurred_at`: Timestamp when the event occurred. */
+  readonly occurred_at: string;
+  /** `payload`: The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded). */
+  readonly payload: string;
+  /** `payload_content_type`: Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters. */
+  readonly payload_content_type: string;
+}
+
 /** The `EventsPerDayEntry` the API declares. */
 export interface EventsPerDayEntry {
   /** `amount`. */
@@ -317,16 +893,138 @@ export interface EventsPerDayEntry {
   readonly is_provisional: boolean;
 }
 
+/** The `Inbox` the API declares. */
+export interface Inbox {
+  /** `messages`. */
+  readonly messages: InboxMessages[];
+  /** `next_cursor`: Pass it as `cursor` to the next pull to continue after the last message of this one */
+  readonly next_cursor?: string;
+}
+
+/** The `InboxAck` the API declares. */
+export interface InboxAck {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `receipts`: Receipts of the leased events */
+  readonly receipts: string[];
+}
+
+/** The `InboxMessages` the API declares. */
+export interface InboxMessages {
+  /** `event_id`. */
+  readonly event_id: string;
+  /** `event_type_name`. */
+  readonly event_type_name: string;
+  /** `labels`. */
+  readonly labels: unknown;
+  /** `leased_until`: When the event is offered again if it was neither acknowledged nor rejected */
+  readonly leased_until: string;
+  /** `occurred_at`. */
+  readonly occurred_at: string;
+  /** `payload`: Base64-encoded */
+  readonly payload: string;
+  /** `payload_content_type`. */
+  readonly payload_content_type: string;
+  /** `receipt`: Identifies this lease of the request attempt; a later lease of the same attempt gets another one */
+  readonly receipt: string;
+  /** `received_at`. */
+  readonly received_at: string;
+  /** `request_attempt_id`. */
+  readonly request_attempt_id: string;
+  /** `retry_count`. */
+  readonly retry_count: number;
+}
+
+/** The `InboxNack` the API declares. */
+export interface InboxNack {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `receipts`: Receipts of the leased events */
+  readonly receipts: string[];
+  /** `retry`: Whether the events are offered again, as new request attempts (true by default); they are not beyond the maximum number of retries of the subscription, or of this instance if it has none */
+  readonly retry?: boolean;
+  /** `retry_in_s`: Delay in seconds before the events are offered again (0 by default) */
+  readonly retry_in_s?: number;
+}
+
+/** The `InboxSettlement` the API declares. */
+export interface InboxSettlement {
+  /** `settled`: Receipts of the events that were settled */
+  readonly settled: string[];
+  /** `stale`: Receipts that settled nothing: their lease expired, or the event was already settled */
+  readonly stale: string[];
+}
+
 /** The `IngestedEvent` the API declares. */
 export interface IngestedEvent {
   /** `application_id`. */
   readonly application_id: string;
+  /** `deprecation`: Present when the event was ingested with a deprecated version of its event type */
+  readonly deprecation?: IngestedEventDeprecation;
   /** `event_id`. */
   readonly event_id: string;
   /** `received_at`. */
   readonly received_at: string;
 }
 
+/** The `IngestedEventDeprecation` the API declares. */
+export interface IngestedEventDeprecation {
+  /** `deprecated_at`. */
+  readonly deprecated_at: string;
+  /** `event_type_version`. */
+  readonly event_type_version: number;
+  /** `sunset_at`. */
+  readonly sunset_at?: string;
+}
+
+/** The `IngestedEventsBatch` the API declares. */
+export interface IngestedEventsBatch {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `events`: One outcome per submitted event, in the order the events were submitted */
+  readonly events: IngestedEventsBatchEvents[];
+}
+
+/** The `IngestedEventsBatchEvents` the API declares. */
+export interface IngestedEventsBatchEvents {
+  /** `deprecation`: Present when the event was ingested with a deprecated version of its event type */
+  readonly deprecation?: IngestedEventsBatchEventsDeprecation;
+  /** `event_id`: ID of the ingested event, or the one the event was submitted with when it was not ingested */
+  readonly event_id?: string;
+  /** `problem`: Present when the event was not ingested */
+  readonly problem?: IngestedEventsBatchEventsProblem;
+  /** `received_at`: Present when the event was ingested */
+  readonly received_at?: string;
+  /** `status`. */
+  readonly status: IngestedEventsBatchEventsStatus;
+}
+
+/** The `IngestedEventsBatchEventsDeprecation` the API declares. */
+export interface IngestedEventsBatchEventsDeprecation {
+  /** `deprecated_at`. */
+  readonly deprecated_at: string;
+  /** `event_type_version`. */
+  readonly event_type_version: number;
+  /** `sunset_at`. */
+  readonly sunset_at?: string;
+}
+
+/** The `IngestedEventsBatchEventsProblem` the API declares. */
+export interface IngestedEventsBatchEventsProblem {
+  /** `detail`. */
+  readonly detail: string;
+  /** `id`: Identifier of the problem that occurred, stable across releases */
+  readonly id: IngestedEventsBatchEventsProblemId;
+  /** `status`. */
+  readonly status: number;
+  /** `title`. */
+  readonly title: string;
+  /** `type`: Documentation page of this problem, which is also what distinguishes one problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing a URL. */
+  readonly type: string;
+  /** `validation`: Which submitted values were rejected and why, keyed by the name of the field they were submitted under. Carried by validation failures (`Validation`, status 422) and `null` for every other problem, as */
+  readonly validation?: unknown;
+}
+
 /** The `InstanceConfig` the API declares. */
 export interface InstanceConfig {
   /** `application_secret_compatibility`. */
@@ -367,6 +1065,56 @@ export interface InstanceConfigMatomo {
   readonly url: string;
 }
 
+/** The `JsonWebKeySet` the API declares. */
+export interface JsonWebKeySet {
+  /** `keys`. */
+  readonly keys: JsonWebKeySetKeys[];
+}
+
+/** The `JsonWebKeySetKeys` the API declares. */
+export interface JsonWebKeySetKeys {
+  /** `alg`: Always `EdDSA` */
+  readonly alg: string;
+  /** `crv`: Always `Ed25519` */
+  readonly crv: string;
+  /** `kid`: ID of the signing key, which signatures name in their `k` part */
+  readonly kid: string;
+  /** `kty`: Always `OKP` */
+  readonly kty: string;
+  /** `use`: Always `sig` */
+  readonly use: string;
+  /** `x`: Public key, encoded in base64url without padding */
+  readonly x: string;
+}
+
+/** The `OAuth2ClientCredentials` the API declares. */
+export interface Oauth2ClientCredentials {
+  /** `client_id`. */
+  readonly client_id: string;
+  /** `created_at`. */
+  readonly created_at: string;
+  /** `scopes`. */
+  readonly scopes: string[];
+  /** `subscription_id`. */
+  readonly subscription_id: string;
+  /** `token_url`. */
+  readonly token_url: string;
+  /** `updated_at`. */
+  readonly updated_at: string;
+}
+
+/** The `OAuth2ClientCredentialsPut` the API declares. */
+export interface Oauth2ClientCredentialsPut {
+  /** `client_id`. */
+  readonly client_id: string;
+  /** `client_secret`. */
+  readonly client_secret: string;
+  /** `scopes`: Scopes to request; none by default */
+  readonly scopes?: string[];
+  /** `token_url`: Token endpoint of the authorization server */
+  readonly token_url: string;
+}
+
 /** The `Organization` the API declares. */
 export interface Organization {
   /** `name`. */
@@ -477,6 +1225,20 @@ export interface Problem {
   readonly validation?: unknown;
 }
 
+/** The `PurgeDeadLetters` the API declares. */
+export interface PurgeDeadLetters {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `subscription_id`: Only purge the dead letters of this subscription */
+  readonly subscription_id?: string;
+}
+
+/** The `PurgedDeadLetters` the API declares. */
+export interface PurgedDeadLetters {
+  /** `purged_count`. */
+  readonly purged_count: number;
+}
+
 /** The `QuotasResponse` the API declares. */
 export interface QuotasResponse {
   /** `enabled`. */
@@ -501,6 +1263,28 @@ export interface QuotasResponseLimits {
   readonly global_subscriptions_per_application_limit: number;
 }
 
+/** The `RedeliverDeadLetter` the API declares. */
+export interface RedeliverDeadLetter {
+  /** `application_id`. */
+  readonly application_id: string;
+}
+
+/** The `RedeliverDeadLetters` the API declares. */
+export interface RedeliverDeadLetters {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `subscription_id`: Only redeliver the dead letters of this subscription */
+  readonly subscription_id?: string;
+}
+
+/** The `RedeliveredDeadLetter` the API declares. */
+export interface RedeliveredDeadLetter {
+  /** `dead_letter_id`. */
+  readonly dead_letter_id: string;
+  /** `request_attempt_id`. */
+  readonly request_attempt_id: string;
+}
+
 /** The `ReplayEvent` the API declares. */
 export interface ReplayEvent {
   /** `application_id`. */
@@ -511,8 +1295,12 @@ export interface ReplayEvent {
 export interface RequestAttempt {
   /** `created_at`. */
   readonly created_at: string;
+  /** `dead_letter_id`: Dead letter the request attempt became, when it was the last attempt of a delivery an output worker gave up on */
+  readonly dead_letter_id?: string;
   /** `delay_until`. */
   readonly delay_until?: string;
+  /** `delivery_batch_id`: Batch the request attempt was delivered in, shared with the other request attempts of the batch and their response */
+  readonly delivery_batch_id?: string;
   /** `event`. */
   readonly event: RequestAttemptEvent;
   /** `event_id`. */
@@ -547,13 +1335,15 @@ export interface RequestAttemptEvent {
 
 /** The `RequestAttemptStatus` the API declares. */
 export interface RequestAttemptStatus {
-  /** `at`: Timestamp when completed (present in successful, failed) */
+  /** `at`: Timestamp when completed (present in successful, failed, expired, cancelled) */
   readonly at?: string;
   /** `full_processing_ms`: Total processing time in milliseconds (present in successful, failed) */
   readonly full_processing_ms?: number;
+  /** `rule_outcome`: Outcome given by a response rule of the subscription, when one matched the response (present in successful, failed). One of: success, retryable, permanent_failure, disable_subscription */
+  readonly rule_outcome?: RequestAttemptStatusRuleOutcome;
   /** `since`: Timestamp when the status started (present in waiting, pending, in_progress) */
   readonly since?: string;
-  /** `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed */
+  /** `type`: Status type discriminator. One of: waiting, pending, in_progress, successful, failed, expired, cancelled */
   readonly type: RequestAttemptStatusType;
   /** `until`: Timestamp until which waiting (only present in waiting status) */
   readonly until?: string;
@@ -583,6 +1373,30 @@ export interface Response {
   readonly response_id: string;
 }
 
+/** The `RetryRequestAttempt` the API declares. */
+export interface RetryRequestAttempt {
+  /** `application_id`. */
+  readonly application_id: string;
+}
+
+/** The `SecretRotation` the API declares. */
+export interface SecretRotation {
+  /** `previous_secret`: The secret the new one replaced */
+  readonly previous_secret: string;
+  /** `previous_secret_expires_at`. */
+  readonly previous_secret_expires_at: string;
+  /** `secret`: The new secret of the subscription */
+  readonly secret: string;
+  /** `subscription_id`. */
+  readonly subscription_id: string;
+}
+
+/** The `SecretRotationPost` the API declares. */
+export interface SecretRotationPost {
+  /** `overlap_seconds`: How long the previous secret stays valid, in seconds (one day by default) */
+  readonly overlap_seconds?: number;
+}
+
 /** The `ServiceToken` the API declares. */
 export interface ServiceToken {
   /** `biscuit`. */
@@ -603,18 +1417,52 @@ export interface ServiceTokenPost {
   readonly organization_id: string;
 }
 
+/** The `SigningKey` the API declares. */
+export interface SigningKey {
+  /** `application_id`. */
+  readonly application_id: string;
+  /** `created_at`. */
+  readonly created_at: string;
+  /** `is_current`: Whether deliveries are signed with this key, which only the newest key of the application is */
+  readonly is_current: boolean;
+  /** `public_key`: Public key, encoded in base64url without padding like the `x` member of its JSON Web Key */
+  readonly public_key: string;
+  /** `signing_key_id`: Identifies the key in the `k` part of signatures and as the `kid` of its JSON Web Key */
+  readonly signing_key_id: string;
+}
+
+/** The `SigningKeyPost` the API declares. */
+export interface SigningKeyPost {
+  /** `application_id`. */
+  readonly application_id: string;
+}
+
 /** The `Subscription` the API declares. */
 export interface Subscription {
   /** `application_id`. */
   readonly application_id: string;
+  /** `auto_disabled_at`: When the circuit breaker or a response rule last disabled the subscription; cleared when it is enabled again */
+  readonly auto_disabled_at?: string;
+  /** `auto_disabled_reason`: Why the circuit breaker or a response rule last disabled the subscription */
+  readonly auto_disabled_reason?: string;
+  /** `batching`: Deliver the events of the subscription in batches: one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or once its oldest event has waited `max_wait_s` se */
+  readonly batching?: SubscriptionBatching;
+  /** `circuit_breaker`: Disables the subscription once its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set */
+  readonly circuit_breaker?: SubscriptionCircuitBreaker;
   /** `created_at`. */
   readonly created_at: string;
   /** `dedicated_workers`. */
   readonly dedicated_workers: string[];
+  /** `delivery_limits`: Limits on how fast deliveries of the subscription start; deliveries over them wait for their turn, which does not count as a failure; at least one limit must be set */
+  readonly delivery_limits?: SubscriptionDeliveryLimits;
   /** `description`. */
   readonly description?: string;
+  /** `event_type_versions`: Versions the subscription is pinned to, by event type */
+  readonly event_type_versions: Record<string, number>;
   /** `event_types`. */
   readonly event_types: string[];
+  /** `filter`: An object with exactly one of: `and` or `or` (a list of filters), `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload); `label` and `field` go alon */
+  readonly filter?: unknown;
   /** `is_enabled`. */
   readonly is_enabled: boolean;
   /** `label_key`: _Kept for backward compatibility, you should use `labels`_ */
@@ -625,8 +1473,18 @@ export interface Subscription {
   readonly labels: Record<string, string>;
   /** `metadata`. */
   readonly metadata: Record<string, string>;
+  /** `ordering_key`: Where the key of an event comes from, to deliver the events that share a key one at a time and in the order they were received; exactly one of `label` or `field` must be set */
+  readonly ordering_key?: SubscriptionOrderingKey;
+  /** `payload_transform`: Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a pay */
+  readonly payload_transform?: SubscriptionPayloadTransform;
+  /** `response_rules`. */
+  readonly response_rules?: SubscriptionResponseRules[];
+  /** `retry_policy`: Retry schedule of the subscription, replacing the one of the instance within its limits; every field left out keeps the value of the instance */
+  readonly retry_policy?: SubscriptionRetryPolicy;
   /** `secret`. */
   readonly secret: string;
+  /** `signature_scheme`. */
+  readonly signature_scheme: SubscriptionSignatureScheme;
   /** `subscription_id`. */
   readonly subscription_id: string;
   /** `target`. */
@@ -635,16 +1493,78 @@ export interface Subscription {
   readonly updated_at: string;
 }
 
+/** The `SubscriptionBatching` the API declares. */
+export interface SubscriptionBatching {
+  /** `max_events`: Largest number of events in a batch */
+  readonly max_events: number;
+  /** `max_wait_s`: Longest time in seconds an event waits for its batch to fill up */
+  readonly max_wait_s: number;
+}
+
+/** The `SubscriptionCircuitBreaker` the API declares. */
+export interface SubscriptionCircuitBreaker {
+  /** `max_consecutive_failures`: Number of failed deliveries in a row after which the subscription is disabled */
+  readonly max_consecutive_failures?: number;
+  /** `max_failing_hours`: Number of hours without a single successful delivery after which the subscription is disabled */
+  readonly max_failing_hours?: number;
+}
+
+/** The `SubscriptionDeliveryLimits` the API declares. */
+export interface SubscriptionDeliveryLimits {
+  /** `max_in_flight`: Number of deliveries that can be in flight at once */
+  readonly max_in_flight?: number;
+  /** `max_per_second`: Number of deliveries that can start in a second */
+  readonly max_per_second?: number;
+  /** `per_host`: Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone */
+  readonly per_host?: boolean;
+}
+
+/** The `SubscriptionOrderingKey` the API declares. */
+export interface SubscriptionOrderingKey {
+  /** `field`: JSON pointer to the value holding the key in a JSON payload, for example `/customer/id` */
+  readonly field?: string;
+  /** `label`: Name of the event label holding the key */
+  readonly label?: string;
+}
+
+/** The `SubscriptionPayloadTransform` the API declares. */
+export interface SubscriptionPayloadTransform {
+  /** `constants`: Properties added at the top level of the result, overriding properties of the same name (the result must then be an object) */
+  readonly constants?: Record<string, unknown>;
+  /** `envelope`: Name of a property the result is wrapped in */
+  readonly envelope?: string;
+  /** `fields`: Values to copy from the payload into a new object; when omitted, the whole payload is kept */
+  readonly fields?: SubscriptionPayloadTransformFields[];
+}
+
+/** The `SubscriptionPayloadTransformFields` the API declares. */
+export interface SubscriptionPayloadTransformFields {
+  /** `from`: JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped) */
+  readonly from: string;
+  /** `to`: JSON pointer where the value is written in the result, for example `/id`; defaults to `from` */
+  readonly to?: string;
+}
+
 /** The `SubscriptionPost` the API declares. */
 export interface SubscriptionPost {
   /** `application_id`. */
   readonly application_id: string;
+  /** `batching`: Deliver the events in batches, as one HTTP request with a JSON array body, rather than one by one */
+  readonly batching?: SubscriptionPostBatching;
+  /** `circuit_breaker`: Disable the subscription once its deliveries keep failing; organization editors are notified by email */
+  readonly circuit_breaker?: SubscriptionPostCircuitBreaker;
   /** `dedicated_workers`. */
   readonly dedicated_workers?: string[];
+  /** `delivery_limits`: Limit how fast deliveries start, for this subscription or for its host */
+  readonly delivery_limits?: SubscriptionPostDeliveryLimits;
   /** `description`. */
   readonly description?: string;
+  /** `event_type_versions`: Only receive the events of these event types that declare this version (or no version at all) */
+  readonly event_type_versions?: Record<string, number>;
   /** `event_types`. */
   readonly event_types: string[];
+  /** `filter`: Only deliver the events of the subscribed types that match this expression over labels and JSON payload fields */
+  readonly filter?: unknown;
   /** `is_enabled`. */
   readonly is_enabled: boolean;
   /** `label_key`: _Kept for backward compatibility, you should use `labels`_ */
@@ -655,30 +1575,184 @@ export interface SubscriptionPost {
   readonly labels?: Record<string, string>;
   /** `metadata`. */
   readonly metadata?: Record<string, string>;
+  /** `ordering_key`: Deliver the events that share a key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out of retries */
+  readonly ordering_key?: SubscriptionPostOrderingKey;
+  /** `payload_transform`: Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a pay */
+  readonly payload_transform?: SubscriptionPostPayloadTransform;
+  /** `response_rules`: Decide the outcome of HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body reports an error; the first rule that matches wins, and a response n */
+  readonly response_rules?: SubscriptionPostResponseRules[];
+  /** `retry_policy`: Retry failed deliveries on this schedule rather than the instance's one */
+  readonly retry_policy?: SubscriptionPostRetryPolicy;
+  /** `signature_scheme`: How deliveries are signed (`hook0` by default); `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of the subscription s */
+  readonly signature_scheme?: SubscriptionPostSignatureScheme;
   /** `target`. */
   readonly target: SubscriptionPostTarget;
 }
 
+/** The `SubscriptionPostBatching` the API declares. */
+export interface SubscriptionPostBatching {
+  /** `max_events`: Largest number of events in a batch */
+  readonly max_events: number;
+  /** `max_wait_s`: Longest time in seconds an event waits for its batch to fill up */
+  readonly max_wait_s: number;
+}
+
+/** The `SubscriptionPostCircuitBreaker` the API declares. */
+export interface SubscriptionPostCircuitBreaker {
+  /** `max_consecutive_failures`: Number of failed deliveries in a row after which the subscription is disabled */
+  readonly max_consecutive_failures?: number;
+  /** `max_failing_hours`: Number of hours without a single successful delivery after which the subscription is disabled */
+  readonly max_failing_hours?: number;
+}
+
+/** The `SubscriptionPostDeliveryLimits` the API declares. */
+export interface SubscriptionPostDeliveryLimits {
+  /** `max_in_flight`: Number of deliveries that can be in flight at once */
+  readonly max_in_flight?: number;
+  /** `max_per_second`: Number of deliveries that can start in a second */
+  readonly max_per_second?: number;
+  /** `per_host`: Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone */
+  readonly per_host?: boolean;
+}
+
+/** The `SubscriptionPostOrderingKey` the API declares. */
+export interface SubscriptionPostOrderingKey {
+  /** `field`: JSON pointer to the value holding the key in a JSON payload, for example `/customer/id` */
+  readonly field?: string;
+  /** `label`: Name of the event label holding the key */
+  readonly label?: string;
+}
+
+/** The `SubscriptionPostPayloadTransform` the API declares. */
+export interface SubscriptionPostPayloadTransform {
+  /** `constants`: Properties added at the top level of the result, overriding properties of the same name (the result must then be an object) */
+  readonly constants?: Record<string, unknown>;
+  /** `envelope`: Name of a property the result is wrapped in */
+  readonly envelope?: string;
+  /** `fields`: Values to copy from the payload into a new object; when omitted, the whole payload is kept */
+  readonly fields?: SubscriptionPostPayloadTransformFields[];
+}
+
+/** The `SubscriptionPostPayloadTransformFields` the API declares. */
+export interface SubscriptionPostPayloadTransformFields {
+  /** `from`: JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped) */
+  readonly from: string;
+  /** `to`: JSON pointer where the value is written in the result, for example `/id`; defaults to `from` */
+  readonly to?: string;
+}
+
+/** The `SubscriptionPostResponseRules` the API declares. */
+export interface SubscriptionPostResponseRules {
+  /** `body_contains`: Text the response body must contain */
+  readonly body_contains?: string;
+  /** `header`: Header the response must have */
+  readonly header?: SubscriptionPostResponseRulesHeader;
+  /** `outcome`: What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled) */
+  readonly outcome: SubscriptionPostResponseRulesOutcome;
+  /** `status`: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx` */
+  readonly status: string[];
+}
+
+/** The `SubscriptionPostResponseRulesHeader` the API declares. */
+export interface SubscriptionPostResponseRulesHeader {
+  /** `name`: Name of the header, regardless of case */
+  readonly name: string;
+  /** `value`: Value the header must have; when omitted, any value matches */
+  readonly value?: string;
+}
+
+/** The `SubscriptionPostRetryPolicy` the API declares. */
+export interface SubscriptionPostRetryPolicy {
+  /** `backoff_steps_s`: Delays in seconds to wait before each retry; the last one is used again for the following retries */
+  readonly backoff_steps_s?: number[];
+  /** `max_retries`: Maximum number of retries after a failed delivery (0 disables retries) */
+  readonly max_retries?: number;
+  /** `max_window_s`: Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled */
+  readonly max_window_s?: number;
+}
+
 /** The `SubscriptionPostTarget` the API declares. */
 export interface SubscriptionPostTarget {
-  /** `headers`. */
-  readonly headers: unknown;
-  /** `method`. */
-  readonly method: string;
-  /** `type`. */
-  readonly type: string;
-  /** `url`. */
-  readonly url: string;
+  /** `access_key_id`: `sqs`: access key ID requests are signed with */
+  readonly access_key_id?: string;
+  /** `exchange`: `amqp`: exchange messages are published to; empty for the default exchange */
+  readonly exchange?: string;
+  /** `headers`: `http`: headers sent with every request */
+  readonly headers?: unknown;
+  /** `method`: `http`: HTTP method of the requests */
+  readonly method?: string;
+  /** `password`: `amqp`: password to log in to the broker with; write-only */
+  readonly password?: string;
+  /** `queue_url`: `sqs`: URL of the queue */
+  readonly queue_url?: string;
+  /** `region`: `sqs`: region requests are signed for */
+  readonly region?: string;
+  /** `routing_key`: `amqp`: routing key messages are published with */
+  readonly routing_key?: string;
+  /** `secret_access_key`: `sqs`: secret access key requests are signed with; write-only */
+  readonly secret_access_key?: string;
+  /** `type`: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it */
+  readonly type: SubscriptionPostTargetType;
+  /** `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials */
+  readonly url?: string;
+  /** `username`: `amqp`: user name to log in to the broker with */
+  readonly username?: string;
+}
+
+/** The `SubscriptionResponseRules` the API declares. */
+export interface SubscriptionResponseRules {
+  /** `body_contains`: Text the response body must contain */
+  readonly body_contains?: string;
+  /** `header`: Header the response must have */
+  readonly header?: SubscriptionResponseRulesHeader;
+  /** `outcome`: What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled) */
+  readonly outcome: SubscriptionResponseRulesOutcome;
+  /** `status`: Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx` */
+  readonly status: string[];
+}
+
+/** The `SubscriptionResponseRulesHeader` the API declares. */
+export interface SubscriptionResponseRulesHeader {
+  /** `name`: Name of the header, regardless of case */
+  readonly name: string;
+  /** `value`: Value the header must have; when omitted, any value matches */
+  readonly value?: string;
+}
+
+/** The `SubscriptionRetryPolicy` the API declares. */
+export interface SubscriptionRetryPolicy {
+  /** `backoff_steps_s`: Delays in seconds to wait before each retry; the last one is used again for the following retries */
+  readonly backoff_steps_s?: number[];
+  /** `max_retries`: Maximum number of retries after a failed delivery (0 disables retries) */
+  readonly max_retries?: number;
+  /** `max_window_s`: Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled */
+  readonly max_window_s?: number;
 }
 
 /** The `SubscriptionTarget` the API declares. */
 export interface SubscriptionTarget {
-  /** `headers`. */
-  readonly headers: unknown;
-  /** `method`. */
-  readonly method: string;
-  /** `type`. */
-  readonly type: string;
-  /** `url`. */
-  readonly url: string;
+  /** `access_key_id`: `sqs`: access key ID requests are signed with */
+  readonly access_key_id?: string;
+  /** `exchange`: `amqp`: exchange messages are published to; empty for the default exchange */
+  readonly exchange?: string;
+  /** `headers`: `http`: headers sent with every request */
+  readonly headers?: unknown;
+  /** `method`: `http`: HTTP method of the requests */
+  readonly method?: string;
+  /** `password`: `amqp`: password to log in to the broker with; write-only */
+  readonly password?: string;
+  /** `queue_url`: `sqs`: URL of the queue */
+  readonly queue_url?: string;
+  /** `region`: `sqs`: region requests are signed for */
+  readonly region?: string;
+  /** `routing_key`: `amqp`: routing key messages are published with */
+  readonly routing_key?: string;
+  /** `secret_access_key`: `sqs`: secret access key requests are signed with; write-only */
+  readonly secret_access_key?: string;
+  /** `type`: Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it */
+  readonly type: SubscriptionTargetType;
+  /** `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials */
+  readonly url?: string;
+  /** `username`: `amqp`: user name to log in to the broker with */
+  readonly username?: string;
 }
@@ -310,8 +310,8 @@ pub const ApplicationsApi = struct {
     }
 };
 
-/// What the API declares under `errors`, issued through the transport it is built on.
-pub const ErrorsApi = struct {
+/// What the API declares under `bulkRetries`, issued through the transport it is built on.
+pub const BulkRetriesApi = struct {
     /// What one request is issued through.
     transport: runtime.Transport,
     /// Where what a failure of this group reported is read into.
@@ -325,27 +325,36 @@ pub const ErrorsApi = struct {
     pub fn init(
         allocator: std.mem.Allocator,
         transport: runtime.Transport,
-    ) ErrorsApi {
+    ) BulkRetriesApi {
         return .{ .allocator = allocator, .transport = transport };
     }
 
     /// Frees what the last failure of this group reported.
-    pub fn deinit(self: *ErrorsApi) void {
+    pub fn deinit(self: *BulkRetriesApi) void {
         self.reported.deinit();
     }
 
-    /// List errors
-    pub fn list(
-        self: *ErrorsApi,
+    /// Cancel a bulk retry
+    /// `bulk_retry_id` carries `bulk_retry_id`.
+    /// `application_id` carries `application_id`.
+    pub fn cancel(
+        self: *BulkRetriesApi,
         allocator: std.mem.Allocator,
-    ) !runtime.Owned([]const models.Problem) {
-        var owned: runtime.Owned([]const models.Problem) = try .init(allocator);
+        bulk_retry_id: []const u8,
+        application_id: []const u8,
+    ) !runtime.Owned(models.BulkRetry) {
+        var owned: runtime.Owned(models.BulkRetry) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
-            .method = "GET",
-            .path = "/api/v1/errors/",
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/bulk_retries/{bulk_retry_id}/cancel", &.{
+                .{ .name = "bulk_retry_id", .value = runtime.value(bulk_retry_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
         });
         try errors.raiseForStatus(
             self.allocator,
@@ -354,48 +363,23 @@ pub const ErrorsApi = struct {
             &self.reported,
         );
 
-        owned.value = try runtime.list(models.Problem.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.BulkRetry.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
-};
-
-/// What the API declares under `eventTypes`, issued through the transport it is built on.
-pub const EventTypesApi = struct {
-    /// What one request is issued through.
-    transport: runtime.Transport,
-    /// Where what a failure of this group reported is read into.
-    ///
-    /// Not the allocator a call is handed: that one frees what the call allocated on its way
-    /// out, and what the failure reported is read after the call has returned.
-    allocator: std.mem.Allocator,
-    /// What the last failure of this group reported, which an error alone cannot carry.
-    reported: errors.Reported = .empty,
-
-    pub fn init(
-        allocator: std.mem.Allocator,
-        transport: runtime.Transport,
-    ) EventTypesApi {
-        return .{ .allocator = allocator, .transport = transport };
-    }
-
-    /// Frees what the last failure of this group reported.
-    pub fn deinit(self: *EventTypesApi) void {
-        self.reported.deinit();
-    }
 
-    /// Create a new event type
+    /// Create a bulk retry
     pub fn create(
-        self: *EventTypesApi,
+        self: *BulkRetriesApi,
         allocator: std.mem.Allocator,
-        body: models.EventTypePost,
-    ) !runtime.Owned(models.EventType) {
-        var owned: runtime.Owned(models.EventType) = try .init(allocator);
+        body: models.BulkRetryPost,
+    ) !runtime.Owned(models.BulkRetry) {
+        var owned: runtime.Owned(models.BulkRetry) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
             .method = "POST",
-            .path = "/api/v1/event_types/",
+            .path = "/api/v1/bulk_retries/",
             .body = try runtime.written(arena, body),
         });
         try errors.raiseForStatus(
@@ -405,31 +389,24 @@ pub const EventTypesApi = struct {
             &self.reported,
         );
 
-        owned.value = try models.EventType.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.BulkRetry.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
 
-    /// Delete an event type
-    /// `event_type_name` carries `event_type_name`.
-    /// `application_id` carries `application_id`.
-    pub fn delete(
-        self: *EventTypesApi,
+    /// Count what a bulk retry would redeliver
+    pub fn dryRun(
+        self: *BulkRetriesApi,
         allocator: std.mem.Allocator,
-        event_type_name: []const u8,
-        application_id: []const u8,
-    ) !void {
-        var held: std.heap.ArenaAllocator = .init(allocator);
-        defer held.deinit();
-        const arena = held.allocator();
+        body: models.BulkRetryPost,
+    ) !runtime.Owned(models.BulkRetryDryRun) {
+        var owned: runtime.Owned(models.BulkRetryDryRun) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
-            .method = "DELETE",
-            .path = try runtime.path(arena, "/api/v1/event_types/{event_type_name}", &.{
-                .{ .name = "event_type_name", .value = runtime.value(event_type_name) },
-            }),
-            .query = &.{
-                .{ .name = "application_id", .value = runtime.value(application_id) },
-            },
+            .method = "POST",
+            .path = "/api/v1/bulk_retries/dry_run",
+            .body = try runtime.written(arena, body),
         });
         try errors.raiseForStatus(
             self.allocator,
@@ -437,25 +414,28 @@ pub const EventTypesApi = struct {
             answered.payload,
             &self.reported,
         );
+
+        owned.value = try models.BulkRetryDryRun.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
     }
 
-    /// Get an event type by its name
-    /// `event_type_name` carries `event_type_name`.
+    /// Get a bulk retry
+    /// `bulk_retry_id` carries `bulk_retry_id`.
     /// `application_id` carries `application_id`.
     pub fn get(
-        self: *EventTypesApi,
+        self: *BulkRetriesApi,
         allocator: std.mem.Allocator,
-        event_type_name: []const u8,
+        bulk_retry_id: []const u8,
         application_id: []const u8,
-    ) !runtime.Owned(models.EventType) {
-        var owned: runtime.Owned(models.EventType) = try .init(allocator);
+    ) !runtime.Owned(models.BulkRetry) {
+        var owned: runtime.Owned(models.BulkRetry) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
             .method = "GET",
-            .path = try runtime.path(arena, "/api/v1/event_types/{event_type_name}", &.{
-                .{ .name = "event_type_name", .value = runtime.value(event_type_name) },
+            .path = try runtime.path(arena, "/api/v1/bulk_retries/{bulk_retry_id}", &.{
+                .{ .name = "bulk_retry_id", .value = runtime.value(bulk_retry_id) },
             }),
             .query = &.{
                 .{ .name = "application_id", .value = runtime.value(application_id) },
@@ -468,24 +448,24 @@ pub const EventTypesApi = struct {
             &self.reported,
         );
 
-        owned.value = try models.EventType.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.BulkRetry.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
 
-    /// List event types
+    /// List bulk retries
     /// `application_id` carries `application_id`.
     pub fn list(
-        self: *EventTypesApi,
+        self: *BulkRetriesApi,
         allocator: std.mem.Allocator,
         application_id: []const u8,
-    ) !runtime.Owned([]const models.EventType) {
-        var owned: runtime.Owned([]const models.EventType) = try .init(allocator);
+    ) !runtime.Owned([]const models.BulkRetry) {
+        var owned: runtime.Owned([]const models.BulkRetry) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
             .method = "GET",
-            .path = "/api/v1/event_types/",
+            .path = "/api/v1/bulk_retries/",
             .query = &.{
                 .{ .name = "application_id", .value = runtime.value(application_id) },
             },
@@ -497,13 +477,13 @@ pub const EventTypesApi = struct {
             &self.reported,
         );
 
-        owned.value = try runtime.list(models.EventType.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try runtime.list(models.BulkRetry.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
 };
 
-/// What the API declares under `events`, issued through the transport it is built on.
-pub const EventsApi = struct {
+/// What the API declares under `clientCertificates`, issued through the transport it is built on.
+pub const ClientCertificatesApi = struct {
     /// What one request is issued through.
     transport: runtime.Transport,
     /// Where what a failure of this group reported is read into.
@@ -517,32 +497,32 @@ pub const EventsApi = struct {
     pub fn init(
         allocator: std.mem.Allocator,
         transport: runtime.Transport,
-    ) EventsApi {
+    ) ClientCertificatesApi {
         return .{ .allocator = allocator, .transport = transport };
     }
 
     /// Frees what the last failure of this group reported.
-    pub fn deinit(self: *EventsApi) void {
+    pub fn deinit(self: *ClientCertificatesApi) void {
         self.reported.deinit();
     }
 
-    /// Get an event by its ID
-    /// `event_id` carries `event_id`.
+    /// Remove the client certificate of a subscription
+    /// `subscription_id` carries `subscription_id`.
     /// `application_id` carries `application_id`.
-    pub fn get(
-        self: *EventsApi,
+    pub fn delete(
+        self: *ClientCertificatesApi,
         allocator: std.mem.Allocator,
-        event_id: []const u8,
+        subscription_id: []const u8,
         application_id: []const u8,
-    ) !runtime.Owned(models.EventWithPayload) {
-        var owned: runtime.Owned(models.EventWithPayload) = try .init(allocator);
-        errdefer owned.deinit();
-        const arena = owned.arena.allocator();
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
 
         const answered = try self.transport.request(arena, .{
-            .method = "GET",
-            .path = try runtime.path(arena, "/api/v1/events/{event_id}", &.{
-                .{ .name = "event_id", .value = runtime.value(event_id) },
+            .method = "DELETE",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/client_certificate", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
             }),
             .query = &.{
                 .{ .name = "application_id", .value = runtime.value(application_id) },
@@ -554,25 +534,29 @@ pub const EventsApi = struct {
             answered.payload,
             &self.reported,
         );
-
-        owned.value = try models.EventWithPayload.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
-        return owned;
     }
 
-    /// Ingest an event
-    pub fn ingest(
-        self: *EventsApi,
+    /// Get the client certificate of a subscription
+    /// `subscription_id` carries `subscription_id`.
+    /// `application_id` carries `application_id`.
+    pub fn get(
+        self: *ClientCertificatesApi,
         allocator: std.mem.Allocator,
-        body: models.EventPost,
-    ) !runtime.Owned(models.IngestedEvent) {
-        var owned: runtime.Owned(models.IngestedEvent) = try .init(allocator);
+        subscription_id: []const u8,
+        application_id: []const u8,
+    ) !runtime.Owned(models.ClientCertificate) {
+        var owned: runtime.Owned(models.ClientCertificate) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
-            .method = "POST",
-            .path = "/api/v1/event/",
-            .body = try runtime.written(arena, body),
+            .method = "GET",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/client_certificate", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
         });
         try errors.raiseForStatus(
             self.allocator,
@@ -581,27 +565,33 @@ pub const EventsApi = struct {
             &self.reported,
         );
 
-        owned.value = try models.IngestedEvent.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.ClientCertificate.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
 
-    /// List latest events
+    /// Set the client certificate of a subscription
+    /// `subscription_id` carries `subscription_id`.
     /// `application_id` carries `application_id`.
-    pub fn list(
-        self: *EventsApi,
+    pub fn update(
+        self: *ClientCertificatesApi,
         allocator: std.mem.Allocator,
+        subscription_id: []const u8,
         application_id: []const u8,
-    ) !runtime.Owned([]const models.Event) {
-        var owned: runtime.Owned([]const models.Event) = try .init(allocator);
+        body: models.ClientCertificatePut,
+    ) !runtime.Owned(models.ClientCertificate) {
+        var owned: runtime.Owned(models.ClientCertificate) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
-            .method = "GET",
-            .path = "/api/v1/events/",
+            .method = "PUT",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/client_certificate", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
             .query = &.{
                 .{ .name = "application_id", .value = runtime.value(application_id) },
             },
+            .body = try runtime.written(arena, body),
         });
         try errors.raiseForStatus(
             self.allocator,
@@ -610,40 +600,13 @@ pub const EventsApi = struct {
             &self.reported,
         );
 
-        owned.value = try runtime.list(models.Event.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.ClientCertificate.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
-
-    /// Replay an event
-    /// `event_id` carries `event_id`.
-    pub fn replay(
-        self: *EventsApi,
-        allocator: std.mem.Allocator,
-        event_id: []const u8,
-        body: models.ReplayEvent,
-    ) !void {
-        var held: std.heap.ArenaAllocator = .init(allocator);
-        defer held.deinit();
-        const arena = held.allocator();
-
-        const answered = try self.transport.request(arena, .{
-            .method = "POST",
-            .path = try runtime.path(arena, "/api/v1/events/{event_id}/replay", &.{
-                .{ .name = "event_id", .value = runtime.value(event_id) },
-            }),
-            .body = try runtime.written(arena, body),
-        });
-        try errors.raiseForStatus(
-            self.allocator,
-            answered.status,
-            answered.payload,
-            &self.reported,
-        );
-    }
 };
 
-/// What the API declares under `events_per_day`, issued through the transport it is built on.
-pub const EventsPerDayApi = struct {
+/// What the API declares under `deadLetters`, issued through the transport it is built on.
+pub const DeadLettersApi = struct {
     /// What one request is issued through.
     transport: runtime.Transport,
     /// Where what a failure of this group reported is read into.
@@ -657,37 +620,65 @@ pub const EventsPerDayApi = struct {
     pub fn init(
         allocator: std.mem.Allocator,
         transport: runtime.Transport,
-    ) EventsPerDayApi {
+    ) DeadLettersApi {
         return .{ .allocator = allocator, .transport = transport };
     }
 
     /// Frees what the last failure of this group reported.
-    pub fn deinit(self: *EventsPerDayApi) void {
+    pub fn deinit(self: *DeadLettersApi) void {
         self.reported.deinit();
     }
 
-    /// List events per day for an application
+    /// Delete a dead letter
+    /// `dead_letter_id` carries `dead_letter_id`.
     /// `application_id` carries `application_id`.
-    /// `from` carries `from`: Start of date range (inclusive). Defaults to 30 days before `to`.
-    /// `to` carries `to`: End of date range (inclusive). Defaults to today.
-    pub fn listForApplication(
-        self: *EventsPerDayApi,
+    pub fn delete(
+        self: *DeadLettersApi,
         allocator: std.mem.Allocator,
+        dead_letter_id: []const u8,
         application_id: []const u8,
-        from: ?[]const u8,
-        to: ?[]const u8,
-    ) !runtime.Owned([]const models.EventsPerDayEntry) {
-        var owned: runtime.Owned([]const models.EventsPerDayEntry) = try .init(allocator);
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "DELETE",
+            .path = try runtime.path(arena, "/api/v1/dead_letters/{dead_letter_id}", &.{
+                .{ .name = "dead_letter_id", .value = runtime.value(dead_letter_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+    }
+
+    /// Get a dead letter by its ID
+    /// `dead_letter_id` carries `dead_letter_id`.
+    /// `application_id` carries `application_id`.
+    pub fn get(
+        self: *DeadLettersApi,
+        allocator: std.mem.Allocator,
+        dead_letter_id: []const u8,
+        application_id: []const u8,
+    ) !runtime.Owned(models.DeadLetterDetails) {
+        var owned: runtime.Owned(models.DeadLetterDetails) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
             .method = "GET",
-            .path = "/api/v1/events_per_day/application",
+            .path = try runtime.path(arena, "/api/v1/dead_letters/{dead_letter_id}", &.{
+                .{ .name = "dead_letter_id", .value = runtime.value(dead_letter_id) },
+            }),
             .query = &.{
                 .{ .name = "application_id", .value = runtime.value(application_id) },
-                .{ .name = "from", .value = runtime.value(from) },
-                .{ .name = "to", .value = runtime.value(to) },
             },
         });
         try errors.raiseForStatus(
@@ -697,32 +688,32 @@ pub const EventsPerDayApi = struct {
             &self.reported,
         );
 
-        owned.value = try runtime.list(models.EventsPerDayEntry.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.DeadLetterDetails.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
 
-    /// List events per day for an organization
-    /// `organization_id` carries `organization_id`.
-    /// `from` carries `from`: Start of date range (inclusive). Defaults to 30 days before `to`.
-    /// `to` carries `to`: End of date range (inclusive). Defaults to today.
-    pub fn listForOrganization(
-        self: *EventsPerDayApi,
+    /// List dead letters
+    /// `application_id` carries `application_id`.
+    /// `pagination_cursor` carries `pagination_cursor`.
+    /// `subscription_id` carries `subscription_id`.
+    pub fn list(
+        self: *DeadLettersApi,
         allocator: std.mem.Allocator,
-        organization_id: []const u8,
-        from: ?[]const u8,
-        to: ?[]const u8,
-    ) !runtime.Owned([]const models.EventsPerDayEntry) {
-        var owned: runtime.Owned([]const models.EventsPerDayEntry) = try .init(allocator);
+        application_id: []const u8,
+        pagination_cursor: ?[]const u8,
+        subscription_id: ?[]const u8,
+    ) !runtime.Owned([]const models.DeadLetter) {
+        var owned: runtime.Owned([]const models.DeadLetter) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
             .method = "GET",
-            .path = "/api/v1/events_per_day/organization",
+            .path = "/api/v1/dead_letters/",
             .query = &.{
-                .{ .name = "organization_id", .value = runtime.value(organization_id) },
-                .{ .name = "from", .value = runtime.value(from) },
-                .{ .name = "to", .value = runtime.value(to) },
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+                .{ .name = "pagination_cursor", .value = runtime.value(pagination_cursor) },
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
             },
         });
         try errors.raiseForStatus(
@@ -732,16 +723,849 @@ pub const EventsPerDayApi = struct {
             &self.reported,
         );
 
-        owned.value = try runtime.list(models.EventsPerDayEntry.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try runtime.list(models.DeadLetter.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
-};
 
-/// What the API declares under `instance`, issued through the transport it is built on.
-pub const InstanceApi = struct {
-    /// What one request is issued through.
-    transport: runtime.Transport,
-    /// Where what a failure of this group reported is read into.
+    /// Purge dead letters
+    pub fn purge(
+        self: *DeadLettersApi,
+        allocator: std.mem.Allocator,
+        body: models.PurgeDeadLetters,
+    ) !runtime.Owned(models.PurgedDeadLetters) {
+        var owned: runtime.Owned(models.PurgedDeadLetters) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = "/api/v1/dead_letters/purge",
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.PurgedDeadLetters.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Redeliver a dead letter
+    /// `dead_letter_id` carries `dead_letter_id`.
+    pub fn redeliver(
+        self: *DeadLettersApi,
+        allocator: std.mem.Allocator,
+        dead_letter_id: []const u8,
+        body: models.RedeliverDeadLetter,
+    ) !runtime.Owned(models.RedeliveredDeadLetter) {
+        var owned: runtime.Owned(models.RedeliveredDeadLetter) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/dead_letters/{dead_letter_id}/redeliver", &.{
+                .{ .name = "dead_letter_id", .value = runtime.value(dead_letter_id) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.RedeliveredDeadLetter.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Redeliver dead letters
+    pub fn redeliverMany(
+        self: *DeadLettersApi,
+        allocator: std.mem.Allocator,
+        body: models.RedeliverDeadLetters,
+    ) !runtime.Owned([]const models.RedeliveredDeadLetter) {
+        var owned: runtime.Owned([]const models.RedeliveredDeadLetter) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = "/api/v1/dead_letters/redeliver",
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try runtime.list(models.RedeliveredDeadLetter.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
+/// What the API declares under `errors`, issued through the transport it is built on.
+pub const ErrorsApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) ErrorsApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *ErrorsApi) void {
+        self.reported.deinit();
+    }
+
+    /// List errors
+    pub fn list(
+        self: *ErrorsApi,
+        allocator: std.mem.Allocator,
+    ) !runtime.Owned([]const models.Problem) {
+        var owned: runtime.Owned([]const models.Problem) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/errors/",
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try runtime.list(models.Problem.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
+/// What the API declares under `eventTypes`, issued through the transport it is built on.
+pub const EventTypesApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) EventTypesApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *EventTypesApi) void {
+        self.reported.deinit();
+    }
+
+    /// Create a new event type
+    pub fn create(
+        self: *EventTypesApi,
+        allocator: std.mem.Allocator,
+        body: models.EventTypePost,
+    ) !runtime.Owned(models.EventType) {
+        var owned: runtime.Owned(models.EventType) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = "/api/v1/event_types/",
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.EventType.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Create a new event type version
+    /// `event_type_name` carries `event_type_name`.
+    pub fn createVersion(
+        self: *EventTypesApi,
+        allocator: std.mem.Allocator,
+        event_type_name: []const u8,
+        body: models.EventTypeVersionPost,
+    ) !runtime.Owned(models.EventTypeVersion) {
+        var owned: runtime.Owned(models.EventTypeVersion) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/event_types/{event_type_name}/versions", &.{
+                .{ .name = "event_type_name", .value = runtime.value(event_type_name) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.EventTypeVersion.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Delete an event type
+    /// `event_type_name` carries `event_type_name`.
+    /// `application_id` carries `application_id`.
+    pub fn delete(
+        self: *EventTypesApi,
+        allocator: std.mem.Allocator,
+        event_type_name: []const u8,
+        application_id: []const u8,
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "DELETE",
+            .path = try runtime.path(arena, "/api/v1/event_types/{event_type_name}", &.{
+                .{ .name = "event_type_name", .value = runtime.value(event_type_name) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+    }
+
+    /// Deprecate an event type version
+    /// `event_type_name` carries `event_type_name`.
+    pub fn deprecateVersion(
+        self: *EventTypesApi,
+        allocator: std.mem.Allocator,
+        event_type_name: []const u8,
+        body: models.EventTypeVersionDeprecate,
+    ) !runtime.Owned(models.EventTypeVersion) {
+        var owned: runtime.Owned(models.EventTypeVersion) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/event_types/{event_type_name}/deprecate", &.{
+                .{ .name = "event_type_name", .value = runtime.value(event_type_name) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.EventTypeVersion.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Get an event type by its name
+    /// `event_type_name` carries `event_type_name`.
+    /// `application_id` carries `application_id`.
+    pub fn get(
+        self: *EventTypesApi,
+        allocator: std.mem.Allocator,
+        event_type_name: []const u8,
+        application_id: []const u8,
+    ) !runtime.Owned(models.EventType) {
+        var owned: runtime.Owned(models.EventType) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = try runtime.path(arena, "/api/v1/event_types/{event_type_name}", &.{
+                .{ .name = "event_type_name", .value = runtime.value(event_type_name) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.EventType.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// List event types
+    /// `application_id` carries `application_id`.
+    pub fn list(
+        self: *EventTypesApi,
+        allocator: std.mem.Allocator,
+        application_id: []const u8,
+    ) !runtime.Owned([]const models.EventType) {
+        var owned: runtime.Owned([]const models.EventType) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/event_types/",
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try runtime.list(models.EventType.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Update an event type
+    /// `event_type_name` carries `event_type_name`.
+    pub fn update(
+        self: *EventTypesApi,
+        allocator: std.mem.Allocator,
+        event_type_name: []const u8,
+        body: models.EventTypePut,
+    ) !runtime.Owned(models.EventType) {
+        var owned: runtime.Owned(models.EventType) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "PUT",
+            .path = try runtime.path(arena, "/api/v1/event_types/{event_type_name}", &.{
+                .{ .name = "event_type_name", .value = runtime.value(event_type_name) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.EventType.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
+/// What the API declares under `events`, issued through the transport it is built on.
+pub const EventsApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) EventsApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *EventsApi) void {
+        self.reported.deinit();
+    }
+
+    /// Cancel a scheduled event
+    /// `event_id` carries `event_id`.
+    pub fn cancel(
+        self: *EventsApi,
+        allocator: std.mem.Allocator,
+        event_id: []const u8,
+        body: models.CancelEvent,
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/events/{event_id}/cancel", &.{
+                .{ .name = "event_id", .value = runtime.value(event_id) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+    }
+
+    /// Get an event by its ID
+    /// `event_id` carries `event_id`.
+    /// `application_id` carries `application_id`.
+    pub fn get(
+        self: *EventsApi,
+        allocator: std.mem.Allocator,
+        event_id: []const u8,
+        application_id: []const u8,
+    ) !runtime.Owned(models.EventWithPayload) {
+        var owned: runtime.Owned(models.EventWithPayload) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = try runtime.path(arena, "/api/v1/events/{event_id}", &.{
+                .{ .name = "event_id", .value = runtime.value(event_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.EventWithPayload.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Ingest an event
+    pub fn ingest(
+        self: *EventsApi,
+        allocator: std.mem.Allocator,
+        body: models.EventPost,
+    ) !runtime.Owned(models.IngestedEvent) {
+        var owned: runtime.Owned(models.IngestedEvent) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = "/api/v1/event/",
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.IngestedEvent.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Ingest a batch of events
+    pub fn ingestBatch(
+        self: *EventsApi,
+        allocator: std.mem.Allocator,
+        body: models.EventsBatchPost,
+    ) !runtime.Owned(models.IngestedEventsBatch) {
+        var owned: runtime.Owned(models.IngestedEventsBatch) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = "/api/v1/events/batch",
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.IngestedEventsBatch.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// List latest events
+    /// `application_id` carries `application_id`.
+    /// `scheduled` carries `scheduled`: Only list the events scheduled for a later delivery that
+    /// was not cancelled, soonest first
+    pub fn list(
+        self: *EventsApi,
+        allocator: std.mem.Allocator,
+        application_id: []const u8,
+        scheduled: ?bool,
+    ) !runtime.Owned([]const models.Event) {
+        var owned: runtime.Owned([]const models.Event) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/events/",
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+                .{ .name = "scheduled", .value = runtime.value(scheduled) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try runtime.list(models.Event.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Replay an event
+    /// `event_id` carries `event_id`.
+    pub fn replay(
+        self: *EventsApi,
+        allocator: std.mem.Allocator,
+        event_id: []const u8,
+        body: models.ReplayEvent,
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/events/{event_id}/replay", &.{
+                .{ .name = "event_id", .value = runtime.value(event_id) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+    }
+};
+
+/// What the API declares under `events_per_day`, issued through the transport it is built on.
+pub const EventsPerDayApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) EventsPerDayApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *EventsPerDayApi) void {
+        self.reported.deinit();
+    }
+
+    /// List events per day for an application
+    /// `application_id` carries `application_id`.
+    /// `from` carries `from`: Start of date range (inclusive). Defaults to 30 days before `to`.
+    /// `to` carries `to`: End of date range (inclusive). Defaults to today.
+    pub fn listForApplication(
+        self: *EventsPerDayApi,
+        allocator: std.mem.Allocator,
+        application_id: []const u8,
+        from: ?[]const u8,
+        to: ?[]const u8,
+    ) !runtime.Owned([]const models.EventsPerDayEntry) {
+        var owned: runtime.Owned([]const models.EventsPerDayEntry) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/events_per_day/application",
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+                .{ .name = "from", .value = runtime.value(from) },
+                .{ .name = "to", .value = runtime.value(to) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try runtime.list(models.EventsPerDayEntry.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// List events per day for an organization
+    /// `organization_id` carries `organization_id`.
+    /// `from` carries `from`: Start of date range (inclusive). Defaults to 30 days before `to`.
+    /// `to` carries `to`: End of date range (inclusive). Defaults to today.
+    pub fn listForOrganization(
+        self: *EventsPerDayApi,
+        allocator: std.mem.Allocator,
+        organization_id: []const u8,
+        from: ?[]const u8,
+        to: ?[]const u8,
+    ) !runtime.Owned([]const models.EventsPerDayEntry) {
+        var owned: runtime.Owned([]const models.EventsPerDayEntry) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/events_per_day/organization",
+            .query = &.{
+                .{ .name = "organization_id", .value = runtime.value(organization_id) },
+                .{ .name = "from", .value = runtime.value(from) },
+                .{ .name = "to", .value = runtime.value(to) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try runtime.list(models.EventsPerDayEntry.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
+/// What the API declares under `inbox`, issued through the transport it is built on.
+pub const InboxApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) InboxApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *InboxApi) void {
+        self.reported.deinit();
+    }
+
+    /// Acknowledge events pulled from an inbox
+    /// `subscription_id` carries `subscription_id`.
+    pub fn ack(
+        self: *InboxApi,
+        allocator: std.mem.Allocator,
+        subscription_id: []const u8,
+        body: models.InboxAck,
+    ) !runtime.Owned(models.InboxSettlement) {
+        var owned: runtime.Owned(models.InboxSettlement) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/inbox/{subscription_id}/ack", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.InboxSettlement.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Reject events pulled from an inbox
+    /// `subscription_id` carries `subscription_id`.
+    pub fn nack(
+        self: *InboxApi,
+        allocator: std.mem.Allocator,
+        subscription_id: []const u8,
+        body: models.InboxNack,
+    ) !runtime.Owned(models.InboxSettlement) {
+        var owned: runtime.Owned(models.InboxSettlement) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/inbox/{subscription_id}/nack", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.InboxSettlement.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Pull events from an inbox
+    /// `subscription_id` carries `subscription_id`.
+    /// `application_id` carries `application_id`.
+    /// `cursor` carries `cursor`: Only lease events after this cursor, returned by a previous pull
+    /// `limit` carries `limit`: Maximum number of events to lease (10 by default)
+    /// `visibility_timeout_s` carries `visibility_timeout_s`: For how long in seconds the leased
+    /// events are not offered again (30 by default)
+    pub fn pull(
+        self: *InboxApi,
+        allocator: std.mem.Allocator,
+        subscription_id: []const u8,
+        application_id: []const u8,
+        cursor: ?[]const u8,
+        limit: ?i64,
+        visibility_timeout_s: ?i64,
+    ) !runtime.Owned(models.Inbox) {
+        var owned: runtime.Owned(models.Inbox) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = try runtime.path(arena, "/api/v1/inbox/{subscription_id}", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+                .{ .name = "cursor", .value = runtime.value(cursor) },
+                .{ .name = "limit", .value = runtime.value(limit) },
+                .{ .name = "visibility_timeout_s", .value = runtime.value(visibility_timeout_s) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.Inbox.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
+/// What the API declares under `instance`, issued through the transport it is built on.
+pub const InstanceApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) InstanceApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *InstanceApi) void {
+        self.reported.deinit();
+    }
+
+    /// Get instance configuration
+    pub fn get(
+        self: *InstanceApi,
+        allocator: std.mem.Allocator,
+    ) !runtime.Owned(models.InstanceConfig) {
+        var owned: runtime.Owned(models.InstanceConfig) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/instance/",
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.InstanceConfig.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
+/// What the API declares under `oauth2ClientCredentials`, issued through the transport it is built on.
+pub const Oauth2ClientCredentialsApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
     ///
     /// Not the allocator a call is handed: that one frees what the call allocated on its way
     /// out, and what the failure reported is read after the call has returned.
@@ -752,27 +1576,66 @@ pub const InstanceApi = struct {
     pub fn init(
         allocator: std.mem.Allocator,
         transport: runtime.Transport,
-    ) InstanceApi {
+    ) Oauth2ClientCredentialsApi {
         return .{ .allocator = allocator, .transport = transport };
     }
 
     /// Frees what the last failure of this group reported.
-    pub fn deinit(self: *InstanceApi) void {
+    pub fn deinit(self: *Oauth2ClientCredentialsApi) void {
         self.reported.deinit();
     }
 
-    /// Get instance configuration
+    /// Remove the OAuth2 client credentials of a subscription
+    /// `subscription_id` carries `subscription_id`.
+    /// `application_id` carries `application_id`.
+    pub fn delete(
+        self: *Oauth2ClientCredentialsApi,
+        allocator: std.mem.Allocator,
+        subscription_id: []const u8,
+        application_id: []const u8,
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "DELETE",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+    }
+
+    /// Get the OAuth2 client credentials of a subscription
+    /// `subscription_id` carries `subscription_id`.
+    /// `application_id` carries `application_id`.
     pub fn get(
-        self: *InstanceApi,
+        self: *Oauth2ClientCredentialsApi,
         allocator: std.mem.Allocator,
-    ) !runtime.Owned(models.InstanceConfig) {
-        var owned: runtime.Owned(models.InstanceConfig) = try .init(allocator);
+        subscription_id: []const u8,
+        application_id: []const u8,
+    ) !runtime.Owned(models.Oauth2ClientCredentials) {
+        var owned: runtime.Owned(models.Oauth2ClientCredentials) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
             .method = "GET",
-            .path = "/api/v1/instance/",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
         });
         try errors.raiseForStatus(
             self.allocator,
@@ -781,7 +1644,42 @@ pub const InstanceApi = struct {
             &self.reported,
         );
 
-        owned.value = try models.InstanceConfig.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.Oauth2ClientCredentials.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Set the OAuth2 client credentials of a subscription
+    /// `subscription_id` carries `subscription_id`.
+    /// `application_id` carries `application_id`.
+    pub fn update(
+        self: *Oauth2ClientCredentialsApi,
+        allocator: std.mem.Allocator,
+        subscription_id: []const u8,
+        application_id: []const u8,
+        body: models.Oauth2ClientCredentialsPut,
+    ) !runtime.Owned(models.Oauth2ClientCredentials) {
+        var owned: runtime.Owned(models.Oauth2ClientCredentials) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "PUT",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.Oauth2ClientCredentials.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
 };
@@ -850,27 +1748,220 @@ pub const QuotasApi = struct {
     pub fn init(
         allocator: std.mem.Allocator,
         transport: runtime.Transport,
-    ) QuotasApi {
+    ) QuotasApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *QuotasApi) void {
+        self.reported.deinit();
+    }
+
+    /// Get quotas
+    pub fn get(
+        self: *QuotasApi,
+        allocator: std.mem.Allocator,
+    ) !runtime.Owned(models.QuotasResponse) {
+        var owned: runtime.Owned(models.QuotasResponse) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/quotas/",
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.QuotasResponse.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
+/// What the API declares under `requestAttempts`, issued through the transport it is built on.
+pub const RequestAttemptsApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) RequestAttemptsApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *RequestAttemptsApi) void {
+        self.reported.deinit();
+    }
+
+    /// Get a request attempt by its ID
+    /// `request_attempt_id` carries `request_attempt_id`.
+    /// `application_id` carries `application_id`.
+    pub fn get(
+        self: *RequestAttemptsApi,
+        allocator: std.mem.Allocator,
+        request_attempt_id: []const u8,
+        application_id: []const u8,
+    ) !runtime.Owned(models.RequestAttempt) {
+        var owned: runtime.Owned(models.RequestAttempt) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = try runtime.path(arena, "/api/v1/request_attempts/{request_attempt_id}", &.{
+                .{ .name = "request_attempt_id", .value = runtime.value(request_attempt_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.RequestAttempt.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// List request attempts
+    /// `application_id` carries `application_id`.
+    /// `event_event_type_names` carries `event.event_type_names`: Comma-separated event types
+    /// `event_id` carries `event_id`.
+    /// `max_created_at` carries `max_created_at`.
+    /// `min_created_at` carries `min_created_at`.
+    /// `pagination_cursor` carries `pagination_cursor`.
+    /// `subscription_id` carries `subscription_id`.
+    pub fn read(
+        self: *RequestAttemptsApi,
+        allocator: std.mem.Allocator,
+        application_id: []const u8,
+        event_event_type_names: ?[]const u8,
+        event_id: ?[]const u8,
+        max_created_at: ?[]const u8,
+        min_created_at: ?[]const u8,
+        pagination_cursor: ?[]const u8,
+        subscription_id: ?[]const u8,
+    ) !runtime.Owned([]const models.RequestAttempt) {
+        var owned: runtime.Owned([]const models.RequestAttempt) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/request_attempts/",
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+                .{ .name = "event.event_type_names", .value = runtime.value(event_event_type_names) },
+                .{ .name = "event_id", .value = runtime.value(event_id) },
+                .{ .name = "max_created_at", .value = runtime.value(max_created_at) },
+                .{ .name = "min_created_at", .value = runtime.value(min_created_at) },
+                .{ .name = "pagination_cursor", .value = runtime.value(pagination_cursor) },
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try runtime.list(models.RequestAttempt.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Retry a request attempt
+    /// `request_attempt_id` carries `request_attempt_id`.
+    pub fn retry(
+        self: *RequestAttemptsApi,
+        allocator: std.mem.Allocator,
+        request_attempt_id: []const u8,
+        body: models.RetryRequestAttempt,
+    ) !runtime.Owned(models.RequestAttempt) {
+        var owned: runtime.Owned(models.RequestAttempt) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/request_attempts/{request_attempt_id}/retry", &.{
+                .{ .name = "request_attempt_id", .value = runtime.value(request_attempt_id) },
+            }),
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.RequestAttempt.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
+/// What the API declares under `response`, issued through the transport it is built on.
+pub const ResponseApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) ResponseApi {
         return .{ .allocator = allocator, .transport = transport };
     }
 
     /// Frees what the last failure of this group reported.
-    pub fn deinit(self: *QuotasApi) void {
+    pub fn deinit(self: *ResponseApi) void {
         self.reported.deinit();
     }
 
-    /// Get quotas
+    /// Get a response by its ID
+    /// `response_id` carries `response_id`.
+    /// `application_id` carries `application_id`.
     pub fn get(
-        self: *QuotasApi,
+        self: *ResponseApi,
         allocator: std.mem.Allocator,
-    ) !runtime.Owned(models.QuotasResponse) {
-        var owned: runtime.Owned(models.QuotasResponse) = try .init(allocator);
+        response_id: []const u8,
+        application_id: []const u8,
+    ) !runtime.Owned(models.Response) {
+        var owned: runtime.Owned(models.Response) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
             .method = "GET",
-            .path = "/api/v1/quotas/",
+            .path = try runtime.path(arena, "/api/v1/responses/{response_id}", &.{
+                .{ .name = "response_id", .value = runtime.value(response_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
         });
         try errors.raiseForStatus(
             self.allocator,
@@ -879,13 +1970,13 @@ pub const QuotasApi = struct {
             &self.reported,
         );
 
-        owned.value = try models.QuotasResponse.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.Response.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
 };
 
-/// What the API declares under `requestAttempts`, issued through the transport it is built on.
-pub const RequestAttemptsApi = struct {
+/// What the API declares under `secretRotations`, issued through the transport it is built on.
+pub const SecretRotationsApi = struct {
     /// What one request is issued through.
     transport: runtime.Transport,
     /// Where what a failure of this group reported is read into.
@@ -899,36 +1990,38 @@ pub const RequestAttemptsApi = struct {
     pub fn init(
         allocator: std.mem.Allocator,
         transport: runtime.Transport,
-    ) RequestAttemptsApi {
+    ) SecretRotationsApi {
         return .{ .allocator = allocator, .transport = transport };
     }
 
     /// Frees what the last failure of this group reported.
-    pub fn deinit(self: *RequestAttemptsApi) void {
+    pub fn deinit(self: *SecretRotationsApi) void {
         self.reported.deinit();
     }
 
-    /// Get a request attempt by its ID
-    /// `request_attempt_id` carries `request_attempt_id`.
+    /// Rotate the secret of a subscription
+    /// `subscription_id` carries `subscription_id`.
     /// `application_id` carries `application_id`.
-    pub fn get(
-        self: *RequestAttemptsApi,
+    pub fn create(
+        self: *SecretRotationsApi,
         allocator: std.mem.Allocator,
-        request_attempt_id: []const u8,
+        subscription_id: []const u8,
         application_id: []const u8,
-    ) !runtime.Owned(models.RequestAttempt) {
-        var owned: runtime.Owned(models.RequestAttempt) = try .init(allocator);
+        body: models.SecretRotationPost,
+    ) !runtime.Owned(models.SecretRotation) {
+        var owned: runtime.Owned(models.SecretRotation) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
-            .method = "GET",
-            .path = try runtime.path(arena, "/api/v1/request_attempts/{request_attempt_id}", &.{
-                .{ .name = "request_attempt_id", .value = runtime.value(request_attempt_id) },
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/secret_rotation", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
             }),
             .query = &.{
                 .{ .name = "application_id", .value = runtime.value(application_id) },
             },
+            .body = try runtime.written(arena, body),
         });
         try errors.raiseForStatus(
             self.allocator,
@@ -937,44 +2030,30 @@ pub const RequestAttemptsApi = struct {
             &self.reported,
         );
 
-        owned.value = try models.RequestAttempt.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.SecretRotation.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
 
-    /// List request attempts
-    /// `application_id` carries `application_id`.
-    /// `event_event_type_names` carries `event.event_type_names`: Comma-separated event types
-    /// `event_id` carries `event_id`.
-    /// `max_created_at` carries `max_created_at`.
-    /// `min_created_at` carries `min_created_at`.
-    /// `pagination_cursor` carries `pagination_cursor`.
+    /// Finalize the secret rotation of a subscription
     /// `subscription_id` carries `subscription_id`.
-    pub fn read(
-        self: *RequestAttemptsApi,
+    /// `application_id` carries `application_id`.
+    pub fn finalize(
+        self: *SecretRotationsApi,
         allocator: std.mem.Allocator,
+        subscription_id: []const u8,
         application_id: []const u8,
-        event_event_type_names: ?[]const u8,
-        event_id: ?[]const u8,
-        max_created_at: ?[]const u8,
-        min_created_at: ?[]const u8,
-        pagination_cursor: ?[]const u8,
-        subscription_id: ?[]const u8,
-    ) !runtime.Owned([]const models.RequestAttempt) {
-        var owned: runtime.Owned([]const models.RequestAttempt) = try .init(allocator);
-        errdefer owned.deinit();
-        const arena = owned.arena.allocator();
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
 
         const answered = try self.transport.request(arena, .{
-            .method = "GET",
-            .path = "/api/v1/request_attempts/",
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
             .query = &.{
                 .{ .name = "application_id", .value = runtime.value(application_id) },
-                .{ .name = "event.event_type_names", .value = runtime.value(event_event_type_names) },
-                .{ .name = "event_id", .value = runtime.value(event_id) },
-                .{ .name = "max_created_at", .value = runtime.value(max_created_at) },
-                .{ .name = "min_created_at", .value = runtime.value(min_created_at) },
-                .{ .name = "pagination_cursor", .value = runtime.value(pagination_cursor) },
-                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
             },
         });
         try errors.raiseForStatus(
@@ -983,53 +2062,25 @@ pub const RequestAttemptsApi = struct {
             answered.payload,
             &self.reported,
         );
-
-        owned.value = try runtime.list(models.RequestAttempt.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
-        return owned;
-    }
-};
-
-/// What the API declares under `response`, issued through the transport it is built on.
-pub const ResponseApi = struct {
-    /// What one request is issued through.
-    transport: runtime.Transport,
-    /// Where what a failure of this group reported is read into.
-    ///
-    /// Not the allocator a call is handed: that one frees what the call allocated on its way
-    /// out, and what the failure reported is read after the call has returned.
-    allocator: std.mem.Allocator,
-    /// What the last failure of this group reported, which an error alone cannot carry.
-    reported: errors.Reported = .empty,
-
-    pub fn init(
-        allocator: std.mem.Allocator,
-        transport: runtime.Transport,
-    ) ResponseApi {
-        return .{ .allocator = allocator, .transport = transport };
-    }
-
-    /// Frees what the last failure of this group reported.
-    pub fn deinit(self: *ResponseApi) void {
-        self.reported.deinit();
     }
 
-    /// Get a response by its ID
-    /// `response_id` carries `response_id`.
+    /// Get the secret rotation of a subscription
+    /// `subscription_id` carries `subscription_id`.
     /// `application_id` carries `application_id`.
     pub fn get(
-        self: *ResponseApi,
+        self: *SecretRotationsApi,
         allocator: std.mem.Allocator,
-        response_id: []const u8,
+        subscription_id: []const u8,
         application_id: []const u8,
-    ) !runtime.Owned(models.Response) {
-        var owned: runtime.Owned(models.Response) = try .init(allocator);
+    ) !runtime.Owned(models.SecretRotation) {
+        var owned: runtime.Owned(models.SecretRotation) = try .init(allocator);
         errdefer owned.deinit();
         const arena = owned.arena.allocator();
 
         const answered = try self.transport.request(arena, .{
             .method = "GET",
-            .path = try runtime.path(arena, "/api/v1/responses/{response_id}", &.{
-                .{ .name = "response_id", .value = runtime.value(response_id) },
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/secret_rotation", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
             }),
             .query = &.{
                 .{ .name = "application_id", .value = runtime.value(application_id) },
@@ -1042,9 +2093,39 @@ pub const ResponseApi = struct {
             &self.reported,
         );
 
-        owned.value = try models.Response.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        owned.value = try models.SecretRotation.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
         return owned;
     }
+
+    /// Roll back the secret rotation of a subscription
+    /// `subscription_id` carries `subscription_id`.
+    /// `application_id` carries `application_id`.
+    pub fn rollback(
+        self: *SecretRotationsApi,
+        allocator: std.mem.Allocator,
+        subscription_id: []const u8,
+        application_id: []const u8,
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = try runtime.path(arena, "/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback", &.{
+                .{ .name = "subscription_id", .value = runtime.value(subscription_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+    }
 };
 
 /// What the API declares under `serviceToken`, issued through the transport it is built on.
@@ -1220,6 +2301,144 @@ pub const ServiceTokenApi = struct {
     }
 };
 
+/// What the API declares under `signingKeys`, issued through the transport it is built on.
+pub const SigningKeysApi = struct {
+    /// What one request is issued through.
+    transport: runtime.Transport,
+    /// Where what a failure of this group reported is read into.
+    ///
+    /// Not the allocator a call is handed: that one frees what the call allocated on its way
+    /// out, and what the failure reported is read after the call has returned.
+    allocator: std.mem.Allocator,
+    /// What the last failure of this group reported, which an error alone cannot carry.
+    reported: errors.Reported = .empty,
+
+    pub fn init(
+        allocator: std.mem.Allocator,
+        transport: runtime.Transport,
+    ) SigningKeysApi {
+        return .{ .allocator = allocator, .transport = transport };
+    }
+
+    /// Frees what the last failure of this group reported.
+    pub fn deinit(self: *SigningKeysApi) void {
+        self.reported.deinit();
+    }
+
+    /// Create a signing key
+    pub fn create(
+        self: *SigningKeysApi,
+        allocator: std.mem.Allocator,
+        body: models.SigningKeyPost,
+    ) !runtime.Owned(models.SigningKey) {
+        var owned: runtime.Owned(models.SigningKey) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "POST",
+            .path = "/api/v1/signing_keys/",
+            .body = try runtime.written(arena, body),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.SigningKey.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// Retire a signing key
+    /// `signing_key_id` carries `signing_key_id`.
+    /// `application_id` carries `application_id`.
+    pub fn delete(
+        self: *SigningKeysApi,
+        allocator: std.mem.Allocator,
+        signing_key_id: []const u8,
+        application_id: []const u8,
+    ) !void {
+        var held: std.heap.ArenaAllocator = .init(allocator);
+        defer held.deinit();
+        const arena = held.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "DELETE",
+            .path = try runtime.path(arena, "/api/v1/signing_keys/{signing_key_id}", &.{
+                .{ .name = "signing_key_id", .value = runtime.value(signing_key_id) },
+            }),
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+    }
+
+    /// Get the public keys of an application
+    /// `application_id` carries `application_id`.
+    pub fn jwks(
+        self: *SigningKeysApi,
+        allocator: std.mem.Allocator,
+        application_id: []const u8,
+    ) !runtime.Owned(models.JsonWebKeySet) {
+        var owned: runtime.Owned(models.JsonWebKeySet) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = try runtime.path(arena, "/api/v1/jwks/{application_id}", &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            }),
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try models.JsonWebKeySet.fromJson(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+
+    /// List signing keys
+    /// `application_id` carries `application_id`.
+    pub fn list(
+        self: *SigningKeysApi,
+        allocator: std.mem.Allocator,
+        application_id: []const u8,
+    ) !runtime.Owned([]const models.SigningKey) {
+        var owned: runtime.Owned([]const models.SigningKey) = try .init(allocator);
+        errdefer owned.deinit();
+        const arena = owned.arena.allocator();
+
+        const answered = try self.transport.request(arena, .{
+            .method = "GET",
+            .path = "/api/v1/signing_keys/",
+            .query = &.{
+                .{ .name = "application_id", .value = runtime.value(application_id) },
+            },
+        });
+        try errors.raiseForStatus(
+            self.allocator,
+            answered.status,
+            answered.payload,
+            &self.reported,
+        );
+
+        owned.value = try runtime.list(models.SigningKey.fromJson).read(arena, try runtime.decodePayload(arena, answered.payload));
+        return owned;
+    }
+};
+
 /// What the API declares under `subscriptions`, issued through the transport it is built on.
 pub const SubscriptionsApi = struct {
     /// What one request is issued through.
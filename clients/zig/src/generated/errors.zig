@@ -26,14 +26,24 @@ pub const Failure = error{
     AuthInvalidAuthorizationHeader,
     AuthInvalidBiscuit,
     AuthNoAuthorizationHeader,
+    BulkRetryAlreadyRunning,
+    BulkRetryFinished,
+    ClientCertificatesDisabled,
     EventAlreadyIngested,
+    EventDeliveryBeyondRetention,
     EventInvalidBase64Payload,
     EventInvalidJsonPayload,
     EventInvalidPayloadContentType,
+    EventNotScheduled,
+    EventPayloadSchemaViolation,
     EventTypeAlreadyExist,
     EventTypeDoesNotExist,
+    EventTypeVersionAlreadyExist,
+    EventTypeVersionDoesNotExist,
+    EventTypeVersionSunset,
     Forbidden,
     InternalServerError,
+    InvalidClientCertificate,
     InvalidDateRange,
     InvalidRole,
     InvitedUserAlreadyInOrganization,
@@ -51,7 +61,14 @@ pub const Failure = error{
     PasswordTooShort,
     RateLimited,
     RegistrationDisabled,
+    RequestAttemptNotFailed,
+    RequestAttemptRetryPending,
+    RetryPolicyOverLimit,
+    SecretRotationInProgress,
     ServiceUnavailable,
+    SigningKeysDisabled,
+    SubscriptionDisabled,
+    SubscriptionNotInbox,
     TooManyApplicationsPerOrganization,
     TooManyEventTypesPerApplication,
     TooManyEventsToday,
@@ -103,14 +120,24 @@ pub const problems = [_]struct { id: []const u8, raised: Failure }{
     .{ .id = models.ProblemId.auth_invalid_authorization_header, .raised = error.AuthInvalidAuthorizationHeader },
     .{ .id = models.ProblemId.auth_invalid_biscuit, .raised = error.AuthInvalidBiscuit },
     .{ .id = models.ProblemId.auth_no_authorization_header, .raised = error.AuthNoAuthorizationHeader },
+    .{ .id = models.ProblemId.bulk_retry_already_running, .raised = error.BulkRetryAlreadyRunning },
+    .{ .id = models.ProblemId.bulk_retry_finished, .raised = error.BulkRetryFinished },
+    .{ .id = models.ProblemId.client_certificates_disabled, .raised = error.ClientCertificatesDisabled },
     .{ .id = models.ProblemId.event_already_ingested, .raised = error.EventAlreadyIngested },
+    .{ .id = models.ProblemId.event_delivery_beyond_retention, .raised = error.EventDeliveryBeyondRetention },
     .{ .id = models.ProblemId.event_invalid_base64_payload, .raised = error.EventInvalidBase64Payload },
     .{ .id = models.ProblemId.event_invalid_json_payload, .raised = error.EventInvalidJsonPayload },
     .{ .id = models.ProblemId.event_invalid_payload_content_type, .raised = error.EventInvalidPayloadContentType },
+    .{ .id = models.ProblemId.event_not_scheduled, .raised = error.EventNotScheduled },
+    .{ .id = models.ProblemId.event_payload_schema_violation, .raised = error.EventPayloadSchemaViolation },
     .{ .id = models.ProblemId.event_type_already_exist, .raised = error.EventTypeAlreadyExist },
     .{ .id = models.ProblemId.event_type_does_not_exist, .raised = error.EventTypeDoesNotExist },
+    .{ .id = models.ProblemId.event_type_version_already_exist, .raised = error.EventTypeVersionAlreadyExist },
+    .{ .id = models.ProblemId.event_type_version_does_not_exist, .raised = error.EventTypeVersionDoesNotExist },
+    .{ .id = models.ProblemId.event_type_version_sunset, .raised = error.EventTypeVersionSunset },
     .{ .id = models.ProblemId.forbidden, .raised = error.Forbidden },
     .{ .id = models.ProblemId.internal_server_error, .raised = error.InternalServerError },
+    .{ .id = models.ProblemId.invalid_client_certificate, .raised = error.InvalidClientCertificate },
     .{ .id = models.ProblemId.invalid_date_range, .raised = error.InvalidDateRange },
     .{ .id = models.ProblemId.invalid_role, .raised = error.InvalidRole },
     .{ .id = models.ProblemId.invited_user_already_in_organization, .raised = error.InvitedUserAlreadyInOrganization },
@@ -128,7 +155,14 @@ pub const problems = [_]struct { id: []const u8, raised: Failure }{
     .{ .id = models.ProblemId.password_too_short, .raised = error.PasswordTooShort },
     .{ .id = models.ProblemId.rate_limited, .raised = error.RateLimited },
     .{ .id = models.ProblemId.registration_disabled, .raised = error.RegistrationDisabled },
+    .{ .id = models.ProblemId.request_attempt_not_failed, .raised = error.RequestAttemptNotFailed },
+    .{ .id = models.ProblemId.request_attempt_retry_pending, .raised = error.RequestAttemptRetryPending },
+    .{ .id = models.ProblemId.retry_policy_over_limit, .raised = error.RetryPolicyOverLimit },
+    .{ .id = models.ProblemId.secret_rotation_in_progress, .raised = error.SecretRotationInProgress },
     .{ .id = models.ProblemId.service_unavailable, .raised = error.ServiceUnavailable },
+    .{ .id = models.ProblemId.signing_keys_disabled, .raised = error.SigningKeysDisabled },
+    .{ .id = models.ProblemId.subscription_disabled, .raised = error.SubscriptionDisabled },
+    .{ .id = models.ProblemId.subscription_not_inbox, .raised = error.SubscriptionNotInbox },
     .{ .id = models.ProblemId.too_many_applications_per_organization, .raised = error.TooManyApplicationsPerOrganization },
     .{ .id = models.ProblemId.too_many_event_types_per_application, .raised = error.TooManyEventTypesPerApplication },
     .{ .id = models.ProblemId.too_many_events_today, .raised = error.TooManyEventsToday },
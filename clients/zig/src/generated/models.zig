@@ -54,6 +54,264 @@ pub const ApplicationInfoOnboardingStepsSubscription = struct {
     }
 };
 
+/// One of the values the API answers with.
+pub const BulkRetryPostStatus = struct {
+    pub const failed: []const u8 = "failed";
+    pub const successful: []const u8 = "successful";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ failed, successful };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const BulkRetryStatus = struct {
+    pub const failed: []const u8 = "failed";
+    pub const successful: []const u8 = "successful";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ failed, successful };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const DeadLetterDetailsDeadLetterReason = struct {
+    pub const retries_exhausted: []const u8 = "retries_exhausted";
+    pub const signature_failed: []const u8 = "signature_failed";
+    pub const transform_failed: []const u8 = "transform_failed";
+    pub const permanent_failure: []const u8 = "permanent_failure";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ retries_exhausted, signature_failed, transform_failed, permanent_failure };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const DeadLetterReason = struct {
+    pub const retries_exhausted: []const u8 = "retries_exhausted";
+    pub const signature_failed: []const u8 = "signature_failed";
+    pub const transform_failed: []const u8 = "transform_failed";
+    pub const permanent_failure: []const u8 = "permanent_failure";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ retries_exhausted, signature_failed, transform_failed, permanent_failure };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const EventTypePayloadSchemaMode = struct {
+    pub const enforce: []const u8 = "enforce";
+    pub const warn: []const u8 = "warn";
+    pub const off: []const u8 = "off";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ enforce, warn, off };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const EventTypePostPayloadSchemaMode = struct {
+    pub const enforce: []const u8 = "enforce";
+    pub const warn: []const u8 = "warn";
+    pub const off: []const u8 = "off";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ enforce, warn, off };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const EventTypePutPayloadSchemaMode = struct {
+    pub const enforce: []const u8 = "enforce";
+    pub const warn: []const u8 = "warn";
+    pub const off: []const u8 = "off";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ enforce, warn, off };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const IngestedEventsBatchEventsProblemId = struct {
+    pub const organization_name_missing: []const u8 = "OrganizationNameMissing";
+    pub const user_already_exist: []const u8 = "UserAlreadyExist";
+    pub const registration_disabled: []const u8 = "RegistrationDisabled";
+    pub const password_too_short: []const u8 = "PasswordTooShort";
+    pub const password_too_long: []const u8 = "PasswordTooLong";
+    pub const password_similar_to_email: []const u8 = "PasswordSimilarToEmail";
+    pub const password_similar_to_name: []const u8 = "PasswordSimilarToName";
+    pub const password_too_common: []const u8 = "PasswordTooCommon";
+    pub const password_not_diverse_enough: []const u8 = "PasswordNotDiverseEnough";
+    pub const organization_is_not_empty: []const u8 = "OrganizationIsNotEmpty";
+    pub const invited_user_does_not_exist: []const u8 = "InvitedUserDoesNotExist";
+    pub const invited_user_already_in_organization: []const u8 = "InvitedUserAlreadyInOrganization";
+    pub const application_name_missing: []const u8 = "ApplicationNameMissing";
+    pub const invalid_role: []const u8 = "InvalidRole";
+    pub const event_type_already_exist: []const u8 = "EventTypeAlreadyExist";
+    pub const event_type_does_not_exist: []const u8 = "EventTypeDoesNotExist";
+    pub const event_type_version_already_exist: []const u8 = "EventTypeVersionAlreadyExist";
+    pub const event_type_version_does_not_exist: []const u8 = "EventTypeVersionDoesNotExist";
+    pub const event_type_version_sunset: []const u8 = "EventTypeVersionSunset";
+    pub const unauthorized_workers: []const u8 = "UnauthorizedWorkers";
+    pub const event_already_ingested: []const u8 = "EventAlreadyIngested";
+    pub const event_invalid_payload_content_type: []const u8 = "EventInvalidPayloadContentType";
+    pub const event_invalid_base64_payload: []const u8 = "EventInvalidBase64Payload";
+    pub const event_invalid_json_payload: []const u8 = "EventInvalidJsonPayload";
+    pub const event_payload_schema_violation: []const u8 = "EventPayloadSchemaViolation";
+    pub const event_delivery_beyond_retention: []const u8 = "EventDeliveryBeyondRetention";
+    pub const event_not_scheduled: []const u8 = "EventNotScheduled";
+    pub const labels_ambiguity: []const u8 = "LabelsAmbiguity";
+    pub const invalid_date_range: []const u8 = "InvalidDateRange";
+    pub const request_attempt_not_failed: []const u8 = "RequestAttemptNotFailed";
+    pub const request_attempt_retry_pending: []const u8 = "RequestAttemptRetryPending";
+    pub const subscription_disabled: []const u8 = "SubscriptionDisabled";
+    pub const subscription_not_inbox: []const u8 = "SubscriptionNotInbox";
+    pub const secret_rotation_in_progress: []const u8 = "SecretRotationInProgress";
+    pub const bulk_retry_already_running: []const u8 = "BulkRetryAlreadyRunning";
+    pub const bulk_retry_finished: []const u8 = "BulkRetryFinished";
+    pub const retry_policy_over_limit: []const u8 = "RetryPolicyOverLimit";
+    pub const client_certificates_disabled: []const u8 = "ClientCertificatesDisabled";
+    pub const invalid_client_certificate: []const u8 = "InvalidClientCertificate";
+    pub const signing_keys_disabled: []const u8 = "SigningKeysDisabled";
+    pub const auth_no_authorization_header: []const u8 = "AuthNoAuthorizationHeader";
+    pub const auth_invalid_authorization_header: []const u8 = "AuthInvalidAuthorizationHeader";
+    pub const auth_application_secret_lookup_error: []const u8 = "AuthApplicationSecretLookupError";
+    pub const auth_invalid_application_secret: []const u8 = "AuthInvalidApplicationSecret";
+    pub const auth_biscuit_lookup_error: []const u8 = "AuthBiscuitLookupError";
+    pub const auth_invalid_biscuit: []const u8 = "AuthInvalidBiscuit";
+    pub const auth_failed_login: []const u8 = "AuthFailedLogin";
+    pub const auth_email_not_verified: []const u8 = "AuthEmailNotVerified";
+    pub const auth_email_already_verified: []const u8 = "AuthEmailAlreadyVerified";
+    pub const auth_failed_refresh: []const u8 = "AuthFailedRefresh";
+    pub const auth_email_expired: []const u8 = "AuthEmailExpired";
+    pub const too_many_members_per_organization: []const u8 = "TooManyMembersPerOrganization";
+    pub const too_many_applications_per_organization: []const u8 = "TooManyApplicationsPerOrganization";
+    pub const too_many_events_today: []const u8 = "TooManyEventsToday";
+    pub const too_many_subscriptions_per_application: []const u8 = "TooManySubscriptionsPerApplication";
+    pub const too_many_event_types_per_application: []const u8 = "TooManyEventTypesPerApplication";
+    pub const json_payload: []const u8 = "JsonPayload";
+    pub const validation: []const u8 = "Validation";
+    pub const not_found: []const u8 = "NotFound";
+    pub const internal_server_error: []const u8 = "InternalServerError";
+    pub const forbidden: []const u8 = "Forbidden";
+    pub const rate_limited: []const u8 = "RateLimited";
+    pub const service_unavailable: []const u8 = "ServiceUnavailable";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{
+        organization_name_missing,
+        user_already_exist,
+        registration_disabled,
+        password_too_short,
+        password_too_long,
+        password_similar_to_email,
+        password_similar_to_name,
+        password_too_common,
+        password_not_diverse_enough,
+        organization_is_not_empty,
+        invited_user_does_not_exist,
+        invited_user_already_in_organization,
+        application_name_missing,
+        invalid_role,
+        event_type_already_exist,
+        event_type_does_not_exist,
+        event_type_version_already_exist,
+        event_type_version_does_not_exist,
+        event_type_version_sunset,
+        unauthorized_workers,
+        event_already_ingested,
+        event_invalid_payload_content_type,
+        event_invalid_base64_payload,
+        event_invalid_json_payload,
+        event_payload_schema_violation,
+        event_delivery_beyond_retention,
+        event_not_scheduled,
+        labels_ambiguity,
+        invalid_date_range,
+        request_attempt_not_failed,
+        request_attempt_retry_pending,
+        subscription_disabled,
+        subscription_not_inbox,
+        secret_rotation_in_progress,
+        bulk_retry_already_running,
+        bulk_retry_finished,
+        retry_policy_over_limit,
+        client_certificates_disabled,
+        invalid_client_certificate,
+        signing_keys_disabled,
+        auth_no_authorization_header,
+        auth_invalid_authorization_header,
+        auth_application_secret_lookup_error,
+        auth_invalid_application_secret,
+        auth_biscuit_lookup_error,
+        auth_invalid_biscuit,
+        auth_failed_login,
+        auth_email_not_verified,
+        auth_email_already_verified,
+        auth_failed_refresh,
+        auth_email_expired,
+        too_many_members_per_organization,
+        too_many_applications_per_organization,
+        too_many_events_today,
+        too_many_subscriptions_per_application,
+        too_many_event_types_per_application,
+        json_payload,
+        validation,
+        not_found,
+        internal_server_error,
+        forbidden,
+        rate_limited,
+        service_unavailable,
+    };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const IngestedEventsBatchEventsStatus = struct {
+    pub const ingested: []const u8 = "ingested";
+    pub const failed: []const u8 = "failed";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ ingested, failed };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
 /// One of the values the API answers with.
 pub const OrganizationInfoOnboardingStepsApplication = struct {
     pub const to_do: []const u8 = "ToDo";
@@ -128,13 +386,30 @@ pub const ProblemId = struct {
     pub const invalid_role: []const u8 = "InvalidRole";
     pub const event_type_already_exist: []const u8 = "EventTypeAlreadyExist";
     pub const event_type_does_not_exist: []const u8 = "EventTypeDoesNotExist";
+    pub const event_type_version_already_exist: []const u8 = "EventTypeVersionAlreadyExist";
+    pub const event_type_version_does_not_exist: []const u8 = "EventTypeVersionDoesNotExist";
+    pub const event_type_version_sunset: []const u8 = "EventTypeVersionSunset";
     pub const unauthorized_workers: []const u8 = "UnauthorizedWorkers";
     pub const event_already_ingested: []const u8 = "EventAlreadyIngested";
     pub const event_invalid_payload_content_type: []const u8 = "EventInvalidPayloadContentType";
     pub const event_invalid_base64_payload: []const u8 = "EventInvalidBase64Payload";
     pub const event_invalid_json_payload: []const u8 = "EventInvalidJsonPayload";
+    pub const event_payload_schema_violation: []const u8 = "EventPayloadSchemaViolation";
+    pub const event_delivery_beyond_retention: []const u8 = "EventDeliveryBeyondRetention";
+    pub const event_not_scheduled: []const u8 = "EventNotScheduled";
     pub const labels_ambiguity: []const u8 = "LabelsAmbiguity";
     pub const invalid_date_range: []const u8 = "InvalidDateRange";
+    pub const request_attempt_not_failed: []const u8 = "RequestAttemptNotFailed";
+    pub const request_attempt_retry_pending: []const u8 = "RequestAttemptRetryPending";
+    pub const subscription_disabled: []const u8 = "SubscriptionDisabled";
+    pub const subscription_not_inbox: []const u8 = "SubscriptionNotInbox";
+    pub const secret_rotation_in_progress: []const u8 = "SecretRotationInProgress";
+    pub const bulk_retry_already_running: []const u8 = "BulkRetryAlreadyRunning";
+    pub const bulk_retry_finished: []const u8 = "BulkRetryFinished";
+    pub const retry_policy_over_limit: []const u8 = "RetryPolicyOverLimit";
+    pub const client_certificates_disabled: []const u8 = "ClientCertificatesDisabled";
+    pub const invalid_client_certificate: []const u8 = "InvalidClientCertificate";
+    pub const signing_keys_disabled: []const u8 = "SigningKeysDisabled";
     pub const auth_no_authorization_header: []const u8 = "AuthNoAuthorizationHeader";
     pub const auth_invalid_authorization_header: []const u8 = "AuthInvalidAuthorizationHeader";
     pub const auth_application_secret_lookup_error: []const u8 = "AuthApplicationSecretLookupError";
@@ -177,13 +452,30 @@ pub const ProblemId = struct {
         invalid_role,
         event_type_already_exist,
         event_type_does_not_exist,
+        event_type_version_already_exist,
+        event_type_version_does_not_exist,
+        event_type_version_sunset,
         unauthorized_workers,
         event_already_ingested,
         event_invalid_payload_content_type,
         event_invalid_base64_payload,
         event_invalid_json_payload,
+        event_payload_schema_violation,
+        event_delivery_beyond_retention,
+        event_not_scheduled,
         labels_ambiguity,
         invalid_date_range,
+        request_attempt_not_failed,
+        request_attempt_retry_pending,
+        subscription_disabled,
+        subscription_not_inbox,
+        secret_rotation_in_progress,
+        bulk_retry_already_running,
+        bulk_retry_finished,
+        retry_policy_over_limit,
+        client_certificates_disabled,
+        invalid_client_certificate,
+        signing_keys_disabled,
         auth_no_authorization_header,
         auth_invalid_authorization_header,
         auth_application_secret_lookup_error,
@@ -215,6 +507,22 @@ pub const ProblemId = struct {
     }
 };
 
+/// One of the values the API answers with.
+pub const RequestAttemptStatusRuleOutcome = struct {
+    pub const success: []const u8 = "success";
+    pub const retryable: []const u8 = "retryable";
+    pub const permanent_failure: []const u8 = "permanent_failure";
+    pub const disable_subscription: []const u8 = "disable_subscription";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ success, retryable, permanent_failure, disable_subscription };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
 /// One of the values the API answers with.
 pub const RequestAttemptStatusType = struct {
     pub const waiting: []const u8 = "waiting";
@@ -222,9 +530,11 @@ pub const RequestAttemptStatusType = struct {
     pub const in_progress: []const u8 = "in_progress";
     pub const successful: []const u8 = "successful";
     pub const failed: []const u8 = "failed";
+    pub const expired: []const u8 = "expired";
+    pub const cancelled: []const u8 = "cancelled";
 
     /// Every value the API declares for this list.
-    pub const values = [_][]const u8{ waiting, pending, in_progress, successful, failed };
+    pub const values = [_][]const u8{ waiting, pending, in_progress, successful, failed, expired, cancelled };
 
     /// Whether the API declares that value.
     pub fn member(value: []const u8) bool {
@@ -232,63 +542,155 @@ pub const RequestAttemptStatusType = struct {
     }
 };
 
-/// The `Application` the API declares.
-pub const Application = struct {
-    /// carries `application_id`: Unique identifier of the application.
-    application_id: []const u8,
-    /// carries `name`: Name of the application. Length: 2-50 characters.
-    name: []const u8,
-    /// carries `organization_id`: UUID of the organization this application belongs to.
-    organization_id: []const u8,
+/// One of the values the API answers with.
+pub const SubscriptionPostResponseRulesOutcome = struct {
+    pub const success: []const u8 = "success";
+    pub const retryable: []const u8 = "retryable";
+    pub const permanent_failure: []const u8 = "permanent_failure";
+    pub const disable_subscription: []const u8 = "disable_subscription";
 
-    /// Read one out of what the API answered.
-    pub fn fromJson(
-        allocator: std.mem.Allocator,
-        value: std.json.Value,
-    ) runtime.DecodeError!Application {
-        const fields = try runtime.asFields(value, "Application");
-        return .{
-            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
-            .name = try runtime.read(allocator, fields, "name", runtime.text),
-            .organization_id = try runtime.read(allocator, fields, "organization_id", runtime.text),
-        };
-    }
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ success, retryable, permanent_failure, disable_subscription };
 
-    /// Write one back the way the API reads it.
-    pub fn toJson(
-        self: Application,
-        allocator: std.mem.Allocator,
-    ) runtime.WriteError!std.json.Value {
-        var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "application_id", self.application_id);
-        try runtime.put(&out, allocator, "name", self.name);
-        try runtime.put(&out, allocator, "organization_id", self.organization_id);
-        return .{ .object = out };
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
     }
 };
 
-/// The `ApplicationInfo` the API declares.
-pub const ApplicationInfo = struct {
-    /// carries `application_id`: Unique identifier of the application.
-    application_id: []const u8,
-    /// carries `consumption`: Current consumption metrics for this application.
-    consumption: models.ApplicationInfoConsumption,
-    /// carries `name`: Name of the application. Length: 2-50 characters.
-    name: []const u8,
-    /// carries `onboarding_steps`: Onboarding completion status for this application.
-    onboarding_steps: models.ApplicationInfoOnboardingSteps,
-    /// carries `organization_id`: UUID of the organization this application belongs to.
-    organization_id: []const u8,
-    /// carries `quotas`: Quota limits for this application.
-    quotas: models.ApplicationInfoQuotas,
+/// One of the values the API answers with.
+pub const SubscriptionPostSignatureScheme = struct {
+    pub const hook0: []const u8 = "hook0";
+    pub const standard_webhooks: []const u8 = "standard_webhooks";
 
-    /// Read one out of what the API answered.
-    pub fn fromJson(
-        allocator: std.mem.Allocator,
-        value: std.json.Value,
-    ) runtime.DecodeError!ApplicationInfo {
-        const fields = try runtime.asFields(value, "ApplicationInfo");
-        return .{
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ hook0, standard_webhooks };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const SubscriptionPostTargetType = struct {
+    pub const http: []const u8 = "http";
+    pub const amqp: []const u8 = "amqp";
+    pub const sqs: []const u8 = "sqs";
+    pub const inbox: []const u8 = "inbox";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ http, amqp, sqs, inbox };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const SubscriptionResponseRulesOutcome = struct {
+    pub const success: []const u8 = "success";
+    pub const retryable: []const u8 = "retryable";
+    pub const permanent_failure: []const u8 = "permanent_failure";
+    pub const disable_subscription: []const u8 = "disable_subscription";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ success, retryable, permanent_failure, disable_subscription };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const SubscriptionSignatureScheme = struct {
+    pub const hook0: []const u8 = "hook0";
+    pub const standard_webhooks: []const u8 = "standard_webhooks";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ hook0, standard_webhooks };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// One of the values the API answers with.
+pub const SubscriptionTargetType = struct {
+    pub const http: []const u8 = "http";
+    pub const amqp: []const u8 = "amqp";
+    pub const sqs: []const u8 = "sqs";
+    pub const inbox: []const u8 = "inbox";
+
+    /// Every value the API declares for this list.
+    pub const values = [_][]const u8{ http, amqp, sqs, inbox };
+
+    /// Whether the API declares that value.
+    pub fn member(value: []const u8) bool {
+        return runtime.declares(&values, value);
+    }
+};
+
+/// The `Application` the API declares.
+pub const Application = struct {
+    /// carries `application_id`: Unique identifier of the application.
+    application_id: []const u8,
+    /// carries `name`: Name of the application. Length: 2-50 characters.
+    name: []const u8,
+    /// carries `organization_id`: UUID of the organization this application belongs to.
+    organization_id: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!Application {
+        const fields = try runtime.asFields(value, "Application");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .name = try runtime.read(allocator, fields, "name", runtime.text),
+            .organization_id = try runtime.read(allocator, fields, "organization_id", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: Application,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "name", self.name);
+        try runtime.put(&out, allocator, "organization_id", self.organization_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `ApplicationInfo` the API declares.
+pub const ApplicationInfo = struct {
+    /// carries `application_id`: Unique identifier of the application.
+    application_id: []const u8,
+    /// carries `consumption`: Current consumption metrics for this application.
+    consumption: models.ApplicationInfoConsumption,
+    /// carries `name`: Name of the application. Length: 2-50 characters.
+    name: []const u8,
+    /// carries `onboarding_steps`: Onboarding completion status for this application.
+    onboarding_steps: models.ApplicationInfoOnboardingSteps,
+    /// carries `organization_id`: UUID of the organization this application belongs to.
+    organization_id: []const u8,
+    /// carries `quotas`: Quota limits for this application.
+    quotas: models.ApplicationInfoQuotas,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!ApplicationInfo {
+        const fields = try runtime.asFields(value, "ApplicationInfo");
+        return .{
             .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
             .consumption = try runtime.read(
                 allocator,
@@ -558,840 +960,4030 @@ pub const ApplicationSecretPost = struct {
     }
 };
 
-/// The `Event` the API declares.
-pub const Event = struct {
+/// The `BulkRetry` the API declares.
+pub const BulkRetry = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `bulk_retry_id`.
+    bulk_retry_id: []const u8,
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `event_type_names`.
+    event_type_names: []const []const u8,
+    /// carries `max_created_at`.
+    max_created_at: []const u8,
+    /// carries `min_created_at`.
+    min_created_at: []const u8,
+    /// carries `processed_count`: Number of pairs handled so far
+    processed_count: i64,
+    /// carries `retried_count`: Number of request attempts created so far
+    retried_count: i64,
+    /// carries `status`, one of `models.BulkRetryStatus.values`.
+    status: []const u8,
+    /// carries `total_count`: Number of (event, subscription) pairs that matched when the bulk
+    /// retry was created
+    total_count: i64,
+    /// carries `updated_at`.
+    updated_at: []const u8,
+    /// carries `cancelled_at`.
+    cancelled_at: ?[]const u8,
+    /// carries `completed_at`.
+    completed_at: ?[]const u8,
     /// carries `event_id`.
-    event_id: []const u8,
-    /// carries `event_type_name`.
-    event_type_name: []const u8,
-    /// carries `ip`.
-    ip: []const u8,
-    /// carries `labels`.
-    labels: std.json.Value,
-    /// carries `occurred_at`.
-    occurred_at: []const u8,
-    /// carries `payload_content_type`.
-    payload_content_type: []const u8,
-    /// carries `received_at`.
-    received_at: []const u8,
-    /// carries `metadata`.
-    metadata: ?std.json.Value,
+    event_id: ?[]const u8,
+    /// carries `subscription_id`.
+    subscription_id: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!Event {
-        const fields = try runtime.asFields(value, "Event");
+    ) runtime.DecodeError!BulkRetry {
+        const fields = try runtime.asFields(value, "BulkRetry");
         return .{
-            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
-            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
-            .ip = try runtime.read(allocator, fields, "ip", runtime.text),
-            .labels = try runtime.read(allocator, fields, "labels", runtime.jsonValue),
-            .occurred_at = try runtime.read(allocator, fields, "occurred_at", runtime.text),
-            .payload_content_type = try runtime.read(
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .bulk_retry_id = try runtime.read(allocator, fields, "bulk_retry_id", runtime.text),
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .event_type_names = try runtime.read(
                 allocator,
                 fields,
-                "payload_content_type",
+                "event_type_names",
+                runtime.list(runtime.text).read,
+            ),
+            .max_created_at = try runtime.read(allocator, fields, "max_created_at", runtime.text),
+            .min_created_at = try runtime.read(allocator, fields, "min_created_at", runtime.text),
+            .processed_count = try runtime.read(
+                allocator,
+                fields,
+                "processed_count",
+                runtime.integer64,
+            ),
+            .retried_count = try runtime.read(
+                allocator,
+                fields,
+                "retried_count",
+                runtime.integer64,
+            ),
+            .status = try runtime.read(
+                allocator,
+                fields,
+                "status",
+                runtime.memberOf(models.BulkRetryStatus).read,
+            ),
+            .total_count = try runtime.read(allocator, fields, "total_count", runtime.integer64),
+            .updated_at = try runtime.read(allocator, fields, "updated_at", runtime.text),
+            .cancelled_at = try runtime.maybe(allocator, fields, "cancelled_at", runtime.text),
+            .completed_at = try runtime.maybe(allocator, fields, "completed_at", runtime.text),
+            .event_id = try runtime.maybe(allocator, fields, "event_id", runtime.text),
+            .subscription_id = try runtime.maybe(
+                allocator,
+                fields,
+                "subscription_id",
                 runtime.text,
             ),
-            .received_at = try runtime.read(allocator, fields, "received_at", runtime.text),
-            .metadata = try runtime.maybe(allocator, fields, "metadata", runtime.jsonValue),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: Event,
+        self: BulkRetry,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "bulk_retry_id", self.bulk_retry_id);
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "event_type_names", self.event_type_names);
+        try runtime.put(&out, allocator, "max_created_at", self.max_created_at);
+        try runtime.put(&out, allocator, "min_created_at", self.min_created_at);
+        try runtime.put(&out, allocator, "processed_count", self.processed_count);
+        try runtime.put(&out, allocator, "retried_count", self.retried_count);
+        try runtime.put(&out, allocator, "status", self.status);
+        try runtime.put(&out, allocator, "total_count", self.total_count);
+        try runtime.put(&out, allocator, "updated_at", self.updated_at);
+        try runtime.put(&out, allocator, "cancelled_at", self.cancelled_at);
+        try runtime.put(&out, allocator, "completed_at", self.completed_at);
         try runtime.put(&out, allocator, "event_id", self.event_id);
-        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
-        try runtime.put(&out, allocator, "ip", self.ip);
-        try runtime.put(&out, allocator, "labels", self.labels);
-        try runtime.put(&out, allocator, "occurred_at", self.occurred_at);
-        try runtime.put(&out, allocator, "payload_content_type", self.payload_content_type);
-        try runtime.put(&out, allocator, "received_at", self.received_at);
-        try runtime.put(&out, allocator, "metadata", self.metadata);
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
         return .{ .object = out };
     }
 };
 
-/// The `EventPost` the API declares.
-pub const EventPost = struct {
-    /// carries `application_id`: UUID of the application this event belongs to.
+/// The `BulkRetryDryRun` the API declares.
+pub const BulkRetryDryRun = struct {
+    /// carries `count`: Number of (event, subscription) pairs a bulk retry would redeliver now
+    count: i64,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!BulkRetryDryRun {
+        const fields = try runtime.asFields(value, "BulkRetryDryRun");
+        return .{
+            .count = try runtime.read(allocator, fields, "count", runtime.integer64),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: BulkRetryDryRun,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "count", self.count);
+        return .{ .object = out };
+    }
+};
+
+/// The `BulkRetryPost` the API declares.
+pub const BulkRetryPost = struct {
+    /// carries `application_id`.
     application_id: []const u8,
-    /// carries `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length:
-    /// 1-200 characters.
-    event_type: []const u8,
-    /// carries `labels`: Labels for event filtering and routing to subscriptions.
-    labels: runtime.Map([]const u8),
-    /// carries `occurred_at`: Timestamp when the event occurred.
-    occurred_at: []const u8,
-    /// carries `payload`: The event payload. For binary content, use base64 encoding. Max length:
-    /// 699050 characters (512 KiB base64-encoded).
-    payload: []const u8,
-    /// carries `payload_content_type`: Content type of the payload. Valid values: text/plain,
-    /// application/json, application/octet-stream+base64. Length: 1-100 characters.
-    payload_content_type: []const u8,
-    /// carries `event_id`: Optional unique identifier for this event (client-generated UUID). If
-    /// not provided, a UUIDv7 will be generated by the server.
+    /// carries `event_id`.
     event_id: ?[]const u8,
-    /// carries `metadata`: Optional metadata key-value pairs associated with the event.
-    metadata: ?runtime.Map([]const u8),
+    /// carries `event_type_names`.
+    event_type_names: ?[]const []const u8,
+    /// carries `max_created_at`.
+    max_created_at: ?[]const u8,
+    /// carries `min_created_at`.
+    min_created_at: ?[]const u8,
+    /// carries `status`, one of `models.BulkRetryPostStatus.values`: Redeliver request attempts
+    /// that failed (default) or that succeeded
+    status: ?[]const u8,
+    /// carries `subscription_id`.
+    subscription_id: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!EventPost {
-        const fields = try runtime.asFields(value, "EventPost");
+    ) runtime.DecodeError!BulkRetryPost {
+        const fields = try runtime.asFields(value, "BulkRetryPost");
         return .{
             .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
-            .event_type = try runtime.read(allocator, fields, "event_type", runtime.text),
-            .labels = try runtime.read(allocator, fields, "labels", runtime.map(runtime.text).read),
-            .occurred_at = try runtime.read(allocator, fields, "occurred_at", runtime.text),
-            .payload = try runtime.read(allocator, fields, "payload", runtime.text),
-            .payload_content_type = try runtime.read(
+            .event_id = try runtime.maybe(allocator, fields, "event_id", runtime.text),
+            .event_type_names = try runtime.maybe(
                 allocator,
                 fields,
-                "payload_content_type",
-                runtime.text,
+                "event_type_names",
+                runtime.list(runtime.text).read,
             ),
-            .event_id = try runtime.maybe(allocator, fields, "event_id", runtime.text),
-            .metadata = try runtime.maybe(
+            .max_created_at = try runtime.maybe(allocator, fields, "max_created_at", runtime.text),
+            .min_created_at = try runtime.maybe(allocator, fields, "min_created_at", runtime.text),
+            .status = try runtime.maybe(
                 allocator,
                 fields,
-                "metadata",
-                runtime.map(runtime.text).read,
+                "status",
+                runtime.memberOf(models.BulkRetryPostStatus).read,
+            ),
+            .subscription_id = try runtime.maybe(
+                allocator,
+                fields,
+                "subscription_id",
+                runtime.text,
             ),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: EventPost,
+        self: BulkRetryPost,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
         try runtime.put(&out, allocator, "application_id", self.application_id);
-        try runtime.put(&out, allocator, "event_type", self.event_type);
-        try runtime.put(&out, allocator, "labels", self.labels);
-        try runtime.put(&out, allocator, "occurred_at", self.occurred_at);
-        try runtime.put(&out, allocator, "payload", self.payload);
-        try runtime.put(&out, allocator, "payload_content_type", self.payload_content_type);
         try runtime.put(&out, allocator, "event_id", self.event_id);
-        try runtime.put(&out, allocator, "metadata", self.metadata);
+        try runtime.put(&out, allocator, "event_type_names", self.event_type_names);
+        try runtime.put(&out, allocator, "max_created_at", self.max_created_at);
+        try runtime.put(&out, allocator, "min_created_at", self.min_created_at);
+        try runtime.put(&out, allocator, "status", self.status);
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
         return .{ .object = out };
     }
 };
 
-/// The `EventType` the API declares.
-pub const EventType = struct {
-    /// carries `event_type_name`.
-    event_type_name: []const u8,
-    /// carries `resource_type_name`.
-    resource_type_name: []const u8,
-    /// carries `service_name`.
-    service_name: []const u8,
-    /// carries `verb_name`.
-    verb_name: []const u8,
-
+/// The `CancelEvent` the API declares.
+pub const CancelEvent = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!EventType {
-        const fields = try runtime.asFields(value, "EventType");
+    ) runtime.DecodeError!CancelEvent {
+        const fields = try runtime.asFields(value, "CancelEvent");
         return .{
-            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
-            .resource_type_name = try runtime.read(
-                allocator,
-                fields,
-                "resource_type_name",
-                runtime.text,
-            ),
-            .service_name = try runtime.read(allocator, fields, "service_name", runtime.text),
-            .verb_name = try runtime.read(allocator, fields, "verb_name", runtime.text),
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: EventType,
+        self: CancelEvent,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
-        try runtime.put(&out, allocator, "resource_type_name", self.resource_type_name);
-        try runtime.put(&out, allocator, "service_name", self.service_name);
-        try runtime.put(&out, allocator, "verb_name", self.verb_name);
+        try runtime.put(&out, allocator, "application_id", self.application_id);
         return .{ .object = out };
     }
 };
 
-/// The `EventTypePost` the API declares.
-pub const EventTypePost = struct {
-    /// carries `application_id`.
-    application_id: []const u8,
-    /// carries `resource_type`.
-    resource_type: []const u8,
-    /// carries `service`.
-    service: []const u8,
-    /// carries `verb`.
-    verb: []const u8,
+/// The `ClientCertificate` the API declares.
+pub const ClientCertificate = struct {
+    /// carries `certificate_pem`: Certificate chain, leaf certificate first
+    certificate_pem: []const u8,
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `fingerprint_sha256`: SHA-256 fingerprint of the leaf certificate, as hexadecimal
+    fingerprint_sha256: []const u8,
+    /// carries `not_after`.
+    not_after: []const u8,
+    /// carries `not_before`.
+    not_before: []const u8,
+    /// carries `subject`.
+    subject: []const u8,
+    /// carries `subscription_id`.
+    subscription_id: []const u8,
+    /// carries `updated_at`.
+    updated_at: []const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!EventTypePost {
-        const fields = try runtime.asFields(value, "EventTypePost");
+    ) runtime.DecodeError!ClientCertificate {
+        const fields = try runtime.asFields(value, "ClientCertificate");
         return .{
-            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
-            .resource_type = try runtime.read(allocator, fields, "resource_type", runtime.text),
-            .service = try runtime.read(allocator, fields, "service", runtime.text),
-            .verb = try runtime.read(allocator, fields, "verb", runtime.text),
+            .certificate_pem = try runtime.read(allocator, fields, "certificate_pem", runtime.text),
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .fingerprint_sha256 = try runtime.read(
+                allocator,
+                fields,
+                "fingerprint_sha256",
+                runtime.text,
+            ),
+            .not_after = try runtime.read(allocator, fields, "not_after", runtime.text),
+            .not_before = try runtime.read(allocator, fields, "not_before", runtime.text),
+            .subject = try runtime.read(allocator, fields, "subject", runtime.text),
+            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
+            .updated_at = try runtime.read(allocator, fields, "updated_at", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: EventTypePost,
+        self: ClientCertificate,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "application_id", self.application_id);
-        try runtime.put(&out, allocator, "resource_type", self.resource_type);
-        try runtime.put(&out, allocator, "service", self.service);
-        try runtime.put(&out, allocator, "verb", self.verb);
+        try runtime.put(&out, allocator, "certificate_pem", self.certificate_pem);
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "fingerprint_sha256", self.fingerprint_sha256);
+        try runtime.put(&out, allocator, "not_after", self.not_after);
+        try runtime.put(&out, allocator, "not_before", self.not_before);
+        try runtime.put(&out, allocator, "subject", self.subject);
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
+        try runtime.put(&out, allocator, "updated_at", self.updated_at);
         return .{ .object = out };
     }
 };
 
-/// The `EventWithPayload` the API declares.
-pub const EventWithPayload = struct {
-    /// carries `event_id`.
-    event_id: []const u8,
-    /// carries `event_type_name`.
-    event_type_name: []const u8,
-    /// carries `ip`.
-    ip: []const u8,
-    /// carries `labels`.
-    labels: std.json.Value,
-    /// carries `occurred_at`.
-    occurred_at: []const u8,
-    /// carries `payload`.
-    payload: []const u8,
-    /// carries `payload_content_type`.
-    payload_content_type: []const u8,
-    /// carries `received_at`.
-    received_at: []const u8,
-    /// carries `metadata`.
-    metadata: ?std.json.Value,
+/// The `ClientCertificatePut` the API declares.
+pub const ClientCertificatePut = struct {
+    /// carries `certificate_pem`: PEM-encoded certificate chain, leaf certificate first
+    certificate_pem: []const u8,
+    /// carries `private_key_pem`: PEM-encoded unencrypted private key of the leaf certificate
+    /// (PKCS#8, PKCS#1 or SEC1)
+    private_key_pem: []const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!EventWithPayload {
-        const fields = try runtime.asFields(value, "EventWithPayload");
+    ) runtime.DecodeError!ClientCertificatePut {
+        const fields = try runtime.asFields(value, "ClientCertificatePut");
         return .{
-            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
-            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
-            .ip = try runtime.read(allocator, fields, "ip", runtime.text),
-            .labels = try runtime.read(allocator, fields, "labels", runtime.jsonValue),
-            .occurred_at = try runtime.read(allocator, fields, "occurred_at", runtime.text),
-            .payload = try runtime.read(allocator, fields, "payload", runtime.text),
-            .payload_content_type = try runtime.read(
-                allocator,
-                fields,
-                "payload_content_type",
-                runtime.text,
-            ),
-            .received_at = try runtime.read(allocator, fields, "received_at", runtime.text),
-            .metadata = try runtime.maybe(allocator, fields, "metadata", runtime.jsonValue),
+            .certificate_pem = try runtime.read(allocator, fields, "certificate_pem", runtime.text),
+            .private_key_pem = try runtime.read(allocator, fields, "private_key_pem", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: EventWithPayload,
+        self: ClientCertificatePut,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "event_id", self.event_id);
-        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
-        try runtime.put(&out, allocator, "ip", self.ip);
-        try runtime.put(&out, allocator, "labels", self.labels);
-        try runtime.put(&out, allocator, "occurred_at", self.occurred_at);
-        try runtime.put(&out, allocator, "payload", self.payload);
-        try runtime.put(&out, allocator, "payload_content_type", self.payload_content_type);
-        try runtime.put(&out, allocator, "received_at", self.received_at);
-        try runtime.put(&out, allocator, "metadata", self.metadata);
+        try runtime.put(&out, allocator, "certificate_pem", self.certificate_pem);
+        try runtime.put(&out, allocator, "private_key_pem", self.private_key_pem);
         return .{ .object = out };
     }
 };
 
-/// The `EventsPerDayEntry` the API declares.
-pub const EventsPerDayEntry = struct {
-    /// carries `amount`.
-    amount: i32,
-    /// carries `application_id`.
-    application_id: []const u8,
-    /// carries `application_name`.
-    application_name: []const u8,
-    /// carries `date`.
-    date: []const u8,
-    /// carries `is_provisional`.
-    is_provisional: bool,
+/// The `DeadLetter` the API declares.
+pub const DeadLetter = struct {
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `dead_letter_id`.
+    dead_letter_id: []const u8,
+    /// carries `event`.
+    event: models.DeadLetterEvent,
+    /// carries `reason`, one of `models.DeadLetterReason.values`.
+    reason: []const u8,
+    /// carries `request_attempt_id`: Last request attempt of the delivery
+    request_attempt_id: []const u8,
+    /// carries `retry_count`.
+    retry_count: i32,
+    /// carries `subscription`.
+    subscription: models.DeadLetterSubscription,
+    /// carries `http_response_status`.
+    http_response_status: ?i32,
+    /// carries `response_id`: Response to the last request attempt, if the target was reached
+    response_id: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!EventsPerDayEntry {
-        const fields = try runtime.asFields(value, "EventsPerDayEntry");
+    ) runtime.DecodeError!DeadLetter {
+        const fields = try runtime.asFields(value, "DeadLetter");
         return .{
-            .amount = try runtime.read(allocator, fields, "amount", runtime.integer32),
-            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
-            .application_name = try runtime.read(
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .dead_letter_id = try runtime.read(allocator, fields, "dead_letter_id", runtime.text),
+            .event = try runtime.read(allocator, fields, "event", models.DeadLetterEvent.fromJson),
+            .reason = try runtime.read(
                 allocator,
                 fields,
-                "application_name",
+                "reason",
+                runtime.memberOf(models.DeadLetterReason).read,
+            ),
+            .request_attempt_id = try runtime.read(
+                allocator,
+                fields,
+                "request_attempt_id",
                 runtime.text,
             ),
-            .date = try runtime.read(allocator, fields, "date", runtime.text),
-            .is_provisional = try runtime.read(
+            .retry_count = try runtime.read(allocator, fields, "retry_count", runtime.integer32),
+            .subscription = try runtime.read(
                 allocator,
                 fields,
-                "is_provisional",
-                runtime.boolean,
+                "subscription",
+                models.DeadLetterSubscription.fromJson,
+            ),
+            .http_response_status = try runtime.maybe(
+                allocator,
+                fields,
+                "http_response_status",
+                runtime.integer32,
             ),
+            .response_id = try runtime.maybe(allocator, fields, "response_id", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: EventsPerDayEntry,
+        self: DeadLetter,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "amount", self.amount);
-        try runtime.put(&out, allocator, "application_id", self.application_id);
-        try runtime.put(&out, allocator, "application_name", self.application_name);
-        try runtime.put(&out, allocator, "date", self.date);
-        try runtime.put(&out, allocator, "is_provisional", self.is_provisional);
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "dead_letter_id", self.dead_letter_id);
+        try runtime.put(&out, allocator, "event", self.event);
+        try runtime.put(&out, allocator, "reason", self.reason);
+        try runtime.put(&out, allocator, "request_attempt_id", self.request_attempt_id);
+        try runtime.put(&out, allocator, "retry_count", self.retry_count);
+        try runtime.put(&out, allocator, "subscription", self.subscription);
+        try runtime.put(&out, allocator, "http_response_status", self.http_response_status);
+        try runtime.put(&out, allocator, "response_id", self.response_id);
         return .{ .object = out };
     }
 };
 
-/// The `IngestedEvent` the API declares.
-pub const IngestedEvent = struct {
-    /// carries `application_id`.
-    application_id: []const u8,
-    /// carries `event_id`.
-    event_id: []const u8,
-    /// carries `received_at`.
-    received_at: []const u8,
+/// The `DeadLetterDetails` the API declares.
+pub const DeadLetterDetails = struct {
+    /// carries `dead_letter`: Delivery an output worker gave up on
+    dead_letter: models.DeadLetterDetailsDeadLetter,
+    /// carries `last_response`: Response to the last request attempt, if the target was reached
+    last_response: ?models.DeadLetterDetailsLastResponse,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!IngestedEvent {
-        const fields = try runtime.asFields(value, "IngestedEvent");
+    ) runtime.DecodeError!DeadLetterDetails {
+        const fields = try runtime.asFields(value, "DeadLetterDetails");
         return .{
-            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
-            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
-            .received_at = try runtime.read(allocator, fields, "received_at", runtime.text),
+            .dead_letter = try runtime.read(
+                allocator,
+                fields,
+                "dead_letter",
+                models.DeadLetterDetailsDeadLetter.fromJson,
+            ),
+            .last_response = try runtime.maybe(
+                allocator,
+                fields,
+                "last_response",
+                models.DeadLetterDetailsLastResponse.fromJson,
+            ),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: IngestedEvent,
+        self: DeadLetterDetails,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "application_id", self.application_id);
-        try runtime.put(&out, allocator, "event_id", self.event_id);
-        try runtime.put(&out, allocator, "received_at", self.received_at);
+        try runtime.put(&out, allocator, "dead_letter", self.dead_letter);
+        try runtime.put(&out, allocator, "last_response", self.last_response);
         return .{ .object = out };
     }
 };
 
-/// The `InstanceConfig` the API declares.
-pub const InstanceConfig = struct {
-    /// carries `application_secret_compatibility`.
-    application_secret_compatibility: bool,
-    /// carries `auto_db_migration`.
-    auto_db_migration: bool,
-    /// carries `biscuit_public_key`.
-    biscuit_public_key: []const u8,
-    /// carries `password_minimum_length`.
-    password_minimum_length: i32,
-    /// carries `quota_enforcement`.
-    quota_enforcement: bool,
-    /// carries `registration_disabled`.
-    registration_disabled: bool,
-    /// carries `support_email_address`.
-    support_email_address: []const u8,
-    /// carries `cloudflare_turnstile_site_key`.
-    cloudflare_turnstile_site_key: ?[]const u8,
-    /// carries `formbricks`.
-    formbricks: ?models.InstanceConfigFormbricks,
-    /// carries `matomo`.
-    matomo: ?models.InstanceConfigMatomo,
+/// The `DeadLetterDetailsDeadLetter` the API declares.
+pub const DeadLetterDetailsDeadLetter = struct {
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `dead_letter_id`.
+    dead_letter_id: []const u8,
+    /// carries `event`.
+    event: models.DeadLetterDetailsDeadLetterEvent,
+    /// carries `reason`, one of `models.DeadLetterDetailsDeadLetterReason.values`.
+    reason: []const u8,
+    /// carries `request_attempt_id`: Last request attempt of the delivery
+    request_attempt_id: []const u8,
+    /// carries `retry_count`.
+    retry_count: i32,
+    /// carries `subscription`.
+    subscription: models.DeadLetterDetailsDeadLetterSubscription,
+    /// carries `http_response_status`.
+    http_response_status: ?i32,
+    /// carries `response_id`: Response to the last request attempt, if the target was reached
+    response_id: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!InstanceConfig {
-        const fields = try runtime.asFields(value, "InstanceConfig");
+    ) runtime.DecodeError!DeadLetterDetailsDeadLetter {
+        const fields = try runtime.asFields(value, "DeadLetterDetailsDeadLetter");
         return .{
-            .application_secret_compatibility = try runtime.read(
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .dead_letter_id = try runtime.read(allocator, fields, "dead_letter_id", runtime.text),
+            .event = try runtime.read(
                 allocator,
                 fields,
-                "application_secret_compatibility",
-                runtime.boolean,
+                "event",
+                models.DeadLetterDetailsDeadLetterEvent.fromJson,
             ),
-            .auto_db_migration = try runtime.read(
+            .reason = try runtime.read(
                 allocator,
                 fields,
-                "auto_db_migration",
-                runtime.boolean,
+                "reason",
+                runtime.memberOf(models.DeadLetterDetailsDeadLetterReason).read,
             ),
-            .biscuit_public_key = try runtime.read(
+            .request_attempt_id = try runtime.read(
                 allocator,
                 fields,
-                "biscuit_public_key",
+                "request_attempt_id",
                 runtime.text,
             ),
-            .password_minimum_length = try runtime.read(
-                allocator,
-                fields,
-                "password_minimum_length",
-                runtime.integer32,
-            ),
-            .quota_enforcement = try runtime.read(
+            .retry_count = try runtime.read(allocator, fields, "retry_count", runtime.integer32),
+            .subscription = try runtime.read(
                 allocator,
                 fields,
-                "quota_enforcement",
-                runtime.boolean,
+                "subscription",
+                models.DeadLetterDetailsDeadLetterSubscription.fromJson,
             ),
-            .registration_disabled = try runtime.read(
+            .http_response_status = try runtime.maybe(
                 allocator,
                 fields,
-                "registration_disabled",
-                runtime.boolean,
+                "http_response_status",
+                runtime.integer32,
             ),
-            .support_email_address = try runtime.read(
-                allocator,
-                fields,
+            .response_id = try runtime.maybe(allocator, fields, "response_id", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: DeadLetterDetailsDeadLetter,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "dead_letter_id", self.dead_letter_id);
+        try runtime.put(&out, allocator, "event", self.event);
+        try runtime.put(&out, allocator, "reason", self.reason);
+        try runtime.put(&out, allocator, "request_attempt_id", self.request_attempt_id);
+        try runtime.put(&out, allocator, "retry_count", self.retry_count);
+        try runtime.put(&out, allocator, "subscription", self.subscription);
+        try runtime.put(&out, allocator, "http_response_status", self.http_response_status);
+        try runtime.put(&out, allocator, "response_id", self.response_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `DeadLetterDetailsDeadLetterEvent` the API declares.
+pub const DeadLetterDetailsDeadLetterEvent = struct {
+    /// carries `event_id`.
+    event_id: []const u8,
+    /// carries `event_type_name`.
+    event_type_name: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!DeadLetterDetailsDeadLetterEvent {
+        const fields = try runtime.asFields(value, "DeadLetterDetailsDeadLetterEvent");
+        return .{
+            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
+            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: DeadLetterDetailsDeadLetterEvent,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
+        return .{ .object = out };
+    }
+};
+
+/// The `DeadLetterDetailsDeadLetterSubscription` the API declares.
+pub const DeadLetterDetailsDeadLetterSubscription = struct {
+    /// carries `subscription_id`.
+    subscription_id: []const u8,
+    /// carries `description`.
+    description: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!DeadLetterDetailsDeadLetterSubscription {
+        const fields = try runtime.asFields(value, "DeadLetterDetailsDeadLetterSubscription");
+        return .{
+            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
+            .description = try runtime.maybe(allocator, fields, "description", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: DeadLetterDetailsDeadLetterSubscription,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
+        try runtime.put(&out, allocator, "description", self.description);
+        return .{ .object = out };
+    }
+};
+
+/// The `DeadLetterDetailsLastResponse` the API declares.
+pub const DeadLetterDetailsLastResponse = struct {
+    /// carries `response_id`.
+    response_id: []const u8,
+    /// carries `body`.
+    body: ?[]const u8,
+    /// carries `elapsed_time_ms`.
+    elapsed_time_ms: ?i32,
+    /// carries `headers`.
+    headers: ?runtime.Map([]const u8),
+    /// carries `http_code`.
+    http_code: ?i32,
+    /// carries `response_error_name`.
+    response_error_name: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!DeadLetterDetailsLastResponse {
+        const fields = try runtime.asFields(value, "DeadLetterDetailsLastResponse");
+        return .{
+            .response_id = try runtime.read(allocator, fields, "response_id", runtime.text),
+            .body = try runtime.maybe(allocator, fields, "body", runtime.text),
+            .elapsed_time_ms = try runtime.maybe(
+                allocator,
+                fields,
+                "elapsed_time_ms",
+                runtime.integer32,
+            ),
+            .headers = try runtime.maybe(
+                allocator,
+                fields,
+                "headers",
+                runtime.map(runtime.text).read,
+            ),
+            .http_code = try runtime.maybe(allocator, fields, "http_code", runtime.integer32),
+            .response_error_name = try runtime.maybe(
+                allocator,
+                fields,
+                "response_error_name",
+                runtime.text,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: DeadLetterDetailsLastResponse,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "response_id", self.response_id);
+        try runtime.put(&out, allocator, "body", self.body);
+        try runtime.put(&out, allocator, "elapsed_time_ms", self.elapsed_time_ms);
+        try runtime.put(&out, allocator, "headers", self.headers);
+        try runtime.put(&out, allocator, "http_code", self.http_code);
+        try runtime.put(&out, allocator, "response_error_name", self.response_error_name);
+        return .{ .object = out };
+    }
+};
+
+/// The `DeadLetterEvent` the API declares.
+pub const DeadLetterEvent = struct {
+    /// carries `event_id`.
+    event_id: []const u8,
+    /// carries `event_type_name`.
+    event_type_name: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!DeadLetterEvent {
+        const fields = try runtime.asFields(value, "DeadLetterEvent");
+        return .{
+            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
+            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: DeadLetterEvent,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
+        return .{ .object = out };
+    }
+};
+
+/// The `DeadLetterSubscription` the API declares.
+pub const DeadLetterSubscription = struct {
+    /// carries `subscription_id`.
+    subscription_id: []const u8,
+    /// carries `description`.
+    description: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!DeadLetterSubscription {
+        const fields = try runtime.asFields(value, "DeadLetterSubscription");
+        return .{
+            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
+            .description = try runtime.maybe(allocator, fields, "description", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: DeadLetterSubscription,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
+        try runtime.put(&out, allocator, "description", self.description);
+        return .{ .object = out };
+    }
+};
+
+/// The `Event` the API declares.
+pub const Event = struct {
+    /// carries `event_id`.
+    event_id: []const u8,
+    /// carries `event_type_name`.
+    event_type_name: []const u8,
+    /// carries `ip`.
+    ip: []const u8,
+    /// carries `labels`.
+    labels: std.json.Value,
+    /// carries `occurred_at`.
+    occurred_at: []const u8,
+    /// carries `payload_content_type`.
+    payload_content_type: []const u8,
+    /// carries `received_at`.
+    received_at: []const u8,
+    /// carries `cancelled_at`: Date the scheduled delivery of the event was cancelled
+    cancelled_at: ?[]const u8,
+    /// carries `deliver_at`: Date from which the event is delivered, when it was scheduled for a
+    /// later delivery
+    deliver_at: ?[]const u8,
+    /// carries `event_type_version`.
+    event_type_version: ?i32,
+    /// carries `expires_at`: Date after which the event is no longer delivered
+    expires_at: ?[]const u8,
+    /// carries `metadata`.
+    metadata: ?std.json.Value,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!Event {
+        const fields = try runtime.asFields(value, "Event");
+        return .{
+            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
+            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
+            .ip = try runtime.read(allocator, fields, "ip", runtime.text),
+            .labels = try runtime.read(allocator, fields, "labels", runtime.jsonValue),
+            .occurred_at = try runtime.read(allocator, fields, "occurred_at", runtime.text),
+            .payload_content_type = try runtime.read(
+                allocator,
+                fields,
+                "payload_content_type",
+                runtime.text,
+            ),
+            .received_at = try runtime.read(allocator, fields, "received_at", runtime.text),
+            .cancelled_at = try runtime.maybe(allocator, fields, "cancelled_at", runtime.text),
+            .deliver_at = try runtime.maybe(allocator, fields, "deliver_at", runtime.text),
+            .event_type_version = try runtime.maybe(
+                allocator,
+                fields,
+                "event_type_version",
+                runtime.integer32,
+            ),
+            .expires_at = try runtime.maybe(allocator, fields, "expires_at", runtime.text),
+            .metadata = try runtime.maybe(allocator, fields, "metadata", runtime.jsonValue),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: Event,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
+        try runtime.put(&out, allocator, "ip", self.ip);
+        try runtime.put(&out, allocator, "labels", self.labels);
+        try runtime.put(&out, allocator, "occurred_at", self.occurred_at);
+        try runtime.put(&out, allocator, "payload_content_type", self.payload_content_type);
+        try runtime.put(&out, allocator, "received_at", self.received_at);
+        try runtime.put(&out, allocator, "cancelled_at", self.cancelled_at);
+        try runtime.put(&out, allocator, "deliver_at", self.deliver_at);
+        try runtime.put(&out, allocator, "event_type_version", self.event_type_version);
+        try runtime.put(&out, allocator, "expires_at", self.expires_at);
+        try runtime.put(&out, allocator, "metadata", self.metadata);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventPost` the API declares.
+pub const EventPost = struct {
+    /// carries `application_id`: UUID of the application this event belongs to.
+    application_id: []const u8,
+    /// carries `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length:
+    /// 1-200 characters.
+    event_type: []const u8,
+    /// carries `labels`: Labels for event filtering and routing to subscriptions.
+    labels: runtime.Map([]const u8),
+    /// carries `occurred_at`: Timestamp when the event occurred.
+    occurred_at: []const u8,
+    /// carries `payload`: The event payload. For binary content, use base64 encoding. Max length:
+    /// 699050 characters (512 KiB base64-encoded).
+    payload: []const u8,
+    /// carries `payload_content_type`: Content type of the payload. Valid values: text/plain,
+    /// application/json, application/octet-stream+base64. Length: 1-100 characters.
+    payload_content_type: []const u8,
+    /// carries `deliver_at`: Optional date from which the event is delivered to its subscriptions;
+    /// it is delivered right away when omitted or in the past. It can be at most as far in the
+    /// future as the events retention of the appl
+    deliver_at: ?[]const u8,
+    /// carries `event_id`: Optional unique identifier for this event (client-generated UUID). If
+    /// not provided, a UUIDv7 will be generated by the server.
+    event_id: ?[]const u8,
+    /// carries `event_type_version`: Optional version of the event type the payload follows.
+    /// Subscriptions pinned to another version of the event type do not receive the event.
+    event_type_version: ?i32,
+    /// carries `expires_at`: Optional date after which the event is no longer delivered: its
+    /// deliveries that are still pending or waiting for a retry are cancelled. Defaults to the
+    /// default time to live of its event type, counted f
+    expires_at: ?[]const u8,
+    /// carries `metadata`: Optional metadata key-value pairs associated with the event.
+    metadata: ?runtime.Map([]const u8),
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventPost {
+        const fields = try runtime.asFields(value, "EventPost");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .event_type = try runtime.read(allocator, fields, "event_type", runtime.text),
+            .labels = try runtime.read(allocator, fields, "labels", runtime.map(runtime.text).read),
+            .occurred_at = try runtime.read(allocator, fields, "occurred_at", runtime.text),
+            .payload = try runtime.read(allocator, fields, "payload", runtime.text),
+            .payload_content_type = try runtime.read(
+                allocator,
+                fields,
+                "payload_content_type",
+                runtime.text,
+            ),
+            .deliver_at = try runtime.maybe(allocator, fields, "deliver_at", runtime.text),
+            .event_id = try runtime.maybe(allocator, fields, "event_id", runtime.text),
+            .event_type_version = try runtime.maybe(
+                allocator,
+                fields,
+                "event_type_version",
+                runtime.integer32,
+            ),
+            .expires_at = try runtime.maybe(allocator, fields, "expires_at", runtime.text),
+            .metadata = try runtime.maybe(
+                allocator,
+                fields,
+                "metadata",
+                runtime.map(runtime.text).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventPost,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "event_type", self.event_type);
+        try runtime.put(&out, allocator, "labels", self.labels);
+        try runtime.put(&out, allocator, "occurred_at", self.occurred_at);
+        try runtime.put(&out, allocator, "payload", self.payload);
+        try runtime.put(&out, allocator, "payload_content_type", self.payload_content_type);
+        try runtime.put(&out, allocator, "deliver_at", self.deliver_at);
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "event_type_version", self.event_type_version);
+        try runtime.put(&out, allocator, "expires_at", self.expires_at);
+        try runtime.put(&out, allocator, "metadata", self.metadata);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventType` the API declares.
+pub const EventType = struct {
+    /// carries `event_type_name`.
+    event_type_name: []const u8,
+    /// carries `payload_schema_mode`, one of `models.EventTypePayloadSchemaMode.values`.
+    payload_schema_mode: []const u8,
+    /// carries `resource_type_name`.
+    resource_type_name: []const u8,
+    /// carries `service_name`.
+    service_name: []const u8,
+    /// carries `verb_name`.
+    verb_name: []const u8,
+    /// carries `versions`.
+    versions: []const models.EventTypeVersions,
+    /// carries `default_ttl_s`: Time to live in seconds of the events of this type that do not give
+    /// an expiry date
+    default_ttl_s: ?i32,
+    /// carries `payload_schema`.
+    payload_schema: ?std.json.Value,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventType {
+        const fields = try runtime.asFields(value, "EventType");
+        return .{
+            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
+            .payload_schema_mode = try runtime.read(
+                allocator,
+                fields,
+                "payload_schema_mode",
+                runtime.memberOf(models.EventTypePayloadSchemaMode).read,
+            ),
+            .resource_type_name = try runtime.read(
+                allocator,
+                fields,
+                "resource_type_name",
+                runtime.text,
+            ),
+            .service_name = try runtime.read(allocator, fields, "service_name", runtime.text),
+            .verb_name = try runtime.read(allocator, fields, "verb_name", runtime.text),
+            .versions = try runtime.read(
+                allocator,
+                fields,
+                "versions",
+                runtime.list(models.EventTypeVersions.fromJson).read,
+            ),
+            .default_ttl_s = try runtime.maybe(
+                allocator,
+                fields,
+                "default_ttl_s",
+                runtime.integer32,
+            ),
+            .payload_schema = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_schema",
+                runtime.jsonValue,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventType,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
+        try runtime.put(&out, allocator, "payload_schema_mode", self.payload_schema_mode);
+        try runtime.put(&out, allocator, "resource_type_name", self.resource_type_name);
+        try runtime.put(&out, allocator, "service_name", self.service_name);
+        try runtime.put(&out, allocator, "verb_name", self.verb_name);
+        try runtime.put(&out, allocator, "versions", self.versions);
+        try runtime.put(&out, allocator, "default_ttl_s", self.default_ttl_s);
+        try runtime.put(&out, allocator, "payload_schema", self.payload_schema);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventTypePost` the API declares.
+pub const EventTypePost = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `resource_type`.
+    resource_type: []const u8,
+    /// carries `service`.
+    service: []const u8,
+    /// carries `verb`.
+    verb: []const u8,
+    /// carries `default_ttl_s`: Time to live in seconds of the events of this type that do not give
+    /// an expiry date; their pending deliveries are cancelled once it is over
+    default_ttl_s: ?i32,
+    /// carries `payload_schema`: JSON Schema the payloads of the `application/json` events of this
+    /// type must match; remote references are not supported
+    payload_schema: ?std.json.Value,
+    /// carries `payload_schema_mode`, one of `models.EventTypePostPayloadSchemaMode.values`: How
+    /// the payload schema is applied at ingestion (default: `enforce`)
+    payload_schema_mode: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventTypePost {
+        const fields = try runtime.asFields(value, "EventTypePost");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .resource_type = try runtime.read(allocator, fields, "resource_type", runtime.text),
+            .service = try runtime.read(allocator, fields, "service", runtime.text),
+            .verb = try runtime.read(allocator, fields, "verb", runtime.text),
+            .default_ttl_s = try runtime.maybe(
+                allocator,
+                fields,
+                "default_ttl_s",
+                runtime.integer32,
+            ),
+            .payload_schema = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_schema",
+                runtime.jsonValue,
+            ),
+            .payload_schema_mode = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_schema_mode",
+                runtime.memberOf(models.EventTypePostPayloadSchemaMode).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventTypePost,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "resource_type", self.resource_type);
+        try runtime.put(&out, allocator, "service", self.service);
+        try runtime.put(&out, allocator, "verb", self.verb);
+        try runtime.put(&out, allocator, "default_ttl_s", self.default_ttl_s);
+        try runtime.put(&out, allocator, "payload_schema", self.payload_schema);
+        try runtime.put(&out, allocator, "payload_schema_mode", self.payload_schema_mode);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventTypePut` the API declares.
+pub const EventTypePut = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `default_ttl_s`: Time to live in seconds of the events of this type that do not give
+    /// an expiry date; their pending deliveries are cancelled once it is over
+    default_ttl_s: ?i32,
+    /// carries `payload_schema`: JSON Schema the payloads of the `application/json` events of this
+    /// type must match; remote references are not supported
+    payload_schema: ?std.json.Value,
+    /// carries `payload_schema_mode`, one of `models.EventTypePutPayloadSchemaMode.values`: How the
+    /// payload schema is applied at ingestion (default: `enforce`)
+    payload_schema_mode: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventTypePut {
+        const fields = try runtime.asFields(value, "EventTypePut");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .default_ttl_s = try runtime.maybe(
+                allocator,
+                fields,
+                "default_ttl_s",
+                runtime.integer32,
+            ),
+            .payload_schema = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_schema",
+                runtime.jsonValue,
+            ),
+            .payload_schema_mode = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_schema_mode",
+                runtime.memberOf(models.EventTypePutPayloadSchemaMode).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventTypePut,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "default_ttl_s", self.default_ttl_s);
+        try runtime.put(&out, allocator, "payload_schema", self.payload_schema);
+        try runtime.put(&out, allocator, "payload_schema_mode", self.payload_schema_mode);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventTypeVersion` the API declares.
+pub const EventTypeVersion = struct {
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `version`.
+    version: i32,
+    /// carries `deprecated_at`.
+    deprecated_at: ?[]const u8,
+    /// carries `payload_schema`: JSON Schema the payloads of the events declaring this version must
+    /// match, instead of the one of the event type
+    payload_schema: ?std.json.Value,
+    /// carries `sunset_at`: Date from which the events declaring this version are refused
+    sunset_at: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventTypeVersion {
+        const fields = try runtime.asFields(value, "EventTypeVersion");
+        return .{
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .version = try runtime.read(allocator, fields, "version", runtime.integer32),
+            .deprecated_at = try runtime.maybe(allocator, fields, "deprecated_at", runtime.text),
+            .payload_schema = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_schema",
+                runtime.jsonValue,
+            ),
+            .sunset_at = try runtime.maybe(allocator, fields, "sunset_at", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventTypeVersion,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "version", self.version);
+        try runtime.put(&out, allocator, "deprecated_at", self.deprecated_at);
+        try runtime.put(&out, allocator, "payload_schema", self.payload_schema);
+        try runtime.put(&out, allocator, "sunset_at", self.sunset_at);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventTypeVersionDeprecate` the API declares.
+pub const EventTypeVersionDeprecate = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `version`.
+    version: i32,
+    /// carries `sunset_at`: Date from which the events declaring this version are refused; none
+    /// keeps accepting them
+    sunset_at: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventTypeVersionDeprecate {
+        const fields = try runtime.asFields(value, "EventTypeVersionDeprecate");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .version = try runtime.read(allocator, fields, "version", runtime.integer32),
+            .sunset_at = try runtime.maybe(allocator, fields, "sunset_at", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventTypeVersionDeprecate,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "version", self.version);
+        try runtime.put(&out, allocator, "sunset_at", self.sunset_at);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventTypeVersionPost` the API declares.
+pub const EventTypeVersionPost = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `version`.
+    version: i32,
+    /// carries `payload_schema`: JSON Schema the payloads of the `application/json` events
+    /// declaring this version must match, instead of the one of the event type; remote references
+    /// are not supported
+    payload_schema: ?std.json.Value,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventTypeVersionPost {
+        const fields = try runtime.asFields(value, "EventTypeVersionPost");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .version = try runtime.read(allocator, fields, "version", runtime.integer32),
+            .payload_schema = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_schema",
+                runtime.jsonValue,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventTypeVersionPost,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "version", self.version);
+        try runtime.put(&out, allocator, "payload_schema", self.payload_schema);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventTypeVersions` the API declares.
+pub const EventTypeVersions = struct {
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `version`.
+    version: i32,
+    /// carries `deprecated_at`.
+    deprecated_at: ?[]const u8,
+    /// carries `payload_schema`: JSON Schema the payloads of the events declaring this version must
+    /// match, instead of the one of the event type
+    payload_schema: ?std.json.Value,
+    /// carries `sunset_at`: Date from which the events declaring this version are refused
+    sunset_at: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventTypeVersions {
+        const fields = try runtime.asFields(value, "EventTypeVersions");
+        return .{
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .version = try runtime.read(allocator, fields, "version", runtime.integer32),
+            .deprecated_at = try runtime.maybe(allocator, fields, "deprecated_at", runtime.text),
+            .payload_schema = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_schema",
+                runtime.jsonValue,
+            ),
+            .sunset_at = try runtime.maybe(allocator, fields, "sunset_at", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventTypeVersions,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "version", self.version);
+        try runtime.put(&out, allocator, "deprecated_at", self.deprecated_at);
+        try runtime.put(&out, allocator, "payload_schema", self.payload_schema);
+        try runtime.put(&out, allocator, "sunset_at", self.sunset_at);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventWithPayload` the API declares.
+pub const EventWithPayload = struct {
+    /// carries `event_id`.
+    event_id: []const u8,
+    /// carries `event_type_name`.
+    event_type_name: []const u8,
+    /// carries `ip`.
+    ip: []const u8,
+    /// carries `labels`.
+    labels: std.json.Value,
+    /// carries `occurred_at`.
+    occurred_at: []const u8,
+    /// carries `payload`.
+    payload: []const u8,
+    /// carries `payload_content_type`.
+    payload_content_type: []const u8,
+    /// carries `received_at`.
+    received_at: []const u8,
+    /// carries `cancelled_at`: Date the scheduled delivery of the event was cancelled
+    cancelled_at: ?[]const u8,
+    /// carries `deliver_at`: Date from which the event is delivered, when it was scheduled for a
+    /// later delivery
+    deliver_at: ?[]const u8,
+    /// carries `event_type_version`.
+    event_type_version: ?i32,
+    /// carries `expires_at`: Date after which the event is no longer delivered
+    expires_at: ?[]const u8,
+    /// carries `metadata`.
+    metadata: ?std.json.Value,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventWithPayload {
+        const fields = try runtime.asFields(value, "EventWithPayload");
+        return .{
+            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
+            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
+            .ip = try runtime.read(allocator, fields, "ip", runtime.text),
+            .labels = try runtime.read(allocator, fields, "labels", runtime.jsonValue),
+            .occurred_at = try runtime.read(allocator, fields, "occurred_at", runtime.text),
+            .payload = try runtime.read(allocator, fields, "payload", runtime.text),
+            .payload_content_type = try runtime.read(
+                allocator,
+                fields,
+                "payload_content_type",
+                runtime.text,
+            ),
+            .received_at = try runtime.read(allocator, fields, "received_at", runtime.text),
+            .cancelled_at = try runtime.maybe(allocator, fields, "cancelled_at", runtime.text),
+            .deliver_at = try runtime.maybe(allocator, fields, "deliver_at", runtime.text),
+            .event_type_version = try runtime.maybe(
+                allocator,
+                fields,
+                "event_type_version",
+                runtime.integer32,
+            ),
+            .expires_at = try runtime.maybe(allocator, fields, "expires_at", runtime.text),
+            .metadata = try runtime.maybe(allocator, fields, "metadata", runtime.jsonValue),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventWithPayload,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
+        try runtime.put(&out, allocator, "ip", self.ip);
+        try runtime.put(&out, allocator, "labels", self.labels);
+        try runtime.put(&out, allocator, "occurred_at", self.occurred_at);
+        try runtime.put(&out, allocator, "payload", self.payload);
+        try runtime.put(&out, allocator, "payload_content_type", self.payload_content_type);
+        try runtime.put(&out, allocator, "received_at", self.received_at);
+        try runtime.put(&out, allocator, "cancelled_at", self.cancelled_at);
+        try runtime.put(&out, allocator, "deliver_at", self.deliver_at);
+        try runtime.put(&out, allocator, "event_type_version", self.event_type_version);
+        try runtime.put(&out, allocator, "expires_at", self.expires_at);
+        try runtime.put(&out, allocator, "metadata", self.metadata);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventsBatchPost` the API declares.
+pub const EventsBatchPost = struct {
+    /// carries `application_id`: UUID of the application the events belong to. Events of the batch
+    /// that carry another application ID are not ingested.
+    application_id: []const u8,
+    /// carries `events`: Events to ingest. Length: 1-100 events; the whole request body is also
+    /// subject to the usual request size limit.
+    events: []const models.EventsBatchPostEvents,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventsBatchPost {
+        const fields = try runtime.asFields(value, "EventsBatchPost");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .events = try runtime.read(
+                allocator,
+                fields,
+                "events",
+                runtime.list(models.EventsBatchPostEvents.fromJson).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventsBatchPost,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "events", self.events);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventsBatchPostEvents` the API declares.
+pub const EventsBatchPostEvents = struct {
+    /// carries `application_id`: UUID of the application this event belongs to.
+    application_id: []const u8,
+    /// carries `event_type`: The type of event (e.g., 'user.created', 'order.completed'). Length:
+    /// 1-200 characters.
+    event_type: []const u8,
+    /// carries `labels`: Labels for event filtering and routing to subscriptions.
+    labels: runtime.Map([]const u8),
+    /// carries `occurred_at`: Timestamp when the event occurred.
+    occurred_at: []const u8,
+    /// carries `payload`: The event payload. For binary content, use base64 encoding. Max length:
+    /// 699050 characters (512 KiB base64-encoded).
+    payload: []const u8,
+    /// carries `payload_content_type`: Content type of the payload. Valid values: text/plain,
+    /// application/json, application/octet-stream+base64. Length: 1-100 characters.
+    payload_content_type: []const u8,
+    /// carries `deliver_at`: Optional date from which the event is delivered to its subscriptions;
+    /// it is delivered right away when omitted or in the past. It can be at most as far in the
+    /// future as the events retention of the appl
+    deliver_at: ?[]const u8,
+    /// carries `event_id`: Optional unique identifier for this event (client-generated UUID). If
+    /// not provided, a UUIDv7 will be generated by the server.
+    event_id: ?[]const u8,
+    /// carries `event_type_version`: Optional version of the event type the payload follows.
+    /// Subscriptions pinned to another version of the event type do not receive the event.
+    event_type_version: ?i32,
+    /// carries `expires_at`: Optional date after which the event is no longer delivered: its
+    /// deliveries that are still pending or waiting for a retry are cancelled. Defaults to the
+    /// default time to live of its event type, counted f
+    expires_at: ?[]const u8,
+    /// carries `metadata`: Optional metadata key-value pairs associated with the event.
+    metadata: ?runtime.Map([]const u8),
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventsBatchPostEvents {
+        const fields = try runtime.asFields(value, "EventsBatchPostEvents");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .event_type = try runtime.read(allocator, fields, "event_type", runtime.text),
+            .labels = try runtime.read(allocator, fields, "labels", runtime.map(runtime.text).read),
+            .occurred_at = try runtime.read(allocator, fields, "occurred_at", runtime.text),
+            .payload = try runtime.read(allocator, fields, "payload", runtime.text),
+            .payload_content_type = try runtime.read(
+                allocator,
+                fields,
+                "payload_content_type",
+                runtime.text,
+            ),
+            .deliver_at = try runtime.maybe(allocator, fields, "deliver_at", runtime.text),
+            .event_id = try runtime.maybe(allocator, fields, "event_id", runtime.text),
+            .event_type_version = try runtime.maybe(
+                allocator,
+                fields,
+                "event_type_version",
+                runtime.integer32,
+            ),
+            .expires_at = try runtime.maybe(allocator, fields, "expires_at", runtime.text),
+            .metadata = try runtime.maybe(
+                allocator,
+                fields,
+                "metadata",
+                runtime.map(runtime.text).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventsBatchPostEvents,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "event_type", self.event_type);
+        try runtime.put(&out, allocator, "labels", self.labels);
+        try runtime.put(&out, allocator, "occurred_at", self.occurred_at);
+        try runtime.put(&out, allocator, "payload", self.payload);
+        try runtime.put(&out, allocator, "payload_content_type", self.payload_content_type);
+        try runtime.put(&out, allocator, "deliver_at", self.deliver_at);
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "event_type_version", self.event_type_version);
+        try runtime.put(&out, allocator, "expires_at", self.expires_at);
+        try runtime.put(&out, allocator, "metadata", self.metadata);
+        return .{ .object = out };
+    }
+};
+
+/// The `EventsPerDayEntry` the API declares.
+pub const EventsPerDayEntry = struct {
+    /// carries `amount`.
+    amount: i32,
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `application_name`.
+    application_name: []const u8,
+    /// carries `date`.
+    date: []const u8,
+    /// carries `is_provisional`.
+    is_provisional: bool,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!EventsPerDayEntry {
+        const fields = try runtime.asFields(value, "EventsPerDayEntry");
+        return .{
+            .amount = try runtime.read(allocator, fields, "amount", runtime.integer32),
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .application_name = try runtime.read(
+                allocator,
+                fields,
+                "application_name",
+                runtime.text,
+            ),
+            .date = try runtime.read(allocator, fields, "date", runtime.text),
+            .is_provisional = try runtime.read(
+                allocator,
+                fields,
+                "is_provisional",
+                runtime.boolean,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: EventsPerDayEntry,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "amount", self.amount);
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "application_name", self.application_name);
+        try runtime.put(&out, allocator, "date", self.date);
+        try runtime.put(&out, allocator, "is_provisional", self.is_provisional);
+        return .{ .object = out };
+    }
+};
+
+/// The `Inbox` the API declares.
+pub const Inbox = struct {
+    /// carries `messages`.
+    messages: []const models.InboxMessages,
+    /// carries `next_cursor`: Pass it as `cursor` to the next pull to continue after the last
+    /// message of this one
+    next_cursor: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!Inbox {
+        const fields = try runtime.asFields(value, "Inbox");
+        return .{
+            .messages = try runtime.read(
+                allocator,
+                fields,
+                "messages",
+                runtime.list(models.InboxMessages.fromJson).read,
+            ),
+            .next_cursor = try runtime.maybe(allocator, fields, "next_cursor", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: Inbox,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "messages", self.messages);
+        try runtime.put(&out, allocator, "next_cursor", self.next_cursor);
+        return .{ .object = out };
+    }
+};
+
+/// The `InboxAck` the API declares.
+pub const InboxAck = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `receipts`: Receipts of the leased events
+    receipts: []const []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!InboxAck {
+        const fields = try runtime.asFields(value, "InboxAck");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .receipts = try runtime.read(
+                allocator,
+                fields,
+                "receipts",
+                runtime.list(runtime.text).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: InboxAck,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "receipts", self.receipts);
+        return .{ .object = out };
+    }
+};
+
+/// The `InboxMessages` the API declares.
+pub const InboxMessages = struct {
+    /// carries `event_id`.
+    event_id: []const u8,
+    /// carries `event_type_name`.
+    event_type_name: []const u8,
+    /// carries `labels`.
+    labels: std.json.Value,
+    /// carries `leased_until`: When the event is offered again if it was neither acknowledged nor
+    /// rejected
+    leased_until: []const u8,
+    /// carries `occurred_at`.
+    occurred_at: []const u8,
+    /// carries `payload`: Base64-encoded
+    payload: []const u8,
+    /// carries `payload_content_type`.
+    payload_content_type: []const u8,
+    /// carries `receipt`: Identifies this lease of the request attempt; a later lease of the same
+    /// attempt gets another one
+    receipt: []const u8,
+    /// carries `received_at`.
+    received_at: []const u8,
+    /// carries `request_attempt_id`.
+    request_attempt_id: []const u8,
+    /// carries `retry_count`.
+    retry_count: i32,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!InboxMessages {
+        const fields = try runtime.asFields(value, "InboxMessages");
+        return .{
+            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
+            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
+            .labels = try runtime.read(allocator, fields, "labels", runtime.jsonValue),
+            .leased_until = try runtime.read(allocator, fields, "leased_until", runtime.text),
+            .occurred_at = try runtime.read(allocator, fields, "occurred_at", runtime.text),
+            .payload = try runtime.read(allocator, fields, "payload", runtime.text),
+            .payload_content_type = try runtime.read(
+                allocator,
+                fields,
+                "payload_content_type",
+                runtime.text,
+            ),
+            .receipt = try runtime.read(allocator, fields, "receipt", runtime.text),
+            .received_at = try runtime.read(allocator, fields, "received_at", runtime.text),
+            .request_attempt_id = try runtime.read(
+                allocator,
+                fields,
+                "request_attempt_id",
+                runtime.text,
+            ),
+            .retry_count = try runtime.read(allocator, fields, "retry_count", runtime.integer32),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: InboxMessages,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
+        try runtime.put(&out, allocator, "labels", self.labels);
+        try runtime.put(&out, allocator, "leased_until", self.leased_until);
+        try runtime.put(&out, allocator, "occurred_at", self.occurred_at);
+        try runtime.put(&out, allocator, "payload", self.payload);
+        try runtime.put(&out, allocator, "payload_content_type", self.payload_content_type);
+        try runtime.put(&out, allocator, "receipt", self.receipt);
+        try runtime.put(&out, allocator, "received_at", self.received_at);
+        try runtime.put(&out, allocator, "request_attempt_id", self.request_attempt_id);
+        try runtime.put(&out, allocator, "retry_count", self.retry_count);
+        return .{ .object = out };
+    }
+};
+
+/// The `InboxNack` the API declares.
+pub const InboxNack = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `receipts`: Receipts of the leased events
+    receipts: []const []const u8,
+    /// carries `retry`: Whether the events are offered again, as new request attempts (true by
+    /// default); they are not beyond the maximum number of retries of the subscription, or of this
+    /// instance if it has none
+    retry: ?bool,
+    /// carries `retry_in_s`: Delay in seconds before the events are offered again (0 by default)
+    retry_in_s: ?i32,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!InboxNack {
+        const fields = try runtime.asFields(value, "InboxNack");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .receipts = try runtime.read(
+                allocator,
+                fields,
+                "receipts",
+                runtime.list(runtime.text).read,
+            ),
+            .retry = try runtime.maybe(allocator, fields, "retry", runtime.boolean),
+            .retry_in_s = try runtime.maybe(allocator, fields, "retry_in_s", runtime.integer32),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: InboxNack,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "receipts", self.receipts);
+        try runtime.put(&out, allocator, "retry", self.retry);
+        try runtime.put(&out, allocator, "retry_in_s", self.retry_in_s);
+        return .{ .object = out };
+    }
+};
+
+/// The `InboxSettlement` the API declares.
+pub const InboxSettlement = struct {
+    /// carries `settled`: Receipts of the events that were settled
+    settled: []const []const u8,
+    /// carries `stale`: Receipts that settled nothing: their lease expired, or the event was
+    /// already settled
+    stale: []const []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!InboxSettlement {
+        const fields = try runtime.asFields(value, "InboxSettlement");
+        return .{
+            .settled = try runtime.read(
+                allocator,
+                fields,
+                "settled",
+                runtime.list(runtime.text).read,
+            ),
+            .stale = try runtime.read(allocator, fields, "stale", runtime.list(runtime.text).read),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: InboxSettlement,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "settled", self.settled);
+        try runtime.put(&out, allocator, "stale", self.stale);
+        return .{ .object = out };
+    }
+};
+
+/// The `IngestedEvent` the API declares.
+pub const IngestedEvent = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `event_id`.
+    event_id: []const u8,
+    /// carries `received_at`.
+    received_at: []const u8,
+    /// carries `deprecation`: Present when the event was ingested with a deprecated version of its
+    /// event type
+    deprecation: ?models.IngestedEventDeprecation,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!IngestedEvent {
+        const fields = try runtime.asFields(value, "IngestedEvent");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
+            .received_at = try runtime.read(allocator, fields, "received_at", runtime.text),
+            .deprecation = try runtime.maybe(
+                allocator,
+                fields,
+                "deprecation",
+                models.IngestedEventDeprecation.fromJson,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: IngestedEvent,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "received_at", self.received_at);
+        try runtime.put(&out, allocator, "deprecation", self.deprecation);
+        return .{ .object = out };
+    }
+};
+
+/// The `IngestedEventDeprecation` the API declares.
+pub const IngestedEventDeprecation = struct {
+    /// carries `deprecated_at`.
+    deprecated_at: []const u8,
+    /// carries `event_type_version`.
+    event_type_version: i32,
+    /// carries `sunset_at`.
+    sunset_at: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!IngestedEventDeprecation {
+        const fields = try runtime.asFields(value, "IngestedEventDeprecation");
+        return .{
+            .deprecated_at = try runtime.read(allocator, fields, "deprecated_at", runtime.text),
+            .event_type_version = try runtime.read(
+                allocator,
+                fields,
+                "event_type_version",
+                runtime.integer32,
+            ),
+            .sunset_at = try runtime.maybe(allocator, fields, "sunset_at", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: IngestedEventDeprecation,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "deprecated_at", self.deprecated_at);
+        try runtime.put(&out, allocator, "event_type_version", self.event_type_version);
+        try runtime.put(&out, allocator, "sunset_at", self.sunset_at);
+        return .{ .object = out };
+    }
+};
+
+/// The `IngestedEventsBatch` the API declares.
+pub const IngestedEventsBatch = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `events`: One outcome per submitted event, in the order the events were submitted
+    events: []const models.IngestedEventsBatchEvents,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!IngestedEventsBatch {
+        const fields = try runtime.asFields(value, "IngestedEventsBatch");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .events = try runtime.read(
+                allocator,
+                fields,
+                "events",
+                runtime.list(models.IngestedEventsBatchEvents.fromJson).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: IngestedEventsBatch,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "events", self.events);
+        return .{ .object = out };
+    }
+};
+
+/// The `IngestedEventsBatchEvents` the API declares.
+pub const IngestedEventsBatchEvents = struct {
+    /// carries `status`, one of `models.IngestedEventsBatchEventsStatus.values`.
+    status: []const u8,
+    /// carries `deprecation`: Present when the event was ingested with a deprecated version of its
+    /// event type
+    deprecation: ?models.IngestedEventsBatchEventsDeprecation,
+    /// carries `event_id`: ID of the ingested event, or the one the event was submitted with when
+    /// it was not ingested
+    event_id: ?[]const u8,
+    /// carries `problem`: Present when the event was not ingested
+    problem: ?models.IngestedEventsBatchEventsProblem,
+    /// carries `received_at`: Present when the event was ingested
+    received_at: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!IngestedEventsBatchEvents {
+        const fields = try runtime.asFields(value, "IngestedEventsBatchEvents");
+        return .{
+            .status = try runtime.read(
+                allocator,
+                fields,
+                "status",
+                runtime.memberOf(models.IngestedEventsBatchEventsStatus).read,
+            ),
+            .deprecation = try runtime.maybe(
+                allocator,
+                fields,
+                "deprecation",
+                models.IngestedEventsBatchEventsDeprecation.fromJson,
+            ),
+            .event_id = try runtime.maybe(allocator, fields, "event_id", runtime.text),
+            .problem = try runtime.maybe(
+                allocator,
+                fields,
+                "problem",
+                models.IngestedEventsBatchEventsProblem.fromJson,
+            ),
+            .received_at = try runtime.maybe(allocator, fields, "received_at", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: IngestedEventsBatchEvents,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "status", self.status);
+        try runtime.put(&out, allocator, "deprecation", self.deprecation);
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "problem", self.problem);
+        try runtime.put(&out, allocator, "received_at", self.received_at);
+        return .{ .object = out };
+    }
+};
+
+/// The `IngestedEventsBatchEventsDeprecation` the API declares.
+pub const IngestedEventsBatchEventsDeprecation = struct {
+    /// carries `deprecated_at`.
+    deprecated_at: []const u8,
+    /// carries `event_type_version`.
+    event_type_version: i32,
+    /// carries `sunset_at`.
+    sunset_at: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!IngestedEventsBatchEventsDeprecation {
+        const fields = try runtime.asFields(value, "IngestedEventsBatchEventsDeprecation");
+        return .{
+            .deprecated_at = try runtime.read(allocator, fields, "deprecated_at", runtime.text),
+            .event_type_version = try runtime.read(
+                allocator,
+                fields,
+                "event_type_version",
+                runtime.integer32,
+            ),
+            .sunset_at = try runtime.maybe(allocator, fields, "sunset_at", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: IngestedEventsBatchEventsDeprecation,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "deprecated_at", self.deprecated_at);
+        try runtime.put(&out, allocator, "event_type_version", self.event_type_version);
+        try runtime.put(&out, allocator, "sunset_at", self.sunset_at);
+        return .{ .object = out };
+    }
+};
+
+/// The `IngestedEventsBatchEventsProblem` the API declares.
+pub const IngestedEventsBatchEventsProblem = struct {
+    /// carries `detail`.
+    detail: []const u8,
+    /// carries `id`, one of `models.IngestedEventsBatchEventsProblemId.values`: Identifier of the
+    /// problem that occurred, stable across releases
+    id: []const u8,
+    /// carries `status`.
+    status: i32,
+    /// carries `title`.
+    title: []const u8,
+    /// carries `type`: Documentation page of this problem, which is also what distinguishes one
+    /// problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing
+    /// without parsing a URL.
+    type: []const u8,
+    /// carries `validation`: Which submitted values were rejected and why, keyed by the name of the
+    /// field they were submitted under. Carried by validation failures (`Validation`, status 422)
+    /// and `null` for every other problem, as
+    validation: ?std.json.Value,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!IngestedEventsBatchEventsProblem {
+        const fields = try runtime.asFields(value, "IngestedEventsBatchEventsProblem");
+        return .{
+            .detail = try runtime.read(allocator, fields, "detail", runtime.text),
+            .id = try runtime.read(
+                allocator,
+                fields,
+                "id",
+                runtime.memberOf(models.IngestedEventsBatchEventsProblemId).read,
+            ),
+            .status = try runtime.read(allocator, fields, "status", runtime.integer32),
+            .title = try runtime.read(allocator, fields, "title", runtime.text),
+            .type = try runtime.read(allocator, fields, "type", runtime.text),
+            .validation = try runtime.maybe(allocator, fields, "validation", runtime.jsonValue),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: IngestedEventsBatchEventsProblem,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "detail", self.detail);
+        try runtime.put(&out, allocator, "id", self.id);
+        try runtime.put(&out, allocator, "status", self.status);
+        try runtime.put(&out, allocator, "title", self.title);
+        try runtime.put(&out, allocator, "type", self.type);
+        try runtime.put(&out, allocator, "validation", self.validation);
+        return .{ .object = out };
+    }
+};
+
+/// The `InstanceConfig` the API declares.
+pub const InstanceConfig = struct {
+    /// carries `application_secret_compatibility`.
+    application_secret_compatibility: bool,
+    /// carries `auto_db_migration`.
+    auto_db_migration: bool,
+    /// carries `biscuit_public_key`.
+    biscuit_public_key: []const u8,
+    /// carries `password_minimum_length`.
+    password_minimum_length: i32,
+    /// carries `quota_enforcement`.
+    quota_enforcement: bool,
+    /// carries `registration_disabled`.
+    registration_disabled: bool,
+    /// carries `support_email_address`.
+    support_email_address: []const u8,
+    /// carries `cloudflare_turnstile_site_key`.
+    cloudflare_turnstile_site_key: ?[]const u8,
+    /// carries `formbricks`.
+    formbricks: ?models.InstanceConfigFormbricks,
+    /// carries `matomo`.
+    matomo: ?models.InstanceConfigMatomo,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!InstanceConfig {
+        const fields = try runtime.asFields(value, "InstanceConfig");
+        return .{
+            .application_secret_compatibility = try runtime.read(
+                allocator,
+                fields,
+                "application_secret_compatibility",
+                runtime.boolean,
+            ),
+            .auto_db_migration = try runtime.read(
+                allocator,
+                fields,
+                "auto_db_migration",
+                runtime.boolean,
+            ),
+            .biscuit_public_key = try runtime.read(
+                allocator,
+                fields,
+                "biscuit_public_key",
+                runtime.text,
+            ),
+            .password_minimum_length = try runtime.read(
+                allocator,
+                fields,
+                "password_minimum_length",
+                runtime.integer32,
+            ),
+            .quota_enforcement = try runtime.read(
+                allocator,
+                fields,
+                "quota_enforcement",
+                runtime.boolean,
+            ),
+            .registration_disabled = try runtime.read(
+                allocator,
+                fields,
+                "registration_disabled",
+                runtime.boolean,
+            ),
+            .support_email_address = try runtime.read(
+                allocator,
+                fields,
                 "support_email_address",
                 runtime.text,
             ),
-            .cloudflare_turnstile_site_key = try runtime.maybe(
+            .cloudflare_turnstile_site_key = try runtime.maybe(
+                allocator,
+                fields,
+                "cloudflare_turnstile_site_key",
+                runtime.text,
+            ),
+            .formbricks = try runtime.maybe(
+                allocator,
+                fields,
+                "formbricks",
+                models.InstanceConfigFormbricks.fromJson,
+            ),
+            .matomo = try runtime.maybe(
+                allocator,
+                fields,
+                "matomo",
+                models.InstanceConfigMatomo.fromJson,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: InstanceConfig,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(
+            &out,
+            allocator,
+            "application_secret_compatibility",
+            self.application_secret_compatibility,
+        );
+        try runtime.put(&out, allocator, "auto_db_migration", self.auto_db_migration);
+        try runtime.put(&out, allocator, "biscuit_public_key", self.biscuit_public_key);
+        try runtime.put(&out, allocator, "password_minimum_length", self.password_minimum_length);
+        try runtime.put(&out, allocator, "quota_enforcement", self.quota_enforcement);
+        try runtime.put(&out, allocator, "registration_disabled", self.registration_disabled);
+        try runtime.put(&out, allocator, "support_email_address", self.support_email_address);
+        try runtime.put(
+            &out,
+            allocator,
+            "cloudflare_turnstile_site_key",
+            self.cloudflare_turnstile_site_key,
+        );
+        try runtime.put(&out, allocator, "formbricks", self.formbricks);
+        try runtime.put(&out, allocator, "matomo", self.matomo);
+        return .{ .object = out };
+    }
+};
+
+/// The `InstanceConfigFormbricks` the API declares.
+pub const InstanceConfigFormbricks = struct {
+    /// carries `api_host`.
+    api_host: []const u8,
+    /// carries `environment_id`.
+    environment_id: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!InstanceConfigFormbricks {
+        const fields = try runtime.asFields(value, "InstanceConfigFormbricks");
+        return .{
+            .api_host = try runtime.read(allocator, fields, "api_host", runtime.text),
+            .environment_id = try runtime.read(allocator, fields, "environment_id", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: InstanceConfigFormbricks,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "api_host", self.api_host);
+        try runtime.put(&out, allocator, "environment_id", self.environment_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `InstanceConfigMatomo` the API declares.
+pub const InstanceConfigMatomo = struct {
+    /// carries `site_id`.
+    site_id: i32,
+    /// carries `url`.
+    url: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!InstanceConfigMatomo {
+        const fields = try runtime.asFields(value, "InstanceConfigMatomo");
+        return .{
+            .site_id = try runtime.read(allocator, fields, "site_id", runtime.integer32),
+            .url = try runtime.read(allocator, fields, "url", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: InstanceConfigMatomo,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "site_id", self.site_id);
+        try runtime.put(&out, allocator, "url", self.url);
+        return .{ .object = out };
+    }
+};
+
+/// The `JsonWebKeySet` the API declares.
+pub const JsonWebKeySet = struct {
+    /// carries `keys`.
+    keys: []const models.JsonWebKeySetKeys,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!JsonWebKeySet {
+        const fields = try runtime.asFields(value, "JsonWebKeySet");
+        return .{
+            .keys = try runtime.read(
+                allocator,
+                fields,
+                "keys",
+                runtime.list(models.JsonWebKeySetKeys.fromJson).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: JsonWebKeySet,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "keys", self.keys);
+        return .{ .object = out };
+    }
+};
+
+/// The `JsonWebKeySetKeys` the API declares.
+pub const JsonWebKeySetKeys = struct {
+    /// carries `alg`: Always `EdDSA`
+    alg: []const u8,
+    /// carries `crv`: Always `Ed25519`
+    crv: []const u8,
+    /// carries `kid`: ID of the signing key, which signatures name in their `k` part
+    kid: []const u8,
+    /// carries `kty`: Always `OKP`
+    kty: []const u8,
+    /// carries `use`: Always `sig`
+    use: []const u8,
+    /// carries `x`: Public key, encoded in base64url without padding
+    x: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!JsonWebKeySetKeys {
+        const fields = try runtime.asFields(value, "JsonWebKeySetKeys");
+        return .{
+            .alg = try runtime.read(allocator, fields, "alg", runtime.text),
+            .crv = try runtime.read(allocator, fields, "crv", runtime.text),
+            .kid = try runtime.read(allocator, fields, "kid", runtime.text),
+            .kty = try runtime.read(allocator, fields, "kty", runtime.text),
+            .use = try runtime.read(allocator, fields, "use", runtime.text),
+            .x = try runtime.read(allocator, fields, "x", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: JsonWebKeySetKeys,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "alg", self.alg);
+        try runtime.put(&out, allocator, "crv", self.crv);
+        try runtime.put(&out, allocator, "kid", self.kid);
+        try runtime.put(&out, allocator, "kty", self.kty);
+        try runtime.put(&out, allocator, "use", self.use);
+        try runtime.put(&out, allocator, "x", self.x);
+        return .{ .object = out };
+    }
+};
+
+/// The `OAuth2ClientCredentials` the API declares.
+pub const Oauth2ClientCredentials = struct {
+    /// carries `client_id`.
+    client_id: []const u8,
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `scopes`.
+    scopes: []const []const u8,
+    /// carries `subscription_id`.
+    subscription_id: []const u8,
+    /// carries `token_url`.
+    token_url: []const u8,
+    /// carries `updated_at`.
+    updated_at: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!Oauth2ClientCredentials {
+        const fields = try runtime.asFields(value, "OAuth2ClientCredentials");
+        return .{
+            .client_id = try runtime.read(allocator, fields, "client_id", runtime.text),
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .scopes = try runtime.read(
+                allocator,
+                fields,
+                "scopes",
+                runtime.list(runtime.text).read,
+            ),
+            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
+            .token_url = try runtime.read(allocator, fields, "token_url", runtime.text),
+            .updated_at = try runtime.read(allocator, fields, "updated_at", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: Oauth2ClientCredentials,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "client_id", self.client_id);
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "scopes", self.scopes);
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
+        try runtime.put(&out, allocator, "token_url", self.token_url);
+        try runtime.put(&out, allocator, "updated_at", self.updated_at);
+        return .{ .object = out };
+    }
+};
+
+/// The `OAuth2ClientCredentialsPut` the API declares.
+pub const Oauth2ClientCredentialsPut = struct {
+    /// carries `client_id`.
+    client_id: []const u8,
+    /// carries `client_secret`.
+    client_secret: []const u8,
+    /// carries `token_url`: Token endpoint of the authorization server
+    token_url: []const u8,
+    /// carries `scopes`: Scopes to request; none by default
+    scopes: ?[]const []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!Oauth2ClientCredentialsPut {
+        const fields = try runtime.asFields(value, "OAuth2ClientCredentialsPut");
+        return .{
+            .client_id = try runtime.read(allocator, fields, "client_id", runtime.text),
+            .client_secret = try runtime.read(allocator, fields, "client_secret", runtime.text),
+            .token_url = try runtime.read(allocator, fields, "token_url", runtime.text),
+            .scopes = try runtime.maybe(
+                allocator,
+                fields,
+                "scopes",
+                runtime.list(runtime.text).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: Oauth2ClientCredentialsPut,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "client_id", self.client_id);
+        try runtime.put(&out, allocator, "client_secret", self.client_secret);
+        try runtime.put(&out, allocator, "token_url", self.token_url);
+        try runtime.put(&out, allocator, "scopes", self.scopes);
+        return .{ .object = out };
+    }
+};
+
+/// The `Organization` the API declares.
+pub const Organization = struct {
+    /// carries `name`.
+    name: []const u8,
+    /// carries `organization_id`.
+    organization_id: []const u8,
+    /// carries `role`.
+    role: []const u8,
+    /// carries `plan`.
+    plan: ?models.OrganizationPlan,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!Organization {
+        const fields = try runtime.asFields(value, "Organization");
+        return .{
+            .name = try runtime.read(allocator, fields, "name", runtime.text),
+            .organization_id = try runtime.read(allocator, fields, "organization_id", runtime.text),
+            .role = try runtime.read(allocator, fields, "role", runtime.text),
+            .plan = try runtime.maybe(allocator, fields, "plan", models.OrganizationPlan.fromJson),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: Organization,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "name", self.name);
+        try runtime.put(&out, allocator, "organization_id", self.organization_id);
+        try runtime.put(&out, allocator, "role", self.role);
+        try runtime.put(&out, allocator, "plan", self.plan);
+        return .{ .object = out };
+    }
+};
+
+/// The `OrganizationInfo` the API declares.
+pub const OrganizationInfo = struct {
+    /// carries `consumption`.
+    consumption: models.OrganizationInfoConsumption,
+    /// carries `name`.
+    name: []const u8,
+    /// carries `onboarding_steps`.
+    onboarding_steps: models.OrganizationInfoOnboardingSteps,
+    /// carries `organization_id`.
+    organization_id: []const u8,
+    /// carries `quotas`.
+    quotas: models.OrganizationInfoQuotas,
+    /// carries `users`.
+    users: []const models.OrganizationInfoUsers,
+    /// carries `plan`.
+    plan: ?models.OrganizationInfoPlan,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!OrganizationInfo {
+        const fields = try runtime.asFields(value, "OrganizationInfo");
+        return .{
+            .consumption = try runtime.read(
+                allocator,
+                fields,
+                "consumption",
+                models.OrganizationInfoConsumption.fromJson,
+            ),
+            .name = try runtime.read(allocator, fields, "name", runtime.text),
+            .onboarding_steps = try runtime.read(
+                allocator,
+                fields,
+                "onboarding_steps",
+                models.OrganizationInfoOnboardingSteps.fromJson,
+            ),
+            .organization_id = try runtime.read(allocator, fields, "organization_id", runtime.text),
+            .quotas = try runtime.read(
+                allocator,
+                fields,
+                "quotas",
+                models.OrganizationInfoQuotas.fromJson,
+            ),
+            .users = try runtime.read(
+                allocator,
+                fields,
+                "users",
+                runtime.list(models.OrganizationInfoUsers.fromJson).read,
+            ),
+            .plan = try runtime.maybe(
+                allocator,
+                fields,
+                "plan",
+                models.OrganizationInfoPlan.fromJson,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: OrganizationInfo,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "consumption", self.consumption);
+        try runtime.put(&out, allocator, "name", self.name);
+        try runtime.put(&out, allocator, "onboarding_steps", self.onboarding_steps);
+        try runtime.put(&out, allocator, "organization_id", self.organization_id);
+        try runtime.put(&out, allocator, "quotas", self.quotas);
+        try runtime.put(&out, allocator, "users", self.users);
+        try runtime.put(&out, allocator, "plan", self.plan);
+        return .{ .object = out };
+    }
+};
+
+/// The `OrganizationInfoConsumption` the API declares.
+pub const OrganizationInfoConsumption = struct {
+    /// carries `applications`.
+    applications: ?i64,
+    /// carries `events_per_day`.
+    events_per_day: ?i64,
+    /// carries `members`.
+    members: ?i64,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!OrganizationInfoConsumption {
+        const fields = try runtime.asFields(value, "OrganizationInfoConsumption");
+        return .{
+            .applications = try runtime.maybe(allocator, fields, "applications", runtime.integer64),
+            .events_per_day = try runtime.maybe(
+                allocator,
+                fields,
+                "events_per_day",
+                runtime.integer64,
+            ),
+            .members = try runtime.maybe(allocator, fields, "members", runtime.integer64),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: OrganizationInfoConsumption,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "applications", self.applications);
+        try runtime.put(&out, allocator, "events_per_day", self.events_per_day);
+        try runtime.put(&out, allocator, "members", self.members);
+        return .{ .object = out };
+    }
+};
+
+/// The `OrganizationInfoOnboardingSteps` the API declares.
+pub const OrganizationInfoOnboardingSteps = struct {
+    /// carries `application`, one of `models.OrganizationInfoOnboardingStepsApplication.values`.
+    application: []const u8,
+    /// carries `event`, one of `models.OrganizationInfoOnboardingStepsEvent.values`.
+    event: []const u8,
+    /// carries `event_type`, one of `models.OrganizationInfoOnboardingStepsEventType.values`.
+    event_type: []const u8,
+    /// carries `subscription`, one of `models.OrganizationInfoOnboardingStepsSubscription.values`.
+    subscription: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!OrganizationInfoOnboardingSteps {
+        const fields = try runtime.asFields(value, "OrganizationInfoOnboardingSteps");
+        return .{
+            .application = try runtime.read(
+                allocator,
+                fields,
+                "application",
+                runtime.memberOf(models.OrganizationInfoOnboardingStepsApplication).read,
+            ),
+            .event = try runtime.read(
+                allocator,
+                fields,
+                "event",
+                runtime.memberOf(models.OrganizationInfoOnboardingStepsEvent).read,
+            ),
+            .event_type = try runtime.read(
+                allocator,
+                fields,
+                "event_type",
+                runtime.memberOf(models.OrganizationInfoOnboardingStepsEventType).read,
+            ),
+            .subscription = try runtime.read(
+                allocator,
+                fields,
+                "subscription",
+                runtime.memberOf(models.OrganizationInfoOnboardingStepsSubscription).read,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: OrganizationInfoOnboardingSteps,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application", self.application);
+        try runtime.put(&out, allocator, "event", self.event);
+        try runtime.put(&out, allocator, "event_type", self.event_type);
+        try runtime.put(&out, allocator, "subscription", self.subscription);
+        return .{ .object = out };
+    }
+};
+
+/// The `OrganizationInfoPlan` the API declares.
+pub const OrganizationInfoPlan = struct {
+    /// carries `label`.
+    label: []const u8,
+    /// carries `name`.
+    name: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!OrganizationInfoPlan {
+        const fields = try runtime.asFields(value, "OrganizationInfoPlan");
+        return .{
+            .label = try runtime.read(allocator, fields, "label", runtime.text),
+            .name = try runtime.read(allocator, fields, "name", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: OrganizationInfoPlan,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "label", self.label);
+        try runtime.put(&out, allocator, "name", self.name);
+        return .{ .object = out };
+    }
+};
+
+/// The `OrganizationInfoQuotas` the API declares.
+pub const OrganizationInfoQuotas = struct {
+    /// carries `applications_per_organization_limit`.
+    applications_per_organization_limit: i32,
+    /// carries `days_of_events_retention_limit`.
+    days_of_events_retention_limit: i32,
+    /// carries `events_per_day_limit`.
+    events_per_day_limit: i32,
+    /// carries `members_per_organization_limit`.
+    members_per_organization_limit: i32,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!OrganizationInfoQuotas {
+        const fields = try runtime.asFields(value, "OrganizationInfoQuotas");
+        return .{
+            .applications_per_organization_limit = try runtime.read(
+                allocator,
+                fields,
+                "applications_per_organization_limit",
+                runtime.integer32,
+            ),
+            .days_of_events_retention_limit = try runtime.read(
+                allocator,
+                fields,
+                "days_of_events_retention_limit",
+                runtime.integer32,
+            ),
+            .events_per_day_limit = try runtime.read(
+                allocator,
+                fields,
+                "events_per_day_limit",
+                runtime.integer32,
+            ),
+            .members_per_organization_limit = try runtime.read(
+                allocator,
+                fields,
+                "members_per_organization_limit",
+                runtime.integer32,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: OrganizationInfoQuotas,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(
+            &out,
+            allocator,
+            "applications_per_organization_limit",
+            self.applications_per_organization_limit,
+        );
+        try runtime.put(
+            &out,
+            allocator,
+            "days_of_events_retention_limit",
+            self.days_of_events_retention_limit,
+        );
+        try runtime.put(&out, allocator, "events_per_day_limit", self.events_per_day_limit);
+        try runtime.put(
+            &out,
+            allocator,
+            "members_per_organization_limit",
+            self.members_per_organization_limit,
+        );
+        return .{ .object = out };
+    }
+};
+
+/// The `OrganizationInfoUsers` the API declares.
+pub const OrganizationInfoUsers = struct {
+    /// carries `email`.
+    email: []const u8,
+    /// carries `first_name`.
+    first_name: []const u8,
+    /// carries `last_name`.
+    last_name: []const u8,
+    /// carries `role`.
+    role: []const u8,
+    /// carries `user_id`.
+    user_id: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!OrganizationInfoUsers {
+        const fields = try runtime.asFields(value, "OrganizationInfoUsers");
+        return .{
+            .email = try runtime.read(allocator, fields, "email", runtime.text),
+            .first_name = try runtime.read(allocator, fields, "first_name", runtime.text),
+            .last_name = try runtime.read(allocator, fields, "last_name", runtime.text),
+            .role = try runtime.read(allocator, fields, "role", runtime.text),
+            .user_id = try runtime.read(allocator, fields, "user_id", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: OrganizationInfoUsers,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "email", self.email);
+        try runtime.put(&out, allocator, "first_name", self.first_name);
+        try runtime.put(&out, allocator, "last_name", self.last_name);
+        try runtime.put(&out, allocator, "role", self.role);
+        try runtime.put(&out, allocator, "user_id", self.user_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `OrganizationPlan` the API declares.
+pub const OrganizationPlan = struct {
+    /// carries `label`.
+    label: []const u8,
+    /// carries `name`.
+    name: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!OrganizationPlan {
+        const fields = try runtime.asFields(value, "OrganizationPlan");
+        return .{
+            .label = try runtime.read(allocator, fields, "label", runtime.text),
+            .name = try runtime.read(allocator, fields, "name", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: OrganizationPlan,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "label", self.label);
+        try runtime.put(&out, allocator, "name", self.name);
+        return .{ .object = out };
+    }
+};
+
+/// The `Problem` the API declares.
+pub const Problem = struct {
+    /// carries `detail`.
+    detail: []const u8,
+    /// carries `id`, one of `models.ProblemId.values`: Identifier of the problem that occurred,
+    /// stable across releases
+    id: []const u8,
+    /// carries `status`.
+    status: i32,
+    /// carries `title`.
+    title: []const u8,
+    /// carries `type`: Documentation page of this problem, which is also what distinguishes one
+    /// problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing
+    /// without parsing a URL.
+    type: []const u8,
+    /// carries `validation`: Which submitted values were rejected and why, keyed by the name of the
+    /// field they were submitted under. Carried by validation failures (`Validation`, status 422)
+    /// and `null` for every other problem, as
+    validation: ?std.json.Value,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!Problem {
+        const fields = try runtime.asFields(value, "Problem");
+        return .{
+            .detail = try runtime.read(allocator, fields, "detail", runtime.text),
+            .id = try runtime.read(
+                allocator,
+                fields,
+                "id",
+                runtime.memberOf(models.ProblemId).read,
+            ),
+            .status = try runtime.read(allocator, fields, "status", runtime.integer32),
+            .title = try runtime.read(allocator, fields, "title", runtime.text),
+            .type = try runtime.read(allocator, fields, "type", runtime.text),
+            .validation = try runtime.maybe(allocator, fields, "validation", runtime.jsonValue),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: Problem,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "detail", self.detail);
+        try runtime.put(&out, allocator, "id", self.id);
+        try runtime.put(&out, allocator, "status", self.status);
+        try runtime.put(&out, allocator, "title", self.title);
+        try runtime.put(&out, allocator, "type", self.type);
+        try runtime.put(&out, allocator, "validation", self.validation);
+        return .{ .object = out };
+    }
+};
+
+/// The `PurgeDeadLetters` the API declares.
+pub const PurgeDeadLetters = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `subscription_id`: Only purge the dead letters of this subscription
+    subscription_id: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!PurgeDeadLetters {
+        const fields = try runtime.asFields(value, "PurgeDeadLetters");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .subscription_id = try runtime.maybe(
+                allocator,
+                fields,
+                "subscription_id",
+                runtime.text,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: PurgeDeadLetters,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `PurgedDeadLetters` the API declares.
+pub const PurgedDeadLetters = struct {
+    /// carries `purged_count`.
+    purged_count: i64,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!PurgedDeadLetters {
+        const fields = try runtime.asFields(value, "PurgedDeadLetters");
+        return .{
+            .purged_count = try runtime.read(allocator, fields, "purged_count", runtime.integer64),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: PurgedDeadLetters,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "purged_count", self.purged_count);
+        return .{ .object = out };
+    }
+};
+
+/// The `QuotasResponse` the API declares.
+pub const QuotasResponse = struct {
+    /// carries `enabled`.
+    enabled: bool,
+    /// carries `limits`.
+    limits: models.QuotasResponseLimits,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!QuotasResponse {
+        const fields = try runtime.asFields(value, "QuotasResponse");
+        return .{
+            .enabled = try runtime.read(allocator, fields, "enabled", runtime.boolean),
+            .limits = try runtime.read(
+                allocator,
+                fields,
+                "limits",
+                models.QuotasResponseLimits.fromJson,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: QuotasResponse,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "enabled", self.enabled);
+        try runtime.put(&out, allocator, "limits", self.limits);
+        return .{ .object = out };
+    }
+};
+
+/// The `QuotasResponseLimits` the API declares.
+pub const QuotasResponseLimits = struct {
+    /// carries `global_applications_per_organization_limit`.
+    global_applications_per_organization_limit: i32,
+    /// carries `global_days_of_events_retention_limit`.
+    global_days_of_events_retention_limit: i32,
+    /// carries `global_event_types_per_application_limit`.
+    global_event_types_per_application_limit: i32,
+    /// carries `global_events_per_day_limit`.
+    global_events_per_day_limit: i32,
+    /// carries `global_members_per_organization_limit`.
+    global_members_per_organization_limit: i32,
+    /// carries `global_subscriptions_per_application_limit`.
+    global_subscriptions_per_application_limit: i32,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!QuotasResponseLimits {
+        const fields = try runtime.asFields(value, "QuotasResponseLimits");
+        return .{
+            .global_applications_per_organization_limit = try runtime.read(
+                allocator,
+                fields,
+                "global_applications_per_organization_limit",
+                runtime.integer32,
+            ),
+            .global_days_of_events_retention_limit = try runtime.read(
+                allocator,
+                fields,
+                "global_days_of_events_retention_limit",
+                runtime.integer32,
+            ),
+            .global_event_types_per_application_limit = try runtime.read(
+                allocator,
+                fields,
+                "global_event_types_per_application_limit",
+                runtime.integer32,
+            ),
+            .global_events_per_day_limit = try runtime.read(
+                allocator,
+                fields,
+                "global_events_per_day_limit",
+                runtime.integer32,
+            ),
+            .global_members_per_organization_limit = try runtime.read(
+                allocator,
+                fields,
+                "global_members_per_organization_limit",
+                runtime.integer32,
+            ),
+            .global_subscriptions_per_application_limit = try runtime.read(
+                allocator,
+                fields,
+                "global_subscriptions_per_application_limit",
+                runtime.integer32,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: QuotasResponseLimits,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(
+            &out,
+            allocator,
+            "global_applications_per_organization_limit",
+            self.global_applications_per_organization_limit,
+        );
+        try runtime.put(
+            &out,
+            allocator,
+            "global_days_of_events_retention_limit",
+            self.global_days_of_events_retention_limit,
+        );
+        try runtime.put(
+            &out,
+            allocator,
+            "global_event_types_per_application_limit",
+            self.global_event_types_per_application_limit,
+        );
+        try runtime.put(
+            &out,
+            allocator,
+            "global_events_per_day_limit",
+            self.global_events_per_day_limit,
+        );
+        try runtime.put(
+            &out,
+            allocator,
+            "global_members_per_organization_limit",
+            self.global_members_per_organization_limit,
+        );
+        try runtime.put(
+            &out,
+            allocator,
+            "global_subscriptions_per_application_limit",
+            self.global_subscriptions_per_application_limit,
+        );
+        return .{ .object = out };
+    }
+};
+
+/// The `RedeliverDeadLetter` the API declares.
+pub const RedeliverDeadLetter = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!RedeliverDeadLetter {
+        const fields = try runtime.asFields(value, "RedeliverDeadLetter");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: RedeliverDeadLetter,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `RedeliverDeadLetters` the API declares.
+pub const RedeliverDeadLetters = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `subscription_id`: Only redeliver the dead letters of this subscription
+    subscription_id: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!RedeliverDeadLetters {
+        const fields = try runtime.asFields(value, "RedeliverDeadLetters");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .subscription_id = try runtime.maybe(
+                allocator,
+                fields,
+                "subscription_id",
+                runtime.text,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: RedeliverDeadLetters,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `RedeliveredDeadLetter` the API declares.
+pub const RedeliveredDeadLetter = struct {
+    /// carries `dead_letter_id`.
+    dead_letter_id: []const u8,
+    /// carries `request_attempt_id`.
+    request_attempt_id: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!RedeliveredDeadLetter {
+        const fields = try runtime.asFields(value, "RedeliveredDeadLetter");
+        return .{
+            .dead_letter_id = try runtime.read(allocator, fields, "dead_letter_id", runtime.text),
+            .request_attempt_id = try runtime.read(
+                allocator,
+                fields,
+                "request_attempt_id",
+                runtime.text,
+            ),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: RedeliveredDeadLetter,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "dead_letter_id", self.dead_letter_id);
+        try runtime.put(&out, allocator, "request_attempt_id", self.request_attempt_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `ReplayEvent` the API declares.
+pub const ReplayEvent = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!ReplayEvent {
+        const fields = try runtime.asFields(value, "ReplayEvent");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: ReplayEvent,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `RequestAttempt` the API declares.
+pub const RequestAttempt = struct {
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `event`.
+    event: models.RequestAttemptEvent,
+    /// carries `event_id`.
+    event_id: []const u8,
+    /// carries `request_attempt_id`.
+    request_attempt_id: []const u8,
+    /// carries `retry_count`.
+    retry_count: i32,
+    /// carries `status`: Status of a request attempt. The 'type' field indicates the status
+    /// variant. - waiting: {type, since, until} - Scheduled for future delivery - pending: {type,
+    /// since} - Ready to be processed - in_progre
+    status: models.RequestAttemptStatus,
+    /// carries `subscription`.
+    subscription: models.RequestAttemptSubscription,
+    /// carries `dead_letter_id`: Dead letter the request attempt became, when it was the last
+    /// attempt of a delivery an output worker gave up on
+    dead_letter_id: ?[]const u8,
+    /// carries `delay_until`.
+    delay_until: ?[]const u8,
+    /// carries `delivery_batch_id`: Batch the request attempt was delivered in, shared with the
+    /// other request attempts of the batch and their response
+    delivery_batch_id: ?[]const u8,
+    /// carries `failed_at`.
+    failed_at: ?[]const u8,
+    /// carries `http_response_status`.
+    http_response_status: ?i32,
+    /// carries `picked_at`.
+    picked_at: ?[]const u8,
+    /// carries `response_id`.
+    response_id: ?[]const u8,
+    /// carries `succeeded_at`.
+    succeeded_at: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!RequestAttempt {
+        const fields = try runtime.asFields(value, "RequestAttempt");
+        return .{
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .event = try runtime.read(
+                allocator,
+                fields,
+                "event",
+                models.RequestAttemptEvent.fromJson,
+            ),
+            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
+            .request_attempt_id = try runtime.read(
+                allocator,
+                fields,
+                "request_attempt_id",
+                runtime.text,
+            ),
+            .retry_count = try runtime.read(allocator, fields, "retry_count", runtime.integer32),
+            .status = try runtime.read(
+                allocator,
+                fields,
+                "status",
+                models.RequestAttemptStatus.fromJson,
+            ),
+            .subscription = try runtime.read(
+                allocator,
+                fields,
+                "subscription",
+                models.RequestAttemptSubscription.fromJson,
+            ),
+            .dead_letter_id = try runtime.maybe(allocator, fields, "dead_letter_id", runtime.text),
+            .delay_until = try runtime.maybe(allocator, fields, "delay_until", runtime.text),
+            .delivery_batch_id = try runtime.maybe(
+                allocator,
+                fields,
+                "delivery_batch_id",
+                runtime.text,
+            ),
+            .failed_at = try runtime.maybe(allocator, fields, "failed_at", runtime.text),
+            .http_response_status = try runtime.maybe(
+                allocator,
+                fields,
+                "http_response_status",
+                runtime.integer32,
+            ),
+            .picked_at = try runtime.maybe(allocator, fields, "picked_at", runtime.text),
+            .response_id = try runtime.maybe(allocator, fields, "response_id", runtime.text),
+            .succeeded_at = try runtime.maybe(allocator, fields, "succeeded_at", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: RequestAttempt,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "event", self.event);
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "request_attempt_id", self.request_attempt_id);
+        try runtime.put(&out, allocator, "retry_count", self.retry_count);
+        try runtime.put(&out, allocator, "status", self.status);
+        try runtime.put(&out, allocator, "subscription", self.subscription);
+        try runtime.put(&out, allocator, "dead_letter_id", self.dead_letter_id);
+        try runtime.put(&out, allocator, "delay_until", self.delay_until);
+        try runtime.put(&out, allocator, "delivery_batch_id", self.delivery_batch_id);
+        try runtime.put(&out, allocator, "failed_at", self.failed_at);
+        try runtime.put(&out, allocator, "http_response_status", self.http_response_status);
+        try runtime.put(&out, allocator, "picked_at", self.picked_at);
+        try runtime.put(&out, allocator, "response_id", self.response_id);
+        try runtime.put(&out, allocator, "succeeded_at", self.succeeded_at);
+        return .{ .object = out };
+    }
+};
+
+/// The `RequestAttemptEvent` the API declares.
+pub const RequestAttemptEvent = struct {
+    /// carries `event_id`.
+    event_id: []const u8,
+    /// carries `event_type_name`.
+    event_type_name: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!RequestAttemptEvent {
+        const fields = try runtime.asFields(value, "RequestAttemptEvent");
+        return .{
+            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
+            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: RequestAttemptEvent,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "event_id", self.event_id);
+        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
+        return .{ .object = out };
+    }
+};
+
+/// The `RequestAttemptStatus` the API declares.
+pub const RequestAttemptStatus = struct {
+    /// carries `type`, one of `models.RequestAttemptStatusType.values`: Status type discriminator.
+    /// One of: waiting, pending, in_progress, successful, failed, expired, cancelled
+    type: []const u8,
+    /// carries `at`: Timestamp when completed (present in successful, failed, expired, cancelled)
+    at: ?[]const u8,
+    /// carries `full_processing_ms`: Total processing time in milliseconds (present in successful,
+    /// failed)
+    full_processing_ms: ?i64,
+    /// carries `rule_outcome`, one of `models.RequestAttemptStatusRuleOutcome.values`: Outcome
+    /// given by a response rule of the subscription, when one matched the response (present in
+    /// successful, failed). One of: success, retryable, permanent_failure, disable_subscription
+    rule_outcome: ?[]const u8,
+    /// carries `since`: Timestamp when the status started (present in waiting, pending,
+    /// in_progress)
+    since: ?[]const u8,
+    /// carries `until`: Timestamp until which waiting (only present in waiting status)
+    until: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!RequestAttemptStatus {
+        const fields = try runtime.asFields(value, "RequestAttemptStatus");
+        return .{
+            .type = try runtime.read(
+                allocator,
+                fields,
+                "type",
+                runtime.memberOf(models.RequestAttemptStatusType).read,
+            ),
+            .at = try runtime.maybe(allocator, fields, "at", runtime.text),
+            .full_processing_ms = try runtime.maybe(
                 allocator,
                 fields,
-                "cloudflare_turnstile_site_key",
-                runtime.text,
+                "full_processing_ms",
+                runtime.integer64,
             ),
-            .formbricks = try runtime.maybe(
+            .rule_outcome = try runtime.maybe(
                 allocator,
                 fields,
-                "formbricks",
-                models.InstanceConfigFormbricks.fromJson,
+                "rule_outcome",
+                runtime.memberOf(models.RequestAttemptStatusRuleOutcome).read,
             ),
-            .matomo = try runtime.maybe(
+            .since = try runtime.maybe(allocator, fields, "since", runtime.text),
+            .until = try runtime.maybe(allocator, fields, "until", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: RequestAttemptStatus,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "type", self.type);
+        try runtime.put(&out, allocator, "at", self.at);
+        try runtime.put(&out, allocator, "full_processing_ms", self.full_processing_ms);
+        try runtime.put(&out, allocator, "rule_outcome", self.rule_outcome);
+        try runtime.put(&out, allocator, "since", self.since);
+        try runtime.put(&out, allocator, "until", self.until);
+        return .{ .object = out };
+    }
+};
+
+/// The `RequestAttemptSubscription` the API declares.
+pub const RequestAttemptSubscription = struct {
+    /// carries `subscription_id`.
+    subscription_id: []const u8,
+    /// carries `description`.
+    description: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!RequestAttemptSubscription {
+        const fields = try runtime.asFields(value, "RequestAttemptSubscription");
+        return .{
+            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
+            .description = try runtime.maybe(allocator, fields, "description", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: RequestAttemptSubscription,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
+        try runtime.put(&out, allocator, "description", self.description);
+        return .{ .object = out };
+    }
+};
+
+/// The `Response` the API declares.
+pub const Response = struct {
+    /// carries `response_id`.
+    response_id: []const u8,
+    /// carries `body`.
+    body: ?[]const u8,
+    /// carries `elapsed_time_ms`.
+    elapsed_time_ms: ?i32,
+    /// carries `headers`.
+    headers: ?runtime.Map([]const u8),
+    /// carries `http_code`.
+    http_code: ?i32,
+    /// carries `response_error_name`.
+    response_error_name: ?[]const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!Response {
+        const fields = try runtime.asFields(value, "Response");
+        return .{
+            .response_id = try runtime.read(allocator, fields, "response_id", runtime.text),
+            .body = try runtime.maybe(allocator, fields, "body", runtime.text),
+            .elapsed_time_ms = try runtime.maybe(
                 allocator,
                 fields,
-                "matomo",
-                models.InstanceConfigMatomo.fromJson,
+                "elapsed_time_ms",
+                runtime.integer32,
+            ),
+            .headers = try runtime.maybe(
+                allocator,
+                fields,
+                "headers",
+                runtime.map(runtime.text).read,
+            ),
+            .http_code = try runtime.maybe(allocator, fields, "http_code", runtime.integer32),
+            .response_error_name = try runtime.maybe(
+                allocator,
+                fields,
+                "response_error_name",
+                runtime.text,
             ),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: InstanceConfig,
+        self: Response,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "response_id", self.response_id);
+        try runtime.put(&out, allocator, "body", self.body);
+        try runtime.put(&out, allocator, "elapsed_time_ms", self.elapsed_time_ms);
+        try runtime.put(&out, allocator, "headers", self.headers);
+        try runtime.put(&out, allocator, "http_code", self.http_code);
+        try runtime.put(&out, allocator, "response_error_name", self.response_error_name);
+        return .{ .object = out };
+    }
+};
+
+/// The `RetryRequestAttempt` the API declares.
+pub const RetryRequestAttempt = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!RetryRequestAttempt {
+        const fields = try runtime.asFields(value, "RetryRequestAttempt");
+        return .{
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: RetryRequestAttempt,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        return .{ .object = out };
+    }
+};
+
+/// The `SecretRotation` the API declares.
+pub const SecretRotation = struct {
+    /// carries `previous_secret`: The secret the new one replaced
+    previous_secret: []const u8,
+    /// carries `previous_secret_expires_at`.
+    previous_secret_expires_at: []const u8,
+    /// carries `secret`: The new secret of the subscription
+    secret: []const u8,
+    /// carries `subscription_id`.
+    subscription_id: []const u8,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!SecretRotation {
+        const fields = try runtime.asFields(value, "SecretRotation");
+        return .{
+            .previous_secret = try runtime.read(allocator, fields, "previous_secret", runtime.text),
+            .previous_secret_expires_at = try runtime.read(
+                allocator,
+                fields,
+                "previous_secret_expires_at",
+                runtime.text,
+            ),
+            .secret = try runtime.read(allocator, fields, "secret", runtime.text),
+            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: SecretRotation,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "previous_secret", self.previous_secret);
         try runtime.put(
             &out,
             allocator,
-            "application_secret_compatibility",
-            self.application_secret_compatibility,
-        );
-        try runtime.put(&out, allocator, "auto_db_migration", self.auto_db_migration);
-        try runtime.put(&out, allocator, "biscuit_public_key", self.biscuit_public_key);
-        try runtime.put(&out, allocator, "password_minimum_length", self.password_minimum_length);
-        try runtime.put(&out, allocator, "quota_enforcement", self.quota_enforcement);
-        try runtime.put(&out, allocator, "registration_disabled", self.registration_disabled);
-        try runtime.put(&out, allocator, "support_email_address", self.support_email_address);
-        try runtime.put(
-            &out,
-            allocator,
-            "cloudflare_turnstile_site_key",
-            self.cloudflare_turnstile_site_key,
+            "previous_secret_expires_at",
+            self.previous_secret_expires_at,
         );
-        try runtime.put(&out, allocator, "formbricks", self.formbricks);
-        try runtime.put(&out, allocator, "matomo", self.matomo);
+        try runtime.put(&out, allocator, "secret", self.secret);
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
         return .{ .object = out };
     }
 };
 
-/// The `InstanceConfigFormbricks` the API declares.
-pub const InstanceConfigFormbricks = struct {
-    /// carries `api_host`.
-    api_host: []const u8,
-    /// carries `environment_id`.
-    environment_id: []const u8,
+/// The `SecretRotationPost` the API declares.
+pub const SecretRotationPost = struct {
+    /// carries `overlap_seconds`: How long the previous secret stays valid, in seconds (one day by
+    /// default)
+    overlap_seconds: ?i32,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!InstanceConfigFormbricks {
-        const fields = try runtime.asFields(value, "InstanceConfigFormbricks");
+    ) runtime.DecodeError!SecretRotationPost {
+        const fields = try runtime.asFields(value, "SecretRotationPost");
         return .{
-            .api_host = try runtime.read(allocator, fields, "api_host", runtime.text),
-            .environment_id = try runtime.read(allocator, fields, "environment_id", runtime.text),
+            .overlap_seconds = try runtime.maybe(
+                allocator,
+                fields,
+                "overlap_seconds",
+                runtime.integer32,
+            ),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: InstanceConfigFormbricks,
+        self: SecretRotationPost,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "api_host", self.api_host);
-        try runtime.put(&out, allocator, "environment_id", self.environment_id);
+        try runtime.put(&out, allocator, "overlap_seconds", self.overlap_seconds);
         return .{ .object = out };
-    }
-};
-
-/// The `InstanceConfigMatomo` the API declares.
-pub const InstanceConfigMatomo = struct {
-    /// carries `site_id`.
-    site_id: i32,
-    /// carries `url`.
-    url: []const u8,
+    }
+};
+
+/// The `ServiceToken` the API declares.
+pub const ServiceToken = struct {
+    /// carries `biscuit`.
+    biscuit: []const u8,
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `name`.
+    name: []const u8,
+    /// carries `token_id`.
+    token_id: []const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!InstanceConfigMatomo {
-        const fields = try runtime.asFields(value, "InstanceConfigMatomo");
+    ) runtime.DecodeError!ServiceToken {
+        const fields = try runtime.asFields(value, "ServiceToken");
         return .{
-            .site_id = try runtime.read(allocator, fields, "site_id", runtime.integer32),
-            .url = try runtime.read(allocator, fields, "url", runtime.text),
+            .biscuit = try runtime.read(allocator, fields, "biscuit", runtime.text),
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .name = try runtime.read(allocator, fields, "name", runtime.text),
+            .token_id = try runtime.read(allocator, fields, "token_id", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: InstanceConfigMatomo,
+        self: ServiceToken,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "site_id", self.site_id);
-        try runtime.put(&out, allocator, "url", self.url);
+        try runtime.put(&out, allocator, "biscuit", self.biscuit);
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "name", self.name);
+        try runtime.put(&out, allocator, "token_id", self.token_id);
         return .{ .object = out };
     }
 };
 
-/// The `Organization` the API declares.
-pub const Organization = struct {
+/// The `ServiceTokenPost` the API declares.
+pub const ServiceTokenPost = struct {
     /// carries `name`.
     name: []const u8,
     /// carries `organization_id`.
     organization_id: []const u8,
-    /// carries `role`.
-    role: []const u8,
-    /// carries `plan`.
-    plan: ?models.OrganizationPlan,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!Organization {
-        const fields = try runtime.asFields(value, "Organization");
+    ) runtime.DecodeError!ServiceTokenPost {
+        const fields = try runtime.asFields(value, "ServiceTokenPost");
         return .{
             .name = try runtime.read(allocator, fields, "name", runtime.text),
             .organization_id = try runtime.read(allocator, fields, "organization_id", runtime.text),
-            .role = try runtime.read(allocator, fields, "role", runtime.text),
-            .plan = try runtime.maybe(allocator, fields, "plan", models.OrganizationPlan.fromJson),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: Organization,
+        self: ServiceTokenPost,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
         try runtime.put(&out, allocator, "name", self.name);
         try runtime.put(&out, allocator, "organization_id", self.organization_id);
-        try runtime.put(&out, allocator, "role", self.role);
-        try runtime.put(&out, allocator, "plan", self.plan);
         return .{ .object = out };
     }
 };
 
-/// The `OrganizationInfo` the API declares.
-pub const OrganizationInfo = struct {
-    /// carries `consumption`.
-    consumption: models.OrganizationInfoConsumption,
-    /// carries `name`.
-    name: []const u8,
-    /// carries `onboarding_steps`.
-    onboarding_steps: models.OrganizationInfoOnboardingSteps,
-    /// carries `organization_id`.
-    organization_id: []const u8,
-    /// carries `quotas`.
-    quotas: models.OrganizationInfoQuotas,
-    /// carries `users`.
-    users: []const models.OrganizationInfoUsers,
-    /// carries `plan`.
-    plan: ?models.OrganizationInfoPlan,
+/// The `SigningKey` the API declares.
+pub const SigningKey = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `is_current`: Whether deliveries are signed with this key, which only the newest key
+    /// of the application is
+    is_current: bool,
+    /// carries `public_key`: Public key, encoded in base64url without padding like the `x` member
+    /// of its JSON Web Key
+    public_key: []const u8,
+    /// carries `signing_key_id`: Identifies the key in the `k` part of signatures and as the `kid`
+    /// of its JSON Web Key
+    signing_key_id: []const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!OrganizationInfo {
-        const fields = try runtime.asFields(value, "OrganizationInfo");
+    ) runtime.DecodeError!SigningKey {
+        const fields = try runtime.asFields(value, "SigningKey");
         return .{
-            .consumption = try runtime.read(
-                allocator,
-                fields,
-                "consumption",
-                models.OrganizationInfoConsumption.fromJson,
-            ),
-            .name = try runtime.read(allocator, fields, "name", runtime.text),
-            .onboarding_steps = try runtime.read(
-                allocator,
-                fields,
-                "onboarding_steps",
-                models.OrganizationInfoOnboardingSteps.fromJson,
-            ),
-            .organization_id = try runtime.read(allocator, fields, "organization_id", runtime.text),
-            .quotas = try runtime.read(
-                allocator,
-                fields,
-                "quotas",
-                models.OrganizationInfoQuotas.fromJson,
-            ),
-            .users = try runtime.read(
-                allocator,
-                fields,
-                "users",
-                runtime.list(models.OrganizationInfoUsers.fromJson).read,
-            ),
-            .plan = try runtime.maybe(
-                allocator,
-                fields,
-                "plan",
-                models.OrganizationInfoPlan.fromJson,
-            ),
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .is_current = try runtime.read(allocator, fields, "is_current", runtime.boolean),
+            .public_key = try runtime.read(allocator, fields, "public_key", runtime.text),
+            .signing_key_id = try runtime.read(allocator, fields, "signing_key_id", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: OrganizationInfo,
+        self: SigningKey,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "consumption", self.consumption);
-        try runtime.put(&out, allocator, "name", self.name);
-        try runtime.put(&out, allocator, "onboarding_steps", self.onboarding_steps);
-        try runtime.put(&out, allocator, "organization_id", self.organization_id);
-        try runtime.put(&out, allocator, "quotas", self.quotas);
-        try runtime.put(&out, allocator, "users", self.users);
-        try runtime.put(&out, allocator, "plan", self.plan);
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "is_current", self.is_current);
+        try runtime.put(&out, allocator, "public_key", self.public_key);
+        try runtime.put(&out, allocator, "signing_key_id", self.signing_key_id);
         return .{ .object = out };
     }
 };
 
-/// The `OrganizationInfoConsumption` the API declares.
-pub const OrganizationInfoConsumption = struct {
-    /// carries `applications`.
-    applications: ?i64,
-    /// carries `events_per_day`.
-    events_per_day: ?i64,
-    /// carries `members`.
-    members: ?i64,
+/// The `SigningKeyPost` the API declares.
+pub const SigningKeyPost = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!OrganizationInfoConsumption {
-        const fields = try runtime.asFields(value, "OrganizationInfoConsumption");
+    ) runtime.DecodeError!SigningKeyPost {
+        const fields = try runtime.asFields(value, "SigningKeyPost");
         return .{
-            .applications = try runtime.maybe(allocator, fields, "applications", runtime.integer64),
-            .events_per_day = try runtime.maybe(
-                allocator,
-                fields,
-                "events_per_day",
-                runtime.integer64,
-            ),
-            .members = try runtime.maybe(allocator, fields, "members", runtime.integer64),
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: OrganizationInfoConsumption,
+        self: SigningKeyPost,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "applications", self.applications);
-        try runtime.put(&out, allocator, "events_per_day", self.events_per_day);
-        try runtime.put(&out, allocator, "members", self.members);
+        try runtime.put(&out, allocator, "application_id", self.application_id);
         return .{ .object = out };
     }
 };
 
-/// The `OrganizationInfoOnboardingSteps` the API declares.
-pub const OrganizationInfoOnboardingSteps = struct {
-    /// carries `application`, one of `models.OrganizationInfoOnboardingStepsApplication.values`.
-    application: []const u8,
-    /// carries `event`, one of `models.OrganizationInfoOnboardingStepsEvent.values`.
-    event: []const u8,
-    /// carries `event_type`, one of `models.OrganizationInfoOnboardingStepsEventType.values`.
-    event_type: []const u8,
-    /// carries `subscription`, one of `models.OrganizationInfoOnboardingStepsSubscription.values`.
-    subscription: []const u8,
+/// The `Subscription` the API declares.
+pub const Subscription = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `created_at`.
+    created_at: []const u8,
+    /// carries `dedicated_workers`.
+    dedicated_workers: []const []const u8,
+    /// carries `event_type_versions`: Versions the subscription is pinned to, by event type
+    event_type_versions: runtime.Map(i32),
+    /// carries `event_types`.
+    event_types: []const []const u8,
+    /// carries `is_enabled`.
+    is_enabled: bool,
+    /// carries `label_key`: _Kept for backward compatibility, you should use `labels`_
+    label_key: []const u8,
+    /// carries `label_value`: _Kept for backward compatibility, you should use `labels`_
+    label_value: []const u8,
+    /// carries `labels`.
+    labels: runtime.Map([]const u8),
+    /// carries `metadata`.
+    metadata: runtime.Map([]const u8),
+    /// carries `secret`.
+    secret: []const u8,
+    /// carries `signature_scheme`, one of `models.SubscriptionSignatureScheme.values`.
+    signature_scheme: []const u8,
+    /// carries `subscription_id`.
+    subscription_id: []const u8,
+    /// carries `target`.
+    target: models.SubscriptionTarget,
+    /// carries `updated_at`.
+    updated_at: []const u8,
+    /// carries `auto_disabled_at`: When the circuit breaker or a response rule last disabled the
+    /// subscription; cleared when it is enabled again
+    auto_disabled_at: ?[]const u8,
+    /// carries `auto_disabled_reason`: Why the circuit breaker or a response rule last disabled the
+    /// subscription
+    auto_disabled_reason: ?[]const u8,
+    /// carries `batching`: Deliver the events of the subscription in batches: one HTTP request
+    /// whose body is a JSON array of the events, sent once it holds `max_events` events or once its
+    /// oldest event has waited `max_wait_s` se
+    batching: ?models.SubscriptionBatching,
+    /// carries `circuit_breaker`: Disables the subscription once its deliveries keep failing,
+    /// whichever threshold is crossed first; at least one threshold must be set
+    circuit_breaker: ?models.SubscriptionCircuitBreaker,
+    /// carries `delivery_limits`: Limits on how fast deliveries of the subscription start;
+    /// deliveries over them wait for their turn, which does not count as a failure; at least one
+    /// limit must be set
+    delivery_limits: ?models.SubscriptionDeliveryLimits,
+    /// carries `description`.
+    description: ?[]const u8,
+    /// carries `filter`: An object with exactly one of: `and` or `or` (a list of filters), `not` (a
+    /// filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON
+    /// payload); `label` and `field` go alon
+    filter: ?std.json.Value,
+    /// carries `ordering_key`: Where the key of an event comes from, to deliver the events that
+    /// share a key one at a time and in the order they were received; exactly one of `label` or
+    /// `field` must be set
+    ordering_key: ?models.SubscriptionOrderingKey,
+    /// carries `payload_transform`: Declarative reshaping of an `application/json` event payload,
+    /// applied by the worker before the request is signed and sent; operations are applied in order
+    /// (`fields`, `envelope`, `constants`) and a pay
+    payload_transform: ?models.SubscriptionPayloadTransform,
+    /// carries `response_rules`.
+    response_rules: ?[]const models.SubscriptionResponseRules,
+    /// carries `retry_policy`: Retry schedule of the subscription, replacing the one of the
+    /// instance within its limits; every field left out keeps the value of the instance
+    retry_policy: ?models.SubscriptionRetryPolicy,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!OrganizationInfoOnboardingSteps {
-        const fields = try runtime.asFields(value, "OrganizationInfoOnboardingSteps");
+    ) runtime.DecodeError!Subscription {
+        const fields = try runtime.asFields(value, "Subscription");
         return .{
-            .application = try runtime.read(
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
+            .dedicated_workers = try runtime.read(
                 allocator,
                 fields,
-                "application",
-                runtime.memberOf(models.OrganizationInfoOnboardingStepsApplication).read,
+                "dedicated_workers",
+                runtime.list(runtime.text).read,
             ),
-            .event = try runtime.read(
+            .event_type_versions = try runtime.read(
                 allocator,
                 fields,
-                "event",
-                runtime.memberOf(models.OrganizationInfoOnboardingStepsEvent).read,
+                "event_type_versions",
+                runtime.map(runtime.integer32).read,
             ),
-            .event_type = try runtime.read(
+            .event_types = try runtime.read(
                 allocator,
                 fields,
-                "event_type",
-                runtime.memberOf(models.OrganizationInfoOnboardingStepsEventType).read,
+                "event_types",
+                runtime.list(runtime.text).read,
             ),
-            .subscription = try runtime.read(
+            .is_enabled = try runtime.read(allocator, fields, "is_enabled", runtime.boolean),
+            .label_key = try runtime.read(allocator, fields, "label_key", runtime.text),
+            .label_value = try runtime.read(allocator, fields, "label_value", runtime.text),
+            .labels = try runtime.read(allocator, fields, "labels", runtime.map(runtime.text).read),
+            .metadata = try runtime.read(
                 allocator,
                 fields,
-                "subscription",
-                runtime.memberOf(models.OrganizationInfoOnboardingStepsSubscription).read,
+                "metadata",
+                runtime.map(runtime.text).read,
+            ),
+            .secret = try runtime.read(allocator, fields, "secret", runtime.text),
+            .signature_scheme = try runtime.read(
+                allocator,
+                fields,
+                "signature_scheme",
+                runtime.memberOf(models.SubscriptionSignatureScheme).read,
+            ),
+            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
+            .target = try runtime.read(
+                allocator,
+                fields,
+                "target",
+                models.SubscriptionTarget.fromJson,
+            ),
+            .updated_at = try runtime.read(allocator, fields, "updated_at", runtime.text),
+            .auto_disabled_at = try runtime.maybe(
+                allocator,
+                fields,
+                "auto_disabled_at",
+                runtime.text,
+            ),
+            .auto_disabled_reason = try runtime.maybe(
+                allocator,
+                fields,
+                "auto_disabled_reason",
+                runtime.text,
+            ),
+            .batching = try runtime.maybe(
+                allocator,
+                fields,
+                "batching",
+                models.SubscriptionBatching.fromJson,
+            ),
+            .circuit_breaker = try runtime.maybe(
+                allocator,
+                fields,
+                "circuit_breaker",
+                models.SubscriptionCircuitBreaker.fromJson,
+            ),
+            .delivery_limits = try runtime.maybe(
+                allocator,
+                fields,
+                "delivery_limits",
+                models.SubscriptionDeliveryLimits.fromJson,
+            ),
+            .description = try runtime.maybe(allocator, fields, "description", runtime.text),
+            .filter = try runtime.maybe(allocator, fields, "filter", runtime.jsonValue),
+            .ordering_key = try runtime.maybe(
+                allocator,
+                fields,
+                "ordering_key",
+                models.SubscriptionOrderingKey.fromJson,
+            ),
+            .payload_transform = try runtime.maybe(
+                allocator,
+                fields,
+                "payload_transform",
+                models.SubscriptionPayloadTransform.fromJson,
+            ),
+            .response_rules = try runtime.maybe(
+                allocator,
+                fields,
+                "response_rules",
+                runtime.list(models.SubscriptionResponseRules.fromJson).read,
+            ),
+            .retry_policy = try runtime.maybe(
+                allocator,
+                fields,
+                "retry_policy",
+                models.SubscriptionRetryPolicy.fromJson,
             ),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: OrganizationInfoOnboardingSteps,
+        self: Subscription,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "application", self.application);
-        try runtime.put(&out, allocator, "event", self.event);
-        try runtime.put(&out, allocator, "event_type", self.event_type);
-        try runtime.put(&out, allocator, "subscription", self.subscription);
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "created_at", self.created_at);
+        try runtime.put(&out, allocator, "dedicated_workers", self.dedicated_workers);
+        try runtime.put(&out, allocator, "event_type_versions", self.event_type_versions);
+        try runtime.put(&out, allocator, "event_types", self.event_types);
+        try runtime.put(&out, allocator, "is_enabled", self.is_enabled);
+        try runtime.put(&out, allocator, "label_key", self.label_key);
+        try runtime.put(&out, allocator, "label_value", self.label_value);
+        try runtime.put(&out, allocator, "labels", self.labels);
+        try runtime.put(&out, allocator, "metadata", self.metadata);
+        try runtime.put(&out, allocator, "secret", self.secret);
+        try runtime.put(&out, allocator, "signature_scheme", self.signature_scheme);
+        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
+        try runtime.put(&out, allocator, "target", self.target);
+        try runtime.put(&out, allocator, "updated_at", self.updated_at);
+        try runtime.put(&out, allocator, "auto_disabled_at", self.auto_disabled_at);
+        try runtime.put(&out, allocator, "auto_disabled_reason", self.auto_disabled_reason);
+        try runtime.put(&out, allocator, "batching", self.batching);
+        try runtime.put(&out, allocator, "circuit_breaker", self.circuit_breaker);
+        try runtime.put(&out, allocator, "delivery_limits", self.delivery_limits);
+        try runtime.put(&out, allocator, "description", self.description);
+        try runtime.put(&out, allocator, "filter", self.filter);
+        try runtime.put(&out, allocator, "ordering_key", self.ordering_key);
+        try runtime.put(&out, allocator, "payload_transform", self.payload_transform);
+        try runtime.put(&out, allocator, "response_rules", self.response_rules);
+        try runtime.put(&out, allocator, "retry_policy", self.retry_policy);
         return .{ .object = out };
     }
 };
 
-/// The `OrganizationInfoPlan` the API declares.
-pub const OrganizationInfoPlan = struct {
-    /// carries `label`.
-    label: []const u8,
-    /// carries `name`.
-    name: []const u8,
+/// The `SubscriptionBatching` the API declares.
+pub const SubscriptionBatching = struct {
+    /// carries `max_events`: Largest number of events in a batch
+    max_events: i32,
+    /// carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill up
+    max_wait_s: i32,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!OrganizationInfoPlan {
-        const fields = try runtime.asFields(value, "OrganizationInfoPlan");
+    ) runtime.DecodeError!SubscriptionBatching {
+        const fields = try runtime.asFields(value, "SubscriptionBatching");
         return .{
-            .label = try runtime.read(allocator, fields, "label", runtime.text),
-            .name = try runtime.read(allocator, fields, "name", runtime.text),
+            .max_events = try runtime.read(allocator, fields, "max_events", runtime.integer32),
+            .max_wait_s = try runtime.read(allocator, fields, "max_wait_s", runtime.integer32),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: OrganizationInfoPlan,
+        self: SubscriptionBatching,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "label", self.label);
-        try runtime.put(&out, allocator, "name", self.name);
+        try runtime.put(&out, allocator, "max_events", self.max_events);
+        try runtime.put(&out, allocator, "max_wait_s", self.max_wait_s);
         return .{ .object = out };
     }
 };
 
-/// The `OrganizationInfoQuotas` the API declares.
-pub const OrganizationInfoQuotas = struct {
-    /// carries `applications_per_organization_limit`.
-    applications_per_organization_limit: i32,
-    /// carries `days_of_events_retention_limit`.
-    days_of_events_retention_limit: i32,
-    /// carries `events_per_day_limit`.
-    events_per_day_limit: i32,
-    /// carries `members_per_organization_limit`.
-    members_per_organization_limit: i32,
+/// The `SubscriptionCircuitBreaker` the API declares.
+pub const SubscriptionCircuitBreaker = struct {
+    /// carries `max_consecutive_failures`: Number of failed deliveries in a row after which the
+    /// subscription is disabled
+    max_consecutive_failures: ?i32,
+    /// carries `max_failing_hours`: Number of hours without a single successful delivery after
+    /// which the subscription is disabled
+    max_failing_hours: ?i32,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!OrganizationInfoQuotas {
-        const fields = try runtime.asFields(value, "OrganizationInfoQuotas");
+    ) runtime.DecodeError!SubscriptionCircuitBreaker {
+        const fields = try runtime.asFields(value, "SubscriptionCircuitBreaker");
         return .{
-            .applications_per_organization_limit = try runtime.read(
-                allocator,
-                fields,
-                "applications_per_organization_limit",
-                runtime.integer32,
-            ),
-            .days_of_events_retention_limit = try runtime.read(
-                allocator,
-                fields,
-                "days_of_events_retention_limit",
-                runtime.integer32,
-            ),
-            .events_per_day_limit = try runtime.read(
+            .max_consecutive_failures = try runtime.maybe(
                 allocator,
                 fields,
-                "events_per_day_limit",
+                "max_consecutive_failures",
                 runtime.integer32,
             ),
-            .members_per_organization_limit = try runtime.read(
+            .max_failing_hours = try runtime.maybe(
                 allocator,
                 fields,
-                "members_per_organization_limit",
+                "max_failing_hours",
                 runtime.integer32,
             ),
         };
@@ -1399,922 +4991,972 @@ pub const OrganizationInfoQuotas = struct {
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: OrganizationInfoQuotas,
+        self: SubscriptionCircuitBreaker,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(
-            &out,
-            allocator,
-            "applications_per_organization_limit",
-            self.applications_per_organization_limit,
-        );
-        try runtime.put(
-            &out,
-            allocator,
-            "days_of_events_retention_limit",
-            self.days_of_events_retention_limit,
-        );
-        try runtime.put(&out, allocator, "events_per_day_limit", self.events_per_day_limit);
-        try runtime.put(
-            &out,
-            allocator,
-            "members_per_organization_limit",
-            self.members_per_organization_limit,
-        );
+        try runtime.put(&out, allocator, "max_consecutive_failures", self.max_consecutive_failures);
+        try runtime.put(&out, allocator, "max_failing_hours", self.max_failing_hours);
         return .{ .object = out };
     }
 };
 
-/// The `OrganizationInfoUsers` the API declares.
-pub const OrganizationInfoUsers = struct {
-    /// carries `email`.
-    email: []const u8,
-    /// carries `first_name`.
-    first_name: []const u8,
-    /// carries `last_name`.
-    last_name: []const u8,
-    /// carries `role`.
-    role: []const u8,
-    /// carries `user_id`.
-    user_id: []const u8,
+/// The `SubscriptionDeliveryLimits` the API declares.
+pub const SubscriptionDeliveryLimits = struct {
+    /// carries `max_in_flight`: Number of deliveries that can be in flight at once
+    max_in_flight: ?i32,
+    /// carries `max_per_second`: Number of deliveries that can start in a second
+    max_per_second: ?i32,
+    /// carries `per_host`: Share the limits with every subscription of the application that
+    /// delivers to the same host and sets this too, rather than counting the deliveries of this
+    /// subscription alone
+    per_host: ?bool,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!OrganizationInfoUsers {
-        const fields = try runtime.asFields(value, "OrganizationInfoUsers");
+    ) runtime.DecodeError!SubscriptionDeliveryLimits {
+        const fields = try runtime.asFields(value, "SubscriptionDeliveryLimits");
         return .{
-            .email = try runtime.read(allocator, fields, "email", runtime.text),
-            .first_name = try runtime.read(allocator, fields, "first_name", runtime.text),
-            .last_name = try runtime.read(allocator, fields, "last_name", runtime.text),
-            .role = try runtime.read(allocator, fields, "role", runtime.text),
-            .user_id = try runtime.read(allocator, fields, "user_id", runtime.text),
+            .max_in_flight = try runtime.maybe(
+                allocator,
+                fields,
+                "max_in_flight",
+                runtime.integer32,
+            ),
+            .max_per_second = try runtime.maybe(
+                allocator,
+                fields,
+                "max_per_second",
+                runtime.integer32,
+            ),
+            .per_host = try runtime.maybe(allocator, fields, "per_host", runtime.boolean),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: OrganizationInfoUsers,
+        self: SubscriptionDeliveryLimits,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "email", self.email);
-        try runtime.put(&out, allocator, "first_name", self.first_name);
-        try runtime.put(&out, allocator, "last_name", self.last_name);
-        try runtime.put(&out, allocator, "role", self.role);
-        try runtime.put(&out, allocator, "user_id", self.user_id);
+        try runtime.put(&out, allocator, "max_in_flight", self.max_in_flight);
+        try runtime.put(&out, allocator, "max_per_second", self.max_per_second);
+        try runtime.put(&out, allocator, "per_host", self.per_host);
         return .{ .object = out };
     }
 };
 
-/// The `OrganizationPlan` the API declares.
-pub const OrganizationPlan = struct {
-    /// carries `label`.
-    label: []const u8,
-    /// carries `name`.
-    name: []const u8,
+/// The `SubscriptionOrderingKey` the API declares.
+pub const SubscriptionOrderingKey = struct {
+    /// carries `field`: JSON pointer to the value holding the key in a JSON payload, for example
+    /// `/customer/id`
+    field: ?[]const u8,
+    /// carries `label`: Name of the event label holding the key
+    label: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!OrganizationPlan {
-        const fields = try runtime.asFields(value, "OrganizationPlan");
+    ) runtime.DecodeError!SubscriptionOrderingKey {
+        const fields = try runtime.asFields(value, "SubscriptionOrderingKey");
         return .{
-            .label = try runtime.read(allocator, fields, "label", runtime.text),
-            .name = try runtime.read(allocator, fields, "name", runtime.text),
+            .field = try runtime.maybe(allocator, fields, "field", runtime.text),
+            .label = try runtime.maybe(allocator, fields, "label", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: OrganizationPlan,
+        self: SubscriptionOrderingKey,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "field", self.field);
         try runtime.put(&out, allocator, "label", self.label);
-        try runtime.put(&out, allocator, "name", self.name);
         return .{ .object = out };
     }
 };
 
-/// The `Problem` the API declares.
-pub const Problem = struct {
-    /// carries `detail`.
-    detail: []const u8,
-    /// carries `id`, one of `models.ProblemId.values`: Identifier of the problem that occurred,
-    /// stable across releases
-    id: []const u8,
-    /// carries `status`.
-    status: i32,
-    /// carries `title`.
-    title: []const u8,
-    /// carries `type`: Documentation page of this problem, which is also what distinguishes one
-    /// problem type from another as RFC 7807 asks. Prefer matching on `id`: it says the same thing
-    /// without parsing a URL.
-    type: []const u8,
-    /// carries `validation`: Which submitted values were rejected and why, keyed by the name of the
-    /// field they were submitted under. Carried by validation failures (`Validation`, status 422)
-    /// and `null` for every other problem, as
-    validation: ?std.json.Value,
+/// The `SubscriptionPayloadTransform` the API declares.
+pub const SubscriptionPayloadTransform = struct {
+    /// carries `constants`: Properties added at the top level of the result, overriding properties
+    /// of the same name (the result must then be an object)
+    constants: ?runtime.Map(std.json.Value),
+    /// carries `envelope`: Name of a property the result is wrapped in
+    envelope: ?[]const u8,
+    /// carries `fields`: Values to copy from the payload into a new object; when omitted, the whole
+    /// payload is kept
+    fields_: ?[]const models.SubscriptionPayloadTransformFields,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!Problem {
-        const fields = try runtime.asFields(value, "Problem");
+    ) runtime.DecodeError!SubscriptionPayloadTransform {
+        const fields = try runtime.asFields(value, "SubscriptionPayloadTransform");
         return .{
-            .detail = try runtime.read(allocator, fields, "detail", runtime.text),
-            .id = try runtime.read(
+            .constants = try runtime.maybe(
                 allocator,
                 fields,
-                "id",
-                runtime.memberOf(models.ProblemId).read,
+                "constants",
+                runtime.map(runtime.jsonValue).read,
+            ),
+            .envelope = try runtime.maybe(allocator, fields, "envelope", runtime.text),
+            .fields_ = try runtime.maybe(
+                allocator,
+                fields,
+                "fields",
+                runtime.list(models.SubscriptionPayloadTransformFields.fromJson).read,
             ),
-            .status = try runtime.read(allocator, fields, "status", runtime.integer32),
-            .title = try runtime.read(allocator, fields, "title", runtime.text),
-            .type = try runtime.read(allocator, fields, "type", runtime.text),
-            .validation = try runtime.maybe(allocator, fields, "validation", runtime.jsonValue),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: Problem,
+        self: SubscriptionPayloadTransform,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "detail", self.detail);
-        try runtime.put(&out, allocator, "id", self.id);
-        try runtime.put(&out, allocator, "status", self.status);
-        try runtime.put(&out, allocator, "title", self.title);
-        try runtime.put(&out, allocator, "type", self.type);
-        try runtime.put(&out, allocator, "validation", self.validation);
+        try runtime.put(&out, allocator, "constants", self.constants);
+        try runtime.put(&out, allocator, "envelope", self.envelope);
+        try runtime.put(&out, allocator, "fields", self.fields_);
         return .{ .object = out };
     }
 };
 
-/// The `QuotasResponse` the API declares.
-pub const QuotasResponse = struct {
-    /// carries `enabled`.
-    enabled: bool,
-    /// carries `limits`.
-    limits: models.QuotasResponseLimits,
+/// The `SubscriptionPayloadTransformFields` the API declares.
+pub const SubscriptionPayloadTransformFields = struct {
+    /// carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that
+    /// are absent from a payload are skipped)
+    from: []const u8,
+    /// carries `to`: JSON pointer where the value is written in the result, for example `/id`;
+    /// defaults to `from`
+    to: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!QuotasResponse {
-        const fields = try runtime.asFields(value, "QuotasResponse");
+    ) runtime.DecodeError!SubscriptionPayloadTransformFields {
+        const fields = try runtime.asFields(value, "SubscriptionPayloadTransformFields");
         return .{
-            .enabled = try runtime.read(allocator, fields, "enabled", runtime.boolean),
-            .limits = try runtime.read(
-                allocator,
-                fields,
-                "limits",
-                models.QuotasResponseLimits.fromJson,
-            ),
+            .from = try runtime.read(allocator, fields, "from", runtime.text),
+            .to = try runtime.maybe(allocator, fields, "to", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: QuotasResponse,
+        self: SubscriptionPayloadTransformFields,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "enabled", self.enabled);
-        try runtime.put(&out, allocator, "limits", self.limits);
+        try runtime.put(&out, allocator, "from", self.from);
+        try runtime.put(&out, allocator, "to", self.to);
         return .{ .object = out };
     }
 };
 
-/// The `QuotasResponseLimits` the API declares.
-pub const QuotasResponseLimits = struct {
-    /// carries `global_applications_per_organization_limit`.
-    global_applications_per_organization_limit: i32,
-    /// carries `global_days_of_events_retention_limit`.
-    global_days_of_events_retention_limit: i32,
-    /// carries `global_event_types_per_application_limit`.
-    global_event_types_per_application_limit: i32,
-    /// carries `global_events_per_day_limit`.
-    global_events_per_day_limit: i32,
-    /// carries `global_members_per_organization_limit`.
-    global_members_per_organization_limit: i32,
-    /// carries `global_subscriptions_per_application_limit`.
-    global_subscriptions_per_application_limit: i32,
+/// The `SubscriptionPost` the API declares.
+pub const SubscriptionPost = struct {
+    /// carries `application_id`.
+    application_id: []const u8,
+    /// carries `event_types`.
+    event_types: []const []const u8,
+    /// carries `is_enabled`.
+    is_enabled: bool,
+    /// carries `target`.
+    target: models.SubscriptionPostTarget,
+    /// carries `batching`: Deliver the events in batches, as one HTTP request with a JSON array
+    /// body, rather than one by one
+    batching: ?models.SubscriptionPostBatching,
+    /// carries `circuit_breaker`: Disable the subscription once its deliveries keep failing;
+    /// organization editors are notified by email
+    circuit_breaker: ?models.SubscriptionPostCircuitBreaker,
+    /// carries `dedicated_workers`.
+    dedicated_workers: ?[]const []const u8,
+    /// carries `delivery_limits`: Limit how fast deliveries start, for this subscription or for its
+    /// host
+    delivery_limits: ?models.SubscriptionPostDeliveryLimits,
+    /// carries `description`.
+    description: ?[]const u8,
+    /// carries `event_type_versions`: Only receive the events of these event types that declare
+    /// this version (or no version at all)
+    event_type_versions: ?runtime.Map(i32),
+    /// carries `filter`: Only deliver the events of the subscribed types that match this expression
+    /// over labels and JSON payload fields
+    filter: ?std.json.Value,
+    /// carries `label_key`: _Kept for backward compatibility, you should use `labels`_
+    label_key: ?[]const u8,
+    /// carries `label_value`: _Kept for backward compatibility, you should use `labels`_
+    label_value: ?[]const u8,
+    /// carries `labels`.
+    labels: ?runtime.Map([]const u8),
+    /// carries `metadata`.
+    metadata: ?runtime.Map([]const u8),
+    /// carries `ordering_key`: Deliver the events that share a key one at a time, in the order they
+    /// were received: the next one waits until the previous one succeeded or ran out of retries
+    ordering_key: ?models.SubscriptionPostOrderingKey,
+    /// carries `payload_transform`: Declarative reshaping of an `application/json` event payload,
+    /// applied by the worker before the request is signed and sent; operations are applied in order
+    /// (`fields`, `envelope`, `constants`) and a pay
+    payload_transform: ?models.SubscriptionPostPayloadTransform,
+    /// carries `response_rules`: Decide the outcome of HTTP deliveries from their response, for
+    /// example to stop retrying on a `410` or to fail on a `200` whose body reports an error; the
+    /// first rule that matches wins, and a response n
+    response_rules: ?[]const models.SubscriptionPostResponseRules,
+    /// carries `retry_policy`: Retry failed deliveries on this schedule rather than the instance's
+    /// one
+    retry_policy: ?models.SubscriptionPostRetryPolicy,
+    /// carries `signature_scheme`, one of `models.SubscriptionPostSignatureScheme.values`: How
+    /// deliveries are signed (`hook0` by default); `standard_webhooks` follows the Standard
+    /// Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of
+    /// the subscription s
+    signature_scheme: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!QuotasResponseLimits {
-        const fields = try runtime.asFields(value, "QuotasResponseLimits");
+    ) runtime.DecodeError!SubscriptionPost {
+        const fields = try runtime.asFields(value, "SubscriptionPost");
         return .{
-            .global_applications_per_organization_limit = try runtime.read(
+            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .event_types = try runtime.read(
+                allocator,
+                fields,
+                "event_types",
+                runtime.list(runtime.text).read,
+            ),
+            .is_enabled = try runtime.read(allocator, fields, "is_enabled", runtime.boolean),
+            .target = try runtime.read(
+                allocator,
+                fields,
+                "target",
+                models.SubscriptionPostTarget.fromJson,
+            ),
+            .batching = try runtime.maybe(
+                allocator,
+                fields,
+                "batching",
+                models.SubscriptionPostBatching.fromJson,
+            ),
+            .circuit_breaker = try runtime.maybe(
+                allocator,
+                fields,
+                "circuit_breaker",
+                models.SubscriptionPostCircuitBreaker.fromJson,
+            ),
+            .dedicated_workers = try runtime.maybe(
+                allocator,
+                fields,
+                "dedicated_workers",
+                runtime.list(runtime.text).read,
+            ),
+            .delivery_limits = try runtime.maybe(
+                allocator,
+                fields,
+                "delivery_limits",
+                models.SubscriptionPostDeliveryLimits.fromJson,
+            ),
+            .description = try runtime.maybe(allocator, fields, "description", runtime.text),
+            .event_type_versions = try runtime.maybe(
+                allocator,
+                fields,
+                "event_type_versions",
+                runtime.map(runtime.integer32).read,
+            ),
+            .filter = try runtime.maybe(allocator, fields, "filter", runtime.jsonValue),
+            .label_key = try runtime.maybe(allocator, fields, "label_key", runtime.text),
+            .label_value = try runtime.maybe(allocator, fields, "label_value", runtime.text),
+            .labels = try runtime.maybe(
                 allocator,
                 fields,
-                "global_applications_per_organization_limit",
-                runtime.integer32,
+                "labels",
+                runtime.map(runtime.text).read,
             ),
-            .global_days_of_events_retention_limit = try runtime.read(
+            .metadata = try runtime.maybe(
                 allocator,
                 fields,
-                "global_days_of_events_retention_limit",
-                runtime.integer32,
+                "metadata",
+                runtime.map(runtime.text).read,
             ),
-            .global_event_types_per_application_limit = try runtime.read(
+            .ordering_key = try runtime.maybe(
                 allocator,
                 fields,
-                "global_event_types_per_application_limit",
-                runtime.integer32,
+                "ordering_key",
+                models.SubscriptionPostOrderingKey.fromJson,
             ),
-            .global_events_per_day_limit = try runtime.read(
+            .payload_transform = try runtime.maybe(
                 allocator,
                 fields,
-                "global_events_per_day_limit",
-                runtime.integer32,
+                "payload_transform",
+                models.SubscriptionPostPayloadTransform.fromJson,
             ),
-            .global_members_per_organization_limit = try runtime.read(
+            .response_rules = try runtime.maybe(
                 allocator,
                 fields,
-                "global_members_per_organization_limit",
-                runtime.integer32,
+                "response_rules",
+                runtime.list(models.SubscriptionPostResponseRules.fromJson).read,
             ),
-            .global_subscriptions_per_application_limit = try runtime.read(
+            .retry_policy = try runtime.maybe(
                 allocator,
                 fields,
-                "global_subscriptions_per_application_limit",
-                runtime.integer32,
+                "retry_policy",
+                models.SubscriptionPostRetryPolicy.fromJson,
+            ),
+            .signature_scheme = try runtime.maybe(
+                allocator,
+                fields,
+                "signature_scheme",
+                runtime.memberOf(models.SubscriptionPostSignatureScheme).read,
             ),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: QuotasResponseLimits,
+        self: SubscriptionPost,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(
-            &out,
-            allocator,
-            "global_applications_per_organization_limit",
-            self.global_applications_per_organization_limit,
-        );
-        try runtime.put(
-            &out,
-            allocator,
-            "global_days_of_events_retention_limit",
-            self.global_days_of_events_retention_limit,
-        );
-        try runtime.put(
-            &out,
-            allocator,
-            "global_event_types_per_application_limit",
-            self.global_event_types_per_application_limit,
-        );
-        try runtime.put(
-            &out,
-            allocator,
-            "global_events_per_day_limit",
-            self.global_events_per_day_limit,
-        );
-        try runtime.put(
-            &out,
-            allocator,
-            "global_members_per_organization_limit",
-            self.global_members_per_organization_limit,
-        );
-        try runtime.put(
-            &out,
-            allocator,
-            "global_subscriptions_per_application_limit",
-            self.global_subscriptions_per_application_limit,
-        );
+        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "event_types", self.event_types);
+        try runtime.put(&out, allocator, "is_enabled", self.is_enabled);
+        try runtime.put(&out, allocator, "target", self.target);
+        try runtime.put(&out, allocator, "batching", self.batching);
+        try runtime.put(&out, allocator, "circuit_breaker", self.circuit_breaker);
+        try runtime.put(&out, allocator, "dedicated_workers", self.dedicated_workers);
+        try runtime.put(&out, allocator, "delivery_limits", self.delivery_limits);
+        try runtime.put(&out, allocator, "description", self.description);
+        try runtime.put(&out, allocator, "event_type_versions", self.event_type_versions);
+        try runtime.put(&out, allocator, "filter", self.filter);
+        try runtime.put(&out, allocator, "label_key", self.label_key);
+        try runtime.put(&out, allocator, "label_value", self.label_value);
+        try runtime.put(&out, allocator, "labels", self.labels);
+        try runtime.put(&out, allocator, "metadata", self.metadata);
+        try runtime.put(&out, allocator, "ordering_key", self.ordering_key);
+        try runtime.put(&out, allocator, "payload_transform", self.payload_transform);
+        try runtime.put(&out, allocator, "response_rules", self.response_rules);
+        try runtime.put(&out, allocator, "retry_policy", self.retry_policy);
+        try runtime.put(&out, allocator, "signature_scheme", self.signature_scheme);
         return .{ .object = out };
     }
 };
 
-/// The `ReplayEvent` the API declares.
-pub const ReplayEvent = struct {
-    /// carries `application_id`.
-    application_id: []const u8,
+/// The `SubscriptionPostBatching` the API declares.
+pub const SubscriptionPostBatching = struct {
+    /// carries `max_events`: Largest number of events in a batch
+    max_events: i32,
+    /// carries `max_wait_s`: Longest time in seconds an event waits for its batch to fill up
+    max_wait_s: i32,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!ReplayEvent {
-        const fields = try runtime.asFields(value, "ReplayEvent");
+    ) runtime.DecodeError!SubscriptionPostBatching {
+        const fields = try runtime.asFields(value, "SubscriptionPostBatching");
         return .{
-            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
+            .max_events = try runtime.read(allocator, fields, "max_events", runtime.integer32),
+            .max_wait_s = try runtime.read(allocator, fields, "max_wait_s", runtime.integer32),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: ReplayEvent,
+        self: SubscriptionPostBatching,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "application_id", self.application_id);
+        try runtime.put(&out, allocator, "max_events", self.max_events);
+        try runtime.put(&out, allocator, "max_wait_s", self.max_wait_s);
         return .{ .object = out };
     }
 };
 
-/// The `RequestAttempt` the API declares.
-pub const RequestAttempt = struct {
-    /// carries `created_at`.
-    created_at: []const u8,
-    /// carries `event`.
-    event: models.RequestAttemptEvent,
-    /// carries `event_id`.
-    event_id: []const u8,
-    /// carries `request_attempt_id`.
-    request_attempt_id: []const u8,
-    /// carries `retry_count`.
-    retry_count: i32,
-    /// carries `status`: Status of a request attempt. The 'type' field indicates the status
-    /// variant. - waiting: {type, since, until} - Scheduled for future delivery - pending: {type,
-    /// since} - Ready to be processed - in_progre
-    status: models.RequestAttemptStatus,
-    /// carries `subscription`.
-    subscription: models.RequestAttemptSubscription,
-    /// carries `delay_until`.
-    delay_until: ?[]const u8,
-    /// carries `failed_at`.
-    failed_at: ?[]const u8,
-    /// carries `http_response_status`.
-    http_response_status: ?i32,
-    /// carries `picked_at`.
-    picked_at: ?[]const u8,
-    /// carries `response_id`.
-    response_id: ?[]const u8,
-    /// carries `succeeded_at`.
-    succeeded_at: ?[]const u8,
+/// The `SubscriptionPostCircuitBreaker` the API declares.
+pub const SubscriptionPostCircuitBreaker = struct {
+    /// carries `max_consecutive_failures`: Number of failed deliveries in a row after which the
+    /// subscription is disabled
+    max_consecutive_failures: ?i32,
+    /// carries `max_failing_hours`: Number of hours without a single successful delivery after
+    /// which the subscription is disabled
+    max_failing_hours: ?i32,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!RequestAttempt {
-        const fields = try runtime.asFields(value, "RequestAttempt");
+    ) runtime.DecodeError!SubscriptionPostCircuitBreaker {
+        const fields = try runtime.asFields(value, "SubscriptionPostCircuitBreaker");
         return .{
-            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
-            .event = try runtime.read(
-                allocator,
-                fields,
-                "event",
-                models.RequestAttemptEvent.fromJson,
-            ),
-            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
-            .request_attempt_id = try runtime.read(
+            .max_consecutive_failures = try runtime.maybe(
                 allocator,
                 fields,
-                "request_attempt_id",
-                runtime.text,
+                "max_consecutive_failures",
+                runtime.integer32,
             ),
-            .retry_count = try runtime.read(allocator, fields, "retry_count", runtime.integer32),
-            .status = try runtime.read(
+            .max_failing_hours = try runtime.maybe(
                 allocator,
                 fields,
-                "status",
-                models.RequestAttemptStatus.fromJson,
+                "max_failing_hours",
+                runtime.integer32,
             ),
-            .subscription = try runtime.read(
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: SubscriptionPostCircuitBreaker,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "max_consecutive_failures", self.max_consecutive_failures);
+        try runtime.put(&out, allocator, "max_failing_hours", self.max_failing_hours);
+        return .{ .object = out };
+    }
+};
+
+/// The `SubscriptionPostDeliveryLimits` the API declares.
+pub const SubscriptionPostDeliveryLimits = struct {
+    /// carries `max_in_flight`: Number of deliveries that can be in flight at once
+    max_in_flight: ?i32,
+    /// carries `max_per_second`: Number of deliveries that can start in a second
+    max_per_second: ?i32,
+    /// carries `per_host`: Share the limits with every subscription of the application that
+    /// delivers to the same host and sets this too, rather than counting the deliveries of this
+    /// subscription alone
+    per_host: ?bool,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!SubscriptionPostDeliveryLimits {
+        const fields = try runtime.asFields(value, "SubscriptionPostDeliveryLimits");
+        return .{
+            .max_in_flight = try runtime.maybe(
                 allocator,
                 fields,
-                "subscription",
-                models.RequestAttemptSubscription.fromJson,
+                "max_in_flight",
+                runtime.integer32,
             ),
-            .delay_until = try runtime.maybe(allocator, fields, "delay_until", runtime.text),
-            .failed_at = try runtime.maybe(allocator, fields, "failed_at", runtime.text),
-            .http_response_status = try runtime.maybe(
+            .max_per_second = try runtime.maybe(
                 allocator,
                 fields,
-                "http_response_status",
+                "max_per_second",
                 runtime.integer32,
             ),
-            .picked_at = try runtime.maybe(allocator, fields, "picked_at", runtime.text),
-            .response_id = try runtime.maybe(allocator, fields, "response_id", runtime.text),
-            .succeeded_at = try runtime.maybe(allocator, fields, "succeeded_at", runtime.text),
+            .per_host = try runtime.maybe(allocator, fields, "per_host", runtime.boolean),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: RequestAttempt,
+        self: SubscriptionPostDeliveryLimits,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "created_at", self.created_at);
-        try runtime.put(&out, allocator, "event", self.event);
-        try runtime.put(&out, allocator, "event_id", self.event_id);
-        try runtime.put(&out, allocator, "request_attempt_id", self.request_attempt_id);
-        try runtime.put(&out, allocator, "retry_count", self.retry_count);
-        try runtime.put(&out, allocator, "status", self.status);
-        try runtime.put(&out, allocator, "subscription", self.subscription);
-        try runtime.put(&out, allocator, "delay_until", self.delay_until);
-        try runtime.put(&out, allocator, "failed_at", self.failed_at);
-        try runtime.put(&out, allocator, "http_response_status", self.http_response_status);
-        try runtime.put(&out, allocator, "picked_at", self.picked_at);
-        try runtime.put(&out, allocator, "response_id", self.response_id);
-        try runtime.put(&out, allocator, "succeeded_at", self.succeeded_at);
+        try runtime.put(&out, allocator, "max_in_flight", self.max_in_flight);
+        try runtime.put(&out, allocator, "max_per_second", self.max_per_second);
+        try runtime.put(&out, allocator, "per_host", self.per_host);
         return .{ .object = out };
     }
 };
 
-/// The `RequestAttemptEvent` the API declares.
-pub const RequestAttemptEvent = struct {
-    /// carries `event_id`.
-    event_id: []const u8,
-    /// carries `event_type_name`.
-    event_type_name: []const u8,
+/// The `SubscriptionPostOrderingKey` the API declares.
+pub const SubscriptionPostOrderingKey = struct {
+    /// carries `field`: JSON pointer to the value holding the key in a JSON payload, for example
+    /// `/customer/id`
+    field: ?[]const u8,
+    /// carries `label`: Name of the event label holding the key
+    label: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!RequestAttemptEvent {
-        const fields = try runtime.asFields(value, "RequestAttemptEvent");
+    ) runtime.DecodeError!SubscriptionPostOrderingKey {
+        const fields = try runtime.asFields(value, "SubscriptionPostOrderingKey");
         return .{
-            .event_id = try runtime.read(allocator, fields, "event_id", runtime.text),
-            .event_type_name = try runtime.read(allocator, fields, "event_type_name", runtime.text),
+            .field = try runtime.maybe(allocator, fields, "field", runtime.text),
+            .label = try runtime.maybe(allocator, fields, "label", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: RequestAttemptEvent,
+        self: SubscriptionPostOrderingKey,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "event_id", self.event_id);
-        try runtime.put(&out, allocator, "event_type_name", self.event_type_name);
+        try runtime.put(&out, allocator, "field", self.field);
+        try runtime.put(&out, allocator, "label", self.label);
         return .{ .object = out };
     }
 };
 
-/// The `RequestAttemptStatus` the API declares.
-pub const RequestAttemptStatus = struct {
-    /// carries `type`, one of `models.RequestAttemptStatusType.values`: Status type discriminator.
-    /// One of: waiting, pending, in_progress, successful, failed
-    type: []const u8,
-    /// carries `at`: Timestamp when completed (present in successful, failed)
-    at: ?[]const u8,
-    /// carries `full_processing_ms`: Total processing time in milliseconds (present in successful,
-    /// failed)
-    full_processing_ms: ?i64,
-    /// carries `since`: Timestamp when the status started (present in waiting, pending,
-    /// in_progress)
-    since: ?[]const u8,
-    /// carries `until`: Timestamp until which waiting (only present in waiting status)
-    until: ?[]const u8,
+/// The `SubscriptionPostPayloadTransform` the API declares.
+pub const SubscriptionPostPayloadTransform = struct {
+    /// carries `constants`: Properties added at the top level of the result, overriding properties
+    /// of the same name (the result must then be an object)
+    constants: ?runtime.Map(std.json.Value),
+    /// carries `envelope`: Name of a property the result is wrapped in
+    envelope: ?[]const u8,
+    /// carries `fields`: Values to copy from the payload into a new object; when omitted, the whole
+    /// payload is kept
+    fields_: ?[]const models.SubscriptionPostPayloadTransformFields,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!RequestAttemptStatus {
-        const fields = try runtime.asFields(value, "RequestAttemptStatus");
+    ) runtime.DecodeError!SubscriptionPostPayloadTransform {
+        const fields = try runtime.asFields(value, "SubscriptionPostPayloadTransform");
         return .{
-            .type = try runtime.read(
+            .constants = try runtime.maybe(
                 allocator,
                 fields,
-                "type",
-                runtime.memberOf(models.RequestAttemptStatusType).read,
+                "constants",
+                runtime.map(runtime.jsonValue).read,
             ),
-            .at = try runtime.maybe(allocator, fields, "at", runtime.text),
-            .full_processing_ms = try runtime.maybe(
+            .envelope = try runtime.maybe(allocator, fields, "envelope", runtime.text),
+            .fields_ = try runtime.maybe(
                 allocator,
                 fields,
-                "full_processing_ms",
-                runtime.integer64,
+                "fields",
+                runtime.list(models.SubscriptionPostPayloadTransformFields.fromJson).read,
             ),
-            .since = try runtime.maybe(allocator, fields, "since", runtime.text),
-            .until = try runtime.maybe(allocator, fields, "until", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: RequestAttemptStatus,
+        self: SubscriptionPostPayloadTransform,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "type", self.type);
-        try runtime.put(&out, allocator, "at", self.at);
-        try runtime.put(&out, allocator, "full_processing_ms", self.full_processing_ms);
-        try runtime.put(&out, allocator, "since", self.since);
-        try runtime.put(&out, allocator, "until", self.until);
+        try runtime.put(&out, allocator, "constants", self.constants);
+        try runtime.put(&out, allocator, "envelope", self.envelope);
+        try runtime.put(&out, allocator, "fields", self.fields_);
         return .{ .object = out };
     }
 };
 
-/// The `RequestAttemptSubscription` the API declares.
-pub const RequestAttemptSubscription = struct {
-    /// carries `subscription_id`.
-    subscription_id: []const u8,
-    /// carries `description`.
-    description: ?[]const u8,
+/// The `SubscriptionPostPayloadTransformFields` the API declares.
+pub const SubscriptionPostPayloadTransformFields = struct {
+    /// carries `from`: JSON pointer to a value of the payload, for example `/user/id` (values that
+    /// are absent from a payload are skipped)
+    from: []const u8,
+    /// carries `to`: JSON pointer where the value is written in the result, for example `/id`;
+    /// defaults to `from`
+    to: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!RequestAttemptSubscription {
-        const fields = try runtime.asFields(value, "RequestAttemptSubscription");
+    ) runtime.DecodeError!SubscriptionPostPayloadTransformFields {
+        const fields = try runtime.asFields(value, "SubscriptionPostPayloadTransformFields");
         return .{
-            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
-            .description = try runtime.maybe(allocator, fields, "description", runtime.text),
+            .from = try runtime.read(allocator, fields, "from", runtime.text),
+            .to = try runtime.maybe(allocator, fields, "to", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: RequestAttemptSubscription,
+        self: SubscriptionPostPayloadTransformFields,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
-        try runtime.put(&out, allocator, "description", self.description);
+        try runtime.put(&out, allocator, "from", self.from);
+        try runtime.put(&out, allocator, "to", self.to);
         return .{ .object = out };
     }
 };
 
-/// The `Response` the API declares.
-pub const Response = struct {
-    /// carries `response_id`.
-    response_id: []const u8,
-    /// carries `body`.
-    body: ?[]const u8,
-    /// carries `elapsed_time_ms`.
-    elapsed_time_ms: ?i32,
-    /// carries `headers`.
-    headers: ?runtime.Map([]const u8),
-    /// carries `http_code`.
-    http_code: ?i32,
-    /// carries `response_error_name`.
-    response_error_name: ?[]const u8,
+/// The `SubscriptionPostResponseRules` the API declares.
+pub const SubscriptionPostResponseRules = struct {
+    /// carries `outcome`, one of `models.SubscriptionPostResponseRulesOutcome.values`: What the
+    /// delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not
+    /// retried) or `disable_subscription` (not retried, and the subscription is disabled)
+    outcome: []const u8,
+    /// carries `status`: Status codes the rule applies to: exact codes such as `410`, or classes
+    /// such as `4xx`
+    status: []const []const u8,
+    /// carries `body_contains`: Text the response body must contain
+    body_contains: ?[]const u8,
+    /// carries `header`: Header the response must have
+    header: ?models.SubscriptionPostResponseRulesHeader,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!Response {
-        const fields = try runtime.asFields(value, "Response");
+    ) runtime.DecodeError!SubscriptionPostResponseRules {
+        const fields = try runtime.asFields(value, "SubscriptionPostResponseRules");
         return .{
-            .response_id = try runtime.read(allocator, fields, "response_id", runtime.text),
-            .body = try runtime.maybe(allocator, fields, "body", runtime.text),
-            .elapsed_time_ms = try runtime.maybe(
+            .outcome = try runtime.read(
                 allocator,
                 fields,
-                "elapsed_time_ms",
-                runtime.integer32,
+                "outcome",
+                runtime.memberOf(models.SubscriptionPostResponseRulesOutcome).read,
             ),
-            .headers = try runtime.maybe(
+            .status = try runtime.read(
                 allocator,
                 fields,
-                "headers",
-                runtime.map(runtime.text).read,
+                "status",
+                runtime.list(runtime.text).read,
             ),
-            .http_code = try runtime.maybe(allocator, fields, "http_code", runtime.integer32),
-            .response_error_name = try runtime.maybe(
+            .body_contains = try runtime.maybe(allocator, fields, "body_contains", runtime.text),
+            .header = try runtime.maybe(
                 allocator,
                 fields,
-                "response_error_name",
-                runtime.text,
+                "header",
+                models.SubscriptionPostResponseRulesHeader.fromJson,
             ),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: Response,
+        self: SubscriptionPostResponseRules,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "response_id", self.response_id);
-        try runtime.put(&out, allocator, "body", self.body);
-        try runtime.put(&out, allocator, "elapsed_time_ms", self.elapsed_time_ms);
-        try runtime.put(&out, allocator, "headers", self.headers);
-        try runtime.put(&out, allocator, "http_code", self.http_code);
-        try runtime.put(&out, allocator, "response_error_name", self.response_error_name);
+        try runtime.put(&out, allocator, "outcome", self.outcome);
+        try runtime.put(&out, allocator, "status", self.status);
+        try runtime.put(&out, allocator, "body_contains", self.body_contains);
+        try runtime.put(&out, allocator, "header", self.header);
         return .{ .object = out };
     }
 };
 
-/// The `ServiceToken` the API declares.
-pub const ServiceToken = struct {
-    /// carries `biscuit`.
-    biscuit: []const u8,
-    /// carries `created_at`.
-    created_at: []const u8,
-    /// carries `name`.
+/// The `SubscriptionPostResponseRulesHeader` the API declares.
+pub const SubscriptionPostResponseRulesHeader = struct {
+    /// carries `name`: Name of the header, regardless of case
     name: []const u8,
-    /// carries `token_id`.
-    token_id: []const u8,
+    /// carries `value`: Value the header must have; when omitted, any value matches
+    value_: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!ServiceToken {
-        const fields = try runtime.asFields(value, "ServiceToken");
+    ) runtime.DecodeError!SubscriptionPostResponseRulesHeader {
+        const fields = try runtime.asFields(value, "SubscriptionPostResponseRulesHeader");
         return .{
-            .biscuit = try runtime.read(allocator, fields, "biscuit", runtime.text),
-            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
             .name = try runtime.read(allocator, fields, "name", runtime.text),
-            .token_id = try runtime.read(allocator, fields, "token_id", runtime.text),
+            .value_ = try runtime.maybe(allocator, fields, "value", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: ServiceToken,
+        self: SubscriptionPostResponseRulesHeader,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "biscuit", self.biscuit);
-        try runtime.put(&out, allocator, "created_at", self.created_at);
         try runtime.put(&out, allocator, "name", self.name);
-        try runtime.put(&out, allocator, "token_id", self.token_id);
+        try runtime.put(&out, allocator, "value", self.value_);
         return .{ .object = out };
     }
 };
 
-/// The `ServiceTokenPost` the API declares.
-pub const ServiceTokenPost = struct {
-    /// carries `name`.
-    name: []const u8,
-    /// carries `organization_id`.
-    organization_id: []const u8,
+/// The `SubscriptionPostRetryPolicy` the API declares.
+pub const SubscriptionPostRetryPolicy = struct {
+    /// carries `backoff_steps_s`: Delays in seconds to wait before each retry; the last one is used
+    /// again for the following retries
+    backoff_steps_s: ?[]const i32,
+    /// carries `max_retries`: Maximum number of retries after a failed delivery (0 disables
+    /// retries)
+    max_retries: ?i32,
+    /// carries `max_window_s`: Maximum duration in seconds the retries can span; a retry that would
+    /// not fit in it is not scheduled
+    max_window_s: ?i32,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!ServiceTokenPost {
-        const fields = try runtime.asFields(value, "ServiceTokenPost");
+    ) runtime.DecodeError!SubscriptionPostRetryPolicy {
+        const fields = try runtime.asFields(value, "SubscriptionPostRetryPolicy");
         return .{
-            .name = try runtime.read(allocator, fields, "name", runtime.text),
-            .organization_id = try runtime.read(allocator, fields, "organization_id", runtime.text),
+            .backoff_steps_s = try runtime.maybe(
+                allocator,
+                fields,
+                "backoff_steps_s",
+                runtime.list(runtime.integer32).read,
+            ),
+            .max_retries = try runtime.maybe(allocator, fields, "max_retries", runtime.integer32),
+            .max_window_s = try runtime.maybe(allocator, fields, "max_window_s", runtime.integer32),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: ServiceTokenPost,
+        self: SubscriptionPostRetryPolicy,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "name", self.name);
-        try runtime.put(&out, allocator, "organization_id", self.organization_id);
+        try runtime.put(&out, allocator, "backoff_steps_s", self.backoff_steps_s);
+        try runtime.put(&out, allocator, "max_retries", self.max_retries);
+        try runtime.put(&out, allocator, "max_window_s", self.max_window_s);
         return .{ .object = out };
     }
 };
 
-/// The `Subscription` the API declares.
-pub const Subscription = struct {
-    /// carries `application_id`.
-    application_id: []const u8,
-    /// carries `created_at`.
-    created_at: []const u8,
-    /// carries `dedicated_workers`.
-    dedicated_workers: []const []const u8,
-    /// carries `event_types`.
-    event_types: []const []const u8,
-    /// carries `is_enabled`.
-    is_enabled: bool,
-    /// carries `label_key`: _Kept for backward compatibility, you should use `labels`_
-    label_key: []const u8,
-    /// carries `label_value`: _Kept for backward compatibility, you should use `labels`_
-    label_value: []const u8,
-    /// carries `labels`.
-    labels: runtime.Map([]const u8),
-    /// carries `metadata`.
-    metadata: runtime.Map([]const u8),
-    /// carries `secret`.
-    secret: []const u8,
-    /// carries `subscription_id`.
-    subscription_id: []const u8,
-    /// carries `target`.
-    target: models.SubscriptionTarget,
-    /// carries `updated_at`.
-    updated_at: []const u8,
-    /// carries `description`.
-    description: ?[]const u8,
+/// The `SubscriptionPostTarget` the API declares.
+pub const SubscriptionPostTarget = struct {
+    /// carries `type`, one of `models.SubscriptionPostTargetType.values`: Kind of target: `http`,
+    /// `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on
+    /// it
+    type: []const u8,
+    /// carries `access_key_id`: `sqs`: access key ID requests are signed with
+    access_key_id: ?[]const u8,
+    /// carries `exchange`: `amqp`: exchange messages are published to; empty for the default
+    /// exchange
+    exchange: ?[]const u8,
+    /// carries `headers`: `http`: headers sent with every request
+    headers: ?std.json.Value,
+    /// carries `method`: `http`: HTTP method of the requests
+    method: ?[]const u8,
+    /// carries `password`: `amqp`: password to log in to the broker with; write-only
+    password: ?[]const u8,
+    /// carries `queue_url`: `sqs`: URL of the queue
+    queue_url: ?[]const u8,
+    /// carries `region`: `sqs`: region requests are signed for
+    region: ?[]const u8,
+    /// carries `routing_key`: `amqp`: routing key messages are published with
+    routing_key: ?[]const u8,
+    /// carries `secret_access_key`: `sqs`: secret access key requests are signed with; write-only
+    secret_access_key: ?[]const u8,
+    /// carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps`
+    /// scheme), without credentials
+    url: ?[]const u8,
+    /// carries `username`: `amqp`: user name to log in to the broker with
+    username: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!Subscription {
-        const fields = try runtime.asFields(value, "Subscription");
+    ) runtime.DecodeError!SubscriptionPostTarget {
+        const fields = try runtime.asFields(value, "SubscriptionPostTarget");
         return .{
-            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
-            .created_at = try runtime.read(allocator, fields, "created_at", runtime.text),
-            .dedicated_workers = try runtime.read(
-                allocator,
-                fields,
-                "dedicated_workers",
-                runtime.list(runtime.text).read,
-            ),
-            .event_types = try runtime.read(
-                allocator,
-                fields,
-                "event_types",
-                runtime.list(runtime.text).read,
-            ),
-            .is_enabled = try runtime.read(allocator, fields, "is_enabled", runtime.boolean),
-            .label_key = try runtime.read(allocator, fields, "label_key", runtime.text),
-            .label_value = try runtime.read(allocator, fields, "label_value", runtime.text),
-            .labels = try runtime.read(allocator, fields, "labels", runtime.map(runtime.text).read),
-            .metadata = try runtime.read(
+            .type = try runtime.read(
                 allocator,
                 fields,
-                "metadata",
-                runtime.map(runtime.text).read,
+                "type",
+                runtime.memberOf(models.SubscriptionPostTargetType).read,
             ),
-            .secret = try runtime.read(allocator, fields, "secret", runtime.text),
-            .subscription_id = try runtime.read(allocator, fields, "subscription_id", runtime.text),
-            .target = try runtime.read(
+            .access_key_id = try runtime.maybe(allocator, fields, "access_key_id", runtime.text),
+            .exchange = try runtime.maybe(allocator, fields, "exchange", runtime.text),
+            .headers = try runtime.maybe(allocator, fields, "headers", runtime.jsonValue),
+            .method = try runtime.maybe(allocator, fields, "method", runtime.text),
+            .password = try runtime.maybe(allocator, fields, "password", runtime.text),
+            .queue_url = try runtime.maybe(allocator, fields, "queue_url", runtime.text),
+            .region = try runtime.maybe(allocator, fields, "region", runtime.text),
+            .routing_key = try runtime.maybe(allocator, fields, "routing_key", runtime.text),
+            .secret_access_key = try runtime.maybe(
                 allocator,
                 fields,
-                "target",
-                models.SubscriptionTarget.fromJson,
+                "secret_access_key",
+                runtime.text,
             ),
-            .updated_at = try runtime.read(allocator, fields, "updated_at", runtime.text),
-            .description = try runtime.maybe(allocator, fields, "description", runtime.text),
+            .url = try runtime.maybe(allocator, fields, "url", runtime.text),
+            .username = try runtime.maybe(allocator, fields, "username", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: Subscription,
+        self: SubscriptionPostTarget,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "application_id", self.application_id);
-        try runtime.put(&out, allocator, "created_at", self.created_at);
-        try runtime.put(&out, allocator, "dedicated_workers", self.dedicated_workers);
-        try runtime.put(&out, allocator, "event_types", self.event_types);
-        try runtime.put(&out, allocator, "is_enabled", self.is_enabled);
-        try runtime.put(&out, allocator, "label_key", self.label_key);
-        try runtime.put(&out, allocator, "label_value", self.label_value);
-        try runtime.put(&out, allocator, "labels", self.labels);
-        try runtime.put(&out, allocator, "metadata", self.metadata);
-        try runtime.put(&out, allocator, "secret", self.secret);
-        try runtime.put(&out, allocator, "subscription_id", self.subscription_id);
-        try runtime.put(&out, allocator, "target", self.target);
-        try runtime.put(&out, allocator, "updated_at", self.updated_at);
-        try runtime.put(&out, allocator, "description", self.description);
+        try runtime.put(&out, allocator, "type", self.type);
+        try runtime.put(&out, allocator, "access_key_id", self.access_key_id);
+        try runtime.put(&out, allocator, "exchange", self.exchange);
+        try runtime.put(&out, allocator, "headers", self.headers);
+        try runtime.put(&out, allocator, "method", self.method);
+        try runtime.put(&out, allocator, "password", self.password);
+        try runtime.put(&out, allocator, "queue_url", self.queue_url);
+        try runtime.put(&out, allocator, "region", self.region);
+        try runtime.put(&out, allocator, "routing_key", self.routing_key);
+        try runtime.put(&out, allocator, "secret_access_key", self.secret_access_key);
+        try runtime.put(&out, allocator, "url", self.url);
+        try runtime.put(&out, allocator, "username", self.username);
         return .{ .object = out };
     }
 };
 
-/// The `SubscriptionPost` the API declares.
-pub const SubscriptionPost = struct {
-    /// carries `application_id`.
-    application_id: []const u8,
-    /// carries `event_types`.
-    event_types: []const []const u8,
-    /// carries `is_enabled`.
-    is_enabled: bool,
-    /// carries `target`.
-    target: models.SubscriptionPostTarget,
-    /// carries `dedicated_workers`.
-    dedicated_workers: ?[]const []const u8,
-    /// carries `description`.
-    description: ?[]const u8,
-    /// carries `label_key`: _Kept for backward compatibility, you should use `labels`_
-    label_key: ?[]const u8,
-    /// carries `label_value`: _Kept for backward compatibility, you should use `labels`_
-    label_value: ?[]const u8,
-    /// carries `labels`.
-    labels: ?runtime.Map([]const u8),
-    /// carries `metadata`.
-    metadata: ?runtime.Map([]const u8),
+/// The `SubscriptionResponseRules` the API declares.
+pub const SubscriptionResponseRules = struct {
+    /// carries `outcome`, one of `models.SubscriptionResponseRulesOutcome.values`: What the
+    /// delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not
+    /// retried) or `disable_subscription` (not retried, and the subscription is disabled)
+    outcome: []const u8,
+    /// carries `status`: Status codes the rule applies to: exact codes such as `410`, or classes
+    /// such as `4xx`
+    status: []const []const u8,
+    /// carries `body_contains`: Text the response body must contain
+    body_contains: ?[]const u8,
+    /// carries `header`: Header the response must have
+    header: ?models.SubscriptionResponseRulesHeader,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!SubscriptionPost {
-        const fields = try runtime.asFields(value, "SubscriptionPost");
+    ) runtime.DecodeError!SubscriptionResponseRules {
+        const fields = try runtime.asFields(value, "SubscriptionResponseRules");
         return .{
-            .application_id = try runtime.read(allocator, fields, "application_id", runtime.text),
-            .event_types = try runtime.read(
-                allocator,
-                fields,
-                "event_types",
-                runtime.list(runtime.text).read,
-            ),
-            .is_enabled = try runtime.read(allocator, fields, "is_enabled", runtime.boolean),
-            .target = try runtime.read(
+            .outcome = try runtime.read(
                 allocator,
                 fields,
-                "target",
-                models.SubscriptionPostTarget.fromJson,
+                "outcome",
+                runtime.memberOf(models.SubscriptionResponseRulesOutcome).read,
             ),
-            .dedicated_workers = try runtime.maybe(
+            .status = try runtime.read(
                 allocator,
                 fields,
-                "dedicated_workers",
+                "status",
                 runtime.list(runtime.text).read,
             ),
-            .description = try runtime.maybe(allocator, fields, "description", runtime.text),
-            .label_key = try runtime.maybe(allocator, fields, "label_key", runtime.text),
-            .label_value = try runtime.maybe(allocator, fields, "label_value", runtime.text),
-            .labels = try runtime.maybe(
-                allocator,
-                fields,
-                "labels",
-                runtime.map(runtime.text).read,
-            ),
-            .metadata = try runtime.maybe(
+            .body_contains = try runtime.maybe(allocator, fields, "body_contains", runtime.text),
+            .header = try runtime.maybe(
                 allocator,
                 fields,
-                "metadata",
-                runtime.map(runtime.text).read,
+                "header",
+                models.SubscriptionResponseRulesHeader.fromJson,
             ),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: SubscriptionPost,
+        self: SubscriptionResponseRules,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "application_id", self.application_id);
-        try runtime.put(&out, allocator, "event_types", self.event_types);
-        try runtime.put(&out, allocator, "is_enabled", self.is_enabled);
-        try runtime.put(&out, allocator, "target", self.target);
-        try runtime.put(&out, allocator, "dedicated_workers", self.dedicated_workers);
-        try runtime.put(&out, allocator, "description", self.description);
-        try runtime.put(&out, allocator, "label_key", self.label_key);
-        try runtime.put(&out, allocator, "label_value", self.label_value);
-        try runtime.put(&out, allocator, "labels", self.labels);
-        try runtime.put(&out, allocator, "metadata", self.metadata);
+        try runtime.put(&out, allocator, "outcome", self.outcome);
+        try runtime.put(&out, allocator, "status", self.status);
+        try runtime.put(&out, allocator, "body_contains", self.body_contains);
+        try runtime.put(&out, allocator, "header", self.header);
         return .{ .object = out };
     }
 };
 
-/// The `SubscriptionPostTarget` the API declares.
-pub const SubscriptionPostTarget = struct {
-    /// carries `headers`.
-    headers: std.json.Value,
-    /// carries `method`.
-    method: []const u8,
-    /// carries `type`.
-    type: []const u8,
-    /// carries `url`.
-    url: []const u8,
+/// The `SubscriptionResponseRulesHeader` the API declares.
+pub const SubscriptionResponseRulesHeader = struct {
+    /// carries `name`: Name of the header, regardless of case
+    name: []const u8,
+    /// carries `value`: Value the header must have; when omitted, any value matches
+    value_: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
         allocator: std.mem.Allocator,
         value: std.json.Value,
-    ) runtime.DecodeError!SubscriptionPostTarget {
-        const fields = try runtime.asFields(value, "SubscriptionPostTarget");
+    ) runtime.DecodeError!SubscriptionResponseRulesHeader {
+        const fields = try runtime.asFields(value, "SubscriptionResponseRulesHeader");
         return .{
-            .headers = try runtime.read(allocator, fields, "headers", runtime.jsonValue),
-            .method = try runtime.read(allocator, fields, "method", runtime.text),
-            .type = try runtime.read(allocator, fields, "type", runtime.text),
-            .url = try runtime.read(allocator, fields, "url", runtime.text),
+            .name = try runtime.read(allocator, fields, "name", runtime.text),
+            .value_ = try runtime.maybe(allocator, fields, "value", runtime.text),
         };
     }
 
     /// Write one back the way the API reads it.
     pub fn toJson(
-        self: SubscriptionPostTarget,
+        self: SubscriptionResponseRulesHeader,
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
-        try runtime.put(&out, allocator, "headers", self.headers);
-        try runtime.put(&out, allocator, "method", self.method);
-        try runtime.put(&out, allocator, "type", self.type);
-        try runtime.put(&out, allocator, "url", self.url);
+        try runtime.put(&out, allocator, "name", self.name);
+        try runtime.put(&out, allocator, "value", self.value_);
+        return .{ .object = out };
+    }
+};
+
+/// The `SubscriptionRetryPolicy` the API declares.
+pub const SubscriptionRetryPolicy = struct {
+    /// carries `backoff_steps_s`: Delays in seconds to wait before each retry; the last one is used
+    /// again for the following retries
+    backoff_steps_s: ?[]const i32,
+    /// carries `max_retries`: Maximum number of retries after a failed delivery (0 disables
+    /// retries)
+    max_retries: ?i32,
+    /// carries `max_window_s`: Maximum duration in seconds the retries can span; a retry that would
+    /// not fit in it is not scheduled
+    max_window_s: ?i32,
+
+    /// Read one out of what the API answered.
+    pub fn fromJson(
+        allocator: std.mem.Allocator,
+        value: std.json.Value,
+    ) runtime.DecodeError!SubscriptionRetryPolicy {
+        const fields = try runtime.asFields(value, "SubscriptionRetryPolicy");
+        return .{
+            .backoff_steps_s = try runtime.maybe(
+                allocator,
+                fields,
+                "backoff_steps_s",
+                runtime.list(runtime.integer32).read,
+            ),
+            .max_retries = try runtime.maybe(allocator, fields, "max_retries", runtime.integer32),
+            .max_window_s = try runtime.maybe(allocator, fields, "max_window_s", runtime.integer32),
+        };
+    }
+
+    /// Write one back the way the API reads it.
+    pub fn toJson(
+        self: SubscriptionRetryPolicy,
+        allocator: std.mem.Allocator,
+    ) runtime.WriteError!std.json.Value {
+        var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "backoff_steps_s", self.backoff_steps_s);
+        try runtime.put(&out, allocator, "max_retries", self.max_retries);
+        try runtime.put(&out, allocator, "max_window_s", self.max_window_s);
         return .{ .object = out };
     }
 };
 
 /// The `SubscriptionTarget` the API declares.
 pub const SubscriptionTarget = struct {
-    /// carries `headers`.
-    headers: std.json.Value,
-    /// carries `method`.
-    method: []const u8,
-    /// carries `type`.
+    /// carries `type`, one of `models.SubscriptionTargetType.values`: Kind of target: `http`,
+    /// `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on
+    /// it
     type: []const u8,
-    /// carries `url`.
-    url: []const u8,
+    /// carries `access_key_id`: `sqs`: access key ID requests are signed with
+    access_key_id: ?[]const u8,
+    /// carries `exchange`: `amqp`: exchange messages are published to; empty for the default
+    /// exchange
+    exchange: ?[]const u8,
+    /// carries `headers`: `http`: headers sent with every request
+    headers: ?std.json.Value,
+    /// carries `method`: `http`: HTTP method of the requests
+    method: ?[]const u8,
+    /// carries `password`: `amqp`: password to log in to the broker with; write-only
+    password: ?[]const u8,
+    /// carries `queue_url`: `sqs`: URL of the queue
+    queue_url: ?[]const u8,
+    /// carries `region`: `sqs`: region requests are signed for
+    region: ?[]const u8,
+    /// carries `routing_key`: `amqp`: routing key messages are published with
+    routing_key: ?[]const u8,
+    /// carries `secret_access_key`: `sqs`: secret access key requests are signed with; write-only
+    secret_access_key: ?[]const u8,
+    /// carries `url`: `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps`
+    /// scheme), without credentials
+    url: ?[]const u8,
+    /// carries `username`: `amqp`: user name to log in to the broker with
+    username: ?[]const u8,
 
     /// Read one out of what the API answered.
     pub fn fromJson(
@@ -2323,10 +5965,28 @@ pub const SubscriptionTarget = struct {
     ) runtime.DecodeError!SubscriptionTarget {
         const fields = try runtime.asFields(value, "SubscriptionTarget");
         return .{
-            .headers = try runtime.read(allocator, fields, "headers", runtime.jsonValue),
-            .method = try runtime.read(allocator, fields, "method", runtime.text),
-            .type = try runtime.read(allocator, fields, "type", runtime.text),
-            .url = try runtime.read(allocator, fields, "url", runtime.text),
+            .type = try runtime.read(
+                allocator,
+                fields,
+                "type",
+                runtime.memberOf(models.SubscriptionTargetType).read,
+            ),
+            .access_key_id = try runtime.maybe(allocator, fields, "access_key_id", runtime.text),
+            .exchange = try runtime.maybe(allocator, fields, "exchange", runtime.text),
+            .headers = try runtime.maybe(allocator, fields, "headers", runtime.jsonValue),
+            .method = try runtime.maybe(allocator, fields, "method", runtime.text),
+            .password = try runtime.maybe(allocator, fields, "password", runtime.text),
+            .queue_url = try runtime.maybe(allocator, fields, "queue_url", runtime.text),
+            .region = try runtime.maybe(allocator, fields, "region", runtime.text),
+            .routing_key = try runtime.maybe(allocator, fields, "routing_key", runtime.text),
+            .secret_access_key = try runtime.maybe(
+                allocator,
+                fields,
+                "secret_access_key",
+                runtime.text,
+            ),
+            .url = try runtime.maybe(allocator, fields, "url", runtime.text),
+            .username = try runtime.maybe(allocator, fields, "username", runtime.text),
         };
     }
 
@@ -2336,10 +5996,18 @@ pub const SubscriptionTarget = struct {
         allocator: std.mem.Allocator,
     ) runtime.WriteError!std.json.Value {
         var out: std.json.ObjectMap = .empty;
+        try runtime.put(&out, allocator, "type", self.type);
+        try runtime.put(&out, allocator, "access_key_id", self.access_key_id);
+        try runtime.put(&out, allocator, "exchange", self.exchange);
         try runtime.put(&out, allocator, "headers", self.headers);
         try runtime.put(&out, allocator, "method", self.method);
-        try runtime.put(&out, allocator, "type", self.type);
+        try runtime.put(&out, allocator, "password", self.password);
+        try runtime.put(&out, allocator, "queue_url", self.queue_url);
+        try runtime.put(&out, allocator, "region", self.region);
+        try runtime.put(&out, allocator, "routing_key", self.routing_key);
+        try runtime.put(&out, allocator, "secret_access_key", self.secret_access_key);
         try runtime.put(&out, allocator, "url", self.url);
+        try runtime.put(&out, allocator, "username", self.username);
         return .{ .object = out };
     }
 };
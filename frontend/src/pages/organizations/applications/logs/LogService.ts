@@ -13,6 +13,8 @@ export const enum RequestAttemptStatusType {
   InProgress = 'inprogress',
   Successful = 'successful',
   Failed = 'failed',
+  Expired = 'expired',
+  Cancelled = 'cancelled',
 }
 
 export type RequestAttemptStatus = {
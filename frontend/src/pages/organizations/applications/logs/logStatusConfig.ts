@@ -1,5 +1,5 @@
 import type { Component } from 'vue';
-import { CheckCircle2, XCircle, Clock, Loader, CircleDashed } from 'lucide-vue-next';
+import { CheckCircle2, XCircle, Clock, Loader, CircleDashed, TimerOff, Ban } from 'lucide-vue-next';
 import { RequestAttemptStatusType } from './LogService';
 
 export type StatusVariant = 'success' | 'error' | 'warning' | 'info' | 'muted';
@@ -27,6 +27,20 @@ export const STATUS_CONFIG: Record<RequestAttemptStatusType, StatusConfig> = {
     tooltipDateField: 'failed_at',
     icon: XCircle,
   },
+  [RequestAttemptStatusType.Expired]: {
+    labelKey: 'logs.statusExpired',
+    variant: 'muted',
+    tooltipKey: 'logs.tooltipExpired',
+    tooltipDateField: 'failed_at',
+    icon: TimerOff,
+  },
+  [RequestAttemptStatusType.Cancelled]: {
+    labelKey: 'logs.statusCancelled',
+    variant: 'muted',
+    tooltipKey: 'logs.tooltipCancelled',
+    tooltipDateField: 'failed_at',
+    icon: Ban,
+  },
   [RequestAttemptStatusType.Pending]: {
     labelKey: 'logs.statusPending',
     variant: 'warning',
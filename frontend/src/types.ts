@@ -244,7 +244,7 @@ export interface paths {
     patch?: never;
     trace?: never;
   };
-  '/api/v1/environment_variables/': {
+  '/api/v1/bulk_retries/': {
     parameters: {
       query?: never;
       header?: never;
@@ -252,83 +252,83 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * List environment variables metadata
-     * @description Returns metadata for all environment variables read by the API
+     * List bulk retries
+     * @description Lists the bulk retries of an application, most recent first, with their progress.
      */
-    get: operations['environment_variables.list'];
+    get: operations['bulkRetries.list'];
     put?: never;
-    post?: never;
+    /**
+     * Create a bulk retry
+     * @description Redelivers, in the background, every (event, subscription) pair with a request attempt matching the filters: each pair gets a new request attempt, at a pace that does not flood the workers. Pairs whose subscription is disabled or that still have a pending delivery are left out, and so are pairs that were eventually delivered when retrying failures. Only one bulk retry per application can run at a time.
+     */
+    post: operations['bulkRetries.create'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/errors/': {
+  '/api/v1/bulk_retries/dry_run': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
+    get?: never;
+    put?: never;
     /**
-     * List errors
-     * @description List of every possible errors that Hook0 can return. Each error is in RFC7807 problem format.
+     * Count what a bulk retry would redeliver
+     * @description Takes the same filters as creating a bulk retry and returns how many (event, subscription) pairs it would redeliver right now, without redelivering anything.
      */
-    get: operations['errors.list'];
-    put?: never;
-    post?: never;
+    post: operations['bulkRetries.dry_run'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/event/': {
+  '/api/v1/bulk_retries/{bulk_retry_id}': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
-    get?: never;
-    put?: never;
     /**
-     * Ingest an event
-     * @description Sends an event to Hook0 for processing. The event will be matched against active subscriptions based on event type and labels, triggering webhook deliveries to matching endpoints. Requires event_type, payload, payload_content_type, labels, and occurred_at.
+     * Get a bulk retry
+     * @description Retrieves a bulk retry and its progress: how many (event, subscription) pairs matched when it was created, how many were handled and how many request attempts were created so far.
      */
-    post: operations['events.ingest'];
+    get: operations['bulkRetries.get'];
+    put?: never;
+    post?: never;
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/event_types/': {
+  '/api/v1/bulk_retries/{bulk_retry_id}/cancel': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
-    /**
-     * List event types
-     * @description Retrieves all active event types for an application. Event types follow the pattern 'service.resource.verb'. Use application_id query parameter to filter by application.
-     */
-    get: operations['eventTypes.list'];
+    get?: never;
     put?: never;
     /**
-     * Create a new event type
-     * @description Registers a new event type for an application. Event types follow the pattern 'service.resource.verb' (e.g., 'order.payment.completed'). Subscriptions can filter which event types trigger webhooks.
+     * Cancel a bulk retry
+     * @description Stops a running bulk retry. Request attempts it already created are delivered anyway.
      */
-    post: operations['eventTypes.create'];
+    post: operations['bulkRetries.cancel'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/event_types/{event_type_name}': {
+  '/api/v1/dead_letters/': {
     parameters: {
       query?: never;
       header?: never;
@@ -336,103 +336,103 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * Get an event type by its name
-     * @description Retrieves details of a specific event type by its name (e.g., 'order.payment.completed'). Returns the service, resource type, and verb components.
+     * List dead letters
+     * @description Retrieves the deliveries output workers gave up on, because the retries of the subscription were exhausted or because the delivery could not be signed or transformed, most recent first. Filter by subscription_id. Paginated via Link header.
      */
-    get: operations['eventTypes.get'];
+    get: operations['deadLetters.list'];
     put?: never;
     post?: never;
-    /**
-     * Delete an event type
-     * @description Deactivates an event type, preventing it from being used for new events. Existing events using this type remain unaffected. Use this to clean up unused event types.
-     */
-    delete: operations['eventTypes.delete'];
+    delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/events/': {
+  '/api/v1/dead_letters/purge': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
+    get?: never;
+    put?: never;
     /**
-     * List latest events
-     * @description Retrieves the 100 most recently ingested events for an application. Each event includes its type, payload content type, metadata, labels, and timestamps. Use application_id query parameter to filter by application.
+     * Purge dead letters
+     * @description Removes every dead letter of the application, or of one of its subscriptions, without redelivering them. Their events and request attempts are kept.
      */
-    get: operations['events.list'];
-    put?: never;
-    post?: never;
+    post: operations['deadLetters.purge'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/events/{event_id}': {
+  '/api/v1/dead_letters/redeliver': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
+    get?: never;
+    put?: never;
     /**
-     * Get an event by its ID
-     * @description Retrieves full details of a specific event including its payload (base64-encoded), event type, content type, metadata, labels, and timestamps. The event must belong to the specified application.
+     * Redeliver dead letters
+     * @description Redelivers the oldest dead letters of the application, or of one of its subscriptions, at most 100 at a time: call it again until it returns an empty list. Dead letters of disabled subscriptions, and dead letters whose event is already being delivered again to their subscription, are left alone.
      */
-    get: operations['events.get'];
-    put?: never;
-    post?: never;
+    post: operations['deadLetters.redeliverMany'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/events/{event_id}/replay': {
+  '/api/v1/dead_letters/{dead_letter_id}': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
-    get?: never;
+    /**
+     * Get a dead letter by its ID
+     * @description Retrieves a delivery an output worker gave up on, with the response to its last request attempt (status code, headers and body) when the target was reached, to tell why it failed.
+     */
+    get: operations['deadLetters.get'];
     put?: never;
+    post?: never;
     /**
-     * Replay an event
-     * @description Re-triggers webhook deliveries for an existing event. All active subscriptions matching the event type and labels will receive the event again. Useful for retrying failed deliveries or testing webhooks.
+     * Delete a dead letter
+     * @description Removes a dead letter without redelivering it. Its event and request attempts are kept.
      */
-    post: operations['events.replay'];
-    delete?: never;
+    delete: operations['deadLetters.delete'];
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/events_per_day/application': {
+  '/api/v1/dead_letters/{dead_letter_id}/redeliver': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
+    get?: never;
+    put?: never;
     /**
-     * List events per day for an application
-     * @description Retrieves the number of events ingested per day for a specific application.
+     * Redeliver a dead letter
+     * @description Delivers the event of a dead letter again, to its subscription only. A new request attempt is created and the dead letter is removed; if the new request attempt fails for good too, it becomes a new dead letter.
      */
-    get: operations['events_per_day.list_for_application'];
-    put?: never;
-    post?: never;
+    post: operations['deadLetters.redeliver'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/events_per_day/organization': {
+  '/api/v1/environment_variables/': {
     parameters: {
       query?: never;
       header?: never;
@@ -440,10 +440,10 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * List events per day for an organization
-     * @description Retrieves the number of events ingested per day across all applications in an organization.
+     * List environment variables metadata
+     * @description Returns metadata for all environment variables read by the API
      */
-    get: operations['events_per_day.list_for_organization'];
+    get: operations['environment_variables.list'];
     put?: never;
     post?: never;
     delete?: never;
@@ -452,7 +452,7 @@ export interface paths {
     patch?: never;
     trace?: never;
   };
-  '/api/v1/health/': {
+  '/api/v1/errors/': {
     parameters: {
       query?: never;
       header?: never;
@@ -460,10 +460,10 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * Check instance health
-     * @description Get an object that shows if this instance is up.
+     * List errors
+     * @description List of every possible errors that Hook0 can return. Each error is in RFC7807 problem format.
      */
-    get: operations['instance.health'];
+    get: operations['errors.list'];
     put?: never;
     post?: never;
     delete?: never;
@@ -472,27 +472,27 @@ export interface paths {
     patch?: never;
     trace?: never;
   };
-  '/api/v1/instance/': {
+  '/api/v1/event/': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
+    get?: never;
+    put?: never;
     /**
-     * Get instance configuration
-     * @description Get an object that shows how this instance is configured.
+     * Ingest an event
+     * @description Sends an event to Hook0 for processing. The event will be matched against active subscriptions based on event type and labels, triggering webhook deliveries to matching endpoints. Requires event_type, payload, payload_content_type, labels, and occurred_at.
      */
-    get: operations['instance.get'];
-    put?: never;
-    post?: never;
+    post: operations['events.ingest'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/organizations/': {
+  '/api/v1/event_types/': {
     parameters: {
       query?: never;
       header?: never;
@@ -500,23 +500,23 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * List organizations
-     * @description Retrieves all organizations the current API token has access to, with the associated roles and plan information. Use this to discover which organizations you can manage.
+     * List event types
+     * @description Retrieves all active event types for an application. Event types follow the pattern 'service.resource.verb'. Use application_id query parameter to filter by application.
      */
-    get: operations['organizations.list'];
+    get: operations['eventTypes.list'];
     put?: never;
     /**
-     * Create an organization
-     * @description Create a new organization. An organization contains applications, members and optionaly a plan. Note that you will need to regenerate an authentication token to be able to see/use the newly created organization.
+     * Create a new event type
+     * @description Registers a new event type for an application. Event types follow the pattern 'service.resource.verb' (e.g., 'order.payment.completed'). Subscriptions can filter which event types trigger webhooks. An optional JSON Schema validates the payloads of the `application/json` events of the type at ingestion. An optional default time to live sets when the events of the type that do not give an expiry date expire. Creating an event type that already exists keeps its payload schema, mode and default time to live, unless new ones are given.
      */
-    post: operations['organizations.create'];
+    post: operations['eventTypes.create'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/organizations/{organization_id}/': {
+  '/api/v1/event_types/{event_type_name}': {
     parameters: {
       query?: never;
       header?: never;
@@ -524,27 +524,27 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * Get organization's info by its ID
-     * @description Retrieves details about a specific organization, including members, quotas, consumption statistics, and onboarding progress. Use this to check organization health and usage limits.
+     * Get an event type by its name
+     * @description Retrieves details of a specific event type by its name (e.g., 'order.payment.completed'). Returns the service, resource type, and verb components.
      */
-    get: operations['organizations.get'];
+    get: operations['eventTypes.get'];
     /**
-     * Edit an organization
-     * @description Note that you will need to regenerate a JWT to be able to see the updated name of the organization.
+     * Update an event type
+     * @description Sets the JSON Schema the payloads of the event type must match, and how it is applied at ingestion: `enforce` refuses the events whose payload does not match, `warn` ingests them anyway and logs the violations, and `off` keeps the schema without applying it. Also sets the default time to live of the events of the type that do not give an expiry date. Omitting the schema or the default time to live removes it.
      */
-    put: operations['organizations.edit'];
+    put: operations['eventTypes.update'];
     post?: never;
     /**
-     * Delete an organization
-     * @description Remove an organization you have write access to. Organization must not contain any application otherwise this will fail.
+     * Delete an event type
+     * @description Deactivates an event type, preventing it from being used for new events. Existing events using this type remain unaffected. Use this to clean up unused event types.
      */
-    delete: operations['organizations.delete'];
+    delete: operations['eventTypes.delete'];
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/organizations/{organization_id}/invite': {
+  '/api/v1/event_types/{event_type_name}/deprecate': {
     parameters: {
       query?: never;
       header?: never;
@@ -552,47 +552,39 @@ export interface paths {
       cookie?: never;
     };
     get?: never;
+    put?: never;
     /**
-     * Edit a user's role in an organization
-     * @description Change the role of a user that has already access to an organization you have write access to.
-     */
-    put: operations['organizations.edit_role'];
-    /**
-     * Invite a user to an organization
-     * @description Give permission to a user to access an organization you have write access to. The user must already have a Hook0 account otherwise this will fail.
-     */
-    post: operations['organizations.invite'];
-    /**
-     * Revoke a user's access to an organization
-     * @description Remove permission of a user to access an organization you have write access to.
+     * Deprecate an event type version
+     * @description Marks a version of an event type as deprecated, optionally with a sunset date from which the events declaring it are refused. The deprecation is reported in the event type, in the responses to the ingestion of events declaring the version, and by an `api.subscription.event_type_version_deprecated` event for each subscription still pinned to it. Deprecating a version again only changes its sunset date.
      */
-    delete: operations['organizations.revoke'];
+    post: operations['eventTypes.deprecateVersion'];
+    delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/payload_content_types/': {
+  '/api/v1/event_types/{event_type_name}/versions': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
+    get?: never;
+    put?: never;
     /**
-     * List supported event payload content types
-     * @description Returns the list of valid content types for event payloads: 'text/plain' for plain text, 'application/json' for JSON data, 'application/octet-stream+base64' for binary data encoded as base64.
+     * Create a new event type version
+     * @description Declares a new version of the payload contract of an event type (e.g., version 2 of 'order.payment.completed'). Events can then declare the version they are emitted with, and subscriptions can pin the version they receive. A version can have its own JSON Schema, applied instead of the one of the event type to the events declaring it.
      */
-    get: operations['payload_content_types.list'];
-    put?: never;
-    post?: never;
+    post: operations['eventTypes.createVersion'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/quotas/': {
+  '/api/v1/events/': {
     parameters: {
       query?: never;
       header?: never;
@@ -600,10 +592,10 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * Get quotas
-     * @description Get the current quotas limitations on the instance.
+     * List latest events
+     * @description Retrieves the 100 most recently ingested events for an application. Each event includes its type, payload content type, metadata, labels, and timestamps. Use application_id query parameter to filter by application.
      */
-    get: operations['quotas.get'];
+    get: operations['events.list'];
     put?: never;
     post?: never;
     delete?: never;
@@ -612,7 +604,7 @@ export interface paths {
     patch?: never;
     trace?: never;
   };
-  '/api/v1/register/': {
+  '/api/v1/events/batch': {
     parameters: {
       query?: never;
       header?: never;
@@ -622,17 +614,17 @@ export interface paths {
     get?: never;
     put?: never;
     /**
-     * Create a new user account and its own personal organization
-     * @description If instance has Cloudflare Turnstile enabled (see response of /instance endpoint), the `turnstile_token` field is mandatory.
+     * Ingest a batch of events
+     * @description Sends up to 100 events of one application to Hook0 in a single request. Authorization and the events-per-day quota are checked once for the whole batch, and the events that pass their own checks are ingested in one transaction. Each event is checked and stored on its own: the response carries one outcome per event, in submission order, with a problem describing why an event was not ingested. Events beyond the remaining daily quota fail with TooManyEventsToday.
      */
-    post: operations['register'];
+    post: operations['events.ingestBatch'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/request_attempts/': {
+  '/api/v1/events/{event_id}': {
     parameters: {
       query?: never;
       header?: never;
@@ -640,10 +632,10 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * List request attempts
-     * @description Retrieves webhook delivery attempts for an application. Each attempt shows the delivery status (pending, in_progress, successful, failed, waiting), retry count, and timestamps. Filter by event_id, subscription_id, date range, or event types. Paginated via Link header.
+     * Get an event by its ID
+     * @description Retrieves full details of a specific event including its payload (base64-encoded), event type, content type, metadata, labels, and timestamps. The event must belong to the specified application.
      */
-    get: operations['requestAttempts.read'];
+    get: operations['events.get'];
     put?: never;
     post?: never;
     delete?: never;
@@ -652,47 +644,47 @@ export interface paths {
     patch?: never;
     trace?: never;
   };
-  '/api/v1/request_attempts/{request_attempt_id}': {
+  '/api/v1/events/{event_id}/cancel': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
+    get?: never;
+    put?: never;
     /**
-     * Get a request attempt by its ID
-     * @description Retrieves a single webhook delivery attempt by ID, including delivery status, retry count, timestamps, and HTTP response status.
+     * Cancel a scheduled event
+     * @description Cancels the delivery of an event scheduled for later, as long as its delivery date has not been reached: its pending request attempts are marked as cancelled and it is no longer listed among scheduled events. Events that were not scheduled, were already cancelled or whose delivery date has passed cannot be cancelled.
      */
-    get: operations['requestAttempts.get'];
-    put?: never;
-    post?: never;
+    post: operations['events.cancel'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/responses/{response_id}': {
+  '/api/v1/events/{event_id}/replay': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
+    get?: never;
+    put?: never;
     /**
-     * Get a response by its ID
-     * @description A response is produced when a request attempt is processed. Response IDs can be obtained from request attempts details.
+     * Replay an event
+     * @description Re-triggers webhook deliveries for an existing event. All active subscriptions matching the event type and labels will receive the event again. Useful for retrying failed deliveries or testing webhooks.
      */
-    get: operations['response.get'];
-    put?: never;
-    post?: never;
+    post: operations['events.replay'];
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/service_token/': {
+  '/api/v1/events_per_day/application': {
     parameters: {
       query?: never;
       header?: never;
@@ -700,23 +692,19 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * List service tokens
-     * @description Retrieves all active service tokens for a given organization. Expired or revoked tokens are not included in the response.
+     * List events per day for an application
+     * @description Retrieves the number of events ingested per day for a specific application.
      */
-    get: operations['serviceToken.list'];
+    get: operations['events_per_day.list_for_application'];
     put?: never;
-    /**
-     * Create a new service token
-     * @description Creates a new organization-wide API key (service token) that allows external applications to send API requests to Hook0. The token can be attenuated to limit its scope for better security.
-     */
-    post: operations['serviceToken.create'];
+    post?: never;
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/service_token/{service_token_id}': {
+  '/api/v1/events_per_day/organization': {
     parameters: {
       query?: never;
       header?: never;
@@ -724,27 +712,19 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * Get a service token
-     * @description Retrieves details of a specific service token if it belongs to the specified organization and is still active.
-     */
-    get: operations['serviceToken.get'];
-    /**
-     * Edit a service token
-     * @description Updates the name of an existing service token. The token must belong to the specified organization and still be active (not expired or revoked).
+     * List events per day for an organization
+     * @description Retrieves the number of events ingested per day across all applications in an organization.
      */
-    put: operations['serviceToken.edit'];
+    get: operations['events_per_day.list_for_organization'];
+    put?: never;
     post?: never;
-    /**
-     * Delete a service token
-     * @description Marks a service token as expired, effectively revoking its access. This operation is irreversible.
-     */
-    delete: operations['serviceToken.delete'];
+    delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/subscriptions/': {
+  '/api/v1/health/': {
     parameters: {
       query?: never;
       header?: never;
@@ -752,23 +732,19 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * List subscriptions
-     * @description Retrieves all active webhook subscriptions for an application. Each subscription defines which event types to listen for and where to deliver them (HTTP endpoint). Use application_id query parameter to filter by application.
+     * Check instance health
+     * @description Get an object that shows if this instance is up.
      */
-    get: operations['subscriptions.list'];
+    get: operations['instance.health'];
     put?: never;
-    /**
-     * Create a new subscription
-     * @description Creates a webhook subscription that listens for specific event types and delivers them to an HTTP endpoint. Configure the target URL, HTTP method, headers, event type filters, labels for routing, and optional metadata.
-     */
-    post: operations['subscriptions.create'];
+    post?: never;
     delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-  '/api/v1/subscriptions/{subscription_id}': {
+  '/api/v1/inbox/{subscription_id}': {
     parameters: {
       query?: never;
       header?: never;
@@ -776,544 +752,3987 @@ export interface paths {
       cookie?: never;
     };
     /**
-     * Get a subscription by its ID
-     * @description Retrieves full details of a specific webhook subscription including target URL, HTTP method, headers, event types, labels, and metadata. The subscription must exist and not be deleted.
-     */
-    get: operations['subscriptions.get'];
-    /**
-     * Update a subscription
-     * @description Modifies an existing webhook subscription. You can update the target URL, HTTP method, headers, event types, labels, metadata, and enabled status. Disabling a subscription marks all pending webhook deliveries as failed (they won't retry if re-enabled).
+     * Pull events from an inbox
+     * @description Leases the next events waiting in the inbox of a subscription whose target is an inbox, oldest first. A leased event is not offered again until its lease expires, unless it is rejected; acknowledge it before then with its receipt. Events with an ordering key are only offered once the previous ones with the same key are settled. Pulls can follow each other with the cursor they return; an event whose lease expired is offered again to pulls that start before it.
      */
-    put: operations['subscriptions.update'];
+    get: operations['inbox.pull'];
+    put?: never;
     post?: never;
-    /**
-     * Delete a subscription
-     * @description Permanently deletes a webhook subscription. No more events will be delivered to this endpoint, and all pending deliveries are marked as failed. This action is irreversible.
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/inbox/{subscription_id}/ack': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    get?: never;
+    put?: never;
+    /**
+     * Acknowledge events pulled from an inbox
+     * @description Marks the events leased with these receipts as successfully delivered. A receipt whose lease expired settles nothing, since the event may have been leased again; it is returned as stale.
      */
-    delete: operations['subscriptions.delete'];
+    post: operations['inbox.ack'];
+    delete?: never;
     options?: never;
     head?: never;
     patch?: never;
     trace?: never;
   };
-}
-export type webhooks = Record<string, never>;
-export interface components {
-  schemas: {
-    /** @description A Hook0 application. */
-    Application: {
-      /**
-       * Format: uuid
-       * @description Unique identifier of the application.
-       */
-      application_id: string;
-      /** @description Name of the application. Length: 2-50 characters. */
-      name: string;
-      /**
-       * Format: uuid
-       * @description UUID of the organization this application belongs to.
-       */
-      organization_id: string;
+  '/api/v1/inbox/{subscription_id}/nack': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    /** @description Detailed information about a Hook0 application. */
-    ApplicationInfo: {
-      /**
-       * Format: uuid
-       * @description Unique identifier of the application.
-       */
-      application_id: string;
-      /** @description Current consumption metrics for this application. */
-      consumption: {
-        /** Format: int32 */
-        events_per_day?: number;
-      };
-      /** @description Name of the application. Length: 2-50 characters. */
-      name: string;
-      /** @description Onboarding completion status for this application. */
-      onboarding_steps: {
-        /** @enum {string} */
-        event: 'ToDo' | 'Done';
-        /** @enum {string} */
-        event_type: 'ToDo' | 'Done';
-        /** @enum {string} */
-        subscription: 'ToDo' | 'Done';
-      };
-      /**
-       * Format: uuid
-       * @description UUID of the organization this application belongs to.
-       */
-      organization_id: string;
-      /** @description Quota limits for this application. */
-      quotas: {
-        /** Format: int32 */
-        days_of_events_retention_limit: number;
-        /** Format: int32 */
-        events_per_day_limit: number;
-      };
+    get?: never;
+    put?: never;
+    /**
+     * Reject events pulled from an inbox
+     * @description Marks the events leased with these receipts as failed. Unless asked otherwise, each is offered again as a new request attempt, after an optional delay, as long as the subscription's maximum number of retries is not reached; otherwise it becomes a dead letter. A receipt whose lease expired settles nothing; it is returned as stale.
+     */
+    post: operations['inbox.nack'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/instance/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    /** @description Request body to create a new application. */
-    ApplicationPost: {
-      /** @description Name of the application. Length: 2-50 characters. */
-      name: string;
-      /**
-       * Format: uuid
-       * @description UUID of the organization this application belongs to.
-       */
-      organization_id: string;
+    /**
+     * Get instance configuration
+     * @description Get an object that shows how this instance is configured.
+     */
+    get: operations['instance.get'];
+    put?: never;
+    post?: never;
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/jwks/{application_id}': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    ApplicationSecret: {
-      /** Format: date-time */
-      created_at: string;
-      /** Format: date-time */
-      deleted_at?: string;
-      name?: string;
-      /** Format: uuid */
-      token: string;
+    /**
+     * Get the public keys of an application
+     * @description Publishes the public keys deliveries of an application are signed with, as a JSON Web Key Set, for consumers to verify the `v2` part of signatures. It needs no authentication. A signature naming a key that is not listed was either forged or signed by a key that was retired.
+     */
+    get: operations['signingKeys.jwks'];
+    put?: never;
+    post?: never;
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/organizations/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    ApplicationSecretPost: {
-      /** Format: uuid */
-      application_id: string;
-      name?: string;
+    /**
+     * List organizations
+     * @description Retrieves all organizations the current API token has access to, with the associated roles and plan information. Use this to discover which organizations you can manage.
+     */
+    get: operations['organizations.list'];
+    put?: never;
+    /**
+     * Create an organization
+     * @description Create a new organization. An organization contains applications, members and optionaly a plan. Note that you will need to regenerate an authentication token to be able to see/use the newly created organization.
+     */
+    post: operations['organizations.create'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/organizations/{organization_id}/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    BeginResetPasswordPost: {
-      email: string;
+    /**
+     * Get organization's info by its ID
+     * @description Retrieves details about a specific organization, including members, quotas, consumption statistics, and onboarding progress. Use this to check organization health and usage limits.
+     */
+    get: operations['organizations.get'];
+    /**
+     * Edit an organization
+     * @description Note that you will need to regenerate a JWT to be able to see the updated name of the organization.
+     */
+    put: operations['organizations.edit'];
+    post?: never;
+    /**
+     * Delete an organization
+     * @description Remove an organization you have write access to. Organization must not contain any application otherwise this will fail.
+     */
+    delete: operations['organizations.delete'];
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/organizations/{organization_id}/invite': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    ChangePasswordPost: {
-      new_password: string;
+    get?: never;
+    /**
+     * Edit a user's role in an organization
+     * @description Change the role of a user that has already access to an organization you have write access to.
+     */
+    put: operations['organizations.edit_role'];
+    /**
+     * Invite a user to an organization
+     * @description Give permission to a user to access an organization you have write access to. The user must already have a Hook0 account otherwise this will fail.
+     */
+    post: operations['organizations.invite'];
+    /**
+     * Revoke a user's access to an organization
+     * @description Remove permission of a user to access an organization you have write access to.
+     */
+    delete: operations['organizations.revoke'];
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/payload_content_types/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    EmailVerificationPost: {
-      token: string;
+    /**
+     * List supported event payload content types
+     * @description Returns the list of valid content types for event payloads: 'text/plain' for plain text, 'application/json' for JSON data, 'application/octet-stream+base64' for binary data encoded as base64.
+     */
+    get: operations['payload_content_types.list'];
+    put?: never;
+    post?: never;
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/quotas/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    EnvVarMetadata: {
-      default?: string;
-      description?: string;
-      env_var: string;
-      group?: string;
-      name: string;
-      required: boolean;
+    /**
+     * Get quotas
+     * @description Get the current quotas limitations on the instance.
+     */
+    get: operations['quotas.get'];
+    put?: never;
+    post?: never;
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/register/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    get?: never;
+    put?: never;
+    /**
+     * Create a new user account and its own personal organization
+     * @description If instance has Cloudflare Turnstile enabled (see response of /instance endpoint), the `turnstile_token` field is mandatory.
+     */
+    post: operations['register'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/request_attempts/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * List request attempts
+     * @description Retrieves webhook delivery attempts for an application. Each attempt shows the delivery status (pending, in_progress, successful, failed, waiting, expired, cancelled), retry count, and timestamps. Filter by event_id, subscription_id, date range, or event types. Paginated via Link header.
+     */
+    get: operations['requestAttempts.read'];
+    put?: never;
+    post?: never;
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/request_attempts/{request_attempt_id}': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * Get a request attempt by its ID
+     * @description Retrieves a single webhook delivery attempt by ID, including delivery status, retry count, timestamps, and HTTP response status.
+     */
+    get: operations['requestAttempts.get'];
+    put?: never;
+    post?: never;
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/request_attempts/{request_attempt_id}/retry': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    get?: never;
+    put?: never;
+    /**
+     * Retry a request attempt
+     * @description Delivers the event of a failed request attempt again, to the subscription of this request attempt only. A new request attempt is created and returned; it follows the usual retry policy if it fails too. Unlike replaying the event, other subscriptions are not affected.
+     */
+    post: operations['requestAttempts.retry'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/responses/{response_id}': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * Get a response by its ID
+     * @description A response is produced when a request attempt is processed. Response IDs can be obtained from request attempts details.
+     */
+    get: operations['response.get'];
+    put?: never;
+    post?: never;
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/service_token/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * List service tokens
+     * @description Retrieves all active service tokens for a given organization. Expired or revoked tokens are not included in the response.
+     */
+    get: operations['serviceToken.list'];
+    put?: never;
+    /**
+     * Create a new service token
+     * @description Creates a new organization-wide API key (service token) that allows external applications to send API requests to Hook0. The token can be attenuated to limit its scope for better security.
+     */
+    post: operations['serviceToken.create'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/service_token/{service_token_id}': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * Get a service token
+     * @description Retrieves details of a specific service token if it belongs to the specified organization and is still active.
+     */
+    get: operations['serviceToken.get'];
+    /**
+     * Edit a service token
+     * @description Updates the name of an existing service token. The token must belong to the specified organization and still be active (not expired or revoked).
+     */
+    put: operations['serviceToken.edit'];
+    post?: never;
+    /**
+     * Delete a service token
+     * @description Marks a service token as expired, effectively revoking its access. This operation is irreversible.
+     */
+    delete: operations['serviceToken.delete'];
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/signing_keys/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * List signing keys
+     * @description Lists the Ed25519 keys of an application that are not retired. The newest one signs deliveries; the others stay published so that deliveries they signed can still be verified.
+     */
+    get: operations['signingKeys.list'];
+    put?: never;
+    /**
+     * Create a signing key
+     * @description Generates an Ed25519 key pair for an application. Deliveries are signed with it from then on, in addition to the signatures keyed with the secrets of subscriptions; the previous keys stay published until they are retired. Creating a key is how keys are rotated.
+     */
+    post: operations['signingKeys.create'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/signing_keys/{signing_key_id}': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    get?: never;
+    put?: never;
+    post?: never;
+    /**
+     * Retire a signing key
+     * @description Stops publishing a signing key, so that deliveries it signed are not verified anymore. When it was the newest key, deliveries are signed with the newest of the remaining ones, if any.
+     */
+    delete: operations['signingKeys.delete'];
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/subscriptions/': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * List subscriptions
+     * @description Retrieves all active webhook subscriptions for an application. Each subscription defines which event types to listen for and where to deliver them (HTTP endpoint). Use application_id query parameter to filter by application.
+     */
+    get: operations['subscriptions.list'];
+    put?: never;
+    /**
+     * Create a new subscription
+     * @description Creates a webhook subscription that listens for specific event types and delivers them to an HTTP endpoint. Configure the target URL, HTTP method, headers, event type filters, labels for routing, and optional metadata.
+     */
+    post: operations['subscriptions.create'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/subscriptions/{subscription_id}': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * Get a subscription by its ID
+     * @description Retrieves full details of a specific webhook subscription including target URL, HTTP method, headers, event types, labels, and metadata. The subscription must exist and not be deleted.
+     */
+    get: operations['subscriptions.get'];
+    /**
+     * Update a subscription
+     * @description Modifies an existing webhook subscription. You can update the target URL, HTTP method, headers, event types, labels, metadata, and enabled status. Disabling a subscription marks all pending webhook deliveries as failed (they won't retry if re-enabled).
+     */
+    put: operations['subscriptions.update'];
+    post?: never;
+    /**
+     * Delete a subscription
+     * @description Permanently deletes a webhook subscription. No more events will be delivered to this endpoint, and all pending deliveries are marked as failed. This action is irreversible.
+     */
+    delete: operations['subscriptions.delete'];
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/subscriptions/{subscription_id}/client_certificate': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * Get the client certificate of a subscription
+     * @description Retrieves the client certificate the subscription presents to its target when the target requires mutual TLS. The private key is never returned.
+     */
+    get: operations['clientCertificates.get'];
+    /**
+     * Set the client certificate of a subscription
+     * @description Uploads the client certificate and private key the subscription presents to its target when the target requires mutual TLS, replacing the previous ones. They are checked on upload: the certificate must not be expired and the private key must be the one of its leaf certificate. The private key is stored encrypted and never returned.
+     */
+    put: operations['clientCertificates.update'];
+    post?: never;
+    /**
+     * Remove the client certificate of a subscription
+     * @description Removes the client certificate of the subscription and its private key; the subscription no longer presents a certificate to its target.
+     */
+    delete: operations['clientCertificates.delete'];
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/subscriptions/{subscription_id}/oauth2_client_credentials': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * Get the OAuth2 client credentials of a subscription
+     * @description Retrieves the OAuth2 client credentials the subscription obtains an access token with; the token is sent to its target as a bearer token. The client secret is never returned.
+     */
+    get: operations['oauth2ClientCredentials.get'];
+    /**
+     * Set the OAuth2 client credentials of a subscription
+     * @description Sets the OAuth2 client credentials the subscription obtains an access token with (client credentials grant), replacing the previous ones. Output workers request the token from the token endpoint, cache it until it expires and send it to the target as a bearer token with every delivery, in place of any Authorization header of the target. The client secret is never returned.
+     */
+    put: operations['oauth2ClientCredentials.update'];
+    post?: never;
+    /**
+     * Remove the OAuth2 client credentials of a subscription
+     * @description Removes the OAuth2 client credentials of the subscription; its deliveries no longer carry an access token.
+     */
+    delete: operations['oauth2ClientCredentials.delete'];
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/subscriptions/{subscription_id}/secret_rotation': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    /**
+     * Get the secret rotation of a subscription
+     * @description Retrieves the rotation of the secret of the subscription that is in progress, if any: until the previous secret expires, deliveries are signed with both secrets.
+     */
+    get: operations['secretRotations.get'];
+    put?: never;
+    /**
+     * Rotate the secret of a subscription
+     * @description Gives the subscription a new secret while keeping the previous one valid for an overlap window: until it expires, deliveries carry a signature for each secret, so that consumers verifying with either keep accepting them. Only one rotation can be in progress at a time; finalize or roll back the current one first.
+     */
+    post: operations['secretRotations.create'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/subscriptions/{subscription_id}/secret_rotation/finalize': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    get?: never;
+    put?: never;
+    /**
+     * Finalize the secret rotation of a subscription
+     * @description Ends the rotation of the secret of the subscription before its overlap window is over: the previous secret stops being valid and deliveries are only signed with the new one.
+     */
+    post: operations['secretRotations.finalize'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+  '/api/v1/subscriptions/{subscription_id}/secret_rotation/rollback': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    get?: never;
+    put?: never;
+    /**
+     * Roll back the secret rotation of a subscription
+     * @description Cancels the rotation of the secret of the subscription that is in progress: the previous secret becomes the secret of the subscription again and the new one stops being valid.
+     */
+    post: operations['secretRotations.rollback'];
+    delete?: never;
+    options?: never;
+    head?: never;
+    patch?: never;
+    trace?: never;
+  };
+}
+export type webhooks = Record<string, never>;
+export interface components {
+  schemas: {
+    /** @description A Hook0 application. */
+    Application: {
+      /**
+       * Format: uuid
+       * @description Unique identifier of the application.
+       */
+      application_id: string;
+      /** @description Name of the application. Length: 2-50 characters. */
+      name: string;
+      /**
+       * Format: uuid
+       * @description UUID of the organization this application belongs to.
+       */
+      organization_id: string;
+    };
+    /** @description Detailed information about a Hook0 application. */
+    ApplicationInfo: {
+      /**
+       * Format: uuid
+       * @description Unique identifier of the application.
+       */
+      application_id: string;
+      /** @description Current consumption metrics for this application. */
+      consumption: {
+        /** Format: int32 */
+        events_per_day?: number;
+      };
+      /** @description Name of the application. Length: 2-50 characters. */
+      name: string;
+      /** @description Onboarding completion status for this application. */
+      onboarding_steps: {
+        /** @enum {string} */
+        event: 'ToDo' | 'Done';
+        /** @enum {string} */
+        event_type: 'ToDo' | 'Done';
+        /** @enum {string} */
+        subscription: 'ToDo' | 'Done';
+      };
+      /**
+       * Format: uuid
+       * @description UUID of the organization this application belongs to.
+       */
+      organization_id: string;
+      /** @description Quota limits for this application. */
+      quotas: {
+        /** Format: int32 */
+        days_of_events_retention_limit: number;
+        /** Format: int32 */
+        events_per_day_limit: number;
+      };
+    };
+    /** @description Request body to create a new application. */
+    ApplicationPost: {
+      /** @description Name of the application. Length: 2-50 characters. */
+      name: string;
+      /**
+       * Format: uuid
+       * @description UUID of the organization this application belongs to.
+       */
+      organization_id: string;
+    };
+    ApplicationSecret: {
+      /** Format: date-time */
+      created_at: string;
+      /** Format: date-time */
+      deleted_at?: string;
+      name?: string;
+      /** Format: uuid */
+      token: string;
+    };
+    ApplicationSecretPost: {
+      /** Format: uuid */
+      application_id: string;
+      name?: string;
+    };
+    BeginResetPasswordPost: {
+      email: string;
+    };
+    BulkRetry: {
+      /** Format: uuid */
+      application_id: string;
+      /** Format: uuid */
+      bulk_retry_id: string;
+      /** Format: date-time */
+      cancelled_at?: string;
+      /** Format: date-time */
+      completed_at?: string;
+      /** Format: date-time */
+      created_at: string;
+      /** Format: uuid */
+      event_id?: string;
+      event_type_names: string[];
+      /** Format: date-time */
+      max_created_at: string;
+      /** Format: date-time */
+      min_created_at: string;
+      /**
+       * Format: int64
+       * @description Number of pairs handled so far
+       */
+      processed_count: number;
+      /**
+       * Format: int64
+       * @description Number of request attempts created so far
+       */
+      retried_count: number;
+      /** @enum {string} */
+      status: 'failed' | 'successful';
+      /** Format: uuid */
+      subscription_id?: string;
+      /**
+       * Format: int64
+       * @description Number of (event, subscription) pairs that matched when the bulk retry was created
+       */
+      total_count: number;
+      /** Format: date-time */
+      updated_at: string;
+    };
+    BulkRetryDryRun: {
+      /**
+       * Format: int64
+       * @description Number of (event, subscription) pairs a bulk retry would redeliver now
+       */
+      count: number;
+    };
+    BulkRetryPost: {
+      /** Format: uuid */
+      application_id: string;
+      /** Format: uuid */
+      event_id?: string;
+      event_type_names?: string[];
+      /** Format: date-time */
+      max_created_at?: string;
+      /** Format: date-time */
+      min_created_at?: string;
+      /**
+       * @description Redeliver request attempts that failed (default) or that succeeded
+       * @enum {string}
+       */
+      status?: 'failed' | 'successful';
+      /** Format: uuid */
+      subscription_id?: string;
+    };
+    CancelEvent: {
+      /** Format: uuid */
+      application_id: string;
+    };
+    ChangePasswordPost: {
+      new_password: string;
+    };
+    /**
+     * @description Client certificate a subscription presents to its target (mutual TLS). Its private key is
+     *      never returned.
+     */
+    ClientCertificate: {
+      /** @description Certificate chain, leaf certificate first */
+      certificate_pem: string;
+      /** Format: date-time */
+      created_at: string;
+      /** @description SHA-256 fingerprint of the leaf certificate, as hexadecimal */
+      fingerprint_sha256: string;
+      /** Format: date-time */
+      not_after: string;
+      /** Format: date-time */
+      not_before: string;
+      subject: string;
+      /** Format: uuid */
+      subscription_id: string;
+      /** Format: date-time */
+      updated_at: string;
+    };
+    ClientCertificatePut: {
+      /** @description PEM-encoded certificate chain, leaf certificate first */
+      certificate_pem: string;
+      /** @description PEM-encoded unencrypted private key of the leaf certificate (PKCS#8, PKCS#1 or SEC1) */
+      private_key_pem: string;
+    };
+    /** @description Delivery an output worker gave up on */
+    DeadLetter: {
+      /** Format: date-time */
+      created_at: string;
+      /** Format: uuid */
+      dead_letter_id: string;
+      event: {
+        /** Format: uuid */
+        event_id: string;
+        event_type_name: string;
+      };
+      /** Format: int32 */
+      http_response_status?: number;
+      /** @enum {string} */
+      reason: 'retries_exhausted' | 'signature_failed' | 'transform_failed' | 'permanent_failure';
+      /**
+       * Format: uuid
+       * @description Last request attempt of the delivery
+       */
+      request_attempt_id: string;
+      /**
+       * Format: uuid
+       * @description Response to the last request attempt, if the target was reached
+       */
+      response_id?: string;
+      /** Format: int32 */
+      retry_count: number;
+      subscription: {
+        description?: string;
+        /** Format: uuid */
+        subscription_id: string;
+      };
+    };
+    /** @description Dead letter with the response to its last request attempt */
+    DeadLetterDetails: {
+      /** @description Delivery an output worker gave up on */
+      dead_letter: {
+        /** Format: date-time */
+        created_at: string;
+        /** Format: uuid */
+        dead_letter_id: string;
+        event: {
+          /** Format: uuid */
+          event_id: string;
+          event_type_name: string;
+        };
+        /** Format: int32 */
+        http_response_status?: number;
+        /** @enum {string} */
+        reason: 'retries_exhausted' | 'signature_failed' | 'transform_failed' | 'permanent_failure';
+        /**
+         * Format: uuid
+         * @description Last request attempt of the delivery
+         */
+        request_attempt_id: string;
+        /**
+         * Format: uuid
+         * @description Response to the last request attempt, if the target was reached
+         */
+        response_id?: string;
+        /** Format: int32 */
+        retry_count: number;
+        subscription: {
+          description?: string;
+          /** Format: uuid */
+          subscription_id: string;
+        };
+      };
+      /** @description Response to the last request attempt, if the target was reached */
+      last_response?: {
+        body?: string;
+        /** Format: int32 */
+        elapsed_time_ms?: number;
+        headers?: {
+          [key: string]: string;
+        };
+        /** Format: int32 */
+        http_code?: number;
+        response_error_name?: string;
+        /** Format: uuid */
+        response_id: string;
+      };
+    };
+    EmailVerificationPost: {
+      token: string;
+    };
+    EnvVarMetadata: {
+      default?: string;
+      description?: string;
+      env_var: string;
+      group?: string;
+      name: string;
+      required: boolean;
       sensitive: boolean;
     };
-    Event: {
-      /** Format: uuid */
-      event_id: string;
-      event_type_name: string;
-      ip: string;
-      labels: Record<string, never>;
-      metadata?: Record<string, never>;
-      /** Format: date-time */
-      occurred_at: string;
-      payload_content_type: string;
-      /** Format: date-time */
-      received_at: string;
+    Event: {
+      /**
+       * Format: date-time
+       * @description Date the scheduled delivery of the event was cancelled
+       */
+      cancelled_at?: string;
+      /**
+       * Format: date-time
+       * @description Date from which the event is delivered, when it was scheduled for a later delivery
+       */
+      deliver_at?: string;
+      /** Format: uuid */
+      event_id: string;
+      event_type_name: string;
+      /** Format: int32 */
+      event_type_version?: number;
+      /**
+       * Format: date-time
+       * @description Date after which the event is no longer delivered
+       */
+      expires_at?: string;
+      ip: string;
+      labels: Record<string, never>;
+      metadata?: Record<string, never>;
+      /** Format: date-time */
+      occurred_at: string;
+      payload_content_type: string;
+      /** Format: date-time */
+      received_at: string;
+    };
+    /** @description Event to be ingested into Hook0. */
+    EventPost: {
+      /**
+       * Format: uuid
+       * @description UUID of the application this event belongs to.
+       */
+      application_id: string;
+      /**
+       * Format: date-time
+       * @description Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the application.
+       */
+      deliver_at?: string;
+      /**
+       * Format: uuid
+       * @description Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
+       */
+      event_id?: string;
+      /** @description The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters. */
+      event_type: string;
+      /**
+       * Format: int32
+       * @description Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+       */
+      event_type_version?: number;
+      /**
+       * Format: date-time
+       * @description Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted from its delivery.
+       */
+      expires_at?: string;
+      /** @description Labels for event filtering and routing to subscriptions. */
+      labels: {
+        [key: string]: string;
+      };
+      /** @description Optional metadata key-value pairs associated with the event. */
+      metadata?: {
+        [key: string]: string;
+      };
+      /**
+       * Format: date-time
+       * @description Timestamp when the event occurred.
+       */
+      occurred_at: string;
+      /** @description The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded). */
+      payload: string;
+      /** @description Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters. */
+      payload_content_type: string;
+    };
+    EventType: {
+      /**
+       * Format: int32
+       * @description Time to live in seconds of the events of this type that do not give an expiry date
+       */
+      default_ttl_s?: number;
+      event_type_name: string;
+      payload_schema?: Record<string, never>;
+      /** @enum {string} */
+      payload_schema_mode: 'enforce' | 'warn' | 'off';
+      resource_type_name: string;
+      service_name: string;
+      verb_name: string;
+      versions: {
+        /** Format: date-time */
+        created_at: string;
+        /** Format: date-time */
+        deprecated_at?: string;
+        /** @description JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type */
+        payload_schema?: Record<string, never>;
+        /**
+         * Format: date-time
+         * @description Date from which the events declaring this version are refused
+         */
+        sunset_at?: string;
+        /** Format: int32 */
+        version: number;
+      }[];
+    };
+    EventTypePost: {
+      /** Format: uuid */
+      application_id: string;
+      /**
+       * Format: int32
+       * @description Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+       */
+      default_ttl_s?: number;
+      /** @description JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported */
+      payload_schema?: Record<string, never>;
+      /**
+       * @description How the payload schema is applied at ingestion (default: `enforce`)
+       * @enum {string}
+       */
+      payload_schema_mode?: 'enforce' | 'warn' | 'off';
+      resource_type: string;
+      service: string;
+      verb: string;
+    };
+    EventTypePut: {
+      /** Format: uuid */
+      application_id: string;
+      /**
+       * Format: int32
+       * @description Time to live in seconds of the events of this type that do not give an expiry date; their pending deliveries are cancelled once it is over
+       */
+      default_ttl_s?: number;
+      /** @description JSON Schema the payloads of the `application/json` events of this type must match; remote references are not supported */
+      payload_schema?: Record<string, never>;
+      /**
+       * @description How the payload schema is applied at ingestion (default: `enforce`)
+       * @enum {string}
+       */
+      payload_schema_mode?: 'enforce' | 'warn' | 'off';
+    };
+    /** @description A version of the payload contract of an event type */
+    EventTypeVersion: {
+      /** Format: date-time */
+      created_at: string;
+      /** Format: date-time */
+      deprecated_at?: string;
+      /** @description JSON Schema the payloads of the events declaring this version must match, instead of the one of the event type */
+      payload_schema?: Record<string, never>;
+      /**
+       * Format: date-time
+       * @description Date from which the events declaring this version are refused
+       */
+      sunset_at?: string;
+      /** Format: int32 */
+      version: number;
+    };
+    EventTypeVersionDeprecate: {
+      /** Format: uuid */
+      application_id: string;
+      /**
+       * Format: date-time
+       * @description Date from which the events declaring this version are refused; none keeps accepting them
+       */
+      sunset_at?: string;
+      /** Format: int32 */
+      version: number;
+    };
+    EventTypeVersionPost: {
+      /** Format: uuid */
+      application_id: string;
+      /** @description JSON Schema the payloads of the `application/json` events declaring this version must match, instead of the one of the event type; remote references are not supported */
+      payload_schema?: Record<string, never>;
+      /** Format: int32 */
+      version: number;
+    };
+    EventWithPayload: {
+      /**
+       * Format: date-time
+       * @description Date the scheduled delivery of the event was cancelled
+       */
+      cancelled_at?: string;
+      /**
+       * Format: date-time
+       * @description Date from which the event is delivered, when it was scheduled for a later delivery
+       */
+      deliver_at?: string;
+      /** Format: uuid */
+      event_id: string;
+      event_type_name: string;
+      /** Format: int32 */
+      event_type_version?: number;
+      /**
+       * Format: date-time
+       * @description Date after which the event is no longer delivered
+       */
+      expires_at?: string;
+      ip: string;
+      labels: Record<string, never>;
+      metadata?: Record<string, never>;
+      /** Format: date-time */
+      occurred_at: string;
+      payload: string;
+      payload_content_type: string;
+      /** Format: date-time */
+      received_at: string;
+    };
+    /** @description Events to be ingested into Hook0 in one request. */
+    EventsBatchPost: {
+      /**
+       * Format: uuid
+       * @description UUID of the application the events belong to. Events of the batch that carry another application ID are not ingested.
+       */
+      application_id: string;
+      /** @description Events to ingest. Length: 1-100 events; the whole request body is also subject to the usual request size limit. */
+      events: {
+        /**
+         * Format: uuid
+         * @description UUID of the application this event belongs to.
+         */
+        application_id: string;
+        /**
+         * Format: date-time
+         * @description Optional date from which the event is delivered to its subscriptions; it is delivered right away when omitted or in the past. It can be at most as far in the future as the events retention of the application.
+         */
+        deliver_at?: string;
+        /**
+         * Format: uuid
+         * @description Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
+         */
+        event_id?: string;
+        /** @description The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters. */
+        event_type: string;
+        /**
+         * Format: int32
+         * @description Optional version of the event type the payload follows. Subscriptions pinned to another version of the event type do not receive the event.
+         */
+        event_type_version?: number;
+        /**
+         * Format: date-time
+         * @description Optional date after which the event is no longer delivered: its deliveries that are still pending or waiting for a retry are cancelled. Defaults to the default time to live of its event type, counted from its delivery.
+         */
+        expires_at?: string;
+        /** @description Labels for event filtering and routing to subscriptions. */
+        labels: {
+          [key: string]: string;
+        };
+        /** @description Optional metadata key-value pairs associated with the event. */
+        metadata?: {
+          [key: string]: string;
+        };
+        /**
+         * Format: date-time
+         * @description Timestamp when the event occurred.
+         */
+        occurred_at: string;
+        /** @description The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded). */
+        payload: string;
+        /** @description Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters. */
+        payload_content_type: string;
+      }[];
+    };
+    EventsPerDayEntry: {
+      /** Format: int32 */
+      amount: number;
+      /** Format: uuid */
+      application_id: string;
+      application_name: string;
+      /** Format: date */
+      date: string;
+      is_provisional: boolean;
+    };
+    HealthCheck: {
+      database: boolean;
+      /** Format: int64 */
+      database_duration_ms: number;
+      object_storage?: boolean;
+      /** Format: int64 */
+      object_storage_duration_ms?: number;
+      pulsar?: boolean;
+      /** Format: int64 */
+      pulsar_duration_ms?: number;
+      /** Format: int64 */
+      total_duration_ms: number;
+    };
+    IngestedEvent: {
+      /** Format: uuid */
+      application_id: string;
+      /** @description Present when the event was ingested with a deprecated version of its event type */
+      deprecation?: {
+        /** Format: date-time */
+        deprecated_at: string;
+        /** Format: int32 */
+        event_type_version: number;
+        /** Format: date-time */
+        sunset_at?: string;
+      };
+      /** Format: uuid */
+      event_id: string;
+      /** Format: date-time */
+      received_at: string;
+    };
+    IngestedEventsBatch: {
+      /** Format: uuid */
+      application_id: string;
+      /** @description One outcome per submitted event, in the order the events were submitted */
+      events: ({
+        /** @description Present when the event was ingested with a deprecated version of its event type */
+        deprecation?: {
+          /** Format: date-time */
+          deprecated_at: string;
+          /** Format: int32 */
+          event_type_version: number;
+          /** Format: date-time */
+          sunset_at?: string;
+        };
+        /**
+         * Format: uuid
+         * @description ID of the ingested event, or the one the event was submitted with when it was not ingested
+         */
+        event_id?: string;
+        /** @description Present when the event was not ingested */
+        problem?: {
+          detail: string;
+          /**
+           * @description Identifier of the problem that occurred, stable across releases
+           * @enum {string}
+           */
+          id: 'OrganizationNameMissing' | 'UserAlreadyExist' | 'RegistrationDisabled' | 'PasswordTooShort' | 'PasswordTooLong' | 'PasswordSimilarToEmail' | 'PasswordSimilarToName' | 'PasswordTooCommon' | 'PasswordNotDiverseEnough' | 'OrganizationIsNotEmpty' | 'InvitedUserDoesNotExist' | 'InvitedUserAlreadyInOrganization' | 'ApplicationNameMissing' | 'InvalidRole' | 'EventTypeAlreadyExist' | 'EventTypeDoesNotExist' | 'EventTypeVersionAlreadyExist' | 'EventTypeVersionDoesNotExist' | 'EventTypeVersionSunset' | 'UnauthorizedWorkers' | 'EventAlreadyIngested' | 'EventInvalidPayloadContentType' | 'EventInvalidBase64Payload' | 'EventInvalidJsonPayload' | 'EventPayloadSchemaViolation' | 'EventDeliveryBeyondRetention' | 'EventNotScheduled' | 'LabelsAmbiguity' | 'InvalidDateRange' | 'RequestAttemptNotFailed' | 'RequestAttemptRetryPending' | 'SubscriptionDisabled' | 'SubscriptionNotInbox' | 'SecretRotationInProgress' | 'BulkRetryAlreadyRunning' | 'BulkRetryFinished' | 'RetryPolicyOverLimit' | 'ClientCertificatesDisabled' | 'InvalidClientCertificate' | 'SigningKeysDisabled' | 'AuthNoAuthorizationHeader' | 'AuthInvalidAuthorizationHeader' | 'AuthApplicationSecretLookupError' | 'AuthInvalidApplicationSecret' | 'AuthBiscuitLookupError' | 'AuthInvalidBiscuit' | 'AuthFailedLogin' | 'AuthEmailNotVerified' | 'AuthEmailAlreadyVerified' | 'AuthFailedRefresh' | 'AuthEmailExpired' | 'TooManyMembersPerOrganization' | 'TooManyApplicationsPerOrganization' | 'TooManyEventsToday' | 'TooManySubscriptionsPerApplication' | 'TooManyEventTypesPerApplication' | 'JsonPayload' | 'Validation' | 'NotFound' | 'InternalServerError' | 'Forbidden' | 'RateLimited' | 'ServiceUnavailable';
+          /** Format: int32 */
+          status: number;
+          title: string;
+          /**
+           * @description Documentation page of this problem, which is also what distinguishes one problem type from
+           * another as RFC 7807 asks. Prefer matching on `id`: it says the same thing without parsing
+           * a URL.
+           */
+          type: string;
+          /**
+           * @description Which submitted values were rejected and why, keyed by the name of the field they were
+           * submitted under. Carried by validation failures (`Validation`, status 422) and `null` for
+           * every other problem, as nothing else has a field to point at.
+           *
+           * The value of a key is either the list of failures on that field — each one a `code`, an
+           * optional `message` and the `params` the check was run with — or the same structure again
+           * for a nested object, or a map from index to that structure for a list. Nesting follows the
+           * shape of the submitted body and is therefore left free-form rather than modelled.
+           */
+          validation?: Record<string, never>;
+        };
+        /**
+         * Format: date-time
+         * @description Present when the event was ingested
+         */
+        received_at?: string;
+        /** @enum {string} */
+        status: 'ingested' | 'failed';
+      })[];
+    };
+    /** @description Events leased from the inbox of a subscription */
+    Inbox: {
+      messages: {
+        /** Format: uuid */
+        event_id: string;
+        event_type_name: string;
+        labels: Record<string, never>;
+        /**
+         * Format: date-time
+         * @description When the event is offered again if it was neither acknowledged nor rejected
+         */
+        leased_until: string;
+        /** Format: date-time */
+        occurred_at: string;
+        /** @description Base64-encoded */
+        payload: string;
+        payload_content_type: string;
+        /**
+         * Format: uuid
+         * @description Identifies this lease of the request attempt; a later lease of the same attempt gets another one
+         */
+        receipt: string;
+        /** Format: date-time */
+        received_at: string;
+        /** Format: uuid */
+        request_attempt_id: string;
+        /** Format: int32 */
+        retry_count: number;
+      }[];
+      /** @description Pass it as `cursor` to the next pull to continue after the last message of this one */
+      next_cursor?: string;
+    };
+    InboxAck: {
+      /** Format: uuid */
+      application_id: string;
+      /** @description Receipts of the leased events */
+      receipts: string[];
+    };
+    InboxNack: {
+      /** Format: uuid */
+      application_id: string;
+      /** @description Receipts of the leased events */
+      receipts: string[];
+      /** @description Whether the events are offered again, as new request attempts (true by default); they are not beyond the maximum number of retries of the subscription, or of this instance if it has none */
+      retry?: boolean;
+      /**
+       * Format: int32
+       * @description Delay in seconds before the events are offered again (0 by default)
+       */
+      retry_in_s?: number;
+    };
+    /** @description Outcome of acknowledging or rejecting leased events */
+    InboxSettlement: {
+      /** @description Receipts of the events that were settled */
+      settled: string[];
+      /** @description Receipts that settled nothing: their lease expired, or the event was already settled */
+      stale: string[];
+    };
+    InstanceConfig: {
+      application_secret_compatibility: boolean;
+      auto_db_migration: boolean;
+      biscuit_public_key: string;
+      cloudflare_turnstile_site_key?: string;
+      formbricks?: {
+        api_host: string;
+        environment_id: string;
+      };
+      matomo?: {
+        /** Format: int32 */
+        site_id: number;
+        url: string;
+      };
+      /** Format: int32 */
+      password_minimum_length: number;
+      quota_enforcement: boolean;
+      registration_disabled: boolean;
+      support_email_address: string;
+    };
+    /** @description Public keys of an application, as a JSON Web Key Set (RFC 7517) */
+    JsonWebKeySet: {
+      keys: {
+        /** @description Always `EdDSA` */
+        alg: string;
+        /** @description Always `Ed25519` */
+        crv: string;
+        /** @description ID of the signing key, which signatures name in their `k` part */
+        kid: string;
+        /** @description Always `OKP` */
+        kty: string;
+        /** @description Always `sig` */
+        use: string;
+        /** @description Public key, encoded in base64url without padding */
+        x: string;
+      }[];
+    };
+    LoginPost: {
+      email: string;
+      password: string;
+    };
+    LoginResponse: {
+      access_token: string;
+      /** Format: date-time */
+      access_token_expiration: string;
+      email: string;
+      first_name: string;
+      last_name: string;
+      refresh_token: string;
+      /** Format: date-time */
+      refresh_token_expiration: string;
+      /** Format: uuid */
+      user_id: string;
+    };
+    /**
+     * @description OAuth2 client credentials a subscription obtains an access token with, which is sent to its
+     *      target as a bearer token. The client secret is never returned.
+     */
+    OAuth2ClientCredentials: {
+      client_id: string;
+      /** Format: date-time */
+      created_at: string;
+      scopes: string[];
+      /** Format: uuid */
+      subscription_id: string;
+      token_url: string;
+      /** Format: date-time */
+      updated_at: string;
+    };
+    OAuth2ClientCredentialsPut: {
+      client_id: string;
+      client_secret: string;
+      /** @description Scopes to request; none by default */
+      scopes?: string[];
+      /** @description Token endpoint of the authorization server */
+      token_url: string;
+    };
+    Organization: {
+      name: string;
+      /** Format: uuid */
+      organization_id: string;
+      plan?: {
+        label: string;
+        name: string;
+      };
+      role: string;
+    };
+    OrganizationEditRole: {
+      role: string;
+      /** Format: uuid */
+      user_id: string;
+    };
+    OrganizationInfo: {
+      consumption: {
+        /** Format: int64 */
+        applications?: number;
+        /** Format: int64 */
+        events_per_day?: number;
+        /** Format: int64 */
+        members?: number;
+      };
+      name: string;
+      onboarding_steps: {
+        /** @enum {string} */
+        application: 'ToDo' | 'Done';
+        /** @enum {string} */
+        event: 'ToDo' | 'Done';
+        /** @enum {string} */
+        event_type: 'ToDo' | 'Done';
+        /** @enum {string} */
+        subscription: 'ToDo' | 'Done';
+      };
+      /** Format: uuid */
+      organization_id: string;
+      plan?: {
+        label: string;
+        name: string;
+      };
+      quotas: {
+        /** Format: int32 */
+        applications_per_organization_limit: number;
+        /** Format: int32 */
+        days_of_events_retention_limit: number;
+        /** Format: int32 */
+        events_per_day_limit: number;
+        /** Format: int32 */
+        members_per_organization_limit: number;
+      };
+      users: {
+        email: string;
+        first_name: string;
+        last_name: string;
+        role: string;
+        /** Format: uuid */
+        user_id: string;
+      }[];
+    };
+    OrganizationPost: {
+      name: string;
+    };
+    Problem: {
+      detail: string;
+      id: string;
+      /** Format: int32 */
+      status: number;
+      title: string;
+    };
+    PurgeDeadLetters: {
+      /** Format: uuid */
+      application_id: string;
+      /**
+       * Format: uuid
+       * @description Only purge the dead letters of this subscription
+       */
+      subscription_id?: string;
+    };
+    PurgedDeadLetters: {
+      /** Format: int64 */
+      purged_count: number;
+    };
+    QuotasResponse: {
+      enabled: boolean;
+      limits: {
+        /** Format: int32 */
+        global_applications_per_organization_limit: number;
+        /** Format: int32 */
+        global_days_of_events_retention_limit: number;
+        /** Format: int32 */
+        global_event_types_per_application_limit: number;
+        /** Format: int32 */
+        global_events_per_day_limit: number;
+        /** Format: int32 */
+        global_members_per_organization_limit: number;
+        /** Format: int32 */
+        global_subscriptions_per_application_limit: number;
+      };
+    };
+    RedeliverDeadLetter: {
+      /** Format: uuid */
+      application_id: string;
+    };
+    RedeliverDeadLetters: {
+      /** Format: uuid */
+      application_id: string;
+      /**
+       * Format: uuid
+       * @description Only redeliver the dead letters of this subscription
+       */
+      subscription_id?: string;
+    };
+    /** @description Dead letter that was redelivered, and the request attempt that redelivers it */
+    RedeliveredDeadLetter: {
+      /** Format: uuid */
+      dead_letter_id: string;
+      /** Format: uuid */
+      request_attempt_id: string;
+    };
+    Registration: {
+      /** Format: uuid */
+      organization_id: string;
+      /** Format: uuid */
+      user_id: string;
+    };
+    RegistrationPost: {
+      email: string;
+      first_name: string;
+      last_name: string;
+      password: string;
+      turnstile_token?: string;
+    };
+    ReplayEvent: {
+      /** Format: uuid */
+      application_id: string;
+    };
+    RequestAttempt: {
+      /** Format: date-time */
+      created_at: string;
+      /**
+       * Format: uuid
+       * @description Dead letter the request attempt became, when it was the last attempt of a delivery an output worker gave up on
+       */
+      dead_letter_id?: string;
+      /** Format: date-time */
+      delay_until?: string;
+      /**
+       * Format: uuid
+       * @description Batch the request attempt was delivered in, shared with the other request attempts of the batch and their response
+       */
+      delivery_batch_id?: string;
+      event: {
+        /** Format: uuid */
+        event_id: string;
+        event_type_name: string;
+      };
+      /** Format: uuid */
+      event_id: string;
+      /** Format: date-time */
+      failed_at?: string;
+      /** Format: int32 */
+      http_response_status?: number;
+      /** Format: date-time */
+      picked_at?: string;
+      /** Format: uuid */
+      request_attempt_id: string;
+      /** Format: uuid */
+      response_id?: string;
+      /** Format: int32 */
+      retry_count: number;
+      /** @description Status of a request attempt. The 'type' field indicates the status variant. - waiting: {type, since, until} - Scheduled for future delivery - pending: {type, since} - Ready to be processed - in_progress: {type, since} - Currently being delivered - successful: {type, at, full_processing_ms, rule_outcome?} - Delivered successfully - failed: {type, at, full_processing_ms, rule_outcome?} - Delivery failed - expired: {type, at} - Cancelled because its event expired before it was delivered - cancelled: {type, at} - Cancelled along with its scheduled event before it was delivered */
+      status: {
+        /**
+         * Format: date-time
+         * @description Timestamp when completed (present in successful, failed, expired, cancelled)
+         */
+        at?: string;
+        /**
+         * Format: int64
+         * @description Total processing time in milliseconds (present in successful, failed)
+         */
+        full_processing_ms?: number;
+        /**
+         * @description Outcome given by a response rule of the subscription, when one matched the response (present in successful, failed). One of: success, retryable, permanent_failure, disable_subscription
+         * @enum {string}
+         */
+        rule_outcome?: 'success' | 'retryable' | 'permanent_failure' | 'disable_subscription';
+        /**
+         * Format: date-time
+         * @description Timestamp when the status started (present in waiting, pending, in_progress)
+         */
+        since?: string;
+        /**
+         * @description Status type discriminator. One of: waiting, pending, in_progress, successful, failed, expired, cancelled
+         * @enum {string}
+         */
+        type: 'waiting' | 'pending' | 'in_progress' | 'successful' | 'failed' | 'expired' | 'cancelled';
+        /**
+         * Format: date-time
+         * @description Timestamp until which waiting (only present in waiting status)
+         */
+        until?: string;
+      };
+      subscription: {
+        description?: string;
+        /** Format: uuid */
+        subscription_id: string;
+      };
+      /** Format: date-time */
+      succeeded_at?: string;
+    };
+    ResetPasswordPost: {
+      new_password: string;
+      token: string;
+    };
+    Response: {
+      body?: string;
+      /** Format: int32 */
+      elapsed_time_ms?: number;
+      headers?: {
+        [key: string]: string;
+      };
+      /** Format: int32 */
+      http_code?: number;
+      response_error_name?: string;
+      /** Format: uuid */
+      response_id: string;
+    };
+    RetryRequestAttempt: {
+      /** Format: uuid */
+      application_id: string;
+    };
+    Revoke: {
+      /** Format: uuid */
+      user_id: string;
+    };
+    /**
+     * @description Rotation of the secret of a subscription: until `previous_secret_expires_at`, deliveries are
+     *      signed with both the new secret and the one it replaced
+     */
+    SecretRotation: {
+      /**
+       * Format: uuid
+       * @description The secret the new one replaced
+       */
+      previous_secret: string;
+      /** Format: date-time */
+      previous_secret_expires_at: string;
+      /**
+       * Format: uuid
+       * @description The new secret of the subscription
+       */
+      secret: string;
+      /** Format: uuid */
+      subscription_id: string;
+    };
+    SecretRotationPost: {
+      /**
+       * Format: int32
+       * @description How long the previous secret stays valid, in seconds (one day by default)
+       */
+      overlap_seconds?: number;
+    };
+    ServiceToken: {
+      biscuit: string;
+      /** Format: date-time */
+      created_at: string;
+      name: string;
+      /** Format: uuid */
+      token_id: string;
+    };
+    ServiceTokenPost: {
+      name: string;
+      /** Format: uuid */
+      organization_id: string;
+    };
+    /** @description Ed25519 key an application signs its deliveries with; its private key is never returned */
+    SigningKey: {
+      /** Format: uuid */
+      application_id: string;
+      /** Format: date-time */
+      created_at: string;
+      /** @description Whether deliveries are signed with this key, which only the newest key of the application is */
+      is_current: boolean;
+      /** @description Public key, encoded in base64url without padding like the `x` member of its JSON Web Key */
+      public_key: string;
+      /**
+       * Format: uuid
+       * @description Identifies the key in the `k` part of signatures and as the `kid` of its JSON Web Key
+       */
+      signing_key_id: string;
+    };
+    SigningKeyPost: {
+      /** Format: uuid */
+      application_id: string;
+    };
+    Subscription: {
+      /** Format: uuid */
+      application_id: string;
+      /**
+       * Format: date-time
+       * @description When the circuit breaker or a response rule last disabled the subscription; cleared when it is enabled again
+       */
+      auto_disabled_at?: string;
+      /** @description Why the circuit breaker or a response rule last disabled the subscription */
+      auto_disabled_reason?: string;
+      /** @description Deliver the events of the subscription in batches: one HTTP request whose body is a JSON array of the events, sent once it holds `max_events` events or once its oldest event has waited `max_wait_s` seconds */
+      batching?: {
+        /**
+         * Format: int32
+         * @description Largest number of events in a batch
+         */
+        max_events: number;
+        /**
+         * Format: int32
+         * @description Longest time in seconds an event waits for its batch to fill up
+         */
+        max_wait_s: number;
+      };
+      /** @description Disables the subscription once its deliveries keep failing, whichever threshold is crossed first; at least one threshold must be set */
+      circuit_breaker?: {
+        /**
+         * Format: int32
+         * @description Number of failed deliveries in a row after which the subscription is disabled
+         */
+        max_consecutive_failures?: number;
+        /**
+         * Format: int32
+         * @description Number of hours without a single successful delivery after which the subscription is disabled
+         */
+        max_failing_hours?: number;
+      };
+      /** Format: date-time */
+      created_at: string;
+      dedicated_workers: string[];
+      /** @description Limits on how fast deliveries of the subscription start; deliveries over them wait for their turn, which does not count as a failure; at least one limit must be set */
+      delivery_limits?: {
+        /**
+         * Format: int32
+         * @description Number of deliveries that can be in flight at once
+         */
+        max_in_flight?: number;
+        /**
+         * Format: int32
+         * @description Number of deliveries that can start in a second
+         */
+        max_per_second?: number;
+        /** @description Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone */
+        per_host?: boolean;
+      };
+      description?: string;
+      /** @description Versions the subscription is pinned to, by event type */
+      event_type_versions: {
+        [key: string]: number;
+      };
+      event_types: string[];
+      /** @description An object with exactly one of: `and` or `or` (a list of filters), `not` (a filter), `label` (name of an event label) or `field` (JSON pointer to a value of a JSON payload); `label` and `field` go along with exactly one operator among `eq`, `in`, `prefix`, `gt`, `gte`, `lt`, `lte` and `exists` */
+      filter?: Record<string, never>;
+      is_enabled: boolean;
+      /** @description _Kept for backward compatibility, you should use `labels`_ */
+      label_key: string;
+      /** @description _Kept for backward compatibility, you should use `labels`_ */
+      label_value: string;
+      labels: {
+        [key: string]: string;
+      };
+      metadata: {
+        [key: string]: string;
+      };
+      /** @description Where the key of an event comes from, to deliver the events that share a key one at a time and in the order they were received; exactly one of `label` or `field` must be set */
+      ordering_key?: {
+        /** @description JSON pointer to the value holding the key in a JSON payload, for example `/customer/id` */
+        field?: string;
+        /** @description Name of the event label holding the key */
+        label?: string;
+      };
+      /** @description Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a payload that cannot be transformed fails without being retried */
+      payload_transform?: {
+        /** @description Properties added at the top level of the result, overriding properties of the same name (the result must then be an object) */
+        constants?: {
+          [key: string]: Record<string, never>;
+        };
+        /** @description Name of a property the result is wrapped in */
+        envelope?: string;
+        /** @description Values to copy from the payload into a new object; when omitted, the whole payload is kept */
+        fields?: {
+          /** @description JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped) */
+          from: string;
+          /** @description JSON pointer where the value is written in the result, for example `/id`; defaults to `from` */
+          to?: string;
+        }[];
+      };
+      response_rules?: ({
+        /** @description Text the response body must contain */
+        body_contains?: string;
+        /** @description Header the response must have */
+        header?: {
+          /** @description Name of the header, regardless of case */
+          name: string;
+          /** @description Value the header must have; when omitted, any value matches */
+          value?: string;
+        };
+        /**
+         * @description What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+         * @enum {string}
+         */
+        outcome: 'success' | 'retryable' | 'permanent_failure' | 'disable_subscription';
+        /** @description Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx` */
+        status: string[];
+      })[];
+      /** @description Retry schedule of the subscription, replacing the one of the instance within its limits; every field left out keeps the value of the instance */
+      retry_policy?: {
+        /** @description Delays in seconds to wait before each retry; the last one is used again for the following retries */
+        backoff_steps_s?: number[];
+        /**
+         * Format: int32
+         * @description Maximum number of retries after a failed delivery (0 disables retries)
+         */
+        max_retries?: number;
+        /**
+         * Format: int32
+         * @description Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled
+         */
+        max_window_s?: number;
+      };
+      /** Format: uuid */
+      secret: string;
+      /** @enum {string} */
+      signature_scheme: 'hook0' | 'standard_webhooks';
+      /** Format: uuid */
+      subscription_id: string;
+      target: {
+        /** @description `sqs`: access key ID requests are signed with */
+        access_key_id?: string;
+        /** @description `amqp`: exchange messages are published to; empty for the default exchange */
+        exchange?: string;
+        /** @description `http`: headers sent with every request */
+        headers?: Record<string, never>;
+        /** @description `http`: HTTP method of the requests */
+        method?: string;
+        /** @description `amqp`: password to log in to the broker with; write-only */
+        password?: string;
+        /**
+         * Format: url
+         * @description `sqs`: URL of the queue
+         */
+        queue_url?: string;
+        /** @description `sqs`: region requests are signed for */
+        region?: string;
+        /** @description `amqp`: routing key messages are published with */
+        routing_key?: string;
+        /** @description `sqs`: secret access key requests are signed with; write-only */
+        secret_access_key?: string;
+        /**
+         * @description Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+         * @example http
+         * @enum {string}
+         */
+        type: 'http' | 'amqp' | 'sqs' | 'inbox';
+        /**
+         * Format: url
+         * @description `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials
+         */
+        url?: string;
+        /** @description `amqp`: user name to log in to the broker with */
+        username?: string;
+      };
+      /** Format: date-time */
+      updated_at: string;
+    };
+    SubscriptionPost: {
+      /** Format: uuid */
+      application_id: string;
+      /** @description Deliver the events in batches, as one HTTP request with a JSON array body, rather than one by one */
+      batching?: {
+        /**
+         * Format: int32
+         * @description Largest number of events in a batch
+         */
+        max_events: number;
+        /**
+         * Format: int32
+         * @description Longest time in seconds an event waits for its batch to fill up
+         */
+        max_wait_s: number;
+      };
+      /** @description Disable the subscription once its deliveries keep failing; organization editors are notified by email */
+      circuit_breaker?: {
+        /**
+         * Format: int32
+         * @description Number of failed deliveries in a row after which the subscription is disabled
+         */
+        max_consecutive_failures?: number;
+        /**
+         * Format: int32
+         * @description Number of hours without a single successful delivery after which the subscription is disabled
+         */
+        max_failing_hours?: number;
+      };
+      dedicated_workers?: string[];
+      /** @description Limit how fast deliveries start, for this subscription or for its host */
+      delivery_limits?: {
+        /**
+         * Format: int32
+         * @description Number of deliveries that can be in flight at once
+         */
+        max_in_flight?: number;
+        /**
+         * Format: int32
+         * @description Number of deliveries that can start in a second
+         */
+        max_per_second?: number;
+        /** @description Share the limits with every subscription of the application that delivers to the same host and sets this too, rather than counting the deliveries of this subscription alone */
+        per_host?: boolean;
+      };
+      description?: string;
+      /** @description Only receive the events of these event types that declare this version (or no version at all) */
+      event_type_versions?: {
+        [key: string]: number;
+      };
+      event_types: string[];
+      /** @description Only deliver the events of the subscribed types that match this expression over labels and JSON payload fields */
+      filter?: Record<string, never>;
+      is_enabled: boolean;
+      /** @description _Kept for backward compatibility, you should use `labels`_ */
+      label_key?: string;
+      /** @description _Kept for backward compatibility, you should use `labels`_ */
+      label_value?: string;
+      labels?: {
+        [key: string]: string;
+      };
+      metadata?: {
+        [key: string]: string;
+      };
+      /** @description Deliver the events that share a key one at a time, in the order they were received: the next one waits until the previous one succeeded or ran out of retries */
+      ordering_key?: {
+        /** @description JSON pointer to the value holding the key in a JSON payload, for example `/customer/id` */
+        field?: string;
+        /** @description Name of the event label holding the key */
+        label?: string;
+      };
+      /** @description Declarative reshaping of an `application/json` event payload, applied by the worker before the request is signed and sent; operations are applied in order (`fields`, `envelope`, `constants`) and a payload that cannot be transformed fails without being retried */
+      payload_transform?: {
+        /** @description Properties added at the top level of the result, overriding properties of the same name (the result must then be an object) */
+        constants?: {
+          [key: string]: Record<string, never>;
+        };
+        /** @description Name of a property the result is wrapped in */
+        envelope?: string;
+        /** @description Values to copy from the payload into a new object; when omitted, the whole payload is kept */
+        fields?: {
+          /** @description JSON pointer to a value of the payload, for example `/user/id` (values that are absent from a payload are skipped) */
+          from: string;
+          /** @description JSON pointer where the value is written in the result, for example `/id`; defaults to `from` */
+          to?: string;
+        }[];
+      };
+      /** @description Decide the outcome of HTTP deliveries from their response, for example to stop retrying on a `410` or to fail on a `200` whose body reports an error; the first rule that matches wins, and a response no rule matches keeps the default behaviour (a `2xx` is a success, anything else is retried) */
+      response_rules?: ({
+        /** @description Text the response body must contain */
+        body_contains?: string;
+        /** @description Header the response must have */
+        header?: {
+          /** @description Name of the header, regardless of case */
+          name: string;
+          /** @description Value the header must have; when omitted, any value matches */
+          value?: string;
+        };
+        /**
+         * @description What the delivery becomes when the rule matches: `success`, `retryable`, `permanent_failure` (not retried) or `disable_subscription` (not retried, and the subscription is disabled)
+         * @enum {string}
+         */
+        outcome: 'success' | 'retryable' | 'permanent_failure' | 'disable_subscription';
+        /** @description Status codes the rule applies to: exact codes such as `410`, or classes such as `4xx` */
+        status: string[];
+      })[];
+      /** @description Retry failed deliveries on this schedule rather than the instance's one */
+      retry_policy?: {
+        /** @description Delays in seconds to wait before each retry; the last one is used again for the following retries */
+        backoff_steps_s?: number[];
+        /**
+         * Format: int32
+         * @description Maximum number of retries after a failed delivery (0 disables retries)
+         */
+        max_retries?: number;
+        /**
+         * Format: int32
+         * @description Maximum duration in seconds the retries can span; a retry that would not fit in it is not scheduled
+         */
+        max_window_s?: number;
+      };
+      /**
+       * @description How deliveries are signed (`hook0` by default); `standard_webhooks` follows the Standard Webhooks specification, whose `whsec_` secret is `whsec_` followed by the base64 encoding of the subscription secret
+       * @enum {string}
+       */
+      signature_scheme?: 'hook0' | 'standard_webhooks';
+      target: {
+        /** @description `sqs`: access key ID requests are signed with */
+        access_key_id?: string;
+        /** @description `amqp`: exchange messages are published to; empty for the default exchange */
+        exchange?: string;
+        /** @description `http`: headers sent with every request */
+        headers?: Record<string, never>;
+        /** @description `http`: HTTP method of the requests */
+        method?: string;
+        /** @description `amqp`: password to log in to the broker with; write-only */
+        password?: string;
+        /**
+         * Format: url
+         * @description `sqs`: URL of the queue
+         */
+        queue_url?: string;
+        /** @description `sqs`: region requests are signed for */
+        region?: string;
+        /** @description `amqp`: routing key messages are published with */
+        routing_key?: string;
+        /** @description `sqs`: secret access key requests are signed with; write-only */
+        secret_access_key?: string;
+        /**
+         * @description Kind of target: `http`, `amqp`, `sqs` or `inbox` (events are pulled through the API); the other properties depend on it
+         * @example http
+         * @enum {string}
+         */
+        type: 'http' | 'amqp' | 'sqs' | 'inbox';
+        /**
+         * Format: url
+         * @description `http`: URL of the endpoint; `amqp`: URL of the broker (`amqp` or `amqps` scheme), without credentials
+         */
+        url?: string;
+        /** @description `amqp`: user name to log in to the broker with */
+        username?: string;
+      };
+    };
+    UserInvitation: {
+      email: string;
+      role: string;
+    };
+  };
+  responses: never;
+  parameters: never;
+  requestBodies: never;
+  headers: never;
+  pathItems: never;
+}
+export type $defs = Record<string, never>;
+export interface operations {
+  'applicationSecrets.read': {
+    parameters: {
+      query: {
+        application_id: string;
+      };
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['ApplicationSecret'][];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'applicationSecrets.create': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['ApplicationSecretPost'];
+      };
+    };
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['ApplicationSecret'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'applicationSecrets.update': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path: {
+        application_secret_token: string;
+      };
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['ApplicationSecretPost'];
+      };
+    };
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['ApplicationSecret'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'applicationSecrets.delete': {
+    parameters: {
+      query: {
+        application_id: string;
+      };
+      header?: never;
+      path: {
+        application_secret_token: string;
+      };
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description No Content */
+      204: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'applications.list': {
+    parameters: {
+      query: {
+        organization_id: string;
+      };
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['Application'][];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'applications.create': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['ApplicationPost'];
+      };
+    };
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['Application'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'applications.get': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path: {
+        application_id: string;
+      };
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['ApplicationInfo'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'applications.update': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path: {
+        application_id: string;
+      };
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['ApplicationPost'];
+      };
+    };
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['Application'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'applications.delete': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path: {
+        application_id: string;
+      };
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description No Content */
+      204: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'auth.begin_reset_password': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['BeginResetPasswordPost'];
+      };
+    };
+    responses: {
+      /** @description No Content */
+      204: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'auth.login': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['LoginPost'];
+      };
+    };
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['LoginResponse'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'auth.logout': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description No Content */
+      204: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'auth.change_password': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['ChangePasswordPost'];
+      };
+    };
+    responses: {
+      /** @description No Content */
+      204: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'auth.refresh': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['LoginResponse'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'auth.reset_password': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['ResetPasswordPost'];
+      };
+    };
+    responses: {
+      /** @description No Content */
+      204: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'auth.verify_email': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['EmailVerificationPost'];
+      };
+    };
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['LoginResponse'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'bulkRetries.list': {
+    parameters: {
+      query: {
+        application_id: string;
+      };
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['BulkRetry'][];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'bulkRetries.create': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['BulkRetryPost'];
+      };
+    };
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['BulkRetry'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'bulkRetries.dry_run': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['BulkRetryPost'];
+      };
+    };
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['BulkRetryDryRun'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'bulkRetries.get': {
+    parameters: {
+      query: {
+        application_id: string;
+      };
+      header?: never;
+      path: {
+        bulk_retry_id: string;
+      };
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['BulkRetry'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'bulkRetries.cancel': {
+    parameters: {
+      query: {
+        application_id: string;
+      };
+      header?: never;
+      path: {
+        bulk_retry_id: string;
+      };
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['BulkRetry'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'deadLetters.list': {
+    parameters: {
+      query: {
+        application_id: string;
+        pagination_cursor?: string;
+        subscription_id?: string;
+      };
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['DeadLetter'][];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'deadLetters.purge': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['PurgeDeadLetters'];
+      };
+    };
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['PurgedDeadLetters'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'deadLetters.redeliverMany': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
+    };
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['RedeliverDeadLetters'];
+      };
+    };
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['RedeliveredDeadLetter'][];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'deadLetters.get': {
+    parameters: {
+      query: {
+        application_id: string;
+      };
+      header?: never;
+      path: {
+        dead_letter_id: string;
+      };
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['DeadLetterDetails'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'deadLetters.delete': {
+    parameters: {
+      query: {
+        application_id: string;
+      };
+      header?: never;
+      path: {
+        dead_letter_id: string;
+      };
+      cookie?: never;
+    };
+    requestBody?: never;
+    responses: {
+      /** @description No Content */
+      204: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+    };
+  };
+  'deadLetters.redeliver': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path: {
+        dead_letter_id: string;
+      };
+      cookie?: never;
     };
-    /** @description Event to be ingested into Hook0. */
-    EventPost: {
-      /**
-       * Format: uuid
-       * @description UUID of the application this event belongs to.
-       */
-      application_id: string;
-      /**
-       * Format: uuid
-       * @description Optional unique identifier for this event (client-generated UUID). If not provided, a UUIDv7 will be generated by the server.
-       */
-      event_id?: string;
-      /** @description The type of event (e.g., 'user.created', 'order.completed'). Length: 1-200 characters. */
-      event_type: string;
-      /** @description Labels for event filtering and routing to subscriptions. */
-      labels: {
-        [key: string]: string;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['RedeliverDeadLetter'];
       };
-      /** @description Optional metadata key-value pairs associated with the event. */
-      metadata?: {
-        [key: string]: string;
+    };
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['RedeliveredDeadLetter'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      /**
-       * Format: date-time
-       * @description Timestamp when the event occurred.
-       */
-      occurred_at: string;
-      /** @description The event payload. For binary content, use base64 encoding. Max length: 699050 characters (512 KiB base64-encoded). */
-      payload: string;
-      /** @description Content type of the payload. Valid values: text/plain, application/json, application/octet-stream+base64. Length: 1-100 characters. */
-      payload_content_type: string;
     };
-    EventType: {
-      event_type_name: string;
-      resource_type_name: string;
-      service_name: string;
-      verb_name: string;
+  };
+  'environment_variables.list': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    EventTypePost: {
-      /** Format: uuid */
-      application_id: string;
-      resource_type: string;
-      service: string;
-      verb: string;
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['EnvVarMetadata'][];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
     };
-    EventWithPayload: {
-      /** Format: uuid */
-      event_id: string;
-      event_type_name: string;
-      ip: string;
-      labels: Record<string, never>;
-      metadata?: Record<string, never>;
-      /** Format: date-time */
-      occurred_at: string;
-      payload: string;
-      payload_content_type: string;
-      /** Format: date-time */
-      received_at: string;
+  };
+  'errors.list': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    EventsPerDayEntry: {
-      /** Format: int32 */
-      amount: number;
-      /** Format: uuid */
-      application_id: string;
-      application_name: string;
-      /** Format: date */
-      date: string;
-      is_provisional: boolean;
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['Problem'][];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
     };
-    HealthCheck: {
-      database: boolean;
-      /** Format: int64 */
-      database_duration_ms: number;
-      object_storage?: boolean;
-      /** Format: int64 */
-      object_storage_duration_ms?: number;
-      pulsar?: boolean;
-      /** Format: int64 */
-      pulsar_duration_ms?: number;
-      /** Format: int64 */
-      total_duration_ms: number;
+  };
+  'events.ingest': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    IngestedEvent: {
-      /** Format: uuid */
-      application_id: string;
-      /** Format: uuid */
-      event_id: string;
-      /** Format: date-time */
-      received_at: string;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['EventPost'];
+      };
     };
-    InstanceConfig: {
-      application_secret_compatibility: boolean;
-      auto_db_migration: boolean;
-      biscuit_public_key: string;
-      cloudflare_turnstile_site_key?: string;
-      formbricks?: {
-        api_host: string;
-        environment_id: string;
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['IngestedEvent'];
+        };
       };
-      matomo?: {
-        /** Format: int32 */
-        site_id: number;
-        url: string;
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      /** Format: int32 */
-      password_minimum_length: number;
-      quota_enforcement: boolean;
-      registration_disabled: boolean;
-      support_email_address: string;
     };
-    LoginPost: {
-      email: string;
-      password: string;
+  };
+  'events.ingestBatch': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    LoginResponse: {
-      access_token: string;
-      /** Format: date-time */
-      access_token_expiration: string;
-      email: string;
-      first_name: string;
-      last_name: string;
-      refresh_token: string;
-      /** Format: date-time */
-      refresh_token_expiration: string;
-      /** Format: uuid */
-      user_id: string;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['EventsBatchPost'];
+      };
     };
-    Organization: {
-      name: string;
-      /** Format: uuid */
-      organization_id: string;
-      plan?: {
-        label: string;
-        name: string;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['IngestedEventsBatch'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      role: string;
     };
-    OrganizationEditRole: {
-      role: string;
-      /** Format: uuid */
-      user_id: string;
+  };
+  'eventTypes.list': {
+    parameters: {
+      query: {
+        application_id: string;
+      };
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    OrganizationInfo: {
-      consumption: {
-        /** Format: int64 */
-        applications?: number;
-        /** Format: int64 */
-        events_per_day?: number;
-        /** Format: int64 */
-        members?: number;
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['EventType'][];
+        };
       };
-      name: string;
-      onboarding_steps: {
-        /** @enum {string} */
-        application: 'ToDo' | 'Done';
-        /** @enum {string} */
-        event: 'ToDo' | 'Done';
-        /** @enum {string} */
-        event_type: 'ToDo' | 'Done';
-        /** @enum {string} */
-        subscription: 'ToDo' | 'Done';
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      /** Format: uuid */
-      organization_id: string;
-      plan?: {
-        label: string;
-        name: string;
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      quotas: {
-        /** Format: int32 */
-        applications_per_organization_limit: number;
-        /** Format: int32 */
-        days_of_events_retention_limit: number;
-        /** Format: int32 */
-        events_per_day_limit: number;
-        /** Format: int32 */
-        members_per_organization_limit: number;
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      users: {
-        email: string;
-        first_name: string;
-        last_name: string;
-        role: string;
-        /** Format: uuid */
-        user_id: string;
-      }[];
     };
-    OrganizationPost: {
-      name: string;
-    };
-    Problem: {
-      detail: string;
-      id: string;
-      /** Format: int32 */
-      status: number;
-      title: string;
+  };
+  'eventTypes.create': {
+    parameters: {
+      query?: never;
+      header?: never;
+      path?: never;
+      cookie?: never;
     };
-    QuotasResponse: {
-      enabled: boolean;
-      limits: {
-        /** Format: int32 */
-        global_applications_per_organization_limit: number;
-        /** Format: int32 */
-        global_days_of_events_retention_limit: number;
-        /** Format: int32 */
-        global_event_types_per_application_limit: number;
-        /** Format: int32 */
-        global_events_per_day_limit: number;
-        /** Format: int32 */
-        global_members_per_organization_limit: number;
-        /** Format: int32 */
-        global_subscriptions_per_application_limit: number;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['EventTypePost'];
       };
     };
-    Registration: {
-      /** Format: uuid */
-      organization_id: string;
-      /** Format: uuid */
-      user_id: string;
-    };
-    RegistrationPost: {
-      email: string;
-      first_name: string;
-      last_name: string;
-      password: string;
-      turnstile_token?: string;
-    };
-    ReplayEvent: {
-      /** Format: uuid */
-      application_id: string;
-    };
-    RequestAttempt: {
-      /** Format: date-time */
-      created_at: string;
-      /** Format: date-time */
-      delay_until?: string;
-      event: {
-        /** Format: uuid */
-        event_id: string;
-        event_type_name: string;
+    responses: {
+      /** @description Created */
+      201: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['EventType'];
+        };
       };
-      /** Format: uuid */
-      event_id: string;
-      /** Format: date-time */
-      failed_at?: string;
-      /** Format: int32 */
-      http_response_status?: number;
-      /** Format: date-time */
-      picked_at?: string;
-      /** Format: uuid */
-      request_attempt_id: string;
-      /** Format: uuid */
-      response_id?: string;
-      /** Format: int32 */
-      retry_count: number;
-      /** @description Status of a request attempt. The 'type' field indicates the status variant. - waiting: {type, since, until} - Scheduled for future delivery - pending: {type, since} - Ready to be processed - in_progress: {type, since} - Currently being delivered - successful: {type, at, full_processing_ms} - Delivered successfully - failed: {type, at, full_processing_ms} - Delivery failed */
-      status: {
-        /**
-         * Format: date-time
-         * @description Timestamp when completed (present in successful, failed)
-         */
-        at?: string;
-        /**
-         * Format: int64
-         * @description Total processing time in milliseconds (present in successful, failed)
-         */
-        full_processing_ms?: number;
-        /**
-         * Format: date-time
-         * @description Timestamp when the status started (present in waiting, pending, in_progress)
-         */
-        since?: string;
-        /**
-         * @description Status type discriminator. One of: waiting, pending, in_progress, successful, failed
-         * @enum {string}
-         */
-        type: 'waiting' | 'pending' | 'in_progress' | 'successful' | 'failed';
-        /**
-         * Format: date-time
-         * @description Timestamp until which waiting (only present in waiting status)
-         */
-        until?: string;
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      subscription: {
-        description?: string;
-        /** Format: uuid */
-        subscription_id: string;
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      /** Format: date-time */
-      succeeded_at?: string;
-    };
-    ResetPasswordPost: {
-      new_password: string;
-      token: string;
-    };
-    Response: {
-      body?: string;
-      /** Format: int32 */
-      elapsed_time_ms?: number;
-      headers?: {
-        [key: string]: string;
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      /** Format: int32 */
-      http_code?: number;
-      response_error_name?: string;
-      /** Format: uuid */
-      response_id: string;
-    };
-    Revoke: {
-      /** Format: uuid */
-      user_id: string;
-    };
-    ServiceToken: {
-      biscuit: string;
-      /** Format: date-time */
-      created_at: string;
-      name: string;
-      /** Format: uuid */
-      token_id: string;
-    };
-    ServiceTokenPost: {
-      name: string;
-      /** Format: uuid */
-      organization_id: string;
-    };
-    Subscription: {
-      /** Format: uuid */
-      application_id: string;
-      /** Format: date-time */
-      created_at: string;
-      dedicated_workers: string[];
-      description?: string;
-      event_types: string[];
-      is_enabled: boolean;
-      /** @description _Kept for backward compatibility, you should use `labels`_ */
-      label_key: string;
-      /** @description _Kept for backward compatibility, you should use `labels`_ */
-      label_value: string;
-      labels: {
-        [key: string]: string;
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      metadata: {
-        [key: string]: string;
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      /** Format: uuid */
-      secret: string;
-      /** Format: uuid */
-      subscription_id: string;
-      target: {
-        headers: Record<string, never>;
-        method: string;
-        /** @example http */
-        type: string;
-        /** Format: url */
-        url: string;
+    };
+  };
+  'eventTypes.get': {
+    parameters: {
+      query: {
+        application_id: string;
       };
-      /** Format: date-time */
-      updated_at: string;
+      header?: never;
+      path: {
+        event_type_name: string;
+      };
+      cookie?: never;
     };
-    SubscriptionPost: {
-      /** Format: uuid */
-      application_id: string;
-      dedicated_workers?: string[];
-      description?: string;
-      event_types: string[];
-      is_enabled: boolean;
-      /** @description _Kept for backward compatibility, you should use `labels`_ */
-      label_key?: string;
-      /** @description _Kept for backward compatibility, you should use `labels`_ */
-      label_value?: string;
-      labels?: {
-        [key: string]: string;
+    requestBody?: never;
+    responses: {
+      /** @description OK */
+      200: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['EventType'];
+        };
+      };
+      /** @description Bad Request */
+      400: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Forbidden */
+      403: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      metadata?: {
-        [key: string]: string;
+      /** @description Not Found */
+      404: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-      target: {
-        headers: Record<string, never>;
-        method: string;
-        /** @example http */
-        type: string;
-        /** Format: url */
-        url: string;
+      /** @description Conflict */
+      409: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
+      };
+      /** @description Internal Server Error */
+      500: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content?: never;
       };
-    };
-    UserInvitation: {
-      email: string;
-      role: string;
     };
   };
-  responses: never;
-  parameters: never;
-  requestBodies: never;
-  headers: never;
-  pathItems: never;
-}
-export type $defs = Record<string, never>;
-export interface operations {
-  'applicationSecrets.read': {
+  'eventTypes.update': {
     parameters: {
-      query: {
-        application_id: string;
-      };
+      query?: never;
       header?: never;
-      path?: never;
+      path: {
+        event_type_name: string;
+      };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['EventTypePut'];
+      };
+    };
     responses: {
       /** @description OK */
       200: {
@@ -1321,7 +4740,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['ApplicationSecret'][];
+          'application/json': components['schemas']['EventType'];
         };
       };
       /** @description Bad Request */
@@ -1361,16 +4780,18 @@ export interface operations {
       };
     };
   };
-  'applicationSecrets.create': {
+  'eventTypes.createVersion': {
     parameters: {
       query?: never;
       header?: never;
-      path?: never;
+      path: {
+        event_type_name: string;
+      };
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['ApplicationSecretPost'];
+        'application/json': components['schemas']['EventTypeVersionPost'];
       };
     };
     responses: {
@@ -1380,7 +4801,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['ApplicationSecret'];
+          'application/json': components['schemas']['EventTypeVersion'];
         };
       };
       /** @description Bad Request */
@@ -1420,18 +4841,18 @@ export interface operations {
       };
     };
   };
-  'applicationSecrets.update': {
+  'eventTypes.deprecateVersion': {
     parameters: {
       query?: never;
       header?: never;
       path: {
-        application_secret_token: string;
+        event_type_name: string;
       };
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['ApplicationSecretPost'];
+        'application/json': components['schemas']['EventTypeVersionDeprecate'];
       };
     };
     responses: {
@@ -1441,7 +4862,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['ApplicationSecret'];
+          'application/json': components['schemas']['EventTypeVersion'];
         };
       };
       /** @description Bad Request */
@@ -1481,14 +4902,14 @@ export interface operations {
       };
     };
   };
-  'applicationSecrets.delete': {
+  'eventTypes.delete': {
     parameters: {
       query: {
         application_id: string;
       };
       header?: never;
       path: {
-        application_secret_token: string;
+        event_type_name: string;
       };
       cookie?: never;
     };
@@ -1538,10 +4959,12 @@ export interface operations {
       };
     };
   };
-  'applications.list': {
+  'events.list': {
     parameters: {
       query: {
-        organization_id: string;
+        application_id: string;
+        /** @description Only list the events scheduled for a later delivery that was not cancelled, soonest first */
+        scheduled?: boolean;
       };
       header?: never;
       path?: never;
@@ -1555,7 +4978,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['Application'][];
+          'application/json': components['schemas']['Event'][];
         };
       };
       /** @description Bad Request */
@@ -1595,26 +5018,26 @@ export interface operations {
       };
     };
   };
-  'applications.create': {
+  'events.get': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
-      path?: never;
-      cookie?: never;
-    };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['ApplicationPost'];
+      path: {
+        event_id: string;
       };
+      cookie?: never;
     };
+    requestBody?: never;
     responses: {
-      /** @description Created */
-      201: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['Application'];
+          'application/json': components['schemas']['EventWithPayload'];
         };
       };
       /** @description Bad Request */
@@ -1654,25 +5077,27 @@ export interface operations {
       };
     };
   };
-  'applications.get': {
+  'events.cancel': {
     parameters: {
       query?: never;
       header?: never;
       path: {
-        application_id: string;
+        event_id: string;
       };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['CancelEvent'];
+      };
+    };
     responses: {
-      /** @description OK */
-      200: {
+      /** @description No Content */
+      204: {
         headers: {
           [name: string]: unknown;
         };
-        content: {
-          'application/json': components['schemas']['ApplicationInfo'];
-        };
+        content?: never;
       };
       /** @description Bad Request */
       400: {
@@ -1711,29 +5136,27 @@ export interface operations {
       };
     };
   };
-  'applications.update': {
+  'events.replay': {
     parameters: {
       query?: never;
       header?: never;
       path: {
-        application_id: string;
+        event_id: string;
       };
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['ApplicationPost'];
+        'application/json': components['schemas']['ReplayEvent'];
       };
     };
     responses: {
-      /** @description OK */
-      200: {
+      /** @description No Content */
+      204: {
         headers: {
           [name: string]: unknown;
         };
-        content: {
-          'application/json': components['schemas']['Application'];
-        };
+        content?: never;
       };
       /** @description Bad Request */
       400: {
@@ -1772,23 +5195,29 @@ export interface operations {
       };
     };
   };
-  'applications.delete': {
+  'events_per_day.list_for_application': {
     parameters: {
-      query?: never;
-      header?: never;
-      path: {
+      query: {
         application_id: string;
+        /** @description Start of date range (inclusive). Defaults to 30 days before `to`. */
+        from?: string;
+        /** @description End of date range (inclusive). Defaults to today. */
+        to?: string;
       };
+      header?: never;
+      path?: never;
       cookie?: never;
     };
     requestBody?: never;
     responses: {
-      /** @description No Content */
-      204: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
-        content?: never;
+        content: {
+          'application/json': components['schemas']['EventsPerDayEntry'][];
+        };
       };
       /** @description Bad Request */
       400: {
@@ -1827,25 +5256,29 @@ export interface operations {
       };
     };
   };
-  'auth.begin_reset_password': {
+  'events_per_day.list_for_organization': {
     parameters: {
-      query?: never;
+      query: {
+        /** @description Start of date range (inclusive). Defaults to 30 days before `to`. */
+        from?: string;
+        organization_id: string;
+        /** @description End of date range (inclusive). Defaults to today. */
+        to?: string;
+      };
       header?: never;
       path?: never;
       cookie?: never;
     };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['BeginResetPasswordPost'];
-      };
-    };
+    requestBody?: never;
     responses: {
-      /** @description No Content */
-      204: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
-        content?: never;
+        content: {
+          'application/json': components['schemas']['EventsPerDayEntry'][];
+        };
       };
       /** @description Bad Request */
       400: {
@@ -1884,26 +5317,28 @@ export interface operations {
       };
     };
   };
-  'auth.login': {
+  'inbox.ack': {
     parameters: {
       query?: never;
       header?: never;
-      path?: never;
+      path: {
+        subscription_id: string;
+      };
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['LoginPost'];
+        'application/json': components['schemas']['InboxAck'];
       };
     };
     responses: {
-      /** @description Created */
-      201: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['LoginResponse'];
+          'application/json': components['schemas']['InboxSettlement'];
         };
       };
       /** @description Bad Request */
@@ -1943,21 +5378,29 @@ export interface operations {
       };
     };
   };
-  'auth.logout': {
+  'inbox.nack': {
     parameters: {
       query?: never;
       header?: never;
-      path?: never;
+      path: {
+        subscription_id: string;
+      };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['InboxNack'];
+      };
+    };
     responses: {
-      /** @description No Content */
-      204: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
-        content?: never;
+        content: {
+          'application/json': components['schemas']['InboxSettlement'];
+        };
       };
       /** @description Bad Request */
       400: {
@@ -1996,25 +5439,30 @@ export interface operations {
       };
     };
   };
-  'auth.change_password': {
+  'inbox.pull': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+        cursor?: string;
+        limit?: number;
+        visibility_timeout_s?: number;
+      };
       header?: never;
-      path?: never;
-      cookie?: never;
-    };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['ChangePasswordPost'];
+      path: {
+        subscription_id: string;
       };
+      cookie?: never;
     };
+    requestBody?: never;
     responses: {
-      /** @description No Content */
-      204: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
-        content?: never;
+        content: {
+          'application/json': components['schemas']['Inbox'];
+        };
       };
       /** @description Bad Request */
       400: {
@@ -2053,22 +5501,23 @@ export interface operations {
       };
     };
   };
-  'auth.refresh': {
+  'instance.health': {
     parameters: {
-      query?: never;
+      query?: {
+        key?: string;
+      };
       header?: never;
       path?: never;
       cookie?: never;
     };
     requestBody?: never;
     responses: {
-      /** @description Created */
-      201: {
+      200: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['LoginResponse'];
+          'application/json': components['schemas']['HealthCheck'];
         };
       };
       /** @description Bad Request */
@@ -2106,27 +5555,33 @@ export interface operations {
         };
         content?: never;
       };
+      503: {
+        headers: {
+          [name: string]: unknown;
+        };
+        content: {
+          'application/json': components['schemas']['HealthCheck'];
+        };
+      };
     };
   };
-  'auth.reset_password': {
+  'instance.get': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['ResetPasswordPost'];
-      };
-    };
+    requestBody?: never;
     responses: {
-      /** @description No Content */
-      204: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
-        content?: never;
+        content: {
+          'application/json': components['schemas']['InstanceConfig'];
+        };
       };
       /** @description Bad Request */
       400: {
@@ -2165,26 +5620,22 @@ export interface operations {
       };
     };
   };
-  'auth.verify_email': {
+  'organizations.list': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['EmailVerificationPost'];
-      };
-    };
+    requestBody?: never;
     responses: {
-      /** @description Created */
-      201: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['LoginResponse'];
+          'application/json': components['schemas']['Organization'][];
         };
       };
       /** @description Bad Request */
@@ -2224,14 +5675,18 @@ export interface operations {
       };
     };
   };
-  'environment_variables.list': {
+  'organizations.create': {
     parameters: {
       query?: never;
       header?: never;
       path?: never;
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['OrganizationPost'];
+      };
+    };
     responses: {
       /** @description OK */
       200: {
@@ -2239,7 +5694,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['EnvVarMetadata'][];
+          'application/json': components['schemas']['OrganizationInfo'];
         };
       };
       /** @description Bad Request */
@@ -2279,11 +5734,13 @@ export interface operations {
       };
     };
   };
-  'errors.list': {
+  'organizations.get': {
     parameters: {
       query?: never;
       header?: never;
-      path?: never;
+      path: {
+        organization_id: string;
+      };
       cookie?: never;
     };
     requestBody?: never;
@@ -2294,7 +5751,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['Problem'][];
+          'application/json': components['schemas']['OrganizationInfo'];
         };
       };
       /** @description Bad Request */
@@ -2334,26 +5791,28 @@ export interface operations {
       };
     };
   };
-  'events.ingest': {
+  'organizations.edit': {
     parameters: {
       query?: never;
       header?: never;
-      path?: never;
+      path: {
+        organization_id: string;
+      };
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['EventPost'];
+        'application/json': components['schemas']['OrganizationPost'];
       };
     };
     responses: {
-      /** @description Created */
-      201: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['IngestedEvent'];
+          'application/json': components['schemas']['OrganizationInfo'];
         };
       };
       /** @description Bad Request */
@@ -2393,25 +5852,23 @@ export interface operations {
       };
     };
   };
-  'eventTypes.list': {
+  'organizations.delete': {
     parameters: {
-      query: {
-        application_id: string;
-      };
+      query?: never;
       header?: never;
-      path?: never;
+      path: {
+        organization_id: string;
+      };
       cookie?: never;
     };
     requestBody?: never;
     responses: {
-      /** @description OK */
-      200: {
+      /** @description No Content */
+      204: {
         headers: {
           [name: string]: unknown;
         };
-        content: {
-          'application/json': components['schemas']['EventType'][];
-        };
+        content?: never;
       };
       /** @description Bad Request */
       400: {
@@ -2450,26 +5907,28 @@ export interface operations {
       };
     };
   };
-  'eventTypes.create': {
+  'organizations.edit_role': {
     parameters: {
       query?: never;
       header?: never;
-      path?: never;
+      path: {
+        organization_id: string;
+      };
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['EventTypePost'];
+        'application/json': components['schemas']['OrganizationEditRole'];
       };
     };
     responses: {
-      /** @description Created */
-      201: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['EventType'];
+          'application/json': components['schemas']['OrganizationEditRole'];
         };
       };
       /** @description Bad Request */
@@ -2509,18 +5968,20 @@ export interface operations {
       };
     };
   };
-  'eventTypes.get': {
+  'organizations.invite': {
     parameters: {
-      query: {
-        application_id: string;
-      };
+      query?: never;
       header?: never;
       path: {
-        event_type_name: string;
+        organization_id: string;
       };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['UserInvitation'];
+      };
+    };
     responses: {
       /** @description OK */
       200: {
@@ -2528,7 +5989,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['EventType'];
+          'application/json': components['schemas']['UserInvitation'];
         };
       };
       /** @description Bad Request */
@@ -2568,25 +6029,29 @@ export interface operations {
       };
     };
   };
-  'eventTypes.delete': {
+  'organizations.revoke': {
     parameters: {
-      query: {
-        application_id: string;
-      };
+      query?: never;
       header?: never;
       path: {
-        event_type_name: string;
+        organization_id: string;
       };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['Revoke'];
+      };
+    };
     responses: {
-      /** @description No Content */
-      204: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
-        content?: never;
+        content: {
+          'application/json': components['schemas']['Revoke'];
+        };
       };
       /** @description Bad Request */
       400: {
@@ -2625,11 +6090,9 @@ export interface operations {
       };
     };
   };
-  'events.list': {
+  'payload_content_types.list': {
     parameters: {
-      query: {
-        application_id: string;
-      };
+      query?: never;
       header?: never;
       path?: never;
       cookie?: never;
@@ -2642,7 +6105,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['Event'][];
+          'application/json': string[];
         };
       };
       /** @description Bad Request */
@@ -2682,15 +6145,11 @@ export interface operations {
       };
     };
   };
-  'events.get': {
+  'quotas.get': {
     parameters: {
-      query: {
-        application_id: string;
-      };
+      query?: never;
       header?: never;
-      path: {
-        event_id: string;
-      };
+      path?: never;
       cookie?: never;
     };
     requestBody?: never;
@@ -2701,7 +6160,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['EventWithPayload'];
+          'application/json': components['schemas']['QuotasResponse'];
         };
       };
       /** @description Bad Request */
@@ -2741,27 +6200,27 @@ export interface operations {
       };
     };
   };
-  'events.replay': {
+  register: {
     parameters: {
       query?: never;
       header?: never;
-      path: {
-        event_id: string;
-      };
+      path?: never;
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['ReplayEvent'];
+        'application/json': components['schemas']['RegistrationPost'];
       };
     };
     responses: {
-      /** @description No Content */
-      204: {
+      /** @description Created */
+      201: {
         headers: {
           [name: string]: unknown;
         };
-        content?: never;
+        content: {
+          'application/json': components['schemas']['Registration'];
+        };
       };
       /** @description Bad Request */
       400: {
@@ -2800,14 +6259,17 @@ export interface operations {
       };
     };
   };
-  'events_per_day.list_for_application': {
+  'requestAttempts.read': {
     parameters: {
       query: {
         application_id: string;
-        /** @description Start of date range (inclusive). Defaults to 30 days before `to`. */
-        from?: string;
-        /** @description End of date range (inclusive). Defaults to today. */
-        to?: string;
+        /** @description Comma-separated event types */
+        'event.event_type_names'?: string;
+        event_id?: string;
+        max_created_at?: string;
+        min_created_at?: string;
+        pagination_cursor?: string;
+        subscription_id?: string;
       };
       header?: never;
       path?: never;
@@ -2821,7 +6283,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['EventsPerDayEntry'][];
+          'application/json': components['schemas']['RequestAttempt'][];
         };
       };
       /** @description Bad Request */
@@ -2861,17 +6323,15 @@ export interface operations {
       };
     };
   };
-  'events_per_day.list_for_organization': {
+  'requestAttempts.get': {
     parameters: {
       query: {
-        /** @description Start of date range (inclusive). Defaults to 30 days before `to`. */
-        from?: string;
-        organization_id: string;
-        /** @description End of date range (inclusive). Defaults to today. */
-        to?: string;
+        application_id: string;
       };
       header?: never;
-      path?: never;
+      path: {
+        request_attempt_id: string;
+      };
       cookie?: never;
     };
     requestBody?: never;
@@ -2882,7 +6342,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['EventsPerDayEntry'][];
+          'application/json': components['schemas']['RequestAttempt'];
         };
       };
       /** @description Bad Request */
@@ -2922,23 +6382,28 @@ export interface operations {
       };
     };
   };
-  'instance.health': {
+  'requestAttempts.retry': {
     parameters: {
-      query?: {
-        key?: string;
-      };
+      query?: never;
       header?: never;
-      path?: never;
+      path: {
+        request_attempt_id: string;
+      };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['RetryRequestAttempt'];
+      };
+    };
     responses: {
-      200: {
+      /** @description Created */
+      201: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['HealthCheck'];
+          'application/json': components['schemas']['RequestAttempt'];
         };
       };
       /** @description Bad Request */
@@ -2976,21 +6441,17 @@ export interface operations {
         };
         content?: never;
       };
-      503: {
-        headers: {
-          [name: string]: unknown;
-        };
-        content: {
-          'application/json': components['schemas']['HealthCheck'];
-        };
-      };
     };
   };
-  'instance.get': {
+  'response.get': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
-      path?: never;
+      path: {
+        response_id: string;
+      };
       cookie?: never;
     };
     requestBody?: never;
@@ -3001,7 +6462,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['InstanceConfig'];
+          'application/json': components['schemas']['Response'];
         };
       };
       /** @description Bad Request */
@@ -3041,9 +6502,11 @@ export interface operations {
       };
     };
   };
-  'organizations.list': {
+  'serviceToken.list': {
     parameters: {
-      query?: never;
+      query: {
+        organization_id: string;
+      };
       header?: never;
       path?: never;
       cookie?: never;
@@ -3056,7 +6519,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['Organization'][];
+          'application/json': components['schemas']['ServiceToken'][];
         };
       };
       /** @description Bad Request */
@@ -3096,7 +6559,7 @@ export interface operations {
       };
     };
   };
-  'organizations.create': {
+  'serviceToken.create': {
     parameters: {
       query?: never;
       header?: never;
@@ -3105,17 +6568,17 @@ export interface operations {
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['OrganizationPost'];
+        'application/json': components['schemas']['ServiceTokenPost'];
       };
     };
     responses: {
-      /** @description OK */
-      200: {
+      /** @description Created */
+      201: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['OrganizationInfo'];
+          'application/json': components['schemas']['ServiceToken'];
         };
       };
       /** @description Bad Request */
@@ -3155,12 +6618,14 @@ export interface operations {
       };
     };
   };
-  'organizations.get': {
+  'serviceToken.get': {
     parameters: {
-      query?: never;
+      query: {
+        organization_id: string;
+      };
       header?: never;
       path: {
-        organization_id: string;
+        service_token_id: string;
       };
       cookie?: never;
     };
@@ -3172,7 +6637,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['OrganizationInfo'];
+          'application/json': components['schemas']['ServiceToken'];
         };
       };
       /** @description Bad Request */
@@ -3212,18 +6677,18 @@ export interface operations {
       };
     };
   };
-  'organizations.edit': {
+  'serviceToken.edit': {
     parameters: {
       query?: never;
       header?: never;
       path: {
-        organization_id: string;
+        service_token_id: string;
       };
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['OrganizationPost'];
+        'application/json': components['schemas']['ServiceTokenPost'];
       };
     };
     responses: {
@@ -3233,7 +6698,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['OrganizationInfo'];
+          'application/json': components['schemas']['ServiceToken'];
         };
       };
       /** @description Bad Request */
@@ -3273,12 +6738,14 @@ export interface operations {
       };
     };
   };
-  'organizations.delete': {
+  'serviceToken.delete': {
     parameters: {
-      query?: never;
+      query: {
+        organization_id: string;
+      };
       header?: never;
       path: {
-        organization_id: string;
+        service_token_id: string;
       };
       cookie?: never;
     };
@@ -3328,20 +6795,16 @@ export interface operations {
       };
     };
   };
-  'organizations.edit_role': {
+  'signingKeys.list': {
     parameters: {
-      query?: never;
-      header?: never;
-      path: {
-        organization_id: string;
+      query: {
+        application_id: string;
       };
+      header?: never;
+      path?: never;
       cookie?: never;
     };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['OrganizationEditRole'];
-      };
-    };
+    requestBody?: never;
     responses: {
       /** @description OK */
       200: {
@@ -3349,7 +6812,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['OrganizationEditRole'];
+          'application/json': components['schemas']['SigningKey'][];
         };
       };
       /** @description Bad Request */
@@ -3389,28 +6852,26 @@ export interface operations {
       };
     };
   };
-  'organizations.invite': {
+  'signingKeys.create': {
     parameters: {
       query?: never;
       header?: never;
-      path: {
-        organization_id: string;
-      };
+      path?: never;
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['UserInvitation'];
+        'application/json': components['schemas']['SigningKeyPost'];
       };
     };
     responses: {
-      /** @description OK */
-      200: {
+      /** @description Created */
+      201: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['UserInvitation'];
+          'application/json': components['schemas']['SigningKey'];
         };
       };
       /** @description Bad Request */
@@ -3450,29 +6911,25 @@ export interface operations {
       };
     };
   };
-  'organizations.revoke': {
+  'signingKeys.delete': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
       path: {
-        organization_id: string;
+        signing_key_id: string;
       };
       cookie?: never;
     };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['Revoke'];
-      };
-    };
+    requestBody?: never;
     responses: {
-      /** @description OK */
-      200: {
+      /** @description No Content */
+      204: {
         headers: {
           [name: string]: unknown;
         };
-        content: {
-          'application/json': components['schemas']['Revoke'];
-        };
+        content?: never;
       };
       /** @description Bad Request */
       400: {
@@ -3511,11 +6968,13 @@ export interface operations {
       };
     };
   };
-  'payload_content_types.list': {
+  'signingKeys.jwks': {
     parameters: {
       query?: never;
       header?: never;
-      path?: never;
+      path: {
+        application_id: string;
+      };
       cookie?: never;
     };
     requestBody?: never;
@@ -3526,7 +6985,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': string[];
+          'application/json': components['schemas']['JsonWebKeySet'];
         };
       };
       /** @description Bad Request */
@@ -3566,9 +7025,11 @@ export interface operations {
       };
     };
   };
-  'quotas.get': {
+  'subscriptions.list': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
       path?: never;
       cookie?: never;
@@ -3581,7 +7042,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['QuotasResponse'];
+          'application/json': components['schemas']['Subscription'][];
         };
       };
       /** @description Bad Request */
@@ -3621,7 +7082,7 @@ export interface operations {
       };
     };
   };
-  register: {
+  'subscriptions.create': {
     parameters: {
       query?: never;
       header?: never;
@@ -3630,7 +7091,7 @@ export interface operations {
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['RegistrationPost'];
+        'application/json': components['schemas']['SubscriptionPost'];
       };
     };
     responses: {
@@ -3640,7 +7101,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['Registration'];
+          'application/json': components['schemas']['Subscription'];
         };
       };
       /** @description Bad Request */
@@ -3680,20 +7141,13 @@ export interface operations {
       };
     };
   };
-  'requestAttempts.read': {
+  'subscriptions.get': {
     parameters: {
-      query: {
-        application_id: string;
-        /** @description Comma-separated event types */
-        'event.event_type_names'?: string;
-        event_id?: string;
-        max_created_at?: string;
-        min_created_at?: string;
-        pagination_cursor?: string;
-        subscription_id?: string;
-      };
+      query?: never;
       header?: never;
-      path?: never;
+      path: {
+        subscription_id: string;
+      };
       cookie?: never;
     };
     requestBody?: never;
@@ -3704,7 +7158,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['RequestAttempt'][];
+          'application/json': components['schemas']['Subscription'];
         };
       };
       /** @description Bad Request */
@@ -3744,18 +7198,20 @@ export interface operations {
       };
     };
   };
-  'requestAttempts.get': {
+  'subscriptions.update': {
     parameters: {
-      query: {
-        application_id: string;
-      };
+      query?: never;
       header?: never;
       path: {
-        request_attempt_id: string;
+        subscription_id: string;
       };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['SubscriptionPost'];
+      };
+    };
     responses: {
       /** @description OK */
       200: {
@@ -3763,7 +7219,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['RequestAttempt'];
+          'application/json': components['schemas']['Subscription'];
         };
       };
       /** @description Bad Request */
@@ -3803,27 +7259,25 @@ export interface operations {
       };
     };
   };
-  'response.get': {
+  'subscriptions.delete': {
     parameters: {
       query: {
         application_id: string;
       };
       header?: never;
       path: {
-        response_id: string;
+        subscription_id: string;
       };
       cookie?: never;
     };
     requestBody?: never;
     responses: {
-      /** @description OK */
-      200: {
+      /** @description No Content */
+      204: {
         headers: {
           [name: string]: unknown;
         };
-        content: {
-          'application/json': components['schemas']['Response'];
-        };
+        content?: never;
       };
       /** @description Bad Request */
       400: {
@@ -3862,13 +7316,15 @@ export interface operations {
       };
     };
   };
-  'serviceToken.list': {
+  'clientCertificates.get': {
     parameters: {
       query: {
-        organization_id: string;
+        application_id: string;
       };
       header?: never;
-      path?: never;
+      path: {
+        subscription_id: string;
+      };
       cookie?: never;
     };
     requestBody?: never;
@@ -3879,7 +7335,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['ServiceToken'][];
+          'application/json': components['schemas']['ClientCertificate'];
         };
       };
       /** @description Bad Request */
@@ -3919,26 +7375,30 @@ export interface operations {
       };
     };
   };
-  'serviceToken.create': {
+  'clientCertificates.update': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
-      path?: never;
+      path: {
+        subscription_id: string;
+      };
       cookie?: never;
     };
     requestBody: {
       content: {
-        'application/json': components['schemas']['ServiceTokenPost'];
+        'application/json': components['schemas']['ClientCertificatePut'];
       };
     };
     responses: {
-      /** @description Created */
-      201: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['ServiceToken'];
+          'application/json': components['schemas']['ClientCertificate'];
         };
       };
       /** @description Bad Request */
@@ -3978,27 +7438,25 @@ export interface operations {
       };
     };
   };
-  'serviceToken.get': {
+  'clientCertificates.delete': {
     parameters: {
       query: {
-        organization_id: string;
+        application_id: string;
       };
       header?: never;
       path: {
-        service_token_id: string;
+        subscription_id: string;
       };
       cookie?: never;
     };
     requestBody?: never;
     responses: {
-      /** @description OK */
-      200: {
+      /** @description No Content */
+      204: {
         headers: {
           [name: string]: unknown;
         };
-        content: {
-          'application/json': components['schemas']['ServiceToken'];
-        };
+        content?: never;
       };
       /** @description Bad Request */
       400: {
@@ -4037,20 +7495,18 @@ export interface operations {
       };
     };
   };
-  'serviceToken.edit': {
+  'oauth2ClientCredentials.get': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
       path: {
-        service_token_id: string;
+        subscription_id: string;
       };
       cookie?: never;
     };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['ServiceTokenPost'];
-      };
-    };
+    requestBody?: never;
     responses: {
       /** @description OK */
       200: {
@@ -4058,7 +7514,7 @@ export interface operations {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['ServiceToken'];
+          'application/json': components['schemas']['OAuth2ClientCredentials'];
         };
       };
       /** @description Bad Request */
@@ -4098,25 +7554,31 @@ export interface operations {
       };
     };
   };
-  'serviceToken.delete': {
+  'oauth2ClientCredentials.update': {
     parameters: {
       query: {
-        organization_id: string;
+        application_id: string;
       };
       header?: never;
       path: {
-        service_token_id: string;
+        subscription_id: string;
       };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['OAuth2ClientCredentialsPut'];
+      };
+    };
     responses: {
-      /** @description No Content */
-      204: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
-        content?: never;
+        content: {
+          'application/json': components['schemas']['OAuth2ClientCredentials'];
+        };
       };
       /** @description Bad Request */
       400: {
@@ -4155,25 +7617,25 @@ export interface operations {
       };
     };
   };
-  'subscriptions.list': {
+  'oauth2ClientCredentials.delete': {
     parameters: {
       query: {
         application_id: string;
       };
       header?: never;
-      path?: never;
+      path: {
+        subscription_id: string;
+      };
       cookie?: never;
     };
     requestBody?: never;
     responses: {
-      /** @description OK */
-      200: {
+      /** @description No Content */
+      204: {
         headers: {
           [name: string]: unknown;
         };
-        content: {
-          'application/json': components['schemas']['Subscription'][];
-        };
+        content?: never;
       };
       /** @description Bad Request */
       400: {
@@ -4212,26 +7674,26 @@ export interface operations {
       };
     };
   };
-  'subscriptions.create': {
+  'secretRotations.get': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
-      path?: never;
-      cookie?: never;
-    };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['SubscriptionPost'];
+      path: {
+        subscription_id: string;
       };
+      cookie?: never;
     };
+    requestBody?: never;
     responses: {
-      /** @description Created */
-      201: {
+      /** @description OK */
+      200: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['Subscription'];
+          'application/json': components['schemas']['SecretRotation'];
         };
       };
       /** @description Bad Request */
@@ -4271,24 +7733,30 @@ export interface operations {
       };
     };
   };
-  'subscriptions.get': {
+  'secretRotations.create': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
       path: {
         subscription_id: string;
       };
       cookie?: never;
     };
-    requestBody?: never;
+    requestBody: {
+      content: {
+        'application/json': components['schemas']['SecretRotationPost'];
+      };
+    };
     responses: {
-      /** @description OK */
-      200: {
+      /** @description Created */
+      201: {
         headers: {
           [name: string]: unknown;
         };
         content: {
-          'application/json': components['schemas']['Subscription'];
+          'application/json': components['schemas']['SecretRotation'];
         };
       };
       /** @description Bad Request */
@@ -4328,29 +7796,25 @@ export interface operations {
       };
     };
   };
-  'subscriptions.update': {
+  'secretRotations.finalize': {
     parameters: {
-      query?: never;
+      query: {
+        application_id: string;
+      };
       header?: never;
       path: {
         subscription_id: string;
       };
       cookie?: never;
     };
-    requestBody: {
-      content: {
-        'application/json': components['schemas']['SubscriptionPost'];
-      };
-    };
+    requestBody?: never;
     responses: {
-      /** @description OK */
-      200: {
+      /** @description No Content */
+      204: {
         headers: {
           [name: string]: unknown;
         };
-        content: {
-          'application/json': components['schemas']['Subscription'];
-        };
+        content?: never;
       };
       /** @description Bad Request */
       400: {
@@ -4389,7 +7853,7 @@ export interface operations {
       };
     };
   };
-  'subscriptions.delete': {
+  'secretRotations.rollback': {
     parameters: {
       query: {
         application_id: string;
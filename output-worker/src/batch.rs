@@ -0,0 +1,609 @@
+//! Batched delivery of the request attempts of a subscription.
+//!
+//! Batching is a JSON document stored on the subscription and validated by the API: a number of
+//! events and a number of seconds. Pending request attempts of the subscription wait until either
+//! that many of them are ready to go, or the oldest one has waited that long; then up to that many
+//! are sent together, as one HTTP request whose body is a JSON array of the events.
+//!
+//! The request attempts of a batch are locked in the transaction of its delivery. They all get the
+//! one response of the batch and its outcome; those that failed are retried on their own schedule,
+//! and their retries are batched again.
+
+use aws_sdk_s3::error::DisplayErrorContext;
+use aws_sdk_s3::primitives::ByteStream;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as Base64;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use sqlx::{PgConnection, PgPool, query, query_as, query_scalar};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::circuit_breaker;
+use crate::transform::{PayloadTransform, TransformError};
+use crate::work::Response;
+use crate::{ObjectStorageConfig, RetryPolicy, compute_next_retry};
+use hook0_protobuf::{ObjectStorageResponse, RequestAttempt};
+use hook0_sentry_integration::log_object_storage_error_with_context;
+
+/// Content type of the body of a batch.
+pub const BATCH_CONTENT_TYPE: &str = "application/json";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Batching {
+    /// Largest number of events in a batch; a batch goes as soon as it is full.
+    max_events: i32,
+    /// Longest time an event waits for its batch to fill up.
+    max_wait_s: i32,
+}
+
+impl Batching {
+    /// Read the batching of a subscription. Batching that cannot be read is ignored: the request
+    /// attempts of the subscription are delivered one by one.
+    pub fn from_value(value: Option<&Value>, subscription_id: Uuid) -> Option<Self> {
+        match Self::deserialize(value?) {
+            Ok(batching) => Some(batching),
+            Err(e) => {
+                warn!(%subscription_id, "Batching is invalid ({e}); ignoring it");
+                None
+            }
+        }
+    }
+
+    /// When a batch can go with a request attempt that became eligible at `eligible_at` in it:
+    /// `None` if it can go now.
+    pub async fn ready_at(
+        &self,
+        pool: &PgPool,
+        subscription_id: Uuid,
+        eligible_at: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        let wait_until = eligible_at + TimeDelta::seconds(self.max_wait_s.into());
+        if wait_until <= Utc::now() {
+            return Ok(None);
+        }
+
+        let pending = query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "pending!"
+                FROM (
+                    SELECT 1
+                    FROM webhook.request_attempt
+                    WHERE subscription__id = $1
+                        AND succeeded_at IS NULL
+                        AND failed_at IS NULL
+                        AND (delay_until IS NULL OR delay_until <= statement_timestamp())
+                    LIMIT $2
+                ) AS pending
+            "#,
+            subscription_id,
+            i64::from(self.max_events),
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok((pending < i64::from(self.max_events)).then_some(wait_until))
+    }
+}
+
+/// A request attempt that is part of a batch.
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub attempt: RequestAttempt,
+    pub ordering_key: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Batch {
+    pub id: Uuid,
+    /// In the order their events were received; never empty.
+    members: Vec<Member>,
+}
+
+/// The retry of a request attempt of a batch that failed.
+#[derive(Debug, Clone)]
+pub struct Retry {
+    pub attempt: RequestAttempt,
+    pub delay_until: DateTime<Utc>,
+}
+
+impl Batch {
+    /// A request attempt of the batch; they all share their subscription, target and secret.
+    pub fn first(&self) -> &RequestAttempt {
+        &self.members[0].attempt
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn attempts(&self) -> impl Iterator<Item = &RequestAttempt> {
+        self.members.iter().map(|m| &m.attempt)
+    }
+
+    /// The body of the batch: one entry per event, with its payload reshaped by the
+    /// subscription's transform if it has one.
+    pub fn body(&self) -> Result<Vec<u8>, TransformError> {
+        let entries = self.attempts().map(entry).collect::<Result<Vec<_>, _>>()?;
+        // Serializing a `Value` cannot fail: its keys are always strings
+        Ok(serde_json::to_vec(&entries).expect("could not serialize batch"))
+    }
+}
+
+fn entry(attempt: &RequestAttempt) -> Result<Value, TransformError> {
+    let (content_type, payload) = match attempt.payload_transform.as_ref() {
+        Some(t) => (
+            "application/json",
+            PayloadTransform::from_value(t)?
+                .apply(&attempt.payload_content_type, &attempt.payload)?,
+        ),
+        None => (
+            attempt.payload_content_type.as_str(),
+            attempt.payload.clone(),
+        ),
+    };
+    // JSON payloads are embedded as they are; others as strings, base64-encoded when binary
+    let payload = match content_type {
+        "application/json" => serde_json::from_slice(&payload)
+            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&payload).into_owned())),
+        "text/plain" => Value::String(String::from_utf8_lossy(&payload).into_owned()),
+        _ => Value::String(Base64.encode(&payload)),
+    };
+    Ok(json!({
+        "event_id": attempt.event_id,
+        "event_type": attempt.event_type_name,
+        "received_at": attempt.event_received_at,
+        "payload_content_type": content_type,
+        "payload": payload,
+    }))
+}
+
+/// Lock a request attempt that is about to lead a batch, if it is still pending and no other
+/// batch holds it.
+pub async fn lock(conn: &mut PgConnection, request_attempt_id: Uuid) -> Result<bool, sqlx::Error> {
+    let locked = query!(
+        "
+            SELECT 1 AS locked
+            FROM webhook.request_attempt
+            WHERE request_attempt__id = $1
+                AND succeeded_at IS NULL
+                AND failed_at IS NULL
+            FOR UPDATE SKIP LOCKED
+        ",
+        request_attempt_id,
+    )
+    .fetch_optional(conn)
+    .await?;
+    Ok(locked.is_some())
+}
+
+/// Make a batch out of a locked request attempt and the other request attempts of its
+/// subscription that are ready to go, locking them as well. Those held by another worker, waiting
+/// for an earlier event with the same ordering key, or whose payload cannot be read right now are
+/// left for a later batch.
+pub async fn collect(
+    conn: &mut PgConnection,
+    object_storage: Option<&ObjectStorageConfig>,
+    batching: &Batching,
+    lead: Member,
+) -> Result<Batch, sqlx::Error> {
+    struct Candidate {
+        request_attempt_id: Uuid,
+        event_id: Uuid,
+        event_received_at: DateTime<Utc>,
+        created_at: DateTime<Utc>,
+        retry_count: i16,
+        event_type_name: String,
+        payload: Option<Vec<u8>>,
+        payload_content_type: String,
+        ordering_key: Option<String>,
+    }
+    let candidates = query_as!(
+        Candidate,
+        "
+            SELECT
+                ra.request_attempt__id AS request_attempt_id,
+                ra.event__id AS event_id,
+                e.received_at AS event_received_at,
+                ra.created_at,
+                ra.retry_count,
+                e.event_type__name AS event_type_name,
+                e.payload,
+                e.payload_content_type,
+                ra.ordering_key
+            FROM webhook.request_attempt AS ra
+            INNER JOIN event.event AS e ON e.event__id = ra.event__id
+            WHERE ra.subscription__id = $1
+                AND ra.request_attempt__id <> $2
+                AND ra.succeeded_at IS NULL
+                AND ra.failed_at IS NULL
+                AND (ra.delay_until IS NULL OR ra.delay_until <= statement_timestamp())
+                AND (e.expires_at IS NULL OR e.expires_at > statement_timestamp())
+                AND (
+                    ra.ordering_key IS NULL
+                    OR NOT EXISTS (
+                        SELECT 1
+                        FROM webhook.request_attempt AS prev
+                        INNER JOIN event.event AS prev_e ON prev_e.event__id = prev.event__id
+                        WHERE prev.subscription__id = ra.subscription__id
+                            AND prev.ordering_key = ra.ordering_key
+                            AND prev.succeeded_at IS NULL
+                            AND prev.failed_at IS NULL
+                            AND (prev_e.received_at, prev_e.event__id) < (e.received_at, e.event__id)
+                    )
+                )
+            ORDER BY ra.created_at ASC
+            LIMIT $3
+            FOR UPDATE OF ra SKIP LOCKED
+        ",
+        lead.attempt.subscription_id,
+        lead.attempt.request_attempt_id,
+        i64::from(batching.max_events - 1),
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut members = Vec::with_capacity(candidates.len() + 1);
+    for c in candidates {
+        let payload = match c.payload {
+            Some(p) => Some(p),
+            None => {
+                fetch_payload(
+                    object_storage,
+                    lead.attempt.application_id,
+                    c.event_received_at,
+                    c.event_id,
+                )
+                .await
+            }
+        };
+        if let Some(payload) = payload {
+            members.push(Member {
+                attempt: RequestAttempt {
+                    request_attempt_id: c.request_attempt_id,
+                    event_id: c.event_id,
+                    event_received_at: c.event_received_at,
+                    created_at: c.created_at,
+                    retry_count: c.retry_count,
+                    event_type_name: c.event_type_name,
+                    payload,
+                    payload_content_type: c.payload_content_type,
+                    ..lead.attempt.clone()
+                },
+                ordering_key: c.ordering_key,
+            });
+        } else {
+            debug!(request_attempt_id = %c.request_attempt_id, "Could not get payload for event; leaving request attempt out of the batch");
+        }
+    }
+    members.push(lead);
+    members.sort_by_key(|m| (m.attempt.event_received_at, m.attempt.event_id));
+
+    Ok(Batch {
+        id: Uuid::now_v7(),
+        members,
+    })
+}
+
+async fn fetch_payload(
+    object_storage: Option<&ObjectStorageConfig>,
+    application_id: Uuid,
+    event_received_at: DateTime<Utc>,
+    event_id: Uuid,
+) -> Option<Vec<u8>> {
+    let os = object_storage?;
+    let key = format!(
+        "{application_id}/event/{}/{event_id}",
+        event_received_at.naive_utc().date(),
+    );
+    match os
+        .client
+        .get_object()
+        .bucket(&os.bucket)
+        .key(&key)
+        .send()
+        .await
+    {
+        Ok(obj) => match obj.body.collect().await {
+            Ok(ab) => Some(ab.to_vec()),
+            Err(e) => {
+                log_object_storage_error_with_context!(
+                    "S3 GET OBJECT body collect failed",
+                    error_chain = format!("{e}"),
+                    object_key = &key,
+                );
+                None
+            }
+        },
+        Err(e) => {
+            log_object_storage_error_with_context!(
+                "S3 GET OBJECT failed",
+                error_chain = DisplayErrorContext(&e).to_string(),
+                object_key = &key,
+            );
+            None
+        }
+    }
+}
+
+/// Record the outcome of a batch: its response, linked to every request attempt of the batch,
+/// which all succeed or fail with it. Returns the retries of those that failed.
+#[allow(clippy::too_many_arguments)]
+pub async fn record(
+    conn: &mut PgConnection,
+    object_storage: Option<&ObjectStorageConfig>,
+    retry_policy: &RetryPolicy,
+    worker_name: &str,
+    worker_version: &str,
+    picked_at: DateTime<Utc>,
+    batch: &Batch,
+    response: &Response,
+) -> anyhow::Result<Vec<Retry>> {
+    let first = batch.first();
+    // A response shared by several request attempts is filed under the earliest of them
+    let created_at = batch
+        .attempts()
+        .map(|a| a.created_at)
+        .min()
+        .unwrap_or(first.created_at);
+
+    // Store response
+    let response_headers = response.headers();
+    let store_in_object_storage = object_storage.is_some_and(|object_storage| {
+        object_storage.store_response_body_and_headers
+            && (object_storage
+                .store_response_body_and_headers_only_for
+                .is_empty()
+                || object_storage
+                    .store_response_body_and_headers_only_for
+                    .contains(&first.application_id))
+            && (response.body.is_some() || response_headers.is_some())
+    });
+    let response_id = query_scalar!(
+        "
+            INSERT INTO webhook.response (response_error__name, http_code, headers, body, elapsed_time_ms, rule_outcome)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING response__id
+        ",
+        response.response_error__name(),
+        response.http_code(),
+        response_headers.clone().filter(|_| !store_in_object_storage),
+        response.body.clone().filter(|_| !store_in_object_storage),
+        response.elapsed_time_ms(),
+        response.rule_outcome(),
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if let Some(object_storage) = object_storage
+        && store_in_object_storage
+    {
+        let key = format!(
+            "{}/response/{}/{response_id}",
+            first.application_id,
+            created_at.naive_utc().date()
+        );
+        let object: Vec<u8> = ObjectStorageResponse {
+            body: response.body.clone().unwrap_or_default(),
+            headers: response_headers.unwrap_or_default(),
+        }
+        .try_into()?;
+        object_storage
+            .client
+            .put_object()
+            .bucket(&object_storage.bucket)
+            .key(&key)
+            .content_type("application/protobuf")
+            .body(ByteStream::from(object))
+            .send()
+            .await
+            .inspect_err(|e| {
+                log_object_storage_error_with_context!(
+                    "S3 PUT OBJECT failed",
+                    error_chain = DisplayErrorContext(e).to_string(),
+                    object_key = &key,
+                );
+            })?;
+    }
+
+    // Associate response and request attempts, and complete or fail them
+    let request_attempt_ids = batch
+        .attempts()
+        .map(|a| a.request_attempt_id)
+        .collect::<Vec<_>>();
+    query!(
+        "
+            UPDATE webhook.request_attempt
+            SET worker_name = $1,
+                worker_version = $2,
+                picked_at = COALESCE(picked_at, $3),
+                response__id = $4,
+                delivery_batch__id = $5,
+                succeeded_at = CASE WHEN $6 THEN statement_timestamp() END,
+                failed_at = CASE WHEN $6 THEN NULL ELSE statement_timestamp() END
+            WHERE request_attempt__id = ANY($7)
+                AND succeeded_at IS NULL
+                AND failed_at IS NULL
+        ",
+        worker_name,
+        worker_version,
+        picked_at,
+        response_id,
+        batch.id,
+        response.is_success(),
+        &request_attempt_ids,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    if response.is_success() {
+        circuit_breaker::record_success(conn, first).await?;
+        debug!(delivery_batch_id = %batch.id, batch_size = batch.len(), "Batch delivered successfully");
+        return Ok(Vec::new());
+    }
+
+    // A batch is one delivery as far as the breaker is concerned; tripping it makes the retries give up
+    circuit_breaker::record_failure(conn, first, response).await?;
+
+    let mut retries = Vec::new();
+    for member in &batch.members {
+        let attempt = &member.attempt;
+        if let Some(retry_in) = compute_next_retry(conn, attempt, response, retry_policy).await? {
+            let next_retry_count = attempt.retry_count + 1;
+            let delay_until = Utc::now() + retry_in;
+            let retry = query!(
+                "
+                    INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, delay_until, retry_count, ordering_key)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    RETURNING request_attempt__id, created_at
+                ",
+                attempt.application_id,
+                attempt.event_id,
+                attempt.subscription_id,
+                delay_until,
+                next_retry_count,
+                member.ordering_key,
+            )
+            .fetch_one(&mut *conn)
+            .await?;
+
+            debug!(request_attempt_id = %attempt.request_attempt_id, delivery_batch_id = %batch.id, retry_count = next_retry_count, retry_id = %retry.request_attempt__id, retry_in_secs = retry_in.as_secs_f64(), "Request attempt failed; retry created");
+            retries.push(Retry {
+                attempt: RequestAttempt {
+                    request_attempt_id: retry.request_attempt__id,
+                    created_at: retry.created_at,
+                    retry_count: next_retry_count,
+                    ..attempt.clone()
+                },
+                delay_until,
+            });
+        } else {
+            debug!(request_attempt_id = %attempt.request_attempt_id, delivery_batch_id = %batch.id, retry_count = attempt.retry_count, "Request attempt failed; giving up");
+        }
+    }
+
+    Ok(retries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(
+        event_id: u128,
+        payload_content_type: &str,
+        payload: &[u8],
+        payload_transform: Option<Value>,
+    ) -> RequestAttempt {
+        RequestAttempt {
+            application_id: Uuid::nil(),
+            request_attempt_id: Uuid::new_v4(),
+            event_id: Uuid::from_u128(event_id),
+            event_received_at: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            subscription_id: Uuid::nil(),
+            created_at: Utc::now(),
+            retry_count: 0,
+            http_method: "POST".to_owned(),
+            http_url: "https://example.com/hook".to_owned(),
+            http_headers: json!({}),
+            event_type_name: "billing.invoice.paid".to_owned(),
+            payload: payload.to_vec(),
+            payload_content_type: payload_content_type.to_owned(),
+            secret: Uuid::nil(),
+            payload_transform,
+            message_queue: None,
+            signature_scheme: "hook0".to_owned(),
+            response_rules: None,
+        }
+    }
+
+    fn batch(attempts: Vec<RequestAttempt>) -> Batch {
+        Batch {
+            id: Uuid::nil(),
+            members: attempts
+                .into_iter()
+                .map(|attempt| Member {
+                    attempt,
+                    ordering_key: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn body(batch: &Batch) -> Value {
+        serde_json::from_slice(&batch.body().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn batch_body_is_an_array_of_events() {
+        let b = batch(vec![
+            attempt(1, "application/json", br#"{"amount":3}"#, None),
+            attempt(2, "text/plain", b"hello", None),
+            attempt(3, "application/octet-stream+base64", &[0, 255], None),
+        ]);
+        assert_eq!(
+            body(&b),
+            json!([
+                {
+                    "event_id": "00000000-0000-0000-0000-000000000001",
+                    "event_type": "billing.invoice.paid",
+                    "received_at": "2023-11-14T22:13:20Z",
+                    "payload_content_type": "application/json",
+                    "payload": { "amount": 3 },
+                },
+                {
+                    "event_id": "00000000-0000-0000-0000-000000000002",
+                    "event_type": "billing.invoice.paid",
+                    "received_at": "2023-11-14T22:13:20Z",
+                    "payload_content_type": "text/plain",
+                    "payload": "hello",
+                },
+                {
+                    "event_id": "00000000-0000-0000-0000-000000000003",
+                    "event_type": "billing.invoice.paid",
+                    "received_at": "2023-11-14T22:13:20Z",
+                    "payload_content_type": "application/octet-stream+base64",
+                    "payload": "AP8=",
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn batch_body_has_payloads_transformed() {
+        let b = batch(vec![attempt(
+            1,
+            "application/json",
+            br#"{"amount":3,"secret":"x"}"#,
+            Some(json!({ "fields": [{ "from": "/amount" }] })),
+        )]);
+        assert_eq!(body(&b)[0]["payload"], json!({ "amount": 3 }));
+    }
+
+    #[test]
+    fn batch_body_fails_with_any_transform() {
+        let b = batch(vec![
+            attempt(1, "application/json", b"{}", None),
+            attempt(
+                2,
+                "text/plain",
+                b"hello",
+                Some(json!({ "envelope": "data" })),
+            ),
+        ]);
+        assert!(matches!(b.body(), Err(TransformError::NotJson(_))));
+    }
+
+    #[test]
+    fn batching_is_ignored_when_invalid() {
+        assert!(Batching::from_value(None, Uuid::nil()).is_none());
+        assert!(Batching::from_value(Some(&json!({ "max_events": 10 })), Uuid::nil()).is_none());
+        let batching = Batching::from_value(
+            Some(&json!({ "max_events": 10, "max_wait_s": 5 })),
+            Uuid::nil(),
+        )
+        .unwrap();
+        assert_eq!((batching.max_events, batching.max_wait_s), (10, 5));
+    }
+}
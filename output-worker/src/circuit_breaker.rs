@@ -0,0 +1,267 @@
+//! Opt-in circuit breaker of a subscription.
+//!
+//! The breaker is a JSON document stored on the subscription and validated by the API. Every
+//! failed delivery of a subscription that has one extends its failure streak; a successful
+//! delivery ends it. Once the streak crosses one of the thresholds, the subscription is disabled
+//! in the same transaction as the failed attempt, so no retry is scheduled for it. Notifying the
+//! organization and cancelling the attempts still pending are left to the API.
+//!
+//! A response matching a `disable_subscription` response rule disables the subscription the same
+//! way, whether it has a breaker or not.
+
+use chrono::{DateTime, TimeDelta, Utc};
+use hook0_protobuf::RequestAttempt;
+use serde::Deserialize;
+use sqlx::{PgConnection, query};
+use tracing::warn;
+
+use crate::opentelemetry::report_subscription_auto_disabled;
+use crate::work::{Response, ResponseError};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CircuitBreaker {
+    /// Number of failed deliveries in a row after which the subscription is disabled.
+    #[serde(default)]
+    max_consecutive_failures: Option<i32>,
+    /// Number of hours without a single successful delivery after which the subscription is
+    /// disabled.
+    #[serde(default)]
+    max_failing_hours: Option<i32>,
+}
+
+impl CircuitBreaker {
+    /// Why the subscription must be disabled, if it must.
+    fn trip_reason(&self, consecutive_failures: i32, failing_for: TimeDelta) -> Option<String> {
+        if let Some(max) = self.max_consecutive_failures
+            && consecutive_failures >= max
+        {
+            return Some(format!(
+                "{consecutive_failures} consecutive failed deliveries (limit is {max})"
+            ));
+        }
+        if let Some(max) = self.max_failing_hours
+            && failing_for >= TimeDelta::hours(i64::from(max))
+        {
+            return Some(format!(
+                "No successful delivery for {} hours (limit is {max})",
+                failing_for.num_hours()
+            ));
+        }
+        None
+    }
+}
+
+/// Whether a failure says something about the health of the target. Signature and transform
+/// failures happen before anything is sent: they are the subscription's configuration at fault,
+/// and already give up without retrying.
+fn counts_against_target(response: &Response) -> bool {
+    !matches!(
+        response.response_error,
+        None | Some(ResponseError::InvalidHeader) | Some(ResponseError::Transform)
+    )
+}
+
+/// End the failure streak of the subscription, if it had one.
+pub async fn record_success(
+    conn: &mut PgConnection,
+    attempt: &RequestAttempt,
+) -> Result<(), sqlx::Error> {
+    query!(
+        "DELETE FROM webhook.subscription_failure_streak WHERE subscription__id = $1",
+        attempt.subscription_id
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// Extend the failure streak of the subscription and disable it if that crosses its breaker.
+/// Returns whether the subscription was disabled. Subscriptions without a breaker are left
+/// untouched.
+pub async fn record_failure(
+    conn: &mut PgConnection,
+    attempt: &RequestAttempt,
+    response: &Response,
+) -> Result<bool, sqlx::Error> {
+    if !counts_against_target(response) {
+        return Ok(false);
+    }
+
+    let streak = query!(
+        r#"
+            WITH streak AS (
+                INSERT INTO webhook.subscription_failure_streak (subscription__id, consecutive_failures, failing_since)
+                SELECT s.subscription__id, 1, statement_timestamp()
+                FROM webhook.subscription AS s
+                WHERE s.subscription__id = $1
+                    AND s.circuit_breaker IS NOT NULL
+                    AND s.is_enabled
+                    AND s.deleted_at IS NULL
+                ON CONFLICT (subscription__id)
+                DO UPDATE SET consecutive_failures = webhook.subscription_failure_streak.consecutive_failures + 1
+                RETURNING subscription__id, consecutive_failures, failing_since
+            )
+            SELECT streak.consecutive_failures, streak.failing_since, s.circuit_breaker AS "circuit_breaker!"
+            FROM streak
+            INNER JOIN webhook.subscription AS s ON s.subscription__id = streak.subscription__id
+        "#,
+        attempt.subscription_id
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+    let Some(streak) = streak else {
+        return Ok(false);
+    };
+
+    let breaker = match CircuitBreaker::deserialize(&streak.circuit_breaker) {
+        Ok(breaker) => breaker,
+        Err(e) => {
+            warn!(subscription_id = %attempt.subscription_id, "Circuit breaker is invalid ({e}); ignoring it");
+            return Ok(false);
+        }
+    };
+    let Some(reason) = breaker.trip_reason(
+        streak.consecutive_failures,
+        failing_for(streak.failing_since, Utc::now()),
+    ) else {
+        return Ok(false);
+    };
+
+    let disabled = disable(conn, attempt, &reason).await?;
+    if disabled {
+        warn!(subscription_id = %attempt.subscription_id, request_attempt_id = %attempt.request_attempt_id, "Circuit breaker tripped ({reason}); subscription disabled");
+    }
+    Ok(disabled)
+}
+
+/// Disable the subscription for `reason`, leaving the follow-up to the API, and end its failure
+/// streak. Returns whether it was still enabled.
+pub async fn disable(
+    conn: &mut PgConnection,
+    attempt: &RequestAttempt,
+    reason: &str,
+) -> Result<bool, sqlx::Error> {
+    let disabled = query!(
+        "
+            UPDATE webhook.subscription
+            SET is_enabled = false,
+                auto_disabled_at = statement_timestamp(),
+                auto_disabled_reason = $2,
+                auto_disabled_notified_at = NULL,
+                updated_at = statement_timestamp()
+            WHERE subscription__id = $1
+                AND is_enabled
+        ",
+        attempt.subscription_id,
+        reason,
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected()
+        > 0;
+    query!(
+        "DELETE FROM webhook.subscription_failure_streak WHERE subscription__id = $1",
+        attempt.subscription_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    if disabled {
+        report_subscription_auto_disabled();
+    }
+    Ok(disabled)
+}
+
+fn failing_for(failing_since: DateTime<Utc>, now: DateTime<Utc>) -> TimeDelta {
+    (now - failing_since).max(TimeDelta::zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn breaker(
+        max_consecutive_failures: Option<i32>,
+        max_failing_hours: Option<i32>,
+    ) -> CircuitBreaker {
+        CircuitBreaker {
+            max_consecutive_failures,
+            max_failing_hours,
+        }
+    }
+
+    fn response(response_error: Option<ResponseError>) -> Response {
+        Response {
+            response_error,
+            http_code: None,
+            headers: None,
+            body: None,
+            elapsed_time: Duration::from_secs(0),
+            rule_outcome: None,
+        }
+    }
+
+    #[test]
+    fn trips_on_consecutive_failures() {
+        let b = breaker(Some(3), None);
+        assert_eq!(b.trip_reason(2, TimeDelta::days(30)), None);
+        assert_eq!(
+            b.trip_reason(3, TimeDelta::zero()).as_deref(),
+            Some("3 consecutive failed deliveries (limit is 3)")
+        );
+    }
+
+    #[test]
+    fn trips_on_failing_duration() {
+        let b = breaker(None, Some(24));
+        assert_eq!(b.trip_reason(1_000, TimeDelta::hours(23)), None);
+        assert_eq!(
+            b.trip_reason(2, TimeDelta::hours(25)).as_deref(),
+            Some("No successful delivery for 25 hours (limit is 24)")
+        );
+    }
+
+    #[test]
+    fn empty_breaker_never_trips() {
+        assert_eq!(
+            breaker(None, None).trip_reason(i32::MAX, TimeDelta::days(365)),
+            None
+        );
+    }
+
+    #[test]
+    fn breaker_deserializes_from_the_api_document() {
+        let b = CircuitBreaker::deserialize(
+            &serde_json::json!({ "max_consecutive_failures": 5, "max_failing_hours": null }),
+        )
+        .unwrap();
+        assert_eq!(b.max_consecutive_failures, Some(5));
+        assert_eq!(b.max_failing_hours, None);
+    }
+
+    #[test]
+    fn configuration_failures_do_not_count_against_the_target() {
+        assert!(!counts_against_target(&response(None)));
+        assert!(!counts_against_target(&response(Some(
+            ResponseError::InvalidHeader
+        ))));
+        assert!(!counts_against_target(&response(Some(
+            ResponseError::Transform
+        ))));
+        assert!(counts_against_target(&response(Some(ResponseError::Http))));
+        assert!(counts_against_target(&response(Some(
+            ResponseError::Timeout
+        ))));
+    }
+
+    #[test]
+    fn clock_skew_never_yields_a_negative_duration() {
+        let now = Utc::now();
+        assert_eq!(
+            failing_for(now + TimeDelta::minutes(1), now),
+            TimeDelta::zero()
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! Client certificates that subscriptions present to targets requiring mutual TLS.
+//!
+//! The API checks a certificate when it is uploaded and stores its private key encrypted with the
+//! instance key (AES-256-GCM, the subscription ID as associated data, the nonce first). The worker
+//! decrypts it right before the delivery that presents it.
+
+use aws_lc_rs::aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{DateTime, Utc};
+use reqwest::Identity;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Instance key the private keys of client certificates are encrypted with (AES-256-GCM).
+#[derive(Clone)]
+pub struct ClientCertificateKey([u8; 32]);
+
+impl fmt::Debug for ClientCertificateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ClientCertificateKey(<redacted>)")
+    }
+}
+
+pub fn parse_client_certificate_key(input: &str) -> Result<ClientCertificateKey, String> {
+    STANDARD
+        .decode(input.trim())
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .map(ClientCertificateKey)
+        .ok_or_else(|| "Value of CLIENT_CERTIFICATE_ENCRYPTION_KEY is invalid: it must be 32 bytes encoded in base64, the same as the API's".to_owned())
+}
+
+impl ClientCertificateKey {
+    /// Decrypt a private key encrypted by the API; `owner_id` is the subscription of a client
+    /// certificate or the application of a signing key.
+    pub fn decrypt(&self, owner_id: &Uuid, encrypted: &[u8]) -> Option<Vec<u8>> {
+        if encrypted.len() < NONCE_LEN {
+            return None;
+        }
+        let (nonce, ciphertext) = encrypted.split_at(NONCE_LEN);
+        let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, &self.0).ok()?);
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = key
+            .open_in_place(
+                Nonce::try_assume_unique_for_key(nonce).ok()?,
+                Aad::from(owner_id.as_bytes()),
+                &mut in_out,
+            )
+            .ok()?;
+        Some(plaintext.to_vec())
+    }
+}
+
+/// The client certificate of a subscription, as stored.
+#[derive(Debug, Clone)]
+pub struct ClientCertificate {
+    pub subscription_id: Uuid,
+    pub certificate_pem: String,
+    pub encrypted_private_key: Vec<u8>,
+    pub not_after: DateTime<Utc>,
+}
+
+impl ClientCertificate {
+    /// Identifies the certificate and its key, which change together when the certificate is
+    /// replaced.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.certificate_pem.as_bytes());
+        hasher.update(&self.encrypted_private_key);
+        hasher.finalize().into()
+    }
+
+    /// Decrypt the private key and build the identity to present. The error is customer-visible.
+    pub fn identity(&self, key: Option<&ClientCertificateKey>) -> Result<Identity, String> {
+        let key = key.ok_or_else(|| {
+            "Client certificate cannot be used: this instance has no key to decrypt it".to_owned()
+        })?;
+        let private_key_pem = key
+            .decrypt(&self.subscription_id, &self.encrypted_private_key)
+            .ok_or_else(|| {
+                "Client certificate cannot be used: its private key cannot be decrypted".to_owned()
+            })?;
+
+        if self.not_after <= Utc::now() {
+            warn!(
+                subscription_id = %self.subscription_id,
+                not_after = %self.not_after,
+                "Presenting an expired client certificate"
+            );
+        }
+
+        let mut pem = self.certificate_pem.clone().into_bytes();
+        pem.extend_from_slice(&private_key_pem);
+        Identity::from_pem(&pem).map_err(|e| format!("Client certificate cannot be used: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_lc_rs::rand::{SecureRandom, SystemRandom};
+
+    fn encrypt(key: &[u8; 32], subscription_id: &Uuid, plaintext: &[u8]) -> Vec<u8> {
+        let mut nonce = [0; NONCE_LEN];
+        SystemRandom::new().fill(&mut nonce).unwrap();
+        let mut in_out = plaintext.to_vec();
+        LessSafeKey::new(UnboundKey::new(&AES_256_GCM, key).unwrap())
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(subscription_id.as_bytes()),
+                &mut in_out,
+            )
+            .unwrap();
+        [nonce.as_slice(), &in_out].concat()
+    }
+
+    #[test]
+    fn private_keys_are_bound_to_their_subscription() {
+        let key = ClientCertificateKey([7; 32]);
+        let subscription_id = Uuid::new_v4();
+        let encrypted = encrypt(&[7; 32], &subscription_id, b"private key");
+
+        assert_eq!(
+            key.decrypt(&subscription_id, &encrypted).as_deref(),
+            Some(b"private key".as_slice())
+        );
+        assert_eq!(key.decrypt(&Uuid::new_v4(), &encrypted), None);
+        assert_eq!(
+            ClientCertificateKey([8; 32]).decrypt(&subscription_id, &encrypted),
+            None
+        );
+        assert_eq!(key.decrypt(&subscription_id, &encrypted[..4]), None);
+    }
+
+    #[test]
+    fn identity_needs_the_instance_key() {
+        let subscription_id = Uuid::new_v4();
+        let certificate = ClientCertificate {
+            subscription_id,
+            certificate_pem: String::new(),
+            encrypted_private_key: encrypt(&[7; 32], &subscription_id, b""),
+            not_after: Utc::now(),
+        };
+
+        let err = certificate.identity(None).unwrap_err();
+        assert!(err.contains("no key to decrypt it"), "{err}");
+        let err = certificate
+            .identity(Some(&ClientCertificateKey([8; 32])))
+            .unwrap_err();
+        assert!(err.contains("cannot be decrypted"), "{err}");
+    }
+
+    #[test]
+    fn identity_is_built_from_the_certificate_and_its_decrypted_key() {
+        let key_pair = rcgen::KeyPair::generate().unwrap();
+        let certificate = rcgen::CertificateParams::new(vec!["client.example.com".to_owned()])
+            .unwrap()
+            .self_signed(&key_pair)
+            .unwrap();
+        let subscription_id = Uuid::new_v4();
+        let certificate = ClientCertificate {
+            subscription_id,
+            certificate_pem: certificate.pem(),
+            encrypted_private_key: encrypt(
+                &[7; 32],
+                &subscription_id,
+                key_pair.serialize_pem().as_bytes(),
+            ),
+            not_after: Utc::now() + chrono::Duration::days(30),
+        };
+
+        assert!(
+            certificate
+                .identity(Some(&ClientCertificateKey([7; 32])))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn encryption_key_is_32_bytes_of_base64() {
+        assert!(parse_client_certificate_key(&STANDARD.encode([7; 32])).is_ok());
+        assert!(parse_client_certificate_key(&STANDARD.encode([7; 31])).is_err());
+    }
+}
@@ -0,0 +1,31 @@
+//! Dead letters: deliveries the worker gave up on while their subscription still wanted them.
+//!
+//! They stay listed per subscription until they are redelivered or purged through the API.
+
+use sqlx::{PgConnection, query};
+
+use crate::RequestAttempt;
+use crate::opentelemetry::GiveUpReason;
+
+/// Record that `attempt`, which just failed, was the last attempt of its delivery.
+pub async fn record(
+    conn: &mut PgConnection,
+    attempt: &RequestAttempt,
+    reason: GiveUpReason,
+) -> Result<(), sqlx::Error> {
+    query!(
+        "
+            INSERT INTO webhook.dead_letter (application__id, subscription__id, event__id, request_attempt__id, reason)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (request_attempt__id) DO NOTHING
+        ",
+        attempt.application_id,
+        attempt.subscription_id,
+        attempt.event_id,
+        attempt.request_attempt_id,
+        <&'static str>::from(reason),
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,125 @@
+//! What a delivery is authenticated and signed with, besides the request attempt itself.
+//!
+//! The client certificate, OAuth2 client credentials and secrets of the subscription and the
+//! signing key of its application are read in a single query right before the delivery, rather
+//! than carried by the request attempt, so that deliveries queued before one of them changed use
+//! the current ones.
+
+use sqlx::{PgExecutor, query};
+use uuid::Uuid;
+
+use crate::client_certificate::{ClientCertificate, ClientCertificateKey};
+use crate::oauth2::OAuth2ClientCredentials;
+use crate::signing_key::SigningKey;
+use crate::subscription_secrets::SubscriptionSecrets;
+
+#[derive(Debug)]
+pub struct DeliveryCredentials {
+    pub client_certificate: Option<ClientCertificate>,
+    pub oauth2: Option<OAuth2ClientCredentials>,
+    pub signing_key: Option<SigningKey>,
+    pub secrets: Option<SubscriptionSecrets>,
+}
+
+impl DeliveryCredentials {
+    /// The credentials of a subscription and the signing key of its application, whichever of
+    /// them exist; the signing key is decrypted with `encryption_key`.
+    pub async fn load<'a, E: PgExecutor<'a>>(
+        executor: E,
+        encryption_key: Option<&ClientCertificateKey>,
+        application_id: Uuid,
+        subscription_id: Uuid,
+    ) -> Result<Self, sqlx::Error> {
+        let row = query!(
+            r#"
+                SELECT
+                    s.secret AS "secret?",
+                    CASE WHEN s.previous_secret_expires_at > statement_timestamp() THEN s.previous_secret END AS previous_secret,
+                    cc.certificate_pem AS "certificate_pem?",
+                    cc.encrypted_private_key AS "encrypted_private_key?",
+                    cc.not_after AS "not_after?",
+                    o.token_url AS "token_url?",
+                    o.client_id AS "client_id?",
+                    o.client_secret AS "client_secret?",
+                    o.scopes AS "scopes?",
+                    o.updated_at AS "oauth2_updated_at?",
+                    sk.signing_key__id AS "signing_key_id?",
+                    sk.encrypted_private_key AS "signing_key_encrypted_private_key?"
+                FROM (SELECT $1::uuid AS application__id, $2::uuid AS subscription__id) AS ids
+                LEFT JOIN webhook.subscription AS s ON s.subscription__id = ids.subscription__id
+                LEFT JOIN webhook.subscription_client_certificate AS cc ON cc.subscription__id = ids.subscription__id
+                LEFT JOIN webhook.subscription_oauth2_client_credentials AS o ON o.subscription__id = ids.subscription__id
+                LEFT JOIN LATERAL (
+                    SELECT signing_key__id, encrypted_private_key
+                    FROM event.application_signing_key
+                    WHERE application__id = ids.application__id AND retired_at IS NULL
+                    ORDER BY created_at DESC, signing_key__id DESC
+                    LIMIT 1
+                ) AS sk ON true
+            "#,
+            application_id,
+            subscription_id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        let client_certificate = match (
+            row.certificate_pem,
+            row.encrypted_private_key,
+            row.not_after,
+        ) {
+            (Some(certificate_pem), Some(encrypted_private_key), Some(not_after)) => {
+                Some(ClientCertificate {
+                    subscription_id,
+                    certificate_pem,
+                    encrypted_private_key,
+                    not_after,
+                })
+            }
+            _ => None,
+        };
+        let oauth2 = match (
+            row.token_url,
+            row.client_id,
+            row.client_secret,
+            row.scopes,
+            row.oauth2_updated_at,
+        ) {
+            (
+                Some(token_url),
+                Some(client_id),
+                Some(client_secret),
+                Some(scopes),
+                Some(updated_at),
+            ) => Some(OAuth2ClientCredentials {
+                subscription_id,
+                token_url,
+                client_id,
+                client_secret,
+                scopes,
+                updated_at,
+            }),
+            _ => None,
+        };
+        let signing_key = match (row.signing_key_id, row.signing_key_encrypted_private_key) {
+            (Some(signing_key_id), Some(encrypted_private_key)) => SigningKey::from_stored(
+                signing_key_id,
+                application_id,
+                &encrypted_private_key,
+                encryption_key,
+            ),
+            _ => None,
+        };
+        let secrets = row.secret.map(|secret| SubscriptionSecrets {
+            secret,
+            previous_secret: row.previous_secret,
+        });
+
+        Ok(Self {
+            client_certificate,
+            oauth2,
+            signing_key,
+            secrets,
+        })
+    }
+}
@@ -0,0 +1,246 @@
+//! HTTP clients reused across deliveries.
+//!
+//! A client is built for one target host, pinned to the addresses the resolver vetted for it, so
+//! it is only reused while the host still resolves to exactly the same addresses.
+
+use reqwest::Client;
+use reqwest::tls::Identity;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::trace;
+
+use crate::opentelemetry::{
+    HttpClientEviction, report_http_client_eviction, report_http_client_lookup,
+    report_pooled_http_clients,
+};
+use crate::work::mk_http_client;
+
+#[derive(Debug, Clone, Copy)]
+pub struct HttpClientsOptions {
+    pub connect_timeout: Duration,
+    pub timeout: Duration,
+    /// Maximum number of clients kept; 0 builds a new client for every delivery.
+    pub max_clients: usize,
+    /// Clients unused for this long are dropped, with their connections.
+    pub idle_timeout: Duration,
+}
+
+/// What a pooled client was built for. Reusing it for anything else could send a request to
+/// addresses that were not vetted for it, or present the wrong client certificate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ClientKey {
+    host: String,
+    /// Vetted addresses the host is pinned to, sorted.
+    addrs: Vec<SocketAddr>,
+    /// Fingerprint of the client certificate to present, for targets requiring mutual TLS.
+    client_certificate: Option<[u8; 32]>,
+}
+
+#[derive(Debug)]
+struct PooledClient {
+    client: Client,
+    last_used: Instant,
+}
+
+/// HTTP clients shared by every unit of the worker, so that consecutive deliveries to the same
+/// target reuse its connections instead of opening new ones.
+#[derive(Debug)]
+pub struct HttpClients {
+    options: HttpClientsOptions,
+    clients: Mutex<HashMap<ClientKey, PooledClient>>,
+}
+
+impl HttpClients {
+    pub fn new(options: HttpClientsOptions) -> Self {
+        Self {
+            options,
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// A client whose connections can only go to `addrs`, which must be the vetted addresses of
+    /// `host`. Domain hosts are pinned to them so that reqwest cannot re-resolve the hostname to a
+    /// different (forbidden) IP (DNS rebinding); IP-literal hosts skip DNS.
+    pub fn client(
+        &self,
+        host: &str,
+        is_domain: bool,
+        addrs: &[SocketAddr],
+        identity: Option<([u8; 32], Identity)>,
+    ) -> reqwest::Result<Client> {
+        let (client_certificate, identity) = identity.unzip();
+        let build = |identity| {
+            let pin = is_domain.then_some((host, addrs));
+            mk_http_client(
+                self.options.connect_timeout,
+                self.options.timeout,
+                pin,
+                identity,
+            )
+        };
+        if self.options.max_clients == 0 {
+            return build(identity);
+        }
+
+        let mut addrs_key = addrs.to_vec();
+        addrs_key.sort_unstable();
+        addrs_key.dedup();
+        let key = ClientKey {
+            host: host.to_owned(),
+            addrs: addrs_key,
+            client_certificate,
+        };
+
+        if let Some(pooled) = self
+            .clients
+            .lock()
+            .expect("HTTP clients were poisoned")
+            .get_mut(&key)
+        {
+            pooled.last_used = Instant::now();
+            report_http_client_lookup(true);
+            return Ok(pooled.client.clone());
+        }
+        report_http_client_lookup(false);
+
+        // Building a client loads the TLS configuration, which must not hold up other units
+        let client = build(identity)?;
+
+        let now = Instant::now();
+        let mut clients = self.clients.lock().expect("HTTP clients were poisoned");
+        self.evict_idle_locked(&mut clients, now);
+        if !clients.contains_key(&key)
+            && clients.len() >= self.options.max_clients
+            && let Some(oldest) = clients
+                .iter()
+                .min_by_key(|(_, pooled)| pooled.last_used)
+                .map(|(key, _)| key.clone())
+        {
+            trace!(
+                host = oldest.host,
+                "Evicting least recently used HTTP client"
+            );
+            clients.remove(&oldest);
+            report_http_client_eviction(HttpClientEviction::Capacity);
+        }
+        // Another unit may have pooled a client for the same target meanwhile
+        let pooled = clients.entry(key).or_insert(PooledClient {
+            client,
+            last_used: now,
+        });
+        pooled.last_used = now;
+        let client = pooled.client.clone();
+        report_pooled_http_clients(clients.len());
+        Ok(client)
+    }
+
+    /// Drop the clients that have not been used for the idle timeout.
+    pub fn evict_idle(&self) {
+        let mut clients = self.clients.lock().expect("HTTP clients were poisoned");
+        self.evict_idle_locked(&mut clients, Instant::now());
+        report_pooled_http_clients(clients.len());
+    }
+
+    fn evict_idle_locked(&self, clients: &mut HashMap<ClientKey, PooledClient>, now: Instant) {
+        clients.retain(|key, pooled| {
+            let keep = now.duration_since(pooled.last_used) < self.options.idle_timeout;
+            if !keep {
+                trace!(host = key.host, "Evicting idle HTTP client");
+                report_http_client_eviction(HttpClientEviction::Idle);
+            }
+            keep
+        });
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.clients
+            .lock()
+            .expect("HTTP clients were poisoned")
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    fn clients(max_clients: usize, idle_timeout: Duration) -> HttpClients {
+        HttpClients::new(HttpClientsOptions {
+            connect_timeout: Duration::from_secs(1),
+            timeout: Duration::from_secs(1),
+            max_clients,
+            idle_timeout,
+        })
+    }
+
+    fn addr(last: u8) -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(203, 0, 113, last)), 443)
+    }
+
+    #[test]
+    fn a_client_is_reused_for_the_same_host_and_addresses() {
+        let clients = clients(10, Duration::from_secs(60));
+        clients
+            .client("example.com", true, &[addr(1), addr(2)], None)
+            .unwrap();
+        clients
+            .client("example.com", true, &[addr(2), addr(1)], None)
+            .unwrap();
+        assert_eq!(clients.len(), 1);
+    }
+
+    #[test]
+    fn a_client_is_not_reused_for_other_addresses() {
+        let clients = clients(10, Duration::from_secs(60));
+        clients
+            .client("example.com", true, &[addr(1)], None)
+            .unwrap();
+        // The host now resolves elsewhere: the connections of the first client must not be used
+        clients
+            .client("example.com", true, &[addr(2)], None)
+            .unwrap();
+        clients
+            .client("example.org", true, &[addr(1)], None)
+            .unwrap();
+        assert_eq!(clients.len(), 3);
+    }
+
+    #[test]
+    fn the_least_recently_used_client_is_evicted_when_the_pool_is_full() {
+        let clients = clients(2, Duration::from_secs(60));
+        clients.client("a.example", true, &[addr(1)], None).unwrap();
+        clients.client("b.example", true, &[addr(1)], None).unwrap();
+        clients.client("a.example", true, &[addr(1)], None).unwrap();
+        clients.client("c.example", true, &[addr(1)], None).unwrap();
+
+        let hosts = clients
+            .clients
+            .lock()
+            .unwrap()
+            .keys()
+            .map(|key| key.host.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(hosts.len(), 2);
+        assert!(hosts.contains(&"a.example".to_owned()));
+        assert!(hosts.contains(&"c.example".to_owned()));
+    }
+
+    #[test]
+    fn idle_clients_are_evicted() {
+        let clients = clients(10, Duration::ZERO);
+        clients.client("a.example", true, &[addr(1)], None).unwrap();
+        clients.evict_idle();
+        assert_eq!(clients.len(), 0);
+    }
+
+    #[test]
+    fn nothing_is_pooled_without_capacity() {
+        let clients = clients(0, Duration::from_secs(60));
+        clients.client("a.example", true, &[addr(1)], None).unwrap();
+        assert_eq!(clients.len(), 0);
+    }
+}
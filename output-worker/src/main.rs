@@ -1,9 +1,23 @@
+mod batch;
+mod circuit_breaker;
+mod client_certificate;
+mod dead_letters;
+mod delivery_credentials;
 mod dns;
+mod http_clients;
+mod message_queue;
 mod monitoring;
+mod oauth2;
 mod opentelemetry;
 mod pg;
 mod pulsar;
+mod response_rules;
+mod signing_key;
+mod subscription_secrets;
+mod throttle;
 mod throughput_log;
+mod transform;
+mod wakeups;
 mod work;
 
 use ::pulsar::{Authentication, ConnectionRetryOptions, Pulsar, TokioExecutor};
@@ -18,6 +32,7 @@ use hickory_resolver::config::LookupIpStrategy;
 use humantime::format_duration;
 use reqwest::Url;
 use reqwest::header::{HeaderName, RETRY_AFTER};
+use serde::Deserialize;
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::{PgConnection, PgPool, query, query_as};
 use std::str::FromStr;
@@ -35,8 +50,10 @@ use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use crate::dns::{DnsResolver, DnsResolverOptions};
+use crate::http_clients::{HttpClients, HttpClientsOptions};
 use crate::opentelemetry::{GiveUpReason, report_given_up};
 use crate::pulsar::LoadMode;
+use crate::response_rules::ResponseOutcome;
 use crate::work::*;
 use hook0_protobuf::RequestAttempt;
 
@@ -44,6 +61,8 @@ use hook0_protobuf::RequestAttempt;
 enum SignatureVersion {
     V0,
     V1,
+    /// Ed25519 signature, for the applications that have a signing key
+    V2,
 }
 
 /// Which address families to ask for when resolving a webhook target.
@@ -261,14 +280,26 @@ struct Config {
     #[clap(long, env, default_value_t = false)]
     dns_append_search_domains: bool,
 
+    /// Maximum number of HTTP clients kept for reuse, one per target host and set of vetted addresses, so that consecutive deliveries to a target reuse its connections (set to 0 to open new connections for every delivery)
+    #[clap(long, env, default_value_t = 1000)]
+    http_client_pool_size: usize,
+
+    /// Duration after which an HTTP client that no delivery used is dropped, closing its connections
+    #[clap(long, env, value_parser = humantime::parse_duration, default_value = "90s")]
+    http_client_idle_timeout: Duration,
+
     /// Name of the header containing webhook's signature
     #[clap(long, env, default_value = "X-Hook0-Signature")]
     signature_header_name: HeaderName,
 
     /// A comma-separated list of enabled signature versions
-    #[clap(long, env, default_value = "v1", value_delimiter = ',')]
+    #[clap(long, env, default_value = "v1,v2", value_delimiter = ',')]
     enabled_signature_versions: Vec<SignatureVersion>,
 
+    /// Key the private keys of client certificates and signing keys are encrypted with; it must be the same as the API's. Without it, deliveries of subscriptions that have a client certificate fail, and deliveries are not signed with the signing keys of applications
+    #[clap(long, env, hide_env_values = true, value_parser = client_certificate::parse_client_certificate_key)]
+    client_certificate_encryption_key: Option<client_certificate::ClientCertificateKey>,
+
     /// Loads request attempts that haven't been delivered yet from the DB into Pulsar before starting work; `all` loads everything; `due-now` skips request attempts scheduled more than ~10 s in the future; this is useful when migrating to a Pulsar worker (only for Pulsar workers)
     #[clap(long, env, default_value = "off")]
     load_waiting_request_attempts_into_pulsar: LoadWaitingRequestAttemptsIntoPulsarMode,
@@ -406,10 +437,17 @@ pub struct RequestAttemptWithOptionalPayload {
     pub http_method: String,
     pub http_url: String,
     pub http_headers: serde_json::Value,
+    pub message_queue: Option<serde_json::Value>,
     pub event_type_name: String,
     pub payload: Option<Vec<u8>>,
     pub payload_content_type: String,
     pub secret: Uuid,
+    pub payload_transform: Option<serde_json::Value>,
+    pub delivery_limits: Option<serde_json::Value>,
+    pub ordering_key: Option<String>,
+    pub batching: Option<serde_json::Value>,
+    pub signature_scheme: String,
+    pub response_rules: Option<serde_json::Value>,
 }
 
 #[tokio::main]
@@ -467,6 +505,17 @@ async fn main() -> anyhow::Result<()> {
         dns::pool_deadline(config.dns_timeout)
     );
 
+    // OAuth2 access tokens are shared by every unit, so a token is requested once per worker
+    let oauth2_tokens = Arc::new(oauth2::OAuth2Tokens::default());
+
+    // HTTP clients are shared by every unit too, so that deliveries to a target reuse its connections
+    let http_clients = Arc::new(HttpClients::new(HttpClientsOptions {
+        connect_timeout: config.connect_timeout,
+        timeout: config.timeout,
+        max_clients: config.http_client_pool_size,
+        idle_timeout: config.http_client_idle_timeout,
+    }));
+
     debug!("Connecting to database...");
     let pool = PgPoolOptions::new()
         .max_connections(config.max_db_connections)
@@ -477,6 +526,18 @@ async fn main() -> anyhow::Result<()> {
         .await?;
     info!("Connected to database");
 
+    // Delivery limits are counted outside of the transactions that hold request attempts, hence
+    // on connections of their own; they are only opened once a subscription has limits
+    let throttle = throttle::Throttle::new(
+        PgPoolOptions::new().max_connections(2).connect_lazy_with(
+            PgConnectOptions::from_str(&config.database_url)?.application_name(&format!(
+                "{}-{worker_version}-{worker_name}-throttle",
+                crate_name!(),
+            )),
+        ),
+        config.timeout + Duration::from_secs(60),
+    );
+
     rustls::crypto::aws_lc_rs::default_provider()
         .install_default()
         .unwrap();
@@ -521,11 +582,13 @@ async fn main() -> anyhow::Result<()> {
         );
     }
 
-    // Periodically collect metrics from database pool
+    // Periodically collect metrics from database pool, and drop idle HTTP clients
     let metrics_pool = pool.clone();
+    let metrics_http_clients = http_clients.clone();
     let metrics_pool_handle = spawn(async move {
         loop {
             opentelemetry::gather_pool_metrics(&metrics_pool);
+            metrics_http_clients.evict_idle();
             sleep(Duration::from_secs(15)).await
         }
     });
@@ -817,11 +880,16 @@ async fn main() -> anyhow::Result<()> {
 
             let stats_pulsar = stats.clone();
             let dr = resolver.clone();
+            let ot = oauth2_tokens.clone();
+            let hc = http_clients.clone();
+            let rp = retry_policy.clone();
+            let th = throttle.clone();
             tasks.spawn(async move {
                 loop {
                     let result = pulsar::look_for_work(
                         &c,
-                        retry_policy,
+                        &rp,
+                        &th,
                         &po,
                         &os,
                         &wid,
@@ -832,6 +900,8 @@ async fn main() -> anyhow::Result<()> {
                         &task_tracker_main,
                         &stats_pulsar,
                         &dr,
+                        &ot,
+                        &hc,
                     )
                     .await;
                     if let Err(ref e) = result {
@@ -851,6 +921,22 @@ async fn main() -> anyhow::Result<()> {
     } else {
         // This worker has a 'pg' queue type
 
+        // Idle units are woken up as soon as request attempts are stored; the listening
+        // connection is a dedicated one
+        let wakeups = wakeups::Wakeups::listen(
+            PgPoolOptions::new()
+                .max_connections(1)
+                .max_lifetime(None)
+                .idle_timeout(None)
+                .connect_lazy_with(
+                    PgConnectOptions::from_str(&config.database_url)?.application_name(&format!(
+                        "{}-{worker_version}-{}-listener",
+                        crate_name!(),
+                        worker.name,
+                    )),
+                ),
+        );
+
         for unit_id in 0..config.concurrent {
             let role = SlotRole::from_unit_id(
                 unit_id,
@@ -866,6 +952,11 @@ async fn main() -> anyhow::Result<()> {
             let tt = task_tracker_main.clone();
             let stats_pg = stats.clone();
             let dr = resolver.clone();
+            let ot = oauth2_tokens.clone();
+            let hc = http_clients.clone();
+            let wu = wakeups.clone();
+            let rp = retry_policy.clone();
+            let th = throttle.clone();
             task_tracker_main.spawn(async move {
                 // Start units progressively
                 sleep(Duration::from_millis(u64::from(unit_id) * 100)).await;
@@ -873,7 +964,8 @@ async fn main() -> anyhow::Result<()> {
                 loop {
                     let t = pg::look_for_work(
                         &cfg,
-                        retry_policy,
+                        &rp,
+                        &th,
                         unit_id,
                         role,
                         &p,
@@ -884,6 +976,9 @@ async fn main() -> anyhow::Result<()> {
                         &tt,
                         &stats_pg,
                         &dr,
+                        &ot,
+                        &hc,
+                        wu.clone(),
                     )
                     .await;
                     if let Err(ref e) = t {
@@ -971,13 +1066,28 @@ async fn get_worker(name: String, conn: &PgPool) -> anyhow::Result<Worker> {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct RetryPolicy {
     max_retries: u8,
+    /// Delays before each retry, the last one being used again for the following retries;
+    /// `None` is the built-in schedule.
+    backoff_steps: Option<Vec<Duration>>,
     jitter_ratio: f64,
     jitter_max_spread: Duration,
 }
 
+/// Retry schedule set on a subscription; validated by the API against the instance's ceilings.
+/// Every field left out keeps the value of the instance.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct SubscriptionRetryPolicy {
+    #[serde(default)]
+    max_retries: Option<u8>,
+    #[serde(default)]
+    backoff_steps_s: Option<Vec<u32>>,
+    #[serde(default)]
+    max_window_s: Option<u32>,
+}
+
 impl RetryPolicy {
     /// Minimum width of the random jitter window.
     const JITTER_MIN_SPREAD: Duration = Duration::from_secs(2);
@@ -998,24 +1108,63 @@ impl RetryPolicy {
         }
         Ok(Self {
             max_retries: config.max_retries,
+            backoff_steps: None,
             jitter_ratio: config.retry_jitter_ratio,
             jitter_max_spread: config.retry_jitter_max_spread,
         })
     }
 
+    /// This policy with the overrides of a subscription applied.
+    ///
+    /// A `max_window_s` is turned into a number of retries here, once, as the worst-case count
+    /// that fits in it; without one, `max_retries` stays the only limit.
+    fn overridden_by(&self, overrides: SubscriptionRetryPolicy) -> Self {
+        let mut policy = Self {
+            max_retries: overrides.max_retries.unwrap_or(self.max_retries),
+            backoff_steps: overrides
+                .backoff_steps_s
+                .filter(|steps| !steps.is_empty())
+                .map(|steps| {
+                    steps
+                        .into_iter()
+                        .map(|s| Duration::from_secs(s.into()))
+                        .collect()
+                })
+                .or_else(|| self.backoff_steps.clone()),
+            ..*self
+        };
+        if let Some(max_window_s) = overrides.max_window_s {
+            policy.max_retries = policy.evaluate(Duration::from_secs(max_window_s.into())).0;
+        }
+        policy
+    }
+
+    /// Delay before the retry that follows `retry_count` failed attempts, whether or not
+    /// retries are exhausted.
+    fn step(&self, retry_count: i16) -> Duration {
+        match &self.backoff_steps {
+            Some(steps) => usize::try_from(retry_count)
+                .ok()
+                .and_then(|i| steps.get(i).or(steps.last()))
+                .copied()
+                .unwrap_or_default(),
+            None => match retry_count {
+                0 => Duration::from_secs(3),
+                1 => Duration::from_secs(10),
+                2 => Duration::from_secs(3 * 60),
+                3 => Duration::from_secs(30 * 60),
+                4 => Duration::from_hours(1),
+                5 => Duration::from_hours(3),
+                6 => Duration::from_hours(5),
+                _ => Duration::from_hours(10),
+            },
+        }
+    }
+
     /// Deterministic base delay from the retry schedule, or `None` once retries are exhausted.
     fn base_delay(&self, retry_count: i16) -> Option<Duration> {
         if retry_count < self.max_retries.into() {
-            match retry_count {
-                0 => Some(Duration::from_secs(3)),
-                1 => Some(Duration::from_secs(10)),
-                2 => Some(Duration::from_secs(3 * 60)),
-                3 => Some(Duration::from_secs(30 * 60)),
-                4 => Some(Duration::from_hours(1)),
-                5 => Some(Duration::from_hours(3)),
-                6 => Some(Duration::from_hours(5)),
-                _ => Some(Duration::from_hours(10)),
-            }
+            Some(self.step(retry_count))
         } else {
             None
         }
@@ -1099,17 +1248,13 @@ impl RetryPolicy {
         let mut effective_retries = 0;
 
         for i in 0..self.max_retries {
-            match self.base_delay(i.into()) {
-                Some(base) => {
-                    let d = base.saturating_add(self.jitter_spread(base));
-                    if cumulative.saturating_add(d) > max_retry_window {
-                        break;
-                    }
-                    cumulative = cumulative.saturating_add(d);
-                    effective_retries = i + 1;
-                }
-                None => break,
+            let base = self.step(i.into());
+            let d = base.saturating_add(self.jitter_spread(base));
+            if cumulative.saturating_add(d) > max_retry_window {
+                break;
             }
+            cumulative = cumulative.saturating_add(d);
+            effective_retries = i + 1;
         }
 
         (effective_retries, cumulative)
@@ -1140,8 +1285,30 @@ async fn compute_next_retry(
     conn: &mut PgConnection,
     attempt: &RequestAttempt,
     response: &Response,
-    policy: RetryPolicy,
+    policy: &RetryPolicy,
 ) -> Result<Option<Duration>, sqlx::Error> {
+    match response.rule_outcome {
+        Some(ResponseOutcome::PermanentFailure) => {
+            info!(request_attempt_id = %attempt.request_attempt_id, http_code = ?response.http_code, "Response rule marked the delivery as a permanent failure; giving up");
+            report_given_up(GiveUpReason::PermanentFailure);
+            dead_letters::record(conn, attempt, GiveUpReason::PermanentFailure).await?;
+            return Ok(None);
+        }
+        Some(ResponseOutcome::DisableSubscription) => {
+            // Like a tripped circuit breaker: nobody waits for the deliveries of a disabled subscription
+            let reason = match response.http_code {
+                Some(code) => format!("A response rule matched an HTTP {code} response"),
+                None => "A response rule matched".to_owned(),
+            };
+            if circuit_breaker::disable(conn, attempt, &reason).await? {
+                warn!(subscription_id = %attempt.subscription_id, request_attempt_id = %attempt.request_attempt_id, "{reason}; subscription disabled");
+            }
+            report_given_up(GiveUpReason::SubscriptionGone);
+            return Ok(None);
+        }
+        Some(ResponseOutcome::Success | ResponseOutcome::Retryable) | None => {}
+    }
+
     match response.response_error {
         Some(ResponseError::InvalidHeader) => {
             let msg = response
@@ -1151,6 +1318,19 @@ async fn compute_next_retry(
                 .unwrap_or("???");
             error!(request_attempt_id = %attempt.request_attempt_id, "Could not construct signature ({msg}); giving up");
             report_given_up(GiveUpReason::SignatureFailed);
+            dead_letters::record(conn, attempt, GiveUpReason::SignatureFailed).await?;
+            Ok(None)
+        }
+        Some(ResponseError::Transform) => {
+            // Retrying would apply the same transform to the same payload and fail the same way
+            let msg = response
+                .body
+                .as_ref()
+                .and_then(|bytes| str::from_utf8(bytes).ok())
+                .unwrap_or("???");
+            warn!(request_attempt_id = %attempt.request_attempt_id, "Could not transform payload ({msg}); giving up");
+            report_given_up(GiveUpReason::TransformFailed);
+            dead_letters::record(conn, attempt, GiveUpReason::TransformFailed).await?;
             Ok(None)
         }
         _ => {
@@ -1165,7 +1345,7 @@ async fn compute_next_retry(
 
             let sub = query!(
                 "
-                    SELECT true AS whatever
+                    SELECT s.retry_policy
                     FROM webhook.subscription AS s
                     INNER JOIN event.application AS a ON a.application__id = s.application__id
                     WHERE s.subscription__id = $1
@@ -1175,10 +1355,22 @@ async fn compute_next_retry(
                 ",
                 attempt.subscription_id
             )
-            .fetch_optional(conn)
+            .fetch_optional(&mut *conn)
             .await?;
 
-            if sub.is_some() {
+            if let Some(sub) = sub {
+                let overridden;
+                let policy = match sub.retry_policy.map(SubscriptionRetryPolicy::deserialize) {
+                    Some(Ok(overrides)) => {
+                        overridden = policy.overridden_by(overrides);
+                        &overridden
+                    }
+                    Some(Err(e)) => {
+                        warn!(subscription_id = %attempt.subscription_id, "Retry policy is invalid ({e}); using the default one");
+                        policy
+                    }
+                    None => policy,
+                };
                 let next_delay = policy.next_delay_honouring(
                     attempt.retry_count,
                     rand::random::<f64>(),
@@ -1187,6 +1379,7 @@ async fn compute_next_retry(
                 );
                 if next_delay.is_none() {
                     report_given_up(GiveUpReason::RetriesExhausted);
+                    dead_letters::record(conn, attempt, GiveUpReason::RetriesExhausted).await?;
                 }
                 Ok(next_delay)
             } else {
@@ -1208,6 +1401,7 @@ mod tests {
     fn no_jitter(max_retries: u8) -> RetryPolicy {
         RetryPolicy {
             max_retries,
+            backoff_steps: None,
             jitter_ratio: 0.0,
             jitter_max_spread: Duration::ZERO,
         }
@@ -1217,6 +1411,7 @@ mod tests {
     fn default_jitter(max_retries: u8) -> RetryPolicy {
         RetryPolicy {
             max_retries,
+            backoff_steps: None,
             jitter_ratio: 0.1,
             jitter_max_spread: Duration::from_secs(15 * 60),
         }
@@ -1259,12 +1454,51 @@ mod tests {
         assert!(cumulative < window);
     }
 
+    #[test]
+    fn test_subscription_max_window_ends_retries() {
+        let policy = no_jitter(30).overridden_by(
+            SubscriptionRetryPolicy::deserialize(&serde_json::json!({ "max_window_s": 15 }))
+                .unwrap(),
+        );
+        assert_eq!(policy.base_delay(1), Some(Duration::from_secs(10)));
+        assert_eq!(policy.base_delay(2), None);
+    }
+
+    #[test]
+    fn test_subscription_overrides_replace_the_schedule() {
+        let policy = no_jitter(24).overridden_by(
+            SubscriptionRetryPolicy::deserialize(
+                &serde_json::json!({ "max_retries": 4, "backoff_steps_s": [1, 5] }),
+            )
+            .unwrap(),
+        );
+        assert_eq!(policy.base_delay(0), Some(Duration::from_secs(1)));
+        assert_eq!(policy.base_delay(1), Some(Duration::from_secs(5)));
+        // The last step is used again for the following retries
+        assert_eq!(policy.base_delay(3), Some(Duration::from_secs(5)));
+        assert_eq!(policy.base_delay(4), None);
+    }
+
+    #[test]
+    fn test_subscription_overrides_keep_what_they_leave_out() {
+        let instance = default_jitter(24);
+        let policy = instance.overridden_by(
+            SubscriptionRetryPolicy::deserialize(&serde_json::json!({ "backoff_steps_s": [60] }))
+                .unwrap(),
+        );
+        assert_eq!(policy.max_retries, instance.max_retries);
+        assert_eq!(policy.jitter_ratio, instance.jitter_ratio);
+        assert_eq!(policy.base_delay(23), Some(Duration::from_secs(60)));
+        assert_eq!(policy.base_delay(24), None);
+    }
+
     #[test]
     fn test_jitter_spread_is_disabled_by_a_non_positive_or_invalid_ratio() {
         let base = Duration::from_secs(3);
         for ratio in [0.0, -0.5, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
             let policy = RetryPolicy {
                 max_retries: 30,
+                backoff_steps: None,
                 jitter_ratio: ratio,
                 jitter_max_spread: Duration::from_secs(15 * 60),
             };
@@ -1280,6 +1514,7 @@ mod tests {
     fn test_jitter_spread_is_disabled_by_a_zero_max_spread() {
         let policy = RetryPolicy {
             max_retries: 30,
+            backoff_steps: None,
             jitter_ratio: 0.1,
             jitter_max_spread: Duration::ZERO,
         };
@@ -1325,6 +1560,7 @@ mod tests {
     fn test_jitter_spread_cap_beats_floor_without_panicking() {
         let policy = RetryPolicy {
             max_retries: 30,
+            backoff_steps: None,
             jitter_ratio: 0.1,
             jitter_max_spread: Duration::from_secs(1),
         };
@@ -1340,6 +1576,7 @@ mod tests {
         // A ratio this large overflows `Duration`; it must saturate to the cap, not panic
         let policy = RetryPolicy {
             max_retries: 30,
+            backoff_steps: None,
             jitter_ratio: f64::MAX,
             jitter_max_spread: Duration::from_secs(15 * 60),
         };
@@ -1508,6 +1745,7 @@ mod tests {
             headers,
             body: None,
             elapsed_time: Duration::ZERO,
+            rule_outcome: None,
         }
     }
 
@@ -1646,6 +1884,7 @@ mod tests {
             let now = a_moment();
             let policy = RetryPolicy {
                 max_retries: 24,
+                    backoff_steps: None,
                 jitter_ratio,
                 jitter_max_spread: Duration::from_secs(15 * 60),
             };
@@ -0,0 +1,621 @@
+//! Delivery to a message queue rather than to an HTTP endpoint.
+//!
+//! The target of a subscription can be an exchange of an AMQP 0.9.1 broker or an SQS-compatible
+//! queue. The message body is what an HTTP target would receive, and so are the headers sent along
+//! with it (content type, event ID and type, batch ID, signature): they become message headers
+//! (AMQP) or message attributes (SQS), so that consumers verify the signature the same way. The
+//! broker or queue is reached like an HTTP target: its addresses are vetted and pinned, and redirects
+//! are not followed. A message is only delivered once the broker confirmed it.
+
+use aws_credential_types::Credentials;
+use aws_sigv4::http_request::{SignableBody, SignableRequest, SigningSettings, sign};
+use aws_sigv4::sign::v4;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use lapin::options::{BasicPublishOptions, ConfirmSelectOptions};
+use lapin::publisher_confirm::Confirmation;
+use lapin::tcp::{TLSConfig, TcpStream};
+use lapin::types::{AMQPValue, FieldTable, LongString, ShortString};
+use lapin::uri::{AMQPScheme, AMQPUri, AMQPUserInfo};
+use lapin::{BasicProperties, Connection, ConnectionProperties};
+use reqwest::Url;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+use std::net::SocketAddr;
+use std::time::{Instant, SystemTime};
+use tokio::time::timeout;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::Config;
+use crate::dns::DnsResolver;
+use crate::work::{Response, ResponseError, mk_http_client};
+
+/// Message attribute telling SQS consumers that the body is base64-encoded, because the payload
+/// could not be sent as SQS message text.
+const SQS_TRANSFER_ENCODING_ATTRIBUTE: &str = "content-transfer-encoding";
+
+/// A message queue a subscription delivers to, as the API stored it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum MessageQueueTarget {
+    Amqp {
+        url: String,
+        username: String,
+        password: String,
+        exchange: String,
+        routing_key: String,
+    },
+    Sqs {
+        queue_url: String,
+        region: String,
+        access_key_id: String,
+        secret_access_key: String,
+    },
+}
+
+impl MessageQueueTarget {
+    pub fn from_value(value: &Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value.to_owned())
+    }
+}
+
+/// A message ready to be published, signature included.
+#[derive(Debug)]
+pub struct Message<'a> {
+    pub headers: &'a HeaderMap,
+    pub body: Vec<u8>,
+    /// Identifies the message for the consumers and for deduplication: the request attempt or the
+    /// batch it delivers.
+    pub id: Uuid,
+    /// Messages of a subscription keep their order in FIFO queues.
+    pub subscription_id: Uuid,
+}
+
+/// Publish a message to the message queue of a subscription.
+pub async fn publish(
+    config: &Config,
+    resolver: &DnsResolver,
+    target: &MessageQueueTarget,
+    message: Message<'_>,
+    start: Instant,
+) -> Response {
+    match target {
+        MessageQueueTarget::Amqp {
+            url,
+            username,
+            password,
+            exchange,
+            routing_key,
+        } => {
+            publish_amqp(
+                config,
+                resolver,
+                url,
+                AMQPUserInfo {
+                    username: username.to_owned(),
+                    password: password.to_owned(),
+                },
+                exchange,
+                routing_key,
+                message,
+                start,
+            )
+            .await
+        }
+        MessageQueueTarget::Sqs {
+            queue_url,
+            region,
+            access_key_id,
+            secret_access_key,
+        } => {
+            let credentials =
+                Credentials::new(access_key_id, secret_access_key, None, None, "subscription");
+            publish_sqs(
+                config,
+                resolver,
+                queue_url,
+                region,
+                credentials,
+                message,
+                start,
+            )
+            .await
+        }
+    }
+}
+
+fn failure(response_error: ResponseError, message: String, start: Instant) -> Response {
+    warn!(%response_error, "Could not publish message: {message}");
+    Response {
+        response_error: Some(response_error),
+        http_code: None,
+        headers: None,
+        body: Some(message.into_bytes()),
+        elapsed_time: start.elapsed(),
+        rule_outcome: None,
+    }
+}
+
+/// Resolve the host of a broker or queue like the host of an HTTP target.
+async fn resolve(
+    config: &Config,
+    resolver: &DnsResolver,
+    url: &Url,
+    start: Instant,
+) -> Result<Vec<SocketAddr>, Box<Response>> {
+    resolver
+        .resolve_target(url, config.disable_target_ip_check)
+        .await
+        .map_err(|e| {
+            if let Some(detail) = e.detail() {
+                debug!(%url, "Could not resolve message queue: {detail}");
+            }
+            Box::new(failure(e.response_error(), e.to_string(), start))
+        })
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn publish_amqp(
+    config: &Config,
+    resolver: &DnsResolver,
+    url: &str,
+    userinfo: AMQPUserInfo,
+    exchange: &str,
+    routing_key: &str,
+    message: Message<'_>,
+    start: Instant,
+) -> Response {
+    // The URL of the broker is resolved with the port the AMQP URI defaults to
+    let (mut uri, url) = match url.parse::<AMQPUri>().and_then(|uri| {
+        let mut url = Url::parse(url).map_err(|e| e.to_string())?;
+        url.set_port(Some(uri.authority.port))
+            .map_err(|_| "URL cannot have a port".to_owned())?;
+        Ok((uri, url))
+    }) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return failure(
+                ResponseError::InvalidTarget,
+                format!("Broker URL is invalid ({e})"),
+                start,
+            );
+        }
+    };
+    uri.authority.userinfo = userinfo;
+    let addrs = match resolve(config, resolver, &url, start).await {
+        Ok(addrs) => addrs,
+        Err(response) => return *response,
+    };
+
+    // The connection goes to the vetted addresses only; TLS still checks the name of the broker
+    let connect_timeout = config.connect_timeout;
+    // The error type is lapin's
+    #[allow(clippy::result_large_err)]
+    let connect = Box::new(move |uri: &AMQPUri| {
+        let stream = TcpStream::connect_timeout(addrs.as_slice(), connect_timeout)?;
+        let stream = match uri.scheme {
+            AMQPScheme::AMQP => stream,
+            AMQPScheme::AMQPS => stream.into_tls(&uri.authority.host, TLSConfig::default())?,
+        };
+        stream.set_nonblocking(true)?;
+        Ok(stream)
+    });
+    let connection = match timeout(
+        config.timeout,
+        Connection::connector(uri, connect, ConnectionProperties::default()),
+    )
+    .await
+    {
+        Ok(Ok(connection)) => connection,
+        Ok(Err(e)) => {
+            return failure(
+                ResponseError::Connection,
+                format!("Could not connect to the broker ({e})"),
+                start,
+            );
+        }
+        Err(_) => {
+            return failure(
+                ResponseError::Timeout,
+                "Timed out while connecting to the broker".to_owned(),
+                start,
+            );
+        }
+    };
+
+    let properties = amqp_properties(&message);
+    let confirmation = timeout(config.timeout.saturating_sub(start.elapsed()), async {
+        let channel = connection.create_channel().await?;
+        channel
+            .confirm_select(ConfirmSelectOptions::default())
+            .await?;
+        channel
+            .basic_publish(
+                exchange,
+                routing_key,
+                // A message no queue is bound to receive is returned rather than dropped
+                BasicPublishOptions {
+                    mandatory: true,
+                    ..Default::default()
+                },
+                &message.body,
+                properties,
+            )
+            .await?
+            .await
+    })
+    .await;
+    // Dropping the connection closes it
+
+    match confirmation {
+        Ok(Ok(Confirmation::Ack(None))) => {
+            debug!("Message was confirmed by the broker");
+            Response {
+                response_error: None,
+                http_code: None,
+                headers: None,
+                body: None,
+                elapsed_time: start.elapsed(),
+                rule_outcome: None,
+            }
+        }
+        Ok(Ok(Confirmation::Ack(Some(returned)))) => failure(
+            ResponseError::MessageQueue,
+            format!(
+                "Broker returned the message: {} {}",
+                returned.reply_code, returned.reply_text
+            ),
+            start,
+        ),
+        Ok(Ok(Confirmation::Nack(_) | Confirmation::NotRequested)) => failure(
+            ResponseError::MessageQueue,
+            "Broker refused the message".to_owned(),
+            start,
+        ),
+        Ok(Err(e)) => failure(
+            ResponseError::MessageQueue,
+            format!("Could not publish to the broker ({e})"),
+            start,
+        ),
+        Err(_) => failure(
+            ResponseError::Timeout,
+            "Timed out while publishing to the broker".to_owned(),
+            start,
+        ),
+    }
+}
+
+/// Properties of an AMQP message: persistent, with the headers of the delivery.
+fn amqp_properties(message: &Message) -> BasicProperties {
+    let mut headers = FieldTable::default();
+    for (name, value) in message.headers {
+        headers.insert(
+            ShortString::from(name.as_str()),
+            AMQPValue::LongString(LongString::from(value.as_bytes())),
+        );
+    }
+
+    let mut properties = BasicProperties::default()
+        .with_message_id(ShortString::from(message.id.to_string()))
+        .with_delivery_mode(2)
+        .with_headers(headers);
+    if let Some(content_type) = message
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+    {
+        properties = properties.with_content_type(ShortString::from(content_type));
+    }
+    if let Some(event_type) = message
+        .headers
+        .get("x-event-type")
+        .and_then(|v| v.to_str().ok())
+    {
+        properties = properties.with_type(ShortString::from(event_type));
+    }
+    properties
+}
+
+async fn publish_sqs(
+    config: &Config,
+    resolver: &DnsResolver,
+    queue_url: &str,
+    region: &str,
+    credentials: Credentials,
+    message: Message<'_>,
+    start: Instant,
+) -> Response {
+    let endpoint = match Url::parse(queue_url).and_then(|url| url.join("/")) {
+        Ok(endpoint) => endpoint,
+        Err(e) => {
+            return failure(
+                ResponseError::InvalidTarget,
+                format!("Queue URL is invalid ({e})"),
+                start,
+            );
+        }
+    };
+    let addrs = match resolve(config, resolver, &endpoint, start).await {
+        Ok(addrs) => addrs,
+        Err(response) => return *response,
+    };
+    let pin = endpoint.domain().map(|host| (host, addrs.as_slice()));
+    let client = match mk_http_client(config.connect_timeout, config.timeout, pin, None) {
+        Ok(client) => client,
+        Err(e) => {
+            return failure(ResponseError::Unknown, e.to_string(), start);
+        }
+    };
+
+    let body = sqs_send_message_body(queue_url, &message);
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("application/x-amz-json-1.0"),
+    );
+    headers.insert(
+        HeaderName::from_static("x-amz-target"),
+        HeaderValue::from_static("AmazonSQS.SendMessage"),
+    );
+    if let Err(e) = sign_sqs_request(&endpoint, region, credentials, &mut headers, &body) {
+        return failure(
+            ResponseError::InvalidTarget,
+            format!("Could not sign the request to the queue ({e})"),
+            start,
+        );
+    }
+
+    match client
+        .post(endpoint)
+        .headers(headers)
+        .body(body)
+        .send()
+        .await
+    {
+        Ok(res) => {
+            let status = res.status();
+            let headers = res.headers().clone();
+            let body = res.bytes().await.ok().map(|b| b.to_vec());
+            if status.is_success() {
+                debug!("Message was accepted by the queue");
+            } else {
+                warn!(http_status = %status, "Queue refused the message");
+            }
+            Response {
+                response_error: (!status.is_success()).then_some(ResponseError::MessageQueue),
+                http_code: Some(status.as_u16()),
+                headers: Some(headers),
+                body,
+                elapsed_time: start.elapsed(),
+                rule_outcome: None,
+            }
+        }
+        Err(e) if e.is_connect() => failure(ResponseError::Connection, e.to_string(), start),
+        Err(e) if e.is_timeout() => failure(ResponseError::Timeout, e.to_string(), start),
+        Err(e) => failure(ResponseError::Unknown, e.to_string(), start),
+    }
+}
+
+/// The body of an SQS `SendMessage` request, in the JSON protocol.
+fn sqs_send_message_body(queue_url: &str, message: &Message) -> Vec<u8> {
+    let string_attribute = |value: &str| json!({ "DataType": "String", "StringValue": value });
+    let mut attributes = message
+        .headers
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                string_attribute(&String::from_utf8_lossy(value.as_bytes())),
+            )
+        })
+        .collect::<Map<_, _>>();
+
+    // Message text is Unicode without most control characters; anything else is sent in base64
+    let message_body = match std::str::from_utf8(&message.body) {
+        Ok(text) if text.chars().all(is_sqs_message_character) => text.to_owned(),
+        _ => {
+            attributes.insert(
+                SQS_TRANSFER_ENCODING_ATTRIBUTE.to_owned(),
+                string_attribute("base64"),
+            );
+            STANDARD.encode(&message.body)
+        }
+    };
+
+    let mut body = json!({
+        "QueueUrl": queue_url,
+        "MessageBody": message_body,
+        "MessageAttributes": attributes,
+    });
+    if queue_url.ends_with(".fifo") {
+        body["MessageGroupId"] = Value::String(message.subscription_id.to_string());
+        body["MessageDeduplicationId"] = Value::String(message.id.to_string());
+    }
+    serde_json::to_vec(&body).expect("could not serialize SQS request")
+}
+
+fn is_sqs_message_character(c: char) -> bool {
+    matches!(c, '\u{9}' | '\u{A}' | '\u{D}' | '\u{20}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}' | '\u{10000}'..)
+}
+
+/// Sign a request to SQS with AWS Signature Version 4, adding the headers it needs.
+fn sign_sqs_request(
+    endpoint: &Url,
+    region: &str,
+    credentials: Credentials,
+    headers: &mut HeaderMap,
+    body: &[u8],
+) -> anyhow::Result<()> {
+    let identity = credentials.into();
+    let params = v4::SigningParams::builder()
+        .identity(&identity)
+        .region(region)
+        .name("sqs")
+        .time(SystemTime::now())
+        .settings(SigningSettings::default())
+        .build()?
+        .into();
+    let signable = SignableRequest::new(
+        "POST",
+        endpoint.as_str(),
+        headers
+            .iter()
+            .map(|(name, value)| Ok((name.as_str(), value.to_str()?)))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter(),
+        SignableBody::Bytes(body),
+    )?;
+    let (instructions, _signature) = sign(signable, &params)?.into_parts();
+    for (name, value) in instructions.headers() {
+        let mut value = HeaderValue::from_str(value)?;
+        value.set_sensitive(name.eq_ignore_ascii_case("authorization"));
+        headers.insert(HeaderName::from_bytes(name.as_bytes())?, value);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message<'a>(headers: &'a HeaderMap, body: &[u8]) -> Message<'a> {
+        Message {
+            headers,
+            body: body.to_vec(),
+            id: Uuid::from_u128(1),
+            subscription_id: Uuid::from_u128(2),
+        }
+    }
+
+    fn headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(
+            "x-event-type",
+            HeaderValue::from_static("billing.invoice.paid"),
+        );
+        headers.insert("x-hook0-signature", HeaderValue::from_static("t=1,v0=abc"));
+        headers
+    }
+
+    #[test]
+    fn targets_are_read_as_the_api_stores_them() {
+        let target = MessageQueueTarget::from_value(&json!({
+            "type": "sqs",
+            "queue_url": "http://localhost:9324/000000000000/events",
+            "region": "elasticmq",
+            "access_key_id": "x",
+            "secret_access_key": "y",
+        }))
+        .unwrap();
+        assert_eq!(
+            target,
+            MessageQueueTarget::Sqs {
+                queue_url: "http://localhost:9324/000000000000/events".to_owned(),
+                region: "elasticmq".to_owned(),
+                access_key_id: "x".to_owned(),
+                secret_access_key: "y".to_owned(),
+            }
+        );
+        assert!(MessageQueueTarget::from_value(&json!({ "type": "http" })).is_err());
+    }
+
+    #[test]
+    fn amqp_messages_carry_the_headers_of_the_delivery() {
+        let headers = headers();
+        let properties = amqp_properties(&message(&headers, b"{}"));
+        assert_eq!(
+            properties.content_type().as_ref().map(|s| s.as_str()),
+            Some("application/json")
+        );
+        assert_eq!(
+            properties.kind().as_ref().map(|s| s.as_str()),
+            Some("billing.invoice.paid")
+        );
+        assert_eq!(
+            properties.message_id().as_ref().map(|s| s.as_str()),
+            Some(Uuid::from_u128(1).to_string().as_str())
+        );
+        assert_eq!(*properties.delivery_mode(), Some(2));
+        let table = properties.headers().as_ref().unwrap();
+        assert!(table.contains_key("x-hook0-signature"));
+        assert!(table.contains_key("content-type"));
+    }
+
+    #[test]
+    fn sqs_messages_carry_the_headers_of_the_delivery_as_attributes() {
+        let headers = headers();
+        let body: Value = serde_json::from_slice(&sqs_send_message_body(
+            "https://sqs.eu-west-3.amazonaws.com/123456789012/events",
+            &message(&headers, br#"{"id":1}"#),
+        ))
+        .unwrap();
+        assert_eq!(body["MessageBody"], r#"{"id":1}"#);
+        assert_eq!(
+            body["MessageAttributes"]["x-hook0-signature"],
+            json!({ "DataType": "String", "StringValue": "t=1,v0=abc" })
+        );
+        assert_eq!(body.get("MessageGroupId"), None);
+    }
+
+    #[test]
+    fn sqs_fifo_queues_get_a_group_and_a_deduplication_id() {
+        let headers = headers();
+        let body: Value = serde_json::from_slice(&sqs_send_message_body(
+            "https://sqs.eu-west-3.amazonaws.com/123456789012/events.fifo",
+            &message(&headers, b"{}"),
+        ))
+        .unwrap();
+        assert_eq!(body["MessageGroupId"], Uuid::from_u128(2).to_string());
+        assert_eq!(
+            body["MessageDeduplicationId"],
+            Uuid::from_u128(1).to_string()
+        );
+    }
+
+    #[test]
+    fn payloads_that_are_not_sqs_message_text_are_sent_in_base64() {
+        let headers = headers();
+        for payload in [&b"\x00\xff"[..], &b"bell\x07"[..]] {
+            let body: Value = serde_json::from_slice(&sqs_send_message_body(
+                "https://sqs.eu-west-3.amazonaws.com/123456789012/events",
+                &message(&headers, payload),
+            ))
+            .unwrap();
+            assert_eq!(body["MessageBody"], STANDARD.encode(payload));
+            assert_eq!(
+                body["MessageAttributes"][SQS_TRANSFER_ENCODING_ATTRIBUTE]["StringValue"],
+                "base64"
+            );
+        }
+    }
+
+    #[test]
+    fn sqs_requests_are_signed_for_the_region() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-amz-json-1.0"),
+        );
+        sign_sqs_request(
+            &Url::parse("http://localhost:9324/").unwrap(),
+            "elasticmq",
+            Credentials::new("AKID", "secret", None, None, "test"),
+            &mut headers,
+            b"{}",
+        )
+        .unwrap();
+        let authorization = headers.get("authorization").unwrap();
+        assert!(authorization.is_sensitive());
+        assert!(authorization.to_str().unwrap().contains("Credential=AKID/"));
+        assert!(
+            authorization
+                .to_str()
+                .unwrap()
+                .contains("/elasticmq/sqs/aws4_request")
+        );
+        assert!(headers.contains_key("x-amz-date"));
+    }
+}
@@ -0,0 +1,285 @@
+//! OAuth2 access tokens that subscriptions send to their target as bearer tokens.
+//!
+//! A token is obtained with the client credentials grant (RFC 6749 section 4.4) from the token
+//! endpoint of the subscription, and shared by every unit of the worker until shortly before it
+//! expires. Units that need the token of a subscription at the same time wait for a single request
+//! to the token endpoint. The token endpoint is called like a target: its addresses are vetted and
+//! pinned, and redirects are not followed.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, HeaderValue};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::Config;
+use crate::dns::DnsResolver;
+use crate::work::mk_http_client;
+
+/// How long before it expires a token stops being used, so that it does not expire in flight.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// How long a token is used when the token endpoint does not say when it expires.
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(5 * 60);
+
+/// Longest part of an error answered by the token endpoint that is quoted in the response body.
+const MAX_QUOTED_ERROR_LENGTH: usize = 500;
+
+/// The OAuth2 client credentials of a subscription, as stored.
+#[derive(Debug, Clone)]
+pub struct OAuth2ClientCredentials {
+    pub subscription_id: Uuid,
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scopes: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    access_token: String,
+    /// Credentials the token was obtained with; updating them makes it stale.
+    credentials_updated_at: DateTime<Utc>,
+    refresh_at: Instant,
+}
+
+/// Access tokens of the subscriptions, shared by every unit of the worker.
+#[derive(Debug, Default)]
+pub struct OAuth2Tokens {
+    slots: Mutex<HashMap<Uuid, Arc<tokio::sync::Mutex<Option<CachedToken>>>>>,
+}
+
+impl OAuth2Tokens {
+    fn slot(&self, subscription_id: Uuid) -> Arc<tokio::sync::Mutex<Option<CachedToken>>> {
+        self.slots
+            .lock()
+            .expect("OAuth2 token slots were poisoned")
+            .entry(subscription_id)
+            .or_default()
+            .clone()
+    }
+
+    /// The access token to send, requested from the token endpoint unless a cached one is still
+    /// good. The error is customer-visible.
+    pub async fn access_token(
+        &self,
+        config: &Config,
+        resolver: &DnsResolver,
+        credentials: &OAuth2ClientCredentials,
+    ) -> Result<String, String> {
+        let slot = self.slot(credentials.subscription_id);
+        let mut cached = slot.lock().await;
+
+        if let Some(token) = cached.as_ref().filter(|token| {
+            token.credentials_updated_at == credentials.updated_at
+                && token.refresh_at > Instant::now()
+        }) {
+            return Ok(token.access_token.clone());
+        }
+
+        let requested_at = Instant::now();
+        let token = request_token(config, resolver, credentials)
+            .await
+            .map_err(|e| format!("Could not obtain an OAuth2 access token: {e}"))?;
+        debug!(subscription_id = %credentials.subscription_id, "Obtained an OAuth2 access token");
+
+        let lifetime = token
+            .expires_in
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_LIFETIME);
+        *cached = Some(CachedToken {
+            access_token: token.access_token.clone(),
+            credentials_updated_at: credentials.updated_at,
+            refresh_at: requested_at + lifetime.saturating_sub(EXPIRY_MARGIN),
+        });
+        Ok(token.access_token)
+    }
+
+    /// Stop using a token the target refused, unless it was already replaced.
+    pub async fn forget(&self, subscription_id: Uuid, access_token: &str) {
+        let slot = self.slot(subscription_id);
+        let mut cached = slot.lock().await;
+        if cached
+            .as_ref()
+            .is_some_and(|token| token.access_token == access_token)
+        {
+            *cached = None;
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    token_type: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// The form-encoded body of the token request.
+fn token_request_body(credentials: &OAuth2ClientCredentials) -> String {
+    let mut form = url::form_urlencoded::Serializer::new(String::new());
+    form.append_pair("grant_type", "client_credentials");
+    if !credentials.scopes.is_empty() {
+        form.append_pair("scope", &credentials.scopes.join(" "));
+    }
+    form.finish()
+}
+
+/// Client authentication with HTTP Basic, which every authorization server must support (RFC 6749
+/// section 2.3.1); the client ID and secret are form-encoded first.
+fn basic_authorization(credentials: &OAuth2ClientCredentials) -> Result<HeaderValue, String> {
+    let encode = |s: &str| url::form_urlencoded::byte_serialize(s.as_bytes()).collect::<String>();
+    let encoded = STANDARD.encode(format!(
+        "{}:{}",
+        encode(&credentials.client_id),
+        encode(&credentials.client_secret)
+    ));
+    let mut value = HeaderValue::from_str(&format!("Basic {encoded}"))
+        .map_err(|_| "client credentials cannot be sent".to_owned())?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+async fn request_token(
+    config: &Config,
+    resolver: &DnsResolver,
+    credentials: &OAuth2ClientCredentials,
+) -> Result<TokenResponse, String> {
+    let url =
+        Url::parse(&credentials.token_url).map_err(|e| format!("token URL is invalid ({e})"))?;
+    let addrs = resolver
+        .resolve_target(&url, config.disable_target_ip_check)
+        .await
+        .map_err(|e| format!("token endpoint cannot be reached ({e})"))?;
+    let pin = url.domain().map(|host| (host, addrs.as_slice()));
+    let client =
+        mk_http_client(config.connect_timeout, config.timeout, pin, None).map_err(|e| {
+            warn!("Could not create HTTP client: {e}");
+            "HTTP client cannot be created".to_owned()
+        })?;
+
+    let response = client
+        .post(url)
+        .header(AUTHORIZATION, basic_authorization(credentials)?)
+        .header(ACCEPT, HeaderValue::from_static("application/json"))
+        .header(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        )
+        .body(token_request_body(credentials))
+        .send()
+        .await
+        .map_err(|e| format!("token endpoint cannot be reached ({e})"))?;
+    let status = response.status();
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| format!("token endpoint answer cannot be read ({e})"))?;
+
+    if !status.is_success() {
+        let quoted = String::from_utf8_lossy(&body)
+            .chars()
+            .take(MAX_QUOTED_ERROR_LENGTH)
+            .collect::<String>();
+        return Err(format!("token endpoint answered HTTP {status}: {quoted}"));
+    }
+
+    parse_token_response(&body)
+}
+
+fn parse_token_response(body: &[u8]) -> Result<TokenResponse, String> {
+    let token = serde_json::from_slice::<TokenResponse>(body)
+        .map_err(|e| format!("token endpoint answer is not a token response ({e})"))?;
+    if !token.token_type.eq_ignore_ascii_case("bearer") {
+        return Err(format!(
+            "token endpoint issued a '{}' token, only bearer tokens are supported",
+            token.token_type
+        ));
+    }
+    if HeaderValue::from_str(&token.access_token).is_err() {
+        return Err("token endpoint issued an access token that cannot be sent".to_owned());
+    }
+    Ok(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credentials(scopes: &[&str]) -> OAuth2ClientCredentials {
+        OAuth2ClientCredentials {
+            subscription_id: Uuid::new_v4(),
+            token_url: "https://auth.example.com/oauth2/token".to_owned(),
+            client_id: "hook0 client".to_owned(),
+            client_secret: "s3cr3t:&".to_owned(),
+            scopes: scopes.iter().map(|s| (*s).to_owned()).collect(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn token_request_asks_for_the_scopes_space_separated() {
+        assert_eq!(
+            token_request_body(&credentials(&[])),
+            "grant_type=client_credentials"
+        );
+        assert_eq!(
+            token_request_body(&credentials(&["read", "webhooks:write"])),
+            "grant_type=client_credentials&scope=read+webhooks%3Awrite"
+        );
+    }
+
+    #[test]
+    fn client_credentials_are_form_encoded_before_basic_authentication() {
+        let value = basic_authorization(&credentials(&[])).unwrap();
+        assert!(value.is_sensitive());
+        assert_eq!(
+            value.to_str().unwrap(),
+            format!("Basic {}", STANDARD.encode("hook0+client:s3cr3t%3A%26"))
+        );
+    }
+
+    #[test]
+    fn only_bearer_tokens_are_accepted() {
+        let token = parse_token_response(
+            br#"{"access_token":"abc","token_type":"Bearer","expires_in":3600}"#,
+        )
+        .unwrap();
+        assert_eq!(token.access_token, "abc");
+        assert_eq!(token.expires_in, Some(3600));
+
+        let err =
+            parse_token_response(br#"{"access_token":"abc","token_type":"mac"}"#).unwrap_err();
+        assert!(err.contains("only bearer tokens"), "{err}");
+        let err = parse_token_response(br#"{"error":"invalid_client"}"#).unwrap_err();
+        assert!(err.contains("not a token response"), "{err}");
+        let err =
+            parse_token_response(br#"{"access_token":"a\nb","token_type":"bearer"}"#).unwrap_err();
+        assert!(err.contains("cannot be sent"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn a_refused_token_is_forgotten_unless_already_replaced() {
+        let tokens = OAuth2Tokens::default();
+        let subscription_id = Uuid::new_v4();
+        let cache = |access_token: &str| CachedToken {
+            access_token: access_token.to_owned(),
+            credentials_updated_at: Utc::now(),
+            refresh_at: Instant::now() + Duration::from_secs(60),
+        };
+        *tokens.slot(subscription_id).lock().await = Some(cache("new"));
+
+        tokens.forget(subscription_id, "old").await;
+        assert!(tokens.slot(subscription_id).lock().await.is_some());
+        tokens.forget(subscription_id, "new").await;
+        assert!(tokens.slot(subscription_id).lock().await.is_none());
+    }
+}
@@ -427,11 +427,11 @@ static DELIVERIES_GIVEN_UP: LazyLock<Counter<u64>> = LazyLock::new(|| {
 });
 
 /// Bounded set of reasons a delivery is abandoned for good: no further attempt
-/// will ever be scheduled for that event on that subscription. `RetriesExhausted`
-/// and `SignatureFailed` lose an event a subscriber was waiting for, while
-/// `SubscriptionGone` is the expected end of a subscription or application that
-/// no longer wants deliveries. Keeping the three apart is the point: only the
-/// first two are worth waking someone up for.
+/// will ever be scheduled for that event on that subscription. `RetriesExhausted`,
+/// `SignatureFailed`, `TransformFailed` and `PermanentFailure` lose an event a
+/// subscriber was waiting for, while `SubscriptionGone` is the expected end of a
+/// subscription or application that no longer wants deliveries. Keeping them
+/// apart is the point: only the former are worth waking someone up for.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
 pub enum GiveUpReason {
     #[strum(serialize = "retries_exhausted")]
@@ -440,12 +440,91 @@ pub enum GiveUpReason {
     SubscriptionGone,
     #[strum(serialize = "signature_failed")]
     SignatureFailed,
+    #[strum(serialize = "transform_failed")]
+    TransformFailed,
+    /// A response rule of the subscription said the delivery would never succeed.
+    #[strum(serialize = "permanent_failure")]
+    PermanentFailure,
 }
 
 pub fn report_given_up(reason: GiveUpReason) {
     DELIVERIES_GIVEN_UP.add(1, &[KeyValue::new("reason", <&'static str>::from(reason))]);
 }
 
+static SUBSCRIPTIONS_AUTO_DISABLED: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(crate_name!())
+        .u64_counter("webhook.subscription.auto_disabled")
+        .with_description(
+            "Count of subscriptions disabled by their circuit breaker or a response rule",
+        )
+        .build()
+});
+
+pub fn report_subscription_auto_disabled() {
+    SUBSCRIPTIONS_AUTO_DISABLED.add(1, &[]);
+}
+
+static REQUEST_ATTEMPTS_THROTTLED: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(crate_name!())
+        .u64_counter("webhook.request_attempt.throttled")
+        .with_description(
+            "Count of request attempts deferred by the delivery limits of their subscription",
+        )
+        .build()
+});
+
+pub fn report_request_attempt_throttled() {
+    REQUEST_ATTEMPTS_THROTTLED.add(1, &[]);
+}
+
+static HTTP_CLIENT_LOOKUPS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(crate_name!())
+        .u64_counter("webhook.http_client.lookups")
+        .with_description(
+            "Count of HTTP clients looked up for a delivery, by whether a pooled one was reused",
+        )
+        .build()
+});
+
+pub fn report_http_client_lookup(reused: bool) {
+    HTTP_CLIENT_LOOKUPS.add(
+        1,
+        &[KeyValue::new("result", if reused { "hit" } else { "miss" })],
+    );
+}
+
+static HTTP_CLIENT_EVICTIONS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(crate_name!())
+        .u64_counter("webhook.http_client.evictions")
+        .with_description("Count of pooled HTTP clients dropped, by bounded reason")
+        .build()
+});
+
+/// Why a pooled HTTP client is dropped: it was not used for the idle timeout, or room was needed
+/// for another one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+pub enum HttpClientEviction {
+    #[strum(serialize = "idle")]
+    Idle,
+    #[strum(serialize = "capacity")]
+    Capacity,
+}
+
+pub fn report_http_client_eviction(reason: HttpClientEviction) {
+    HTTP_CLIENT_EVICTIONS.add(1, &[KeyValue::new("reason", <&'static str>::from(reason))]);
+}
+
+static POOLED_HTTP_CLIENTS: LazyLock<Gauge<u64>> = LazyLock::new(|| {
+    global::meter(crate_name!())
+        .u64_gauge("webhook.http_client.pooled")
+        .with_description("Number of HTTP clients kept for reuse by deliveries")
+        .build()
+});
+
+pub fn report_pooled_http_clients(count: usize) {
+    POOLED_HTTP_CLIENTS.record(u64::try_from(count).unwrap_or(u64::MAX), &[]);
+}
+
 /// Total mapping from a delivery `Response` to exactly one bounded `DeliveryOutcome`.
 /// A success maps to `Success`; an HTTP error with a 4xx/5xx code maps to the
 /// matching class; anything else falls back to the transport error (`Timeout` for a
@@ -491,11 +570,12 @@ mod tests {
             headers: None,
             body: None,
             elapsed_time: Duration::from_secs(0),
+            rule_outcome: None,
         }
     }
 
     fn error_variant(sel: u8) -> Option<ResponseError> {
-        match sel % 8 {
+        match sel % 9 {
             0 => None,
             1 => Some(ResponseError::Unknown),
             2 => Some(ResponseError::InvalidHeader),
@@ -503,6 +583,7 @@ mod tests {
             4 => Some(ResponseError::Dns),
             5 => Some(ResponseError::Connection),
             6 => Some(ResponseError::Timeout),
+            7 => Some(ResponseError::Transform),
             _ => Some(ResponseError::Http),
         }
     }
@@ -7,17 +7,23 @@ use sqlx::postgres::types::PgInterval;
 use sqlx::{PgPool, query, query_as};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc::Sender;
-use tokio::time::sleep;
 use tokio_util::task::TaskTracker;
 use tracing::{debug, info, trace, warn};
 
+use crate::batch::{self, Batching, Member};
+use crate::circuit_breaker;
+use crate::delivery_credentials::DeliveryCredentials;
 use crate::dns::DnsResolver;
+use crate::http_clients::HttpClients;
+use crate::oauth2::OAuth2Tokens;
 use crate::opentelemetry::{
     classify_outcome, compute_delivery_lag_seconds, delivery_trace_ids, end_request_attempt_span,
     report_delivery_outcome, report_worker_delivery_lag, start_request_attempt_span,
 };
+use crate::throttle::{Admission, Target, Throttle};
 use crate::throughput_log::ThroughputStats;
-use crate::work::{ResponseError, work};
+use crate::wakeups::Wakeups;
+use crate::work::{ResponseError, work, work_batch};
 use crate::{
     Config, ObjectStorageConfig, RequestAttemptWithOptionalPayload, RetryPolicy, SlotRole, Worker,
     compute_next_retry,
@@ -34,7 +40,8 @@ const MAX_POLLING_SLEEP: Duration = Duration::from_secs(10);
 #[allow(clippy::too_many_arguments)]
 pub async fn look_for_work(
     config: &Config,
-    retry_policy: RetryPolicy,
+    retry_policy: &RetryPolicy,
+    throttle: &Throttle,
     unit_id: u16,
     slot_role: SlotRole,
     pool: &PgPool,
@@ -45,6 +52,9 @@ pub async fn look_for_work(
     task_tracker: &TaskTracker,
     stats: &ThroughputStats,
     resolver: &DnsResolver,
+    tokens: &OAuth2Tokens,
+    clients: &HttpClients,
+    mut wakeups: Wakeups,
 ) -> anyhow::Result<()> {
     let (retry_count_lt, retry_count_gte): (Option<i16>, Option<i16>) = match slot_role {
         SlotRole::HpReserved => (Some(config.hp_retry_cutoff), None),
@@ -54,12 +64,13 @@ pub async fn look_for_work(
     info!(unit_id, %slot_role, "Begin looking for work");
     loop {
         trace!(unit_id, "Fetching next unprocessed request attempt...");
+        wakeups.mark_seen();
         let mut tx = pool.begin().await?;
 
         let fetch_start = Instant::now();
         let next_attempt = query_as!(
             RequestAttemptWithOptionalPayload,
-            "
+            r#"
                 SELECT
                     e.application__id AS application_id,
                     ra.request_attempt__id AS request_attempt_id,
@@ -69,27 +80,66 @@ pub async fn look_for_work(
                     ra.created_at,
                     ra.retry_count,
                     ra.delay_until,
-                    t_http.method AS http_method,
-                    t_http.url AS http_url,
-                    t_http.headers AS http_headers,
+                    COALESCE(t_http.method, '') AS "http_method!",
+                    COALESCE(t_http.url, '') AS "http_url!",
+                    COALESCE(t_http.headers, '{}'::jsonb) AS "http_headers!",
+                    t_mq.target AS message_queue,
                     e.event_type__name AS event_type_name,
                     e.payload AS payload,
                     e.payload_content_type AS payload_content_type,
-                    s.secret
+                    s.secret,
+                    s.payload_transform,
+                    s.delivery_limits,
+                    ra.ordering_key,
+                    s.batching,
+                    s.signature_scheme,
+                    s.response_rules
                 FROM webhook.request_attempt AS ra
                 INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
                 LEFT JOIN webhook.subscription__worker AS sw ON sw.subscription__id = s.subscription__id
                 INNER JOIN event.application AS a ON a.application__id = s.application__id AND a.deleted_at IS NULL
                 INNER JOIN iam.organization AS o ON o.organization__id = a.organization__id
                 LEFT JOIN iam.organization__worker AS ow ON ow.organization__id = o.organization__id AND ow.default = true
-                INNER JOIN webhook.target_http AS t_http ON t_http.target__id = s.target__id
+                LEFT JOIN webhook.target_http AS t_http ON t_http.target__id = s.target__id
+                LEFT JOIN webhook.target_message_queue AS t_mq ON t_mq.target__id = s.target__id
                 INNER JOIN event.event AS e ON e.event__id = ra.event__id
                 WHERE
                     ra.succeeded_at IS NULL
                     AND ra.failed_at IS NULL
                     AND s.is_enabled
                     AND s.deleted_at IS NULL
+                    AND (t_http.target__id IS NOT NULL OR t_mq.target__id IS NOT NULL)
                     AND (ra.delay_until IS NULL OR ra.delay_until <= statement_timestamp())
+                    AND (e.expires_at IS NULL OR e.expires_at > statement_timestamp())
+                    AND (
+                        ra.ordering_key IS NULL
+                        OR NOT EXISTS (
+                            SELECT 1
+                            FROM webhook.request_attempt AS prev
+                            INNER JOIN event.event AS prev_e ON prev_e.event__id = prev.event__id
+                            WHERE prev.subscription__id = ra.subscription__id
+                                AND prev.ordering_key = ra.ordering_key
+                                AND prev.succeeded_at IS NULL
+                                AND prev.failed_at IS NULL
+                                AND (prev_e.received_at, prev_e.event__id) < (e.received_at, e.event__id)
+                        )
+                    )
+                    AND (
+                        s.batching IS NULL
+                        OR COALESCE(ra.delay_until, ra.created_at) <= statement_timestamp() - make_interval(secs => (s.batching->>'max_wait_s')::int)
+                        OR (
+                            SELECT COUNT(*)
+                            FROM (
+                                SELECT 1
+                                FROM webhook.request_attempt AS waiting
+                                WHERE waiting.subscription__id = ra.subscription__id
+                                    AND waiting.succeeded_at IS NULL
+                                    AND waiting.failed_at IS NULL
+                                    AND (waiting.delay_until IS NULL OR waiting.delay_until <= statement_timestamp())
+                                LIMIT (s.batching->>'max_events')::int
+                            ) AS waiting
+                        ) >= (s.batching->>'max_events')::int
+                    )
                     AND (
                         ($2 AND COALESCE(sw.worker__id, ow.worker__id) IS NULL)
                         OR COALESCE(sw.worker__id, ow.worker__id) = $1
@@ -100,7 +150,7 @@ pub async fn look_for_work(
                 LIMIT 1
                 FOR UPDATE OF ra
                 SKIP LOCKED
-            ",
+            "#,
             worker.scope.worker_id(),
             worker.scope.is_public(),
             retry_count_lt,
@@ -111,6 +161,39 @@ pub async fn look_for_work(
         stats.record_db_fetch(fetch_start.elapsed());
 
         if let Some(attempt) = next_attempt {
+            let request_attempt_id = attempt.request_attempt_id;
+            let admission = throttle
+                .acquire(
+                    attempt.delivery_limits.as_ref(),
+                    Target {
+                        request_attempt_id,
+                        application_id: attempt.application_id,
+                        subscription_id: attempt.subscription_id,
+                        http_url: &attempt.http_url,
+                    },
+                )
+                .await?;
+            let leased = match admission {
+                Admission::Now { leased } => leased,
+                Admission::Later(retry_at) => {
+                    // Over the subscription's limits: wait for a turn, which is not a failure
+                    debug!(unit_id, %request_attempt_id, %retry_at, "Request attempt throttled; delaying it without incrementing retry count");
+                    query!(
+                        "UPDATE webhook.request_attempt SET delay_until = $1 WHERE request_attempt__id = $2",
+                        retry_at,
+                        request_attempt_id,
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                    tx.commit().await?;
+
+                    if task_tracker.is_closed() {
+                        break;
+                    }
+                    continue;
+                }
+            };
+
             let attempt_is_hp = SlotRole::is_hp(attempt.retry_count, config.hp_retry_cutoff);
             let _slot_guard = stats.slot_enter(attempt_is_hp);
 
@@ -147,7 +230,7 @@ pub async fn look_for_work(
             // Fetch the payload. `give_up` distinguishes a payload object that is
             // permanently missing (S3 NoSuchKey) from a transient/unavailable read (or
             // object storage not being configured), which we retry later instead.
-            let (payload, give_up): (Option<Vec<u8>>, bool) = if let Some(p) = attempt.payload {
+            let (mut payload, give_up): (Option<Vec<u8>>, bool) = if let Some(p) = attempt.payload {
                 (Some(p), false)
             } else if let Some(os) = &object_storage {
                 let key = format!(
@@ -201,7 +284,92 @@ pub async fn look_for_work(
                 (None, false)
             };
 
-            if let Some(p) = payload {
+            let batching = Batching::from_value(attempt.batching.as_ref(), attempt.subscription_id);
+            if let Some(batching) = batching
+                && let Some(p) = payload.take()
+            {
+                let lead = Member {
+                    attempt: RequestAttempt {
+                        application_id: attempt.application_id,
+                        request_attempt_id: attempt.request_attempt_id,
+                        event_id: attempt.event_id,
+                        event_received_at: attempt.event_received_at,
+                        subscription_id: attempt.subscription_id,
+                        created_at: attempt.created_at,
+                        retry_count: attempt.retry_count,
+                        http_method: attempt.http_method,
+                        http_url: attempt.http_url,
+                        http_headers: attempt.http_headers,
+                        event_type_name: attempt.event_type_name,
+                        payload: p,
+                        payload_content_type: attempt.payload_content_type,
+                        secret: attempt.secret,
+                        payload_transform: attempt.payload_transform,
+                        message_queue: attempt.message_queue,
+                        signature_scheme: attempt.signature_scheme,
+                        response_rules: attempt.response_rules,
+                    },
+                    ordering_key: attempt.ordering_key,
+                };
+                let batch =
+                    batch::collect(&mut tx, object_storage.as_ref(), &batching, lead).await?;
+
+                // Start OpenTelemetry span
+                let span = start_request_attempt_span(batch.first());
+                let ids = delivery_trace_ids(&span);
+
+                // Work
+                let credentials = DeliveryCredentials::load(
+                    &mut *tx,
+                    config.client_certificate_encryption_key.as_ref(),
+                    batch.first().application_id,
+                    batch.first().subscription_id,
+                )
+                .await?;
+                let response = work_batch(
+                    config,
+                    resolver,
+                    tokens,
+                    clients,
+                    &batch,
+                    credentials.client_certificate.as_ref(),
+                    credentials.oauth2.as_ref(),
+                    credentials.signing_key.as_ref(),
+                    credentials.secrets.as_ref(),
+                    &ids,
+                )
+                .await;
+                trace!(unit_id, delivery_batch_id = %batch.id, batch_size = batch.len(), trace_id = %ids.trace_id, span_id = %ids.span_id, elapsed_ms = response.elapsed_time_ms(), "Got response for batch");
+
+                // Store response and complete or fail every request attempt of the batch
+                batch::record(
+                    &mut tx,
+                    object_storage.as_ref(),
+                    retry_policy,
+                    &worker.name,
+                    worker_version,
+                    Utc::now(),
+                    &batch,
+                    &response,
+                )
+                .await?;
+
+                // Commit transaction
+                tx.commit().await?;
+
+                for attempt in batch.attempts() {
+                    stats.record_attempt(
+                        response.is_success(),
+                        attempt.retry_count,
+                        response.elapsed_time,
+                        config.hp_retry_cutoff,
+                    );
+                }
+
+                // Record the bounded delivery outcome, then end the OpenTelemetry span
+                report_delivery_outcome(classify_outcome(&response));
+                end_request_attempt_span(span, &response);
+            } else if let Some(p) = payload {
                 let attempt_with_payload = RequestAttempt {
                     application_id: attempt.application_id,
                     request_attempt_id: attempt.request_attempt_id,
@@ -217,6 +385,10 @@ pub async fn look_for_work(
                     payload: p,
                     payload_content_type: attempt.payload_content_type,
                     secret: attempt.secret,
+                    payload_transform: attempt.payload_transform,
+                    message_queue: attempt.message_queue,
+                    signature_scheme: attempt.signature_scheme,
+                    response_rules: attempt.response_rules,
                 };
 
                 // Start OpenTelemetry span
@@ -226,7 +398,26 @@ pub async fn look_for_work(
                 let ids = delivery_trace_ids(&span);
 
                 // Work
-                let response = work(config, resolver, &attempt_with_payload, &ids).await;
+                let credentials = DeliveryCredentials::load(
+                    &mut *tx,
+                    config.client_certificate_encryption_key.as_ref(),
+                    attempt_with_payload.application_id,
+                    attempt.subscription_id,
+                )
+                .await?;
+                let response = work(
+                    config,
+                    resolver,
+                    tokens,
+                    clients,
+                    &attempt_with_payload,
+                    credentials.client_certificate.as_ref(),
+                    credentials.oauth2.as_ref(),
+                    credentials.signing_key.as_ref(),
+                    credentials.secrets.as_ref(),
+                    &ids,
+                )
+                .await;
                 trace!(unit_id, request_attempt_id = %attempt.request_attempt_id, trace_id = %ids.trace_id, span_id = %ids.span_id, elapsed_ms = response.elapsed_time_ms(), "Got response for request attempt");
 
                 // Store response
@@ -249,8 +440,8 @@ pub async fn look_for_work(
                 };
                 let response_id = query!(
                     "
-                        INSERT INTO webhook.response (response_error__name, http_code, headers, body, elapsed_time_ms)
-                        VALUES ($1, $2, $3, $4, $5)
+                        INSERT INTO webhook.response (response_error__name, http_code, headers, body, elapsed_time_ms, rule_outcome)
+                        VALUES ($1, $2, $3, $4, $5, $6)
                         RETURNING response__id
                     ",
                     response.response_error__name(),
@@ -258,6 +449,7 @@ pub async fn look_for_work(
                     response_contents_to_insert.map(|(_, headers)| headers.to_owned()).unwrap_or(None),
                     response_contents_to_insert.map(|(body, _)| body.to_owned()).unwrap_or(None),
                     response.elapsed_time_ms(),
+                    response.rule_outcome(),
                 )
                 .fetch_one(&mut *tx)
                 .await?
@@ -319,6 +511,7 @@ pub async fn look_for_work(
                     )
                     .execute(&mut *tx)
                     .await?;
+                    circuit_breaker::record_success(&mut tx, &attempt_with_payload).await?;
 
                     debug!(unit_id, request_attempt_id = %attempt.request_attempt_id, trace_id = %ids.trace_id, span_id = %ids.span_id, "Request attempt completed successfully");
                 } else {
@@ -330,6 +523,9 @@ pub async fn look_for_work(
                     )
                     .execute(&mut *tx)
                     .await?;
+                    // Tripping the breaker disables the subscription, which makes the retry give up
+                    circuit_breaker::record_failure(&mut tx, &attempt_with_payload, &response)
+                        .await?;
 
                     // Creating a retry request or giving up
                     if let Some(retry_in) =
@@ -344,8 +540,8 @@ pub async fn look_for_work(
                         })?;
                         let retry_id = query!(
                             "
-                                INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, delay_until, retry_count)
-                                VALUES ($1, $2, $3, statement_timestamp() + $4, $5)
+                                INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, delay_until, retry_count, ordering_key)
+                                VALUES ($1, $2, $3, statement_timestamp() + $4, $5, $6)
                                 RETURNING request_attempt__id
                             ",
                             attempt.application_id,
@@ -353,6 +549,7 @@ pub async fn look_for_work(
                             attempt.subscription_id,
                             retry_interval,
                             next_retry_count,
+                            attempt.ordering_key,
                         )
                         .fetch_one(&mut *tx)
                         .await?
@@ -433,13 +630,17 @@ pub async fn look_for_work(
 
                 tx.commit().await?;
             }
+
+            if leased {
+                throttle.release(request_attempt_id).await;
+            }
         } else {
             trace!(unit_id, "No unprocessed attempt found");
 
             // Commit transaction
             tx.commit().await?;
 
-            wait_because_no_work(unit_id).await;
+            wait_because_no_work(unit_id, &mut wakeups).await;
         }
 
         // Send monitoring heartbeat if necessary
@@ -459,17 +660,18 @@ pub async fn look_for_work(
     }
 }
 
-async fn wait_because_no_work(unit_id: u16) {
+async fn wait_because_no_work(unit_id: u16, wakeups: &mut Wakeups) {
     // In order to reduce load on the database when there is no work to do, but simultaneously keep a low latency when some work becomes available,
     // we wait a variable duration between checks:
     // - for unit 0, we wait for a short duration, so that new work gets picked up fast
     // - for units 1 and 2, we wait for a medium duration
     // - for units > 3, we wait for a long duration, to avoid unnecessary stress on the database
     // Note: units do not wait after finishing a task (they keep going as fast as possible), they wait only if there is no more work to do
+    // A notification that request attempts were stored cuts the wait short; polling remains for delayed attempts and missed notifications
     let sleep_duration = match unit_id {
         0 => MIN_POLLING_SLEEP,
         1 | 2 => (MIN_POLLING_SLEEP + MAX_POLLING_SLEEP) / 2,
         _ => MAX_POLLING_SLEEP,
     };
-    sleep(sleep_duration).await;
+    wakeups.wait(sleep_duration).await;
 }
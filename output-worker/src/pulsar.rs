@@ -23,14 +23,20 @@ use tokio_util::task::TaskTracker;
 use tracing::{debug, error, info, trace, warn};
 use uuid::Uuid;
 
+use crate::batch::{self, Batching, Member};
+use crate::circuit_breaker;
+use crate::delivery_credentials::DeliveryCredentials;
 use crate::dns::DnsResolver;
+use crate::http_clients::HttpClients;
+use crate::oauth2::OAuth2Tokens;
 use crate::opentelemetry::{
     classify_outcome, compute_delivery_lag_seconds, delivery_trace_ids, end_request_attempt_span,
     gather_pulsar_consumer_metrics, gather_slot_metrics, report_delivery_outcome,
     report_worker_delivery_lag, start_request_attempt_span,
 };
+use crate::throttle::{Admission, Target, Throttle};
 use crate::throughput_log::ThroughputStats;
-use crate::work::work;
+use crate::work::{work, work_batch};
 use crate::{
     Config, ObjectStorageConfig, PulsarConfig, RequestAttempt, RequestAttemptWithOptionalPayload,
     RetryPolicy, SlotRole, compute_next_retry,
@@ -40,6 +46,14 @@ use hook0_sentry_integration::log_object_storage_error_with_context;
 
 const DELAY_TOLERANCE: Duration = Duration::from_secs(3);
 
+/// How long a request attempt waiting behind an earlier one with the same ordering key is put
+/// back in the topic before it is checked again
+const ORDERING_RECHECK_DELAY: TimeDelta = TimeDelta::seconds(5);
+
+/// How long a request attempt held by another batch is put back in the topic before it is checked
+/// again
+const BATCH_RECHECK_DELAY: TimeDelta = TimeDelta::seconds(5);
+
 /// Number of waiting request attempts fetched per pass when loading the backlog into Pulsar.
 const LOAD_BATCH_SIZE: i64 = 1000;
 
@@ -110,6 +124,39 @@ async fn await_receipt(
     Ok(())
 }
 
+/// Put a request attempt back in the topic (HP or LP) it came from, to be checked again from
+/// `deliver_at`. This is not a retry: its retry count is left as it is.
+async fn requeue(
+    config: &Config,
+    hp_retry_producer: &Mutex<Producer<TokioExecutor>>,
+    lp_retry_producer: &Mutex<Producer<TokioExecutor>>,
+    is_lp: bool,
+    request_attempt: RequestAttempt,
+    deliver_at: DateTime<Utc>,
+) -> anyhow::Result<()> {
+    let producer = if is_lp {
+        lp_retry_producer
+    } else {
+        hp_retry_producer
+    };
+    let request_attempt_id = request_attempt.request_attempt_id;
+    let created_at = request_attempt.created_at;
+    let send_future = enqueue(
+        producer,
+        request_attempt,
+        created_at,
+        Some(deliver_at),
+        config.pulsar_send_receipt_timeout,
+    )
+    .await?;
+    await_receipt(
+        send_future,
+        config.pulsar_send_receipt_timeout,
+        request_attempt_id,
+    )
+    .await
+}
+
 /// Wraps an OwnedSemaphorePermit so that dropping it returns the permit to the correct pool.
 /// The inner permit is held for its Drop semantics, not read directly.
 #[allow(dead_code)]
@@ -206,7 +253,7 @@ pub async fn load_waiting_request_attempts_from_db(
         let batch_start = Instant::now();
         let rows = query_as!(
             RequestAttemptWithOptionalPayload,
-            "
+            r#"
                 SELECT
                     e.application__id AS application_id,
                     ra.request_attempt__id AS request_attempt_id,
@@ -216,16 +263,24 @@ pub async fn load_waiting_request_attempts_from_db(
                     ra.created_at,
                     ra.retry_count,
                     ra.delay_until,
-                    t_http.method as http_method,
-                    t_http.url as http_url,
-                    t_http.headers as http_headers,
+                    COALESCE(t_http.method, '') AS "http_method!",
+                    COALESCE(t_http.url, '') AS "http_url!",
+                    COALESCE(t_http.headers, '{}'::jsonb) AS "http_headers!",
+                    t_mq.target AS message_queue,
                     e.event_type__name AS event_type_name,
                     e.payload,
                     e.payload_content_type,
-                    s.secret
+                    s.secret,
+                    s.payload_transform,
+                    s.delivery_limits,
+                    ra.ordering_key,
+                    s.batching,
+                    s.signature_scheme,
+                    s.response_rules
                 FROM webhook.request_attempt AS ra
                 INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
-                INNER JOIN webhook.target_http AS t_http ON t_http.target__id = s.target__id
+                LEFT JOIN webhook.target_http AS t_http ON t_http.target__id = s.target__id
+                LEFT JOIN webhook.target_message_queue AS t_mq ON t_mq.target__id = s.target__id
                 INNER JOIN event.event AS e ON e.event__id = ra.event__id
                 LEFT JOIN webhook.subscription__worker AS sw ON sw.subscription__id = ra.subscription__id
                 INNER JOIN event.application AS a ON a.application__id = s.application__id
@@ -234,6 +289,8 @@ pub async fn load_waiting_request_attempts_from_db(
                     AND a.deleted_at IS NULL
                     AND s.is_enabled
                     AND s.deleted_at IS NULL
+                    AND (t_http.target__id IS NOT NULL OR t_mq.target__id IS NOT NULL)
+                    AND (e.expires_at IS NULL OR e.expires_at > $3::timestamptz)
                     AND COALESCE(sw.worker__id, ow.worker__id) = $1
                     AND ra.created_at <= $3::timestamptz
                     AND (ra.created_at, ra.request_attempt__id) > ($4::timestamptz, $5::uuid)
@@ -244,7 +301,7 @@ pub async fn load_waiting_request_attempts_from_db(
                     )
                 ORDER BY ra.created_at ASC, ra.request_attempt__id ASC
                 LIMIT $6::bigint
-            ",
+            "#,
             worker_id.as_ref(),
             only_due_now,
             started_at,
@@ -323,6 +380,10 @@ pub async fn load_waiting_request_attempts_from_db(
                         payload: p,
                         payload_content_type: ra.payload_content_type,
                         secret: ra.secret,
+                        payload_transform: ra.payload_transform,
+                        message_queue: ra.message_queue,
+                        signature_scheme: ra.signature_scheme,
+                        response_rules: ra.response_rules,
                     };
 
                     let producer = if SlotRole::is_hp(ra.retry_count, hp_retry_cutoff) {
@@ -390,7 +451,8 @@ pub async fn load_waiting_request_attempts_from_db(
 #[allow(clippy::too_many_arguments)]
 pub async fn look_for_work(
     config: &Arc<Config>,
-    retry_policy: RetryPolicy,
+    retry_policy: &RetryPolicy,
+    throttle: &Throttle,
     pool: &PgPool,
     object_storage: &Arc<Option<ObjectStorageConfig>>,
     worker_id: &Arc<Uuid>,
@@ -401,6 +463,8 @@ pub async fn look_for_work(
     task_tracker: &TaskTracker,
     stats: &Arc<ThroughputStats>,
     resolver: &Arc<DnsResolver>,
+    tokens: &Arc<OAuth2Tokens>,
+    clients: &Arc<HttpClients>,
 ) -> anyhow::Result<()> {
     info!("Begin looking for work");
 
@@ -617,6 +681,8 @@ pub async fn look_for_work(
 
                         let ack_tx = ack_tx.clone();
                         let c = config.clone();
+                        let rp = retry_policy.clone();
+                        let th = throttle.clone();
                         let po = pool.clone();
                         let os = object_storage.clone();
                         let wi = worker_id.clone();
@@ -627,11 +693,13 @@ pub async fn look_for_work(
                         let st = stats.clone();
                         let infl = in_flight.clone();
                         let dr = resolver.clone();
+                        let ot = tokens.clone();
+                        let hc = clients.clone();
 
                         // We handle the request attempt in a new Tokio task
                         task_tracker.spawn(async move {
                             if let Err(e) = handle_message(
-                                &c, retry_policy, &po, &os, &wi, &wn, &wv, &hp_rp, &lp_rp, msg, permit, ack_tx, &st, is_lp, infl, &dr,
+                                &c, &rp, &th, &po, &os, &wi, &wn, &wv, &hp_rp, &lp_rp, msg, permit, ack_tx, &st, is_lp, infl, &dr, &ot, &hc,
                             )
                             .await
                             {
@@ -705,11 +773,16 @@ pub async fn look_for_work(
 enum RequestAttemptStatus {
     Ready {
         delay_until: Option<DateTime<Utc>>,
+        delivery_limits: Option<serde_json::Value>,
+        ordering_key: Option<String>,
+        batching: Option<serde_json::Value>,
     },
     Delayed {
         delay_until: DateTime<Utc>,
         lead: TimeDelta,
     },
+    /// An attempt of an earlier event with the same ordering key is still pending
+    OrderedBehind,
     AlreadyDone,
     Cancelled,
     NotForThisWorker,
@@ -719,7 +792,8 @@ enum RequestAttemptStatus {
 #[allow(clippy::too_many_arguments)]
 async fn handle_message(
     config: &Config,
-    retry_policy: RetryPolicy,
+    retry_policy: &RetryPolicy,
+    throttle: &Throttle,
     pool: &PgPool,
     object_storage: &Arc<Option<ObjectStorageConfig>>,
     worker_id: &Uuid,
@@ -734,6 +808,8 @@ async fn handle_message(
     is_lp: bool,
     in_flight: Arc<papaya::HashSet<Uuid>>,
     resolver: &DnsResolver,
+    tokens: &OAuth2Tokens,
+    clients: &HttpClients,
 ) -> anyhow::Result<()> {
     let picked_at = Utc::now();
     let attempt_is_hp = !is_lp;
@@ -769,13 +845,17 @@ async fn handle_message(
                     not_done: bool,
                     delay_until: Option<DateTime<Utc>>,
                     for_this_worker: bool,
+                    ordered_behind: bool,
+                    delivery_limits: Option<serde_json::Value>,
+                    ordering_key: Option<String>,
+                    batching: Option<serde_json::Value>,
                 }
                 let fetch_start = std::time::Instant::now();
                 let request_attempt_status = match query_as!(
                     RawRequestAttemptStatus,
                     r#"
                         SELECT
-                            (s.is_enabled AND s.deleted_at IS NULL AND a.deleted_at IS NULL) AS "not_cancelled!",
+                            (s.is_enabled AND s.deleted_at IS NULL AND a.deleted_at IS NULL AND (e.expires_at IS NULL OR e.expires_at > statement_timestamp())) AS "not_cancelled!",
                             (ra.succeeded_at IS NULL AND ra.failed_at IS NULL) AS "not_done!",
                             ra.delay_until,
                             (
@@ -799,10 +879,27 @@ async fn handle_message(
                                             AND ow.worker__id IS NOT DISTINCT FROM $2
                                     )
                                 )
-                            ) AS "for_this_worker!"
+                            ) AS "for_this_worker!",
+                            (
+                                ra.ordering_key IS NOT NULL
+                                AND EXISTS (
+                                    SELECT 1
+                                    FROM webhook.request_attempt AS prev
+                                    INNER JOIN event.event AS prev_e ON prev_e.event__id = prev.event__id
+                                    WHERE prev.subscription__id = ra.subscription__id
+                                        AND prev.ordering_key = ra.ordering_key
+                                        AND prev.succeeded_at IS NULL
+                                        AND prev.failed_at IS NULL
+                                        AND (prev_e.received_at, prev_e.event__id) < (e.received_at, e.event__id)
+                                )
+                            ) AS "ordered_behind!",
+                            s.delivery_limits,
+                            ra.ordering_key,
+                            s.batching
                         FROM webhook.request_attempt AS ra
                         INNER JOIN webhook.subscription AS s ON s.subscription__id = ra.subscription__id
                         INNER JOIN event.application AS a ON a.application__id = s.application__id
+                        INNER JOIN event.event AS e ON e.event__id = ra.event__id
                         WHERE ra.request_attempt__id = $1
                     "#,
                     attempt.request_attempt_id,
@@ -816,6 +913,7 @@ async fn handle_message(
                         not_done: true,
                         for_this_worker: true,
                         delay_until: Some(d),
+                        ..
                     }) if d > (Utc::now() + DELAY_TOLERANCE) => RequestAttemptStatus::Delayed {
                         delay_until: d,
                         lead: d - Utc::now(),
@@ -824,8 +922,24 @@ async fn handle_message(
                         not_cancelled: true,
                         not_done: true,
                         for_this_worker: true,
+                        ordered_behind: true,
+                        ..
+                    }) => RequestAttemptStatus::OrderedBehind,
+                    Some(RawRequestAttemptStatus {
+                        not_cancelled: true,
+                        not_done: true,
+                        for_this_worker: true,
+                        ordered_behind: false,
                         delay_until,
-                    }) => RequestAttemptStatus::Ready { delay_until },
+                        delivery_limits,
+                        ordering_key,
+                        batching,
+                    }) => RequestAttemptStatus::Ready {
+                        delay_until,
+                        delivery_limits,
+                        ordering_key,
+                        batching,
+                    },
                     Some(RawRequestAttemptStatus {
                         not_cancelled: true,
                         not_done: false,
@@ -846,11 +960,86 @@ async fn handle_message(
                 stats.record_db_fetch(fetch_start.elapsed());
 
                 match request_attempt_status {
-                    RequestAttemptStatus::Ready { delay_until } => {
-                        // Record queue lag: time between becoming eligible and pickup
+                    RequestAttemptStatus::Ready {
+                        delay_until,
+                        delivery_limits,
+                        ordering_key,
+                        batching,
+                    } => {
                         let eligible_at = delay_until
                             .unwrap_or(attempt.created_at)
                             .max(attempt.created_at);
+
+                        let batching =
+                            Batching::from_value(batching.as_ref(), attempt.subscription_id);
+                        if let Some(batching) = &batching
+                            && let Some(ready_at) = batching
+                                .ready_at(pool, attempt.subscription_id, eligible_at)
+                                .await?
+                        {
+                            // Its batch is neither full nor old enough yet
+                            stats.record_not_ready();
+                            trace!(%request_attempt_id, %ready_at, "Request attempt is waiting for its batch");
+                            requeue(
+                                config,
+                                hp_retry_producer,
+                                lp_retry_producer,
+                                is_lp,
+                                attempt,
+                                ready_at,
+                            )
+                            .await?;
+                            ack_tx
+                                .send(AckMessage {
+                                    msg_id: msg.message_id().clone(),
+                                    permit: Some(permit),
+                                    is_ok: true,
+                                    is_lp,
+                                })
+                                .await?;
+
+                            return Ok(());
+                        }
+
+                        let leased = match throttle
+                            .acquire(delivery_limits.as_ref(), Target::from(&attempt))
+                            .await?
+                        {
+                            Admission::Now { leased } => leased,
+                            Admission::Later(retry_at) => {
+                                // Over the subscription's limits: wait for a turn, which is not a failure
+                                stats.record_not_ready();
+                                debug!(%request_attempt_id, %retry_at, "Request attempt throttled; delaying it without incrementing retry count");
+                                query!(
+                                    "UPDATE webhook.request_attempt SET delay_until = $1 WHERE request_attempt__id = $2",
+                                    retry_at,
+                                    request_attempt_id,
+                                )
+                                .execute(pool)
+                                .await?;
+                                requeue(
+                                    config,
+                                    hp_retry_producer,
+                                    lp_retry_producer,
+                                    is_lp,
+                                    attempt,
+                                    retry_at,
+                                )
+                                .await?;
+                                ack_tx
+                                    .send(AckMessage {
+                                        msg_id: msg.message_id().clone(),
+                                        permit: Some(permit),
+                                        is_ok: true,
+                                        is_lp,
+                                    })
+                                    .await?;
+
+                                return Ok(());
+                            }
+                        };
+
+                        // Record queue lag: time between becoming eligible and pickup
                         if let Ok(lag) = (picked_at - eligible_at).to_std() {
                             stats.record_lag(lag, attempt_is_hp);
                         }
@@ -860,6 +1049,46 @@ async fn handle_message(
                             delay_until,
                         ));
 
+                        if let Some(batching) = batching {
+                            deliver_batch(
+                                config,
+                                retry_policy,
+                                pool,
+                                object_storage,
+                                worker_name,
+                                worker_version,
+                                hp_retry_producer,
+                                lp_retry_producer,
+                                &batching,
+                                Member {
+                                    attempt,
+                                    ordering_key,
+                                },
+                                picked_at,
+                                stats,
+                                is_lp,
+                                resolver,
+                                tokens,
+                                clients,
+                            )
+                            .await?;
+
+                            if leased {
+                                throttle.release(request_attempt_id).await;
+                            }
+
+                            ack_tx
+                                .send(AckMessage {
+                                    msg_id: msg.message_id().clone(),
+                                    permit: Some(permit),
+                                    is_ok: true,
+                                    is_lp,
+                                })
+                                .await?;
+
+                            return Ok(());
+                        }
+
                         // Start OpenTelemetry span
                         let span = start_request_attempt_span(&attempt);
                         // Stamp the delivery log lines with the span's trace/span ids so
@@ -867,7 +1096,26 @@ async fn handle_message(
                         let ids = delivery_trace_ids(&span);
 
                         // Work
-                        let response = work(config, resolver, &attempt, &ids).await;
+                        let credentials = DeliveryCredentials::load(
+                            pool,
+                            config.client_certificate_encryption_key.as_ref(),
+                            attempt.application_id,
+                            attempt.subscription_id,
+                        )
+                        .await?;
+                        let response = work(
+                            config,
+                            resolver,
+                            tokens,
+                            clients,
+                            &attempt,
+                            credentials.client_certificate.as_ref(),
+                            credentials.oauth2.as_ref(),
+                            credentials.signing_key.as_ref(),
+                            credentials.secrets.as_ref(),
+                            &ids,
+                        )
+                        .await;
                         trace!(request_attempt_id = %attempt.request_attempt_id, trace_id = %ids.trace_id, span_id = %ids.span_id, elapsed_ms = response.elapsed_time_ms(), "Got response for request attempt");
 
                         // Open DB transaction
@@ -893,8 +1141,8 @@ async fn handle_message(
                         };
                         let response_id = query!(
                             "
-                                INSERT INTO webhook.response (response_error__name, http_code, headers, body, elapsed_time_ms)
-                                VALUES ($1, $2, $3, $4, $5)
+                                INSERT INTO webhook.response (response_error__name, http_code, headers, body, elapsed_time_ms, rule_outcome)
+                                VALUES ($1, $2, $3, $4, $5, $6)
                                 RETURNING response__id
                             ",
                             response.response_error__name(),
@@ -902,6 +1150,7 @@ async fn handle_message(
                             response_contents_to_insert.map(|(_, headers)| headers.to_owned()).unwrap_or(None),
                             response_contents_to_insert.map(|(body, _)| body.to_owned()).unwrap_or(None),
                             response.elapsed_time_ms(),
+                            response.rule_outcome(),
                         )
                         .fetch_one(&mut *tx)
                         .await?
@@ -975,6 +1224,7 @@ async fn handle_message(
                                 warn!(request_attempt_id = %attempt.request_attempt_id, trace_id = %ids.trace_id, span_id = %ids.span_id, "Race detected: request attempt was already finalized by another process; skipping");
                                 true
                             } else {
+                                circuit_breaker::record_success(&mut tx, &attempt).await?;
                                 debug!(request_attempt_id = %attempt.request_attempt_id, trace_id = %ids.trace_id, span_id = %ids.span_id, "Request attempt completed successfully");
                                 false
                             }
@@ -1006,6 +1256,10 @@ async fn handle_message(
                                 warn!(request_attempt_id = %attempt.request_attempt_id, trace_id = %ids.trace_id, span_id = %ids.span_id, "Race detected: request attempt was already finalized by another process; skipping retry");
                                 true
                             } else {
+                                // Tripping the breaker disables the subscription, which makes the retry give up
+                                circuit_breaker::record_failure(&mut tx, &attempt, &response)
+                                    .await?;
+
                                 // Creating a retry request or giving up
                                 if let Some(retry_in) =
                                     compute_next_retry(&mut tx, &attempt, &response, retry_policy)
@@ -1022,8 +1276,8 @@ async fn handle_message(
                                     let retry = query_as!(
                                         Retry,
                                         "
-                                            INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, delay_until, retry_count)
-                                            VALUES ($1, $2, $3, $4, $5)
+                                            INSERT INTO webhook.request_attempt (application__id, event__id, subscription__id, delay_until, retry_count, ordering_key)
+                                            VALUES ($1, $2, $3, $4, $5, $6)
                                             RETURNING request_attempt__id, created_at
                                         ",
                                         attempt.application_id,
@@ -1031,6 +1285,7 @@ async fn handle_message(
                                         attempt.subscription_id,
                                         delay_until,
                                         next_retry_count,
+                                        ordering_key,
                                     )
                                     .fetch_one(&mut *tx)
                                     .await?;
@@ -1092,6 +1347,10 @@ async fn handle_message(
                             );
                         }
 
+                        if leased {
+                            throttle.release(request_attempt_id).await;
+                        }
+
                         // Record the bounded delivery outcome, then end the OpenTelemetry span
                         report_delivery_outcome(classify_outcome(&response));
                         end_request_attempt_span(span, &response);
@@ -1112,26 +1371,36 @@ async fn handle_message(
                     RequestAttemptStatus::Delayed { delay_until, lead } => {
                         stats.record_not_ready();
                         warn!(request_attempt_id = %attempt.request_attempt_id, lead = lead.as_seconds_f64(), "Request attempt was scheduled for later");
-                        // Re-send to the same topic (HP or LP) it came from
-                        let retry_producer = if is_lp {
-                            lp_retry_producer
-                        } else {
-                            hp_retry_producer
-                        };
-                        let request_attempt_id = attempt.request_attempt_id;
-                        let created_at = attempt.created_at;
-                        let send_future = enqueue(
-                            retry_producer,
+                        requeue(
+                            config,
+                            hp_retry_producer,
+                            lp_retry_producer,
+                            is_lp,
                             attempt,
-                            created_at,
-                            Some(delay_until),
-                            config.pulsar_send_receipt_timeout,
+                            delay_until,
                         )
                         .await?;
-                        await_receipt(
-                            send_future,
-                            config.pulsar_send_receipt_timeout,
-                            request_attempt_id,
+                        ack_tx
+                            .send(AckMessage {
+                                msg_id: msg.message_id().clone(),
+                                permit: Some(permit),
+                                is_ok: true,
+                                is_lp,
+                            })
+                            .await?;
+
+                        Ok(())
+                    }
+                    RequestAttemptStatus::OrderedBehind => {
+                        stats.record_not_ready();
+                        trace!(request_attempt_id = %attempt.request_attempt_id, "Request attempt is waiting for an earlier one with the same ordering key");
+                        requeue(
+                            config,
+                            hp_retry_producer,
+                            lp_retry_producer,
+                            is_lp,
+                            attempt,
+                            Utc::now() + ORDERING_RECHECK_DELAY,
                         )
                         .await?;
                         ack_tx
@@ -1237,6 +1506,133 @@ async fn handle_message(
     }
 }
 
+/// Deliver the batch of a request attempt of a subscription with batching, in the transaction
+/// that locks every request attempt of the batch. A request attempt already held by another batch
+/// is put back in the topic, to be checked again once that batch is over.
+#[allow(clippy::too_many_arguments)]
+async fn deliver_batch(
+    config: &Config,
+    retry_policy: &RetryPolicy,
+    pool: &PgPool,
+    object_storage: &Arc<Option<ObjectStorageConfig>>,
+    worker_name: &str,
+    worker_version: &str,
+    hp_retry_producer: &Mutex<Producer<TokioExecutor>>,
+    lp_retry_producer: &Mutex<Producer<TokioExecutor>>,
+    batching: &Batching,
+    lead: Member,
+    picked_at: DateTime<Utc>,
+    stats: &ThroughputStats,
+    is_lp: bool,
+    resolver: &DnsResolver,
+    tokens: &OAuth2Tokens,
+    clients: &HttpClients,
+) -> anyhow::Result<()> {
+    let mut tx = pool.begin().await?;
+
+    let request_attempt_id = lead.attempt.request_attempt_id;
+    if !batch::lock(&mut tx, request_attempt_id).await? {
+        tx.rollback().await?;
+        stats.record_not_ready();
+        trace!(%request_attempt_id, "Request attempt is held by another batch");
+        requeue(
+            config,
+            hp_retry_producer,
+            lp_retry_producer,
+            is_lp,
+            lead.attempt,
+            Utc::now() + BATCH_RECHECK_DELAY,
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let batch = batch::collect(&mut tx, object_storage.as_ref().as_ref(), batching, lead).await?;
+
+    // Start OpenTelemetry span
+    let span = start_request_attempt_span(batch.first());
+    let ids = delivery_trace_ids(&span);
+
+    // Work
+    let credentials = DeliveryCredentials::load(
+        &mut *tx,
+        config.client_certificate_encryption_key.as_ref(),
+        batch.first().application_id,
+        batch.first().subscription_id,
+    )
+    .await?;
+    let response = work_batch(
+        config,
+        resolver,
+        tokens,
+        clients,
+        &batch,
+        credentials.client_certificate.as_ref(),
+        credentials.oauth2.as_ref(),
+        credentials.signing_key.as_ref(),
+        credentials.secrets.as_ref(),
+        &ids,
+    )
+    .await;
+    trace!(delivery_batch_id = %batch.id, batch_size = batch.len(), trace_id = %ids.trace_id, span_id = %ids.span_id, elapsed_ms = response.elapsed_time_ms(), "Got response for batch");
+
+    // Store response and complete or fail every request attempt of the batch
+    let retries = batch::record(
+        &mut tx,
+        object_storage.as_ref().as_ref(),
+        retry_policy,
+        worker_name,
+        worker_version,
+        picked_at,
+        &batch,
+        &response,
+    )
+    .await?;
+
+    // As for a single request attempt, a retry that cannot be published rolls the whole batch back
+    for retry in retries {
+        // Route retry to HP or LP topic based on priority cutoff
+        let retry_producer = if SlotRole::is_hp(retry.attempt.retry_count, config.hp_retry_cutoff) {
+            hp_retry_producer
+        } else {
+            lp_retry_producer
+        };
+        let request_attempt_id = retry.attempt.request_attempt_id;
+        let created_at = retry.attempt.created_at;
+        let send_future = enqueue(
+            retry_producer,
+            retry.attempt,
+            created_at,
+            Some(retry.delay_until),
+            config.pulsar_send_receipt_timeout,
+        )
+        .await?;
+        await_receipt(
+            send_future,
+            config.pulsar_send_receipt_timeout,
+            request_attempt_id,
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    for attempt in batch.attempts() {
+        stats.record_attempt(
+            response.is_success(),
+            attempt.retry_count,
+            response.elapsed_time,
+            config.hp_retry_cutoff,
+        );
+    }
+
+    // Record the bounded delivery outcome, then end the OpenTelemetry span
+    report_delivery_outcome(classify_outcome(&response));
+    end_request_attempt_span(span, &response);
+
+    Ok(())
+}
+
 /// ACK or NACK a message on the correct consumer (HP or LP) based on is_lp flag
 async fn dispatch_ack<T, E>(
     hp_consumer: &mut Consumer<T, E>,
@@ -0,0 +1,203 @@
+//! Response rules of a subscription.
+//!
+//! The rules are a JSON document stored on the subscription and validated by the API. They
+//! classify the response of an HTTP delivery: the first rule whose status codes match, along with
+//! its body text and header when it has them, gives the outcome of the delivery. A response no
+//! rule matches keeps the default outcome (a `2xx` status is a success, anything else is retried),
+//! and deliveries that got no response at all are never classified.
+
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use strum::IntoStaticStr;
+use tracing::warn;
+
+use crate::RequestAttempt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, IntoStaticStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ResponseOutcome {
+    Success,
+    Retryable,
+    /// The delivery gives up without retrying.
+    PermanentFailure,
+    /// The delivery gives up without retrying and the subscription is disabled.
+    DisableSubscription,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ResponseRule {
+    /// Exact status codes such as `410`, or classes such as `4xx`.
+    status: Vec<String>,
+    #[serde(default)]
+    body_contains: Option<String>,
+    #[serde(default)]
+    header: Option<HeaderMatch>,
+    outcome: ResponseOutcome,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct HeaderMatch {
+    name: String,
+    /// When absent, any value matches.
+    #[serde(default)]
+    value: Option<String>,
+}
+
+impl ResponseRule {
+    fn matches(&self, status: u16, headers: &HeaderMap, body: Option<&[u8]>) -> bool {
+        let status = status.to_string();
+        self.status.iter().any(|s| status_matches(s, &status))
+            && self
+                .body_contains
+                .as_ref()
+                .is_none_or(|text| body.is_some_and(|body| contains(body, text.as_bytes())))
+            && self.header.as_ref().is_none_or(|header| {
+                headers.get_all(header.name.as_str()).iter().any(|v| {
+                    header
+                        .value
+                        .as_ref()
+                        .is_none_or(|expected| v.as_bytes() == expected.as_bytes())
+                })
+            })
+    }
+}
+
+fn status_matches(pattern: &str, status: &str) -> bool {
+    match pattern.strip_suffix("xx") {
+        Some(class) => class.len() == 1 && status.starts_with(class),
+        None => pattern == status,
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.is_empty() || haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+/// The outcome the rules of the subscription of `attempt` give to a response, if one matches.
+/// Invalid rules are ignored, so that the delivery keeps its default outcome.
+pub fn classify(
+    attempt: &RequestAttempt,
+    status: u16,
+    headers: &HeaderMap,
+    body: Option<&[u8]>,
+) -> Option<ResponseOutcome> {
+    let rules = attempt.response_rules.as_ref()?;
+    match Vec::<ResponseRule>::deserialize(rules) {
+        Ok(rules) => rules
+            .iter()
+            .find(|rule| rule.matches(status, headers, body))
+            .map(|rule| rule.outcome),
+        Err(e) => {
+            warn!(subscription_id = %attempt.subscription_id, "Response rules are invalid ({e}); ignoring them");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use serde_json::json;
+
+    use super::*;
+
+    fn rules(value: serde_json::Value) -> Vec<ResponseRule> {
+        Vec::<ResponseRule>::deserialize(&value).unwrap()
+    }
+
+    fn first_match(
+        rules: &[ResponseRule],
+        status: u16,
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Option<ResponseOutcome> {
+        rules
+            .iter()
+            .find(|rule| rule.matches(status, headers, Some(body.as_bytes())))
+            .map(|rule| rule.outcome)
+    }
+
+    #[test]
+    fn statuses_match_exactly_or_by_class() {
+        assert!(status_matches("410", "410"));
+        assert!(!status_matches("410", "411"));
+        assert!(status_matches("4xx", "404"));
+        assert!(!status_matches("4xx", "500"));
+        assert!(!status_matches("xx", "404"));
+    }
+
+    #[test]
+    fn the_first_matching_rule_wins() {
+        let rules = rules(json!([
+            { "status": ["410"], "outcome": "disable_subscription" },
+            { "status": ["4xx"], "outcome": "permanent_failure" },
+            { "status": ["429"], "outcome": "retryable" },
+        ]));
+        let headers = HeaderMap::new();
+        assert_eq!(
+            first_match(&rules, 410, &headers, ""),
+            Some(ResponseOutcome::DisableSubscription)
+        );
+        assert_eq!(
+            first_match(&rules, 429, &headers, ""),
+            Some(ResponseOutcome::PermanentFailure)
+        );
+        assert_eq!(first_match(&rules, 503, &headers, ""), None);
+    }
+
+    #[test]
+    fn body_and_header_narrow_a_rule() {
+        let rules = rules(json!([
+            { "status": ["200"], "body_contains": "\"ok\":false", "outcome": "retryable" },
+            {
+                "status": ["400"],
+                "header": { "name": "X-Error-Kind", "value": "permanent" },
+                "outcome": "permanent_failure",
+            },
+            { "status": ["503"], "header": { "name": "retry-after" }, "outcome": "retryable" },
+        ]));
+        let mut headers = HeaderMap::new();
+        assert_eq!(
+            first_match(&rules, 200, &headers, r#"{"ok":false}"#),
+            Some(ResponseOutcome::Retryable)
+        );
+        assert_eq!(first_match(&rules, 200, &headers, r#"{"ok":true}"#), None);
+        assert_eq!(first_match(&rules, 400, &headers, ""), None);
+        assert_eq!(first_match(&rules, 503, &headers, ""), None);
+
+        headers.insert("x-error-kind", HeaderValue::from_static("permanent"));
+        headers.insert("retry-after", HeaderValue::from_static("120"));
+        assert_eq!(
+            first_match(&rules, 400, &headers, ""),
+            Some(ResponseOutcome::PermanentFailure)
+        );
+        assert_eq!(
+            first_match(&rules, 503, &headers, ""),
+            Some(ResponseOutcome::Retryable)
+        );
+
+        headers.insert("x-error-kind", HeaderValue::from_static("transient"));
+        assert_eq!(first_match(&rules, 400, &headers, ""), None);
+    }
+
+    #[test]
+    fn a_body_rule_does_not_match_a_response_without_body() {
+        let rules = rules(json!([
+            { "status": ["200"], "body_contains": "error", "outcome": "retryable" },
+        ]));
+        assert!(!rules[0].matches(200, &HeaderMap::new(), None));
+    }
+
+    #[test]
+    fn outcomes_are_stored_in_snake_case() {
+        assert_eq!(
+            <&'static str>::from(ResponseOutcome::DisableSubscription),
+            "disable_subscription"
+        );
+        assert_eq!(
+            <&'static str>::from(ResponseOutcome::PermanentFailure),
+            "permanent_failure"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! Ed25519 keys applications sign their deliveries with, on top of the HMAC signatures keyed with
+//! the secrets of their subscriptions.
+//!
+//! The API generates them, publishes their public keys and stores their private keys encrypted like
+//! those of client certificates, the application ID as associated data; the worker signs with the
+//! newest key of the application that is not retired, and names it in the `k` part of the
+//! signature.
+
+use aws_lc_rs::signature::Ed25519KeyPair;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::client_certificate::ClientCertificateKey;
+
+/// The key deliveries of an application are signed with.
+#[derive(Debug)]
+pub struct SigningKey {
+    pub signing_key_id: Uuid,
+    pub key_pair: Ed25519KeyPair,
+}
+
+impl SigningKey {
+    /// The key as stored, if it can be decrypted and read. A stored key that cannot be is reported
+    /// and left out, so that deliveries keep their HMAC signatures.
+    pub fn from_stored(
+        signing_key_id: Uuid,
+        application_id: Uuid,
+        encrypted_private_key: &[u8],
+        key: Option<&ClientCertificateKey>,
+    ) -> Option<Self> {
+        let Some(key) = key else {
+            error!(%signing_key_id, "Signing key cannot be used: this instance has no key to decrypt it");
+            return None;
+        };
+        let Some(private_key_pkcs8) = key.decrypt(&application_id, encrypted_private_key) else {
+            error!(%signing_key_id, "Signing key cannot be used: its private key cannot be decrypted");
+            return None;
+        };
+        match Ed25519KeyPair::from_pkcs8(&private_key_pkcs8) {
+            Ok(key_pair) => Some(Self {
+                signing_key_id,
+                key_pair,
+            }),
+            Err(e) => {
+                error!(%signing_key_id, "Signing key cannot be read: {e}");
+                None
+            }
+        }
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.key_pair.sign(message).as_ref().to_vec()
+    }
+}
@@ -0,0 +1,24 @@
+//! Secrets the deliveries of a subscription are signed with.
+//!
+//! While the secret of a subscription is being rotated, deliveries are signed with both the new
+//! secret and the one it replaced until the overlap window of the rotation is over, so that
+//! consumers verifying with either keep accepting them.
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SubscriptionSecrets {
+    pub secret: Uuid,
+    /// The secret the current one replaced, while the overlap window of its rotation is not over.
+    pub previous_secret: Option<Uuid>,
+}
+
+impl SubscriptionSecrets {
+    /// The secrets to sign with, the current one first.
+    pub fn to_strings(&self) -> Vec<String> {
+        std::iter::once(self.secret)
+            .chain(self.previous_secret)
+            .map(|secret| secret.to_string())
+            .collect()
+    }
+}
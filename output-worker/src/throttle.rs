@@ -0,0 +1,213 @@
+//! Opt-in delivery limits of a subscription.
+//!
+//! The limits are a JSON document stored on the subscription and validated by the API: a number
+//! of deliveries that can start per second and a number of deliveries that can be in flight at
+//! once, counted for the subscription alone or for every subscription of its application that
+//! delivers to the same host.
+//!
+//! Counters are shared by every worker of every process through the database. They are updated
+//! on a pool of their own, outside of the transaction in which a pg worker holds the request
+//! attempt it delivers: that transaction lasts as long as the delivery, and nothing it writes is
+//! visible to the other workers before it ends. A delivery that cannot start now is not a failure:
+//! it is deferred, and its retry count is left untouched.
+
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::Deserialize;
+use sqlx::postgres::types::PgInterval;
+use sqlx::{PgPool, query, query_scalar};
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::opentelemetry::report_request_attempt_throttled;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DeliveryLimits {
+    /// Number of deliveries that can start in a second.
+    #[serde(default)]
+    max_per_second: Option<i32>,
+    /// Number of deliveries that can be in flight at once.
+    #[serde(default)]
+    max_in_flight: Option<i32>,
+    /// Count the deliveries of every subscription of the application that delivers to the same
+    /// host, rather than those of the subscription alone.
+    #[serde(default)]
+    per_host: Option<bool>,
+}
+
+impl DeliveryLimits {
+    /// What the counters of a delivery are shared by.
+    fn throttle_key(&self, target: &Target) -> String {
+        let host = self
+            .per_host
+            .unwrap_or(false)
+            .then(|| Url::parse(target.http_url).ok())
+            .flatten()
+            .and_then(|url| url.host_str().map(str::to_lowercase));
+        match host {
+            Some(host) => format!("host:{}:{host}", target.application_id),
+            None => format!("subscription:{}", target.subscription_id),
+        }
+    }
+}
+
+/// The delivery of a request attempt, as far as its limits are concerned.
+#[derive(Debug, Clone, Copy)]
+pub struct Target<'a> {
+    pub request_attempt_id: Uuid,
+    pub application_id: Uuid,
+    pub subscription_id: Uuid,
+    pub http_url: &'a str,
+}
+
+impl<'a> From<&'a hook0_protobuf::RequestAttempt> for Target<'a> {
+    fn from(attempt: &'a hook0_protobuf::RequestAttempt) -> Self {
+        Self {
+            request_attempt_id: attempt.request_attempt_id,
+            application_id: attempt.application_id,
+            subscription_id: attempt.subscription_id,
+            http_url: &attempt.http_url,
+        }
+    }
+}
+
+/// Whether a delivery can start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Admission {
+    /// It can start now; with a lease to release once it is over when `leased`.
+    Now { leased: bool },
+    /// It must wait until then.
+    Later(DateTime<Utc>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    pool: PgPool,
+    /// How long a delivery in flight is counted if its worker never releases it.
+    lease_duration: Duration,
+}
+
+impl Throttle {
+    pub fn new(pool: PgPool, lease_duration: Duration) -> Self {
+        Self {
+            pool,
+            lease_duration,
+        }
+    }
+
+    /// Ask for the right to start delivering a request attempt. Subscriptions without limits (or
+    /// with limits that cannot be read) are always let through, without a round trip.
+    pub async fn acquire(
+        &self,
+        limits: Option<&serde_json::Value>,
+        target: Target<'_>,
+    ) -> Result<Admission, sqlx::Error> {
+        let Some(limits) = limits else {
+            return Ok(Admission::Now { leased: false });
+        };
+        let limits = match DeliveryLimits::deserialize(limits) {
+            Ok(limits) => limits,
+            Err(e) => {
+                warn!(subscription_id = %target.subscription_id, "Delivery limits are invalid ({e}); ignoring them");
+                return Ok(Admission::Now { leased: false });
+            }
+        };
+        if limits.max_per_second.is_none() && limits.max_in_flight.is_none() {
+            return Ok(Admission::Now { leased: false });
+        }
+
+        let lease_duration =
+            PgInterval::try_from(self.lease_duration).map_err(sqlx::Error::Encode)?;
+        let retry_at = query_scalar!(
+            "SELECT webhook.acquire_delivery_slot($1, $2, $3, $4, $5)",
+            limits.throttle_key(&target),
+            target.request_attempt_id,
+            limits.max_per_second,
+            limits.max_in_flight,
+            lease_duration,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        match retry_at {
+            Some(retry_at) => {
+                report_request_attempt_throttled();
+                Ok(Admission::Later(retry_at))
+            }
+            None => Ok(Admission::Now {
+                leased: limits.max_in_flight.is_some(),
+            }),
+        }
+    }
+
+    /// Stop counting a delivery as in flight.
+    pub async fn release(&self, request_attempt_id: Uuid) {
+        if let Err(e) = query!(
+            "DELETE FROM webhook.delivery_lease WHERE request_attempt__id = $1",
+            request_attempt_id
+        )
+        .execute(&self.pool)
+        .await
+        {
+            warn!(%request_attempt_id, "Could not release delivery lease; it will expire on its own: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(http_url: &str) -> Target<'_> {
+        Target {
+            request_attempt_id: Uuid::new_v4(),
+            application_id: Uuid::nil(),
+            subscription_id: Uuid::from_u128(1),
+            http_url,
+        }
+    }
+
+    fn limits(per_host: bool) -> DeliveryLimits {
+        DeliveryLimits {
+            max_per_second: Some(1),
+            max_in_flight: None,
+            per_host: Some(per_host),
+        }
+    }
+
+    #[test]
+    fn limits_are_counted_per_subscription_by_default() {
+        assert_eq!(
+            limits(false).throttle_key(&target("https://example.com/hook")),
+            "subscription:00000000-0000-0000-0000-000000000001"
+        );
+    }
+
+    #[test]
+    fn limits_can_be_counted_per_host() {
+        assert_eq!(
+            limits(true).throttle_key(&target("https://Example.COM:8443/hook?a=b")),
+            "host:00000000-0000-0000-0000-000000000000:example.com"
+        );
+    }
+
+    #[test]
+    fn limits_fall_back_to_the_subscription_without_a_host() {
+        assert_eq!(
+            limits(true).throttle_key(&target("not a url")),
+            "subscription:00000000-0000-0000-0000-000000000001"
+        );
+    }
+
+    #[test]
+    fn limits_deserialize_from_the_api_document() {
+        let l = DeliveryLimits::deserialize(
+            &serde_json::json!({ "max_in_flight": 4, "max_per_second": null }),
+        )
+        .unwrap();
+        assert_eq!(l.max_in_flight, Some(4));
+        assert_eq!(l.max_per_second, None);
+        assert_eq!(l.per_host, None);
+    }
+}
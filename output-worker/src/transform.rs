@@ -0,0 +1,232 @@
+//! Declarative reshaping of an event payload before it is delivered to a subscription.
+//!
+//! A transform is a JSON document stored on the subscription and validated by the API. It is
+//! interpreted here, never executed: the only operations are copying values between JSON
+//! pointers, wrapping the result in an envelope and adding constant fields. The output can
+//! therefore never be larger than the payload plus the constants the API allowed.
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// Only JSON payloads can be reshaped.
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PayloadTransform {
+    /// Values to copy from the payload; when absent, the whole payload is kept.
+    #[serde(default)]
+    fields: Option<Vec<FieldMapping>>,
+    /// Name of the property the result is wrapped in.
+    #[serde(default)]
+    envelope: Option<String>,
+    /// Properties added to the top level of the result, after wrapping.
+    #[serde(default)]
+    constants: Option<Map<String, Value>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct FieldMapping {
+    /// JSON pointer of the value in the payload.
+    from: String,
+    /// JSON pointer of the value in the result; defaults to `from`.
+    #[serde(default)]
+    to: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransformError {
+    #[error("Payload transform is invalid: {0}")]
+    InvalidDefinition(serde_json::Error),
+    #[error("Payload transform can only apply to a '{JSON_CONTENT_TYPE}' payload (found '{0}')")]
+    NotJson(String),
+    #[error("Payload is not valid JSON: {0}")]
+    InvalidPayload(serde_json::Error),
+    #[error("Payload transform cannot write a value at '{0}'")]
+    InvalidDestination(String),
+    #[error("Payload transform can only add constant fields to a JSON object")]
+    NotAnObject,
+}
+
+impl PayloadTransform {
+    pub fn from_value(value: &Value) -> Result<Self, TransformError> {
+        Self::deserialize(value).map_err(TransformError::InvalidDefinition)
+    }
+
+    /// Reshape `payload`, returning the body that must be sent (and signed).
+    pub fn apply(&self, content_type: &str, payload: &[u8]) -> Result<Vec<u8>, TransformError> {
+        if content_type != JSON_CONTENT_TYPE {
+            return Err(TransformError::NotJson(content_type.to_owned()));
+        }
+        let input =
+            serde_json::from_slice::<Value>(payload).map_err(TransformError::InvalidPayload)?;
+
+        let mut output = match &self.fields {
+            Some(fields) => {
+                let mut output = Value::Object(Map::new());
+                for field in fields {
+                    // A missing source is skipped rather than written as null: events of the same
+                    // type do not always carry every optional property.
+                    if let Some(value) = input.pointer(&field.from) {
+                        let to = field.to.as_deref().unwrap_or(&field.from);
+                        write_at_pointer(&mut output, to, value.to_owned())
+                            .ok_or_else(|| TransformError::InvalidDestination(to.to_owned()))?;
+                    }
+                }
+                output
+            }
+            None => input,
+        };
+
+        if let Some(envelope) = &self.envelope {
+            output = Value::Object(Map::from_iter([(envelope.to_owned(), output)]));
+        }
+
+        if let Some(constants) = &self.constants {
+            match &mut output {
+                Value::Object(o) => o.extend(constants.to_owned()),
+                _ => return Err(TransformError::NotAnObject),
+            }
+        }
+
+        // Serializing a `Value` cannot fail: its keys are always strings
+        Ok(serde_json::to_vec(&output).expect("could not serialize transformed payload"))
+    }
+}
+
+/// Write `value` at `pointer` in `target`, creating intermediate objects as needed.
+///
+/// Returns `None` when the path goes through something that is not an object, or when the
+/// pointer is malformed.
+fn write_at_pointer(target: &mut Value, pointer: &str, value: Value) -> Option<()> {
+    if pointer.is_empty() {
+        *target = value;
+        return Some(());
+    }
+
+    let mut tokens = pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(|t| t.replace("~1", "/").replace("~0", "~"))
+        .peekable();
+    let mut current = target;
+    while let Some(token) = tokens.next() {
+        let object = current.as_object_mut()?;
+        if tokens.peek().is_none() {
+            object.insert(token, value);
+            return Some(());
+        }
+        current = object
+            .entry(token)
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn apply(transform: Value, payload: Value) -> Result<Value, TransformError> {
+        let output = PayloadTransform::from_value(&transform)?.apply(
+            JSON_CONTENT_TYPE,
+            serde_json::to_vec(&payload).unwrap().as_slice(),
+        )?;
+        Ok(serde_json::from_slice(&output).unwrap())
+    }
+
+    #[test]
+    fn an_empty_transform_keeps_the_payload() {
+        let payload = json!({ "a": 1, "b": [true, null] });
+        assert_eq!(apply(json!({}), payload.clone()).unwrap(), payload);
+    }
+
+    #[test]
+    fn fields_are_picked_and_renamed() {
+        let payload = json!({
+            "user": { "id": 42, "email": "jane@example.com" },
+            "secret": "do not forward",
+        });
+        let transform = json!({
+            "fields": [
+                { "from": "/user/id", "to": "/id" },
+                { "from": "/user/email", "to": "/contact/email" },
+                { "from": "/missing" },
+            ],
+        });
+        assert_eq!(
+            apply(transform, payload).unwrap(),
+            json!({ "id": 42, "contact": { "email": "jane@example.com" } })
+        );
+    }
+
+    #[test]
+    fn a_field_keeps_its_path_when_no_destination_is_given() {
+        let payload = json!({ "a/b": { "c~d": 1 }, "e": 2 });
+        let transform = json!({ "fields": [{ "from": "/a~1b/c~0d" }] });
+        assert_eq!(
+            apply(transform, payload).unwrap(),
+            json!({ "a/b": { "c~d": 1 } })
+        );
+    }
+
+    #[test]
+    fn envelope_and_constants_are_applied_in_order() {
+        let payload = json!([1, 2, 3]);
+        let transform = json!({
+            "envelope": "data",
+            "constants": { "source": "hook0", "version": 2 },
+        });
+        assert_eq!(
+            apply(transform, payload).unwrap(),
+            json!({ "data": [1, 2, 3], "source": "hook0", "version": 2 })
+        );
+    }
+
+    #[test]
+    fn constants_override_payload_properties() {
+        let payload = json!({ "text": "hello", "channel": "general" });
+        let transform = json!({ "constants": { "channel": "alerts" } });
+        assert_eq!(
+            apply(transform, payload).unwrap(),
+            json!({ "text": "hello", "channel": "alerts" })
+        );
+    }
+
+    #[test]
+    fn constants_need_an_object() {
+        let transform = json!({ "constants": { "source": "hook0" } });
+        assert!(matches!(
+            apply(transform, json!("a string")),
+            Err(TransformError::NotAnObject)
+        ));
+    }
+
+    #[test]
+    fn a_destination_through_a_non_object_is_refused() {
+        let transform = json!({
+            "fields": [
+                { "from": "/a", "to": "/x" },
+                { "from": "/b", "to": "/x/y" },
+            ],
+        });
+        assert!(matches!(
+            apply(transform, json!({ "a": 1, "b": 2 })),
+            Err(TransformError::InvalidDestination(to)) if to == "/x/y"
+        ));
+    }
+
+    #[test]
+    fn non_json_payloads_are_refused() {
+        let transform = PayloadTransform::from_value(&json!({ "envelope": "data" })).unwrap();
+        assert!(matches!(
+            transform.apply("text/plain", b"hello"),
+            Err(TransformError::NotJson(ct)) if ct == "text/plain"
+        ));
+        assert!(matches!(
+            transform.apply(JSON_CONTENT_TYPE, b"{not json"),
+            Err(TransformError::InvalidPayload(_))
+        ));
+    }
+}
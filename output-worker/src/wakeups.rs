@@ -0,0 +1,111 @@
+//! Wakeups of idle units of a Postgres-backed worker.
+//!
+//! The database notifies the `webhook_request_attempt` channel when it stores request attempts
+//! that are due now. Units still poll, for attempts delayed to later and for notifications that
+//! were missed while the listening connection was down.
+
+use sqlx::PgPool;
+use sqlx::postgres::PgListener;
+use std::time::Duration;
+use tokio::spawn;
+use tokio::sync::watch;
+use tokio::time::{sleep, timeout};
+use tracing::{debug, trace, warn};
+
+/// Channel the database notifies when request attempts are ready to be delivered.
+const CHANNEL: &str = "webhook_request_attempt";
+
+/// Time to wait before listening again after the connection failed.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Lets a unit wait until request attempts may be ready to be delivered.
+#[derive(Debug, Clone)]
+pub struct Wakeups(watch::Receiver<u64>);
+
+impl Wakeups {
+    /// Listen for notifications on a connection of `pool`, which it keeps for itself.
+    pub fn listen(pool: PgPool) -> Self {
+        let (tx, rx) = watch::channel(0);
+        spawn(async move {
+            while !tx.is_closed() {
+                if let Err(e) = relay(&pool, &tx).await {
+                    warn!(
+                        "Could not listen for request attempt notifications, relying on polling: {e}"
+                    );
+                }
+                // Attempts stored while the connection was down were not notified
+                wake(&tx);
+                sleep(RECONNECT_DELAY).await;
+            }
+        });
+        Self(rx)
+    }
+
+    /// Consider the notifications received so far as handled; call this before looking for work.
+    pub fn mark_seen(&mut self) {
+        self.0.borrow_and_update();
+    }
+
+    /// Wait for a notification received since the last `mark_seen`, for at most `duration`.
+    pub async fn wait(&mut self, duration: Duration) {
+        let notified = async {
+            if self.0.changed().await.is_err() {
+                // Nothing will ever be notified: only polling is left
+                std::future::pending::<()>().await;
+            }
+        };
+        if timeout(duration, notified).await.is_ok() {
+            trace!("Woken up by a request attempt notification");
+        }
+    }
+}
+
+async fn relay(pool: &PgPool, tx: &watch::Sender<u64>) -> Result<(), sqlx::Error> {
+    let mut listener = PgListener::connect_with(pool).await?;
+    listener.listen(CHANNEL).await?;
+    debug!("Listening for request attempt notifications");
+
+    loop {
+        if listener.try_recv().await?.is_none() {
+            // The connection was lost and is back: attempts stored meanwhile were not notified
+            debug!("Listening for request attempt notifications again");
+        }
+        wake(tx);
+        if tx.is_closed() {
+            return Ok(());
+        }
+    }
+}
+
+fn wake(tx: &watch::Sender<u64>) {
+    tx.send_modify(|generation| *generation = generation.wrapping_add(1));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[tokio::test]
+    async fn a_notification_received_while_looking_for_work_is_not_missed() {
+        let (tx, rx) = watch::channel(0);
+        let mut wakeups = Wakeups(rx);
+        wakeups.mark_seen();
+        wake(&tx);
+
+        let start = Instant::now();
+        wakeups.wait(Duration::from_secs(10)).await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn units_fall_back_to_polling_when_nothing_is_listening() {
+        let (tx, rx) = watch::channel(0);
+        let mut wakeups = Wakeups(rx);
+        drop(tx);
+
+        let start = Instant::now();
+        wakeups.wait(Duration::from_millis(50)).await;
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
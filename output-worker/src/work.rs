@@ -1,9 +1,11 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as Base64;
 use chrono::{DateTime, Utc};
 use clap::{crate_name, crate_version};
 use hex::ToHex;
 use hmac::{Hmac, KeyInit, Mac};
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, InvalidHeaderValue};
-use reqwest::{Client, Method, Url};
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue, InvalidHeaderValue};
+use reqwest::{Client, Identity, Method, StatusCode, Url};
 use serde_json::Value;
 use sha2::Sha256;
 use std::collections::HashMap;
@@ -13,9 +15,19 @@ use std::str::FromStr;
 use std::time::{Duration, Instant};
 use strum::VariantNames;
 use tracing::{debug, error, instrument, trace, warn};
+use uuid::Uuid;
 
+use crate::batch::{BATCH_CONTENT_TYPE, Batch};
+use crate::client_certificate::ClientCertificate;
 use crate::dns::DnsResolver;
+use crate::http_clients::HttpClients;
+use crate::message_queue::{self, Message, MessageQueueTarget};
+use crate::oauth2::{OAuth2ClientCredentials, OAuth2Tokens};
 use crate::opentelemetry::DeliveryTraceIds;
+use crate::response_rules::{self, ResponseOutcome};
+use crate::signing_key::SigningKey;
+use crate::subscription_secrets::SubscriptionSecrets;
+use crate::transform::{PayloadTransform, TransformError};
 use crate::{Config, RequestAttempt, SignatureVersion};
 
 const USER_AGENT: &str = concat!(crate_name!(), "/", crate_version!());
@@ -36,6 +48,14 @@ pub enum ResponseError {
     Timeout,
     #[strum(serialize = "E_HTTP")]
     Http,
+    #[strum(serialize = "E_TRANSFORM")]
+    Transform,
+    #[strum(serialize = "E_CLIENT_CERTIFICATE")]
+    ClientCertificate,
+    #[strum(serialize = "E_OAUTH2_TOKEN")]
+    OAuth2Token,
+    #[strum(serialize = "E_MESSAGE_QUEUE")]
+    MessageQueue,
 }
 
 /// A failure that happened before any HTTP request was made: the stored URL could not be
@@ -56,6 +76,8 @@ pub struct Response {
     pub headers: Option<HeaderMap>,
     pub body: Option<Vec<u8>>,
     pub elapsed_time: Duration,
+    /// Outcome given by the response rules of the subscription, when one of them matched.
+    pub rule_outcome: Option<ResponseOutcome>,
 }
 
 impl Response {
@@ -68,6 +90,10 @@ impl Response {
         self.response_error.map(|re| re.to_string())
     }
 
+    pub fn rule_outcome(&self) -> Option<&'static str> {
+        self.rule_outcome.map(<&'static str>::from)
+    }
+
     pub fn http_code(&self) -> Option<i16> {
         self.http_code.and_then(|c| c.try_into().ok())
     }
@@ -99,15 +125,241 @@ impl Response {
 }
 
 #[instrument(skip_all, fields(request_attempt_id = %attempt.request_attempt_id, trace_id = %ids.trace_id, span_id = %ids.span_id))]
+#[allow(clippy::too_many_arguments)]
 pub async fn work(
     config: &Config,
     resolver: &DnsResolver,
+    tokens: &OAuth2Tokens,
+    clients: &HttpClients,
     attempt: &RequestAttempt,
+    client_certificate: Option<&ClientCertificate>,
+    oauth2: Option<&OAuth2ClientCredentials>,
+    signing_key: Option<&SigningKey>,
+    secrets: Option<&SubscriptionSecrets>,
     ids: &DeliveryTraceIds,
 ) -> Response {
     debug!("Processing request attempt");
     let start = Instant::now();
 
+    let destination = prepare_destination(
+        config,
+        resolver,
+        tokens,
+        attempt,
+        client_certificate,
+        oauth2,
+        start,
+    )
+    .await;
+    let et = HeaderValue::from_str(&attempt.event_type_name);
+    let event_id = HeaderValue::from_str(attempt.event_id.to_string().as_str())
+        .expect("Could not create a header value from the event ID UUID");
+    let content_type = HeaderValue::from_str(attempt.payload_content_type.as_str())
+        .expect("Could not create a header value from the event content type");
+
+    match (destination, et) {
+        (Ok(mut destination), Ok(et)) => {
+            // The body is reshaped before anything else so that the signature covers exactly what is sent
+            let body = match attempt.payload_transform.as_ref().map(|t| {
+                PayloadTransform::from_value(t)
+                    .and_then(|t| t.apply(&attempt.payload_content_type, &attempt.payload))
+            }) {
+                None => attempt.payload.clone(),
+                Some(Ok(body)) => body,
+                Some(Err(e)) => return transform_failure(e, start),
+            };
+
+            let headers = destination.headers_mut();
+            headers.insert("Content-Type", content_type);
+            headers.insert("X-Event-Id", event_id);
+            headers.insert("X-Event-Type", et);
+
+            deliver(
+                config,
+                resolver,
+                tokens,
+                clients,
+                destination,
+                body,
+                attempt,
+                signing_key,
+                secrets,
+                attempt.request_attempt_id,
+                attempt.event_id,
+                start,
+            )
+            .await
+        }
+        (Err(response), _) => *response,
+        (_, Err(_)) => {
+            let msg = format!(
+                "Event type has an invalid header value: {}",
+                attempt.event_type_name
+            );
+            warn!("{msg}");
+            Response {
+                response_error: Some(ResponseError::InvalidHeader),
+                http_code: None,
+                headers: None,
+                body: Some(msg.into_bytes()),
+                elapsed_time: start.elapsed(),
+                rule_outcome: None,
+            }
+        }
+    }
+}
+
+/// Deliver the request attempts of a batch as one HTTP request to their subscription's target.
+/// The body is a JSON array of the events; the signature covers all of it.
+#[instrument(skip_all, fields(delivery_batch_id = %batch.id, batch_size = batch.len(), trace_id = %ids.trace_id, span_id = %ids.span_id))]
+#[allow(clippy::too_many_arguments)]
+pub async fn work_batch(
+    config: &Config,
+    resolver: &DnsResolver,
+    tokens: &OAuth2Tokens,
+    clients: &HttpClients,
+    batch: &Batch,
+    client_certificate: Option<&ClientCertificate>,
+    oauth2: Option<&OAuth2ClientCredentials>,
+    signing_key: Option<&SigningKey>,
+    secrets: Option<&SubscriptionSecrets>,
+    ids: &DeliveryTraceIds,
+) -> Response {
+    debug!("Processing batch of request attempts");
+    let start = Instant::now();
+
+    match prepare_destination(
+        config,
+        resolver,
+        tokens,
+        batch.first(),
+        client_certificate,
+        oauth2,
+        start,
+    )
+    .await
+    {
+        Ok(mut destination) => {
+            let body = match batch.body() {
+                Ok(body) => body,
+                Err(e) => return transform_failure(e, start),
+            };
+
+            let headers = destination.headers_mut();
+            headers.insert("Content-Type", HeaderValue::from_static(BATCH_CONTENT_TYPE));
+            headers.insert(
+                "X-Batch-Id",
+                HeaderValue::from_str(batch.id.to_string().as_str())
+                    .expect("Could not create a header value from the batch ID UUID"),
+            );
+
+            deliver(
+                config,
+                resolver,
+                tokens,
+                clients,
+                destination,
+                body,
+                batch.first(),
+                signing_key,
+                secrets,
+                batch.id,
+                batch.id,
+                start,
+            )
+            .await
+        }
+        Err(response) => *response,
+    }
+}
+
+/// Where a delivery goes: the HTTP target or the message queue of its subscription.
+enum Destination {
+    Http(Call),
+    MessageQueue {
+        target: MessageQueueTarget,
+        /// Sent as message headers or attributes.
+        headers: HeaderMap,
+    },
+}
+
+impl Destination {
+    fn headers_mut(&mut self) -> &mut HeaderMap {
+        match self {
+            Self::Http(call) => &mut call.headers,
+            Self::MessageQueue { headers, .. } => headers,
+        }
+    }
+}
+
+/// An HTTP request ready to be signed and sent, save for its body.
+struct Call {
+    method: Method,
+    url: Url,
+    /// Vetted addresses of the target's host.
+    addrs: Vec<SocketAddr>,
+    headers: HeaderMap,
+    /// Client certificate to present, for targets requiring mutual TLS, and its fingerprint.
+    identity: Option<([u8; 32], Identity)>,
+    /// OAuth2 access token sent as a bearer token, and the subscription it was obtained for.
+    access_token: Option<(Uuid, String)>,
+}
+
+/// Check the destination of a request attempt. A destination that cannot be used yields the
+/// response to record instead.
+async fn prepare_destination(
+    config: &Config,
+    resolver: &DnsResolver,
+    tokens: &OAuth2Tokens,
+    attempt: &RequestAttempt,
+    client_certificate: Option<&ClientCertificate>,
+    oauth2: Option<&OAuth2ClientCredentials>,
+    start: Instant,
+) -> Result<Destination, Box<Response>> {
+    match &attempt.message_queue {
+        None => prepare_call(
+            config,
+            resolver,
+            tokens,
+            attempt,
+            client_certificate,
+            oauth2,
+            start,
+        )
+        .await
+        .map(Destination::Http),
+        // Queues are resolved when the message is published, since AMQP has its own client
+        Some(value) => match MessageQueueTarget::from_value(value) {
+            Ok(target) => Ok(Destination::MessageQueue {
+                target,
+                headers: HeaderMap::new(),
+            }),
+            Err(e) => {
+                error!(subscription_id = %attempt.subscription_id, "Message queue target is invalid: {e}");
+                Err(Box::new(Response {
+                    response_error: Some(ResponseError::InvalidTarget),
+                    http_code: None,
+                    headers: None,
+                    body: Some(format!("Message queue target is invalid: {e}").into_bytes()),
+                    elapsed_time: start.elapsed(),
+                    rule_outcome: None,
+                }))
+            }
+        },
+    }
+}
+
+/// Check and resolve the target of a request attempt. A target that cannot be called yields the
+/// response to record instead.
+async fn prepare_call(
+    config: &Config,
+    resolver: &DnsResolver,
+    tokens: &OAuth2Tokens,
+    attempt: &RequestAttempt,
+    client_certificate: Option<&ClientCertificate>,
+    oauth2: Option<&OAuth2ClientCredentials>,
+    start: Instant,
+) -> Result<Call, Box<Response>> {
     let m = Method::from_str(attempt.http_method.as_str());
     let u = match Url::parse(attempt.http_url.as_str()) {
         Err(e) => Err(TargetFailure {
@@ -128,174 +380,35 @@ pub async fn work(
         },
     };
     let hs = parse_headers(attempt.http_headers.clone());
-    let et = HeaderValue::from_str(&attempt.event_type_name);
-    let event_id = HeaderValue::from_str(attempt.event_id.to_string().as_str())
-        .expect("Could not create a header value from the event ID UUID");
-    let content_type = HeaderValue::from_str(attempt.payload_content_type.as_str())
-        .expect("Could not create a header value from the event content type");
-
-    match (m, u, hs, et) {
-        (Ok(method), Ok((url, addrs)), Ok(mut headers), Ok(et)) => {
-            // Pin the connection to the exact addresses we just vetted so reqwest cannot re-resolve the hostname to a different (forbidden) IP between the check and the request (DNS rebinding).
-            // Only domain hosts need this; IP-literal URLs skip DNS.
-            let pin = url.domain().map(|host| (host, addrs.as_slice()));
-            let client = match mk_http_client(config.connect_timeout, config.timeout, pin) {
-                Ok(client) => client,
-                Err(e) => {
-                    error!("Could not create HTTP client: {e}");
-                    return Response {
-                        response_error: Some(ResponseError::Unknown),
-                        http_code: None,
-                        headers: None,
-                        body: Some(e.to_string().into_bytes()),
-                        elapsed_time: start.elapsed(),
-                    };
-                }
-            };
-
-            headers.insert("Content-Type", content_type);
-            headers.insert("X-Event-Id", event_id);
-            headers.insert("X-Event-Type", et);
+    let id = client_certificate
+        .map(|c| {
+            c.identity(config.client_certificate_encryption_key.as_ref())
+                .map(|identity| (c.fingerprint(), identity))
+        })
+        .transpose();
 
-            let s = Signature::new(
-                &attempt.secret.to_string(),
-                &attempt.payload,
-                Utc::now(),
-                &headers,
-            )
-            .map_err(|e| {
-                let msg =
-                    format!("Could not construct header '{e}' because it has an invalid value");
-                warn!["{msg}"];
-                Box::new(Response {
-                    response_error: Some(ResponseError::InvalidHeader),
-                    http_code: None,
-                    headers: None,
-                    body: Some(msg.into_bytes()),
-                    elapsed_time: start.elapsed(),
-                })
-            })
-            .and_then(|sig| {
-                sig.to_header_value(
-                    config
-                        .enabled_signature_versions
-                        .contains(&SignatureVersion::V0),
-                    config
-                        .enabled_signature_versions
-                        .contains(&SignatureVersion::V1),
-                )
-                .map_err(|_| {
-                    Box::new(Response {
-                        response_error: Some(ResponseError::InvalidHeader),
-                        http_code: None,
-                        headers: None,
-                        body: None,
-                        elapsed_time: start.elapsed(),
-                    })
-                })
-            });
-
-            match s {
-                Ok(sig) => {
-                    headers.insert(&config.signature_header_name, sig);
-
-                    debug!("Calling webhook...");
-                    let redacted_headers = RedactedHeaders {
-                        headers: &headers,
-                        safe_headers: &[
-                            HeaderName::from_static("content-type"),
-                            HeaderName::from_static("x-event-id"),
-                            HeaderName::from_static("x-event-type"),
-                            config.signature_header_name.clone(),
-                        ],
-                    };
-                    trace!(
-                        http_method = %method.to_string().to_uppercase(),
-                        %url,
-                        headers = ?redacted_headers,
-                        headers_count = headers.len(),
-                        "Calling webhook"
-                    );
-                    let response = client
-                        .request(method, url)
-                        .headers(headers)
-                        .body(attempt.payload.clone())
-                        .send()
-                        .await;
-
-                    match response {
-                        Ok(res) => {
-                            let status = res.status();
-                            let headers = res.headers().clone();
-                            let body = res.bytes().await.ok().map(|b| b.to_vec());
-
-                            if status.is_success() {
-                                debug!("Webhook call was successful");
-                                Response {
-                                    response_error: None,
-                                    http_code: Some(status.as_u16()),
-                                    headers: Some(headers),
-                                    body,
-                                    elapsed_time: start.elapsed(),
-                                }
-                            } else {
-                                warn!(http_status = %status, "Webhook call failed with HTTP error");
-                                Response {
-                                    response_error: Some(ResponseError::Http),
-                                    http_code: Some(status.as_u16()),
-                                    headers: Some(headers),
-                                    body,
-                                    elapsed_time: start.elapsed(),
-                                }
-                            }
-                        }
-                        Err(e) if e.is_connect() => {
-                            warn!("Webhook call failed with connection error: {e}");
-                            Response {
-                                response_error: Some(ResponseError::Connection),
-                                http_code: None,
-                                headers: None,
-                                body: Some(e.to_string().into_bytes()),
-                                elapsed_time: start.elapsed(),
-                            }
-                        }
-                        Err(e) if e.is_timeout() => {
-                            warn!("Webhook call failed with timeout error: {e}");
-                            Response {
-                                response_error: Some(ResponseError::Timeout),
-                                http_code: None,
-                                headers: None,
-                                body: Some(e.to_string().into_bytes()),
-                                elapsed_time: start.elapsed(),
-                            }
-                        }
-                        Err(e) => {
-                            warn!("Webhook call failed with unknown error: {e}");
-                            Response {
-                                response_error: Some(ResponseError::Unknown),
-                                http_code: None,
-                                headers: None,
-                                body: Some(e.to_string().into_bytes()),
-                                elapsed_time: start.elapsed(),
-                            }
-                        }
-                    }
-                }
-                Err(e) => *e,
-            }
-        }
+    let mut call = match (m, u, hs, id) {
+        (Ok(method), Ok((url, addrs)), Ok(headers), Ok(identity)) => Call {
+            method,
+            url,
+            addrs,
+            headers,
+            identity,
+            access_token: None,
+        },
         (Err(e), _, _, _) => {
             error!(
                 target_http_method = attempt.http_method,
                 "Target has an invalid HTTP method: {e}"
             );
-            Response {
+            return Err(Box::new(Response {
                 response_error: Some(ResponseError::InvalidTarget),
                 http_code: None,
                 headers: None,
                 body: Some(e.to_string().into_bytes()),
                 elapsed_time: start.elapsed(),
-            }
+                rule_outcome: None,
+            }));
         }
         (_, Err(f), _, _) => {
             warn!(
@@ -304,47 +417,354 @@ pub async fn work(
                 detail = f.detail.as_deref(),
                 "Could not use target URL: {}", f.message
             );
-            Response {
+            return Err(Box::new(Response {
                 response_error: Some(f.response_error),
                 http_code: None,
                 headers: None,
                 body: Some(f.message.into_bytes()),
                 elapsed_time: start.elapsed(),
-            }
+                rule_outcome: None,
+            }));
         }
         (_, _, Err(e), _) => {
             warn!("Target has invalid headers: {e}");
-            Response {
+            return Err(Box::new(Response {
                 response_error: Some(ResponseError::InvalidTarget),
                 http_code: None,
                 headers: None,
                 body: Some(e.to_string().into_bytes()),
                 elapsed_time: start.elapsed(),
+                rule_outcome: None,
+            }));
+        }
+        (_, _, _, Err(e)) => {
+            error!(subscription_id = %attempt.subscription_id, "{e}");
+            return Err(Box::new(Response {
+                response_error: Some(ResponseError::ClientCertificate),
+                http_code: None,
+                headers: None,
+                body: Some(e.into_bytes()),
+                elapsed_time: start.elapsed(),
+                rule_outcome: None,
+            }));
+        }
+    };
+
+    // The token replaces any Authorization header of the target
+    if let Some(credentials) = oauth2 {
+        match tokens.access_token(config, resolver, credentials).await {
+            Ok(access_token) => {
+                let mut value = HeaderValue::from_str(&format!("Bearer {access_token}"))
+                    .expect("Could not create a header value from a checked access token");
+                value.set_sensitive(true);
+                call.headers.insert(AUTHORIZATION, value);
+                call.access_token = Some((credentials.subscription_id, access_token));
+            }
+            Err(e) => {
+                warn!(subscription_id = %attempt.subscription_id, "{e}");
+                return Err(Box::new(Response {
+                    response_error: Some(ResponseError::OAuth2Token),
+                    http_code: None,
+                    headers: None,
+                    body: Some(e.into_bytes()),
+                    elapsed_time: start.elapsed(),
+                    rule_outcome: None,
+                }));
             }
         }
-        (_, _, _, Err(_)) => {
-            let msg = format!(
-                "Event type has an invalid header value: {}",
-                attempt.event_type_name
-            );
-            warn!("{msg}");
-            Response {
+    }
+
+    Ok(call)
+}
+
+fn transform_failure(e: TransformError, start: Instant) -> Response {
+    warn!("Could not transform payload: {e}");
+    Response {
+        response_error: Some(ResponseError::Transform),
+        http_code: None,
+        headers: None,
+        body: Some(e.to_string().into_bytes()),
+        elapsed_time: start.elapsed(),
+        rule_outcome: None,
+    }
+}
+
+/// Sign `body` and deliver it to its destination; `message_id` identifies what is delivered, the
+/// request attempt or the batch, to message queues, and `webhook_id`, the event or the batch, to
+/// Standard Webhooks consumers (it stays the same when a delivery is retried).
+#[allow(clippy::too_many_arguments)]
+async fn deliver(
+    config: &Config,
+    resolver: &DnsResolver,
+    tokens: &OAuth2Tokens,
+    clients: &HttpClients,
+    destination: Destination,
+    body: Vec<u8>,
+    attempt: &RequestAttempt,
+    signing_key: Option<&SigningKey>,
+    secrets: Option<&SubscriptionSecrets>,
+    message_id: Uuid,
+    webhook_id: Uuid,
+    start: Instant,
+) -> Response {
+    let signer = Signer {
+        attempt,
+        signing_key,
+        secrets,
+        webhook_id,
+    };
+    match destination {
+        Destination::Http(call) => send(config, tokens, clients, call, body, &signer, start).await,
+        Destination::MessageQueue {
+            target,
+            mut headers,
+        } => match sign(config, &mut headers, &body, &signer, start) {
+            Ok(()) => {
+                debug!("Publishing message...");
+                let message = Message {
+                    headers: &headers,
+                    body,
+                    id: message_id,
+                    subscription_id: attempt.subscription_id,
+                };
+                message_queue::publish(config, resolver, &target, message, start).await
+            }
+            Err(response) => *response,
+        },
+    }
+}
+
+/// What signs a delivery: the secret of its subscription, following its signature scheme, and the
+/// signing key of its application, if it has one.
+struct Signer<'a> {
+    attempt: &'a RequestAttempt,
+    signing_key: Option<&'a SigningKey>,
+    /// The secrets of the subscription as they are now; the request attempt carries the one it had
+    /// when it was created, which is used when they cannot be read.
+    secrets: Option<&'a SubscriptionSecrets>,
+    /// The event or the batch delivered, for the Standard Webhooks scheme.
+    webhook_id: Uuid,
+}
+
+/// Add the signature headers of a delivery, following the signature scheme of its subscription.
+fn sign(
+    config: &Config,
+    headers: &mut HeaderMap,
+    body: &[u8],
+    signer: &Signer<'_>,
+    start: Instant,
+) -> Result<(), Box<Response>> {
+    let secrets = match signer.secrets {
+        Some(secrets) => secrets.to_strings(),
+        None => vec![signer.attempt.secret.to_string()],
+    };
+    let secrets = secrets.iter().map(String::as_str).collect::<Vec<_>>();
+    if signer.attempt.signature_scheme == STANDARD_WEBHOOKS_SCHEME {
+        StandardWebhooksSignature::new(&secrets, &signer.webhook_id.to_string(), body, Utc::now())
+            .insert_into(headers);
+        return Ok(());
+    }
+
+    let signing_key = signer.signing_key.filter(|_| {
+        config
+            .enabled_signature_versions
+            .contains(&SignatureVersion::V2)
+    });
+    Signature::new(&secrets, signing_key, body, Utc::now(), headers)
+        .map_err(|e| {
+            let msg = format!("Could not construct header '{e}' because it has an invalid value");
+            warn!["{msg}"];
+            Box::new(Response {
                 response_error: Some(ResponseError::InvalidHeader),
                 http_code: None,
                 headers: None,
                 body: Some(msg.into_bytes()),
                 elapsed_time: start.elapsed(),
+                rule_outcome: None,
+            })
+        })
+        .and_then(|sig| {
+            sig.to_header_value(
+                config
+                    .enabled_signature_versions
+                    .contains(&SignatureVersion::V0),
+                config
+                    .enabled_signature_versions
+                    .contains(&SignatureVersion::V1),
+            )
+            .map_err(|_| {
+                Box::new(Response {
+                    response_error: Some(ResponseError::InvalidHeader),
+                    http_code: None,
+                    headers: None,
+                    body: None,
+                    elapsed_time: start.elapsed(),
+                    rule_outcome: None,
+                })
+            })
+        })
+        .map(|sig| {
+            headers.insert(&config.signature_header_name, sig);
+        })
+}
+
+/// Sign `body` and send it to the target.
+async fn send(
+    config: &Config,
+    tokens: &OAuth2Tokens,
+    clients: &HttpClients,
+    call: Call,
+    body: Vec<u8>,
+    signer: &Signer<'_>,
+    start: Instant,
+) -> Response {
+    let Call {
+        method,
+        url,
+        addrs,
+        mut headers,
+        identity,
+        access_token,
+    } = call;
+
+    // The client is pinned to the exact addresses we just vetted so reqwest cannot re-resolve the hostname to a different (forbidden) IP between the check and the request (DNS rebinding).
+    let host = url.host_str().unwrap_or_default();
+    let client = match clients.client(host, url.domain().is_some(), &addrs, identity) {
+        Ok(client) => client,
+        Err(e) => {
+            error!("Could not create HTTP client: {e}");
+            return Response {
+                response_error: Some(ResponseError::Unknown),
+                http_code: None,
+                headers: None,
+                body: Some(e.to_string().into_bytes()),
+                elapsed_time: start.elapsed(),
+                rule_outcome: None,
+            };
+        }
+    };
+
+    match sign(config, &mut headers, &body, signer, start) {
+        Ok(()) => {
+            debug!("Calling webhook...");
+            let redacted_headers = RedactedHeaders {
+                headers: &headers,
+                safe_headers: &[
+                    HeaderName::from_static("content-type"),
+                    HeaderName::from_static("x-event-id"),
+                    HeaderName::from_static("x-event-type"),
+                    HeaderName::from_static("x-batch-id"),
+                    config.signature_header_name.clone(),
+                    HeaderName::from_static(StandardWebhooksSignature::ID_HEADER),
+                    HeaderName::from_static(StandardWebhooksSignature::TIMESTAMP_HEADER),
+                    HeaderName::from_static(StandardWebhooksSignature::SIGNATURE_HEADER),
+                ],
+            };
+            trace!(
+                http_method = %method.to_string().to_uppercase(),
+                %url,
+                headers = ?redacted_headers,
+                headers_count = headers.len(),
+                "Calling webhook"
+            );
+            let response = client
+                .request(method, url)
+                .headers(headers)
+                .body(body)
+                .send()
+                .await;
+
+            match response {
+                Ok(res) => {
+                    let status = res.status();
+                    let headers = res.headers().clone();
+                    let body = res.bytes().await.ok().map(|b| b.to_vec());
+
+                    let rule_outcome = response_rules::classify(
+                        signer.attempt,
+                        status.as_u16(),
+                        &headers,
+                        body.as_deref(),
+                    );
+                    let is_success = match rule_outcome {
+                        Some(outcome) => outcome == ResponseOutcome::Success,
+                        None => status.is_success(),
+                    };
+
+                    if is_success {
+                        debug!("Webhook call was successful");
+                        Response {
+                            response_error: None,
+                            http_code: Some(status.as_u16()),
+                            headers: Some(headers),
+                            body,
+                            elapsed_time: start.elapsed(),
+                            rule_outcome,
+                        }
+                    } else {
+                        warn!(http_status = %status, rule_outcome = ?rule_outcome, "Webhook call failed with HTTP error");
+                        // The target refused the token: the next delivery requests a new one
+                        if let (StatusCode::UNAUTHORIZED, Some((subscription_id, access_token))) =
+                            (status, &access_token)
+                        {
+                            tokens.forget(*subscription_id, access_token).await;
+                        }
+                        Response {
+                            response_error: Some(ResponseError::Http),
+                            http_code: Some(status.as_u16()),
+                            headers: Some(headers),
+                            body,
+                            elapsed_time: start.elapsed(),
+                            rule_outcome,
+                        }
+                    }
+                }
+                Err(e) if e.is_connect() => {
+                    warn!("Webhook call failed with connection error: {e}");
+                    Response {
+                        response_error: Some(ResponseError::Connection),
+                        http_code: None,
+                        headers: None,
+                        body: Some(e.to_string().into_bytes()),
+                        elapsed_time: start.elapsed(),
+                        rule_outcome: None,
+                    }
+                }
+                Err(e) if e.is_timeout() => {
+                    warn!("Webhook call failed with timeout error: {e}");
+                    Response {
+                        response_error: Some(ResponseError::Timeout),
+                        http_code: None,
+                        headers: None,
+                        body: Some(e.to_string().into_bytes()),
+                        elapsed_time: start.elapsed(),
+                        rule_outcome: None,
+                    }
+                }
+                Err(e) => {
+                    warn!("Webhook call failed with unknown error: {e}");
+                    Response {
+                        response_error: Some(ResponseError::Unknown),
+                        http_code: None,
+                        headers: None,
+                        body: Some(e.to_string().into_bytes()),
+                        elapsed_time: start.elapsed(),
+                        rule_outcome: None,
+                    }
+                }
             }
         }
+        Err(e) => *e,
     }
 }
 
-fn mk_http_client(
+pub fn mk_http_client(
     connect_timeout: Duration,
     timeout: Duration,
     // When set, pins DNS resolution of `host` to the already-vetted addresses so reqwest
     // cannot re-resolve the hostname to a different IP than the one we checked.
     pin: Option<(&str, &[SocketAddr])>,
+    identity: Option<Identity>,
 ) -> reqwest::Result<Client> {
     let mut builder = Client::builder()
         .connection_verbose(true)
@@ -365,6 +785,10 @@ fn mk_http_client(
         builder = builder.resolve_to_addrs(host, addrs);
     }
 
+    if let Some(identity) = identity {
+        builder = builder.identity(identity);
+    }
+
     builder.build()
 }
 
@@ -397,8 +821,12 @@ fn parse_headers(value: Value) -> anyhow::Result<HeaderMap> {
 struct Signature {
     pub timestamp: i64,
     pub headers: String,
-    pub v0: String,
-    pub v1: String,
+    /// One code per secret, the current secret first.
+    pub v0: Vec<String>,
+    /// One code per secret, the current secret first.
+    pub v1: Vec<String>,
+    /// Ed25519 signature of what `v1` covers, and the ID of the key that made it.
+    pub v2: Option<(Uuid, String)>,
 }
 
 impl Signature {
@@ -408,8 +836,11 @@ impl Signature {
     const SIGNATURE_PART_SEPARATOR: &'static str = ",";
     const SIGNATURE_PART_HEADER_NAMES_SEPARATOR: &'static str = " ";
 
+    /// `secrets` are the secrets of the subscription, the current one first; the previous one is
+    /// only there while the secret is being rotated.
     pub fn new(
-        secret: &str,
+        secrets: &[&str],
+        signing_key: Option<&SigningKey>,
         payload: &[u8],
         signed_at: DateTime<Utc>,
         headers: &HeaderMap,
@@ -432,14 +863,16 @@ impl Signature {
         };
 
         type HmacSha256 = Hmac<Sha256>;
-        let mut mac_v0 = HmacSha256::new_from_slice(secret.as_bytes()).unwrap(); // MAC can take key of any size; this should never fail
-        mac_v0.update(timestamp_str_bytes);
-        mac_v0.update(Self::PAYLOAD_SEPARATOR_BYTES);
-
-        let mut mac_v1 = mac_v0.clone();
-
-        mac_v0.update(payload);
-        let v0 = mac_v0.finalize().into_bytes().encode_hex::<String>();
+        let v0 = secrets
+            .iter()
+            .map(|secret| {
+                let mut mac_v0 = HmacSha256::new_from_slice(secret.as_bytes()).unwrap(); // MAC can take key of any size; this should never fail
+                mac_v0.update(timestamp_str_bytes);
+                mac_v0.update(Self::PAYLOAD_SEPARATOR_BYTES);
+                mac_v0.update(payload);
+                mac_v0.finalize().into_bytes().encode_hex::<String>()
+            })
+            .collect();
 
         let header_names = sorted_headers_with_lowercased_names
             .iter()
@@ -447,41 +880,72 @@ impl Signature {
             .collect::<Vec<_>>()
             .join(Self::SIGNATURE_PART_HEADER_NAMES_SEPARATOR);
 
-        mac_v1.update(header_names.as_bytes());
-        mac_v1.update(Self::PAYLOAD_SEPARATOR_BYTES);
+        let header_values = sorted_headers_with_lowercased_names
+            .iter()
+            .map(|(_k, v)| *v)
+            .collect::<Vec<_>>()
+            .join(Self::PAYLOAD_SEPARATOR);
 
-        mac_v1.update(
-            sorted_headers_with_lowercased_names
-                .iter()
-                .map(|(_k, v)| *v)
-                .collect::<Vec<_>>()
-                .join(Self::PAYLOAD_SEPARATOR)
-                .as_bytes(),
-        );
-        mac_v1.update(Self::PAYLOAD_SEPARATOR_BYTES);
+        // v2 covers the same message as v1
+        let v1_message = [
+            timestamp_str_bytes,
+            Self::PAYLOAD_SEPARATOR_BYTES,
+            header_names.as_bytes(),
+            Self::PAYLOAD_SEPARATOR_BYTES,
+            header_values.as_bytes(),
+            Self::PAYLOAD_SEPARATOR_BYTES,
+            payload,
+        ]
+        .concat();
+
+        let v1 = secrets
+            .iter()
+            .map(|secret| {
+                let mut mac_v1 = HmacSha256::new_from_slice(secret.as_bytes()).unwrap(); // MAC can take key of any size; this should never fail
+                mac_v1.update(&v1_message);
+                mac_v1.finalize().into_bytes().encode_hex::<String>()
+            })
+            .collect();
 
-        mac_v1.update(payload);
-        let v1 = mac_v1.finalize().into_bytes().encode_hex::<String>();
+        let v2 = signing_key.map(|key| {
+            (
+                key.signing_key_id,
+                key.sign(&v1_message).encode_hex::<String>(),
+            )
+        });
 
         Ok(Self {
             timestamp,
             headers: header_names,
             v0,
             v1,
+            v2,
         })
     }
 
+    /// `v2` is part of the value whenever the signature has one. A version is repeated for each
+    /// secret, and the code of the previous secret comes last: verifiers that only keep the last
+    /// code of a version then keep accepting deliveries until they switch secrets.
     pub fn value(&self, v0_enabled: bool, v1_enabled: bool) -> String {
         let timestamp_str = self.timestamp.to_string();
+        let key_id_str = self.v2.as_ref().map(|(key_id, _)| key_id.to_string());
         let mut parts = vec![("t", timestamp_str.as_str())];
 
         if v0_enabled {
-            parts.push(("v0", self.v0.as_str()));
+            parts.extend(self.v0.iter().map(|v0| ("v0", v0.as_str())));
         }
 
-        if v1_enabled {
+        if v1_enabled || self.v2.is_some() {
             parts.push(("h", self.headers.as_str()));
-            parts.push(("v1", self.v1.as_str()));
+        }
+
+        if v1_enabled {
+            parts.extend(self.v1.iter().map(|v1| ("v1", v1.as_str())));
+        }
+
+        if let (Some(key_id), Some((_, v2))) = (key_id_str.as_deref(), self.v2.as_ref()) {
+            parts.push(("k", key_id));
+            parts.push(("v2", v2.as_str()));
         }
 
         itertools::Itertools::intersperse(
@@ -502,6 +966,74 @@ impl Signature {
     }
 }
 
+/// Signature scheme of the subscriptions whose deliveries follow the Standard Webhooks specification.
+const STANDARD_WEBHOOKS_SCHEME: &str = "standard_webhooks";
+
+/// Signature of the Standard Webhooks specification: an HMAC-SHA256 of `{id}.{timestamp}.{body}`,
+/// keyed with the bytes of the subscription secret and encoded in base64.
+struct StandardWebhooksSignature {
+    pub id: String,
+    pub timestamp: i64,
+    /// One code per secret, the current secret first.
+    pub v1: Vec<String>,
+}
+
+impl StandardWebhooksSignature {
+    const ID_HEADER: &'static str = "webhook-id";
+    const TIMESTAMP_HEADER: &'static str = "webhook-timestamp";
+    const SIGNATURE_HEADER: &'static str = "webhook-signature";
+    const PAYLOAD_SEPARATOR_BYTES: &'static [u8] = b".";
+
+    pub fn new(secrets: &[&str], id: &str, payload: &[u8], signed_at: DateTime<Utc>) -> Self {
+        let timestamp = signed_at.timestamp();
+
+        type HmacSha256 = Hmac<Sha256>;
+        let v1 = secrets
+            .iter()
+            .map(|secret| {
+                let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).unwrap(); // MAC can take key of any size; this should never fail
+                mac.update(id.as_bytes());
+                mac.update(Self::PAYLOAD_SEPARATOR_BYTES);
+                mac.update(timestamp.to_string().as_bytes());
+                mac.update(Self::PAYLOAD_SEPARATOR_BYTES);
+                mac.update(payload);
+                Base64.encode(mac.finalize().into_bytes())
+            })
+            .collect();
+
+        Self {
+            id: id.to_owned(),
+            timestamp,
+            v1,
+        }
+    }
+
+    /// The value of the `webhook-signature` header, whose space-separated signatures are each
+    /// enough to verify the delivery.
+    pub fn value(&self) -> String {
+        self.v1
+            .iter()
+            .map(|v1| format!("v1,{v1}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Add the three headers of the scheme; their values are made of visible ASCII only.
+    pub fn insert_into(&self, headers: &mut HeaderMap) {
+        for (name, value) in [
+            (Self::ID_HEADER, self.id.to_owned()),
+            (Self::TIMESTAMP_HEADER, self.timestamp.to_string()),
+            (Self::SIGNATURE_HEADER, self.value()),
+        ] {
+            headers.insert(
+                name,
+                HeaderValue::from_str(&value)
+                    .expect("Could not create a header value from a Standard Webhooks signature"),
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -524,6 +1056,7 @@ mod tests {
             headers: Some(headers),
             body: None,
             elapsed_time: Duration::from_millis(1),
+            rule_outcome: None,
         };
 
         let read = response.headers().expect("headers must be readable");
@@ -546,6 +1079,7 @@ mod tests {
             headers: Some(headers),
             body: None,
             elapsed_time: Duration::from_millis(1),
+            rule_outcome: None,
         };
 
         let read = response.headers().expect("headers must be readable");
@@ -558,7 +1092,14 @@ mod tests {
         let payload = "hello !";
         let secret = "secret";
 
-        let sig = Signature::new(secret, payload.as_bytes(), signed_at, &HeaderMap::new()).unwrap();
+        let sig = Signature::new(
+            &[secret],
+            None,
+            payload.as_bytes(),
+            signed_at,
+            &HeaderMap::new(),
+        )
+        .unwrap();
         assert_eq!(
             sig.value(true, false),
             "t=1636936200,v0=1b3d69df55f1e52f05224ba94a5162abeb17ef52cd7f4948c390f810d6a87e98"
@@ -582,7 +1123,7 @@ mod tests {
                 .expect("Invalid header values"),
         );
 
-        let sig = Signature::new(secret, payload.as_bytes(), signed_at, &headers).unwrap();
+        let sig = Signature::new(&[secret], None, payload.as_bytes(), signed_at, &headers).unwrap();
         assert_eq!(
             sig.value(false, true),
             "t=1636936200,h=x-event-id x-event-type,v1=bc521546ba5de381b12f135782d2008b028c3065c191760b12b76850a8fc8f51"
@@ -605,13 +1146,119 @@ mod tests {
             HeaderValue::from_str("service.resource.verb").expect("Invalid header values"),
         );
 
-        let sig = Signature::new(secret, payload.as_bytes(), signed_at, &headers).unwrap();
+        let sig = Signature::new(&[secret], None, payload.as_bytes(), signed_at, &headers).unwrap();
         assert_eq!(
             sig.value(true, true),
             "t=1636936200,v0=1b3d69df55f1e52f05224ba94a5162abeb17ef52cd7f4948c390f810d6a87e98,h=x-event-id x-event-type,v1=bc521546ba5de381b12f135782d2008b028c3065c191760b12b76850a8fc8f51"
         );
     }
 
+    #[test]
+    fn create_signature_v1_and_v2() {
+        let signed_at = Utc.with_ymd_and_hms(2021, 11, 15, 0, 30, 0).unwrap();
+        let payload = "hello !";
+        let secret = "secret";
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Event-Id",
+            HeaderValue::from_str("1a01cb48-5142-4d9b-8f90-d20cca61f0ee")
+                .expect("Invalid header values"),
+        );
+        headers.insert(
+            "X-Event-Type",
+            HeaderValue::from_str("service.resource.verb").expect("Invalid header values"),
+        );
+        let signing_key = SigningKey {
+            signing_key_id: uuid::uuid!("0192a3b4-c5d6-7e8f-9a0b-1c2d3e4f5a6b"),
+            key_pair: aws_lc_rs::signature::Ed25519KeyPair::from_seed_unchecked(&[7; 32]).unwrap(),
+        };
+
+        let sig = Signature::new(
+            &[secret],
+            Some(&signing_key),
+            payload.as_bytes(),
+            signed_at,
+            &headers,
+        )
+        .unwrap();
+        assert_eq!(
+            sig.value(false, true),
+            "t=1636936200,h=x-event-id x-event-type,v1=bc521546ba5de381b12f135782d2008b028c3065c191760b12b76850a8fc8f51,k=0192a3b4-c5d6-7e8f-9a0b-1c2d3e4f5a6b,v2=87ef31f30b36d4207855dcfffe757006d4b93221964cd3f214cebf867bf37cac80f23790c7b6498c5e37911f2d41f6c4811998d0da3ae643454bee32411e2b06"
+        );
+        // Without v1, the header names are still given for v2
+        assert_eq!(
+            sig.value(false, false),
+            "t=1636936200,h=x-event-id x-event-type,k=0192a3b4-c5d6-7e8f-9a0b-1c2d3e4f5a6b,v2=87ef31f30b36d4207855dcfffe757006d4b93221964cd3f214cebf867bf37cac80f23790c7b6498c5e37911f2d41f6c4811998d0da3ae643454bee32411e2b06"
+        );
+    }
+
+    #[test]
+    fn create_signature_during_a_secret_rotation() {
+        let signed_at = Utc.with_ymd_and_hms(2021, 11, 15, 0, 30, 0).unwrap();
+        let payload = "hello !";
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Event-Id",
+            HeaderValue::from_str("1a01cb48-5142-4d9b-8f90-d20cca61f0ee")
+                .expect("Invalid header values"),
+        );
+        headers.insert(
+            "X-Event-Type",
+            HeaderValue::from_str("service.resource.verb").expect("Invalid header values"),
+        );
+
+        let sig = Signature::new(
+            &["secret", "previous"],
+            None,
+            payload.as_bytes(),
+            signed_at,
+            &headers,
+        )
+        .unwrap();
+        assert_eq!(
+            sig.value(true, true),
+            "t=1636936200,v0=1b3d69df55f1e52f05224ba94a5162abeb17ef52cd7f4948c390f810d6a87e98,v0=f808f7b214f3f0ab62af1cb7080b9ff15045e1d1c50b78d97adacc2d2fbdc14c,h=x-event-id x-event-type,v1=bc521546ba5de381b12f135782d2008b028c3065c191760b12b76850a8fc8f51,v1=692078c799492ed2b21bac350d9840d6fd93ace67751f8930a4b8dca23db9ffd"
+        );
+
+        let sig = StandardWebhooksSignature::new(
+            &["secret", "previous"],
+            "1a01cb48-5142-4d9b-8f90-d20cca61f0ee",
+            payload.as_bytes(),
+            signed_at,
+        );
+        assert_eq!(
+            sig.value(),
+            "v1,jv6nrhFagT2ZrtruVCw4OdKkVhut1Qa4CaD0Pw4/oGk= v1,RYUXcwmE0o1wVcUpSlT41vxjM9uZlZxPYvWyO+Jyefk="
+        );
+    }
+
+    #[test]
+    fn create_standard_webhooks_signature() {
+        let signed_at = Utc.with_ymd_and_hms(2021, 11, 15, 0, 30, 0).unwrap();
+        let sig = StandardWebhooksSignature::new(
+            &["secret"],
+            "1a01cb48-5142-4d9b-8f90-d20cca61f0ee",
+            "hello !".as_bytes(),
+            signed_at,
+        );
+        assert_eq!(
+            sig.value(),
+            "v1,jv6nrhFagT2ZrtruVCw4OdKkVhut1Qa4CaD0Pw4/oGk="
+        );
+
+        let mut headers = HeaderMap::new();
+        sig.insert_into(&mut headers);
+        assert_eq!(
+            headers["webhook-id"],
+            "1a01cb48-5142-4d9b-8f90-d20cca61f0ee"
+        );
+        assert_eq!(headers["webhook-timestamp"], "1636936200");
+        assert_eq!(
+            headers["webhook-signature"],
+            "v1,jv6nrhFagT2ZrtruVCw4OdKkVhut1Qa4CaD0Pw4/oGk="
+        );
+    }
+
     #[test]
     fn create_signature_wrong_header() {
         let signed_at = Utc.with_ymd_and_hms(2021, 11, 15, 0, 30, 0).unwrap();
@@ -624,7 +1271,7 @@ mod tests {
             HeaderValue::from_str("pj.cartão.autorização").expect("Invalid header values"),
         );
 
-        let sig = Signature::new(secret, payload.as_bytes(), signed_at, &headers);
+        let sig = Signature::new(&[secret], None, payload.as_bytes(), signed_at, &headers);
         assert!(matches!(sig, Err(h) if h == HeaderName::from_static("x-event-type")));
     }
 }
@@ -22,6 +22,13 @@ pub struct RequestAttempt {
     pub payload: Vec<u8>,
     pub payload_content_type: String,
     pub secret: Uuid,
+    pub payload_transform: Option<serde_json::Value>,
+    /// The message queue to deliver to instead of the HTTP target, when the subscription has one
+    pub message_queue: Option<serde_json::Value>,
+    /// How the delivery is signed: `hook0` or `standard_webhooks`; empty in messages produced before it was added, which are signed with `hook0`
+    pub signature_scheme: String,
+    /// The response rules of the subscription, deciding the outcome of HTTP deliveries from their response
+    pub response_rules: Option<serde_json::Value>,
 }
 
 impl TryFrom<crate::raw_proto::request_attempt::RequestAttempt> for RequestAttempt {
@@ -81,6 +88,30 @@ impl TryFrom<crate::raw_proto::request_attempt::RequestAttempt> for RequestAttem
                 error,
                 str: value.secret,
             })?;
+        // This field was added afterwards; an absent value means the payload is delivered verbatim
+        let payload_transform = value
+            .payload_transform
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Hook0ProtobufError::ProstWktTypesToSerdeJsonValue {
+                error: e.to_string(),
+            })?;
+        // This field was added afterwards; an absent value means the target is the HTTP one
+        let message_queue = value
+            .message_queue
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Hook0ProtobufError::ProstWktTypesToSerdeJsonValue {
+                error: e.to_string(),
+            })?;
+        // This field was added afterwards; an absent value means the default outcomes apply
+        let response_rules = value
+            .response_rules
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Hook0ProtobufError::ProstWktTypesToSerdeJsonValue {
+                error: e.to_string(),
+            })?;
 
         Ok(Self {
             application_id,
@@ -97,6 +128,10 @@ impl TryFrom<crate::raw_proto::request_attempt::RequestAttempt> for RequestAttem
             payload: value.payload,
             payload_content_type: value.payload_content_type,
             secret,
+            payload_transform,
+            message_queue,
+            signature_scheme: value.signature_scheme,
+            response_rules,
         })
     }
 }
@@ -114,6 +149,27 @@ impl TryFrom<RequestAttempt> for crate::raw_proto::request_attempt::RequestAttem
                 }
             })?,
         );
+        let payload_transform = value
+            .payload_transform
+            .map(serde_json::from_value::<prost_wkt_types::Value>)
+            .transpose()
+            .map_err(|e| Hook0ProtobufError::SerdeJsonToProstWktTypesValue {
+                error: e.to_string(),
+            })?;
+        let message_queue = value
+            .message_queue
+            .map(serde_json::from_value::<prost_wkt_types::Value>)
+            .transpose()
+            .map_err(|e| Hook0ProtobufError::SerdeJsonToProstWktTypesValue {
+                error: e.to_string(),
+            })?;
+        let response_rules = value
+            .response_rules
+            .map(serde_json::from_value::<prost_wkt_types::Value>)
+            .transpose()
+            .map_err(|e| Hook0ProtobufError::SerdeJsonToProstWktTypesValue {
+                error: e.to_string(),
+            })?;
 
         Ok(Self {
             application_id: value.application_id.to_string(),
@@ -130,6 +186,10 @@ impl TryFrom<RequestAttempt> for crate::raw_proto::request_attempt::RequestAttem
             payload: value.payload,
             payload_content_type: value.payload_content_type,
             secret: value.secret.to_string(),
+            payload_transform,
+            message_queue,
+            signature_scheme: value.signature_scheme,
+            response_rules,
         })
     }
 }
@@ -196,10 +256,41 @@ mod tests {
             payload: b"this is a test payload".to_vec(),
             payload_content_type: "text/plain".to_owned(),
             secret: uuid!("00000000-0000-0000-0000-000000000004"),
+            payload_transform: Some(json!({
+                "envelope": "data",
+                "constants": { "source": "hook0" },
+            })),
+            message_queue: Some(json!({
+                "type": "amqp",
+                "url": "amqp://localhost",
+                "exchange": "events",
+            })),
+            signature_scheme: "standard_webhooks".to_owned(),
+            response_rules: Some(json!([{
+                "status": ["410"],
+                "outcome": "disable_subscription",
+            }])),
         };
         let proto_request_attempt: crate::raw_proto::request_attempt::RequestAttempt =
             request_attempt.clone().try_into().unwrap();
         let output: RequestAttempt = proto_request_attempt.try_into().unwrap();
         assert_eq!(output, request_attempt)
     }
+
+    #[test]
+    fn protobuf_conversion_without_payload_transform() {
+        let proto_request_attempt = crate::raw_proto::request_attempt::RequestAttempt {
+            request_attempt_id: "00000000-0000-0000-0000-000000000001".to_owned(),
+            event_id: "00000000-0000-0000-0000-000000000002".to_owned(),
+            subscription_id: "00000000-0000-0000-0000-000000000003".to_owned(),
+            created_at: Some(Utc.with_ymd_and_hms(2025, 10, 5, 16, 0, 42).unwrap().into()),
+            secret: "00000000-0000-0000-0000-000000000004".to_owned(),
+            ..Default::default()
+        };
+        let output: RequestAttempt = proto_request_attempt.try_into().unwrap();
+        assert_eq!(output.payload_transform, None);
+        assert_eq!(output.message_queue, None);
+        assert_eq!(output.signature_scheme, "");
+        assert_eq!(output.response_rules, None)
+    }
 }
@@ -558,7 +558,7 @@ func surface(apiURL string) error {
 	}
 	decoded("EventWithPayload", whole)
 
-	listed, failed := events.List(ctx, application)
+	listed, failed := events.List(ctx, application, nil)
 	if err := read("events.list", failed); err != nil {
 		return err
 	}
@@ -323,7 +323,7 @@ final class LiveSmokeTest {
       EventWithPayload whole = read("events.get", () -> events.get(sent, application));
       decoded("EventWithPayload", whole);
 
-      List<com.hook0.client.generated.Event> listed = read("events.list", () -> events.list(application));
+      List<com.hook0.client.generated.Event> listed = read("events.list", () -> events.list(application, null));
       assertFalse(listed.isEmpty(), "the instance ingested an event and then listed none");
       decoded("Event", listed.get(0));
 
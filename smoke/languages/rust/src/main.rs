@@ -400,8 +400,11 @@ async fn surface(settings: &Settings) -> Result<(), String> {
         events
             .ingest(EventPost {
                 application_id: settings.application_id,
+                deliver_at: None,
                 event_id: Some(Uuid::now_v7()),
                 event_type: declared.event_type_name.clone(),
+                event_type_version: None,
+                expires_at: None,
                 labels,
                 metadata: None,
                 occurred_at: Utc::now(),
@@ -416,7 +419,7 @@ async fn surface(settings: &Settings) -> Result<(), String> {
     let whole = read("events.get", events.get(&sent, &application).await)?;
     decoded("EventWithPayload", &whole);
 
-    let listed = read("events.list", events.list(&application).await)?;
+    let listed = read("events.list", events.list(&application, None).await)?;
     let event = listed
         .first()
         .ok_or("the instance ingested an event and then listed none")?;
@@ -380,7 +380,7 @@ fn surface(
     defer whole.deinit();
     try decoded(out, "EventWithPayload", whole.value);
 
-    const listed = try read(out, "events.list", events.list(allocator, application));
+    const listed = try read(out, "events.list", events.list(allocator, application, null));
     defer listed.deinit();
     if (listed.value.len == 0) {
         std.debug.print("the instance ingested an event and then listed none\n", .{});